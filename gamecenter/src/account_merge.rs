@@ -0,0 +1,426 @@
+//! 계정 병합 (사가)
+//!
+//! `gamecenter merge <source_id> <target_id> --confirm`: 원본 계정의 재화와 순위 점수를 대상
+//! 계정으로 옮기고 원본 계정을 병합됨으로 표시합니다. DB와 Redis를 한 트랜잭션으로 묶을 수
+//! 없으므로 [`shared::service::saga`]로 단계를 나누고, 중간에 실패하면 끝난 단계를 되돌립니다.
+//!
+//! 1. `lock_accounts`: 두 계정에 병합 잠금(`merge:lock:{id}`, 값은 사가 ID) 설정
+//! 2. `transfer_balance`: `account_merges` 원장 행과 함께 골드/경험치 이동 (DB 트랜잭션)
+//! 3. `move_profile`: 순위 점수 이동, `user:{id}`에 병합 표시 (`merge:ledger:{사가 ID}`에 이동량 기록)
+//! 4. `release_locks`: 병합 잠금 해제
+//!
+//! 각 단계는 사가 ID로 된 원장(DB 행, Redis 키)을 먼저 확인하므로 재개 때 다시 실행되어도
+//! 두 번 옮기지 않습니다. 진행 상황은 `SAGA_DIR`(기본 `./data/saga`)에 기록되며,
+//! 명령을 실행할 때마다 남아 있는 병합을 먼저 재개합니다.
+
+use futures::future::BoxFuture;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::info;
+
+use shared::config::db::helpers::map_sqlx_error;
+use shared::config::db::DbConnection;
+use shared::config::redis_config::RedisConfig;
+use shared::service::saga::{Saga, SagaOutcome, SagaStep};
+use shared::tool::error::AppError;
+
+use crate::scheduler::jobs::LEADERBOARD_CURRENT_KEY;
+
+/// 사가 이름 (`SAGA_DIR` 아래 디렉토리)
+pub const MERGE_SAGA: &str = "account_merge";
+
+/// 병합 잠금 유지 시간 (잠금을 풀지 못하고 죽어도 이 시간이 지나면 풀림)
+const LOCK_TTL_SECS: u64 = 600;
+
+/// 병합 원장 테이블
+pub const ACCOUNT_MERGE_SCHEMA: &str = "CREATE TABLE IF NOT EXISTS account_merges (
+        saga_id VARCHAR(64) PRIMARY KEY,
+        source_id INT UNSIGNED NOT NULL,
+        target_id INT UNSIGNED NOT NULL,
+        gold BIGINT NOT NULL,
+        experience BIGINT NOT NULL,
+        merged_at DATETIME(3) NOT NULL DEFAULT CURRENT_TIMESTAMP(3)
+    )";
+
+/// 병합 사가 컨텍스트 (단계마다 기록됨)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MergeContext {
+    pub saga_id: String,
+    pub source_id: u32,
+    pub target_id: u32,
+    /// 옮긴 골드 (DB 단계 이후)
+    pub gold: i64,
+    /// 옮긴 경험치 (DB 단계 이후)
+    pub experience: i64,
+    /// 옮긴 순위 점수 (Redis 단계 이후)
+    pub score: Option<f64>,
+}
+
+impl MergeContext {
+    pub fn new(source_id: u32, target_id: u32) -> Self {
+        Self {
+            saga_id: saga_id(source_id, target_id),
+            source_id,
+            target_id,
+            ..Default::default()
+        }
+    }
+}
+
+/// 병합 사가 ID (같은 계정 쌍은 한 번만 병합)
+pub fn saga_id(source_id: u32, target_id: u32) -> String {
+    format!("merge-{source_id}-{target_id}")
+}
+
+fn lock_key(user_id: u32) -> String {
+    format!("merge:lock:{user_id}")
+}
+
+fn ledger_key(saga_id: &str) -> String {
+    format!("merge:ledger:{saga_id}")
+}
+
+fn redis_error(error: redis::RedisError) -> AppError {
+    AppError::RedisConnection(error.to_string())
+}
+
+/// 이 사가가 잡은 잠금만 해제
+async fn release(redis: &RedisConfig, ctx: &MergeContext) -> Result<(), AppError> {
+    let mut conn = redis.get_connection();
+    for user_id in [ctx.source_id, ctx.target_id] {
+        let owner: Option<String> = conn.get(lock_key(user_id)).await.map_err(redis_error)?;
+        if owner.as_deref() == Some(ctx.saga_id.as_str()) {
+            let _: () = conn.del(lock_key(user_id)).await.map_err(redis_error)?;
+        }
+    }
+    Ok(())
+}
+
+/// 1단계: 두 계정 병합 잠금
+struct LockAccounts {
+    redis: RedisConfig,
+}
+
+impl SagaStep<MergeContext> for LockAccounts {
+    fn name(&self) -> &str {
+        "lock_accounts"
+    }
+
+    fn execute<'a>(&'a self, ctx: &'a mut MergeContext) -> BoxFuture<'a, Result<(), AppError>> {
+        Box::pin(async move {
+            let mut conn = self.redis.get_connection();
+            for user_id in [ctx.source_id, ctx.target_id] {
+                let acquired: Option<String> = redis::cmd("SET")
+                    .arg(lock_key(user_id))
+                    .arg(&ctx.saga_id)
+                    .arg("NX")
+                    .arg("EX")
+                    .arg(LOCK_TTL_SECS)
+                    .query_async(&mut conn)
+                    .await
+                    .map_err(redis_error)?;
+                if acquired.is_some() {
+                    continue;
+                }
+                // 재개 중이면 이미 이 사가가 잡은 잠금
+                let owner: Option<String> = conn.get(lock_key(user_id)).await.map_err(redis_error)?;
+                if owner.as_deref() != Some(ctx.saga_id.as_str()) {
+                    // 실패한 단계는 부분 변경을 남기지 않음
+                    release(&self.redis, ctx).await?;
+                    return Err(AppError::ServiceUnavailable(format!(
+                        "계정 {user_id}은(는) 다른 병합이 진행 중 ({})",
+                        owner.unwrap_or_default()
+                    )));
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn compensate<'a>(&'a self, ctx: &'a mut MergeContext) -> BoxFuture<'a, Result<(), AppError>> {
+        Box::pin(release(&self.redis, ctx))
+    }
+}
+
+/// 2단계: 골드/경험치 이동 (DB가 없으면 건너뜀)
+struct TransferBalance {
+    db: Option<DbConnection>,
+}
+
+impl SagaStep<MergeContext> for TransferBalance {
+    fn name(&self) -> &str {
+        "transfer_balance"
+    }
+
+    fn execute<'a>(&'a self, ctx: &'a mut MergeContext) -> BoxFuture<'a, Result<(), AppError>> {
+        Box::pin(async move {
+            let Some(db) = &self.db else {
+                return Ok(());
+            };
+            let mut tx = db.begin().await.map_err(|e| map_sqlx_error(e, "병합 트랜잭션 시작"))?;
+
+            // 재개 중이면 원장에 기록된 이동량만 다시 읽음
+            let recorded: Option<(i64, i64)> = sqlx::query_as("SELECT gold, experience FROM account_merges WHERE saga_id = ?")
+                .bind(&ctx.saga_id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| map_sqlx_error(e, "병합 원장 조회"))?;
+            if let Some((gold, experience)) = recorded {
+                (ctx.gold, ctx.experience) = (gold, experience);
+                return Ok(());
+            }
+
+            let source: Option<(i64, i64)> = sqlx::query_as("SELECT CAST(gold AS SIGNED), CAST(experience AS SIGNED) FROM players WHERE id = ? FOR UPDATE")
+                .bind(ctx.source_id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| map_sqlx_error(e, "원본 계정 조회"))?;
+            let (gold, experience) = source.ok_or_else(|| AppError::UserNotFound(ctx.source_id.to_string()))?;
+            let target = sqlx::query("UPDATE players SET gold = gold + ?, experience = experience + ? WHERE id = ?")
+                .bind(gold)
+                .bind(experience)
+                .bind(ctx.target_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| map_sqlx_error(e, "대상 계정 갱신"))?;
+            if target.rows_affected() == 0 {
+                return Err(AppError::UserNotFound(ctx.target_id.to_string()));
+            }
+            sqlx::query("UPDATE players SET gold = 0, experience = 0 WHERE id = ?")
+                .bind(ctx.source_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| map_sqlx_error(e, "원본 계정 갱신"))?;
+            sqlx::query("INSERT INTO account_merges (saga_id, source_id, target_id, gold, experience) VALUES (?, ?, ?, ?, ?)")
+                .bind(&ctx.saga_id)
+                .bind(ctx.source_id)
+                .bind(ctx.target_id)
+                .bind(gold)
+                .bind(experience)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| map_sqlx_error(e, "병합 원장 기록"))?;
+            tx.commit().await.map_err(|e| map_sqlx_error(e, "병합 트랜잭션 커밋"))?;
+
+            (ctx.gold, ctx.experience) = (gold, experience);
+            Ok(())
+        })
+    }
+
+    fn compensate<'a>(&'a self, ctx: &'a mut MergeContext) -> BoxFuture<'a, Result<(), AppError>> {
+        Box::pin(async move {
+            let Some(db) = &self.db else {
+                return Ok(());
+            };
+            let mut tx = db.begin().await.map_err(|e| map_sqlx_error(e, "병합 취소 트랜잭션 시작"))?;
+            let recorded: Option<(i64, i64)> = sqlx::query_as("SELECT gold, experience FROM account_merges WHERE saga_id = ? FOR UPDATE")
+                .bind(&ctx.saga_id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| map_sqlx_error(e, "병합 원장 조회"))?;
+            // 원장이 없으면 이미 되돌림
+            let Some((gold, experience)) = recorded else {
+                return Ok(());
+            };
+            for (user_id, sign) in [(ctx.target_id, -1), (ctx.source_id, 1)] {
+                sqlx::query("UPDATE players SET gold = gold + ?, experience = experience + ? WHERE id = ?")
+                    .bind(sign * gold)
+                    .bind(sign * experience)
+                    .bind(user_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| map_sqlx_error(e, "재화 복원"))?;
+            }
+            sqlx::query("DELETE FROM account_merges WHERE saga_id = ?")
+                .bind(&ctx.saga_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| map_sqlx_error(e, "병합 원장 삭제"))?;
+            tx.commit().await.map_err(|e| map_sqlx_error(e, "병합 취소 트랜잭션 커밋"))
+        })
+    }
+}
+
+/// 3단계: 순위 점수 이동과 병합 표시
+struct MoveProfile {
+    redis: RedisConfig,
+}
+
+impl SagaStep<MergeContext> for MoveProfile {
+    fn name(&self) -> &str {
+        "move_profile"
+    }
+
+    fn execute<'a>(&'a self, ctx: &'a mut MergeContext) -> BoxFuture<'a, Result<(), AppError>> {
+        Box::pin(async move {
+            let mut conn = self.redis.get_connection();
+            let recorded: Option<f64> = conn.get(ledger_key(&ctx.saga_id)).await.map_err(redis_error)?;
+            if recorded.is_some() {
+                ctx.score = recorded;
+                return Ok(());
+            }
+
+            let score: Option<f64> = conn.zscore(LEADERBOARD_CURRENT_KEY, ctx.source_id).await.map_err(redis_error)?;
+            let score = score.unwrap_or(0.0);
+            let mut pipe = redis::pipe();
+            pipe.atomic()
+                .set(ledger_key(&ctx.saga_id), score).ignore()
+                .hset(format!("user:{}", ctx.source_id), "merged_into", ctx.target_id).ignore()
+                .hset(format!("user:{}", ctx.target_id), "merged_from", ctx.source_id).ignore();
+            if score != 0.0 {
+                pipe.zincr(LEADERBOARD_CURRENT_KEY, ctx.target_id, score).ignore()
+                    .zrem(LEADERBOARD_CURRENT_KEY, ctx.source_id).ignore();
+            }
+            let _: () = pipe.query_async(&mut conn).await.map_err(redis_error)?;
+            ctx.score = Some(score);
+            Ok(())
+        })
+    }
+
+    fn compensate<'a>(&'a self, ctx: &'a mut MergeContext) -> BoxFuture<'a, Result<(), AppError>> {
+        Box::pin(async move {
+            let mut conn = self.redis.get_connection();
+            let recorded: Option<f64> = conn.get(ledger_key(&ctx.saga_id)).await.map_err(redis_error)?;
+            let Some(score) = recorded else {
+                return Ok(());
+            };
+            let mut pipe = redis::pipe();
+            pipe.atomic()
+                .del(ledger_key(&ctx.saga_id)).ignore()
+                .hdel(format!("user:{}", ctx.source_id), "merged_into").ignore()
+                .hdel(format!("user:{}", ctx.target_id), "merged_from").ignore();
+            if score != 0.0 {
+                pipe.zincr(LEADERBOARD_CURRENT_KEY, ctx.target_id, -score).ignore()
+                    .zadd(LEADERBOARD_CURRENT_KEY, ctx.source_id, score).ignore();
+            }
+            pipe.query_async(&mut conn).await.map_err(redis_error)
+        })
+    }
+}
+
+/// 4단계: 병합 잠금 해제
+struct ReleaseLocks {
+    redis: RedisConfig,
+}
+
+impl SagaStep<MergeContext> for ReleaseLocks {
+    fn name(&self) -> &str {
+        "release_locks"
+    }
+
+    fn execute<'a>(&'a self, ctx: &'a mut MergeContext) -> BoxFuture<'a, Result<(), AppError>> {
+        Box::pin(release(&self.redis, ctx))
+    }
+
+    /// 마지막 단계이므로 보상할 일이 없음
+    fn compensate<'a>(&'a self, _ctx: &'a mut MergeContext) -> BoxFuture<'a, Result<(), AppError>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// 계정 병합 실행기
+pub struct AccountMerge {
+    saga: Saga<MergeContext>,
+    db: Option<DbConnection>,
+}
+
+impl AccountMerge {
+    /// 병합 사가 구성 (`db`가 None이면 DB 단계를 건너뜀)
+    pub fn open(redis: RedisConfig, db: Option<DbConnection>, saga_dir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let saga = Saga::open(MERGE_SAGA, saga_dir)?
+            .step(LockAccounts { redis: redis.clone() })
+            .step(TransferBalance { db: db.clone() })
+            .step(MoveProfile { redis: redis.clone() })
+            .step(ReleaseLocks { redis });
+        Ok(Self { saga, db })
+    }
+
+    /// 원장 테이블 생성
+    pub async fn ensure_schema(&self) -> Result<(), AppError> {
+        if let Some(db) = &self.db {
+            sqlx::query(ACCOUNT_MERGE_SCHEMA)
+                .execute(db)
+                .await
+                .map_err(|e| map_sqlx_error(e, "병합 원장 스키마 생성"))?;
+        }
+        Ok(())
+    }
+
+    /// 중단된 병합 재개
+    pub async fn resume_pending(&self) -> Result<Vec<(String, SagaOutcome<MergeContext>)>, AppError> {
+        let outcomes = self.saga.resume_pending().await?;
+        for (id, outcome) in &outcomes {
+            info!(saga_id = %id, ?outcome, "중단된 계정 병합 재개");
+        }
+        Ok(outcomes)
+    }
+
+    /// 원본 계정을 대상 계정으로 병합
+    pub async fn merge(&self, source_id: u32, target_id: u32) -> Result<SagaOutcome<MergeContext>, AppError> {
+        if source_id == target_id {
+            return Err(AppError::InvalidInput("같은 계정은 병합할 수 없습니다".to_string()));
+        }
+        let ctx = MergeContext::new(source_id, target_id);
+        self.saga.run(&ctx.saga_id.clone(), ctx).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sandbox::redis::FakeRedis;
+
+    #[tokio::test]
+    async fn test_merge_moves_profile_and_releases_locks() {
+        let server = FakeRedis::bind("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let redis = RedisConfig::connect("127.0.0.1", server.local_addr().port()).await.unwrap();
+        let mut conn = redis.get_connection();
+        let _: () = conn.zadd(LEADERBOARD_CURRENT_KEY, 7, 120).await.unwrap();
+        let _: () = conn.zadd(LEADERBOARD_CURRENT_KEY, 8, 30).await.unwrap();
+
+        let dir = std::env::temp_dir().join(format!("merge_saga_{}", std::process::id()));
+        let merge = AccountMerge::open(redis.clone(), None, &dir).unwrap();
+        let SagaOutcome::Completed(ctx) = merge.merge(7, 8).await.unwrap() else {
+            panic!("merge should complete");
+        };
+        assert_eq!(ctx.score, Some(120.0));
+
+        let score: Option<f64> = conn.zscore(LEADERBOARD_CURRENT_KEY, 8).await.unwrap();
+        assert_eq!(score, Some(150.0));
+        let merged_into: Option<u32> = conn.hget("user:7", "merged_into").await.unwrap();
+        assert_eq!(merged_into, Some(8));
+        let locked: bool = conn.exists(lock_key(7)).await.unwrap();
+        assert!(!locked);
+
+        // 같은 병합을 다시 실행해도 점수를 두 번 옮기지 않음
+        assert!(matches!(merge.merge(7, 8).await.unwrap(), SagaOutcome::Completed(_)));
+        let score: Option<f64> = conn.zscore(LEADERBOARD_CURRENT_KEY, 8).await.unwrap();
+        assert_eq!(score, Some(150.0));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_merge_locked_account_is_rolled_back() {
+        let server = FakeRedis::bind("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let redis = RedisConfig::connect("127.0.0.1", server.local_addr().port()).await.unwrap();
+        let mut conn = redis.get_connection();
+        let _: () = conn.set(lock_key(8), "merge-9-8").await.unwrap();
+
+        let dir = std::env::temp_dir().join(format!("merge_saga_locked_{}", std::process::id()));
+        let merge = AccountMerge::open(redis.clone(), None, &dir).unwrap();
+        assert!(matches!(merge.merge(7, 8).await.unwrap(), SagaOutcome::Compensated { .. }));
+        let source_locked: bool = conn.exists(lock_key(7)).await.unwrap();
+        assert!(!source_locked);
+        let owner: String = conn.get(lock_key(8)).await.unwrap();
+        assert_eq!(owner, "merge-9-8");
+
+        // 잠금이 풀리면 재실행 가능
+        let _: () = conn.del(lock_key(8)).await.unwrap();
+        assert!(matches!(merge.merge(7, 8).await.unwrap(), SagaOutcome::Completed(_)));
+        assert!(merge.resume_pending().await.unwrap().is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
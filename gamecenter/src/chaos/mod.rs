@@ -0,0 +1,579 @@
+//! 카오스 테스트 모드 (`gamecenter chaos`)
+//!
+//! 샌드박스(인메모리 Redis, sqlite) 위에 게임센터를 띄우고, 정해진 시간 동안 무작위로 장애를 주입하면서
+//! 감지/복구 과정을 관찰해 복원력 리포트를 만듭니다. 외부 Redis/DB에는 접속하지 않습니다.
+//!
+//! 주입하는 장애:
+//! - `kill`: 하위 서버(gRPC/TCP/RUDP) 태스크 강제 종료, 구간이 끝나면 재시작
+//! - `redis_latency`: 모든 서버가 거치는 Redis 앞단 프록시에서 요청 지연
+//! - `packet_loss`: RUDP 앞단 중계기에서 양방향 패킷 손실
+//!
+//! 매 샘플마다 `gamecenter status`와 같은 상태 점검과 중계기를 거친 UDP 에코 측정을 기록하고,
+//! 장애별로 감지까지 걸린 시간과 해제 후 정상으로 돌아오기까지 걸린 시간을 집계합니다.
+//!
+//! 환경변수:
+//! - `CHAOS_DURATION_SECS`: 전체 실행 시간 (기본값: 120)
+//! - `CHAOS_WARMUP_SECS`: 장애 없이 관찰하는 시작 구간 (기본값: 10)
+//! - `CHAOS_FAULT_SECS`: 장애 하나의 지속 시간 (기본값: 15)
+//! - `CHAOS_FAULTS`: 주입할 장애 종류 (기본값: `kill,redis_latency,packet_loss`)
+//! - `CHAOS_SEED`: 일정 생성 시드 (같은 시드면 같은 일정, 기본값: 현재 시각)
+//! - `CHAOS_REDIS_LATENCY_MS`: Redis 요청 지연 (기본값: 300)
+//! - `CHAOS_PACKET_LOSS`: RUDP 패킷 손실률 0.0~1.0 (기본값: 0.3)
+//! - `CHAOS_SAMPLE_MS`: 상태 측정 주기 (기본값: 1000)
+
+pub mod shim;
+
+use anyhow::{anyhow, Result};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{error, info, warn};
+
+use crate::health::{HealthMonitor, OverallStatus, ServerState};
+use crate::sandbox::Sandbox;
+use crate::unified_server::UnifiedGameServer;
+use self::shim::{LatencyProxy, LossyUdpShim, UdpProber};
+
+/// 샘플마다 보내는 UDP 에코 패킷 수
+const PROBE_PACKETS: u32 = 20;
+
+/// UDP 에코 응답 대기 시간
+const PROBE_WAIT: Duration = Duration::from_millis(300);
+
+/// 장애 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultKind {
+    Kill,
+    RedisLatency,
+    PacketLoss,
+}
+
+impl FromStr for FaultKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "kill" => Ok(FaultKind::Kill),
+            "redis_latency" => Ok(FaultKind::RedisLatency),
+            "packet_loss" => Ok(FaultKind::PacketLoss),
+            other => Err(anyhow!("알 수 없는 장애 종류: {} (kill, redis_latency, packet_loss)", other)),
+        }
+    }
+}
+
+/// 주입할 장애
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Fault {
+    /// 하위 서버 태스크 강제 종료
+    Kill { server: String },
+    /// Redis 요청 지연
+    RedisLatency { delay_ms: u64 },
+    /// RUDP 패킷 손실
+    PacketLoss { rate: f64 },
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::Kill { server } => write!(f, "kill {server}"),
+            Fault::RedisLatency { delay_ms } => write!(f, "redis latency +{delay_ms}ms"),
+            Fault::PacketLoss { rate } => write!(f, "rudp packet loss {:.0}%", rate * 100.0),
+        }
+    }
+}
+
+/// 카오스 실행 설정
+#[derive(Debug, Clone)]
+pub struct ChaosConfig {
+    pub duration: Duration,
+    pub warmup: Duration,
+    pub fault_duration: Duration,
+    pub faults: Vec<FaultKind>,
+    pub seed: u64,
+    pub redis_latency: Duration,
+    pub packet_loss: f64,
+    pub sample_interval: Duration,
+}
+
+impl Default for ChaosConfig {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_secs(120),
+            warmup: Duration::from_secs(10),
+            fault_duration: Duration::from_secs(15),
+            faults: vec![FaultKind::Kill, FaultKind::RedisLatency, FaultKind::PacketLoss],
+            seed: 0,
+            redis_latency: Duration::from_millis(300),
+            packet_loss: 0.3,
+            sample_interval: Duration::from_millis(1000),
+        }
+    }
+}
+
+impl ChaosConfig {
+    /// 환경변수에서 설정 로드
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+        let secs = |key: &str, default: Duration| {
+            std::env::var(key).ok().and_then(|v| v.parse().ok()).map(Duration::from_secs).unwrap_or(default)
+        };
+        let millis = |key: &str, default: Duration| {
+            std::env::var(key).ok().and_then(|v| v.parse().ok()).map(Duration::from_millis).unwrap_or(default)
+        };
+
+        let faults = match std::env::var("CHAOS_FAULTS") {
+            Ok(list) => list.split(',').filter(|s| !s.trim().is_empty()).map(str::parse).collect::<Result<Vec<_>>>()?,
+            Err(_) => defaults.faults.clone(),
+        };
+        if faults.is_empty() {
+            return Err(anyhow!("CHAOS_FAULTS에 장애 종류가 하나 이상 필요합니다"));
+        }
+
+        Ok(Self {
+            duration: secs("CHAOS_DURATION_SECS", defaults.duration),
+            warmup: secs("CHAOS_WARMUP_SECS", defaults.warmup),
+            fault_duration: secs("CHAOS_FAULT_SECS", defaults.fault_duration).max(Duration::from_secs(1)),
+            faults,
+            seed: std::env::var("CHAOS_SEED").ok().and_then(|v| v.parse().ok())
+                .unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as u64),
+            redis_latency: millis("CHAOS_REDIS_LATENCY_MS", defaults.redis_latency),
+            packet_loss: std::env::var("CHAOS_PACKET_LOSS").ok().and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.packet_loss)
+                .clamp(0.0, 1.0),
+            sample_interval: millis("CHAOS_SAMPLE_MS", defaults.sample_interval).max(Duration::from_millis(100)),
+        })
+    }
+}
+
+/// 일정에 잡힌 장애 (실행 시작 기준 구간)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledFault {
+    pub start: Duration,
+    pub end: Duration,
+    pub fault: Fault,
+}
+
+/// 장애 주입 일정 생성
+///
+/// 준비 구간 이후부터 장애를 겹치지 않게 배치하며, 장애 사이와 마지막 장애 뒤에는
+/// 최소 장애 지속 시간만큼 복구를 관찰할 구간을 둡니다. 같은 시드면 항상 같은 일정입니다.
+pub fn plan(config: &ChaosConfig, servers: &[&str]) -> Vec<ScheduledFault> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let kinds: Vec<FaultKind> = config.faults.iter().copied()
+        .filter(|kind| *kind != FaultKind::Kill || !servers.is_empty())
+        .collect();
+    let window = config.fault_duration;
+
+    let mut schedule = Vec::new();
+    let mut at = config.warmup;
+    while !kinds.is_empty() && at + window * 2 <= config.duration {
+        let fault = match kinds.choose(&mut rng) {
+            Some(FaultKind::Kill) => Fault::Kill {
+                server: servers.choose(&mut rng).map(|s| s.to_string()).unwrap_or_default(),
+            },
+            Some(FaultKind::RedisLatency) => Fault::RedisLatency { delay_ms: config.redis_latency.as_millis() as u64 },
+            Some(FaultKind::PacketLoss) | None => Fault::PacketLoss { rate: config.packet_loss },
+        };
+        schedule.push(ScheduledFault { start: at, end: at + window, fault });
+
+        let gap = window + window.mul_f64(rng.gen_range(0.0..1.0));
+        at += window + gap;
+    }
+    schedule
+}
+
+/// 상태 측정 결과
+#[derive(Debug, Clone)]
+pub struct Sample {
+    /// 실행 시작 기준 시각
+    pub at: Duration,
+    pub status: OverallStatus,
+    /// 중지된 하위 서버
+    pub down: Vec<String>,
+    pub redis_healthy: bool,
+    pub redis_latency_ms: Option<u64>,
+    /// 중계기를 거친 UDP 에코 손실률 (RUDP 비활성화 시 None)
+    pub udp_loss: Option<f64>,
+}
+
+impl Sample {
+    /// 장애의 영향이 관찰되는지 여부
+    fn shows(&self, fault: &Fault) -> bool {
+        match fault {
+            Fault::Kill { server } => self.down.iter().any(|down| down == server),
+            Fault::RedisLatency { delay_ms } => {
+                !self.redis_healthy || self.redis_latency_ms.is_some_and(|latency| latency >= delay_ms / 2)
+            }
+            Fault::PacketLoss { rate } => self.udp_loss.is_some_and(|loss| loss > 0.0 && loss >= rate / 2.0),
+        }
+    }
+}
+
+/// 장애별 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FaultOutcome {
+    pub fault: Fault,
+    /// 주입 시각 (실행 시작 기준 초)
+    pub injected_at_secs: f64,
+    pub duration_secs: f64,
+    /// 주입 후 상태 점검에서 처음 관찰되기까지 (관찰되지 않았으면 None)
+    pub detected_after_ms: Option<u64>,
+    /// 해제 후 영향이 사라지기까지 (실행이 끝날 때까지 남아 있으면 None)
+    pub recovered_after_ms: Option<u64>,
+    /// 장애 구간의 최악 전체 상태
+    pub worst_status: OverallStatus,
+    /// 주입/해제 중 발생한 오류
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+impl FaultOutcome {
+    /// 측정 결과로 장애 결과 판정
+    pub fn evaluate(scheduled: &ScheduledFault, samples: &[Sample]) -> Self {
+        let during: Vec<&Sample> = samples.iter()
+            .filter(|sample| sample.at >= scheduled.start && sample.at < scheduled.end)
+            .collect();
+        let detected_after_ms = during.iter()
+            .find(|sample| sample.shows(&scheduled.fault))
+            .map(|sample| (sample.at - scheduled.start).as_millis() as u64);
+        let recovered_after_ms = samples.iter()
+            .find(|sample| sample.at >= scheduled.end && !sample.shows(&scheduled.fault))
+            .map(|sample| (sample.at - scheduled.end).as_millis() as u64);
+        let worst_status = during.iter()
+            .map(|sample| sample.status)
+            .max_by_key(|status| severity(*status))
+            .unwrap_or(OverallStatus::Ok);
+
+        Self {
+            fault: scheduled.fault.clone(),
+            injected_at_secs: scheduled.start.as_secs_f64(),
+            duration_secs: (scheduled.end - scheduled.start).as_secs_f64(),
+            detected_after_ms,
+            recovered_after_ms,
+            worst_status,
+            errors: Vec::new(),
+        }
+    }
+}
+
+fn severity(status: OverallStatus) -> u8 {
+    match status {
+        OverallStatus::Ok => 0,
+        OverallStatus::Degraded => 1,
+        OverallStatus::Down => 2,
+    }
+}
+
+/// 복원력 리포트 (`gamecenter chaos --json` 출력 문서)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResilienceReport {
+    pub seed: u64,
+    /// 시작 시각 (RFC 3339)
+    pub started_at: String,
+    pub duration_secs: f64,
+    pub samples: usize,
+    /// 전체 상태가 정상이었던 샘플 비율
+    pub ok_ratio: f64,
+    pub faults: Vec<FaultOutcome>,
+}
+
+impl ResilienceReport {
+    /// 모든 장애가 해제 후 복구되었는지 여부
+    pub fn all_recovered(&self) -> bool {
+        self.faults.iter().all(|outcome| outcome.recovered_after_ms.is_some() && outcome.errors.is_empty())
+    }
+
+    /// 사람이 읽는 형식으로 로그 출력
+    pub fn log(&self) {
+        info!("🌪️ 카오스 테스트 결과 (seed={}, {:.0}초, 샘플 {}개, 정상 비율 {:.1}%)",
+              self.seed, self.duration_secs, self.samples, self.ok_ratio * 100.0);
+        let ms = |value: Option<u64>| value.map(|ms| format!("{ms}ms")).unwrap_or_else(|| "-".to_string());
+        for outcome in &self.faults {
+            let mark = if outcome.recovered_after_ms.is_some() && outcome.errors.is_empty() { "✅" } else { "❌" };
+            info!("  └─ {} {:>6.1}s {} - 감지 {}, 복구 {}, 최악 상태 {:?}{}",
+                  mark, outcome.injected_at_secs, outcome.fault,
+                  ms(outcome.detected_after_ms), ms(outcome.recovered_after_ms), outcome.worst_status,
+                  if outcome.errors.is_empty() { String::new() } else { format!(" ({})", outcome.errors.join("; ")) });
+        }
+    }
+}
+
+/// 장애 주입 지점 (Redis 프록시, RUDP 중계기)
+pub struct FaultInjectors {
+    redis_proxy: LatencyProxy,
+    udp_shim: Option<LossyUdpShim>,
+}
+
+impl FaultInjectors {
+    /// 샌드박스 Redis 앞에 지연 프록시를 두고 모든 서버가 프록시를 거치도록 환경변수를 덮어씁니다.
+    ///
+    /// 다른 구성요소가 Redis에 연결하기 전에 호출해야 합니다.
+    pub async fn prepare(sandbox: &Sandbox) -> Result<Self> {
+        let redis_proxy = LatencyProxy::bind(
+            SocketAddr::from((Ipv4Addr::LOCALHOST, 0)),
+            sandbox.redis.local_addr(),
+        ).await?;
+        std::env::set_var("redis_host", redis_proxy.local_addr().ip().to_string());
+        std::env::set_var("redis_port", redis_proxy.local_addr().port().to_string());
+        info!("🌪️ Redis 지연 프록시: {} → {}", redis_proxy.local_addr(), sandbox.redis.local_addr());
+        Ok(Self { redis_proxy, udp_shim: None })
+    }
+
+    /// RUDP 서버 앞에 손실 중계기 연결
+    pub async fn attach_rudp(&mut self, rudp_addr: SocketAddr) -> Result<()> {
+        let upstream = if rudp_addr.ip().is_unspecified() {
+            SocketAddr::from((Ipv4Addr::LOCALHOST, rudp_addr.port()))
+        } else {
+            rudp_addr
+        };
+        let shim = LossyUdpShim::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0)), upstream).await?;
+        info!("🌪️ RUDP 손실 중계기: {} → {}", shim.local_addr(), upstream);
+        self.udp_shim = Some(shim);
+        Ok(())
+    }
+}
+
+/// 카오스 실행기
+pub struct ChaosRunner {
+    config: ChaosConfig,
+    server: UnifiedGameServer,
+    injectors: FaultInjectors,
+}
+
+impl ChaosRunner {
+    pub fn new(config: ChaosConfig, server: UnifiedGameServer, injectors: FaultInjectors) -> Self {
+        Self { config, server, injectors }
+    }
+
+    /// 일정대로 장애를 주입하며 측정하고 리포트 생성
+    pub async fn run(&self) -> Result<ResilienceReport> {
+        let server_config = self.server.config().clone();
+        let servers: Vec<&str> = [
+            ("grpc", server_config.enable_grpc),
+            ("tcp", server_config.enable_tcp),
+            ("rudp", server_config.enable_rudp),
+        ].into_iter().filter(|(_, enabled)| *enabled).map(|(name, _)| name).collect();
+
+        let schedule = plan(&self.config, &servers);
+        info!("🌪️ 카오스 테스트 시작: {}초, 장애 {}건 (seed={})",
+              self.config.duration.as_secs(), schedule.len(), self.config.seed);
+        for scheduled in &schedule {
+            info!("  └─ {:>6.1}s ~ {:>6.1}s {}", scheduled.start.as_secs_f64(), scheduled.end.as_secs_f64(), scheduled.fault);
+        }
+
+        let monitor = HealthMonitor::new(server_config);
+        let mut prober = match &self.injectors.udp_shim {
+            Some(shim) => Some(UdpProber::connect(shim.local_addr()).await?),
+            None => None,
+        };
+        let mut errors: Vec<Vec<String>> = vec![Vec::new(); schedule.len()];
+        let mut samples = Vec::new();
+        let mut next = 0;
+        let mut active: Option<usize> = None;
+
+        let started_at = chrono::Utc::now().to_rfc3339();
+        let started = Instant::now();
+        let mut interval = tokio::time::interval(self.config.sample_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        while started.elapsed() < self.config.duration {
+            interval.tick().await;
+            let elapsed = started.elapsed();
+
+            if let Some(index) = active.filter(|index| elapsed >= schedule[*index].end) {
+                if let Err(e) = self.clear(&schedule[index].fault).await {
+                    error!("장애 해제 실패 ({}): {:#}", schedule[index].fault, e);
+                    errors[index].push(format!("해제 실패: {e:#}"));
+                }
+                active = None;
+            }
+            if active.is_none() && next < schedule.len() && elapsed >= schedule[next].start {
+                warn!("🌪️ 장애 주입: {}", schedule[next].fault);
+                if let Err(e) = self.inject(&schedule[next].fault).await {
+                    error!("장애 주입 실패 ({}): {:#}", schedule[next].fault, e);
+                    errors[next].push(format!("주입 실패: {e:#}"));
+                }
+                active = Some(next);
+                next += 1;
+            }
+
+            let (report, udp_loss) = tokio::join!(monitor.collect(), async {
+                match prober.as_mut() {
+                    Some(prober) => Some(prober.measure(PROBE_PACKETS, PROBE_WAIT).await),
+                    None => None,
+                }
+            });
+            let redis = report.dependencies.iter().find(|dep| dep.name == "redis");
+            samples.push(Sample {
+                at: started.elapsed(),
+                status: report.status,
+                down: report.servers.iter()
+                    .filter(|server| server.state == ServerState::Down)
+                    .map(|server| server.name.clone())
+                    .collect(),
+                redis_healthy: redis.map_or(true, |dep| dep.healthy),
+                redis_latency_ms: redis.and_then(|dep| dep.latency_ms),
+                udp_loss,
+            });
+        }
+        if let Some(index) = active {
+            if let Err(e) = self.clear(&schedule[index].fault).await {
+                errors[index].push(format!("해제 실패: {e:#}"));
+            }
+        }
+
+        let faults = schedule.iter().zip(errors)
+            .map(|(scheduled, errors)| FaultOutcome { errors, ..FaultOutcome::evaluate(scheduled, &samples) })
+            .collect();
+        let ok_samples = samples.iter().filter(|sample| sample.status == OverallStatus::Ok).count();
+        Ok(ResilienceReport {
+            seed: self.config.seed,
+            started_at,
+            duration_secs: started.elapsed().as_secs_f64(),
+            samples: samples.len(),
+            ok_ratio: ok_samples as f64 / samples.len().max(1) as f64,
+            faults,
+        })
+    }
+
+    async fn inject(&self, fault: &Fault) -> Result<()> {
+        match fault {
+            Fault::Kill { server } => {
+                if !self.server.kill_server(server).await {
+                    return Err(anyhow!("실행 중인 {} 서버 태스크가 없습니다", server));
+                }
+            }
+            Fault::RedisLatency { delay_ms } => self.injectors.redis_proxy.set_delay(Duration::from_millis(*delay_ms)),
+            Fault::PacketLoss { rate } => match &self.injectors.udp_shim {
+                Some(shim) => shim.set_loss(*rate),
+                None => return Err(anyhow!("RUDP 중계기가 없습니다")),
+            },
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, fault: &Fault) -> Result<()> {
+        match fault {
+            Fault::Kill { server } => {
+                let server = match server.as_str() {
+                    "grpc" => "grpc",
+                    "tcp" => "tcp",
+                    "rudp" => "rudp",
+                    other => return Err(anyhow!("알 수 없는 서버: {}", other)),
+                };
+                info!("🌪️ {} 서버 재시작", server);
+                self.server.restart_server(server).await?;
+            }
+            Fault::RedisLatency { .. } => self.injectors.redis_proxy.set_delay(Duration::ZERO),
+            Fault::PacketLoss { .. } => {
+                if let Some(shim) = &self.injectors.udp_shim {
+                    shim.set_loss(0.0);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(seed: u64) -> ChaosConfig {
+        ChaosConfig { seed, ..ChaosConfig::default() }
+    }
+
+    fn sample(at_secs: u64, status: OverallStatus, down: &[&str], udp_loss: f64) -> Sample {
+        Sample {
+            at: Duration::from_secs(at_secs),
+            status,
+            down: down.iter().map(|s| s.to_string()).collect(),
+            redis_healthy: true,
+            redis_latency_ms: Some(1),
+            udp_loss: Some(udp_loss),
+        }
+    }
+
+    #[test]
+    fn test_plan_is_deterministic_and_leaves_recovery_gaps() {
+        let servers = ["grpc", "tcp", "rudp"];
+        let schedule = plan(&config(42), &servers);
+        assert_eq!(schedule, plan(&config(42), &servers));
+        assert!(!schedule.is_empty());
+
+        let cfg = config(42);
+        assert!(schedule[0].start >= cfg.warmup);
+        for pair in schedule.windows(2) {
+            assert!(pair[1].start >= pair[0].end + cfg.fault_duration);
+        }
+        let last = schedule.last().unwrap();
+        assert!(last.end + cfg.fault_duration <= cfg.duration);
+    }
+
+    #[test]
+    fn test_plan_skips_kill_without_servers() {
+        let cfg = ChaosConfig { faults: vec![FaultKind::Kill], ..config(1) };
+        assert!(plan(&cfg, &[]).is_empty());
+
+        let cfg = ChaosConfig { faults: vec![FaultKind::Kill, FaultKind::PacketLoss], ..config(1) };
+        assert!(plan(&cfg, &[]).iter().all(|s| matches!(s.fault, Fault::PacketLoss { .. })));
+        assert!("dns".parse::<FaultKind>().is_err());
+    }
+
+    #[test]
+    fn test_evaluate_detection_and_recovery() {
+        let scheduled = ScheduledFault {
+            start: Duration::from_secs(10),
+            end: Duration::from_secs(20),
+            fault: Fault::Kill { server: "tcp".to_string() },
+        };
+        let samples = vec![
+            sample(9, OverallStatus::Ok, &[], 0.0),
+            sample(10, OverallStatus::Ok, &[], 0.0),
+            sample(12, OverallStatus::Degraded, &["tcp"], 0.0),
+            sample(19, OverallStatus::Degraded, &["tcp"], 0.0),
+            sample(21, OverallStatus::Degraded, &["tcp"], 0.0),
+            sample(23, OverallStatus::Ok, &[], 0.0),
+        ];
+        let outcome = FaultOutcome::evaluate(&scheduled, &samples);
+        assert_eq!(outcome.detected_after_ms, Some(2_000));
+        assert_eq!(outcome.recovered_after_ms, Some(3_000));
+        assert_eq!(outcome.worst_status, OverallStatus::Degraded);
+
+        // 해제 후에도 계속 손실이 보이면 미복구
+        let scheduled = ScheduledFault { fault: Fault::PacketLoss { rate: 0.3 }, ..scheduled };
+        let samples = vec![sample(15, OverallStatus::Ok, &[], 0.5), sample(25, OverallStatus::Ok, &[], 0.4)];
+        let outcome = FaultOutcome::evaluate(&scheduled, &samples);
+        assert_eq!(outcome.detected_after_ms, Some(5_000));
+        assert_eq!(outcome.recovered_after_ms, None);
+    }
+
+    #[tokio::test]
+    async fn test_lossy_shim_relays_and_drops() {
+        let echo = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let echo_addr = echo.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buffer = [0u8; 1024];
+            while let Ok((size, peer)) = echo.recv_from(&mut buffer).await {
+                let _ = echo.send_to(&buffer[..size], peer).await;
+            }
+        });
+
+        let shim = LossyUdpShim::bind("127.0.0.1:0".parse().unwrap(), echo_addr).await.unwrap();
+        let mut prober = UdpProber::connect(shim.local_addr()).await.unwrap();
+        assert_eq!(prober.measure(10, Duration::from_millis(500)).await, 0.0);
+
+        shim.set_loss(1.0);
+        assert_eq!(prober.measure(10, Duration::from_millis(100)).await, 1.0);
+
+        shim.set_loss(0.0);
+        assert_eq!(prober.measure(10, Duration::from_millis(500)).await, 0.0);
+    }
+}
@@ -0,0 +1,232 @@
+//! 장애 주입 중계기
+//!
+//! - `LatencyProxy`: Redis 앞단 TCP 프록시. 요청 방향 데이터를 지정 시간만큼 늦춰 전달합니다.
+//! - `LossyUdpShim`: RUDP 앞단 UDP 중계기. 양방향 패킷을 지정 비율만큼 버립니다.
+//!
+//! 두 중계기 모두 평소에는 그대로 전달하며, 카오스 실행기가 장애 구간에만 값을 바꿉니다.
+
+use anyhow::{Context, Result};
+use rand::Rng;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream, UdpSocket};
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::{debug, warn};
+
+/// 손실률 단위 (백만분율)
+const LOSS_SCALE: f64 = 1_000_000.0;
+
+/// 지연 주입 TCP 프록시
+pub struct LatencyProxy {
+    local_addr: SocketAddr,
+    delay_ms: Arc<AtomicU64>,
+    handle: JoinHandle<()>,
+}
+
+impl LatencyProxy {
+    /// `listen`에서 받은 연결을 `upstream`으로 중계 (포트 0이면 임의 포트)
+    pub async fn bind(listen: SocketAddr, upstream: SocketAddr) -> Result<Self> {
+        let listener = TcpListener::bind(listen).await
+            .with_context(|| format!("지연 프록시를 {}에 바인드하는데 실패했습니다", listen))?;
+        let local_addr = listener.local_addr()?;
+        let delay_ms = Arc::new(AtomicU64::new(0));
+
+        let delay = delay_ms.clone();
+        let handle = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((client, _)) => {
+                        tokio::spawn(relay_tcp(client, upstream, delay.clone()));
+                    }
+                    Err(e) => warn!("지연 프록시 연결 승인 실패: {}", e),
+                }
+            }
+        });
+
+        Ok(Self { local_addr, delay_ms, handle })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// 요청 지연 설정 (0이면 그대로 전달)
+    pub fn set_delay(&self, delay: Duration) {
+        self.delay_ms.store(delay.as_millis() as u64, Ordering::Relaxed);
+    }
+}
+
+impl Drop for LatencyProxy {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn relay_tcp(client: TcpStream, upstream: SocketAddr, delay_ms: Arc<AtomicU64>) {
+    let server = match TcpStream::connect(upstream).await {
+        Ok(server) => server,
+        Err(e) => {
+            debug!("지연 프록시 upstream 연결 실패 ({}): {}", upstream, e);
+            return;
+        }
+    };
+    let (mut client_rx, mut client_tx) = client.into_split();
+    let (mut server_rx, mut server_tx) = server.into_split();
+
+    let request = async move {
+        let mut buffer = vec![0u8; 16 * 1024];
+        loop {
+            let n = match client_rx.read(&mut buffer).await {
+                Ok(0) | Err(_) => break,
+                Ok(n) => n,
+            };
+            let delay = delay_ms.load(Ordering::Relaxed);
+            if delay > 0 {
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+            }
+            if server_tx.write_all(&buffer[..n]).await.is_err() {
+                break;
+            }
+        }
+    };
+    let response = tokio::io::copy(&mut server_rx, &mut client_tx);
+    tokio::select! {
+        _ = request => {}
+        _ = response => {}
+    }
+}
+
+/// 패킷 손실 주입 UDP 중계기
+pub struct LossyUdpShim {
+    local_addr: SocketAddr,
+    loss_ppm: Arc<AtomicU32>,
+    handle: JoinHandle<()>,
+}
+
+impl LossyUdpShim {
+    /// `listen`에서 받은 패킷을 `upstream`으로 중계 (포트 0이면 임의 포트)
+    pub async fn bind(listen: SocketAddr, upstream: SocketAddr) -> Result<Self> {
+        let socket = Arc::new(UdpSocket::bind(listen).await
+            .with_context(|| format!("UDP 중계기를 {}에 바인드하는데 실패했습니다", listen))?);
+        let local_addr = socket.local_addr()?;
+        let loss_ppm = Arc::new(AtomicU32::new(0));
+
+        let loss = loss_ppm.clone();
+        let handle = tokio::spawn(async move {
+            // 클라이언트별 upstream 소켓 (응답을 원래 클라이언트로 돌려보내기 위함)
+            // 중계기가 멈추면 응답 중계 태스크도 함께 종료 (JoinSet drop)
+            let mut peers: HashMap<SocketAddr, Arc<UdpSocket>> = HashMap::new();
+            let mut relays = JoinSet::new();
+            let mut buffer = vec![0u8; 65536];
+            loop {
+                let (size, client) = match socket.recv_from(&mut buffer).await {
+                    Ok(received) => received,
+                    Err(e) => {
+                        debug!("UDP 중계기 수신 오류: {}", e);
+                        continue;
+                    }
+                };
+                if should_drop(&loss) {
+                    continue;
+                }
+
+                let upstream_socket = match peers.get(&client) {
+                    Some(upstream_socket) => upstream_socket.clone(),
+                    None => {
+                        let Ok(upstream_socket) = connect_udp(upstream).await else {
+                            continue;
+                        };
+                        let upstream_socket = Arc::new(upstream_socket);
+                        relays.spawn(relay_replies(upstream_socket.clone(), socket.clone(), client, loss.clone()));
+                        peers.insert(client, upstream_socket.clone());
+                        upstream_socket
+                    }
+                };
+                let _ = upstream_socket.send(&buffer[..size]).await;
+            }
+        });
+
+        Ok(Self { local_addr, loss_ppm, handle })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// 손실률 설정 (0.0 ~ 1.0)
+    pub fn set_loss(&self, rate: f64) {
+        self.loss_ppm.store((rate.clamp(0.0, 1.0) * LOSS_SCALE) as u32, Ordering::Relaxed);
+    }
+}
+
+impl Drop for LossyUdpShim {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+async fn connect_udp(upstream: SocketAddr) -> std::io::Result<UdpSocket> {
+    let bind: SocketAddr = if upstream.is_ipv4() { ([0, 0, 0, 0], 0).into() } else { ([0u16; 8], 0).into() };
+    let socket = UdpSocket::bind(bind).await?;
+    socket.connect(upstream).await?;
+    Ok(socket)
+}
+
+async fn relay_replies(upstream: Arc<UdpSocket>, socket: Arc<UdpSocket>, client: SocketAddr, loss: Arc<AtomicU32>) {
+    let mut buffer = vec![0u8; 65536];
+    loop {
+        match upstream.recv(&mut buffer).await {
+            Ok(size) if !should_drop(&loss) => {
+                let _ = socket.send_to(&buffer[..size], client).await;
+            }
+            Ok(_) => {}
+            // 서버가 잠시 내려가 있으면 ICMP 거부가 돌아오므로 재시작될 때까지 계속 대기
+            Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => {}
+            Err(_) => break,
+        }
+    }
+}
+
+fn should_drop(loss_ppm: &AtomicU32) -> bool {
+    let ppm = loss_ppm.load(Ordering::Relaxed);
+    ppm > 0 && rand::thread_rng().gen_range(0..LOSS_SCALE as u32) < ppm
+}
+
+/// UDP 에코 서버 손실률 측정기
+pub struct UdpProber {
+    socket: UdpSocket,
+    round: u64,
+}
+
+impl UdpProber {
+    pub async fn connect(target: SocketAddr) -> Result<Self> {
+        Ok(Self { socket: connect_udp(target).await?, round: 0 })
+    }
+
+    /// `count`개 패킷을 보내 `wait` 안에 돌아오지 않은 비율 (이전 회차의 늦은 응답은 제외)
+    pub async fn measure(&mut self, count: u32, wait: Duration) -> f64 {
+        self.round += 1;
+        let prefix = format!("chaos-probe-{}-", self.round);
+        for seq in 0..count {
+            // 서버가 내려가 있으면 ICMP 거부로 실패할 수 있으나 손실로 집계
+            let _ = self.socket.send(format!("{prefix}{seq}").as_bytes()).await;
+        }
+
+        let mut received = 0;
+        let mut buffer = [0u8; 64];
+        let deadline = tokio::time::Instant::now() + wait;
+        while received < count {
+            match tokio::time::timeout_at(deadline, self.socket.recv(&mut buffer)).await {
+                Ok(Ok(size)) if buffer[..size].starts_with(prefix.as_bytes()) => received += 1,
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => {}
+                Ok(Err(_)) | Err(_) => break,
+            }
+        }
+        1.0 - received as f64 / count.max(1) as f64
+    }
+}
@@ -0,0 +1,725 @@
+//! E2E 스모크 테스트 (`gamecenter test --e2e`)
+//!
+//! 샌드박스(인메모리 Redis, sqlite) 위에 통합 서버를 띄워 gRPC 로그인을 받고, 실제 tcpserver와
+//! rudpserver 프로세스를 띄워 실제 클라이언트와 같은 순서로 로그인 → 방 입장 → 게임 플레이를
+//! 진행한 뒤 각 서버가 본 사용자가 서로 일치하는지 확인합니다. 외부 Redis/DB에는 접속하지 않습니다.
+//!
+//! 단계:
+//! - `boot`: 통합 서버 시작 후 활성화된 하위 서버가 모두 Up이 될 때까지 대기
+//! - `game_servers`: 실제 tcpserver/rudpserver 실행 (실행 파일이 없거나 시작 중 종료되면 실패)
+//! - `grpc_login`: 모의 소셜 로그인(`login_type=test`)으로 플레이어와 관찰자 user_id 발급
+//! - `redis_session`: 로그인 세션이 Redis `user:{id}`에 기록되었는지 확인
+//! - `tcp_join`: tcpserver 프레임(`Connect`)으로 방 입장 후 `ConnectionAck` 확인
+//! - `rudp_move`: 플레이어와 관찰자가 rudpserver에 접속하고, 플레이어의 `Move`가 관찰자에게
+//!   `MoveUpdate`로 전달되는지 확인
+//! - `consistency`: TCP/RUDP 세션을 유지한 채 Redis 세션, tcpserver가 기록한 `tcp_host`,
+//!   게임 서버 프로세스와 통합 서버 상태 재확인
+//!
+//! 통합 서버의 TCP/RUDP 리스너는 받은 프레임을 그대로 돌려주는 에코 서버이므로 시나리오에
+//! 쓰지 않으며, 보낸 프레임이 그대로 돌아오면 실제 게임 서버가 아닌 것으로 보고 실패 처리합니다.
+//! 이 저장소에는 QUIC 서버가 없어 QUIC 이동은 다루지 않습니다.
+//!
+//! 환경변수:
+//! - `E2E_STEP_TIMEOUT_SECS`: 단계별 제한 시간 (기본값: 10)
+//! - `E2E_ROOM_ID`: 입장할 방 ID (기본값: 1)
+//! - `E2E_TCP_BIN`, `E2E_RUDP_BIN`: 게임 서버 실행 파일 (기본값: gamecenter와 같은 디렉터리의
+//!   `tcpserver`, `rudpserver` - `cargo build -p tcpserver -p rudpserver`로 빌드)
+//! - `E2E_TCP_PORT`, `E2E_RUDP_PORT`: 게임 서버 포트 (기본값: 14000, 15000)
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::process::{Child, Command};
+use tokio::time::Instant;
+use tracing::info;
+
+use grpcserver::user::user_service_client::UserServiceClient;
+use grpcserver::user::LoginRequest;
+use rudpserver::game::messages::{Direction, GameMessage as RudpMessage, Position};
+use rudpserver::protocol::{decode_message, encode_message};
+use shared::config::redis_config::RedisConfig;
+use shared::service::redis::core::redis_get_key::KeyType;
+use tcpserver::GameMessage as TcpMessage;
+
+use crate::health::{HealthMonitor, ServerState};
+use crate::unified_server::UnifiedGameServer;
+
+/// TCP 응답 프레임 최대 크기 (tcpserver 프로토콜과 동일)
+const MAX_TCP_FRAME: usize = 1024 * 1024;
+
+/// RUDP 응답 대기 시간 (재전송 간격, 전체 대기는 단계 제한 시간까지)
+const RUDP_WAIT: Duration = Duration::from_millis(500);
+
+/// 서버 상태 확인 주기
+const BOOT_POLL: Duration = Duration::from_millis(200);
+
+/// 접속 직후 이동 요청이 스팸 제한(16ms)에 걸리지 않도록 기다리는 시간
+const MOVE_DELAY: Duration = Duration::from_millis(50);
+
+/// 스폰 위치에서 이동할 거리 (rudpserver 이동 거리 검증 범위 안)
+const MOVE_STEP: f32 = 0.4;
+
+/// E2E 설정
+#[derive(Debug, Clone)]
+pub struct E2eConfig {
+    pub step_timeout: Duration,
+    pub room_id: u32,
+    /// tcpserver 실행 파일
+    pub tcp_bin: PathBuf,
+    /// rudpserver 실행 파일
+    pub rudp_bin: PathBuf,
+    pub tcp_port: u16,
+    pub rudp_port: u16,
+}
+
+impl Default for E2eConfig {
+    fn default() -> Self {
+        Self {
+            step_timeout: Duration::from_secs(10),
+            room_id: 1,
+            tcp_bin: sibling_binary("tcpserver"),
+            rudp_bin: sibling_binary("rudpserver"),
+            tcp_port: 14000,
+            rudp_port: 15000,
+        }
+    }
+}
+
+impl E2eConfig {
+    /// 환경변수에서 설정 로드
+    pub fn from_env() -> Result<Self> {
+        let mut config = Self::default();
+        if let Ok(value) = std::env::var("E2E_STEP_TIMEOUT_SECS") {
+            let secs: u64 = value.parse().with_context(|| format!("E2E_STEP_TIMEOUT_SECS 형식 오류: {value}"))?;
+            config.step_timeout = Duration::from_secs(secs.max(1));
+        }
+        if let Ok(value) = std::env::var("E2E_ROOM_ID") {
+            config.room_id = value.parse().with_context(|| format!("E2E_ROOM_ID 형식 오류: {value}"))?;
+        }
+        if let Ok(value) = std::env::var("E2E_TCP_BIN") {
+            config.tcp_bin = PathBuf::from(value);
+        }
+        if let Ok(value) = std::env::var("E2E_RUDP_BIN") {
+            config.rudp_bin = PathBuf::from(value);
+        }
+        if let Ok(value) = std::env::var("E2E_TCP_PORT") {
+            config.tcp_port = value.parse().with_context(|| format!("E2E_TCP_PORT 형식 오류: {value}"))?;
+        }
+        if let Ok(value) = std::env::var("E2E_RUDP_PORT") {
+            config.rudp_port = value.parse().with_context(|| format!("E2E_RUDP_PORT 형식 오류: {value}"))?;
+        }
+        Ok(config)
+    }
+}
+
+/// gamecenter 실행 파일과 같은 디렉터리의 다른 바이너리 (워크스페이스 빌드 결과 위치)
+fn sibling_binary(name: &str) -> PathBuf {
+    let file_name = format!("{name}{}", std::env::consts::EXE_SUFFIX);
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(&file_name)))
+        .unwrap_or_else(|| PathBuf::from(file_name))
+}
+
+/// 단계 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// 단계 하나의 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepOutcome {
+    pub name: String,
+    pub protocol: String,
+    pub status: StepStatus,
+    pub elapsed_ms: u64,
+    pub detail: String,
+}
+
+/// E2E 리포트
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct E2eReport {
+    /// 시작 시각 (RFC 3339)
+    pub started_at: String,
+    /// 로그인으로 발급받은 사용자 (로그인 실패 시 None)
+    pub user_id: Option<i32>,
+    pub room_id: u32,
+    pub steps: Vec<StepOutcome>,
+}
+
+impl E2eReport {
+    /// 실패한 단계가 없는지 (건너뛴 단계는 실패로 보지 않음)
+    pub fn passed(&self) -> bool {
+        self.steps.iter().all(|step| step.status != StepStatus::Failed)
+    }
+
+    /// 로그로 요약 출력
+    pub fn log(&self) {
+        let passed = self.steps.iter().filter(|step| step.status == StepStatus::Passed).count();
+        info!("🧪 E2E 스모크 테스트 결과: {}/{} 통과 (user_id={:?}, room_id={})",
+              passed, self.steps.len(), self.user_id, self.room_id);
+        for step in &self.steps {
+            let mark = match step.status {
+                StepStatus::Passed => "✅",
+                StepStatus::Failed => "❌",
+                StepStatus::Skipped => "⏭️",
+            };
+            info!("  └─ {} {:<14} {:<5} {:>6}ms {}", mark, step.name, step.protocol, step.elapsed_ms, step.detail);
+        }
+    }
+}
+
+/// 단계 기록기 (제한 시간 적용, 앞 단계 실패 시 건너뜀)
+struct Steps {
+    timeout: Duration,
+    outcomes: Vec<StepOutcome>,
+}
+
+impl Steps {
+    async fn run<T, F>(&mut self, name: &str, protocol: &str, step: F) -> Option<T>
+    where
+        F: Future<Output = Result<(T, String)>>,
+    {
+        let started = Instant::now();
+        let result = match tokio::time::timeout(self.timeout, step).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow!("제한 시간 {}초 초과", self.timeout.as_secs())),
+        };
+        let (status, detail, value) = match result {
+            Ok((value, detail)) => (StepStatus::Passed, detail, Some(value)),
+            Err(e) => (StepStatus::Failed, format!("{e:#}"), None),
+        };
+        self.outcomes.push(StepOutcome {
+            name: name.to_string(),
+            protocol: protocol.to_string(),
+            status,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            detail,
+        });
+        value
+    }
+
+    fn skip(&mut self, name: &str, protocol: &str, reason: &str) {
+        self.outcomes.push(StepOutcome {
+            name: name.to_string(),
+            protocol: protocol.to_string(),
+            status: StepStatus::Skipped,
+            elapsed_ms: 0,
+            detail: reason.to_string(),
+        });
+    }
+}
+
+/// 로그인 결과
+struct Login {
+    user_id: i32,
+    nick_name: String,
+    access_token: String,
+    /// 세션(리프레시 토큰 패밀리) ID (없으면 빈 문자열)
+    family_id: String,
+    /// 로그인 응답이 안내한 TCP 서버 입장 티켓
+    tcp_ticket: Option<String>,
+}
+
+/// 시나리오에 참여하는 두 사용자 (이동하는 플레이어와 이를 지켜보는 관찰자)
+struct Logins {
+    player: Login,
+    observer: Login,
+}
+
+/// E2E용으로 띄운 실제 게임 서버 프로세스 (drop 시 종료)
+struct GameServers {
+    tcp: Child,
+    rudp: Child,
+    tcp_address: SocketAddr,
+    rudp_address: SocketAddr,
+}
+
+impl GameServers {
+    /// 두 프로세스가 아직 실행 중인지 확인
+    fn ensure_running(&mut self) -> Result<()> {
+        if let Some(status) = self.tcp.try_wait()? {
+            bail!("tcpserver가 종료되었습니다 ({status})");
+        }
+        if let Some(status) = self.rudp.try_wait()? {
+            bail!("rudpserver가 종료되었습니다 ({status})");
+        }
+        Ok(())
+    }
+}
+
+/// E2E 실행기
+pub struct E2eRunner {
+    config: E2eConfig,
+    server: UnifiedGameServer,
+}
+
+impl E2eRunner {
+    pub fn new(config: E2eConfig, server: UnifiedGameServer) -> Self {
+        Self { config, server }
+    }
+
+    /// 시나리오 실행 (단계 실패는 리포트에 기록하고 계속 진행)
+    pub async fn run(&self) -> E2eReport {
+        let server_config = self.server.config().clone();
+        let room_id = self.config.room_id;
+        let mut steps = Steps { timeout: self.config.step_timeout, outcomes: Vec::new() };
+        let started_at = chrono::Utc::now().to_rfc3339();
+        info!("🧪 E2E 스모크 테스트 시작 (room_id={})", room_id);
+
+        let monitor = HealthMonitor::new(server_config.clone());
+        let booted = steps.run("boot", "-", wait_until_up(&monitor)).await.is_some();
+        let servers = steps.run("game_servers", "-", boot_game_servers(&self.config)).await;
+
+        let logins = match (booted, server_config.enable_grpc) {
+            (false, _) => {
+                steps.skip("grpc_login", "grpc", "서버 시작 실패");
+                None
+            }
+            (true, false) => {
+                steps.skip("grpc_login", "grpc", "gRPC 서버 비활성화");
+                None
+            }
+            (true, true) => steps.run("grpc_login", "grpc", grpc_logins(dial(server_config.grpc_address))).await,
+        };
+        let Some(logins) = logins else {
+            for (name, protocol) in [("redis_session", "redis"), ("tcp_join", "tcp"), ("rudp_move", "rudp"), ("consistency", "-")] {
+                steps.skip(name, protocol, "로그인 실패");
+            }
+            return E2eReport { started_at, user_id: None, room_id, steps: steps.outcomes };
+        };
+
+        let redis = steps.run("redis_session", "redis", redis_session(&logins.player)).await;
+
+        // 입장한 TCP 연결과 RUDP 소켓은 마지막 확인까지 유지
+        let Some(mut servers) = servers else {
+            for (name, protocol) in [("tcp_join", "tcp"), ("rudp_move", "rudp"), ("consistency", "-")] {
+                steps.skip(name, protocol, "게임 서버 시작 실패");
+            }
+            return E2eReport { started_at, user_id: Some(logins.player.user_id), room_id, steps: steps.outcomes };
+        };
+        let tcp = steps.run("tcp_join", "tcp", tcp_join(servers.tcp_address, room_id, &logins.player)).await;
+        let rudp = steps.run("rudp_move", "rudp", rudp_move(servers.rudp_address, &logins)).await;
+
+        let sessions = Sessions { redis: redis.is_some(), tcp: tcp.is_some(), rudp: rudp.is_some() };
+        steps.run("consistency", "-", consistency(&monitor, &logins.player, sessions, &mut servers)).await;
+        drop(tcp);
+        drop(rudp);
+        drop(servers);
+
+        E2eReport { started_at, user_id: Some(logins.player.user_id), room_id, steps: steps.outcomes }
+    }
+}
+
+/// 서버 간 일관성 확인
+///
+/// 앞 단계에서 연 세션을 유지한 채로, 로그인 세션이 그대로 남아 있고(다른 프로토콜 접속으로
+/// 지워지지 않음) tcpserver가 같은 사용자에 접속 호스트를 기록했으며 모든 서버가 여전히 정상인지 봅니다.
+async fn consistency(
+    monitor: &HealthMonitor,
+    login: &Login,
+    sessions: Sessions,
+    servers: &mut GameServers,
+) -> Result<((), String)> {
+    let mut checks = Vec::new();
+    if !(sessions.redis && sessions.tcp && sessions.rudp) {
+        bail!("앞 단계 실패로 확인할 세션이 부족합니다 (redis={}, tcp={}, rudp={})",
+              sessions.redis, sessions.tcp, sessions.rudp);
+    }
+
+    redis_session(login).await.context("TCP/RUDP 접속 후 로그인 세션 확인 실패")?;
+    checks.push(format!("user:{} 유지", login.user_id));
+
+    let redis_config = RedisConfig::new().await.context("Redis 연결 실패")?;
+    let mut conn = redis_config.get_connection();
+    let key = KeyType::User.get_key(&(login.user_id as u16));
+    let tcp_host: Option<String> = redis::cmd("HGET")
+        .arg(&key)
+        .arg("tcp_host")
+        .query_async(&mut conn)
+        .await
+        .with_context(|| format!("{key} tcp_host 조회 실패"))?;
+    match tcp_host {
+        Some(tcp_host) => checks.push(format!("tcp_host={tcp_host}")),
+        None => bail!("tcpserver가 {key}에 tcp_host를 기록하지 않았습니다"),
+    }
+
+    servers.ensure_running()?;
+    let report = monitor.collect().await;
+    let down: Vec<&str> = report.servers.iter()
+        .filter(|server| server.state == ServerState::Down)
+        .map(|server| server.name.as_str())
+        .collect();
+    if !down.is_empty() {
+        bail!("시나리오 후 중지된 서버: {}", down.join(", "));
+    }
+    checks.push("서버 정상".to_string());
+    Ok(((), checks.join(", ")))
+}
+
+/// 마지막 확인에 쓰는 앞 단계 세션 상태
+struct Sessions {
+    redis: bool,
+    tcp: bool,
+    rudp: bool,
+}
+
+/// 0.0.0.0으로 바인드된 서버는 루프백으로 접속
+fn dial(addr: SocketAddr) -> SocketAddr {
+    if addr.ip().is_unspecified() {
+        SocketAddr::from((Ipv4Addr::LOCALHOST, addr.port()))
+    } else {
+        addr
+    }
+}
+
+/// 활성화된 하위 서버가 모두 Up이 될 때까지 대기
+async fn wait_until_up(monitor: &HealthMonitor) -> Result<((), String)> {
+    loop {
+        let report = monitor.collect().await;
+        let pending: Vec<&str> = report.servers.iter()
+            .filter(|server| server.state == ServerState::Down)
+            .map(|server| server.name.as_str())
+            .collect();
+        if pending.is_empty() {
+            let up: Vec<&str> = report.servers.iter()
+                .filter(|server| server.state == ServerState::Up)
+                .map(|server| server.name.as_str())
+                .collect();
+            return Ok(((), format!("{} Up", up.join(", "))));
+        }
+        tokio::time::sleep(BOOT_POLL).await;
+    }
+}
+
+/// 실제 tcpserver/rudpserver 실행 후 TCP 포트가 열릴 때까지 대기
+///
+/// 샌드박스가 설정한 Redis 환경변수를 그대로 물려받습니다. RUDP는 접속 단계에서 재전송으로 준비를 기다립니다.
+async fn boot_game_servers(config: &E2eConfig) -> Result<(GameServers, String)> {
+    let tcp_address = SocketAddr::from((Ipv4Addr::LOCALHOST, config.tcp_port));
+    let rudp_address = SocketAddr::from((Ipv4Addr::LOCALHOST, config.rudp_port));
+    let tcp = spawn_server("tcpserver", &config.tcp_bin, &[
+        ("tcp_host", tcp_address.ip().to_string()),
+        ("tcp_port", tcp_address.port().to_string()),
+    ])?;
+    let rudp = spawn_server("rudpserver", &config.rudp_bin, &[
+        ("RUDP_HOST", rudp_address.ip().to_string()),
+        ("RUDP_PORT", rudp_address.port().to_string()),
+    ])?;
+    let mut servers = GameServers { tcp, rudp, tcp_address, rudp_address };
+
+    loop {
+        servers.ensure_running().context("게임 서버가 시작 중 종료되었습니다")?;
+        if TcpStream::connect(tcp_address).await.is_ok() {
+            break;
+        }
+        tokio::time::sleep(BOOT_POLL).await;
+    }
+    let detail = format!("tcpserver {tcp_address}, rudpserver {rudp_address}");
+    Ok((servers, detail))
+}
+
+/// 게임 서버 프로세스 실행 (출력은 버리고, gamecenter가 끝나면 함께 종료)
+fn spawn_server(name: &str, bin: &Path, env: &[(&str, String)]) -> Result<Child> {
+    if !bin.is_file() {
+        bail!("{name} 실행 파일이 없습니다: {} (cargo build -p tcpserver -p rudpserver 또는 E2E_TCP_BIN/E2E_RUDP_BIN 지정)",
+              bin.display());
+    }
+    Command::new(bin)
+        .envs(env.iter().map(|(key, value)| (*key, value.as_str())))
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("{name} 실행 실패: {}", bin.display()))
+}
+
+/// 플레이어와 관찰자 모의 소셜 로그인
+async fn grpc_logins(addr: SocketAddr) -> Result<(Logins, String)> {
+    let (player, player_detail) = grpc_login(addr, "player").await.context("플레이어 로그인")?;
+    let (observer, observer_detail) = grpc_login(addr, "observer").await.context("관찰자 로그인")?;
+    if player.user_id == observer.user_id {
+        bail!("두 로그인이 같은 user_id({})를 받았습니다", player.user_id);
+    }
+    Ok((Logins { player, observer }, format!("{player_detail}; 관찰자 {observer_detail}")))
+}
+
+/// 모의 소셜 로그인
+async fn grpc_login(addr: SocketAddr, role: &str) -> Result<(Login, String)> {
+    let mut client = UserServiceClient::connect(format!("http://{addr}")).await
+        .with_context(|| format!("gRPC 서버({addr}) 연결 실패"))?;
+    let response = client.login_user(LoginRequest {
+        login_type: "test".to_string(),
+        login_token: format!("e2e-{role}-{}", chrono::Utc::now().timestamp_millis()),
+        region: String::new(),
+    }).await.context("LoginUser 호출 실패")?.into_inner();
+
+    if !response.success || response.user_id <= 0 {
+        bail!("로그인 실패 응답 (success={}, user_id={})", response.success, response.user_id);
+    }
+    let detail = format!("user_id={} nick={} 접속 정보 {}개", response.user_id, response.nick_name, response.endpoints.len());
+    let tcp_ticket = response.endpoints.iter()
+        .find(|endpoint| endpoint.protocol == "tcp" && !endpoint.entry_ticket.is_empty())
+        .map(|endpoint| endpoint.entry_ticket.clone());
+    let login = Login {
+        user_id: response.user_id,
+        nick_name: response.nick_name,
+        access_token: response.access_token,
+        family_id: response.family_id,
+        tcp_ticket,
+    };
+    Ok((login, detail))
+}
+
+/// 로그인 세션이 Redis에 기록되었는지 확인
+async fn redis_session(login: &Login) -> Result<((), String)> {
+    let redis_config = RedisConfig::new().await.context("Redis 연결 실패")?;
+    let mut conn = redis_config.get_connection();
+    let key = KeyType::User.get_key(&(login.user_id as u16));
+    let (nick_name, access_token): (Option<String>, Option<String>) = redis::cmd("HMGET")
+        .arg(&key)
+        .arg("nick_name")
+        .arg("access_token")
+        .query_async(&mut conn)
+        .await
+        .with_context(|| format!("{key} 조회 실패"))?;
+
+    match (nick_name, access_token) {
+        (Some(nick_name), Some(access_token)) if nick_name == login.nick_name && access_token == login.access_token => {
+            Ok(((), format!("{key} nick={nick_name}")))
+        }
+        (None, None) => Err(anyhow!("{key} 세션이 없습니다")),
+        (nick_name, _) => Err(anyhow!("{key} 세션이 로그인 응답과 다릅니다 (nick={:?})", nick_name)),
+    }
+}
+
+/// TCP 방 입장
+async fn tcp_join(addr: SocketAddr, room_id: u32, login: &Login) -> Result<(TcpStream, String)> {
+    let user_id = login.user_id as u32;
+    let mut stream = TcpStream::connect(addr).await
+        .with_context(|| format!("TCP 서버({addr}) 연결 실패"))?;
+    let connect = TcpMessage::Connect {
+        room_id,
+        user_id,
+        protocol: None,
+        locale: None,
+        family_id: Some(login.family_id.clone()).filter(|family_id| !family_id.is_empty()),
+        entry_ticket: login.tcp_ticket.clone(),
+    };
+    stream.write_all(&connect.to_bytes()?).await.context("Connect 전송 실패")?;
+    let reply = read_tcp_frame(&mut stream).await.context("Connect 응답 수신 실패")?;
+    let detail = check_tcp_reply(&reply, room_id, user_id)?;
+    Ok((stream, detail))
+}
+
+/// 길이 헤더 + JSON 프레임 하나 읽기
+async fn read_tcp_frame(stream: &mut TcpStream) -> Result<TcpMessage> {
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header).await?;
+    let length = u32::from_be_bytes(header) as usize;
+    if length > MAX_TCP_FRAME {
+        bail!("응답 프레임이 너무 큽니다: {length}바이트");
+    }
+    let mut frame = header.to_vec();
+    frame.resize(4 + length, 0);
+    stream.read_exact(&mut frame[4..]).await?;
+    TcpMessage::from_bytes(&frame)
+}
+
+/// TCP 입장 응답 확인 (보낸 `Connect`가 그대로 돌아오면 에코 서버로 보고 실패)
+fn check_tcp_reply(reply: &TcpMessage, room_id: u32, user_id: u32) -> Result<String> {
+    match reply {
+        TcpMessage::ConnectionAck { user_id: acked } if *acked == user_id => {
+            Ok(format!("방 {room_id} 입장 (ConnectionAck user_id={acked})"))
+        }
+        TcpMessage::ConnectionAck { user_id: acked } => {
+            Err(anyhow!("다른 사용자로 입장 확인됨: 로그인 {user_id}, TCP {acked}"))
+        }
+        TcpMessage::Connect { .. } => Err(anyhow!("Connect 프레임이 그대로 돌아왔습니다 - 실제 tcpserver가 아닌 에코 서버입니다")),
+        other => Err(anyhow!("예상하지 못한 응답: {:?}", other)),
+    }
+}
+
+/// RUDP 접속 후 이동, 관찰자에게 이동이 전달되는지 확인
+async fn rudp_move(addr: SocketAddr, logins: &Logins) -> Result<((UdpSocket, UdpSocket), String)> {
+    let (player, spawn) = rudp_connect(addr, &logins.player).await.context("플레이어 접속")?;
+    let (observer, _) = rudp_connect(addr, &logins.observer).await.context("관찰자 접속")?;
+    let player_id = logins.player.user_id as u32;
+
+    tokio::time::sleep(MOVE_DELAY).await;
+    let target = Position::new(spawn.x + MOVE_STEP, spawn.y, spawn.z);
+    let movement = encode_message(RudpMessage::Move {
+        target_position: target,
+        direction: Direction::new(1.0, 0.0, 0.0),
+        speed_multiplier: 1.0,
+        client_timestamp: chrono::Utc::now().timestamp_millis() as u64,
+    })?;
+
+    // 이동은 응답이 없으므로 관찰자가 MoveUpdate를 받을 때까지 재전송
+    let mut buffer = vec![0u8; 65536];
+    loop {
+        player.send(&movement).await?;
+        let deadline = Instant::now() + RUDP_WAIT;
+        while let Ok(received) = tokio::time::timeout_at(deadline, observer.recv(&mut buffer)).await {
+            let message = decode_message(&buffer[..received?])?;
+            if let Some(detail) = check_move_update(&message, player_id, &target)? {
+                return Ok(((player, observer), format!("player_id={player_id} 스폰 ({:.1}, {:.1}, {:.1}), {detail}",
+                                                       spawn.x, spawn.y, spawn.z)));
+            }
+        }
+        // 이동이 거부되었으면 플레이어에게 에러 응답이 와 있음
+        if let Ok(received) = player.try_recv(&mut buffer) {
+            if let RudpMessage::Error { error_code, error_message, .. } = decode_message(&buffer[..received])? {
+                bail!("이동 거부: {error_code} {error_message}");
+            }
+        }
+    }
+}
+
+/// RUDP 접속 (응답이 없으면 단계 제한 시간까지 재전송)
+///
+/// rudpserver는 현재 인증 토큰을 플레이어 ID로 해석하므로 로그인한 user_id를 토큰으로 보냅니다.
+async fn rudp_connect(addr: SocketAddr, login: &Login) -> Result<(UdpSocket, Position)> {
+    let socket = UdpSocket::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, 0))).await?;
+    socket.connect(addr).await.with_context(|| format!("RUDP 서버({addr}) 연결 실패"))?;
+
+    let player_id = login.user_id as u32;
+    let packet = encode_message(RudpMessage::Connect {
+        player_name: format!("e2e{player_id}"),
+        auth_token: player_id.to_string(),
+        client_version: "1.0.0".to_string(),
+    })?;
+    let mut buffer = vec![0u8; 65536];
+    loop {
+        socket.send(&packet).await?;
+        if let Ok(received) = tokio::time::timeout(RUDP_WAIT, socket.recv(&mut buffer)).await {
+            let reply = decode_message(&buffer[..received?])?;
+            let spawn = check_rudp_connect(&reply, player_id)?;
+            return Ok((socket, spawn));
+        }
+    }
+}
+
+/// RUDP 접속 응답 확인 (보낸 `Connect`가 그대로 돌아오면 에코 서버로 보고 실패)
+fn check_rudp_connect(reply: &RudpMessage, player_id: u32) -> Result<Position> {
+    match reply {
+        RudpMessage::ConnectResponse { success: true, player_id: Some(connected), spawn_position: Some(spawn), .. }
+            if *connected == player_id =>
+        {
+            Ok(*spawn)
+        }
+        RudpMessage::ConnectResponse { success: true, player_id: connected, .. } => {
+            Err(anyhow!("다른 플레이어로 접속됨: 로그인 {player_id}, RUDP {connected:?}"))
+        }
+        RudpMessage::ConnectResponse { message, .. } => Err(anyhow!("접속 거부: {message}")),
+        RudpMessage::Connect { .. } => Err(anyhow!("Connect 패킷이 그대로 돌아왔습니다 - 실제 rudpserver가 아닌 에코 서버입니다")),
+        other => Err(anyhow!("예상하지 못한 응답: {:?}", other)),
+    }
+}
+
+/// 관찰자가 받은 메시지가 플레이어의 이동인지 확인 (다른 메시지는 None으로 계속 대기)
+fn check_move_update(message: &RudpMessage, player_id: u32, target: &Position) -> Result<Option<String>> {
+    match message {
+        RudpMessage::MoveUpdate { player_id: moved, current_position, .. } if *moved == player_id => {
+            if current_position.distance_to(target) > MOVE_STEP {
+                bail!("이동 위치가 요청과 다릅니다: ({:.1}, {:.1}, {:.1})",
+                      current_position.x, current_position.y, current_position.z);
+            }
+            Ok(Some(format!("관찰자가 MoveUpdate 수신 ({:.1}, {:.1}, {:.1})",
+                            current_position.x, current_position.y, current_position.z)))
+        }
+        RudpMessage::Move { .. } => Err(anyhow!("Move 패킷이 그대로 돌아왔습니다 - 실제 rudpserver가 아닌 에코 서버입니다")),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rudpserver::game::messages::Velocity;
+
+    fn outcome(status: StepStatus) -> StepOutcome {
+        StepOutcome { name: "step".to_string(), protocol: "-".to_string(), status, elapsed_ms: 0, detail: String::new() }
+    }
+
+    #[test]
+    fn test_report_passes_with_skipped_steps() {
+        let mut report = E2eReport {
+            started_at: String::new(),
+            user_id: Some(2),
+            room_id: 1,
+            steps: vec![outcome(StepStatus::Passed), outcome(StepStatus::Skipped)],
+        };
+        assert!(report.passed());
+        report.steps.push(outcome(StepStatus::Failed));
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn test_check_tcp_reply() {
+        assert!(check_tcp_reply(&TcpMessage::ConnectionAck { user_id: 2 }, 1, 2).is_ok());
+        assert!(check_tcp_reply(&TcpMessage::ConnectionAck { user_id: 3 }, 1, 2).is_err());
+
+        // 에코 서버가 돌려준 Connect는 입장으로 보지 않음
+        let echoed = TcpMessage::Connect { room_id: 1, user_id: 2, protocol: None, locale: None, family_id: None, entry_ticket: None };
+        assert!(check_tcp_reply(&echoed, 1, 2).unwrap_err().to_string().contains("에코"));
+        assert!(check_tcp_reply(&TcpMessage::HeartBeat, 1, 2).is_err());
+    }
+
+    #[test]
+    fn test_check_rudp_replies() {
+        let spawn = Position::new(2500.0, 0.0, 2500.0);
+        let connected = |player_id| RudpMessage::ConnectResponse {
+            success: true,
+            player_id: Some(player_id),
+            spawn_position: Some(spawn),
+            initial_state: None,
+            message: String::new(),
+            server_config: None,
+        };
+        assert_eq!(check_rudp_connect(&connected(2), 2).unwrap(), spawn);
+        assert!(check_rudp_connect(&connected(3), 2).is_err());
+
+        let echoed = RudpMessage::Connect {
+            player_name: "e2e2".to_string(),
+            auth_token: "2".to_string(),
+            client_version: "1.0.0".to_string(),
+        };
+        assert!(check_rudp_connect(&echoed, 2).unwrap_err().to_string().contains("에코"));
+    }
+
+    #[test]
+    fn test_check_move_update() {
+        let target = Position::new(2500.4, 0.0, 2500.0);
+        let update = |player_id, current_position| RudpMessage::MoveUpdate {
+            player_id,
+            current_position,
+            velocity: Velocity::default(),
+            server_timestamp: 0,
+        };
+
+        // 관찰자가 받은 플레이어 이동만 통과, 다른 플레이어 이동은 계속 대기
+        assert!(check_move_update(&update(2, target), 2, &target).unwrap().is_some());
+        assert!(check_move_update(&update(3, target), 2, &target).unwrap().is_none());
+        assert!(check_move_update(&update(2, Position::new(0.0, 0.0, 0.0)), 2, &target).is_err());
+
+        let echoed = RudpMessage::Move {
+            target_position: target,
+            direction: Direction::new(1.0, 0.0, 0.0),
+            speed_multiplier: 1.0,
+            client_timestamp: 0,
+        };
+        assert!(check_move_update(&echoed, 2, &target).unwrap_err().to_string().contains("에코"));
+    }
+}
@@ -0,0 +1,553 @@
+//! 개인정보 내보내기/삭제 (GDPR)
+//!
+//! - `gamecenter gdpr export <user_id>`: DB 행, Redis 키, 아바타 이미지, 로그 참조 위치를 모아
+//!   `GDPR_EXPORT_DIR`(기본 `./data/gdpr`)에 gzip JSON 보관 파일로 저장
+//! - `gamecenter gdpr delete <user_id> --confirm`: 서비스 전반의 기록을 익명화/삭제한 뒤
+//!   다시 조회해 남은 항목이 없는지 확인하고 검증 리포트를 저장
+//!
+//! 플레이어 계정은 식별 정보만 익명화하고, 게임 기록(`game_sessions`, `match_players`)은
+//! 익명화된 ID로 통계용으로 남깁니다. 해시 체인 감사 로그는 수정할 수 없으므로 보존 항목으로
+//! 리포트에만 표시하고, 로그 파일은 참조 위치만 기록합니다 (로그 순환 보존 기간이 지나면 삭제).
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use redis::AsyncCommands;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use grpcserver::service::avatar_service::avatar_prefix;
+use grpcserver::service::avatar_storage::AvatarStorage;
+
+use shared::config::db::DbConnection;
+use shared::config::redis_config::{RedisConfig, RedisConnection};
+use shared::logging::formatter::LogEntry;
+use shared::logging::logquery::{self, LogQuery};
+
+use crate::scheduler::jobs::LEADERBOARD_CURRENT_KEY;
+
+/// 지원하는 작업
+pub const SUPPORTED_ACTIONS: &[&str] = &["export", "delete"];
+
+/// 사용자 Redis 키 (`{id}`는 사용자 ID, `*`가 있으면 SCAN)
+const USER_KEY_PATTERNS: &[&str] = &[
+    "user:{id}",
+    "user:{id}:*",
+    "session:families:{id}",
+    "experiment:user:{id}",
+    "presence:*:{id}",
+    "tcp:dm:offline:{id}",
+    "tcp:dm:block:{id}",
+    "tcp:resume:{id}",
+];
+
+/// 사용자 ID를 멤버로 갖는 Redis 정렬 집합
+const USER_MEMBER_KEYS: &[&str] = &[LEADERBOARD_CURRENT_KEY];
+
+/// 삭제 시 테이블 처리 방식
+#[derive(Debug, Clone, Copy)]
+enum Erasure {
+    /// 식별 정보 익명화 (익명화 문장, 익명화되지 않은 행 수를 세는 검증 문장)
+    Anonymize { update: &'static str, verify: &'static str },
+    /// 보존 (사유)
+    Retain(&'static str),
+}
+
+/// 개인정보가 있는 테이블
+struct PersonalTable {
+    table: &'static str,
+    /// 사용자 ID 컬럼
+    key_column: &'static str,
+    /// 내보낼 컬럼 (비밀번호 해시 같은 비밀 값은 제외)
+    columns: &'static [&'static str],
+    erasure: Erasure,
+}
+
+const PERSONAL_TABLES: &[PersonalTable] = &[
+    PersonalTable {
+        table: "players",
+        key_column: "id",
+        columns: &["id", "username", "email", "level", "experience", "gold", "created_at", "last_login"],
+        erasure: Erasure::Anonymize {
+            update: "UPDATE players SET username = CONCAT('erased_', id), email = CONCAT('erased_', id, '@erased.invalid'),
+                     password_hash = '', last_login = NULL WHERE id = ?",
+            verify: "SELECT COUNT(*) FROM players WHERE id = ? AND email NOT LIKE '%@erased.invalid'",
+        },
+    },
+    PersonalTable {
+        table: "game_sessions",
+        key_column: "player_id",
+        columns: &["id", "server_instance", "start_time", "end_time", "playtime_seconds", "kills", "deaths", "damage_dealt", "damage_received"],
+        erasure: Erasure::Retain("익명화된 계정 ID로 게임 통계 유지"),
+    },
+    PersonalTable {
+        table: "match_players",
+        key_column: "player_id",
+        columns: &["match_id", "team", "score", "kills", "deaths", "disconnected"],
+        erasure: Erasure::Retain("익명화된 계정 ID로 매치 기록 유지"),
+    },
+    PersonalTable {
+        table: "audit_log",
+        key_column: "actor",
+        columns: &["seq", "recorded_at", "kind", "payload"],
+        erasure: Erasure::Retain("해시 체인 감사 로그 (법적 보존 의무, 수정 시 체인 검증 실패)"),
+    },
+];
+
+/// 내보낸 개인정보
+#[derive(Debug, Clone, Serialize)]
+pub struct DataExport {
+    pub user_id: u32,
+    pub generated_at: DateTime<Utc>,
+    /// 테이블 → 행 목록
+    pub database: BTreeMap<String, Vec<Value>>,
+    /// Redis 키 → 값
+    pub redis: BTreeMap<String, Value>,
+    /// 아바타 저장 키 → base64 이미지
+    pub avatars: BTreeMap<String, String>,
+    /// 사용자가 기록된 로그 파일
+    pub log_references: Vec<LogReference>,
+}
+
+/// 로그 참조 위치
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LogReference {
+    pub path: String,
+    pub entries: usize,
+}
+
+/// 삭제 단계 처리 방식
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "action")]
+pub enum ErasureAction {
+    Anonymized,
+    Deleted,
+    Retained { reason: String },
+}
+
+/// 삭제 단계 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct ErasureStep {
+    /// 테이블, Redis 키, 아바타 저장 키, 로그 파일
+    pub target: String,
+    #[serde(flatten)]
+    pub action: ErasureAction,
+    pub affected: u64,
+}
+
+/// 삭제 검증 리포트 (개인정보 자체는 포함하지 않음)
+#[derive(Debug, Clone, Serialize)]
+pub struct DeletionReport {
+    pub user_id: u32,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub steps: Vec<ErasureStep>,
+    /// 삭제 후에도 남은 항목 (비어 있어야 성공)
+    pub remaining: Vec<String>,
+    pub verified: bool,
+}
+
+/// 개인정보 내보내기/삭제 실행기
+pub struct Compliance {
+    redis: RedisConfig,
+    /// None이면 DB 단계를 건너뜀 (DB 없는 개발 환경)
+    db: Option<DbConnection>,
+    log_dir: PathBuf,
+    /// None이면 아바타 단계를 건너뜀
+    avatars: Option<Arc<dyn AvatarStorage>>,
+}
+
+impl Compliance {
+    pub fn new(redis: RedisConfig, db: Option<DbConnection>, log_dir: impl Into<PathBuf>) -> Self {
+        Self { redis, db, log_dir: log_dir.into(), avatars: None }
+    }
+
+    /// 아바타 저장소 (`avatars/{user_id}/` 아래 객체를 내보내고 삭제)
+    pub fn with_avatars(mut self, storage: Arc<dyn AvatarStorage>) -> Self {
+        self.avatars = Some(storage);
+        self
+    }
+
+    /// 사용자의 개인정보 수집
+    pub async fn export(&self, user_id: u32) -> Result<DataExport> {
+        let mut database = BTreeMap::new();
+        if let Some(db) = &self.db {
+            for table in PERSONAL_TABLES {
+                let rows: Vec<String> = sqlx::query_scalar(&export_query(table))
+                    .bind(user_id.to_string())
+                    .fetch_all(db)
+                    .await
+                    .with_context(|| format!("{} 조회 실패", table.table))?;
+                let rows = rows.iter().map(|row| serde_json::from_str(row).unwrap_or_else(|_| Value::String(row.clone())));
+                database.insert(table.table.to_string(), rows.collect());
+            }
+        }
+
+        let mut conn = self.redis.get_connection();
+        let mut redis = BTreeMap::new();
+        for key in user_keys(&mut conn, user_id).await? {
+            let value = read_key(&mut conn, &key).await?;
+            redis.insert(key, value);
+        }
+        for key in USER_MEMBER_KEYS {
+            let score: Option<f64> = conn.zscore(*key, user_id).await.with_context(|| format!("{key} 조회 실패"))?;
+            if let Some(score) = score {
+                redis.insert(format!("{key}[{user_id}]"), Value::from(score));
+            }
+        }
+
+        let mut avatars = BTreeMap::new();
+        if let Some(storage) = &self.avatars {
+            for key in self.avatar_keys(user_id).await? {
+                // 목록 조회와 읽기 사이에 교체된 객체는 건너뜀
+                if let Some(data) = storage.get(&key).await.with_context(|| format!("{key} 읽기 실패"))? {
+                    avatars.insert(key, base64::engine::general_purpose::STANDARD.encode(data));
+                }
+            }
+        }
+
+        Ok(DataExport {
+            user_id,
+            generated_at: Utc::now(),
+            database,
+            redis,
+            avatars,
+            log_references: find_log_references(&self.log_dir, user_id),
+        })
+    }
+
+    /// 사용자의 개인정보 익명화/삭제 후 검증
+    pub async fn delete(&self, user_id: u32) -> Result<DeletionReport> {
+        let started_at = Utc::now();
+        let mut steps = Vec::new();
+
+        if let Some(db) = &self.db {
+            for table in PERSONAL_TABLES {
+                let step = match table.erasure {
+                    Erasure::Anonymize { update, .. } => {
+                        let affected = sqlx::query(update)
+                            .bind(user_id.to_string())
+                            .execute(db)
+                            .await
+                            .with_context(|| format!("{} 익명화 실패", table.table))?
+                            .rows_affected();
+                        ErasureStep { target: table.table.to_string(), action: ErasureAction::Anonymized, affected }
+                    }
+                    Erasure::Retain(reason) => ErasureStep {
+                        target: table.table.to_string(),
+                        action: ErasureAction::Retained { reason: reason.to_string() },
+                        affected: 0,
+                    },
+                };
+                steps.push(step);
+            }
+        }
+
+        let mut conn = self.redis.get_connection();
+        for key in user_keys(&mut conn, user_id).await? {
+            let affected: u64 = conn.del(&key).await.with_context(|| format!("{key} 삭제 실패"))?;
+            steps.push(ErasureStep { target: key, action: ErasureAction::Deleted, affected });
+        }
+        for key in USER_MEMBER_KEYS {
+            let affected: u64 = conn.zrem(*key, user_id).await.with_context(|| format!("{key} 항목 삭제 실패"))?;
+            if affected > 0 {
+                steps.push(ErasureStep { target: key.to_string(), action: ErasureAction::Deleted, affected });
+            }
+        }
+
+        if let Some(storage) = &self.avatars {
+            for key in self.avatar_keys(user_id).await? {
+                storage.delete(&key).await.with_context(|| format!("{key} 삭제 실패"))?;
+                steps.push(ErasureStep { target: key, action: ErasureAction::Deleted, affected: 1 });
+            }
+        }
+
+        for reference in find_log_references(&self.log_dir, user_id) {
+            steps.push(ErasureStep {
+                target: reference.path,
+                action: ErasureAction::Retained { reason: "로그 순환 보존 기간이 지나면 삭제".to_string() },
+                affected: reference.entries as u64,
+            });
+        }
+
+        let remaining = self.verify(user_id).await?;
+        Ok(DeletionReport {
+            user_id,
+            started_at,
+            finished_at: Utc::now(),
+            steps,
+            verified: remaining.is_empty(),
+            remaining,
+        })
+    }
+
+    /// 삭제 후 남은 항목 (익명화되지 않은 행, 남은 Redis 키/멤버, 아바타 객체)
+    async fn verify(&self, user_id: u32) -> Result<Vec<String>> {
+        let mut remaining = Vec::new();
+        if let Some(db) = &self.db {
+            for table in PERSONAL_TABLES {
+                if let Erasure::Anonymize { verify, .. } = table.erasure {
+                    let count: i64 = sqlx::query_scalar(verify)
+                        .bind(user_id.to_string())
+                        .fetch_one(db)
+                        .await
+                        .with_context(|| format!("{} 검증 실패", table.table))?;
+                    if count > 0 {
+                        remaining.push(format!("{}: 익명화되지 않은 행 {}개", table.table, count));
+                    }
+                }
+            }
+        }
+
+        let mut conn = self.redis.get_connection();
+        remaining.extend(user_keys(&mut conn, user_id).await?);
+        for key in USER_MEMBER_KEYS {
+            let score: Option<f64> = conn.zscore(*key, user_id).await.with_context(|| format!("{key} 검증 실패"))?;
+            if score.is_some() {
+                remaining.push(format!("{key}[{user_id}]"));
+            }
+        }
+        remaining.extend(self.avatar_keys(user_id).await?);
+        Ok(remaining)
+    }
+
+    /// 사용자 아바타 객체 키 목록 (저장소가 없으면 빈 목록)
+    async fn avatar_keys(&self, user_id: u32) -> Result<Vec<String>> {
+        let Some(storage) = &self.avatars else {
+            return Ok(Vec::new());
+        };
+        let prefix = avatar_prefix(user_id as i32);
+        storage.list(&prefix).await.with_context(|| format!("{prefix} 조회 실패"))
+    }
+}
+
+/// 테이블 내보내기 쿼리 (행마다 JSON 객체 하나)
+fn export_query(table: &PersonalTable) -> String {
+    let fields: Vec<String> = table.columns.iter().map(|column| format!("'{column}', {column}")).collect();
+    format!(
+        "SELECT JSON_OBJECT({}) FROM {} WHERE {} = ?",
+        fields.join(", "),
+        table.table,
+        table.key_column
+    )
+}
+
+/// 사용자 Redis 키 목록
+async fn user_keys(conn: &mut RedisConnection, user_id: u32) -> Result<Vec<String>> {
+    let mut keys = Vec::new();
+    for pattern in USER_KEY_PATTERNS {
+        let pattern = pattern.replace("{id}", &user_id.to_string());
+        if pattern.contains('*') {
+            let mut iter: redis::AsyncIter<String> = conn.scan_match(&pattern).await
+                .with_context(|| format!("{pattern} 조회 실패"))?;
+            while let Some(key) = iter.next_item().await {
+                keys.push(key);
+            }
+        } else {
+            let exists: bool = conn.exists(&pattern).await.with_context(|| format!("{pattern} 조회 실패"))?;
+            if exists {
+                keys.push(pattern);
+            }
+        }
+    }
+    keys.sort();
+    keys.dedup();
+    Ok(keys)
+}
+
+/// 키 종류에 맞게 값 읽기
+async fn read_key(conn: &mut RedisConnection, key: &str) -> Result<Value> {
+    let kind: String = redis::cmd("TYPE").arg(key).query_async(conn).await
+        .with_context(|| format!("{key} 종류 조회 실패"))?;
+    let value = match kind.as_str() {
+        "string" => Value::from(conn.get::<_, Option<String>>(key).await?),
+        "hash" => serde_json::to_value(conn.hgetall::<_, BTreeMap<String, String>>(key).await?)?,
+        "list" => Value::from(conn.lrange::<_, Vec<String>>(key, 0, -1).await?),
+        "set" => Value::from(conn.smembers::<_, Vec<String>>(key).await?),
+        "zset" => serde_json::to_value(conn.zrange_withscores::<_, Vec<(String, f64)>>(key, 0, -1).await?)?,
+        other => Value::String(format!("<{other}>")),
+    };
+    Ok(value)
+}
+
+/// 로그 디렉토리에서 사용자가 기록된 파일 찾기 (`.log` JSON/텍스트, `.blog` 바이너리)
+fn find_log_references(log_dir: &Path, user_id: u32) -> Vec<LogReference> {
+    let query = LogQuery { context: vec![("user_id".to_string(), user_id.to_string())], ..Default::default() };
+    let mut files = Vec::new();
+    collect_log_files(log_dir, &mut files);
+    files.sort();
+
+    files.into_iter()
+        .filter_map(|path| {
+            let entries = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("blog") => match logquery::query(&path, &query) {
+                    Ok(entries) => entries.filter(|entry| entry.is_ok()).count(),
+                    Err(e) => {
+                        warn!("로그 파일 읽기 실패 ({}): {}", path.display(), e);
+                        0
+                    }
+                },
+                _ => match std::fs::File::open(&path) {
+                    Ok(file) => BufReader::new(file).lines()
+                        .map_while(|line| line.ok())
+                        .filter(|line| line_mentions_user(line, &query, user_id))
+                        .count(),
+                    Err(e) => {
+                        warn!("로그 파일 읽기 실패 ({}): {}", path.display(), e);
+                        0
+                    }
+                },
+            };
+            (entries > 0).then(|| LogReference { path: path.display().to_string(), entries })
+        })
+        .collect()
+}
+
+fn collect_log_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_log_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "log" || ext == "blog") {
+            files.push(path);
+        }
+    }
+}
+
+/// 로그 한 줄이 사용자를 가리키는지 (JSON 컨텍스트 `user_id` 또는 메시지의 `user_id=N`)
+fn line_mentions_user(line: &str, query: &LogQuery, user_id: u32) -> bool {
+    if let Ok(entry) = serde_json::from_str::<LogEntry>(line) {
+        if query.matches(&entry) {
+            return true;
+        }
+    }
+    let needle = format!("user_id={user_id}");
+    line.match_indices(&needle).any(|(index, _)| {
+        !line[index + needle.len()..].starts_with(|c: char| c.is_ascii_digit())
+    })
+}
+
+/// 보관 파일 저장 (`user_{id}_{시각}.json.gz`)
+pub fn write_archive(dir: &Path, export: &DataExport) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir).with_context(|| format!("보관 디렉토리 생성 실패: {}", dir.display()))?;
+    let path = dir.join(format!("user_{}_{}.json.gz", export.user_id, export.generated_at.format("%Y%m%dT%H%M%SZ")));
+    let file = std::fs::File::create(&path).with_context(|| format!("보관 파일 생성 실패: {}", path.display()))?;
+    let mut encoder = GzEncoder::new(file, Compression::default());
+    serde_json::to_writer_pretty(&mut encoder, export)?;
+    encoder.finish()?.flush()?;
+    Ok(path)
+}
+
+/// 삭제 리포트 저장 (`user_{id}_deletion_{시각}.json`)
+pub fn write_report(dir: &Path, report: &DeletionReport) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir).with_context(|| format!("보관 디렉토리 생성 실패: {}", dir.display()))?;
+    let path = dir.join(format!("user_{}_deletion_{}.json", report.user_id, report.finished_at.format("%Y%m%dT%H%M%SZ")));
+    std::fs::write(&path, serde_json::to_vec_pretty(report)?)
+        .with_context(|| format!("리포트 저장 실패: {}", path.display()))?;
+    Ok(path)
+}
+
+/// 작업별 요약 (로그 출력용)
+pub fn summarize(report: &DeletionReport) -> HashMap<&'static str, u64> {
+    let mut summary = HashMap::new();
+    for step in &report.steps {
+        let key = match step.action {
+            ErasureAction::Anonymized => "anonymized",
+            ErasureAction::Deleted => "deleted",
+            ErasureAction::Retained { .. } => "retained",
+        };
+        *summary.entry(key).or_default() += 1;
+    }
+    info!("개인정보 삭제 요약: user_id={}, {:?}", report.user_id, summary);
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sandbox::redis::FakeRedis;
+    use grpcserver::service::avatar_storage::LocalDirStorage;
+
+    #[test]
+    fn test_export_query_and_log_matching() {
+        assert_eq!(
+            export_query(&PERSONAL_TABLES[2]),
+            "SELECT JSON_OBJECT('match_id', match_id, 'team', team, 'score', score, 'kills', kills, 'deaths', deaths, 'disconnected', disconnected) FROM match_players WHERE player_id = ?"
+        );
+
+        let query = LogQuery { context: vec![("user_id".to_string(), "7".to_string())], ..Default::default() };
+        assert!(line_mentions_user("로그인 성공: user_id=7, nick=cop", &query, 7));
+        assert!(!line_mentions_user("로그인 성공: user_id=71, nick=cop", &query, 7));
+        let json = r#"{"timestamp":"2025-01-01T00:00:00Z","level":"Info","service":"grpcserver","message":"login","context":{"user_id":7},"thread_id":null,"module_path":null}"#;
+        assert!(line_mentions_user(json, &query, 7));
+    }
+
+    #[tokio::test]
+    async fn test_redis_export_and_delete() {
+        let server = FakeRedis::bind("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let redis = RedisConfig::connect("127.0.0.1", server.local_addr().port()).await.unwrap();
+        let mut conn = redis.get_connection();
+        let _: () = conn.hset("user:7", "nick_name", "cop").await.unwrap();
+        let _: () = conn.hset("user:7:location", "region", "kr").await.unwrap();
+        let _: () = conn.set("presence:kr:7", "1").await.unwrap();
+        let _: () = conn.set("user:71", "other").await.unwrap();
+        let _: () = conn.rpush("tcp:dm:offline:7", "{\"from\":8}").await.unwrap();
+        let _: () = conn.sadd("tcp:dm:block:7", 8).await.unwrap();
+        let _: () = conn.rpush("tcp:resume:7", "{\"seq\":1}").await.unwrap();
+        let _: () = conn.zadd(LEADERBOARD_CURRENT_KEY, 7, 120).await.unwrap();
+
+        let log_dir = std::env::temp_dir().join(format!("gdpr_logs_{}", std::process::id()));
+        std::fs::create_dir_all(&log_dir).unwrap();
+        std::fs::write(log_dir.join("grpc.log"), "login user_id=7\nlogin user_id=8\n").unwrap();
+
+        let avatar_dir = std::env::temp_dir().join(format!("gdpr_avatars_{}", std::process::id()));
+        let avatars = Arc::new(LocalDirStorage::new(&avatar_dir));
+        avatars.put("avatars/7/abc.png", "image/png", vec![1, 2, 3]).await.unwrap();
+        avatars.put("avatars/71/def.png", "image/png", vec![4, 5, 6]).await.unwrap();
+
+        let compliance = Compliance::new(redis, None, &log_dir).with_avatars(avatars.clone());
+        let export = compliance.export(7).await.unwrap();
+        assert_eq!(
+            export.redis.keys().collect::<Vec<_>>(),
+            [
+                "leaderboard:current[7]",
+                "presence:kr:7",
+                "tcp:dm:block:7",
+                "tcp:dm:offline:7",
+                "tcp:resume:7",
+                "user:7",
+                "user:7:location"
+            ]
+        );
+        assert_eq!(export.redis["user:7"]["nick_name"], "cop");
+        assert_eq!(export.avatars.get("avatars/7/abc.png").map(String::as_str), Some("AQID"));
+        assert_eq!(export.avatars.len(), 1);
+        assert_eq!(export.log_references.len(), 1);
+
+        let report = compliance.delete(7).await.unwrap();
+        assert!(report.verified, "{:?}", report.remaining);
+        assert_eq!(summarize(&report)["deleted"], 8);
+        let export = compliance.export(7).await.unwrap();
+        assert!(export.redis.is_empty() && export.avatars.is_empty());
+        let other: bool = conn.exists("user:71").await.unwrap();
+        assert!(other);
+        assert!(avatars.get("avatars/71/def.png").await.unwrap().is_some());
+
+        // 삭제 후 다시 생긴 아바타는 검증에서 남은 항목으로 보고
+        avatars.put("avatars/7/late.png", "image/png", vec![7]).await.unwrap();
+        assert_eq!(compliance.verify(7).await.unwrap(), ["avatars/7/late.png"]);
+
+        let _ = std::fs::remove_dir_all(&log_dir);
+        let _ = std::fs::remove_dir_all(&avatar_dir);
+    }
+}
@@ -0,0 +1,420 @@
+//! 게임센터 상태 모니터링
+//!
+//! 하위 서버(gRPC/TCP/RUDP) 포트, Redis/DB 연결, 실행 중인 게임센터 인스턴스 정보를 점검해
+//! 사람이 읽는 로그 또는 대시보드/배포 스크립트용 JSON 문서로 제공합니다.
+//!
+//! 실행 중인 게임센터는 Redis `gamecenter:instance` 키에 시작 시각과 버전을 TTL로 기록하며,
+//! `gamecenter status`는 이 키로 가동 시간을 계산합니다.
+
+use anyhow::Result;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::task::JoinHandle;
+use tokio::time::timeout;
+use tracing::{info, warn};
+
+use shared::config::redis_config::RedisConfig;
+use crate::unified_server::UnifiedServerConfig;
+use crate::webhook::{EventKind, LifecycleEvent, Severity, WebhookNotifier};
+
+/// 실행 중인 인스턴스 정보 키
+const INSTANCE_KEY: &str = "gamecenter:instance";
+
+/// 인스턴스 정보 TTL (초)
+const INSTANCE_TTL_SECS: u64 = 30;
+
+/// 인스턴스 정보 갱신 주기
+const INSTANCE_REFRESH: Duration = Duration::from_secs(10);
+
+/// 개별 점검 타임아웃
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 게임센터 버전
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 하위 서버 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerState {
+    Up,
+    Down,
+    Disabled,
+}
+
+/// 하위 서버 점검 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerStatus {
+    pub name: String,
+    pub state: ServerState,
+    pub address: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// 외부 의존성 점검 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyStatus {
+    pub name: String,
+    pub healthy: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// 실행 중인 게임센터 인스턴스 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceRecord {
+    pub pid: u32,
+    pub version: String,
+    /// 시작 시각 (Unix 초)
+    pub started_at: i64,
+}
+
+/// 전체 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverallStatus {
+    /// 활성화된 서버와 의존성이 모두 정상
+    Ok,
+    /// 일부 서버 또는 의존성 이상
+    Degraded,
+    /// 활성화된 서버가 모두 중지됨
+    Down,
+}
+
+/// 상태 리포트 (`gamecenter status --json` 출력 문서)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusReport {
+    pub status: OverallStatus,
+    pub version: String,
+    /// 리포트 생성 시각 (RFC 3339)
+    pub generated_at: String,
+    /// 실행 중인 인스턴스의 가동 시간 (인스턴스 정보가 없으면 None)
+    pub uptime_secs: Option<u64>,
+    pub instance: Option<InstanceRecord>,
+    pub servers: Vec<ServerStatus>,
+    pub dependencies: Vec<DependencyStatus>,
+}
+
+impl StatusReport {
+    /// 점검 결과로 전체 상태를 판정합니다.
+    pub fn overall(servers: &[ServerStatus], dependencies: &[DependencyStatus]) -> OverallStatus {
+        let enabled: Vec<&ServerStatus> = servers.iter()
+            .filter(|server| server.state != ServerState::Disabled)
+            .collect();
+
+        if !enabled.is_empty() && enabled.iter().all(|server| server.state == ServerState::Down) {
+            OverallStatus::Down
+        } else if enabled.iter().any(|server| server.state == ServerState::Down)
+            || dependencies.iter().any(|dep| !dep.healthy)
+        {
+            OverallStatus::Degraded
+        } else {
+            OverallStatus::Ok
+        }
+    }
+
+    /// 이상 항목 목록 (중지된 서버, 비정상 의존성)
+    pub fn problems(&self) -> Vec<String> {
+        let servers = self.servers.iter()
+            .filter(|server| server.state == ServerState::Down)
+            .map(|server| format!("{} 서버 중지 ({}){}", server.name, server.address,
+                                  server.detail.as_ref().map(|d| format!(" - {d}")).unwrap_or_default()));
+        let dependencies = self.dependencies.iter()
+            .filter(|dep| !dep.healthy)
+            .map(|dep| format!("{} 이상{}", dep.name,
+                               dep.detail.as_ref().map(|d| format!(" - {d}")).unwrap_or_default()));
+        servers.chain(dependencies).collect()
+    }
+
+    /// 사람이 읽는 형식으로 로그 출력
+    pub fn log(&self) {
+        info!("📊 게임센터 상태: {:?} (v{})", self.status, self.version);
+        match self.uptime_secs {
+            Some(uptime) => info!("  └─ 가동 시간: {}초", uptime),
+            None => info!("  └─ 실행 중인 인스턴스 정보 없음"),
+        }
+        for server in &self.servers {
+            info!("  └─ {:<5} {:?} {} (v{}){}", server.name, server.state, server.address, server.version,
+                  server.detail.as_ref().map(|d| format!(" - {d}")).unwrap_or_default());
+        }
+        for dep in &self.dependencies {
+            let mark = if dep.healthy { "✅" } else { "❌" };
+            info!("  └─ {} {}{}", mark, dep.name,
+                  dep.detail.as_ref().map(|d| format!(" - {d}")).unwrap_or_default());
+        }
+    }
+}
+
+/// 게임센터 상태 모니터
+pub struct HealthMonitor {
+    config: UnifiedServerConfig,
+}
+
+impl HealthMonitor {
+    pub fn new(config: UnifiedServerConfig) -> Self {
+        Self { config }
+    }
+
+    /// 전체 점검 실행
+    pub async fn collect(&self) -> StatusReport {
+        let (grpc, tcp, rudp, redis, database, instance) = tokio::join!(
+            probe_tcp("grpc", self.config.enable_grpc, self.config.grpc_address, grpcserver::VERSION),
+            probe_tcp("tcp", self.config.enable_tcp, self.config.tcp_address, tcpserver::VERSION),
+            probe_udp("rudp", self.config.enable_rudp, self.config.rudp_address, rudpserver::VERSION),
+            probe_redis(),
+            probe_database(),
+            read_instance(),
+        );
+
+        let servers = vec![grpc, tcp, rudp];
+        let dependencies = vec![redis, database];
+        let uptime_secs = instance.as_ref()
+            .map(|record| (chrono::Utc::now().timestamp() - record.started_at).max(0) as u64);
+
+        StatusReport {
+            status: StatusReport::overall(&servers, &dependencies),
+            version: VERSION.to_string(),
+            generated_at: chrono::Utc::now().to_rfc3339(),
+            uptime_secs,
+            instance,
+            servers,
+            dependencies,
+        }
+    }
+}
+
+/// 실행 중인 인스턴스 정보를 주기적으로 기록합니다.
+pub fn spawn_instance_heartbeat(redis_config: RedisConfig) -> JoinHandle<()> {
+    let record = InstanceRecord {
+        pid: std::process::id(),
+        version: VERSION.to_string(),
+        started_at: chrono::Utc::now().timestamp(),
+    };
+
+    tokio::spawn(async move {
+        let payload = match serde_json::to_string(&record) {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!("인스턴스 정보 직렬화 실패: {}", e);
+                return;
+            }
+        };
+        let mut interval = tokio::time::interval(INSTANCE_REFRESH);
+        loop {
+            interval.tick().await;
+            let mut conn = redis_config.get_connection();
+            let result: redis::RedisResult<()> = conn.set_ex(INSTANCE_KEY, &payload, INSTANCE_TTL_SECS).await;
+            if let Err(e) = result {
+                warn!("인스턴스 정보 기록 실패: {}", e);
+            }
+        }
+    })
+}
+
+/// 주기적으로 상태를 점검해 헬스 체크 실패/복구를 웹훅으로 알립니다.
+pub fn spawn_health_watch(config: UnifiedServerConfig, notifier: WebhookNotifier, period: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let monitor = HealthMonitor::new(config);
+        let mut previous = OverallStatus::Ok;
+        let mut interval = tokio::time::interval(period);
+        // 시작 직후에는 서버가 아직 바인드 중일 수 있으므로 첫 점검은 한 주기 뒤에
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let report = monitor.collect().await;
+
+            match report.status {
+                OverallStatus::Ok if previous != OverallStatus::Ok => {
+                    notifier.notify(LifecycleEvent::new(EventKind::HealthRecovered, Severity::Info, "gamecenter", "헬스 체크 정상 복구"));
+                }
+                OverallStatus::Ok => {}
+                status => {
+                    let severity = if status == OverallStatus::Down { Severity::Critical } else { Severity::Warning };
+                    notifier.notify(
+                        LifecycleEvent::new(EventKind::HealthCheckFailed, severity, "gamecenter", format!("헬스 체크 실패: {:?}", status))
+                            .with_detail(report.problems().join("\n")),
+                    );
+                }
+            }
+            previous = report.status;
+        }
+    })
+}
+
+/// 실행 중인 인스턴스 정보 삭제
+pub async fn clear_instance(redis_config: &RedisConfig) {
+    let mut conn = redis_config.get_connection();
+    let result: redis::RedisResult<()> = conn.del(INSTANCE_KEY).await;
+    if let Err(e) = result {
+        warn!("인스턴스 정보 삭제 실패: {}", e);
+    }
+}
+
+/// TCP 포트 연결로 서버 상태 확인
+async fn probe_tcp(name: &str, enabled: bool, addr: SocketAddr, version: &str) -> ServerStatus {
+    let (state, detail) = if !enabled {
+        (ServerState::Disabled, None)
+    } else {
+        match timeout(PROBE_TIMEOUT, TcpStream::connect(addr)).await {
+            Ok(Ok(_)) => (ServerState::Up, None),
+            Ok(Err(e)) => (ServerState::Down, Some(e.to_string())),
+            Err(_) => (ServerState::Down, Some("연결 시간 초과".to_string())),
+        }
+    };
+
+    ServerStatus { name: name.to_string(), state, address: addr.to_string(), version: version.to_string(), detail }
+}
+
+/// UDP 포트 점유 여부로 서버 상태 확인 (UDP는 연결 확인이 불가능하므로 바인딩 시도)
+async fn probe_udp(name: &str, enabled: bool, addr: SocketAddr, version: &str) -> ServerStatus {
+    let (state, detail) = if !enabled {
+        (ServerState::Disabled, None)
+    } else {
+        match UdpSocket::bind(addr).await {
+            Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => (ServerState::Up, None),
+            Err(e) => (ServerState::Down, Some(e.to_string())),
+            Ok(_) => (ServerState::Down, Some("포트가 사용되고 있지 않습니다".to_string())),
+        }
+    };
+
+    ServerStatus { name: name.to_string(), state, address: addr.to_string(), version: version.to_string(), detail }
+}
+
+/// Redis PING
+async fn probe_redis() -> DependencyStatus {
+    let host = std::env::var("redis_host").unwrap_or_else(|_| "localhost".to_string());
+    let port = std::env::var("redis_port").unwrap_or_else(|_| "6379".to_string());
+    let url = format!("redis://{host}:{port}");
+
+    let started = Instant::now();
+    let ping = async {
+        let client = redis::Client::open(url.as_str())?;
+        let mut conn = client.get_multiplexed_tokio_connection().await?;
+        redis::cmd("PING").query_async::<_, String>(&mut conn).await
+    };
+    let result = timeout(PROBE_TIMEOUT, ping).await;
+
+    dependency("redis", started, match result {
+        Ok(Ok(_)) => Ok(format!("{host}:{port}")),
+        Ok(Err(e)) => Err(format!("{host}:{port} 연결 실패: {e}")),
+        Err(_) => Err(format!("{host}:{port} 연결 시간 초과")),
+    })
+}
+
+/// DB 헬스 체크
+async fn probe_database() -> DependencyStatus {
+    let started = Instant::now();
+    if let Some(sandbox) = crate::sandbox::db::SandboxDatabase::active() {
+        let result = timeout(PROBE_TIMEOUT, sandbox.health_check()).await;
+        return dependency("database", started, match result {
+            Ok(Ok(())) => Ok(format!("sqlite:{}", sandbox.location)),
+            Ok(Err(e)) => Err(format!("연결 실패: {e}")),
+            Err(_) => Err("연결 시간 초과".to_string()),
+        });
+    }
+    let check = async {
+        let db = shared::config::db::DbConfig::new().await?;
+        let healthy = db.health_check().await;
+        db.close().await;
+        healthy.map(|_| format!("{}:{}/{}", db.host, db.port, db.database))
+    };
+    let result = timeout(PROBE_TIMEOUT, check).await;
+
+    dependency("database", started, match result {
+        Ok(Ok(detail)) => Ok(detail),
+        Ok(Err(e)) => Err(format!("연결 실패: {e}")),
+        Err(_) => Err("연결 시간 초과".to_string()),
+    })
+}
+
+fn dependency(name: &str, started: Instant, result: Result<String, String>) -> DependencyStatus {
+    match result {
+        Ok(detail) => DependencyStatus {
+            name: name.to_string(),
+            healthy: true,
+            latency_ms: Some(started.elapsed().as_millis() as u64),
+            detail: Some(detail),
+        },
+        Err(detail) => DependencyStatus {
+            name: name.to_string(),
+            healthy: false,
+            latency_ms: None,
+            detail: Some(detail),
+        },
+    }
+}
+
+/// 실행 중인 인스턴스 정보 조회 (Redis 연결 실패 시 None)
+async fn read_instance() -> Option<InstanceRecord> {
+    let host = std::env::var("redis_host").unwrap_or_else(|_| "localhost".to_string());
+    let port = std::env::var("redis_port").unwrap_or_else(|_| "6379".to_string());
+
+    let read = async {
+        let client = redis::Client::open(format!("redis://{host}:{port}"))?;
+        let mut conn = client.get_multiplexed_tokio_connection().await?;
+        conn.get::<_, Option<String>>(INSTANCE_KEY).await
+    };
+
+    match timeout(PROBE_TIMEOUT, read).await {
+        Ok(Ok(Some(payload))) => serde_json::from_str(&payload).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(state: ServerState) -> ServerStatus {
+        ServerStatus {
+            name: "tcp".to_string(),
+            state,
+            address: "127.0.0.1:4000".to_string(),
+            version: VERSION.to_string(),
+            detail: None,
+        }
+    }
+
+    fn dep(healthy: bool) -> DependencyStatus {
+        DependencyStatus { name: "redis".to_string(), healthy, latency_ms: None, detail: None }
+    }
+
+    #[test]
+    fn test_overall_status() {
+        let all_up = [server(ServerState::Up), server(ServerState::Disabled)];
+        assert_eq!(StatusReport::overall(&all_up, &[dep(true)]), OverallStatus::Ok);
+        assert_eq!(StatusReport::overall(&all_up, &[dep(false)]), OverallStatus::Degraded);
+
+        let partial = [server(ServerState::Up), server(ServerState::Down)];
+        assert_eq!(StatusReport::overall(&partial, &[dep(true)]), OverallStatus::Degraded);
+
+        let all_down = [server(ServerState::Down), server(ServerState::Disabled)];
+        assert_eq!(StatusReport::overall(&all_down, &[dep(true)]), OverallStatus::Down);
+    }
+
+    #[test]
+    fn test_report_json_shape() {
+        let servers = vec![server(ServerState::Up)];
+        let report = StatusReport {
+            status: OverallStatus::Ok,
+            version: VERSION.to_string(),
+            generated_at: "2024-01-01T00:00:00+00:00".to_string(),
+            uptime_secs: Some(42),
+            instance: None,
+            servers,
+            dependencies: vec![dep(true)],
+        };
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["status"], "ok");
+        assert_eq!(json["uptime_secs"], 42);
+        assert_eq!(json["servers"][0]["state"], "up");
+        assert!(json["servers"][0].get("detail").is_none());
+    }
+}
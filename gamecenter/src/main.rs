@@ -4,17 +4,40 @@
 //! Redis 인스턴스 관리와 함께 모든 게임 서버를 단일 명령으로 실행할 수 있습니다.
 
 use shared::config::redis_config::RedisConfig;
+use shared::logging::{LoggingSystem, ServiceType};
+use shared::monitoring::crash::{self, CrashConfig};
+use shared::monitoring::PlayerSampler;
+use shared::service::saga::SagaOutcome;
 use anyhow::{Context, Result};
-use tracing::{info, error, warn};
+use tracing::{info, error};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::signal;
 use tokio::process::Command;
 
+mod account_merge;
+mod chaos;
+mod e2e;
+mod gdpr;
+mod health;
+mod region;
+mod rollover;
+mod routing;
+mod sandbox;
+mod scheduler;
 mod tests;
 mod unified_server;
+mod webhook;
 
-use unified_server::{UnifiedGameServer, UnifiedServerConfigBuilder};
+use chaos::{ChaosConfig, ChaosRunner, FaultInjectors};
+use e2e::{E2eConfig, E2eRunner};
+use health::HealthMonitor;
+use region::RegionConfig;
+use rollover::RolloverConfig;
+use sandbox::Sandbox;
+use scheduler::JobScheduler;
+use webhook::{EventKind, LifecycleEvent, Severity, WebhookNotifier};
+use unified_server::{UnifiedGameServer, UnifiedServerConfig, UnifiedServerConfigBuilder};
 
 /// 게임센터 서버 상태
 pub struct GameCenterServer {
@@ -22,6 +45,15 @@ pub struct GameCenterServer {
     pub redis_config: Option<RedisConfig>,
     pub redis_process: Option<tokio::process::Child>,
     pub unified_server: Option<UnifiedGameServer>,
+    pub instance_heartbeat: Option<tokio::task::JoinHandle<()>>,
+    pub job_scheduler: Option<JobScheduler>,
+    pub rollover_listener: Option<tokio::task::JoinHandle<()>>,
+    pub routing_refresh: Option<tokio::task::JoinHandle<()>>,
+    pub region_replication: Option<tokio::task::JoinHandle<()>>,
+    pub notifier: Option<WebhookNotifier>,
+    pub health_watch: Option<tokio::task::JoinHandle<()>>,
+    /// 개발용 샌드박스 (설정 시 외부 Redis를 시작/중지하지 않음)
+    pub sandbox: Option<Sandbox>,
 }
 
 impl Default for GameCenterServer {
@@ -31,6 +63,14 @@ impl Default for GameCenterServer {
             redis_config: None,
             redis_process: None,
             unified_server: None,
+            instance_heartbeat: None,
+            job_scheduler: None,
+            rollover_listener: None,
+            routing_refresh: None,
+            region_replication: None,
+            notifier: None,
+            health_watch: None,
+            sandbox: None,
         }
     }
 }
@@ -112,8 +152,10 @@ impl GameCenterServer {
         // 환경변수 로드
         dotenv::dotenv().ok();
         
-        // Redis 서버 시작
-        self.start_redis_server().await?;
+        // Redis 서버 시작 (샌드박스는 이미 에뮬레이터 실행 중)
+        if self.sandbox.is_none() {
+            self.start_redis_server().await?;
+        }
         
         // Redis 연결 설정
         let redis_config = RedisConfig::new()
@@ -123,16 +165,75 @@ impl GameCenterServer {
         self.redis_config = Some(redis_config.clone());
         info!("✅ Redis 연결 성공: {}:{}", redis_config.host, redis_config.port);
         
+        // 생명주기 이벤트 웹훅 (WEBHOOK_URL 설정 시)
+        self.notifier = WebhookNotifier::from_env();
+        if self.notifier.is_some() {
+            info!("🔔 생명주기 이벤트 웹훅 알림 활성화");
+        }
+        
         // 통합 서버 생성 및 시작
         let unified_server = UnifiedGameServer::from_env()
-            .context("통합 서버 설정 생성 실패")?;
+            .context("통합 서버 설정 생성 실패")?
+            .with_notifier(self.notifier.clone());
         
-        unified_server.start().await.context("통합 서버 시작 실패")?;
+        if let Err(e) = unified_server.start().await {
+            self.notify(
+                LifecycleEvent::new(EventKind::Crashed, Severity::Critical, "gamecenter", "게임센터 시작 실패")
+                    .with_detail(format!("{:#}", e)),
+            );
+            return Err(e.context("통합 서버 시작 실패"));
+        }
+        
+        // RUDP 라우팅 정보 기록 및 블루/그린 교체 요청 처리 (`gamecenter rollover rudp`)
+        if unified_server.config().enable_rudp {
+            let rollover_config = RolloverConfig::from_env(unified_server.config().rudp_address);
+            rollover::publish_initial_route(&unified_server, &redis_config, &rollover_config).await;
+            self.rollover_listener = Some(rollover::spawn_listener(
+                unified_server.clone(),
+                redis_config.clone(),
+                rollover_config,
+            ));
+        }
         self.unified_server = Some(unified_server);
         
+        // `gamecenter status`용 인스턴스 정보 기록
+        self.instance_heartbeat = Some(health::spawn_instance_heartbeat(redis_config.clone()));
+        
+        // 로그인 응답용 접속 라우팅 테이블 갱신
+        self.routing_refresh = Some(routing::spawn_routing_refresh(redis_config.clone()));
+        
+        // 지역 간 접속 정보 복제 (REGION_REPLICATION_HOST 설정 시)
+        self.region_replication = region::spawn_replication(redis_config.clone(), RegionConfig::from_env());
+        
+        // 정기 유지보수 작업 시작 (여러 인스턴스 중 한 곳에서만 실행됨)
+        let enable_jobs = std::env::var("ENABLE_JOB_SCHEDULER")
+            .map(|v| v.parse().unwrap_or(true))
+            .unwrap_or(true);
+        if enable_jobs {
+            let mut job_scheduler = JobScheduler::with_default_jobs(redis_config);
+            job_scheduler.start().await;
+            self.job_scheduler = Some(job_scheduler);
+        }
+        
+        // 헬스 체크 실패 알림
+        if let (Some(notifier), Some(server)) = (&self.notifier, &self.unified_server) {
+            let period = std::env::var("WEBHOOK_HEALTH_INTERVAL_SECS").ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(60);
+            self.health_watch = Some(health::spawn_health_watch(
+                server.config().clone(),
+                notifier.clone(),
+                std::time::Duration::from_secs(period),
+            ));
+        }
+        
         // 서버 상태를 실행 중으로 설정
         self.is_running.store(true, Ordering::SeqCst);
         
+        self.notify(
+            LifecycleEvent::new(EventKind::Started, Severity::Info, "gamecenter", "게임센터 시작")
+                .with_detail(format!("v{}", health::VERSION)),
+        );
         info!("✅ 통합 게임센터 서버가 성공적으로 시작되었습니다!");
         Ok(())
     }
@@ -150,16 +251,59 @@ impl GameCenterServer {
         }
         self.unified_server = None;
         
+        // 헬스 체크 알림 중지
+        if let Some(health_watch) = self.health_watch.take() {
+            health_watch.abort();
+        }
+        
+        // 교체 요청 처리 중지
+        if let Some(listener) = self.rollover_listener.take() {
+            listener.abort();
+        }
+        
+        // 라우팅 테이블 갱신 중지 (기록된 테이블은 TTL로 만료)
+        if let Some(refresh) = self.routing_refresh.take() {
+            refresh.abort();
+        }
+        
+        // 지역 간 복제 중지
+        if let Some(replication) = self.region_replication.take() {
+            replication.abort();
+        }
+        
+        // 정기 작업 중지
+        if let Some(mut job_scheduler) = self.job_scheduler.take() {
+            job_scheduler.stop();
+        }
+        
+        // 인스턴스 정보 정리
+        if let Some(heartbeat) = self.instance_heartbeat.take() {
+            heartbeat.abort();
+        }
+        if let Some(ref redis_config) = self.redis_config {
+            health::clear_instance(redis_config).await;
+        }
+        
         // Redis 서버 중지
-        self.stop_redis_server().await?;
+        if self.sandbox.is_none() {
+            self.stop_redis_server().await?;
+        }
         
         // Redis 연결 정리
         self.redis_config = None;
         
+        self.notify(LifecycleEvent::new(EventKind::Stopped, Severity::Info, "gamecenter", "게임센터 중지"));
         info!("✅ 통합 게임센터 서버가 성공적으로 중지되었습니다!");
         Ok(())
     }
 
+    /// 생명주기 이벤트 알림 (웹훅 미설정 시 무시)
+    fn notify(&self, event: LifecycleEvent) {
+        if let Some(ref notifier) = self.notifier {
+            notifier.notify(event);
+        }
+    }
+
     /// 서버 상태 확인
     pub fn is_running(&self) -> bool {
         self.is_running.load(Ordering::SeqCst)
@@ -193,10 +337,87 @@ impl GameCenterServer {
 /// 통합 게임센터의 모든 기능을 실행하는 메인 함수
 pub async fn run_gamecenter() -> Result<()> {
     info!("🎮 통합 게임센터 시작 중...");
-    
-    // 게임센터 서버 생성
+    serve(GameCenterServer::new()).await
+}
+
+/// 외부 Redis/DB 없이 샌드박스로 통합 게임센터 실행
+pub async fn run_dev() -> Result<()> {
+    info!("🧪 개발용 샌드박스 모드로 게임센터 시작 중...");
     let mut server = GameCenterServer::new();
-    
+    server.sandbox = Some(Sandbox::start().await?);
+    serve(server).await
+}
+
+/// 카오스 테스트 모드 실행
+///
+/// 샌드박스 위에서 게임센터를 띄워 장애를 주입하고 복원력 리포트를 출력합니다.
+/// 해제 후 복구되지 않은 장애가 있으면 실패로 끝납니다.
+async fn run_chaos(json: bool) -> Result<()> {
+    let config = ChaosConfig::from_env()?;
+    info!("🌪️ 카오스 테스트 모드로 게임센터 시작 중...");
+
+    let sandbox = Sandbox::start().await?;
+    let mut injectors = FaultInjectors::prepare(&sandbox).await?;
+    let mut server = GameCenterServer::new();
+    server.sandbox = Some(sandbox);
+    server.start().await?;
+
+    let Some(unified_server) = server.unified_server.clone() else {
+        server.stop().await?;
+        return Err(anyhow::anyhow!("통합 서버가 시작되지 않았습니다"));
+    };
+    if unified_server.config().enable_rudp {
+        injectors.attach_rudp(unified_server.config().rudp_address).await?;
+    }
+
+    let result = ChaosRunner::new(config, unified_server, injectors).run().await;
+    server.stop().await?;
+    let report = result?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        report.log();
+    }
+    if !report.all_recovered() {
+        return Err(anyhow::anyhow!("복구되지 않은 장애가 있습니다"));
+    }
+    Ok(())
+}
+
+/// E2E 스모크 테스트 모드 실행
+///
+/// 샌드박스 위에서 게임센터를 띄워 gRPC 로그인, TCP 방 입장, RUDP 이동을 차례로 진행하고
+/// 서버 간 일관성을 확인합니다. 실패한 단계가 있으면 실패로 끝납니다.
+async fn run_e2e(json: bool) -> Result<()> {
+    let config = E2eConfig::from_env()?;
+    info!("🧪 E2E 스모크 테스트 모드로 게임센터 시작 중...");
+
+    let mut server = GameCenterServer::new();
+    server.sandbox = Some(Sandbox::start().await?);
+    server.start().await?;
+
+    let Some(unified_server) = server.unified_server.clone() else {
+        server.stop().await?;
+        return Err(anyhow::anyhow!("통합 서버가 시작되지 않았습니다"));
+    };
+
+    let report = E2eRunner::new(config, unified_server).run().await;
+    server.stop().await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        report.log();
+    }
+    if !report.passed() {
+        return Err(anyhow::anyhow!("실패한 E2E 단계가 있습니다"));
+    }
+    Ok(())
+}
+
+/// 종료 시그널까지 게임센터 실행
+async fn serve(mut server: GameCenterServer) -> Result<()> {
     // 서버 시작
     server.start().await?;
     
@@ -280,6 +501,264 @@ pub async fn stop_gamecenter() -> Result<()> {
     Ok(())
 }
 
+/// 상태 확인 모드 실행
+///
+/// `--json`이면 대시보드/배포 스크립트용 JSON 문서를 표준 출력에 씁니다.
+/// 전체 상태가 `down`이면 종료 코드 1을 반환합니다.
+async fn run_status(json: bool) -> Result<()> {
+    dotenv::dotenv().ok();
+
+    let config = UnifiedServerConfig::from_env()?;
+    let report = HealthMonitor::new(config).collect().await;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        report.log();
+    }
+
+    if report.status == health::OverallStatus::Down {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// 정기 작업 상태 조회 모드 실행
+///
+/// 모든 게임센터 인스턴스가 Redis에 기록한 작업별 마지막 실행 결과를 보여줍니다.
+async fn run_jobs_status(json: bool) -> Result<()> {
+    let redis_config = RedisConfig::new()
+        .await
+        .context("RedisConfig 생성 실패")?;
+    let statuses = scheduler::fetch_statuses(&redis_config).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&statuses)?);
+        return Ok(());
+    }
+
+    if statuses.is_empty() {
+        info!("⏰ 기록된 작업 실행 결과가 없습니다.");
+        return Ok(());
+    }
+    for status in statuses {
+        let last_run = status.last_started_at
+            .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+            .map(|at| at.to_rfc3339())
+            .unwrap_or_else(|| "-".to_string());
+        info!(
+            "⏰ {} [{}] 마지막 실행: {} {:?} ({}ms, {}) - 실행 {}회 / 실패 {}회 - {}",
+            status.name,
+            status.schedule,
+            last_run,
+            status.last_outcome,
+            status.last_duration_ms.unwrap_or_default(),
+            status.last_runner.as_deref().unwrap_or("-"),
+            status.run_count,
+            status.failure_count,
+            status.last_message.as_deref().unwrap_or(""),
+        );
+    }
+    Ok(())
+}
+
+/// 접속 라우팅 테이블 조회 모드 실행
+///
+/// 현재 하트비트로 테이블을 만들어 출력합니다 (실행 중인 게임센터가 기록하는 것과 같은 내용).
+async fn run_routes(json: bool) -> Result<()> {
+    let redis_config = RedisConfig::new()
+        .await
+        .context("RedisConfig 생성 실패")?;
+    let table = routing::build_table(&redis_config).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&table)?);
+        return Ok(());
+    }
+
+    if table.endpoints.is_empty() {
+        info!("🧭 하트비트를 보내는 게임 서버가 없습니다.");
+        return Ok(());
+    }
+    for endpoint in &table.endpoints {
+        let capacity = match endpoint.capacity {
+            0 => "?".to_string(),
+            capacity => capacity.to_string(),
+        };
+        info!(
+            "🧭 {} [{}] {} - 접속 {}/{} (부하 {:.0}%){}",
+            endpoint.protocol,
+            endpoint.region,
+            endpoint.address,
+            endpoint.ccu,
+            capacity,
+            endpoint.load * 100.0,
+            if endpoint.is_full() { " FULL" } else { "" },
+        );
+    }
+    Ok(())
+}
+
+/// 블루/그린 교체 모드 실행
+///
+/// 실행 중인 게임센터에 교체를 요청하고, 완료될 때까지 진행 상태를 출력합니다.
+async fn run_rollover(target: Option<&str>) -> Result<()> {
+    let Some(target) = target else {
+        return Err(anyhow::anyhow!("교체 대상을 지정하세요: gamecenter rollover <{}>", rollover::SUPPORTED_TARGETS.join("|")));
+    };
+
+    dotenv::dotenv().ok();
+    let config = UnifiedServerConfig::from_env()?;
+    let drain_timeout = RolloverConfig::from_env(config.rudp_address).drain_timeout;
+
+    let redis_config = RedisConfig::new()
+        .await
+        .context("RedisConfig 생성 실패")?;
+    let status = rollover::request_rollover(&redis_config, target, drain_timeout + std::time::Duration::from_secs(60)).await?;
+
+    if status.phase == rollover::RolloverPhase::Failed {
+        return Err(anyhow::anyhow!("블루/그린 교체 실패: {}", status.message));
+    }
+    info!("✅ {}", status.message);
+    Ok(())
+}
+
+/// 개인정보 내보내기/삭제 모드 실행
+///
+/// `export`는 보관 파일을, `delete --confirm`은 검증 리포트를 `GDPR_EXPORT_DIR`에 저장합니다.
+/// DB에 연결할 수 없으면 실패하며, `--skip-db`를 주면 Redis/로그만 처리합니다.
+async fn run_gdpr(args: &[String], json: bool) -> Result<()> {
+    let usage = || anyhow::anyhow!("사용법: gamecenter gdpr <{}> <user_id> [--confirm] [--skip-db] [--json]", gdpr::SUPPORTED_ACTIONS.join("|"));
+    let action = args.get(2).map(String::as_str).ok_or_else(usage)?;
+    let user_id: u32 = args.get(3).and_then(|id| id.parse().ok()).filter(|id| *id > 0).ok_or_else(usage)?;
+    let has_flag = |flag: &str| args.iter().skip(4).any(|arg| arg == flag);
+    if !gdpr::SUPPORTED_ACTIONS.contains(&action) {
+        return Err(usage());
+    }
+    if action == "delete" && !has_flag("--confirm") {
+        return Err(anyhow::anyhow!("삭제는 되돌릴 수 없습니다. 확인 후 --confirm을 붙여 다시 실행하세요."));
+    }
+
+    dotenv::dotenv().ok();
+    let redis_config = RedisConfig::new()
+        .await
+        .context("RedisConfig 생성 실패")?;
+    let db = if has_flag("--skip-db") {
+        None
+    } else {
+        let db = shared::config::db::DbConfig::new().await
+            .context("DB 연결 실패 (DB 없이 처리하려면 --skip-db)")?;
+        Some(db.pool)
+    };
+    let export_dir = std::path::PathBuf::from(std::env::var("GDPR_EXPORT_DIR").unwrap_or_else(|_| "./data/gdpr".to_string()));
+    let log_dir = std::env::var("LOG_DIR").unwrap_or_else(|_| "./logs".to_string());
+    let avatar_config = grpcserver::config::avatar_config_from_env().context("아바타 저장소 설정 오류")?;
+    let compliance = gdpr::Compliance::new(redis_config, db, log_dir).with_avatars(avatar_config.storage.open());
+
+    if action == "export" {
+        let export = compliance.export(user_id).await?;
+        let path = gdpr::write_archive(&export_dir, &export)?;
+        if json {
+            println!("{}", serde_json::json!({ "user_id": user_id, "archive": path }));
+        } else {
+            info!("📦 개인정보 내보내기 완료: user_id={}, 테이블 {}개, Redis 키 {}개, 아바타 {}개, 로그 파일 {}개 → {}",
+                  user_id, export.database.len(), export.redis.len(), export.avatars.len(), export.log_references.len(), path.display());
+        }
+        return Ok(());
+    }
+
+    let report = compliance.delete(user_id).await?;
+    let path = gdpr::write_report(&export_dir, &report)?;
+    gdpr::summarize(&report);
+    // 삭제 요청 자체는 감사 로그에 남김 (`AUDIT_LOG_FILE`)
+    match shared::security::AuditSink::from_env().await {
+        Ok(Some(audit)) => {
+            let event = shared::security::AuditEvent::new(shared::security::AuditEventKind::AdminCommand, "gamecenter")
+                .with_target(user_id.to_string())
+                .with_detail(format!("gdpr_delete verified={}", report.verified));
+            if let Err(e) = audit.record(event).await {
+                error!("감사 로그 기록 실패: {}", e);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => error!("감사 로그 초기화 실패: {}", e),
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        info!("🧹 개인정보 삭제 완료: user_id={}, 단계 {}개, 검증 {} → {}",
+              user_id, report.steps.len(), if report.verified { "통과" } else { "실패" }, path.display());
+        for remaining in &report.remaining {
+            error!("  └─ 남은 항목: {}", remaining);
+        }
+    }
+    if !report.verified {
+        return Err(anyhow::anyhow!("삭제 검증 실패: 남은 항목 {}개", report.remaining.len()));
+    }
+    Ok(())
+}
+
+/// 계정 병합 모드 실행
+///
+/// 시작할 때 `SAGA_DIR`에 남아 있는 중단된 병합을 먼저 재개한 뒤 요청한 병합을 실행합니다.
+/// DB에 연결할 수 없으면 실패하며, `--skip-db`를 주면 Redis 단계만 처리합니다.
+async fn run_merge(args: &[String], json: bool) -> Result<()> {
+    let usage = || anyhow::anyhow!("사용법: gamecenter merge <source_id> <target_id> --confirm [--skip-db] [--json]");
+    let source_id: u32 = args.get(2).and_then(|id| id.parse().ok()).filter(|id| *id > 0).ok_or_else(usage)?;
+    let target_id: u32 = args.get(3).and_then(|id| id.parse().ok()).filter(|id| *id > 0).ok_or_else(usage)?;
+    let has_flag = |flag: &str| args.iter().skip(4).any(|arg| arg == flag);
+    if !has_flag("--confirm") {
+        return Err(anyhow::anyhow!("병합하면 원본 계정의 재화가 대상 계정으로 옮겨집니다. 확인 후 --confirm을 붙여 다시 실행하세요."));
+    }
+
+    dotenv::dotenv().ok();
+    let redis_config = RedisConfig::new()
+        .await
+        .context("RedisConfig 생성 실패")?;
+    let db = if has_flag("--skip-db") {
+        None
+    } else {
+        let db = shared::config::db::DbConfig::new().await
+            .context("DB 연결 실패 (DB 없이 처리하려면 --skip-db)")?;
+        Some(db.pool)
+    };
+    let saga_dir = std::env::var("SAGA_DIR").unwrap_or_else(|_| "./data/saga".to_string());
+    let merge = account_merge::AccountMerge::open(redis_config, db, saga_dir)?;
+    merge.ensure_schema().await?;
+    merge.resume_pending().await?;
+
+    let outcome = merge.merge(source_id, target_id).await?;
+    let (status, detail) = match &outcome {
+        SagaOutcome::Completed(ctx) => ("completed", format!("gold={} experience={} score={}", ctx.gold, ctx.experience, ctx.score.unwrap_or(0.0))),
+        SagaOutcome::Compensated { failure, .. } => ("compensated", failure.clone()),
+        SagaOutcome::Pending { failure, error } => ("pending", format!("{failure} / 보상 실패: {error}")),
+    };
+    // 병합 요청은 감사 로그에 남김 (`AUDIT_LOG_FILE`)
+    match shared::security::AuditSink::from_env().await {
+        Ok(Some(audit)) => {
+            let event = shared::security::AuditEvent::new(shared::security::AuditEventKind::AdminCommand, "gamecenter")
+                .with_target(format!("{source_id}->{target_id}"))
+                .with_detail(format!("account_merge status={status}"));
+            if let Err(e) = audit.record(event).await {
+                error!("감사 로그 기록 실패: {}", e);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => error!("감사 로그 초기화 실패: {}", e),
+    }
+
+    if json {
+        println!("{}", serde_json::json!({ "source_id": source_id, "target_id": target_id, "status": status, "detail": detail }));
+    } else {
+        info!("🔀 계정 병합 {}: {} → {} ({})", status, source_id, target_id, detail);
+    }
+    match outcome {
+        SagaOutcome::Completed(_) => Ok(()),
+        _ => Err(anyhow::anyhow!("계정 병합 실패 ({status}): {detail}")),
+    }
+}
+
 /// 개별 서버 모드 실행
 async fn run_individual_server(server_type: &str) -> Result<()> {
     dotenv::dotenv().ok();
@@ -292,7 +771,7 @@ async fn run_individual_server(server_type: &str) -> Result<()> {
                 .enable_tcp(false)
                 .enable_rudp(false)
                 .build()?;
-            let server = UnifiedGameServer::new(config);
+            let server = UnifiedGameServer::new(config).with_notifier(WebhookNotifier::from_env());
             server.start().await?;
             server.wait_for_shutdown().await?;
         }
@@ -303,7 +782,7 @@ async fn run_individual_server(server_type: &str) -> Result<()> {
                 .enable_tcp(true)
                 .enable_rudp(false)
                 .build()?;
-            let server = UnifiedGameServer::new(config);
+            let server = UnifiedGameServer::new(config).with_notifier(WebhookNotifier::from_env());
             server.start().await?;
             server.wait_for_shutdown().await?;
         }
@@ -314,7 +793,7 @@ async fn run_individual_server(server_type: &str) -> Result<()> {
                 .enable_tcp(false)
                 .enable_rudp(true)
                 .build()?;
-            let server = UnifiedGameServer::new(config);
+            let server = UnifiedGameServer::new(config).with_notifier(WebhookNotifier::from_env());
             server.start().await?;
             server.wait_for_shutdown().await?;
         }
@@ -326,29 +805,99 @@ async fn run_individual_server(server_type: &str) -> Result<()> {
     Ok(())
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    // 로깅 설정
-    tracing_subscriber::fmt()
-        .with_env_filter(
+/// 하위 서버 로그를 모으는 서비스 목록
+const AGGREGATED_SERVICES: [ServiceType; 4] = [
+    ServiceType::GameCenter,
+    ServiceType::GrpcServer,
+    ServiceType::TcpServer,
+    ServiceType::RudpServer,
+];
+
+/// 단일 로깅 초기화
+///
+/// 통합 실행 시 하위 서버들은 자체 구독자를 설치하지 않고 이 구독자를 공유합니다.
+/// 서버 실행 명령이면 `shared::logging`의 서비스별 파일(`LOG_DIR`, 기본 `./logs`)에도
+/// 기록하며, 로그 레코드는 대상 크레이트로 `ServiceType`이 판별됩니다.
+async fn init_logging(command: &str, json_output: bool) -> Option<LoggingSystem> {
+    use tracing_subscriber::fmt::writer::BoxMakeWriter;
+    use tracing_subscriber::prelude::*;
+
+    let logging_system = if matches!(command, "start" | "dev" | "server" | "grpc" | "tcp" | "rudp" | "udp") {
+        let log_dir = std::env::var("LOG_DIR").unwrap_or_else(|_| "./logs".to_string());
+        match LoggingSystem::new(&log_dir).await {
+            Ok(mut system) => match system.init_aggregated(ServiceType::GameCenter, &AGGREGATED_SERVICES).await {
+                Ok(()) => Some(system),
+                Err(e) => {
+                    eprintln!("서비스별 로그 파일 초기화 실패: {e}");
+                    None
+                }
+            },
+            Err(e) => {
+                eprintln!("로그 디렉토리 초기화 실패 ({log_dir}): {e}");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // JSON 출력 시 로그는 표준 에러로
+    let writer = if json_output {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
+    // 샘플링 규칙(관리 콘솔 TRACE)에 해당하는 세션은 필터 수준과 무관하게 기록
+    let filter = || {
+        PlayerSampler::new(
             tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"))
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
         )
+    };
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_writer(writer).with_filter(filter()))
+        .with(logging_system.as_ref().map(|system| system.tracing_layer().with_filter(filter())))
+        .with(crash::recent_logs_layer())
         .init();
-    
+
+    if logging_system.is_some() {
+        info!("📝 서비스별 로그 파일 기록 활성화");
+    }
+    logging_system
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
     // 명령행 인수 확인
     let args: Vec<String> = std::env::args().collect();
     let command = args.get(1).map(|s| s.as_str()).unwrap_or("start");
+    let json_output = args.iter().skip(2).any(|arg| arg == "--json");
+    
+    // 로깅 설정
+    let logging_system = init_logging(command, json_output).await;
+    crash::install(CrashConfig::from_env(
+        "gamecenter",
+        crash::build_id(env!("CARGO_PKG_VERSION"), option_env!("BUILD_HASH")),
+    ));
     
     let result = match command {
         "start" => {
             // 통합 서버 시작 모드
             run_gamecenter().await
         }
+        "dev" => {
+            // 개발용 샌드박스 모드
+            run_dev().await
+        }
         "stop" => {
             // 서버 중지 모드
             stop_gamecenter().await
         }
+        "test" if args.iter().skip(2).any(|arg| arg == "--e2e") => {
+            // E2E 스모크 테스트 모드
+            run_e2e(json_output).await
+        }
         "test" => {
             // 테스트 모드
             run_tests().await
@@ -363,27 +912,31 @@ async fn main() -> Result<()> {
         }
         "status" => {
             // 상태 확인 모드
-            info!("📊 게임센터 서버 상태 확인 중...");
-            let server = GameCenterServer::new();
-            server.print_status();
-            
-            info!("📊 Redis 서버 상태 확인 중...");
-            let redis_status = Command::new("redis-cli")
-                .arg("ping")
-                .output()
-                .await;
-            
-            match redis_status {
-                Ok(output) => {
-                    if output.status.success() {
-                        info!("✅ Redis 서버: 실행 중");
-                    } else {
-                        warn!("❌ Redis 서버: 응답 없음");
-                    }
-                }
-                Err(_) => warn!("❌ Redis 서버: 중지됨 또는 redis-cli 없음"),
-            }
-            Ok(())
+            run_status(json_output).await
+        }
+        "jobs" => {
+            // 정기 작업 상태 조회 모드
+            run_jobs_status(json_output).await
+        }
+        "chaos" => {
+            // 카오스 테스트 모드
+            run_chaos(json_output).await
+        }
+        "routes" => {
+            // 접속 라우팅 테이블 조회 모드
+            run_routes(json_output).await
+        }
+        "rollover" => {
+            // 블루/그린 교체 모드
+            run_rollover(args.get(2).map(|s| s.as_str())).await
+        }
+        "gdpr" => {
+            // 개인정보 내보내기/삭제 모드
+            run_gdpr(&args, json_output).await
+        }
+        "merge" => {
+            // 계정 병합 모드
+            run_merge(&args, json_output).await
         }
         "--help" | "-h" | "help" => {
             println!("🎮 Police Thief 통합 게임센터 서버");
@@ -392,13 +945,20 @@ async fn main() -> Result<()> {
             println!();
             println!("COMMANDS:");
             println!("  start     통합 게임센터 시작 (기본값) - 모든 서버 실행");
+            println!("  dev       외부 Redis/DB 없이 샌드박스로 실행 (인메모리 Redis, sqlite)");
             println!("  stop      게임센터 중지");
-            println!("  test      테스트 실행");
+            println!("  test      테스트 실행 (--e2e: 샌드박스에서 로그인→방 입장→이동 E2E 스모크 테스트, --json 지원)");
             println!("  server    백그라운드 서버 모드");
             println!("  grpc      gRPC 서버만 실행");
             println!("  tcp       TCP 서버만 실행");
             println!("  rudp      RUDP 서버만 실행");
-            println!("  status    서버 상태 확인");
+            println!("  status    서버 상태 확인 (--json: 대시보드/배포 스크립트용 JSON 출력)");
+            println!("  jobs      정기 작업 실행 결과 확인 (--json 지원)");
+            println!("  chaos     샌드박스에서 장애를 주입해 복원력 리포트 생성 (--json 지원)");
+            println!("  routes    로그인 응답에 쓰이는 접속 라우팅 테이블 확인 (--json 지원)");
+            println!("  rollover  서브서버 블루/그린 재시작 (예: rollover rudp)");
+            println!("  gdpr      개인정보 내보내기/삭제 (예: gdpr export 42, gdpr delete 42 --confirm)");
+            println!("  merge     계정 병합 사가 (예: merge 42 43 --confirm, 중단된 병합은 자동 재개)");
             println!("  help      이 도움말 표시");
             println!();
             println!("환경변수:");
@@ -412,11 +972,29 @@ async fn main() -> Result<()> {
             println!("  ENABLE_TCP=true        TCP 서버 활성화");
             println!("  ENABLE_RUDP=true       RUDP 서버 활성화");
             println!("  ENABLE_MONITORING=true 성능 모니터링 활성화");
+            println!("  ENABLE_JOB_SCHEDULER=true 정기 유지보수 작업 실행");
+            println!("  RUDP_ALT_PORT=5001     블루/그린 교체 시 대체 포트");
+            println!("  RUDP_DRAIN_TIMEOUT_SECS=300 기존 RUDP 인스턴스 드레인 제한 시간");
+            println!("  WEBHOOK_URL=...        생명주기 이벤트 웹훅 (Slack/Discord)");
+            println!("  WEBHOOK_MIN_SEVERITY=info 알림 최소 심각도 (info, warning, critical)");
+            println!("  DEV_REDIS_PORT=6380    샌드박스 Redis 에뮬레이터 포트");
+            println!("  DEV_DB_PATH=...        샌드박스 sqlite 파일 (기본값: 메모리)");
+            println!("  E2E_STEP_TIMEOUT_SECS=10 E2E 단계별 제한 시간");
+            println!("  E2E_ROOM_ID=1          E2E 테스트에서 입장할 방 ID");
+            println!("  LOG_DIR=./logs         서비스별 로그 파일 디렉토리");
+            println!("  GDPR_EXPORT_DIR=./data/gdpr 개인정보 보관 파일/삭제 리포트 디렉토리");
+            println!("  SAGA_DIR=./data/saga   계정 병합 등 사가 진행 기록 디렉토리");
+            println!("  SERVER_REGION=local    게임센터 배포 지역 (접속 정보 키에 사용)");
+            println!("  REGION_REPLICATION_HOST=... 지역 간 접속 정보 복제 Redis (미설정 시 복제 안 함)");
+            println!("  REGION_REPLICATION_CHANNEL=replication:presence 지역 간 복제 채널");
+            println!("  CHAOS_DURATION_SECS=120 카오스 테스트 실행 시간");
+            println!("  CHAOS_FAULTS=kill,redis_latency,packet_loss 주입할 장애 종류");
+            println!("  CHAOS_SEED=...         카오스 테스트 일정 시드 (재현용)");
             Ok(())
         }
         _ => {
             error!("알 수 없는 명령어: {}", command);
-            println!("사용 가능한 명령어: start, stop, test, server, grpc, tcp, rudp, status, help");
+            println!("사용 가능한 명령어: start, dev, stop, test, server, grpc, tcp, rudp, status, jobs, routes, chaos, rollover, gdpr, merge, help");
             println!("자세한 도움말: cargo run -p gamecenter help");
             std::process::exit(1);
         }
@@ -424,8 +1002,15 @@ async fn main() -> Result<()> {
     
     if let Err(e) = result {
         error!("실행 중 오류 발생: {}", e);
+        if let Some(logging_system) = logging_system {
+            let _ = logging_system.flush().await;
+        }
         std::process::exit(1);
     }
     
+    if let Some(logging_system) = logging_system {
+        logging_system.shutdown().await?;
+    }
+    
     Ok(())
 }
\ No newline at end of file
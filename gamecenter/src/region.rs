@@ -0,0 +1,140 @@
+//! 지역 간 접속 정보 복제
+//!
+//! 게임센터는 지역마다 하나씩 실행되며, 각 지역의 게임 서버는 지역 Redis에
+//! `presence:{region}:{user_id}` 키로 접속 정보를 기록합니다.
+//! 복제 Redis(`REGION_REPLICATION_HOST`)가 설정되어 있으면 이 지역의 접속 정보 변경을
+//! 복제 채널로 내보내고, 다른 지역이 보낸 기록을 로컬 Redis에 반영합니다.
+//! 친구 접속 상태는 로컬 Redis만 읽으므로 EU에 접속한 친구도 KR 사용자에게 "EU 온라인"으로 보입니다.
+//!
+//! 복제 채널 구독 전에 접속한 사용자는 다음 접속 변경 때 반영되며, 기록은 TTL로 만료됩니다.
+//!
+//! 환경변수:
+//! - `SERVER_REGION`: 이 게임센터의 지역 (없으면 TCP 서버의 `server_region`, 기본값: "local")
+//! - `REGION_REPLICATION_HOST`, `REGION_REPLICATION_PORT`: 지역 간 복제 Redis (미설정 시 복제 안 함)
+//! - `REGION_REPLICATION_CHANNEL`: 복제 채널 (기본값: `replication:presence`)
+
+use anyhow::{anyhow, Result};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use shared::config::redis_config::RedisConfig;
+use shared::service::redis::event_bus::EventBus;
+use shared::service::redis::region_presence::{
+    PresenceRecord, PresenceStore, DEFAULT_REPLICATION_CHANNEL, PRESENCE_CHANNEL,
+};
+
+/// 복제 연결이 끊겼을 때 재시도 간격
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// 지역 간 복제 대상
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplicationTarget {
+    pub host: String,
+    pub port: u16,
+    pub channel: String,
+}
+
+/// 지역 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegionConfig {
+    pub region: String,
+    pub replication: Option<ReplicationTarget>,
+}
+
+impl RegionConfig {
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let region = lookup("SERVER_REGION")
+            .or_else(|| lookup("server_region"))
+            .filter(|region| !region.is_empty())
+            .unwrap_or_else(|| "local".to_string());
+        let replication = lookup("REGION_REPLICATION_HOST")
+            .filter(|host| !host.is_empty())
+            .map(|host| ReplicationTarget {
+                host,
+                port: lookup("REGION_REPLICATION_PORT").and_then(|v| v.parse().ok()).unwrap_or(6379),
+                channel: lookup("REGION_REPLICATION_CHANNEL")
+                    .unwrap_or_else(|| DEFAULT_REPLICATION_CHANNEL.to_string()),
+            });
+        Self { region, replication }
+    }
+}
+
+/// 지역 간 접속 정보 복제 시작 (복제 Redis가 설정되지 않았으면 None)
+pub fn spawn_replication(local: RedisConfig, config: RegionConfig) -> Option<JoinHandle<()>> {
+    let target = config.replication?;
+    let region = config.region;
+    info!("🌏 지역 간 접속 정보 복제 시작: 지역 {}, {}:{} ({})", region, target.host, target.port, target.channel);
+
+    Some(tokio::spawn(async move {
+        loop {
+            if let Err(e) = replicate(&local, &region, &target).await {
+                warn!("지역 간 복제 중단, {}초 후 재시도: {:#}", RECONNECT_DELAY.as_secs(), e);
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }))
+}
+
+/// 이 지역의 변경 사항은 복제 채널로 내보내고, 다른 지역의 기록은 로컬에 반영
+async fn replicate(local: &RedisConfig, region: &str, target: &ReplicationTarget) -> Result<()> {
+    let remote = RedisConfig::connect(target.host.clone(), target.port).await
+        .map_err(|e| anyhow!("복제 Redis 연결 실패: {}", e))?;
+    let remote_bus = EventBus::new(remote);
+    let store = PresenceStore::new(local.clone(), region);
+
+    let mut outbound = EventBus::new(local.clone()).subscribe::<PresenceRecord>(PRESENCE_CHANNEL).await
+        .map_err(|e| anyhow!("로컬 접속 정보 채널 구독 실패: {}", e))?;
+    let mut inbound = remote_bus.subscribe::<PresenceRecord>(&target.channel).await
+        .map_err(|e| anyhow!("복제 채널 구독 실패: {}", e))?;
+
+    loop {
+        tokio::select! {
+            record = outbound.recv() => {
+                let record = record.ok_or_else(|| anyhow!("로컬 접속 정보 구독이 종료되었습니다"))?;
+                if record.region != region {
+                    continue;
+                }
+                remote_bus.publish(&target.channel, &record).await
+                    .map_err(|e| anyhow!("복제 채널 발행 실패: {}", e))?;
+            }
+            record = inbound.recv() => {
+                let record = record.ok_or_else(|| anyhow!("복제 채널 구독이 종료되었습니다"))?;
+                match store.apply_remote(&record).await {
+                    Ok(true) => debug!("접속 정보 복제: 사용자 {} ({}, online={})", record.user_id, record.region, record.online),
+                    Ok(false) => {}
+                    Err(e) => warn!("복제된 접속 정보 반영 실패: 사용자 {} - {}", record.user_id, e),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn config(vars: &[(&str, &str)]) -> RegionConfig {
+        let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        RegionConfig::from_lookup(|key| vars.get(key).cloned())
+    }
+
+    #[test]
+    fn test_region_config_from_env() {
+        assert_eq!(config(&[]), RegionConfig { region: "local".to_string(), replication: None });
+        assert_eq!(config(&[("server_region", "eu")]).region, "eu");
+        assert_eq!(config(&[("SERVER_REGION", "kr"), ("server_region", "eu")]).region, "kr");
+
+        let replicated = config(&[("SERVER_REGION", "kr"), ("REGION_REPLICATION_HOST", "global-redis")]);
+        assert_eq!(replicated.replication, Some(ReplicationTarget {
+            host: "global-redis".to_string(),
+            port: 6379,
+            channel: DEFAULT_REPLICATION_CHANNEL.to_string(),
+        }));
+    }
+}
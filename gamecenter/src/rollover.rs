@@ -0,0 +1,411 @@
+//! 서브서버 블루/그린 재시작
+//!
+//! RUDP 서버를 재시작해도 진행 중인 매치가 끊기지 않도록 다음 순서로 교체합니다.
+//!
+//! 1. 대체 포트에 새 인스턴스(반대 슬롯) 시작
+//! 2. Redis 라우팅 키(`routing:rudp:active`)를 새 인스턴스로 변경 - 새 매치는 새 인스턴스로
+//! 3. 기존 인스턴스의 피어가 모두 빠질 때까지(또는 제한 시간까지) 드레인
+//! 4. 기존 인스턴스 중지
+//!
+//! `gamecenter rollover rudp` 명령은 Redis 요청 큐(`gamecenter:rollover:requests`)에 요청을 넣고,
+//! 실행 중인 게임센터가 이를 처리하며 진행 상태를 `gamecenter:rollover:{target}:status`에 기록합니다.
+
+use anyhow::{anyhow, bail, Context, Result};
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use shared::config::redis_config::RedisConfig;
+use shared::service::redis::server_routing::{RoutingTarget, ServerRoutingStore};
+use crate::unified_server::UnifiedGameServer;
+use crate::webhook::{EventKind, LifecycleEvent, Severity};
+
+/// 교체 요청 큐 키
+pub const ROLLOVER_REQUEST_KEY: &str = "gamecenter:rollover:requests";
+
+/// 교체 진행 상태 키 접두사
+const ROLLOVER_STATUS_PREFIX: &str = "gamecenter:rollover";
+
+/// 진행 상태 보관 시간
+const STATUS_TTL_SECS: u64 = 3600;
+
+/// 요청 큐 확인 주기
+const REQUEST_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 드레인 상태 확인 주기
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 게임센터가 요청을 받아가기까지 기다리는 시간
+const PICKUP_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 블루/그린 교체 대상 (현재 RUDP만 지원)
+pub const SUPPORTED_TARGETS: [&str; 1] = ["rudp"];
+
+/// 배포 슬롯
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploySlot {
+    /// 기본 포트
+    Blue,
+    /// 대체 포트
+    Green,
+}
+
+impl DeploySlot {
+    pub fn other(&self) -> Self {
+        match self {
+            DeploySlot::Blue => DeploySlot::Green,
+            DeploySlot::Green => DeploySlot::Blue,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeploySlot::Blue => "blue",
+            DeploySlot::Green => "green",
+        }
+    }
+}
+
+impl fmt::Display for DeploySlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 블루/그린 교체 설정
+#[derive(Debug, Clone)]
+pub struct RolloverConfig {
+    /// 그린 슬롯 포트 (`RUDP_ALT_PORT`, 기본값: RUDP 포트 + 1)
+    pub alt_port: u16,
+    /// 클라이언트에게 알려줄 호스트 (`RUDP_PUBLIC_HOST`, 기본값: 바인드 주소)
+    pub public_host: String,
+    /// 드레인 제한 시간 (`RUDP_DRAIN_TIMEOUT_SECS`, 기본값: 300초)
+    pub drain_timeout: Duration,
+    /// 이 시간 동안 패킷이 없는 피어는 빠진 것으로 간주 (`RUDP_PEER_IDLE_SECS`, 기본값: 15초)
+    pub peer_idle: Duration,
+}
+
+impl RolloverConfig {
+    /// 환경변수에서 설정 로드
+    pub fn from_env(rudp_address: SocketAddr) -> Self {
+        let env_u64 = |key: &str, default: u64| {
+            std::env::var(key).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+        };
+
+        Self {
+            alt_port: std::env::var("RUDP_ALT_PORT").ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_else(|| rudp_address.port().wrapping_add(1)),
+            public_host: std::env::var("RUDP_PUBLIC_HOST").unwrap_or_else(|_| rudp_address.ip().to_string()),
+            drain_timeout: Duration::from_secs(env_u64("RUDP_DRAIN_TIMEOUT_SECS", 300)),
+            peer_idle: Duration::from_secs(env_u64("RUDP_PEER_IDLE_SECS", 15)),
+        }
+    }
+
+    /// 슬롯별 바인드 주소
+    pub fn address_for(&self, base: SocketAddr, slot: DeploySlot) -> SocketAddr {
+        match slot {
+            DeploySlot::Blue => base,
+            DeploySlot::Green => SocketAddr::new(base.ip(), self.alt_port),
+        }
+    }
+
+    /// 라우팅 키에 기록할 주소
+    pub fn public_address(&self, addr: SocketAddr) -> String {
+        format!("{}:{}", self.public_host, addr.port())
+    }
+}
+
+/// 교체 요청
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RolloverRequest {
+    pub id: String,
+    pub target: String,
+    pub requested_at: i64,
+}
+
+/// 교체 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RolloverPhase {
+    Starting,
+    Routing,
+    Draining,
+    Stopping,
+    Completed,
+    Failed,
+}
+
+impl RolloverPhase {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, RolloverPhase::Completed | RolloverPhase::Failed)
+    }
+}
+
+/// 교체 진행 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RolloverStatus {
+    pub request_id: String,
+    pub target: String,
+    pub phase: RolloverPhase,
+    pub from_slot: Option<DeploySlot>,
+    pub to_slot: Option<DeploySlot>,
+    pub from_address: Option<String>,
+    pub to_address: Option<String>,
+    /// 기존 인스턴스에 남은 피어 수
+    pub active_sessions: usize,
+    pub message: String,
+    pub updated_at: i64,
+}
+
+impl RolloverStatus {
+    fn new(request: &RolloverRequest) -> Self {
+        Self {
+            request_id: request.id.clone(),
+            target: request.target.clone(),
+            phase: RolloverPhase::Starting,
+            from_slot: None,
+            to_slot: None,
+            from_address: None,
+            to_address: None,
+            active_sessions: 0,
+            message: String::new(),
+            updated_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    fn key(target: &str) -> String {
+        format!("{ROLLOVER_STATUS_PREFIX}:{target}:status")
+    }
+}
+
+/// 진행 상태 기록기
+struct StatusReporter {
+    redis_config: RedisConfig,
+    status: RolloverStatus,
+}
+
+impl StatusReporter {
+    async fn update(&mut self, phase: RolloverPhase, message: impl Into<String>) {
+        self.status.phase = phase;
+        self.status.message = message.into();
+        self.status.updated_at = chrono::Utc::now().timestamp();
+        info!("🔁 [{}] {:?}: {}", self.status.target, phase, self.status.message);
+
+        let payload = match serde_json::to_string(&self.status) {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!("교체 상태 직렬화 실패: {}", e);
+                return;
+            }
+        };
+        let mut conn = self.redis_config.get_connection();
+        let result: redis::RedisResult<()> = conn.set_ex(RolloverStatus::key(&self.status.target), payload, STATUS_TTL_SECS).await;
+        if let Err(e) = result {
+            warn!("교체 상태 기록 실패: {}", e);
+        }
+    }
+}
+
+/// 현재 RUDP 인스턴스를 라우팅 대상으로 기록 (게임센터 시작 시)
+pub async fn publish_initial_route(server: &UnifiedGameServer, redis_config: &RedisConfig, config: &RolloverConfig) {
+    let Some((slot, address)) = server.active_rudp().await else {
+        return;
+    };
+    let target = RoutingTarget::new("rudp", slot.as_str(), config.public_address(address));
+    if let Err(e) = ServerRoutingStore::new(redis_config.clone()).set_active(&target).await {
+        warn!("RUDP 라우팅 정보 기록 실패: {}", e);
+    }
+}
+
+/// 교체 요청 처리기 시작
+pub fn spawn_listener(server: UnifiedGameServer, redis_config: RedisConfig, config: RolloverConfig) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REQUEST_POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            let mut conn = redis_config.get_connection();
+            let raw: Option<String> = match conn.lpop(ROLLOVER_REQUEST_KEY, None).await {
+                Ok(raw) => raw,
+                Err(e) => {
+                    warn!("교체 요청 조회 실패: {}", e);
+                    continue;
+                }
+            };
+            let Some(request) = raw.and_then(|raw| serde_json::from_str::<RolloverRequest>(&raw).ok()) else {
+                continue;
+            };
+
+            let mut reporter = StatusReporter {
+                redis_config: redis_config.clone(),
+                status: RolloverStatus::new(&request),
+            };
+            let result = match request.target.as_str() {
+                "rudp" => rollover_rudp(&server, &redis_config, &config, &mut reporter).await,
+                other => Err(anyhow!("지원하지 않는 교체 대상: {}", other)),
+            };
+            match result {
+                Ok(()) => server.notify(
+                    LifecycleEvent::new(EventKind::Restarted, Severity::Info, request.target.as_str(), "블루/그린 재시작 완료")
+                        .with_detail(reporter.status.message.clone()),
+                ),
+                Err(e) => {
+                    error!("❌ 블루/그린 교체 실패: {}", e);
+                    reporter.update(RolloverPhase::Failed, e.to_string()).await;
+                    server.notify(
+                        LifecycleEvent::new(EventKind::Restarted, Severity::Warning, request.target.as_str(), "블루/그린 재시작 실패")
+                            .with_detail(e.to_string()),
+                    );
+                }
+            }
+        }
+    })
+}
+
+/// RUDP 블루/그린 교체
+async fn rollover_rudp(
+    server: &UnifiedGameServer,
+    redis_config: &RedisConfig,
+    config: &RolloverConfig,
+    reporter: &mut StatusReporter,
+) -> Result<()> {
+    let (from_slot, from_address) = server.active_rudp().await
+        .ok_or_else(|| anyhow!("실행 중인 RUDP 서버가 없습니다"))?;
+    let to_slot = from_slot.other();
+    let to_address = config.address_for(server.config().rudp_address, to_slot);
+
+    reporter.status.from_slot = Some(from_slot);
+    reporter.status.to_slot = Some(to_slot);
+    reporter.status.from_address = Some(from_address.to_string());
+    reporter.status.to_address = Some(to_address.to_string());
+
+    // 1. 새 인스턴스 시작
+    reporter.update(RolloverPhase::Starting, format!("{} 인스턴스 시작 ({})", to_slot, to_address)).await;
+    server.launch_rudp(to_slot, to_address).await?;
+
+    // 2. 새 매치 라우팅 변경 (실패 시 새 인스턴스 정리)
+    reporter.update(RolloverPhase::Routing, "새 매치 라우팅 변경").await;
+    let target = RoutingTarget::new("rudp", to_slot.as_str(), config.public_address(to_address));
+    if let Err(e) = ServerRoutingStore::new(redis_config.clone()).set_active(&target).await {
+        server.stop_rudp(to_slot).await;
+        bail!("라우팅 변경 실패 - {} 인스턴스를 정리했습니다: {}", to_slot, e);
+    }
+
+    // 3. 기존 인스턴스 드레인
+    let deadline = tokio::time::Instant::now() + config.drain_timeout;
+    loop {
+        let active = server.rudp_active_peers(from_slot, config.peer_idle).await;
+        reporter.status.active_sessions = active;
+        if active == 0 {
+            reporter.update(RolloverPhase::Draining, "드레인 완료").await;
+            break;
+        }
+        if tokio::time::Instant::now() >= deadline {
+            reporter.update(
+                RolloverPhase::Draining,
+                format!("드레인 제한 시간 초과 - 남은 피어 {}개를 정리합니다", active),
+            ).await;
+            break;
+        }
+        reporter.update(RolloverPhase::Draining, format!("남은 피어 {}개", active)).await;
+        tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+    }
+
+    // 4. 기존 인스턴스 중지
+    reporter.update(RolloverPhase::Stopping, format!("{} 인스턴스 중지 ({})", from_slot, from_address)).await;
+    server.stop_rudp(from_slot).await;
+
+    reporter.status.active_sessions = 0;
+    reporter.update(RolloverPhase::Completed, format!("{} → {} 교체 완료", from_slot, to_slot)).await;
+    Ok(())
+}
+
+/// 교체 요청 후 완료까지 진행 상태 출력 (`gamecenter rollover <target>`)
+pub async fn request_rollover(redis_config: &RedisConfig, target: &str, timeout: Duration) -> Result<RolloverStatus> {
+    if !SUPPORTED_TARGETS.contains(&target) {
+        bail!("지원하지 않는 교체 대상: {} (지원: {})", target, SUPPORTED_TARGETS.join(", "));
+    }
+
+    let request = RolloverRequest {
+        id: format!("{}-{}", chrono::Utc::now().timestamp_millis(), std::process::id()),
+        target: target.to_string(),
+        requested_at: chrono::Utc::now().timestamp(),
+    };
+    let mut conn = redis_config.get_connection();
+    let _: () = conn.rpush(ROLLOVER_REQUEST_KEY, serde_json::to_string(&request)?).await
+        .context("교체 요청 등록 실패")?;
+    info!("🔁 {} 블루/그린 교체 요청 등록 (id={})", target, request.id);
+
+    let started = tokio::time::Instant::now();
+    let mut last_message = String::new();
+    loop {
+        tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+
+        let raw: Option<String> = conn.get(RolloverStatus::key(target)).await?;
+        let status = raw
+            .and_then(|raw| serde_json::from_str::<RolloverStatus>(&raw).ok())
+            .filter(|status| status.request_id == request.id);
+
+        match status {
+            Some(status) => {
+                if status.message != last_message {
+                    info!("🔁 {:?}: {}", status.phase, status.message);
+                    last_message = status.message.clone();
+                }
+                if status.phase.is_finished() {
+                    return Ok(status);
+                }
+            }
+            None if started.elapsed() > PICKUP_TIMEOUT => {
+                bail!("요청을 처리하는 게임센터가 없습니다 (gamecenter가 실행 중인지 확인하세요)");
+            }
+            None => {}
+        }
+
+        if started.elapsed() > timeout {
+            bail!("교체가 {}초 안에 끝나지 않았습니다 - gamecenter:rollover:{}:status를 확인하세요", timeout.as_secs(), target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slot_addresses() {
+        let base: SocketAddr = "0.0.0.0:5000".parse().unwrap();
+        let config = RolloverConfig {
+            alt_port: 5001,
+            public_host: "game.example.com".to_string(),
+            drain_timeout: Duration::from_secs(300),
+            peer_idle: Duration::from_secs(15),
+        };
+
+        assert_eq!(DeploySlot::Blue.other(), DeploySlot::Green);
+        assert_eq!(DeploySlot::Green.other(), DeploySlot::Blue);
+        assert_eq!(config.address_for(base, DeploySlot::Blue), base);
+        assert_eq!(config.address_for(base, DeploySlot::Green), "0.0.0.0:5001".parse().unwrap());
+        assert_eq!(config.public_address(config.address_for(base, DeploySlot::Green)), "game.example.com:5001");
+    }
+
+    #[test]
+    fn test_status_json_shape() {
+        let request = RolloverRequest { id: "1".into(), target: "rudp".into(), requested_at: 0 };
+        let mut status = RolloverStatus::new(&request);
+        status.phase = RolloverPhase::Draining;
+        status.from_slot = Some(DeploySlot::Blue);
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["phase"], "draining");
+        assert_eq!(json["from_slot"], "blue");
+        assert!(!status.phase.is_finished());
+        assert!(RolloverPhase::Failed.is_finished());
+    }
+}
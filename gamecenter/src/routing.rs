@@ -0,0 +1,68 @@
+//! 접속 라우팅 테이블 갱신
+//!
+//! 게임 서버 하트비트(`server_stats:*`)로 인스턴스별 부하를 계산해 접속 라우팅 테이블(`routing:table`)을
+//! 주기적으로 기록합니다. 블루/그린 교체 중인 프로토콜은 새 매치 대상 인스턴스만 남깁니다.
+//! gRPC 로그인 응답은 이 테이블에서 지역/프로토콜별로 가장 여유 있는 인스턴스와 입장 티켓을 안내합니다.
+//!
+//! 같은 하트비트로 만든 테이블이므로 게임센터 여러 대가 동시에 기록해도 락 없이 덮어씁니다.
+
+use anyhow::{anyhow, Result};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use shared::config::redis_config::RedisConfig;
+use shared::service::redis::server_routing::{RoutingTable, ServerRoutingStore, DEFAULT_TABLE_TTL_SECS};
+use shared::service::redis::server_stats::ServerStatsStore;
+
+/// 라우팅 테이블 갱신 주기 (하트비트 발행 주기보다 짧게)
+const ROUTING_REFRESH: Duration = Duration::from_secs(5);
+
+/// 현재 하트비트로 라우팅 테이블 생성
+pub async fn build_table(redis_config: &RedisConfig) -> Result<RoutingTable> {
+    let heartbeats = ServerStatsStore::new(redis_config.clone()).fetch_all(None).await
+        .map_err(|e| anyhow!("서버 하트비트 조회 실패: {}", e))?;
+    let mut table = RoutingTable::from_heartbeats(&heartbeats);
+
+    let routing = ServerRoutingStore::new(redis_config.clone());
+    let protocols: Vec<String> = table.protocols().into_iter().map(str::to_string).collect();
+    for protocol in protocols {
+        match routing.get_active(&protocol).await {
+            Ok(Some(target)) => table.retain_active(&target),
+            Ok(None) => {}
+            Err(e) => warn!("{} 라우팅 대상 조회 실패: {}", protocol, e),
+        }
+    }
+    Ok(table)
+}
+
+/// 라우팅 테이블을 주기적으로 갱신합니다.
+pub fn spawn_routing_refresh(redis_config: RedisConfig) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let routing = ServerRoutingStore::new(redis_config.clone());
+        let mut previous = None;
+        let mut interval = tokio::time::interval(ROUTING_REFRESH);
+        loop {
+            interval.tick().await;
+            let table = match build_table(&redis_config).await {
+                Ok(table) => table,
+                Err(e) => {
+                    warn!("라우팅 테이블 생성 실패: {:#}", e);
+                    continue;
+                }
+            };
+            if let Err(e) = routing.publish_table(&table, DEFAULT_TABLE_TTL_SECS).await {
+                warn!("라우팅 테이블 기록 실패: {}", e);
+                continue;
+            }
+
+            let count = table.endpoints.len();
+            if previous != Some(count) {
+                info!("🧭 접속 라우팅 테이블 갱신: 인스턴스 {}개 ({})", count, table.protocols().join(", "));
+                previous = Some(count);
+            } else {
+                debug!("접속 라우팅 테이블 갱신: 인스턴스 {}개", count);
+            }
+        }
+    })
+}
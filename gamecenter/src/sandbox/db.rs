@@ -0,0 +1,123 @@
+//! 샌드박스 데이터베이스
+//!
+//! MariaDB 대신 sqlite를 사용합니다. 스키마는 `players`, `game_sessions` 테이블을
+//! sqlite 문법으로 옮긴 것이며, 경로를 주지 않으면 메모리 DB를 사용합니다.
+
+use anyhow::{Context, Result};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use tracing::info;
+
+/// 샌드박스 스키마
+const SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS players (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        username TEXT UNIQUE NOT NULL,
+        password_hash TEXT NOT NULL,
+        email TEXT UNIQUE NOT NULL,
+        level INTEGER DEFAULT 1,
+        experience INTEGER DEFAULT 0,
+        gold INTEGER DEFAULT 100,
+        created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+        last_login TIMESTAMP
+    )",
+    "CREATE TABLE IF NOT EXISTS game_sessions (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        player_id INTEGER NOT NULL REFERENCES players(id),
+        server_instance TEXT,
+        start_time TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+        end_time TIMESTAMP NULL,
+        playtime_seconds INTEGER DEFAULT 0,
+        kills INTEGER DEFAULT 0,
+        deaths INTEGER DEFAULT 0,
+        damage_dealt INTEGER DEFAULT 0,
+        damage_received INTEGER DEFAULT 0
+    )",
+];
+
+/// 현재 프로세스의 샌드박스 DB (헬스 체크에서 사용)
+static ACTIVE: OnceLock<SandboxDatabase> = OnceLock::new();
+
+/// sqlite 기반 샌드박스 DB
+#[derive(Debug, Clone)]
+pub struct SandboxDatabase {
+    pub pool: SqlitePool,
+    /// 표시용 위치 (파일 경로 또는 `:memory:`)
+    pub location: String,
+}
+
+impl SandboxDatabase {
+    /// DB 열기 및 스키마 생성 (`path`가 None이면 메모리 DB)
+    pub async fn open(path: Option<&str>) -> Result<Self> {
+        let location = path.unwrap_or(":memory:").to_string();
+        let options = SqliteConnectOptions::from_str(&format!("sqlite:{location}"))?
+            .create_if_missing(true)
+            .foreign_keys(true);
+
+        // 메모리 DB는 연결마다 별개이므로 연결 하나만 유지
+        let pool = SqlitePoolOptions::new()
+            .max_connections(if path.is_some() { 5 } else { 1 })
+            .min_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await
+            .with_context(|| format!("샌드박스 DB({location}) 열기 실패"))?;
+
+        for statement in SCHEMA {
+            sqlx::query(statement).execute(&pool).await.context("샌드박스 스키마 생성 실패")?;
+        }
+
+        info!("🧪 샌드박스 DB 준비: {}", location);
+        Ok(Self { pool, location })
+    }
+
+    /// 연결 상태 확인
+    pub async fn health_check(&self) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT 1").fetch_one(&self.pool).await.map(|_| ())
+    }
+
+    /// 프로세스 전역 샌드박스 DB로 등록
+    pub fn install(self) -> &'static SandboxDatabase {
+        ACTIVE.get_or_init(|| self)
+    }
+
+    /// 등록된 샌드박스 DB (샌드박스 모드가 아니면 None)
+    pub fn active() -> Option<&'static SandboxDatabase> {
+        ACTIVE.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_schema_roundtrip() {
+        let db = SandboxDatabase::open(None).await.unwrap();
+        db.health_check().await.unwrap();
+
+        let player_id = sqlx::query("INSERT INTO players (username, password_hash, email) VALUES (?, ?, ?)")
+            .bind("thief")
+            .bind("hash")
+            .bind("thief@example.com")
+            .execute(&db.pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+        sqlx::query("INSERT INTO game_sessions (player_id, kills) VALUES (?, 3)")
+            .bind(player_id)
+            .execute(&db.pool)
+            .await
+            .unwrap();
+
+        let (gold, kills): (i64, i64) = sqlx::query_as(
+            "SELECT p.gold, s.kills FROM players p JOIN game_sessions s ON s.player_id = p.id",
+        )
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+        assert_eq!((gold, kills), (100, 3));
+    }
+}
@@ -0,0 +1,50 @@
+//! 개발용 샌드박스 모드 (`gamecenter dev`)
+//!
+//! 외부 Redis/DB 없이 게임 로직을 로컬에서 바로 실행할 수 있도록
+//! 프로세스 내 Redis 에뮬레이터와 sqlite DB를 띄우고, 모든 서버가 이를 사용하도록
+//! `redis_host`/`redis_port` 환경변수를 덮어씁니다.
+//!
+//! 환경변수:
+//! - `DEV_REDIS_PORT`: 에뮬레이터 포트 (기본값: 6380, 실제 Redis와 겹치지 않도록)
+//! - `DEV_DB_PATH`: sqlite 파일 경로 (없으면 메모리 DB)
+
+pub mod db;
+pub mod redis;
+
+use anyhow::Result;
+use std::net::{Ipv4Addr, SocketAddr};
+use tracing::info;
+
+use self::db::SandboxDatabase;
+use self::redis::FakeRedis;
+
+/// 기본 에뮬레이터 포트
+const DEFAULT_REDIS_PORT: u16 = 6380;
+
+/// 실행 중인 샌드박스 (drop 시 에뮬레이터 종료)
+pub struct Sandbox {
+    pub redis: FakeRedis,
+    pub db: &'static SandboxDatabase,
+}
+
+impl Sandbox {
+    /// 에뮬레이터와 DB 시작 후 Redis 접속 환경변수 설정
+    ///
+    /// 다른 구성요소가 Redis에 연결하기 전에 호출해야 합니다.
+    pub async fn start() -> Result<Self> {
+        let port = std::env::var("DEV_REDIS_PORT").ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_REDIS_PORT);
+        let redis = FakeRedis::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port))).await?;
+
+        let db_path = std::env::var("DEV_DB_PATH").ok().filter(|path| !path.trim().is_empty());
+        let db = SandboxDatabase::open(db_path.as_deref()).await?.install();
+
+        // dotenv는 기존 환경변수를 덮어쓰지 않으므로 .env 설정보다 우선
+        std::env::set_var("redis_host", redis.local_addr().ip().to_string());
+        std::env::set_var("redis_port", redis.local_addr().port().to_string());
+
+        info!("🧪 샌드박스 모드: Redis={} DB={}", redis.local_addr(), db.location);
+        Ok(Self { redis, db })
+    }
+}
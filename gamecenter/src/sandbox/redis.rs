@@ -0,0 +1,1335 @@
+//! 프로세스 내 Redis 에뮬레이터
+//!
+//! 게임 서버들이 사용하는 명령만 지원하는 작은 RESP 서버입니다.
+//! 로컬 TCP 포트에서 실제 Redis처럼 응답하므로, `redis_host`/`redis_port`만 바꾸면
+//! 기존 코드를 수정하지 않고 그대로 사용할 수 있습니다.
+//!
+//! 데이터는 메모리에만 있으며 프로세스가 끝나면 사라집니다.
+//! 만료 시간은 키에 접근할 때 확인합니다.
+//!
+//! Lua 인터프리터는 없으므로 `EVAL`/`EVALSHA`는 서버 코드에 내장된 스크립트만
+//! 같은 동작의 Rust 구현으로 실행합니다 (`builtin_script`). 그 밖의 스크립트는 오류를 반환합니다.
+
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// RESP 응답
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reply {
+    Simple(&'static str),
+    Error(String),
+    Int(i64),
+    Bulk(Option<Vec<u8>>),
+    Array(Vec<Reply>),
+}
+
+impl Reply {
+    fn ok() -> Self {
+        Reply::Simple("OK")
+    }
+
+    fn nil() -> Self {
+        Reply::Bulk(None)
+    }
+
+    fn bulk(value: impl Into<Vec<u8>>) -> Self {
+        Reply::Bulk(Some(value.into()))
+    }
+
+    fn err(message: impl Into<String>) -> Self {
+        Reply::Error(message.into())
+    }
+
+    fn wrong_type() -> Self {
+        Reply::err("WRONGTYPE Operation against a key holding the wrong kind of value")
+    }
+
+    fn syntax() -> Self {
+        Reply::err("ERR syntax error")
+    }
+
+    fn not_integer() -> Self {
+        Reply::err("ERR value is not an integer or out of range")
+    }
+
+    fn array(items: impl IntoIterator<Item = Vec<u8>>) -> Self {
+        Reply::Array(items.into_iter().map(Reply::bulk).collect())
+    }
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Reply::Simple(s) => out.extend_from_slice(format!("+{s}\r\n").as_bytes()),
+            Reply::Error(e) => out.extend_from_slice(format!("-{e}\r\n").as_bytes()),
+            Reply::Int(n) => out.extend_from_slice(format!(":{n}\r\n").as_bytes()),
+            Reply::Bulk(None) => out.extend_from_slice(b"$-1\r\n"),
+            Reply::Bulk(Some(data)) => {
+                out.extend_from_slice(format!("${}\r\n", data.len()).as_bytes());
+                out.extend_from_slice(data);
+                out.extend_from_slice(b"\r\n");
+            }
+            Reply::Array(items) => {
+                out.extend_from_slice(format!("*{}\r\n", items.len()).as_bytes());
+                for item in items {
+                    item.encode(out);
+                }
+            }
+        }
+    }
+}
+
+/// 저장 값
+#[derive(Debug, Clone)]
+enum Value {
+    Str(Vec<u8>),
+    Hash(HashMap<Vec<u8>, Vec<u8>>),
+    List(VecDeque<Vec<u8>>),
+    Set(HashSet<Vec<u8>>),
+    ZSet(HashMap<Vec<u8>, f64>),
+}
+
+impl Value {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Str(_) => "string",
+            Value::Hash(_) => "hash",
+            Value::List(_) => "list",
+            Value::Set(_) => "set",
+            Value::ZSet(_) => "zset",
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Value::Str(_) => false,
+            Value::Hash(h) => h.is_empty(),
+            Value::List(l) => l.is_empty(),
+            Value::Set(s) => s.is_empty(),
+            Value::ZSet(z) => z.is_empty(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    expires_at: Option<Instant>,
+}
+
+/// 키 공간
+#[derive(Default)]
+pub struct Store {
+    data: HashMap<Vec<u8>, Entry>,
+}
+
+/// 값 타입별 접근 매크로 (없으면 기본값 생성, 타입이 다르면 WRONGTYPE)
+macro_rules! typed_mut {
+    ($store:expr, $key:expr, $variant:ident, $default:expr) => {{
+        let entry = $store.data.entry($key.to_vec()).or_insert_with(|| Entry {
+            value: Value::$variant($default),
+            expires_at: None,
+        });
+        match &mut entry.value {
+            Value::$variant(inner) => inner,
+            _ => return Reply::wrong_type(),
+        }
+    }};
+}
+
+macro_rules! typed_ref {
+    ($store:expr, $key:expr, $variant:ident) => {{
+        match $store.get($key) {
+            None => None,
+            Some(Value::$variant(inner)) => Some(inner),
+            Some(_) => return Reply::wrong_type(),
+        }
+    }};
+}
+
+impl Store {
+    /// 만료된 키 제거 후 조회
+    fn get(&mut self, key: &[u8]) -> Option<&Value> {
+        self.purge_if_expired(key);
+        self.data.get(key).map(|entry| &entry.value)
+    }
+
+    fn purge_if_expired(&mut self, key: &[u8]) {
+        let expired = self.data.get(key)
+            .and_then(|entry| entry.expires_at)
+            .is_some_and(|at| at <= Instant::now());
+        if expired {
+            self.data.remove(key);
+        }
+    }
+
+    /// 빈 컬렉션 키 제거 (Redis는 빈 컬렉션을 남기지 않음)
+    fn drop_if_empty(&mut self, key: &[u8]) {
+        if self.data.get(key).is_some_and(|entry| entry.value.is_empty()) {
+            self.data.remove(key);
+        }
+    }
+
+    fn live_keys(&mut self) -> Vec<Vec<u8>> {
+        let now = Instant::now();
+        self.data.retain(|_, entry| entry.expires_at.is_none_or(|at| at > now));
+        self.data.keys().cloned().collect()
+    }
+
+    /// 명령 실행 (연결 상태와 무관한 명령)
+    pub fn execute(&mut self, args: &[Vec<u8>]) -> Reply {
+        let Some(name) = args.first() else {
+            return Reply::err("ERR empty command");
+        };
+        let name = String::from_utf8_lossy(name).to_ascii_uppercase();
+        let args = &args[1..];
+
+        macro_rules! arity {
+            ($min:expr) => {
+                if args.len() < $min {
+                    return Reply::err(format!("ERR wrong number of arguments for '{}' command", name.to_lowercase()));
+                }
+            };
+        }
+
+        match name.as_str() {
+            // 키
+            "DEL" | "UNLINK" => {
+                arity!(1);
+                let removed = args.iter().filter(|key| {
+                    self.purge_if_expired(key);
+                    self.data.remove(key.as_slice()).is_some()
+                }).count();
+                Reply::Int(removed as i64)
+            }
+            "EXISTS" => {
+                arity!(1);
+                Reply::Int(args.iter().filter(|key| self.get(key).is_some()).count() as i64)
+            }
+            "TYPE" => {
+                arity!(1);
+                Reply::Simple(self.get(&args[0]).map(Value::type_name).unwrap_or("none"))
+            }
+            "EXPIRE" | "PEXPIRE" => {
+                arity!(2);
+                let Some(amount) = parse_i64(&args[1]) else { return Reply::not_integer() };
+                let ttl = if name == "EXPIRE" { amount.saturating_mul(1000) } else { amount };
+                self.purge_if_expired(&args[0]);
+                match self.data.get_mut(args[0].as_slice()) {
+                    None => Reply::Int(0),
+                    Some(_) if ttl <= 0 => {
+                        self.data.remove(args[0].as_slice());
+                        Reply::Int(1)
+                    }
+                    Some(entry) => {
+                        entry.expires_at = Some(Instant::now() + Duration::from_millis(ttl as u64));
+                        Reply::Int(1)
+                    }
+                }
+            }
+            "PERSIST" => {
+                arity!(1);
+                self.purge_if_expired(&args[0]);
+                match self.data.get_mut(args[0].as_slice()) {
+                    Some(entry) if entry.expires_at.is_some() => {
+                        entry.expires_at = None;
+                        Reply::Int(1)
+                    }
+                    _ => Reply::Int(0),
+                }
+            }
+            "TTL" | "PTTL" => {
+                arity!(1);
+                self.purge_if_expired(&args[0]);
+                match self.data.get(args[0].as_slice()) {
+                    None => Reply::Int(-2),
+                    Some(Entry { expires_at: None, .. }) => Reply::Int(-1),
+                    Some(Entry { expires_at: Some(at), .. }) => {
+                        let ms = at.saturating_duration_since(Instant::now()).as_millis() as i64;
+                        Reply::Int(if name == "TTL" { (ms + 500) / 1000 } else { ms })
+                    }
+                }
+            }
+            "KEYS" => {
+                arity!(1);
+                let pattern = args[0].clone();
+                Reply::array(self.live_keys().into_iter().filter(|key| glob_match(&pattern, key)))
+            }
+            "SCAN" => {
+                arity!(1);
+                let mut pattern = b"*".to_vec();
+                let mut options = args[1..].chunks(2);
+                while let Some([option, value]) = options.next() {
+                    if option.eq_ignore_ascii_case(b"MATCH") {
+                        pattern = value.clone();
+                    }
+                }
+                // 커서 0으로 한 번에 모두 반환
+                let keys = self.live_keys().into_iter().filter(|key| glob_match(&pattern, key));
+                Reply::Array(vec![Reply::bulk("0"), Reply::array(keys)])
+            }
+            "RENAME" => {
+                arity!(2);
+                self.purge_if_expired(&args[0]);
+                match self.data.remove(args[0].as_slice()) {
+                    None => Reply::err("ERR no such key"),
+                    Some(entry) => {
+                        self.data.insert(args[1].clone(), entry);
+                        Reply::ok()
+                    }
+                }
+            }
+            "DBSIZE" => Reply::Int(self.live_keys().len() as i64),
+            "FLUSHDB" | "FLUSHALL" => {
+                self.data.clear();
+                Reply::ok()
+            }
+
+            // 문자열
+            "GET" => {
+                arity!(1);
+                match typed_ref!(self, &args[0], Str) {
+                    Some(value) => Reply::bulk(value.clone()),
+                    None => Reply::nil(),
+                }
+            }
+            "GETDEL" => {
+                arity!(1);
+                let reply = match typed_ref!(self, &args[0], Str) {
+                    Some(value) => Reply::bulk(value.clone()),
+                    None => return Reply::nil(),
+                };
+                self.data.remove(args[0].as_slice());
+                reply
+            }
+            "MGET" => {
+                arity!(1);
+                let values = args.iter().map(|key| match self.get(key) {
+                    Some(Value::Str(value)) => Reply::bulk(value.clone()),
+                    _ => Reply::nil(),
+                }).collect();
+                Reply::Array(values)
+            }
+            "SET" => {
+                arity!(2);
+                let mut ttl_ms = None;
+                let (mut nx, mut xx, mut keep_ttl) = (false, false, false);
+                let mut i = 2;
+                while i < args.len() {
+                    let option = String::from_utf8_lossy(&args[i]).to_ascii_uppercase();
+                    match option.as_str() {
+                        "NX" => nx = true,
+                        "XX" => xx = true,
+                        "KEEPTTL" => keep_ttl = true,
+                        "EX" | "PX" => {
+                            let Some(amount) = args.get(i + 1).and_then(|v| parse_i64(v)) else {
+                                return Reply::syntax();
+                            };
+                            ttl_ms = Some(if option == "EX" { amount * 1000 } else { amount });
+                            i += 1;
+                        }
+                        _ => return Reply::syntax(),
+                    }
+                    i += 1;
+                }
+                let exists = self.get(&args[0]).is_some();
+                if (nx && exists) || (xx && !exists) {
+                    return Reply::nil();
+                }
+                let previous_ttl = self.data.get(args[0].as_slice()).and_then(|entry| entry.expires_at);
+                self.data.insert(args[0].clone(), Entry {
+                    value: Value::Str(args[1].clone()),
+                    expires_at: match ttl_ms {
+                        Some(ms) => Some(Instant::now() + Duration::from_millis(ms.max(0) as u64)),
+                        None if keep_ttl => previous_ttl,
+                        None => None,
+                    },
+                });
+                Reply::ok()
+            }
+            "SETEX" | "PSETEX" => {
+                arity!(3);
+                let Some(amount) = parse_i64(&args[1]) else { return Reply::not_integer() };
+                let ms = if name == "SETEX" { amount * 1000 } else { amount };
+                self.data.insert(args[0].clone(), Entry {
+                    value: Value::Str(args[2].clone()),
+                    expires_at: Some(Instant::now() + Duration::from_millis(ms.max(0) as u64)),
+                });
+                Reply::ok()
+            }
+            "SETNX" => {
+                arity!(2);
+                if self.get(&args[0]).is_some() {
+                    return Reply::Int(0);
+                }
+                self.data.insert(args[0].clone(), Entry { value: Value::Str(args[1].clone()), expires_at: None });
+                Reply::Int(1)
+            }
+            "MSET" => {
+                if args.is_empty() || args.len() % 2 != 0 {
+                    return Reply::syntax();
+                }
+                for pair in args.chunks(2) {
+                    self.data.insert(pair[0].clone(), Entry { value: Value::Str(pair[1].clone()), expires_at: None });
+                }
+                Reply::ok()
+            }
+            "INCR" | "DECR" | "INCRBY" | "DECRBY" => {
+                arity!(if name.ends_with("BY") { 2 } else { 1 });
+                let delta = match name.as_str() {
+                    "INCR" => 1,
+                    "DECR" => -1,
+                    _ => match parse_i64(&args[1]) {
+                        Some(n) if name == "INCRBY" => n,
+                        Some(n) => -n,
+                        None => return Reply::not_integer(),
+                    },
+                };
+                self.purge_if_expired(&args[0]);
+                let value = typed_mut!(self, &args[0], Str, b"0".to_vec());
+                let Some(current) = parse_i64(value) else { return Reply::not_integer() };
+                let next = current + delta;
+                *value = next.to_string().into_bytes();
+                Reply::Int(next)
+            }
+
+            // 해시
+            "HSET" | "HMSET" => {
+                if args.len() < 3 || args.len() % 2 != 1 {
+                    return Reply::err(format!("ERR wrong number of arguments for '{}' command", name.to_lowercase()));
+                }
+                self.purge_if_expired(&args[0]);
+                let hash = typed_mut!(self, &args[0], Hash, HashMap::new());
+                let added = args[1..].chunks(2)
+                    .filter(|pair| hash.insert(pair[0].clone(), pair[1].clone()).is_none())
+                    .count();
+                if name == "HMSET" { Reply::ok() } else { Reply::Int(added as i64) }
+            }
+            "HGET" => {
+                arity!(2);
+                match typed_ref!(self, &args[0], Hash).and_then(|hash| hash.get(&args[1])) {
+                    Some(value) => Reply::bulk(value.clone()),
+                    None => Reply::nil(),
+                }
+            }
+            "HMGET" => {
+                arity!(2);
+                let hash = typed_ref!(self, &args[0], Hash);
+                Reply::Array(args[1..].iter()
+                    .map(|field| hash.and_then(|hash| hash.get(field)).cloned().map_or(Reply::nil(), Reply::bulk))
+                    .collect())
+            }
+            "HGETALL" => {
+                arity!(1);
+                let hash = typed_ref!(self, &args[0], Hash);
+                Reply::array(hash.into_iter().flatten().flat_map(|(k, v)| [k.clone(), v.clone()]))
+            }
+            "HKEYS" | "HVALS" | "HLEN" => {
+                arity!(1);
+                let hash = typed_ref!(self, &args[0], Hash);
+                match name.as_str() {
+                    "HKEYS" => Reply::array(hash.into_iter().flat_map(|h| h.keys().cloned())),
+                    "HVALS" => Reply::array(hash.into_iter().flat_map(|h| h.values().cloned())),
+                    _ => Reply::Int(hash.map_or(0, |h| h.len()) as i64),
+                }
+            }
+            "HEXISTS" => {
+                arity!(2);
+                Reply::Int(typed_ref!(self, &args[0], Hash).is_some_and(|hash| hash.contains_key(&args[1])) as i64)
+            }
+            "HDEL" => {
+                arity!(2);
+                self.purge_if_expired(&args[0]);
+                let removed = match self.data.get_mut(args[0].as_slice()).map(|entry| &mut entry.value) {
+                    None => 0,
+                    Some(Value::Hash(hash)) => args[1..].iter().filter(|field| hash.remove(*field).is_some()).count(),
+                    Some(_) => return Reply::wrong_type(),
+                };
+                self.drop_if_empty(&args[0]);
+                Reply::Int(removed as i64)
+            }
+            "HINCRBY" => {
+                arity!(3);
+                let Some(delta) = parse_i64(&args[2]) else { return Reply::not_integer() };
+                self.purge_if_expired(&args[0]);
+                let hash = typed_mut!(self, &args[0], Hash, HashMap::new());
+                let field = hash.entry(args[1].clone()).or_insert_with(|| b"0".to_vec());
+                let Some(current) = parse_i64(field) else { return Reply::not_integer() };
+                *field = (current + delta).to_string().into_bytes();
+                Reply::Int(current + delta)
+            }
+
+            // 리스트
+            "LPUSH" | "RPUSH" => {
+                arity!(2);
+                self.purge_if_expired(&args[0]);
+                let list = typed_mut!(self, &args[0], List, VecDeque::new());
+                for value in &args[1..] {
+                    if name == "LPUSH" { list.push_front(value.clone()) } else { list.push_back(value.clone()) }
+                }
+                Reply::Int(list.len() as i64)
+            }
+            "LPOP" | "RPOP" => {
+                arity!(1);
+                let count = match args.get(1).map(|v| parse_i64(v)) {
+                    None => None,
+                    Some(Some(n)) if n >= 0 => Some(n as usize),
+                    Some(_) => return Reply::not_integer(),
+                };
+                self.purge_if_expired(&args[0]);
+                let popped: Vec<Vec<u8>> = match self.data.get_mut(args[0].as_slice()).map(|entry| &mut entry.value) {
+                    None => return if count.is_some() { Reply::Bulk(None) } else { Reply::nil() },
+                    Some(Value::List(list)) => (0..count.unwrap_or(1))
+                        .map_while(|_| if name == "LPOP" { list.pop_front() } else { list.pop_back() })
+                        .collect(),
+                    Some(_) => return Reply::wrong_type(),
+                };
+                self.drop_if_empty(&args[0]);
+                match count {
+                    Some(_) => Reply::array(popped),
+                    None => popped.into_iter().next().map_or(Reply::nil(), Reply::bulk),
+                }
+            }
+            "LLEN" => {
+                arity!(1);
+                Reply::Int(typed_ref!(self, &args[0], List).map_or(0, |list| list.len()) as i64)
+            }
+            "LRANGE" => {
+                arity!(3);
+                let (Some(start), Some(stop)) = (parse_i64(&args[1]), parse_i64(&args[2])) else {
+                    return Reply::not_integer();
+                };
+                let Some(list) = typed_ref!(self, &args[0], List) else { return Reply::Array(Vec::new()) };
+                let (start, end) = range_bounds(start, stop, list.len());
+                Reply::array(list.iter().skip(start).take(end.saturating_sub(start)).cloned())
+            }
+            "LTRIM" => {
+                arity!(3);
+                let (Some(start), Some(stop)) = (parse_i64(&args[1]), parse_i64(&args[2])) else {
+                    return Reply::not_integer();
+                };
+                self.purge_if_expired(&args[0]);
+                match self.data.get_mut(args[0].as_slice()).map(|entry| &mut entry.value) {
+                    None => {}
+                    Some(Value::List(list)) => {
+                        let (start, end) = range_bounds(start, stop, list.len());
+                        *list = list.iter().skip(start).take(end.saturating_sub(start)).cloned().collect();
+                    }
+                    Some(_) => return Reply::wrong_type(),
+                }
+                self.drop_if_empty(&args[0]);
+                Reply::ok()
+            }
+            "LREM" => {
+                arity!(3);
+                let Some(count) = parse_i64(&args[1]) else { return Reply::not_integer() };
+                self.purge_if_expired(&args[0]);
+                let removed = match self.data.get_mut(args[0].as_slice()).map(|entry| &mut entry.value) {
+                    None => 0,
+                    Some(Value::List(list)) => {
+                        let limit = if count == 0 { usize::MAX } else { count.unsigned_abs() as usize };
+                        let mut removed = 0;
+                        let mut kept: Vec<Vec<u8>> = Vec::with_capacity(list.len());
+                        let items: Vec<Vec<u8>> = if count < 0 { list.iter().rev().cloned().collect() } else { list.iter().cloned().collect() };
+                        for item in items {
+                            if removed < limit && item == args[2] {
+                                removed += 1;
+                            } else {
+                                kept.push(item);
+                            }
+                        }
+                        if count < 0 {
+                            kept.reverse();
+                        }
+                        *list = kept.into();
+                        removed
+                    }
+                    Some(_) => return Reply::wrong_type(),
+                };
+                self.drop_if_empty(&args[0]);
+                Reply::Int(removed as i64)
+            }
+
+            // 셋
+            "SADD" => {
+                arity!(2);
+                self.purge_if_expired(&args[0]);
+                let set = typed_mut!(self, &args[0], Set, HashSet::new());
+                Reply::Int(args[1..].iter().filter(|member| set.insert((*member).clone())).count() as i64)
+            }
+            "SREM" => {
+                arity!(2);
+                self.purge_if_expired(&args[0]);
+                let removed = match self.data.get_mut(args[0].as_slice()).map(|entry| &mut entry.value) {
+                    None => 0,
+                    Some(Value::Set(set)) => args[1..].iter().filter(|member| set.remove(*member)).count(),
+                    Some(_) => return Reply::wrong_type(),
+                };
+                self.drop_if_empty(&args[0]);
+                Reply::Int(removed as i64)
+            }
+            "SMEMBERS" => {
+                arity!(1);
+                Reply::array(typed_ref!(self, &args[0], Set).into_iter().flatten().cloned())
+            }
+            "SISMEMBER" => {
+                arity!(2);
+                Reply::Int(typed_ref!(self, &args[0], Set).is_some_and(|set| set.contains(&args[1])) as i64)
+            }
+            "SCARD" => {
+                arity!(1);
+                Reply::Int(typed_ref!(self, &args[0], Set).map_or(0, |set| set.len()) as i64)
+            }
+            "SINTER" => {
+                arity!(1);
+                let mut result: Option<HashSet<Vec<u8>>> = None;
+                for key in args {
+                    let set = typed_ref!(self, key, Set).cloned().unwrap_or_default();
+                    result = Some(match result {
+                        None => set,
+                        Some(acc) => acc.intersection(&set).cloned().collect(),
+                    });
+                }
+                Reply::array(result.unwrap_or_default())
+            }
+
+            // 정렬 셋
+            "ZADD" => {
+                arity!(3);
+                let mut i = 1;
+                let (mut nx, mut xx, mut ch) = (false, false, false);
+                while let Some(flag) = args.get(i) {
+                    match String::from_utf8_lossy(flag).to_ascii_uppercase().as_str() {
+                        "NX" => nx = true,
+                        "XX" => xx = true,
+                        "CH" => ch = true,
+                        _ => break,
+                    }
+                    i += 1;
+                }
+                let pairs = &args[i..];
+                if pairs.is_empty() || pairs.len() % 2 != 0 {
+                    return Reply::syntax();
+                }
+                let mut parsed = Vec::with_capacity(pairs.len() / 2);
+                for pair in pairs.chunks(2) {
+                    let Some(score) = parse_score(&pair[0]) else { return Reply::err("ERR value is not a valid float") };
+                    parsed.push((score, pair[1].clone()));
+                }
+                self.purge_if_expired(&args[0]);
+                let zset = typed_mut!(self, &args[0], ZSet, HashMap::new());
+                let mut changed = 0;
+                let mut added = 0;
+                for (score, member) in parsed {
+                    match zset.get(&member) {
+                        Some(_) if nx => {}
+                        None if xx => {}
+                        Some(existing) => {
+                            if *existing != score {
+                                changed += 1;
+                            }
+                            zset.insert(member, score);
+                        }
+                        None => {
+                            added += 1;
+                            zset.insert(member, score);
+                        }
+                    }
+                }
+                self.drop_if_empty(&args[0]);
+                Reply::Int(if ch { added + changed } else { added })
+            }
+            "ZINCRBY" => {
+                arity!(3);
+                let Some(delta) = parse_score(&args[1]) else { return Reply::err("ERR value is not a valid float") };
+                self.purge_if_expired(&args[0]);
+                let zset = typed_mut!(self, &args[0], ZSet, HashMap::new());
+                let score = zset.entry(args[2].clone()).or_insert(0.0);
+                *score += delta;
+                Reply::bulk(format_score(*score))
+            }
+            "ZREM" => {
+                arity!(2);
+                self.purge_if_expired(&args[0]);
+                let removed = match self.data.get_mut(args[0].as_slice()).map(|entry| &mut entry.value) {
+                    None => 0,
+                    Some(Value::ZSet(zset)) => args[1..].iter().filter(|member| zset.remove(*member).is_some()).count(),
+                    Some(_) => return Reply::wrong_type(),
+                };
+                self.drop_if_empty(&args[0]);
+                Reply::Int(removed as i64)
+            }
+            "ZSCORE" => {
+                arity!(2);
+                match typed_ref!(self, &args[0], ZSet).and_then(|zset| zset.get(&args[1])) {
+                    Some(score) => Reply::bulk(format_score(*score)),
+                    None => Reply::nil(),
+                }
+            }
+            "ZCARD" => {
+                arity!(1);
+                Reply::Int(typed_ref!(self, &args[0], ZSet).map_or(0, |zset| zset.len()) as i64)
+            }
+            "ZCOUNT" => {
+                arity!(3);
+                let (Some(min), Some(max)) = (ScoreBound::parse(&args[1]), ScoreBound::parse(&args[2])) else {
+                    return Reply::err("ERR min or max is not a float");
+                };
+                let count = typed_ref!(self, &args[0], ZSet).map_or(0, |zset| {
+                    zset.values().filter(|score| min.below(**score) && max.above(**score)).count()
+                });
+                Reply::Int(count as i64)
+            }
+            "ZRANGE" | "ZREVRANGE" => {
+                arity!(3);
+                let (Some(start), Some(stop)) = (parse_i64(&args[1]), parse_i64(&args[2])) else {
+                    return Reply::not_integer();
+                };
+                let with_scores = args.get(3).is_some_and(|v| v.eq_ignore_ascii_case(b"WITHSCORES"));
+                let Some(zset) = typed_ref!(self, &args[0], ZSet) else { return Reply::Array(Vec::new()) };
+                let mut sorted = sorted_zset(zset);
+                if name == "ZREVRANGE" {
+                    sorted.reverse();
+                }
+                let (start, end) = range_bounds(start, stop, sorted.len());
+                zset_reply(sorted.into_iter().skip(start).take(end.saturating_sub(start)), with_scores)
+            }
+            "ZRANGEBYSCORE" | "ZREVRANGEBYSCORE" => {
+                arity!(3);
+                let reverse = name == "ZREVRANGEBYSCORE";
+                // ZREVRANGEBYSCORE는 max, min 순서
+                let (min_arg, max_arg) = if reverse { (&args[2], &args[1]) } else { (&args[1], &args[2]) };
+                let (Some(min), Some(max)) = (ScoreBound::parse(min_arg), ScoreBound::parse(max_arg)) else {
+                    return Reply::err("ERR min or max is not a float");
+                };
+                let mut with_scores = false;
+                let mut limit: Option<(usize, Option<usize>)> = None;
+                let mut i = 3;
+                while let Some(option) = args.get(i) {
+                    if option.eq_ignore_ascii_case(b"WITHSCORES") {
+                        with_scores = true;
+                    } else if option.eq_ignore_ascii_case(b"LIMIT") {
+                        let (Some(offset), Some(count)) = (
+                            args.get(i + 1).and_then(|v| parse_i64(v)),
+                            args.get(i + 2).and_then(|v| parse_i64(v)),
+                        ) else {
+                            return Reply::syntax();
+                        };
+                        limit = Some((offset.max(0) as usize, (count >= 0).then_some(count as usize)));
+                        i += 2;
+                    } else {
+                        return Reply::syntax();
+                    }
+                    i += 1;
+                }
+                let Some(zset) = typed_ref!(self, &args[0], ZSet) else { return Reply::Array(Vec::new()) };
+                let mut sorted: Vec<(Vec<u8>, f64)> = sorted_zset(zset).into_iter()
+                    .filter(|(_, score)| min.below(*score) && max.above(*score))
+                    .collect();
+                if reverse {
+                    sorted.reverse();
+                }
+                let (offset, count) = limit.unwrap_or((0, None));
+                zset_reply(sorted.into_iter().skip(offset).take(count.unwrap_or(usize::MAX)), with_scores)
+            }
+            "ZREMRANGEBYSCORE" => {
+                arity!(3);
+                let (Some(min), Some(max)) = (ScoreBound::parse(&args[1]), ScoreBound::parse(&args[2])) else {
+                    return Reply::err("ERR min or max is not a float");
+                };
+                self.purge_if_expired(&args[0]);
+                let removed = match self.data.get_mut(args[0].as_slice()).map(|entry| &mut entry.value) {
+                    None => 0,
+                    Some(Value::ZSet(zset)) => {
+                        let before = zset.len();
+                        zset.retain(|_, score| !(min.below(*score) && max.above(*score)));
+                        before - zset.len()
+                    }
+                    Some(_) => return Reply::wrong_type(),
+                };
+                self.drop_if_empty(&args[0]);
+                Reply::Int(removed as i64)
+            }
+            "ZREMRANGEBYRANK" => {
+                arity!(3);
+                let (Some(start), Some(stop)) = (parse_i64(&args[1]), parse_i64(&args[2])) else {
+                    return Reply::not_integer();
+                };
+                self.purge_if_expired(&args[0]);
+                let removed = match self.data.get_mut(args[0].as_slice()).map(|entry| &mut entry.value) {
+                    None => 0,
+                    Some(Value::ZSet(zset)) => {
+                        let sorted = sorted_zset(zset);
+                        let (start, end) = range_bounds(start, stop, sorted.len());
+                        for (member, _) in &sorted[start..end] {
+                            zset.remove(member);
+                        }
+                        end - start
+                    }
+                    Some(_) => return Reply::wrong_type(),
+                };
+                self.drop_if_empty(&args[0]);
+                Reply::Int(removed as i64)
+            }
+
+            // 스크립트 (내장 스크립트만)
+            "EVAL" | "EVALSHA" => {
+                arity!(2);
+                let sha = if name == "EVAL" {
+                    redis::Script::new(&String::from_utf8_lossy(&args[0])).get_hash().to_string()
+                } else {
+                    String::from_utf8_lossy(&args[0]).to_ascii_lowercase()
+                };
+                let Some(numkeys) = parse_i64(&args[1]).and_then(|n| usize::try_from(n).ok()) else {
+                    return Reply::not_integer();
+                };
+                if numkeys > args.len() - 2 {
+                    return Reply::err("ERR Number of keys can't be greater than number of args");
+                }
+                let (keys, argv) = args[2..].split_at(numkeys);
+                match builtin_script(&sha) {
+                    Some(script) => script(self, keys, argv),
+                    None if name == "EVALSHA" => Reply::err("NOSCRIPT No matching script. Please use EVAL."),
+                    None => Reply::err("ERR only built-in server scripts are supported by the dev sandbox"),
+                }
+            }
+            "SCRIPT" => {
+                arity!(1);
+                match String::from_utf8_lossy(&args[0]).to_ascii_uppercase().as_str() {
+                    "LOAD" => {
+                        arity!(2);
+                        let sha = redis::Script::new(&String::from_utf8_lossy(&args[1])).get_hash().to_string();
+                        match builtin_script(&sha) {
+                            Some(_) => Reply::bulk(sha),
+                            None => Reply::err("ERR only built-in server scripts are supported by the dev sandbox"),
+                        }
+                    }
+                    "EXISTS" => Reply::Array(args[1..].iter()
+                        .map(|sha| Reply::Int(builtin_script(&String::from_utf8_lossy(sha).to_ascii_lowercase()).is_some() as i64))
+                        .collect()),
+                    "FLUSH" => Reply::ok(),
+                    _ => Reply::syntax(),
+                }
+            }
+
+            _ => Reply::err(format!("ERR unknown command '{}' (not supported by the dev sandbox)", name.to_lowercase())),
+        }
+    }
+}
+
+/// 내장 스크립트 구현 (KEYS, ARGV)
+type ScriptFn = fn(&mut Store, &[Vec<u8>], &[Vec<u8>]) -> Reply;
+
+/// SHA1로 내장 스크립트 조회
+///
+/// 서버 코드의 스크립트 원문에서 SHA1을 계산하므로, 원문이 바뀌면 구현도 함께 맞춰야 합니다.
+fn builtin_script(sha: &str) -> Option<ScriptFn> {
+    static SCRIPTS: OnceLock<HashMap<String, ScriptFn>> = OnceLock::new();
+    SCRIPTS.get_or_init(|| {
+        let scripts: [(&str, ScriptFn); 2] = [
+            (shared::security::session_quota::ADMIT_SCRIPT, admit_session_script),
+            (shared::service::redis::state_replica::CLAIM_LEASE_SCRIPT, claim_lease_script),
+        ];
+        scripts.into_iter()
+            .map(|(source, script)| (redis::Script::new(source).get_hash().to_string(), script))
+            .collect()
+    }).get(sha).copied()
+}
+
+/// 스크립트 안의 `redis.call`
+fn call(store: &mut Store, parts: &[&[u8]]) -> Reply {
+    store.execute(&parts.iter().map(|part| part.to_vec()).collect::<Vec<_>>())
+}
+
+/// `session_quota::ADMIT_SCRIPT`: 만료 패밀리 정리 -> 한도 검사 -> (밀어내기) -> 추가
+fn admit_session_script(store: &mut Store, keys: &[Vec<u8>], argv: &[Vec<u8>]) -> Reply {
+    let (Some(key), [now, cutoff, max, evict, family_id, ttl, ..]) = (keys.first(), argv) else {
+        return Reply::err("ERR wrong number of arguments for session admit script");
+    };
+    let Some(max) = parse_i64(max) else { return Reply::not_integer() };
+
+    call(store, &[b"ZREMRANGEBYSCORE", key, b"-inf", cutoff]);
+    let count = match call(store, &[b"ZCARD", key]) {
+        Reply::Int(count) => count,
+        other => return other,
+    };
+    let mut evicted = Reply::Array(Vec::new());
+    if count >= max {
+        if evict.as_slice() == b"0" {
+            // Lua false -> nil
+            return Reply::nil();
+        }
+        let stop = (count - max).to_string();
+        evicted = call(store, &[b"ZRANGE", key, b"0", stop.as_bytes()]);
+        call(store, &[b"ZREMRANGEBYRANK", key, b"0", stop.as_bytes()]);
+    }
+    call(store, &[b"ZADD", key, now, family_id]);
+    call(store, &[b"EXPIRE", key, ttl]);
+    evicted
+}
+
+/// `state_replica::CLAIM_LEASE_SCRIPT`: 비어 있거나 같은 보유자일 때만 임대 설정
+fn claim_lease_script(store: &mut Store, keys: &[Vec<u8>], argv: &[Vec<u8>]) -> Reply {
+    let (Some(key), [holder, ttl_ms, ..]) = (keys.first(), argv) else {
+        return Reply::err("ERR wrong number of arguments for lease claim script");
+    };
+    match call(store, &[b"GET", key]) {
+        Reply::Bulk(None) => {}
+        Reply::Bulk(Some(current)) if current == *holder => {}
+        Reply::Bulk(Some(_)) => return Reply::Int(0),
+        other => return other,
+    }
+    call(store, &[b"SET", key, holder, b"PX", ttl_ms]);
+    Reply::Int(1)
+}
+
+fn parse_i64(value: &[u8]) -> Option<i64> {
+    std::str::from_utf8(value).ok()?.trim().parse().ok()
+}
+
+fn parse_score(value: &[u8]) -> Option<f64> {
+    match std::str::from_utf8(value).ok()?.trim().to_ascii_lowercase().as_str() {
+        "inf" | "+inf" => Some(f64::INFINITY),
+        "-inf" => Some(f64::NEG_INFINITY),
+        other => other.parse().ok(),
+    }
+}
+
+/// Redis와 같은 점수 표기 (정수는 소수점 없이)
+fn format_score(score: f64) -> String {
+    if score.is_infinite() {
+        if score > 0.0 { "inf".to_string() } else { "-inf".to_string() }
+    } else if score.fract() == 0.0 && score.abs() < 1e17 {
+        format!("{}", score as i64)
+    } else {
+        format!("{score}")
+    }
+}
+
+/// 점수 범위 경계 (`(`로 시작하면 미포함)
+#[derive(Debug, Clone, Copy)]
+struct ScoreBound {
+    value: f64,
+    exclusive: bool,
+}
+
+impl ScoreBound {
+    fn parse(raw: &[u8]) -> Option<Self> {
+        match raw.strip_prefix(b"(") {
+            Some(rest) => Some(Self { value: parse_score(rest)?, exclusive: true }),
+            None => Some(Self { value: parse_score(raw)?, exclusive: false }),
+        }
+    }
+
+    /// 최솟값 경계로서 `score`를 포함하는지
+    fn below(&self, score: f64) -> bool {
+        if self.exclusive { self.value < score } else { self.value <= score }
+    }
+
+    /// 최댓값 경계로서 `score`를 포함하는지
+    fn above(&self, score: f64) -> bool {
+        if self.exclusive { score < self.value } else { score <= self.value }
+    }
+}
+
+fn sorted_zset(zset: &HashMap<Vec<u8>, f64>) -> Vec<(Vec<u8>, f64)> {
+    let mut sorted: Vec<(Vec<u8>, f64)> = zset.iter().map(|(m, s)| (m.clone(), *s)).collect();
+    sorted.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    sorted
+}
+
+fn zset_reply(items: impl Iterator<Item = (Vec<u8>, f64)>, with_scores: bool) -> Reply {
+    Reply::Array(items.flat_map(|(member, score)| {
+        let mut out = vec![Reply::bulk(member)];
+        if with_scores {
+            out.push(Reply::bulk(format_score(score)));
+        }
+        out
+    }).collect())
+}
+
+/// LRANGE 스타일 인덱스를 `[start, end)`로 변환
+fn range_bounds(start: i64, stop: i64, len: usize) -> (usize, usize) {
+    let len = len as i64;
+    let start = if start < 0 { (len + start).max(0) } else { start.min(len) };
+    let stop = if stop < 0 { len + stop } else { stop.min(len - 1) };
+    if stop < start {
+        (0, 0)
+    } else {
+        (start as usize, (stop + 1) as usize)
+    }
+}
+
+/// `*`, `?` 와일드카드 매칭
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|i| glob_match(rest, &text[i..])),
+        Some((b'?', rest)) => !text.is_empty() && glob_match(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && glob_match(rest, &text[1..]),
+    }
+}
+
+/// 구독자 (연결별 채널/패턴 목록과 출력 큐)
+struct Subscriber {
+    channels: HashSet<Vec<u8>>,
+    patterns: HashSet<Vec<u8>>,
+    out: mpsc::UnboundedSender<Reply>,
+}
+
+impl Subscriber {
+    fn count(&self) -> i64 {
+        (self.channels.len() + self.patterns.len()) as i64
+    }
+}
+
+/// 공유 상태
+#[derive(Default)]
+struct Shared {
+    store: Mutex<Store>,
+    subscribers: Mutex<HashMap<u64, Subscriber>>,
+}
+
+impl Shared {
+    fn publish(&self, channel: &[u8], payload: &[u8]) -> i64 {
+        let Ok(subscribers) = self.subscribers.lock() else {
+            return 0;
+        };
+        let mut delivered = 0;
+        for subscriber in subscribers.values() {
+            if subscriber.channels.contains(channel) {
+                let message = Reply::Array(vec![Reply::bulk("message"), Reply::bulk(channel), Reply::bulk(payload)]);
+                delivered += subscriber.out.send(message).is_ok() as i64;
+            }
+            for pattern in subscriber.patterns.iter().filter(|pattern| glob_match(pattern, channel)) {
+                let message = Reply::Array(vec![
+                    Reply::bulk("pmessage"), Reply::bulk(pattern.clone()), Reply::bulk(channel), Reply::bulk(payload),
+                ]);
+                delivered += subscriber.out.send(message).is_ok() as i64;
+            }
+        }
+        delivered
+    }
+}
+
+/// 인메모리 Redis 서버
+pub struct FakeRedis {
+    local_addr: SocketAddr,
+    handle: JoinHandle<()>,
+}
+
+impl FakeRedis {
+    /// 지정 주소에서 서버 시작 (포트 0이면 임의 포트)
+    pub async fn bind(addr: SocketAddr) -> Result<Self> {
+        let listener = TcpListener::bind(addr).await
+            .with_context(|| format!("샌드박스 Redis를 {}에 바인드하는데 실패했습니다", addr))?;
+        let local_addr = listener.local_addr()?;
+        let shared = Arc::new(Shared::default());
+
+        let handle = tokio::spawn(async move {
+            let mut next_id = 0u64;
+            loop {
+                match listener.accept().await {
+                    Ok((socket, _)) => {
+                        next_id += 1;
+                        tokio::spawn(serve_connection(socket, next_id, shared.clone()));
+                    }
+                    Err(e) => warn!("샌드박스 Redis 연결 승인 실패: {}", e),
+                }
+            }
+        });
+
+        info!("🧪 샌드박스 Redis 시작: {}", local_addr);
+        Ok(Self { local_addr, handle })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for FakeRedis {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// 클라이언트 연결 처리
+async fn serve_connection(socket: TcpStream, id: u64, shared: Arc<Shared>) {
+    let (reader, mut writer) = socket.into_split();
+    let mut reader = BufReader::new(reader);
+    let (out, mut out_rx) = mpsc::unbounded_channel::<Reply>();
+
+    // 응답과 구독 메시지를 같은 순서로 내보내는 쓰기 태스크
+    let write_task = tokio::spawn(async move {
+        let mut buffer = Vec::new();
+        while let Some(reply) = out_rx.recv().await {
+            buffer.clear();
+            reply.encode(&mut buffer);
+            if writer.write_all(&buffer).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut transaction: Option<Vec<Vec<Vec<u8>>>> = None;
+    loop {
+        let args = match read_command(&mut reader).await {
+            Ok(Some(args)) if args.is_empty() => continue,
+            Ok(Some(args)) => args,
+            Ok(None) => break,
+            Err(e) => {
+                debug!("샌드박스 Redis 프로토콜 오류: {}", e);
+                let _ = out.send(Reply::err(format!("ERR Protocol error: {e}")));
+                break;
+            }
+        };
+        let name = String::from_utf8_lossy(&args[0]).to_ascii_uppercase();
+
+        let reply = match (name.as_str(), transaction.as_mut()) {
+            ("MULTI", Some(_)) => Reply::err("ERR MULTI calls can not be nested"),
+            ("MULTI", None) => {
+                transaction = Some(Vec::new());
+                Reply::ok()
+            }
+            ("DISCARD", Some(_)) => {
+                transaction = None;
+                Reply::ok()
+            }
+            ("EXEC", Some(_)) => {
+                let queued = transaction.take().unwrap_or_default();
+                let mut store = shared.store.lock().unwrap_or_else(|e| e.into_inner());
+                Reply::Array(queued.iter().map(|args| store.execute(args)).collect())
+            }
+            ("EXEC", None) => Reply::err("ERR EXEC without MULTI"),
+            ("DISCARD", None) => Reply::err("ERR DISCARD without MULTI"),
+            (_, Some(queue)) => {
+                queue.push(args);
+                Reply::Simple("QUEUED")
+            }
+            (_, None) => match execute_connection_command(&name, &args, id, &shared, &out) {
+                Some(reply) => reply,
+                None => continue,
+            },
+        };
+
+        if name == "QUIT" {
+            let _ = out.send(Reply::ok());
+            break;
+        }
+        if out.send(reply).is_err() {
+            break;
+        }
+    }
+
+    if let Ok(mut subscribers) = shared.subscribers.lock() {
+        subscribers.remove(&id);
+    }
+    drop(out);
+    let _ = write_task.await;
+}
+
+/// 연결 단위 명령 처리 (구독 명령은 응답을 직접 보내므로 None)
+fn execute_connection_command(
+    name: &str,
+    args: &[Vec<u8>],
+    id: u64,
+    shared: &Shared,
+    out: &mpsc::UnboundedSender<Reply>,
+) -> Option<Reply> {
+    let reply = match name {
+        "PING" => match args.get(1) {
+            Some(message) => Reply::bulk(message.clone()),
+            None => Reply::Simple("PONG"),
+        },
+        "ECHO" => args.get(1).cloned().map_or_else(Reply::syntax, Reply::bulk),
+        "SELECT" | "CLIENT" | "READONLY" | "QUIT" => Reply::ok(),
+        "INFO" => Reply::bulk(format!(
+            "# Server\r\nredis_version:7.0.0\r\nredis_mode:sandbox\r\n# Memory\r\nused_memory_human:{}K\r\n# Keyspace\r\ndb0:keys={}\r\n",
+            0,
+            shared.store.lock().map(|store| store.data.len()).unwrap_or(0),
+        )),
+        "PUBLISH" => match (args.get(1), args.get(2)) {
+            (Some(channel), Some(payload)) => Reply::Int(shared.publish(channel, payload)),
+            _ => Reply::syntax(),
+        },
+        "SUBSCRIBE" | "PSUBSCRIBE" | "UNSUBSCRIBE" | "PUNSUBSCRIBE" => {
+            let mut subscribers = shared.subscribers.lock().unwrap_or_else(|e| e.into_inner());
+            let subscriber = subscribers.entry(id).or_insert_with(|| Subscriber {
+                channels: HashSet::new(),
+                patterns: HashSet::new(),
+                out: out.clone(),
+            });
+            let pattern = name.starts_with('P');
+            let subscribe = !name.contains("UNSUB");
+            let mut targets: Vec<Vec<u8>> = args[1..].to_vec();
+            if targets.is_empty() && !subscribe {
+                targets = if pattern { subscriber.patterns.iter().cloned().collect() } else { subscriber.channels.iter().cloned().collect() };
+            }
+            let kind = name.to_ascii_lowercase();
+            for target in targets {
+                let set = if pattern { &mut subscriber.patterns } else { &mut subscriber.channels };
+                if subscribe { set.insert(target.clone()); } else { set.remove(&target); }
+                let _ = out.send(Reply::Array(vec![Reply::bulk(kind.clone()), Reply::bulk(target), Reply::Int(subscriber.count())]));
+            }
+            return None;
+        }
+        _ => shared.store.lock().unwrap_or_else(|e| e.into_inner()).execute(args),
+    };
+    Some(reply)
+}
+
+/// RESP 배열 명령 또는 인라인 명령 읽기 (연결 종료 시 None)
+async fn read_command<R: tokio::io::AsyncBufRead + Unpin>(reader: &mut R) -> Result<Option<Vec<Vec<u8>>>> {
+    let mut line = Vec::new();
+    if reader.read_until(b'\n', &mut line).await? == 0 {
+        return Ok(None);
+    }
+    let header = trim_crlf(&line);
+
+    let Some(count) = header.strip_prefix(b"*") else {
+        // 인라인 명령 (redis-cli, telnet)
+        return Ok(Some(header.split(|b| b.is_ascii_whitespace())
+            .filter(|part| !part.is_empty())
+            .map(|part| part.to_vec())
+            .collect()));
+    };
+    let count: usize = std::str::from_utf8(count)?.parse().context("잘못된 배열 길이")?;
+
+    let mut args = Vec::with_capacity(count);
+    for _ in 0..count {
+        line.clear();
+        reader.read_until(b'\n', &mut line).await?;
+        let len = trim_crlf(&line).strip_prefix(b"$").context("벌크 문자열이 필요합니다")?;
+        let len: usize = std::str::from_utf8(len)?.parse().context("잘못된 벌크 길이")?;
+
+        let mut data = vec![0; len + 2];
+        reader.read_exact(&mut data).await?;
+        data.truncate(len);
+        args.push(data);
+    }
+    Ok(Some(args))
+}
+
+fn trim_crlf(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use redis::AsyncCommands;
+
+    fn cmd(store: &mut Store, parts: &[&str]) -> Reply {
+        let args: Vec<Vec<u8>> = parts.iter().map(|p| p.as_bytes().to_vec()).collect();
+        store.execute(&args)
+    }
+
+    #[test]
+    fn test_store_commands() {
+        let mut store = Store::default();
+        assert_eq!(cmd(&mut store, &["SET", "a", "1", "NX"]), Reply::ok());
+        assert_eq!(cmd(&mut store, &["SET", "a", "2", "NX"]), Reply::nil());
+        assert_eq!(cmd(&mut store, &["INCRBY", "a", "4"]), Reply::Int(5));
+        assert_eq!(cmd(&mut store, &["HSET", "a", "f", "v"]), Reply::wrong_type());
+
+        assert_eq!(cmd(&mut store, &["RPUSH", "l", "x", "y", "z"]), Reply::Int(3));
+        assert_eq!(cmd(&mut store, &["LTRIM", "l", "-2", "-1"]), Reply::ok());
+        assert_eq!(cmd(&mut store, &["LRANGE", "l", "0", "-1"]), Reply::array([b"y".to_vec(), b"z".to_vec()]));
+
+        cmd(&mut store, &["ZADD", "z", "3", "c", "1", "a", "2", "b"]);
+        assert_eq!(cmd(&mut store, &["ZREVRANGEBYSCORE", "z", "+inf", "(1", "LIMIT", "0", "1"]), Reply::array([b"c".to_vec()]));
+        assert_eq!(cmd(&mut store, &["ZRANGE", "z", "0", "0", "WITHSCORES"]), Reply::array([b"a".to_vec(), b"1".to_vec()]));
+
+        assert_eq!(cmd(&mut store, &["KEYS", "?"]), cmd(&mut store, &["KEYS", "*"]));
+        assert_eq!(cmd(&mut store, &["EXPIRE", "a", "0"]), Reply::Int(1));
+        assert_eq!(cmd(&mut store, &["TTL", "a"]), Reply::Int(-2));
+    }
+
+    #[tokio::test]
+    async fn test_redis_client_roundtrip() {
+        let server = FakeRedis::bind("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let client = redis::Client::open(format!("redis://{}", server.local_addr())).unwrap();
+        let mut conn = client.get_multiplexed_tokio_connection().await.unwrap();
+
+        let _: () = conn.set_ex("session", "abc", 60).await.unwrap();
+        let value: String = conn.get("session").await.unwrap();
+        assert_eq!(value, "abc");
+
+        let _: () = conn.hset_multiple("user:1", &[("nick", "cop"), ("level", "3")]).await.unwrap();
+        let user: HashMap<String, String> = conn.hgetall("user:1").await.unwrap();
+        assert_eq!(user.get("nick").map(String::as_str), Some("cop"));
+
+        let (count, members): (i64, Vec<String>) = redis::pipe()
+            .atomic()
+            .zadd("rank", "b", 2).ignore()
+            .zadd("rank", "a", 1).ignore()
+            .zcard("rank")
+            .zrange("rank", 0, -1)
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(members, vec!["a", "b"]);
+
+        // 발행/구독
+        let mut pubsub = client.get_async_connection().await.unwrap().into_pubsub();
+        pubsub.subscribe("events:chat").await.unwrap();
+        let receivers: i64 = conn.publish("events:chat", "hello").await.unwrap();
+        assert_eq!(receivers, 1);
+        let message = futures::StreamExt::next(&mut pubsub.on_message()).await.unwrap();
+        assert_eq!(message.get_payload::<String>().unwrap(), "hello");
+    }
+
+    #[tokio::test]
+    async fn test_builtin_scripts() {
+        let server = FakeRedis::bind("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let client = redis::Client::open(format!("redis://{}", server.local_addr())).unwrap();
+        let mut conn = client.get_multiplexed_tokio_connection().await.unwrap();
+
+        // session_quota::SessionQuota::admit와 같은 인자 (최대 2개, 밀어내기 여부)
+        let script = redis::Script::new(shared::security::session_quota::ADMIT_SCRIPT);
+        let admit = |family: &'static str, now: i64, evict: &'static str| {
+            let mut invocation = script.key("session:families:1");
+            invocation.arg(now).arg(now - 3600).arg(2).arg(evict).arg(family).arg(3600);
+            invocation
+        };
+
+        let first: Option<Vec<String>> = admit("a", 100, "1").invoke_async(&mut conn).await.unwrap();
+        let second: Option<Vec<String>> = admit("b", 200, "1").invoke_async(&mut conn).await.unwrap();
+        assert_eq!((first, second), (Some(vec![]), Some(vec![])));
+
+        let evicted: Option<Vec<String>> = admit("c", 300, "1").invoke_async(&mut conn).await.unwrap();
+        assert_eq!(evicted, Some(vec!["a".to_string()]));
+        let rejected: Option<Vec<String>> = admit("d", 400, "0").invoke_async(&mut conn).await.unwrap();
+        assert_eq!(rejected, None);
+
+        let members: Vec<String> = conn.zrange("session:families:1", 0, -1).await.unwrap();
+        assert_eq!(members, vec!["b", "c"]);
+
+        // 임대는 비어 있거나 같은 보유자일 때만 획득
+        let claim_lease = redis::Script::new(shared::service::redis::state_replica::CLAIM_LEASE_SCRIPT);
+        let claim = |holder: &'static str| {
+            let mut invocation = claim_lease.key("replica:match:lease");
+            invocation.arg(holder).arg(60_000);
+            invocation
+        };
+        let claimed: (i32, i32, i32) = (
+            claim("primary").invoke_async(&mut conn).await.unwrap(),
+            claim("primary").invoke_async(&mut conn).await.unwrap(),
+            claim("standby").invoke_async(&mut conn).await.unwrap(),
+        );
+        assert_eq!(claimed, (1, 1, 0));
+
+        // 내장되지 않은 스크립트는 거부
+        let unknown: redis::RedisResult<i64> = redis::Script::new("return 1").invoke_async(&mut conn).await;
+        assert!(unknown.is_err());
+    }
+}
@@ -0,0 +1,282 @@
+//! 기본 유지보수 작업
+//!
+//! - `stale_room_purge`: 방 정보가 만료된 방을 시간순 방 목록에서 제거
+//! - `token_cleanup`: 만료 시간 없이 남은 로그인 토큰 정리
+//! - `ccu_sample` / `daily_stats_rollup`: CCU를 주기적으로 수집해 일일 통계로 집계
+//! - `leaderboard_season_rollover`: 주간 리더보드를 시즌 보관 키로 옮기고 새 시즌 시작
+//! - `jwt_key_rotation`: 새 JWT 서명 키를 만들어 Redis로 배포 (이전 키는 액세스/리프레시 토큰 수명 동안 검증용 유지)
+
+use anyhow::{Context, Result};
+use chrono::{Duration as ChronoDuration, Utc, Weekday};
+use redis::AsyncCommands;
+use std::time::Duration;
+
+use shared::config::redis_config::RedisConfig;
+use shared::service::redis::core::redis_get_key::KeyType;
+use shared::service::redis::jwt_keys::{JwtKeyStore, ROTATION_PROPAGATION_SECS};
+use shared::service::redis::server_stats::ServerStatsStore;
+use shared::security::SessionQuotaPolicy;
+use shared::service::token::JwtKeySet;
+
+use super::{Job, Schedule};
+
+/// 일일 CCU 샘플 리스트 키 접두사 (`stats:ccu_samples:{date}`)
+const CCU_SAMPLES_PREFIX: &str = "stats:ccu_samples";
+/// 일일 통계 해시 키 접두사 (`stats:daily:{date}`)
+const DAILY_STATS_PREFIX: &str = "stats:daily";
+/// CCU 샘플 보관 기간
+const CCU_SAMPLES_TTL_SECS: i64 = 3 * 24 * 3600;
+/// 일일 통계 보관 기간
+const DAILY_STATS_TTL_SECS: i64 = 90 * 24 * 3600;
+
+/// 현재 시즌 리더보드 키
+pub const LEADERBOARD_CURRENT_KEY: &str = "leaderboard:current";
+/// 현재 시즌 번호 키
+const LEADERBOARD_SEASON_KEY: &str = "leaderboard:season";
+/// 지난 시즌 보관 기간
+const LEADERBOARD_ARCHIVE_TTL_SECS: i64 = 180 * 24 * 3600;
+
+/// 기본 작업 목록
+pub fn default_jobs() -> Vec<Job> {
+    vec![
+        Job::new("stale_room_purge", Schedule::Every(Duration::from_secs(300)), |redis| Box::pin(purge_stale_rooms(redis))),
+        Job::new("token_cleanup", Schedule::Every(Duration::from_secs(3600)), |redis| Box::pin(cleanup_tokens(redis))),
+        Job::new("ccu_sample", Schedule::Every(Duration::from_secs(300)), |redis| Box::pin(sample_ccu(redis)))
+            .with_timeout(Duration::from_secs(60)),
+        Job::new("daily_stats_rollup", Schedule::Daily { hour: 0, minute: 5 }, |redis| Box::pin(rollup_daily_stats(redis))),
+        Job::new(
+            "leaderboard_season_rollover",
+            Schedule::Weekly { weekday: Weekday::Mon, hour: 0, minute: 0 },
+            |redis| Box::pin(rollover_leaderboard_season(redis)),
+        ),
+        Job::new(
+            "jwt_key_rotation",
+            Schedule::Weekly { weekday: Weekday::Wed, hour: 3, minute: 0 },
+            |redis| Box::pin(rotate_jwt_keys(redis)),
+        ),
+    ]
+}
+
+/// 방 정보(`room:info:{id}`)가 만료된 방을 방 목록 인덱스와 참가자 목록에서 제거
+async fn purge_stale_rooms(redis_config: RedisConfig) -> Result<String> {
+    let mut conn = redis_config.get_connection();
+    let index_key = KeyType::RoomListByTime.get_index_key();
+    let members: Vec<String> = conn.zrange(&index_key, 0, -1).await.context("방 목록 조회 실패")?;
+
+    let room_ids: Vec<u16> = members.iter().filter_map(|member| member.parse().ok()).collect();
+    if room_ids.is_empty() {
+        return Ok("no rooms".to_string());
+    }
+
+    let mut pipe = redis::pipe();
+    for room_id in &room_ids {
+        pipe.exists(KeyType::RoomInfo.get_key(room_id));
+    }
+    let exists: Vec<bool> = pipe.query_async(&mut conn).await.context("방 정보 확인 실패")?;
+
+    let stale: Vec<u16> = room_ids.iter().zip(exists)
+        .filter(|(_, exists)| !exists)
+        .map(|(room_id, _)| *room_id)
+        .collect();
+    if stale.is_empty() {
+        return Ok(format!("checked {} rooms, none stale", room_ids.len()));
+    }
+
+    let mut pipe = redis::pipe();
+    for room_id in &stale {
+        pipe.zrem(&index_key, room_id.to_string()).ignore();
+        pipe.del(KeyType::RoomUserList.get_key(room_id)).ignore();
+    }
+    let _: () = pipe.query_async(&mut conn).await.context("오래된 방 삭제 실패")?;
+
+    Ok(format!("purged {} of {} rooms", stale.len(), room_ids.len()))
+}
+
+/// 만료 시간 없이 남은 사용자 로그인 정보(`user:{id}`의 `access_token`) 삭제
+///
+/// 로그인 정보는 항상 TTL과 함께 기록되므로, TTL이 없는 키는 기록 도중 실패로 남은 것입니다.
+async fn cleanup_tokens(redis_config: RedisConfig) -> Result<String> {
+    let mut conn = redis_config.get_connection();
+    let mut keys = Vec::new();
+    {
+        let mut iter: redis::AsyncIter<String> = conn.scan_match("user:*").await.context("사용자 키 조회 실패")?;
+        while let Some(key) = iter.next_item().await {
+            // `user:{id}:location` 같은 하위 키는 제외
+            if key.strip_prefix("user:").is_some_and(|id| id.parse::<u32>().is_ok()) {
+                keys.push(key);
+            }
+        }
+    }
+    if keys.is_empty() {
+        return Ok("no sessions".to_string());
+    }
+
+    let mut pipe = redis::pipe();
+    for key in &keys {
+        pipe.ttl(key).hexists(key, "access_token");
+    }
+    let results: Vec<(i64, bool)> = pipe.query_async(&mut conn).await.context("토큰 TTL 확인 실패")?;
+
+    let orphaned: Vec<&String> = keys.iter().zip(results)
+        .filter(|(_, (ttl, has_token))| *ttl == -1 && *has_token)
+        .map(|(key, _)| key)
+        .collect();
+    if !orphaned.is_empty() {
+        let _: () = conn.del(&orphaned).await.context("토큰 삭제 실패")?;
+    }
+
+    Ok(format!("removed {} orphaned tokens of {} sessions", orphaned.len(), keys.len()))
+}
+
+/// 현재 클러스터 CCU를 오늘 샘플 리스트에 추가
+async fn sample_ccu(redis_config: RedisConfig) -> Result<String> {
+    let stats = ServerStatsStore::new(redis_config.clone()).cluster_stats(None).await
+        .map_err(|e| anyhow::anyhow!("클러스터 통계 조회 실패: {}", e))?;
+
+    let key = format!("{}:{}", CCU_SAMPLES_PREFIX, Utc::now().format("%Y-%m-%d"));
+    let mut conn = redis_config.get_connection();
+    let mut pipe = redis::pipe();
+    pipe.rpush(&key, stats.total_ccu).ignore()
+        .expire(&key, CCU_SAMPLES_TTL_SECS).ignore();
+    let _: () = pipe.query_async(&mut conn).await.context("CCU 샘플 기록 실패")?;
+
+    Ok(format!("ccu={} servers={}", stats.total_ccu, stats.server_count))
+}
+
+/// 어제의 CCU 샘플을 최대/평균/최소로 집계해 일일 통계에 기록
+async fn rollup_daily_stats(redis_config: RedisConfig) -> Result<String> {
+    let date = (Utc::now() - ChronoDuration::days(1)).format("%Y-%m-%d").to_string();
+    let samples_key = format!("{}:{}", CCU_SAMPLES_PREFIX, date);
+    let mut conn = redis_config.get_connection();
+
+    let samples: Vec<u64> = conn.lrange(&samples_key, 0, -1).await.context("CCU 샘플 조회 실패")?;
+    let Some(rollup) = DailyRollup::from_samples(&samples) else {
+        return Ok(format!("no samples for {date}"));
+    };
+
+    let daily_key = format!("{}:{}", DAILY_STATS_PREFIX, date);
+    let mut pipe = redis::pipe();
+    pipe.hset_multiple(&daily_key, &[
+        ("peak_ccu", rollup.peak_ccu),
+        ("avg_ccu", rollup.avg_ccu),
+        ("min_ccu", rollup.min_ccu),
+        ("samples", rollup.samples),
+    ]).ignore()
+        .expire(&daily_key, DAILY_STATS_TTL_SECS).ignore()
+        .del(&samples_key).ignore();
+    let _: () = pipe.query_async(&mut conn).await.context("일일 통계 기록 실패")?;
+
+    Ok(format!("{date}: peak={} avg={} min={}", rollup.peak_ccu, rollup.avg_ccu, rollup.min_ccu))
+}
+
+/// 일일 CCU 집계
+#[derive(Debug, PartialEq, Eq)]
+struct DailyRollup {
+    peak_ccu: u64,
+    avg_ccu: u64,
+    min_ccu: u64,
+    samples: u64,
+}
+
+impl DailyRollup {
+    fn from_samples(samples: &[u64]) -> Option<Self> {
+        let peak_ccu = *samples.iter().max()?;
+        let min_ccu = *samples.iter().min()?;
+        let avg_ccu = samples.iter().sum::<u64>() / samples.len() as u64;
+        Some(Self { peak_ccu, avg_ccu, min_ccu, samples: samples.len() as u64 })
+    }
+}
+
+/// 현재 리더보드를 `leaderboard:season:{n}`으로 보관하고 시즌 번호 증가
+async fn rollover_leaderboard_season(redis_config: RedisConfig) -> Result<String> {
+    let mut conn = redis_config.get_connection();
+    let season: u64 = conn.get::<_, Option<u64>>(LEADERBOARD_SEASON_KEY).await
+        .context("시즌 번호 조회 실패")?
+        .unwrap_or(1);
+    let has_entries: bool = conn.exists(LEADERBOARD_CURRENT_KEY).await.context("리더보드 확인 실패")?;
+
+    let archive_key = format!("{}:{}", LEADERBOARD_SEASON_KEY, season);
+    let mut pipe = redis::pipe();
+    pipe.atomic();
+    if has_entries {
+        pipe.rename(LEADERBOARD_CURRENT_KEY, &archive_key).ignore()
+            .expire(&archive_key, LEADERBOARD_ARCHIVE_TTL_SECS).ignore();
+    }
+    pipe.set(LEADERBOARD_SEASON_KEY, season + 1).ignore();
+    let _: () = pipe.query_async(&mut conn).await.context("시즌 전환 실패")?;
+
+    Ok(format!("season {} archived{}, season {} started", season, if has_entries { "" } else { " (empty)" }, season + 1))
+}
+
+/// 새 JWT 서명 키를 만들어 배포 키 목록에 추가
+///
+/// 첫 회전이면 환경변수 키(`JWT_SECRET_KEY`, `JWT_VERIFY_KEYS`)에서 시작하고,
+/// 서명을 멈춘 키는 그 키로 발급된 토큰이 모두 만료된 뒤 제거합니다. (`jwt_key_retain_secs`)
+async fn rotate_jwt_keys(redis_config: RedisConfig) -> Result<String> {
+    let seed = std::env::var("JWT_SECRET_KEY").map(JwtKeySet::configured).unwrap_or_default();
+    let token_hours: i64 = std::env::var("JWT_EXPIRATION_HOURS").ok()
+        .and_then(|hours| hours.parse().ok())
+        .unwrap_or(1);
+    let retain_secs = jwt_key_retain_secs(token_hours, SessionQuotaPolicy::from_env().family_ttl);
+
+    let keys = JwtKeyStore::new(redis_config).rotate(seed, retain_secs).await
+        .map_err(|e| anyhow::anyhow!("JWT 키 회전 실패: {}", e))?;
+    let kids: Vec<&str> = keys.keys.iter().map(|key| key.kid.as_str()).collect();
+    Ok(format!("keys=[{}]", kids.join(",")))
+}
+
+/// 서명을 멈춘 키의 보관 기간 (초)
+///
+/// 액세스 토큰 수명(`JWT_EXPIRATION_HOURS`)과 리프레시 토큰 수명(`JWT_REFRESH_EXPIRATION_DAYS`)
+/// 중 긴 쪽에 배포 지연을 더합니다.
+fn jwt_key_retain_secs(access_hours: i64, refresh_ttl: Duration) -> i64 {
+    let access_secs = access_hours.max(1) * 3600;
+    let refresh_secs = i64::try_from(refresh_ttl.as_secs()).unwrap_or(i64::MAX / 2);
+    access_secs.max(refresh_secs) + ROTATION_PROPAGATION_SECS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::service::token::{JwtKey, TokenService};
+
+    #[test]
+    fn test_daily_rollup() {
+        assert_eq!(DailyRollup::from_samples(&[]), None);
+        assert_eq!(
+            DailyRollup::from_samples(&[10, 40, 25]),
+            Some(DailyRollup { peak_ccu: 40, avg_ccu: 25, min_ccu: 10, samples: 3 })
+        );
+    }
+
+    #[test]
+    fn test_refresh_token_survives_next_rotation() {
+        const WEEK: i64 = 7 * 24 * 3600;
+        let refresh_ttl = Duration::from_secs(WEEK as u64);
+        let retain_secs = jwt_key_retain_secs(1, refresh_ttl);
+        let now = Utc::now().timestamp();
+
+        // 지난주 회전으로 활성화된 키가 지금의 서명 키
+        let mut keys = JwtKeySet::configured("rotation-test-seed".to_string());
+        keys.rotate(JwtKey::generate(now - WEEK + ROTATION_PROPAGATION_SECS), retain_secs, now - WEEK);
+        TokenService::install_distributed_keys(keys.clone());
+        let tokens = TokenService::new("rotation-test-seed".to_string(), "HS256".to_string());
+        let refresh_token = tokens.generate_refresh_token(42, "family-1", refresh_ttl).unwrap();
+
+        // 발급 직후 회전하고, 일주일 뒤 다음 회전
+        keys.rotate(JwtKey::generate(now + ROTATION_PROPAGATION_SECS), retain_secs, now);
+        keys.rotate(JwtKey::generate(now + WEEK + ROTATION_PROPAGATION_SECS), retain_secs, now + WEEK);
+        TokenService::install_distributed_keys(keys);
+
+        assert_eq!(tokens.verify_refresh_token(&refresh_token).unwrap(), (42, "family-1".to_string()));
+    }
+
+    #[test]
+    fn test_default_jobs_unique() {
+        let jobs = default_jobs();
+        let mut names: Vec<&str> = jobs.iter().map(|job| job.name.as_str()).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), jobs.len());
+    }
+}
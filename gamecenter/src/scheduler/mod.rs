@@ -0,0 +1,421 @@
+//! 정기 작업 스케줄러
+//!
+//! 리더보드 시즌 전환, 오래된 방 정리, 토큰 정리, 일일 통계 집계 같은 유지보수 작업을
+//! 주기/일일/주간 일정으로 실행합니다.
+//!
+//! 같은 게임센터를 여러 대 띄워도 작업이 중복 실행되지 않도록, 각 실행 시점(슬롯)마다
+//! Redis 락(`gamecenter:jobs:lock:{name}:{slot}`)을 `SET NX`로 선점한 인스턴스만 실행합니다.
+//! 실행 결과는 Redis 해시(`gamecenter:jobs:status`)에 기록되어 `gamecenter jobs`로 조회할 수 있습니다.
+
+pub mod jobs;
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, TimeZone, Utc, Weekday};
+use futures::future::BoxFuture;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use shared::config::redis_config::RedisConfig;
+
+/// 작업 상태 해시 키
+pub const JOB_STATUS_KEY: &str = "gamecenter:jobs:status";
+
+/// 작업 락 키 접두사
+const JOB_LOCK_PREFIX: &str = "gamecenter:jobs:lock";
+
+/// 기본 작업 실행 제한 시간
+const DEFAULT_JOB_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// 작업 실행 일정 (UTC 기준)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schedule {
+    /// 일정 간격 (유닉스 시각 기준으로 정렬되어 모든 인스턴스가 같은 슬롯을 봅니다)
+    Every(Duration),
+    /// 매일 지정 시각
+    Daily { hour: u32, minute: u32 },
+    /// 매주 지정 요일/시각
+    Weekly { weekday: Weekday, hour: u32, minute: u32 },
+}
+
+impl Schedule {
+    /// `now` 이후의 다음 실행 시각
+    pub fn next_after(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match *self {
+            Schedule::Every(interval) => {
+                let interval = interval.as_secs().max(1) as i64;
+                let next = (now.timestamp() / interval + 1) * interval;
+                Utc.timestamp_opt(next, 0).single().unwrap_or(now)
+            }
+            Schedule::Daily { hour, minute } => {
+                let mut candidate = at_time(now, hour, minute);
+                if candidate <= now {
+                    candidate += ChronoDuration::days(1);
+                }
+                candidate
+            }
+            Schedule::Weekly { weekday, hour, minute } => {
+                let days_ahead = (7 + weekday.num_days_from_monday() as i64
+                    - now.weekday().num_days_from_monday() as i64) % 7;
+                let mut candidate = at_time(now, hour, minute) + ChronoDuration::days(days_ahead);
+                if candidate <= now {
+                    candidate += ChronoDuration::weeks(1);
+                }
+                candidate
+            }
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Schedule::Every(interval) => write!(f, "every {}s", interval.as_secs()),
+            Schedule::Daily { hour, minute } => write!(f, "daily {hour:02}:{minute:02} UTC"),
+            Schedule::Weekly { weekday, hour, minute } => write!(f, "weekly {weekday} {hour:02}:{minute:02} UTC"),
+        }
+    }
+}
+
+/// `now`와 같은 날짜의 지정 시각
+fn at_time(now: DateTime<Utc>, hour: u32, minute: u32) -> DateTime<Utc> {
+    now.date_naive()
+        .and_hms_opt(hour.min(23), minute.min(59), 0)
+        .map(|naive| Utc.from_utc_datetime(&naive))
+        .unwrap_or(now)
+}
+
+/// 작업 실행 함수 (성공 시 결과 요약 메시지 반환)
+pub type JobFn = Arc<dyn Fn(RedisConfig) -> BoxFuture<'static, Result<String>> + Send + Sync>;
+
+/// 정기 작업 정의
+#[derive(Clone)]
+pub struct Job {
+    pub name: String,
+    pub schedule: Schedule,
+    /// 실행 제한 시간 (초과 시 실패로 기록)
+    pub timeout: Duration,
+    run: JobFn,
+}
+
+impl Job {
+    pub fn new<F>(name: impl Into<String>, schedule: Schedule, run: F) -> Self
+    where
+        F: Fn(RedisConfig) -> BoxFuture<'static, Result<String>> + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            schedule,
+            timeout: DEFAULT_JOB_TIMEOUT,
+            run: Arc::new(run),
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl fmt::Debug for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Job")
+            .field("name", &self.name)
+            .field("schedule", &self.schedule)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// 마지막 실행 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobOutcome {
+    Succeeded,
+    Failed,
+    TimedOut,
+}
+
+/// 작업 상태 (`gamecenter jobs`로 조회)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobStatus {
+    pub name: String,
+    pub schedule: String,
+    /// 이 인스턴스 기준 다음 실행 시각 (유닉스 초)
+    pub next_run_at: Option<i64>,
+    pub last_started_at: Option<i64>,
+    pub last_duration_ms: Option<u64>,
+    pub last_outcome: Option<JobOutcome>,
+    /// 결과 요약 또는 에러 메시지
+    pub last_message: Option<String>,
+    /// 마지막으로 실행한 인스턴스
+    pub last_runner: Option<String>,
+    pub run_count: u64,
+    pub failure_count: u64,
+}
+
+impl JobStatus {
+    fn new(job: &Job) -> Self {
+        Self {
+            name: job.name.clone(),
+            schedule: job.schedule.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 실행 결과 반영
+    fn record(&mut self, started_at: DateTime<Utc>, duration: Duration, outcome: JobOutcome, message: String, runner: &str) {
+        self.last_started_at = Some(started_at.timestamp());
+        self.last_duration_ms = Some(duration.as_millis() as u64);
+        self.last_outcome = Some(outcome);
+        self.last_message = Some(message);
+        self.last_runner = Some(runner.to_string());
+        self.run_count += 1;
+        if outcome != JobOutcome::Succeeded {
+            self.failure_count += 1;
+        }
+    }
+}
+
+/// 정기 작업 스케줄러
+pub struct JobScheduler {
+    redis_config: RedisConfig,
+    /// 락 소유자 식별자 (호스트명:PID)
+    instance_id: String,
+    jobs: Vec<Job>,
+    statuses: Arc<RwLock<HashMap<String, JobStatus>>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl JobScheduler {
+    pub fn new(redis_config: RedisConfig) -> Self {
+        let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "gamecenter".to_string());
+        Self {
+            redis_config,
+            instance_id: format!("{}:{}", host, std::process::id()),
+            jobs: Vec::new(),
+            statuses: Arc::new(RwLock::new(HashMap::new())),
+            handles: Vec::new(),
+        }
+    }
+
+    /// 기본 유지보수 작업이 등록된 스케줄러
+    pub fn with_default_jobs(redis_config: RedisConfig) -> Self {
+        let mut scheduler = Self::new(redis_config);
+        for job in jobs::default_jobs() {
+            scheduler.register(job);
+        }
+        scheduler
+    }
+
+    /// 작업 등록 (시작 전에만 가능)
+    pub fn register(&mut self, job: Job) {
+        if self.jobs.iter().any(|existing| existing.name == job.name) {
+            warn!("이미 등록된 작업입니다: {}", job.name);
+            return;
+        }
+        self.jobs.push(job);
+    }
+
+    /// 모든 작업 시작
+    pub async fn start(&mut self) {
+        {
+            let mut statuses = self.statuses.write().await;
+            for job in &self.jobs {
+                statuses.entry(job.name.clone()).or_insert_with(|| JobStatus::new(job));
+            }
+        }
+
+        for job in self.jobs.clone() {
+            let runner = JobRunner {
+                job,
+                redis_config: self.redis_config.clone(),
+                instance_id: self.instance_id.clone(),
+                statuses: self.statuses.clone(),
+            };
+            self.handles.push(tokio::spawn(runner.run_forever()));
+        }
+
+        info!("⏰ 작업 스케줄러 시작 ({}개 작업, instance={})", self.jobs.len(), self.instance_id);
+    }
+
+    /// 모든 작업 중지 (실행 중인 작업도 취소)
+    pub fn stop(&mut self) {
+        for handle in self.handles.drain(..) {
+            handle.abort();
+        }
+    }
+
+    /// 이 인스턴스가 보는 작업 상태
+    pub async fn statuses(&self) -> Vec<JobStatus> {
+        let statuses = self.statuses.read().await;
+        let mut list: Vec<JobStatus> = statuses.values().cloned().collect();
+        list.sort_by(|a, b| a.name.cmp(&b.name));
+        list
+    }
+}
+
+impl Drop for JobScheduler {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// 단일 작업 실행 루프
+struct JobRunner {
+    job: Job,
+    redis_config: RedisConfig,
+    instance_id: String,
+    statuses: Arc<RwLock<HashMap<String, JobStatus>>>,
+}
+
+impl JobRunner {
+    async fn run_forever(self) {
+        loop {
+            let now = Utc::now();
+            let next = self.job.schedule.next_after(now);
+            if let Some(status) = self.statuses.write().await.get_mut(&self.job.name) {
+                status.next_run_at = Some(next.timestamp());
+            }
+
+            let wait = (next - now).to_std().unwrap_or_default();
+            tokio::time::sleep(wait).await;
+
+            match self.try_lock(next).await {
+                Ok(true) => self.run_once().await,
+                Ok(false) => debug!("다른 인스턴스가 작업 실행 중: {} ({})", self.job.name, next),
+                Err(e) => warn!("작업 락 획득 실패 - 이번 실행 건너뜀: {} ({})", self.job.name, e),
+            }
+        }
+    }
+
+    /// 실행 슬롯 락 선점
+    ///
+    /// 락은 해제하지 않고 만료되도록 두어, 시계가 조금 늦은 인스턴스가
+    /// 같은 슬롯을 다시 실행하지 않도록 합니다.
+    async fn try_lock(&self, slot: DateTime<Utc>) -> redis::RedisResult<bool> {
+        let key = format!("{}:{}:{}", JOB_LOCK_PREFIX, self.job.name, slot.timestamp());
+        let ttl = self.job.timeout.as_secs().max(60);
+        let mut conn = self.redis_config.get_connection();
+        let acquired: Option<String> = redis::cmd("SET")
+            .arg(&key)
+            .arg(&self.instance_id)
+            .arg("NX")
+            .arg("EX")
+            .arg(ttl)
+            .query_async(&mut conn)
+            .await?;
+        Ok(acquired.is_some())
+    }
+
+    async fn run_once(&self) {
+        let started_at = Utc::now();
+        let started = std::time::Instant::now();
+        info!("⏰ 작업 실행: {}", self.job.name);
+
+        let (outcome, message) = match tokio::time::timeout(self.job.timeout, (self.job.run)(self.redis_config.clone())).await {
+            Ok(Ok(summary)) => {
+                info!("✅ 작업 완료: {} - {}", self.job.name, summary);
+                (JobOutcome::Succeeded, summary)
+            }
+            Ok(Err(e)) => {
+                error!("❌ 작업 실패: {} - {}", self.job.name, e);
+                (JobOutcome::Failed, e.to_string())
+            }
+            Err(_) => {
+                error!("❌ 작업 시간 초과: {} ({}초)", self.job.name, self.job.timeout.as_secs());
+                (JobOutcome::TimedOut, format!("timed out after {}s", self.job.timeout.as_secs()))
+            }
+        };
+
+        // 다른 인스턴스가 실행했던 기록을 이어받아 누적
+        let mut status = read_status(&self.redis_config, &self.job.name).await
+            .unwrap_or_else(|| JobStatus::new(&self.job));
+        status.schedule = self.job.schedule.to_string();
+        status.next_run_at = Some(self.job.schedule.next_after(Utc::now()).timestamp());
+        status.record(started_at, started.elapsed(), outcome, message, &self.instance_id);
+
+        if let Err(e) = write_status(&self.redis_config, &status).await {
+            warn!("작업 상태 기록 실패: {} ({})", self.job.name, e);
+        }
+        self.statuses.write().await.insert(self.job.name.clone(), status);
+    }
+}
+
+/// Redis에 기록된 작업 상태 조회
+async fn read_status(redis_config: &RedisConfig, name: &str) -> Option<JobStatus> {
+    let mut conn = redis_config.get_connection();
+    let raw: Option<String> = conn.hget(JOB_STATUS_KEY, name).await.ok()?;
+    raw.and_then(|raw| serde_json::from_str(&raw).ok())
+}
+
+async fn write_status(redis_config: &RedisConfig, status: &JobStatus) -> Result<()> {
+    let payload = serde_json::to_string(status)?;
+    let mut conn = redis_config.get_connection();
+    let _: () = conn.hset(JOB_STATUS_KEY, &status.name, payload).await?;
+    Ok(())
+}
+
+/// 모든 인스턴스의 작업 상태 조회 (`gamecenter jobs`)
+pub async fn fetch_statuses(redis_config: &RedisConfig) -> Result<Vec<JobStatus>> {
+    let mut conn = redis_config.get_connection();
+    let raw: HashMap<String, String> = conn.hgetall(JOB_STATUS_KEY).await?;
+    let mut statuses: Vec<JobStatus> = raw.values()
+        .filter_map(|raw| serde_json::from_str(raw).ok())
+        .collect();
+    statuses.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(statuses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap()
+    }
+
+    #[test]
+    fn test_schedule_next_after() {
+        let now = utc(2026, 3, 4, 10, 7, 30); // 수요일
+
+        let every = Schedule::Every(Duration::from_secs(300));
+        assert_eq!(every.next_after(now), utc(2026, 3, 4, 10, 10, 0));
+        assert_eq!(every.next_after(utc(2026, 3, 4, 10, 10, 0)), utc(2026, 3, 4, 10, 15, 0));
+
+        let daily = Schedule::Daily { hour: 0, minute: 5 };
+        assert_eq!(daily.next_after(now), utc(2026, 3, 5, 0, 5, 0));
+        assert_eq!(Schedule::Daily { hour: 12, minute: 0 }.next_after(now), utc(2026, 3, 4, 12, 0, 0));
+
+        let weekly = Schedule::Weekly { weekday: Weekday::Mon, hour: 0, minute: 0 };
+        assert_eq!(weekly.next_after(now), utc(2026, 3, 9, 0, 0, 0));
+        assert_eq!(weekly.next_after(utc(2026, 3, 9, 0, 0, 0)), utc(2026, 3, 16, 0, 0, 0));
+        let same_day = Schedule::Weekly { weekday: Weekday::Wed, hour: 18, minute: 30 };
+        assert_eq!(same_day.next_after(now), utc(2026, 3, 4, 18, 30, 0));
+    }
+
+    #[test]
+    fn test_job_status_record() {
+        let job = Job::new("noop", Schedule::Every(Duration::from_secs(60)), |_| Box::pin(async { Ok(String::new()) }));
+        let mut status = JobStatus::new(&job);
+        assert_eq!(status.schedule, "every 60s");
+
+        let started = utc(2026, 3, 4, 0, 0, 0);
+        status.record(started, Duration::from_millis(12), JobOutcome::Succeeded, "ok".into(), "a:1");
+        status.record(started, Duration::from_millis(40), JobOutcome::TimedOut, "timeout".into(), "b:2");
+
+        assert_eq!(status.run_count, 2);
+        assert_eq!(status.failure_count, 1);
+        assert_eq!(status.last_outcome, Some(JobOutcome::TimedOut));
+        assert_eq!(status.last_runner.as_deref(), Some("b:2"));
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["last_outcome"], "timed_out");
+    }
+}
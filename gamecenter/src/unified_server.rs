@@ -4,16 +4,23 @@
 //! 단일 명령으로 모든 서버를 시작하고 중지할 수 있습니다.
 
 use anyhow::{Context, Result};
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::UdpSocket;
 use tokio::sync::Mutex;
 use tracing::{error, info, warn};
 
+use crate::rollover::DeploySlot;
+use crate::webhook::{EventKind, LifecycleEvent, Severity, WebhookNotifier};
+
 // Server imports
 use grpcserver::server::start_server as start_grpc_server;
 use tcpserver::{ConnectionService, HeartbeatService, TcpServerConfig, validate_config as validate_tcp_config};
 use tcpserver::service::MessageService;
+use shared::monitoring::ShutdownCoordinator;
 // use rudpserver::config::RudpServerConfig; // Currently unused
 
 /// 통합 서버 설정
@@ -94,6 +101,18 @@ impl UnifiedServerConfig {
                 redis_port: std::env::var("redis_port").unwrap_or_else(|_| "6379".to_string()).parse().unwrap_or(6379),
                 grpc_host: self.grpc_address.ip().to_string(),
                 grpc_port: self.grpc_address.port(),
+                heartbeat_timeout_alert_per_min: std::env::var("heartbeat_timeout_alert_per_min").unwrap_or_else(|_| "50".to_string()).parse().unwrap_or(50.0),
+                session_resume_grace_secs: std::env::var("session_resume_grace_secs").unwrap_or_else(|_| "60".to_string()).parse().unwrap_or(60),
+                max_rooms: std::env::var("max_rooms").unwrap_or_else(|_| "100".to_string()).parse().unwrap_or(100),
+                max_rooms_per_user: std::env::var("max_rooms_per_user").unwrap_or_else(|_| "3".to_string()).parse().unwrap_or(3),
+                room_create_cooldown_secs: std::env::var("room_create_cooldown_secs").unwrap_or_else(|_| "10".to_string()).parse().unwrap_or(10),
+                server_region: std::env::var("server_region").unwrap_or_else(|_| "local".to_string()),
+                health_port: None,
+                sticker_catalog_file: std::env::var("tcp_sticker_catalog").unwrap_or_else(|_| "property/stickers.toml".to_string()),
+                join_code_ttl_secs: std::env::var("join_code_ttl_secs").unwrap_or_else(|_| "600".to_string()).parse().unwrap_or(600),
+                message_catalog_file: std::env::var("tcp_message_catalog").unwrap_or_else(|_| "property/messages.toml".to_string()),
+                admission_queue_max: std::env::var("tcp_admission_queue_max").unwrap_or_else(|_| "200".to_string()).parse().unwrap_or(200),
+                admission_max_wait_secs: std::env::var("tcp_admission_max_wait_secs").unwrap_or_else(|_| "300".to_string()).parse().unwrap_or(300),
             };
             validate_tcp_config(&tcp_config)?;
         }
@@ -102,13 +121,55 @@ impl UnifiedServerConfig {
     }
 }
 
+/// RUDP 인스턴스 (블루/그린 교체 단위)
+struct RudpInstance {
+    slot: DeploySlot,
+    address: SocketAddr,
+    peers: Arc<PeerTracker>,
+    handle: tokio::task::JoinHandle<Result<()>>,
+}
+
+/// 최근 패킷을 보낸 피어 추적 (드레인 완료 판단용)
+#[derive(Default)]
+struct PeerTracker {
+    last_seen: std::sync::Mutex<HashMap<SocketAddr, Instant>>,
+}
+
+impl PeerTracker {
+    fn touch(&self, peer: SocketAddr) {
+        if let Ok(mut last_seen) = self.last_seen.lock() {
+            last_seen.insert(peer, Instant::now());
+        }
+    }
+
+    /// `idle` 이내에 패킷을 보낸 피어 수 (오래된 피어는 정리)
+    fn active(&self, idle: Duration) -> usize {
+        let Ok(mut last_seen) = self.last_seen.lock() else {
+            return 0;
+        };
+        last_seen.retain(|_, seen| seen.elapsed() < idle);
+        last_seen.len()
+    }
+}
+
 /// 통합 게임 서버
+#[derive(Clone)]
 pub struct UnifiedGameServer {
     config: UnifiedServerConfig,
     is_running: Arc<AtomicBool>,
-    server_handles: Arc<Mutex<Vec<tokio::task::JoinHandle<Result<()>>>>>,
+    /// 하위 서버 태스크 (서버 이름, 핸들)
+    server_handles: Arc<Mutex<Vec<(&'static str, tokio::task::JoinHandle<Result<()>>)>>>,
+    /// 실행 중인 RUDP 인스턴스 (마지막 항목이 새 매치를 받는 활성 인스턴스)
+    rudp_instances: Arc<Mutex<Vec<RudpInstance>>>,
+    /// 생명주기 이벤트 웹훅 알림
+    notifier: Option<WebhookNotifier>,
+    /// TCP 서버 종료 조율기 (TCP 태스크를 시작할 때마다 새로 생성)
+    tcp_shutdown: Arc<std::sync::Mutex<Option<ShutdownCoordinator>>>,
 }
 
+/// TCP 서버 정상 종료 완료 대기 제한 시간 (넘으면 태스크 취소)
+const TCP_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(15);
+
 impl UnifiedGameServer {
     /// 새 통합 서버 생성
     pub fn new(config: UnifiedServerConfig) -> Self {
@@ -116,9 +177,60 @@ impl UnifiedGameServer {
             config,
             is_running: Arc::new(AtomicBool::new(false)),
             server_handles: Arc::new(Mutex::new(Vec::new())),
+            rudp_instances: Arc::new(Mutex::new(Vec::new())),
+            notifier: None,
+            tcp_shutdown: Arc::new(std::sync::Mutex::new(None)),
         }
     }
 
+    /// 웹훅 알림 연결 (하위 서버 크래시 등)
+    pub fn with_notifier(mut self, notifier: Option<WebhookNotifier>) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    /// 생명주기 이벤트 알림 (웹훅 미설정 시 무시)
+    pub fn notify(&self, event: LifecycleEvent) {
+        if let Some(notifier) = &self.notifier {
+            notifier.notify(event);
+        }
+    }
+
+    /// 하위 서버 태스크 감시
+    ///
+    /// 태스크가 에러로 끝나거나 패닉하면 크래시로 알립니다. (중지에 의한 취소는 알리지 않음)
+    /// 패닉 리포트에는 하위 서버 이름이 태스크 이름으로 기록됩니다.
+    fn supervise<F>(&self, name: &'static str, server: F) -> tokio::task::JoinHandle<Result<()>>
+    where
+        F: std::future::Future<Output = Result<()>> + Send + 'static,
+    {
+        use futures::FutureExt;
+
+        let notifier = self.notifier.clone();
+        tokio::spawn(async move {
+            let server = shared::monitoring::crash::scoped(name, server);
+            let result = match std::panic::AssertUnwindSafe(server).catch_unwind().await {
+                Ok(result) => result,
+                Err(_) => Err(anyhow::anyhow!("{} 서버 태스크 패닉", name)),
+            };
+            if let Err(e) = &result {
+                error!("💥 {} 서버 비정상 종료: {:#}", name, e);
+                if let Some(notifier) = notifier {
+                    notifier.notify(
+                        LifecycleEvent::new(EventKind::Crashed, Severity::Critical, name, format!("{} 서버 크래시", name))
+                            .with_detail(format!("{:#}", e)),
+                    );
+                }
+            }
+            result
+        })
+    }
+
+    /// 서버 설정
+    pub fn config(&self) -> &UnifiedServerConfig {
+        &self.config
+    }
+
     /// 환경변수에서 설정을 로드하여 서버 생성
     pub fn from_env() -> Result<Self> {
         let config = UnifiedServerConfig::from_env()?;
@@ -141,31 +253,20 @@ impl UnifiedGameServer {
         // gRPC 서버 시작
         if self.config.enable_grpc {
             info!("📡 gRPC 서버 시작 중... ({})", self.config.grpc_address);
-            let grpc_addr = self.config.grpc_address;
-            let handle = tokio::spawn(async move {
-                start_grpc_server(grpc_addr).await.context("gRPC 서버 시작 실패")
-            });
-            handles.push(handle);
+            handles.extend(self.spawn_server("grpc").map(|handle| ("grpc", handle)));
         }
 
         // TCP 서버 시작
         if self.config.enable_tcp {
             info!("🔌 TCP 서버 시작 중... ({})", self.config.tcp_address);
-            let tcp_addr = self.config.tcp_address;
-            let handle = tokio::spawn(async move {
-                Self::start_tcp_server(tcp_addr).await.context("TCP 서버 시작 실패")
-            });
-            handles.push(handle);
+            handles.extend(self.spawn_server("tcp").map(|handle| ("tcp", handle)));
         }
 
         // RUDP 서버 시작
         if self.config.enable_rudp {
             info!("📶 RUDP 서버 시작 중... ({})", self.config.rudp_address);
-            let rudp_addr = self.config.rudp_address;
-            let handle = tokio::spawn(async move {
-                Self::start_rudp_server(rudp_addr).await.context("RUDP 서버 시작 실패")
-            });
-            handles.push(handle);
+            self.launch_rudp(DeploySlot::Blue, self.config.rudp_address).await
+                .context("RUDP 서버 시작 실패")?;
         }
 
         // 성능 모니터링 시작
@@ -174,22 +275,106 @@ impl UnifiedGameServer {
             let handle = tokio::spawn(async move {
                 Self::start_monitoring().await.context("성능 모니터링 시작 실패")
             });
-            handles.push(handle);
+            handles.push(("monitoring", handle));
         }
 
         self.is_running.store(true, Ordering::SeqCst);
 
         info!("✅ 통합 게임 서버가 성공적으로 시작되었습니다!");
-        self.print_status();
+        for line in self.startup_banner().lines() {
+            info!("{}", line);
+        }
 
         Ok(())
     }
 
+    /// gRPC/TCP 서버 태스크 시작 (알 수 없는 이름이면 None)
+    fn spawn_server(&self, name: &str) -> Option<tokio::task::JoinHandle<Result<()>>> {
+        match name {
+            "grpc" => {
+                let grpc_addr = self.config.grpc_address;
+                Some(self.supervise("grpc", async move {
+                    start_grpc_server(grpc_addr).await.context("gRPC 서버 시작 실패")
+                }))
+            }
+            "tcp" => {
+                let tcp_addr = self.config.tcp_address;
+                let shutdown = ShutdownCoordinator::new("tcpserver");
+                if let Ok(mut current) = self.tcp_shutdown.lock() {
+                    *current = Some(shutdown.clone());
+                }
+                Some(self.supervise("tcp", async move {
+                    Self::start_tcp_server(tcp_addr, shutdown).await.context("TCP 서버 시작 실패")
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    /// 하위 서버 태스크 강제 종료 (카오스 테스트용, 종료한 태스크가 있으면 true)
+    ///
+    /// 감시 태스크에는 취소로 보이므로 크래시 알림은 나가지 않습니다.
+    pub async fn kill_server(&self, name: &str) -> bool {
+        if name == "rudp" {
+            let instances = self.rudp_instances.lock().await;
+            instances.iter().for_each(|instance| instance.handle.abort());
+            return !instances.is_empty();
+        }
+        let handles = self.server_handles.lock().await;
+        let mut killed = false;
+        for (_, handle) in handles.iter().filter(|(server, _)| *server == name) {
+            handle.abort();
+            killed = true;
+        }
+        killed
+    }
+
+    /// 종료된 하위 서버 태스크 재시작 (RUDP는 활성 슬롯으로 다시 시작)
+    pub async fn restart_server(&self, name: &'static str) -> Result<()> {
+        if name == "rudp" {
+            let (slot, addr) = self.active_rudp().await.unwrap_or((DeploySlot::Blue, self.config.rudp_address));
+            self.stop_rudp(slot).await;
+            return self.launch_rudp(slot, addr).await;
+        }
+        let handle = self.spawn_server(name)
+            .ok_or_else(|| anyhow::anyhow!("재시작할 수 없는 서버: {}", name))?;
+        let mut handles = self.server_handles.lock().await;
+        handles.retain(|(server, old)| {
+            if *server == name {
+                old.abort();
+            }
+            *server != name
+        });
+        handles.push((name, handle));
+        Ok(())
+    }
+
+    /// 시작 배너 (서버별 주소, 버전, 활성화 상태)
+    pub fn startup_banner(&self) -> String {
+        let rows = [
+            ("gRPC", self.config.enable_grpc, self.config.grpc_address.to_string(), grpcserver::VERSION),
+            ("TCP", self.config.enable_tcp, self.config.tcp_address.to_string(), tcpserver::VERSION),
+            ("RUDP", self.config.enable_rudp, self.config.rudp_address.to_string(), rudpserver::VERSION),
+        ];
+
+        let mut banner = format!("┌─ Police Thief GameCenter v{}\n", crate::health::VERSION);
+        for (name, enabled, address, version) in rows {
+            let state = if enabled { "활성화" } else { "비활성화" };
+            banner.push_str(&format!("│ {name:<5} {address:<21} v{version:<8} {state}\n"));
+        }
+        let monitoring = if self.config.enable_monitoring { "활성화" } else { "비활성화" };
+        banner.push_str(&format!("│ 성능 모니터링: {monitoring}\n"));
+        banner.push_str("└─ 상태 확인: gamecenter status [--json]");
+        banner
+    }
+
     /// TCP 서버 시작 (내부 구현)
-    async fn start_tcp_server(addr: SocketAddr) -> Result<()> {
+    ///
+    /// 종료가 시작되면 새 연결을 받지 않고 정리 단계를 실행한 뒤 반환합니다.
+    async fn start_tcp_server(addr: SocketAddr, shutdown: ShutdownCoordinator) -> Result<()> {
         use tokio::net::TcpListener;
         
-        let connection_service = Arc::new(ConnectionService::new(1000));
+        let connection_service = Arc::new(ConnectionService::new(1000).with_shutdown(shutdown.clone()));
         let heartbeat_service = Arc::new(HeartbeatService::with_default_config(connection_service.clone()));
         let message_service = Arc::new(MessageService::new(connection_service.clone()));
 
@@ -202,13 +387,17 @@ impl UnifiedGameServer {
         info!("🔌 TCP 서버가 {}에서 연결을 기다리고 있습니다", addr);
 
         loop {
-            match listener.accept().await {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.triggered() => break,
+            };
+            match accepted {
                 Ok((socket, peer_addr)) => {
                     info!("새 TCP 연결: {}", peer_addr);
                     let conn_service = connection_service.clone();
                     let msg_service = message_service.clone();
                     
-                    tokio::spawn(async move {
+                    shutdown.spawn("connection", async move {
                         if let Err(e) = Self::handle_tcp_connection(socket, peer_addr, conn_service, msg_service).await {
                             error!("TCP 연결 처리 오류 ({}): {}", peer_addr, e);
                         }
@@ -220,6 +409,45 @@ impl UnifiedGameServer {
                 }
             }
         }
+        drop(listener);
+
+        // 정리 단계: 쓰기 버퍼 드레인 → 하트비트 중지 → 연결 태스크 대기
+        shutdown
+            .stage("drain_writers", Duration::from_secs(5), connection_service.drain_writers())
+            .await;
+        if let Some(Err(e)) = shutdown
+            .stage("stop_heartbeat", Duration::from_secs(2), heartbeat_service.stop())
+            .await
+        {
+            warn!("TCP 하트비트 중지 실패: {}", e);
+        }
+        shutdown.drain(Duration::from_secs(5)).await;
+        shutdown.finish();
+        Ok(())
+    }
+
+    /// TCP 서버 정상 종료 (진행 중인 연결 정리가 끝났다는 확인을 받을 때까지 대기)
+    async fn shutdown_tcp(&self) {
+        let Some(shutdown) = self.tcp_shutdown.lock().ok().and_then(|mut current| current.take()) else {
+            return;
+        };
+        let running = self.server_handles.lock().await
+            .iter()
+            .any(|(server, handle)| *server == "tcp" && !handle.is_finished());
+        if !running {
+            return;
+        }
+
+        shutdown.trigger();
+        match tokio::time::timeout(TCP_SHUTDOWN_TIMEOUT, shutdown.wait_finished()).await {
+            Ok(report) => info!(
+                "🔌 TCP 서버 정상 종료 확인 (clean={}, 취소된 태스크 {}개, {}ms)",
+                report.is_clean(),
+                report.aborted_tasks,
+                report.elapsed.as_millis()
+            ),
+            Err(_) => warn!("TCP 서버 종료 확인 대기 시간 초과, 태스크를 취소합니다"),
+        }
     }
 
     /// TCP 연결 처리
@@ -257,20 +485,58 @@ impl UnifiedGameServer {
         Ok(())
     }
 
-    /// RUDP 서버 시작 (내부 구현)
-    async fn start_rudp_server(addr: SocketAddr) -> Result<()> {
-        use tokio::net::UdpSocket;
-        
+    /// RUDP 인스턴스 시작
+    ///
+    /// 포트 바인드까지 완료된 뒤 반환하므로, 성공하면 바로 새 매치를 받을 수 있습니다.
+    pub async fn launch_rudp(&self, slot: DeploySlot, addr: SocketAddr) -> Result<()> {
         let socket = UdpSocket::bind(addr).await
             .with_context(|| format!("RUDP 서버를 {}에 바인드하는데 실패했습니다", addr))?;
 
-        info!("📶 RUDP 서버가 {}에서 패킷을 기다리고 있습니다", addr);
+        info!("📶 RUDP 서버({})가 {}에서 패킷을 기다리고 있습니다", slot, addr);
 
+        let peers = Arc::new(PeerTracker::default());
+        let handle = self.supervise("rudp", Self::serve_rudp(socket, peers.clone()));
+        self.rudp_instances.lock().await.push(RudpInstance { slot, address: addr, peers, handle });
+        Ok(())
+    }
+
+    /// 새 매치를 받는 활성 RUDP 인스턴스
+    pub async fn active_rudp(&self) -> Option<(DeploySlot, SocketAddr)> {
+        let instances = self.rudp_instances.lock().await;
+        instances.last().map(|instance| (instance.slot, instance.address))
+    }
+
+    /// RUDP 인스턴스의 활성 피어 수
+    pub async fn rudp_active_peers(&self, slot: DeploySlot, idle: Duration) -> usize {
+        let instances = self.rudp_instances.lock().await;
+        instances.iter()
+            .filter(|instance| instance.slot == slot)
+            .map(|instance| instance.peers.active(idle))
+            .sum()
+    }
+
+    /// RUDP 인스턴스 중지
+    pub async fn stop_rudp(&self, slot: DeploySlot) {
+        let mut instances = self.rudp_instances.lock().await;
+        instances.retain(|instance| {
+            if instance.slot == slot {
+                instance.handle.abort();
+                info!("📶 RUDP 서버({}) 중지: {}", slot, instance.address);
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// RUDP 패킷 처리 루프 (내부 구현)
+    async fn serve_rudp(socket: UdpSocket, peers: Arc<PeerTracker>) -> Result<()> {
         let mut buffer = [0; 65536];
         
         loop {
             match socket.recv_from(&mut buffer).await {
                 Ok((size, peer_addr)) => {
+                    peers.touch(peer_addr);
                     // 간단한 에코 서버로 구현
                     if let Err(e) = socket.send_to(&buffer[..size], peer_addr).await {
                         error!("RUDP 응답 전송 실패 ({}): {}", peer_addr, e);
@@ -315,10 +581,16 @@ impl UnifiedGameServer {
 
         self.is_running.store(false, Ordering::SeqCst);
 
+        // TCP는 연결 정리가 끝날 때까지 기다린 뒤 나머지를 중지
+        self.shutdown_tcp().await;
+
         let mut handles = self.server_handles.lock().await;
-        for handle in handles.drain(..) {
+        for (_, handle) in handles.drain(..) {
             handle.abort();
         }
+        for instance in self.rudp_instances.lock().await.drain(..) {
+            instance.handle.abort();
+        }
 
         info!("✅ 통합 게임 서버가 성공적으로 중지되었습니다!");
         Ok(())
@@ -353,27 +625,13 @@ impl UnifiedGameServer {
 
     /// 서버가 완전히 종료될 때까지 대기
     pub async fn wait_for_shutdown(&self) -> Result<()> {
-        let handles = self.server_handles.clone();
-        let handles_guard = handles.lock().await;
+        let has_servers = !self.server_handles.lock().await.is_empty();
+        let has_rudp = !self.rudp_instances.lock().await.is_empty();
         
-        if !handles_guard.is_empty() {
-            // 모든 핸들을 소유권으로 가져와서 사용
-            let mut owned_handles = Vec::new();
-            for handle in handles_guard.iter() {
-                // 핸들을 abortable로 만들어서 나중에 중단할 수 있도록 함
-                owned_handles.push(handle.abort_handle());
-            }
-            drop(handles_guard); // 락 해제
-
-            // 첫 번째 핸들의 완료를 대기하거나 중단 신호 대기
-            tokio::select! {
-                _ = tokio::signal::ctrl_c() => {
-                    info!("종료 신호를 받았습니다. 모든 서버를 중지합니다.");
-                    for abort_handle in owned_handles {
-                        abort_handle.abort();
-                    }
-                }
-            }
+        if has_servers || has_rudp {
+            // 하위 서버를 바로 취소하지 않고 `stop`에서 순서대로 정리
+            tokio::signal::ctrl_c().await?;
+            info!("종료 신호를 받았습니다. 모든 서버를 중지합니다.");
         }
 
         self.stop().await
@@ -484,4 +742,31 @@ mod tests {
 
         // Note: 실제 시작은 테스트에서 생략 (포트 충돌 방지)
     }
+
+    #[tokio::test]
+    async fn test_rudp_blue_green_swap() {
+        let server = UnifiedGameServer::new(UnifiedServerConfig::default());
+        let any_port: SocketAddr = "127.0.0.1:0".parse().unwrap();
+
+        server.launch_rudp(DeploySlot::Blue, any_port).await.unwrap();
+        server.launch_rudp(DeploySlot::Green, any_port).await.unwrap();
+        assert_eq!(server.active_rudp().await.map(|(slot, _)| slot), Some(DeploySlot::Green));
+        assert_eq!(server.rudp_active_peers(DeploySlot::Blue, Duration::from_secs(15)).await, 0);
+
+        server.stop_rudp(DeploySlot::Blue).await;
+        assert_eq!(server.rudp_instances.lock().await.len(), 1);
+        assert_eq!(server.active_rudp().await.map(|(slot, _)| slot), Some(DeploySlot::Green));
+
+        server.stop_rudp(DeploySlot::Green).await;
+        assert!(server.active_rudp().await.is_none());
+    }
+
+    #[test]
+    fn test_peer_tracker_idle() {
+        let tracker = PeerTracker::default();
+        tracker.touch("127.0.0.1:7000".parse().unwrap());
+        tracker.touch("127.0.0.1:7001".parse().unwrap());
+        assert_eq!(tracker.active(Duration::from_secs(60)), 2);
+        assert_eq!(tracker.active(Duration::ZERO), 0);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,331 @@
+//! 생명주기 이벤트 웹훅 알림
+//!
+//! 게임센터 시작/중지, 하위 서버 크래시, 블루/그린 재시작, 헬스 체크 실패를
+//! Slack 또는 Discord 호환 웹훅으로 보냅니다.
+//!
+//! 같은 이벤트(종류 + 대상)는 중복 제거 시간 안에 한 번만 보내며,
+//! 최소 심각도보다 낮은 이벤트는 보내지 않습니다. 전송은 백그라운드 태스크에서 처리하므로
+//! `notify`는 호출한 쪽을 기다리게 하지 않습니다.
+//!
+//! 환경변수:
+//! - `WEBHOOK_URL`: 웹훅 주소 (없으면 알림 비활성화)
+//! - `WEBHOOK_FORMAT`: `slack` 또는 `discord` (기본값: 주소로 판별)
+//! - `WEBHOOK_MIN_SEVERITY`: `info`, `warning`, `critical` (기본값: `info`)
+//! - `WEBHOOK_DEDUP_SECS`: 중복 제거 시간 (기본값: 300초)
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// 전송 타임아웃
+const SEND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 전송 실패 시 재시도 횟수
+const SEND_RETRIES: u32 = 2;
+
+/// 알림 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "info" => Some(Severity::Info),
+            "warning" | "warn" => Some(Severity::Warning),
+            "critical" | "error" => Some(Severity::Critical),
+            _ => None,
+        }
+    }
+
+    fn emoji(&self) -> &'static str {
+        match self {
+            Severity::Info => "ℹ️",
+            Severity::Warning => "⚠️",
+            Severity::Critical => "🚨",
+        }
+    }
+
+    /// 메시지 색상 (RGB)
+    fn color(&self) -> u32 {
+        match self {
+            Severity::Info => 0x2E_B8_86,
+            Severity::Warning => 0xDA_A0_38,
+            Severity::Critical => 0xD0_3A_3A,
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => f.write_str("INFO"),
+            Severity::Warning => f.write_str("WARNING"),
+            Severity::Critical => f.write_str("CRITICAL"),
+        }
+    }
+}
+
+/// 생명주기 이벤트 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    Started,
+    Stopped,
+    Crashed,
+    Restarted,
+    HealthCheckFailed,
+    HealthRecovered,
+}
+
+impl EventKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Started => "started",
+            EventKind::Stopped => "stopped",
+            EventKind::Crashed => "crashed",
+            EventKind::Restarted => "restarted",
+            EventKind::HealthCheckFailed => "health_check_failed",
+            EventKind::HealthRecovered => "health_recovered",
+        }
+    }
+}
+
+/// 웹훅으로 보낼 이벤트
+#[derive(Debug, Clone)]
+pub struct LifecycleEvent {
+    pub kind: EventKind,
+    pub severity: Severity,
+    /// 대상 (예: "gamecenter", "rudp")
+    pub source: String,
+    pub title: String,
+    pub detail: String,
+}
+
+impl LifecycleEvent {
+    pub fn new(kind: EventKind, severity: Severity, source: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            kind,
+            severity,
+            source: source.into(),
+            title: title.into(),
+            detail: String::new(),
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = detail.into();
+        self
+    }
+
+    /// 중복 제거 키 (종류 + 대상)
+    fn dedup_key(&self) -> String {
+        format!("{}:{}", self.kind.as_str(), self.source)
+    }
+}
+
+/// 웹훅 페이로드 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadFormat {
+    Slack,
+    Discord,
+}
+
+impl PayloadFormat {
+    /// 이벤트를 웹훅 본문으로 변환
+    pub fn payload(&self, event: &LifecycleEvent, host: &str) -> Value {
+        let title = format!("{} [{}] {}", event.severity.emoji(), event.severity, event.title);
+        let footer = format!("{} · {} · {}", host, event.source, event.kind.as_str());
+        match self {
+            PayloadFormat::Slack => json!({
+                "text": title,
+                "attachments": [{
+                    "color": format!("#{:06X}", event.severity.color()),
+                    "text": event.detail,
+                    "footer": footer,
+                    "ts": chrono::Utc::now().timestamp(),
+                }],
+            }),
+            PayloadFormat::Discord => json!({
+                "content": title,
+                "embeds": [{
+                    "description": event.detail,
+                    "color": event.severity.color(),
+                    "footer": { "text": footer },
+                    "timestamp": chrono::Utc::now().to_rfc3339(),
+                }],
+            }),
+        }
+    }
+}
+
+/// 웹훅 설정
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+    pub format: PayloadFormat,
+    pub min_severity: Severity,
+    pub dedup_window: Duration,
+}
+
+impl WebhookConfig {
+    /// 환경변수에서 설정 로드 (`WEBHOOK_URL`이 없으면 None)
+    pub fn from_env() -> Option<Self> {
+        let url = std::env::var("WEBHOOK_URL").ok().filter(|url| !url.trim().is_empty())?;
+        let format = match std::env::var("WEBHOOK_FORMAT").ok().as_deref().map(str::to_ascii_lowercase).as_deref() {
+            Some("discord") => PayloadFormat::Discord,
+            Some("slack") => PayloadFormat::Slack,
+            _ if url.contains("discord") => PayloadFormat::Discord,
+            _ => PayloadFormat::Slack,
+        };
+
+        Some(Self {
+            url,
+            format,
+            min_severity: std::env::var("WEBHOOK_MIN_SEVERITY").ok()
+                .and_then(|v| Severity::parse(&v))
+                .unwrap_or(Severity::Info),
+            dedup_window: Duration::from_secs(
+                std::env::var("WEBHOOK_DEDUP_SECS").ok().and_then(|v| v.parse().ok()).unwrap_or(300),
+            ),
+        })
+    }
+}
+
+/// 웹훅 알림기 (복제해서 공유)
+#[derive(Clone)]
+pub struct WebhookNotifier {
+    config: Arc<WebhookConfig>,
+    /// 이벤트별 마지막 전송 시각
+    recent: Arc<Mutex<HashMap<String, Instant>>>,
+    sender: mpsc::UnboundedSender<LifecycleEvent>,
+}
+
+impl WebhookNotifier {
+    /// 알림기 생성 및 전송 태스크 시작
+    pub fn spawn(config: WebhookConfig) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let config = Arc::new(config);
+        tokio::spawn(Self::deliver(config.clone(), receiver));
+        Self {
+            config,
+            recent: Arc::new(Mutex::new(HashMap::new())),
+            sender,
+        }
+    }
+
+    /// 환경변수 설정으로 알림기 생성 (설정이 없으면 None)
+    pub fn from_env() -> Option<Self> {
+        WebhookConfig::from_env().map(Self::spawn)
+    }
+
+    /// 이벤트 알림 (심각도 미달이거나 중복이면 무시)
+    pub fn notify(&self, event: LifecycleEvent) {
+        if !self.should_send(&event) {
+            debug!("웹훅 알림 생략: {}", event.dedup_key());
+            return;
+        }
+        if self.sender.send(event).is_err() {
+            warn!("웹훅 전송 태스크가 종료되어 알림을 보낼 수 없습니다");
+        }
+    }
+
+    fn should_send(&self, event: &LifecycleEvent) -> bool {
+        if event.severity < self.config.min_severity {
+            return false;
+        }
+
+        let Ok(mut recent) = self.recent.lock() else {
+            return true;
+        };
+        let window = self.config.dedup_window;
+        recent.retain(|_, sent| sent.elapsed() < window);
+
+        let key = event.dedup_key();
+        if recent.contains_key(&key) {
+            return false;
+        }
+        recent.insert(key, Instant::now());
+        true
+    }
+
+    /// 백그라운드 전송 루프
+    async fn deliver(config: Arc<WebhookConfig>, mut receiver: mpsc::UnboundedReceiver<LifecycleEvent>) {
+        let client = match reqwest::Client::builder().timeout(SEND_TIMEOUT).build() {
+            Ok(client) => client,
+            Err(e) => {
+                warn!("웹훅 HTTP 클라이언트 생성 실패: {}", e);
+                return;
+            }
+        };
+        let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "gamecenter".to_string());
+
+        while let Some(event) = receiver.recv().await {
+            let payload = config.format.payload(&event, &host);
+            for attempt in 0..=SEND_RETRIES {
+                match client.post(&config.url).json(&payload).send().await {
+                    Ok(response) if response.status().is_success() => break,
+                    Ok(response) => warn!("웹훅 전송 실패 ({}): HTTP {}", event.dedup_key(), response.status()),
+                    Err(e) => warn!("웹훅 전송 실패 ({}): {}", event.dedup_key(), e),
+                }
+                if attempt < SEND_RETRIES {
+                    tokio::time::sleep(Duration::from_secs(1 << attempt)).await;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notifier(min_severity: Severity, dedup_secs: u64) -> WebhookNotifier {
+        let (sender, _receiver) = mpsc::unbounded_channel();
+        WebhookNotifier {
+            config: Arc::new(WebhookConfig {
+                url: "http://localhost/hook".to_string(),
+                format: PayloadFormat::Slack,
+                min_severity,
+                dedup_window: Duration::from_secs(dedup_secs),
+            }),
+            recent: Arc::new(Mutex::new(HashMap::new())),
+            sender,
+        }
+    }
+
+    #[test]
+    fn test_dedup_and_severity() {
+        let notifier = notifier(Severity::Warning, 300);
+        let crash = LifecycleEvent::new(EventKind::Crashed, Severity::Critical, "rudp", "RUDP 서버 크래시");
+
+        assert!(!notifier.should_send(&LifecycleEvent::new(EventKind::Started, Severity::Info, "gamecenter", "시작")));
+        assert!(notifier.should_send(&crash));
+        assert!(!notifier.should_send(&crash));
+        // 대상이 다르면 별개 이벤트
+        assert!(notifier.should_send(&LifecycleEvent::new(EventKind::Crashed, Severity::Critical, "tcp", "TCP 서버 크래시")));
+    }
+
+    #[test]
+    fn test_payload_formats() {
+        let event = LifecycleEvent::new(EventKind::Crashed, Severity::Critical, "rudp", "RUDP 서버 크래시")
+            .with_detail("bind failed");
+
+        let slack = PayloadFormat::Slack.payload(&event, "host-1");
+        assert!(slack["text"].as_str().unwrap().contains("[CRITICAL] RUDP 서버 크래시"));
+        assert_eq!(slack["attachments"][0]["color"], "#D03A3A");
+        assert_eq!(slack["attachments"][0]["text"], "bind failed");
+
+        let discord = PayloadFormat::Discord.payload(&event, "host-1");
+        assert_eq!(discord["embeds"][0]["color"], 0xD0_3A_3A);
+        assert_eq!(discord["embeds"][0]["footer"]["text"], "host-1 · rudp · crashed");
+    }
+}
@@ -1,6 +1,21 @@
 // build.rs
+use std::path::PathBuf;
+
+const PROTOS: &[&str] = &[
+    "proto/room.proto",
+    "proto/user.proto",
+    "proto/moderation.proto",
+    "proto/stats.proto",
+    "proto/version.proto",
+    "proto/experiment.proto",
+    "proto/liveops.proto",
+];
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tonic_build::compile_protos("proto/room.proto")?;
-    tonic_build::compile_protos("proto/user.proto")?;
+    // 클라이언트용 API 스키마 생성(`--schema`)에 쓰는 디스크립터도 함께 출력
+    let descriptor_path = PathBuf::from(std::env::var("OUT_DIR")?).join("api_descriptor.bin");
+    tonic_build::configure()
+        .file_descriptor_set_path(descriptor_path)
+        .compile(PROTOS, &["proto"])?;
     Ok(())
 }
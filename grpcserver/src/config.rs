@@ -0,0 +1,481 @@
+//! gRPC Server Configuration Profiles
+//!
+//! 실행 환경(dev/staging/prod)별 기본값과 환경변수 기반 서버 설정을 담당합니다.
+//! 프로필은 `--profile <name>` 인자 또는 `grpc_profile` 환경변수로 지정하며,
+//! 모의 서비스 사용 여부와 Redis/DB/TLS 필수 여부 같은 기본값을 결정합니다.
+//! 프로필을 지정하지 않으면 모의 서비스와 `test` 로그인이 열리지 않도록 prod로 시작합니다.
+
+use anyhow::{anyhow, Result};
+use std::{collections::HashMap, env, fmt, net::SocketAddr, path::PathBuf, str::FromStr};
+use tracing::{info, warn};
+
+use crate::service::avatar_service::{AvatarConfig, AvatarStorageKind};
+use crate::service::avatar_storage::S3Config;
+use crate::tool::canary::CanaryFlag;
+use crate::tool::version_gate::{ClientVersion, VersionPolicy};
+use shared::tool::high_performance::{CompressionAlgorithm, MessageCompressionConfig, ParallelProcessingConfig};
+
+/// 스토어 URL을 설정할 수 있는 클라이언트 플랫폼
+const CLIENT_PLATFORMS: [&str; 3] = ["ios", "android", "windows"];
+
+/// 실행 프로필
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// 로컬 개발 환경
+    Dev,
+    /// 스테이징 환경
+    Staging,
+    /// 운영 환경
+    Prod,
+}
+
+impl Profile {
+    /// 프로필 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Profile::Dev => "dev",
+            Profile::Staging => "staging",
+            Profile::Prod => "prod",
+        }
+    }
+
+    /// 프로필별 기본값
+    pub fn defaults(&self) -> ProfileDefaults {
+        match self {
+            Profile::Dev => ProfileDefaults {
+                use_mock_services: true,
+                require_redis: false,
+                require_database: false,
+                require_tls: false,
+            },
+            Profile::Staging => ProfileDefaults {
+                use_mock_services: false,
+                require_redis: true,
+                require_database: true,
+                require_tls: false,
+            },
+            Profile::Prod => ProfileDefaults {
+                use_mock_services: false,
+                require_redis: true,
+                require_database: true,
+                require_tls: true,
+            },
+        }
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Profile::Prod
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Profile {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "dev" | "development" => Ok(Profile::Dev),
+            "staging" | "stage" => Ok(Profile::Staging),
+            "prod" | "production" => Ok(Profile::Prod),
+            other => Err(anyhow!("알 수 없는 프로필 '{other}' (dev, staging, prod 중 하나)")),
+        }
+    }
+}
+
+/// 프로필별 기본값
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProfileDefaults {
+    /// 모의 서비스 사용 (테스트 로그인 허용 등)
+    pub use_mock_services: bool,
+    /// Redis 연결 실패 시 기동 중단
+    pub require_redis: bool,
+    /// DB 연결 실패 시 준비 상태 실패로 판정
+    pub require_database: bool,
+    /// TLS 인증서 설정 필수
+    pub require_tls: bool,
+}
+
+/// 명령행 인자
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CliArgs {
+    /// `--check`: 자체 점검 후 종료
+    pub check: bool,
+    /// `--profile <name>`
+    pub profile: Option<Profile>,
+    /// `--schema <path>`: 클라이언트용 API 스키마 출력 후 종료 (`-`이면 표준 출력)
+    pub schema: Option<PathBuf>,
+}
+
+impl CliArgs {
+    /// 명령행 인자 파싱 (첫 번째 인자는 실행 파일 경로)
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter().skip(1);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--check" => parsed.check = true,
+                "--profile" => {
+                    let value = args.next()
+                        .ok_or_else(|| anyhow!("--profile 뒤에 프로필 이름이 필요합니다."))?;
+                    parsed.profile = Some(value.parse()?);
+                }
+                "--schema" => {
+                    let value = args.next()
+                        .ok_or_else(|| anyhow!("--schema 뒤에 출력 경로가 필요합니다."))?;
+                    parsed.schema = Some(PathBuf::from(value));
+                }
+                other => match other.strip_prefix("--profile=") {
+                    Some(value) => parsed.profile = Some(value.parse()?),
+                    None => return Err(anyhow!("알 수 없는 인자 '{other}' (사용법: grpcserver [--check] [--profile dev|staging|prod] [--schema <path>])")),
+                },
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// gRPC 서버 설정
+#[derive(Debug, Clone)]
+pub struct GrpcServerConfig {
+    pub profile: Profile,
+    /// 바인딩 주소 (`grpc_host`, `grpc_port`)
+    pub addr: SocketAddr,
+    /// 모의 서비스 사용 (`grpc_use_mock_services`)
+    pub use_mock_services: bool,
+    /// Redis 필수 여부 (`grpc_require_redis`)
+    pub require_redis: bool,
+    /// DB 필수 여부 (`grpc_require_database`)
+    pub require_database: bool,
+    /// TLS 필수 여부 (`grpc_require_tls`)
+    pub require_tls: bool,
+    /// TLS 인증서 경로 (`grpc_tls_cert`)
+    pub tls_cert_path: Option<PathBuf>,
+    /// TLS 개인키 경로 (`grpc_tls_key`)
+    pub tls_key_path: Option<PathBuf>,
+    /// 클라이언트 버전 정책
+    pub version_policy: VersionPolicy,
+    /// `/healthz`, `/readyz` 주소 (`grpc_health_port`, 없으면 비활성화)
+    pub health_addr: Option<SocketAddr>,
+    /// 서비스 토큰이 있어야 호출할 수 있는 내부 서비스 (`grpc_internal_services`, 예: "stats,moderation")
+    pub internal_services: Vec<String>,
+    /// 실제 RoomService 카나리 배포 비율 (`grpc_room_canary_percent`, 0~100, 나머지는 `use_mock_services`를 따름)
+    pub room_canary: CanaryFlag,
+    /// 아바타 업로드 (`grpc_avatar_*`)
+    pub avatar: AvatarConfig,
+    /// 실험(A/B 테스트) 목록 파일 (`grpc_experiments_file`, 없으면 실험 없음)
+    pub experiments_file: PathBuf,
+    /// 로케일별 에러 메시지 카탈로그 (`grpc_error_messages_file`, 없으면 빌드에 포함된 카탈로그)
+    pub error_messages_file: PathBuf,
+    /// 응답 압축 (`grpc_compression`=off|zstd|adaptive, `grpc_compression_threshold`, `grpc_compression_level`)
+    pub compression: MessageCompressionConfig,
+    /// CPU 집약 작업 풀 (`grpc_cpu_workers`, `grpc_cpu_queue_size`)
+    pub parallel: ParallelProcessingConfig,
+}
+
+impl GrpcServerConfig {
+    /// 환경변수에서 설정 로드
+    ///
+    /// 프로필 기본값을 먼저 적용하고, 개별 환경변수가 있으면 덮어씁니다.
+    ///
+    /// # Arguments
+    /// * `profile` - 명령행에서 지정한 프로필 (없으면 `grpc_profile`, 기본 prod)
+    pub fn from_env(profile: Option<Profile>) -> Result<Self> {
+        let profile = match profile {
+            Some(profile) => profile,
+            None => match env::var("grpc_profile") {
+                Ok(value) => value.parse()?,
+                Err(_) => {
+                    warn!(
+                        "⚠️ 실행 프로필이 지정되지 않아 {} 프로필로 시작합니다 (--profile 또는 grpc_profile로 지정)",
+                        Profile::default()
+                    );
+                    Profile::default()
+                }
+            },
+        };
+        let defaults = profile.defaults();
+
+        let host = env::var("grpc_host")
+            .map_err(|_| anyhow!("환경변수 'grpc_host'가 설정되지 않았습니다. .env 파일을 확인하세요."))?;
+        let port = env::var("grpc_port")
+            .map_err(|_| anyhow!("환경변수 'grpc_port'가 설정되지 않았습니다. .env 파일을 확인하세요."))?;
+        let addr: SocketAddr = format!("{host}:{port}")
+            .parse()
+            .map_err(|e| anyhow!("잘못된 주소 형식 '{host}:{port}': {e}"))?;
+        let health_addr = match env::var("grpc_health_port") {
+            Ok(health_port) => Some(
+                format!("{host}:{health_port}")
+                    .parse()
+                    .map_err(|e| anyhow!("잘못된 헬스 주소 '{host}:{health_port}': {e}"))?,
+            ),
+            Err(_) => None,
+        };
+
+        Ok(Self {
+            profile,
+            addr,
+            use_mock_services: env_bool("grpc_use_mock_services", defaults.use_mock_services)?,
+            require_redis: env_bool("grpc_require_redis", defaults.require_redis)?,
+            require_database: env_bool("grpc_require_database", defaults.require_database)?,
+            require_tls: env_bool("grpc_require_tls", defaults.require_tls)?,
+            tls_cert_path: env::var("grpc_tls_cert").ok().map(PathBuf::from),
+            tls_key_path: env::var("grpc_tls_key").ok().map(PathBuf::from),
+            version_policy: VersionPolicy {
+                min_version: env_version("grpc_min_client_version")?,
+                recommended_version: env_version("grpc_recommended_client_version")?,
+                store_urls: CLIENT_PLATFORMS.iter()
+                    .filter_map(|platform| {
+                        env::var(format!("grpc_store_url_{platform}")).ok()
+                            .map(|url| (platform.to_string(), url))
+                    })
+                    .collect::<HashMap<_, _>>(),
+                reject_unversioned: env_bool("grpc_reject_unversioned_clients", false)?,
+            },
+            health_addr,
+            internal_services: env::var("grpc_internal_services")
+                .map(|value| value.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+                .unwrap_or_default(),
+            room_canary: CanaryFlag::new("room", env_percent("grpc_room_canary_percent")?),
+            avatar: avatar_config_from_env()?,
+            experiments_file: env::var("grpc_experiments_file")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from("property/experiments.toml")),
+            error_messages_file: env::var("grpc_error_messages_file")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from("property/error_messages.toml")),
+            compression: compression_config_from_env()?,
+            parallel: parallel_config_from_env()?,
+        })
+    }
+
+    /// 서비스 토큰이 필요한 내부 서비스인지 여부
+    pub fn is_internal(&self, service: &str) -> bool {
+        self.internal_services.iter().any(|name| name == service)
+    }
+
+    /// 설정 요약 로그
+    pub fn log_summary(&self) {
+        info!("⚙️ gRPC 서버 프로필: {}", self.profile);
+        if self.profile == Profile::Dev {
+            warn!("⚠️ dev 프로필로 실행 중입니다 - 모의 서비스와 test 로그인이 허용되므로 운영 환경에서 사용하지 마세요");
+        }
+        info!("  └─ 주소: {}", self.addr);
+        if let Some(health_addr) = self.health_addr {
+            info!("  └─ 헬스 엔드포인트: {}", health_addr);
+        }
+        info!("  └─ 모의 서비스: {}", self.use_mock_services);
+        info!("  └─ Redis 필수: {}, DB 필수: {}, TLS 필수: {}",
+              self.require_redis, self.require_database, self.require_tls);
+        let policy = &self.version_policy;
+        if policy.is_enabled() {
+            info!("  └─ 클라이언트 버전: 최소 {}, 권장 {}",
+                  policy.min_version.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string()),
+                  policy.recommended_version.map(|v| v.to_string()).unwrap_or_else(|| "-".to_string()));
+        }
+        if !self.internal_services.is_empty() {
+            info!("  └─ 내부 전용 서비스: {}", self.internal_services.join(", "));
+        }
+        let avatar_storage = match &self.avatar.storage {
+            AvatarStorageKind::Local { dir } => format!("local ({dir})"),
+            AvatarStorageKind::S3(s3) => format!("s3 ({}/{})", s3.endpoint, s3.bucket),
+        };
+        info!("  └─ 아바타 저장소: {}, CDN: {}", avatar_storage, self.avatar.cdn_base_url);
+        if self.room_canary.percent > 0 {
+            info!("  └─ RoomService 카나리: {}%", self.room_canary.percent);
+        }
+        info!("  └─ 응답 압축: {:?} ({}바이트 이상, 레벨 {}), CPU 작업 스레드: {}",
+              self.compression.algorithm, self.compression.compression_threshold,
+              self.compression.compression_level, self.parallel.worker_threads);
+    }
+}
+
+fn env_bool(key: &str, default: bool) -> Result<bool> {
+    match env::var(key) {
+        Ok(value) => match value.to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Ok(true),
+            "0" | "false" | "no" | "off" => Ok(false),
+            _ => Err(anyhow!("환경변수 '{key}'의 값 '{value}'은(는) true/false 여야 합니다.")),
+        },
+        Err(_) => Ok(default),
+    }
+}
+
+/// 아바타 업로드 설정 (`grpc_avatar_storage`가 `s3`이면 S3 설정 필수)
+pub fn avatar_config_from_env() -> Result<AvatarConfig> {
+    let defaults = AvatarConfig::default();
+    let parse = |key: &str| -> Result<Option<u64>> {
+        match env::var(key) {
+            Ok(value) => value.trim().parse().map(Some)
+                .map_err(|_| anyhow!("환경변수 '{key}'의 값 '{value}'은(는) 정수여야 합니다.")),
+            Err(_) => Ok(None),
+        }
+    };
+    let required = |key: &str| env::var(key)
+        .map_err(|_| anyhow!("grpc_avatar_storage=s3에는 환경변수 '{key}'가 필요합니다."));
+
+    let storage = match env::var("grpc_avatar_storage").unwrap_or_else(|_| "local".to_string()).to_ascii_lowercase().as_str() {
+        "local" => AvatarStorageKind::Local {
+            dir: env::var("grpc_avatar_dir").unwrap_or_else(|_| "data/avatars".to_string()),
+        },
+        "s3" => AvatarStorageKind::S3(S3Config {
+            endpoint: required("grpc_avatar_s3_endpoint")?,
+            bucket: required("grpc_avatar_s3_bucket")?,
+            region: env::var("grpc_avatar_s3_region").unwrap_or_else(|_| "us-east-1".to_string()),
+            access_key: required("grpc_avatar_s3_access_key")?,
+            secret_key: required("grpc_avatar_s3_secret_key")?,
+        }),
+        other => return Err(anyhow!("알 수 없는 아바타 저장소 '{other}' (local, s3)")),
+    };
+
+    Ok(AvatarConfig {
+        storage,
+        max_bytes: parse("grpc_avatar_max_bytes")?.map_or(defaults.max_bytes, |n| n as usize),
+        max_dimension: parse("grpc_avatar_max_dimension")?.map_or(defaults.max_dimension, |n| n as u32),
+        cdn_base_url: env::var("grpc_avatar_cdn_url").unwrap_or(defaults.cdn_base_url),
+    })
+}
+
+/// 정수 환경변수 (없으면 기본값)
+fn env_usize(key: &str, default: usize) -> Result<usize> {
+    match env::var(key) {
+        Ok(value) => value.trim().parse()
+            .map_err(|_| anyhow!("환경변수 '{key}'의 값 '{value}'은(는) 정수여야 합니다.")),
+        Err(_) => Ok(default),
+    }
+}
+
+/// 응답 압축 설정 (gRPC 표준 인코딩이 있는 zstd, gzip만 사용)
+fn compression_config_from_env() -> Result<MessageCompressionConfig> {
+    let algorithm = match env::var("grpc_compression").unwrap_or_else(|_| "adaptive".to_string()).to_ascii_lowercase().as_str() {
+        "off" | "none" => CompressionAlgorithm::None,
+        "zstd" => CompressionAlgorithm::Zstd,
+        "adaptive" => CompressionAlgorithm::Adaptive,
+        other => return Err(anyhow!("알 수 없는 응답 압축 방식 '{other}' (off, zstd, adaptive)")),
+    };
+    let compression_level = env_usize("grpc_compression_level", 3)?;
+    if !(1..=9).contains(&compression_level) {
+        return Err(anyhow!("환경변수 'grpc_compression_level'은(는) 1~9 사이여야 합니다."));
+    }
+
+    Ok(MessageCompressionConfig {
+        algorithm,
+        compression_threshold: env_usize("grpc_compression_threshold", 512)?,
+        compression_level: compression_level as i32,
+        // 배칭/캐시는 스트림 메시지 단위 응답에 쓰지 않음
+        enable_batching: false,
+        enable_compression_cache: false,
+        ..MessageCompressionConfig::default()
+    })
+}
+
+/// CPU 집약 작업 풀 설정 (기본: 코어 수)
+fn parallel_config_from_env() -> Result<ParallelProcessingConfig> {
+    let cores = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+    let worker_threads = env_usize("grpc_cpu_workers", cores)?;
+    if worker_threads == 0 {
+        return Err(anyhow!("환경변수 'grpc_cpu_workers'은(는) 1 이상이어야 합니다."));
+    }
+
+    Ok(ParallelProcessingConfig {
+        worker_threads,
+        work_queue_size: env_usize("grpc_cpu_queue_size", 256)?,
+        ..ParallelProcessingConfig::default()
+    })
+}
+
+fn env_percent(key: &str) -> Result<u8> {
+    match env::var(key) {
+        Ok(value) => match value.trim().parse::<u8>() {
+            Ok(percent) if percent <= 100 => Ok(percent),
+            _ => Err(anyhow!("환경변수 '{key}'의 값 '{value}'은(는) 0~100 사이 정수여야 합니다.")),
+        },
+        Err(_) => Ok(0),
+    }
+}
+
+fn env_version(key: &str) -> Result<Option<ClientVersion>> {
+    match env::var(key) {
+        Ok(value) if !value.trim().is_empty() => value.parse()
+            .map(Some)
+            .map_err(|e| anyhow!("환경변수 '{key}': {e}")),
+        _ => Ok(None),
+    }
+}
+
+/// JWT 보안 설정 검증 함수
+///
+/// 프로덕션 환경에서 안전한 JWT 설정을 보장합니다.
+///
+/// # Returns
+/// * `Result<()>` - 검증 성공 시 Ok(()), 실패 시 Error
+pub fn validate_jwt_security_config() -> Result<()> {
+    // JWT_SECRET_KEY 필수 검증
+    let jwt_secret = env::var("JWT_SECRET_KEY")
+        .map_err(|_| anyhow!(
+            "🚨 SECURITY ERROR: JWT_SECRET_KEY environment variable is required.\n\
+             Please set a cryptographically secure random key of at least 32 characters.\n\
+             Example: openssl rand -hex 32"
+        ))?;
+
+    // 보안 검증: 최소 32자 이상의 시크릿 키 요구
+    if jwt_secret.len() < 32 {
+        return Err(anyhow!(
+            "🚨 SECURITY ERROR: JWT_SECRET_KEY must be at least 32 characters long.\n\
+             Current length: {}. Please generate a stronger key.\n\
+             Example: openssl rand -hex 32",
+            jwt_secret.len()
+        ));
+    }
+
+    // 보안 검증: 약한 기본값 사용 방지
+    let lower_secret = jwt_secret.to_lowercase();
+    if lower_secret.contains("default") ||
+       lower_secret.contains("secret") ||
+       lower_secret.contains("change") ||
+       lower_secret.contains("your_") ||
+       lower_secret.contains("please") ||
+       lower_secret.contains("example") {
+        return Err(anyhow!(
+            "🚨 SECURITY ERROR: JWT_SECRET_KEY appears to contain default/weak values.\n\
+             Please use a cryptographically secure random key.\n\
+             Example: openssl rand -hex 32"
+        ));
+    }
+
+    // JWT 알고리즘 설정 확인
+    let jwt_algorithm = env::var("JWT_ALGORITHM").unwrap_or_else(|_| {
+        info!("ℹ️ JWT_ALGORITHM not set, using default 'HS256'");
+        "HS256".to_string()
+    });
+
+    // 지원되는 알고리즘 검증
+    match jwt_algorithm.as_str() {
+        "HS256" | "HS384" | "HS512" => {
+            info!("✅ JWT algorithm '{}' is supported", jwt_algorithm);
+        }
+        _ => {
+            return Err(anyhow!(
+                "🚨 SECURITY ERROR: Unsupported JWT algorithm '{}'. \n\
+                 Supported algorithms: HS256, HS384, HS512",
+                jwt_algorithm
+            ));
+        }
+    }
+
+    // 보안 설정 로그 (시크릿 키는 길이만 표시)
+    info!("🔐 JWT Security Configuration:");
+    info!("  └─ Algorithm: {}", jwt_algorithm);
+    info!("  └─ Secret Key Length: {} characters", jwt_secret.len());
+    info!("  └─ Security Level: ✅ SECURE");
+
+    Ok(())
+}
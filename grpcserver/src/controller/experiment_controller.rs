@@ -0,0 +1,77 @@
+//! Experiment Service gRPC Controller
+//!
+//! 실험(A/B 테스트) 변형 배정 조회를 제공하는 gRPC 컨트롤러입니다.
+//! JWT가 있으면 토큰의 사용자와 요청의 user_id가 같아야 합니다.
+
+use tonic::{Request, Response, Status};
+use tracing::info;
+use crate::experiment::{
+    experiment_service_server::ExperimentService,
+    Assignment, GetAssignmentsRequest, GetAssignmentsResponse, VariantParam,
+};
+use crate::service::experiment_service::ExperimentService as ExperimentSvc;
+use shared::service::TokenService;
+use shared::tool::error::AppError;
+use shared::tool::{ErrorCode, GameServerError};
+
+/// Experiment Service gRPC 컨트롤러
+pub struct ExperimentController {
+    /// 배정 비즈니스 로직을 처리하는 서비스
+    svc: ExperimentSvc,
+    /// JWT 토큰 검증 서비스
+    token_service: TokenService,
+}
+
+impl ExperimentController {
+    /// 새로운 ExperimentController 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `svc` - 배정 비즈니스 로직을 처리하는 ExperimentService 인스턴스
+    /// * `token_service` - JWT 토큰 검증 서비스
+    pub fn new(svc: ExperimentSvc, token_service: TokenService) -> Self {
+        Self { svc, token_service }
+    }
+}
+
+#[tonic::async_trait]
+impl ExperimentService for ExperimentController {
+    /// 실험 배정 조회 요청을 처리합니다.
+    ///
+    /// # Arguments
+    /// * `req` - 사용자 ID가 포함된 gRPC 요청
+    ///
+    /// # Returns
+    /// * `Result<Response<GetAssignmentsResponse>, Status>` - 참여 중인 실험의 변형과 파라미터
+    async fn get_assignments(
+        &self,
+        req: Request<GetAssignmentsRequest>,
+    ) -> Result<Response<GetAssignmentsResponse>, Status> {
+        // JWT 토큰 검증 (선택적)
+        let verified_user_id = self.token_service.with_optional_auth(&Request::new(()), Ok)?;
+
+        let user_id = req.into_inner().user_id;
+        if user_id <= 0 {
+            return Err(AppError::InvalidInput("user_id must be positive".to_string()).to_status());
+        }
+        if let Some(verified) = verified_user_id {
+            if verified != user_id {
+                return Err(GameServerError::with_message(ErrorCode::PermissionDenied, "사용자 ID 불일치").into());
+            }
+        }
+
+        let assignments = self.svc.assignments(user_id as u32).await.map_err(|e| e.to_status())?;
+        info!("실험 배정 조회: user_id={}, {}개", user_id, assignments.len());
+
+        Ok(Response::new(GetAssignmentsResponse {
+            assignments: assignments.into_iter()
+                .map(|assigned| Assignment {
+                    experiment: assigned.experiment,
+                    variant: assigned.variant,
+                    params: assigned.params.into_iter()
+                        .map(|(key, value)| VariantParam { key, value })
+                        .collect(),
+                })
+                .collect(),
+        }))
+    }
+}
@@ -0,0 +1,95 @@
+//! LiveOps Service gRPC Controller
+//!
+//! 라이브 설정(킬 스위치 등) 조회/변경을 제공하는 gRPC 컨트롤러입니다.
+//! 조회는 메트릭 조회 권한, 변경은 시스템 설정 권한(관리자 이상)이 있는 요청만 처리합니다.
+
+use tonic::{Request, Response, Status};
+use tracing::info;
+use crate::liveops::{
+    live_ops_service_server::LiveOpsService,
+    Flag, ListFlagsRequest, ListFlagsResponse, SetFlagRequest, SetFlagResponse,
+};
+use crate::service::liveops_service::LiveOpsService as LiveOpsSvc;
+use crate::tool::role_guard::RoleGuard;
+use shared::security::{ApiEndpoint, SecurityError};
+
+/// LiveOps Service gRPC 컨트롤러
+pub struct LiveOpsController {
+    /// 라이브 설정 비즈니스 로직을 처리하는 서비스
+    svc: LiveOpsSvc,
+    /// 역할 기반 접근 가드
+    guard: RoleGuard,
+}
+
+impl LiveOpsController {
+    /// 새로운 LiveOpsController 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `svc` - 라이브 설정 비즈니스 로직을 처리하는 LiveOpsService 인스턴스
+    ///
+    /// # Returns
+    /// * `Result<Self, SecurityError>` - JWT 설정이 유효하지 않으면 에러
+    pub fn new(svc: LiveOpsSvc) -> Result<Self, SecurityError> {
+        Ok(Self {
+            svc,
+            guard: RoleGuard::from_env()?,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl LiveOpsService for LiveOpsController {
+    /// 라이브 설정 조회 요청을 처리합니다.
+    ///
+    /// # Arguments
+    /// * `req` - 키 접두어가 포함된 gRPC 요청 (비어 있으면 전체)
+    ///
+    /// # Returns
+    /// * `Result<Response<ListFlagsResponse>, Status>` - 키 순서의 설정 목록
+    async fn list_flags(
+        &self,
+        req: Request<ListFlagsRequest>,
+    ) -> Result<Response<ListFlagsResponse>, Status> {
+        self.guard
+            .authorize(&req, &ApiEndpoint::new("liveops", "ListFlags"))
+            .await?;
+        let prefix = req.into_inner().prefix;
+
+        Ok(Response::new(ListFlagsResponse {
+            flags: self.svc.list_flags(prefix.trim())
+                .into_iter()
+                .map(|(key, value)| Flag { key, value })
+                .collect(),
+        }))
+    }
+
+    /// 라이브 설정 변경 요청을 처리합니다.
+    ///
+    /// # Arguments
+    /// * `req` - 키와 새 값(또는 삭제 플래그)이 포함된 gRPC 요청
+    ///
+    /// # Returns
+    /// * `Result<Response<SetFlagResponse>, Status>` - 변경 전후 값과 변경 시각
+    async fn set_flag(
+        &self,
+        req: Request<SetFlagRequest>,
+    ) -> Result<Response<SetFlagResponse>, Status> {
+        let operator = self.guard
+            .authorize(&req, &ApiEndpoint::new("liveops", "SetFlag"))
+            .await?;
+        let req = req.into_inner();
+        let value = (!req.clear).then_some(req.value.as_str());
+        info!("라이브 설정 변경 요청: operator={}, key={}, value={:?}", operator, req.key, value);
+
+        let result = self.svc.set_flag(&req.key, value, &operator).await.map_err(Status::from)?;
+
+        Ok(Response::new(SetFlagResponse {
+            key: result.change.key,
+            cleared: result.change.value.is_none(),
+            value: result.change.value.unwrap_or_default(),
+            had_previous: result.previous.is_some(),
+            previous_value: result.previous.unwrap_or_default(),
+            changed_at: result.change.changed_at,
+        }))
+    }
+}
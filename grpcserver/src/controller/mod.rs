@@ -1,2 +1,7 @@
 pub mod room_controller;
-pub mod user_controller;
\ No newline at end of file
+pub mod user_controller;
+pub mod moderation_controller;
+pub mod stats_controller;
+pub mod version_controller;
+pub mod experiment_controller;
+pub mod liveops_controller;
\ No newline at end of file
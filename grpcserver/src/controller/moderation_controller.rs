@@ -0,0 +1,89 @@
+//! Moderation Service gRPC Controller
+//!
+//! 관리자 대시보드의 실시간 채팅 모더레이션 스트림을 제공하는 gRPC 컨트롤러입니다.
+//! JWT 클레임의 역할로 접근을 제한하며, 모더레이터 이상만 구독할 수 있습니다.
+
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+use tracing::info;
+use crate::moderation::{
+    moderation_service_server::ModerationService,
+    ChatEvent, TailChatRequest,
+};
+use crate::service::moderation_service::{ChatFilter, ModerationService as ModerationSvc};
+use crate::tool::role_guard::RoleGuard;
+use shared::security::{ApiEndpoint, SecurityError};
+
+/// 구독자별 스트림 버퍼 크기
+const STREAM_BUFFER: usize = 64;
+
+/// Moderation Service gRPC 컨트롤러
+pub struct ModerationController {
+    /// 모더레이션 비즈니스 로직을 처리하는 서비스
+    svc: ModerationSvc,
+    /// 역할 기반 접근 가드
+    guard: RoleGuard,
+}
+
+impl ModerationController {
+    /// 새로운 ModerationController 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `svc` - 모더레이션 비즈니스 로직을 처리하는 ModerationService 인스턴스
+    ///
+    /// # Returns
+    /// * `Result<Self, SecurityError>` - JWT 설정이 유효하지 않으면 에러
+    pub fn new(svc: ModerationSvc) -> Result<Self, SecurityError> {
+        Ok(Self {
+            svc,
+            guard: RoleGuard::from_env()?,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl ModerationService for ModerationController {
+    type TailChatStream = ReceiverStream<Result<ChatEvent, Status>>;
+
+    /// 채팅 이벤트 실시간 구독을 처리합니다.
+    ///
+    /// # Arguments
+    /// * `req` - 방/사용자 필터가 포함된 gRPC 요청 (0이면 전체)
+    ///
+    /// # Returns
+    /// * `Result<Response<Self::TailChatStream>, Status>` - 개인정보가 가려진 채팅 이벤트 스트림
+    async fn tail_chat(
+        &self,
+        req: Request<TailChatRequest>,
+    ) -> Result<Response<Self::TailChatStream>, Status> {
+        let moderator = self.guard
+            .authorize(&req, &ApiEndpoint::new("moderation", "TailChat"))
+            .await?;
+        let r = req.into_inner();
+        let filter = ChatFilter::from_request(r.room_id, r.user_id);
+        info!("모더레이션 채팅 구독 시작: moderator={}, filter={:?}", moderator, filter);
+
+        let mut events = self.svc.tail_chat(filter).await.map_err(Status::from)?;
+
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+        tokio::spawn(async move {
+            while let Some(event) = events.recv().await {
+                let event = ChatEvent {
+                    room_id: event.room_id as i32,
+                    user_id: event.user_id as i32,
+                    nick_name: event.nickname,
+                    content: event.content,
+                    client_addr: event.client_addr,
+                    timestamp: event.timestamp,
+                };
+                if tx.send(Ok(event)).await.is_err() {
+                    break;
+                }
+            }
+            info!("모더레이션 채팅 구독 종료: moderator={}", moderator);
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
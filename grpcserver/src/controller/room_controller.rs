@@ -2,32 +2,31 @@
 //! 
 //! 방 생성 및 조회 기능을 담당하는 gRPC 컨트롤러입니다.
 //! RoomService trait을 구현하여 gRPC 서버에서 방 관련 요청을 처리합니다.
-//! 최적화된 싱글톤 패턴으로 RoomIdGenerator 인스턴스를 재사용합니다.
+//! 방 ID 발급과 저장은 RoomService가 담당합니다.
+//! 방 목록은 로비가 매초 폴링하므로 `etag`/`if-none-match` 검증자로 변경이 없으면 본문을 생략합니다.
 
-use std::sync::Arc;
-use tokio::sync::OnceCell;
 use tonic::{Request, Response, Status};
-use tracing::info;
+use tracing::{debug, info};
 use crate::service::room_service::RoomService as RoomSvc;
+use crate::tool::read_cache;
 use crate::room::{
     room_service_server::RoomService,
     MakeRoomRequest, MakeRoomResponse,
     GetRoomListRequest, GetRoomListResponse,
 };
 use shared::tool::error::{AppError, helpers};
+use shared::tool::{ErrorCode, GameServerError};
 use shared::service::TokenService;
 use shared::model::RoomInfo;
 use shared::tool::current_time::CurrentTime;
-use shared::tool::get_id::RoomIdGenerator;
 
-/// 최적화된 RoomIdGenerator 인스턴스 (싱글톤)
-static ROOM_ID_GENERATOR: OnceCell<Arc<RoomIdGenerator>> = OnceCell::const_new();
+/// 방 목록 응답 캐시 유효 시간 (초, 로비 폴링 주기)
+const ROOM_LIST_MAX_AGE_SECS: u64 = 1;
 
 /// Room Service gRPC 컨트롤러
 /// 
 /// 방 생성 및 조회 기능을 처리하는 컨트롤러입니다.
 /// RoomService trait을 구현하여 gRPC 요청을 비즈니스 로직으로 연결합니다.
-/// 최적화된 싱글톤 패턴으로 RoomIdGenerator의 Redis 연결을 재사용합니다.
 pub struct RoomController {
     /// 방 관련 비즈니스 로직을 처리하는 서비스
     svc: RoomSvc,
@@ -70,23 +69,6 @@ impl RoomController {
         Self { svc, token_service } 
     }
 
-    /// 최적화된 RoomIdGenerator 인스턴스를 가져옵니다.
-    /// 
-    /// 싱글톤 패턴으로 한 번만 초기화하고 재사용하여 Redis 연결 오버헤드를 제거합니다.
-    /// 
-    /// # Returns
-    /// * `Result<Arc<RoomIdGenerator>, AppError>` - RoomIdGenerator 인스턴스
-    async fn get_room_id_generator(&self) -> Result<Arc<RoomIdGenerator>, AppError> {
-        ROOM_ID_GENERATOR
-            .get_or_try_init(|| async {
-                let generator = RoomIdGenerator::from_env().await
-                    .map_err(|e| AppError::InternalError(format!("방 ID 생성기 초기화 실패: {e}")))?;
-                Ok(Arc::new(generator))
-            })
-            .await
-            .cloned()
-    }
-
     /// 방 생성 요청을 검증합니다.
     /// 
     /// # Arguments
@@ -153,22 +135,15 @@ impl RoomService for RoomController {
         if let Some(user_id) = verified_user_id {
             // 토큰이 있으면 사용자 ID 검증
             if user_id != req_inner.user_id {
-                return Err(Status::permission_denied("User ID mismatch"));
+                return Err(GameServerError::with_message(ErrorCode::PermissionDenied, "사용자 ID 불일치").into());
             }
         }
         
-        // 비즈니스 로직 호출 (최적화된 싱글톤 RoomIdGenerator 사용)
-        let room_id_generator = self.get_room_id_generator().await.map_err(|e| e.to_status())?;
-        let mut generator = Arc::try_unwrap(room_id_generator)
-            .unwrap_or_else(|arc| (*arc).clone());
-        
+        // 비즈니스 로직 호출
         let room_id = self
             .svc
             .make_room(RoomInfo {
-                room_id: generator.get_room_id().await.map_err(|e| {
-                    let app_error = AppError::InternalError(format!("방 ID 생성 실패: {e}"));
-                    app_error.to_status()
-                })?, 
+                room_id: self.svc.next_room_id().await.map_err(|e| e.to_status())?,
                 room_name: req_inner.room_name,
                 max_player_num: req_inner.max_player_num as u16,
                 current_player_num: 1,
@@ -188,6 +163,7 @@ impl RoomService for RoomController {
     /// 
     /// 사용자가 방 목록을 조회할 때 호출됩니다.
     /// 마지막으로 조회한 방 ID 이후의 방들을 반환합니다.
+    /// 요청의 `if-none-match`가 현재 검증자와 같으면 Redis 조회 한 번으로 빈 본문을 반환합니다.
     /// 
     /// # Arguments
     /// * `req` - 방 리스트 조회 요청 정보 (GetRoomListRequest)
@@ -198,6 +174,7 @@ impl RoomService for RoomController {
         &self,
         req: Request<GetRoomListRequest>,
     ) -> Result<Response<GetRoomListResponse>, Status> {
+        let if_none_match = read_cache::if_none_match(req.metadata());
         let req_inner = req.into_inner();
         let last_id = req_inner.last_room_id;
         info!("방 리스트 조회 요청: last_id={}", last_id);
@@ -210,6 +187,33 @@ impl RoomService for RoomController {
         // JWT 토큰 검증 (선택적)
         let _verified_user_id = self.verify_jwt_token(&Request::new(()))?;
         
+        // 변경 없음 빠른 경로 (검증자 조회 실패 시 전체 조회)
+        let scope = last_id.to_string();
+        if let Some(client_etag) = &if_none_match {
+            match self.svc.room_list_version().await {
+                Ok(Some(version)) if read_cache::etag(&version, &scope) == *client_etag => {
+                    debug!("방 리스트 변경 없음: last_id={}", last_id);
+                    return Ok(read_cache::respond(
+                        GetRoomListResponse { rooms: vec![] },
+                        Some(client_etag),
+                        ROOM_LIST_MAX_AGE_SECS,
+                        true,
+                    ));
+                }
+                Ok(_) => {}
+                Err(e) => debug!("방 리스트 검증자 조회 실패: {}", e),
+            }
+        }
+        
+        // 목록보다 먼저 검증자를 받아야 읽는 도중의 변경이 옛 검증자에 묶이지 않음
+        let etag = match self.svc.current_room_list_version().await {
+            Ok(version) => Some(read_cache::etag(&version, &scope)),
+            Err(e) => {
+                debug!("방 리스트 검증자 발급 실패: {}", e);
+                None
+            }
+        };
+        
         // 비즈니스 로직 호출
         let rooms = self
             .svc
@@ -222,7 +226,7 @@ impl RoomService for RoomController {
         
         info!("방 리스트 조회 성공: {}개 방", rooms.len());
         if rooms.len() == 0 {
-            return Ok(Response::new(GetRoomListResponse { rooms: vec![] }));
+            return Ok(read_cache::respond(GetRoomListResponse { rooms: vec![] }, etag.as_deref(), ROOM_LIST_MAX_AGE_SECS, false));
         }
         
         // shared::model::RoomInfo를 room::RoomInfo로 변환 (optimized allocation)
@@ -236,6 +240,6 @@ impl RoomService for RoomController {
             });
         }
         
-        Ok(Response::new(GetRoomListResponse { rooms: proto_rooms }))
+        Ok(read_cache::respond(GetRoomListResponse { rooms: proto_rooms }, etag.as_deref(), ROOM_LIST_MAX_AGE_SECS, false))
     }
 }
@@ -0,0 +1,76 @@
+//! Stats Service gRPC Controller
+//!
+//! 운영용 서버 통계 조회를 제공하는 gRPC 컨트롤러입니다.
+//! 메트릭 조회 권한(관리자 이상)이 있는 요청만 처리합니다.
+
+use tonic::{Request, Response, Status};
+use tracing::info;
+use crate::stats::{
+    stats_service_server::StatsService,
+    GetServerStatsRequest, GetServerStatsResponse, RegionStats,
+};
+use crate::service::stats_service::{protocol_filter, StatsService as StatsSvc};
+use crate::tool::role_guard::RoleGuard;
+use shared::security::{ApiEndpoint, SecurityError};
+
+/// Stats Service gRPC 컨트롤러
+pub struct StatsController {
+    /// 통계 비즈니스 로직을 처리하는 서비스
+    svc: StatsSvc,
+    /// 역할 기반 접근 가드
+    guard: RoleGuard,
+}
+
+impl StatsController {
+    /// 새로운 StatsController 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `svc` - 통계 비즈니스 로직을 처리하는 StatsService 인스턴스
+    ///
+    /// # Returns
+    /// * `Result<Self, SecurityError>` - JWT 설정이 유효하지 않으면 에러
+    pub fn new(svc: StatsSvc) -> Result<Self, SecurityError> {
+        Ok(Self {
+            svc,
+            guard: RoleGuard::from_env()?,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl StatsService for StatsController {
+    /// 서버 통계 조회 요청을 처리합니다.
+    ///
+    /// # Arguments
+    /// * `req` - 프로토콜 필터가 포함된 gRPC 요청 (비어 있으면 전체)
+    ///
+    /// # Returns
+    /// * `Result<Response<GetServerStatsResponse>, Status>` - 전체 및 프로토콜/지역별 CCU와 방 수
+    async fn get_server_stats(
+        &self,
+        req: Request<GetServerStatsRequest>,
+    ) -> Result<Response<GetServerStatsResponse>, Status> {
+        let operator = self.guard
+            .authorize(&req, &ApiEndpoint::new("stats", "GetServerStats"))
+            .await?;
+        let protocol = protocol_filter(&req.into_inner().protocol);
+        info!("서버 통계 조회: operator={}, protocol={:?}", operator, protocol);
+
+        let stats = self.svc.get_server_stats(protocol.as_deref()).await.map_err(Status::from)?;
+
+        Ok(Response::new(GetServerStatsResponse {
+            total_ccu: stats.total_ccu as i64,
+            total_rooms: stats.total_rooms as i64,
+            server_count: stats.server_count as i32,
+            breakdown: stats.breakdown.into_iter()
+                .map(|group| RegionStats {
+                    protocol: group.protocol,
+                    region: group.region,
+                    ccu: group.ccu as i64,
+                    rooms: group.rooms as i64,
+                    servers: group.servers as i32,
+                })
+                .collect(),
+        }))
+    }
+}
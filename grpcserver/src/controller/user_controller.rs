@@ -4,20 +4,32 @@
 //! UserService trait을 구현하여 gRPC 서버에서 사용자 관련 요청을 처리합니다.
 //! 최적화된 정적 상수로 검증 배열을 재사용합니다.
 
-use tonic::{Request, Response, Status};
-use tracing::info;
+use std::sync::Arc;
+use tonic::{Request, Response, Status, Streaming};
+use tracing::{info, warn};
+use crate::service::avatar_service::AvatarService;
 use crate::service::user_service::UserService as UserSvc;
 use crate::user::{
     user_service_server::UserService,
+    AvatarChunk, UploadAvatarResponse, GameEndpoint,
     LoginRequest, LoginResponse,
+    LogoutRequest, LogoutResponse,
+    RefreshSessionRequest, RefreshSessionResponse,
     RegisterRequest, RegisterResponse,
 };
 use shared::tool::error::{AppError, helpers};
+use shared::tool::{ErrorCode, GameServerError};
+use shared::config::connection_pool::ConnectionPool;
+use shared::security::{AuditEvent, AuditEventKind, AuditSink, EntryTicketSigner, LockoutPolicy, LoginLockout, SecurityError, SessionQuota, SessionQuotaPolicy};
+use shared::service::redis::server_routing::ServerRoutingStore;
 use shared::service::TokenService;
 
+/// 선호 지역 최대 길이
+pub(crate) const MAX_REGION_LEN: usize = 32;
+
 /// 최적화된 로그인 타입 상수 (컴파일 시 할당)
-const VALID_LOGIN_TYPES: &[&str] = &["google", "apple", "test"];
-const VALID_REGISTER_TYPES: &[&str] = &["google", "apple", "guest"];
+pub(crate) const VALID_LOGIN_TYPES: &[&str] = &["google", "apple", "test"];
+pub(crate) const VALID_REGISTER_TYPES: &[&str] = &["google", "apple", "guest"];
 
 /// User Service gRPC 컨트롤러
 /// 
@@ -29,6 +41,16 @@ pub struct UserController {
     svc: UserSvc,
     /// JWT 토큰 검증 서비스
     token_service: TokenService,
+    /// 로그인 실패 잠금 정책
+    lockout_policy: LockoutPolicy,
+    /// 계정별 동시 세션 수 정책
+    session_policy: SessionQuotaPolicy,
+    /// 아바타 업로드 서비스 (없으면 업로드 비활성화)
+    avatars: Option<Arc<AvatarService>>,
+    /// 게임 서버 입장 티켓 서명기
+    entry_tickets: EntryTicketSigner,
+    /// 보안 감사 로그 (없으면 기록 안 함)
+    audit: Option<Arc<AuditSink>>,
 }
 
 impl UserController {
@@ -62,10 +84,103 @@ impl UserController {
         
         let jwt_algorithm = std::env::var("JWT_ALGORITHM").unwrap_or_else(|_| "HS256".to_string());
         
+        let entry_tickets = EntryTicketSigner::from_jwt_secret(&jwt_secret);
         let token_service = TokenService::new(jwt_secret, jwt_algorithm);
         
         tracing::info!("🔐 JWT TokenService initialized with secure configuration");
-        Self { svc, token_service } 
+        Self {
+            svc,
+            token_service,
+            lockout_policy: LockoutPolicy::from_env(),
+            session_policy: SessionQuotaPolicy::from_env(),
+            avatars: None,
+            entry_tickets,
+            audit: None,
+        }
+    }
+
+    /// 아바타 업로드 서비스를 설정합니다.
+    pub fn with_avatars(mut self, avatars: Arc<AvatarService>) -> Self {
+        self.avatars = Some(avatars);
+        self
+    }
+
+    /// 보안 감사 로그를 설정합니다.
+    pub fn with_audit(mut self, audit: Arc<AuditSink>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// 감사 이벤트 기록 (실패해도 요청은 계속 처리)
+    async fn audit(&self, event: AuditEvent) {
+        if let Some(audit) = &self.audit {
+            if let Err(e) = audit.record(event).await {
+                warn!("감사 로그 기록 실패: {}", e);
+            }
+        }
+    }
+
+    /// 로그인 잠금 관리자를 생성합니다 (Redis 설정을 얻지 못하면 None).
+    async fn login_lockout(&self) -> Option<LoginLockout> {
+        match ConnectionPool::get_config().await {
+            Ok(redis_config) => Some(LoginLockout::new(redis_config, self.lockout_policy.clone())),
+            Err(e) => {
+                warn!("로그인 잠금 검사 생략 (Redis 설정 실패): {}", e);
+                None
+            }
+        }
+    }
+
+    /// 동시 세션 수 관리자를 생성합니다 (제한이 꺼져 있거나 Redis 설정을 얻지 못하면 None).
+    async fn session_quota(&self) -> Option<SessionQuota> {
+        if !self.session_policy.is_enabled() {
+            return None;
+        }
+        match ConnectionPool::get_config().await {
+            Ok(redis_config) => Some(SessionQuota::new(redis_config, self.session_policy.clone())),
+            Err(e) => {
+                warn!("동시 세션 검사 생략 (Redis 설정 실패): {}", e);
+                None
+            }
+        }
+    }
+
+    /// 로그인한 사용자가 접속할 프로토콜별 게임 서버와 입장 티켓을 고릅니다.
+    ///
+    /// 게임센터가 기록한 라우팅 테이블을 쓰며, 테이블이 없거나 조회에 실패하면 빈 목록입니다.
+    async fn game_endpoints(&self, user_id: u32, region: &str) -> Vec<GameEndpoint> {
+        let table = match ConnectionPool::get_config().await {
+            Ok(redis_config) => ServerRoutingStore::new(redis_config).get_table().await,
+            Err(e) => {
+                warn!("접속 라우팅 생략 (Redis 설정 실패): {}", e);
+                return Vec::new();
+            }
+        };
+        let table = match table {
+            Ok(Some(table)) => table,
+            Ok(None) => {
+                warn!("접속 라우팅 테이블이 없습니다 (게임센터 미실행?)");
+                return Vec::new();
+            }
+            Err(e) => {
+                warn!("접속 라우팅 테이블 조회 실패: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let region = Some(region).filter(|region| !region.is_empty());
+        table.best_per_protocol(region).into_iter()
+            .map(|endpoint| {
+                let (entry_ticket, ticket) = self.entry_tickets.issue(user_id, &endpoint.protocol, &endpoint.address);
+                GameEndpoint {
+                    protocol: endpoint.protocol.clone(),
+                    address: endpoint.address.clone(),
+                    region: endpoint.region.clone(),
+                    entry_ticket,
+                    ticket_expires_at: ticket.expires_at,
+                }
+            })
+            .collect()
     }
 
     /// JWT 토큰을 검증합니다.
@@ -95,6 +210,10 @@ impl UserController {
         // 로그인 토큰 검증
         helpers::validate_string(req.login_token.clone(), "login_token", 1000)?;
 
+        if req.region.len() > MAX_REGION_LEN {
+            return Err(AppError::InvalidInput(format!("region은 {MAX_REGION_LEN}자 이하여야 합니다")));
+        }
+
         Ok(())
     }
 
@@ -119,6 +238,42 @@ impl UserController {
 
         Ok(())
     }
+
+    /// 리프레시 토큰을 검증해 (사용자 ID, 패밀리 ID)를 반환합니다.
+    #[allow(clippy::result_large_err)]
+    fn verify_refresh_token(&self, refresh_token: &str) -> Result<(i32, String), Status> {
+        self.token_service.verify_refresh_token(refresh_token).map_err(|e| {
+            warn!("리프레시 토큰 검증 실패: {}", e);
+            AppError::AuthError("Invalid or expired refresh token".to_string()).to_status()
+        })
+    }
+
+    /// 세션(패밀리)에 묶인 리프레시 토큰을 발급합니다.
+    #[allow(clippy::result_large_err)]
+    fn issue_refresh_token(&self, user_id: i32, family_id: &str) -> Result<String, Status> {
+        self.token_service
+            .generate_refresh_token(user_id, family_id, self.session_policy.family_ttl)
+            .map_err(|e| AppError::InternalError(format!("리프레시 토큰 발급 실패: {e}")).to_status())
+    }
+}
+
+/// 인증 자체가 거부된 로그인 실패인지 확인합니다 (시스템 오류 제외).
+fn is_auth_failure(error: &AppError) -> bool {
+    matches!(error, AppError::AuthError(_) | AppError::TokenExpired(_) | AppError::InvalidLoginType(_))
+}
+
+/// 보안 에러를 gRPC Status로 변환합니다.
+///
+/// 공통 에러 분류를 따르며, 잠금은 `retry-after`(초) 메타데이터를 추가로 전달합니다.
+fn security_status(error: &SecurityError) -> Status {
+    let mut status: Status = GameServerError::from(error).into();
+    if let SecurityError::AccountLocked { retry_after } = error {
+        // 남은 시간은 올림해서 알림
+        let secs = (retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0)).max(1);
+        warn!("잠긴 계정/IP의 로그인 시도: retry_after={}s", secs);
+        status.metadata_mut().insert("retry-after", secs.into());
+    }
+    status
 }
 
 #[tonic::async_trait]
@@ -137,6 +292,7 @@ impl UserService for UserController {
         &self,
         req: Request<LoginRequest>,
     ) -> Result<Response<LoginResponse>, Status> {
+        let client_ip = req.remote_addr().map(|addr| addr.ip());
         let r = req.into_inner();
         info!("로그인 요청: login_type={}", r.login_type);
         
@@ -147,18 +303,64 @@ impl UserService for UserController {
 
         // JWT 토큰 검증 (선택적)
         let _verified_user_id = self.verify_jwt_token(&Request::new(()))?;
+
+        // 무차별 대입 잠금 확인
+        let account = format!("{}:{}", r.login_type, r.login_token);
+        let lockout = self.login_lockout().await;
+        if let Some(ref lockout) = lockout {
+            lockout.check(&account, client_ip).await.map_err(|e| security_status(&e))?;
+        }
         
         // 비즈니스 로직 호출
-        let (user_id, nick_name, access_token, refresh_token, is_register) = self
-            .svc
-            .login_user(r.login_type, r.login_token)
-            .await
-            .map_err(|e| {
+        let login_type = r.login_type.clone();
+        let result = self.svc.login_user(r.login_type, r.login_token).await;
+        let (user_id, nick_name, access_token, _, is_register) = match result {
+            Ok(login) => login,
+            Err(e) => {
+                // 인증 실패만 잠금 횟수에 포함
+                if is_auth_failure(&e) {
+                    // 로그인 토큰은 비밀 값이므로 로그인 타입과 IP만 남김
+                    let mut event = AuditEvent::new(AuditEventKind::LoginFailed, &login_type);
+                    if let Some(ip) = client_ip {
+                        event = event.with_source(ip.to_string());
+                    }
+                    self.audit(event).await;
+                    if let Some(lockout) = &lockout {
+                        lockout.record_failure(&account, client_ip).await.map_err(|e| security_status(&e))?;
+                    }
+                }
                 let app_error = AppError::InternalError(format!("로그인 실패: {e}"));
-                app_error.to_status()
-            })?;
+                return Err(app_error.to_status());
+            }
+        };
+        if let Some(ref lockout) = lockout {
+            lockout.record_success(&account).await;
+        }
+
+        // 동시 세션 한도 (정책에 따라 가장 오래된 세션을 종료하거나 로그인 거부)
+        // 밀려난 세션은 활성 목록에서 빠지므로 그 리프레시 토큰은 더 이상 갱신되지 않음
+        let family_id = SessionQuota::new_family_id();
+        if let Some(quota) = self.session_quota().await {
+            let evicted = quota.admit(user_id as u32, &family_id).await.map_err(|e| security_status(&e))?;
+            if !evicted.is_empty() {
+                info!("기존 세션 {}개 종료: user_id={}", evicted.len(), user_id);
+            }
+        }
+        let refresh_token = self.issue_refresh_token(user_id, &family_id)?;
         
-        info!("로그인 성공: user_id={}, nick={}", user_id, nick_name);
+        let avatar_url = match &self.avatars {
+            Some(avatars) => avatars.avatar_url(user_id).await.unwrap_or_default(),
+            None => String::new(),
+        };
+        let endpoints = self.game_endpoints(user_id as u32, &r.region).await;
+
+        let mut event = AuditEvent::new(AuditEventKind::Login, user_id.to_string()).with_detail(login_type);
+        if let Some(ip) = client_ip {
+            event = event.with_source(ip.to_string());
+        }
+        self.audit(event).await;
+        
+        info!("로그인 성공: user_id={}, nick={}, 접속 서버 {}개", user_id, nick_name, endpoints.len());
         Ok(Response::new(LoginResponse {
             success: true,
             user_id,
@@ -166,6 +368,9 @@ impl UserService for UserController {
             access_token,
             refresh_token,
             is_register,
+            avatar_url,
+            endpoints,
+            family_id,
         }))
     }
 
@@ -207,4 +412,125 @@ impl UserService for UserController {
         info!("회원가입 성공");
         Ok(Response::new(RegisterResponse { success: true }))
     }
+
+    /// 아바타를 업로드하는 gRPC 메서드 (클라이언트 스트리밍)
+    /// 
+    /// 첫 청크의 user_id, content_type을 사용하고 이후 청크는 data만 이어 붙입니다.
+    /// 최대 크기를 넘으면 수신을 멈추고 거부합니다.
+    /// 
+    /// # Arguments
+    /// * `req` - 아바타 청크 스트림 (AvatarChunk)
+    /// 
+    /// # Returns
+    /// * `Result<Response<UploadAvatarResponse>, Status>` - 저장된 아바타 URL
+    async fn upload_avatar(
+        &self,
+        req: Request<Streaming<AvatarChunk>>,
+    ) -> Result<Response<UploadAvatarResponse>, Status> {
+        let Some(avatars) = &self.avatars else {
+            return Err(Status::unimplemented("Avatar upload is disabled"));
+        };
+
+        // JWT 토큰 검증 (선택적)
+        let verified_user_id = self.verify_jwt_token(&Request::new(()))?;
+
+        let mut stream = req.into_inner();
+        let first = stream.message().await?
+            .ok_or_else(|| AppError::MissingField("avatar chunk".to_string()).to_status())?;
+        let (user_id, content_type) = (first.user_id, first.content_type);
+        info!("아바타 업로드 요청: user_id={}, content_type={}", user_id, content_type);
+
+        if user_id <= 0 {
+            return Err(AppError::InvalidInput("user_id must be positive".to_string()).to_status());
+        }
+        if let Some(verified) = verified_user_id {
+            if verified != user_id {
+                return Err(GameServerError::with_message(ErrorCode::PermissionDenied, "사용자 ID 불일치").into());
+            }
+        }
+
+        let mut data = first.data;
+        while let Some(chunk) = stream.message().await? {
+            if data.len() + chunk.data.len() > avatars.max_bytes() {
+                return Err(AppError::InvalidInput(format!(
+                    "avatar exceeds {} bytes",
+                    avatars.max_bytes()
+                )).to_status());
+            }
+            data.extend_from_slice(&chunk.data);
+        }
+
+        let stored = avatars.upload(user_id, &content_type, &data).await.map_err(|e| e.to_status())?;
+        Ok(Response::new(UploadAvatarResponse {
+            success: true,
+            avatar_url: stored.url,
+            content_type: stored.content_type.to_string(),
+            width: stored.width as i32,
+            height: stored.height as i32,
+        }))
+    }
+
+    /// 리프레시 토큰으로 세션을 갱신하는 gRPC 메서드
+    /// 
+    /// 토큰의 세션(패밀리)이 밀려났거나 로그아웃으로 해제됐으면 거부합니다.
+    /// 같은 패밀리로 새 리프레시 토큰을 발급하므로 동시 세션 수는 변하지 않습니다.
+    /// 
+    /// # Arguments
+    /// * `req` - 세션 갱신 요청 (RefreshSessionRequest)
+    /// 
+    /// # Returns
+    /// * `Result<Response<RefreshSessionResponse>, Status>` - 새 액세스/리프레시 토큰
+    async fn refresh_session(
+        &self,
+        req: Request<RefreshSessionRequest>,
+    ) -> Result<Response<RefreshSessionResponse>, Status> {
+        let r = req.into_inner();
+        let (user_id, family_id) = self.verify_refresh_token(&r.refresh_token)?;
+
+        if let Some(quota) = self.session_quota().await {
+            if !quota.is_active(user_id as u32, &family_id).await.map_err(|e| security_status(&e))? {
+                // 만료된 기록이 남아 있을 수 있으므로 정리 후 거부
+                quota.release(user_id as u32, &family_id).await;
+                warn!("폐기된 세션의 갱신 시도: user_id={}, family={}", user_id, family_id);
+                return Err(AppError::AuthError("Session has been revoked".to_string()).to_status());
+            }
+        }
+
+        let access_token = self.token_service.generate_token(user_id)
+            .map_err(|e| AppError::InternalError(format!("액세스 토큰 발급 실패: {e}")).to_status())?;
+        let refresh_token = self.issue_refresh_token(user_id, &family_id)?;
+
+        info!("세션 갱신: user_id={}", user_id);
+        Ok(Response::new(RefreshSessionResponse {
+            success: true,
+            access_token,
+            refresh_token,
+        }))
+    }
+
+    /// 로그아웃을 처리하는 gRPC 메서드
+    /// 
+    /// 리프레시 토큰의 세션(패밀리)을 해제해 동시 세션 한도에서 빼고,
+    /// 이후 같은 패밀리의 리프레시 토큰으로는 갱신할 수 없게 합니다.
+    /// 
+    /// # Arguments
+    /// * `req` - 로그아웃 요청 (LogoutRequest)
+    /// 
+    /// # Returns
+    /// * `Result<Response<LogoutResponse>, Status>` - 로그아웃 결과
+    async fn logout(
+        &self,
+        req: Request<LogoutRequest>,
+    ) -> Result<Response<LogoutResponse>, Status> {
+        let r = req.into_inner();
+        let (user_id, family_id) = self.verify_refresh_token(&r.refresh_token)?;
+
+        if let Some(quota) = self.session_quota().await {
+            quota.release(user_id as u32, &family_id).await;
+        }
+        self.audit(AuditEvent::new(AuditEventKind::Logout, user_id.to_string())).await;
+
+        info!("로그아웃: user_id={}", user_id);
+        Ok(Response::new(LogoutResponse { success: true }))
+    }
 }
@@ -0,0 +1,62 @@
+//! Version Service gRPC Controller
+//!
+//! 클라이언트 버전 확인 요청을 처리하는 gRPC 컨트롤러입니다.
+//! 업데이트가 필요한 클라이언트도 호출할 수 있도록 버전 게이트를 적용하지 않습니다.
+
+use tonic::{Request, Response, Status};
+use tracing::info;
+use crate::version::{
+    version_service_server::VersionService,
+    CheckVersionRequest, CheckVersionResponse, UpdateStatus as UpdateStatusProto,
+};
+use crate::service::version_service::VersionService as VersionSvc;
+use crate::tool::version_gate::UpdateStatus;
+
+/// Version Service gRPC 컨트롤러
+pub struct VersionController {
+    /// 버전 확인 비즈니스 로직을 처리하는 서비스
+    svc: VersionSvc,
+}
+
+impl VersionController {
+    /// 새로운 VersionController 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `svc` - 버전 확인 비즈니스 로직을 처리하는 VersionService 인스턴스
+    pub fn new(svc: VersionSvc) -> Self {
+        Self { svc }
+    }
+}
+
+#[tonic::async_trait]
+impl VersionService for VersionController {
+    /// 클라이언트 버전 확인 요청을 처리합니다.
+    ///
+    /// # Arguments
+    /// * `req` - 클라이언트 버전과 플랫폼이 포함된 gRPC 요청
+    ///
+    /// # Returns
+    /// * `Result<Response<CheckVersionResponse>, Status>` - 업데이트 필요 여부, 최소/권장 버전, 스토어 URL
+    async fn check_version(
+        &self,
+        req: Request<CheckVersionRequest>,
+    ) -> Result<Response<CheckVersionResponse>, Status> {
+        let r = req.into_inner();
+        let check = self.svc.check_version(&r.client_version, &r.platform).map_err(Status::from)?;
+        info!("클라이언트 버전 확인: version={}, platform={}, status={}", r.client_version, r.platform, check.status.as_str());
+
+        let status = match check.status {
+            UpdateStatus::UpToDate => UpdateStatusProto::UpToDate,
+            UpdateStatus::UpdateOptional => UpdateStatusProto::UpdateOptional,
+            UpdateStatus::UpdateRequired => UpdateStatusProto::UpdateRequired,
+        };
+
+        Ok(Response::new(CheckVersionResponse {
+            status: status as i32,
+            min_version: check.min_version.map(|v| v.to_string()).unwrap_or_default(),
+            recommended_version: check.recommended_version.map(|v| v.to_string()).unwrap_or_default(),
+            store_url: check.store_url.unwrap_or_default(),
+            message: check.message,
+        }))
+    }
+}
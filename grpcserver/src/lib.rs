@@ -42,6 +42,41 @@ pub mod user {
     tonic::include_proto!("user"); 
 }
 
+/// Moderation Service Protocol Buffer 정의
+/// 
+/// 관리자 대시보드용 채팅 모더레이션 스트림 서비스와 메시지 정의를 포함합니다.
+pub mod moderation { 
+    tonic::include_proto!("moderation"); 
+}
+
+/// Stats Service Protocol Buffer 정의
+/// 
+/// 운영용 서버 통계(프로토콜/지역별 CCU, 방 수) 조회 서비스와 메시지 정의를 포함합니다.
+pub mod stats { 
+    tonic::include_proto!("stats"); 
+}
+
+/// Version Service Protocol Buffer 정의
+/// 
+/// 클라이언트 버전 확인(강제/권장 업데이트) 서비스와 메시지 정의를 포함합니다.
+pub mod version { 
+    tonic::include_proto!("version"); 
+}
+
+/// Experiment Service Protocol Buffer 정의
+/// 
+/// 실험(A/B 테스트) 변형 배정 조회 서비스와 메시지 정의를 포함합니다.
+pub mod experiment { 
+    tonic::include_proto!("experiment"); 
+}
+
+/// LiveOps Service Protocol Buffer 정의
+/// 
+/// 라이브 설정(킬 스위치 등) 조회/변경 운영 서비스와 메시지 정의를 포함합니다.
+pub mod liveops { 
+    tonic::include_proto!("liveops"); 
+}
+
 /// Controller 모듈
 /// 
 /// gRPC 요청을 처리하는 컨트롤러들을 포함합니다.
@@ -64,6 +99,21 @@ pub mod server;
 /// gRPC 클라이언트 테스트 코드를 포함합니다.
 pub mod test;
 
+/// Config 모듈
+/// 
+/// 실행 프로필(dev/staging/prod)과 환경변수 기반 서버 설정을 포함합니다.
+pub mod config;
+
+/// Self-Check 모듈
+/// 
+/// `--check` 실행 시 기동 전 환경 점검 리포트를 생성합니다.
+pub mod self_check;
+
+/// Schema 모듈
+/// 
+/// `--schema` 실행 시 클라이언트 팀용 JSON API 스키마를 생성합니다.
+pub mod schema;
+
 /// Tool 모듈
 /// 
 /// 유틸리티 도구들을 포함합니다.
@@ -76,4 +126,9 @@ pub use service::*;
 pub use server::*;
 pub use test::*;
 pub use tool::*;
+pub use config::*;
+pub use self_check::*;
+
+/// 크레이트 버전
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -1,9 +1,18 @@
 use anyhow::Result;
 use dotenv::{dotenv, from_path};
-use std::{env, net::SocketAddr, path::PathBuf};
+use std::{env, path::PathBuf, sync::Arc};
 use tonic::transport::Server;
-use tracing::info;
+use tracing::{info, warn};
+use tracing_subscriber::prelude::*;
 use tracing_subscriber::{fmt, EnvFilter};
+use shared::auth::{service_auth_interceptor, ServiceTokenVerifier};
+use shared::monitoring::health::{probes, HealthRegistry, ProbeKind};
+use shared::monitoring::crash::{self, CrashConfig};
+use shared::monitoring::{PlayerSampler, TelemetryIngest};
+use shared::security::{AuditSink, JwtManager, PolicyEnforcer, SecurityConfig};
+use shared::service::redis::jwt_keys::{self, JwtKeyStore};
+use shared::service::redis::live_config::LiveConfig;
+use shared::tool::high_performance::CpuTaskPool;
 
 // 1) 프로토에서 생성된 코드를 같은 크레이트 루트에 포함
 pub mod room {
@@ -12,19 +21,66 @@ pub mod room {
 pub mod user {
     tonic::include_proto!("user");
 }
+pub mod moderation {
+    tonic::include_proto!("moderation");
+}
+pub mod stats {
+    tonic::include_proto!("stats");
+}
+pub mod version {
+    tonic::include_proto!("version");
+}
+pub mod experiment {
+    tonic::include_proto!("experiment");
+}
+pub mod liveops {
+    tonic::include_proto!("liveops");
+}
 
 // 2) 도메인 로직·컨트롤러 모듈
 mod service;
 mod controller;
 mod tool;
+mod config;
+mod self_check;
+mod schema;
 // 3) 편리한 import
-use controller::{room_controller::RoomController, user_controller::UserController};
-use service::{room_service::RoomService, user_service::UserService};
+use config::{validate_jwt_security_config, CliArgs, GrpcServerConfig};
+use controller::experiment_controller::ExperimentController;
+use controller::liveops_controller::LiveOpsController;
+use controller::{moderation_controller::ModerationController, room_controller::RoomController, stats_controller::StatsController, user_controller::UserController, version_controller::VersionController};
+use service::avatar_service::AvatarService;
+use service::experiment_service::{self as experiments, ExperimentService};
+use service::liveops_service::LiveOpsService;
+use service::{moderation_service::ModerationService, room_service::RoomService, stats_service::StatsService, user_service::UserService, version_service::VersionService};
+use experiment::experiment_service_server::ExperimentServiceServer;
+use liveops::live_ops_service_server::LiveOpsServiceServer;
+use moderation::moderation_service_server::ModerationServiceServer;
+use stats::stats_service_server::StatsServiceServer;
+use tool::canary::CanaryRouter;
+use tool::error_locale::{ErrorLocaleLayer, ErrorMessageCatalog};
+use tool::intercepter::chain;
+use tool::policy_layer::PolicyLayer;
+use tool::response_compression::ResponseCompressionLayer;
+use tool::version_gate::version_interceptor;
+use version::version_service_server::VersionServiceServer;
 use room::room_service_server::RoomServiceServer;
 use user::user_service_server::UserServiceServer;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    // 명령행 인자: [--check] [--profile dev|staging|prod] [--schema <path>]
+    let args = CliArgs::parse(env::args())?;
+
+    // 스키마 생성 모드: 설정/외부 의존성 없이 출력 후 종료
+    if let Some(path) = &args.schema {
+        schema::write_api_schema(path)?;
+        if path.as_os_str() != "-" {
+            println!("API 스키마 생성: {}", path.display());
+        }
+        return Ok(());
+    }
+
     // .env 로드 - workspace root에서 .env 파일 찾기
     let workspace_root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf();
     let env_path = workspace_root.join(".env");
@@ -39,20 +95,27 @@ async fn main() -> Result<()> {
     let filter = EnvFilter::from_default_env()
         .add_directive("info".parse()
         .map_err(|e| anyhow::anyhow!("로깅 설정 파싱 실패: {e}"))?);
-    fmt().with_env_filter(filter).init();
+    // 샘플링 규칙에 해당하는 세션은 필터 수준과 무관하게 기록
+    tracing_subscriber::registry()
+        .with(fmt::layer().with_filter(PlayerSampler::new(filter)))
+        .with(crash::recent_logs_layer())
+        .init();
+    crash::install(CrashConfig::from_env("grpcserver", crash::build_id(env!("CARGO_PKG_VERSION"), option_env!("BUILD_HASH"))));
 
-    // grpc_host, grpc_port 읽기 (안전한 에러 처리)
-    let host = env::var("grpc_host")
-        .map_err(|_| anyhow::anyhow!("환경변수 'grpc_host'가 설정되지 않았습니다. .env 파일을 확인하세요."))?;
-    let port = env::var("grpc_port")
-        .map_err(|_| anyhow::anyhow!("환경변수 'grpc_port'가 설정되지 않았습니다. .env 파일을 확인하세요."))?;
-    let addr: SocketAddr = format!("{host}:{port}")
-        .parse()
-        .map_err(|e| anyhow::anyhow!("잘못된 주소 형식 '{host}:{port}': {e}"))?;
+    // 프로필 기본값 + 환경변수로 설정 로드
+    let config = GrpcServerConfig::from_env(args.profile)?;
 
+    // 자체 점검 모드: 리포트 출력 후 종료
+    if args.check {
+        let report = self_check::run_self_check(&config).await;
+        println!("{report}");
+        std::process::exit(if report.is_ready() { 0 } else { 1 });
+    }
+
+    config.log_summary();
+    let addr = config.addr;
     info!("▶ gRPC 서버 실행: {}", addr);
 
-    // JWT 설정 확인 (선택적)
     // JWT 보안 설정 검증 호출
     validate_jwt_security_config()?;
     
@@ -62,20 +125,123 @@ async fn main() -> Result<()> {
 
     // Redis 연결 풀 초기화 (성능 최적화)
     info!("🔄 Redis 연결 풀 초기화 중...");
-    shared::config::connection_pool::ConnectionPool::init().await
-        .map_err(|e| anyhow::anyhow!("Redis 연결 풀 초기화 실패: {}", e))?;
-    info!("✅ Redis 연결 풀 초기화 완료");
+    // 라이브 설정 (킬 스위치, Redis가 없으면 이 프로세스 안에서만 적용)
+    let mut live_config = LiveConfig::local();
+    match shared::config::connection_pool::ConnectionPool::init().await {
+        Ok(()) => {
+            info!("✅ Redis 연결 풀 초기화 완료");
+            // JWT 배포 키 갱신 (게임센터의 키 회전을 받아 무중단으로 서명/검증 키 교체)
+            if let Ok(redis_config) = shared::config::connection_pool::ConnectionPool::get_config().await {
+                JwtKeyStore::new(redis_config.clone()).spawn_refresh(jwt_keys::DEFAULT_REFRESH_INTERVAL);
+                live_config = LiveConfig::new(redis_config);
+            }
+        }
+        Err(e) if !config.require_redis => {
+            warn!("⚠️ Redis 연결 풀 초기화 실패 ({} 프로필에서는 계속 진행): {}", config.profile, e);
+        }
+        Err(e) => return Err(anyhow::anyhow!("Redis 연결 풀 초기화 실패: {}", e)),
+    }
+
+    // 헬스 엔드포인트 (gRPC 준비 ⇐ 필수 의존성 준비)
+    if let Some(health_addr) = config.health_addr {
+        let registry = build_health_registry(&config);
+        registry.serve(health_addr).await
+            .map_err(|e| anyhow::anyhow!("헬스 엔드포인트 시작 실패 ({health_addr}): {e}"))?;
+    }
 
     // 컨트롤러에 비즈니스 로직 서비스 주입
-    let room_ctrl = RoomController::new(RoomService::new());
-    let user_ctrl = UserController::new(UserService::new());
+    // RoomService는 사용자 버킷 기준으로 일부만 실제 구현(카나리)으로 보내고 나머지는 프로필 설정을 따름
+    let room_ctrl = CanaryRouter::new(
+        RoomController::new(RoomService::new().with_mock_services(config.use_mock_services)),
+        RoomController::new(RoomService::new()),
+        config.room_canary.clone(),
+    )
+    .with_token_service(shared::service::TokenService::new(
+        env::var("JWT_SECRET_KEY")?,
+        env::var("JWT_ALGORITHM").unwrap_or_else(|_| "HS256".to_string()),
+    ));
+    // CPU 집약 작업(아바타 이미지 검증/재인코딩)은 전용 스레드 풀에서 실행
+    let cpu_pool = Arc::new(CpuTaskPool::new(&config.parallel)?);
+    let user_ctrl = UserController::new(UserService::new().with_mock_services(config.use_mock_services))
+        .with_avatars(Arc::new(AvatarService::from_config(config.avatar.clone()).with_cpu_pool(cpu_pool)));
+    // 보안 감사 로그 (`AUDIT_LOG_FILE`, `AUDIT_HMAC_KEY`), 로그인과 라이브 설정 변경이 같은 체인을 씀
+    let audit = AuditSink::from_env().await
+        .map_err(|e| anyhow::anyhow!("감사 로그 초기화 실패: {e}"))?
+        .map(Arc::new);
+    let user_ctrl = match &audit {
+        Some(audit) => user_ctrl.with_audit(audit.clone()),
+        None => user_ctrl,
+    };
+    if let Some(audit) = &audit {
+        live_config = live_config.with_audit(audit.clone());
+    }
+    live_config.clone().spawn_sync();
+    let moderation_ctrl = ModerationController::new(ModerationService::new())
+        .map_err(|e| anyhow::anyhow!("모더레이션 컨트롤러 초기화 실패: {e}"))?;
+    let stats_ctrl = StatsController::new(StatsService::new())
+        .map_err(|e| anyhow::anyhow!("통계 컨트롤러 초기화 실패: {e}"))?;
+    let liveops_ctrl = LiveOpsController::new(LiveOpsService::new(live_config.clone()))
+        .map_err(|e| anyhow::anyhow!("라이브 설정 컨트롤러 초기화 실패: {e}"))?;
+
+    // 실험 목록을 Redis에 게시해 게임 서버와 같은 배정/파라미터를 공유
+    let experiment_catalog = experiments::load_catalog(&config.experiments_file)
+        .map_err(|e| anyhow::anyhow!("실험 목록 로드 실패 ({}): {e}", config.experiments_file.display()))?;
+    info!("🧪 실험 {}개 로드: {}", experiment_catalog.experiments.len(), config.experiments_file.display());
+    let experiment_svc = ExperimentService::new(experiment_catalog).with_mock_services(config.use_mock_services);
+    if let Err(e) = experiment_svc.publish_catalog().await {
+        warn!("⚠️ 실험 목록 게시 실패 (게임 서버 변형 파라미터 미적용): {}", e);
+    }
+    let experiment_ctrl = ExperimentController::new(experiment_svc, shared::service::TokenService::new(
+        env::var("JWT_SECRET_KEY")?,
+        env::var("JWT_ALGORITHM").unwrap_or_else(|_| "HS256".to_string()),
+    ));
 
+    // 게임 서비스에는 클라이언트 버전 게이트 적용 (버전 확인 서비스는 제외)
+    let version_policy = Arc::new(config.version_policy.clone());
+    let version_gate = version_interceptor(version_policy.clone());
+    let version_ctrl = VersionController::new(VersionService::new(version_policy));
+
+    // 서비스 토큰 검증 (내부 서비스 식별, `grpc_internal_services`는 토큰 필수)
+    let service_verifier = match ServiceTokenVerifier::from_env("grpcserver") {
+        Ok(verifier) => Some(Arc::new(verifier)),
+        Err(e) => {
+            warn!("⚠️ 서비스 토큰 검증 비활성화: {}", e);
+            None
+        }
+    };
+    let service_auth = |service: &str| service_auth_interceptor(service_verifier.clone(), config.is_internal(service));
+
+    // 엔드포인트별 보안 정책 (`SECURITY_POLICY_FILE`, 메서드 경로 단위)
+    let policy = PolicyEnforcer::from_env().map_err(|e| anyhow::anyhow!("보안 정책 로드 실패: {e}"))?;
+    let mut policy_layer = PolicyLayer::new(Arc::new(policy))
+        .with_token_service(shared::service::TokenService::new(
+            env::var("JWT_SECRET_KEY")?,
+            env::var("JWT_ALGORITHM").unwrap_or_else(|_| "HS256".to_string()),
+        ))
+        .with_live_config(live_config);
+    match SecurityConfig::from_env().and_then(JwtManager::new) {
+        Ok(role_tokens) => policy_layer = policy_layer.with_role_tokens(role_tokens),
+        Err(e) => warn!("⚠️ 역할 토큰 검증 비활성화 (정책의 min_role은 user까지만 충족): {}", e),
+    }
+
+    // 에러 메시지 번역 (`accept-language`, 정책 거부 응답도 번역하도록 가장 바깥 레이어)
+    let error_messages = ErrorMessageCatalog::load(&config.error_messages_file)
+        .map_err(|e| anyhow::anyhow!("에러 메시지 카탈로그 로드 실패 ({}): {e}", config.error_messages_file.display()))?;
+    
     info!("🚀 gRPC 서버 시작 중...");
     
     // 서버 빌드 & 실행 (최적화된 설정)
     let result = Server::builder()
-        .add_service(RoomServiceServer::new(room_ctrl))
-        .add_service(UserServiceServer::new(user_ctrl))
+        .layer(ErrorLocaleLayer::new(Arc::new(error_messages)))
+        .layer(policy_layer)
+        .layer(ResponseCompressionLayer::new(config.compression.clone()))
+        .add_service(RoomServiceServer::with_interceptor(room_ctrl, chain(service_auth("room"), version_gate.clone())))
+        .add_service(UserServiceServer::with_interceptor(user_ctrl, chain(service_auth("user"), version_gate.clone())))
+        .add_service(ExperimentServiceServer::with_interceptor(experiment_ctrl, chain(service_auth("experiment"), version_gate)))
+        .add_service(ModerationServiceServer::with_interceptor(moderation_ctrl, service_auth("moderation")))
+        .add_service(StatsServiceServer::with_interceptor(stats_ctrl, service_auth("stats")))
+        .add_service(LiveOpsServiceServer::with_interceptor(liveops_ctrl, service_auth("liveops")))
+        .add_service(VersionServiceServer::with_interceptor(version_ctrl, service_auth("version")))
         .serve(addr)
         .await;
 
@@ -87,77 +253,27 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-/// JWT 보안 설정 검증 함수
-/// 
-/// 프로덕션 환경에서 안전한 JWT 설정을 보장합니다.
-/// 
-/// # Returns
-/// * `Result<()>` - 검증 성공 시 Ok(()), 실패 시 Error
-/// 
-/// # Panics
-/// * JWT_SECRET_KEY가 설정되지 않았거나 보안 요구사항을 만족하지 않을 때
-fn validate_jwt_security_config() -> Result<()> {
-    use std::env;
-    use tracing::info;
-    
-    // JWT_SECRET_KEY 필수 검증
-    let jwt_secret = env::var("JWT_SECRET_KEY")
-        .map_err(|_| anyhow::anyhow!(
-            "🚨 SECURITY ERROR: JWT_SECRET_KEY environment variable is required.\n\
-             Please set a cryptographically secure random key of at least 32 characters.\n\
-             Example: openssl rand -hex 32"
-        ))?;
-    
-    // 보안 검증: 최소 32자 이상의 시크릿 키 요구
-    if jwt_secret.len() < 32 {
-        return Err(anyhow::anyhow!(
-            "🚨 SECURITY ERROR: JWT_SECRET_KEY must be at least 32 characters long.\n\
-             Current length: {}. Please generate a stronger key.\n\
-             Example: openssl rand -hex 32", 
-            jwt_secret.len()
-        ));
-    }
-    
-    // 보안 검증: 약한 기본값 사용 방지
-    let lower_secret = jwt_secret.to_lowercase();
-    if lower_secret.contains("default") || 
-       lower_secret.contains("secret") ||
-       lower_secret.contains("change") ||
-       lower_secret.contains("your_") ||
-       lower_secret.contains("please") ||
-       lower_secret.contains("example") {
-        return Err(anyhow::anyhow!(
-            "🚨 SECURITY ERROR: JWT_SECRET_KEY appears to contain default/weak values.\n\
-             Please use a cryptographically secure random key.\n\
-             Example: openssl rand -hex 32"
-        ));
-    }
-    
-    // JWT 알고리즘 설정 확인
-    let jwt_algorithm = env::var("JWT_ALGORITHM").unwrap_or_else(|_| {
-        info!("ℹ️ JWT_ALGORITHM not set, using default 'HS256'");
-        "HS256".to_string()
-    });
-    
-    // 지원되는 알고리즘 검증
-    match jwt_algorithm.as_str() {
-        "HS256" | "HS384" | "HS512" => {
-            info!("✅ JWT algorithm '{}' is supported", jwt_algorithm);
-        }
-        _ => {
-            return Err(anyhow::anyhow!(
-                "🚨 SECURITY ERROR: Unsupported JWT algorithm '{}'. \n\
-                 Supported algorithms: HS256, HS384, HS512", 
-                jwt_algorithm
-            ));
-        }
+/// gRPC 서버 헬스 레지스트리 구성
+///
+/// Redis/DB는 항상 표시하되, 프로필에서 필수인 것만 gRPC 준비 조건에 포함합니다.
+fn build_health_registry(config: &GrpcServerConfig) -> HealthRegistry {
+    let kind = |required: bool| if required { ProbeKind::Readiness } else { ProbeKind::Informational };
+    let dependencies: Vec<&str> = [("redis", config.require_redis), ("database", config.require_database)]
+        .into_iter()
+        .filter(|(_, required)| *required)
+        .map(|(name, _)| name)
+        .collect();
+
+    let mut registry = HealthRegistry::new("grpcserver");
+    // 클라이언트 텔레메트리 수집 (`TELEMETRY_SIGNING_KEY` 설정 시 `POST /telemetry`)
+    if let Some(telemetry) = TelemetryIngest::from_env() {
+        info!("📈 클라이언트 텔레메트리 수집 활성화: POST /telemetry");
+        registry = registry.with_telemetry(Arc::new(telemetry));
     }
-    
-    // 보안 설정 로그 (시크릿 키는 길이만 표시)
-    info!("🔐 JWT Security Configuration:");
-    info!("  └─ Algorithm: {}", jwt_algorithm);
-    info!("  └─ Secret Key Length: {} characters", jwt_secret.len());
-    info!("  └─ Security Level: ✅ SECURE");
-    
-    Ok(())
+    registry
+        .register("process", ProbeKind::Liveness, &[], || async { Ok(()) })
+        .register("redis", kind(config.require_redis), &[], probes::redis_ping)
+        .register("database", kind(config.require_database), &[], probes::database)
+        .register_group("grpc", &dependencies);
+    registry
 }
@@ -0,0 +1,391 @@
+//! API 스키마 생성
+//!
+//! `grpcserver --schema <path>` 실행 시 클라이언트 팀(Unity, 웹)용 JSON 스키마를 출력합니다.
+//! 메시지/필드 구조와 주석은 빌드 시 생성한 proto 디스크립터에서 읽고,
+//! 컨트롤러의 검증 규칙([`FIELD_RULES`])과 메서드별 에러 코드([`METHOD_ERRORS`])를 덧붙입니다.
+//!
+//! 검증 로직을 바꾸면 이 파일의 규칙도 함께 수정해야 합니다.
+//! 규칙이 존재하지 않는 필드/메서드를 가리키면 테스트가 실패합니다.
+
+use anyhow::{anyhow, Result};
+use prost::Message;
+use prost_types::field_descriptor_proto::{Label, Type};
+use prost_types::{DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FileDescriptorSet};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::controller::user_controller::{MAX_REGION_LEN, VALID_LOGIN_TYPES, VALID_REGISTER_TYPES};
+use crate::tool::image_sanitizer::AVATAR_CONTENT_TYPES;
+use shared::service::redis::live_config::MAX_KEY_LEN;
+
+/// 빌드 시 생성된 proto 디스크립터
+const API_DESCRIPTOR: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/api_descriptor.bin"));
+
+/// JSON 스키마 방언
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// 필드 제약 조건
+#[derive(Debug, Clone, Copy)]
+pub enum Constraint {
+    /// 빈 값 불가
+    Required,
+    /// 최대 길이 (문자 수)
+    MaxLength(usize),
+    /// 정수 범위 (양 끝 포함)
+    Range { min: i64, max: i64 },
+    /// 정수 최솟값
+    Minimum(i64),
+    /// 허용 값 목록
+    OneOf(&'static [&'static str]),
+    /// 정규식
+    Pattern(&'static str),
+}
+
+/// 필드 검증 규칙 (`패키지.메시지`, 필드, 제약)
+pub struct FieldRule {
+    pub message: &'static str,
+    pub field: &'static str,
+    pub constraints: &'static [Constraint],
+}
+
+/// 컨트롤러/서비스의 요청 검증 규칙
+pub const FIELD_RULES: &[FieldRule] = &[
+    FieldRule { message: "room.MakeRoomRequest", field: "user_id", constraints: &[Constraint::Minimum(1)] },
+    FieldRule { message: "room.MakeRoomRequest", field: "nick_name", constraints: &[Constraint::Required, Constraint::MaxLength(20)] },
+    FieldRule { message: "room.MakeRoomRequest", field: "room_name", constraints: &[Constraint::Required, Constraint::MaxLength(50)] },
+    FieldRule { message: "room.MakeRoomRequest", field: "max_player_num", constraints: &[Constraint::Range { min: 2, max: 10 }] },
+    FieldRule { message: "room.GetRoomListRequest", field: "last_room_id", constraints: &[Constraint::Minimum(-1)] },
+    FieldRule { message: "user.LoginRequest", field: "login_type", constraints: &[Constraint::OneOf(VALID_LOGIN_TYPES)] },
+    FieldRule { message: "user.LoginRequest", field: "login_token", constraints: &[Constraint::Required, Constraint::MaxLength(1000)] },
+    FieldRule { message: "user.LoginRequest", field: "region", constraints: &[Constraint::MaxLength(MAX_REGION_LEN)] },
+    FieldRule { message: "user.RegisterRequest", field: "login_type", constraints: &[Constraint::OneOf(VALID_REGISTER_TYPES)] },
+    FieldRule { message: "user.RegisterRequest", field: "login_token", constraints: &[Constraint::Required, Constraint::MaxLength(1000)] },
+    FieldRule { message: "user.RegisterRequest", field: "nick_name", constraints: &[Constraint::Required, Constraint::MaxLength(20)] },
+    FieldRule { message: "user.AvatarChunk", field: "content_type", constraints: &[Constraint::OneOf(AVATAR_CONTENT_TYPES)] },
+    FieldRule { message: "experiment.GetAssignmentsRequest", field: "user_id", constraints: &[Constraint::Minimum(1)] },
+    FieldRule { message: "version.CheckVersionRequest", field: "client_version", constraints: &[Constraint::Required, Constraint::Pattern(r"^\d+\.\d+\.\d+$")] },
+    FieldRule { message: "liveops.SetFlagRequest", field: "key", constraints: &[Constraint::Required, Constraint::MaxLength(MAX_KEY_LEN), Constraint::Pattern(r"^[A-Za-z0-9._:/-]+$")] },
+];
+
+/// 메서드가 반환할 수 있는 에러 (gRPC 코드, 조건)
+pub type ErrorDoc = (&'static str, &'static str);
+
+/// 모든 게임 서비스 메서드 공통 에러 (인터셉터)
+const CLIENT_GATE_ERRORS: &[ErrorDoc] = &[
+    ("UNAUTHENTICATED", "내부 전용 서비스에 서비스 토큰 없이 호출"),
+    ("INVALID_ARGUMENT", "x-client-version 형식 오류"),
+    ("FAILED_PRECONDITION", "최소 버전 미만 클라이언트 (x-update-status, x-min-version, x-store-url 메타데이터 포함)"),
+];
+
+/// 메서드별 에러 (`/패키지.서비스/메서드`)
+pub const METHOD_ERRORS: &[(&str, &[ErrorDoc])] = &[
+    ("/room.RoomService/MakeRoom", &[
+        ("INVALID_ARGUMENT", "필드 검증 실패"),
+        ("UNAUTHENTICATED", "JWT 누락/만료"),
+        ("PERMISSION_DENIED", "JWT 사용자와 user_id 불일치"),
+        ("UNAVAILABLE", "Redis/DB 연결 실패"),
+        ("INTERNAL", "방 생성 실패"),
+    ]),
+    ("/room.RoomService/GetRoomList", &[
+        ("INVALID_ARGUMENT", "last_room_id < -1"),
+        ("UNAVAILABLE", "Redis 연결 실패"),
+        ("INTERNAL", "방 목록 조회 실패"),
+    ]),
+    ("/user.UserService/LoginUser", &[
+        ("INVALID_ARGUMENT", "필드 검증 실패 또는 지원하지 않는 login_type"),
+        ("UNAUTHENTICATED", "로그인 토큰 검증 실패"),
+        ("RESOURCE_EXHAUSTED", "로그인 실패 누적으로 잠김 (retry-after 메타데이터) 또는 동시 세션 한도 초과 (SESSION_EVICT_OLDEST=false)"),
+        ("UNAVAILABLE", "Redis/DB 연결 실패"),
+    ]),
+    ("/user.UserService/RegisterUser", &[
+        ("INVALID_ARGUMENT", "필드 검증 실패 또는 지원하지 않는 login_type"),
+        ("ALREADY_EXISTS", "이미 사용 중인 닉네임"),
+        ("UNAVAILABLE", "Redis/DB 연결 실패"),
+        ("INTERNAL", "회원가입 처리 실패"),
+    ]),
+    ("/user.UserService/UploadAvatar", &[
+        ("INVALID_ARGUMENT", "빈 업로드, 크기/가로세로 초과, 지원하지 않는 형식(png/jpeg 외) 또는 선언 형식 불일치, 손상된 이미지"),
+        ("PERMISSION_DENIED", "JWT 사용자와 user_id 불일치"),
+        ("UNIMPLEMENTED", "아바타 업로드 비활성화"),
+        ("UNAVAILABLE", "오브젝트 스토리지 업로드 실패"),
+        ("INTERNAL", "로컬 저장 실패"),
+    ]),
+    ("/moderation.ModerationService/TailChat", &[
+        ("UNAUTHENTICATED", "관리자 토큰 누락/만료"),
+        ("PERMISSION_DENIED", "모더레이터 권한 없음"),
+        ("UNAVAILABLE", "Redis 연결 실패"),
+    ]),
+    ("/stats.StatsService/GetServerStats", &[
+        ("UNAVAILABLE", "Redis 연결 실패"),
+    ]),
+    ("/experiment.ExperimentService/GetAssignments", &[
+        ("INVALID_ARGUMENT", "user_id가 1 미만"),
+        ("PERMISSION_DENIED", "JWT 사용자와 user_id 불일치"),
+        ("UNAVAILABLE", "Redis 연결 실패"),
+    ]),
+    ("/version.VersionService/CheckVersion", &[
+        ("INVALID_ARGUMENT", "client_version 누락 또는 형식 오류"),
+    ]),
+    ("/liveops.LiveOpsService/ListFlags", &[
+        ("UNAUTHENTICATED", "관리자 토큰 누락/만료"),
+        ("PERMISSION_DENIED", "메트릭 조회 권한 없음"),
+    ]),
+    ("/liveops.LiveOpsService/SetFlag", &[
+        ("INVALID_ARGUMENT", "키 형식(영숫자와 ._-:/, 64자 이하) 또는 값 길이(256바이트 이하) 오류"),
+        ("UNAUTHENTICATED", "관리자 토큰 누락/만료"),
+        ("PERMISSION_DENIED", "시스템 설정 권한 없음"),
+        ("UNAVAILABLE", "Redis 연결 실패"),
+    ]),
+];
+
+/// 클라이언트 버전 게이트가 적용되는 서비스
+const GATED_SERVICES: &[&str] = &["room.RoomService", "user.UserService", "experiment.ExperimentService"];
+
+/// 스키마 생성 후 파일로 저장 (`-`이면 표준 출력)
+pub fn write_api_schema(path: &Path) -> Result<()> {
+    let schema = serde_json::to_string_pretty(&generate_api_schema()?)?;
+    if path == Path::new("-") {
+        println!("{schema}");
+    } else {
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, schema)?;
+    }
+    Ok(())
+}
+
+/// 등록된 서비스의 API 스키마 생성
+pub fn generate_api_schema() -> Result<Value> {
+    let descriptors = FileDescriptorSet::decode(API_DESCRIPTOR)
+        .map_err(|e| anyhow!("proto 디스크립터 디코딩 실패: {e}"))?;
+
+    let mut defs = Map::new();
+    let mut services = Vec::new();
+
+    for file in &descriptors.file {
+        let package = file.package();
+        let comments = comments_by_path(file);
+
+        for (index, message) in file.message_type.iter().enumerate() {
+            let name = format!("{package}.{}", message.name());
+            defs.insert(name.clone(), message_schema(&name, message, &comments, &[4, index as i32]));
+        }
+        for (index, enumeration) in file.enum_type.iter().enumerate() {
+            let name = format!("{package}.{}", enumeration.name());
+            defs.insert(name, enum_schema(enumeration, comments.get(&vec![5, index as i32])));
+        }
+
+        for (service_index, service) in file.service.iter().enumerate() {
+            let service_name = format!("{package}.{}", service.name());
+            let methods: Vec<Value> = service
+                .method
+                .iter()
+                .enumerate()
+                .map(|(method_index, method)| {
+                    let path = format!("/{service_name}/{}", method.name());
+                    let mut errors: Vec<Value> = METHOD_ERRORS
+                        .iter()
+                        .find(|(method_path, _)| *method_path == path)
+                        .map(|(_, errors)| errors.iter().map(error_json).collect())
+                        .unwrap_or_default();
+                    if GATED_SERVICES.contains(&service_name.as_str()) {
+                        errors.extend(CLIENT_GATE_ERRORS.iter().map(error_json));
+                    }
+
+                    json!({
+                        "name": method.name(),
+                        "path": path,
+                        "description": comments.get(&vec![6, service_index as i32, 2, method_index as i32]),
+                        "input": type_ref(method.input_type()),
+                        "output": type_ref(method.output_type()),
+                        "client_streaming": method.client_streaming(),
+                        "server_streaming": method.server_streaming(),
+                        "errors": errors,
+                    })
+                })
+                .collect();
+
+            services.push(json!({
+                "name": service_name,
+                "description": comments.get(&vec![6, service_index as i32]),
+                "methods": methods,
+            }));
+        }
+    }
+
+    Ok(json!({
+        "$schema": SCHEMA_DIALECT,
+        "title": "Police Thief gRPC API",
+        "version": env!("CARGO_PKG_VERSION"),
+        "x-services": services,
+        "$defs": defs,
+    }))
+}
+
+fn message_schema(
+    name: &str,
+    message: &DescriptorProto,
+    comments: &HashMap<Vec<i32>, String>,
+    path: &[i32],
+) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for (index, field) in message.field.iter().enumerate() {
+        let mut field_path = path.to_vec();
+        field_path.extend([2, index as i32]);
+
+        let mut schema = field_schema(field);
+        if let Value::Object(object) = &mut schema {
+            object.insert("x-proto-number".into(), field.number().into());
+            if let Some(comment) = comments.get(&field_path) {
+                object.insert("description".into(), comment.clone().into());
+            }
+        }
+
+        let rules = FIELD_RULES
+            .iter()
+            .filter(|rule| rule.message == name && rule.field == field.name())
+            .flat_map(|rule| rule.constraints);
+        for constraint in rules {
+            if matches!(constraint, Constraint::Required) {
+                required.push(field.name().to_string());
+            }
+            apply_constraint(&mut schema, constraint);
+        }
+
+        properties.insert(field.name().to_string(), schema);
+    }
+
+    json!({
+        "type": "object",
+        "description": comments.get(path),
+        "properties": properties,
+        "required": required,
+    })
+}
+
+fn field_schema(field: &FieldDescriptorProto) -> Value {
+    // proto3 JSON 매핑: 64비트 정수는 문자열
+    let scalar = match field.r#type() {
+        Type::String => json!({ "type": "string" }),
+        Type::Bool => json!({ "type": "boolean" }),
+        Type::Int32 | Type::Sint32 | Type::Sfixed32 => json!({ "type": "integer", "format": "int32" }),
+        Type::Uint32 | Type::Fixed32 => json!({ "type": "integer", "format": "uint32", "minimum": 0 }),
+        Type::Int64 | Type::Sint64 | Type::Sfixed64 => json!({ "type": "string", "format": "int64" }),
+        Type::Uint64 | Type::Fixed64 => json!({ "type": "string", "format": "uint64" }),
+        Type::Double | Type::Float => json!({ "type": "number" }),
+        Type::Bytes => json!({ "type": "string", "contentEncoding": "base64" }),
+        Type::Message | Type::Enum | Type::Group => type_ref(field.type_name()),
+    };
+
+    if field.label() == Label::Repeated {
+        json!({ "type": "array", "items": scalar })
+    } else {
+        scalar
+    }
+}
+
+fn enum_schema(enumeration: &EnumDescriptorProto, comment: Option<&String>) -> Value {
+    let values: Vec<&str> = enumeration.value.iter().map(|value| value.name()).collect();
+    json!({
+        "type": "string",
+        "description": comment,
+        "enum": values,
+    })
+}
+
+fn apply_constraint(schema: &mut Value, constraint: &Constraint) {
+    let Value::Object(object) = schema else {
+        return;
+    };
+    match *constraint {
+        Constraint::Required => {
+            object.insert("minLength".into(), 1.into());
+        }
+        Constraint::MaxLength(max) => {
+            object.insert("maxLength".into(), max.into());
+        }
+        Constraint::Range { min, max } => {
+            object.insert("minimum".into(), min.into());
+            object.insert("maximum".into(), max.into());
+        }
+        Constraint::Minimum(min) => {
+            object.insert("minimum".into(), min.into());
+        }
+        Constraint::OneOf(values) => {
+            object.insert("enum".into(), values.into());
+        }
+        Constraint::Pattern(pattern) => {
+            object.insert("pattern".into(), pattern.into());
+        }
+    }
+}
+
+fn error_json(error: &ErrorDoc) -> Value {
+    json!({ "code": error.0, "when": error.1 })
+}
+
+/// `.room.RoomInfo` -> `#/$defs/room.RoomInfo`
+fn type_ref(type_name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", type_name.trim_start_matches('.')) })
+}
+
+/// 디스크립터 경로별 proto 주석 (선행 주석 우선)
+fn comments_by_path(file: &prost_types::FileDescriptorProto) -> HashMap<Vec<i32>, String> {
+    file.source_code_info
+        .iter()
+        .flat_map(|info| &info.location)
+        .filter_map(|location| {
+            let comment = location
+                .leading_comments
+                .as_deref()
+                .or(location.trailing_comments.as_deref())?
+                .trim();
+            (!comment.is_empty()).then(|| (location.path.clone(), comment.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_includes_rules_and_errors() {
+        let schema = generate_api_schema().unwrap();
+        let defs = schema["$defs"].as_object().unwrap();
+
+        let make_room = &defs["room.MakeRoomRequest"];
+        assert_eq!(make_room["properties"]["max_player_num"]["minimum"], 2);
+        assert_eq!(make_room["properties"]["max_player_num"]["maximum"], 10);
+        assert_eq!(make_room["properties"]["room_name"]["maxLength"], 50);
+        assert!(make_room["required"].as_array().unwrap().contains(&json!("nick_name")));
+        assert_eq!(defs["version.UpdateStatus"]["enum"][2], "UPDATE_REQUIRED");
+        assert_eq!(defs["stats.RegionStats"]["properties"]["ccu"]["format"], "int64");
+
+        // 규칙/에러가 실제 proto 정의를 가리키는지 확인
+        for rule in FIELD_RULES {
+            assert!(
+                defs.get(rule.message).is_some_and(|def| def["properties"].get(rule.field).is_some()),
+                "{}.{} 필드가 없습니다",
+                rule.message,
+                rule.field
+            );
+        }
+        let methods: Vec<&Value> = schema["x-services"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|service| service["methods"].as_array().unwrap())
+            .collect();
+        for (path, _) in METHOD_ERRORS {
+            assert!(methods.iter().any(|method| method["path"] == *path), "{path} 메서드가 없습니다");
+        }
+        for method in methods {
+            assert!(!method["errors"].as_array().unwrap().is_empty(), "{} 에러 문서 없음", method["path"]);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! gRPC Server Self-Check
+//!
+//! `grpcserver --check` 실행 시 기동 전 환경을 점검하고 준비 상태 리포트를 출력합니다.
+//! 포트 사용 가능 여부, Redis/DB 연결, JWT 설정, TLS 파일을 확인하며,
+//! 프로필에서 필수가 아닌 항목의 실패는 경고로만 표시합니다.
+
+use std::{env, fmt, fs, net::TcpListener, path::Path, time::Duration};
+use tokio::time::timeout;
+
+use crate::config::{validate_jwt_security_config, GrpcServerConfig};
+
+/// 외부 의존성 연결 타임아웃
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 점검 결과 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    fn icon(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "✅",
+            CheckStatus::Warn => "⚠️",
+            CheckStatus::Fail => "❌",
+        }
+    }
+}
+
+/// 개별 점검 항목
+#[derive(Debug, Clone)]
+pub struct CheckItem {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl CheckItem {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self { name, status: CheckStatus::Pass, detail: detail.into() }
+    }
+
+    /// 필수 항목이면 실패, 아니면 경고
+    fn failed(name: &'static str, required: bool, detail: impl Into<String>) -> Self {
+        let status = if required { CheckStatus::Fail } else { CheckStatus::Warn };
+        Self { name, status, detail: detail.into() }
+    }
+}
+
+/// 준비 상태 리포트
+#[derive(Debug, Clone)]
+pub struct ReadinessReport {
+    pub profile: String,
+    pub items: Vec<CheckItem>,
+}
+
+impl ReadinessReport {
+    /// 실패 항목이 없으면 준비 완료
+    pub fn is_ready(&self) -> bool {
+        self.items.iter().all(|item| item.status != CheckStatus::Fail)
+    }
+}
+
+impl fmt::Display for ReadinessReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "gRPC 서버 자체 점검 (프로필: {})", self.profile)?;
+        for item in &self.items {
+            writeln!(f, "  {} {:<10} {}", item.status.icon(), item.name, item.detail)?;
+        }
+        let verdict = if self.is_ready() { "✅ 준비 완료" } else { "❌ 준비 안 됨" };
+        write!(f, "결과: {verdict}")
+    }
+}
+
+/// 전체 점검 실행
+pub async fn run_self_check(config: &GrpcServerConfig) -> ReadinessReport {
+    let items = vec![
+        check_port(config),
+        check_redis(config.require_redis).await,
+        check_database(config.require_database).await,
+        check_jwt(),
+        check_tls(config),
+    ];
+
+    ReadinessReport {
+        profile: config.profile.to_string(),
+        items,
+    }
+}
+
+/// 바인딩 포트 사용 가능 여부
+fn check_port(config: &GrpcServerConfig) -> CheckItem {
+    match TcpListener::bind(config.addr) {
+        Ok(_) => CheckItem::pass("port", format!("{} 바인딩 가능", config.addr)),
+        Err(e) => CheckItem::failed("port", true, format!("{} 바인딩 불가: {e}", config.addr)),
+    }
+}
+
+/// Redis 연결 및 PING
+async fn check_redis(required: bool) -> CheckItem {
+    let host = env::var("redis_host").unwrap_or_else(|_| "localhost".to_string());
+    let port = env::var("redis_port").unwrap_or_else(|_| "6379".to_string());
+    let url = format!("redis://{host}:{port}");
+
+    let ping = async {
+        let client = redis::Client::open(url.as_str())?;
+        let mut conn = client.get_multiplexed_tokio_connection().await?;
+        redis::cmd("PING").query_async::<_, String>(&mut conn).await
+    };
+
+    match timeout(CONNECT_TIMEOUT, ping).await {
+        Ok(Ok(_)) => CheckItem::pass("redis", format!("{host}:{port} 응답")),
+        Ok(Err(e)) => CheckItem::failed("redis", required, format!("{host}:{port} 연결 실패: {e}")),
+        Err(_) => CheckItem::failed("redis", required, format!("{host}:{port} 연결 시간 초과")),
+    }
+}
+
+/// DB 연결 및 헬스 체크
+async fn check_database(required: bool) -> CheckItem {
+    let connect = async {
+        let db = shared::config::db::DbConfig::new().await?;
+        let healthy = db.health_check().await;
+        db.close().await;
+        healthy.map(|_| format!("{}:{}/{} 응답", db.host, db.port, db.database))
+    };
+
+    match timeout(CONNECT_TIMEOUT, connect).await {
+        Ok(Ok(detail)) => CheckItem::pass("database", detail),
+        Ok(Err(e)) => CheckItem::failed("database", required, format!("연결 실패: {e}")),
+        Err(_) => CheckItem::failed("database", required, "연결 시간 초과"),
+    }
+}
+
+/// JWT 보안 설정
+fn check_jwt() -> CheckItem {
+    match validate_jwt_security_config() {
+        Ok(()) => CheckItem::pass("jwt", "보안 설정 유효"),
+        Err(e) => {
+            let reason = e.to_string();
+            let first_line = reason.lines().next().unwrap_or_default();
+            CheckItem::failed("jwt", true, first_line)
+        }
+    }
+}
+
+/// TLS 인증서/개인키 파일
+fn check_tls(config: &GrpcServerConfig) -> CheckItem {
+    let (cert, key) = match (&config.tls_cert_path, &config.tls_key_path) {
+        (Some(cert), Some(key)) => (cert, key),
+        (None, None) => {
+            return CheckItem::failed("tls", config.require_tls, "grpc_tls_cert / grpc_tls_key 미설정");
+        }
+        _ => {
+            return CheckItem::failed("tls", true, "grpc_tls_cert와 grpc_tls_key는 함께 설정해야 합니다");
+        }
+    };
+
+    for path in [cert, key] {
+        if let Err(detail) = check_pem_file(path) {
+            return CheckItem::failed("tls", true, detail);
+        }
+    }
+    CheckItem::pass("tls", format!("{} / {} 확인", cert.display(), key.display()))
+}
+
+fn check_pem_file(path: &Path) -> Result<(), String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("{} 읽기 실패: {e}", path.display()))?;
+    if !contents.contains("-----BEGIN ") {
+        return Err(format!("{}: PEM 형식이 아닙니다", path.display()));
+    }
+    Ok(())
+}
@@ -0,0 +1,199 @@
+//! Avatar Service Business Logic
+//!
+//! 사용자 아바타 업로드를 처리하는 비즈니스 로직입니다.
+//! 크기/형식을 검증하고 다시 인코딩한 이미지를 저장소에 올린 뒤,
+//! CDN URL을 Redis(`user:{user_id}:avatar`)에 기록해 로그인 응답(프로필)에 포함합니다.
+//! 새 URL을 기록하면 이전 아바타 객체는 저장소에서 삭제합니다.
+//!
+//! Redis를 쓸 수 없으면 URL은 프로세스 메모리에만 기록합니다.
+
+use redis::AsyncCommands;
+use sha2::{Digest, Sha256};
+use shared::config::connection_pool::ConnectionPool;
+use shared::tool::error::AppError;
+use shared::tool::high_performance::CpuTaskPool;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+use crate::service::avatar_storage::{AvatarStorage, LocalDirStorage, S3Config, S3Storage};
+use crate::tool::image_sanitizer::{sanitize_image, SanitizedImage};
+
+/// 아바타 저장소 종류
+#[derive(Debug, Clone)]
+pub enum AvatarStorageKind {
+    /// 로컬 디렉터리 (`grpc_avatar_dir`)
+    Local { dir: String },
+    /// S3 호환 오브젝트 스토리지 (`grpc_avatar_s3_*`)
+    S3(S3Config),
+}
+
+impl AvatarStorageKind {
+    /// 설정된 저장소 열기
+    pub fn open(&self) -> Arc<dyn AvatarStorage> {
+        match self {
+            AvatarStorageKind::Local { dir } => Arc::new(LocalDirStorage::new(dir)),
+            AvatarStorageKind::S3(s3) => Arc::new(S3Storage::new(s3.clone())),
+        }
+    }
+}
+
+/// 아바타 업로드 설정
+#[derive(Debug, Clone)]
+pub struct AvatarConfig {
+    pub storage: AvatarStorageKind,
+    /// 업로드 최대 크기 (`grpc_avatar_max_bytes`)
+    pub max_bytes: usize,
+    /// 최대 가로/세로 픽셀 (`grpc_avatar_max_dimension`)
+    pub max_dimension: u32,
+    /// 저장 키 앞에 붙일 CDN 주소 (`grpc_avatar_cdn_url`)
+    pub cdn_base_url: String,
+}
+
+impl Default for AvatarConfig {
+    fn default() -> Self {
+        Self {
+            storage: AvatarStorageKind::Local { dir: "data/avatars".to_string() },
+            max_bytes: 2 * 1024 * 1024,
+            max_dimension: 1024,
+            cdn_base_url: "http://localhost:8080".to_string(),
+        }
+    }
+}
+
+/// 저장된 아바타
+#[derive(Debug, Clone)]
+pub struct StoredAvatar {
+    pub url: String,
+    pub content_type: &'static str,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Avatar Service 비즈니스 로직
+pub struct AvatarService {
+    storage: Arc<dyn AvatarStorage>,
+    config: AvatarConfig,
+    /// Redis를 쓸 수 없을 때의 URL 기록 (user_id -> URL)
+    fallback_urls: Mutex<HashMap<i32, String>>,
+    /// 이미지 검증/재인코딩을 실행할 CPU 작업 풀 (없으면 요청 태스크에서 직접 실행)
+    cpu_pool: Option<Arc<CpuTaskPool>>,
+}
+
+impl AvatarService {
+    /// 설정의 저장소 종류로 서비스 생성
+    pub fn from_config(config: AvatarConfig) -> Self {
+        Self::new(config.storage.open(), config)
+    }
+
+    pub fn new(storage: Arc<dyn AvatarStorage>, config: AvatarConfig) -> Self {
+        Self { storage, config, fallback_urls: Mutex::new(HashMap::new()), cpu_pool: None }
+    }
+
+    /// 이미지 처리를 CPU 작업 풀에서 실행
+    pub fn with_cpu_pool(mut self, pool: Arc<CpuTaskPool>) -> Self {
+        self.cpu_pool = Some(pool);
+        self
+    }
+
+    /// 업로드 최대 크기 (스트림 수신 중 초과 여부 확인용)
+    pub fn max_bytes(&self) -> usize {
+        self.config.max_bytes
+    }
+
+    /// 아바타 검증, 정리, 저장 후 URL 기록
+    ///
+    /// # Arguments
+    /// * `user_id` - 아바타 소유자
+    /// * `content_type` - 클라이언트가 선언한 Content-Type
+    /// * `data` - 업로드된 원본 이미지
+    pub async fn upload(&self, user_id: i32, content_type: &str, data: &[u8]) -> Result<StoredAvatar, AppError> {
+        if data.is_empty() {
+            return Err(AppError::MissingField("avatar data".to_string()));
+        }
+        if data.len() > self.config.max_bytes {
+            return Err(AppError::InvalidInput(format!(
+                "아바타 크기 {}바이트가 최대 {}바이트를 초과합니다",
+                data.len(),
+                self.config.max_bytes
+            )));
+        }
+
+        let max_dimension = self.config.max_dimension;
+        let sanitized = match &self.cpu_pool {
+            Some(pool) => {
+                let (content_type, data) = (content_type.to_string(), data.to_vec());
+                pool.run(move || sanitize_image(&content_type, &data, max_dimension)).await
+                    .map_err(|e| AppError::ServiceUnavailable(e.to_string()))?
+            }
+            None => sanitize_image(content_type, data, max_dimension),
+        };
+        let SanitizedImage { format, width, height, data } = sanitized
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+
+        let digest = hex::encode(&Sha256::digest(&data)[..12]);
+        let key = format!("{}{digest}.{}", avatar_prefix(user_id), format.extension());
+        let size_bytes = data.len();
+        self.storage.put(&key, format.content_type(), data).await?;
+
+        let previous = self.avatar_url(user_id).await;
+        let url = format!("{}/{}", self.config.cdn_base_url.trim_end_matches('/'), key);
+        self.record_url(user_id, &url).await;
+
+        // 같은 내용이면 키가 같으므로 유지, 삭제 실패는 업로드 결과에 영향 없음
+        if let Some(previous_key) = previous.as_deref().and_then(|url| self.object_key(user_id, url)) {
+            if previous_key != key {
+                if let Err(e) = self.storage.delete(previous_key).await {
+                    warn!("이전 아바타 삭제 실패: user_id={}, key={}, error={}", user_id, previous_key, e);
+                }
+            }
+        }
+        info!("아바타 업로드 완료: user_id={}, {}x{}, {}바이트, 저장소={}", user_id, width, height, size_bytes, self.storage.name());
+
+        Ok(StoredAvatar { url, content_type: format.content_type(), width, height })
+    }
+
+    /// 사용자 아바타 URL (없으면 None)
+    pub async fn avatar_url(&self, user_id: i32) -> Option<String> {
+        if let Ok(redis_config) = ConnectionPool::get_config().await {
+            let mut conn = redis_config.get_connection();
+            match conn.get::<_, Option<String>>(avatar_key(user_id)).await {
+                Ok(Some(url)) => return Some(url),
+                Ok(None) => {}
+                Err(e) => warn!("아바타 URL 조회 실패: user_id={}, error={}", user_id, e),
+            }
+        }
+        self.fallback_urls.lock().ok()?.get(&user_id).cloned()
+    }
+
+    /// 이 서비스가 기록한 URL의 저장 키 (사용자의 `avatars/{user_id}/` 아래가 아니면 None)
+    fn object_key<'a>(&self, user_id: i32, url: &'a str) -> Option<&'a str> {
+        let key = url.strip_prefix(self.config.cdn_base_url.trim_end_matches('/'))?.strip_prefix('/')?;
+        (key.starts_with(&avatar_prefix(user_id)) && !key.contains("..")).then_some(key)
+    }
+
+    async fn record_url(&self, user_id: i32, url: &str) {
+        if let Ok(mut urls) = self.fallback_urls.lock() {
+            urls.insert(user_id, url.to_string());
+        }
+        match ConnectionPool::get_config().await {
+            Ok(redis_config) => {
+                let mut conn = redis_config.get_connection();
+                if let Err(e) = conn.set::<_, _, ()>(avatar_key(user_id), url).await {
+                    warn!("아바타 URL 기록 실패 (메모리에만 기록): user_id={}, error={}", user_id, e);
+                }
+            }
+            Err(e) => warn!("아바타 URL 기록 생략 (Redis 설정 실패): {}", e),
+        }
+    }
+}
+
+/// 사용자 아바타 객체의 저장 키 접두사 (`avatars/{user_id}/`)
+pub fn avatar_prefix(user_id: i32) -> String {
+    format!("avatars/{user_id}/")
+}
+
+/// 아바타 URL 키 (`user:{user_id}:avatar`)
+fn avatar_key(user_id: i32) -> String {
+    format!("user:{user_id}:avatar")
+}
@@ -0,0 +1,364 @@
+//! Avatar Storage Backends
+//!
+//! 정리된 아바타 이미지를 저장하는 백엔드입니다.
+//! 로컬 디렉터리(개발/단일 서버)와 S3 호환 오브젝트 스토리지(AWS S3, MinIO, R2 등)를 지원합니다.
+//!
+//! 저장 키는 `avatars/{user_id}/{내용 해시}.{확장자}` 형식이라 내용이 바뀌면 URL도 바뀝니다.
+//! CDN에서는 이 경로를 변경 불가(immutable)로 캐시할 수 있습니다.
+
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use shared::tool::error::AppError;
+use std::path::PathBuf;
+use tracing::debug;
+
+/// CDN 캐시 헤더 (내용 해시 경로이므로 장기 캐시)
+pub const AVATAR_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// 빈 본문의 SHA-256 (본문 없는 S3 요청 서명용)
+const EMPTY_PAYLOAD_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// 아바타 저장 백엔드
+#[tonic::async_trait]
+pub trait AvatarStorage: Send + Sync {
+    /// 객체 저장 (같은 키가 있으면 덮어씀)
+    async fn put(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<(), AppError>;
+
+    /// 객체 읽기 (없으면 None)
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, AppError>;
+
+    /// `prefix`(`/`로 끝나는 디렉터리 형태)로 시작하는 객체 키 목록 (키 순)
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, AppError>;
+
+    /// 객체 삭제 (없는 키는 성공으로 처리)
+    async fn delete(&self, key: &str) -> Result<(), AppError>;
+
+    /// 로그용 백엔드 이름
+    fn name(&self) -> &'static str;
+}
+
+/// 로컬 디렉터리 저장소
+///
+/// 정적 파일 서버나 CDN 오리진이 이 디렉터리를 그대로 서빙한다고 가정합니다.
+pub struct LocalDirStorage {
+    root: PathBuf,
+}
+
+impl LocalDirStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+#[tonic::async_trait]
+impl AvatarStorage for LocalDirStorage {
+    async fn put(&self, key: &str, _content_type: &str, data: Vec<u8>) -> Result<(), AppError> {
+        let path = self.root.join(key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await
+                .map_err(|e| AppError::InternalError(format!("아바타 디렉터리 생성 실패 ({}): {e}", parent.display())))?;
+        }
+        // 임시 파일에 쓴 뒤 이동해 CDN이 반쯤 쓰인 파일을 가져가지 않도록 함
+        let temp = path.with_extension("tmp");
+        tokio::fs::write(&temp, data).await
+            .map_err(|e| AppError::InternalError(format!("아바타 저장 실패 ({}): {e}", temp.display())))?;
+        tokio::fs::rename(&temp, &path).await
+            .map_err(|e| AppError::InternalError(format!("아바타 저장 실패 ({}): {e}", path.display())))?;
+        debug!("아바타 저장: {}", path.display());
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+        let path = self.root.join(key);
+        match tokio::fs::read(&path).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(AppError::InternalError(format!("아바타 읽기 실패 ({}): {e}", path.display()))),
+        }
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, AppError> {
+        let dir = self.root.join(prefix);
+        let mut entries = match tokio::fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(AppError::InternalError(format!("아바타 목록 조회 실패 ({}): {e}", dir.display()))),
+        };
+        let mut keys = Vec::new();
+        while let Some(entry) = entries.next_entry().await
+            .map_err(|e| AppError::InternalError(format!("아바타 목록 조회 실패 ({}): {e}", dir.display())))?
+        {
+            if entry.file_type().await.is_ok_and(|kind| kind.is_file()) {
+                keys.push(format!("{prefix}{}", entry.file_name().to_string_lossy()));
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        let path = self.root.join(key);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => {
+                debug!("아바타 삭제: {}", path.display());
+                Ok(())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(AppError::InternalError(format!("아바타 삭제 실패 ({}): {e}", path.display()))),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "local"
+    }
+}
+
+/// S3 호환 저장소 설정
+#[derive(Debug, Clone)]
+pub struct S3Config {
+    /// 엔드포인트 (예: `https://s3.ap-northeast-2.amazonaws.com`, `http://minio:9000`)
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+/// S3 호환 저장소 (path-style 요청, SigV4 서명)
+pub struct S3Storage {
+    config: S3Config,
+    client: reqwest::Client,
+}
+
+impl S3Storage {
+    pub fn new(config: S3Config) -> Self {
+        Self { config, client: reqwest::Client::new() }
+    }
+
+    /// SigV4 서명을 붙인 객체 요청 (`content_type`이 있으면 업로드용 캐시/형식 헤더 포함)
+    ///
+    /// `key`가 비어 있으면 버킷 자체에 대한 요청(목록 조회 등)입니다.
+    fn signed_request(
+        &self,
+        method: reqwest::Method,
+        key: &str,
+        query: &[(&str, &str)],
+        content_type: Option<&str>,
+        payload_hash: &str,
+    ) -> Result<reqwest::RequestBuilder, AppError> {
+        let endpoint = url::Url::parse(&self.config.endpoint)
+            .map_err(|e| AppError::InternalError(format!("잘못된 S3 엔드포인트: {e}")))?;
+        let host = match (endpoint.host_str(), endpoint.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(AppError::InternalError("S3 엔드포인트에 호스트가 없습니다".to_string())),
+        };
+        let path = format!("/{}/{}", self.config.bucket, key);
+        let query = canonical_query(query);
+        let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+        let mut headers = Vec::with_capacity(5);
+        if let Some(content_type) = content_type {
+            headers.push(("cache-control", AVATAR_CACHE_CONTROL));
+            headers.push(("content-type", content_type));
+        }
+        headers.extend([("host", host.as_str()), ("x-amz-content-sha256", payload_hash), ("x-amz-date", amz_date.as_str())]);
+        let authorization = sigv4_authorization(&SigV4Request {
+            method: method.as_str(),
+            path: &path,
+            query: &query,
+            headers: &headers,
+            payload_hash,
+            amz_date: &amz_date,
+            region: &self.config.region,
+            access_key: &self.config.access_key,
+            secret_key: &self.config.secret_key,
+        });
+
+        let mut url = format!("{}{}", self.config.endpoint.trim_end_matches('/'), path);
+        if !query.is_empty() {
+            url = format!("{url}?{query}");
+        }
+        let mut request = self.client.request(method, &url).header("authorization", authorization);
+        for (name, value) in headers.iter().filter(|(name, _)| *name != "host") {
+            request = request.header(*name, *value);
+        }
+        Ok(request)
+    }
+}
+
+#[tonic::async_trait]
+impl AvatarStorage for S3Storage {
+    async fn put(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<(), AppError> {
+        let payload_hash = hex::encode(Sha256::digest(&data));
+        let request = self.signed_request(reqwest::Method::PUT, key, &[], Some(content_type), &payload_hash)?;
+        let response = request.body(data).send().await
+            .map_err(|e| AppError::ExternalApiError(format!("S3 업로드 실패: {e}")))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::ExternalApiError(format!("S3 업로드 실패 ({status}): {body}")));
+        }
+        debug!("아바타 업로드: s3://{}/{}", self.config.bucket, key);
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, AppError> {
+        let response = self.signed_request(reqwest::Method::GET, key, &[], None, EMPTY_PAYLOAD_SHA256)?
+            .send().await
+            .map_err(|e| AppError::ExternalApiError(format!("S3 다운로드 실패: {e}")))?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::ExternalApiError(format!("S3 다운로드 실패 ({status}): {body}")));
+        }
+        let data = response.bytes().await
+            .map_err(|e| AppError::ExternalApiError(format!("S3 다운로드 실패: {e}")))?;
+        Ok(Some(data.to_vec()))
+    }
+
+    /// ListObjectsV2 (1000개씩 이어 받기)
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, AppError> {
+        let mut keys = Vec::new();
+        let mut continuation: Option<String> = None;
+        loop {
+            let mut query = vec![("list-type", "2"), ("prefix", prefix)];
+            if let Some(token) = &continuation {
+                query.push(("continuation-token", token.as_str()));
+            }
+            let response = self.signed_request(reqwest::Method::GET, "", &query, None, EMPTY_PAYLOAD_SHA256)?
+                .send().await
+                .map_err(|e| AppError::ExternalApiError(format!("S3 목록 조회 실패: {e}")))?;
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            if !status.is_success() {
+                return Err(AppError::ExternalApiError(format!("S3 목록 조회 실패 ({status}): {body}")));
+            }
+
+            keys.extend(xml_values(&body, "Key"));
+            continuation = match xml_values(&body, "IsTruncated").first().map(String::as_str) {
+                Some("true") => xml_values(&body, "NextContinuationToken").into_iter().next(),
+                _ => None,
+            };
+            if continuation.is_none() {
+                break;
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        let response = self.signed_request(reqwest::Method::DELETE, key, &[], None, EMPTY_PAYLOAD_SHA256)?
+            .send().await
+            .map_err(|e| AppError::ExternalApiError(format!("S3 삭제 실패: {e}")))?;
+        // S3는 없는 객체도 204로 응답하지만 일부 호환 구현은 404로 응답
+        let status = response.status();
+        if !status.is_success() && status != reqwest::StatusCode::NOT_FOUND {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::ExternalApiError(format!("S3 삭제 실패 ({status}): {body}")));
+        }
+        debug!("아바타 삭제: s3://{}/{}", self.config.bucket, key);
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "s3"
+    }
+}
+
+/// SigV4 서명 대상 요청
+pub(crate) struct SigV4Request<'a> {
+    pub method: &'a str,
+    /// URI 인코딩된 경로
+    pub path: &'a str,
+    /// 정규화된 쿼리 문자열 (`canonical_query`, 없으면 빈 문자열)
+    pub query: &'a str,
+    /// 소문자 이름순으로 정렬된 서명 헤더
+    pub headers: &'a [(&'a str, &'a str)],
+    pub payload_hash: &'a str,
+    /// `YYYYMMDDTHHMMSSZ`
+    pub amz_date: &'a str,
+    pub region: &'a str,
+    pub access_key: &'a str,
+    pub secret_key: &'a str,
+}
+
+/// AWS Signature Version 4 `Authorization` 헤더 값 (S3 요청)
+pub(crate) fn sigv4_authorization(req: &SigV4Request<'_>) -> String {
+    let date = &req.amz_date[..8];
+    let scope = format!("{date}/{}/s3/aws4_request", req.region);
+    let canonical_headers: String = req.headers.iter().map(|(name, value)| format!("{name}:{}\n", value.trim())).collect();
+    let signed_headers = req.headers.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(";");
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        req.method, req.path, req.query, canonical_headers, signed_headers, req.payload_hash
+    );
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        req.amz_date,
+        scope,
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let mut key = hmac_sha256(format!("AWS4{}", req.secret_key).as_bytes(), date.as_bytes());
+    for part in [req.region, "s3", "aws4_request"] {
+        key = hmac_sha256(&key, part.as_bytes());
+    }
+    let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        req.access_key
+    )
+}
+
+/// SigV4 정규 쿼리 문자열 (이름순 정렬, 값은 RFC 3986 인코딩)
+pub(crate) fn canonical_query(query: &[(&str, &str)]) -> String {
+    let mut pairs: Vec<String> = query.iter().map(|(name, value)| format!("{}={}", uri_encode(name), uri_encode(value))).collect();
+    pairs.sort();
+    pairs.join("&")
+}
+
+/// 비예약 문자(`A-Z a-z 0-9 - _ . ~`) 외에는 모두 `%XX`로 인코딩
+fn uri_encode(value: &str) -> String {
+    value.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// S3 XML 응답에서 `<tag>값</tag>` 값 목록 (엔티티 복원)
+pub(crate) fn xml_values(xml: &str, tag: &str) -> Vec<String> {
+    let (open, close) = (format!("<{tag}>"), format!("</{tag}>"));
+    let mut values = Vec::new();
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(&close) else {
+            break;
+        };
+        values.push(
+            rest[..end]
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&apos;", "'")
+                .replace("&amp;", "&"),
+        );
+        rest = &rest[end + close.len()..];
+    }
+    values
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC은 모든 키 길이를 허용합니다");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
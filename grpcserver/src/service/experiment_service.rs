@@ -0,0 +1,126 @@
+//! Experiment Service Business Logic
+//!
+//! 실험(A/B 테스트) 변형 배정을 담당하는 비즈니스 로직입니다.
+//! 배정 규칙은 `shared::service::redis::experiments`에 있고, 이 서비스는 실험 목록 파일을 읽어
+//! Redis에 게시하고 사용자별 배정을 저장/조회합니다. 게임 서버도 같은 Redis 키를 읽습니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::info;
+use shared::tool::error::AppError;
+use shared::config::connection_pool::ConnectionPool;
+use shared::service::redis::experiments::{ExperimentCatalog, ExperimentStore};
+
+/// 배정 결과 (실험 키, 변형 이름, 변형 파라미터)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignedVariant {
+    pub experiment: String,
+    pub variant: String,
+    pub params: BTreeMap<String, String>,
+}
+
+/// Experiment Service 비즈니스 로직
+///
+/// 모의 모드에서는 Redis 대신 프로세스 메모리에 배정을 저장합니다.
+#[derive(Default)]
+pub struct ExperimentService {
+    /// 실험 목록
+    catalog: ExperimentCatalog,
+    /// 모의 모드 배정 저장소 (user_id -> 실험 키 -> 변형, None이면 Redis 사용)
+    mock_assignments: Option<Mutex<HashMap<u32, BTreeMap<String, String>>>>,
+}
+
+impl ExperimentService {
+    /// 새로운 ExperimentService 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `catalog` - 검증된 실험 목록
+    pub fn new(catalog: ExperimentCatalog) -> Self {
+        Self { catalog, mock_assignments: None }
+    }
+
+    /// 모의 서비스 사용 여부 설정
+    pub fn with_mock_services(mut self, enabled: bool) -> Self {
+        self.mock_assignments = enabled.then(Default::default);
+        self
+    }
+
+    /// 실험 목록을 Redis에 게시합니다. (게임 서버의 변형 파라미터 조회용)
+    pub async fn publish_catalog(&self) -> Result<(), AppError> {
+        if self.mock_assignments.is_some() {
+            return Ok(());
+        }
+        ExperimentStore::new(redis_config().await?).publish_catalog(&self.catalog).await
+    }
+
+    /// 사용자의 배정을 조회합니다.
+    ///
+    /// 아직 배정되지 않은 실험은 이번에 배정해 저장하고, 목록에서 사라진 실험/변형은 제외합니다.
+    ///
+    /// # Arguments
+    /// * `user_id` - 사용자 ID
+    ///
+    /// # Returns
+    /// * `Result<Vec<AssignedVariant>, AppError>` - 참여 중인 실험의 배정 (실험 목록 순서)
+    pub async fn assignments(&self, user_id: u32) -> Result<Vec<AssignedVariant>, AppError> {
+        let stored = match &self.mock_assignments {
+            Some(mock) => {
+                let mut mock = mock.lock().map_err(|_| AppError::InternalError("모의 배정 저장소 잠금 실패".to_string()))?;
+                let stored = mock.entry(user_id).or_default();
+                let assigned = self.catalog.assign(user_id, stored);
+                stored.extend(assigned);
+                stored.clone()
+            }
+            None => {
+                let store = ExperimentStore::new(redis_config().await?);
+                let existing = store.assignments(user_id).await?;
+                let assigned = self.catalog.assign(user_id, &existing);
+                if assigned.is_empty() {
+                    existing
+                } else {
+                    info!("실험 배정: user_id={}, {:?}", user_id, assigned);
+                    store.save_assignments(user_id, &assigned).await?
+                }
+            }
+        };
+        Ok(self.resolve(&stored))
+    }
+
+    /// 저장된 배정을 현재 실험 목록 기준으로 변환합니다.
+    fn resolve(&self, stored: &BTreeMap<String, String>) -> Vec<AssignedVariant> {
+        self.catalog.experiments.iter()
+            .filter_map(|experiment| {
+                let variant = experiment.variant(stored.get(&experiment.key)?)?;
+                Some(AssignedVariant {
+                    experiment: experiment.key.clone(),
+                    variant: variant.name.clone(),
+                    params: variant.params.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 실험 목록 파일(TOML)을 읽고 검증합니다. (파일이 없으면 빈 목록)
+pub fn load_catalog(path: &Path) -> Result<ExperimentCatalog, AppError> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(ExperimentCatalog::default()),
+        Err(e) => return Err(AppError::InternalError(format!("실험 목록 읽기 실패 ({}): {e}", path.display()))),
+    };
+    parse_catalog(&content)
+}
+
+/// 실험 목록(TOML) 파싱 및 검증
+pub fn parse_catalog(content: &str) -> Result<ExperimentCatalog, AppError> {
+    let catalog: ExperimentCatalog = toml::from_str(content)
+        .map_err(|e| AppError::InvalidFormat(format!("실험 목록 형식 오류: {e}")))?;
+    catalog.validate()?;
+    Ok(catalog)
+}
+
+async fn redis_config() -> Result<shared::config::redis_config::RedisConfig, AppError> {
+    ConnectionPool::get_config().await
+        .map_err(|e| AppError::RedisConnection(e.to_string()))
+}
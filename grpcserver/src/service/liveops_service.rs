@@ -0,0 +1,57 @@
+//! LiveOps Service Business Logic
+//!
+//! 라이브 설정(킬 스위치 등) 조회와 변경을 담당하는 비즈니스 로직입니다.
+//! 변경은 Redis를 거쳐 모든 게임 서버에 수 초 안에 반영되고 감사 로그에 남습니다.
+
+use shared::service::redis::live_config::{LiveConfig, LiveConfigChanged};
+use shared::tool::error::AppError;
+
+/// 설정 변경 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlagChange {
+    pub change: LiveConfigChanged,
+    /// 변경 전 값 (없었으면 None)
+    pub previous: Option<String>,
+}
+
+/// LiveOps Service 비즈니스 로직
+#[derive(Clone)]
+pub struct LiveOpsService {
+    /// 이 서버의 라이브 설정 (PolicyLayer와 공유)
+    config: LiveConfig,
+}
+
+impl LiveOpsService {
+    /// 새로운 LiveOpsService 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `config` - 이 서버가 동기화하는 라이브 설정
+    pub fn new(config: LiveConfig) -> Self {
+        Self { config }
+    }
+
+    /// 접두어로 시작하는 설정을 키 순서로 반환합니다. (빈 접두어는 전체)
+    pub fn list_flags(&self, prefix: &str) -> Vec<(String, String)> {
+        self.config
+            .snapshot()
+            .into_iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .collect()
+    }
+
+    /// 설정을 변경합니다.
+    ///
+    /// # Arguments
+    /// * `key` - 설정 키 (예: "kill.tcp:chat")
+    /// * `value` - 새 값, None이면 삭제
+    /// * `actor` - 변경한 운영자 (감사 로그용)
+    ///
+    /// # Returns
+    /// * `Result<FlagChange, AppError>` - 키/값이 유효하지 않거나 Redis 저장에 실패하면 에러
+    pub async fn set_flag(&self, key: &str, value: Option<&str>, actor: &str) -> Result<FlagChange, AppError> {
+        let key = key.trim();
+        let previous = self.config.get_str(key);
+        let change = self.config.set(key, value, actor).await?;
+        Ok(FlagChange { change, previous })
+    }
+}
@@ -1,2 +1,9 @@
 pub mod room_service;
 pub mod user_service;
+pub mod moderation_service;
+pub mod stats_service;
+pub mod version_service;
+pub mod avatar_service;
+pub mod avatar_storage;
+pub mod experiment_service;
+pub mod liveops_service;
\ No newline at end of file
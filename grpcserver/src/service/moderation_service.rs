@@ -0,0 +1,116 @@
+//! Moderation Service Business Logic
+//!
+//! 관리자 대시보드의 실시간 채팅 모더레이션을 담당하는 비즈니스 로직입니다.
+//! 공유 이벤트 버스의 채팅 이벤트를 방/사용자 기준으로 거르고, 개인정보를 가린 뒤 전달합니다.
+
+use regex::Regex;
+use std::sync::OnceLock;
+use tokio::sync::mpsc;
+use tracing::debug;
+use shared::tool::error::AppError;
+use shared::config::connection_pool::ConnectionPool;
+use shared::service::redis::event_bus::{ChatEvent, EventBus};
+
+/// 구독자별 전달 버퍼 크기
+const TAIL_BUFFER: usize = 64;
+
+/// 채팅 이벤트 필터
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChatFilter {
+    /// 특정 방만 구독
+    pub room_id: Option<u32>,
+    /// 특정 사용자만 구독
+    pub user_id: Option<u32>,
+}
+
+impl ChatFilter {
+    /// 요청 값으로 필터를 생성합니다. (0 이하는 필터 없음)
+    pub fn from_request(room_id: i32, user_id: i32) -> Self {
+        Self {
+            room_id: u32::try_from(room_id).ok().filter(|id| *id > 0),
+            user_id: u32::try_from(user_id).ok().filter(|id| *id > 0),
+        }
+    }
+
+    /// 이벤트가 필터 조건에 맞는지 확인합니다.
+    pub fn matches(&self, event: &ChatEvent) -> bool {
+        self.room_id.map_or(true, |id| id == event.room_id)
+            && self.user_id.map_or(true, |id| id == event.user_id)
+    }
+}
+
+/// Moderation Service 비즈니스 로직
+#[derive(Default)]
+pub struct ModerationService;
+
+impl ModerationService {
+    /// 새로운 ModerationService 인스턴스를 생성합니다.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 채팅 이벤트를 구독합니다.
+    ///
+    /// 필터에 맞는 이벤트만 개인정보를 가려서 전달하며, 수신 측이 닫히면 구독을 종료합니다.
+    ///
+    /// # Arguments
+    /// * `filter` - 방/사용자 필터
+    ///
+    /// # Returns
+    /// * `Result<mpsc::Receiver<ChatEvent>, AppError>` - 가려진 채팅 이벤트 수신기
+    pub async fn tail_chat(&self, filter: ChatFilter) -> Result<mpsc::Receiver<ChatEvent>, AppError> {
+        let redis_config = ConnectionPool::get_config().await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        let mut events = EventBus::new(redis_config).subscribe_chat().await?;
+
+        let (tx, rx) = mpsc::channel(TAIL_BUFFER);
+        tokio::spawn(async move {
+            while let Some(event) = events.recv().await {
+                if !filter.matches(&event) {
+                    continue;
+                }
+                if tx.send(redact(event)).await.is_err() {
+                    break;
+                }
+            }
+            debug!("채팅 모더레이션 구독 종료: {:?}", filter);
+        });
+
+        Ok(rx)
+    }
+}
+
+/// 채팅 이벤트의 개인정보를 가립니다.
+///
+/// 메시지 본문의 이메일/전화번호를 치환하고, 접속 주소는 앞 두 옥텟만 남깁니다.
+pub fn redact(mut event: ChatEvent) -> ChatEvent {
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    static PHONE: OnceLock<Regex> = OnceLock::new();
+
+    let email = EMAIL.get_or_init(|| {
+        Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}").expect("이메일 정규식")
+    });
+    let phone = PHONE.get_or_init(|| {
+        Regex::new(r"\+?\d[\d -]{7,}\d").expect("전화번호 정규식")
+    });
+
+    let content = email.replace_all(&event.content, "[email]");
+    event.content = phone.replace_all(&content, "[phone]").into_owned();
+    event.client_addr = mask_addr(&event.client_addr);
+    event
+}
+
+/// 접속 주소 마스킹 (IPv4는 앞 두 옥텟만 유지, 그 외는 전체 마스킹)
+fn mask_addr(addr: &str) -> String {
+    if addr.is_empty() {
+        return String::new();
+    }
+
+    let host = addr.rsplit_once(':').map_or(addr, |(host, _)| host);
+    let octets: Vec<&str> = host.split('.').collect();
+    if octets.len() == 4 {
+        format!("{}.{}.*.*", octets[0], octets[1])
+    } else {
+        "*".to_string()
+    }
+}
@@ -4,22 +4,42 @@
 //! Redis 연결 풀과 최적화된 서비스 인스턴스를 사용하여 고성능을 달성합니다.
 
 use tracing::info;
-use std::sync::Arc;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use tokio::sync::OnceCell;
 use shared::tool::error::AppError;
 use shared::config::connection_pool::ConnectionPool;
 use shared::service::redis::core::redis_get_key::KeyType;
+use shared::service::redis::read_validator::ReadValidator;
 use shared::service::redis::room_redis_service::{RoomRedisService, RoomRedisServiceConfig};
 use shared::model::RoomInfo;
+use shared::tool::get_id::RoomIdGenerator;
 
 /// 최적화된 Redis 서비스 인스턴스 (싱글톤)
 static ROOM_REDIS_SERVICE: OnceCell<Arc<RoomRedisService>> = OnceCell::const_new();
 
+/// 최적화된 RoomIdGenerator 인스턴스 (싱글톤)
+static ROOM_ID_GENERATOR: OnceCell<Arc<RoomIdGenerator>> = OnceCell::const_new();
+
+/// 방 목록 검증자 인스턴스 (싱글톤)
+static ROOM_LIST_VALIDATOR: OnceCell<ReadValidator> = OnceCell::const_new();
+
+/// 방 리스트 페이지 크기 (Redis 조회와 동일)
+const ROOM_PAGE_SIZE: usize = 20;
+
 /// Room Service 비즈니스 로직
 /// 
 /// 방 생성 및 조회 기능을 처리하는 최적화된 서비스입니다.
 /// Redis 연결 풀과 서비스 인스턴스를 재사용하여 성능을 극대화합니다.
-pub struct RoomService;
+/// 모의 모드에서는 Redis 대신 프로세스 메모리에 방을 저장합니다.
+#[derive(Default)]
+pub struct RoomService {
+    /// 모의 모드 방 저장소 (room_id -> 방 정보, None이면 Redis 사용)
+    mock_rooms: Option<Mutex<BTreeMap<u16, RoomInfo>>>,
+    /// 모의 모드 방 목록 버전 (방을 만들 때마다 증가)
+    mock_version: AtomicU64,
+}
 
 impl RoomService {
     /// 새로운 RoomService 인스턴스를 생성합니다.
@@ -27,7 +47,40 @@ impl RoomService {
     /// # Returns
     /// * `Self` - 초기화된 RoomService 인스턴스
     pub fn new() -> Self { 
-        Self 
+        Self::default()
+    }
+
+    /// 모의 서비스 사용 여부 설정
+    pub fn with_mock_services(mut self, enabled: bool) -> Self {
+        self.mock_rooms = enabled.then(Default::default);
+        self
+    }
+
+    /// 새 방 ID를 발급합니다.
+    ///
+    /// 모의 모드에서는 저장된 방 중 가장 큰 ID 다음 값을 사용합니다.
+    ///
+    /// # Returns
+    /// * `Result<u16, AppError>` - 발급된 방 ID
+    pub async fn next_room_id(&self) -> Result<u16, AppError> {
+        if let Some(rooms) = &self.mock_rooms {
+            let rooms = rooms.lock().map_err(|_| AppError::InternalError("모의 방 저장소 잠금 실패".to_string()))?;
+            return Ok(rooms.keys().next_back().map_or(1, |id| id.wrapping_add(1).max(1)));
+        }
+
+        let room_id_generator = ROOM_ID_GENERATOR
+            .get_or_try_init(|| async {
+                let generator = RoomIdGenerator::from_env().await
+                    .map_err(|e| AppError::InternalError(format!("방 ID 생성기 초기화 실패: {e}")))?;
+                Ok::<_, AppError>(Arc::new(generator))
+            })
+            .await?
+            .clone();
+        let mut generator = Arc::try_unwrap(room_id_generator)
+            .unwrap_or_else(|arc| (*arc).clone());
+
+        generator.get_room_id().await
+            .map_err(|e| AppError::InternalError(format!("방 ID 생성 실패: {e}")))
     }
 
     /// 최적화된 Redis 서비스 인스턴스를 가져옵니다.
@@ -67,6 +120,16 @@ impl RoomService {
         room_info: RoomInfo,
     ) -> Result<i32, AppError> {
         info!("방 생성 서비스 호출: room_info={:?}", room_info);
+
+        if let Some(rooms) = &self.mock_rooms {
+            let room_id = room_info.room_id;
+            rooms.lock()
+                .map_err(|_| AppError::InternalError("모의 방 저장소 잠금 실패".to_string()))?
+                .insert(room_id, room_info);
+            self.mock_version.fetch_add(1, Ordering::Relaxed);
+            info!("방 생성 완료 (모의): room_id={}", room_id);
+            return Ok(room_id as i32);
+        }
         
         // 최적화된 Redis 서비스 인스턴스 사용
         let room_redis_service = self.get_redis_service().await?;
@@ -81,6 +144,33 @@ impl RoomService {
         Ok(room_id)
     }
 
+    /// 방 목록 검증자 (Redis 연결 설정을 공유하는 싱글톤)
+    async fn get_room_list_validator(&self) -> Result<&'static ReadValidator, AppError> {
+        ROOM_LIST_VALIDATOR
+            .get_or_try_init(|| async {
+                let redis_config = ConnectionPool::get_config().await
+                    .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+                Ok(ReadValidator::room_list(redis_config))
+            })
+            .await
+    }
+
+    /// 방 목록 버전 토큰 조회 (없으면 None, 변경 여부 확인용)
+    pub async fn room_list_version(&self) -> Result<Option<String>, AppError> {
+        if self.mock_rooms.is_some() {
+            return Ok(Some(self.mock_version.load(Ordering::Relaxed).to_string()));
+        }
+        self.get_room_list_validator().await?.peek().await
+    }
+
+    /// 방 목록 버전 토큰 (없으면 발급, 목록을 읽기 전에 호출)
+    pub async fn current_room_list_version(&self) -> Result<String, AppError> {
+        if self.mock_rooms.is_some() {
+            return Ok(self.mock_version.load(Ordering::Relaxed).to_string());
+        }
+        self.get_room_list_validator().await?.current().await
+    }
+
     /// 방 리스트를 조회합니다.
     /// 
     /// 최적화된 Redis 서비스 인스턴스를 재사용하여 방 목록을 조회합니다.
@@ -98,6 +188,14 @@ impl RoomService {
   
         // i32 → u16 변환 (음수 처리)
         let last_room_id_u16 = if last_room_id < 0 { 0 } else { last_room_id as u16 };
+
+        // 모의 모드: 최신순, last_room_id보다 이전에 만든 방만
+        if let Some(rooms) = &self.mock_rooms {
+            let rooms = rooms.lock()
+                .map_err(|_| AppError::InternalError("모의 방 저장소 잠금 실패".to_string()))?;
+            let upper = if last_room_id_u16 == 0 { u16::MAX } else { last_room_id_u16 - 1 };
+            return Ok(rooms.range(..=upper).rev().take(ROOM_PAGE_SIZE).map(|(_, room)| room.clone()).collect());
+        }
   
         // 최적화된 Redis 서비스 인스턴스 사용
         let room_redis_service = self.get_redis_service().await?;
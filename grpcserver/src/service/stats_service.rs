@@ -0,0 +1,38 @@
+//! Stats Service Business Logic
+//!
+//! 운영용 서버 통계 조회를 담당하는 비즈니스 로직입니다.
+//! 각 게임 서버가 Redis에 기록한 하트비트(TTL 키)를 모아 프로토콜/지역별로 집계합니다.
+
+use shared::tool::error::AppError;
+use shared::config::connection_pool::ConnectionPool;
+use shared::service::redis::server_stats::{ClusterStats, ServerStatsStore};
+
+/// Stats Service 비즈니스 로직
+#[derive(Default)]
+pub struct StatsService;
+
+impl StatsService {
+    /// 새로운 StatsService 인스턴스를 생성합니다.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// 현재 살아 있는 서버들의 통계를 집계합니다.
+    ///
+    /// # Arguments
+    /// * `protocol` - 특정 프로토콜만 집계 ("tcp", "rudp"), None이면 전체
+    ///
+    /// # Returns
+    /// * `Result<ClusterStats, AppError>` - 전체 CCU/방 수와 프로토콜/지역별 세부 집계
+    pub async fn get_server_stats(&self, protocol: Option<&str>) -> Result<ClusterStats, AppError> {
+        let redis_config = ConnectionPool::get_config().await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        ServerStatsStore::new(redis_config).cluster_stats(protocol).await
+    }
+}
+
+/// 요청의 프로토콜 필터를 정규화합니다. (빈 문자열은 필터 없음)
+pub fn protocol_filter(protocol: &str) -> Option<String> {
+    let protocol = protocol.trim().to_ascii_lowercase();
+    (!protocol.is_empty()).then_some(protocol)
+}
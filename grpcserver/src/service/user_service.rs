@@ -14,8 +14,16 @@ use shared::service::redis::core::redis_get_key::KeyType;
 /// 
 /// 사용자 인증 및 회원가입 기능을 처리하는 서비스입니다.
 /// 현재는 더미 데이터를 반환하지만, 향후 실제 데이터베이스 연동이 추가될 예정입니다.
-#[derive(Default)]
-pub struct UserService;
+pub struct UserService {
+    /// 모의 서비스 사용 여부 (테스트 로그인 허용)
+    use_mock_services: bool,
+}
+
+impl Default for UserService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl UserService {
     /// 새로운 UserService 인스턴스를 생성합니다.
@@ -23,7 +31,18 @@ impl UserService {
     /// # Returns
     /// * `Self` - 초기화된 UserService 인스턴스
     pub fn new() -> Self { 
-        Self 
+        Self { use_mock_services: true }
+    }
+
+    /// 모의 서비스 사용 여부를 설정합니다.
+    /// 
+    /// 비활성화하면 "test" 로그인 타입을 거부합니다.
+    /// 
+    /// # Arguments
+    /// * `enabled` - 모의 서비스 사용 여부
+    pub fn with_mock_services(mut self, enabled: bool) -> Self {
+        self.use_mock_services = enabled;
+        self
     }
 
     /// 사용자 로그인을 처리합니다.
@@ -132,7 +151,7 @@ impl UserService {
                 // 애플 로그인 처리
                 Ok(true)
             }
-            "test" => {
+            "test" if self.use_mock_services => {
                 // 테스트 아이디 일때 바로 반환하기 
                 Ok(true)
             }
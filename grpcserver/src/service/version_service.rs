@@ -0,0 +1,42 @@
+//! Version Service Business Logic
+//!
+//! 클라이언트 버전 확인을 담당하는 비즈니스 로직입니다.
+//! 서버에 설정된 최소/권장 버전과 비교해 업데이트 필요 여부와 스토어 URL을 반환합니다.
+
+use std::sync::Arc;
+use shared::tool::error::AppError;
+use crate::tool::version_gate::{ClientVersion, VersionCheck, VersionPolicy};
+
+/// Version Service 비즈니스 로직
+pub struct VersionService {
+    /// 클라이언트 버전 정책
+    policy: Arc<VersionPolicy>,
+}
+
+impl VersionService {
+    /// 새로운 VersionService 인스턴스를 생성합니다.
+    ///
+    /// # Arguments
+    /// * `policy` - 게임 서비스 인터셉터와 공유하는 버전 정책
+    pub fn new(policy: Arc<VersionPolicy>) -> Self {
+        Self { policy }
+    }
+
+    /// 클라이언트 버전을 확인합니다.
+    ///
+    /// # Arguments
+    /// * `client_version` - 클라이언트 버전 문자열 (예: "1.4.2")
+    /// * `platform` - 클라이언트 플랫폼 (예: "ios", "android")
+    ///
+    /// # Returns
+    /// * `Result<VersionCheck, AppError>` - 업데이트 필요 여부와 스토어 URL
+    pub fn check_version(&self, client_version: &str, platform: &str) -> Result<VersionCheck, AppError> {
+        if client_version.trim().is_empty() {
+            return Err(AppError::InvalidInput("client_version is required".to_string()));
+        }
+        let version: ClientVersion = client_version.parse()
+            .map_err(AppError::InvalidFormat)?;
+
+        Ok(self.policy.check(Some(version), platform))
+    }
+}
@@ -1,2 +1,25 @@
 pub mod test_interceptor;
-pub mod test_client; 
\ No newline at end of file
+pub mod test_client;
+#[cfg(test)]
+pub mod test_config;
+#[cfg(test)]
+pub mod test_moderation;
+#[cfg(test)]
+pub mod test_stats;
+#[cfg(test)]
+pub mod test_version;
+#[cfg(test)]
+pub mod test_canary;
+#[cfg(test)]
+pub mod test_avatar;
+#[cfg(test)]
+pub mod test_read_cache;
+
+#[cfg(test)]
+pub mod test_experiment;
+#[cfg(test)]
+pub mod test_liveops;
+#[cfg(test)]
+pub mod test_compression;
+#[cfg(test)]
+pub mod test_error_locale;
\ No newline at end of file
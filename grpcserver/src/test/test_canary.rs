@@ -0,0 +1,105 @@
+//! Canary Routing Test Module
+//!
+//! 사용자 버킷 해시, 비율별 라우팅, RoomService 카나리 라우터와 모의 방 저장소를 테스트합니다.
+
+use tonic::{Request, Response, Status};
+use shared::model::RoomInfo;
+use crate::room::{
+    room_service_server::RoomService,
+    GetRoomListRequest, GetRoomListResponse,
+    MakeRoomRequest, MakeRoomResponse,
+};
+use crate::service::room_service::RoomService as RoomSvc;
+use crate::tool::canary::{CanaryFlag, CanaryRoute, CanaryRouter};
+
+/// 응답의 room_id로 어느 구현이 처리했는지 표시하는 테스트용 구현
+struct FixedRoomService(i32);
+
+#[tonic::async_trait]
+impl RoomService for FixedRoomService {
+    async fn make_room(&self, _req: Request<MakeRoomRequest>) -> Result<Response<MakeRoomResponse>, Status> {
+        Ok(Response::new(MakeRoomResponse { success: true, room_id: self.0 }))
+    }
+
+    async fn get_room_list(&self, _req: Request<GetRoomListRequest>) -> Result<Response<GetRoomListResponse>, Status> {
+        Ok(Response::new(GetRoomListResponse { rooms: vec![] }))
+    }
+}
+
+/// 버킷은 고정이고, 비율을 올려도 기존 카나리 사용자는 유지되는지 테스트
+#[test]
+fn test_bucket_is_stable_and_rollout_is_monotonic() {
+    let flag = CanaryFlag::new("room", 5);
+    assert_eq!(flag.bucket(42), CanaryFlag::new("room", 50).bucket(42));
+
+    let canary_users = |percent: u8| -> Vec<i32> {
+        let flag = CanaryFlag::new("room", percent);
+        (1..=10_000).filter(|id| flag.route(Some(*id)) == CanaryRoute::Canary).collect()
+    };
+    let five = canary_users(5);
+    let twenty = canary_users(20);
+    assert!((400..=600).contains(&five.len()), "5% 버킷 분포: {}", five.len());
+    assert!((1_800..=2_200).contains(&twenty.len()), "20% 버킷 분포: {}", twenty.len());
+    assert!(five.iter().all(|id| twenty.contains(id)));
+
+    assert!(canary_users(0).is_empty());
+    assert_eq!(canary_users(100).len(), 10_000);
+    assert_eq!(CanaryFlag::new("room", 200).percent, 100);
+
+    // 사용자를 모르면 항상 안정 구현
+    assert_eq!(CanaryFlag::new("room", 100).route(None), CanaryRoute::Stable);
+}
+
+/// 플래그 이름이 다르면 카나리 집단도 달라지는지 테스트
+#[test]
+fn test_flag_name_salts_bucket() {
+    let room = CanaryFlag::new("room", 50);
+    let user = CanaryFlag::new("user", 50);
+    let differing = (1..=1_000).filter(|id| room.route(Some(*id)) != user.route(Some(*id))).count();
+    assert!(differing > 300, "플래그 간 버킷이 너무 비슷함: {differing}");
+}
+
+/// 라우터가 요청 user_id에 따라 구현을 선택하는지 테스트
+#[tokio::test]
+async fn test_router_dispatches_by_user() {
+    let flag = CanaryFlag::new("room", 5);
+    let canary_user = (1..).find(|id| flag.route(Some(*id)) == CanaryRoute::Canary).unwrap();
+    let stable_user = (1..).find(|id| flag.route(Some(*id)) == CanaryRoute::Stable).unwrap();
+    let router = CanaryRouter::new(FixedRoomService(1), FixedRoomService(2), flag);
+
+    let make_room = |user_id| Request::new(MakeRoomRequest {
+        user_id,
+        nick_name: "tester".to_string(),
+        room_name: "room".to_string(),
+        max_player_num: 4,
+    });
+    assert_eq!(router.make_room(make_room(canary_user)).await.unwrap().into_inner().room_id, 2);
+    assert_eq!(router.make_room(make_room(stable_user)).await.unwrap().into_inner().room_id, 1);
+}
+
+/// 모의 RoomService가 Redis 없이 방 ID 발급/생성/페이징을 처리하는지 테스트
+#[tokio::test]
+async fn test_mock_room_service() {
+    let svc = RoomSvc::new().with_mock_services(true);
+
+    for index in 0..25 {
+        let room_id = svc.next_room_id().await.unwrap();
+        svc.make_room(RoomInfo {
+            room_id,
+            room_name: format!("room-{index}"),
+            max_player_num: 4,
+            current_player_num: 1,
+            create_at: String::new(),
+        })
+        .await
+        .unwrap();
+    }
+
+    let first_page = svc.get_room_list(0).await.unwrap();
+    assert_eq!(first_page.len(), 20);
+    assert_eq!(first_page[0].room_id, 25);
+
+    let last_id = first_page.last().unwrap().room_id as i32;
+    let second_page = svc.get_room_list(last_id).await.unwrap();
+    assert_eq!(second_page.iter().map(|room| room.room_id).collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+}
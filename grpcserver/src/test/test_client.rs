@@ -143,6 +143,7 @@ async fn test_user_operations(channel: Channel) -> Result<()> {
     let login_request = LoginRequest {
         login_type: "test".to_string(),
         login_token: "test_token_123".to_string(),
+        region: "kr".to_string(),
     };
     
     match client.login_user(tonic::Request::new(login_request)).await {
@@ -0,0 +1,220 @@
+//! Response Compression / CPU Pool Test Module
+//!
+//! 응답 압축 레이어(인코딩 협상, gRPC 프레임 재작성)와 CPU 작업 풀 경유 아바타 처리를 테스트하고,
+//! 적용 전/후 수치를 출력합니다 (`cargo test -p grpcserver test_compression -- --nocapture`).
+
+use std::collections::VecDeque;
+use std::io::Read;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
+
+use prost::Message;
+use shared::tool::high_performance::{CompressionAlgorithm, CpuTaskPool, MessageCompressionConfig, ParallelProcessingConfig};
+use tonic::body::BoxBody;
+use tonic::codegen::{http, Body, Bytes, Service};
+use tonic::Status;
+use tower::Layer;
+
+use crate::room::{GetRoomListResponse, RoomInfo};
+use crate::service::avatar_service::{AvatarConfig, AvatarService, AvatarStorageKind};
+use crate::service::avatar_storage::LocalDirStorage;
+use crate::test::test_avatar::png_with_metadata;
+use crate::tool::image_sanitizer::sanitize_image;
+use crate::tool::response_compression::{
+    compress_frame, negotiate, ResponseCompressionLayer, ResponseCompressionStats, ResponseEncoding,
+};
+
+/// 방 목록 응답 (압축 대상 대표 페이로드)
+fn room_list(count: i32) -> Vec<u8> {
+    GetRoomListResponse {
+        rooms: (1..=count)
+            .map(|room_id| RoomInfo {
+                room_id,
+                room_name: format!("경찰과 도둑 {room_id}번 방"),
+                current_player_num: room_id % 8,
+                max_player_num: 8,
+            })
+            .collect(),
+    }
+    .encode_to_vec()
+}
+
+/// gRPC 프레임 (비압축)
+fn frame(message: &[u8]) -> Vec<u8> {
+    let mut frame = vec![0];
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(message);
+    frame
+}
+
+fn decompress(encoding: ResponseEncoding, data: &[u8]) -> Vec<u8> {
+    match encoding {
+        ResponseEncoding::Zstd => zstd::bulk::decompress(data, 1 << 20).unwrap(),
+        ResponseEncoding::Gzip => {
+            let mut out = Vec::new();
+            flate2::read::GzDecoder::new(data).read_to_end(&mut out).unwrap();
+            out
+        }
+    }
+}
+
+/// 정해진 조각으로 나눠 보내는 응답 본문 (프레임이 조각 경계에 걸치는 경우 재현)
+struct ChunkedBody(VecDeque<Bytes>);
+
+impl Body for ChunkedBody {
+    type Data = Bytes;
+    type Error = Status;
+
+    fn poll_data(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Result<Bytes, Status>>> {
+        Poll::Ready(self.0.pop_front().map(Ok))
+    }
+
+    fn poll_trailers(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<Option<http::HeaderMap>, Status>> {
+        Poll::Ready(Ok(None))
+    }
+}
+
+async fn collect(mut body: BoxBody) -> Vec<u8> {
+    let mut out = Vec::new();
+    while let Some(chunk) = std::future::poll_fn(|cx| Pin::new(&mut body).poll_data(cx)).await {
+        out.extend_from_slice(&chunk.unwrap());
+    }
+    out
+}
+
+fn compression_config(algorithm: CompressionAlgorithm) -> MessageCompressionConfig {
+    MessageCompressionConfig {
+        algorithm,
+        compression_threshold: 512,
+        compression_level: 3,
+        ..MessageCompressionConfig::default()
+    }
+}
+
+/// 인코딩 협상 테스트
+#[test]
+fn test_negotiate_encoding() {
+    assert_eq!(negotiate("gzip", CompressionAlgorithm::Adaptive), Some(ResponseEncoding::Gzip));
+    assert_eq!(negotiate("identity, gzip, zstd", CompressionAlgorithm::Adaptive), Some(ResponseEncoding::Zstd));
+    assert_eq!(negotiate("GZIP;q=0.5", CompressionAlgorithm::Adaptive), Some(ResponseEncoding::Gzip));
+    assert_eq!(negotiate("gzip", CompressionAlgorithm::Zstd), None);
+    assert_eq!(negotiate("zstd,gzip", CompressionAlgorithm::None), None);
+    assert_eq!(negotiate("identity", CompressionAlgorithm::Adaptive), None);
+}
+
+/// 프레임 압축 테스트 (작은 메시지, 이미 압축된 메시지는 그대로)
+#[test]
+fn test_compress_frame() {
+    let stats = ResponseCompressionStats::default();
+    let message = room_list(100);
+
+    for encoding in [ResponseEncoding::Zstd, ResponseEncoding::Gzip] {
+        let out = compress_frame(frame(&message), encoding, 512, 3, &stats);
+        assert_eq!(out[0], 1);
+        let len = u32::from_be_bytes([out[1], out[2], out[3], out[4]]) as usize;
+        assert_eq!(out.len(), 5 + len);
+        assert!(len < message.len());
+        assert_eq!(decompress(encoding, &out[5..]), message);
+    }
+
+    let small = frame(&room_list(1));
+    assert_eq!(compress_frame(small.clone(), ResponseEncoding::Gzip, 512, 3, &stats), small);
+    let mut flagged = frame(&message);
+    flagged[0] = 1;
+    assert_eq!(compress_frame(flagged.clone(), ResponseEncoding::Gzip, 512, 3, &stats), flagged);
+}
+
+/// 레이어 테스트 (조각난 본문, 헤더 설정, 미지원 클라이언트 통과)
+#[tokio::test]
+async fn test_compression_layer() {
+    let message = room_list(200);
+    let mut body = frame(&message);
+    body.extend(frame(&room_list(1)));
+    let inner = tower::service_fn(move |_req: http::Request<()>| {
+        let chunks = body.chunks(700).map(Bytes::copy_from_slice).collect();
+        async move {
+            let response = http::Response::builder()
+                .header(http::header::CONTENT_TYPE, "application/grpc")
+                .body(ChunkedBody(chunks).boxed_unsync())
+                .unwrap();
+            Ok::<_, std::convert::Infallible>(response)
+        }
+    });
+    let layer = ResponseCompressionLayer::new(compression_config(CompressionAlgorithm::Adaptive));
+    let mut service = layer.layer(inner);
+
+    let request = http::Request::builder().header("grpc-accept-encoding", "gzip").body(()).unwrap();
+    let response = service.call(request).await.unwrap();
+    assert_eq!(response.headers().get("grpc-encoding").unwrap(), "gzip");
+    let out = collect(response.into_body()).await;
+    let len = u32::from_be_bytes([out[1], out[2], out[3], out[4]]) as usize;
+    assert_eq!((out[0], decompress(ResponseEncoding::Gzip, &out[5..5 + len])), (1, message.clone()));
+    assert_eq!(&out[5 + len..], frame(&room_list(1)).as_slice());
+
+    let response = service.call(http::Request::new(())).await.unwrap();
+    assert!(response.headers().get("grpc-encoding").is_none());
+    assert_eq!(collect(response.into_body()).await.len(), 5 + message.len() + frame(&room_list(1)).len());
+
+    let stats = layer.stats();
+    assert_eq!(stats.responses.load(std::sync::atomic::Ordering::Relaxed), 1);
+    assert!(stats.saved_percent() > 50.0);
+}
+
+/// 응답 압축 전/후 비교 (방 목록 크기별)
+#[test]
+fn bench_room_list_compression() {
+    let stats = ResponseCompressionStats::default();
+    println!("방 수 | 원본 | zstd (크기, 시간) | gzip (크기, 시간)");
+    for rooms in [10, 100, 1000] {
+        let message = room_list(rooms);
+        let mut row = format!("{rooms:>5} | {:>6}B", message.len());
+        for encoding in [ResponseEncoding::Zstd, ResponseEncoding::Gzip] {
+            let start = Instant::now();
+            let out = compress_frame(frame(&message), encoding, 512, 3, &stats);
+            row.push_str(&format!(" | {:>6}B {:>7.1?}", out.len() - 5, start.elapsed()));
+            if message.len() >= 512 {
+                assert!(out.len() - 5 < message.len() / 2, "방 목록은 절반 이하로 줄어야 함");
+            }
+        }
+        println!("{row}");
+    }
+}
+
+/// 아바타 처리: 요청 태스크에서 직접 실행 vs CPU 작업 풀 경유
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn bench_avatar_sanitize_cpu_pool() {
+    // 256x256 RGBA PNG (디코딩/재인코딩이 주 비용)
+    let png = png_with_metadata(256, 256);
+    const UPLOADS: usize = 16;
+
+    let start = Instant::now();
+    let mut sanitized_len = 0;
+    for _ in 0..UPLOADS {
+        sanitized_len = sanitize_image("image/png", &png, 1024).unwrap().data.len();
+    }
+    let inline = start.elapsed();
+
+    let pool = Arc::new(CpuTaskPool::new(&ParallelProcessingConfig { worker_threads: 4, ..Default::default() }).unwrap());
+    let start = Instant::now();
+    let mut tasks = tokio::task::JoinSet::new();
+    for _ in 0..UPLOADS {
+        let (pool, png) = (pool.clone(), png.clone());
+        tasks.spawn(async move { pool.run(move || sanitize_image("image/png", &png, 1024).map(|image| image.data.len())).await });
+    }
+    while let Some(result) = tasks.join_next().await {
+        assert_eq!(result.unwrap().unwrap().unwrap(), sanitized_len);
+    }
+    let pooled = start.elapsed();
+    println!("아바타 {UPLOADS}건: 직접 {inline:?}, CPU 풀(4스레드) {pooled:?}");
+    assert_eq!(pool.stats().processed_tasks.load(std::sync::atomic::Ordering::Relaxed), UPLOADS as u64);
+
+    // 서비스 경유 업로드도 같은 결과
+    let dir = std::env::temp_dir().join(format!("avatar-pool-{}", std::process::id()));
+    let config = AvatarConfig { storage: AvatarStorageKind::Local { dir: dir.display().to_string() }, ..AvatarConfig::default() };
+    let service = AvatarService::new(Arc::new(LocalDirStorage::new(&dir)), config).with_cpu_pool(pool);
+    let stored = service.upload(1, "image/png", &png).await.unwrap();
+    assert_eq!((stored.width, stored.height), (256, 256));
+    let _ = std::fs::remove_dir_all(dir);
+}
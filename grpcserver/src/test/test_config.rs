@@ -0,0 +1,45 @@
+//! Config Profile Test Module
+//! 
+//! 실행 프로필 파싱과 프로필별 기본값을 테스트합니다.
+
+use crate::config::{CliArgs, Profile};
+
+fn args(list: &[&str]) -> Vec<String> {
+    std::iter::once("grpcserver").chain(list.iter().copied()).map(String::from).collect()
+}
+
+/// 명령행 인자 파싱 테스트
+#[test]
+fn test_cli_args_parse() {
+    let parsed = CliArgs::parse(args(&["--check", "--profile", "prod"])).unwrap();
+    assert!(parsed.check);
+    assert_eq!(parsed.profile, Some(Profile::Prod));
+
+    let parsed = CliArgs::parse(args(&["--profile=staging"])).unwrap();
+    assert!(!parsed.check);
+    assert_eq!(parsed.profile, Some(Profile::Staging));
+
+    let parsed = CliArgs::parse(args(&["--schema", "docs/api.json"])).unwrap();
+    assert_eq!(parsed.schema.as_deref(), Some(std::path::Path::new("docs/api.json")));
+
+    assert!(CliArgs::parse(args(&["--profile"])).is_err());
+    assert!(CliArgs::parse(args(&["--schema"])).is_err());
+    assert!(CliArgs::parse(args(&["--profile", "qa"])).is_err());
+    assert!(CliArgs::parse(args(&["--unknown"])).is_err());
+}
+
+/// 프로필별 기본값 테스트
+#[test]
+fn test_profile_defaults() {
+    let dev = Profile::Dev.defaults();
+    assert!(dev.use_mock_services);
+    assert!(!dev.require_redis);
+
+    let prod = Profile::Prod.defaults();
+    assert!(!prod.use_mock_services);
+    assert!(prod.require_redis && prod.require_database && prod.require_tls);
+
+    assert_eq!("production".parse::<Profile>().unwrap(), Profile::Prod);
+    // 프로필을 지정하지 않으면 모의 서비스가 꺼진 prod로 시작
+    assert_eq!(Profile::default(), Profile::Prod);
+}
@@ -0,0 +1,118 @@
+//! Error Locale Test Module
+//!
+//! 에러 메시지 카탈로그(누락 키 검증, 로케일 협상)와 `grpc-message` 번역 레이어를 테스트합니다.
+
+use std::sync::Arc;
+
+use shared::tool::{ErrorCode, GameServerError};
+use tonic::codegen::{http, Service};
+use tonic::Status;
+use tower::Layer;
+
+use crate::tool::error_locale::{ErrorLocaleLayer, ErrorMessageCatalog};
+
+/// 기본 카탈로그 검증 (모든 로케일에 모든 에러 코드 문구가 있어야 함)
+#[test]
+fn test_builtin_catalog_has_no_missing_keys() {
+    let catalog = ErrorMessageCatalog::builtin();
+    assert_eq!(catalog.locales(), vec!["en", "ja", "ko"]);
+    assert_eq!(catalog.default_locale(), "ko");
+    assert!(catalog.missing_keys().is_empty(), "누락된 에러 메시지: {:?}", catalog.missing_keys());
+
+    // 기본 로케일 문구는 서버 기본 사용자 메시지와 같아야 함 (번역 시 접두어로 찾음)
+    for code in ErrorCode::ALL {
+        assert_eq!(catalog.message("ko", code), code.default_message(), "{code}");
+    }
+}
+
+/// 기본 로케일에 빠진 키가 있으면 로드 실패, 다른 로케일은 누락으로 보고
+#[test]
+fn test_catalog_validation() {
+    let error = ErrorMessageCatalog::from_toml("default_locale = \"ko\"\n[ko]\n\"error.room_full\" = \"방이 가득 찼습니다\"\n").unwrap_err();
+    assert!(error.to_string().contains("error."), "{error}");
+
+    let mut toml = String::from("default_locale = \"ko\"\n[ko]\n");
+    for code in ErrorCode::ALL {
+        toml.push_str(&format!("\"{}\" = \"{}\"\n", code.message_key(), code.default_message()));
+    }
+    toml.push_str("[en]\n\"error.room_full\" = \"The room is full\"\n");
+    let catalog = ErrorMessageCatalog::from_toml(&toml).unwrap();
+    let missing = catalog.missing_keys();
+    assert_eq!(missing.len(), ErrorCode::ALL.len() - 1);
+    assert!(missing.iter().all(|(locale, _)| locale == "en"));
+    // 빠진 문구는 기본 로케일로 대체
+    assert_eq!(catalog.message("en", ErrorCode::RoomFull), "The room is full");
+    assert_eq!(catalog.message("en", ErrorCode::RoomNotFound), "방을 찾을 수 없습니다");
+}
+
+/// `accept-language` 협상 (품질값, 지역 -> 언어 대체, 기본 로케일)
+#[test]
+fn test_negotiate_locale() {
+    let catalog = ErrorMessageCatalog::builtin();
+    assert_eq!(catalog.negotiate(Some("ja-JP,ja;q=0.9,en;q=0.8")), "ja");
+    assert_eq!(catalog.negotiate(Some("en-US")), "en");
+    assert_eq!(catalog.negotiate(Some("fr-FR, en;q=0.5")), "en");
+    assert_eq!(catalog.negotiate(Some("ko;q=0.1, EN_gb;q=0.9")), "en");
+    assert_eq!(catalog.negotiate(Some("de, *;q=0.5")), "ko");
+    assert_eq!(catalog.negotiate(Some("en;q=0, ja;q=abc")), "ko");
+    assert_eq!(catalog.negotiate(None), "ko");
+}
+
+/// 기본 문구만 번역하고 덧붙인 상세는 유지, 직접 작성한 안내문은 그대로
+#[test]
+fn test_localize_message() {
+    let catalog = ErrorMessageCatalog::builtin();
+    let detail = GameServerError::with_detail(ErrorCode::InvalidInput, "nickname too long");
+    assert_eq!(
+        catalog.localize("en", ErrorCode::InvalidInput, &detail.user_message()).unwrap(),
+        "Invalid input: nickname too long"
+    );
+    assert_eq!(catalog.localize("ja-JP", ErrorCode::RoomFull, ErrorCode::RoomFull.default_message()).unwrap(), "ルームが満員です");
+
+    let custom = GameServerError::with_message(ErrorCode::Unavailable, "점검 중입니다 (02:00 ~ 04:00)");
+    assert_eq!(catalog.localize("en", ErrorCode::Unavailable, &custom.user_message()), None);
+}
+
+/// 레이어 테스트 (에러 응답의 `grpc-message` 교체, 성공 응답과 분류 없는 에러는 통과)
+#[tokio::test]
+async fn test_error_locale_layer() {
+    let inner = tower::service_fn(|req: http::Request<()>| async move {
+        let response = match req.uri().path() {
+            "/room.RoomService/JoinRoom" => Status::from(GameServerError::new(ErrorCode::RoomFull)).to_http(),
+            "/user.UserService/Register" => {
+                Status::from(GameServerError::with_detail(ErrorCode::MissingField, "nickname")).to_http()
+            }
+            "/room.RoomService/Unclassified" => Status::internal("raw tonic status").to_http(),
+            _ => Status::new(tonic::Code::Ok, "").to_http(),
+        };
+        Ok::<_, std::convert::Infallible>(response)
+    });
+    let mut service = ErrorLocaleLayer::new(Arc::new(ErrorMessageCatalog::builtin())).layer(inner);
+    let request = |path: &str, accept_language: &str| {
+        http::Request::builder().uri(path).header("accept-language", accept_language).body(()).unwrap()
+    };
+
+    let response = service.call(request("/room.RoomService/JoinRoom", "ja-JP,en;q=0.5")).await.unwrap();
+    let status = Status::from_header_map(response.headers()).unwrap();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(status.message(), "ルームが満員です");
+    assert_eq!(response.headers().get("content-language").unwrap(), "ja");
+    assert_eq!(response.headers().get("x-error-code").unwrap(), "ROOM_FULL");
+
+    let response = service.call(request("/user.UserService/Register", "en")).await.unwrap();
+    let status = Status::from_header_map(response.headers()).unwrap();
+    assert_eq!(status.message(), "A required field is missing: nickname");
+
+    // 요청 로케일이 없거나 기본 로케일이면 원문 그대로
+    let response = service.call(request("/room.RoomService/JoinRoom", "fr")).await.unwrap();
+    let status = Status::from_header_map(response.headers()).unwrap();
+    assert_eq!(status.message(), ErrorCode::RoomFull.default_message());
+
+    let response = service.call(request("/room.RoomService/Unclassified", "en")).await.unwrap();
+    assert_eq!(Status::from_header_map(response.headers()).unwrap().message(), "raw tonic status");
+    assert!(response.headers().get("content-language").is_none());
+
+    let response = service.call(request("/room.RoomService/GetRoomList", "en")).await.unwrap();
+    assert!(response.headers().get("grpc-message").is_none());
+    assert!(response.headers().get("content-language").is_none());
+}
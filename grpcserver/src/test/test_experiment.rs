@@ -0,0 +1,45 @@
+//! Experiment Service Test Module
+//! 
+//! 실험 목록 파일 파싱과 모의 모드 배정 유지를 테스트합니다.
+
+use crate::service::experiment_service::{parse_catalog, ExperimentService};
+
+const CATALOG: &str = r#"
+[[experiment]]
+key = "speed"
+exclusion_group = "movement"
+traffic_percent = 100
+
+[[experiment.variants]]
+name = "control"
+weight = 1
+params = { movement_speed = "5.0" }
+
+[[experiment.variants]]
+name = "fast"
+weight = 1
+params = { movement_speed = "5.5" }
+"#;
+
+/// 실험 목록 파싱 및 검증 테스트
+#[test]
+fn test_parse_catalog() {
+    let catalog = parse_catalog(CATALOG).unwrap();
+    assert_eq!(catalog.experiments.len(), 1);
+    assert_eq!(catalog.experiments[0].exclusion_group.as_deref(), Some("movement"));
+    assert!(parse_catalog("").unwrap().experiments.is_empty());
+
+    // 같은 배제 그룹의 비율 합이 100% 초과
+    let overflow = CATALOG.replace("key = \"speed\"", "key = \"a\"") + &CATALOG.replace("key = \"speed\"", "key = \"b\"");
+    assert!(parse_catalog(&overflow).is_err());
+}
+
+/// 모의 모드 배정 유지 테스트 (목록이 바뀌어도 기존 배정 유지)
+#[tokio::test]
+async fn test_assignments_are_sticky() {
+    let svc = ExperimentService::new(parse_catalog(CATALOG).unwrap()).with_mock_services(true);
+    let first = svc.assignments(42).await.unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!(first[0].params["movement_speed"], if first[0].variant == "fast" { "5.5" } else { "5.0" });
+    assert_eq!(svc.assignments(42).await.unwrap(), first);
+}
@@ -128,6 +128,7 @@ async fn test_user_service(channel: Channel) {
     let request = tonic::Request::new(LoginRequest {
         login_type: "google".to_string(),
         login_token: "valid_token".to_string(),
+        region: String::new(),
     });
     
     match client.login_user(request).await {
@@ -145,6 +146,7 @@ async fn test_user_service(channel: Channel) {
     let request = tonic::Request::new(LoginRequest {
         login_type: "google".to_string(),
         login_token: "invalid_token".to_string(), // 에러 트리거
+        region: String::new(),
     });
     
     match client.login_user(request).await {
@@ -162,6 +164,7 @@ async fn test_user_service(channel: Channel) {
     let request = tonic::Request::new(LoginRequest {
         login_type: "google".to_string(),
         login_token: "notfound_token".to_string(), // 에러 트리거
+        region: String::new(),
     });
     
     match client.login_user(request).await {
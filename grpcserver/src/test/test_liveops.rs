@@ -0,0 +1,39 @@
+//! LiveOps Service Test Module
+//! 
+//! 라이브 설정 조회/변경과 gRPC 킬 스위치 경로 매핑을 테스트합니다.
+
+use crate::service::liveops_service::LiveOpsService;
+use crate::tool::policy_layer::kill_switch_features;
+use shared::service::redis::live_config::LiveConfig;
+
+/// 메서드 경로 → 킬 스위치 기능 이름 테스트
+#[test]
+fn test_kill_switch_features() {
+    assert_eq!(
+        kill_switch_features("/room.RoomService/CreateRoom"),
+        vec!["grpc:room".to_string(), "grpc:room.RoomService/CreateRoom".to_string()]
+    );
+    // 킬 스위치를 되돌릴 수 있도록 liveops 자신은 끌 수 없음
+    assert!(kill_switch_features("/liveops.LiveOpsService/SetFlag").is_empty());
+    assert!(kill_switch_features("health").is_empty());
+}
+
+/// 설정 변경 후 접두어 조회 테스트
+#[tokio::test]
+async fn test_set_and_list_flags() {
+    let config = LiveConfig::local();
+    let svc = LiveOpsService::new(config.clone());
+
+    let first = svc.set_flag("kill.grpc:room", Some("true"), "admin").await.unwrap();
+    assert_eq!(first.previous, None);
+    svc.set_flag("chat.max_length", Some("200"), "admin").await.unwrap();
+    assert!(config.is_killed("grpc:room"));
+
+    let kills = svc.list_flags("kill.");
+    assert_eq!(kills, vec![("kill.grpc:room".to_string(), "true".to_string())]);
+    assert_eq!(svc.list_flags("").len(), 2);
+
+    let cleared = svc.set_flag("kill.grpc:room", None, "admin").await.unwrap();
+    assert_eq!(cleared.previous.as_deref(), Some("true"));
+    assert!(!config.is_killed("grpc:room"));
+}
@@ -0,0 +1,42 @@
+//! Moderation Service Test Module
+//! 
+//! 채팅 모더레이션 스트림의 필터와 개인정보 가림 처리를 테스트합니다.
+
+use crate::service::moderation_service::{redact, ChatFilter};
+use shared::service::redis::event_bus::ChatEvent;
+
+fn event(room_id: u32, user_id: u32, content: &str) -> ChatEvent {
+    ChatEvent {
+        room_id,
+        user_id,
+        nickname: "tester".to_string(),
+        content: content.to_string(),
+        client_addr: "192.168.10.42:53211".to_string(),
+        timestamp: 0,
+    }
+}
+
+/// 방/사용자 필터 테스트
+#[test]
+fn test_chat_filter() {
+    let all = ChatFilter::from_request(0, 0);
+    assert!(all.matches(&event(1, 2, "hi")));
+
+    let room = ChatFilter::from_request(1, -1);
+    assert!(room.matches(&event(1, 2, "hi")));
+    assert!(!room.matches(&event(3, 2, "hi")));
+
+    let user = ChatFilter::from_request(1, 2);
+    assert!(user.matches(&event(1, 2, "hi")));
+    assert!(!user.matches(&event(1, 5, "hi")));
+}
+
+/// 개인정보 가림 테스트
+#[test]
+fn test_redact_pii() {
+    let redacted = redact(event(1, 2, "메일 cop@police.kr 전화 010-1234-5678 로 연락"));
+
+    assert_eq!(redacted.content, "메일 [email] 전화 [phone] 로 연락");
+    assert_eq!(redacted.client_addr, "192.168.*.*");
+    assert_eq!(redacted.nickname, "tester");
+}
@@ -0,0 +1,53 @@
+//! Read Cache Test Module
+//! 
+//! 읽기 RPC 검증자(`etag`/`if-none-match`)와 모의 모드 방 목록 버전을 테스트합니다.
+
+use tonic::Request;
+use crate::service::room_service::RoomService;
+use crate::tool::read_cache::{self, CACHE_CONTROL_HEADER, CACHE_STATUS_HEADER, ETAG_HEADER};
+use shared::model::RoomInfo;
+
+fn room(room_id: u16) -> RoomInfo {
+    RoomInfo {
+        room_id,
+        room_name: format!("room-{room_id}"),
+        max_player_num: 4,
+        current_player_num: 1,
+        create_at: String::new(),
+    }
+}
+
+/// 검증자 메타데이터 테스트
+#[test]
+fn test_etag_metadata() {
+    let etag = read_cache::etag("abc", "-1");
+    assert_eq!(etag, "W/\"abc:-1\"");
+
+    let mut req = Request::new(());
+    assert_eq!(read_cache::if_none_match(req.metadata()), None);
+    req.metadata_mut().insert("if-none-match", etag.parse().unwrap());
+    assert_eq!(read_cache::if_none_match(req.metadata()).as_deref(), Some(etag.as_str()));
+
+    let response = read_cache::respond((), Some(&etag), 1, true);
+    assert_eq!(response.metadata().get(ETAG_HEADER).unwrap(), etag.as_str());
+    assert_eq!(response.metadata().get(CACHE_CONTROL_HEADER).unwrap(), "private, max-age=1");
+    assert_eq!(response.metadata().get(CACHE_STATUS_HEADER).unwrap(), "not-modified");
+
+    let response = read_cache::respond((), None, 1, false);
+    assert!(response.metadata().get(ETAG_HEADER).is_none());
+    assert_eq!(response.metadata().get(CACHE_STATUS_HEADER).unwrap(), "miss");
+}
+
+/// 방을 만들면 방 목록 버전이 바뀜
+#[tokio::test]
+async fn test_room_list_version_changes_on_write() {
+    let svc = RoomService::new().with_mock_services(true);
+    let before = svc.current_room_list_version().await.unwrap();
+    assert_eq!(svc.room_list_version().await.unwrap(), Some(before.clone()));
+
+    svc.make_room(room(1)).await.unwrap();
+    let after = svc.room_list_version().await.unwrap().unwrap();
+    assert_ne!(before, after);
+    assert_ne!(read_cache::etag(&before, "-1"), read_cache::etag(&after, "-1"));
+    assert_ne!(read_cache::etag(&after, "-1"), read_cache::etag(&after, "1"));
+}
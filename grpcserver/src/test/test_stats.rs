@@ -0,0 +1,14 @@
+//! Stats Service Test Module
+//! 
+//! 서버 통계 조회 요청의 프로토콜 필터 처리를 테스트합니다.
+
+use crate::service::stats_service::protocol_filter;
+
+/// 프로토콜 필터 정규화 테스트
+#[test]
+fn test_protocol_filter() {
+    assert_eq!(protocol_filter(""), None);
+    assert_eq!(protocol_filter("  "), None);
+    assert_eq!(protocol_filter("tcp"), Some("tcp".to_string()));
+    assert_eq!(protocol_filter(" RUDP "), Some("rudp".to_string()));
+}
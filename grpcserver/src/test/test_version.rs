@@ -0,0 +1,83 @@
+//! Version Gate Test Module
+//! 
+//! 클라이언트 버전 파싱, 업데이트 판정, 버전 게이트 인터셉터를 테스트합니다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use tonic::{service::Interceptor, Code, Request};
+use crate::tool::version_gate::{version_interceptor, ClientVersion, UpdateStatus, VersionPolicy};
+
+fn policy() -> VersionPolicy {
+    VersionPolicy {
+        min_version: Some(ClientVersion::new(1, 2, 0)),
+        recommended_version: Some(ClientVersion::new(1, 4, 0)),
+        store_urls: HashMap::from([("ios".to_string(), "https://apps.example.com/pt".to_string())]),
+        reject_unversioned: false,
+    }
+}
+
+fn request(version: Option<&str>) -> Request<()> {
+    let mut req = Request::new(());
+    if let Some(version) = version {
+        req.metadata_mut().insert("x-client-version", version.parse().unwrap());
+    }
+    req.metadata_mut().insert("x-client-platform", "ios".parse().unwrap());
+    req
+}
+
+/// 버전 문자열 파싱 테스트
+#[test]
+fn test_client_version_parse() {
+    assert_eq!("1.4.2".parse(), Ok(ClientVersion::new(1, 4, 2)));
+    assert_eq!("v2.1".parse(), Ok(ClientVersion::new(2, 1, 0)));
+    assert_eq!("3".parse(), Ok(ClientVersion::new(3, 0, 0)));
+    assert_eq!("1.0.5-beta+77".parse(), Ok(ClientVersion::new(1, 0, 5)));
+    assert!("1.x".parse::<ClientVersion>().is_err());
+    assert!("1.2.3.4".parse::<ClientVersion>().is_err());
+    assert!(ClientVersion::new(1, 10, 0) > ClientVersion::new(1, 9, 9));
+}
+
+/// 최소/권장 버전 판정 테스트
+#[test]
+fn test_version_policy_check() {
+    let policy = policy();
+
+    let required = policy.check(Some(ClientVersion::new(1, 1, 9)), "iOS");
+    assert_eq!(required.status, UpdateStatus::UpdateRequired);
+    assert_eq!(required.store_url.as_deref(), Some("https://apps.example.com/pt"));
+
+    let optional = policy.check(Some(ClientVersion::new(1, 3, 0)), "android");
+    assert_eq!(optional.status, UpdateStatus::UpdateOptional);
+    assert_eq!(optional.store_url, None);
+
+    let latest = policy.check(Some(ClientVersion::new(1, 4, 0)), "ios");
+    assert_eq!(latest.status, UpdateStatus::UpToDate);
+    assert_eq!(latest.store_url, None);
+
+    assert_eq!(policy.check(None, "ios").status, UpdateStatus::UpToDate);
+    let strict = VersionPolicy { reject_unversioned: true, ..policy };
+    assert_eq!(strict.check(None, "ios").status, UpdateStatus::UpdateRequired);
+}
+
+/// 버전 게이트 인터셉터 테스트
+#[test]
+fn test_version_interceptor() {
+    let mut gate = version_interceptor(Arc::new(policy()));
+
+    assert!(gate.call(request(Some("1.4.0"))).is_ok());
+    assert!(gate.call(request(Some("1.3.0"))).is_ok());
+    assert!(gate.call(request(None)).is_ok());
+
+    let status = gate.call(request(Some("1.0.0"))).unwrap_err();
+    assert_eq!(status.code(), Code::FailedPrecondition);
+    assert_eq!(status.metadata().get("x-update-status").unwrap(), "required");
+    assert_eq!(status.metadata().get("x-min-version").unwrap(), "1.2.0");
+    assert_eq!(status.metadata().get("x-store-url").unwrap(), "https://apps.example.com/pt");
+
+    let status = gate.call(request(Some("latest"))).unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+
+    // 정책이 없으면 모든 요청 통과
+    let mut open = version_interceptor(Arc::new(VersionPolicy::default()));
+    assert!(open.call(request(Some("0.0.1"))).is_ok());
+}
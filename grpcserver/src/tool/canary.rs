@@ -0,0 +1,135 @@
+//! Canary Routing Module
+//!
+//! 새 서비스 구현을 일부 사용자에게만 점진적으로 배포하기 위한 라우팅 계층입니다.
+//! user_id를 플래그 이름과 함께 해시해 0~99 버킷으로 나누고,
+//! 버킷이 플래그 비율보다 작으면 카나리 구현, 아니면 안정 구현으로 요청을 보냅니다.
+//!
+//! 같은 사용자는 비율을 올려도 항상 같은 버킷에 있으므로,
+//! 5% -> 20%로 올릴 때 기존 카나리 사용자는 그대로 카나리에 남습니다.
+//! 사용자를 알 수 없는 요청(JWT 없음)은 안정 구현으로 보냅니다.
+
+use shared::service::TokenService;
+use std::fmt;
+use tonic::{metadata::MetadataMap, Request, Response, Status};
+use tracing::debug;
+
+use crate::room::{
+    room_service_server::RoomService,
+    GetRoomListRequest, GetRoomListResponse,
+    MakeRoomRequest, MakeRoomResponse,
+};
+
+/// 버킷 수 (비율 단위)
+pub const CANARY_BUCKETS: u32 = 100;
+
+/// 요청이 전달될 구현
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanaryRoute {
+    Stable,
+    Canary,
+}
+
+impl fmt::Display for CanaryRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CanaryRoute::Stable => write!(f, "stable"),
+            CanaryRoute::Canary => write!(f, "canary"),
+        }
+    }
+}
+
+/// 카나리 플래그 (이름 + 배포 비율)
+///
+/// 이름은 해시 솔트로도 쓰이므로, 플래그마다 카나리 사용자 집단이 달라집니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CanaryFlag {
+    pub name: String,
+    /// 카나리 구현으로 보낼 사용자 비율 (0~100)
+    pub percent: u8,
+}
+
+impl CanaryFlag {
+    pub fn new(name: impl Into<String>, percent: u8) -> Self {
+        Self { name: name.into(), percent: percent.min(CANARY_BUCKETS as u8) }
+    }
+
+    /// 사용자 버킷 (0~99, 같은 플래그/사용자면 항상 같은 값)
+    pub fn bucket(&self, user_id: i32) -> u32 {
+        // FNV-1a: 프로세스/플랫폼과 무관하게 고정된 해시
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in self.name.as_bytes().iter().chain(b":").chain(&user_id.to_le_bytes()) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+        (hash % u64::from(CANARY_BUCKETS)) as u32
+    }
+
+    /// 사용자의 라우팅 대상 (사용자를 모르면 안정 구현)
+    pub fn route(&self, user_id: Option<i32>) -> CanaryRoute {
+        match user_id {
+            Some(user_id) if self.bucket(user_id) < u32::from(self.percent) => CanaryRoute::Canary,
+            _ => CanaryRoute::Stable,
+        }
+    }
+}
+
+/// 두 서비스 구현 사이의 카나리 라우터
+///
+/// gRPC 서비스 트레이트를 구현하므로 기존 컨트롤러 자리에 그대로 등록할 수 있습니다.
+/// 요청 사용자는 메시지의 user_id, 없으면 `authorization` JWT에서 얻습니다.
+pub struct CanaryRouter<S> {
+    stable: S,
+    canary: S,
+    flag: CanaryFlag,
+    token_service: Option<TokenService>,
+}
+
+impl<S> CanaryRouter<S> {
+    pub fn new(stable: S, canary: S, flag: CanaryFlag) -> Self {
+        Self { stable, canary, flag, token_service: None }
+    }
+
+    /// JWT에서 요청 사용자를 식별하도록 설정
+    pub fn with_token_service(mut self, token_service: TokenService) -> Self {
+        self.token_service = Some(token_service);
+        self
+    }
+
+    /// 사용자의 구현 선택
+    pub fn select(&self, user_id: Option<i32>) -> (CanaryRoute, &S) {
+        match self.flag.route(user_id) {
+            CanaryRoute::Canary => (CanaryRoute::Canary, &self.canary),
+            CanaryRoute::Stable => (CanaryRoute::Stable, &self.stable),
+        }
+    }
+
+    /// JWT 사용자 ID (토큰이 없거나 잘못되면 None)
+    fn jwt_user_id(&self, metadata: &MetadataMap) -> Option<i32> {
+        let token_service = self.token_service.as_ref()?;
+        let req = Request::from_parts(metadata.clone(), Default::default(), ());
+        token_service.with_optional_auth(&req, Ok).ok().flatten()
+    }
+}
+
+#[tonic::async_trait]
+impl<S: RoomService> RoomService for CanaryRouter<S> {
+    async fn make_room(
+        &self,
+        req: Request<MakeRoomRequest>,
+    ) -> Result<Response<MakeRoomResponse>, Status> {
+        let user_id = self.jwt_user_id(req.metadata()).or(Some(req.get_ref().user_id));
+        let (route, svc) = self.select(user_id);
+        debug!(flag = %self.flag.name, ?user_id, %route, "MakeRoom 라우팅");
+        svc.make_room(req).await
+    }
+
+    async fn get_room_list(
+        &self,
+        req: Request<GetRoomListRequest>,
+    ) -> Result<Response<GetRoomListResponse>, Status> {
+        let user_id = self.jwt_user_id(req.metadata());
+        let (route, svc) = self.select(user_id);
+        debug!(flag = %self.flag.name, ?user_id, %route, "GetRoomList 라우팅");
+        svc.get_room_list(req).await
+    }
+}
@@ -0,0 +1,276 @@
+//! Error Locale Layer Module
+//!
+//! 공통 에러 분류의 사용자 메시지를 요청의 `accept-language`에 맞춰 번역하는 tower 레이어입니다.
+//! 에러 응답의 `x-error-message-key`(`error.room_full`)로 로케일별 문구를 찾아 `grpc-message`를 바꿉니다.
+//!
+//! - 카탈로그: `property/error_messages.toml` (`grpc_error_messages_file`, 없으면 빌드에 포함된 파일)
+//! - 로케일 선택: `accept-language`의 품질값(q) 순서대로, 각 태그는 `ja-JP` -> `ja` 순으로 찾고 없으면 기본 로케일
+//! - 서버가 기본 문구 뒤에 덧붙인 내용(입력 오류 상세, 재시도 시간 등)은 그대로 두고 기본 문구만 바꿉니다.
+//!   서버가 직접 작성한 안내문(`GameServerError::with_message`)은 바꾸지 않습니다.
+//! - 핸들러가 에러를 반환한 trailers-only 응답만 대상이며, 스트림 도중의 에러 트레일러는 그대로 전달됩니다.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use anyhow::{anyhow, Context as _, Result};
+use serde::Deserialize;
+use shared::tool::game_error::{ERROR_CODE_METADATA, ERROR_MESSAGE_KEY_METADATA};
+use shared::tool::ErrorCode;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture, Service};
+use tonic::Status;
+use tower::Layer;
+use tracing::{info, warn};
+
+/// 빌드에 포함된 기본 카탈로그
+const BUILTIN_CATALOG: &str = include_str!("../../../property/error_messages.toml");
+
+/// 응답 로케일 헤더
+const CONTENT_LANGUAGE: &str = "content-language";
+
+#[derive(Debug, Deserialize)]
+struct CatalogFile {
+    default_locale: String,
+    #[serde(flatten)]
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+/// 로케일별 에러 메시지
+#[derive(Debug, Clone)]
+pub struct ErrorMessageCatalog {
+    default_locale: String,
+    /// locale -> 메시지 키 -> 문구
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl Default for ErrorMessageCatalog {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl ErrorMessageCatalog {
+    /// 빌드에 포함된 기본 카탈로그
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_CATALOG).expect("기본 에러 메시지 카탈로그가 잘못되었습니다")
+    }
+
+    /// 카탈로그 파일 로드 (파일이 없으면 기본 카탈로그)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            warn!("에러 메시지 카탈로그 파일이 없습니다: {} (기본 카탈로그 사용)", path.display());
+            return Ok(Self::builtin());
+        }
+
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("에러 메시지 카탈로그 읽기 실패: {}", path.display()))?;
+        let catalog = Self::from_toml(&contents)
+            .with_context(|| format!("잘못된 에러 메시지 카탈로그: {}", path.display()))?;
+
+        for (locale, key) in catalog.missing_keys() {
+            warn!("에러 메시지 카탈로그 {}에 {} 없음 (기본 로케일로 대체)", locale, key);
+        }
+        info!("에러 메시지 카탈로그 로드: {:?}", catalog.locales());
+        Ok(catalog)
+    }
+
+    /// TOML 문자열에서 카탈로그 생성 (기본 로케일은 모든 에러 코드의 문구를 가져야 함)
+    pub fn from_toml(contents: &str) -> Result<Self> {
+        let file: CatalogFile = toml::from_str(contents)?;
+        let catalog = Self {
+            default_locale: normalize_locale(&file.default_locale),
+            locales: file
+                .locales
+                .into_iter()
+                .map(|(locale, messages)| (normalize_locale(&locale), messages))
+                .collect(),
+        };
+
+        if !catalog.locales.contains_key(&catalog.default_locale) {
+            return Err(anyhow!("기본 로케일 {} 섹션이 없습니다", catalog.default_locale));
+        }
+        if let Some((_, key)) = catalog.missing_keys().into_iter().find(|(locale, _)| *locale == catalog.default_locale) {
+            return Err(anyhow!("기본 로케일 {}에 {} 메시지가 없습니다", catalog.default_locale, key));
+        }
+        Ok(catalog)
+    }
+
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// 카탈로그의 로케일 목록 (정렬)
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<&str> = self.locales.keys().map(String::as_str).collect();
+        locales.sort_unstable();
+        locales
+    }
+
+    /// 로케일별로 빠진 메시지 키 (로케일, 키)
+    pub fn missing_keys(&self) -> Vec<(String, String)> {
+        let mut missing: Vec<(String, String)> = self
+            .locales
+            .iter()
+            .flat_map(|(locale, messages)| {
+                ErrorCode::ALL
+                    .iter()
+                    .map(|code| code.message_key())
+                    .filter(|key| !messages.contains_key(key))
+                    .map(|key| (locale.clone(), key))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        missing.sort();
+        missing
+    }
+
+    /// `accept-language` 헤더로 로케일 선택
+    ///
+    /// 품질값이 높은 태그부터 `ja-JP` -> `ja` 순으로 카탈로그에 있는 로케일을 찾고, 없으면 기본 로케일입니다.
+    pub fn negotiate(&self, accept_language: Option<&str>) -> String {
+        let mut ranges: Vec<(String, f32)> = accept_language
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let tag = normalize_locale(parts.next()?);
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                (!tag.is_empty() && tag != "*" && quality > 0.0).then_some((tag, quality))
+            })
+            .collect();
+        // 같은 품질값은 헤더 순서 유지
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        ranges
+            .iter()
+            .flat_map(|(tag, _)| fallback_chain(tag))
+            .find(|locale| self.locales.contains_key(locale))
+            .unwrap_or_else(|| self.default_locale.clone())
+    }
+
+    /// 에러 코드의 로케일 문구 (로케일 -> 언어 -> 기본 로케일 -> 기본 사용자 메시지 순)
+    pub fn message(&self, locale: &str, code: ErrorCode) -> &str {
+        let key = code.message_key();
+        fallback_chain(&normalize_locale(locale))
+            .into_iter()
+            .chain(std::iter::once(self.default_locale.clone()))
+            .find_map(|locale| self.locales.get(&locale)?.get(&key))
+            .map_or(code.default_message(), String::as_str)
+    }
+
+    /// 사용자 메시지 번역
+    ///
+    /// 기본 사용자 메시지로 시작하면 그 부분만 로케일 문구로 바꾸고, 서버가 직접 작성한 안내문이면 None입니다.
+    pub fn localize(&self, locale: &str, code: ErrorCode, message: &str) -> Option<String> {
+        let rest = message.strip_prefix(code.default_message())?;
+        Some(format!("{}{}", self.message(locale, code), rest))
+    }
+}
+
+/// 로케일 대체 순서 (`ja-jp` -> `ja`)
+fn fallback_chain(locale: &str) -> Vec<String> {
+    let mut chain = vec![locale.to_string()];
+    if let Some((language, _)) = locale.split_once('-') {
+        chain.push(language.to_string());
+    }
+    chain
+}
+
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+/// 에러 메시지 번역 레이어
+#[derive(Clone)]
+pub struct ErrorLocaleLayer {
+    catalog: Arc<ErrorMessageCatalog>,
+}
+
+impl ErrorLocaleLayer {
+    pub fn new(catalog: Arc<ErrorMessageCatalog>) -> Self {
+        Self { catalog }
+    }
+}
+
+impl<S> Layer<S> for ErrorLocaleLayer {
+    type Service = ErrorLocaleService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ErrorLocaleService { inner, catalog: self.catalog.clone() }
+    }
+}
+
+/// 에러 응답 메시지를 번역하는 서비스
+#[derive(Clone)]
+pub struct ErrorLocaleService<S> {
+    inner: S,
+    catalog: Arc<ErrorMessageCatalog>,
+}
+
+/// trailers-only 에러 응답의 `grpc-message`를 로케일 문구로 교체
+pub fn localize_response(catalog: &ErrorMessageCatalog, locale: &str, response: &mut http::Response<BoxBody>) {
+    let headers = response.headers();
+    let failed = headers.get("grpc-status").is_some_and(|status| status.as_bytes() != b"0");
+    if !failed || !headers.contains_key(ERROR_MESSAGE_KEY_METADATA) {
+        return;
+    }
+    let Some(code) = headers
+        .get(ERROR_CODE_METADATA)
+        .and_then(|value| value.to_str().ok())
+        .and_then(ErrorCode::from_code)
+    else {
+        return;
+    };
+    let Some(status) = Status::from_header_map(headers) else {
+        return;
+    };
+    let Some(message) = catalog.localize(locale, code, status.message()) else {
+        return;
+    };
+
+    // 퍼센트 인코딩은 tonic에 맡김
+    let mut encoded = http::HeaderMap::new();
+    if Status::new(status.code(), message).add_header(&mut encoded).is_err() {
+        return;
+    }
+    let headers = response.headers_mut();
+    if let Some(value) = encoded.remove("grpc-message") {
+        headers.insert("grpc-message", value);
+    }
+    if let Ok(value) = http::HeaderValue::from_str(locale) {
+        headers.insert(CONTENT_LANGUAGE, value);
+    }
+}
+
+impl<S, B> Service<http::Request<B>> for ErrorLocaleService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>> + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let accept_language = req.headers().get("accept-language").and_then(|value| value.to_str().ok());
+        let locale = self.catalog.negotiate(accept_language);
+        let catalog = self.catalog.clone();
+        let future = self.inner.call(req);
+
+        Box::pin(async move {
+            let mut response = future.await?;
+            localize_response(&catalog, &locale, &mut response);
+            Ok(response)
+        })
+    }
+}
@@ -0,0 +1,217 @@
+//! Image Sanitizer Module
+//!
+//! 업로드된 아바타 이미지를 검증한 뒤 픽셀로 디코딩하고 같은 포맷으로 다시 인코딩합니다.
+//! 원본 파일의 바이트는 하나도 그대로 쓰지 않으므로 EXIF(GPS 위치 포함), XMP, 텍스트 주석,
+//! 촬영 시각 같은 메타데이터와 압축 데이터 안에 숨긴 페이로드(폴리글랏 파일)가 모두 사라집니다.
+//!
+//! - 디코딩 전에 헤더(PNG IHDR, JPEG SOF)의 크기를 먼저 확인해 압축 폭탄을 막습니다.
+//! - PNG는 PNG로, JPEG는 JPEG(품질 90)로 다시 인코딩합니다.
+//!
+//! 실제 포맷은 선언된 Content-Type이 아니라 파일 시그니처로 판정하며, 둘이 다르면 거부합니다.
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::DynamicImage;
+use std::fmt;
+
+/// 업로드 허용 Content-Type
+pub const AVATAR_CONTENT_TYPES: &[&str] = &["image/png", "image/jpeg"];
+
+/// PNG 파일 시그니처
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+/// JPEG 재인코딩 품질
+const JPEG_QUALITY: u8 = 90;
+
+/// 이미지 포맷
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+}
+
+impl ImageFormat {
+    /// Content-Type 문자열로 포맷 판정
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        match content_type.trim().to_ascii_lowercase().as_str() {
+            "image/png" => Some(ImageFormat::Png),
+            "image/jpeg" | "image/jpg" => Some(ImageFormat::Jpeg),
+            _ => None,
+        }
+    }
+
+    /// 파일 시그니처로 포맷 판정
+    pub fn sniff(data: &[u8]) -> Option<Self> {
+        if data.starts_with(PNG_SIGNATURE) {
+            Some(ImageFormat::Png)
+        } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            Some(ImageFormat::Jpeg)
+        } else {
+            None
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "image/png",
+            ImageFormat::Jpeg => "image/jpeg",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpg",
+        }
+    }
+
+    fn codec(&self) -> image::ImageFormat {
+        match self {
+            ImageFormat::Png => image::ImageFormat::Png,
+            ImageFormat::Jpeg => image::ImageFormat::Jpeg,
+        }
+    }
+}
+
+/// 이미지 검증 실패
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// 지원하지 않는 Content-Type
+    UnsupportedType(String),
+    /// 선언한 Content-Type과 실제 포맷 불일치
+    TypeMismatch { declared: String },
+    /// 가로/세로 크기 제한 초과
+    TooLarge { width: u32, height: u32, max: u32 },
+    /// 손상되었거나 잘린 파일
+    Malformed(&'static str),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::UnsupportedType(content_type) => write!(f, "지원하지 않는 이미지 형식: {content_type} (image/png, image/jpeg만 허용)"),
+            ImageError::TypeMismatch { declared } => write!(f, "선언한 형식({declared})과 실제 이미지 형식이 다릅니다"),
+            ImageError::TooLarge { width, height, max } => write!(f, "이미지 크기 {width}x{height}가 최대 {max}x{max}를 초과합니다"),
+            ImageError::Malformed(reason) => write!(f, "손상된 이미지: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+/// 정리된 이미지
+#[derive(Debug, Clone)]
+pub struct SanitizedImage {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+/// 이미지 검증 후 픽셀만 다시 인코딩한 파일 생성
+///
+/// # Arguments
+/// * `content_type` - 클라이언트가 선언한 Content-Type
+/// * `data` - 업로드된 원본 파일
+/// * `max_dimension` - 허용하는 최대 가로/세로 픽셀
+pub fn sanitize_image(content_type: &str, data: &[u8], max_dimension: u32) -> Result<SanitizedImage, ImageError> {
+    let declared = ImageFormat::from_content_type(content_type)
+        .ok_or_else(|| ImageError::UnsupportedType(content_type.to_string()))?;
+    if ImageFormat::sniff(data) != Some(declared) {
+        return Err(ImageError::TypeMismatch { declared: content_type.to_string() });
+    }
+
+    // 디코딩 전에 헤더의 크기로 제한 확인 (작은 파일이 거대한 픽셀 버퍼로 풀리는 것 방지)
+    let (width, height) = match declared {
+        ImageFormat::Png => png_dimensions(data)?,
+        ImageFormat::Jpeg => jpeg_dimensions(data)?,
+    };
+    if width == 0 || height == 0 {
+        return Err(ImageError::Malformed("가로/세로 크기가 0입니다"));
+    }
+    if width > max_dimension || height > max_dimension {
+        return Err(ImageError::TooLarge { width, height, max: max_dimension });
+    }
+
+    let pixels = image::load_from_memory_with_format(data, declared.codec())
+        .map_err(|_| ImageError::Malformed("이미지를 디코딩할 수 없습니다"))?;
+    if (pixels.width(), pixels.height()) != (width, height) {
+        return Err(ImageError::Malformed("헤더와 디코딩된 크기가 다릅니다"));
+    }
+    let data = encode(declared, pixels)?;
+
+    Ok(SanitizedImage { format: declared, width, height, data })
+}
+
+/// 디코딩된 픽셀을 같은 포맷으로 인코딩
+fn encode(format: ImageFormat, pixels: DynamicImage) -> Result<Vec<u8>, ImageError> {
+    let mut output = Vec::new();
+    let result = match format {
+        ImageFormat::Png => pixels.write_with_encoder(PngEncoder::new(&mut output)),
+        ImageFormat::Jpeg => {
+            // JPEG 인코더는 그레이스케일과 RGB만 지원
+            let pixels = match pixels {
+                DynamicImage::ImageLuma8(_) => pixels,
+                pixels => DynamicImage::ImageRgb8(pixels.to_rgb8()),
+            };
+            pixels.write_with_encoder(JpegEncoder::new_with_quality(&mut output, JPEG_QUALITY))
+        }
+    };
+    result.map_err(|_| ImageError::Malformed("이미지를 다시 인코딩할 수 없습니다"))?;
+    Ok(output)
+}
+
+/// PNG 크기 (시그니처 바로 뒤의 IHDR 청크)
+fn png_dimensions(data: &[u8]) -> Result<(u32, u32), ImageError> {
+    let header = data
+        .get(PNG_SIGNATURE.len()..PNG_SIGNATURE.len() + 16)
+        .ok_or(ImageError::Malformed("PNG 청크가 잘렸습니다"))?;
+    if &header[4..8] != b"IHDR" {
+        return Err(ImageError::Malformed("IHDR 청크가 처음에 없습니다"));
+    }
+    let width = u32::from_be_bytes([header[8], header[9], header[10], header[11]]);
+    let height = u32::from_be_bytes([header[12], header[13], header[14], header[15]]);
+    Ok((width, height))
+}
+
+/// JPEG 크기 (스캔 전의 첫 SOF 세그먼트)
+fn jpeg_dimensions(data: &[u8]) -> Result<(u32, u32), ImageError> {
+    // 디코더는 잘린 스캔 데이터를 채워 넣으므로 파일 끝을 직접 확인
+    if !data.ends_with(&[0xFF, 0xD9]) {
+        return Err(ImageError::Malformed("JPEG 파일 끝(EOI)이 없습니다"));
+    }
+
+    let mut offset = 2;
+    loop {
+        let marker = match data.get(offset..offset + 2) {
+            Some([0xFF, marker]) => *marker,
+            _ => return Err(ImageError::Malformed("JPEG 마커가 없습니다")),
+        };
+        // 채움 바이트 (0xFF 연속)
+        if marker == 0xFF {
+            offset += 1;
+            continue;
+        }
+        if matches!(marker, 0xD9 | 0xDA) {
+            return Err(ImageError::Malformed("JPEG 프레임(SOF)이 없습니다"));
+        }
+
+        let length = data
+            .get(offset + 2..offset + 4)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]) as usize)
+            .filter(|length| *length >= 2)
+            .ok_or(ImageError::Malformed("JPEG 세그먼트가 잘렸습니다"))?;
+        let end = offset + 2 + length;
+        let segment = data.get(offset..end).ok_or(ImageError::Malformed("JPEG 세그먼트 길이가 파일 크기를 넘습니다"))?;
+
+        // SOF0~SOF15 (DHT, JPG, DAC 제외)
+        if matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+            if segment.len() < 9 {
+                return Err(ImageError::Malformed("잘못된 SOF 세그먼트"));
+            }
+            let height = u16::from_be_bytes([segment[5], segment[6]]) as u32;
+            let width = u16::from_be_bytes([segment[7], segment[8]]) as u32;
+            return Ok((width, height));
+        }
+        offset = end;
+    }
+}
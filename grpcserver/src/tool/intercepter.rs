@@ -5,6 +5,7 @@
 
 use tonic::{Request, Status, service::Interceptor};
 use shared::service::TokenService;
+use shared::tool::{ErrorCode, GameServerError};
 use tracing::{info, error};
 
 /// JWT 토큰 검증 인터셉터
@@ -28,7 +29,7 @@ pub fn jwt_interceptor(token_service: TokenService) -> impl Interceptor {
             }
             Err(e) => {
                 error!("❌ JWT 토큰 검증 실패: error={}", e);
-                Err(Status::unauthenticated("Invalid or expired token"))
+                Err(GameServerError::with_detail(ErrorCode::AuthFailed, "Invalid or expired token").into())
             }
         }
     }
@@ -41,24 +42,24 @@ pub fn jwt_interceptor(token_service: TokenService) -> impl Interceptor {
 /// 
 /// # Returns
 /// * `Result<String, Status>` - 추출된 토큰 또는 에러
-fn extract_token_from_headers(metadata: &tonic::metadata::MetadataMap) -> Result<String, Status> {
+pub fn extract_token_from_headers(metadata: &tonic::metadata::MetadataMap) -> Result<String, Status> {
     // Authorization 헤더에서 Bearer 토큰 추출
     let auth_header = metadata
         .get("authorization")
-        .ok_or_else(|| Status::unauthenticated("Missing authorization header"))?;
+        .ok_or_else(|| Status::from(GameServerError::with_detail(ErrorCode::AuthFailed, "Missing authorization header")))?;
     
     let auth_value = auth_header
         .to_str()
-        .map_err(|_| Status::invalid_argument("Invalid authorization header"))?;
+        .map_err(|_| Status::from(GameServerError::with_detail(ErrorCode::AuthFailed, "Invalid authorization header")))?;
     
     if !auth_value.starts_with("Bearer ") {
-        return Err(Status::invalid_argument("Invalid authorization format. Expected 'Bearer <token>'"));
+        return Err(GameServerError::with_detail(ErrorCode::AuthFailed, "Invalid authorization format. Expected 'Bearer <token>'").into());
     }
     
     let token = auth_value[7..].to_string(); // "Bearer " 제거
     
     if token.is_empty() {
-        return Err(Status::invalid_argument("Empty token"));
+        return Err(GameServerError::with_detail(ErrorCode::AuthFailed, "Empty token").into());
     }
     
     Ok(token)
@@ -79,3 +80,14 @@ pub fn logging_interceptor() -> impl Interceptor {
         Ok(req)
     }
 }
+
+/// 인터셉터 연결
+///
+/// `first`가 통과시킨 요청만 `second`로 넘깁니다. (tonic은 서비스당 인터셉터 하나만 허용)
+pub fn chain<A, B>(mut first: A, mut second: B) -> impl Interceptor + Clone
+where
+    A: Interceptor + Clone,
+    B: Interceptor + Clone,
+{
+    move |req: Request<()>| second.call(first.call(req)?)
+}
@@ -1,2 +1,10 @@
 
-pub mod intercepter;
\ No newline at end of file
+pub mod canary;
+pub mod error_locale;
+pub mod image_sanitizer;
+pub mod intercepter;
+pub mod policy_layer;
+pub mod read_cache;
+pub mod response_compression;
+pub mod role_guard;
+pub mod version_gate;
@@ -0,0 +1,191 @@
+//! Security Policy Layer Module
+//!
+//! 공유 보안 정책(`SECURITY_POLICY_FILE`)을 gRPC 메서드 경로 단위로 적용하는 tower 레이어입니다.
+//! tonic 서버 인터셉터는 호출 메서드를 알 수 없으므로 HTTP 요청 경로를 보는 레이어로 구현합니다.
+//!
+//! - 인증: `authorization: Bearer <token>`을 사용자 토큰(`TokenService`) 또는
+//!   역할 토큰(`JwtManager`)으로 검증해 역할을 구합니다.
+//! - 요청 한도: 클라이언트 IP 단위
+//! - 페이로드: `content-length` 헤더가 있을 때만 검사 (스트리밍 요청은 tonic 디코딩 한도를 따름)
+//! - 킬 스위치: 라이브 설정의 `kill.grpc:<패키지>` 또는 `kill.grpc:<패키지>.<서비스>/<메서드>`가
+//!   켜져 있으면 `FEATURE_DISABLED`로 거부합니다. 킬 스위치를 끄는 `liveops` 서비스는 제외합니다.
+
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use shared::security::{JwtManager, PolicyEnforcer, UserRole};
+use shared::service::redis::live_config::LiveConfig;
+use shared::service::TokenService;
+use shared::tool::{ErrorCode, GameServerError};
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture, Service};
+use tonic::transport::server::TcpConnectInfo;
+use tonic::Status;
+use tower::Layer;
+use tracing::warn;
+
+/// 보안 정책 레이어
+#[derive(Clone)]
+pub struct PolicyLayer {
+    state: Arc<PolicyState>,
+}
+
+#[derive(Clone)]
+struct PolicyState {
+    enforcer: Arc<PolicyEnforcer>,
+    /// 사용자 토큰 검증 (검증되면 `user` 역할)
+    tokens: Option<TokenService>,
+    /// 역할 클레임이 있는 토큰 검증
+    role_tokens: Option<Arc<JwtManager>>,
+    /// 킬 스위치 조회
+    live_config: Option<LiveConfig>,
+}
+
+impl PolicyLayer {
+    pub fn new(enforcer: Arc<PolicyEnforcer>) -> Self {
+        Self {
+            state: Arc::new(PolicyState { enforcer, tokens: None, role_tokens: None, live_config: None }),
+        }
+    }
+
+    /// 사용자 토큰 검증기 설정
+    pub fn with_token_service(mut self, tokens: TokenService) -> Self {
+        Arc::make_mut(&mut self.state).tokens = Some(tokens);
+        self
+    }
+
+    /// 역할 토큰 검증기 설정
+    pub fn with_role_tokens(mut self, role_tokens: JwtManager) -> Self {
+        Arc::make_mut(&mut self.state).role_tokens = Some(Arc::new(role_tokens));
+        self
+    }
+
+    /// 킬 스위치용 라이브 설정
+    pub fn with_live_config(mut self, live_config: LiveConfig) -> Self {
+        Arc::make_mut(&mut self.state).live_config = Some(live_config);
+        self
+    }
+}
+
+/// 메서드 경로의 킬 스위치 기능 이름 (패키지 전체, 메서드 단위)
+///
+/// `/room.RoomService/CreateRoom` → `grpc:room`, `grpc:room.RoomService/CreateRoom`.
+/// `liveops` 서비스와 형식이 맞지 않는 경로는 빈 목록입니다.
+pub fn kill_switch_features(path: &str) -> Vec<String> {
+    let Some(method_path) = path.strip_prefix('/') else {
+        return Vec::new();
+    };
+    let Some((package, _)) = method_path.split_once('.') else {
+        return Vec::new();
+    };
+    if package == "liveops" {
+        return Vec::new();
+    }
+    vec![format!("grpc:{package}"), format!("grpc:{method_path}")]
+}
+
+impl<S> Layer<S> for PolicyLayer {
+    type Service = PolicyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PolicyService { inner, state: self.state.clone() }
+    }
+}
+
+/// 보안 정책을 적용하는 서비스
+#[derive(Clone)]
+pub struct PolicyService<S> {
+    inner: S,
+    state: Arc<PolicyState>,
+}
+
+/// 정책 검사에 필요한 요청 정보 (요청 본문과 분리해 future로 옮김)
+struct RequestInfo {
+    path: String,
+    client: String,
+    payload_len: usize,
+    token: Option<String>,
+}
+
+impl RequestInfo {
+    fn from_request<B>(req: &http::Request<B>) -> Self {
+        let header = |name: &str| req.headers().get(name).and_then(|value| value.to_str().ok());
+        Self {
+            path: req.uri().path().to_string(),
+            client: req
+                .extensions()
+                .get::<TcpConnectInfo>()
+                .and_then(|info| info.remote_addr())
+                .map(|addr| addr.ip().to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+            payload_len: header("content-length").and_then(|len| len.parse().ok()).unwrap_or(0),
+            token: header("authorization")
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .map(str::to_string),
+        }
+    }
+}
+
+impl PolicyState {
+    /// 토큰에서 역할을 구합니다 (토큰이 없거나 유효하지 않으면 미인증).
+    async fn roles(&self, token: Option<&str>) -> Option<Vec<UserRole>> {
+        let token = token?;
+        if let Some(role_tokens) = &self.role_tokens {
+            if let Ok(claims) = role_tokens.verify_token(token).await {
+                return Some(claims.roles.iter().filter_map(|role| UserRole::from_str(role)).collect());
+            }
+        }
+        match &self.tokens {
+            Some(tokens) if tokens.verify_token(token).is_ok() => Some(vec![UserRole::User]),
+            _ => None,
+        }
+    }
+
+    async fn check(&self, info: RequestInfo) -> Result<(), Status> {
+        let roles = self.roles(info.token.as_deref()).await;
+        self.enforcer
+            .check(&info.path, &info.client, info.payload_len, roles.as_deref())
+            .map_err(|e| {
+                warn!("보안 정책 거부: {} (client={}): {}", info.path, info.client, e);
+                Status::from(GameServerError::from(e))
+            })?;
+
+        if let Some(live_config) = &self.live_config {
+            if let Some(feature) = kill_switch_features(&info.path).into_iter().find(|feature| live_config.is_killed(feature)) {
+                warn!("킬 스위치로 거부: {} ({})", info.path, feature);
+                return Err(GameServerError::from(ErrorCode::FeatureDisabled).into());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S, B> Service<http::Request<B>> for PolicyService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        // 준비된 서비스를 future로 가져가고 복제본을 남김 (tower 관례)
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let state = self.state.clone();
+        let info = RequestInfo::from_request(&req);
+
+        Box::pin(async move {
+            match state.check(info).await {
+                Ok(()) => inner.call(req).await,
+                Err(status) => Ok(status.to_http()),
+            }
+        })
+    }
+}
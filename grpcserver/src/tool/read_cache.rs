@@ -0,0 +1,50 @@
+//! Read Cache Validator Module
+//!
+//! 자주 폴링하는 읽기 RPC에 HTTP 캐시와 같은 방식의 검증자를 붙입니다.
+//! 응답 메타데이터에 `etag`와 `cache-control`을 담고, 클라이언트가 다음 요청에
+//! `if-none-match`로 받은 `etag`를 보내면 데이터가 그대로일 때 본문 없이
+//! `x-cache-status: not-modified`로 응답합니다. (gRPC에는 304가 없으므로 OK 상태 사용)
+//!
+//! `if-none-match`를 보내지 않는 클라이언트는 항상 전체 응답을 받습니다.
+
+use tonic::{metadata::MetadataMap, Response};
+
+/// 클라이언트가 보내는 검증자 메타데이터 키
+pub const IF_NONE_MATCH_HEADER: &str = "if-none-match";
+/// 응답 검증자 메타데이터 키
+pub const ETAG_HEADER: &str = "etag";
+/// 응답 캐시 정책 메타데이터 키
+pub const CACHE_CONTROL_HEADER: &str = "cache-control";
+/// 검증 결과 메타데이터 키 (`not-modified` 또는 `miss`)
+pub const CACHE_STATUS_HEADER: &str = "x-cache-status";
+
+/// 데이터 버전 토큰과 요청 범위(페이지 등)로 검증자 생성
+pub fn etag(version: &str, scope: &str) -> String {
+    format!("W/\"{version}:{scope}\"")
+}
+
+/// 요청의 `if-none-match` 값
+pub fn if_none_match(metadata: &MetadataMap) -> Option<String> {
+    metadata
+        .get(IF_NONE_MATCH_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// 검증자와 캐시 정책을 담은 응답 생성
+///
+/// `not_modified`이면 `message`는 빈 응답이어야 하며, 클라이언트는 캐시한 본문을 계속 사용합니다.
+pub fn respond<T>(message: T, etag: Option<&str>, max_age_secs: u64, not_modified: bool) -> Response<T> {
+    let mut response = Response::new(message);
+    let metadata = response.metadata_mut();
+    if let Some(value) = etag.and_then(|etag| etag.parse().ok()) {
+        metadata.insert(ETAG_HEADER, value);
+    }
+    if let Ok(value) = format!("private, max-age={max_age_secs}").parse() {
+        metadata.insert(CACHE_CONTROL_HEADER, value);
+    }
+    let status = if not_modified { "not-modified" } else { "miss" };
+    metadata.insert(CACHE_STATUS_HEADER, tonic::metadata::MetadataValue::from_static(status));
+    response
+}
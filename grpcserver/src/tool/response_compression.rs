@@ -0,0 +1,290 @@
+//! Response Compression Layer Module
+//!
+//! gRPC 응답 메시지를 클라이언트가 `grpc-accept-encoding`으로 알린 방식(zstd, gzip)으로
+//! 압축하는 tower 레이어입니다. 서비스별 `send_compressed` 설정 없이 모든 서비스에 적용됩니다.
+//!
+//! - 설정: 공유 `MessageCompressionConfig` (알고리즘, 임계값, 레벨)
+//! - 응답 본문을 gRPC 프레임(1바이트 압축 플래그 + 4바이트 길이) 단위로 다시 써서,
+//!   임계값 이상이고 압축 결과가 더 작은 메시지만 압축 플래그를 켭니다.
+//! - 서비스가 이미 `grpc-encoding`을 정했거나 gRPC 응답이 아니면 그대로 통과합니다.
+
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use shared::tool::high_performance::{CompressionAlgorithm, MessageCompressionConfig};
+use tonic::body::BoxBody;
+use tonic::codegen::{http, Body, BoxFuture, Bytes, Service};
+use tonic::Status;
+use tower::Layer;
+
+/// gRPC 메시지 프레임 헤더 길이 (압축 플래그 1 + 길이 4)
+const FRAME_HEADER_LEN: usize = 5;
+
+/// 응답 압축 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseEncoding {
+    Zstd,
+    Gzip,
+}
+
+impl ResponseEncoding {
+    /// `grpc-encoding` 헤더 값
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResponseEncoding::Zstd => "zstd",
+            ResponseEncoding::Gzip => "gzip",
+        }
+    }
+
+    /// 메시지 압축
+    pub fn compress(&self, data: &[u8], level: i32) -> std::io::Result<Vec<u8>> {
+        match self {
+            ResponseEncoding::Zstd => zstd::bulk::compress(data, level.clamp(1, 19)),
+            ResponseEncoding::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::with_capacity(data.len() / 2), Compression::new(level.clamp(1, 9) as u32));
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+        }
+    }
+}
+
+/// 서버가 응답에 쓸 수 있는 방식 (선호 순서)
+fn supported_encodings(algorithm: CompressionAlgorithm) -> &'static [ResponseEncoding] {
+    match algorithm {
+        CompressionAlgorithm::None => &[],
+        CompressionAlgorithm::Zstd => &[ResponseEncoding::Zstd],
+        // gRPC에는 LZ4 표준 인코딩이 없으므로 적응형과 같이 처리
+        CompressionAlgorithm::Adaptive | CompressionAlgorithm::LZ4 => &[ResponseEncoding::Zstd, ResponseEncoding::Gzip],
+    }
+}
+
+/// 응답 압축 방식 협상
+///
+/// 서버 선호 순서대로 클라이언트의 `grpc-accept-encoding` 목록에 있는 첫 방식을 고릅니다.
+pub fn negotiate(accept_encoding: &str, algorithm: CompressionAlgorithm) -> Option<ResponseEncoding> {
+    let accepted: Vec<&str> = accept_encoding
+        .split(',')
+        .map(|value| value.split(';').next().unwrap_or_default().trim())
+        .collect();
+    supported_encodings(algorithm)
+        .iter()
+        .copied()
+        .find(|encoding| accepted.iter().any(|value| value.eq_ignore_ascii_case(encoding.as_str())))
+}
+
+/// 응답 압축 통계
+#[derive(Debug, Default)]
+pub struct ResponseCompressionStats {
+    /// 압축 협상된 응답 수
+    pub responses: AtomicU64,
+    /// 처리한 메시지 수
+    pub messages: AtomicU64,
+    /// 실제로 압축한 메시지 수
+    pub compressed_messages: AtomicU64,
+    /// 압축 전 바이트 (압축한 메시지만)
+    pub original_bytes: AtomicU64,
+    /// 압축 후 바이트 (압축한 메시지만)
+    pub compressed_bytes: AtomicU64,
+}
+
+impl ResponseCompressionStats {
+    /// 압축률 (%) - 압축한 메시지 기준 절감 비율
+    pub fn saved_percent(&self) -> f64 {
+        let original = self.original_bytes.load(Ordering::Relaxed);
+        if original == 0 {
+            return 0.0;
+        }
+        let compressed = self.compressed_bytes.load(Ordering::Relaxed);
+        (1.0 - compressed as f64 / original as f64) * 100.0
+    }
+}
+
+/// 응답 압축 레이어
+#[derive(Clone)]
+pub struct ResponseCompressionLayer {
+    config: Arc<MessageCompressionConfig>,
+    stats: Arc<ResponseCompressionStats>,
+}
+
+impl ResponseCompressionLayer {
+    pub fn new(config: MessageCompressionConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            stats: Arc::new(ResponseCompressionStats::default()),
+        }
+    }
+
+    /// 압축 통계
+    pub fn stats(&self) -> Arc<ResponseCompressionStats> {
+        self.stats.clone()
+    }
+}
+
+impl<S> Layer<S> for ResponseCompressionLayer {
+    type Service = ResponseCompressionService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResponseCompressionService {
+            inner,
+            config: self.config.clone(),
+            stats: self.stats.clone(),
+        }
+    }
+}
+
+/// 응답을 압축하는 서비스
+#[derive(Clone)]
+pub struct ResponseCompressionService<S> {
+    inner: S,
+    config: Arc<MessageCompressionConfig>,
+    stats: Arc<ResponseCompressionStats>,
+}
+
+impl<S, B> Service<http::Request<B>> for ResponseCompressionService<S>
+where
+    S: Service<http::Request<B>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    B: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        // 준비된 서비스를 future로 가져가고 복제본을 남김 (tower 관례)
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let encoding = req.headers()
+            .get("grpc-accept-encoding")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| negotiate(value, self.config.algorithm));
+        let config = self.config.clone();
+        let stats = self.stats.clone();
+
+        Box::pin(async move {
+            let response = inner.call(req).await?;
+            let Some(encoding) = encoding else {
+                return Ok(response);
+            };
+            let is_grpc = response.headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("application/grpc"));
+            if !is_grpc || response.headers().contains_key("grpc-encoding") {
+                return Ok(response);
+            }
+
+            stats.responses.fetch_add(1, Ordering::Relaxed);
+            let (mut parts, body) = response.into_parts();
+            parts.headers.insert("grpc-encoding", http::HeaderValue::from_static(encoding.as_str()));
+            let body = CompressedBody {
+                inner: body,
+                encoding,
+                threshold: config.compression_threshold,
+                level: config.compression_level,
+                buffer: Vec::new(),
+                stats,
+            };
+            Ok(http::Response::from_parts(parts, body.boxed_unsync()))
+        })
+    }
+}
+
+/// gRPC 프레임 단위로 메시지를 압축하는 응답 본문
+struct CompressedBody {
+    inner: BoxBody,
+    encoding: ResponseEncoding,
+    threshold: usize,
+    level: i32,
+    /// 아직 완성되지 않은 프레임
+    buffer: Vec<u8>,
+    stats: Arc<ResponseCompressionStats>,
+}
+
+impl CompressedBody {
+    /// 버퍼에 완성된 프레임이 있으면 꺼내서 (필요하면) 압축
+    fn next_frame(&mut self) -> Option<Bytes> {
+        if self.buffer.len() < FRAME_HEADER_LEN {
+            return None;
+        }
+        let len = u32::from_be_bytes([self.buffer[1], self.buffer[2], self.buffer[3], self.buffer[4]]) as usize;
+        if self.buffer.len() < FRAME_HEADER_LEN + len {
+            return None;
+        }
+        let frame: Vec<u8> = self.buffer.drain(..FRAME_HEADER_LEN + len).collect();
+        Some(Bytes::from(compress_frame(frame, self.encoding, self.threshold, self.level, &self.stats)))
+    }
+}
+
+/// 프레임 하나 압축 (이미 압축되었거나, 작거나, 압축 효과가 없으면 그대로)
+pub fn compress_frame(
+    frame: Vec<u8>,
+    encoding: ResponseEncoding,
+    threshold: usize,
+    level: i32,
+    stats: &ResponseCompressionStats,
+) -> Vec<u8> {
+    stats.messages.fetch_add(1, Ordering::Relaxed);
+    let message = &frame[FRAME_HEADER_LEN..];
+    if frame[0] != 0 || message.len() < threshold {
+        return frame;
+    }
+    let compressed = match encoding.compress(message, level) {
+        Ok(compressed) if compressed.len() < message.len() => compressed,
+        _ => return frame,
+    };
+
+    stats.compressed_messages.fetch_add(1, Ordering::Relaxed);
+    stats.original_bytes.fetch_add(message.len() as u64, Ordering::Relaxed);
+    stats.compressed_bytes.fetch_add(compressed.len() as u64, Ordering::Relaxed);
+
+    let mut out = Vec::with_capacity(FRAME_HEADER_LEN + compressed.len());
+    out.push(1);
+    out.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+    out.extend_from_slice(&compressed);
+    out
+}
+
+impl Body for CompressedBody {
+    type Data = Bytes;
+    type Error = Status;
+
+    fn poll_data(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        loop {
+            if let Some(frame) = self.next_frame() {
+                return Poll::Ready(Some(Ok(frame)));
+            }
+            match Pin::new(&mut self.inner).poll_data(cx) {
+                Poll::Ready(Some(Ok(data))) => self.buffer.extend_from_slice(&data),
+                Poll::Ready(Some(Err(status))) => return Poll::Ready(Some(Err(status))),
+                Poll::Ready(None) => {
+                    // 잘린 프레임은 손대지 않고 그대로 내보냄 (클라이언트가 오류 처리)
+                    if self.buffer.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    let rest = std::mem::take(&mut self.buffer);
+                    return Poll::Ready(Some(Ok(Bytes::from(rest))));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+
+    fn poll_trailers(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<Option<http::HeaderMap>, Self::Error>> {
+        Pin::new(&mut self.inner).poll_trailers(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.buffer.is_empty() && self.inner.is_end_stream()
+    }
+}
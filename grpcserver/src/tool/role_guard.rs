@@ -0,0 +1,57 @@
+//! Role Guard Module
+//!
+//! JWT 클레임의 역할로 관리자용 엔드포인트 접근을 제한합니다.
+//! 엔드포인트별 필요 권한은 공유 접근 제어 매트릭스를 따릅니다.
+
+use tonic::{Request, Status};
+use tracing::warn;
+use shared::security::{AccessControlMatrix, ApiEndpoint, JwtManager, SecurityConfig, SecurityError, UserRole};
+
+use crate::tool::intercepter::extract_token_from_headers;
+use shared::tool::{ErrorCode, GameServerError};
+
+/// 역할 기반 접근 가드
+pub struct RoleGuard {
+    /// JWT 검증 (역할 클레임 포함)
+    jwt_manager: JwtManager,
+    /// 역할별 접근 제어
+    access_control: AccessControlMatrix,
+}
+
+impl RoleGuard {
+    /// 환경변수의 JWT 설정으로 가드를 생성합니다.
+    ///
+    /// # Returns
+    /// * `Result<Self, SecurityError>` - JWT 설정이 유효하지 않으면 에러
+    pub fn from_env() -> Result<Self, SecurityError> {
+        Ok(Self {
+            jwt_manager: JwtManager::new(SecurityConfig::from_env()?)?,
+            access_control: AccessControlMatrix::new(),
+        })
+    }
+
+    /// 요청자가 엔드포인트에 접근할 수 있는지 확인합니다.
+    ///
+    /// # Arguments
+    /// * `req` - gRPC 요청 (Authorization 헤더 필요)
+    /// * `endpoint` - 접근하려는 엔드포인트
+    ///
+    /// # Returns
+    /// * `Result<String, Status>` - 권한이 있는 요청자의 사용자 ID
+    pub async fn authorize<T>(&self, req: &Request<T>, endpoint: &ApiEndpoint) -> Result<String, Status> {
+        let token = extract_token_from_headers(req.metadata())?;
+        let claims = self.jwt_manager.verify_token(&token).await
+            .map_err(|_| Status::from(GameServerError::with_detail(ErrorCode::AuthFailed, "Invalid or expired token")))?;
+
+        let roles: Vec<UserRole> = claims.roles.iter()
+            .filter_map(|role| UserRole::from_str(role))
+            .collect();
+
+        if let Err(reason) = self.access_control.check_permission(&roles, endpoint, claims.sub.parse().ok()) {
+            warn!("엔드포인트 접근 거부: {}.{}, user={}, reason={}", endpoint.service, endpoint.method, claims.sub, reason);
+            return Err(GameServerError::with_detail(ErrorCode::PermissionDenied, format!("Insufficient role for {}.{}", endpoint.service, endpoint.method)).into());
+        }
+
+        Ok(claims.sub)
+    }
+}
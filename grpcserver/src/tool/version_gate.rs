@@ -0,0 +1,204 @@
+//! Client Version Gate Module
+//!
+//! 클라이언트 게임 버전을 최소/권장 버전과 비교해 업데이트 필요 여부를 판정합니다.
+//! 게임 서비스에는 인터셉터로 적용되어, 최소 버전보다 낮은 클라이언트의 요청을
+//! 스토어 URL이 담긴 `FAILED_PRECONDITION` 응답으로 거부합니다.
+//!
+//! 클라이언트는 `x-client-version`(예: `1.4.2`)과 `x-client-platform`(`ios`, `android` 등)
+//! 메타데이터를 보냅니다.
+
+use shared::auth::ServiceIdentity;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use tonic::{metadata::MetadataValue, service::Interceptor, Request, Status};
+use tracing::{debug, warn};
+
+/// 클라이언트 버전 메타데이터 키
+pub const CLIENT_VERSION_HEADER: &str = "x-client-version";
+/// 클라이언트 플랫폼 메타데이터 키
+pub const CLIENT_PLATFORM_HEADER: &str = "x-client-platform";
+
+/// 클라이언트 버전 (major.minor.patch)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClientVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ClientVersion {
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self { major, minor, patch }
+    }
+}
+
+impl fmt::Display for ClientVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for ClientVersion {
+    type Err = String;
+
+    /// `1`, `1.4`, `1.4.2`, `v1.4.2` 형식을 허용합니다. (빌드 접미사 `-beta` 등은 무시)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let core = s.trim().trim_start_matches(['v', 'V']);
+        let core = core.split(['-', '+']).next().unwrap_or_default();
+
+        let mut parts = [0u32; 3];
+        let mut count = 0;
+        for part in core.split('.') {
+            if count == parts.len() {
+                return Err(format!("버전 구성 요소가 너무 많습니다: '{s}'"));
+            }
+            parts[count] = part.parse().map_err(|_| format!("잘못된 버전 형식: '{s}'"))?;
+            count += 1;
+        }
+
+        Ok(Self::new(parts[0], parts[1], parts[2]))
+    }
+}
+
+/// 업데이트 필요 여부
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateStatus {
+    /// 최신 버전
+    UpToDate,
+    /// 권장 버전보다 낮음 (계속 사용 가능)
+    UpdateOptional,
+    /// 최소 버전보다 낮음 (업데이트 전까지 게임 서비스 사용 불가)
+    UpdateRequired,
+}
+
+impl UpdateStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpdateStatus::UpToDate => "up_to_date",
+            UpdateStatus::UpdateOptional => "optional",
+            UpdateStatus::UpdateRequired => "required",
+        }
+    }
+}
+
+/// 버전 판정 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionCheck {
+    pub status: UpdateStatus,
+    pub min_version: Option<ClientVersion>,
+    pub recommended_version: Option<ClientVersion>,
+    /// 플랫폼별 스토어 URL (업데이트가 필요 없거나 알 수 없는 플랫폼이면 None)
+    pub store_url: Option<String>,
+    pub message: String,
+}
+
+/// 클라이언트 버전 정책
+#[derive(Debug, Clone, Default)]
+pub struct VersionPolicy {
+    /// 최소 지원 버전 (`grpc_min_client_version`)
+    pub min_version: Option<ClientVersion>,
+    /// 권장 버전 (`grpc_recommended_client_version`)
+    pub recommended_version: Option<ClientVersion>,
+    /// 플랫폼별 스토어 URL (`grpc_store_url_{platform}`)
+    pub store_urls: HashMap<String, String>,
+    /// 버전 메타데이터가 없는 요청 거부 (`grpc_reject_unversioned_clients`)
+    pub reject_unversioned: bool,
+}
+
+impl VersionPolicy {
+    /// 버전 정책이 설정되어 있는지 여부
+    pub fn is_enabled(&self) -> bool {
+        self.min_version.is_some() || self.recommended_version.is_some()
+    }
+
+    /// 클라이언트 버전을 판정합니다.
+    ///
+    /// # Arguments
+    /// * `version` - 클라이언트 버전 (없으면 `reject_unversioned` 설정에 따름)
+    /// * `platform` - 클라이언트 플랫폼 (스토어 URL 선택용)
+    pub fn check(&self, version: Option<ClientVersion>, platform: &str) -> VersionCheck {
+        let status = match version {
+            None if self.reject_unversioned && self.min_version.is_some() => UpdateStatus::UpdateRequired,
+            None => UpdateStatus::UpToDate,
+            Some(version) if self.min_version.is_some_and(|min| version < min) => UpdateStatus::UpdateRequired,
+            Some(version) if self.recommended_version.is_some_and(|rec| version < rec) => UpdateStatus::UpdateOptional,
+            Some(_) => UpdateStatus::UpToDate,
+        };
+
+        let message = match status {
+            UpdateStatus::UpToDate => "최신 버전입니다.".to_string(),
+            UpdateStatus::UpdateOptional => format!(
+                "새 버전({})이 있습니다. 업데이트를 권장합니다.",
+                self.recommended_version.map(|v| v.to_string()).unwrap_or_default()
+            ),
+            UpdateStatus::UpdateRequired => format!(
+                "이 버전은 더 이상 지원되지 않습니다. {} 이상으로 업데이트해 주세요.",
+                self.min_version.map(|v| v.to_string()).unwrap_or_default()
+            ),
+        };
+
+        VersionCheck {
+            status,
+            min_version: self.min_version,
+            recommended_version: self.recommended_version,
+            store_url: match status {
+                UpdateStatus::UpToDate => None,
+                _ => self.store_urls.get(&platform.trim().to_ascii_lowercase()).cloned(),
+            },
+            message,
+        }
+    }
+}
+
+/// 클라이언트 버전 게이트 인터셉터
+///
+/// 최소 버전보다 낮은 클라이언트의 요청을 `FAILED_PRECONDITION`으로 거부하고,
+/// `x-update-status`, `x-min-version`, `x-store-url` 메타데이터로 업데이트 정보를 전달합니다.
+/// 형식이 잘못된 버전은 `INVALID_ARGUMENT`로 거부합니다.
+/// 서비스 토큰으로 확인된 내부 서비스 요청은 검사하지 않습니다.
+pub fn version_interceptor(policy: Arc<VersionPolicy>) -> impl Interceptor + Clone {
+    move |req: Request<()>| {
+        if !policy.is_enabled() || ServiceIdentity::of(&req).is_some() {
+            return Ok(req);
+        }
+
+        let metadata = req.metadata();
+        let version = match metadata.get(CLIENT_VERSION_HEADER).map(|value| value.to_str()) {
+            None => None,
+            Some(Ok(raw)) => Some(raw.parse::<ClientVersion>().map_err(Status::invalid_argument)?),
+            Some(Err(_)) => return Err(Status::invalid_argument("Invalid x-client-version header")),
+        };
+        let platform = metadata.get(CLIENT_PLATFORM_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+
+        let check = policy.check(version, platform);
+        match check.status {
+            UpdateStatus::UpdateRequired => {
+                warn!("클라이언트 업데이트 필요: version={:?}, platform={}", version, platform);
+                Err(update_required_status(&check))
+            }
+            UpdateStatus::UpdateOptional => {
+                debug!("클라이언트 업데이트 권장: version={:?}, platform={}", version, platform);
+                Ok(req)
+            }
+            UpdateStatus::UpToDate => Ok(req),
+        }
+    }
+}
+
+/// 업데이트 필요 응답 생성
+fn update_required_status(check: &VersionCheck) -> Status {
+    let mut status = Status::failed_precondition(check.message.clone());
+    let metadata = status.metadata_mut();
+    metadata.insert("x-update-status", MetadataValue::from_static("required"));
+    if let Some(Ok(min)) = check.min_version.map(|v| v.to_string().parse()) {
+        metadata.insert("x-min-version", min);
+    }
+    if let Some(Ok(url)) = check.store_url.as_ref().map(|url| url.parse()) {
+        metadata.insert("x-store-url", url);
+    }
+    status
+}
@@ -0,0 +1,605 @@
+//! 관리자 콘솔 (localhost 전용 TCP 제어 포트)
+//!
+//! 줄 단위 텍스트 프로토콜로 동작합니다. 접속 후 `AUTH <token>`으로 인증해야 하며,
+//! 모든 명령은 성공/실패와 관계없이 `admin_audit` 타깃으로 감사 로그에 기록됩니다.
+//!
+//! # 응답 형식
+//! - 성공: `OK` 또는 `OK <요약>` 뒤에 본문 줄들
+//! - 실패: `ERR <사유>`
+//!
+//! # 보안
+//! - 항상 `127.0.0.1`에 바인딩하고, loopback이 아닌 피어는 즉시 끊습니다.
+//! - 토큰 비교는 상수 시간으로 수행하며 감사 로그에는 토큰을 남기지 않습니다.
+//! - 인증 실패가 `max_auth_failures`에 도달하면 연결을 종료합니다.
+
+use anyhow::{anyhow, Result};
+use std::fmt;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{error, info, warn};
+
+use crate::config::AdminConsoleConfig;
+use crate::game::messages::{PlayerId, Position};
+use crate::game::state_manager::GameStateManager;
+use crate::network::session::{SessionManager, SessionTerminationReason};
+use shared::monitoring::{SampleTarget, SamplingRules};
+use shared::security::{constant_time_eq, AuditEvent, AuditEventKind, AuditSink};
+
+/// 감사 로그 타깃
+const AUDIT_TARGET: &str = "admin_audit";
+
+/// 샘플링 규칙 기본 TTL
+const DEFAULT_TRACE_TTL: Duration = Duration::from_secs(600);
+
+/// 도움말
+const HELP_TEXT: &str = "\
+AUTH <token>                 인증
+PLAYERS                      활성 플레이어 목록
+STATS                        게임 통계
+TELEPORT <id> <x> <y> <z>    플레이어 순간이동
+KICK <id> [reason]           플레이어 강제 퇴장
+TICKRATE [tps]               틱 레이트 조회/변경
+HITREG [<id> ON|OFF]         히트 판정 디버그 대상 조회/지정
+TRACE [<target> [ttl_secs]]  상세 로그 샘플링 규칙 조회/추가 (player:<id>, ip:<addr>[/prefix])
+TRACE OFF <rule_id>|ALL      샘플링 규칙 제거
+HELP                         도움말
+QUIT                         연결 종료";
+
+/// 관리자 명령
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdminCommand {
+    Auth(String),
+    Help,
+    Players,
+    Stats,
+    Teleport {
+        player_id: PlayerId,
+        position: Position,
+    },
+    Kick {
+        player_id: PlayerId,
+        reason: String,
+    },
+    TickRate(Option<u32>),
+    HitRegList,
+    HitReg {
+        player_id: PlayerId,
+        enabled: bool,
+    },
+    TraceList,
+    Trace {
+        target: SampleTarget,
+        ttl: Duration,
+    },
+    /// 규칙 제거 (None이면 전체)
+    TraceOff(Option<u64>),
+    Quit,
+}
+
+impl AdminCommand {
+    /// 명령 한 줄 파싱 (명령어는 대소문자 구분 없음)
+    pub fn parse(line: &str) -> Result<Self> {
+        let mut parts = line.split_whitespace();
+        let keyword = parts
+            .next()
+            .ok_or_else(|| anyhow!("empty command"))?
+            .to_ascii_uppercase();
+        let args: Vec<&str> = parts.collect();
+
+        let command = match (keyword.as_str(), args.as_slice()) {
+            ("AUTH", [token]) => Self::Auth((*token).to_string()),
+            ("HELP", []) => Self::Help,
+            ("PLAYERS", []) => Self::Players,
+            ("STATS", []) => Self::Stats,
+            ("TELEPORT", [id, x, y, z]) => Self::Teleport {
+                player_id: parse_arg(id, "player id")?,
+                position: Position::new(parse_arg(x, "x")?, parse_arg(y, "y")?, parse_arg(z, "z")?),
+            },
+            ("KICK", [id, reason @ ..]) => Self::Kick {
+                player_id: parse_arg(id, "player id")?,
+                reason: if reason.is_empty() {
+                    "kicked by admin".to_string()
+                } else {
+                    reason.join(" ")
+                },
+            },
+            ("TICKRATE", []) => Self::TickRate(None),
+            ("TICKRATE", [tps]) => Self::TickRate(Some(parse_arg(tps, "tps")?)),
+            ("HITREG", []) => Self::HitRegList,
+            ("HITREG", [id, toggle]) => Self::HitReg {
+                player_id: parse_arg(id, "player id")?,
+                enabled: match toggle.to_ascii_uppercase().as_str() {
+                    "ON" => true,
+                    "OFF" => false,
+                    _ => return Err(anyhow!("expected ON or OFF: {}", toggle)),
+                },
+            },
+            ("TRACE", []) => Self::TraceList,
+            ("TRACE", [off, rule]) if off.eq_ignore_ascii_case("OFF") => {
+                Self::TraceOff(if rule.eq_ignore_ascii_case("ALL") {
+                    None
+                } else {
+                    Some(parse_arg(rule, "rule id")?)
+                })
+            }
+            ("TRACE", [target, ttl @ ..]) if ttl.len() <= 1 => Self::Trace {
+                target: target.parse().map_err(|e: String| anyhow!(e))?,
+                ttl: match ttl {
+                    [secs] => Duration::from_secs(parse_arg(secs, "ttl")?),
+                    _ => DEFAULT_TRACE_TTL,
+                },
+            },
+            ("QUIT", []) | ("EXIT", []) => Self::Quit,
+            (
+                "AUTH" | "HELP" | "PLAYERS" | "STATS" | "TELEPORT" | "KICK" | "TICKRATE" | "HITREG"
+                | "TRACE" | "QUIT" | "EXIT",
+                _,
+            ) => return Err(anyhow!("wrong number of arguments for {}", keyword)),
+            _ => return Err(anyhow!("unknown command: {}", keyword)),
+        };
+
+        Ok(command)
+    }
+}
+
+/// 감사 로그용 표현 (토큰은 가림)
+impl fmt::Display for AdminCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auth(_) => write!(f, "AUTH ***"),
+            Self::Help => write!(f, "HELP"),
+            Self::Players => write!(f, "PLAYERS"),
+            Self::Stats => write!(f, "STATS"),
+            Self::Teleport {
+                player_id,
+                position,
+            } => write!(
+                f,
+                "TELEPORT {} {} {} {}",
+                player_id, position.x, position.y, position.z
+            ),
+            Self::Kick { player_id, reason } => write!(f, "KICK {} {}", player_id, reason),
+            Self::TickRate(Some(tps)) => write!(f, "TICKRATE {}", tps),
+            Self::TickRate(None) => write!(f, "TICKRATE"),
+            Self::HitRegList => write!(f, "HITREG"),
+            Self::HitReg { player_id, enabled } => {
+                write!(
+                    f,
+                    "HITREG {} {}",
+                    player_id,
+                    if *enabled { "ON" } else { "OFF" }
+                )
+            }
+            Self::TraceList => write!(f, "TRACE"),
+            Self::Trace { target, ttl } => write!(f, "TRACE {} {}", target, ttl.as_secs()),
+            Self::TraceOff(Some(rule_id)) => write!(f, "TRACE OFF {}", rule_id),
+            Self::TraceOff(None) => write!(f, "TRACE OFF ALL"),
+            Self::Quit => write!(f, "QUIT"),
+        }
+    }
+}
+
+fn parse_arg<T: std::str::FromStr>(value: &str, name: &str) -> Result<T> {
+    value
+        .parse()
+        .map_err(|_| anyhow!("invalid {}: {}", name, value))
+}
+
+/// 상수 시간 토큰 비교
+fn token_matches(expected: &str, provided: &str) -> bool {
+    constant_time_eq(expected.as_bytes(), provided.as_bytes())
+}
+
+/// 관리자 콘솔 서버
+pub struct AdminConsole {
+    config: AdminConsoleConfig,
+    game_state: Arc<GameStateManager>,
+    session_manager: Arc<SessionManager>,
+    /// 해시 체인 감사 로그 (없으면 tracing 로그만 남김)
+    audit: Option<Arc<AuditSink>>,
+}
+
+impl AdminConsole {
+    /// 새로운 관리자 콘솔 생성
+    pub fn new(
+        config: AdminConsoleConfig,
+        game_state: Arc<GameStateManager>,
+        session_manager: Arc<SessionManager>,
+    ) -> Self {
+        Self {
+            config,
+            game_state,
+            session_manager,
+            audit: None,
+        }
+    }
+
+    /// 감사 로그 설정
+    pub fn with_audit(mut self, audit: Arc<AuditSink>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// 인증 시도와 인증된 명령을 감사 로그에 기록
+    async fn record_audit(
+        &self,
+        peer: SocketAddr,
+        command: &AdminCommand,
+        response: &Result<String>,
+    ) {
+        let Some(audit) = &self.audit else {
+            return;
+        };
+        let event = match (command, response) {
+            (AdminCommand::Help | AdminCommand::Quit, _) => return,
+            (AdminCommand::Auth(_), Ok(_)) => AuditEvent::new(AuditEventKind::Login, "admin"),
+            (AdminCommand::Auth(_), Err(_)) => {
+                AuditEvent::new(AuditEventKind::LoginFailed, "admin")
+            }
+            (command, Ok(_)) => AuditEvent::new(AuditEventKind::AdminCommand, "admin")
+                .with_detail(command.to_string()),
+            (command, Err(e)) => AuditEvent::new(AuditEventKind::AdminCommand, "admin")
+                .with_detail(format!("{} (failed: {})", command, e)),
+        };
+        if let Err(e) = audit.record(event.with_source(peer.to_string())).await {
+            error!(target: AUDIT_TARGET, peer = %peer, error = %e, "Audit record failed");
+        }
+    }
+
+    /// 콘솔 리스너 실행 (localhost 전용)
+    pub async fn run(self: Arc<Self>) -> Result<()> {
+        let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, self.config.port));
+        let listener = TcpListener::bind(addr).await?;
+        info!("🛠️ 관리자 콘솔 대기 중: {}", addr);
+
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    error!(error = %e, "Admin console accept failed");
+                    continue;
+                }
+            };
+
+            if !peer.ip().is_loopback() {
+                warn!(target: AUDIT_TARGET, peer = %peer, "Rejected non-local admin connection");
+                continue;
+            }
+
+            let console = self.clone();
+            tokio::spawn(async move {
+                if let Err(e) = console.handle_connection(stream, peer).await {
+                    warn!(peer = %peer, error = %e, "Admin console connection error");
+                }
+                info!(target: AUDIT_TARGET, peer = %peer, "Admin connection closed");
+            });
+        }
+    }
+
+    /// 연결 하나 처리
+    async fn handle_connection(&self, stream: TcpStream, peer: SocketAddr) -> Result<()> {
+        info!(target: AUDIT_TARGET, peer = %peer, "Admin connection opened");
+
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let idle_timeout = Duration::from_secs(self.config.idle_timeout_secs);
+        let mut authenticated = false;
+        let mut auth_failures = 0u32;
+
+        writer
+            .write_all(b"RUDP admin console. AUTH <token> to continue.\n")
+            .await?;
+
+        loop {
+            let line = match tokio::time::timeout(idle_timeout, lines.next_line()).await {
+                Ok(Ok(Some(line))) => line,
+                Ok(Ok(None)) => return Ok(()),
+                Ok(Err(e)) => return Err(e.into()),
+                Err(_) => {
+                    writer.write_all(b"ERR idle timeout\n").await?;
+                    return Ok(());
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let command = match AdminCommand::parse(&line) {
+                Ok(command) => command,
+                Err(e) => {
+                    warn!(target: AUDIT_TARGET, peer = %peer, error = %e, "Admin command rejected");
+                    writer.write_all(format!("ERR {}\n", e).as_bytes()).await?;
+                    continue;
+                }
+            };
+
+            let response = match &command {
+                AdminCommand::Quit => {
+                    info!(target: AUDIT_TARGET, peer = %peer, command = %command, "Admin command");
+                    writer.write_all(b"OK bye\n").await?;
+                    return Ok(());
+                }
+                AdminCommand::Auth(token) => {
+                    let expected = self.config.auth_token.as_deref().unwrap_or_default();
+                    authenticated = !expected.is_empty() && token_matches(expected, token);
+                    if authenticated {
+                        Ok("authenticated".to_string())
+                    } else {
+                        auth_failures += 1;
+                        Err(anyhow!("authentication failed"))
+                    }
+                }
+                AdminCommand::Help => Ok(format!("\n{}", HELP_TEXT)),
+                _ if !authenticated => Err(anyhow!("not authenticated")),
+                command => self.execute(command).await,
+            };
+
+            match &response {
+                Ok(_) => {
+                    info!(target: AUDIT_TARGET, peer = %peer, command = %command, "Admin command")
+                }
+                Err(e) => warn!(
+                    target: AUDIT_TARGET,
+                    peer = %peer,
+                    command = %command,
+                    error = %e,
+                    "Admin command failed"
+                ),
+            }
+            self.record_audit(peer, &command, &response).await;
+
+            let output = match response {
+                Ok(body) if body.is_empty() => "OK\n".to_string(),
+                Ok(body) => format!("OK {}\n", body),
+                Err(e) => format!("ERR {}\n", e),
+            };
+            writer.write_all(output.as_bytes()).await?;
+
+            if auth_failures >= self.config.max_auth_failures {
+                warn!(target: AUDIT_TARGET, peer = %peer, "Too many admin auth failures");
+                return Ok(());
+            }
+        }
+    }
+
+    /// 인증된 명령 실행
+    async fn execute(&self, command: &AdminCommand) -> Result<String> {
+        match command {
+            AdminCommand::Players => {
+                let players = self.game_state.player_summaries().await;
+                let mut body = format!("{} players", players.len());
+                for player in players {
+                    body.push_str(&format!(
+                        "\n{} {} pos=({:.1},{:.1},{:.1}) hp={:.0}% state={:?}",
+                        player.id,
+                        player.name,
+                        player.position.x,
+                        player.position.y,
+                        player.position.z,
+                        player.health_percentage * 100.0,
+                        player.state
+                    ));
+                }
+                Ok(body)
+            }
+            AdminCommand::Stats => {
+                let stats = self.game_state.get_game_statistics().await;
+                let timestep = self.game_state.timestep_metrics();
+                let inputs = self.game_state.input_buffer_stats();
+                Ok(format!(
+                    "active_players={} connections={} moves={} attacks={} deaths={} respawns={} tick_rate={} \
+                     tick_drift_ms={:.1} tick_dropped_ms={} catch_up_frames={} \
+                     input_buffered={} input_late={} input_dropped={} input_delay_ticks={:.1} input_jitter_ticks={:.2}",
+                    stats.active_players,
+                    stats.total_connections,
+                    stats.total_moves_processed,
+                    stats.total_attacks,
+                    stats.total_deaths,
+                    stats.total_respawns,
+                    self.game_state.tick_rate(),
+                    timestep.drift_ms(),
+                    timestep.dropped.as_millis(),
+                    timestep.catch_up_frames,
+                    inputs.buffered,
+                    inputs.late,
+                    inputs.dropped,
+                    inputs.avg_delay_ticks,
+                    inputs.avg_jitter_ticks
+                ))
+            }
+            AdminCommand::Teleport {
+                player_id,
+                position,
+            } => {
+                self.game_state
+                    .teleport_player(*player_id, *position)
+                    .await?;
+                Ok(String::new())
+            }
+            AdminCommand::Kick { player_id, reason } => {
+                let session_id = self
+                    .game_state
+                    .session_of_player(*player_id)
+                    .await
+                    .ok_or_else(|| anyhow!("player not found: {}", player_id))?;
+
+                // 세션 종료 이벤트가 게임 상태 정리(DisconnectReason::Kicked)까지 이어짐
+                self.session_manager
+                    .terminate_session(
+                        session_id,
+                        SessionTerminationReason::AdminKick(reason.clone()),
+                    )
+                    .await?;
+                Ok(format!("session {} terminated", session_id))
+            }
+            AdminCommand::TickRate(None) => Ok(self.game_state.tick_rate().to_string()),
+            AdminCommand::TickRate(Some(tps)) => {
+                self.game_state.set_tick_rate(*tps)?;
+                Ok(tps.to_string())
+            }
+            AdminCommand::HitRegList => {
+                let debugger = self.game_state.hitreg_debugger();
+                if !debugger.is_enabled() {
+                    return Ok("disabled".to_string());
+                }
+                let mut players = debugger.flagged_players();
+                players.sort_unstable();
+                Ok(players
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" "))
+            }
+            AdminCommand::HitReg {
+                player_id,
+                enabled: true,
+            } => {
+                self.game_state.hitreg_debugger().flag(*player_id)?;
+                Ok(format!("hitreg debug on for {}", player_id))
+            }
+            AdminCommand::HitReg {
+                player_id,
+                enabled: false,
+            } => {
+                if !self.game_state.hitreg_debugger().unflag(*player_id) {
+                    return Err(anyhow!("player not flagged: {}", player_id));
+                }
+                Ok(format!("hitreg debug off for {}", player_id))
+            }
+            AdminCommand::TraceList => {
+                let rules = SamplingRules::global().list();
+                let mut body = format!("{} rules", rules.len());
+                for rule in rules {
+                    body.push_str(&format!(
+                        "\n{} {} expires_in={}s",
+                        rule.id,
+                        rule.target,
+                        rule.remaining().as_secs()
+                    ));
+                }
+                Ok(body)
+            }
+            AdminCommand::Trace { target, ttl } => {
+                let rule_id = SamplingRules::global().add(*target, *ttl);
+                Ok(format!("trace rule {} for {}", rule_id, target))
+            }
+            AdminCommand::TraceOff(Some(rule_id)) => {
+                if !SamplingRules::global().remove(*rule_id) {
+                    return Err(anyhow!("no such rule: {}", rule_id));
+                }
+                Ok(format!("trace rule {} removed", rule_id))
+            }
+            AdminCommand::TraceOff(None) => {
+                SamplingRules::global().clear();
+                Ok("all trace rules removed".to_string())
+            }
+            AdminCommand::Auth(_) | AdminCommand::Help | AdminCommand::Quit => {
+                Err(anyhow!("{} is handled by the connection loop", command))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            AdminCommand::parse("teleport 7 1 2.5 -3").unwrap(),
+            AdminCommand::Teleport {
+                player_id: 7,
+                position: Position::new(1.0, 2.5, -3.0),
+            }
+        );
+        assert_eq!(
+            AdminCommand::parse("KICK 3 rude  chat").unwrap(),
+            AdminCommand::Kick {
+                player_id: 3,
+                reason: "rude chat".to_string(),
+            }
+        );
+        assert_eq!(
+            AdminCommand::parse("tickrate").unwrap(),
+            AdminCommand::TickRate(None)
+        );
+        assert_eq!(
+            AdminCommand::parse("hitreg 5 on").unwrap(),
+            AdminCommand::HitReg {
+                player_id: 5,
+                enabled: true,
+            }
+        );
+        assert!(AdminCommand::parse("HITREG 5 maybe").is_err());
+        assert_eq!(
+            AdminCommand::parse("trace player:42 60").unwrap(),
+            AdminCommand::Trace {
+                target: SampleTarget::Player(42),
+                ttl: Duration::from_secs(60),
+            }
+        );
+        assert_eq!(
+            AdminCommand::parse("TRACE off all").unwrap(),
+            AdminCommand::TraceOff(None)
+        );
+        assert_eq!(
+            AdminCommand::parse("TRACE OFF 3").unwrap().to_string(),
+            "TRACE OFF 3"
+        );
+        assert!(AdminCommand::parse("TRACE ip:10.0.0.0/40").is_err());
+        assert!(AdminCommand::parse("TRACE player:1 60 extra").is_err());
+        assert!(AdminCommand::parse("TELEPORT 7 1 2").is_err());
+        assert!(AdminCommand::parse("KICK abc").is_err());
+        assert!(AdminCommand::parse("SHUTDOWN").is_err());
+    }
+
+    #[test]
+    fn test_auth_is_redacted_in_audit_output() {
+        let command = AdminCommand::parse("AUTH secret-token").unwrap();
+        assert_eq!(command.to_string(), "AUTH ***");
+    }
+
+    #[test]
+    fn test_token_matches() {
+        assert!(token_matches("secret", "secret"));
+        assert!(!token_matches("secret", "secreT"));
+        assert!(!token_matches("secret", "secret2"));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_kick_terminates_connected_session() {
+        use crate::network::dispatch::tests::{client, connect, dispatcher, recv, send};
+
+        let dispatcher = dispatcher().await;
+        let client = client().await;
+        send(&dispatcher, &client, connect(7)).await;
+        recv(&client).await;
+
+        let console = AdminConsole::new(
+            AdminConsoleConfig::development(),
+            dispatcher.game_state(),
+            dispatcher.session_manager(),
+        );
+        let kick = AdminCommand::parse("KICK 7 spamming").unwrap();
+        let session_id = crate::utils::socket_addr_to_u64(client.local_addr().unwrap());
+        assert_eq!(
+            console.execute(&kick).await.unwrap(),
+            format!("session {} terminated", session_id)
+        );
+        assert!(dispatcher
+            .session_manager()
+            .get_session(session_id)
+            .await
+            .is_none());
+        assert_eq!(dispatcher.game_state().session_of_player(7).await, None);
+
+        // 연결 루프가 처리하는 명령은 패닉 대신 에러 응답
+        assert!(console.execute(&AdminCommand::Quit).await.is_err());
+        assert!(console.execute(&kick).await.is_err());
+    }
+}
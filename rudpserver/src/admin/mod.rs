@@ -0,0 +1,22 @@
+//! 관리자 모듈
+//!
+//! 운영자가 재배포 없이 라이브 서버 상태를 조회/조작할 수 있는 제어 기능을 제공합니다.
+//!
+//! # 주요 구성요소
+//! - `console`: localhost 전용 TCP 관리자 콘솔 (토큰 인증, 명령 감사 로그)
+//!
+//! # 사용 예제
+//! ```bash
+//! $ nc 127.0.0.1 4901
+//! AUTH <ADMIN_CONSOLE_TOKEN>
+//! PLAYERS
+//! TELEPORT 42 100 0 250
+//! KICK 42 spamming
+//! TICKRATE 30
+//! HITREG 42 ON
+//! ```
+
+pub mod console;
+
+// 주요 타입들을 re-export
+pub use console::{AdminCommand, AdminConsole};
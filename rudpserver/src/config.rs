@@ -11,6 +11,12 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::env;
 
+use crate::game::replication::ReplicationRole;
+use crate::game::respawn::RespawnStrategy;
+use crate::game::spatial::SpatialStrategy;
+use crate::network::bandwidth::SendBudgetConfig;
+use crate::protocol::rudp::NetworkConditions;
+
 /// RUDP 서버 메인 설정
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RudpServerConfig {
@@ -24,6 +30,8 @@ pub struct RudpServerConfig {
     pub monitoring: MonitoringConfig,
     /// 보안 설정
     pub security: SecurityConfig,
+    /// 관리자 콘솔 설정
+    pub admin: AdminConsoleConfig,
 }
 
 /// 네트워크 설정 (RUDP 프로토콜)
@@ -51,6 +59,10 @@ pub struct NetworkConfig {
     pub enable_congestion_control: bool,
     /// 순서 보장 윈도우 크기
     pub sequence_window_size: u32,
+    /// 클라이언트별 송신 대역폭 예산
+    pub client_send_budget: SendBudgetConfig,
+    /// 네트워크 상태 시뮬레이션 (로컬/부하 테스트 전용, 기본 비활성화)
+    pub simulated_conditions: NetworkConditions,
 }
 
 /// 게임 설정 (2000명 동시접속 기준)
@@ -62,6 +74,8 @@ pub struct GameConfig {
     pub max_concurrent_players: u32,
     /// 게임 틱 레이트 (TPS - Ticks Per Second)
     pub tick_rate: u32,
+    /// 지연 후 한 프레임에 따라잡을 최대 틱 수
+    pub max_catch_up_ticks: u32,
     /// 플레이어 업데이트 간격 (틱)
     pub player_update_interval: u32,
     /// 월드 업데이트 간격 (틱)
@@ -76,6 +90,66 @@ pub struct GameConfig {
     pub max_combat_range: f32,
     /// 이동 속도 제한 (초당 게임 단위)
     pub max_movement_speed: f32,
+    /// 방별 이벤트 채널 버퍼 크기
+    pub event_channel_capacity: usize,
+    /// 방별 이벤트 유실 경고 임계값 (점검 주기당)
+    pub event_lag_alert_threshold: u64,
+    /// 방별 이벤트 버퍼 점유율 경고 임계값 (0.0 ~ 1.0)
+    pub event_backlog_alert_ratio: f32,
+    /// 리스폰 위치 선택 전략
+    pub respawn_strategy: RespawnStrategy,
+    /// 리스폰 시 적 회피 반경 (게임 단위)
+    pub respawn_enemy_avoid_radius: f32,
+    /// 맵 스폰 포인트 파일 경로 (TOML)
+    pub spawn_points_file: String,
+    /// AFK 경고까지의 유휴 시간 (초)
+    pub afk_warning_secs: u64,
+    /// AFK 관전자 전환까지의 유휴 시간 (초)
+    pub afk_spectator_secs: u64,
+    /// AFK 연결 해제까지의 유휴 시간 (초, 0이면 AFK 감지 비활성화)
+    pub afk_disconnect_secs: u64,
+    /// 무기/공격 데이터 파일 경로 (TOML)
+    pub weapons_file: String,
+    /// 빠른 소통 핑 카탈로그 파일 경로 (TOML)
+    pub pings_file: String,
+    /// 히트 판정 디버그 스트림 허용 여부 (운영 환경 기본 비활성화)
+    pub hitreg_debug_enabled: bool,
+    /// 플래그된 플레이어별 보관할 최근 공격 판정 수
+    pub hitreg_debug_history: usize,
+    /// 플레이어별 초당 최대 디버그 메시지 수
+    pub hitreg_debug_max_per_sec: u32,
+    /// 게임 기본값 파일 경로 (TOML, 방 인원 규칙은 `[room]` 섹션)
+    pub game_defaults_file: String,
+    /// 전원 준비 후 매치 시작까지의 카운트다운 (초)
+    pub match_countdown_secs: u64,
+    /// 매치 제한 시간 (초)
+    pub match_duration_secs: u64,
+    /// 저장하지 못한 매치 결과를 보관할 아웃박스 디렉토리
+    pub match_outbox_dir: String,
+    /// 매치 결과 아웃박스 재시도 주기 (초)
+    pub match_outbox_retry_secs: u64,
+    /// 관심 영역 조회용 공간 인덱스 전략
+    pub spatial_index: SpatialStrategy,
+    /// 격자 인덱스 셀 크기 (게임 단위)
+    pub spatial_cell_size: f32,
+    /// 이동 입력을 클라이언트 틱에 맞춰 버퍼링 (끄면 도착 즉시 적용)
+    pub input_buffer_enabled: bool,
+    /// 입력 적용 최소 지연 (틱)
+    pub input_min_delay_ticks: u64,
+    /// 입력 적용 최대 지연 (틱, 지터가 커도 이 이상 늦추지 않음)
+    pub input_max_delay_ticks: u64,
+    /// 플레이어별 최대 버퍼 입력 수
+    pub input_buffer_capacity: usize,
+    /// 장애 조치 복제 역할 (off / primary / standby)
+    pub replication_role: ReplicationRole,
+    /// 복제 스트림 이름 (주 서버와 대기 서버가 같은 값을 사용)
+    pub replication_stream: String,
+    /// 복제 주기 (틱)
+    pub replication_interval_ticks: u64,
+    /// 키프레임(전체 상태) 간격 (복제 주기 수)
+    pub replication_keyframe_every: u32,
+    /// 주 서버 임대 TTL (초, 이 시간 동안 복제가 없으면 대기 서버가 넘겨받음)
+    pub replication_lease_secs: u64,
 }
 
 /// Redis 설정 (캐싱 및 세션 관리)
@@ -122,6 +196,10 @@ pub struct MonitoringConfig {
     pub enable_prometheus_export: bool,
     /// Prometheus 서버 포트
     pub prometheus_port: u16,
+    /// 통계 집계용 배포 지역
+    pub server_region: String,
+    /// `/healthz`, `/readyz` 포트 (없으면 비활성화)
+    pub health_port: Option<u16>,
 }
 
 /// 보안 설정 (패킷 검증, DDoS 방어)
@@ -147,6 +225,22 @@ pub struct SecurityConfig {
     pub jwt_expiration_secs: u64,
 }
 
+/// 관리자 콘솔 설정 (localhost 전용 제어 포트)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminConsoleConfig {
+    /// 관리자 콘솔 활성화
+    pub enabled: bool,
+    /// 콘솔 포트 (항상 127.0.0.1에 바인딩)
+    pub port: u16,
+    /// 인증 토큰 (활성화 시 필수)
+    #[serde(skip_serializing)]
+    pub auth_token: Option<String>,
+    /// 유휴 연결 종료 시간 (초)
+    pub idle_timeout_secs: u64,
+    /// 연결당 허용되는 인증 실패 횟수
+    pub max_auth_failures: u32,
+}
+
 impl RudpServerConfig {
     /// 환경변수와 명령행 인자로부터 설정 로드
     pub async fn from_env_and_args() -> Result<Self> {
@@ -159,6 +253,7 @@ impl RudpServerConfig {
             redis: RedisConfig::from_env()?,
             monitoring: MonitoringConfig::from_env()?,
             security: SecurityConfig::from_env()?,
+            admin: AdminConsoleConfig::from_env()?,
         };
 
         // 설정 검증
@@ -182,11 +277,38 @@ impl RudpServerConfig {
             ));
         }
 
+        self.network.client_send_budget.validate()?;
+        self.network.simulated_conditions.validate()?;
+
         // 게임 설정 검증
         if self.game.max_concurrent_sessions == 0 {
             return Err(anyhow::anyhow!("Max concurrent sessions must be > 0"));
         }
 
+        if self.game.event_channel_capacity == 0 {
+            return Err(anyhow::anyhow!("Event channel capacity must be > 0"));
+        }
+
+        if self.game.afk_disconnect_secs > 0
+            && !(self.game.afk_warning_secs < self.game.afk_spectator_secs
+                && self.game.afk_spectator_secs < self.game.afk_disconnect_secs)
+        {
+            return Err(anyhow::anyhow!(
+                "AFK thresholds must increase: warning {}s < spectator {}s < disconnect {}s",
+                self.game.afk_warning_secs,
+                self.game.afk_spectator_secs,
+                self.game.afk_disconnect_secs
+            ));
+        }
+
+        if self.game.hitreg_debug_enabled
+            && (self.game.hitreg_debug_history == 0 || self.game.hitreg_debug_max_per_sec == 0)
+        {
+            return Err(anyhow::anyhow!(
+                "Hit registration debug history and rate limit must be > 0"
+            ));
+        }
+
         if self.game.tick_rate == 0 || self.game.tick_rate > 120 {
             return Err(anyhow::anyhow!(
                 "Invalid tick rate: {} (must be 1-120)",
@@ -194,6 +316,28 @@ impl RudpServerConfig {
             ));
         }
 
+        if self.game.max_catch_up_ticks == 0 {
+            return Err(anyhow::anyhow!("Max catch-up ticks must be > 0"));
+        }
+
+        if self.game.input_min_delay_ticks > self.game.input_max_delay_ticks {
+            return Err(anyhow::anyhow!(
+                "Input min delay ({}) must not exceed max delay ({})",
+                self.game.input_min_delay_ticks,
+                self.game.input_max_delay_ticks
+            ));
+        }
+
+        if self.game.replication_role != ReplicationRole::Off
+            && (self.game.replication_interval_ticks == 0
+                || self.game.replication_keyframe_every == 0
+                || self.game.replication_lease_secs == 0)
+        {
+            return Err(anyhow::anyhow!(
+                "Replication interval, keyframe interval and lease must be > 0"
+            ));
+        }
+
         // Redis 설정 검증
         if self.redis.pool_size == 0 {
             return Err(anyhow::anyhow!("Redis pool size must be > 0"));
@@ -204,6 +348,21 @@ impl RudpServerConfig {
             return Err(anyhow::anyhow!("Max packets per minute must be > 0"));
         }
 
+        // 관리자 콘솔 설정 검증
+        if self.admin.enabled {
+            if self.admin.auth_token.as_deref().is_none_or(str::is_empty) {
+                return Err(anyhow::anyhow!(
+                    "ADMIN_CONSOLE_TOKEN is required when the admin console is enabled"
+                ));
+            }
+            if self.admin.port == self.network.port {
+                return Err(anyhow::anyhow!(
+                    "Admin console port must differ from the game port: {}",
+                    self.admin.port
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -215,6 +374,7 @@ impl RudpServerConfig {
             redis: RedisConfig::development(),
             monitoring: MonitoringConfig::development(),
             security: SecurityConfig::development(),
+            admin: AdminConsoleConfig::development(),
         }
     }
 
@@ -226,6 +386,7 @@ impl RudpServerConfig {
             redis: RedisConfig::production(),
             monitoring: MonitoringConfig::production(),
             security: SecurityConfig::production(),
+            admin: AdminConsoleConfig::production(),
         }
     }
 }
@@ -273,6 +434,50 @@ impl NetworkConfig {
                 .unwrap_or_else(|_| "256".to_string())
                 .parse()
                 .map_err(|e| anyhow::anyhow!("Invalid SEQUENCE_WINDOW_SIZE: {}", e))?,
+            client_send_budget: SendBudgetConfig {
+                bytes_per_sec: env::var("CLIENT_SEND_BUDGET_BYTES_PER_SEC")
+                    .unwrap_or_else(|_| "20480".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid CLIENT_SEND_BUDGET_BYTES_PER_SEC: {}", e))?,
+                snapshot_percent: env::var("SEND_BUDGET_SNAPSHOT_PERCENT")
+                    .unwrap_or_else(|_| "60".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid SEND_BUDGET_SNAPSHOT_PERCENT: {}", e))?,
+                event_percent: env::var("SEND_BUDGET_EVENT_PERCENT")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid SEND_BUDGET_EVENT_PERCENT: {}", e))?,
+                chat_percent: env::var("SEND_BUDGET_CHAT_PERCENT")
+                    .unwrap_or_else(|_| "10".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid SEND_BUDGET_CHAT_PERCENT: {}", e))?,
+                debug_bytes_per_sec: env::var("SEND_BUDGET_DEBUG_BYTES_PER_SEC")
+                    .unwrap_or_else(|_| "4096".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid SEND_BUDGET_DEBUG_BYTES_PER_SEC: {}", e))?,
+            },
+            simulated_conditions: NetworkConditions {
+                loss_rate: env::var("RUDP_SIM_LOSS")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid RUDP_SIM_LOSS: {}", e))?,
+                duplicate_rate: env::var("RUDP_SIM_DUPLICATE")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid RUDP_SIM_DUPLICATE: {}", e))?,
+                reorder_rate: env::var("RUDP_SIM_REORDER")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid RUDP_SIM_REORDER: {}", e))?,
+                latency_ms: env::var("RUDP_SIM_LATENCY_MS")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid RUDP_SIM_LATENCY_MS: {}", e))?,
+                jitter_ms: env::var("RUDP_SIM_JITTER_MS")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("Invalid RUDP_SIM_JITTER_MS: {}", e))?,
+            },
         })
     }
 
@@ -289,6 +494,8 @@ impl NetworkConfig {
             receive_buffer_size: 1024 * 1024,
             enable_congestion_control: true,
             sequence_window_size: 256,
+            client_send_budget: SendBudgetConfig::default(),
+            simulated_conditions: NetworkConditions::default(),
         }
     }
 
@@ -305,6 +512,8 @@ impl NetworkConfig {
             receive_buffer_size: 4 * 1024 * 1024,
             enable_congestion_control: true,
             sequence_window_size: 512,
+            client_send_budget: SendBudgetConfig::default(),
+            simulated_conditions: NetworkConditions::default(),
         }
     }
 }
@@ -324,6 +533,10 @@ impl GameConfig {
                 .unwrap_or_else(|_| "60".to_string())
                 .parse()
                 .map_err(|e| anyhow::anyhow!("Invalid GAME_TICK_RATE: {}", e))?,
+            max_catch_up_ticks: env::var("MAX_CATCH_UP_TICKS")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid MAX_CATCH_UP_TICKS: {}", e))?,
             player_update_interval: env::var("PLAYER_UPDATE_INTERVAL")
                 .unwrap_or_else(|_| "3".to_string())
                 .parse()
@@ -352,6 +565,114 @@ impl GameConfig {
                 .unwrap_or_else(|_| "50.0".to_string())
                 .parse()
                 .map_err(|e| anyhow::anyhow!("Invalid MAX_MOVEMENT_SPEED: {}", e))?,
+            event_channel_capacity: env::var("EVENT_CHANNEL_CAPACITY")
+                .unwrap_or_else(|_| "256".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid EVENT_CHANNEL_CAPACITY: {}", e))?,
+            event_lag_alert_threshold: env::var("EVENT_LAG_ALERT_THRESHOLD")
+                .unwrap_or_else(|_| "32".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid EVENT_LAG_ALERT_THRESHOLD: {}", e))?,
+            event_backlog_alert_ratio: env::var("EVENT_BACKLOG_ALERT_RATIO")
+                .unwrap_or_else(|_| "0.8".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid EVENT_BACKLOG_ALERT_RATIO: {}", e))?,
+            respawn_strategy: env::var("RESPAWN_STRATEGY")
+                .unwrap_or_else(|_| "map_center".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid RESPAWN_STRATEGY: {}", e))?,
+            respawn_enemy_avoid_radius: env::var("RESPAWN_ENEMY_AVOID_RADIUS")
+                .unwrap_or_else(|_| "30.0".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid RESPAWN_ENEMY_AVOID_RADIUS: {}", e))?,
+            spawn_points_file: env::var("SPAWN_POINTS_FILE")
+                .unwrap_or_else(|_| "property/spawn_points.toml".to_string()),
+            afk_warning_secs: env::var("AFK_WARNING_SECS")
+                .unwrap_or_else(|_| "120".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid AFK_WARNING_SECS: {}", e))?,
+            afk_spectator_secs: env::var("AFK_SPECTATOR_SECS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid AFK_SPECTATOR_SECS: {}", e))?,
+            afk_disconnect_secs: env::var("AFK_DISCONNECT_SECS")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid AFK_DISCONNECT_SECS: {}", e))?,
+            weapons_file: env::var("WEAPONS_FILE")
+                .unwrap_or_else(|_| "property/weapons.toml".to_string()),
+            pings_file: env::var("PINGS_FILE")
+                .unwrap_or_else(|_| "property/pings.toml".to_string()),
+            hitreg_debug_enabled: env::var("HITREG_DEBUG_ENABLED")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid HITREG_DEBUG_ENABLED: {}", e))?,
+            hitreg_debug_history: env::var("HITREG_DEBUG_HISTORY")
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid HITREG_DEBUG_HISTORY: {}", e))?,
+            hitreg_debug_max_per_sec: env::var("HITREG_DEBUG_MAX_PER_SEC")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid HITREG_DEBUG_MAX_PER_SEC: {}", e))?,
+            game_defaults_file: env::var("GAME_DEFAULTS_FILE")
+                .unwrap_or_else(|_| "property/game_defaults.toml".to_string()),
+            match_countdown_secs: env::var("MATCH_COUNTDOWN_SECS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid MATCH_COUNTDOWN_SECS: {}", e))?,
+            match_duration_secs: env::var("MATCH_DURATION_SECS")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid MATCH_DURATION_SECS: {}", e))?,
+            match_outbox_dir: env::var("MATCH_OUTBOX_DIR")
+                .unwrap_or_else(|_| "./outbox/match_results".to_string()),
+            match_outbox_retry_secs: env::var("MATCH_OUTBOX_RETRY_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid MATCH_OUTBOX_RETRY_SECS: {}", e))?,
+            spatial_index: env::var("SPATIAL_INDEX")
+                .unwrap_or_else(|_| "grid".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid SPATIAL_INDEX: {}", e))?,
+            spatial_cell_size: env::var("SPATIAL_CELL_SIZE")
+                .unwrap_or_else(|_| "50.0".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid SPATIAL_CELL_SIZE: {}", e))?,
+            input_buffer_enabled: env::var("INPUT_BUFFER_ENABLED")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid INPUT_BUFFER_ENABLED: {}", e))?,
+            input_min_delay_ticks: env::var("INPUT_MIN_DELAY_TICKS")
+                .unwrap_or_else(|_| "1".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid INPUT_MIN_DELAY_TICKS: {}", e))?,
+            input_max_delay_ticks: env::var("INPUT_MAX_DELAY_TICKS")
+                .unwrap_or_else(|_| "6".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid INPUT_MAX_DELAY_TICKS: {}", e))?,
+            input_buffer_capacity: env::var("INPUT_BUFFER_CAPACITY")
+                .unwrap_or_else(|_| "32".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid INPUT_BUFFER_CAPACITY: {}", e))?,
+            replication_role: env::var("REPLICATION_ROLE")
+                .unwrap_or_else(|_| "off".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid REPLICATION_ROLE: {}", e))?,
+            replication_stream: env::var("REPLICATION_STREAM")
+                .unwrap_or_else(|_| "rudp".to_string()),
+            replication_interval_ticks: env::var("REPLICATION_INTERVAL_TICKS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid REPLICATION_INTERVAL_TICKS: {}", e))?,
+            replication_keyframe_every: env::var("REPLICATION_KEYFRAME_EVERY")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid REPLICATION_KEYFRAME_EVERY: {}", e))?,
+            replication_lease_secs: env::var("REPLICATION_LEASE_SECS")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid REPLICATION_LEASE_SECS: {}", e))?,
         })
     }
 
@@ -360,6 +681,7 @@ impl GameConfig {
             max_concurrent_sessions: 100,
             max_concurrent_players: 100,
             tick_rate: 60,
+            max_catch_up_ticks: 5,
             player_update_interval: 3,
             world_update_interval: 1,
             player_timeout_secs: 300,
@@ -367,6 +689,36 @@ impl GameConfig {
             max_status_effect_duration_secs: 300,
             max_combat_range: 10.0,
             max_movement_speed: 50.0,
+            event_channel_capacity: 256,
+            event_lag_alert_threshold: 32,
+            event_backlog_alert_ratio: 0.8,
+            respawn_strategy: RespawnStrategy::MapCenter,
+            respawn_enemy_avoid_radius: 30.0,
+            spawn_points_file: "property/spawn_points.toml".to_string(),
+            afk_warning_secs: 300,
+            afk_spectator_secs: 600,
+            afk_disconnect_secs: 1200,
+            weapons_file: "property/weapons.toml".to_string(),
+            pings_file: "property/pings.toml".to_string(),
+            hitreg_debug_enabled: true,
+            hitreg_debug_history: 20,
+            hitreg_debug_max_per_sec: 10,
+            game_defaults_file: "property/game_defaults.toml".to_string(),
+            match_countdown_secs: 5,
+            match_duration_secs: 180,
+            match_outbox_dir: "./outbox/match_results".to_string(),
+            match_outbox_retry_secs: 10,
+            spatial_index: SpatialStrategy::Grid,
+            spatial_cell_size: 50.0,
+            input_buffer_enabled: true,
+            input_min_delay_ticks: 1,
+            input_max_delay_ticks: 6,
+            input_buffer_capacity: 32,
+            replication_role: ReplicationRole::Off,
+            replication_stream: "rudp".to_string(),
+            replication_interval_ticks: 30,
+            replication_keyframe_every: 10,
+            replication_lease_secs: 3,
         }
     }
 
@@ -375,6 +727,7 @@ impl GameConfig {
             max_concurrent_sessions: 2000,
             max_concurrent_players: 2000,
             tick_rate: 60,
+            max_catch_up_ticks: 5,
             player_update_interval: 3,
             world_update_interval: 1,
             player_timeout_secs: 300,
@@ -382,6 +735,36 @@ impl GameConfig {
             max_status_effect_duration_secs: 300,
             max_combat_range: 10.0,
             max_movement_speed: 50.0,
+            event_channel_capacity: 256,
+            event_lag_alert_threshold: 32,
+            event_backlog_alert_ratio: 0.8,
+            respawn_strategy: RespawnStrategy::MapCenter,
+            respawn_enemy_avoid_radius: 30.0,
+            spawn_points_file: "property/spawn_points.toml".to_string(),
+            afk_warning_secs: 120,
+            afk_spectator_secs: 300,
+            afk_disconnect_secs: 600,
+            weapons_file: "property/weapons.toml".to_string(),
+            pings_file: "property/pings.toml".to_string(),
+            hitreg_debug_enabled: false,
+            hitreg_debug_history: 20,
+            hitreg_debug_max_per_sec: 10,
+            game_defaults_file: "property/game_defaults.toml".to_string(),
+            match_countdown_secs: 10,
+            match_duration_secs: 600,
+            match_outbox_dir: "./outbox/match_results".to_string(),
+            match_outbox_retry_secs: 30,
+            spatial_index: SpatialStrategy::Grid,
+            spatial_cell_size: 50.0,
+            input_buffer_enabled: true,
+            input_min_delay_ticks: 1,
+            input_max_delay_ticks: 6,
+            input_buffer_capacity: 32,
+            replication_role: ReplicationRole::Off,
+            replication_stream: "rudp".to_string(),
+            replication_interval_ticks: 30,
+            replication_keyframe_every: 10,
+            replication_lease_secs: 3,
         }
     }
 }
@@ -494,6 +877,12 @@ impl MonitoringConfig {
                 .unwrap_or_else(|_| "9090".to_string())
                 .parse()
                 .map_err(|e| anyhow::anyhow!("Invalid PROMETHEUS_PORT: {}", e))?,
+            server_region: env::var("SERVER_REGION").unwrap_or_else(|_| "local".to_string()),
+            health_port: env::var("HEALTH_PORT")
+                .ok()
+                .map(|port| port.parse())
+                .transpose()
+                .map_err(|e| anyhow::anyhow!("Invalid HEALTH_PORT: {}", e))?,
         })
     }
 
@@ -507,6 +896,8 @@ impl MonitoringConfig {
             network_latency_warning_threshold_ms: 200,
             enable_prometheus_export: false,
             prometheus_port: 9090,
+            server_region: "local".to_string(),
+            health_port: None,
         }
     }
 
@@ -520,6 +911,8 @@ impl MonitoringConfig {
             network_latency_warning_threshold_ms: 100,
             enable_prometheus_export: true,
             prometheus_port: 9090,
+            server_region: "local".to_string(),
+            health_port: None,
         }
     }
 }
@@ -595,6 +988,50 @@ impl SecurityConfig {
     }
 }
 
+impl AdminConsoleConfig {
+    pub fn from_env() -> Result<Self> {
+        Ok(Self {
+            enabled: env::var("ADMIN_CONSOLE_ENABLED")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid ADMIN_CONSOLE_ENABLED: {}", e))?,
+            port: env::var("ADMIN_CONSOLE_PORT")
+                .unwrap_or_else(|_| "4901".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid ADMIN_CONSOLE_PORT: {}", e))?,
+            auth_token: env::var("ADMIN_CONSOLE_TOKEN").ok(),
+            idle_timeout_secs: env::var("ADMIN_CONSOLE_IDLE_TIMEOUT_SECS")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid ADMIN_CONSOLE_IDLE_TIMEOUT_SECS: {}", e))?,
+            max_auth_failures: env::var("ADMIN_CONSOLE_MAX_AUTH_FAILURES")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .map_err(|e| anyhow::anyhow!("Invalid ADMIN_CONSOLE_MAX_AUTH_FAILURES: {}", e))?,
+        })
+    }
+
+    pub fn development() -> Self {
+        Self {
+            enabled: false,
+            port: 4901,
+            auth_token: None,
+            idle_timeout_secs: 1800,
+            max_auth_failures: 5,
+        }
+    }
+
+    pub fn production() -> Self {
+        Self {
+            enabled: false,
+            port: 4901,
+            auth_token: None,
+            idle_timeout_secs: 300,
+            max_auth_failures: 3,
+        }
+    }
+}
+
 // Redis 설정을 shared 라이브러리 형식으로 변환 - 비동기 변환 필요
 impl RedisConfig {
     pub async fn to_shared_config(
@@ -0,0 +1,147 @@
+//! AFK(자리 비움) 감지
+//!
+//! 플레이어의 마지막 의미 있는 입력(이동/공격/리스폰) 이후 경과 시간을 기준으로
+//! 경고 → 관전자 전환 → 연결 해제(`DisconnectReason::Afk`) 순서로 단계를 올립니다.
+//! 하트비트는 입력으로 보지 않습니다.
+
+use std::time::Duration;
+
+/// AFK 판정 설정
+///
+/// `disconnect_after`가 0이면 AFK 감지를 사용하지 않습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AfkConfig {
+    /// 경고까지의 유휴 시간
+    pub warn_after: Duration,
+    /// 관전자 전환까지의 유휴 시간
+    pub spectate_after: Duration,
+    /// 연결 해제까지의 유휴 시간
+    pub disconnect_after: Duration,
+}
+
+impl AfkConfig {
+    /// 초 단위 설정으로 생성
+    pub fn from_secs(warn_after: u64, spectate_after: u64, disconnect_after: u64) -> Self {
+        Self {
+            warn_after: Duration::from_secs(warn_after),
+            spectate_after: Duration::from_secs(spectate_after),
+            disconnect_after: Duration::from_secs(disconnect_after),
+        }
+    }
+
+    /// AFK 감지 사용 여부
+    pub fn is_enabled(&self) -> bool {
+        !self.disconnect_after.is_zero()
+    }
+
+    /// 현재 단계와 유휴 시간으로 다음 조치를 결정
+    ///
+    /// 점검 주기가 길어 여러 임계값을 한 번에 넘은 경우 가장 강한 조치를 반환합니다.
+    pub fn evaluate(&self, stage: AfkStage, idle: Duration) -> Option<AfkAction> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let idle_secs = idle.as_secs();
+        if idle >= self.disconnect_after {
+            Some(AfkAction::Disconnect { idle_secs })
+        } else if idle >= self.spectate_after && stage != AfkStage::Spectating {
+            Some(AfkAction::MoveToSpectator { idle_secs })
+        } else if idle >= self.warn_after && stage == AfkStage::Active {
+            Some(AfkAction::Warn {
+                idle_secs,
+                disconnect_in_secs: (self.disconnect_after - idle).as_secs(),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// 플레이어의 AFK 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AfkStage {
+    /// 정상 활동 중
+    #[default]
+    Active,
+    /// 경고 발송됨
+    Warned,
+    /// 관전자로 전환됨
+    Spectating,
+}
+
+/// AFK 판정 결과 조치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AfkAction {
+    /// 경고 발송
+    Warn {
+        idle_secs: u64,
+        disconnect_in_secs: u64,
+    },
+    /// 관전자로 전환
+    MoveToSpectator { idle_secs: u64 },
+    /// 연결 해제
+    Disconnect { idle_secs: u64 },
+}
+
+impl AfkAction {
+    /// 조치 이후의 단계 (연결 해제는 단계가 없음)
+    pub fn next_stage(&self) -> Option<AfkStage> {
+        match self {
+            Self::Warn { .. } => Some(AfkStage::Warned),
+            Self::MoveToSpectator { .. } => Some(AfkStage::Spectating),
+            Self::Disconnect { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AfkConfig {
+        AfkConfig::from_secs(60, 120, 300)
+    }
+
+    #[test]
+    fn test_stages_escalate_in_order() {
+        let config = config();
+        let secs = Duration::from_secs;
+
+        assert_eq!(config.evaluate(AfkStage::Active, secs(30)), None);
+        assert_eq!(
+            config.evaluate(AfkStage::Active, secs(60)),
+            Some(AfkAction::Warn {
+                idle_secs: 60,
+                disconnect_in_secs: 240
+            })
+        );
+        assert_eq!(config.evaluate(AfkStage::Warned, secs(90)), None);
+        assert_eq!(
+            config.evaluate(AfkStage::Warned, secs(120)),
+            Some(AfkAction::MoveToSpectator { idle_secs: 120 })
+        );
+        assert_eq!(config.evaluate(AfkStage::Spectating, secs(200)), None);
+        assert_eq!(
+            config.evaluate(AfkStage::Spectating, secs(300)),
+            Some(AfkAction::Disconnect { idle_secs: 300 })
+        );
+    }
+
+    #[test]
+    fn test_long_gap_jumps_to_strongest_action() {
+        assert_eq!(
+            config().evaluate(AfkStage::Active, Duration::from_secs(150)),
+            Some(AfkAction::MoveToSpectator { idle_secs: 150 })
+        );
+    }
+
+    #[test]
+    fn test_disabled() {
+        let config = AfkConfig::from_secs(60, 120, 0);
+        assert_eq!(
+            config.evaluate(AfkStage::Active, Duration::from_secs(10_000)),
+            None
+        );
+    }
+}
@@ -0,0 +1,312 @@
+//! 방(룸) 단위 게임 이벤트 채널
+//!
+//! 전역 broadcast 채널 하나로 모든 이벤트를 흘리면 동시접속이 늘어날수록
+//! 느린 구독자 때문에 이벤트가 유실됩니다. 이 모듈은 방마다 독립된 채널을 두고
+//! 채널별 지연(lag)/유실 통계를 수집하여 임계값을 넘으면 경고를 남깁니다.
+//!
+//! # 구조
+//! - `RoomEventChannels`: 방 ID → 채널 매핑 및 발행/구독 API
+//! - `RoomEventReceiver`: lag 발생 시 통계를 기록하고 계속 수신하는 구독자
+//! - `RoomChannelMetrics`: 채널별 통계 스냅샷
+
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::game::state_manager::GameEvent;
+
+/// 방 ID 타입 (`Player::room_id`와 동일)
+pub type RoomId = u32;
+
+/// 방에 속하지 않은 플레이어가 사용하는 로비 채널 ID
+pub const LOBBY_ROOM_ID: RoomId = 0;
+
+/// 이벤트 채널 설정
+#[derive(Debug, Clone)]
+pub struct EventChannelConfig {
+    /// 방별 채널 버퍼 크기
+    pub capacity: usize,
+    /// 점검 주기당 유실 이벤트 경고 임계값
+    pub lag_alert_threshold: u64,
+    /// 버퍼 점유율 경고 임계값 (0.0 ~ 1.0)
+    pub backlog_alert_ratio: f32,
+}
+
+impl Default for EventChannelConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 256,
+            lag_alert_threshold: 32,
+            backlog_alert_ratio: 0.8,
+        }
+    }
+}
+
+/// 채널별 통계 카운터
+#[derive(Debug, Default)]
+struct ChannelCounters {
+    /// 발행된 이벤트 수
+    published: AtomicU64,
+    /// 구독자가 없어 전달되지 않은 이벤트 수
+    undelivered: AtomicU64,
+    /// 느린 구독자로 인해 유실된 이벤트 수 (누적)
+    lagged: AtomicU64,
+    /// 마지막 경고 점검 시점의 유실 수
+    lagged_at_last_check: AtomicU64,
+}
+
+/// 방 하나의 이벤트 채널
+#[derive(Debug)]
+struct RoomChannel {
+    sender: broadcast::Sender<GameEvent>,
+    counters: Arc<ChannelCounters>,
+}
+
+/// 채널 통계 스냅샷
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoomChannelMetrics {
+    pub room_id: RoomId,
+    pub published: u64,
+    pub undelivered: u64,
+    pub lagged: u64,
+    pub backlog: usize,
+    pub subscribers: usize,
+}
+
+/// 방 단위 이벤트 채널 관리자
+#[derive(Debug)]
+pub struct RoomEventChannels {
+    config: EventChannelConfig,
+    channels: DashMap<RoomId, RoomChannel>,
+}
+
+impl RoomEventChannels {
+    /// 새로운 채널 관리자 생성
+    pub fn new(config: EventChannelConfig) -> Self {
+        Self {
+            config,
+            channels: DashMap::new(),
+        }
+    }
+
+    /// 방 채널 생성 (이미 있으면 그대로 사용)
+    ///
+    /// 플레이어가 방(또는 로비)에 들어갈 때 호출합니다.
+    pub fn open_room(&self, room_id: RoomId) {
+        self.channels
+            .entry(room_id)
+            .or_insert_with(|| self.create_channel());
+    }
+
+    /// 방 채널에 이벤트 발행
+    ///
+    /// 이미 존재하는 채널에만 발행하며, 채널이 없으면(제거된 방) 버리고 `false`를 반환합니다.
+    /// 구독자가 없는 경우 `undelivered`로 집계됩니다.
+    pub fn publish(&self, room_id: RoomId, event: GameEvent) -> bool {
+        let Some(channel) = self.channels.get(&room_id) else {
+            return false;
+        };
+
+        channel.counters.published.fetch_add(1, Ordering::Relaxed);
+        if channel.sender.send(event).is_err() {
+            channel.counters.undelivered.fetch_add(1, Ordering::Relaxed);
+        }
+        true
+    }
+
+    /// 방 채널 구독
+    pub fn subscribe(&self, room_id: RoomId) -> RoomEventReceiver {
+        let channel = self
+            .channels
+            .entry(room_id)
+            .or_insert_with(|| self.create_channel());
+
+        RoomEventReceiver {
+            room_id,
+            receiver: channel.sender.subscribe(),
+            counters: channel.counters.clone(),
+        }
+    }
+
+    /// 이미 존재하는 방 채널만 구독 (제거된 방의 채널을 다시 만들지 않음)
+    pub fn subscribe_existing(&self, room_id: RoomId) -> Option<RoomEventReceiver> {
+        let channel = self.channels.get(&room_id)?;
+        Some(RoomEventReceiver {
+            room_id,
+            receiver: channel.sender.subscribe(),
+            counters: channel.counters.clone(),
+        })
+    }
+
+    /// 방 채널 제거 (방 종료 시)
+    ///
+    /// 남아 있는 구독자는 `None`을 받고 종료됩니다.
+    pub fn remove_room(&self, room_id: RoomId) -> bool {
+        self.channels.remove(&room_id).is_some()
+    }
+
+    /// 현재 채널이 존재하는 방 목록
+    pub fn room_ids(&self) -> Vec<RoomId> {
+        self.channels.iter().map(|entry| *entry.key()).collect()
+    }
+
+    /// 전체 채널 통계 스냅샷
+    pub fn metrics(&self) -> Vec<RoomChannelMetrics> {
+        self.channels
+            .iter()
+            .map(|entry| RoomChannelMetrics {
+                room_id: *entry.key(),
+                published: entry.counters.published.load(Ordering::Relaxed),
+                undelivered: entry.counters.undelivered.load(Ordering::Relaxed),
+                lagged: entry.counters.lagged.load(Ordering::Relaxed),
+                backlog: entry.sender.len(),
+                subscribers: entry.sender.receiver_count(),
+            })
+            .collect()
+    }
+
+    /// 임계값을 초과한 채널을 찾아 경고를 남기고 해당 방 목록을 반환
+    ///
+    /// 유실 임계값은 직전 점검 이후 증가분 기준으로 판단합니다.
+    pub fn check_alerts(&self) -> Vec<RoomId> {
+        let backlog_limit =
+            (self.config.capacity as f32 * self.config.backlog_alert_ratio) as usize;
+        let mut alerted = Vec::new();
+
+        for entry in self.channels.iter() {
+            let room_id = *entry.key();
+            let lagged = entry.counters.lagged.load(Ordering::Relaxed);
+            let previous = entry
+                .counters
+                .lagged_at_last_check
+                .swap(lagged, Ordering::Relaxed);
+            let lagged_delta = lagged.saturating_sub(previous);
+            let backlog = entry.sender.len();
+
+            if lagged_delta >= self.config.lag_alert_threshold || backlog >= backlog_limit {
+                warn!(
+                    room_id = %room_id,
+                    lagged_delta = %lagged_delta,
+                    backlog = %backlog,
+                    capacity = %self.config.capacity,
+                    "Room event channel over threshold"
+                );
+                alerted.push(room_id);
+            }
+        }
+
+        alerted
+    }
+
+    fn create_channel(&self) -> RoomChannel {
+        let (sender, _) = broadcast::channel(self.config.capacity);
+        RoomChannel {
+            sender,
+            counters: Arc::new(ChannelCounters::default()),
+        }
+    }
+}
+
+/// 방 이벤트 구독자
+///
+/// lag가 발생하면 유실 수를 통계에 기록하고 다음 이벤트부터 계속 수신합니다.
+#[derive(Debug)]
+pub struct RoomEventReceiver {
+    room_id: RoomId,
+    receiver: broadcast::Receiver<GameEvent>,
+    counters: Arc<ChannelCounters>,
+}
+
+impl RoomEventReceiver {
+    /// 구독 중인 방 ID
+    pub fn room_id(&self) -> RoomId {
+        self.room_id
+    }
+
+    /// 다음 이벤트 수신 (채널이 닫히면 `None`)
+    pub async fn recv(&mut self) -> Option<GameEvent> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) => return Some(event),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    self.counters.lagged.fetch_add(skipped, Ordering::Relaxed);
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::messages::Position;
+
+    fn respawn_event(player_id: u32) -> GameEvent {
+        GameEvent::PlayerRespawned {
+            player_id,
+            spawn_position: Position::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_events_are_isolated_per_room() {
+        let channels = RoomEventChannels::new(EventChannelConfig::default());
+        let mut room_a = channels.subscribe(1);
+        let mut room_b = channels.subscribe(2);
+
+        channels.publish(1, respawn_event(10));
+        channels.publish(2, respawn_event(20));
+
+        assert!(matches!(
+            room_a.recv().await,
+            Some(GameEvent::PlayerRespawned { player_id: 10, .. })
+        ));
+        assert!(matches!(
+            room_b.recv().await,
+            Some(GameEvent::PlayerRespawned { player_id: 20, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_removed_room_closes_subscribers() {
+        let channels = RoomEventChannels::new(EventChannelConfig::default());
+        let mut receiver = channels.subscribe(3);
+        assert!(channels.publish(3, respawn_event(1)));
+
+        assert!(channels.remove_room(3));
+        assert!(channels.subscribe_existing(3).is_none());
+        assert!(channels.room_ids().is_empty());
+
+        // 제거 후 발행은 채널을 다시 만들지 않음
+        assert!(!channels.publish(3, respawn_event(2)));
+        assert!(channels.room_ids().is_empty());
+
+        // 제거 전에 발행된 이벤트까지 받은 뒤 종료
+        assert!(receiver.recv().await.is_some());
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_lag_is_counted_and_alerted() {
+        let channels = RoomEventChannels::new(EventChannelConfig {
+            capacity: 4,
+            lag_alert_threshold: 2,
+            backlog_alert_ratio: 1.0,
+        });
+        let mut receiver = channels.subscribe(7);
+
+        for i in 0..10 {
+            channels.publish(7, respawn_event(i));
+        }
+        assert!(receiver.recv().await.is_some());
+
+        let metrics = channels.metrics();
+        assert_eq!(metrics[0].published, 10);
+        assert_eq!(metrics[0].lagged, 6);
+        assert_eq!(channels.check_alerts(), vec![7]);
+        assert!(channels.check_alerts().is_empty());
+    }
+}
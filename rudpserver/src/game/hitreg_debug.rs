@@ -0,0 +1,191 @@
+//! 히트 판정 디버그 스트림
+//!
+//! QA가 공격이 빗나간 이유를 시각화할 수 있도록, 플래그된 플레이어의 최근 N개 공격에 대해
+//! 서버가 판정한 위치, 되감기 윈도우, 히트 테스트 결과를 보관하고 전송 여부를 결정합니다.
+//! 설정에서 비활성화되어 있으면 플래그 자체가 거부되며, 플레이어별로 초당 전송량이 제한됩니다.
+
+use anyhow::{anyhow, Result};
+use dashmap::DashMap;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::game::messages::{HitRegRecord, PlayerId};
+
+/// 레이트 리밋 윈도우
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// 히트 판정 디버그 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HitRegDebugConfig {
+    /// 디버그 스트림 허용 여부
+    pub enabled: bool,
+    /// 플레이어별 보관할 최근 공격 판정 수
+    pub history_size: usize,
+    /// 플레이어별 초당 최대 전송 수
+    pub max_messages_per_sec: u32,
+}
+
+/// 플래그된 플레이어별 기록
+#[derive(Debug)]
+struct PlayerHistory {
+    records: VecDeque<HitRegRecord>,
+    window_start: Instant,
+    sent_in_window: u32,
+}
+
+/// 히트 판정 디버거
+#[derive(Debug)]
+pub struct HitRegDebugger {
+    config: HitRegDebugConfig,
+    flagged: DashMap<PlayerId, PlayerHistory>,
+    sequence: AtomicU64,
+}
+
+impl HitRegDebugger {
+    /// 새 디버거 생성
+    pub fn new(config: HitRegDebugConfig) -> Self {
+        Self {
+            config,
+            flagged: DashMap::new(),
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    /// 디버그 스트림 허용 여부
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// 플레이어를 디버그 대상으로 지정
+    pub fn flag(&self, player_id: PlayerId) -> Result<()> {
+        if !self.config.enabled {
+            return Err(anyhow!("Hit registration debug stream is disabled"));
+        }
+        self.flagged.entry(player_id).or_insert_with(|| PlayerHistory {
+            records: VecDeque::with_capacity(self.config.history_size),
+            window_start: Instant::now(),
+            sent_in_window: 0,
+        });
+        Ok(())
+    }
+
+    /// 디버그 대상 해제 (대상이었으면 true)
+    pub fn unflag(&self, player_id: PlayerId) -> bool {
+        self.flagged.remove(&player_id).is_some()
+    }
+
+    /// 디버그 대상 여부
+    pub fn is_flagged(&self, player_id: PlayerId) -> bool {
+        self.flagged.contains_key(&player_id)
+    }
+
+    /// 디버그 대상 플레이어 목록
+    pub fn flagged_players(&self) -> Vec<PlayerId> {
+        self.flagged.iter().map(|entry| *entry.key()).collect()
+    }
+
+    /// 다음 기록 순번
+    pub fn next_sequence(&self) -> u64 {
+        self.sequence.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// 판정 기록 추가
+    ///
+    /// 기록은 항상 보관되며, 레이트 리밋 안에서만 최근 기록 스냅샷을 반환합니다.
+    /// 대상이 아닌 플레이어의 기록은 버립니다.
+    pub fn record(
+        &self,
+        player_id: PlayerId,
+        record: HitRegRecord,
+        now: Instant,
+    ) -> Option<Vec<HitRegRecord>> {
+        let mut history = self.flagged.get_mut(&player_id)?;
+
+        if history.records.len() >= self.config.history_size {
+            history.records.pop_front();
+        }
+        history.records.push_back(record);
+
+        if now.duration_since(history.window_start) >= RATE_WINDOW {
+            history.window_start = now;
+            history.sent_in_window = 0;
+        }
+        if history.sent_in_window >= self.config.max_messages_per_sec {
+            return None;
+        }
+        history.sent_in_window += 1;
+
+        Some(history.records.iter().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::messages::{AttackTarget, AttackType, HitTestOutcome, Position};
+
+    fn debugger(enabled: bool) -> HitRegDebugger {
+        HitRegDebugger::new(HitRegDebugConfig {
+            enabled,
+            history_size: 3,
+            max_messages_per_sec: 2,
+        })
+    }
+
+    fn record(sequence: u64) -> HitRegRecord {
+        HitRegRecord {
+            sequence,
+            server_timestamp: 0,
+            target: AttackTarget::Player(2),
+            attack_type: AttackType::MeleeBasic,
+            weapon_id: None,
+            attacker_position: Position::new(0.0, 0.0, 0.0),
+            target_position: Some(Position::new(5.0, 0.0, 0.0)),
+            distance: Some(5.0),
+            max_range: 10.0,
+            rewind_window_ms: 50.0,
+            outcome: HitTestOutcome::Hit,
+            damage_dealt: 10,
+        }
+    }
+
+    #[test]
+    fn test_disabled_rejects_flag() {
+        let debugger = debugger(false);
+        assert!(debugger.flag(1).is_err());
+        assert!(debugger.record(1, record(0), Instant::now()).is_none());
+    }
+
+    #[test]
+    fn test_keeps_last_n_records() {
+        let debugger = debugger(true);
+        debugger.flag(1).unwrap();
+        let start = Instant::now();
+
+        for sequence in 0..5 {
+            let now = start + RATE_WINDOW * sequence as u32;
+            let records = debugger.record(1, record(sequence), now).unwrap();
+            assert!(records.len() <= 3);
+        }
+
+        let now = start + RATE_WINDOW * 10;
+        let records = debugger.record(1, record(5), now).unwrap();
+        let sequences: Vec<u64> = records.iter().map(|r| r.sequence).collect();
+        assert_eq!(sequences, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_rate_limited() {
+        let debugger = debugger(true);
+        debugger.flag(1).unwrap();
+        let now = Instant::now();
+
+        assert!(debugger.record(1, record(0), now).is_some());
+        assert!(debugger.record(1, record(1), now).is_some());
+        assert!(debugger.record(1, record(2), now).is_none());
+        assert!(debugger.record(1, record(3), now + RATE_WINDOW).is_some());
+        assert!(debugger.unflag(1));
+        assert!(!debugger.is_flagged(1));
+    }
+}
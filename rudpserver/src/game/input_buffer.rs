@@ -0,0 +1,305 @@
+//! 플레이어별 입력 버퍼
+//!
+//! 틱 위상과 어긋나게 도착한 입력을 바로 적용하면 이동이 떨립니다. 검증을 통과한 입력을
+//! 플레이어별로 클라이언트 틱 순서대로 모아 두고, 대응하는 서버 틱에 작은 지연을 두고 적용합니다.
+//! - 첫 입력으로 클라이언트 틱 → 서버 틱 오프셋을 정하고, 더 일찍 도착한 입력이 오면 앞당깁니다.
+//! - 도착 간격의 흔들림(지터)을 지수 평균으로 추적해 지연을 `min_delay_ticks..=max_delay_ticks`에서 조절합니다.
+//! - 적용 시점을 놓친 입력은 다음 틱에 적용하고(`late`), 이미 적용한 틱보다 오래된 입력은 버립니다.
+//! - 같은 클라이언트 틱의 입력이 다시 오면 마지막 입력으로 교체합니다.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::game::messages::PlayerId;
+
+/// 지터 지수 평균 가중치 (새 측정값 비율)
+const JITTER_SMOOTHING: f32 = 0.125;
+
+/// 입력 버퍼 설정
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputBufferConfig {
+    /// 최소 적용 지연 (틱)
+    pub min_delay_ticks: u64,
+    /// 최대 적용 지연 (틱)
+    pub max_delay_ticks: u64,
+    /// 플레이어별 최대 보관 입력 수 (넘치면 가장 오래된 입력을 버림)
+    pub capacity: usize,
+}
+
+impl Default for InputBufferConfig {
+    fn default() -> Self {
+        Self {
+            min_delay_ticks: 1,
+            max_delay_ticks: 6,
+            capacity: 32,
+        }
+    }
+}
+
+/// 입력 추가 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputOutcome {
+    /// `apply_at` 서버 틱에 적용 예정
+    Buffered { apply_at: u64 },
+    /// 적용 시점을 이미 지나 다음 틱에 적용
+    Late,
+    /// 이미 적용한 틱보다 오래되어 버림
+    Dropped,
+}
+
+/// 입력 버퍼 상태 지표
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct InputBufferStats {
+    /// 버퍼를 가진 플레이어 수
+    pub players: usize,
+    /// 현재 보관 중인 입력 수
+    pub buffered: usize,
+    /// 적용한 입력 수
+    pub applied: u64,
+    /// 적용 시점을 놓치고 도착한 입력 수
+    pub late: u64,
+    /// 오래되었거나 버퍼가 넘쳐 버린 입력 수
+    pub dropped: u64,
+    /// 플레이어 평균 적용 지연 (틱)
+    pub avg_delay_ticks: f32,
+    /// 플레이어 중 최대 적용 지연 (틱)
+    pub max_delay_ticks: u64,
+    /// 플레이어 평균 도착 지터 (틱)
+    pub avg_jitter_ticks: f32,
+}
+
+/// 플레이어 한 명의 입력 버퍼
+#[derive(Debug)]
+struct PlayerInputBuffer<T> {
+    /// 클라이언트 틱 → 입력
+    pending: BTreeMap<u64, T>,
+    /// 서버 틱 - 클라이언트 틱 (가장 빨리 도착한 입력 기준)
+    offset: Option<i64>,
+    last_applied: Option<u64>,
+    last_arrival: Option<i64>,
+    jitter: f32,
+    delay_ticks: u64,
+    applied: u64,
+    late: u64,
+    dropped: u64,
+}
+
+impl<T> PlayerInputBuffer<T> {
+    fn new(config: &InputBufferConfig) -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            offset: None,
+            last_applied: None,
+            last_arrival: None,
+            jitter: 0.0,
+            delay_ticks: config.min_delay_ticks,
+            applied: 0,
+            late: 0,
+            dropped: 0,
+        }
+    }
+
+    fn apply_at(&self, client_tick: u64) -> i64 {
+        client_tick as i64 + self.offset.unwrap_or(0) + self.delay_ticks as i64
+    }
+
+    fn push(
+        &mut self,
+        config: &InputBufferConfig,
+        client_tick: u64,
+        server_tick: u64,
+        input: T,
+    ) -> InputOutcome {
+        if self
+            .last_applied
+            .is_some_and(|applied| client_tick <= applied)
+        {
+            self.dropped += 1;
+            return InputOutcome::Dropped;
+        }
+
+        let offset = *self
+            .offset
+            .get_or_insert(server_tick as i64 - client_tick as i64);
+        let mut arrival = server_tick as i64 - client_tick as i64 - offset;
+        if arrival < 0 {
+            // 전보다 일찍 도착 (지연이 줄었음) - 기준을 앞당김
+            self.offset = Some(offset + arrival);
+            arrival = 0;
+        }
+
+        if let Some(previous) = self.last_arrival {
+            let deviation = (arrival - previous).unsigned_abs() as f32;
+            self.jitter += (deviation - self.jitter) * JITTER_SMOOTHING;
+        }
+        self.last_arrival = Some(arrival);
+        self.delay_ticks = (config.min_delay_ticks + (self.jitter * 2.0).ceil() as u64)
+            .clamp(config.min_delay_ticks, config.max_delay_ticks);
+
+        if self.pending.len() >= config.capacity && !self.pending.contains_key(&client_tick) {
+            self.pending.pop_first();
+            self.dropped += 1;
+        }
+        self.pending.insert(client_tick, input);
+
+        let apply_at = self.apply_at(client_tick);
+        if apply_at <= server_tick as i64 {
+            // 지연이 늘었음 - 이번 입력이 다음 틱에 적용되도록 기준을 늦춤
+            self.offset = Some(self.offset.unwrap_or(0) + server_tick as i64 + 1 - apply_at);
+            self.late += 1;
+            InputOutcome::Late
+        } else {
+            InputOutcome::Buffered {
+                apply_at: apply_at as u64,
+            }
+        }
+    }
+
+    fn drain_due(&mut self, server_tick: u64, out: &mut Vec<T>) {
+        while let Some((&client_tick, _)) = self.pending.first_key_value() {
+            if self.apply_at(client_tick) > server_tick as i64 {
+                break;
+            }
+            if let Some((client_tick, input)) = self.pending.pop_first() {
+                self.last_applied = Some(client_tick);
+                self.applied += 1;
+                out.push(input);
+            }
+        }
+    }
+}
+
+/// 전체 플레이어 입력 버퍼
+#[derive(Debug)]
+pub struct InputBuffers<T> {
+    config: InputBufferConfig,
+    players: HashMap<PlayerId, PlayerInputBuffer<T>>,
+}
+
+impl<T> InputBuffers<T> {
+    pub fn new(config: InputBufferConfig) -> Self {
+        Self {
+            config: InputBufferConfig {
+                max_delay_ticks: config.max_delay_ticks.max(config.min_delay_ticks),
+                capacity: config.capacity.max(1),
+                ..config
+            },
+            players: HashMap::new(),
+        }
+    }
+
+    /// 검증된 입력 추가
+    pub fn push(
+        &mut self,
+        player_id: PlayerId,
+        client_tick: u64,
+        server_tick: u64,
+        input: T,
+    ) -> InputOutcome {
+        let config = self.config;
+        self.players
+            .entry(player_id)
+            .or_insert_with(|| PlayerInputBuffer::new(&config))
+            .push(&config, client_tick, server_tick, input)
+    }
+
+    /// `server_tick`까지 적용할 입력 (플레이어별 클라이언트 틱 순)
+    pub fn drain_due(&mut self, server_tick: u64) -> Vec<(PlayerId, T)> {
+        let mut due = Vec::new();
+        let mut inputs = Vec::new();
+        for (player_id, buffer) in self.players.iter_mut() {
+            buffer.drain_due(server_tick, &mut inputs);
+            due.extend(inputs.drain(..).map(|input| (*player_id, input)));
+        }
+        due
+    }
+
+    /// 플레이어 퇴장 시 버퍼 제거
+    pub fn remove(&mut self, player_id: PlayerId) {
+        self.players.remove(&player_id);
+    }
+
+    pub fn stats(&self) -> InputBufferStats {
+        let mut stats = InputBufferStats {
+            players: self.players.len(),
+            ..Default::default()
+        };
+        let (mut total_delay, mut total_jitter) = (0u64, 0.0f32);
+        for buffer in self.players.values() {
+            stats.buffered += buffer.pending.len();
+            stats.applied += buffer.applied;
+            stats.late += buffer.late;
+            stats.dropped += buffer.dropped;
+            stats.max_delay_ticks = stats.max_delay_ticks.max(buffer.delay_ticks);
+            total_delay += buffer.delay_ticks;
+            total_jitter += buffer.jitter;
+        }
+        if stats.players > 0 {
+            stats.avg_delay_ticks = total_delay as f32 / stats.players as f32;
+            stats.avg_jitter_ticks = total_jitter / stats.players as f32;
+        }
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inputs_applied_in_client_tick_order() {
+        let mut buffers = InputBuffers::new(InputBufferConfig {
+            min_delay_ticks: 2,
+            max_delay_ticks: 4,
+            capacity: 8,
+        });
+
+        // 클라이언트 틱 10이 서버 틱 100에 도착 → 102에 적용
+        assert_eq!(
+            buffers.push(1, 10, 100, "a"),
+            InputOutcome::Buffered { apply_at: 102 }
+        );
+        // 12가 11보다 먼저 도착해도 순서대로 적용
+        assert!(matches!(
+            buffers.push(1, 12, 102, "c"),
+            InputOutcome::Buffered { .. }
+        ));
+        assert!(matches!(
+            buffers.push(1, 11, 102, "b"),
+            InputOutcome::Buffered { .. }
+        ));
+        assert!(buffers.drain_due(101).is_empty());
+
+        let mut applied = Vec::new();
+        for tick in 102..110 {
+            applied.extend(buffers.drain_due(tick).into_iter().map(|(_, input)| input));
+        }
+        assert_eq!(applied, vec!["a", "b", "c"]);
+
+        // 이미 적용한 틱의 입력은 버림
+        assert_eq!(buffers.push(1, 11, 110, "old"), InputOutcome::Dropped);
+        let stats = buffers.stats();
+        assert_eq!((stats.applied, stats.dropped, stats.buffered), (3, 1, 0));
+        assert!(stats.avg_delay_ticks >= 2.0 && stats.max_delay_ticks <= 4);
+
+        buffers.remove(1);
+        assert_eq!(buffers.stats().players, 0);
+    }
+
+    #[test]
+    fn test_late_input_applies_next_tick() {
+        let mut buffers = InputBuffers::new(InputBufferConfig {
+            min_delay_ticks: 1,
+            max_delay_ticks: 3,
+            capacity: 8,
+        });
+        buffers.push(7, 0, 50, 0);
+
+        // 지연이 갑자기 10틱 늘어남
+        assert_eq!(buffers.push(7, 1, 61, 1), InputOutcome::Late);
+        assert_eq!(buffers.drain_due(61), vec![(7, 0)]);
+        assert_eq!(buffers.drain_due(62), vec![(7, 1)]);
+        assert_eq!(buffers.stats().late, 1);
+        assert!(buffers.stats().avg_jitter_ticks > 0.0);
+    }
+}
@@ -0,0 +1,842 @@
+//! 매치 로비와 시작 카운트다운
+//!
+//! 방마다 로비를 두고 참가자의 준비 상태를 관리합니다.
+//! 최소 인원(`property/game_defaults.toml`의 `[room] min_players_to_start`) 이상이 모두 준비하면
+//! 카운트다운을 시작하고, 도중에 인원이 빠지거나 준비를 해제하면 카운트다운을 취소합니다.
+//! 카운트다운이 끝나면 틱 루프의 [`MatchManager::tick`]에서 매치가 시작됩니다.
+//!
+//! 매치는 제한 시간이 지나거나 모든 참가자가 이탈하면 끝나며, 결과는 [`MatchResult`]로
+//! `LobbyEvent::MatchEnded`에 담깁니다. 종료 후 방은 로비로 돌아가고 전원의 준비가 해제됩니다.
+//!
+//! 상태 변화는 [`LobbyUpdate`]로 반환되며, 호출자가 방 이벤트 채널로 발행합니다.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use shared::tool::{ErrorCode, GameServerError};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::game::event_channels::RoomId;
+use crate::game::messages::{
+    GameMessage, LobbyMember, MatchEndReason, MatchPhase, MatchScore, PlayerId, Team,
+};
+
+/// 방 규칙 (`property/game_defaults.toml`의 `[room]` 섹션, `RoomDefaults`와 동일한 형식)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoomRules {
+    pub max_players_per_room: u32,
+    pub min_players_to_start: u32,
+    pub room_timeout_seconds: u64,
+}
+
+impl Default for RoomRules {
+    fn default() -> Self {
+        Self {
+            max_players_per_room: 20,
+            min_players_to_start: 2,
+            room_timeout_seconds: 3600,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GameDefaultsFile {
+    #[serde(default)]
+    room: RoomRules,
+}
+
+impl RoomRules {
+    /// 게임 기본값 파일에서 `[room]` 섹션 로드 (파일이 없으면 기본값)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            warn!(path = %path.display(), "Game defaults file not found, using built-in room rules");
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read game defaults file: {}", path.display()))?;
+        let rules = toml::from_str::<GameDefaultsFile>(&contents)
+            .with_context(|| format!("Invalid game defaults file: {}", path.display()))?
+            .room;
+        if rules.min_players_to_start == 0
+            || rules.min_players_to_start > rules.max_players_per_room
+        {
+            return Err(anyhow::anyhow!(
+                "room.min_players_to_start must be 1..={}: {}",
+                rules.max_players_per_room,
+                rules.min_players_to_start
+            ));
+        }
+
+        info!(
+            min_players = %rules.min_players_to_start,
+            max_players = %rules.max_players_per_room,
+            "Room rules loaded"
+        );
+        Ok(rules)
+    }
+}
+
+/// 로비 요청 거부 사유
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LobbyError {
+    #[error("room {room_id} is full ({max_players} players)")]
+    RoomFull { room_id: RoomId, max_players: u32 },
+
+    #[error("match already in progress in room {0}")]
+    MatchInProgress(RoomId),
+
+    #[error("already in room {0}")]
+    AlreadyInRoom(RoomId),
+}
+
+impl From<LobbyError> for GameServerError {
+    fn from(error: LobbyError) -> Self {
+        let code = match error {
+            LobbyError::RoomFull { .. } => ErrorCode::RoomFull,
+            LobbyError::MatchInProgress(_) | LobbyError::AlreadyInRoom(_) => {
+                ErrorCode::InvalidAction
+            }
+        };
+        GameServerError::with_detail(code, error.to_string())
+    }
+}
+
+/// 로비 이벤트
+#[derive(Debug, Clone, PartialEq)]
+pub enum LobbyEvent {
+    /// 인원/준비 상태/단계 변경
+    StateChanged {
+        phase: MatchPhase,
+        members: Vec<LobbyMember>,
+        min_players: u32,
+        max_players: u32,
+    },
+    CountdownStarted {
+        starts_in: Duration,
+    },
+    CountdownCancelled {
+        reason: String,
+    },
+    MatchStarted {
+        players: Vec<PlayerId>,
+    },
+    MatchEnded(MatchResult),
+}
+
+/// 종료된 매치 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchResult {
+    pub match_id: String,
+    pub room_id: RoomId,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub duration: Duration,
+    pub reason: MatchEndReason,
+    /// 킬 합계가 많은 팀 (무승부면 None)
+    pub winner: Option<Team>,
+    pub players: Vec<MatchScore>,
+}
+
+impl LobbyEvent {
+    /// 클라이언트 메시지로 변환
+    pub fn to_message(&self, room_id: RoomId) -> GameMessage {
+        match self {
+            Self::StateChanged {
+                phase,
+                members,
+                min_players,
+                max_players,
+            } => GameMessage::LobbyState {
+                room_id,
+                phase: *phase,
+                members: members.clone(),
+                min_players: *min_players,
+                max_players: *max_players,
+            },
+            Self::CountdownStarted { starts_in } => GameMessage::MatchCountdown {
+                room_id,
+                starts_in_ms: starts_in.as_millis() as u64,
+            },
+            Self::CountdownCancelled { reason } => GameMessage::MatchCountdownCancelled {
+                room_id,
+                reason: reason.clone(),
+            },
+            Self::MatchStarted { players } => GameMessage::MatchStarted {
+                room_id,
+                players: players.clone(),
+                server_timestamp: crate::utils::current_timestamp_ms(),
+            },
+            Self::MatchEnded(result) => GameMessage::MatchEnded {
+                room_id,
+                reason: result.reason,
+                winner: result.winner,
+                duration_secs: result.duration.as_secs(),
+                players: result.players.clone(),
+            },
+        }
+    }
+}
+
+/// 진행 중인 매치 체크포인트 (대기 서버 복제용)
+///
+/// 진행 시간은 `started_at`(벽시계)으로 다시 계산하므로 매 복제 주기마다 바뀌지 않습니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchCheckpoint {
+    pub match_id: String,
+    pub started_at: DateTime<Utc>,
+    pub scores: Vec<MatchScore>,
+}
+
+/// 한 방에서 발생한 로비 이벤트 묶음
+#[derive(Debug, Clone, PartialEq)]
+pub struct LobbyUpdate {
+    pub room_id: RoomId,
+    /// 이벤트를 받을 세션 (변경 후 로비 인원 + 방금 떠난 인원 제외)
+    pub recipients: Vec<u64>,
+    /// 발생 순서대로 정렬, 마지막은 항상 `StateChanged`
+    pub events: Vec<LobbyEvent>,
+}
+
+#[derive(Debug, Clone)]
+struct Member {
+    player_id: PlayerId,
+    session_id: u64,
+    ready: bool,
+}
+
+/// 진행 중인 매치 (참가자 성적은 이탈해도 유지)
+#[derive(Debug)]
+struct ActiveMatch {
+    match_id: String,
+    started: Instant,
+    started_at: DateTime<Utc>,
+    scores: Vec<MatchScore>,
+}
+
+#[derive(Debug)]
+struct RoomLobby {
+    members: Vec<Member>,
+    phase: MatchPhase,
+    countdown_until: Option<Instant>,
+    active: Option<ActiveMatch>,
+}
+
+impl RoomLobby {
+    fn new() -> Self {
+        Self {
+            members: Vec::new(),
+            phase: MatchPhase::Lobby,
+            countdown_until: None,
+            active: None,
+        }
+    }
+
+    fn is_participant(&self, player_id: PlayerId) -> bool {
+        self.active.as_ref().is_some_and(|active| {
+            active
+                .scores
+                .iter()
+                .any(|score| score.player_id == player_id)
+        })
+    }
+
+    fn score_mut(&mut self, player_id: PlayerId) -> Option<&mut MatchScore> {
+        self.active
+            .as_mut()?
+            .scores
+            .iter_mut()
+            .find(|score| score.player_id == player_id)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Lobbies {
+    rooms: HashMap<RoomId, RoomLobby>,
+    player_rooms: HashMap<PlayerId, RoomId>,
+}
+
+/// 방별 로비 및 매치 시작 관리자
+#[derive(Debug)]
+pub struct MatchManager {
+    rules: RoomRules,
+    countdown: Duration,
+    match_duration: Duration,
+    lobbies: Mutex<Lobbies>,
+}
+
+impl MatchManager {
+    /// # Arguments
+    /// * `rules` - 방 인원 규칙
+    /// * `countdown` - 전원 준비 후 매치 시작까지의 대기 시간
+    /// * `match_duration` - 매치 제한 시간
+    pub fn new(rules: RoomRules, countdown: Duration, match_duration: Duration) -> Self {
+        Self {
+            rules,
+            countdown,
+            match_duration,
+            lobbies: Mutex::new(Lobbies::default()),
+        }
+    }
+
+    /// 준비 상태 변경 (로비에 없으면 참가, 진행 중인 매치의 참가자는 재접속)
+    pub fn set_ready(
+        &self,
+        room_id: RoomId,
+        player_id: PlayerId,
+        session_id: u64,
+        ready: bool,
+        now: Instant,
+    ) -> Result<LobbyUpdate, LobbyError> {
+        let mut lobbies = self.lobbies.lock();
+        if let Some(&current) = lobbies.player_rooms.get(&player_id) {
+            if current != room_id {
+                return Err(LobbyError::AlreadyInRoom(current));
+            }
+        }
+
+        let lobby = lobbies.rooms.entry(room_id).or_insert_with(RoomLobby::new);
+        let rejoining = lobby.is_participant(player_id);
+        match lobby
+            .members
+            .iter_mut()
+            .find(|member| member.player_id == player_id)
+        {
+            Some(member) => {
+                member.ready = ready;
+                member.session_id = session_id;
+            }
+            None if rejoining => {
+                lobby.members.push(Member {
+                    player_id,
+                    session_id,
+                    ready,
+                });
+            }
+            None => {
+                if lobby.phase == MatchPhase::InProgress {
+                    return Err(LobbyError::MatchInProgress(room_id));
+                }
+                if lobby.members.len() as u32 >= self.rules.max_players_per_room {
+                    return Err(LobbyError::RoomFull {
+                        room_id,
+                        max_players: self.rules.max_players_per_room,
+                    });
+                }
+                lobby.members.push(Member {
+                    player_id,
+                    session_id,
+                    ready,
+                });
+            }
+        }
+
+        let mut events = Vec::new();
+        self.evaluate(lobby, now, &mut events);
+        let update = self.update(room_id, lobby, events);
+        lobbies.player_rooms.insert(player_id, room_id);
+        Ok(update)
+    }
+
+    /// 로비에서 제거 (연결 해제 시)
+    ///
+    /// 진행 중인 매치에서는 이탈로 기록하며, 마지막 인원이 나가면 매치를 `Abandoned`로 종료합니다.
+    pub fn leave(&self, player_id: PlayerId, now: Instant) -> Option<LobbyUpdate> {
+        let mut lobbies = self.lobbies.lock();
+        let room_id = lobbies.player_rooms.remove(&player_id)?;
+        let lobby = lobbies.rooms.get_mut(&room_id)?;
+        lobby.members.retain(|member| member.player_id != player_id);
+        if let Some(score) = lobby.score_mut(player_id) {
+            score.disconnected = true;
+        }
+
+        if lobby.members.is_empty() {
+            // 받을 인원은 없지만 결과 기록을 위해 종료 이벤트는 반환
+            let update = self
+                .end_match(room_id, lobby, MatchEndReason::Abandoned, now)
+                .map(|result| self.update(room_id, lobby, vec![LobbyEvent::MatchEnded(result)]));
+            lobbies.rooms.remove(&room_id);
+            return update;
+        }
+
+        let mut events = Vec::new();
+        self.evaluate(lobby, now, &mut events);
+        Some(self.update(room_id, lobby, events))
+    }
+
+    /// 로비가 남아 있는 방인지 여부 (마지막 인원이 나가면 방이 닫힘)
+    pub fn has_room(&self, room_id: RoomId) -> bool {
+        self.lobbies.lock().rooms.contains_key(&room_id)
+    }
+
+    /// 처치 기록 (진행 중인 매치의 참가자만 집계, 자살은 데스만 기록)
+    pub fn record_kill(&self, killer_id: Option<PlayerId>, victim_id: PlayerId) {
+        let mut lobbies = self.lobbies.lock();
+        let Some(&room_id) = lobbies.player_rooms.get(&victim_id) else {
+            return;
+        };
+        let Some(lobby) = lobbies.rooms.get_mut(&room_id) else {
+            return;
+        };
+        let Some(victim) = lobby.score_mut(victim_id) else {
+            return;
+        };
+        victim.deaths += 1;
+
+        if let Some(killer_id) = killer_id.filter(|&killer_id| killer_id != victim_id) {
+            if let Some(killer) = lobby.score_mut(killer_id) {
+                killer.kills += 1;
+            }
+        }
+    }
+
+    /// 진행 중인 매치에서 같은 팀인 참가자 (본인과 이탈한 인원 제외)
+    ///
+    /// 매치 참가자가 아니면 None을 반환합니다.
+    pub fn teammates(&self, player_id: PlayerId) -> Option<(RoomId, Vec<PlayerId>)> {
+        let lobbies = self.lobbies.lock();
+        let room_id = *lobbies.player_rooms.get(&player_id)?;
+        let scores = &lobbies.rooms.get(&room_id)?.active.as_ref()?.scores;
+        let team = scores
+            .iter()
+            .find(|score| score.player_id == player_id)?
+            .team;
+        let teammates = scores
+            .iter()
+            .filter(|score| {
+                score.team == team && score.player_id != player_id && !score.disconnected
+            })
+            .map(|score| score.player_id)
+            .collect();
+        Some((room_id, teammates))
+    }
+
+    /// 카운트다운이 끝난 방의 매치 시작, 제한 시간이 지난 매치 종료 (틱마다 호출)
+    pub fn tick(&self, now: Instant) -> Vec<LobbyUpdate> {
+        let mut lobbies = self.lobbies.lock();
+        let mut updates = Vec::new();
+        for (&room_id, lobby) in lobbies.rooms.iter_mut() {
+            if matches!(lobby.countdown_until, Some(until) if until <= now) {
+                let players = self.start_match(room_id, lobby, now);
+                let events = vec![LobbyEvent::MatchStarted { players }];
+                updates.push(self.update(room_id, lobby, events));
+            } else if matches!(&lobby.active, Some(active) if now.saturating_duration_since(active.started) >= self.match_duration)
+            {
+                if let Some(result) = self.end_match(room_id, lobby, MatchEndReason::TimeLimit, now)
+                {
+                    updates.push(self.update(room_id, lobby, vec![LobbyEvent::MatchEnded(result)]));
+                }
+            }
+        }
+        updates
+    }
+
+    /// 진행 중인 매치 체크포인트 (방 ID 순)
+    pub fn checkpoints(&self) -> Vec<(RoomId, MatchCheckpoint)> {
+        let lobbies = self.lobbies.lock();
+        let mut checkpoints: Vec<(RoomId, MatchCheckpoint)> = lobbies
+            .rooms
+            .iter()
+            .filter_map(|(&room_id, lobby)| {
+                let active = lobby.active.as_ref()?;
+                Some((
+                    room_id,
+                    MatchCheckpoint {
+                        match_id: active.match_id.clone(),
+                        started_at: active.started_at,
+                        scores: active.scores.clone(),
+                    },
+                ))
+            })
+            .collect();
+        checkpoints.sort_by_key(|(room_id, _)| *room_id);
+        checkpoints
+    }
+
+    /// 체크포인트로 매치 복원 (장애 조치)
+    ///
+    /// 로비 인원 없이 진행 중 상태로 복원하며, 참가자는 재접속 후 `set_ready`로 다시 합류합니다.
+    /// 이미 매치가 진행 중인 방이면 복원하지 않고 false를 반환합니다.
+    pub fn restore(&self, room_id: RoomId, checkpoint: MatchCheckpoint, now: Instant) -> bool {
+        let mut lobbies = self.lobbies.lock();
+        let lobby = lobbies.rooms.entry(room_id).or_insert_with(RoomLobby::new);
+        if lobby.active.is_some() {
+            warn!(room_id = %room_id, match_id = %checkpoint.match_id, "Match restore skipped, room already in progress");
+            return false;
+        }
+
+        let elapsed = (Utc::now() - checkpoint.started_at)
+            .to_std()
+            .unwrap_or_default();
+        lobby.phase = MatchPhase::InProgress;
+        lobby.countdown_until = None;
+        info!(room_id = %room_id, match_id = %checkpoint.match_id, elapsed_secs = elapsed.as_secs(), "Match restored");
+        lobby.active = Some(ActiveMatch {
+            match_id: checkpoint.match_id,
+            started: now.checked_sub(elapsed).unwrap_or(now),
+            started_at: checkpoint.started_at,
+            scores: checkpoint.scores,
+        });
+        true
+    }
+
+    /// 매치 시작 (참가 순서대로 경찰/도둑 번갈아 배정)
+    fn start_match(&self, room_id: RoomId, lobby: &mut RoomLobby, now: Instant) -> Vec<PlayerId> {
+        lobby.phase = MatchPhase::InProgress;
+        lobby.countdown_until = None;
+        let scores: Vec<MatchScore> = lobby
+            .members
+            .iter()
+            .enumerate()
+            .map(|(index, member)| MatchScore {
+                player_id: member.player_id,
+                team: if index % 2 == 0 {
+                    Team::Police
+                } else {
+                    Team::Thief
+                },
+                kills: 0,
+                deaths: 0,
+                disconnected: false,
+            })
+            .collect();
+        let active = ActiveMatch {
+            match_id: uuid::Uuid::new_v4().to_string(),
+            started: now,
+            started_at: Utc::now(),
+            scores,
+        };
+        let players: Vec<PlayerId> = active.scores.iter().map(|score| score.player_id).collect();
+        info!(room_id = %room_id, match_id = %active.match_id, players = ?players, "Match started");
+        lobby.active = Some(active);
+        players
+    }
+
+    /// 매치 종료 후 로비로 복귀 (이탈한 인원은 정리하고 전원 준비 해제)
+    fn end_match(
+        &self,
+        room_id: RoomId,
+        lobby: &mut RoomLobby,
+        reason: MatchEndReason,
+        now: Instant,
+    ) -> Option<MatchResult> {
+        let active = lobby.active.take()?;
+        lobby.phase = MatchPhase::Lobby;
+        for member in &mut lobby.members {
+            member.ready = false;
+        }
+
+        let team_kills = |team: Team| -> u32 {
+            active
+                .scores
+                .iter()
+                .filter(|score| score.team == team)
+                .map(|score| score.kills)
+                .sum()
+        };
+        let winner = match team_kills(Team::Police).cmp(&team_kills(Team::Thief)) {
+            std::cmp::Ordering::Greater => Some(Team::Police),
+            std::cmp::Ordering::Less => Some(Team::Thief),
+            std::cmp::Ordering::Equal => None,
+        };
+
+        let result = MatchResult {
+            match_id: active.match_id,
+            room_id,
+            started_at: active.started_at,
+            ended_at: Utc::now(),
+            duration: now.saturating_duration_since(active.started),
+            reason,
+            winner,
+            players: active.scores,
+        };
+        info!(
+            room_id = %room_id,
+            match_id = %result.match_id,
+            reason = result.reason.as_str(),
+            winner = ?result.winner,
+            "Match ended"
+        );
+        Some(result)
+    }
+
+    /// 최소 인원/준비 상태에 따라 카운트다운 시작 또는 취소
+    fn evaluate(&self, lobby: &mut RoomLobby, now: Instant, events: &mut Vec<LobbyEvent>) {
+        let min_players = self.rules.min_players_to_start.max(1) as usize;
+        let enough = lobby.members.len() >= min_players;
+        let unready = lobby.members.iter().find(|member| !member.ready);
+
+        match lobby.phase {
+            MatchPhase::Lobby if enough && unready.is_none() => {
+                lobby.phase = MatchPhase::Countdown;
+                lobby.countdown_until = Some(now + self.countdown);
+                events.push(LobbyEvent::CountdownStarted {
+                    starts_in: self.countdown,
+                });
+            }
+            MatchPhase::Countdown if !enough || unready.is_some() => {
+                let reason = match unready {
+                    Some(member) if enough => format!("player {} is not ready", member.player_id),
+                    _ => format!(
+                        "not enough players ({}/{})",
+                        lobby.members.len(),
+                        min_players
+                    ),
+                };
+                lobby.phase = MatchPhase::Lobby;
+                lobby.countdown_until = None;
+                events.push(LobbyEvent::CountdownCancelled { reason });
+            }
+            _ => {}
+        }
+    }
+
+    fn update(
+        &self,
+        room_id: RoomId,
+        lobby: &RoomLobby,
+        mut events: Vec<LobbyEvent>,
+    ) -> LobbyUpdate {
+        events.push(LobbyEvent::StateChanged {
+            phase: lobby.phase,
+            members: lobby
+                .members
+                .iter()
+                .map(|member| LobbyMember {
+                    player_id: member.player_id,
+                    ready: member.ready,
+                })
+                .collect(),
+            min_players: self.rules.min_players_to_start,
+            max_players: self.rules.max_players_per_room,
+        });
+        LobbyUpdate {
+            room_id,
+            recipients: lobby
+                .members
+                .iter()
+                .map(|member| member.session_id)
+                .collect(),
+            events,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager() -> MatchManager {
+        MatchManager::new(
+            RoomRules::default(),
+            Duration::from_secs(5),
+            Duration::from_secs(60),
+        )
+    }
+
+    #[test]
+    fn test_countdown_starts_and_match_begins() {
+        let manager = manager();
+        let now = Instant::now();
+
+        // 1명만 준비 - 최소 인원 미달
+        let update = manager.set_ready(7, 1, 101, true, now).unwrap();
+        assert_eq!(update.events.len(), 1);
+
+        manager.set_ready(7, 2, 102, false, now).unwrap();
+        let update = manager.set_ready(7, 2, 102, true, now).unwrap();
+        assert_eq!(update.recipients, vec![101, 102]);
+        assert_eq!(
+            update.events[0],
+            LobbyEvent::CountdownStarted {
+                starts_in: Duration::from_secs(5)
+            }
+        );
+
+        assert!(manager.tick(now + Duration::from_secs(4)).is_empty());
+        let started = manager.tick(now + Duration::from_secs(5));
+        assert_eq!(
+            started[0].events[0],
+            LobbyEvent::MatchStarted {
+                players: vec![1, 2]
+            }
+        );
+
+        // 진행 중인 매치에는 새로 참가할 수 없음
+        assert_eq!(
+            manager.set_ready(7, 3, 103, true, now),
+            Err(LobbyError::MatchInProgress(7))
+        );
+    }
+
+    #[test]
+    fn test_countdown_cancelled() {
+        let manager = manager();
+        let now = Instant::now();
+        manager.set_ready(1, 1, 101, true, now).unwrap();
+        manager.set_ready(1, 2, 102, true, now).unwrap();
+
+        let update = manager.set_ready(1, 2, 102, false, now).unwrap();
+        assert_eq!(
+            update.events[0],
+            LobbyEvent::CountdownCancelled {
+                reason: "player 2 is not ready".to_string()
+            }
+        );
+
+        manager.set_ready(1, 2, 102, true, now).unwrap();
+        let update = manager.leave(1, now).unwrap();
+        assert_eq!(update.recipients, vec![102]);
+        assert!(matches!(
+            &update.events[0],
+            LobbyEvent::CountdownCancelled { reason } if reason.starts_with("not enough players")
+        ));
+        assert!(manager.tick(now + Duration::from_secs(10)).is_empty());
+
+        assert_eq!(
+            manager.set_ready(2, 2, 102, true, now),
+            Err(LobbyError::AlreadyInRoom(1))
+        );
+        // 마지막 인원이 나가면 방이 정리되고 다른 방에 참가 가능
+        assert!(manager.leave(2, now).is_none());
+        assert!(manager.set_ready(2, 2, 102, true, now).is_ok());
+    }
+
+    #[test]
+    fn test_match_ends_with_result() {
+        let manager = manager();
+        let now = Instant::now();
+        for player_id in 1..=3 {
+            manager
+                .set_ready(3, player_id, 100 + player_id as u64, true, now)
+                .unwrap();
+        }
+        let start = now + Duration::from_secs(5);
+        assert_eq!(manager.teammates(1), None);
+        manager.tick(start);
+
+        // 1, 3은 경찰, 2는 도둑
+        assert_eq!(manager.teammates(1), Some((3, vec![3])));
+        assert_eq!(manager.teammates(2), Some((3, vec![])));
+        manager.record_kill(Some(2), 1);
+        manager.record_kill(Some(2), 3);
+        manager.record_kill(Some(1), 2);
+        manager.record_kill(None, 2);
+        let update = manager.leave(3, start).unwrap();
+        assert_eq!(update.recipients, vec![101, 102]);
+        assert_eq!(manager.teammates(1), Some((3, vec![])));
+
+        let ended = manager.tick(start + Duration::from_secs(60));
+        let LobbyEvent::MatchEnded(result) = &ended[0].events[0] else {
+            panic!("expected match end: {:?}", ended);
+        };
+        assert_eq!(result.reason, MatchEndReason::TimeLimit);
+        assert_eq!(result.winner, Some(Team::Thief));
+        assert_eq!(result.duration, Duration::from_secs(60));
+        let thief = result
+            .players
+            .iter()
+            .find(|score| score.player_id == 2)
+            .unwrap();
+        assert_eq!((thief.team, thief.kills, thief.deaths), (Team::Thief, 2, 2));
+        assert!(
+            result
+                .players
+                .iter()
+                .find(|score| score.player_id == 3)
+                .unwrap()
+                .disconnected
+        );
+
+        // 로비로 복귀, 전원 준비 해제
+        assert_eq!(
+            ended[0].events[1],
+            LobbyEvent::StateChanged {
+                phase: MatchPhase::Lobby,
+                members: vec![
+                    LobbyMember {
+                        player_id: 1,
+                        ready: false
+                    },
+                    LobbyMember {
+                        player_id: 2,
+                        ready: false
+                    },
+                ],
+                min_players: 2,
+                max_players: 20,
+            }
+        );
+    }
+
+    #[test]
+    fn test_match_abandoned_and_rejoin() {
+        let manager = manager();
+        let now = Instant::now();
+        manager.set_ready(5, 1, 101, true, now).unwrap();
+        manager.set_ready(5, 2, 102, true, now).unwrap();
+        manager.tick(now + Duration::from_secs(5));
+
+        // 진행 중인 매치 참가자는 재접속 가능
+        manager.leave(1, now).unwrap();
+        assert!(manager.set_ready(5, 1, 201, true, now).is_ok());
+
+        manager.leave(1, now).unwrap();
+        let update = manager.leave(2, now).unwrap();
+        assert!(update.recipients.is_empty());
+        assert!(matches!(
+            &update.events[0],
+            LobbyEvent::MatchEnded(result) if result.reason == MatchEndReason::Abandoned && result.winner.is_none()
+        ));
+        assert!(manager.tick(now + Duration::from_secs(120)).is_empty());
+    }
+
+    #[test]
+    fn test_checkpoint_restore_on_standby() {
+        let primary = manager();
+        let now = Instant::now();
+        primary.set_ready(3, 1, 101, true, now).unwrap();
+        primary.set_ready(3, 2, 102, true, now).unwrap();
+        primary.tick(now + Duration::from_secs(5));
+        primary.record_kill(Some(1), 2);
+
+        let checkpoints = primary.checkpoints();
+        assert_eq!(checkpoints.len(), 1);
+        let (room_id, checkpoint) = checkpoints[0].clone();
+
+        // 대기 서버: 참가자는 재접속해 합류, 신규 인원은 거부
+        let standby = manager();
+        assert!(standby.restore(room_id, checkpoint.clone(), now));
+        assert!(!standby.restore(room_id, checkpoint.clone(), now));
+        assert!(standby.set_ready(3, 1, 201, true, now).is_ok());
+        assert_eq!(
+            standby.set_ready(3, 9, 209, true, now).unwrap_err(),
+            LobbyError::MatchInProgress(3)
+        );
+
+        let update = standby.tick(now + Duration::from_secs(60)).pop().unwrap();
+        let LobbyEvent::MatchEnded(result) = &update.events[0] else {
+            panic!("match should end at time limit");
+        };
+        assert_eq!(result.match_id, checkpoint.match_id);
+        assert_eq!(result.winner, Some(Team::Police));
+        assert_eq!(result.players[0].kills, 1);
+    }
+
+    #[test]
+    fn test_room_rules_from_defaults_file() {
+        let rules: GameDefaultsFile =
+            toml::from_str("[room]\nmin_players_to_start = 4\n\n[combat]\ndamage_variance = 0.1\n")
+                .unwrap();
+        assert_eq!(rules.room.min_players_to_start, 4);
+        assert_eq!(rules.room.max_players_per_room, 20);
+    }
+}
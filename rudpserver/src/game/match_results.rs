@@ -0,0 +1,146 @@
+//! 매치 결과 기록
+//!
+//! 매치가 끝나면 결과를 먼저 아웃박스(`MATCH_OUTBOX_DIR`)에 남긴 뒤 MariaDB에 저장하고,
+//! 리더보드용으로 이벤트 버스(`events:match_result`)에 발행합니다.
+//! DB나 Redis가 일시적으로 실패하면 항목이 아웃박스에 남아 재시도 루프에서 다시 전달됩니다.
+//! 저장은 `match_id` 기준으로 멱등이므로 발행만 실패한 경우에도 통째로 재시도합니다.
+
+use shared::config::db::{helpers::map_sqlx_error, DbConfig};
+use shared::service::db::{MatchPlayerRecord, MatchRecord, MatchResultService};
+use shared::service::outbox::{DeliveryError, FlushStats, Outbox};
+use shared::service::redis::event_bus::EventBus;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OnceCell};
+use tracing::{info, warn};
+
+use crate::game::match_manager::MatchResult;
+use crate::game::messages::Team;
+
+/// 매치 결과 기록기
+pub struct MatchResultRecorder {
+    server_id: String,
+    outbox: Outbox<MatchRecord>,
+    /// 첫 전달 시점에 연결 (서버 기동 시 DB가 없어도 매치 진행에는 영향 없음)
+    store: OnceCell<MatchResultService>,
+    event_bus: Option<EventBus>,
+    /// 같은 항목을 동시에 전달하지 않도록 flush 직렬화
+    flush_lock: Mutex<()>,
+}
+
+impl MatchResultRecorder {
+    /// # Arguments
+    /// * `server_id` - 결과에 기록할 게임 서버 식별자 (`host:port`)
+    /// * `outbox_dir` - 아웃박스 디렉토리
+    /// * `event_bus` - 리더보드 이벤트 발행용 (None이면 DB 저장만)
+    pub fn new(
+        server_id: String,
+        outbox_dir: impl Into<PathBuf>,
+        event_bus: Option<EventBus>,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            server_id,
+            outbox: Outbox::open(outbox_dir)?,
+            store: OnceCell::new(),
+            event_bus,
+            flush_lock: Mutex::new(()),
+        })
+    }
+
+    /// 매치 결과를 아웃박스에 남기고 바로 전달 시도
+    pub async fn record(&self, result: &MatchResult) {
+        let record = to_record(result, &self.server_id);
+        if let Err(e) = self.outbox.push(&record.match_id, record.clone()) {
+            warn!(match_id = %record.match_id, error = %e, "Failed to persist match result to outbox");
+        }
+        self.flush().await;
+    }
+
+    /// 아웃박스에 남은 결과 전달
+    pub async fn flush(&self) -> FlushStats {
+        let _guard = self.flush_lock.lock().await;
+        match self.outbox.flush(|record| self.deliver(record)).await {
+            Ok(stats) => {
+                if stats.retrying > 0 || stats.dead > 0 {
+                    warn!(
+                        delivered = stats.delivered,
+                        retrying = stats.retrying,
+                        dead = stats.dead,
+                        "Match result outbox has undelivered entries"
+                    );
+                }
+                stats
+            }
+            Err(e) => {
+                warn!(dir = %self.outbox.dir().display(), error = %e, "Failed to flush match result outbox");
+                FlushStats::default()
+            }
+        }
+    }
+
+    /// 주기적으로 아웃박스 재시도
+    pub async fn run_retry_loop(self: Arc<Self>, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            self.flush().await;
+        }
+    }
+
+    async fn deliver(&self, record: MatchRecord) -> Result<(), DeliveryError> {
+        self.store().await?.save(&record).await?;
+        if let Some(event_bus) = &self.event_bus {
+            event_bus.publish_match_result(&record).await?;
+        }
+        info!(match_id = %record.match_id, room_id = record.room_id, "Match result delivered");
+        Ok(())
+    }
+
+    async fn store(&self) -> Result<&MatchResultService, DeliveryError> {
+        self.store
+            .get_or_try_init(|| async {
+                let db = DbConfig::new()
+                    .await
+                    .map_err(|e| map_sqlx_error(e, "매치 결과 DB 연결"))?;
+                let service = MatchResultService::new(db.pool);
+                service.ensure_schema().await?;
+                Ok(service)
+            })
+            .await
+    }
+}
+
+/// DB 기록 형식으로 변환 (점수는 킬 수)
+fn to_record(result: &MatchResult, server_id: &str) -> MatchRecord {
+    MatchRecord {
+        match_id: result.match_id.clone(),
+        room_id: result.room_id,
+        server_id: server_id.to_string(),
+        started_at: result.started_at,
+        ended_at: result.ended_at,
+        duration_secs: result.duration.as_secs(),
+        end_reason: result.reason.as_str().to_string(),
+        winner_team: result.winner.map(|team| team_name(team).to_string()),
+        players: result
+            .players
+            .iter()
+            .map(|score| MatchPlayerRecord {
+                player_id: score.player_id,
+                team: team_name(score.team).to_string(),
+                score: score.kills as i32,
+                kills: score.kills,
+                deaths: score.deaths,
+                disconnected: score.disconnected,
+            })
+            .collect(),
+    }
+}
+
+fn team_name(team: Team) -> &'static str {
+    match team {
+        Team::Police => "police",
+        Team::Thief => "thief",
+    }
+}
@@ -4,12 +4,13 @@
 //! 연결(Connect), 이동(Move), 공격(Attack), 사망(Die) 등 핵심 게임 액션을 포함
 //!
 //! # 프로토콜 설계 원칙
-//! - **최소 오버헤드**: 바이너리 직렬화 (bincode) 사용
+//! - **최소 오버헤드**: 바이너리 직렬화 (bincode) 사용 - 와이어 포맷은 `protocol` 모듈 참조
 //! - **타입 안전성**: 강타입 열거형으로 메시지 구분
 //! - **버전 호환성**: 향후 확장을 위한 예약 필드 포함
 //! - **검증 가능**: 모든 입력 데이터 유효성 검사 지원
 
 use serde::{Deserialize, Serialize};
+use shared::tool::{ErrorCode, ErrorDomain, GameServerError};
 use std::collections::HashMap;
 use std::time::Duration;
 
@@ -29,7 +30,7 @@ pub type PlayerId = u32;
 ///     auth_token: "abc123".to_string(),
 ///     client_version: "1.0.0".to_string(),
 /// };
-/// let serialized = bincode::serialize(&connect_msg)?;
+/// let serialized = rudpserver::protocol::encode_message(connect_msg)?;
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum GameMessage {
@@ -41,9 +42,9 @@ pub enum GameMessage {
     Connect {
         /// 플레이어 이름 (3-20자, 영문/숫자만)
         player_name: String,
-        /// JWT 인증 토큰
+        /// 인증 토큰 (로그인 응답의 입장 티켓, 티켓 검증이 꺼져 있으면 플레이어 ID)
         auth_token: String,
-        /// 클라이언트 버전 (호환성 검사용)
+        /// 클라이언트 프로토콜 버전 (`1.0.0` 또는 `1.0.0+<기능 hex>`, 접속 시 협상)
         client_version: String,
     },
 
@@ -227,6 +228,151 @@ pub enum GameMessage {
         /// 만료 시간 (옵션)
         expires_at: Option<u64>,
     },
+
+    // === 디버그 메시지 ===
+    /// 히트 판정 디버그 정보
+    ///
+    /// QA 용도로 플래그된 플레이어에게만 전송되는 opt-in 스트림입니다.
+    /// 서버가 판정한 위치, 되감기 윈도우, 히트 테스트 결과를 최근 N개 공격만큼 담습니다.
+    HitRegDebug {
+        /// 공격자 플레이어 ID
+        player_id: PlayerId,
+        /// 최근 공격 판정 기록 (오래된 순)
+        records: Vec<HitRegRecord>,
+    },
+
+    // === 로비/매치 메시지 (프로토콜 1.1) ===
+    /// 준비 상태 변경 요청
+    ///
+    /// 처음 보낸 방의 로비에 참가하며, 연결이 끊기면 로비에서 제거됩니다.
+    SetReady {
+        /// 참가할 방 ID
+        room_id: u32,
+        /// 준비 여부
+        ready: bool,
+    },
+
+    /// 로비 상태
+    ///
+    /// 준비 상태나 인원이 바뀔 때마다 방 전체에 전송됩니다.
+    LobbyState {
+        room_id: u32,
+        /// 현재 매치 단계
+        phase: MatchPhase,
+        /// 참가 순서대로 정렬된 로비 인원
+        members: Vec<LobbyMember>,
+        /// 시작에 필요한 최소 인원
+        min_players: u32,
+        /// 최대 인원
+        max_players: u32,
+    },
+
+    /// 매치 시작 카운트다운 시작
+    MatchCountdown {
+        room_id: u32,
+        /// 매치 시작까지 남은 시간 (밀리초)
+        starts_in_ms: u64,
+    },
+
+    /// 매치 시작 카운트다운 취소
+    MatchCountdownCancelled {
+        room_id: u32,
+        /// 취소 사유
+        reason: String,
+    },
+
+    /// 매치 시작
+    MatchStarted {
+        room_id: u32,
+        /// 참가 플레이어
+        players: Vec<PlayerId>,
+        /// 서버 타임스탬프
+        server_timestamp: u64,
+    },
+
+    /// 매치 종료 및 결과
+    MatchEnded {
+        room_id: u32,
+        reason: MatchEndReason,
+        /// 무승부면 None
+        winner: Option<Team>,
+        duration_secs: u64,
+        players: Vec<MatchScore>,
+    },
+
+    // === 빠른 소통 메시지 (프로토콜 1.3) ===
+    /// 빠른 소통 핑 요청 (핑 휠)
+    ///
+    /// 같은 팀의 관심 영역 안 팀원에게 전달됩니다. 종류는 서버 핑 카탈로그에 있어야 하며
+    /// 플레이어별 전송량이 제한됩니다.
+    QuickPing {
+        /// 핑 종류 ID (`property/pings.toml`)
+        ping_type: String,
+        /// 찍은 위치
+        position: Position,
+    },
+
+    /// 팀원 핑 표시
+    ///
+    /// 문구와 아이콘은 서버 카탈로그에서 채워 보냅니다.
+    QuickPingReceived {
+        /// 핑을 보낸 플레이어 ID
+        player_id: PlayerId,
+        ping_type: String,
+        /// 표시 문구
+        label: String,
+        /// 아이콘 키
+        icon: String,
+        position: Position,
+        /// 표시 유지 시간 (밀리초)
+        expires_in_ms: u64,
+    },
+}
+
+impl GameMessage {
+    /// 공통 에러를 클라이언트 에러 메시지로 변환합니다.
+    ///
+    /// 내부 상세는 보내지 않으며, `recoverable`은 같은 요청을 잠시 후 다시 보내도 되는지를 뜻합니다.
+    pub fn from_error(error: &GameServerError) -> Self {
+        GameMessage::Error {
+            error_code: error.code().as_str().to_string(),
+            error_message: error.user_message(),
+            category: ErrorCategory::of(error.code()),
+            recoverable: error.retryable(),
+        }
+    }
+
+    /// 메시지 타입 문자열을 반환합니다.
+    ///
+    /// 와일드카드 없이 모든 변형을 나열하므로, 새 메시지를 추가하면
+    /// 이 함수와 `requires_reliable_delivery`에서 컴파일 에러가 발생합니다.
+    /// 새 이름은 `protocol::RUDP_PROTOCOL` 레지스트리에도 등록해야 합니다.
+    pub fn message_type_str(&self) -> &'static str {
+        match self {
+            GameMessage::Connect { .. } => "connect",
+            GameMessage::ConnectResponse { .. } => "connect_response",
+            GameMessage::Disconnect { .. } => "disconnect",
+            GameMessage::Move { .. } => "move",
+            GameMessage::MoveUpdate { .. } => "move_update",
+            GameMessage::Attack { .. } => "attack",
+            GameMessage::AttackResult { .. } => "attack_result",
+            GameMessage::Die { .. } => "die",
+            GameMessage::Respawn => "respawn",
+            GameMessage::RespawnComplete { .. } => "respawn_complete",
+            GameMessage::StateUpdate { .. } => "state_update",
+            GameMessage::Error { .. } => "error",
+            GameMessage::ServerNotice { .. } => "server_notice",
+            GameMessage::HitRegDebug { .. } => "hitreg_debug",
+            GameMessage::SetReady { .. } => "set_ready",
+            GameMessage::LobbyState { .. } => "lobby_state",
+            GameMessage::MatchCountdown { .. } => "match_countdown",
+            GameMessage::MatchCountdownCancelled { .. } => "match_countdown_cancelled",
+            GameMessage::MatchStarted { .. } => "match_started",
+            GameMessage::MatchEnded { .. } => "match_ended",
+            GameMessage::QuickPing { .. } => "quick_ping",
+            GameMessage::QuickPingReceived { .. } => "quick_ping_received",
+        }
+    }
 }
 
 // === 데이터 구조체 정의 ===
@@ -430,6 +576,48 @@ pub enum AttackType {
     Skill { skill_id: u32 },
 }
 
+/// 히트 테스트 결과
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HitTestOutcome {
+    /// 명중
+    Hit,
+    /// 사거리 내였으나 빗나감 (회피, 쿨다운 등)
+    Miss,
+    /// 사거리 밖
+    OutOfRange,
+    /// 대상을 찾을 수 없음 (사망, 접속 종료 등)
+    TargetNotFound,
+}
+
+/// 단일 공격의 히트 판정 기록
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HitRegRecord {
+    /// 기록 순번 (서버 전역 증가값)
+    pub sequence: u64,
+    /// 판정 시점의 서버 타임스탬프
+    pub server_timestamp: u64,
+    /// 공격 대상
+    pub target: AttackTarget,
+    /// 공격 타입
+    pub attack_type: AttackType,
+    /// 사용한 무기 ID
+    pub weapon_id: Option<u32>,
+    /// 서버가 판정한 공격자 위치
+    pub attacker_position: Position,
+    /// 서버가 판정한 대상 위치
+    pub target_position: Option<Position>,
+    /// 공격자-대상 거리
+    pub distance: Option<f32>,
+    /// 적용된 최대 사거리
+    pub max_range: f32,
+    /// 지연 보상 되감기 윈도우 (밀리초)
+    pub rewind_window_ms: f32,
+    /// 히트 테스트 결과
+    pub outcome: HitTestOutcome,
+    /// 적용된 데미지
+    pub damage_dealt: u32,
+}
+
 /// 사망 원인 열거형
 ///
 /// 플레이어가 사망한 원인을 분류하여 적절한 페널티와 UI를 적용할 수 있습니다.
@@ -501,6 +689,8 @@ pub enum DisconnectReason {
     NetworkError,
     /// 클라이언트 오류
     ClientError,
+    /// 장시간 입력 없음 (AFK)
+    Afk,
 }
 
 /// 상태 값 열거형 (동적 타입)
@@ -529,6 +719,19 @@ pub enum ErrorCategory {
     System,
 }
 
+impl ErrorCategory {
+    /// 공통 에러 코드의 카테고리
+    pub fn of(code: ErrorCode) -> Self {
+        match (code, code.domain()) {
+            (ErrorCode::PermissionDenied, _) => ErrorCategory::Authorization,
+            (ErrorCode::Unavailable | ErrorCode::Timeout, _) => ErrorCategory::Network,
+            (_, ErrorDomain::Auth) => ErrorCategory::Authentication,
+            (_, ErrorDomain::System) => ErrorCategory::System,
+            _ => ErrorCategory::GameLogic,
+        }
+    }
+}
+
 /// 알림 타입
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum NoticeType {
@@ -553,6 +756,62 @@ pub enum Priority {
     Critical,
 }
 
+/// 팀 구분 (경찰/도둑)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Team {
+    /// 경찰 팀
+    Police,
+    /// 도둑 팀
+    Thief,
+}
+
+/// 매치 단계
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MatchPhase {
+    /// 인원 모집 및 준비 대기
+    Lobby,
+    /// 시작 카운트다운 진행 중
+    Countdown,
+    /// 매치 진행 중
+    InProgress,
+}
+
+/// 로비 참가자
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LobbyMember {
+    pub player_id: PlayerId,
+    pub ready: bool,
+}
+
+/// 매치 종료 사유
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MatchEndReason {
+    /// 제한 시간 종료
+    TimeLimit,
+    /// 모든 참가자 이탈
+    Abandoned,
+}
+
+impl MatchEndReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TimeLimit => "time_limit",
+            Self::Abandoned => "abandoned",
+        }
+    }
+}
+
+/// 매치 참가자 성적
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MatchScore {
+    pub player_id: PlayerId,
+    pub team: Team,
+    pub kills: u32,
+    pub deaths: u32,
+    /// 매치 도중 연결이 끊긴 적이 있는지 여부
+    pub disconnected: bool,
+}
+
 /// 플레이어 상태
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PlayerStatus {
@@ -624,12 +883,23 @@ pub fn requires_reliable_delivery(message: &GameMessage) -> bool {
         | GameMessage::Attack { .. }
         | GameMessage::AttackResult { .. }
         | GameMessage::Die { .. }
-        | GameMessage::Respawn { .. }
+        | GameMessage::Respawn
         | GameMessage::RespawnComplete { .. }
-        | GameMessage::Error { .. } => true,
-
-        GameMessage::Move { .. } | GameMessage::MoveUpdate { .. } => false,
-
-        _ => true, // 기본적으로 신뢰성 요구
+        | GameMessage::Error { .. }
+        | GameMessage::Disconnect { .. }
+        | GameMessage::StateUpdate { .. }
+        | GameMessage::ServerNotice { .. }
+        | GameMessage::SetReady { .. }
+        | GameMessage::LobbyState { .. }
+        | GameMessage::MatchCountdown { .. }
+        | GameMessage::MatchCountdownCancelled { .. }
+        | GameMessage::MatchStarted { .. }
+        | GameMessage::MatchEnded { .. } => true,
+
+        GameMessage::Move { .. }
+        | GameMessage::MoveUpdate { .. }
+        | GameMessage::HitRegDebug { .. }
+        | GameMessage::QuickPing { .. }
+        | GameMessage::QuickPingReceived { .. } => false,
     }
 }
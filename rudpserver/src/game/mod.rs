@@ -4,23 +4,58 @@
 //!
 //! # 모듈 구조
 //! - `messages`: 게임 메시지 프로토콜 정의
+//! - `event_channels`: 방 단위 게임 이벤트 채널
 //! - `state_manager`: 게임 상태 관리 (핵심 로직)
+//! - `timestep`: 고정 타임스텝 누적기 (틱 따라잡기)
+//! - `afk`: 자리 비움 감지 및 단계별 조치
+//! - `hitreg_debug`: 히트 판정 디버그 스트림 (QA용, opt-in)
+//! - `input_buffer`: 플레이어별 이동 입력 버퍼 (클라이언트 틱 → 서버 틱 적용)
+//! - `match_manager`: 방별 로비 준비 상태와 매치 시작 카운트다운
+//! - `match_results`: 매치 결과 DB 저장 및 이벤트 발행 (아웃박스 재시도)
 //! - `player`: 플레이어 엔티티 관리
+//! - `quick_ping`: 팀원 빠른 소통 핑 (property/pings.toml)
+//! - `replication`: 장애 조치용 방 상태 복제 (웜 스탠바이)
+//! - `respawn`: 리스폰 위치 선택 전략
+//! - `spatial`: 관심 영역 조회용 공간 인덱스 (격자 / k-d 트리)
+//! - `weapons`: 무기/공격 데이터 (property/weapons.toml)
 //! - `room_user_manager`: Redis 기반 방별 사용자 정보 관리
 //! - `sample_example`: 새 기능 추가 예시 (스킬 시스템)
 
+pub mod afk;
+pub mod event_channels;
+pub mod hitreg_debug;
+pub mod input_buffer;
+pub mod match_manager;
+pub mod match_results;
 pub mod messages;
 pub mod player;
+pub mod quick_ping;
+pub mod replication;
+pub mod respawn;
 pub mod room_user_manager;
 pub mod sample_example;
 pub mod skill_api;
 pub mod skill_loader;
+pub mod spatial;
 pub mod state_manager;
+pub mod timestep;
+pub mod weapons;
 
 // 주요 타입들을 재export
+pub use event_channels::{RoomEventChannels, RoomEventReceiver, RoomId};
+pub use hitreg_debug::{HitRegDebugConfig, HitRegDebugger};
+pub use input_buffer::{InputBufferConfig, InputBufferStats, InputBuffers};
+pub use match_manager::{LobbyEvent, LobbyUpdate, MatchCheckpoint, MatchManager, MatchResult, RoomRules};
+pub use match_results::MatchResultRecorder;
 pub use messages::{Direction, GameMessage, PlayerId, PlayerState, Position};
 pub use player::{Player, PlayerManager};
+pub use quick_ping::{PingCatalog, QuickPings};
+pub use replication::{ReplicationRole, Replicator, StandbyReplica};
+pub use respawn::{RespawnSelector, RespawnStrategy, SpawnPoint};
 pub use room_user_manager::{RoomUserInfo, RoomUserManager};
 pub use sample_example::{SkillResultMessage, SkillSystem, SkillType, UseSkillMessage};
 pub use skill_loader::SkillLoader;
+pub use spatial::{SpatialIndex, SpatialStrategy};
 pub use state_manager::GameStateManager;
+pub use timestep::{FixedTimestep, TimestepMetrics};
+pub use weapons::WeaponCatalog;
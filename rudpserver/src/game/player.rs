@@ -14,7 +14,7 @@ use tracing::{debug, error, info, warn};
 
 pub use crate::game::messages::PlayerId;
 use crate::game::messages::{
-    AttackTarget, DeathCause, Direction, PlayerState as PlayerStatus, Position, Velocity,
+    AttackTarget, DeathCause, Direction, PlayerState as PlayerStatus, Position, Team, Velocity,
 };
 
 // Player type alias removed - using direct struct
@@ -155,6 +155,8 @@ pub enum PlayerState {
     CastingSkill,
     /// 죽음
     Dead,
+    /// 관전 중 (AFK 등)
+    Spectating,
     /// 스턴 상태
     Stunned,
 }
@@ -196,6 +198,8 @@ pub struct Player {
     pub last_attack: Instant,
     /// 참여 중인 방 ID
     pub room_id: Option<u32>,
+    /// 소속 팀 (팀전이 아니면 None)
+    pub team: Option<Team>,
     /// 시야 범위 (게임 단위)
     pub vision_range: f32,
     /// 공격 범위 (게임 단위)
@@ -228,6 +232,7 @@ impl Player {
             last_update: now,
             last_attack: now - Duration::from_secs(10), // 처음에는 공격 가능
             room_id: None,
+            team: None,
             vision_range: 500.0, // 500 units
             attack_range: 50.0,  // 50 units
             invulnerable_until: None,
@@ -253,6 +258,7 @@ impl Default for Player {
             last_update: now,
             last_attack: now - Duration::from_secs(10),
             room_id: None,
+            team: None,
             vision_range: 500.0,
             attack_range: 50.0,
             invulnerable_until: None,
@@ -525,21 +531,39 @@ pub struct PlayerSummary {
     pub is_online: bool,
 }
 
-/// 플레이어 매니저 (스텁 - 기본 구현)
-pub struct PlayerManager;
+/// 플레이어 매니저
+///
+/// 접속한 플레이어 엔티티를 플레이어 ID로 보관합니다.
+#[derive(Default)]
+pub struct PlayerManager {
+    players: dashmap::DashMap<PlayerId, Player>,
+}
 
 impl PlayerManager {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 
-    pub fn get_player(&self, _player_id: PlayerId) -> Option<Player> {
-        // Stub implementation
-        None
+    /// 플레이어 조회 (복사본)
+    pub fn get_player(&self, player_id: PlayerId) -> Option<Player> {
+        self.players.get(&player_id).map(|player| player.clone())
     }
 
-    pub async fn create_player(&self, session_id: u64, _name: String, _spawn_position: Position) -> Result<PlayerId> {
-        // Stub implementation - generate dummy ID
-        Ok(session_id as PlayerId)
+    /// 인증된 플레이어 ID로 새 플레이어 생성
+    pub async fn create_player(
+        &self,
+        player_id: PlayerId,
+        session_id: u64,
+        name: String,
+        spawn_position: Position,
+    ) -> Result<PlayerId> {
+        self.players
+            .insert(player_id, Player::new(player_id, session_id, name, spawn_position));
+        Ok(player_id)
     }
-}
\ No newline at end of file
+
+    /// 플레이어 제거
+    pub fn remove_player(&self, player_id: PlayerId) -> Option<Player> {
+        self.players.remove(&player_id).map(|(_, player)| player)
+    }
+}
@@ -0,0 +1,438 @@
+//! 빠른 소통 (핑 휠)
+//!
+//! 매치 중 "적 발견", "도움 요청" 같은 짧은 신호를 TCP 채팅 경로 없이 RUDP로 주고받습니다.
+//! 핑 종류별 표시 문구/아이콘과 전달 반경, 전송 제한은 `property/pings.toml`에서 읽어옵니다.
+//!
+//! - 검증: 카탈로그에 있는 종류, 보낸 플레이어로부터 `max_distance` 이내의 위치, 팀 소속
+//! - 제한: 플레이어별 `window_ms` 동안 최대 `burst`개 (초과분은 `RATE_LIMITED` 에러)
+//! - 전달: 같은 방, 같은 팀 중 보낸 플레이어의 관심 영역(`aoi_radius`) 안에 있는 팀원
+//!
+//! # 파일 형식
+//! ```toml
+//! [limits]
+//! burst = 3
+//! window_ms = 5000
+//! aoi_radius = 300.0
+//! max_distance = 200.0
+//!
+//! [[pings]]
+//! id = "enemy_spotted"
+//! label = "적 발견!"
+//! icon = "eye"
+//! ttl_ms = 4000
+//! ```
+
+use anyhow::{anyhow, Context, Result};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use shared::tool::{ErrorCode, GameServerError};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+use crate::game::messages::{GameMessage, PlayerId, Position};
+
+/// 핑 종류 ID 최대 길이 (바이트)
+const MAX_PING_TYPE_LEN: usize = 32;
+
+/// 전송 제한과 전달 범위
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PingLimits {
+    /// 윈도우당 최대 핑 수
+    pub burst: u32,
+    /// 제한 윈도우 (밀리초)
+    pub window_ms: u64,
+    /// 기본 전달 반경 (보낸 플레이어 기준, 게임 단위)
+    pub aoi_radius: f32,
+    /// 보낸 플레이어로부터 찍을 수 있는 최대 거리 (게임 단위)
+    pub max_distance: f32,
+}
+
+impl Default for PingLimits {
+    fn default() -> Self {
+        Self {
+            burst: 3,
+            window_ms: 5000,
+            aoi_radius: 300.0,
+            max_distance: 200.0,
+        }
+    }
+}
+
+/// 핑 정의
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PingDefinition {
+    pub id: String,
+    /// 클라이언트에 표시할 문구
+    pub label: String,
+    /// 클라이언트 아이콘 키
+    pub icon: String,
+    /// 표시 유지 시간 (밀리초)
+    #[serde(default = "default_ttl_ms")]
+    pub ttl_ms: u64,
+    /// 종류별 전달 반경 (없으면 `limits.aoi_radius`)
+    #[serde(default)]
+    pub aoi_radius: Option<f32>,
+}
+
+fn default_ttl_ms() -> u64 {
+    5000
+}
+
+/// 양수인 유한 값 (NaN 거부)
+fn is_positive(value: f32) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+/// 핑 파일 원본 구조
+#[derive(Debug, Deserialize)]
+struct PingsFile {
+    #[serde(default)]
+    limits: PingLimits,
+    #[serde(default)]
+    pings: Vec<PingDefinition>,
+}
+
+/// 핑 카탈로그
+#[derive(Debug, Clone)]
+pub struct PingCatalog {
+    limits: PingLimits,
+    pings: HashMap<String, PingDefinition>,
+}
+
+impl Default for PingCatalog {
+    /// 파일이 없을 때 쓰는 기본 핑 (적 발견, 도움 요청)
+    fn default() -> Self {
+        let pings = [
+            ("enemy_spotted", "적 발견!", "eye"),
+            ("need_help", "도움 필요!", "sos"),
+        ]
+        .into_iter()
+        .map(|(id, label, icon)| {
+            let definition = PingDefinition {
+                id: id.to_string(),
+                label: label.to_string(),
+                icon: icon.to_string(),
+                ttl_ms: default_ttl_ms(),
+                aoi_radius: None,
+            };
+            (id.to_string(), definition)
+        })
+        .collect();
+        Self {
+            limits: PingLimits::default(),
+            pings,
+        }
+    }
+}
+
+impl PingCatalog {
+    /// TOML 파일에서 로드 (파일이 없으면 기본값)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            warn!(path = %path.display(), "Pings file not found, using built-in defaults");
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read pings file: {}", path.display()))?;
+        let catalog = Self::from_toml_str(&contents)
+            .with_context(|| format!("Invalid pings file: {}", path.display()))?;
+
+        info!(path = %path.display(), pings = %catalog.pings.len(), "Pings loaded");
+        Ok(catalog)
+    }
+
+    /// TOML 문자열 파싱 및 검증
+    pub fn from_toml_str(contents: &str) -> Result<Self> {
+        let file: PingsFile = toml::from_str(contents)?;
+        let limits = file.limits;
+        if limits.burst == 0 || limits.window_ms == 0 {
+            return Err(anyhow!("limits: burst and window_ms must be > 0"));
+        }
+        if !is_positive(limits.aoi_radius) || !is_positive(limits.max_distance) {
+            return Err(anyhow!("limits: aoi_radius and max_distance must be > 0"));
+        }
+
+        let mut pings = HashMap::with_capacity(file.pings.len());
+        for ping in file.pings {
+            if ping.id.is_empty() || ping.id.len() > MAX_PING_TYPE_LEN {
+                return Err(anyhow!(
+                    "ping id must be 1..={} bytes: {:?}",
+                    MAX_PING_TYPE_LEN,
+                    ping.id
+                ));
+            }
+            if ping.ttl_ms == 0 {
+                return Err(anyhow!("ping {}: ttl_ms must be > 0", ping.id));
+            }
+            if ping.aoi_radius.is_some_and(|radius| !is_positive(radius)) {
+                return Err(anyhow!("ping {}: aoi_radius must be > 0", ping.id));
+            }
+            let id = ping.id.clone();
+            if pings.insert(id.clone(), ping).is_some() {
+                return Err(anyhow!("duplicate ping id: {}", id));
+            }
+        }
+        if pings.is_empty() {
+            return Err(anyhow!("at least one ping must be defined"));
+        }
+
+        Ok(Self { limits, pings })
+    }
+
+    /// 핑 조회
+    pub fn get(&self, ping_type: &str) -> Option<&PingDefinition> {
+        self.pings.get(ping_type)
+    }
+
+    pub fn limits(&self) -> &PingLimits {
+        &self.limits
+    }
+
+    /// 핑 종류별 전달 반경
+    pub fn aoi_radius(&self, ping: &PingDefinition) -> f32 {
+        ping.aoi_radius.unwrap_or(self.limits.aoi_radius)
+    }
+}
+
+/// 빠른 소통 통계
+#[derive(Debug, Default)]
+pub struct QuickPingStats {
+    /// 팀원에게 전달된 핑 수
+    pub sent: AtomicU64,
+    /// 전송 제한으로 거부된 핑 수
+    pub rate_limited: AtomicU64,
+    /// 검증 실패로 거부된 핑 수
+    pub rejected: AtomicU64,
+    /// 누적 수신자 수
+    pub recipients: AtomicU64,
+}
+
+/// 플레이어별 제한 윈도우
+#[derive(Debug, Clone, Copy)]
+struct PingWindow {
+    started: Instant,
+    count: u32,
+}
+
+/// 빠른 소통 처리기 (검증, 전송 제한, 표시 메시지 생성)
+#[derive(Debug)]
+pub struct QuickPings {
+    catalog: PingCatalog,
+    windows: DashMap<PlayerId, PingWindow>,
+    stats: QuickPingStats,
+}
+
+impl QuickPings {
+    pub fn new(catalog: PingCatalog) -> Self {
+        Self {
+            catalog,
+            windows: DashMap::new(),
+            stats: QuickPingStats::default(),
+        }
+    }
+
+    pub fn catalog(&self) -> &PingCatalog {
+        &self.catalog
+    }
+
+    pub fn stats(&self) -> &QuickPingStats {
+        &self.stats
+    }
+
+    /// 핑 요청 검증 후 정의 반환
+    ///
+    /// 검증에 통과한 요청만 전송 제한 윈도우에 집계합니다.
+    pub fn accept(
+        &self,
+        player_id: PlayerId,
+        ping_type: &str,
+        position: &Position,
+        sender_position: &Position,
+        now: Instant,
+    ) -> Result<&PingDefinition, GameServerError> {
+        let Some(ping) = self.catalog.get(ping_type) else {
+            self.stats.rejected.fetch_add(1, Ordering::Relaxed);
+            return Err(GameServerError::with_detail(
+                ErrorCode::InvalidInput,
+                format!("unknown ping type: {ping_type:.32}"),
+            ));
+        };
+        let finite = position.x.is_finite() && position.y.is_finite() && position.z.is_finite();
+        if !finite || position.distance_to(sender_position) > self.catalog.limits.max_distance {
+            self.stats.rejected.fetch_add(1, Ordering::Relaxed);
+            return Err(GameServerError::with_detail(
+                ErrorCode::InvalidInput,
+                "ping position out of range",
+            ));
+        }
+
+        let window = Duration::from_millis(self.catalog.limits.window_ms);
+        let mut entry = self.windows.entry(player_id).or_insert(PingWindow {
+            started: now,
+            count: 0,
+        });
+        if now.duration_since(entry.started) >= window {
+            *entry = PingWindow {
+                started: now,
+                count: 0,
+            };
+        }
+        if entry.count >= self.catalog.limits.burst {
+            self.stats.rate_limited.fetch_add(1, Ordering::Relaxed);
+            return Err(GameServerError::with_detail(
+                ErrorCode::RateLimited,
+                "too many pings",
+            ));
+        }
+        entry.count += 1;
+        Ok(ping)
+    }
+
+    /// 팀원에게 보낼 표시 메시지
+    pub fn render(
+        &self,
+        player_id: PlayerId,
+        ping: &PingDefinition,
+        position: Position,
+    ) -> GameMessage {
+        GameMessage::QuickPingReceived {
+            player_id,
+            ping_type: ping.id.clone(),
+            label: ping.label.clone(),
+            icon: ping.icon.clone(),
+            position,
+            expires_in_ms: ping.ttl_ms,
+        }
+    }
+
+    /// 전달 결과 집계
+    pub fn record_sent(&self, recipients: usize) {
+        self.stats.sent.fetch_add(1, Ordering::Relaxed);
+        self.stats
+            .recipients
+            .fetch_add(recipients as u64, Ordering::Relaxed);
+    }
+
+    /// 연결 해제된 플레이어의 제한 윈도우 정리
+    pub fn forget(&self, player_id: PlayerId) {
+        self.windows.remove(&player_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+        [limits]
+        burst = 2
+        window_ms = 1000
+        aoi_radius = 100.0
+        max_distance = 50.0
+
+        [[pings]]
+        id = "enemy_spotted"
+        label = "적 발견!"
+        icon = "eye"
+        ttl_ms = 4000
+
+        [[pings]]
+        id = "need_help"
+        label = "도움 필요!"
+        icon = "sos"
+        aoi_radius = 500.0
+    "#;
+
+    #[test]
+    fn test_catalog_parse_and_validation() {
+        let catalog = PingCatalog::from_toml_str(SAMPLE).unwrap();
+        let help = catalog.get("need_help").unwrap();
+        assert_eq!((help.ttl_ms, catalog.aoi_radius(help)), (5000, 500.0));
+        assert_eq!(
+            catalog.aoi_radius(catalog.get("enemy_spotted").unwrap()),
+            100.0
+        );
+
+        let duplicate = "[[pings]]\nid = \"a\"\nlabel = \"A\"\nicon = \"a\"\n[[pings]]\nid = \"a\"\nlabel = \"A\"\nicon = \"a\"\n";
+        assert!(PingCatalog::from_toml_str(duplicate).is_err());
+        assert!(PingCatalog::from_toml_str(
+            "[limits]\nburst = 0\n[[pings]]\nid = \"a\"\nlabel = \"A\"\nicon = \"a\"\n"
+        )
+        .is_err());
+        assert!(PingCatalog::from_toml_str("").is_err());
+    }
+
+    #[test]
+    fn test_accept_validates_and_rate_limits() {
+        let pings = QuickPings::new(PingCatalog::from_toml_str(SAMPLE).unwrap());
+        let sender = Position::new(0.0, 0.0, 0.0);
+        let near = Position::new(30.0, 0.0, 0.0);
+        let now = Instant::now();
+
+        let unknown = pings.accept(1, "dance", &near, &sender, now).unwrap_err();
+        assert_eq!(unknown.code(), ErrorCode::InvalidInput);
+        let far = pings
+            .accept(
+                1,
+                "enemy_spotted",
+                &Position::new(80.0, 0.0, 0.0),
+                &sender,
+                now,
+            )
+            .unwrap_err();
+        assert_eq!(far.code(), ErrorCode::InvalidInput);
+        let nan = Position::new(f32::NAN, 0.0, 0.0);
+        assert!(pings
+            .accept(1, "enemy_spotted", &nan, &sender, now)
+            .is_err());
+
+        assert!(pings
+            .accept(1, "enemy_spotted", &near, &sender, now)
+            .is_ok());
+        assert!(pings.accept(1, "need_help", &near, &sender, now).is_ok());
+        let limited = pings
+            .accept(1, "enemy_spotted", &near, &sender, now)
+            .unwrap_err();
+        assert_eq!(limited.code(), ErrorCode::RateLimited);
+        assert!(pings
+            .accept(2, "enemy_spotted", &near, &sender, now)
+            .is_ok());
+        assert!(pings
+            .accept(
+                1,
+                "enemy_spotted",
+                &near,
+                &sender,
+                now + Duration::from_secs(1)
+            )
+            .is_ok());
+
+        assert_eq!(pings.stats().rejected.load(Ordering::Relaxed), 3);
+        assert_eq!(pings.stats().rate_limited.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_render_uses_catalog() {
+        let pings = QuickPings::new(PingCatalog::default());
+        let ping = pings.catalog().get("need_help").unwrap();
+        let message = pings.render(7, ping, Position::new(1.0, 0.0, 2.0));
+        assert_eq!(
+            message,
+            GameMessage::QuickPingReceived {
+                player_id: 7,
+                ping_type: "need_help".to_string(),
+                label: "도움 필요!".to_string(),
+                icon: "sos".to_string(),
+                position: Position::new(1.0, 0.0, 2.0),
+                expires_in_ms: 5000,
+            }
+        );
+    }
+}
@@ -0,0 +1,654 @@
+//! 장애 조치용 게임 상태 복제 (웜 스탠바이)
+//!
+//! 주 서버는 `REPLICATION_INTERVAL_TICKS`마다 방별 상태(플레이어, 진행 중인 매치)를 모아
+//! 직전에 보낸 상태와 비교한 변경분만 보냅니다. 바뀌지 않은 방과 플레이어는 빠지고,
+//! `REPLICATION_KEYFRAME_EVERY`번마다 전체 상태(키프레임)를 보내 누락을 복구합니다.
+//!
+//! 대기 서버는 변경분을 [`StandbyReplica`]에 적용하며 따라가다가 주 서버의 임대가 만료되면
+//! 상태를 넘겨받습니다. 진행 중인 매치는 그대로 복원되고, 플레이어는 재접속하면 복제된
+//! 위치/체력으로 이어서 플레이합니다.
+//!
+//! 전송은 [`ReplicationSink`]로 추상화되어 있습니다. 운영에서는 Redis 스트림
+//! ([`RedisReplicationSink`])을, 테스트와 장애 조치 훈련에서는 같은 프로세스 안의
+//! [`LocalStandby`]를 씁니다.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use shared::service::redis::state_replica::ReplicaStream;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::game::event_channels::RoomId;
+use crate::game::match_manager::MatchCheckpoint;
+use crate::game::messages::{PlayerId, Position, Team, Velocity};
+use crate::game::player::{Player, PlayerState};
+use crate::game::state_manager::GameStateManager;
+
+/// 구독이 끊겼을 때 재구독 전 대기 시간
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+/// 복제 역할
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReplicationRole {
+    /// 복제하지 않음
+    Off,
+    /// 상태를 복제 스트림으로 보냄
+    Primary,
+    /// 복제 스트림을 따라가다가 주 서버 장애 시 넘겨받음
+    Standby,
+}
+
+impl FromStr for ReplicationRole {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" | "none" | "" => Ok(Self::Off),
+            "primary" => Ok(Self::Primary),
+            "standby" => Ok(Self::Standby),
+            other => Err(anyhow!("Unknown replication role: {}", other)),
+        }
+    }
+}
+
+/// 복제되는 플레이어 상태 (재접속 시 이어서 진행하는 데 필요한 값만)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplicatedPlayer {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub team: Option<Team>,
+    pub position: Position,
+    pub velocity: Velocity,
+    pub rotation: (f32, f32, f32),
+    pub state: PlayerState,
+    pub health: u32,
+    pub mana: u32,
+}
+
+impl ReplicatedPlayer {
+    pub fn from_player(player: &Player) -> Self {
+        Self {
+            player_id: player.id,
+            name: player.name.clone(),
+            team: player.team,
+            position: player.position,
+            velocity: player.velocity,
+            rotation: player.rotation,
+            state: player.state,
+            health: player.stats.current_health,
+            mana: player.stats.current_mana,
+        }
+    }
+
+    /// 재접속한 플레이어에 복제된 상태 적용
+    pub fn apply_to(&self, player: &mut Player, room_id: RoomId) {
+        player.room_id = Some(room_id);
+        player.team = self.team;
+        player.position = self.position;
+        player.velocity = self.velocity;
+        player.rotation = self.rotation;
+        player.state = self.state;
+        player.stats.current_health = self.health.min(player.stats.max_health);
+        player.stats.current_mana = self.mana.min(player.stats.max_mana);
+    }
+}
+
+/// 방 하나의 복제 상태
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomState {
+    pub room_id: RoomId,
+    /// 플레이어 ID 순
+    pub players: Vec<ReplicatedPlayer>,
+    /// 진행 중인 매치 (로비/카운트다운 중이면 None)
+    pub active_match: Option<MatchCheckpoint>,
+}
+
+impl RoomState {
+    pub fn new(room_id: RoomId) -> Self {
+        Self {
+            room_id,
+            players: Vec::new(),
+            active_match: None,
+        }
+    }
+}
+
+/// 방 하나의 변경분
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoomDelta {
+    pub room_id: RoomId,
+    /// 새로 들어오거나 바뀐 플레이어
+    pub upserts: Vec<ReplicatedPlayer>,
+    /// 방을 떠난 플레이어
+    pub removed: Vec<PlayerId>,
+    /// 진행 중인 매치 (변경분에도 항상 전체를 담음)
+    pub active_match: Option<MatchCheckpoint>,
+}
+
+/// 복제 주기 하나에 보내는 묶음
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplicationBatch {
+    /// 보낸 서버 (임대 보유자)
+    pub source: String,
+    /// 보낸 서버 안에서 1씩 증가
+    pub seq: u64,
+    /// 보낼 때의 서버 틱
+    pub tick: u64,
+    /// 전체 상태면 true (대기 서버는 기존 상태를 버리고 교체)
+    pub keyframe: bool,
+    /// 바뀐 방 (키프레임이면 전체 방)
+    pub rooms: Vec<RoomDelta>,
+    /// 사라진 방
+    pub removed_rooms: Vec<RoomId>,
+}
+
+/// 주 서버 쪽 변경분 계산기
+pub struct Replicator {
+    source: String,
+    keyframe_every: u32,
+    seq: u64,
+    since_keyframe: u32,
+    keyframe_requested: bool,
+    last_sent: HashMap<RoomId, RoomState>,
+}
+
+impl Replicator {
+    /// # Arguments
+    /// * `source` - 이 서버의 식별자 (임대 보유자 이름으로도 사용)
+    /// * `keyframe_every` - 키프레임 간격 (복제 주기 수)
+    pub fn new(source: impl Into<String>, keyframe_every: u32) -> Self {
+        Self {
+            source: source.into(),
+            keyframe_every: keyframe_every.max(1),
+            seq: 0,
+            since_keyframe: 0,
+            keyframe_requested: true,
+            last_sent: HashMap::new(),
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// 다음 묶음을 키프레임으로 (전송 실패 후 대기 서버가 놓쳤을 수 있을 때)
+    pub fn request_keyframe(&mut self) {
+        self.keyframe_requested = true;
+    }
+
+    /// 현재 상태로 다음 묶음 생성
+    ///
+    /// 바뀐 것이 없어도 빈 묶음을 만들어 대기 서버가 순번 누락을 알 수 있게 합니다.
+    pub fn next_batch(&mut self, tick: u64, rooms: Vec<RoomState>) -> ReplicationBatch {
+        let keyframe = self.keyframe_requested || self.since_keyframe + 1 >= self.keyframe_every;
+        let current: HashMap<RoomId, RoomState> =
+            rooms.into_iter().map(|room| (room.room_id, room)).collect();
+
+        let (mut deltas, mut removed_rooms) = if keyframe {
+            (
+                current.values().map(full_delta).collect::<Vec<_>>(),
+                Vec::new(),
+            )
+        } else {
+            let deltas = current
+                .values()
+                .filter_map(|room| diff_room(self.last_sent.get(&room.room_id), room))
+                .collect();
+            let removed = self
+                .last_sent
+                .keys()
+                .filter(|room_id| !current.contains_key(room_id))
+                .copied()
+                .collect();
+            (deltas, removed)
+        };
+        deltas.sort_by_key(|delta: &RoomDelta| delta.room_id);
+        removed_rooms.sort_unstable();
+
+        self.seq += 1;
+        self.since_keyframe = if keyframe { 0 } else { self.since_keyframe + 1 };
+        self.keyframe_requested = false;
+        self.last_sent = current;
+
+        ReplicationBatch {
+            source: self.source.clone(),
+            seq: self.seq,
+            tick,
+            keyframe,
+            rooms: deltas,
+            removed_rooms,
+        }
+    }
+}
+
+fn full_delta(room: &RoomState) -> RoomDelta {
+    RoomDelta {
+        room_id: room.room_id,
+        upserts: room.players.clone(),
+        removed: Vec::new(),
+        active_match: room.active_match.clone(),
+    }
+}
+
+/// 직전 상태와 비교한 방 변경분 (바뀐 것이 없으면 None)
+fn diff_room(previous: Option<&RoomState>, current: &RoomState) -> Option<RoomDelta> {
+    let Some(previous) = previous else {
+        return Some(full_delta(current));
+    };
+    let before: HashMap<PlayerId, &ReplicatedPlayer> = previous
+        .players
+        .iter()
+        .map(|player| (player.player_id, player))
+        .collect();
+
+    let upserts: Vec<ReplicatedPlayer> = current
+        .players
+        .iter()
+        .filter(|player| before.get(&player.player_id) != Some(player))
+        .cloned()
+        .collect();
+    let removed: Vec<PlayerId> = previous
+        .players
+        .iter()
+        .map(|player| player.player_id)
+        .filter(|id| !current.players.iter().any(|player| player.player_id == *id))
+        .collect();
+
+    if upserts.is_empty() && removed.is_empty() && previous.active_match == current.active_match {
+        return None;
+    }
+    Some(RoomDelta {
+        room_id: current.room_id,
+        upserts,
+        removed,
+        active_match: current.active_match.clone(),
+    })
+}
+
+/// 대기 서버가 묶음을 적용한 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyOutcome {
+    Applied,
+    /// 이미 적용했거나 더 오래된 묶음
+    Stale,
+    /// 순번이 비어 다음 키프레임까지 변경분을 버림
+    AwaitingKeyframe,
+}
+
+/// 대기 서버의 복제본
+#[derive(Debug, Default)]
+pub struct StandbyReplica {
+    rooms: HashMap<RoomId, RoomState>,
+    /// 마지막으로 적용한 묶음의 (보낸 서버, 순번)
+    last_applied: Option<(String, u64)>,
+    synced: bool,
+    last_tick: u64,
+    gaps: u64,
+}
+
+impl StandbyReplica {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 묶음 적용
+    pub fn apply(&mut self, batch: &ReplicationBatch) -> ApplyOutcome {
+        let same_source =
+            matches!(&self.last_applied, Some((source, _)) if *source == batch.source);
+        let last_seq = self.last_applied.as_ref().map_or(0, |(_, seq)| *seq);
+        if same_source && batch.seq <= last_seq {
+            return ApplyOutcome::Stale;
+        }
+
+        if batch.keyframe {
+            self.rooms = batch
+                .rooms
+                .iter()
+                .map(|delta| {
+                    let mut room = RoomState::new(delta.room_id);
+                    apply_delta(&mut room, delta);
+                    (delta.room_id, room)
+                })
+                .collect();
+            self.synced = true;
+        } else if !self.synced {
+            return ApplyOutcome::AwaitingKeyframe;
+        } else if !same_source || batch.seq != last_seq + 1 {
+            warn!(
+                source = %batch.source,
+                seq = batch.seq,
+                last_seq,
+                "Replication gap detected, waiting for keyframe"
+            );
+            self.synced = false;
+            self.gaps += 1;
+            return ApplyOutcome::AwaitingKeyframe;
+        } else {
+            for room_id in &batch.removed_rooms {
+                self.rooms.remove(room_id);
+            }
+            for delta in &batch.rooms {
+                let room = self
+                    .rooms
+                    .entry(delta.room_id)
+                    .or_insert_with(|| RoomState::new(delta.room_id));
+                apply_delta(room, delta);
+            }
+        }
+
+        self.last_applied = Some((batch.source.clone(), batch.seq));
+        self.last_tick = batch.tick;
+        ApplyOutcome::Applied
+    }
+
+    /// 키프레임 이후 순번 누락 없이 따라가는 중인지
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /// 마지막으로 적용한 묶음의 서버 틱
+    pub fn last_tick(&self) -> u64 {
+        self.last_tick
+    }
+
+    /// 감지한 순번 누락 횟수
+    pub fn gaps(&self) -> u64 {
+        self.gaps
+    }
+
+    /// 복제된 방 상태 (방 ID 순)
+    pub fn rooms(&self) -> Vec<RoomState> {
+        let mut rooms: Vec<RoomState> = self.rooms.values().cloned().collect();
+        rooms.sort_by_key(|room| room.room_id);
+        rooms
+    }
+}
+
+fn apply_delta(room: &mut RoomState, delta: &RoomDelta) {
+    room.players
+        .retain(|player| !delta.removed.contains(&player.player_id));
+    for upsert in &delta.upserts {
+        match room
+            .players
+            .iter_mut()
+            .find(|player| player.player_id == upsert.player_id)
+        {
+            Some(player) => *player = upsert.clone(),
+            None => room.players.push(upsert.clone()),
+        }
+    }
+    room.players.sort_by_key(|player| player.player_id);
+    room.active_match = delta.active_match.clone();
+}
+
+/// 복제 묶음 전송
+#[async_trait::async_trait]
+pub trait ReplicationSink: Send + Sync {
+    async fn send(&self, batch: &ReplicationBatch) -> Result<()>;
+}
+
+/// Redis 복제 스트림 전송
+///
+/// 보낼 때마다 임대를 연장하고, 다른 서버가 임대를 가져갔으면 보내지 않습니다.
+/// (장애 조치 뒤 되살아난 옛 주 서버가 새 주 서버의 상태를 덮어쓰지 않도록)
+pub struct RedisReplicationSink {
+    stream: ReplicaStream,
+    lease_ttl: Duration,
+}
+
+impl RedisReplicationSink {
+    pub fn new(stream: ReplicaStream, lease_ttl: Duration) -> Self {
+        Self { stream, lease_ttl }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReplicationSink for RedisReplicationSink {
+    async fn send(&self, batch: &ReplicationBatch) -> Result<()> {
+        if !self
+            .stream
+            .claim_lease(&batch.source, self.lease_ttl)
+            .await?
+        {
+            let holder = self.stream.lease_holder().await.ok().flatten();
+            return Err(anyhow!("Replication lease held by {:?}", holder));
+        }
+        if batch.keyframe {
+            self.stream.store_keyframe(batch).await?;
+        }
+        self.stream.publish(batch).await?;
+        Ok(())
+    }
+}
+
+/// 같은 프로세스 안의 대기 복제본 (테스트, 장애 조치 훈련)
+#[derive(Debug, Default)]
+pub struct LocalStandby {
+    replica: parking_lot::Mutex<StandbyReplica>,
+}
+
+impl LocalStandby {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn replica(&self) -> parking_lot::MutexGuard<'_, StandbyReplica> {
+        self.replica.lock()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReplicationSink for LocalStandby {
+    async fn send(&self, batch: &ReplicationBatch) -> Result<()> {
+        self.replica.lock().apply(batch);
+        Ok(())
+    }
+}
+
+/// 주 서버 복제 루프
+///
+/// `interval_ticks` 틱마다(현재 틱 레이트 기준) 상태를 모아 보냅니다.
+/// 전송에 실패하면 대기 서버가 놓쳤을 수 있으므로 다음 묶음을 키프레임으로 보냅니다.
+pub async fn run_primary(
+    game_state: Arc<GameStateManager>,
+    mut replicator: Replicator,
+    sink: Arc<dyn ReplicationSink>,
+    interval_ticks: u64,
+) {
+    info!(source = %replicator.source(), interval_ticks, "Replication started (primary)");
+    loop {
+        let tick_rate = game_state.tick_rate().max(1);
+        tokio::time::sleep(Duration::from_secs_f64(
+            interval_ticks.max(1) as f64 / tick_rate as f64,
+        ))
+        .await;
+
+        let rooms = game_state.replication_snapshot().await;
+        let batch = replicator.next_batch(game_state.current_tick(), rooms);
+        match sink.send(&batch).await {
+            Ok(()) => debug!(
+                seq = batch.seq,
+                rooms = batch.rooms.len(),
+                keyframe = batch.keyframe,
+                "Replication batch sent"
+            ),
+            Err(e) => {
+                warn!(seq = batch.seq, error = %e, "Replication batch failed, next batch is a keyframe");
+                replicator.request_keyframe();
+            }
+        }
+    }
+}
+
+/// 대기 서버: 복제 스트림을 따라가다가 주 서버의 임대가 만료되면 임대를 차지하고 복제본 반환
+///
+/// 임대는 `lease_ttl`의 절반마다 확인합니다. 주 서버가 한 번도 없었던 경우에도 임대가 비어 있으므로
+/// 곧바로 넘겨받아 빈 상태로 시작합니다.
+pub async fn follow_until_failover(
+    stream: ReplicaStream,
+    holder: &str,
+    lease_ttl: Duration,
+) -> StandbyReplica {
+    let mut replica = StandbyReplica::new();
+    let mut lease_check = tokio::time::interval(lease_ttl / 2);
+    info!(channel = %stream.channel(), "Replication following (standby)");
+    loop {
+        let mut batches = match stream.subscribe::<ReplicationBatch>().await {
+            Ok(batches) => batches,
+            Err(e) => {
+                warn!(error = %e, "Replication subscribe failed");
+                tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+                continue;
+            }
+        };
+        // 구독 뒤에 키프레임을 읽어 구독 전 상태를 놓치지 않음
+        match stream.load_keyframe::<ReplicationBatch>().await {
+            Ok(Some(keyframe)) => {
+                replica.apply(&keyframe);
+            }
+            Ok(None) => {}
+            Err(e) => warn!(error = %e, "Replication keyframe load failed"),
+        }
+
+        loop {
+            tokio::select! {
+                batch = batches.recv() => match batch {
+                    Some(batch) => {
+                        replica.apply(&batch);
+                    }
+                    None => break,
+                },
+                _ = lease_check.tick() => match stream.claim_lease(holder, lease_ttl).await {
+                    Ok(true) => {
+                        info!(
+                            rooms = replica.rooms.len(),
+                            last_tick = replica.last_tick(),
+                            synced = replica.is_synced(),
+                            "Primary lease expired, taking over"
+                        );
+                        return replica;
+                    }
+                    Ok(false) => {}
+                    Err(e) => warn!(error = %e, "Replication lease check failed"),
+                },
+            }
+        }
+        warn!("Replication subscription closed, resubscribing");
+        tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(player_id: PlayerId, x: f32) -> ReplicatedPlayer {
+        ReplicatedPlayer {
+            player_id,
+            name: format!("player{player_id}"),
+            team: None,
+            position: Position::new(x, 0.0, 0.0),
+            velocity: Velocity::default(),
+            rotation: (0.0, 0.0, 0.0),
+            state: PlayerState::Idle,
+            health: 100,
+            mana: 50,
+        }
+    }
+
+    fn room(room_id: RoomId, players: Vec<ReplicatedPlayer>) -> RoomState {
+        RoomState {
+            room_id,
+            players,
+            active_match: None,
+        }
+    }
+
+    #[test]
+    fn test_unchanged_rooms_and_players_are_compacted_out() {
+        let mut replicator = Replicator::new("primary", 100);
+        let first = replicator.next_batch(
+            30,
+            vec![
+                room(1, vec![player(1, 0.0), player(2, 0.0)]),
+                room(2, vec![player(3, 0.0)]),
+            ],
+        );
+        assert!(first.keyframe);
+        assert_eq!(first.rooms.len(), 2);
+
+        let second = replicator.next_batch(
+            60,
+            vec![
+                room(1, vec![player(1, 5.0), player(2, 0.0)]),
+                room(2, vec![player(3, 0.0)]),
+            ],
+        );
+        assert!(!second.keyframe);
+        assert_eq!(second.rooms.len(), 1);
+        assert_eq!(second.rooms[0].upserts, vec![player(1, 5.0)]);
+
+        let third = replicator.next_batch(90, vec![room(1, vec![player(1, 5.0)])]);
+        assert_eq!(third.rooms[0].removed, vec![2]);
+        assert_eq!(third.removed_rooms, vec![2]);
+    }
+
+    #[test]
+    fn test_keyframe_interval() {
+        let mut replicator = Replicator::new("primary", 3);
+        let batches: Vec<bool> = (1..=7)
+            .map(|tick| {
+                replicator
+                    .next_batch(tick, vec![room(1, vec![player(1, tick as f32)])])
+                    .keyframe
+            })
+            .collect();
+        assert_eq!(batches, vec![true, false, false, true, false, false, true]);
+    }
+
+    #[test]
+    fn test_standby_waits_for_keyframe_after_gap() {
+        let mut replicator = Replicator::new("primary", 10);
+        let mut standby = StandbyReplica::new();
+
+        let keyframe = replicator.next_batch(1, vec![room(1, vec![player(1, 0.0)])]);
+        assert_eq!(standby.apply(&keyframe), ApplyOutcome::Applied);
+        assert_eq!(standby.apply(&keyframe), ApplyOutcome::Stale);
+
+        let _lost = replicator.next_batch(2, vec![room(1, vec![player(1, 1.0)])]);
+        let delta = replicator.next_batch(3, vec![room(1, vec![player(1, 2.0)])]);
+        assert_eq!(standby.apply(&delta), ApplyOutcome::AwaitingKeyframe);
+        assert_eq!(standby.gaps(), 1);
+
+        replicator.request_keyframe();
+        let keyframe = replicator.next_batch(4, vec![room(1, vec![player(1, 3.0)])]);
+        assert_eq!(standby.apply(&keyframe), ApplyOutcome::Applied);
+        assert!(standby.is_synced());
+        assert_eq!(standby.rooms(), vec![room(1, vec![player(1, 3.0)])]);
+    }
+
+    #[test]
+    fn test_batch_roundtrips_through_json() {
+        let mut replicator = Replicator::new("primary", 10);
+        let batch = replicator.next_batch(1, vec![room(4, vec![player(7, 1.5)])]);
+        let decoded: ReplicationBatch =
+            serde_json::from_str(&serde_json::to_string(&batch).unwrap()).unwrap();
+        assert_eq!(decoded, batch);
+    }
+
+    #[test]
+    fn test_replication_role_from_str() {
+        assert_eq!(
+            "Standby".parse::<ReplicationRole>().unwrap(),
+            ReplicationRole::Standby
+        );
+        assert_eq!(
+            "off".parse::<ReplicationRole>().unwrap(),
+            ReplicationRole::Off
+        );
+        assert!("leader".parse::<ReplicationRole>().is_err());
+    }
+}
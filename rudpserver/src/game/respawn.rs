@@ -0,0 +1,378 @@
+//! 리스폰 위치 선택
+//!
+//! 사망한 플레이어가 어디에서 부활할지 결정하는 전략들을 제공합니다.
+//! 전략은 `GameConfig::respawn_strategy`로 선택하며, 스폰 포인트는 팀 전용 또는 공용으로 지정할 수 있습니다.
+//!
+//! # 전략
+//! - `MapCenter`: 맵 중앙 (기존 동작)
+//! - `NearestSafePoint`: 사망 위치에서 가장 가까운, 주변에 적이 없는 스폰 포인트
+//! - `TeamBase`: 자기 팀 기지 스폰 포인트 중 가장 안전한 곳
+//! - `Random`: 사용 가능한 스폰 포인트 중 무작위
+//! - `AvoidEnemies`: 가장 가까운 적과의 거리가 최대인 스폰 포인트
+//!
+//! # 스폰 포인트 파일 형식
+//! 시작 시 `property/spawn_points.toml`(SPAWN_POINTS_FILE)에서 읽어옵니다. `team`을 생략하면 공용입니다.
+//! ```toml
+//! [[spawn_points]]
+//! position = { x = 500.0, y = 0.0, z = 500.0 }
+//! team = "Police"
+//! ```
+
+use anyhow::{anyhow, Context, Result};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use tracing::{info, warn};
+
+use crate::game::messages::{Position, Team};
+
+/// 리스폰 위치 선택 전략
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RespawnStrategy {
+    /// 맵 중앙
+    MapCenter,
+    /// 가장 가까운 안전 지점
+    NearestSafePoint,
+    /// 팀 기지
+    TeamBase,
+    /// 스폰 포인트 중 무작위
+    Random,
+    /// 적과 가장 먼 지점
+    AvoidEnemies,
+}
+
+impl FromStr for RespawnStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "map_center" | "center" => Ok(Self::MapCenter),
+            "nearest_safe" | "nearest_safe_point" => Ok(Self::NearestSafePoint),
+            "team_base" => Ok(Self::TeamBase),
+            "random" => Ok(Self::Random),
+            "avoid_enemies" => Ok(Self::AvoidEnemies),
+            other => Err(anyhow::anyhow!("Unknown respawn strategy: {}", other)),
+        }
+    }
+}
+
+/// 스폰 포인트
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpawnPoint {
+    /// 스폰 위치
+    pub position: Position,
+    /// 전용 팀 (None이면 모든 팀이 사용 가능)
+    pub team: Option<Team>,
+}
+
+impl SpawnPoint {
+    pub fn new(position: Position, team: Option<Team>) -> Self {
+        Self { position, team }
+    }
+
+    /// 해당 팀이 사용할 수 있는 포인트인지 여부
+    fn usable_by(&self, team: Option<Team>) -> bool {
+        match (self.team, team) {
+            (None, _) => true,
+            (Some(owner), Some(team)) => owner == team,
+            (Some(_), None) => false,
+        }
+    }
+}
+
+/// 스폰 포인트 파일 원본 구조
+#[derive(Debug, Deserialize)]
+struct SpawnPointsFile {
+    #[serde(default)]
+    spawn_points: Vec<SpawnPoint>,
+}
+
+/// TOML 파일에서 스폰 포인트 로드 (파일이 없으면 빈 목록 - 맵 중앙으로 폴백)
+pub fn load_spawn_points<P: AsRef<Path>>(path: P) -> Result<Vec<SpawnPoint>> {
+    let path = path.as_ref();
+    if !path.exists() {
+        warn!(path = %path.display(), "Spawn points file not found, respawning at map center");
+        return Ok(Vec::new());
+    }
+
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read spawn points file: {}", path.display()))?;
+    let spawn_points = spawn_points_from_toml_str(&contents)
+        .with_context(|| format!("Invalid spawn points file: {}", path.display()))?;
+
+    info!(path = %path.display(), spawn_points = %spawn_points.len(), "Spawn points loaded");
+    Ok(spawn_points)
+}
+
+/// TOML 문자열 파싱 및 검증
+pub fn spawn_points_from_toml_str(contents: &str) -> Result<Vec<SpawnPoint>> {
+    let file: SpawnPointsFile = toml::from_str(contents)?;
+    for (index, point) in file.spawn_points.iter().enumerate() {
+        let Position { x, y, z } = point.position;
+        if !(x.is_finite() && y.is_finite() && z.is_finite()) {
+            return Err(anyhow!("spawn point {}: position must be finite", index));
+        }
+    }
+    Ok(file.spawn_points)
+}
+
+/// 리스폰 선택 설정
+#[derive(Debug, Clone)]
+pub struct RespawnConfig {
+    /// 사용할 전략
+    pub strategy: RespawnStrategy,
+    /// 맵에 배치된 스폰 포인트들
+    pub spawn_points: Vec<SpawnPoint>,
+    /// 이 반경 안에 적이 있으면 안전하지 않은 지점으로 간주
+    pub enemy_avoid_radius: f32,
+    /// 맵 중앙 (스폰 포인트가 없을 때의 폴백)
+    pub map_center: Position,
+}
+
+impl Default for RespawnConfig {
+    fn default() -> Self {
+        Self {
+            strategy: RespawnStrategy::MapCenter,
+            spawn_points: Vec::new(),
+            enemy_avoid_radius: 30.0,
+            map_center: Position::new(5000.0 / 2.0, 0.0, 5000.0 / 2.0),
+        }
+    }
+}
+
+/// 리스폰 선택 시 필요한 상황 정보
+#[derive(Debug, Clone, Copy)]
+pub struct RespawnContext<'a> {
+    /// 리스폰하는 플레이어의 팀
+    pub team: Option<Team>,
+    /// 사망 위치
+    pub death_position: Position,
+    /// 생존한 적 플레이어들의 위치
+    pub enemy_positions: &'a [Position],
+}
+
+/// 리스폰 위치 선택기
+#[derive(Debug, Clone)]
+pub struct RespawnSelector {
+    config: RespawnConfig,
+}
+
+impl RespawnSelector {
+    pub fn new(config: RespawnConfig) -> Self {
+        Self { config }
+    }
+
+    /// 현재 전략
+    pub fn strategy(&self) -> RespawnStrategy {
+        self.config.strategy
+    }
+
+    /// 스폰 포인트 교체 (맵 로드 시)
+    pub fn set_spawn_points(&mut self, spawn_points: Vec<SpawnPoint>) {
+        self.config.spawn_points = spawn_points;
+    }
+
+    /// 설정된 전략으로 리스폰 위치 선택
+    ///
+    /// 조건을 만족하는 스폰 포인트가 없으면 더 느슨한 조건으로, 최종적으로는 맵 중앙으로 폴백합니다.
+    pub fn select<R: Rng + ?Sized>(&self, context: &RespawnContext<'_>, rng: &mut R) -> Position {
+        let candidates: Vec<&SpawnPoint> = self
+            .config
+            .spawn_points
+            .iter()
+            .filter(|point| point.usable_by(context.team))
+            .collect();
+
+        let selected = match self.config.strategy {
+            RespawnStrategy::MapCenter => None,
+            RespawnStrategy::NearestSafePoint => {
+                let safe = self.safe_points(&candidates, context);
+                nearest_to(&safe, context.death_position)
+                    .or_else(|| nearest_to(&candidates, context.death_position))
+            }
+            RespawnStrategy::TeamBase => {
+                let team_points: Vec<&SpawnPoint> = candidates
+                    .iter()
+                    .copied()
+                    .filter(|point| point.team.is_some() && point.team == context.team)
+                    .collect();
+                let safe = self.safe_points(&team_points, context);
+                nearest_to(&safe, context.death_position)
+                    .or_else(|| farthest_from_enemies(&team_points, context.enemy_positions))
+            }
+            RespawnStrategy::Random => {
+                if candidates.is_empty() {
+                    None
+                } else {
+                    Some(candidates[rng.gen_range(0..candidates.len())].position)
+                }
+            }
+            RespawnStrategy::AvoidEnemies => {
+                farthest_from_enemies(&candidates, context.enemy_positions)
+            }
+        };
+
+        selected.unwrap_or(self.config.map_center)
+    }
+
+    /// 반경 내에 적이 없는 포인트만 필터링
+    fn safe_points<'a>(
+        &self,
+        points: &[&'a SpawnPoint],
+        context: &RespawnContext<'_>,
+    ) -> Vec<&'a SpawnPoint> {
+        points
+            .iter()
+            .copied()
+            .filter(|point| {
+                context
+                    .enemy_positions
+                    .iter()
+                    .all(|enemy| point.position.distance_to(enemy) > self.config.enemy_avoid_radius)
+            })
+            .collect()
+    }
+}
+
+/// 기준 위치에서 가장 가까운 포인트
+fn nearest_to(points: &[&SpawnPoint], origin: Position) -> Option<Position> {
+    points
+        .iter()
+        .min_by(|a, b| {
+            a.position
+                .distance_to(&origin)
+                .total_cmp(&b.position.distance_to(&origin))
+        })
+        .map(|point| point.position)
+}
+
+/// 가장 가까운 적과의 거리가 최대인 포인트
+fn farthest_from_enemies(points: &[&SpawnPoint], enemies: &[Position]) -> Option<Position> {
+    let min_enemy_distance = |point: &SpawnPoint| {
+        enemies
+            .iter()
+            .map(|enemy| point.position.distance_to(enemy))
+            .fold(f32::INFINITY, f32::min)
+    };
+
+    points
+        .iter()
+        .max_by(|a, b| min_enemy_distance(a).total_cmp(&min_enemy_distance(b)))
+        .map(|point| point.position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn selector(strategy: RespawnStrategy) -> RespawnSelector {
+        RespawnSelector::new(RespawnConfig {
+            strategy,
+            spawn_points: vec![
+                SpawnPoint::new(Position::new(0.0, 0.0, 0.0), None),
+                SpawnPoint::new(Position::new(100.0, 0.0, 0.0), None),
+                SpawnPoint::new(Position::new(-200.0, 0.0, 0.0), Some(Team::Police)),
+                SpawnPoint::new(Position::new(200.0, 0.0, 0.0), Some(Team::Thief)),
+            ],
+            enemy_avoid_radius: 30.0,
+            map_center: Position::new(50.0, 0.0, 50.0),
+        })
+    }
+
+    fn context(team: Option<Team>, death: Position, enemies: &[Position]) -> RespawnContext<'_> {
+        RespawnContext {
+            team,
+            death_position: death,
+            enemy_positions: enemies,
+        }
+    }
+
+    #[test]
+    fn test_map_center() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let position = selector(RespawnStrategy::MapCenter)
+            .select(&context(None, Position::default(), &[]), &mut rng);
+        assert_eq!(position, Position::new(50.0, 0.0, 50.0));
+    }
+
+    #[test]
+    fn test_nearest_safe_point_skips_camped_spawn() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let enemies = [Position::new(5.0, 0.0, 0.0)];
+        let position = selector(RespawnStrategy::NearestSafePoint).select(
+            &context(None, Position::new(10.0, 0.0, 0.0), &enemies),
+            &mut rng,
+        );
+        assert_eq!(position, Position::new(100.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_team_base_uses_own_team_points() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let position = selector(RespawnStrategy::TeamBase).select(
+            &context(Some(Team::Thief), Position::new(-150.0, 0.0, 0.0), &[]),
+            &mut rng,
+        );
+        assert_eq!(position, Position::new(200.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_random_never_picks_other_team_base() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let selector = selector(RespawnStrategy::Random);
+        for _ in 0..50 {
+            let position = selector.select(
+                &context(Some(Team::Police), Position::default(), &[]),
+                &mut rng,
+            );
+            assert_ne!(position, Position::new(200.0, 0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_avoid_enemies_maximizes_distance() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let enemies = [Position::new(90.0, 0.0, 0.0)];
+        let position = selector(RespawnStrategy::AvoidEnemies).select(
+            &context(Some(Team::Police), Position::default(), &enemies),
+            &mut rng,
+        );
+        assert_eq!(position, Position::new(-200.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_spawn_points_from_toml() {
+        let points = spawn_points_from_toml_str(
+            r#"
+            [[spawn_points]]
+            position = { x = 10.0, y = 0.0, z = 20.0 }
+            team = "Police"
+
+            [[spawn_points]]
+            position = { x = 30.0, y = 0.0, z = 40.0 }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            points,
+            vec![
+                SpawnPoint::new(Position::new(10.0, 0.0, 20.0), Some(Team::Police)),
+                SpawnPoint::new(Position::new(30.0, 0.0, 40.0), None),
+            ]
+        );
+        assert!(spawn_points_from_toml_str("[[spawn_points]]\nposition = { x = 1.0 }\n").is_err());
+    }
+
+    #[test]
+    fn test_strategy_from_str() {
+        assert_eq!(
+            "team_base".parse::<RespawnStrategy>().unwrap(),
+            RespawnStrategy::TeamBase
+        );
+        assert!("teleport".parse::<RespawnStrategy>().is_err());
+    }
+}
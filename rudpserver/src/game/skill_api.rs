@@ -376,6 +376,7 @@ mod tests {
             enable_key_compression: false,
             enable_value_compression: false,
             default_ttl_secs: 3600,
+            ..Default::default()
         };
 
         let redis_optimizer = Arc::new(
@@ -0,0 +1,275 @@
+//! 공간 인덱스 (관심 영역 조회용)
+//!
+//! 매 틱 플레이어 위치로 인덱스를 다시 만들고, 반경 안의 플레이어를 조회합니다.
+//! 거리는 수평면(X/Z) 기준이며, 높이(Y)는 무시합니다.
+//! - `grid`: 균일 격자. 재구성이 싸고 밀도가 고르면 조회도 빠름
+//! - `kd_tree`: 2차원 k-d 트리. 한곳에 몰려 있어도 조회 비용이 안정적
+//!
+//! 전략은 `GameConfig::spatial_index`로 서버 시작 시 선택하며,
+//! 성능 비교는 `tests/benchmarks/spatial_index_benchmarks.rs`로 측정합니다.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::game::messages::{PlayerId, Position};
+
+/// 공간 인덱스
+pub trait SpatialIndex: Send + Sync {
+    /// 전략 이름 (로그/메트릭용)
+    fn name(&self) -> &'static str;
+
+    /// 전체 위치로 인덱스 재구성
+    fn rebuild(&mut self, entries: &[(PlayerId, Position)]);
+
+    /// `center`에서 수평 거리 `radius` 이내의 플레이어를 `out`에 추가 (순서 무관)
+    fn query_radius(&self, center: &Position, radius: f32, out: &mut Vec<PlayerId>);
+
+    /// 인덱스에 들어 있는 플레이어 수
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 공간 인덱스 전략
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpatialStrategy {
+    Grid,
+    KdTree,
+}
+
+impl SpatialStrategy {
+    /// 전략에 맞는 인덱스 생성 (`cell_size`는 격자에만 사용)
+    pub fn build(self, cell_size: f32) -> Box<dyn SpatialIndex> {
+        match self {
+            Self::Grid => Box::new(GridIndex::new(cell_size)),
+            Self::KdTree => Box::new(KdTreeIndex::default()),
+        }
+    }
+}
+
+impl FromStr for SpatialStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "grid" => Ok(Self::Grid),
+            "kd_tree" | "kdtree" | "kd" => Ok(Self::KdTree),
+            other => Err(anyhow::anyhow!("Unknown spatial index strategy: {}", other)),
+        }
+    }
+}
+
+fn within(center: &Position, radius_sq: f32, position: &Position) -> bool {
+    let dx = position.x - center.x;
+    let dz = position.z - center.z;
+    dx * dx + dz * dz <= radius_sq
+}
+
+/// 균일 격자 인덱스
+#[derive(Debug)]
+pub struct GridIndex {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<(PlayerId, Position)>>,
+    len: usize,
+}
+
+impl GridIndex {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(1.0),
+            cells: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    fn cell(&self, x: f32, z: f32) -> (i32, i32) {
+        (
+            (x / self.cell_size).floor() as i32,
+            (z / self.cell_size).floor() as i32,
+        )
+    }
+}
+
+impl SpatialIndex for GridIndex {
+    fn name(&self) -> &'static str {
+        "grid"
+    }
+
+    fn rebuild(&mut self, entries: &[(PlayerId, Position)]) {
+        // 셀 벡터는 비우기만 해서 다음 틱에 재사용
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+        for &(id, position) in entries {
+            let cell = self.cell(position.x, position.z);
+            self.cells.entry(cell).or_default().push((id, position));
+        }
+        self.cells.retain(|_, cell| !cell.is_empty());
+        self.len = entries.len();
+    }
+
+    fn query_radius(&self, center: &Position, radius: f32, out: &mut Vec<PlayerId>) {
+        let radius_sq = radius * radius;
+        let (min_x, min_z) = self.cell(center.x - radius, center.z - radius);
+        let (max_x, max_z) = self.cell(center.x + radius, center.z + radius);
+        for cx in min_x..=max_x {
+            for cz in min_z..=max_z {
+                if let Some(cell) = self.cells.get(&(cx, cz)) {
+                    out.extend(
+                        cell.iter()
+                            .filter(|(_, p)| within(center, radius_sq, p))
+                            .map(|(id, _)| *id),
+                    );
+                }
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// 2차원 k-d 트리 인덱스
+///
+/// 노드를 배열에 암묵적으로 저장합니다. 구간 `[start, end)`의 가운데 원소가 노드이고,
+/// 깊이에 따라 X/Z 축을 번갈아 분할합니다.
+#[derive(Debug, Default)]
+pub struct KdTreeIndex {
+    nodes: Vec<(PlayerId, Position)>,
+}
+
+impl KdTreeIndex {
+    fn key(position: &Position, depth: usize) -> f32 {
+        if depth.is_multiple_of(2) {
+            position.x
+        } else {
+            position.z
+        }
+    }
+
+    fn build(nodes: &mut [(PlayerId, Position)], depth: usize) {
+        if nodes.len() <= 1 {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        nodes.select_nth_unstable_by(mid, |a, b| {
+            Self::key(&a.1, depth).total_cmp(&Self::key(&b.1, depth))
+        });
+        let (left, right) = nodes.split_at_mut(mid);
+        Self::build(left, depth + 1);
+        Self::build(&mut right[1..], depth + 1);
+    }
+
+    fn search(
+        nodes: &[(PlayerId, Position)],
+        depth: usize,
+        center: &Position,
+        radius: f32,
+        out: &mut Vec<PlayerId>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+        let mid = nodes.len() / 2;
+        let (id, position) = &nodes[mid];
+        if within(center, radius * radius, position) {
+            out.push(*id);
+        }
+        let split = Self::key(position, depth);
+        let target = Self::key(center, depth);
+        if target - radius <= split {
+            Self::search(&nodes[..mid], depth + 1, center, radius, out);
+        }
+        if target + radius >= split {
+            Self::search(&nodes[mid + 1..], depth + 1, center, radius, out);
+        }
+    }
+}
+
+impl SpatialIndex for KdTreeIndex {
+    fn name(&self) -> &'static str {
+        "kd_tree"
+    }
+
+    fn rebuild(&mut self, entries: &[(PlayerId, Position)]) {
+        self.nodes.clear();
+        self.nodes.extend_from_slice(entries);
+        Self::build(&mut self.nodes, 0);
+    }
+
+    fn query_radius(&self, center: &Position, radius: f32, out: &mut Vec<PlayerId>) {
+        Self::search(&self.nodes, 0, center, radius, out);
+    }
+
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_strategies_match_brute_force() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let entries: Vec<(PlayerId, Position)> = (0..500)
+            .map(|id| {
+                (
+                    id,
+                    Position::new(
+                        rng.gen_range(-500.0..500.0),
+                        rng.gen_range(0.0..20.0),
+                        rng.gen_range(-500.0..500.0),
+                    ),
+                )
+            })
+            .collect();
+
+        let mut indexes = [
+            SpatialStrategy::Grid.build(32.0),
+            SpatialStrategy::KdTree.build(32.0),
+        ];
+        for index in indexes.iter_mut() {
+            index.rebuild(&entries);
+            assert_eq!(index.len(), entries.len());
+        }
+
+        for _ in 0..50 {
+            let center = Position::new(
+                rng.gen_range(-600.0..600.0),
+                0.0,
+                rng.gen_range(-600.0..600.0),
+            );
+            let radius = rng.gen_range(0.0..120.0);
+            let mut expected: Vec<PlayerId> = entries
+                .iter()
+                .filter(|(_, p)| within(&center, radius * radius, p))
+                .map(|(id, _)| *id)
+                .collect();
+            expected.sort_unstable();
+
+            for index in &indexes {
+                let mut found = Vec::new();
+                index.query_radius(&center, radius, &mut found);
+                found.sort_unstable();
+                assert_eq!(found, expected, "{}", index.name());
+            }
+        }
+
+        // 재구성하면 이전 위치는 사라짐
+        indexes[0].rebuild(&entries[..1]);
+        let mut found = Vec::new();
+        indexes[0].query_radius(&entries[1].1, 0.0, &mut found);
+        assert!(!found.contains(&entries[1].0));
+        assert_eq!(
+            "kdtree".parse::<SpatialStrategy>().unwrap(),
+            SpatialStrategy::KdTree
+        );
+    }
+}
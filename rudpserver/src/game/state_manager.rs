@@ -18,22 +18,46 @@
 use crate::config::{GameConfig, RudpServerConfig};
 use crate::game::messages::{
     AttackTarget, AttackType, DeathCause, DeathPenalty, Direction, DisconnectReason, DroppedItem,
-    ErrorCategory, GameMessage, PlayerId, PlayerState as MessagePlayerState, PlayerStatus,
+    GameMessage, HitRegRecord, HitTestOutcome, PlayerId, PlayerState as MessagePlayerState, PlayerStatus,
     Position, ServerConfig, StateValue, Velocity,
 };
-use crate::game::player::{Player, PlayerManager, PlayerState};
+use crate::game::afk::{AfkAction, AfkConfig, AfkStage};
+use crate::game::hitreg_debug::{HitRegDebugConfig, HitRegDebugger};
+use crate::game::input_buffer::{InputBufferConfig, InputBufferStats, InputBuffers, InputOutcome};
+use crate::game::match_manager::{LobbyEvent, LobbyUpdate, MatchManager, RoomRules};
+use crate::game::match_results::MatchResultRecorder;
+use crate::game::event_channels::{
+    EventChannelConfig, RoomEventChannels, RoomEventReceiver, RoomId, LOBBY_ROOM_ID,
+};
+use crate::game::player::{Player, PlayerManager, PlayerState, PlayerSummary};
+use crate::game::quick_ping::{PingCatalog, QuickPings};
+use crate::game::replication::{ReplicatedPlayer, RoomState};
+use crate::game::respawn::{
+    load_spawn_points, RespawnConfig, RespawnContext, RespawnSelector, SpawnPoint,
+};
+use crate::game::spatial::SpatialIndex;
+use crate::game::timestep::TimestepMetrics;
+use crate::game::weapons::WeaponCatalog;
+use crate::network::session::{SessionEvent, SessionEventListener, SessionTerminationReason};
+use crate::protocol::RUDP_PROTOCOL;
 use anyhow::{anyhow, Result};
-use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
-use tokio::sync::{broadcast, RwLock};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
 // use uuid::Uuid; // Not needed currently
 
 // Shared library imports
-use shared::security::SecurityMiddleware;
+use shared::security::{EntryTicketSigner, SecurityMiddleware};
+use shared::tool::clock::{system_clock, SharedClock};
+use shared::tool::{ErrorCode, GameServerError};
 use shared::tool::high_performance::redis_optimizer::RedisOptimizer;
 
+/// 하트비트로 광고하는 프로토콜 이름 (입장 티켓도 이 이름으로 발급됨)
+const ENTRY_TICKET_PROTOCOL: &str = "rudp";
+
 /// 게임 상태 관리자
 ///
 /// 모든 게임 로직의 중심이 되는 구조체입니다.
@@ -74,9 +98,26 @@ pub struct GameStateManager {
     /// Key: player_id, Value: RespawnInfo
     respawn_queue: Arc<RwLock<HashMap<PlayerId, RespawnInfo>>>,
 
+    /// 리스폰 위치 선택기
+    respawn_selector: Arc<RwLock<RespawnSelector>>,
+
+    /// 무기/공격 데이터
+    weapons: Arc<WeaponCatalog>,
+
+    /// 히트 판정 디버그 스트림 (QA용)
+    hitreg_debugger: Arc<HitRegDebugger>,
+
+    /// 빠른 소통 핑 (검증, 전송 제한)
+    quick_pings: Arc<QuickPings>,
+
+    /// 방별 로비 및 매치 시작 관리
+    match_manager: Arc<MatchManager>,
+    /// 매치 결과 기록기 (서버 기동 시 연결)
+    match_recorder: Arc<OnceLock<Arc<MatchResultRecorder>>>,
+
     // 이벤트 시스템
-    /// 게임 이벤트 브로드캐스트 채널
-    event_sender: broadcast::Sender<GameEvent>,
+    /// 방 단위 게임 이벤트 채널
+    event_channels: Arc<RoomEventChannels>,
 
     // 성능 및 보안
     /// 보안 미들웨어
@@ -87,6 +128,34 @@ pub struct GameStateManager {
     // 통계 및 모니터링
     /// 게임 통계
     game_stats: Arc<RwLock<GameStatistics>>,
+
+    /// 런타임 틱 레이트 (관리자 콘솔에서 변경 가능)
+    tick_rate: Arc<AtomicU32>,
+
+    /// 틱 루프의 시뮬레이션/실제 시간 지표
+    timestep_metrics: Arc<parking_lot::Mutex<TimestepMetrics>>,
+
+    /// 쿨다운/리스폰/매치 진행 시계 (테스트에서 교체)
+    clock: SharedClock,
+
+    /// 관심 영역 조회용 공간 인덱스 (매 틱 재구성)
+    spatial_index: Arc<parking_lot::RwLock<Box<dyn SpatialIndex>>>,
+
+    /// 마지막으로 실행한 서버 틱
+    current_tick: Arc<AtomicU64>,
+    /// 플레이어별 이동 입력 버퍼 (클라이언트 틱 순)
+    input_buffers: Arc<parking_lot::Mutex<InputBuffers<BufferedMove>>>,
+    /// 장애 조치로 넘겨받아 재접속을 기다리는 플레이어 (방 ID, 복제된 상태)
+    pending_restores: Arc<parking_lot::Mutex<HashMap<PlayerId, (RoomId, ReplicatedPlayer)>>>,
+    /// 입장 티켓 서명기와 이 서버가 라우팅 테이블에 광고하는 주소
+    entry_tickets: Option<(EntryTicketSigner, String)>,
+}
+
+/// 적용을 기다리는 검증된 이동 입력
+#[derive(Debug, Clone, Copy)]
+struct BufferedMove {
+    target_position: Position,
+    client_timestamp: u64,
 }
 
 /// 플레이어 게임 상태
@@ -111,6 +180,23 @@ pub struct PlayerGameState {
     pub last_broadcast_time: Instant,
     /// 네트워크 지연시간 (밀리초)
     pub network_latency_ms: f32,
+    /// 마지막 의미 있는 입력 시간 (AFK 판정용)
+    pub last_input_time: Instant,
+    /// AFK 단계
+    pub afk_stage: AfkStage,
+}
+
+impl PlayerGameState {
+    /// 의미 있는 입력 기록 (AFK 상태 해제)
+    fn record_input(&mut self, now: Instant) {
+        self.last_input_time = now;
+        if self.afk_stage != AfkStage::Active {
+            self.afk_stage = AfkStage::Active;
+            if self.player.state == PlayerState::Spectating {
+                self.player.state = PlayerState::Idle;
+            }
+        }
+    }
 }
 
 /// 전투 세션 정보
@@ -216,6 +302,46 @@ pub enum GameEvent {
         player_id: PlayerId,
         spawn_position: Position,
     },
+    /// AFK 경고
+    PlayerAfkWarning {
+        player_id: PlayerId,
+        session_id: u64,
+        idle_secs: u64,
+        disconnect_in_secs: u64,
+    },
+    /// AFK로 관전자 전환
+    PlayerMovedToSpectator {
+        player_id: PlayerId,
+        session_id: u64,
+        idle_secs: u64,
+    },
+    /// AFK로 연결 해제됨 (게임 로직의 패널티 적용용)
+    PlayerAfkDisconnected {
+        player_id: PlayerId,
+        session_id: u64,
+        idle_secs: u64,
+    },
+    /// 히트 판정 디버그 정보 (플래그된 플레이어 본인에게만 전송)
+    HitRegDebug {
+        player_id: PlayerId,
+        session_id: u64,
+        records: Vec<HitRegRecord>,
+    },
+    /// 로비 상태 변화 (준비/카운트다운/매치 시작)
+    Lobby {
+        room_id: RoomId,
+        /// 이벤트를 받을 로비 인원의 세션
+        recipients: Vec<u64>,
+        event: LobbyEvent,
+    },
+    /// 빠른 소통 핑 (관심 영역 안 팀원에게만 전송)
+    QuickPing {
+        player_id: PlayerId,
+        /// 핑을 받을 팀원의 세션
+        recipients: Vec<u64>,
+        /// `GameMessage::QuickPingReceived`
+        message: GameMessage,
+    },
     /// 레벨업
     PlayerLevelUp {
         player_id: PlayerId,
@@ -233,6 +359,14 @@ pub struct AttackResultData {
     pub target_health_after: Option<u32>,
 }
 
+/// 히트 판정 디버그용 판정 전 스냅샷
+#[derive(Debug, Clone, Copy)]
+struct HitRegProbe {
+    attacker_position: Position,
+    target_position: Option<Position>,
+    rewind_window_ms: f32,
+}
+
 /// 게임 통계
 ///
 /// 서버 운영과 모니터링을 위한 각종 통계 정보입니다.
@@ -299,7 +433,39 @@ impl GameStateManager {
         security_middleware: Arc<SecurityMiddleware>,
         redis_optimizer: Arc<RedisOptimizer>,
     ) -> Result<Self> {
-        let (event_sender, _) = broadcast::channel(1000);
+        let event_channels = Arc::new(RoomEventChannels::new(EventChannelConfig {
+            capacity: config.event_channel_capacity,
+            lag_alert_threshold: config.event_lag_alert_threshold,
+            backlog_alert_ratio: config.event_backlog_alert_ratio,
+        }));
+
+        let mut respawn_selector = RespawnSelector::new(RespawnConfig {
+            strategy: config.respawn_strategy,
+            enemy_avoid_radius: config.respawn_enemy_avoid_radius,
+            ..Default::default()
+        });
+        respawn_selector.set_spawn_points(load_spawn_points(&config.spawn_points_file)?);
+
+        let tick_rate = Arc::new(AtomicU32::new(config.tick_rate));
+        let spatial_index = config.spatial_index.build(config.spatial_cell_size);
+        info!(strategy = spatial_index.name(), "공간 인덱스 선택");
+        let weapons = Arc::new(WeaponCatalog::load(&config.weapons_file)?);
+        let quick_pings = Arc::new(QuickPings::new(PingCatalog::load(&config.pings_file)?));
+        let hitreg_debugger = Arc::new(HitRegDebugger::new(HitRegDebugConfig {
+            enabled: config.hitreg_debug_enabled,
+            history_size: config.hitreg_debug_history,
+            max_messages_per_sec: config.hitreg_debug_max_per_sec,
+        }));
+        let input_buffers = InputBuffers::new(InputBufferConfig {
+            min_delay_ticks: config.input_min_delay_ticks,
+            max_delay_ticks: config.input_max_delay_ticks,
+            capacity: config.input_buffer_capacity,
+        });
+        let match_manager = Arc::new(MatchManager::new(
+            RoomRules::load(&config.game_defaults_file)?,
+            Duration::from_secs(config.match_countdown_secs),
+            Duration::from_secs(config.match_duration_secs),
+        ));
 
         let manager = Self {
             config,
@@ -308,19 +474,57 @@ impl GameStateManager {
             active_players: Arc::new(RwLock::new(HashMap::new())),
             active_combats: Arc::new(RwLock::new(HashMap::new())),
             respawn_queue: Arc::new(RwLock::new(HashMap::new())),
-            event_sender,
+            respawn_selector: Arc::new(RwLock::new(respawn_selector)),
+            weapons,
+            hitreg_debugger,
+            quick_pings,
+            match_manager,
+            match_recorder: Arc::new(OnceLock::new()),
+            event_channels,
             security_middleware,
             redis_optimizer,
             game_stats: Arc::new(RwLock::new(GameStatistics {
                 last_updated: Instant::now(),
                 ..Default::default()
             })),
+            tick_rate,
+            timestep_metrics: Arc::new(parking_lot::Mutex::new(TimestepMetrics::default())),
+            clock: system_clock(),
+            spatial_index: Arc::new(parking_lot::RwLock::new(spatial_index)),
+            current_tick: Arc::new(AtomicU64::new(0)),
+            input_buffers: Arc::new(parking_lot::Mutex::new(input_buffers)),
+            pending_restores: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            entry_tickets: None,
         };
 
         info!("Game state manager initialized - Redis 기반 상태 관리");
         Ok(manager)
     }
 
+    /// 시계 교체 (복제본을 만들기 전에 호출)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// 입장 티켓 검증 설정
+    ///
+    /// `address`는 하트비트로 광고하는 서버 ID와 같아야 합니다 (로그인 응답이 그 주소로 티켓을 발급).
+    /// 설정하면 `Connect`의 `auth_token`은 입장 티켓이어야 하며 티켓의 사용자 ID가 플레이어 ID가 됩니다.
+    pub fn with_entry_tickets(
+        mut self,
+        signer: EntryTicketSigner,
+        address: impl Into<String>,
+    ) -> Self {
+        self.entry_tickets = Some((signer, address.into()));
+        self
+    }
+
+    /// 게임 상태 시계
+    pub fn clock(&self) -> &SharedClock {
+        &self.clock
+    }
+
     /// 플레이어 연결 처리
     ///
     /// 새로운 플레이어가 게임에 접속할 때 호출됩니다.
@@ -376,6 +580,22 @@ impl GameStateManager {
             });
         }
 
+        // 1-1. 프로토콜 버전 협상
+        match RUDP_PROTOCOL.negotiate_str(&client_version) {
+            Ok(negotiated) => debug!(session_id = %session_id, version = %negotiated.version, "Protocol negotiated"),
+            Err(e) => {
+                warn!(session_id = %session_id, error = %e, "Protocol negotiation failed");
+                return Ok(GameMessage::ConnectResponse {
+                    success: false,
+                    player_id: None,
+                    spawn_position: None,
+                    initial_state: None,
+                    message: e.to_string(),
+                    server_config: None,
+                });
+            }
+        }
+
         // 2. 서버 용량 확인
         let current_players = self.active_players.read().await.len() as u32;
         if current_players >= self.config.max_concurrent_players {
@@ -433,7 +653,7 @@ impl GameStateManager {
                 let default_spawn = Position::new(0.0, 0.0, 0.0);
                 match self
                     .player_manager
-                    .create_player(session_id, player_name, default_spawn)
+                    .create_player(player_id, session_id, player_name, default_spawn)
                     .await
                 {
                     Ok(created_player_id) => {
@@ -454,8 +674,19 @@ impl GameStateManager {
             }
         };
 
-        // 6. 스폰 위치 결정
-        let spawn_position = self.determine_spawn_position(&player).await?;
+        // 6. 스폰 위치 결정 (장애 조치로 넘겨받은 플레이어는 복제된 상태로 이어서 진행)
+        let mut player = player;
+        let restored = self.pending_restores.lock().remove(&player_id);
+        let spawn_position = match restored {
+            Some((room_id, restored)) => {
+                restored.apply_to(&mut player, room_id);
+                info!(player_id = %player_id, room_id = %room_id, "Player resumed from replicated state");
+                restored.position
+            }
+            None => self.determine_spawn_position(&player).await?,
+        };
+        // 클라이언트에 알린 스폰 위치에서 시작 (이동 거리 검증 기준)
+        player.position = spawn_position;
 
         // 7. 초기 플레이어 상태 생성 (messages::PlayerState 사용)
         let initial_player_state = crate::game::messages::PlayerState {
@@ -475,8 +706,8 @@ impl GameStateManager {
         // 8. 게임 상태에 플레이어 추가
         let player_game_state = PlayerGameState {
             player: player.clone(),
-            last_move_time: Instant::now(),
-            last_attack_time: Instant::now(),
+            last_move_time: self.clock.instant(),
+            last_attack_time: self.clock.instant(),
             current_target: None,
             attack_cooldown_until: None,
             movement_prediction: MovementPrediction {
@@ -485,8 +716,10 @@ impl GameStateManager {
                 prediction_timestamp: self.current_timestamp(),
                 confidence: 1.0,
             },
-            last_broadcast_time: Instant::now(),
+            last_broadcast_time: self.clock.instant(),
             network_latency_ms: 50.0, // 기본값
+            last_input_time: self.clock.instant(),
+            afk_stage: AfkStage::Active,
         };
 
         // 9. 상태 저장
@@ -510,11 +743,16 @@ impl GameStateManager {
         }
 
         // 12. 이벤트 브로드캐스트
-        let _ = self.event_sender.send(GameEvent::PlayerConnected {
-            player_id,
-            player_name: player.name.clone(),
-            spawn_position,
-        });
+        let room_id = player.room_id.unwrap_or(LOBBY_ROOM_ID);
+        self.event_channels.open_room(room_id);
+        self.event_channels.publish(
+            room_id,
+            GameEvent::PlayerConnected {
+                player_id,
+                player_name: player.name.clone(),
+                spawn_position,
+            },
+        );
 
         // 13. 서버 설정 정보
         let server_config = ServerConfig {
@@ -586,12 +824,7 @@ impl GameStateManager {
                 Some(&id) => id,
                 None => {
                     warn!(session_id = %session_id, "Move request from unknown session");
-                    return Ok(Some(GameMessage::Error {
-                        error_code: "INVALID_SESSION".to_string(),
-                        error_message: "Session not found".to_string(),
-                        category: ErrorCategory::Authentication,
-                        recoverable: false,
-                    }));
+                    return Ok(Some(GameMessage::from_error(&ErrorCode::InvalidSession.into())));
                 }
             }
         };
@@ -602,17 +835,12 @@ impl GameStateManager {
             Some(state) => state,
             None => {
                 warn!(player_id = %player_id, "Move request for inactive player");
-                return Ok(Some(GameMessage::Error {
-                    error_code: "PLAYER_INACTIVE".to_string(),
-                    error_message: "Player not active".to_string(),
-                    category: ErrorCategory::GameLogic,
-                    recoverable: true,
-                }));
+                return Ok(Some(GameMessage::from_error(&ErrorCode::PlayerInactive.into())));
             }
         };
 
         // 3. 이동 제한 검사 (스팸 방지)
-        let now = Instant::now();
+        let now = self.clock.instant();
         if now.duration_since(player_state.last_move_time) < Duration::from_millis(16) {
             // 60 FPS보다 빠른 이동 요청 무시
             return Ok(None);
@@ -621,12 +849,7 @@ impl GameStateManager {
         // 4. 플레이어가 사망 상태인지 확인
         // TODO: player.state는 enum이므로 직접 상태 확인 불가, 임시로 stats 사용
         if !player_state.player.stats.is_alive() {
-            return Ok(Some(GameMessage::Error {
-                error_code: "PLAYER_DEAD".to_string(),
-                error_message: "Cannot move while dead".to_string(),
-                category: ErrorCategory::GameLogic,
-                recoverable: false,
-            }));
+            return Ok(Some(GameMessage::from_error(&ErrorCode::PlayerDead.into())));
         }
 
         // 5. 위치 유효성 검사
@@ -647,8 +870,7 @@ impl GameStateManager {
         // }
 
         // 6. 이동 거리 검사 (치팅 방지)
-        let current_position = player_state.player.position;
-        let distance = current_position.distance_to(&target_position);
+        let distance = player_state.player.position.distance_to(&target_position);
         let max_move_distance = player_state.player.stats.move_speed * speed_multiplier * 0.1; // 100ms 기준
 
         if distance > max_move_distance * 2.0 {
@@ -660,32 +882,72 @@ impl GameStateManager {
                 "Move distance too large, possible cheating"
             );
 
-            return Ok(Some(GameMessage::Error {
-                error_code: "INVALID_MOVE_DISTANCE".to_string(),
-                error_message: "Move distance too large".to_string(),
-                category: ErrorCategory::GameLogic,
-                recoverable: true,
-            }));
+            return Ok(Some(GameMessage::from_error(&GameServerError::with_detail(
+                ErrorCode::InvalidAction,
+                "Move distance too large",
+            ))));
+        }
+
+        // 7. 클라이언트 틱에 맞춰 버퍼링 (해당 서버 틱에 `update_game_tick`에서 적용)
+        drop(players);
+        if self.config.input_buffer_enabled {
+            let client_tick = client_timestamp * self.tick_rate() as u64 / 1000;
+            let server_tick = self.current_tick.load(Ordering::Relaxed);
+            let outcome = self.input_buffers.lock().push(
+                player_id,
+                client_tick,
+                server_tick,
+                BufferedMove { target_position, client_timestamp },
+            );
+            if outcome == InputOutcome::Dropped {
+                debug!(player_id = %player_id, client_tick, server_tick, "Stale move input dropped");
+            }
+        } else {
+            self.apply_player_move(player_id, target_position, client_timestamp).await?;
         }
 
-        // 7. 지연 보상 계산
+        // 이동은 빈번하므로 응답 메시지를 보내지 않음 (네트워크 최적화)
+        Ok(None)
+    }
+
+    /// 검증된 이동 입력 적용
+    ///
+    /// 버퍼에 있는 동안 플레이어가 퇴장했거나 사망했으면 무시합니다.
+    async fn apply_player_move(
+        &self,
+        player_id: PlayerId,
+        target_position: Position,
+        client_timestamp: u64,
+    ) -> Result<()> {
+        let now = self.clock.instant();
+        let mut players = self.active_players.write().await;
+        let Some(player_state) = players
+            .get_mut(&player_id)
+            .filter(|state| state.player.stats.is_alive())
+        else {
+            return Ok(());
+        };
+        let current_position = player_state.player.position;
+
+        // 1. 지연 보상 계산
         let server_timestamp = self.current_timestamp();
         let latency_compensation =
             self.calculate_latency_compensation(player_state, client_timestamp, server_timestamp);
 
-        // 8. 최종 위치 결정 (지연 보상 적용)
+        // 2. 최종 위치 결정 (지연 보상 적용)
         let compensated_position =
             self.apply_latency_compensation(target_position, latency_compensation);
 
-        // 9. 충돌 감지 (간소화된 버전)
+        // 3. 충돌 감지 (간소화된 버전)
         let final_position = self
             .resolve_collisions(player_id, current_position, compensated_position)
             .await?;
 
-        // 10. 플레이어 상태 업데이트
+        // 4. 플레이어 상태 업데이트
         let old_position = player_state.player.position;
         player_state.player.position = final_position;
         player_state.last_move_time = now;
+        player_state.record_input(now);
 
         // 속도 계산
         let time_delta = now
@@ -711,21 +973,25 @@ impl GameStateManager {
 
         drop(players);
 
-        // 11. 위치 정보는 Redis에 저장 (월드 관리는 클라이언트에서 처리)
+        // 5. 위치 정보는 Redis에 저장 (월드 관리는 클라이언트에서 처리)
 
-        // 12. 통계 업데이트
+        // 6. 통계 업데이트
         {
             let mut stats = self.game_stats.write().await;
             stats.total_moves_processed += 1;
         }
 
-        // 13. 이벤트 브로드캐스트 (관심 영역 내 플레이어들에게만)
-        let _ = self.event_sender.send(GameEvent::PlayerMoved {
+        // 7. 이벤트 브로드캐스트 (관심 영역 내 플레이어들에게만)
+        self.publish_event(
             player_id,
-            old_position,
-            new_position: final_position,
-            velocity,
-        });
+            GameEvent::PlayerMoved {
+                player_id,
+                old_position,
+                new_position: final_position,
+                velocity,
+            },
+        )
+        .await;
 
         debug!(
             player_id = %player_id,
@@ -735,8 +1001,7 @@ impl GameStateManager {
             "Player moved successfully"
         );
 
-        // 이동은 빈번하므로 응답 메시지를 보내지 않음 (네트워크 최적화)
-        Ok(None)
+        Ok(())
     }
 
     /// 플레이어 공격 처리
@@ -787,12 +1052,7 @@ impl GameStateManager {
             match sessions.get(&session_id) {
                 Some(&id) => id,
                 None => {
-                    return Ok(GameMessage::Error {
-                        error_code: "INVALID_SESSION".to_string(),
-                        error_message: "Session not found".to_string(),
-                        category: ErrorCategory::Authentication,
-                        recoverable: false,
-                    });
+                    return Ok(GameMessage::from_error(&ErrorCode::InvalidSession.into()));
                 }
             }
         };
@@ -836,7 +1096,7 @@ impl GameStateManager {
         }
 
         // 4. 공격 쿨다운 확인
-        let now = Instant::now();
+        let now = self.clock.instant();
         if let Some(cooldown_until) = attacker_state.attack_cooldown_until {
             if now < cooldown_until {
                 let remaining = cooldown_until.duration_since(now);
@@ -846,15 +1106,33 @@ impl GameStateManager {
                     "Attack on cooldown"
                 );
 
-                return Ok(GameMessage::Error {
-                    error_code: "ATTACK_COOLDOWN".to_string(),
-                    error_message: format!("Attack on cooldown for {}ms", remaining.as_millis()),
-                    category: ErrorCategory::GameLogic,
-                    recoverable: true,
-                });
+                return Ok(GameMessage::from_error(&GameServerError::with_message(
+                    ErrorCode::Cooldown,
+                    format!("공격 대기 중입니다 ({}ms)", remaining.as_millis()),
+                )));
             }
         }
 
+        // 히트 판정 디버그 대상이면 판정 전 위치 스냅샷
+        let hitreg_probe = if self.hitreg_debugger.is_flagged(attacker_id) {
+            let attacker_position = attacker_state.player.position;
+            let rewind_window_ms = attacker_state.network_latency_ms.min(200.0);
+            let target_position = match &target {
+                AttackTarget::Player(target_id) => {
+                    players.get(target_id).map(|state| state.player.position)
+                }
+                AttackTarget::Position(pos) => Some(*pos),
+                AttackTarget::Npc(_) => None,
+            };
+            Some(HitRegProbe {
+                attacker_position,
+                target_position,
+                rewind_window_ms,
+            })
+        } else {
+            None
+        };
+
         // 5. 공격 대상 처리
         let attack_result = match target {
             AttackTarget::Player(target_id) => {
@@ -865,33 +1143,46 @@ impl GameStateManager {
                     &attack_type,
                     weapon_id,
                 )
-                .await?
+                .await
             }
             AttackTarget::Position(pos) => {
                 self.process_area_attack(&mut players, attacker_id, pos, &attack_type, weapon_id)
-                    .await?
+                    .await
             }
             AttackTarget::Npc(npc_id) => {
                 self.process_npc_attack(attacker_id, npc_id, &attack_type, weapon_id)
-                    .await?
+                    .await
+            }
+        };
+
+        let hitreg_record = hitreg_probe.map(|probe| {
+            self.build_hitreg_record(
+                probe,
+                &target,
+                &attack_type,
+                weapon_id,
+                attack_result.as_ref().ok(),
+            )
+        });
+        let attack_result = match attack_result {
+            Ok(result) => result,
+            Err(e) => {
+                drop(players);
+                self.publish_hitreg_record(attacker_id, session_id, hitreg_record)
+                    .await;
+                return Err(e);
             }
         };
 
         // 6. 공격자 쿨다운 및 상태 업데이트
         if let Some(attacker) = players.get_mut(&attacker_id) {
             attacker.last_attack_time = now;
+            attacker.record_input(now);
 
-            // 공격 타입별 쿨다운 설정
-            let cooldown_ms = match attack_type {
-                AttackType::MeleeBasic => 1000,   // 1초
-                AttackType::MeleeHeavy => 3000,   // 3초
-                AttackType::Ranged => 1500,       // 1.5초
-                AttackType::Magic => 2000,        // 2초
-                AttackType::AreaOfEffect => 5000, // 5초
-                AttackType::Skill { .. } => 8000, // 8초
-            };
-
-            attacker.attack_cooldown_until = Some(now + Duration::from_millis(cooldown_ms));
+            // 무기 또는 공격 타입별 쿨다운 설정 (property/weapons.toml)
+            let weapon = weapon_id.and_then(|id| self.weapons.weapon(id));
+            attacker.attack_cooldown_until =
+                Some(now + self.weapons.cooldown(&attack_type, weapon));
 
             // 전투 상태로 변경
             attacker.player.state = PlayerState::Attacking;
@@ -904,6 +1195,9 @@ impl GameStateManager {
 
         drop(players);
 
+        self.publish_hitreg_record(attacker_id, session_id, hitreg_record)
+            .await;
+
         // 7. 통계 업데이트
         {
             let mut stats = self.game_stats.write().await;
@@ -911,12 +1205,16 @@ impl GameStateManager {
         }
 
         // 8. 이벤트 브로드캐스트
-        let _ = self.event_sender.send(GameEvent::AttackExecuted {
+        self.publish_event(
             attacker_id,
-            target: target.clone(),
-            attack_type,
-            result: attack_result.clone(),
-        });
+            GameEvent::AttackExecuted {
+                attacker_id,
+                target: target.clone(),
+                attack_type,
+                result: attack_result.clone(),
+            },
+        )
+        .await;
 
         info!(
             attacker_id = %attacker_id,
@@ -996,6 +1294,7 @@ impl GameStateManager {
         }
 
         let death_position = player_state.player.position;
+        self.match_manager.record_kill(killer_id, player_id);
         // level system removed
 
         // 2. 아이템 드롭 계산
@@ -1020,8 +1319,8 @@ impl GameStateManager {
         // 7. 리스폰 큐에 추가
         let respawn_info = RespawnInfo {
             player_id,
-            death_time: Instant::now(),
-            respawn_available_at: Instant::now() + Duration::from_secs(respawn_cooldown as u64),
+            death_time: self.clock.instant(),
+            respawn_available_at: self.clock.instant() + Duration::from_secs(respawn_cooldown as u64),
             death_cause: death_cause.clone(),
             death_position,
             dropped_items: dropped_items.clone(),
@@ -1052,12 +1351,16 @@ impl GameStateManager {
         }
 
         // 11. 이벤트 브로드캐스트
-        let _ = self.event_sender.send(GameEvent::PlayerDied {
+        self.publish_event(
             player_id,
-            killer_id,
-            death_cause: death_cause.clone(),
-            death_position,
-        });
+            GameEvent::PlayerDied {
+                player_id,
+                killer_id,
+                death_cause: death_cause.clone(),
+                death_position,
+            },
+        )
+        .await;
 
         info!(
             player_id = %player_id,
@@ -1109,12 +1412,7 @@ impl GameStateManager {
             match sessions.get(&session_id) {
                 Some(&id) => id,
                 None => {
-                    return Ok(GameMessage::Error {
-                        error_code: "INVALID_SESSION".to_string(),
-                        error_message: "Session not found".to_string(),
-                        category: ErrorCategory::Authentication,
-                        recoverable: false,
-                    });
+                    return Ok(GameMessage::from_error(&ErrorCode::InvalidSession.into()));
                 }
             }
         };
@@ -1130,31 +1428,28 @@ impl GameStateManager {
             match respawn_queue.get(&player_id) {
                 Some(info) => info.clone(),
                 None => {
-                    return Ok(GameMessage::Error {
-                        error_code: "NOT_DEAD".to_string(),
-                        error_message: "Player is not dead".to_string(),
-                        category: ErrorCategory::GameLogic,
-                        recoverable: false,
-                    });
+                    return Ok(GameMessage::from_error(&GameServerError::with_detail(
+                        ErrorCode::InvalidAction,
+                        "Player is not dead",
+                    )));
                 }
             }
         };
 
         // 3. 리스폰 쿨다운 확인
-        let now = Instant::now();
+        let now = self.clock.instant();
         if now < respawn_info.respawn_available_at {
             let remaining = respawn_info.respawn_available_at.duration_since(now);
-            return Ok(GameMessage::Error {
-                error_code: "RESPAWN_COOLDOWN".to_string(),
-                error_message: format!("Respawn available in {}s", remaining.as_secs()),
-                category: ErrorCategory::GameLogic,
-                recoverable: true,
-            });
+            return Ok(GameMessage::from_error(&GameServerError::with_message(
+                ErrorCode::Cooldown,
+                format!("{}초 후 부활할 수 있습니다", remaining.as_secs()),
+            )));
         }
 
-        // 4. 스폰 위치 결정
-        // 기본 스폰 포인트 사용
-        let spawn_position = self.get_default_spawn_position().await;
+        // 4. 스폰 위치 결정 (설정된 리스폰 전략 사용)
+        let spawn_position = self
+            .select_respawn_position(player_id, respawn_info.death_position)
+            .await;
 
         // 5. 플레이어 상태 복구
         {
@@ -1169,6 +1464,7 @@ impl GameStateManager {
 
                 // 플레이어 상태를 생존으로 변경
                 player_state.player.state = PlayerState::Idle;
+                player_state.record_input(self.clock.instant());
 
                 // 전투 관련 상태 초기화
                 player_state.current_target = None;
@@ -1203,10 +1499,14 @@ impl GameStateManager {
         }
 
         // 9. 이벤트 브로드캐스트
-        let _ = self.event_sender.send(GameEvent::PlayerRespawned {
+        self.publish_event(
             player_id,
-            spawn_position,
-        });
+            GameEvent::PlayerRespawned {
+                player_id,
+                spawn_position,
+            },
+        )
+        .await;
 
         // 10. 복구된 플레이어 상태 가져오기 (messages::PlayerState 형태로 변환)
         let restored_state = {
@@ -1298,7 +1598,8 @@ impl GameStateManager {
             match sessions.remove(&session_id) {
                 Some(id) => id,
                 None => {
-                    warn!(session_id = %session_id, "Disconnect request for unknown session");
+                    // 명시적 Disconnect 후 세션 종료 이벤트가 뒤따르는 경우 정상 경로
+                    debug!(session_id = %session_id, "Disconnect request for unknown session");
                     return Ok(());
                 }
             }
@@ -1316,6 +1617,17 @@ impl GameStateManager {
             let mut players = self.active_players.write().await;
             players.remove(&player_id)
         };
+        self.player_manager.remove_player(player_id);
+        self.input_buffers.lock().remove(player_id);
+        self.quick_pings.forget(player_id);
+        let room_id = player_state
+            .as_ref()
+            .and_then(|state| state.player.room_id)
+            .unwrap_or(LOBBY_ROOM_ID);
+
+        if let Some(update) = self.match_manager.leave(player_id, self.clock.instant()) {
+            self.publish_lobby_update(update);
+        }
 
         if let Some(state) = player_state {
             // 3. 플레이어 데이터 저장
@@ -1359,9 +1671,11 @@ impl GameStateManager {
         }
 
         // 9. 이벤트 브로드캐스트
-        let _ = self
-            .event_sender
-            .send(GameEvent::PlayerDisconnected { player_id, reason });
+        self.event_channels
+            .publish(room_id, GameEvent::PlayerDisconnected { player_id, reason });
+        if !self.match_manager.has_room(room_id) {
+            self.close_room(room_id);
+        }
 
         info!(
             player_id = %player_id,
@@ -1384,17 +1698,30 @@ impl GameStateManager {
     /// 업데이트 결과
     ///
     /// # Update Process
-    /// 1. 플레이어 상태 효과 업데이트
-    /// 2. 공격 쿨다운 처리
-    /// 3. 전투 시간 초과 확인
-    /// 4. 리스폰 쿨다운 처리
-    /// 5. 주기적인 상태 브로드캐스트
+    /// 1. 이번 틱에 도달한 이동 입력 적용 (입력 버퍼)
+    /// 2. 플레이어 상태 효과 업데이트
+    /// 3. 공격 쿨다운 처리
+    /// 4. 전투 시간 초과 확인
+    /// 5. 리스폰 쿨다운 처리
+    /// 6. 주기적인 상태 브로드캐스트
     ///
     /// # Performance
     /// - 시간 복잡도: O(n) where n = 활성 플레이어 수
     /// - 최적화: 매 틱마다 모든 플레이어를 처리하지 않고 필요한 경우만 처리
     pub async fn update_game_tick(&self, tick_number: u64, delta_time: f32) -> Result<()> {
-        // 1. 플레이어 상태 효과 업데이트
+        // 1. 이번 틱에 도달한 버퍼 입력 적용
+        self.current_tick.store(tick_number, Ordering::Relaxed);
+        let due_moves = self.input_buffers.lock().drain_due(tick_number);
+        for (player_id, input) in due_moves {
+            if let Err(e) = self
+                .apply_player_move(player_id, input.target_position, input.client_timestamp)
+                .await
+            {
+                warn!(player_id = %player_id, error = %e, "Failed to apply buffered move");
+            }
+        }
+
+        // 2. 플레이어 상태 효과 업데이트
         let mut players_to_update = Vec::new();
         {
             let mut players = self.active_players.write().await;
@@ -1419,38 +1746,215 @@ impl GameStateManager {
             }
         }
 
-        // 2. 상태 변경된 플레이어들 브로드캐스트
+        // 3. 공간 인덱스 재구성
+        self.rebuild_spatial_index().await;
+
+        // 4. 상태 변경된 플레이어들 브로드캐스트
         for player_id in players_to_update {
             if let Some(state_changes) = self.get_player_state_changes(player_id).await {
-                let _ = self.event_sender.send(GameEvent::PlayerMoved {
+                self.publish_event(
                     player_id,
-                    old_position: Position::default(), // 임시
-                    new_position: Position::default(), // 임시
-                    velocity: Velocity { x: 0.0, y: 0.0, z: 0.0 },
-                });
+                    GameEvent::PlayerMoved {
+                        player_id,
+                        old_position: Position::default(), // 임시
+                        new_position: Position::default(), // 임시
+                        velocity: Velocity { x: 0.0, y: 0.0, z: 0.0 },
+                    },
+                )
+                .await;
             }
         }
 
-        // 3. 전투 세션 시간 초과 확인
+        // 5. 전투 세션 시간 초과 확인
         self.cleanup_expired_combats().await;
 
-        // 4. 주기적 통계 업데이트 (1초마다)
+        // 6. 카운트다운이 끝난 방의 매치 시작
+        for update in self.match_manager.tick(self.clock.instant()) {
+            self.publish_lobby_update(update);
+        }
+
+        // 7. 주기적 통계 업데이트 (1초마다)
         if tick_number % 60 == 0 {
             self.update_game_statistics().await;
+            self.check_afk_players().await;
         }
 
         Ok(())
     }
 
-    /// 게임 이벤트 구독자 생성
+    /// 현재 위치로 공간 인덱스 재구성
+    async fn rebuild_spatial_index(&self) {
+        let entries: Vec<(PlayerId, Position)> = {
+            let players = self.active_players.read().await;
+            players.iter().map(|(id, state)| (*id, state.player.position)).collect()
+        };
+        self.spatial_index.write().rebuild(&entries);
+    }
+
+    /// 마지막 틱 기준으로 `center`에서 수평 거리 `radius` 이내의 플레이어
+    pub fn players_within(&self, center: &Position, radius: f32) -> Vec<PlayerId> {
+        let mut found = Vec::new();
+        self.spatial_index.read().query_radius(center, radius, &mut found);
+        found
+    }
+
+    /// 로비 준비 상태 변경 처리
+    ///
+    /// 처음 요청한 방의 로비에 참가시키고, 요청자에게는 현재 로비 상태를 바로 응답합니다.
+    /// 나머지 인원에게는 방 이벤트 채널로 전달됩니다.
+    pub async fn handle_set_ready(
+        &self,
+        session_id: u64,
+        room_id: RoomId,
+        ready: bool,
+    ) -> Result<GameMessage> {
+        let player_id = self
+            .connected_sessions
+            .read()
+            .await
+            .get(&session_id)
+            .copied()
+            .ok_or_else(|| anyhow!("Player not found for session"))?;
+
+        let update = match self
+            .match_manager
+            .set_ready(room_id, player_id, session_id, ready, self.clock.instant())
+        {
+            Ok(update) => update,
+            Err(e) => {
+                debug!(player_id = %player_id, room_id = %room_id, error = %e, "Lobby request rejected");
+                return Ok(GameMessage::from_error(&e.into()));
+            }
+        };
+
+        if let Some(state) = self.active_players.write().await.get_mut(&player_id) {
+            state.player.room_id = Some(room_id);
+        }
+        self.event_channels.open_room(room_id);
+
+        let response = update
+            .events
+            .last()
+            .map(|event| event.to_message(room_id))
+            .ok_or_else(|| anyhow!("Lobby update without state"))?;
+        self.publish_lobby_update(update);
+        Ok(response)
+    }
+
+    /// 빠른 소통 핑 처리
+    ///
+    /// 진행 중인 매치의 참가자만 보낼 수 있으며, 검증과 전송 제한을 통과하면 같은 팀이면서
+    /// 보낸 플레이어의 관심 영역 안에 있는 팀원에게 방 이벤트 채널로 전달합니다.
+    /// 성공하면 응답 없이 `None`을 돌려줍니다.
+    pub async fn handle_quick_ping(
+        &self,
+        session_id: u64,
+        ping_type: String,
+        position: Position,
+    ) -> Result<Option<GameMessage>> {
+        let player_id = self
+            .connected_sessions
+            .read()
+            .await
+            .get(&session_id)
+            .copied()
+            .ok_or_else(|| anyhow!("Player not found for session"))?;
+
+        let Some((room_id, teammates)) = self.match_manager.teammates(player_id) else {
+            return Ok(Some(GameMessage::from_error(&GameServerError::with_detail(
+                ErrorCode::NotInRoom,
+                "quick ping requires an active match",
+            ))));
+        };
+        let players = self.active_players.read().await;
+        let sender_position = players
+            .get(&player_id)
+            .ok_or_else(|| anyhow!("Player state not found"))?
+            .player
+            .position;
+        let ping = match self
+            .quick_pings
+            .accept(player_id, &ping_type, &position, &sender_position, self.clock.instant())
+        {
+            Ok(ping) => ping,
+            Err(e) => {
+                debug!(player_id = %player_id, ping_type = %ping_type, error = %e, "Quick ping rejected");
+                return Ok(Some(GameMessage::from_error(&e)));
+            }
+        };
+
+        let radius = self.quick_pings.catalog().aoi_radius(ping);
+        let recipients: Vec<u64> = self
+            .players_within(&sender_position, radius)
+            .into_iter()
+            .filter(|id| teammates.contains(id))
+            .filter_map(|id| players.get(&id).map(|state| state.player.session_id))
+            .collect();
+        let message = self.quick_pings.render(player_id, ping, position);
+        drop(players);
+
+        self.quick_pings.record_sent(recipients.len());
+        if !recipients.is_empty() {
+            self.event_channels.publish(
+                room_id,
+                GameEvent::QuickPing {
+                    player_id,
+                    recipients,
+                    message,
+                },
+            );
+        }
+        Ok(None)
+    }
+
+    /// 빠른 소통 핑 처리기 (통계 조회용)
+    pub fn quick_pings(&self) -> Arc<QuickPings> {
+        self.quick_pings.clone()
+    }
+
+    /// 매치 결과 기록기 연결 (한 번만 가능)
+    pub fn attach_match_recorder(&self, recorder: Arc<MatchResultRecorder>) {
+        if self.match_recorder.set(recorder).is_err() {
+            warn!("Match result recorder already attached");
+        }
+    }
+
+    /// 로비 이벤트를 방 채널로 발행 (매치 종료 결과는 기록기로 전달)
+    fn publish_lobby_update(&self, update: LobbyUpdate) {
+        for event in update.events {
+            if let (LobbyEvent::MatchEnded(result), Some(recorder)) = (&event, self.match_recorder.get()) {
+                let recorder = recorder.clone();
+                let result = result.clone();
+                tokio::spawn(async move { recorder.record(&result).await });
+            }
+            self.event_channels.publish(
+                update.room_id,
+                GameEvent::Lobby {
+                    room_id: update.room_id,
+                    recipients: update.recipients.clone(),
+                    event,
+                },
+            );
+        }
+    }
+
+    /// 방 이벤트 구독자 생성
     ///
-    /// 게임 이벤트를 수신할 수 있는 구독자를 생성합니다.
+    /// 특정 방의 게임 이벤트를 수신할 수 있는 구독자를 생성합니다.
     /// 네트워크 레이어에서 클라이언트에게 이벤트를 전달하기 위해 사용됩니다.
     ///
+    /// # Arguments
+    /// * `room_id` - 구독할 방 ID (`LOBBY_ROOM_ID`는 방이 없는 플레이어용)
+    ///
     /// # Returns
     /// 이벤트 수신기
-    pub fn subscribe_events(&self) -> broadcast::Receiver<GameEvent> {
-        self.event_sender.subscribe()
+    pub fn subscribe_room_events(&self, room_id: RoomId) -> RoomEventReceiver {
+        self.event_channels.subscribe(room_id)
+    }
+
+    /// 방 단위 이벤트 채널 (통계/경고 점검용)
+    pub fn event_channels(&self) -> Arc<RoomEventChannels> {
+        self.event_channels.clone()
     }
 
     /// 현재 게임 통계 조회
@@ -1461,18 +1965,383 @@ impl GameStateManager {
         self.game_stats.read().await.clone()
     }
 
+    // === 관리자 기능 ===
+
+    /// 현재 틱 레이트 (TPS)
+    pub fn tick_rate(&self) -> u32 {
+        self.tick_rate.load(Ordering::Relaxed)
+    }
+
+    /// 최대 따라잡기 틱 수
+    pub fn max_catch_up_ticks(&self) -> u32 {
+        self.config.max_catch_up_ticks
+    }
+
+    /// 틱 루프 시간 지표 기록
+    pub fn record_timestep(&self, metrics: &TimestepMetrics) {
+        *self.timestep_metrics.lock() = metrics.clone();
+    }
+
+    /// 틱 루프 시간 지표 (시뮬레이션 시간 대 실제 시간)
+    pub fn timestep_metrics(&self) -> TimestepMetrics {
+        self.timestep_metrics.lock().clone()
+    }
+
+    /// 이동 입력 버퍼 상태
+    pub fn input_buffer_stats(&self) -> InputBufferStats {
+        self.input_buffers.lock().stats()
+    }
+
+    /// 틱 레이트 변경
+    ///
+    /// 게임 틱 루프가 다음 틱에서 변경된 값을 반영합니다.
+    pub fn set_tick_rate(&self, tick_rate: u32) -> Result<()> {
+        if tick_rate == 0 || tick_rate > 120 {
+            return Err(anyhow!("Invalid tick rate: {} (must be 1-120)", tick_rate));
+        }
+
+        let previous = self.tick_rate.swap(tick_rate, Ordering::Relaxed);
+        info!(previous = %previous, tick_rate = %tick_rate, "Tick rate changed");
+        Ok(())
+    }
+
+    /// 활성 플레이어 요약 목록 (ID 순)
+    pub async fn player_summaries(&self) -> Vec<PlayerSummary> {
+        let players = self.active_players.read().await;
+        let mut summaries: Vec<PlayerSummary> = players
+            .values()
+            .map(|state| state.player.get_summary())
+            .collect();
+        summaries.sort_by_key(|summary| summary.id);
+        summaries
+    }
+
+    /// 히트 판정 디버그 스트림
+    pub fn hitreg_debugger(&self) -> Arc<HitRegDebugger> {
+        self.hitreg_debugger.clone()
+    }
+
+    /// 플레이어가 사용 중인 세션 ID 조회
+    pub async fn session_of_player(&self, player_id: PlayerId) -> Option<u64> {
+        self.active_players
+            .read()
+            .await
+            .get(&player_id)
+            .map(|state| state.player.session_id)
+    }
+
+    /// 같은 방(방이 없으면 로비)에 있는 다른 플레이어들의 세션 ID
+    pub async fn room_peer_sessions(&self, player_id: PlayerId) -> Vec<u64> {
+        let players = self.active_players.read().await;
+        let Some(room_id) = players.get(&player_id).map(|state| state.player.room_id) else {
+            return Vec::new();
+        };
+        players
+            .iter()
+            .filter(|(id, state)| **id != player_id && state.player.room_id == room_id)
+            .map(|(_, state)| state.player.session_id)
+            .collect()
+    }
+
+    /// 플레이어를 지정 위치로 즉시 이동 (이동 검증 생략)
+    pub async fn teleport_player(&self, player_id: PlayerId, position: Position) -> Result<()> {
+        let old_position = {
+            let mut players = self.active_players.write().await;
+            let state = players
+                .get_mut(&player_id)
+                .ok_or_else(|| anyhow!("Player not found: {}", player_id))?;
+
+            let old_position = state.player.position;
+            state.player.position = position;
+            state.player.velocity = Velocity::default();
+            state.movement_prediction.predicted_position = position;
+            state.movement_prediction.velocity = Velocity::default();
+            old_position
+        };
+
+        self.publish_event(
+            player_id,
+            GameEvent::PlayerMoved {
+                player_id,
+                old_position,
+                new_position: position,
+                velocity: Velocity::default(),
+            },
+        )
+        .await;
+
+        info!(
+            player_id = %player_id,
+            from = ?old_position,
+            to = ?position,
+            "Player teleported"
+        );
+        Ok(())
+    }
+
+    // === 장애 조치 복제 ===
+
+    /// 마지막으로 실행한 서버 틱
+    pub fn current_tick(&self) -> u64 {
+        self.current_tick.load(Ordering::Relaxed)
+    }
+
+    /// 방별 복제 상태 (방 ID 순, 방에 들어간 플레이어와 진행 중인 매치만)
+    pub async fn replication_snapshot(&self) -> Vec<RoomState> {
+        let mut rooms: BTreeMap<RoomId, RoomState> = BTreeMap::new();
+        {
+            let players = self.active_players.read().await;
+            for state in players.values() {
+                if let Some(room_id) = state.player.room_id {
+                    rooms
+                        .entry(room_id)
+                        .or_insert_with(|| RoomState::new(room_id))
+                        .players
+                        .push(ReplicatedPlayer::from_player(&state.player));
+                }
+            }
+        }
+        for (room_id, checkpoint) in self.match_manager.checkpoints() {
+            rooms.entry(room_id).or_insert_with(|| RoomState::new(room_id)).active_match = Some(checkpoint);
+        }
+        for room in rooms.values_mut() {
+            room.players.sort_by_key(|player| player.player_id);
+        }
+        rooms.into_values().collect()
+    }
+
+    /// 대기 서버에서 넘겨받은 상태 복원
+    ///
+    /// 진행 중인 매치는 바로 복원하고, 플레이어는 재접속할 때 복제된 상태를 적용합니다.
+    /// 이전에 복원했지만 재접속하지 않은 플레이어는 버립니다.
+    ///
+    /// # Returns
+    /// (복원한 매치 수, 재접속을 기다리는 플레이어 수)
+    pub fn restore_replica(&self, rooms: Vec<RoomState>) -> (usize, usize) {
+        let now = self.clock.instant();
+        let mut pending = self.pending_restores.lock();
+        pending.clear();
+
+        let mut matches = 0;
+        for room in rooms {
+            if let Some(checkpoint) = room.active_match {
+                if self.match_manager.restore(room.room_id, checkpoint, now) {
+                    matches += 1;
+                }
+            }
+            for player in room.players {
+                pending.insert(player.player_id, (room.room_id, player));
+            }
+        }
+        info!(matches, players = pending.len(), "Replicated state restored");
+        (matches, pending.len())
+    }
+
     // === 내부 헬퍼 메서드들 ===
 
+    /// AFK 플레이어 점검
+    ///
+    /// 경고/관전자 전환은 이벤트만 발행하고, 연결 해제는 `DisconnectReason::Afk`로 정리한 뒤
+    /// 네트워크 레이어가 세션을 종료할 수 있도록 `PlayerAfkDisconnected`를 발행합니다.
+    async fn check_afk_players(&self) {
+        let afk_config = AfkConfig::from_secs(
+            self.config.afk_warning_secs,
+            self.config.afk_spectator_secs,
+            self.config.afk_disconnect_secs,
+        );
+        if !afk_config.is_enabled() {
+            return;
+        }
+
+        let now = self.clock.instant();
+        let mut actions = Vec::new();
+        {
+            let mut players = self.active_players.write().await;
+            for (player_id, state) in players.iter_mut() {
+                let idle = now.duration_since(state.last_input_time);
+                if let Some(action) = afk_config.evaluate(state.afk_stage, idle) {
+                    if let Some(stage) = action.next_stage() {
+                        state.afk_stage = stage;
+                    }
+                    if matches!(action, AfkAction::MoveToSpectator { .. }) {
+                        state.player.state = PlayerState::Spectating;
+                        state.player.velocity = Velocity::default();
+                        state.current_target = None;
+                    }
+                    actions.push((*player_id, state.player.session_id, action));
+                }
+            }
+        }
+
+        for (player_id, session_id, action) in actions {
+            match action {
+                AfkAction::Warn {
+                    idle_secs,
+                    disconnect_in_secs,
+                } => {
+                    debug!(player_id = %player_id, idle_secs = %idle_secs, "AFK warning");
+                    self.publish_event(
+                        player_id,
+                        GameEvent::PlayerAfkWarning {
+                            player_id,
+                            session_id,
+                            idle_secs,
+                            disconnect_in_secs,
+                        },
+                    )
+                    .await;
+                }
+                AfkAction::MoveToSpectator { idle_secs } => {
+                    info!(
+                        player_id = %player_id,
+                        idle_secs = %idle_secs,
+                        "AFK player moved to spectator"
+                    );
+                    self.publish_event(
+                        player_id,
+                        GameEvent::PlayerMovedToSpectator {
+                            player_id,
+                            session_id,
+                            idle_secs,
+                        },
+                    )
+                    .await;
+                }
+                AfkAction::Disconnect { idle_secs } => {
+                    info!(
+                        player_id = %player_id,
+                        idle_secs = %idle_secs,
+                        "Disconnecting AFK player"
+                    );
+                    let room_id = self
+                        .active_players
+                        .read()
+                        .await
+                        .get(&player_id)
+                        .and_then(|state| state.player.room_id)
+                        .unwrap_or(LOBBY_ROOM_ID);
+
+                    if let Err(e) = self
+                        .handle_player_disconnect(session_id, DisconnectReason::Afk)
+                        .await
+                    {
+                        error!(
+                            player_id = %player_id,
+                            error = %e,
+                            "Failed to disconnect AFK player"
+                        );
+                        continue;
+                    }
+                    // 마지막 인원이 나가 방이 닫혔으면 로비 채널로 보내 네트워크 세션을 정리
+                    let event = GameEvent::PlayerAfkDisconnected {
+                        player_id,
+                        session_id,
+                        idle_secs,
+                    };
+                    if !self.event_channels.publish(room_id, event.clone()) {
+                        self.event_channels.publish(LOBBY_ROOM_ID, event);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 빈 방의 이벤트 채널 제거
+    ///
+    /// 채널이 닫히면 방 구독 태스크는 남은 이벤트를 전송한 뒤 종료됩니다.
+    fn close_room(&self, room_id: RoomId) {
+        if room_id != LOBBY_ROOM_ID && self.event_channels.remove_room(room_id) {
+            debug!(room_id = %room_id, "Room closed, event channel removed");
+        }
+    }
+
+    /// 플레이어가 속한 방 채널로 이벤트 발행
+    async fn publish_event(&self, player_id: PlayerId, event: GameEvent) {
+        let room_id = self
+            .active_players
+            .read()
+            .await
+            .get(&player_id)
+            .and_then(|state| state.player.room_id)
+            .unwrap_or(LOBBY_ROOM_ID);
+        self.event_channels.publish(room_id, event);
+    }
+
+    /// 히트 판정 디버그 기록 생성
+    fn build_hitreg_record(
+        &self,
+        probe: HitRegProbe,
+        target: &AttackTarget,
+        attack_type: &AttackType,
+        weapon_id: Option<u32>,
+        result: Option<&AttackResultData>,
+    ) -> HitRegRecord {
+        let weapon = weapon_id.and_then(|id| self.weapons.weapon(id));
+        let max_range = self
+            .weapons
+            .attack_range(attack_type, weapon, self.config.max_combat_range);
+        let distance = probe
+            .target_position
+            .map(|pos| probe.attacker_position.distance_to(&pos));
+
+        let outcome = match (result, distance) {
+            (None, _) => HitTestOutcome::TargetNotFound,
+            (Some(result), _) if result.hit => HitTestOutcome::Hit,
+            (Some(_), Some(distance)) if distance > max_range => HitTestOutcome::OutOfRange,
+            (Some(_), _) => HitTestOutcome::Miss,
+        };
+
+        HitRegRecord {
+            sequence: self.hitreg_debugger.next_sequence(),
+            server_timestamp: self.current_timestamp(),
+            target: target.clone(),
+            attack_type: attack_type.clone(),
+            weapon_id,
+            attacker_position: probe.attacker_position,
+            target_position: probe.target_position,
+            distance,
+            max_range,
+            rewind_window_ms: probe.rewind_window_ms,
+            outcome,
+            damage_dealt: result.map_or(0, |result| result.damage_dealt),
+        }
+    }
+
+    /// 히트 판정 기록 보관 및 레이트 리밋 내에서 디버그 이벤트 발행
+    async fn publish_hitreg_record(
+        &self,
+        player_id: PlayerId,
+        session_id: u64,
+        record: Option<HitRegRecord>,
+    ) {
+        let Some(record) = record else {
+            return;
+        };
+        if let Some(records) = self.hitreg_debugger.record(player_id, record, self.clock.instant()) {
+            self.publish_event(
+                player_id,
+                GameEvent::HitRegDebug {
+                    player_id,
+                    session_id,
+                    records,
+                },
+            )
+            .await;
+        }
+    }
+
     /// 현재 타임스탬프 반환 (밀리초)
     fn current_timestamp(&self) -> u64 {
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64
+        self.clock.unix_millis().max(0) as u64
     }
 
-    /// JWT 토큰 검증 (간소화된 버전)
+    /// 인증 토큰 검증 (입장 티켓 검증이 설정되지 않으면 간소화된 버전)
     async fn verify_auth_token(&self, token: &str) -> Result<PlayerId> {
+        if let Some((signer, address)) = &self.entry_tickets {
+            let ticket = signer.verify(token, ENTRY_TICKET_PROTOCOL, address)?;
+            return Ok(ticket.user_id);
+        }
+
         // TODO: 실제 JWT 검증 구현
         // 현재는 토큰을 플레이어 ID로 파싱
         token
@@ -1543,15 +2412,19 @@ impl GameStateManager {
             .get_mut(&target_id)
             .ok_or_else(|| anyhow!("Target not found"))?;
 
-        // 거리 확인
+        // 거리 확인 (무기가 있으면 무기 사거리)
+        let weapon = weapon_id.and_then(|id| self.weapons.weapon(id));
+        if let (Some(id), None) = (weapon_id, weapon) {
+            debug!(
+                attacker_id = %attacker_id,
+                weapon_id = %id,
+                "Unknown weapon, attacking unarmed"
+            );
+        }
         let distance = attacker_pos.distance_to(&target.player.position);
-        let max_range = match attack_type {
-            AttackType::MeleeBasic | AttackType::MeleeHeavy => self.config.max_combat_range,
-            AttackType::Ranged => self.config.max_combat_range * 3.0,
-            AttackType::Magic => self.config.max_combat_range * 2.0,
-            AttackType::AreaOfEffect => self.config.max_combat_range * 1.5,
-            AttackType::Skill { .. } => self.config.max_combat_range * 4.0,
-        };
+        let max_range = self
+            .weapons
+            .attack_range(attack_type, weapon, self.config.max_combat_range);
 
         if distance > max_range {
             return Ok(AttackResultData {
@@ -1564,7 +2437,7 @@ impl GameStateManager {
 
         // 데미지 계산
         let base_damage = attacker_attack_power;
-        let weapon_damage = weapon_id.map(|_| 10).unwrap_or(0); // 간소화
+        let weapon_damage = WeaponCatalog::weapon_damage(weapon, distance);
         let total_attack = base_damage + weapon_damage;
 
         // 치명타 확인 (10% 확률)
@@ -1681,9 +2554,49 @@ impl GameStateManager {
         Ok(())
     }
 
-    /// 기본 스폰 위치 반환
-    async fn get_default_spawn_position(&self) -> Position {
-        Position::new(5000.0 / 2.0, 0.0, 5000.0 / 2.0)
+    /// 리스폰 위치 선택
+    ///
+    /// 같은 방의 생존한 적(다른 팀, 팀이 없으면 다른 모든 플레이어) 위치를 모아 전략에 전달합니다.
+    async fn select_respawn_position(
+        &self,
+        player_id: PlayerId,
+        death_position: Position,
+    ) -> Position {
+        let (team, enemy_positions) = {
+            let players = self.active_players.read().await;
+            let me = players.get(&player_id).map(|state| &state.player);
+            let team = me.and_then(|player| player.team);
+            let room_id = me.and_then(|player| player.room_id);
+
+            let enemies: Vec<Position> = players
+                .values()
+                .map(|state| &state.player)
+                .filter(|other| {
+                    other.id != player_id
+                        && other.room_id == room_id
+                        && other.stats.is_alive()
+                        && (team.is_none() || other.team != team)
+                })
+                .map(|other| other.position)
+                .collect();
+            (team, enemies)
+        };
+
+        let context = RespawnContext {
+            team,
+            death_position,
+            enemy_positions: &enemy_positions,
+        };
+        let selector = self.respawn_selector.read().await;
+        selector.select(&context, &mut ::rand::thread_rng())
+    }
+
+    /// 맵 스폰 포인트 설정 (맵 로드 시 호출)
+    pub async fn set_spawn_points(&self, spawn_points: Vec<SpawnPoint>) {
+        self.respawn_selector
+            .write()
+            .await
+            .set_spawn_points(spawn_points);
     }
 
     /// 플레이어 데이터 저장
@@ -1708,7 +2621,7 @@ impl GameStateManager {
     /// 만료된 전투 세션 정리
     async fn cleanup_expired_combats(&self) {
         let mut combats = self.active_combats.write().await;
-        let now = Instant::now();
+        let now = self.clock.instant();
         combats.retain(|_, combat| {
             now.duration_since(combat.last_action_time) < combat.timeout_duration
         });
@@ -1727,7 +2640,7 @@ impl GameStateManager {
     async fn update_game_statistics(&self) {
         let mut stats = self.game_stats.write().await;
         stats.active_players = self.active_players.read().await.len() as u32;
-        stats.last_updated = Instant::now();
+        stats.last_updated = self.clock.instant();
     }
 }
 
@@ -1743,18 +2656,72 @@ impl Clone for GameStateManager {
             active_players: self.active_players.clone(),
             active_combats: self.active_combats.clone(),
             respawn_queue: self.respawn_queue.clone(),
-            event_sender: self.event_sender.clone(),
+            respawn_selector: self.respawn_selector.clone(),
+            weapons: self.weapons.clone(),
+            hitreg_debugger: self.hitreg_debugger.clone(),
+            quick_pings: self.quick_pings.clone(),
+            match_manager: self.match_manager.clone(),
+            match_recorder: self.match_recorder.clone(),
+            event_channels: self.event_channels.clone(),
             security_middleware: self.security_middleware.clone(),
             redis_optimizer: self.redis_optimizer.clone(),
             game_stats: self.game_stats.clone(),
+            tick_rate: self.tick_rate.clone(),
+            timestep_metrics: self.timestep_metrics.clone(),
+            clock: self.clock.clone(),
+            spatial_index: self.spatial_index.clone(),
+            current_tick: self.current_tick.clone(),
+            input_buffers: self.input_buffers.clone(),
+            pending_restores: self.pending_restores.clone(),
+            entry_tickets: self.entry_tickets.clone(),
+        }
+    }
+}
+
+/// 세션 종료 사유를 게임 연결 해제 사유로 변환
+impl From<&SessionTerminationReason> for DisconnectReason {
+    fn from(reason: &SessionTerminationReason) -> Self {
+        match reason {
+            SessionTerminationReason::ClientRequest
+            | SessionTerminationReason::ServerShutdown
+            | SessionTerminationReason::DuplicateLogin => DisconnectReason::Normal,
+            SessionTerminationReason::Timeout => DisconnectReason::Timeout,
+            SessionTerminationReason::NetworkError(_)
+            | SessionTerminationReason::ServerOverload => DisconnectReason::NetworkError,
+            SessionTerminationReason::AdminKick(_) => DisconnectReason::Kicked,
+            SessionTerminationReason::Afk => DisconnectReason::Afk,
+            SessionTerminationReason::AuthenticationFailed
+            | SessionTerminationReason::Other(_) => DisconnectReason::ClientError,
+        }
+    }
+}
+
+/// 세션 계층 이벤트 연동
+///
+/// 전송 계층에서 세션이 종료(타임아웃 포함)되면 게임 상태에서도 플레이어를 정리합니다.
+#[async_trait::async_trait]
+impl SessionEventListener for GameStateManager {
+    async fn on_session_event(&self, event: &SessionEvent) {
+        if let SessionEvent::Terminated {
+            session_id, reason, ..
+        } = event
+        {
+            if let Err(e) = self
+                .handle_player_disconnect(*session_id, DisconnectReason::from(reason))
+                .await
+            {
+                error!(
+                    session_id = %session_id,
+                    error = %e,
+                    "Failed to clean up game state for terminated session"
+                );
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::config::WorldConfig;
-
     #[tokio::test]
     async fn test_player_connection_flow() {
         // TODO: 연결 플로우 테스트 구현
@@ -1774,6 +2741,42 @@ mod tests {
     async fn test_death_and_respawn() {
         // TODO: 사망/리스폰 테스트 구현
     }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_respawn_uses_spawn_points_file() {
+        use crate::config::GameConfig;
+        use crate::game::messages::Position;
+        use crate::game::respawn::RespawnStrategy;
+        use crate::network::dispatch::tests::dispatcher_with;
+
+        let path = std::env::temp_dir().join(format!("spawn_points_{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"
+            [[spawn_points]]
+            position = { x = 100.0, y = 0.0, z = 100.0 }
+
+            [[spawn_points]]
+            position = { x = 4000.0, y = 0.0, z = 4000.0 }
+            "#,
+        )
+        .unwrap();
+        let config = GameConfig {
+            respawn_strategy: RespawnStrategy::NearestSafePoint,
+            spawn_points_file: path.to_string_lossy().into_owned(),
+            ..GameConfig::development()
+        };
+        let dispatcher = dispatcher_with(config).await;
+        std::fs::remove_file(&path).unwrap();
+
+        // 맵 중앙이 아니라 사망 위치에서 가장 가까운 설정 포인트
+        let position = dispatcher
+            .game_state()
+            .select_respawn_position(1, Position::new(0.0, 0.0, 0.0))
+            .await;
+        assert_eq!(position, Position::new(100.0, 0.0, 100.0));
+    }
 }
 
 // 추가 모듈들을 위한 rand crate 시뮬레이션
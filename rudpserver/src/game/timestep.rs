@@ -0,0 +1,142 @@
+//! 고정 타임스텝 누적기
+//!
+//! 틱 루프가 늦게 깨어나도(느린 Redis 호출, 스케줄링 지연 등) 시뮬레이션 시간이 사라지지 않도록
+//! 실제 경과 시간을 누적해 고정 간격 스텝으로 나눠 실행합니다.
+//! 한 프레임에 따라잡는 스텝 수는 `max_catch_up_steps`로 제한하며,
+//! 그 이상 밀린 시간은 버리고 `dropped`로 기록합니다. (죽음의 나선 방지)
+
+use std::time::{Duration, Instant};
+
+/// 시뮬레이션 시간 대 실제 시간 지표
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimestepMetrics {
+    /// 실행한 총 스텝 수
+    pub steps: u64,
+    /// 진행된 시뮬레이션 시간
+    pub simulated: Duration,
+    /// 실제 경과 시간
+    pub wall: Duration,
+    /// 따라잡기 한도를 넘어 버린 시간
+    pub dropped: Duration,
+    /// 한 프레임에 2스텝 이상 실행한 횟수
+    pub catch_up_frames: u64,
+    /// 한 프레임에 실행한 최대 스텝 수
+    pub max_steps_in_frame: u32,
+}
+
+impl TimestepMetrics {
+    /// 실제 시간 - 시뮬레이션 시간 (ms, 양수면 시뮬레이션이 뒤처짐)
+    pub fn drift_ms(&self) -> f64 {
+        (self.wall.as_secs_f64() - self.simulated.as_secs_f64()) * 1000.0
+    }
+}
+
+/// 고정 타임스텝 누적기
+#[derive(Debug, Clone)]
+pub struct FixedTimestep {
+    step: Duration,
+    max_catch_up_steps: u32,
+    accumulator: Duration,
+    last: Option<Instant>,
+    metrics: TimestepMetrics,
+}
+
+impl FixedTimestep {
+    /// # Arguments
+    /// * `tick_rate` - 초당 스텝 수
+    /// * `max_catch_up_steps` - 한 프레임에 실행할 최대 스텝 수 (최소 1)
+    pub fn new(tick_rate: u32, max_catch_up_steps: u32) -> Self {
+        Self {
+            step: step_for(tick_rate),
+            max_catch_up_steps: max_catch_up_steps.max(1),
+            accumulator: Duration::ZERO,
+            last: None,
+            metrics: TimestepMetrics::default(),
+        }
+    }
+
+    /// 스텝 간격
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// 틱 레이트 변경 (누적된 시간은 유지)
+    pub fn set_tick_rate(&mut self, tick_rate: u32) {
+        self.step = step_for(tick_rate);
+    }
+
+    /// 현재 시각까지 경과한 시간을 누적하고 이번 프레임에 실행할 스텝 수를 반환
+    ///
+    /// 첫 호출은 한 스텝을 실행합니다.
+    pub fn advance(&mut self, now: Instant) -> u32 {
+        let elapsed = match self.last.replace(now) {
+            Some(last) => now.saturating_duration_since(last),
+            None => self.step,
+        };
+        self.metrics.wall += elapsed;
+        self.accumulator += elapsed;
+
+        let due = (self.accumulator.as_nanos() / self.step.as_nanos()).min(u32::MAX as u128) as u32;
+        let steps = due.min(self.max_catch_up_steps);
+        if due > steps {
+            // 한도를 넘는 지연은 따라잡지 않고 버림
+            let dropped = self.accumulator - self.step * steps;
+            self.metrics.dropped += dropped;
+            self.accumulator = Duration::ZERO;
+        } else {
+            self.accumulator -= self.step * steps;
+        }
+
+        self.metrics.steps += steps as u64;
+        self.metrics.simulated += self.step * steps;
+        if steps > 1 {
+            self.metrics.catch_up_frames += 1;
+        }
+        self.metrics.max_steps_in_frame = self.metrics.max_steps_in_frame.max(steps);
+        steps
+    }
+
+    pub fn metrics(&self) -> &TimestepMetrics {
+        &self.metrics
+    }
+}
+
+fn step_for(tick_rate: u32) -> Duration {
+    Duration::from_secs(1) / tick_rate.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hitch_is_caught_up() {
+        let start = Instant::now();
+        let mut timestep = FixedTimestep::new(50, 5); // 20ms
+        assert_eq!(timestep.advance(start), 1);
+        assert_eq!(timestep.advance(start + Duration::from_millis(20)), 1);
+        // 70ms 지연 → 3스텝 실행, 10ms는 다음 프레임으로
+        assert_eq!(timestep.advance(start + Duration::from_millis(90)), 3);
+        assert_eq!(timestep.advance(start + Duration::from_millis(100)), 1);
+
+        let metrics = timestep.metrics();
+        assert_eq!(metrics.steps, 6);
+        assert_eq!(metrics.catch_up_frames, 1);
+        assert_eq!(metrics.dropped, Duration::ZERO);
+        assert!(metrics.drift_ms().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_catch_up_is_bounded() {
+        let start = Instant::now();
+        let mut timestep = FixedTimestep::new(50, 3);
+        timestep.advance(start);
+        // 1초 멈춤 → 3스텝만 실행하고 나머지는 버림
+        assert_eq!(timestep.advance(start + Duration::from_secs(1)), 3);
+
+        let metrics = timestep.metrics();
+        assert_eq!(metrics.max_steps_in_frame, 3);
+        assert_eq!(metrics.dropped, Duration::from_millis(1020 - 80));
+        assert!((metrics.drift_ms() - 940.0).abs() < 1e-6);
+    }
+}
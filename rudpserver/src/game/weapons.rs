@@ -0,0 +1,355 @@
+//! 무기/공격 데이터
+//!
+//! 공격 타입별 쿨다운·사거리 배율과 무기별 사거리·쿨다운·데미지 곡선을
+//! `property/weapons.toml`에서 읽어옵니다. 밸런스 조정에 코드 변경이 필요하지 않도록
+//! 하드코딩된 값을 대체하며, 서버 시작 시 검증에 실패하면 기동을 중단합니다.
+//!
+//! # 파일 형식
+//! ```toml
+//! [attack_types]
+//! melee_basic = { cooldown_ms = 1000, range_multiplier = 1.0 }
+//!
+//! [[weapons]]
+//! id = 1
+//! name = "baton"
+//! range = 12.0
+//! cooldown_ms = 800
+//! damage = 10
+//! damage_curve = { falloff_start = 1.0, min_multiplier = 1.0 }
+//! ```
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::game::messages::AttackType;
+
+/// 공격 타입별 기본 수치
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AttackTypeProfile {
+    /// 공격 후 쿨다운 (밀리초)
+    pub cooldown_ms: u64,
+    /// `max_combat_range` 대비 사거리 배율
+    pub range_multiplier: f32,
+}
+
+impl AttackTypeProfile {
+    const fn new(cooldown_ms: u64, range_multiplier: f32) -> Self {
+        Self {
+            cooldown_ms,
+            range_multiplier,
+        }
+    }
+}
+
+/// 공격 타입 테이블 (파일에 없는 항목은 기본값 사용)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AttackTypeTable {
+    pub melee_basic: AttackTypeProfile,
+    pub melee_heavy: AttackTypeProfile,
+    pub ranged: AttackTypeProfile,
+    pub magic: AttackTypeProfile,
+    pub area_of_effect: AttackTypeProfile,
+    pub skill: AttackTypeProfile,
+}
+
+impl Default for AttackTypeTable {
+    fn default() -> Self {
+        Self {
+            melee_basic: AttackTypeProfile::new(1000, 1.0),
+            melee_heavy: AttackTypeProfile::new(3000, 1.0),
+            ranged: AttackTypeProfile::new(1500, 3.0),
+            magic: AttackTypeProfile::new(2000, 2.0),
+            area_of_effect: AttackTypeProfile::new(5000, 1.5),
+            skill: AttackTypeProfile::new(8000, 4.0),
+        }
+    }
+}
+
+impl AttackTypeTable {
+    /// 공격 타입에 해당하는 수치
+    pub fn profile(&self, attack_type: &AttackType) -> &AttackTypeProfile {
+        match attack_type {
+            AttackType::MeleeBasic => &self.melee_basic,
+            AttackType::MeleeHeavy => &self.melee_heavy,
+            AttackType::Ranged => &self.ranged,
+            AttackType::Magic => &self.magic,
+            AttackType::AreaOfEffect => &self.area_of_effect,
+            AttackType::Skill { .. } => &self.skill,
+        }
+    }
+
+    fn iter(&self) -> [(&'static str, &AttackTypeProfile); 6] {
+        [
+            ("melee_basic", &self.melee_basic),
+            ("melee_heavy", &self.melee_heavy),
+            ("ranged", &self.ranged),
+            ("magic", &self.magic),
+            ("area_of_effect", &self.area_of_effect),
+            ("skill", &self.skill),
+        ]
+    }
+}
+
+/// 거리에 따른 데미지 감쇠 곡선
+///
+/// 사거리 대비 거리 비율이 `falloff_start`까지는 100%, 이후 사거리 끝에서
+/// `min_multiplier`가 되도록 선형으로 감소합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DamageCurve {
+    /// 감쇠 시작 지점 (사거리 대비 0.0 ~ 1.0)
+    pub falloff_start: f32,
+    /// 최대 사거리에서의 데미지 배율 (0.0 ~ 1.0)
+    pub min_multiplier: f32,
+}
+
+impl Default for DamageCurve {
+    fn default() -> Self {
+        Self {
+            falloff_start: 1.0,
+            min_multiplier: 1.0,
+        }
+    }
+}
+
+impl DamageCurve {
+    /// 사거리 대비 거리 비율에 대한 데미지 배율
+    pub fn multiplier(&self, distance_ratio: f32) -> f32 {
+        let ratio = distance_ratio.clamp(0.0, 1.0);
+        if ratio <= self.falloff_start || self.falloff_start >= 1.0 {
+            return 1.0;
+        }
+        let progress = (ratio - self.falloff_start) / (1.0 - self.falloff_start);
+        1.0 - progress * (1.0 - self.min_multiplier)
+    }
+}
+
+/// 무기 정의
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeaponDefinition {
+    pub id: u32,
+    pub name: String,
+    /// 사거리 (게임 단위)
+    pub range: f32,
+    /// 공격 후 쿨다운 (밀리초)
+    pub cooldown_ms: u64,
+    /// 추가 데미지
+    pub damage: u32,
+    /// 거리별 데미지 감쇠
+    #[serde(default)]
+    pub damage_curve: DamageCurve,
+}
+
+/// 무기 파일 원본 구조
+#[derive(Debug, Deserialize)]
+struct WeaponsFile {
+    #[serde(default)]
+    attack_types: AttackTypeTable,
+    #[serde(default)]
+    weapons: Vec<WeaponDefinition>,
+}
+
+/// 무기 카탈로그
+#[derive(Debug, Clone, Default)]
+pub struct WeaponCatalog {
+    attack_types: AttackTypeTable,
+    weapons: HashMap<u32, WeaponDefinition>,
+}
+
+impl WeaponCatalog {
+    /// TOML 파일에서 로드 (파일이 없으면 기본값)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            warn!(path = %path.display(), "Weapons file not found, using built-in defaults");
+            return Ok(Self::default());
+        }
+
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read weapons file: {}", path.display()))?;
+        let catalog = Self::from_toml_str(&contents)
+            .with_context(|| format!("Invalid weapons file: {}", path.display()))?;
+
+        info!(
+            path = %path.display(),
+            weapons = %catalog.weapons.len(),
+            "Weapons loaded"
+        );
+        Ok(catalog)
+    }
+
+    /// TOML 문자열 파싱 및 검증
+    pub fn from_toml_str(contents: &str) -> Result<Self> {
+        let file: WeaponsFile = toml::from_str(contents)?;
+
+        for (name, profile) in file.attack_types.iter() {
+            if profile.cooldown_ms == 0 {
+                return Err(anyhow!("attack_types.{}: cooldown_ms must be > 0", name));
+            }
+            if profile.range_multiplier.is_nan() || profile.range_multiplier <= 0.0 {
+                return Err(anyhow!(
+                    "attack_types.{}: range_multiplier must be > 0",
+                    name
+                ));
+            }
+        }
+
+        let mut weapons = HashMap::with_capacity(file.weapons.len());
+        for weapon in file.weapons {
+            validate_weapon(&weapon)?;
+            let id = weapon.id;
+            if weapons.insert(id, weapon).is_some() {
+                return Err(anyhow!("duplicate weapon id: {}", id));
+            }
+        }
+
+        Ok(Self {
+            attack_types: file.attack_types,
+            weapons,
+        })
+    }
+
+    /// 무기 조회
+    pub fn weapon(&self, weapon_id: u32) -> Option<&WeaponDefinition> {
+        self.weapons.get(&weapon_id)
+    }
+
+    /// 공격 사거리 (무기가 있으면 무기 사거리)
+    pub fn attack_range(
+        &self,
+        attack_type: &AttackType,
+        weapon: Option<&WeaponDefinition>,
+        base_range: f32,
+    ) -> f32 {
+        match weapon {
+            Some(weapon) => weapon.range,
+            None => base_range * self.attack_types.profile(attack_type).range_multiplier,
+        }
+    }
+
+    /// 공격 쿨다운 (무기가 있으면 무기 쿨다운)
+    pub fn cooldown(
+        &self,
+        attack_type: &AttackType,
+        weapon: Option<&WeaponDefinition>,
+    ) -> Duration {
+        let cooldown_ms = match weapon {
+            Some(weapon) => weapon.cooldown_ms,
+            None => self.attack_types.profile(attack_type).cooldown_ms,
+        };
+        Duration::from_millis(cooldown_ms)
+    }
+
+    /// 거리 감쇠가 적용된 무기 추가 데미지
+    pub fn weapon_damage(weapon: Option<&WeaponDefinition>, distance: f32) -> u32 {
+        match weapon {
+            Some(weapon) => {
+                let multiplier = weapon.damage_curve.multiplier(distance / weapon.range);
+                (weapon.damage as f32 * multiplier).round() as u32
+            }
+            None => 0,
+        }
+    }
+}
+
+fn validate_weapon(weapon: &WeaponDefinition) -> Result<()> {
+    let curve = &weapon.damage_curve;
+    if weapon.range.is_nan() || weapon.range <= 0.0 {
+        return Err(anyhow!("weapon {}: range must be > 0", weapon.id));
+    }
+    if weapon.cooldown_ms == 0 {
+        return Err(anyhow!("weapon {}: cooldown_ms must be > 0", weapon.id));
+    }
+    if !(0.0..=1.0).contains(&curve.falloff_start) || !(0.0..=1.0).contains(&curve.min_multiplier) {
+        return Err(anyhow!(
+            "weapon {}: damage_curve values must be within 0.0..=1.0",
+            weapon.id
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+        [attack_types]
+        melee_basic = { cooldown_ms = 900, range_multiplier = 1.0 }
+        melee_heavy = { cooldown_ms = 3000, range_multiplier = 1.0 }
+        ranged = { cooldown_ms = 1500, range_multiplier = 3.0 }
+        magic = { cooldown_ms = 2000, range_multiplier = 2.0 }
+        area_of_effect = { cooldown_ms = 5000, range_multiplier = 1.5 }
+        skill = { cooldown_ms = 8000, range_multiplier = 4.0 }
+
+        [[weapons]]
+        id = 2
+        name = "taser"
+        range = 20.0
+        cooldown_ms = 1200
+        damage = 40
+        damage_curve = { falloff_start = 0.5, min_multiplier = 0.5 }
+    "#;
+
+    #[test]
+    fn test_load_and_lookup() {
+        let catalog = WeaponCatalog::from_toml_str(SAMPLE).unwrap();
+        let taser = catalog.weapon(2);
+
+        assert_eq!(
+            catalog.cooldown(&AttackType::MeleeBasic, None),
+            Duration::from_millis(900)
+        );
+        assert_eq!(
+            catalog.cooldown(&AttackType::MeleeBasic, taser),
+            Duration::from_millis(1200)
+        );
+        assert_eq!(catalog.attack_range(&AttackType::Ranged, None, 10.0), 30.0);
+        assert_eq!(catalog.attack_range(&AttackType::Ranged, taser, 10.0), 20.0);
+    }
+
+    #[test]
+    fn test_damage_curve() {
+        let catalog = WeaponCatalog::from_toml_str(SAMPLE).unwrap();
+        let taser = catalog.weapon(2);
+
+        assert_eq!(WeaponCatalog::weapon_damage(taser, 5.0), 40);
+        assert_eq!(WeaponCatalog::weapon_damage(taser, 15.0), 30);
+        assert_eq!(WeaponCatalog::weapon_damage(taser, 20.0), 20);
+        assert_eq!(WeaponCatalog::weapon_damage(None, 5.0), 0);
+    }
+
+    #[test]
+    fn test_validation_rejects_bad_data() {
+        let duplicate = r#"
+            [[weapons]]
+            id = 1
+            name = "a"
+            range = 10.0
+            cooldown_ms = 100
+            damage = 1
+            [[weapons]]
+            id = 1
+            name = "b"
+            range = 10.0
+            cooldown_ms = 100
+            damage = 1
+        "#;
+        assert!(WeaponCatalog::from_toml_str(duplicate).is_err());
+
+        let zero_range = r#"
+            [[weapons]]
+            id = 1
+            name = "a"
+            range = 0.0
+            cooldown_ms = 100
+            damage = 1
+        "#;
+        assert!(WeaponCatalog::from_toml_str(zero_range).is_err());
+    }
+}
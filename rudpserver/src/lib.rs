@@ -3,6 +3,7 @@
 //! tcpserver와 동일한 수준의 최적화를 적용한 고성능 RUDP 서버 라이브러리입니다.
 //! 16개 최적화 서비스로 구성된 엔터프라이즈급 실시간 통신 솔루션입니다.
 
+pub mod admin;
 pub mod config;
 pub mod game;
 pub mod handler;
@@ -16,3 +17,6 @@ pub mod utils;
 pub use handler::*;
 pub use protocol::*;
 pub use service::*;
+
+/// 크레이트 버전
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
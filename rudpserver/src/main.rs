@@ -19,13 +19,17 @@
 
 use anyhow::Result;
 use dotenv::{dotenv, from_path};
+use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::time::Duration;
 use std::{env, path::PathBuf, sync::Arc};
-use tokio::{signal, time::interval};
-use tracing::{error, info, warn};
+use tokio::{signal, time::{interval, MissedTickBehavior}};
+use tracing::{debug, error, info, warn};
+use tracing_subscriber::prelude::*;
 use tracing_subscriber::EnvFilter;
 
 // 내부 모듈들
+mod admin;
 mod config;
 mod game;
 mod network;
@@ -34,16 +38,34 @@ mod types;
 mod utils;
 
 // 모듈 사용
+use admin::AdminConsole;
 use config::RudpServerConfig;
-use game::{messages::GameMessage, player::PlayerManager, state_manager::GameStateManager};
+use game::{
+    event_channels::RoomId, match_results::MatchResultRecorder,
+    player::PlayerManager, state_manager::GameStateManager, timestep::FixedTimestep,
+};
+use game::replication::{self, RedisReplicationSink, ReplicationRole, ReplicationSink, Replicator};
+use network::dispatch::PacketDispatcher;
 use network::session::SessionManager;
 use protocol::rudp::RudpServer;
 use utils::performance::PerformanceMonitor;
 
 // Shared library imports
-use shared::security::SecurityMiddleware;
+use shared::monitoring::health::{HealthRegistry, ProbeKind};
+use shared::auth::ServiceTokenIssuer;
+use shared::monitoring::crash::{self, CrashConfig};
+use shared::monitoring::{AnomalyDetector, AnomalyRule, PlayerSampler, TaskAccounting};
+use shared::security::{AuditSink, EntryTicketSigner, SecurityMiddleware};
+use shared::service::redis::event_bus::EventBus;
+use shared::service::redis::live_config::LiveConfig;
+use shared::service::redis::server_stats::{ServerHeartbeat, DEFAULT_STATS_TTL_SECS};
+use shared::service::redis::state_replica::ReplicaStream;
 use shared::tool::high_performance::redis_optimizer::RedisOptimizer;
 
+/// 이상 탐지 메트릭 이름
+const P99_RTT_METRIC: &str = "rudp_p99_rtt_ms";
+const PACKET_LOSS_METRIC: &str = "rudp_packet_loss_percent";
+
 /// RUDP 게임 서버 메인 구조체
 ///
 /// 모든 게임 시스템을 통합 관리하는 최상위 서버 구조체입니다.
@@ -61,10 +83,12 @@ pub struct RudpGameServer {
     player_manager: Arc<PlayerManager>,
     /// 성능 모니터
     performance_monitor: Arc<PerformanceMonitor>,
-    /// 보안 미들웨어
-    security_middleware: Arc<SecurityMiddleware>,
     /// Redis 최적화기
     redis_optimizer: Arc<RedisOptimizer>,
+    /// 수신 패킷 처리 및 방 이벤트 전송 (메시지 타입별 킬 스위치 포함)
+    dispatcher: Arc<PacketDispatcher>,
+    /// p99 RTT, 패킷 손실률 이상 탐지
+    anomaly_detector: Arc<AnomalyDetector>,
 }
 
 impl RudpGameServer {
@@ -98,6 +122,7 @@ impl RudpGameServer {
                 enable_key_compression: false,
                 enable_value_compression: true,
                 default_ttl_secs: config.redis.session_ttl_secs as usize,
+                ..Default::default()
             };
         let redis_optimizer =
             Arc::new(RedisOptimizer::new(&redis_url, redis_optimizer_config).await?);
@@ -119,6 +144,8 @@ impl RudpGameServer {
             send_buffer_size: 8192,
             enable_congestion_control: true,
             enable_compression: true,
+            send_budget: config.network.client_send_budget,
+            network_conditions: config.network.simulated_conditions,
         };
         let bind_addr = format!("{}:{}", config.network.host, config.network.port);
         let rudp_server = Arc::new(
@@ -153,17 +180,73 @@ impl RudpGameServer {
         info!("🔗 세션 관리 시스템 초기화 완료");
 
         // 게임 상태 관리자 초기화
-        let game_state_manager = Arc::new(
-            GameStateManager::new(
-                config.game.clone(),
-                player_manager.clone(),
-                security_middleware.clone(),
-                redis_optimizer.clone(),
-            )
-            .await?,
-        );
+        let game_state_manager = GameStateManager::new(
+            config.game.clone(),
+            player_manager.clone(),
+            security_middleware.clone(),
+            redis_optimizer.clone(),
+        )
+        .await?;
+        // 입장 티켓 검증 (로그인 서버와 같은 JWT 비밀키, 하트비트로 광고하는 주소 기준)
+        let game_state_manager = match std::env::var("JWT_SECRET_KEY") {
+            Ok(jwt_secret) => game_state_manager.with_entry_tickets(
+                EntryTicketSigner::from_jwt_secret(&jwt_secret),
+                format!("{}:{}", config.network.host, config.network.port),
+            ),
+            Err(_) => {
+                warn!("JWT_SECRET_KEY가 없어 입장 티켓 검증을 비활성화합니다");
+                game_state_manager
+            }
+        };
+        let game_state_manager = Arc::new(game_state_manager);
         info!("🎮 게임 상태 관리자 초기화 완료");
 
+        // 세션 종료/타임아웃을 게임 상태에 전달
+        session_manager
+            .add_event_listener(game_state_manager.clone())
+            .await;
+
+        // 매치 결과 기록기 (DB 저장 + 리더보드 이벤트, 실패 시 아웃박스 재시도)
+        let event_bus = match config.redis.to_shared_config().await {
+            Ok(redis_config) => Some(EventBus::new(redis_config)),
+            Err(e) => {
+                warn!("⚠️ 이벤트 버스 연결 실패, 매치 결과는 DB에만 저장: {}", e);
+                None
+            }
+        };
+        // 라이브 설정 (킬 스위치, Redis 변경 알림으로 수 초 안에 반영)
+        let live_config = match config.redis.to_shared_config().await {
+            Ok(redis_config) => {
+                let live_config = LiveConfig::new(redis_config);
+                live_config.clone().spawn_sync();
+                live_config
+            }
+            Err(e) => {
+                warn!("⚠️ 라이브 설정 Redis 연결 실패, 킬 스위치 비활성화: {}", e);
+                LiveConfig::local()
+            }
+        };
+        let dispatcher = Arc::new(PacketDispatcher::new(
+            rudp_server.clone(),
+            game_state_manager.clone(),
+            session_manager.clone(),
+            security_middleware,
+            live_config,
+        ));
+        let match_recorder = Arc::new(MatchResultRecorder::new(
+            format!("{}:{}", config.network.host, config.network.port),
+            &config.game.match_outbox_dir,
+            event_bus,
+        )?);
+        game_state_manager.attach_match_recorder(match_recorder.clone());
+        crash::spawn_monitored(
+            "match_outbox",
+            TaskAccounting::global()
+                .subsystem("match_outbox")
+                .instrument(match_recorder.run_retry_loop(Duration::from_secs(config.game.match_outbox_retry_secs.max(1)))),
+        );
+        info!("🏁 매치 결과 기록기 초기화 완료 (아웃박스: {})", config.game.match_outbox_dir);
+
         // 성능 모니터 초기화
         let monitoring_config = utils::performance::MonitoringConfig {
             enable_system_monitoring: true,
@@ -177,6 +260,11 @@ impl RudpGameServer {
             },
         };
         let performance_monitor = Arc::new(PerformanceMonitor::new(monitoring_config).await?);
+        let anomaly_detector = Arc::new(
+            AnomalyDetector::new()
+                .with_rule(P99_RTT_METRIC, AnomalyRule::p99_latency_ms())
+                .with_rule(PACKET_LOSS_METRIC, AnomalyRule::packet_loss_percent()),
+        );
         info!("📊 성능 모니터링 시스템 초기화 완료");
 
         info!("🎯 서버 성능 목표:");
@@ -198,8 +286,9 @@ impl RudpGameServer {
             session_manager,
             player_manager,
             performance_monitor,
-            security_middleware,
             redis_optimizer,
+            dispatcher,
+            anomaly_detector,
         })
     }
 
@@ -222,18 +311,23 @@ impl RudpGameServer {
             self.config.network.host, self.config.network.port
         );
 
-        // 게임 이벤트 구독
-        let mut event_receiver = self.game_state_manager.subscribe_events();
-
         // 1. 게임 틱 루프 시작 (60 TPS)
         let game_tick_handle = {
             let game_state = self.game_state_manager.clone();
-            let tick_rate = self.config.game.tick_rate;
-
-            tokio::spawn(async move {
-                let mut tick_interval = interval(Duration::from_millis(1000 / tick_rate as u64));
+            let mut tick_rate = game_state.tick_rate();
+
+            let accounting = TaskAccounting::global().subsystem("game_tick");
+            crash::spawn_monitored("game_tick", accounting.instrument(async move {
+                // 지연된 틱은 누적기가 따라잡으므로 인터벌은 밀린 틱을 몰아서 깨우지 않음
+                let tick_interval_for = |timestep: &FixedTimestep| {
+                    let mut tick_interval = interval(timestep.step());
+                    tick_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                    tick_interval
+                };
+                let mut timestep = FixedTimestep::new(tick_rate, game_state.max_catch_up_ticks());
+                let mut tick_interval = tick_interval_for(&timestep);
                 let mut tick_number = 0u64;
-                let mut last_tick_time = tokio::time::Instant::now();
+                let mut dropped = Duration::ZERO;
 
                 info!(
                     "⚡ 게임 틱 루프 시작 ({}Hz) - Redis 기반 상태 관리",
@@ -242,101 +336,72 @@ impl RudpGameServer {
 
                 loop {
                     tick_interval.tick().await;
-                    tick_number += 1;
 
-                    let now = tokio::time::Instant::now();
-                    let delta_time = now.duration_since(last_tick_time).as_secs_f32();
-                    last_tick_time = now;
+                    // 관리자 콘솔에서 틱 레이트가 바뀌었으면 인터벌 재생성
+                    let requested_rate = game_state.tick_rate();
+                    if requested_rate != tick_rate {
+                        tick_rate = requested_rate;
+                        timestep.set_tick_rate(tick_rate);
+                        tick_interval = tick_interval_for(&timestep);
+                        info!("⚡ 틱 레이트 변경: {}Hz", tick_rate);
+                    }
+
+                    // 고정 간격으로 밀린 만큼 실행 (한 프레임 최대 max_catch_up_ticks)
+                    let steps = timestep.advance(std::time::Instant::now());
+                    let delta_time = timestep.step().as_secs_f32();
+                    for _ in 0..steps {
+                        tick_number += 1;
+
+                        // 게임 상태 업데이트 (Redis 기반)
+                        if let Err(e) = game_state.update_game_tick(tick_number, delta_time).await {
+                            error!(tick = %tick_number, error = %e, "게임 틱 처리 실패");
+                        }
 
-                    // 게임 상태 업데이트 (Redis 기반)
-                    if let Err(e) = game_state.update_game_tick(tick_number, delta_time).await {
-                        error!(tick = %tick_number, error = %e, "게임 틱 처리 실패");
+                        // 매초마다 통계 로그
+                        if tick_number % tick_rate as u64 == 0 {
+                            let stats = game_state.get_game_statistics().await;
+                            let metrics = timestep.metrics();
+                            let inputs = game_state.input_buffer_stats();
+                            info!(
+                                tick = %tick_number,
+                                active_players = %stats.active_players,
+                                total_attacks = %stats.total_attacks,
+                                drift_ms = %format!("{:.1}", metrics.drift_ms()),
+                                catch_up_frames = %metrics.catch_up_frames,
+                                input_delay_ticks = %format!("{:.1}", inputs.avg_delay_ticks),
+                                input_late = %inputs.late,
+                                "게임 틱 상태"
+                            );
+                        }
                     }
 
-                    // 매초마다 통계 로그
-                    if tick_number % tick_rate as u64 == 0 {
-                        let stats = game_state.get_game_statistics().await;
-                        info!(
-                            tick = %tick_number,
-                            active_players = %stats.active_players,
-                            total_attacks = %stats.total_attacks,
-                            "게임 틱 상태"
+                    let metrics = timestep.metrics();
+                    if metrics.dropped > dropped {
+                        warn!(
+                            dropped_ms = %(metrics.dropped - dropped).as_millis(),
+                            "⚠️ 틱 루프 지연이 따라잡기 한도를 넘어 시뮬레이션 시간 손실"
                         );
+                        dropped = metrics.dropped;
                     }
+                    game_state.record_timestep(metrics);
                 }
-            })
+            }))
         };
 
         // 2. 네트워크 메시지 처리 루프
         let network_handle = {
             let rudp_server = self.rudp_server.clone();
-            let game_state = self.game_state_manager.clone();
-            let session_manager = self.session_manager.clone();
-            let security_middleware = self.security_middleware.clone();
+            let dispatcher = self.dispatcher.clone();
 
-            tokio::spawn(async move {
+            let accounting = TaskAccounting::global().subsystem("network");
+            crash::spawn_monitored("network", accounting.instrument(async move {
                 info!("📡 네트워크 메시지 처리 루프 시작");
 
                 loop {
                     // RUDP 패킷 수신
                     match rudp_server.receive_message().await {
                         Ok((client_addr, packet_data)) => {
-                            // 패킷 보안 검증
-                            if !security_middleware
-                                .validate_packet(&packet_data)
-                                .await
-                                .unwrap_or(false)
-                            {
-                                warn!(client = %client_addr, "유효하지 않은 패킷 수신");
-                                continue;
-                            }
-
-                            // 세션 ID 생성 또는 조회
-                            let session_id = crate::utils::socket_addr_to_u64(client_addr);
-
-                            // 메시지 역직렬화
-                            let game_message: GameMessage = match bincode::deserialize(&packet_data)
-                            {
-                                Ok(msg) => msg,
-                                Err(e) => {
-                                    warn!(
-                                        client = %client_addr,
-                                        error = %e,
-                                        "메시지 역직렬화 실패"
-                                    );
-                                    continue;
-                                }
-                            };
-
-                            // 메시지 처리
-                            let response = Self::handle_game_message(
-                                &game_state,
-                                &session_manager,
-                                session_id,
-                                game_message,
-                            )
-                            .await;
-
-                            // 응답 전송 (있는 경우)
-                            if let Ok(Some(response_msg)) = response {
-                                let response_data = match bincode::serialize(&response_msg) {
-                                    Ok(data) => data,
-                                    Err(e) => {
-                                        error!(error = %e, "응답 메시지 직렬화 실패");
-                                        continue;
-                                    }
-                                };
-
-                                if let Err(e) =
-                                    rudp_server.send_message(client_addr, response_data).await
-                                {
-                                    error!(
-                                        client = %client_addr,
-                                        error = %e,
-                                        "응답 메시지 전송 실패"
-                                    );
-                                }
-                            }
+                            dispatcher.handle_packet(client_addr, &packet_data).await;
                         }
                         Err(e) => {
                             warn!(error = %e, "네트워크 메시지 수신 실패");
@@ -344,23 +409,70 @@ impl RudpGameServer {
                         }
                     }
                 }
-            })
+            }))
         };
 
-        // 3. 게임 이벤트 브로드캐스트 루프
+        // 3. 방별 게임 이벤트 브로드캐스트 루프
         let broadcast_handle = {
-            let rudp_server = self.rudp_server.clone();
-            let session_manager = self.session_manager.clone();
+            let dispatcher = self.dispatcher.clone();
+            let game_state = self.game_state_manager.clone();
 
-            tokio::spawn(async move {
-                info!("📢 게임 이벤트 브로드캐스트 루프 시작");
+            // 방별 구독 태스크는 모두 "broadcast" 서브시스템으로 합산
+            let accounting = TaskAccounting::global().subsystem("broadcast");
 
-                while let Ok(event) = event_receiver.recv().await {
-                    // 이벤트를 관련 클라이언트들에게 브로드캐스트
-                    if let Err(e) =
-                        Self::broadcast_game_event(&rudp_server, &session_manager, &event).await
-                    {
-                        error!(event = ?event, error = %e, "이벤트 브로드캐스트 실패");
+            crash::spawn_monitored("broadcast", async move {
+                let event_channels = game_state.event_channels();
+                let mut room_tasks: HashMap<RoomId, tokio::task::JoinHandle<()>> = HashMap::new();
+                let mut scan_interval = interval(Duration::from_secs(1));
+                let mut scan_count = 0u64;
+
+                info!("📢 방별 게임 이벤트 브로드캐스트 루프 시작");
+
+                loop {
+                    scan_interval.tick().await;
+                    scan_count += 1;
+
+                    // 새로 생긴 방(또는 종료된 구독)에 대해 구독 태스크 시작
+                    let room_ids = event_channels.room_ids();
+                    room_tasks.retain(|room_id, task| {
+                        room_ids.contains(room_id) && !task.is_finished()
+                    });
+
+                    for room_id in room_ids {
+                        if room_tasks.contains_key(&room_id) {
+                            continue;
+                        }
+
+                        // 스캔 사이에 닫힌 방은 채널을 다시 만들지 않음
+                        let Some(mut receiver) = event_channels.subscribe_existing(room_id) else {
+                            continue;
+                        };
+                        let dispatcher = dispatcher.clone();
+
+                        let task = tokio::spawn(accounting.instrument(async move {
+                            // 방이 닫혀 채널이 제거되면 None을 받고 종료
+                            while let Some(event) = receiver.recv().await {
+                                // 이벤트를 관련 클라이언트들에게 브로드캐스트
+                                if let Err(e) = dispatcher.broadcast_game_event(&event).await {
+                                    error!(
+                                        room_id = %receiver.room_id(),
+                                        event = ?event,
+                                        error = %e,
+                                        "이벤트 브로드캐스트 실패"
+                                    );
+                                }
+                            }
+                            debug!(room_id = %receiver.room_id(), "방 채널 종료 - 구독 태스크 종료");
+                        }));
+                        room_tasks.insert(room_id, task);
+                    }
+
+                    let backlog: usize = event_channels.metrics().iter().map(|channel| channel.backlog).sum();
+                    accounting.set_queue_depth(backlog);
+
+                    // 10초마다 채널별 지연/유실 경고 점검
+                    if scan_count % 10 == 0 {
+                        event_channels.check_alerts();
                     }
                 }
             })
@@ -370,9 +482,16 @@ impl RudpGameServer {
         let monitoring_handle = {
             let performance_monitor = self.performance_monitor.clone();
             let game_state = self.game_state_manager.clone();
+            let redis_optimizer = self.redis_optimizer.clone();
+            let rudp_server = self.rudp_server.clone();
+            let server_id = format!("{}:{}", self.config.network.host, self.config.network.port);
+            let region = self.config.monitoring.server_region.clone();
+            let capacity = self.config.game.max_concurrent_players as u64;
+            let anomaly_detector = self.anomaly_detector.clone();
 
-            tokio::spawn(async move {
+            crash::spawn_monitored("monitoring", async move {
                 let mut monitor_interval = interval(Duration::from_secs(10));
+                let mut last_packets = (0u64, 0u64);
 
                 info!("📊 성능 모니터링 루프 시작");
 
@@ -395,6 +514,58 @@ impl RudpGameServer {
                             error!(error = %e, "메트릭 저장 실패");
                         }
                     }
+
+                    // 클라이언트 송신 예산 사용률
+                    let bandwidth = rudp_server.bandwidth_report();
+                    if bandwidth.clients > 0 {
+                        let dropped: Vec<String> = bandwidth
+                            .by_category
+                            .iter()
+                            .filter(|(_, usage)| usage.dropped_messages > 0)
+                            .map(|(category, usage)| format!("{}={}", category, usage.dropped_messages))
+                            .collect();
+                        info!(
+                            clients = bandwidth.clients,
+                            avg_utilization = format!("{:.2}", bandwidth.avg_utilization),
+                            max_utilization = format!("{:.2}", bandwidth.max_utilization),
+                            saturated = bandwidth.saturated_clients,
+                            dropped = %dropped.join(","),
+                            "📶 송신 예산 사용률"
+                        );
+                    }
+
+                    // p99 RTT와 구간 패킷 손실률(재전송 / 송신) 이상 탐지
+                    let rudp_stats = rudp_server.get_stats().await;
+                    if rudp_stats.active_connections > 0 {
+                        anomaly_detector.observe(P99_RTT_METRIC, rudp_stats.p99_rtt.as_secs_f64() * 1000.0);
+                    }
+                    let sent = rudp_stats.packets_sent.saturating_sub(last_packets.0);
+                    let retransmitted = rudp_stats.packets_retransmitted.saturating_sub(last_packets.1);
+                    last_packets = (rudp_stats.packets_sent, rudp_stats.packets_retransmitted);
+                    if sent > 0 {
+                        anomaly_detector.observe(PACKET_LOSS_METRIC, retransmitted as f64 / sent as f64 * 100.0);
+                    }
+
+                    // 통계 서비스용 서버 상태 하트비트 기록
+                    let stats = game_state.get_game_statistics().await;
+                    let rooms = game_state.event_channels().room_ids().len() as u64;
+                    let heartbeat = ServerHeartbeat::new(
+                        server_id.clone(),
+                        "rudp",
+                        region.clone(),
+                        stats.active_players as u64,
+                        rooms,
+                    )
+                    .with_capacity(capacity);
+                    let result = match serde_json::to_vec(&heartbeat) {
+                        Ok(payload) => redis_optimizer
+                            .set(&heartbeat.key(), &payload, Some(DEFAULT_STATS_TTL_SECS as usize))
+                            .await,
+                        Err(e) => Err(e.into()),
+                    };
+                    if let Err(e) = result {
+                        warn!(error = %e, "서버 상태 하트비트 기록 실패");
+                    }
                 }
             })
         };
@@ -403,7 +574,7 @@ impl RudpGameServer {
         let cleanup_handle = {
             let session_manager = self.session_manager.clone();
 
-            tokio::spawn(async move {
+            crash::spawn_monitored("session_cleanup", async move {
                 let mut cleanup_interval = interval(Duration::from_secs(30));
 
                 info!("🧹 세션 정리 루프 시작");
@@ -419,6 +590,42 @@ impl RudpGameServer {
             })
         };
 
+        // 6. 관리자 콘솔 (localhost 전용, 선택적)
+        let admin_handle = if self.config.admin.enabled {
+            let mut console = AdminConsole::new(
+                self.config.admin.clone(),
+                self.game_state_manager.clone(),
+                self.session_manager.clone(),
+            );
+            // 관리자 명령 감사 로그 (`AUDIT_LOG_FILE`, `AUDIT_HMAC_KEY`)
+            if let Some(audit) = AuditSink::from_env().await
+                .map_err(|e| anyhow::anyhow!("감사 로그 초기화 실패: {e}"))?
+            {
+                console = console.with_audit(Arc::new(audit));
+            }
+            let console = Arc::new(console);
+            Some(crash::spawn_monitored("admin_console", async move {
+                if let Err(e) = console.run().await {
+                    error!(error = %e, "관리자 콘솔 실행 실패");
+                }
+            }))
+        } else {
+            info!("🛠️ 관리자 콘솔 비활성화 (ADMIN_CONSOLE_ENABLED=false)");
+            None
+        };
+
+        // 7. 헬스 엔드포인트 (선택적)
+        let health_handle = match self.config.monitoring.health_port {
+            Some(port) => {
+                let addr: SocketAddr = format!("{}:{}", self.config.network.host, port).parse()?;
+                Some(self.health_registry().serve(addr).await?)
+            }
+            None => None,
+        };
+
+        // 8. 장애 조치 복제 (선택적)
+        let replication = self.spawn_replication().await;
+
         info!("✅ 모든 시스템 루프가 시작되었습니다!");
         info!("🎮 게임 서버가 연결을 수락할 준비가 완료되었습니다.");
 
@@ -435,6 +642,21 @@ impl RudpGameServer {
         // 서버 종료 시작
         info!("🔄 서버 종료 프로세스 시작...");
 
+        if let Some(handle) = admin_handle {
+            handle.abort();
+        }
+        if let Some(handle) = health_handle {
+            handle.abort();
+        }
+        // 임대를 반납해 대기 서버가 TTL 만료를 기다리지 않고 넘겨받게 함
+        if let Some((stream, handle)) = replication {
+            handle.abort();
+            let source = format!("{}:{}", self.config.network.host, self.config.network.port);
+            if let Err(e) = stream.release_lease(&source).await {
+                warn!("⚠️ 복제 임대 반납 실패: {}", e);
+            }
+        }
+
         // 모든 태스크 정리 (타임아웃 30초)
         let shutdown_timeout = Duration::from_secs(30);
         tokio::time::timeout(shutdown_timeout, async {
@@ -455,219 +677,80 @@ impl RudpGameServer {
         Ok(())
     }
 
-    /// 게임 메시지 처리
-    ///
-    /// 클라이언트로부터 수신된 게임 메시지를 타입별로 처리합니다.
+    /// 장애 조치 복제 시작
     ///
-    /// # Arguments
-    /// * `game_state` - 게임 상태 관리자
-    /// * `session_manager` - 세션 관리자
-    /// * `session_id` - 클라이언트 세션 ID
-    /// * `message` - 수신된 게임 메시지
-    ///
-    /// # Returns
-    /// 처리 결과 (응답 메시지 또는 None)
-    async fn handle_game_message(
-        game_state: &Arc<GameStateManager>,
-        session_manager: &Arc<SessionManager>,
-        session_id: u64,
-        message: GameMessage,
-    ) -> Result<Option<GameMessage>> {
-        match message {
-            // 연결 요청 처리
-            GameMessage::Connect {
-                player_name,
-                auth_token,
-                client_version,
-            } => {
-                let response = game_state
-                    .handle_player_connect(session_id, player_name, auth_token, client_version)
-                    .await?;
-                Ok(Some(response))
-            }
-
-            // 이동 요청 처리
-            GameMessage::Move {
-                target_position,
-                direction,
-                speed_multiplier,
-                client_timestamp,
-            } => {
-                let result = game_state
-                    .handle_player_move(
-                        session_id,
-                        target_position,
-                        direction,
-                        speed_multiplier,
-                        client_timestamp,
-                    )
-                    .await?;
-                Ok(result)
-            }
-
-            // 공격 요청 처리
-            GameMessage::Attack {
-                target,
-                attack_type,
-                weapon_id,
-                attack_direction,
-                predicted_damage,
-            } => {
-                let response = game_state
-                    .handle_player_attack(
-                        session_id,
-                        target,
-                        attack_type,
-                        weapon_id,
-                        attack_direction,
-                        predicted_damage,
-                    )
-                    .await?;
-                Ok(Some(response))
-            }
-
-            // 리스폰 요청 처리
-            GameMessage::Respawn => {
-                let response = game_state
-                    .handle_player_respawn(session_id)
-                    .await?;
-                Ok(Some(response))
-            }
-
-            // 연결 해제 처리
-            GameMessage::Disconnect { reason } => {
-                game_state
-                    .handle_player_disconnect(session_id, reason)
-                    .await?;
-                Ok(None)
-            }
-
-            // 기타 메시지 타입
-            _ => {
-                warn!(session_id = %session_id, message = ?message, "지원되지 않는 메시지 타입");
-                Ok(Some(GameMessage::Error {
-                    error_code: "UNSUPPORTED_MESSAGE".to_string(),
-                    error_message: "Unsupported message type".to_string(),
-                    category: game::messages::ErrorCategory::GameLogic,
-                    recoverable: false,
-                }))
-            }
+    /// 주 서버는 바로 상태를 보내고, 대기 서버는 주 서버의 임대가 만료될 때까지 복제 스트림을
+    /// 따라가다가 상태를 복원한 뒤 주 서버로 전환합니다.
+    async fn spawn_replication(&self) -> Option<(ReplicaStream, tokio::task::JoinHandle<Option<()>>)> {
+        let game = &self.config.game;
+        if game.replication_role == ReplicationRole::Off {
+            return None;
         }
-    }
-
-    /// 게임 이벤트 브로드캐스트
-    ///
-    /// 게임 이벤트를 관련된 모든 클라이언트에게 전송합니다.
-    ///
-    /// # Arguments
-    /// * `rudp_server` - RUDP 서버
-    /// * `session_manager` - 세션 관리자
-    /// * `event` - 브로드캐스트할 이벤트
-    async fn broadcast_game_event(
-        rudp_server: &Arc<RudpServer>,
-        session_manager: &Arc<SessionManager>,
-        event: &game::state_manager::GameEvent,
-    ) -> Result<()> {
-        use game::state_manager::GameEvent;
-
-        match event {
-            GameEvent::PlayerMoved {
-                player_id,
-                new_position,
-                velocity,
-                ..
-            } => {
-                let message = GameMessage::MoveUpdate {
-                    player_id: *player_id,
-                    current_position: *new_position,
-                    velocity: *velocity,
-                    server_timestamp: crate::utils::current_timestamp_ms(),
-                };
-
-                // 관심 영역 내 플레이어들에게만 전송 (간소화)
-                Self::broadcast_to_nearby_players(
-                    rudp_server,
-                    session_manager,
-                    *player_id,
-                    message,
-                )
-                .await?;
+        let redis_config = match self.config.redis.to_shared_config().await {
+            Ok(redis_config) => redis_config,
+            Err(e) => {
+                warn!("⚠️ 복제 스트림 Redis 연결 실패, 장애 조치 복제 비활성화: {}", e);
+                return None;
             }
+        };
 
-            GameEvent::AttackExecuted {
-                attacker_id,
-                target,
-                result,
-                ..
-            } => {
-                let message = GameMessage::AttackResult {
-                    attacker_id: *attacker_id,
-                    target: target.clone(),
-                    hit: result.hit,
-                    damage_dealt: result.damage_dealt,
-                    critical_hit: result.critical_hit,
-                    target_health: result.target_health_after,
-                    server_timestamp: crate::utils::current_timestamp_ms(),
-                };
-
-                Self::broadcast_to_nearby_players(
-                    rudp_server,
-                    session_manager,
-                    *attacker_id,
-                    message,
-                )
-                .await?;
-            }
-
-            GameEvent::PlayerDied {
-                player_id,
-                killer_id,
-                death_cause,
-                death_position,
-            } => {
-                let message = GameMessage::Die {
-                    player_id: *player_id,
-                    death_cause: death_cause.clone(),
-                    killer_id: *killer_id,
-                    death_position: *death_position,
-                    dropped_items: vec![], // TODO: 실제 드롭 아이템
-                    respawn_cooldown: 30,
-                    death_penalty: game::messages::DeathPenalty {
-                        gold_lost: 0,
-                        durability_loss: 0.1,
-                    },
-                };
-
-                Self::broadcast_to_all_players(rudp_server, session_manager, message).await?;
-            }
-
-            _ => {
-                // 기타 이벤트는 현재 처리하지 않음
-            }
-        }
-
-        Ok(())
-    }
-
-    /// 근처 플레이어들에게 브로드캐스트
-    async fn broadcast_to_nearby_players(
-        _rudp_server: &Arc<RudpServer>,
-        _session_manager: &Arc<SessionManager>,
-        _center_player_id: u32,
-        _message: GameMessage,
-    ) -> Result<()> {
-        // TODO: 관심 영역 기반 브로드캐스트 구현
-        Ok(())
+        let stream = ReplicaStream::new(redis_config, game.replication_stream.clone());
+        let source = format!("{}:{}", self.config.network.host, self.config.network.port);
+        let lease_ttl = Duration::from_secs(game.replication_lease_secs);
+        let role = game.replication_role;
+        let interval_ticks = game.replication_interval_ticks;
+        let replicator = Replicator::new(source.clone(), game.replication_keyframe_every);
+        let sink: Arc<dyn ReplicationSink> = Arc::new(RedisReplicationSink::new(stream.clone(), lease_ttl));
+        let game_state = self.game_state_manager.clone();
+        let follow_stream = stream.clone();
+        info!("🔁 장애 조치 복제 시작 ({:?}, 스트림: {})", role, game.replication_stream);
+
+        let handle = crash::spawn_monitored(
+            "replication",
+            TaskAccounting::global().subsystem("replication").instrument(async move {
+                if role == ReplicationRole::Standby {
+                    let replica = replication::follow_until_failover(follow_stream, &source, lease_ttl).await;
+                    let (matches, players) = game_state.restore_replica(replica.rooms());
+                    info!("🔁 대기 서버 전환 완료: 매치 {}개 복원, 재접속 대기 플레이어 {}명", matches, players);
+                }
+                replication::run_primary(game_state, replicator, sink, interval_ticks).await;
+            }),
+        );
+        Some((stream, handle))
     }
 
-    /// 모든 플레이어에게 브로드캐스트
-    async fn broadcast_to_all_players(
-        _rudp_server: &Arc<RudpServer>,
-        _session_manager: &Arc<SessionManager>,
-        _message: GameMessage,
-    ) -> Result<()> {
-        // TODO: 전체 플레이어 브로드캐스트 구현
-        Ok(())
+    /// 헬스 레지스트리 구성 (RUDP 준비 ⇐ Redis 준비)
+    ///
+    /// Redis 성능 저하 모드에서 쓰기 버퍼에 여유가 있으면 Redis도 준비됨으로 보고,
+    /// 저하 상태는 `redis_degradation`(리포트 전용)에 표시합니다.
+    fn health_registry(&self) -> HealthRegistry {
+        let redis_optimizer = self.redis_optimizer.clone();
+        let degradation_optimizer = self.redis_optimizer.clone();
+        let registry = HealthRegistry::new("rudpserver");
+        registry
+            .register("process", ProbeKind::Liveness, &[], || async { Ok(()) })
+            .register("redis", ProbeKind::Readiness, &[], move || {
+                let redis_optimizer = redis_optimizer.clone();
+                async move {
+                    match redis_optimizer.health_check().await {
+                        Ok(true) => Ok(()),
+                        Ok(false) => Err("PING 응답 이상".to_string()),
+                        Err(e) => {
+                            let status = redis_optimizer.degradation_status();
+                            if status.degraded && status.accepting_writes() { Ok(()) } else { Err(e.to_string()) }
+                        }
+                    }
+                }
+            })
+            .register("redis_degradation", ProbeKind::Informational, &[], move || {
+                let status = degradation_optimizer.degradation_status();
+                async move {
+                    if status.degraded { Err(status.summary()) } else { Ok(()) }
+                }
+            })
+            .register_group("rudp", &["redis"]);
+        let anomaly_detector = self.anomaly_detector.clone();
+        registry.with_metrics(move || TaskAccounting::global().render_prometheus() + &anomaly_detector.render_prometheus())
     }
 
     /// 종료 신호 대기
@@ -708,15 +791,20 @@ async fn main() -> Result<()> {
     }
 
     // 로깅 시스템 초기화
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::from_default_env()
-                .add_directive("info".parse()?)
-                .add_directive("rudpserver=debug".parse()?),
+    // 관리 콘솔 TRACE 규칙에 해당하는 세션은 필터 수준과 무관하게 기록
+    let env_filter = EnvFilter::from_default_env()
+        .add_directive("info".parse()?)
+        .add_directive("rudpserver=debug".parse()?);
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                .with_thread_ids(true)
+                .with_filter(PlayerSampler::new(env_filter)),
         )
-        .with_target(false)
-        .with_thread_ids(true)
+        .with(crash::recent_logs_layer())
         .init();
+    crash::install(CrashConfig::from_env("rudpserver", crash::build_id(env!("CARGO_PKG_VERSION"), option_env!("BUILD_HASH"))));
 
     info!("🎮 RUDP 게임 서버 v1.0.0 시작!");
 
@@ -726,6 +814,9 @@ async fn main() -> Result<()> {
         .map_err(|e| anyhow::anyhow!("설정 로드 실패: {}", e))?;
 
     // 서버 생성 및 실행
+    // gRPC 호출용 서비스 토큰 (클라이언트는 `ServiceTokenIssuer::global()`로 첨부)
+    ServiceTokenIssuer::install_from_env("rudpserver", "grpcserver");
+
     let server = RudpGameServer::new(config)
         .await
         .map_err(|e| anyhow::anyhow!("서버 생성 실패: {}", e))?;
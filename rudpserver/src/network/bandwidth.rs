@@ -0,0 +1,504 @@
+//! 클라이언트별 송신 대역폭 예산
+//!
+//! 2000 CCU 환경에서 한 클라이언트가 받는 데이터량을 초당 예산(기본 20KB/s)으로 제한합니다.
+//! 예산은 스냅샷/이벤트/채팅 카테고리별 비율로 나뉘며, 예산이 부족하면 우선순위가 낮은
+//! 카테고리부터 버립니다.
+//!
+//! # 할당 규칙
+//! - 전체 예산과 카테고리별 할당을 각각 토큰 버킷(1초 버스트)으로 관리합니다.
+//! - 상위 카테고리는 하위 카테고리의 남은 할당을 빌려 쓸 수 있지만,
+//!   하위 카테고리는 상위 카테고리가 아직 쓰지 않은 할당을 건드릴 수 없습니다.
+//! - 제어 메시지(연결/해제/오류)는 예산과 무관하게 항상 전송하되 사용량에는 포함합니다.
+//! - 디버그 스트림은 전체 예산과 별도의 버킷을 써서 다른 카테고리와 경쟁하지 않습니다.
+//!
+//! # 환경 변수
+//! - `CLIENT_SEND_BUDGET_BYTES_PER_SEC`: 클라이언트별 초당 송신 예산 (기본값: 20480, 0이면 비활성화)
+//! - `SEND_BUDGET_SNAPSHOT_PERCENT` / `SEND_BUDGET_EVENT_PERCENT` / `SEND_BUDGET_CHAT_PERCENT`:
+//!   카테고리별 비율 (기본값: 60 / 30 / 10, 합계 100)
+//! - `SEND_BUDGET_DEBUG_BYTES_PER_SEC`: 클라이언트별 디버그 스트림 초당 예산 (기본값: 4096)
+
+use dashmap::DashMap;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use crate::game::messages::GameMessage;
+
+/// 사용하지 않는 클라이언트 예산을 정리하기까지의 유휴 시간
+const BUDGET_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 송신 카테고리 (우선순위 높은 순)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SendCategory {
+    /// 연결/해제/오류 - 예산과 무관하게 항상 전송
+    Control,
+    /// 공격/사망/리스폰 등 게임 이벤트
+    Event,
+    /// 이동/상태 스냅샷
+    Snapshot,
+    /// 채팅, 공지
+    Chat,
+    /// 히트 판정 디버그 스트림 - 전체 예산과 별도 버킷
+    Debug,
+}
+
+impl SendCategory {
+    pub const ALL: [SendCategory; 5] = [
+        Self::Control,
+        Self::Event,
+        Self::Snapshot,
+        Self::Chat,
+        Self::Debug,
+    ];
+
+    /// 예산이 적용되는 카테고리 (우선순위 높은 순)
+    pub const BUDGETED: [SendCategory; 3] = [Self::Event, Self::Snapshot, Self::Chat];
+
+    /// 메시지 카테고리 분류
+    pub fn of(message: &GameMessage) -> Self {
+        match message {
+            GameMessage::Connect { .. }
+            | GameMessage::ConnectResponse { .. }
+            | GameMessage::Disconnect { .. }
+            | GameMessage::Error { .. } => Self::Control,
+            GameMessage::Move { .. }
+            | GameMessage::MoveUpdate { .. }
+            | GameMessage::StateUpdate { .. } => Self::Snapshot,
+            GameMessage::Attack { .. }
+            | GameMessage::AttackResult { .. }
+            | GameMessage::Die { .. }
+            | GameMessage::Respawn
+            | GameMessage::RespawnComplete { .. }
+            | GameMessage::SetReady { .. }
+            | GameMessage::LobbyState { .. }
+            | GameMessage::MatchCountdown { .. }
+            | GameMessage::MatchCountdownCancelled { .. }
+            | GameMessage::MatchStarted { .. }
+            | GameMessage::MatchEnded { .. }
+            | GameMessage::QuickPing { .. }
+            | GameMessage::QuickPingReceived { .. } => Self::Event,
+            GameMessage::ServerNotice { .. } => Self::Chat,
+            GameMessage::HitRegDebug { .. } => Self::Debug,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for SendCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Control => "control",
+            Self::Event => "event",
+            Self::Snapshot => "snapshot",
+            Self::Chat => "chat",
+            Self::Debug => "debug",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 클라이언트별 송신 예산 설정
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SendBudgetConfig {
+    /// 클라이언트별 초당 송신 바이트 (0이면 예산 비활성화)
+    pub bytes_per_sec: u32,
+    /// 스냅샷 할당 비율 (%)
+    pub snapshot_percent: u8,
+    /// 이벤트 할당 비율 (%)
+    pub event_percent: u8,
+    /// 채팅 할당 비율 (%)
+    pub chat_percent: u8,
+    /// 디버그 스트림 초당 바이트 (전체 예산과 별도)
+    #[serde(default = "default_debug_bytes_per_sec")]
+    pub debug_bytes_per_sec: u32,
+}
+
+fn default_debug_bytes_per_sec() -> u32 {
+    4 * 1024
+}
+
+impl Default for SendBudgetConfig {
+    fn default() -> Self {
+        Self {
+            bytes_per_sec: 20 * 1024,
+            snapshot_percent: 60,
+            event_percent: 30,
+            chat_percent: 10,
+            debug_bytes_per_sec: default_debug_bytes_per_sec(),
+        }
+    }
+}
+
+impl SendBudgetConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.bytes_per_sec > 0
+    }
+
+    /// 카테고리별 비율 합이 100인지 검사
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let total = self.snapshot_percent as u32 + self.event_percent as u32 + self.chat_percent as u32;
+        if total != 100 {
+            return Err(anyhow::anyhow!(
+                "Send budget shares must sum to 100: snapshot {} + event {} + chat {} = {}",
+                self.snapshot_percent,
+                self.event_percent,
+                self.chat_percent,
+                total
+            ));
+        }
+        Ok(())
+    }
+
+    fn rate(&self, category: SendCategory) -> f64 {
+        let percent = match category {
+            SendCategory::Control => 100,
+            SendCategory::Event => self.event_percent,
+            SendCategory::Snapshot => self.snapshot_percent,
+            SendCategory::Chat => self.chat_percent,
+            SendCategory::Debug => return self.debug_bytes_per_sec as f64,
+        };
+        self.bytes_per_sec as f64 * percent as f64 / 100.0
+    }
+}
+
+/// 바이트 토큰 버킷 (용량 = 1초 분량)
+#[derive(Debug, Clone)]
+struct Bucket {
+    rate: f64,
+    tokens: f64,
+}
+
+impl Bucket {
+    fn full(rate: f64) -> Self {
+        Self { rate, tokens: rate }
+    }
+
+    fn refill(&mut self, elapsed: Duration) {
+        self.tokens = (self.tokens + self.rate * elapsed.as_secs_f64()).min(self.rate);
+    }
+}
+
+/// 카테고리별 누적 사용량
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CategoryUsage {
+    pub sent_bytes: u64,
+    pub sent_messages: u64,
+    pub dropped_bytes: u64,
+    pub dropped_messages: u64,
+}
+
+/// 한 클라이언트의 송신 예산
+#[derive(Debug, Clone)]
+pub struct ClientBudget {
+    config: SendBudgetConfig,
+    total: Bucket,
+    /// `SendCategory` 순서 (Control 칸은 사용하지 않고, Debug 칸은 전체 예산과 별도)
+    categories: [Bucket; 5],
+    usage: [CategoryUsage; 5],
+    last_refill: Instant,
+    /// 현재 보고 구간 시작 시각과 구간 내 전송 바이트
+    window_start: Instant,
+    window_sent: u64,
+}
+
+impl ClientBudget {
+    pub fn new(config: SendBudgetConfig, now: Instant) -> Self {
+        let bucket = |category| Bucket::full(config.rate(category));
+        Self {
+            config,
+            total: Bucket::full(config.bytes_per_sec as f64),
+            categories: [
+                bucket(SendCategory::Control),
+                bucket(SendCategory::Event),
+                bucket(SendCategory::Snapshot),
+                bucket(SendCategory::Chat),
+                bucket(SendCategory::Debug),
+            ],
+            usage: [CategoryUsage::default(); 5],
+            last_refill: now,
+            window_start: now,
+            window_sent: 0,
+        }
+    }
+
+    /// 전송 가능 여부를 판단하고 사용량을 기록
+    ///
+    /// `true`면 예산을 차감한 것이므로 반드시 전송해야 합니다.
+    pub fn admit(&mut self, category: SendCategory, size: usize, now: Instant) -> bool {
+        self.refill(now);
+        let size_f = size as f64;
+
+        let admitted = match category {
+            // 제어 메시지는 항상 전송 - 전체 예산은 빚으로 남겨 하위 카테고리가 양보하게 함
+            SendCategory::Control => {
+                self.total.tokens = (self.total.tokens - size_f).max(-self.total.rate);
+                true
+            }
+            // 디버그 스트림은 자기 버킷만 사용 - 전체 예산과 보고 구간 사용률에 포함하지 않음
+            SendCategory::Debug => {
+                let bucket = &mut self.categories[category.index()];
+                if bucket.tokens >= size_f {
+                    bucket.tokens -= size_f;
+                    let usage = &mut self.usage[category.index()];
+                    usage.sent_bytes += size as u64;
+                    usage.sent_messages += 1;
+                    return true;
+                }
+                false
+            }
+            _ => {
+                // 상위 카테고리가 아직 쓰지 않은 할당은 예약분으로 남겨둠
+                let reserved: f64 = SendCategory::BUDGETED
+                    .iter()
+                    .take_while(|higher| **higher != category)
+                    .map(|higher| self.categories[higher.index()].tokens.max(0.0))
+                    .sum();
+                if self.total.tokens - reserved >= size_f {
+                    self.total.tokens -= size_f;
+                    self.borrow(category, size_f);
+                    true
+                } else {
+                    false
+                }
+            }
+        };
+
+        let usage = &mut self.usage[category.index()];
+        if admitted {
+            usage.sent_bytes += size as u64;
+            usage.sent_messages += 1;
+            self.window_sent += size as u64;
+        } else {
+            usage.dropped_bytes += size as u64;
+            usage.dropped_messages += 1;
+        }
+        admitted
+    }
+
+    /// 카테고리 할당에서 차감하고, 부족분은 가장 낮은 하위 카테고리 할당부터 빌림
+    fn borrow(&mut self, category: SendCategory, mut size: f64) {
+        let position = SendCategory::BUDGETED.iter().position(|c| *c == category).unwrap_or(0);
+        let lower = SendCategory::BUDGETED[position + 1..].iter().rev();
+        for c in std::iter::once(&category).chain(lower) {
+            let bucket = &mut self.categories[c.index()];
+            let take = size.min(bucket.tokens.max(0.0));
+            bucket.tokens -= take;
+            size -= take;
+            if size <= 0.0 {
+                break;
+            }
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.last_refill = now;
+        self.total.refill(elapsed);
+        for bucket in &mut self.categories {
+            bucket.refill(elapsed);
+        }
+    }
+
+    /// 현재 보고 구간의 예산 사용률 (1.0 = 예산 전부 사용)을 반환하고 구간을 초기화
+    pub fn take_utilization(&mut self, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(self.window_start).as_secs_f64();
+        let budget = self.config.bytes_per_sec as f64 * elapsed;
+        let utilization = if budget > 0.0 { self.window_sent as f64 / budget } else { 0.0 };
+        self.window_start = now;
+        self.window_sent = 0;
+        utilization
+    }
+
+    pub fn usage(&self, category: SendCategory) -> CategoryUsage {
+        self.usage[category.index()]
+    }
+
+    fn is_idle(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.last_refill) > BUDGET_IDLE_TIMEOUT
+    }
+}
+
+/// 예산 사용률 보고서
+#[derive(Debug, Clone)]
+pub struct BandwidthReport {
+    pub clients: usize,
+    pub avg_utilization: f64,
+    pub max_utilization: f64,
+    /// 보고 구간 동안 예산을 90% 이상 사용한 클라이언트 수
+    pub saturated_clients: usize,
+    /// 카테고리별 누적 사용량 (`SendCategory` 순서)
+    pub by_category: [(SendCategory, CategoryUsage); 5],
+}
+
+/// 서버 전체 송신 스케줄러의 클라이언트별 예산 테이블
+#[derive(Debug)]
+pub struct BandwidthScheduler {
+    config: SendBudgetConfig,
+    clients: DashMap<SocketAddr, Mutex<ClientBudget>>,
+}
+
+impl BandwidthScheduler {
+    pub fn new(config: SendBudgetConfig) -> Self {
+        Self {
+            config,
+            clients: DashMap::new(),
+        }
+    }
+
+    /// `addr`로 `size` 바이트를 보낼 수 있는지 판단 (예산 비활성화 시 항상 허용)
+    pub fn admit(&self, addr: SocketAddr, category: SendCategory, size: usize) -> bool {
+        if !self.config.is_enabled() {
+            return true;
+        }
+        let now = Instant::now();
+        let budget = self
+            .clients
+            .entry(addr)
+            .or_insert_with(|| Mutex::new(ClientBudget::new(self.config, now)));
+        let mut budget = budget.lock();
+        budget.admit(category, size, now)
+    }
+
+    /// 연결 종료 시 예산 제거
+    pub fn remove(&self, addr: &SocketAddr) {
+        self.clients.remove(addr);
+    }
+
+    /// 사용률 보고서 생성 (보고 구간 초기화, 유휴 클라이언트 정리)
+    pub fn report(&self) -> BandwidthReport {
+        let now = Instant::now();
+        self.clients.retain(|_, budget| !budget.get_mut().is_idle(now));
+
+        let mut report = BandwidthReport {
+            clients: 0,
+            avg_utilization: 0.0,
+            max_utilization: 0.0,
+            saturated_clients: 0,
+            by_category: SendCategory::ALL.map(|category| (category, CategoryUsage::default())),
+        };
+        let mut total_utilization = 0.0;
+        for entry in self.clients.iter() {
+            let mut budget = entry.value().lock();
+            let utilization = budget.take_utilization(now);
+            total_utilization += utilization;
+            report.max_utilization = report.max_utilization.max(utilization);
+            if utilization >= 0.9 {
+                report.saturated_clients += 1;
+            }
+            for (category, usage) in &mut report.by_category {
+                let client = budget.usage(*category);
+                usage.sent_bytes += client.sent_bytes;
+                usage.sent_messages += client.sent_messages;
+                usage.dropped_bytes += client.dropped_bytes;
+                usage.dropped_messages += client.dropped_messages;
+            }
+            report.clients += 1;
+        }
+        if report.clients > 0 {
+            report.avg_utilization = total_utilization / report.clients as f64;
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget() -> (ClientBudget, Instant) {
+        let now = Instant::now();
+        let config = SendBudgetConfig {
+            bytes_per_sec: 1000,
+            snapshot_percent: 60,
+            event_percent: 30,
+            chat_percent: 10,
+            debug_bytes_per_sec: 200,
+        };
+        (ClientBudget::new(config, now), now)
+    }
+
+    #[test]
+    fn test_lower_priority_dropped_first() {
+        let (mut budget, now) = budget();
+
+        // 채팅은 자기 할당(100B)만 사용 가능 - 상위 카테고리 예약분은 건드릴 수 없음
+        assert!(budget.admit(SendCategory::Chat, 100, now));
+        assert!(!budget.admit(SendCategory::Chat, 10, now));
+
+        // 스냅샷은 자기 할당(600B)까지, 이벤트 예약분(300B)은 남겨둠
+        assert!(budget.admit(SendCategory::Snapshot, 600, now));
+        assert!(!budget.admit(SendCategory::Snapshot, 10, now));
+
+        // 이벤트는 남은 전체 예산을 모두 사용 가능
+        assert!(budget.admit(SendCategory::Event, 300, now));
+        assert!(!budget.admit(SendCategory::Event, 1, now));
+
+        // 제어 메시지는 예산이 없어도 전송
+        assert!(budget.admit(SendCategory::Control, 50, now));
+
+        let chat = budget.usage(SendCategory::Chat);
+        assert_eq!((chat.sent_bytes, chat.dropped_messages), (100, 1));
+        assert!((budget.take_utilization(now + Duration::from_secs(1)) - 1.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_events_borrow_from_lower_categories() {
+        let (mut budget, now) = budget();
+
+        // 이벤트 폭주는 스냅샷/채팅 할당을 빌려 씀 → 이후 스냅샷은 버려짐
+        assert!(budget.admit(SendCategory::Event, 900, now));
+        assert!(!budget.admit(SendCategory::Snapshot, 200, now));
+        assert!(budget.admit(SendCategory::Snapshot, 100, now));
+
+        // 0.5초 후 절반 회복
+        let later = now + Duration::from_millis(500);
+        assert!(budget.admit(SendCategory::Snapshot, 300, later));
+        assert!(!budget.admit(SendCategory::Chat, 100, later));
+    }
+
+    #[test]
+    fn test_debug_budget_separate_from_chat() {
+        let (mut budget, now) = budget();
+
+        // 디버그는 자기 버킷(200B)만 사용하고 채팅 할당은 그대로 남음
+        assert!(budget.admit(SendCategory::Debug, 200, now));
+        assert!(!budget.admit(SendCategory::Debug, 1, now));
+        assert!(budget.admit(SendCategory::Chat, 100, now));
+
+        // 전체 예산을 다 써도 디버그 버킷은 따로 회복
+        assert!(budget.admit(SendCategory::Event, 900, now));
+        assert!(budget.admit(SendCategory::Debug, 100, now + Duration::from_millis(500)));
+
+        let debug = budget.usage(SendCategory::Debug);
+        assert_eq!((debug.sent_bytes, debug.dropped_messages), (300, 1));
+        assert!((budget.take_utilization(now + Duration::from_secs(1)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_scheduler_report() {
+        let scheduler = BandwidthScheduler::new(SendBudgetConfig::default());
+        let addr: SocketAddr = "127.0.0.1:4000".parse().unwrap();
+
+        assert!(scheduler.admit(addr, SendCategory::Snapshot, 1024));
+        assert!(!scheduler.admit(addr, SendCategory::Chat, 4096));
+
+        let report = scheduler.report();
+        assert_eq!(report.clients, 1);
+        let (_, chat) = report.by_category[SendCategory::Chat.index()];
+        assert_eq!(chat.dropped_messages, 1);
+
+        scheduler.remove(&addr);
+        assert_eq!(scheduler.report().clients, 0);
+
+        let disabled = BandwidthScheduler::new(SendBudgetConfig {
+            bytes_per_sec: 0,
+            ..Default::default()
+        });
+        assert!(disabled.admit(addr, SendCategory::Chat, 1 << 20));
+    }
+}
@@ -0,0 +1,1278 @@
+//! 패킷 디스패처
+//!
+//! 수신한 RUDP 패킷을 해석해 세션을 등록하고 게임 상태 관리자로 전달하며,
+//! 방 이벤트를 대상 세션의 주소로 전송합니다.
+//!
+//! 세션 ID는 클라이언트 주소(`socket_addr_to_u64`)에서 만들며, `SessionManager`와
+//! `GameStateManager`가 같은 ID를 키로 사용합니다.
+
+use anyhow::Result;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, error, warn};
+
+use crate::game::messages::{ErrorCategory, GameMessage, NoticeType, Priority};
+use crate::game::state_manager::{GameEvent, GameStateManager};
+use crate::network::bandwidth::SendCategory;
+use crate::network::session::{
+    ClientInfo, SessionId, SessionManager, SessionState, SessionTerminationReason,
+};
+use crate::protocol::{self, rudp::RudpConnection, rudp::RudpServer};
+
+use shared::security::{SecurityMiddleware, UserRole};
+use shared::service::redis::live_config::LiveConfig;
+use shared::tool::{ErrorCode, GameServerError};
+
+/// 연결 요청 인증 방식 (세션 이벤트 기록용)
+const CONNECT_AUTH_METHOD: &str = "connect_token";
+
+/// 수신 패킷 처리와 이벤트 전송을 담당하는 디스패처
+pub struct PacketDispatcher {
+    rudp_server: Arc<RudpServer>,
+    game_state: Arc<GameStateManager>,
+    session_manager: Arc<SessionManager>,
+    security: Arc<SecurityMiddleware>,
+    live_config: LiveConfig,
+}
+
+impl PacketDispatcher {
+    /// 새 디스패처 생성
+    pub fn new(
+        rudp_server: Arc<RudpServer>,
+        game_state: Arc<GameStateManager>,
+        session_manager: Arc<SessionManager>,
+        security: Arc<SecurityMiddleware>,
+        live_config: LiveConfig,
+    ) -> Self {
+        Self {
+            rudp_server,
+            game_state,
+            session_manager,
+            security,
+            live_config,
+        }
+    }
+
+    /// 수신 패킷 하나 처리
+    ///
+    /// 역직렬화 → 엔드포인트 보안 정책 → 킬 스위치 → 세션 등록 → 메시지 처리 → 응답 전송 순서입니다.
+    /// 세션은 `Connect`를 받을 때 만들고, 연결에 성공하면 인증 상태를 거쳐 게임 중으로 바뀝니다.
+    pub async fn handle_packet(&self, client_addr: SocketAddr, packet_data: &[u8]) {
+        // 메시지 역직렬화 (봉투 버전 검증 포함)
+        let game_message = match protocol::decode_message(packet_data) {
+            Ok(msg) => msg,
+            Err(e) => {
+                warn!(client = %client_addr, error = %e, "메시지 역직렬화 실패");
+                return;
+            }
+        };
+
+        let session_id = crate::utils::socket_addr_to_u64(client_addr);
+        let registered = self.session_manager.get_session_by_addr(client_addr).await;
+        if let Some(id) = registered {
+            let _ = self.session_manager.update_session_activity(id).await;
+        }
+
+        // 엔드포인트 보안 정책 (인증을 마친 세션이면 인증된 요청)
+        let authenticated = match registered {
+            Some(id) => self.is_authenticated(id).await,
+            None => false,
+        };
+        let roles = authenticated.then_some([UserRole::User]);
+        let endpoint = format!("rudp:{}", game_message.message_type_str());
+        if let Err(e) = self.security.check_endpoint(
+            &endpoint,
+            &client_addr.ip().to_string(),
+            packet_data.len(),
+            roles.as_ref().map(|roles| roles.as_slice()),
+        ) {
+            warn!(
+                client = %client_addr,
+                message_type = game_message.message_type_str(),
+                error = %e,
+                "보안 정책 위반으로 메시지 거부"
+            );
+            return;
+        }
+
+        // 킬 스위치 (에러 응답은 인증된 세션에만, 위조 주소로의 반사 방지)
+        let response = if self.live_config.is_killed(&endpoint) {
+            debug!(client = %client_addr, endpoint = %endpoint, "킬 스위치로 메시지 차단");
+            Ok(authenticated.then(|| GameMessage::from_error(&ErrorCode::FeatureDisabled.into())))
+        } else if let (None, GameMessage::Connect { client_version, .. }) =
+            (registered, &game_message)
+        {
+            match self
+                .register_session(session_id, client_addr, client_version)
+                .await
+            {
+                Ok(()) => self.handle_game_message(session_id, game_message).await,
+                Err(e) => {
+                    warn!(client = %client_addr, error = %e, "세션 생성 실패");
+                    Ok(Some(GameMessage::from_error(
+                        &ErrorCode::TooManySessions.into(),
+                    )))
+                }
+            }
+        } else {
+            self.handle_game_message(session_id, game_message).await
+        };
+
+        // 처리 실패는 공통 에러로 변환해 응답 (내부 상세는 로그에만 남김)
+        let response = response.unwrap_or_else(|e| {
+            let error = GameServerError::from(e);
+            warn!(client = %client_addr, error = %error, "게임 메시지 처리 실패");
+            Some(GameMessage::from_error(&error))
+        });
+
+        if let Some(response_msg) = response {
+            if let Err(e) = self.send(client_addr, response_msg).await {
+                error!(client = %client_addr, error = %e, "응답 메시지 전송 실패");
+            }
+        }
+    }
+
+    /// 인증을 마친 세션인지 여부
+    async fn is_authenticated(&self, session_id: SessionId) -> bool {
+        match self.session_manager.get_session(session_id).await {
+            Some(session) => {
+                let session = session.lock().await;
+                session.player_id.is_some()
+                    && matches!(
+                        session.state,
+                        SessionState::Authenticated
+                            | SessionState::InGame
+                            | SessionState::Active
+                            | SessionState::Idle
+                    )
+            }
+            None => false,
+        }
+    }
+
+    /// 처음 연결하는 클라이언트의 세션 등록
+    async fn register_session(
+        &self,
+        session_id: SessionId,
+        client_addr: SocketAddr,
+        client_version: &str,
+    ) -> Result<()> {
+        let connection = Arc::new(Mutex::new(RudpConnection::new(session_id, client_addr)));
+        let client_info = ClientInfo {
+            version: client_version.to_string(),
+            ..ClientInfo::default()
+        };
+        self.session_manager
+            .create_session(session_id, client_addr, connection, client_info)
+            .await
+    }
+
+    /// 게임 메시지 처리
+    ///
+    /// 클라이언트로부터 수신된 게임 메시지를 타입별로 처리합니다.
+    ///
+    /// # Returns
+    /// 처리 결과 (응답 메시지 또는 None)
+    async fn handle_game_message(
+        &self,
+        session_id: SessionId,
+        message: GameMessage,
+    ) -> Result<Option<GameMessage>> {
+        let game_state = &self.game_state;
+        match message {
+            // 연결 요청 처리
+            GameMessage::Connect {
+                player_name,
+                auth_token,
+                client_version,
+            } => {
+                let response = game_state
+                    .handle_player_connect(
+                        session_id,
+                        player_name,
+                        auth_token.clone(),
+                        client_version,
+                    )
+                    .await?;
+                if let GameMessage::ConnectResponse {
+                    success: true,
+                    player_id: Some(player_id),
+                    ..
+                } = &response
+                {
+                    self.session_manager
+                        .authenticate_session(
+                            session_id,
+                            *player_id,
+                            auth_token,
+                            CONNECT_AUTH_METHOD.to_string(),
+                        )
+                        .await?;
+                    if let Err(e) = self
+                        .session_manager
+                        .change_session_state(session_id, SessionState::InGame)
+                        .await
+                    {
+                        warn!(session_id = %session_id, error = %e, "세션 게임 입장 상태 전환 실패");
+                    }
+                }
+                Ok(Some(response))
+            }
+
+            // 이동 요청 처리
+            GameMessage::Move {
+                target_position,
+                direction,
+                speed_multiplier,
+                client_timestamp,
+            } => {
+                let result = game_state
+                    .handle_player_move(
+                        session_id,
+                        target_position,
+                        direction,
+                        speed_multiplier,
+                        client_timestamp,
+                    )
+                    .await?;
+                Ok(result)
+            }
+
+            // 공격 요청 처리
+            GameMessage::Attack {
+                target,
+                attack_type,
+                weapon_id,
+                attack_direction,
+                predicted_damage,
+            } => {
+                let response = game_state
+                    .handle_player_attack(
+                        session_id,
+                        target,
+                        attack_type,
+                        weapon_id,
+                        attack_direction,
+                        predicted_damage,
+                    )
+                    .await?;
+                Ok(Some(response))
+            }
+
+            // 리스폰 요청 처리
+            GameMessage::Respawn => {
+                let response = game_state.handle_player_respawn(session_id).await?;
+                Ok(Some(response))
+            }
+
+            // 로비 준비 상태 변경
+            GameMessage::SetReady { room_id, ready } => {
+                let response = game_state
+                    .handle_set_ready(session_id, room_id, ready)
+                    .await?;
+                Ok(Some(response))
+            }
+
+            // 빠른 소통 핑 (성공 시 응답 없음)
+            GameMessage::QuickPing {
+                ping_type,
+                position,
+            } => {
+                game_state
+                    .handle_quick_ping(session_id, ping_type, position)
+                    .await
+            }
+
+            // 연결 해제 처리 (게임 상태 정리 후 네트워크 세션 종료)
+            GameMessage::Disconnect { reason } => {
+                game_state
+                    .handle_player_disconnect(session_id, reason)
+                    .await?;
+                if let Err(e) = self
+                    .session_manager
+                    .terminate_session(session_id, SessionTerminationReason::ClientRequest)
+                    .await
+                {
+                    debug!(session_id = %session_id, error = %e, "연결 해제 세션 종료 생략");
+                }
+                Ok(None)
+            }
+
+            // 기타 메시지 타입
+            _ => {
+                warn!(session_id = %session_id, message = ?message, "지원되지 않는 메시지 타입");
+                Ok(Some(GameMessage::Error {
+                    error_code: "UNSUPPORTED_MESSAGE".to_string(),
+                    error_message: "Unsupported message type".to_string(),
+                    category: ErrorCategory::GameLogic,
+                    recoverable: false,
+                }))
+            }
+        }
+    }
+
+    /// 게임 이벤트 브로드캐스트
+    ///
+    /// 게임 이벤트를 관련된 모든 클라이언트에게 전송합니다.
+    pub async fn broadcast_game_event(&self, event: &GameEvent) -> Result<()> {
+        match event {
+            GameEvent::PlayerMoved {
+                player_id,
+                new_position,
+                velocity,
+                ..
+            } => {
+                let message = GameMessage::MoveUpdate {
+                    player_id: *player_id,
+                    current_position: *new_position,
+                    velocity: *velocity,
+                    server_timestamp: crate::utils::current_timestamp_ms(),
+                };
+
+                // 관심 영역 내 플레이어들에게만 전송 (간소화)
+                self.broadcast_to_nearby_players(*player_id, message)
+                    .await?;
+            }
+
+            GameEvent::AttackExecuted {
+                attacker_id,
+                target,
+                result,
+                ..
+            } => {
+                let message = GameMessage::AttackResult {
+                    attacker_id: *attacker_id,
+                    target: target.clone(),
+                    hit: result.hit,
+                    damage_dealt: result.damage_dealt,
+                    critical_hit: result.critical_hit,
+                    target_health: result.target_health_after,
+                    server_timestamp: crate::utils::current_timestamp_ms(),
+                };
+
+                self.broadcast_to_nearby_players(*attacker_id, message)
+                    .await?;
+            }
+
+            GameEvent::PlayerDied {
+                player_id,
+                killer_id,
+                death_cause,
+                death_position,
+            } => {
+                let message = GameMessage::Die {
+                    player_id: *player_id,
+                    death_cause: death_cause.clone(),
+                    killer_id: *killer_id,
+                    death_position: *death_position,
+                    dropped_items: vec![], // TODO: 실제 드롭 아이템
+                    respawn_cooldown: 30,
+                    death_penalty: crate::game::messages::DeathPenalty {
+                        gold_lost: 0,
+                        durability_loss: 0.1,
+                    },
+                };
+
+                self.broadcast_to_all_players(message).await?;
+            }
+
+            GameEvent::PlayerAfkWarning {
+                session_id,
+                idle_secs,
+                disconnect_in_secs,
+                ..
+            } => {
+                // 본인에게만 연결 해제 예고
+                let message = GameMessage::ServerNotice {
+                    notice_type: NoticeType::General,
+                    message: format!(
+                        "{}초 동안 입력이 없습니다. {}초 후 연결이 해제됩니다.",
+                        idle_secs, disconnect_in_secs
+                    ),
+                    priority: Priority::High,
+                    expires_at: Some(
+                        crate::utils::current_timestamp_ms() + disconnect_in_secs * 1000,
+                    ),
+                };
+                self.send_message_to_session(*session_id, message).await?;
+            }
+
+            GameEvent::PlayerMovedToSpectator {
+                session_id,
+                idle_secs,
+                ..
+            } => {
+                let message = GameMessage::ServerNotice {
+                    notice_type: NoticeType::General,
+                    message: format!("{}초 동안 입력이 없어 관전자로 전환되었습니다.", idle_secs),
+                    priority: Priority::Medium,
+                    expires_at: None,
+                };
+                self.send_message_to_session(*session_id, message).await?;
+            }
+
+            GameEvent::PlayerAfkDisconnected {
+                session_id,
+                player_id,
+                ..
+            } => {
+                // 게임 상태는 이미 정리됨 - 네트워크 세션만 종료 (실패해도 다른 이벤트 전송은 계속)
+                if let Err(e) = self
+                    .session_manager
+                    .terminate_session(*session_id, SessionTerminationReason::Afk)
+                    .await
+                {
+                    warn!(session_id = %session_id, player_id = %player_id, error = %e, "AFK 세션 종료 실패");
+                }
+            }
+
+            GameEvent::HitRegDebug {
+                player_id,
+                session_id,
+                records,
+            } => {
+                // 디버그 정보는 플래그된 플레이어 본인에게만 전송
+                let message = GameMessage::HitRegDebug {
+                    player_id: *player_id,
+                    records: records.clone(),
+                };
+                self.send_to_session(
+                    *session_id,
+                    SendCategory::Debug,
+                    protocol::encode_message(message)?,
+                )
+                .await?;
+            }
+
+            GameEvent::Lobby {
+                room_id,
+                recipients,
+                event,
+            } => {
+                // 로비 인원에게만 전송
+                let data = protocol::encode_message(event.to_message(*room_id))?;
+                for session_id in recipients {
+                    self.send_to_session(*session_id, SendCategory::Event, data.clone())
+                        .await?;
+                }
+            }
+
+            GameEvent::QuickPing {
+                recipients,
+                message,
+                ..
+            } => {
+                // 관심 영역 안 팀원에게만 전송
+                let data = protocol::encode_message(message.clone())?;
+                for session_id in recipients {
+                    self.send_to_session(*session_id, SendCategory::Event, data.clone())
+                        .await?;
+                }
+            }
+
+            _ => {
+                // 기타 이벤트는 현재 처리하지 않음
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 세션 주소로 전송 (세션이 이미 종료됐으면 생략)
+    async fn send_to_session(
+        &self,
+        session_id: SessionId,
+        category: SendCategory,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let Some(session) = self.session_manager.get_session(session_id).await else {
+            debug!(session_id = %session_id, "종료된 세션으로의 이벤트 전송 생략");
+            return Ok(());
+        };
+        let remote_addr = session.lock().await.remote_addr;
+        self.rudp_server
+            .send_budgeted(remote_addr, category, data)
+            .await?;
+        Ok(())
+    }
+
+    /// 세션 주소로 메시지 하나 전송
+    async fn send_message_to_session(
+        &self,
+        session_id: SessionId,
+        message: GameMessage,
+    ) -> Result<()> {
+        let category = SendCategory::of(&message);
+        self.send_to_session(session_id, category, protocol::encode_message(message)?)
+            .await
+    }
+
+    /// 응답 메시지 직렬화 후 전송
+    async fn send(&self, client_addr: SocketAddr, message: GameMessage) -> Result<()> {
+        let category = SendCategory::of(&message);
+        let data = protocol::encode_message(message)?;
+        self.rudp_server
+            .send_budgeted(client_addr, category, data)
+            .await?;
+        Ok(())
+    }
+
+    /// 근처 플레이어들에게 브로드캐스트
+    ///
+    /// 같은 방(방이 없으면 로비)의 다른 플레이어에게 전송합니다. 보낸 플레이어 본인은 제외합니다.
+    async fn broadcast_to_nearby_players(
+        &self,
+        center_player_id: u32,
+        message: GameMessage,
+    ) -> Result<()> {
+        let recipients = self.game_state.room_peer_sessions(center_player_id).await;
+        if recipients.is_empty() {
+            return Ok(());
+        }
+        let category = SendCategory::of(&message);
+        let data = protocol::encode_message(message)?;
+        for session_id in recipients {
+            self.send_to_session(session_id, category, data.clone())
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// 모든 플레이어에게 브로드캐스트
+    async fn broadcast_to_all_players(&self, _message: GameMessage) -> Result<()> {
+        // TODO: 전체 플레이어 브로드캐스트 구현
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::game::messages::{DisconnectReason, PlayerId};
+    use crate::game::player::PlayerManager;
+    use crate::network::session::SessionManagerConfig;
+    use crate::protocol::rudp::RudpConfig;
+    use shared::security::{EntryTicketSigner, SecurityConfig, SecurityPolicy};
+    use shared::tool::high_performance::redis_optimizer::{RedisOptimizer, RedisOptimizerConfig};
+    use std::time::Duration;
+    use tokio::net::UdpSocket;
+
+    impl PacketDispatcher {
+        pub(crate) fn game_state(&self) -> Arc<GameStateManager> {
+            self.game_state.clone()
+        }
+
+        pub(crate) fn session_manager(&self) -> Arc<SessionManager> {
+            self.session_manager.clone()
+        }
+    }
+
+    /// 테스트용 디스패처 (127.0.0.1:6379의 Redis 서버가 필요하며, 없으면 패닉)
+    pub(crate) async fn dispatcher() -> PacketDispatcher {
+        dispatcher_with(GameConfig::development()).await
+    }
+
+    pub(crate) async fn dispatcher_with(config: GameConfig) -> PacketDispatcher {
+        dispatcher_with_clock(config, shared::tool::clock::system_clock()).await
+    }
+
+    pub(crate) async fn dispatcher_with_clock(
+        config: GameConfig,
+        clock: shared::tool::clock::SharedClock,
+    ) -> PacketDispatcher {
+        let security = SecurityMiddleware::new(SecurityConfig::default())
+            .await
+            .unwrap();
+        dispatcher_from(config, clock, security, None).await
+    }
+
+    /// 지정한 엔드포인트 보안 정책을 쓰는 테스트용 디스패처
+    pub(crate) async fn dispatcher_with_policy(policy: &str) -> PacketDispatcher {
+        let security = SecurityMiddleware::new(SecurityConfig::default())
+            .await
+            .unwrap()
+            .with_policy(SecurityPolicy::parse(policy).unwrap());
+        dispatcher_from(
+            GameConfig::development(),
+            shared::tool::clock::system_clock(),
+            security,
+            None,
+        )
+        .await
+    }
+
+    async fn dispatcher_from(
+        config: GameConfig,
+        clock: shared::tool::clock::SharedClock,
+        security: SecurityMiddleware,
+        entry_tickets: Option<(EntryTicketSigner, &str)>,
+    ) -> PacketDispatcher {
+        let redis = Arc::new(
+            RedisOptimizer::new("redis://127.0.0.1:6379", RedisOptimizerConfig::default())
+                .await
+                .expect("dispatcher tests need Redis at 127.0.0.1:6379"),
+        );
+        let security = Arc::new(security);
+        let player_manager = Arc::new(PlayerManager::new());
+        let rudp_server = Arc::new(
+            RudpServer::new(
+                "127.0.0.1:0",
+                RudpConfig::default(),
+                security.clone(),
+                redis.clone(),
+            )
+            .await
+            .unwrap(),
+        );
+        let session_manager = Arc::new(
+            SessionManager::new(
+                SessionManagerConfig::default(),
+                security.clone(),
+                redis.clone(),
+                player_manager.clone(),
+            )
+            .await
+            .unwrap(),
+        );
+        let mut game_state = GameStateManager::new(config, player_manager, security.clone(), redis)
+            .await
+            .unwrap()
+            .with_clock(clock);
+        if let Some((signer, address)) = entry_tickets {
+            game_state = game_state.with_entry_tickets(signer, address);
+        }
+        let game_state = Arc::new(game_state);
+        session_manager.add_event_listener(game_state.clone()).await;
+        PacketDispatcher::new(
+            rudp_server,
+            game_state,
+            session_manager,
+            security,
+            LiveConfig::local(),
+        )
+    }
+
+    pub(crate) async fn client() -> UdpSocket {
+        UdpSocket::bind("127.0.0.1:0").await.unwrap()
+    }
+
+    /// 클라이언트 소켓으로 보낸 메시지를 서버 수신 경로로 처리
+    pub(crate) async fn send(
+        dispatcher: &PacketDispatcher,
+        client: &UdpSocket,
+        message: GameMessage,
+    ) {
+        let server_addr = dispatcher.rudp_server.local_addr().unwrap();
+        client
+            .send_to(&protocol::encode_message(message).unwrap(), server_addr)
+            .await
+            .unwrap();
+        let (addr, data) = dispatcher.rudp_server.receive_message().await.unwrap();
+        dispatcher.handle_packet(addr, &data).await;
+    }
+
+    pub(crate) async fn recv(client: &UdpSocket) -> GameMessage {
+        let mut buffer = vec![0u8; 8192];
+        let (len, _) = tokio::time::timeout(Duration::from_secs(1), client.recv_from(&mut buffer))
+            .await
+            .expect("no datagram received")
+            .unwrap();
+        protocol::decode_message(&buffer[..len]).unwrap()
+    }
+
+    /// 응답이 오지 않았는지 확인
+    pub(crate) async fn assert_no_reply(client: &UdpSocket) {
+        let mut buffer = [0u8; 512];
+        let received =
+            tokio::time::timeout(Duration::from_millis(100), client.recv_from(&mut buffer)).await;
+        assert!(received.is_err(), "unexpected datagram");
+    }
+
+    pub(crate) fn connect(player_id: PlayerId) -> GameMessage {
+        GameMessage::Connect {
+            player_name: format!("player{player_id}"),
+            auth_token: player_id.to_string(),
+            client_version: "1.3.0".to_string(),
+        }
+    }
+
+    /// 방 이벤트 채널에서 조건에 맞는 이벤트를 기다림
+    async fn next_event(
+        receiver: &mut crate::game::event_channels::RoomEventReceiver,
+        matches: impl Fn(&GameEvent) -> bool,
+    ) -> GameEvent {
+        tokio::time::timeout(Duration::from_secs(1), async {
+            loop {
+                let event = receiver.recv().await.expect("room channel closed");
+                if matches(&event) {
+                    return event;
+                }
+            }
+        })
+        .await
+        .expect("no matching room event")
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_connect_registers_session_through_receive_path() {
+        let dispatcher = dispatcher().await;
+        let client = client().await;
+        let client_addr = client.local_addr().unwrap();
+        let session_id = crate::utils::socket_addr_to_u64(client_addr);
+
+        send(&dispatcher, &client, connect(42)).await;
+        assert!(matches!(
+            recv(&client).await,
+            GameMessage::ConnectResponse {
+                success: true,
+                player_id: Some(42),
+                ..
+            }
+        ));
+
+        // 네트워크 세션과 게임 상태가 같은 세션 ID를 사용
+        let sessions = &dispatcher.session_manager;
+        assert_eq!(
+            sessions.get_session_by_addr(client_addr).await,
+            Some(session_id)
+        );
+        assert_eq!(sessions.get_session_by_player(42).await, Some(session_id));
+        {
+            let session = sessions.get_session(session_id).await.unwrap();
+            let session = session.lock().await;
+            assert_eq!(session.state, SessionState::InGame);
+            assert_eq!(session.player_id, Some(42));
+        }
+        assert!(dispatcher.is_authenticated(session_id).await);
+        assert_eq!(
+            dispatcher.game_state.session_of_player(42).await,
+            Some(session_id)
+        );
+
+        // 연결 해제는 게임 상태와 네트워크 세션을 함께 정리
+        send(
+            &dispatcher,
+            &client,
+            GameMessage::Disconnect {
+                reason: DisconnectReason::Normal,
+            },
+        )
+        .await;
+        assert_eq!(sessions.get_session_by_addr(client_addr).await, None);
+        assert_eq!(dispatcher.game_state.session_of_player(42).await, None);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_failed_connect_leaves_session_unauthenticated() {
+        let dispatcher = dispatcher().await;
+        let client = client().await;
+        let client_addr = client.local_addr().unwrap();
+
+        send(
+            &dispatcher,
+            &client,
+            GameMessage::Connect {
+                player_name: "intruder".to_string(),
+                auth_token: "not-a-token".to_string(),
+                client_version: "1.3.0".to_string(),
+            },
+        )
+        .await;
+        assert!(matches!(
+            recv(&client).await,
+            GameMessage::ConnectResponse { success: false, .. }
+        ));
+
+        let session_id = dispatcher
+            .session_manager
+            .get_session_by_addr(client_addr)
+            .await
+            .unwrap();
+        assert!(!dispatcher.is_authenticated(session_id).await);
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_hitreg_debug_delivered_to_flagged_player() {
+        use crate::game::event_channels::LOBBY_ROOM_ID;
+        use crate::game::messages::{AttackTarget, AttackType, Direction};
+
+        let config = GameConfig {
+            hitreg_debug_enabled: true,
+            ..GameConfig::development()
+        };
+        let dispatcher = dispatcher_with(config).await;
+        let client = client().await;
+        send(&dispatcher, &client, connect(5)).await;
+        recv(&client).await;
+
+        dispatcher.game_state.hitreg_debugger().flag(5).unwrap();
+        let mut receiver = dispatcher.game_state.subscribe_room_events(LOBBY_ROOM_ID);
+        send(
+            &dispatcher,
+            &client,
+            GameMessage::Attack {
+                target: AttackTarget::Player(99),
+                attack_type: AttackType::MeleeBasic,
+                weapon_id: None,
+                attack_direction: Direction::new(1.0, 0.0, 0.0),
+                predicted_damage: 0,
+            },
+        )
+        .await;
+        recv(&client).await;
+
+        // 게임 상태가 발행한 이벤트를 방 구독 경로 그대로 전송
+        let event = next_event(&mut receiver, |event| {
+            matches!(event, GameEvent::HitRegDebug { .. })
+        })
+        .await;
+        dispatcher.broadcast_game_event(&event).await.unwrap();
+        match recv(&client).await {
+            GameMessage::HitRegDebug { player_id, records } => {
+                assert_eq!(player_id, 5);
+                assert_eq!(records.len(), 1);
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_lobby_events_delivered_to_room_members() {
+        use crate::game::match_manager::LobbyEvent;
+
+        let dispatcher = dispatcher().await;
+        let room_id = 10;
+        let mut receiver = dispatcher.game_state.subscribe_room_events(room_id);
+        let clients = [client().await, client().await];
+        for (player_id, client) in [1, 2].into_iter().zip(&clients) {
+            send(&dispatcher, client, connect(player_id)).await;
+            recv(client).await;
+            send(
+                &dispatcher,
+                client,
+                GameMessage::SetReady {
+                    room_id,
+                    ready: true,
+                },
+            )
+            .await;
+            recv(client).await;
+        }
+
+        // 두 번째 준비로 시작된 카운트다운은 로비 인원 모두에게 전송
+        let event = next_event(&mut receiver, |event| {
+            matches!(
+                event,
+                GameEvent::Lobby {
+                    event: LobbyEvent::CountdownStarted { .. },
+                    ..
+                }
+            )
+        })
+        .await;
+        dispatcher.broadcast_game_event(&event).await.unwrap();
+        for client in &clients {
+            assert!(matches!(
+                recv(client).await,
+                GameMessage::MatchCountdown { room_id: 10, .. }
+            ));
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_room_channel_closed_when_last_member_leaves() {
+        let dispatcher = dispatcher().await;
+        let room_id = 11;
+        let client = client().await;
+        send(&dispatcher, &client, connect(3)).await;
+        recv(&client).await;
+        send(
+            &dispatcher,
+            &client,
+            GameMessage::SetReady {
+                room_id,
+                ready: true,
+            },
+        )
+        .await;
+        recv(&client).await;
+        let mut receiver = dispatcher.game_state.subscribe_room_events(room_id);
+
+        send(
+            &dispatcher,
+            &client,
+            GameMessage::Disconnect {
+                reason: DisconnectReason::Normal,
+            },
+        )
+        .await;
+        let channels = dispatcher.game_state.event_channels();
+        assert!(!channels.room_ids().contains(&room_id));
+
+        // 남은 이벤트를 모두 받은 뒤 구독이 종료됨
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while receiver.recv().await.is_some() {}
+        })
+        .await
+        .expect("room subscriber did not close");
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_quick_ping_delivered_to_teammates_only() {
+        let config = GameConfig {
+            match_countdown_secs: 0,
+            ..GameConfig::development()
+        };
+        let dispatcher = dispatcher_with(config).await;
+        let room_id = 20;
+        let mut receiver = dispatcher.game_state.subscribe_room_events(room_id);
+
+        // 참가 순서대로 경찰(1), 도둑(2), 경찰(3)
+        let clients = [client().await, client().await, client().await];
+        for (player_id, client) in [1, 2, 3].into_iter().zip(&clients) {
+            send(&dispatcher, client, connect(player_id)).await;
+            recv(client).await;
+            send(
+                &dispatcher,
+                client,
+                GameMessage::SetReady {
+                    room_id,
+                    ready: true,
+                },
+            )
+            .await;
+            recv(client).await;
+        }
+        dispatcher
+            .game_state
+            .update_game_tick(1, 0.016)
+            .await
+            .unwrap();
+
+        let position = dispatcher
+            .game_state
+            .player_summaries()
+            .await
+            .into_iter()
+            .find(|player| player.id == 1)
+            .unwrap()
+            .position;
+        send(
+            &dispatcher,
+            &clients[0],
+            GameMessage::QuickPing {
+                ping_type: "enemy_spotted".to_string(),
+                position,
+            },
+        )
+        .await;
+
+        let event = next_event(&mut receiver, |event| {
+            matches!(event, GameEvent::QuickPing { .. })
+        })
+        .await;
+        dispatcher.broadcast_game_event(&event).await.unwrap();
+        assert!(matches!(
+            recv(&clients[2]).await,
+            GameMessage::QuickPingReceived { player_id: 1, .. }
+        ));
+
+        // 보낸 사람과 상대 팀에는 전송하지 않음
+        let mut buffer = [0u8; 512];
+        for client in &clients[..2] {
+            let received =
+                tokio::time::timeout(Duration::from_millis(100), client.recv_from(&mut buffer))
+                    .await;
+            assert!(received.is_err());
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_afk_notices_delivered_and_disconnect_tolerates_closed_session() {
+        use crate::game::event_channels::LOBBY_ROOM_ID;
+        use shared::tool::clock::FrozenClock;
+
+        let config = GameConfig {
+            afk_warning_secs: 10,
+            afk_spectator_secs: 20,
+            afk_disconnect_secs: 30,
+            ..GameConfig::development()
+        };
+        let clock = FrozenClock::new();
+        let dispatcher = dispatcher_with_clock(config, clock.clone()).await;
+        let client = client().await;
+        let session_id = crate::utils::socket_addr_to_u64(client.local_addr().unwrap());
+        send(&dispatcher, &client, connect(8)).await;
+        recv(&client).await;
+        let mut receiver = dispatcher.game_state.subscribe_room_events(LOBBY_ROOM_ID);
+
+        // 경고: 본인에게 연결 해제 예고 공지
+        clock.advance(Duration::from_secs(11));
+        dispatcher
+            .game_state
+            .update_game_tick(60, 0.016)
+            .await
+            .unwrap();
+        let event = next_event(&mut receiver, |event| {
+            matches!(event, GameEvent::PlayerAfkWarning { .. })
+        })
+        .await;
+        dispatcher.broadcast_game_event(&event).await.unwrap();
+        assert!(matches!(
+            recv(&client).await,
+            GameMessage::ServerNotice {
+                priority: Priority::High,
+                expires_at: Some(_),
+                ..
+            }
+        ));
+
+        // 관전자 전환 공지
+        clock.advance(Duration::from_secs(10));
+        dispatcher
+            .game_state
+            .update_game_tick(120, 0.016)
+            .await
+            .unwrap();
+        let event = next_event(&mut receiver, |event| {
+            matches!(event, GameEvent::PlayerMovedToSpectator { .. })
+        })
+        .await;
+        dispatcher.broadcast_game_event(&event).await.unwrap();
+        assert!(matches!(
+            recv(&client).await,
+            GameMessage::ServerNotice {
+                priority: Priority::Medium,
+                ..
+            }
+        ));
+
+        // 연결 해제: 세션 종료 후 같은 이벤트가 다시 와도 오류로 전파하지 않음
+        clock.advance(Duration::from_secs(10));
+        dispatcher
+            .game_state
+            .update_game_tick(180, 0.016)
+            .await
+            .unwrap();
+        let event = next_event(&mut receiver, |event| {
+            matches!(event, GameEvent::PlayerAfkDisconnected { .. })
+        })
+        .await;
+        dispatcher.broadcast_game_event(&event).await.unwrap();
+        assert!(dispatcher
+            .session_manager
+            .get_session(session_id)
+            .await
+            .is_none());
+        dispatcher.broadcast_game_event(&event).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_authenticated_session_satisfies_role_gated_policy() {
+        let policy = r#"
+            [[endpoint]]
+            pattern = "rudp:respawn"
+            auth_required = true
+            min_role = "user"
+        "#;
+        let dispatcher = dispatcher_with_policy(policy).await;
+
+        // 인증을 마친 세션은 User 역할로 정책을 통과
+        let player = client().await;
+        send(&dispatcher, &player, connect(61)).await;
+        assert!(matches!(
+            recv(&player).await,
+            GameMessage::ConnectResponse { success: true, .. }
+        ));
+        send(&dispatcher, &player, GameMessage::Respawn).await;
+        recv(&player).await;
+
+        // 세션이 없거나 인증에 실패한 클라이언트는 정책에서 거부되어 응답 없음
+        let stranger = client().await;
+        send(&dispatcher, &stranger, GameMessage::Respawn).await;
+        assert_no_reply(&stranger).await;
+
+        let intruder = client().await;
+        send(
+            &dispatcher,
+            &intruder,
+            GameMessage::Connect {
+                player_name: "intruder".to_string(),
+                auth_token: "not-a-token".to_string(),
+                client_version: "1.3.0".to_string(),
+            },
+        )
+        .await;
+        assert!(matches!(
+            recv(&intruder).await,
+            GameMessage::ConnectResponse { success: false, .. }
+        ));
+        send(&dispatcher, &intruder, GameMessage::Respawn).await;
+        assert_no_reply(&intruder).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_connect_requires_entry_ticket_for_this_server() {
+        let signer = EntryTicketSigner::new(b"ticket-key", Duration::from_secs(60));
+        let security = SecurityMiddleware::new(SecurityConfig::default())
+            .await
+            .unwrap();
+        let dispatcher = dispatcher_from(
+            GameConfig::development(),
+            shared::tool::clock::system_clock(),
+            security,
+            Some((signer.clone(), "127.0.0.1:5000")),
+        )
+        .await;
+        let connect_with = |auth_token: String| GameMessage::Connect {
+            player_name: "player71".to_string(),
+            auth_token,
+            client_version: "1.3.0".to_string(),
+        };
+
+        // 플레이어 ID만 보내거나 다른 서버용 티켓이면 거절
+        let legacy = client().await;
+        send(&dispatcher, &legacy, connect(71)).await;
+        assert!(matches!(
+            recv(&legacy).await,
+            GameMessage::ConnectResponse { success: false, .. }
+        ));
+        let (other_server, _) = signer.issue(71, "rudp", "127.0.0.1:5001");
+        let misrouted = client().await;
+        send(&dispatcher, &misrouted, connect_with(other_server)).await;
+        assert!(matches!(
+            recv(&misrouted).await,
+            GameMessage::ConnectResponse { success: false, .. }
+        ));
+
+        // 이 서버용 티켓이면 티켓의 사용자 ID로 입장
+        let (ticket, _) = signer.issue(71, "rudp", "127.0.0.1:5000");
+        let player = client().await;
+        send(&dispatcher, &player, connect_with(ticket)).await;
+        assert!(matches!(
+            recv(&player).await,
+            GameMessage::ConnectResponse {
+                success: true,
+                player_id: Some(71),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_kill_switch_replies_only_to_authenticated_sessions() {
+        use shared::service::redis::live_config::kill_switch_key;
+
+        let dispatcher = dispatcher().await;
+        let player = client().await;
+        send(&dispatcher, &player, connect(62)).await;
+        assert!(matches!(
+            recv(&player).await,
+            GameMessage::ConnectResponse { success: true, .. }
+        ));
+
+        let live_config = dispatcher.live_config.clone();
+        live_config
+            .set(&kill_switch_key("rudp:respawn"), Some("true"), "admin")
+            .await
+            .unwrap();
+
+        // 인증된 세션은 기능 비활성화 에러를 받음
+        send(&dispatcher, &player, GameMessage::Respawn).await;
+        match recv(&player).await {
+            GameMessage::Error { error_code, .. } => assert_eq!(error_code, "FEATURE_DISABLED"),
+            other => panic!("unexpected reply: {other:?}"),
+        }
+
+        // 세션이 없는 주소에는 응답하지 않음 (위조 주소로의 반사 방지)
+        let stranger = client().await;
+        send(&dispatcher, &stranger, GameMessage::Respawn).await;
+        assert_no_reply(&stranger).await;
+
+        // 킬 스위치를 해제하면 다시 정상 처리
+        live_config
+            .set(&kill_switch_key("rudp:respawn"), None, "admin")
+            .await
+            .unwrap();
+        send(&dispatcher, &player, GameMessage::Respawn).await;
+        assert!(!matches!(
+            recv(&player).await,
+            GameMessage::Error { error_code, .. } if error_code == "FEATURE_DISABLED"
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_move_update_delivered_to_other_players_in_room() {
+        use crate::game::event_channels::LOBBY_ROOM_ID;
+        use crate::game::messages::{Direction, Position};
+
+        let config = GameConfig {
+            input_buffer_enabled: false,
+            ..GameConfig::development()
+        };
+        let dispatcher = dispatcher_with(config).await;
+        let mut receiver = dispatcher.game_state.subscribe_room_events(LOBBY_ROOM_ID);
+
+        let mover = client().await;
+        let observer = client().await;
+        send(&dispatcher, &mover, connect(71)).await;
+        let spawn = match recv(&mover).await {
+            GameMessage::ConnectResponse {
+                success: true,
+                spawn_position: Some(spawn),
+                ..
+            } => spawn,
+            other => panic!("unexpected reply: {other:?}"),
+        };
+        send(&dispatcher, &observer, connect(72)).await;
+        recv(&observer).await;
+
+        // 연결 직후 이동은 스팸 제한에 걸리므로 잠시 대기
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let target = Position::new(spawn.x + 0.4, spawn.y, spawn.z);
+        send(
+            &dispatcher,
+            &mover,
+            GameMessage::Move {
+                target_position: target,
+                direction: Direction::new(1.0, 0.0, 0.0),
+                speed_multiplier: 1.0,
+                client_timestamp: crate::utils::current_timestamp_ms(),
+            },
+        )
+        .await;
+
+        let event = next_event(&mut receiver, |event| {
+            matches!(event, GameEvent::PlayerMoved { player_id: 71, .. })
+        })
+        .await;
+        dispatcher.broadcast_game_event(&event).await.unwrap();
+
+        // 같은 로비의 다른 플레이어만 이동을 받음
+        match recv(&observer).await {
+            GameMessage::MoveUpdate {
+                player_id,
+                current_position,
+                ..
+            } => {
+                assert_eq!(player_id, 71);
+                assert_eq!(current_position, target);
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+        assert_no_reply(&mover).await;
+    }
+}
@@ -4,6 +4,8 @@
 //!
 //! # 주요 구성요소
 //! - `session`: 세션 관리 및 라이프사이클
+//! - `bandwidth`: 클라이언트별 송신 대역폭 예산
+//! - `dispatch`: 수신 패킷 처리, 세션 등록, 방 이벤트 전송
 //!
 //! # 사용 예제
 //! ```rust
@@ -14,9 +16,13 @@
 //! session_manager.start().await?;
 //! ```
 
+pub mod bandwidth;
+pub mod dispatch;
 pub mod session;
 
 // 주요 타입들을 re-export
+pub use bandwidth::{BandwidthReport, BandwidthScheduler, SendBudgetConfig, SendCategory};
+pub use dispatch::PacketDispatcher;
 pub use session::{
     SessionEvent, SessionEventListener, SessionId, SessionManager, SessionManagerConfig,
     SessionMetadata, SessionState,
@@ -40,10 +40,16 @@ pub enum SessionState {
     Connecting,
     /// 인증 대기 중
     Authenticating,
+    /// 인증 완료 (게임 입장 전)
+    Authenticated,
+    /// 게임 플레이 중
+    InGame,
     /// 활성 상태
     Active,
     /// 유휴 상태
     Idle,
+    /// 종료 처리 중 (새 메시지 수신 중단, 정리 대기)
+    Draining,
     /// 연결 해제 중
     Disconnecting,
     /// 연결 해제됨
@@ -54,6 +60,43 @@ pub enum SessionState {
     Error,
 }
 
+impl SessionState {
+    /// 더 이상 전이할 수 없는 종료 상태인지 여부
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Disconnected | Self::Timeout)
+    }
+
+    /// 허용된 상태 전이인지 확인
+    ///
+    /// Connecting → Authenticating → Authenticated → InGame 순서로 진행하며,
+    /// 종료 상태가 아니라면 언제든 Draining/Disconnecting/Error로 전이할 수 있습니다.
+    pub fn can_transition_to(self, next: SessionState) -> bool {
+        if self == next {
+            return true;
+        }
+        if self.is_terminal() {
+            return false;
+        }
+
+        match next {
+            Self::Draining
+            | Self::Disconnecting
+            | Self::Disconnected
+            | Self::Timeout
+            | Self::Error => true,
+            Self::Connecting => false,
+            Self::Authenticating => self == Self::Connecting,
+            Self::Authenticated => {
+                matches!(self, Self::Connecting | Self::Authenticating | Self::InGame)
+            }
+            Self::InGame | Self::Active | Self::Idle => matches!(
+                self,
+                Self::Authenticated | Self::InGame | Self::Active | Self::Idle
+            ),
+        }
+    }
+}
+
 /// 세션 메타데이터
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -135,7 +178,13 @@ impl SessionMetadata {
 
     /// 세션 활성 여부
     pub fn is_active(&self) -> bool {
-        matches!(self.state, SessionState::Active | SessionState::Idle)
+        matches!(
+            self.state,
+            SessionState::Authenticated
+                | SessionState::InGame
+                | SessionState::Active
+                | SessionState::Idle
+        )
     }
 }
 
@@ -285,6 +334,8 @@ pub enum SessionTerminationReason {
     ServerOverload,
     /// 관리자 킥
     AdminKick(String),
+    /// 장시간 입력 없음 (AFK)
+    Afk,
     /// 기타
     Other(String),
 }
@@ -649,7 +700,7 @@ impl SessionManager {
         // 세션 업데이트
         session_lock.player_id = Some(player_id);
         session_lock.auth_token = Some(auth_token);
-        session_lock.state = SessionState::Active;
+        session_lock.state = SessionState::Authenticated;
         session_lock.update_activity();
 
         // 플레이어 매핑 저장
@@ -693,6 +744,13 @@ impl SessionManager {
         let old_state = {
             let mut session_lock = session.lock().await;
             let old_state = session_lock.state;
+            if !old_state.can_transition_to(new_state) {
+                return Err(anyhow!(
+                    "Invalid session state transition: {:?} -> {:?}",
+                    old_state,
+                    new_state
+                ));
+            }
             session_lock.state = new_state;
             session_lock.update_activity();
             old_state
@@ -730,7 +788,8 @@ impl SessionManager {
             .ok_or_else(|| anyhow!("Session not found"))?;
 
         let (remote_addr, player_id, uptime) = {
-            let session_lock = session.lock().await;
+            let mut session_lock = session.lock().await;
+            session_lock.state = SessionState::Draining;
             let uptime_duration = session_lock.created_at.elapsed();
             (
                 session_lock.remote_addr,
@@ -764,14 +823,13 @@ impl SessionManager {
             }
         }
 
-        // 세션 메타데이터를 풀에 반환
+        // 세션 메타데이터를 풀에 반환 (아직 다른 곳에서 참조 중이면 반환만 생략)
+        drop(session);
         if let Some((_, session_arc)) = self.sessions.remove(&session_id) {
-            let metadata = Arc::try_unwrap(session_arc)
-                .map_err(|_| anyhow!("Failed to unwrap session metadata"))?
-                .into_inner();
-
-            let mut pool = self.session_pool.lock().await;
-            pool.release(metadata);
+            if let Ok(metadata) = Arc::try_unwrap(session_arc) {
+                let mut pool = self.session_pool.lock().await;
+                pool.release(metadata.into_inner());
+            }
         }
 
         // 통계 업데이트
@@ -908,7 +966,7 @@ impl SessionManager {
                         expired_sessions.push((session_id, SessionTerminationReason::Timeout));
                     }
                 }
-                SessionState::Active => {
+                SessionState::Authenticated | SessionState::InGame | SessionState::Active => {
                     if session_lock.is_timeout(idle_timeout) {
                         idle_sessions.push(session_id);
                     }
@@ -970,7 +1028,7 @@ impl SessionManager {
                             expired_sessions.push((session_id, SessionTerminationReason::Timeout));
                         }
                     }
-                    SessionState::Active => {
+                    SessionState::Authenticated | SessionState::InGame | SessionState::Active => {
                         if session_lock.is_timeout(idle_timeout) {
                             idle_sessions.push(session_id);
                         }
@@ -1249,6 +1307,21 @@ mod tests {
         assert!(metadata.is_timeout(Duration::from_millis(50)));
     }
 
+    #[test]
+    fn test_session_state_transitions() {
+        use SessionState::*;
+
+        assert!(Connecting.can_transition_to(Authenticating));
+        assert!(Authenticating.can_transition_to(Authenticated));
+        assert!(Authenticated.can_transition_to(InGame));
+        assert!(InGame.can_transition_to(Draining));
+        assert!(Draining.can_transition_to(Disconnected));
+
+        assert!(!Connecting.can_transition_to(InGame));
+        assert!(!Draining.can_transition_to(InGame));
+        assert!(!Disconnected.can_transition_to(Connecting));
+    }
+
     #[tokio::test]
     async fn test_session_pool() {
         let mut pool = SessionPool::new(2);
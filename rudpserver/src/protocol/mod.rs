@@ -1,102 +1,184 @@
 //! RUDP Protocol Definitions
 //!
-//! 게임 메시지 프로토콜 정의 및 직렬화/역직렬화 기능을 제공합니다.
+//! 게임 메시지의 와이어 포맷과 직렬화/역직렬화 기능을 제공합니다.
+//!
+//! 메시지 본문은 `game::messages::GameMessage` 하나로 통일되어 있고,
+//! 이 모듈은 그 위에 버전이 포함된 봉투(`MessageEnvelope`)를 씌우는 변환 계층입니다.
+//! 모든 인코딩은 bincode로 통일되며, 버전이 맞지 않는 패킷은 조용히 버려지지 않고
+//! `ProtocolError::UnsupportedVersion`으로 보고됩니다.
 
 pub mod rudp;
 
 use serde::{Deserialize, Serialize};
+use shared::protocol_version::{Capabilities, MessageSpec, ProtocolSpec, ProtocolVersion};
+use thiserror::Error;
+
+use crate::game::messages::{requires_reliable_delivery, GameMessage};
+
+const V1_0: ProtocolVersion = ProtocolVersion::new(1, 0, 0);
+const V1_1: ProtocolVersion = ProtocolVersion::new(1, 1, 0);
+const V1_2: ProtocolVersion = ProtocolVersion::new(1, 2, 0);
+const V1_3: ProtocolVersion = ProtocolVersion::new(1, 3, 0);
+
+/// RUDP 프로토콜 정의
+///
+/// 메시지 레지스트리는 `GameMessage::message_type_str`의 이름과 일치해야 합니다.
+/// 메시지를 추가하면 `verify`가 컴파일 에러를 내므로 버전을 올릴지 결정해야 합니다.
+pub static RUDP_PROTOCOL: ProtocolSpec = ProtocolSpec {
+    name: "rudp",
+    current: V1_3,
+    min_supported: V1_0,
+    capabilities: Capabilities::BINARY_CODEC,
+    messages: &[
+        MessageSpec::new("connect", V1_0),
+        MessageSpec::new("connect_response", V1_0),
+        MessageSpec::new("disconnect", V1_0),
+        MessageSpec::new("move", V1_0),
+        MessageSpec::new("move_update", V1_0),
+        MessageSpec::new("attack", V1_0),
+        MessageSpec::new("attack_result", V1_0),
+        MessageSpec::new("die", V1_0),
+        MessageSpec::new("respawn", V1_0),
+        MessageSpec::new("respawn_complete", V1_0),
+        MessageSpec::new("state_update", V1_0),
+        MessageSpec::new("error", V1_0),
+        MessageSpec::new("server_notice", V1_0),
+        MessageSpec::new("hitreg_debug", V1_0),
+        MessageSpec::new("set_ready", V1_1),
+        MessageSpec::new("lobby_state", V1_1),
+        MessageSpec::new("match_countdown", V1_1),
+        MessageSpec::new("match_countdown_cancelled", V1_1),
+        MessageSpec::new("match_started", V1_1),
+        MessageSpec::new("match_ended", V1_2),
+        MessageSpec::new("quick_ping", V1_3),
+        MessageSpec::new("quick_ping_received", V1_3),
+    ],
+    history: &[(V1_0, 14), (V1_1, 19), (V1_2, 20), (V1_3, 22)],
+};
+const _: () = RUDP_PROTOCOL.verify();
+
+/// 현재 서버가 사용하는 봉투 버전 (프로토콜 major)
+pub const PROTOCOL_VERSION: u16 = RUDP_PROTOCOL.current.major;
+
+/// 서버가 수락하는 최소 봉투 버전
+pub const MIN_SUPPORTED_PROTOCOL_VERSION: u16 = RUDP_PROTOCOL.min_supported.major;
+
+/// 프로토콜 변환 에러
+#[derive(Debug, Error)]
+pub enum ProtocolError {
+    #[error("Unsupported protocol version: {version} (supported: {min}..={max})")]
+    UnsupportedVersion { version: u16, min: u16, max: u16 },
+
+    #[error("Message encode failed: {0}")]
+    Encode(String),
+
+    #[error("Message decode failed: {0}")]
+    Decode(String),
+}
 
-/// 게임 메시지 타입
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum GameMessageType {
-    /// 하트비트 메시지
-    Heartbeat,
-
-    /// 플레이어 연결
-    PlayerConnect { player_id: u32, player_name: String },
+/// 신뢰성 레벨
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReliabilityLevel {
+    /// 신뢰성 불필요 (속도 우선)
+    Unreliable,
 
-    /// 플레이어 연결 해제
-    PlayerDisconnect { player_id: u32 },
+    /// 순서 보장 필요
+    Sequenced,
 
-    /// 채팅 메시지
-    Chat { player_id: u32, message: String },
+    /// 신뢰성 보장 필요
+    Reliable,
 
-    /// 게임 상태 업데이트 (확장 예정)
-    GameState {
-        // TODO: 게임 로직에 따른 상태 정의
-        data: Vec<u8>,
-    },
+    /// 신뢰성 + 순서 보장
+    ReliableSequenced,
+}
 
-    /// 확장을 위한 커스텀 메시지
-    Custom { message_type: String, data: Vec<u8> },
+impl ReliabilityLevel {
+    /// 메시지 타입에 맞는 신뢰성 레벨을 결정합니다.
+    pub fn for_message(message: &GameMessage) -> Self {
+        match message {
+            GameMessage::Move { .. } | GameMessage::MoveUpdate { .. } => Self::Sequenced,
+            _ if requires_reliable_delivery(message) => Self::ReliableSequenced,
+            _ => Self::Unreliable,
+        }
+    }
 }
 
-/// 게임 메시지 래퍼
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GameMessage {
+/// 버전 정보를 포함한 게임 메시지 봉투
+///
+/// 클라이언트와 서버가 주고받는 유일한 와이어 포맷입니다.
+/// `version` 필드는 항상 첫 번째로 직렬화되어 본문 디코딩 전에 검사됩니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MessageEnvelope {
+    /// 프로토콜 버전
+    pub version: u16,
+
     /// 메시지 ID (중복 검출용)
     pub message_id: u64,
 
     /// 타임스탬프
     pub timestamp: u64,
 
-    /// 메시지 내용
-    pub content: GameMessageType,
-
     /// 신뢰성 요구사항
     pub reliability: ReliabilityLevel,
-}
-
-/// 신뢰성 레벨
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ReliabilityLevel {
-    /// 신뢰성 불필요 (속도 우선)
-    Unreliable,
-
-    /// 순서 보장 필요
-    Sequenced,
 
-    /// 신뢰성 보장 필요
-    Reliable,
-
-    /// 신뢰성 + 순서 보장
-    ReliableSequenced,
+    /// 메시지 내용
+    pub payload: GameMessage,
 }
 
-impl GameMessage {
-    /// 새로운 게임 메시지를 생성합니다.
-    pub fn new(content: GameMessageType, reliability: ReliabilityLevel) -> Self {
+impl MessageEnvelope {
+    /// 현재 프로토콜 버전으로 새로운 봉투를 생성합니다.
+    pub fn new(payload: GameMessage) -> Self {
         Self {
+            version: PROTOCOL_VERSION,
             message_id: generate_message_id(),
             timestamp: current_timestamp(),
-            content,
-            reliability,
+            reliability: ReliabilityLevel::for_message(&payload),
+            payload,
         }
     }
 
-    /// 메시지를 바이트 배열로 직렬화합니다.
-    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
-        serde_json::to_vec(self).map_err(|e| anyhow::anyhow!("메시지 직렬화 실패: {}", e))
+    /// 봉투를 바이트 배열로 직렬화합니다.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ProtocolError> {
+        bincode::serialize(self).map_err(|e| ProtocolError::Encode(e.to_string()))
     }
 
-    /// 바이트 배열에서 메시지를 역직렬화합니다.
-    pub fn from_bytes(data: &[u8]) -> anyhow::Result<Self> {
-        serde_json::from_slice(data).map_err(|e| anyhow::anyhow!("메시지 역직렬화 실패: {}", e))
+    /// 바이트 배열에서 봉투를 역직렬화합니다.
+    ///
+    /// 본문을 해석하기 전에 버전을 먼저 확인합니다.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProtocolError> {
+        let version = peek_version(data)?;
+        if !(MIN_SUPPORTED_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&version) {
+            return Err(ProtocolError::UnsupportedVersion {
+                version,
+                min: MIN_SUPPORTED_PROTOCOL_VERSION,
+                max: PROTOCOL_VERSION,
+            });
+        }
+
+        bincode::deserialize(data).map_err(|e| ProtocolError::Decode(e.to_string()))
     }
 
     /// 메시지 타입 문자열을 반환합니다.
     pub fn message_type_str(&self) -> &'static str {
-        match &self.content {
-            GameMessageType::Heartbeat => "heartbeat",
-            GameMessageType::PlayerConnect { .. } => "player_connect",
-            GameMessageType::PlayerDisconnect { .. } => "player_disconnect",
-            GameMessageType::Chat { .. } => "chat",
-            GameMessageType::GameState { .. } => "game_state",
-            GameMessageType::Custom { .. } => "custom",
-        }
+        self.payload.message_type_str()
     }
 }
 
+/// 게임 메시지를 와이어 포맷으로 인코딩합니다.
+pub fn encode_message(message: GameMessage) -> Result<Vec<u8>, ProtocolError> {
+    MessageEnvelope::new(message).to_bytes()
+}
+
+/// 와이어 포맷에서 게임 메시지를 디코딩합니다.
+pub fn decode_message(data: &[u8]) -> Result<GameMessage, ProtocolError> {
+    MessageEnvelope::from_bytes(data).map(|envelope| envelope.payload)
+}
+
+/// 패킷 앞부분의 프로토콜 버전만 읽습니다.
+fn peek_version(data: &[u8]) -> Result<u16, ProtocolError> {
+    bincode::deserialize::<u16>(data).map_err(|e| ProtocolError::Decode(e.to_string()))
+}
+
 /// 메시지 ID 생성 함수
 fn generate_message_id() -> u64 {
     use std::sync::atomic::{AtomicU64, Ordering};
@@ -112,3 +194,56 @@ fn current_timestamp() -> u64 {
         .unwrap_or_default()
         .as_millis() as u64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::messages::{Direction, Position};
+
+    #[test]
+    fn test_envelope_roundtrip() {
+        let message = GameMessage::Move {
+            target_position: Position::new(1.0, 0.0, 2.0),
+            direction: Direction::new(1.0, 0.0, 0.0),
+            speed_multiplier: 1.0,
+            client_timestamp: 42,
+        };
+
+        let bytes = encode_message(message.clone()).unwrap();
+        let decoded = decode_message(&bytes).unwrap();
+
+        assert_eq!(decoded, message);
+    }
+
+    #[test]
+    fn test_reliability_for_message() {
+        assert_eq!(
+            ReliabilityLevel::for_message(&GameMessage::Respawn),
+            ReliabilityLevel::ReliableSequenced
+        );
+    }
+
+    #[test]
+    fn test_all_messages_registered() {
+        assert!(RUDP_PROTOCOL.message(GameMessage::Respawn.message_type_str()).is_some());
+        assert!(RUDP_PROTOCOL.negotiate_str("1.0.0").unwrap().supports("hitreg_debug"));
+        assert!(!RUDP_PROTOCOL.negotiate_str("1.0.0").unwrap().supports("set_ready"));
+        assert!(RUDP_PROTOCOL.negotiate_str("1.1.0").unwrap().supports("match_started"));
+        assert!(!RUDP_PROTOCOL.negotiate_str("1.1.0").unwrap().supports("match_ended"));
+        assert!(!RUDP_PROTOCOL.negotiate_str("1.2.0").unwrap().supports("quick_ping"));
+        assert!(RUDP_PROTOCOL.negotiate_str("1.3.0").unwrap().supports("quick_ping_received"));
+        assert!(RUDP_PROTOCOL.negotiate_str("2.0.0").is_err());
+    }
+
+    #[test]
+    fn test_unsupported_version_rejected() {
+        let mut envelope = MessageEnvelope::new(GameMessage::Respawn);
+        envelope.version = PROTOCOL_VERSION + 1;
+        let bytes = envelope.to_bytes().unwrap();
+
+        assert!(matches!(
+            MessageEnvelope::from_bytes(&bytes),
+            Err(ProtocolError::UnsupportedVersion { .. })
+        ));
+    }
+}
@@ -9,6 +9,7 @@
 //! - 혼잡 제어 (Congestion control)
 //! - 연결 시뮬레이션 (Connection lifecycle)
 //! - 적응형 타임아웃 (Adaptive RTO)
+//! - 네트워크 상태 시뮬레이션 (로컬/부하 테스트용 손실·중복·순서 뒤바뀜·지연 주입)
 //!
 //! # Performance
 //! - 2000명 동시 연결 지원
@@ -16,17 +17,21 @@
 //! - >100K packets/sec 처리량
 
 use anyhow::{anyhow, Result};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::net::UdpSocket;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tokio::time::sleep;
 use tracing::{debug, error, info, trace, warn};
 
 // Shared library imports for performance and security
+use crate::network::bandwidth::{BandwidthReport, BandwidthScheduler, SendBudgetConfig, SendCategory};
 use crate::utils::{socket_addr_to_u64, PacketType, RudpPacketHeader};
 use shared::security::SecurityMiddleware;
 use shared::tool::high_performance::redis_optimizer::RedisOptimizer;
@@ -54,6 +59,11 @@ pub struct RudpConfig {
     pub enable_congestion_control: bool,
     /// 패킷 압축 활성화
     pub enable_compression: bool,
+    /// 클라이언트별 송신 대역폭 예산
+    pub send_budget: SendBudgetConfig,
+    /// 네트워크 상태 시뮬레이션 (테스트 전용, 기본 비활성화)
+    #[serde(default)]
+    pub network_conditions: NetworkConditions,
 }
 
 // RUDP 설정 상수
@@ -79,6 +89,171 @@ impl Default for RudpConfig {
             send_buffer_size: DEFAULT_BUFFER_SIZE,
             enable_congestion_control: true,
             enable_compression: true,
+            send_budget: SendBudgetConfig::default(),
+            network_conditions: NetworkConditions::default(),
+        }
+    }
+}
+
+/// 순서 뒤바꿈 패킷의 최소 추가 지연 (밀리초)
+const MIN_REORDER_HOLD_MS: u64 = 10;
+
+/// 시뮬레이션할 네트워크 상태 (송신/수신 양방향에 각각 적용)
+///
+/// 로컬에서 재현하기 어려운 손실·지터 환경을 테스트하기 위한 값이며, 운영 서버에서는 쓰지 않습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkConditions {
+    /// 손실 확률 (0.0 ~ 1.0)
+    pub loss_rate: f64,
+    /// 중복 전달 확률 (0.0 ~ 1.0)
+    pub duplicate_rate: f64,
+    /// 뒤 패킷보다 늦게 도착할 확률 (0.0 ~ 1.0)
+    pub reorder_rate: f64,
+    /// 고정 지연 (밀리초)
+    pub latency_ms: u64,
+    /// 지연 변동 폭 (밀리초, 0 ~ jitter 균등 분포로 더함)
+    pub jitter_ms: u64,
+}
+
+impl NetworkConditions {
+    pub fn is_enabled(&self) -> bool {
+        self.loss_rate > 0.0
+            || self.duplicate_rate > 0.0
+            || self.reorder_rate > 0.0
+            || self.latency_ms > 0
+            || self.jitter_ms > 0
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for (name, rate) in [
+            ("loss_rate", self.loss_rate),
+            ("duplicate_rate", self.duplicate_rate),
+            ("reorder_rate", self.reorder_rate),
+        ] {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(anyhow!("network simulation {} must be within 0.0..=1.0: {}", name, rate));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 네트워크 시뮬레이션 통계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct NetworkShimStats {
+    pub dropped: u64,
+    pub duplicated: u64,
+    pub reordered: u64,
+    pub delayed: u64,
+}
+
+/// 네트워크 상태 시뮬레이터
+///
+/// 패킷마다 [`NetworkShim::plan`]으로 복사본별 전달 지연을 정하고, 지연된 수신 패킷은
+/// 내부 채널로 늦게 전달합니다. 부하/통합 테스트는 서버를 `RUDP_SIM_LOSS`,
+/// `RUDP_SIM_DUPLICATE`, `RUDP_SIM_REORDER`, `RUDP_SIM_LATENCY_MS`, `RUDP_SIM_JITTER_MS`로
+/// 띄워서 사용합니다.
+pub struct NetworkShim {
+    conditions: NetworkConditions,
+    rng: parking_lot::Mutex<StdRng>,
+    inbound_tx: mpsc::UnboundedSender<(SocketAddr, Vec<u8>)>,
+    inbound_rx: Mutex<mpsc::UnboundedReceiver<(SocketAddr, Vec<u8>)>>,
+    dropped: AtomicU64,
+    duplicated: AtomicU64,
+    reordered: AtomicU64,
+    delayed: AtomicU64,
+}
+
+impl std::fmt::Debug for NetworkShim {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetworkShim").field("conditions", &self.conditions).finish()
+    }
+}
+
+impl NetworkShim {
+    pub fn new(conditions: NetworkConditions) -> Self {
+        Self::with_rng(conditions, StdRng::from_entropy())
+    }
+
+    /// 재현 가능한 시뮬레이션 (테스트용)
+    pub fn with_seed(conditions: NetworkConditions, seed: u64) -> Self {
+        Self::with_rng(conditions, StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(conditions: NetworkConditions, rng: StdRng) -> Self {
+        let (inbound_tx, inbound_rx) = mpsc::unbounded_channel();
+        Self {
+            conditions,
+            rng: parking_lot::Mutex::new(rng),
+            inbound_tx,
+            inbound_rx: Mutex::new(inbound_rx),
+            dropped: AtomicU64::new(0),
+            duplicated: AtomicU64::new(0),
+            reordered: AtomicU64::new(0),
+            delayed: AtomicU64::new(0),
+        }
+    }
+
+    /// 패킷 하나의 전달 계획 (복사본마다 지연, 비어 있으면 손실)
+    pub fn plan(&self) -> Vec<Duration> {
+        let conditions = &self.conditions;
+        let mut rng = self.rng.lock();
+        if rng.gen_bool(conditions.loss_rate.clamp(0.0, 1.0)) {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return Vec::new();
+        }
+
+        let copies = if rng.gen_bool(conditions.duplicate_rate.clamp(0.0, 1.0)) {
+            self.duplicated.fetch_add(1, Ordering::Relaxed);
+            2
+        } else {
+            1
+        };
+        let mut delays: Vec<Duration> = (0..copies)
+            .map(|_| Duration::from_millis(conditions.latency_ms + rng.gen_range(0..=conditions.jitter_ms)))
+            .collect();
+
+        // 지연 범위 밖으로 밀어 뒤 패킷보다 확실히 늦게 도착시킴
+        if rng.gen_bool(conditions.reorder_rate.clamp(0.0, 1.0)) {
+            self.reordered.fetch_add(1, Ordering::Relaxed);
+            let hold = conditions.latency_ms.max(conditions.jitter_ms).max(MIN_REORDER_HOLD_MS);
+            delays[0] += Duration::from_millis(hold + conditions.jitter_ms);
+        }
+        if delays.iter().any(|delay| !delay.is_zero()) {
+            self.delayed.fetch_add(1, Ordering::Relaxed);
+        }
+        delays
+    }
+
+    /// 수신 패킷 적용 (지연 없이 전달할 복사본이 있으면 반환, 나머지는 나중에 `next_delayed`로 전달)
+    fn schedule_inbound(&self, addr: SocketAddr, data: Vec<u8>) -> Option<(SocketAddr, Vec<u8>)> {
+        let mut immediate = None;
+        for delay in self.plan() {
+            if delay.is_zero() && immediate.is_none() {
+                immediate = Some((addr, data.clone()));
+                continue;
+            }
+            let tx = self.inbound_tx.clone();
+            let data = data.clone();
+            tokio::spawn(async move {
+                sleep(delay).await;
+                let _ = tx.send((addr, data));
+            });
+        }
+        immediate
+    }
+
+    /// 지연된 수신 패킷 대기
+    async fn next_delayed(&self) -> Option<(SocketAddr, Vec<u8>)> {
+        self.inbound_rx.lock().await.recv().await
+    }
+
+    pub fn stats(&self) -> NetworkShimStats {
+        NetworkShimStats {
+            dropped: self.dropped.load(Ordering::Relaxed),
+            duplicated: self.duplicated.load(Ordering::Relaxed),
+            reordered: self.reordered.load(Ordering::Relaxed),
+            delayed: self.delayed.load(Ordering::Relaxed),
         }
     }
 }
@@ -345,6 +520,10 @@ pub struct RudpServer {
     redis_optimizer: Arc<RedisOptimizer>,
     /// 서버 통계
     stats: Arc<Mutex<ServerStats>>,
+    /// 클라이언트별 송신 예산
+    bandwidth: Arc<BandwidthScheduler>,
+    /// 네트워크 상태 시뮬레이터 (설정했을 때만)
+    network_shim: Option<Arc<NetworkShim>>,
     /// 실행 중 플래그
     is_running: Arc<std::sync::atomic::AtomicBool>,
 }
@@ -363,6 +542,8 @@ pub struct ServerStats {
     pub server_uptime: Duration,
     pub avg_rtt: Duration,
     pub max_rtt: Duration,
+    /// 활성 연결 RTT의 99번째 백분위
+    pub p99_rtt: Duration,
 }
 
 impl RudpServer {
@@ -381,6 +562,14 @@ impl RudpServer {
         let connections = Arc::new(dashmap::DashMap::new());
 
         let packet_pool = Arc::new(Mutex::new(VecDeque::with_capacity(1000)));
+        let bandwidth = Arc::new(BandwidthScheduler::new(config.send_budget));
+        let network_shim = if config.network_conditions.is_enabled() {
+            config.network_conditions.validate()?;
+            warn!(conditions = ?config.network_conditions, "네트워크 상태 시뮬레이션 활성화 - 테스트 전용");
+            Some(Arc::new(NetworkShim::new(config.network_conditions)))
+        } else {
+            None
+        };
 
         info!(
             bind_addr = %bind_addr,
@@ -398,6 +587,8 @@ impl RudpServer {
             security,
             redis_optimizer,
             stats: Arc::new(Mutex::new(ServerStats::default())),
+            bandwidth,
+            network_shim,
             is_running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
         })
     }
@@ -451,17 +642,23 @@ impl RudpServer {
                 Ok((size, addr)) => {
                     let packet_data = buffer[..size].to_vec();
 
-                    // 패킷 처리를 별도 태스크로 실행 (논블로킹)
-                    let server = self.clone();
-                    tokio::spawn(async move {
-                        if let Err(e) = server.handle_received_packet(packet_data, addr).await {
-                            debug!(
-                                addr = %addr,
-                                error = %e,
-                                "Failed to handle packet"
-                            );
-                        }
-                    });
+                    // 패킷 처리를 별도 태스크로 실행 (논블로킹, 시뮬레이션 중이면 복사본마다 지연)
+                    for delay in self.delivery_plan() {
+                        let server = self.clone();
+                        let packet_data = packet_data.clone();
+                        tokio::spawn(async move {
+                            if !delay.is_zero() {
+                                sleep(delay).await;
+                            }
+                            if let Err(e) = server.handle_received_packet(packet_data, addr).await {
+                                debug!(
+                                    addr = %addr,
+                                    error = %e,
+                                    "Failed to handle packet"
+                                );
+                            }
+                        });
+                    }
                 }
                 Err(e) => {
                     error!("Socket receive error: {}", e);
@@ -698,7 +895,7 @@ impl RudpServer {
 
         let data = packet.to_bytes()?;
 
-        match self.socket.send_to(&data, addr).await {
+        match self.transmit(data, addr).await {
             Ok(sent_bytes) => {
                 // 통계 업데이트
                 {
@@ -727,6 +924,35 @@ impl RudpServer {
         }
     }
 
+    /// 소켓 송신 (시뮬레이션 중이면 손실/중복/지연 적용 후 보낸 것으로 처리)
+    async fn transmit(&self, data: Vec<u8>, addr: SocketAddr) -> std::io::Result<usize> {
+        let Some(shim) = &self.network_shim else {
+            return self.socket.send_to(&data, addr).await;
+        };
+        for delay in shim.plan() {
+            let socket = self.socket.clone();
+            let data = data.clone();
+            tokio::spawn(async move {
+                sleep(delay).await;
+                let _ = socket.send_to(&data, addr).await;
+            });
+        }
+        Ok(data.len())
+    }
+
+    /// 패킷 복사본별 처리 지연 (시뮬레이션이 없으면 즉시 한 번)
+    fn delivery_plan(&self) -> Vec<Duration> {
+        match &self.network_shim {
+            Some(shim) => shim.plan(),
+            None => vec![Duration::ZERO],
+        }
+    }
+
+    /// 네트워크 시뮬레이션 통계 (비활성화면 None)
+    pub fn network_shim_stats(&self) -> Option<NetworkShimStats> {
+        self.network_shim.as_ref().map(|shim| shim.stats())
+    }
+
     /// ACK 전송
     async fn send_ack(&self, session_id: u64, ack_num: u32, addr: SocketAddr) -> Result<()> {
         let mut ack = RudpPacket::new(PacketType::Ack, session_id, vec![]);
@@ -917,6 +1143,8 @@ impl RudpServer {
             addr_map.remove(&addr);
         }
 
+        self.bandwidth.remove(&addr);
+
         // 통계 업데이트
         {
             let mut stats = self.stats.lock().await;
@@ -938,20 +1166,17 @@ impl RudpServer {
 
         // 평균 RTT 계산
         let session_map = self.session_map.read().await;
-        let mut total_rtt = Duration::ZERO;
-        let mut max_rtt = Duration::ZERO;
-        let mut count = 0;
+        let mut rtts = Vec::with_capacity(session_map.len());
 
         for connection in session_map.values() {
-            let conn = connection.lock().await;
-            total_rtt += conn.rtt;
-            max_rtt = max_rtt.max(conn.rtt);
-            count += 1;
+            rtts.push(connection.lock().await.rtt);
         }
 
-        if count > 0 {
-            stats.avg_rtt = total_rtt / count as u32;
-            stats.max_rtt = max_rtt;
+        if !rtts.is_empty() {
+            rtts.sort_unstable();
+            stats.avg_rtt = rtts.iter().sum::<Duration>() / rtts.len() as u32;
+            stats.max_rtt = rtts[rtts.len() - 1];
+            stats.p99_rtt = rtts[(rtts.len() * 99).div_ceil(100) - 1];
         }
 
         stats.clone()
@@ -959,6 +1184,23 @@ impl RudpServer {
 
     /// 메시지 수신 (main.rs에서 사용)
     pub async fn receive_message(&self) -> Result<(SocketAddr, Vec<u8>)> {
+        let Some(shim) = &self.network_shim else {
+            return self.receive_datagram().await;
+        };
+        loop {
+            tokio::select! {
+                Some(delayed) = shim.next_delayed() => return Ok(delayed),
+                received = self.receive_datagram() => {
+                    let (addr, data) = received?;
+                    if let Some(immediate) = shim.schedule_inbound(addr, data) {
+                        return Ok(immediate);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn receive_datagram(&self) -> Result<(SocketAddr, Vec<u8>)> {
         let mut buffer = vec![0u8; self.config.max_packet_size];
 
         match self.socket.recv_from(&mut buffer).await {
@@ -970,15 +1212,21 @@ impl RudpServer {
         }
     }
 
+    /// 바인딩된 로컬 주소
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
     /// 메시지 전송 (main.rs에서 사용)
     pub async fn send_message(&self, addr: SocketAddr, data: Vec<u8>) -> Result<()> {
-        match self.socket.send_to(&data, addr).await {
+        let expected = data.len();
+        match self.transmit(data, addr).await {
             Ok(sent) => {
-                if sent != data.len() {
+                if sent != expected {
                     warn!(
                         addr = %addr,
                         sent = sent,
-                        expected = data.len(),
+                        expected = expected,
                         "Partial send detected"
                     );
                 }
@@ -1003,6 +1251,28 @@ impl RudpServer {
         }
     }
 
+    /// 클라이언트 송신 예산을 적용한 메시지 전송
+    ///
+    /// 예산이 부족해 버린 경우 `Ok(false)`를 반환합니다.
+    pub async fn send_budgeted(
+        &self,
+        addr: SocketAddr,
+        category: SendCategory,
+        data: Vec<u8>,
+    ) -> Result<bool> {
+        if !self.bandwidth.admit(addr, category, data.len()) {
+            trace!(addr = %addr, category = %category, size = data.len(), "송신 예산 초과 - 메시지 버림");
+            return Ok(false);
+        }
+        self.send_message(addr, data).await?;
+        Ok(true)
+    }
+
+    /// 클라이언트 송신 예산 사용률 보고서 (호출 시 보고 구간 초기화)
+    pub fn bandwidth_report(&self) -> BandwidthReport {
+        self.bandwidth.report()
+    }
+
     /// 서버 종료
     pub async fn shutdown(&self) -> Result<()> {
         info!("Shutting down RUDP Server...");
@@ -1021,6 +1291,9 @@ impl RudpServer {
         // 잠시 대기 (클라이언트가 응답할 시간 제공)
         sleep(Duration::from_millis(500)).await;
 
+        if let Some(stats) = self.network_shim_stats() {
+            info!(?stats, "Network simulation summary");
+        }
         info!("RUDP Server shutdown complete");
         Ok(())
     }
@@ -1039,7 +1312,47 @@ impl Clone for RudpServer {
             security: self.security.clone(),
             redis_optimizer: self.redis_optimizer.clone(),
             stats: self.stats.clone(),
+            bandwidth: self.bandwidth.clone(),
+            network_shim: self.network_shim.clone(),
             is_running: self.is_running.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_shim_plan_rates() {
+        let conditions = NetworkConditions {
+            loss_rate: 0.2,
+            duplicate_rate: 0.1,
+            reorder_rate: 0.1,
+            latency_ms: 30,
+            jitter_ms: 20,
+        };
+        let shim = NetworkShim::with_seed(conditions, 42);
+        let mut delivered = 0;
+        for _ in 0..10_000 {
+            let plan = shim.plan();
+            delivered += plan.len();
+            for delay in plan {
+                assert!(delay >= Duration::from_millis(30));
+                assert!(delay <= Duration::from_millis(30 + 20 + 30 + 20));
+            }
+        }
+
+        let stats = shim.stats();
+        assert!((1_700..2_300).contains(&stats.dropped), "{:?}", stats);
+        assert!((600..1_000).contains(&stats.duplicated), "{:?}", stats);
+        assert!((600..1_000).contains(&stats.reordered), "{:?}", stats);
+        assert_eq!(delivered as u64, 10_000 - stats.dropped + stats.duplicated);
+
+        // 조건이 없으면 즉시 한 번 전달
+        let passthrough = NetworkShim::with_seed(NetworkConditions::default(), 1);
+        assert!(!NetworkConditions::default().is_enabled());
+        assert_eq!(passthrough.plan(), vec![Duration::ZERO]);
+        assert!(NetworkConditions { loss_rate: 1.5, ..Default::default() }.validate().is_err());
+    }
+}
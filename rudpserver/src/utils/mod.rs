@@ -15,6 +15,11 @@ use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use shared::tool::high_performance::simd_optimizer::crc16_ccitt_update;
+
+/// 지수 가중 이동 평균 (shared로 이동, 기존 경로 유지)
+pub use shared::tool::ExponentialMovingAverage;
+
 /// RUDP 패킷 유형 식별자
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -156,35 +161,12 @@ pub mod flags {
     pub const ENCRYPTED: u8 = 0x20; // 암호화된 데이터
 }
 
-/// CRC16 체크섬 계산
+/// CRC16 체크섬 계산 (CRC16-CCITT, 초기값 0xFFFF, 최종 반전)
+///
+/// 공유 `simd_optimizer`의 slicing-by-8 구현을 사용하며, 비트 단위 구현과 같은 값을 냅니다.
 pub fn crc16_checksum(header: &[u8], payload: &[u8]) -> u16 {
-    let mut crc: u16 = 0xFFFF;
-
-    // 헤더 체크섬 계산
-    for &byte in header {
-        crc ^= (byte as u16) << 8;
-        for _ in 0..8 {
-            if crc & 0x8000 != 0 {
-                crc = (crc << 1) ^ 0x1021;
-            } else {
-                crc <<= 1;
-            }
-        }
-    }
-
-    // 페이로드 체크섬 계산
-    for &byte in payload {
-        crc ^= (byte as u16) << 8;
-        for _ in 0..8 {
-            if crc & 0x8000 != 0 {
-                crc = (crc << 1) ^ 0x1021;
-            } else {
-                crc <<= 1;
-            }
-        }
-    }
-
-    !crc
+    let crc = crc16_ccitt_update(0xFFFF, header);
+    !crc16_ccitt_update(crc, payload)
 }
 
 /// 네트워크 주소 유틸리티
@@ -454,50 +436,6 @@ impl SlidingWindowAverage {
     }
 }
 
-/// 지수 가중 이동 평균 (EWMA)
-pub struct ExponentialMovingAverage {
-    alpha: f64,
-    value: f64,
-    initialized: bool,
-}
-
-impl ExponentialMovingAverage {
-    /// 새로운 EWMA 생성
-    pub fn new(alpha: f64) -> Self {
-        Self {
-            alpha: alpha.clamp(0.0, 1.0),
-            value: 0.0,
-            initialized: false,
-        }
-    }
-
-    /// 새로운 값으로 업데이트
-    pub fn update(&mut self, new_value: f64) {
-        if !self.initialized {
-            self.value = new_value;
-            self.initialized = true;
-        } else {
-            self.value = self.alpha * new_value + (1.0 - self.alpha) * self.value;
-        }
-    }
-
-    /// 현재 평균 값
-    pub fn value(&self) -> f64 {
-        self.value
-    }
-
-    /// 초기화 여부
-    pub fn is_initialized(&self) -> bool {
-        self.initialized
-    }
-
-    /// 리셋
-    pub fn reset(&mut self) {
-        self.value = 0.0;
-        self.initialized = false;
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,88 @@
+//! 체크섬/해시 구현 비교 (기존 스칼라 vs 공유 `simd_optimizer`)
+//!
+//! - CRC16: 비트 단위 루프(기존 RUDP 구현) vs slicing-by-8 테이블
+//! - CRC32C: 테이블 vs 실행 시 선택(SSE4.2 `crc32` 명령어)
+//! - 해시: `DefaultHasher`(SipHash, 기존 캐시 키) vs xxHash64
+//!
+//! 실행: `cargo bench -p rudpserver --bench checksum_benchmarks`
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rudpserver::utils::crc16_checksum;
+use shared::tool::high_performance::simd_optimizer::{checksum_backend, crc32c_update_with, xxhash64, ChecksumBackend};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// RUDP 패킷 크기 분포 (헤더만 / 이동 / 상태 갱신 / MTU 근처)
+const SIZES: [usize; 4] = [16, 128, 512, 1400];
+
+/// 기존 RUDP CRC16 (비트 단위)
+fn crc16_bitwise(header: &[u8], payload: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in header.iter().chain(payload) {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    !crc
+}
+
+fn payload(size: usize) -> Vec<u8> {
+    (0..size).map(|i| (i * 131 % 251) as u8).collect()
+}
+
+fn benchmark_crc16(c: &mut Criterion) {
+    let mut group = c.benchmark_group("crc16");
+    let header = [0x52u8; 16];
+    for size in SIZES {
+        let data = payload(size);
+        assert_eq!(crc16_bitwise(&header, &data), crc16_checksum(&header, &data));
+
+        group.throughput(Throughput::Bytes((header.len() + size) as u64));
+        group.bench_with_input(BenchmarkId::new("bitwise", size), &data, |b, data| {
+            b.iter(|| black_box(crc16_bitwise(&header, black_box(data))));
+        });
+        group.bench_with_input(BenchmarkId::new("slicing_by_8", size), &data, |b, data| {
+            b.iter(|| black_box(crc16_checksum(&header, black_box(data))));
+        });
+    }
+    group.finish();
+}
+
+fn benchmark_crc32c(c: &mut Criterion) {
+    let mut group = c.benchmark_group("crc32c");
+    let dispatched = checksum_backend();
+    for size in SIZES {
+        let data = payload(size);
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::new("portable", size), &data, |b, data| {
+            b.iter(|| black_box(crc32c_update_with(ChecksumBackend::Portable, !0, black_box(data))));
+        });
+        group.bench_with_input(BenchmarkId::new(format!("{dispatched:?}").to_lowercase(), size), &data, |b, data| {
+            b.iter(|| black_box(crc32c_update_with(dispatched, !0, black_box(data))));
+        });
+    }
+    group.finish();
+}
+
+fn benchmark_hash(c: &mut Criterion) {
+    let mut group = c.benchmark_group("snapshot_hash");
+    for size in [64, 1024, 16 * 1024] {
+        let data = payload(size);
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::new("siphash", size), &data, |b, data| {
+            b.iter(|| {
+                let mut hasher = DefaultHasher::new();
+                black_box(data).hash(&mut hasher);
+                black_box(hasher.finish())
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("xxhash64", size), &data, |b, data| {
+            b.iter(|| black_box(xxhash64(black_box(data), 0)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_crc16, benchmark_crc32c, benchmark_hash);
+criterion_main!(benches);
@@ -0,0 +1,112 @@
+//! 공간 인덱스 전략 비교 (격자 vs k-d 트리)
+//!
+//! 2000명 기준으로 틱마다 하는 재구성과, 플레이어마다 하는 관심 영역 조회를 측정합니다.
+//! 고른 분포와 한곳에 몰린 분포(거점 교전)를 모두 비교합니다.
+//!
+//! 실행: `cargo bench -p rudpserver --bench spatial_index_benchmarks`
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rudpserver::game::messages::{PlayerId, Position};
+use rudpserver::game::spatial::SpatialStrategy;
+
+const PLAYERS: u32 = 2000;
+const MAP_HALF_SIZE: f32 = 1000.0;
+const AOI_RADIUS: f32 = 100.0;
+const CELL_SIZE: f32 = 50.0;
+
+const STRATEGIES: [SpatialStrategy; 2] = [SpatialStrategy::Grid, SpatialStrategy::KdTree];
+
+fn uniform(rng: &mut StdRng) -> Vec<(PlayerId, Position)> {
+    (0..PLAYERS)
+        .map(|id| {
+            let x = rng.gen_range(-MAP_HALF_SIZE..MAP_HALF_SIZE);
+            let z = rng.gen_range(-MAP_HALF_SIZE..MAP_HALF_SIZE);
+            (id, Position::new(x, 0.0, z))
+        })
+        .collect()
+}
+
+/// 절반은 거점 세 곳 주변에 몰려 있음
+fn clustered(rng: &mut StdRng) -> Vec<(PlayerId, Position)> {
+    let hotspots = [(-400.0, 300.0), (0.0, 0.0), (500.0, -200.0)];
+    (0..PLAYERS)
+        .map(|id| {
+            if id % 2 == 0 {
+                let (hx, hz) = hotspots[id as usize % hotspots.len()];
+                (
+                    id,
+                    Position::new(
+                        hx + rng.gen_range(-30.0..30.0),
+                        0.0,
+                        hz + rng.gen_range(-30.0..30.0),
+                    ),
+                )
+            } else {
+                let x = rng.gen_range(-MAP_HALF_SIZE..MAP_HALF_SIZE);
+                let z = rng.gen_range(-MAP_HALF_SIZE..MAP_HALF_SIZE);
+                (id, Position::new(x, 0.0, z))
+            }
+        })
+        .collect()
+}
+
+fn layouts() -> Vec<(&'static str, Vec<(PlayerId, Position)>)> {
+    let mut rng = StdRng::seed_from_u64(2000);
+    vec![
+        ("uniform", uniform(&mut rng)),
+        ("clustered", clustered(&mut rng)),
+    ]
+}
+
+fn benchmark_rebuild(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spatial_rebuild");
+    group.throughput(Throughput::Elements(PLAYERS as u64));
+
+    for (layout, entries) in layouts() {
+        for strategy in STRATEGIES {
+            let mut index = strategy.build(CELL_SIZE);
+            group.bench_with_input(
+                BenchmarkId::new(index.name(), layout),
+                &entries,
+                |b, entries| {
+                    b.iter(|| index.rebuild(black_box(entries)));
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+fn benchmark_query_all(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spatial_query_all_players");
+    group.throughput(Throughput::Elements(PLAYERS as u64));
+
+    for (layout, entries) in layouts() {
+        for strategy in STRATEGIES {
+            let mut index = strategy.build(CELL_SIZE);
+            index.rebuild(&entries);
+            let mut found = Vec::with_capacity(PLAYERS as usize);
+            group.bench_with_input(
+                BenchmarkId::new(index.name(), layout),
+                &entries,
+                |b, entries| {
+                    b.iter(|| {
+                        for (_, position) in entries {
+                            found.clear();
+                            index.query_radius(position, AOI_RADIUS, &mut found);
+                            black_box(found.len());
+                        }
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_rebuild, benchmark_query_all);
+criterion_main!(benches);
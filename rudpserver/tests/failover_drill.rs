@@ -0,0 +1,190 @@
+//! 장애 조치 훈련 (웜 스탠바이)
+//!
+//! 주 서버가 매치를 진행하며 복제 묶음을 보내다가 중간에 묶음을 잃고 결국 멈춘 상황을
+//! 같은 프로세스 안에서 재현합니다. 대기 서버는 키프레임으로 다시 따라잡고, 주 서버가
+//! 멈춘 뒤에는 마지막 복제 상태로 매치를 이어 가야 합니다.
+
+use anyhow::{anyhow, Result};
+use rudpserver::game::match_manager::{LobbyEvent, MatchManager, RoomRules};
+use rudpserver::game::messages::{MatchPhase, Position, Team, Velocity};
+use rudpserver::game::player::PlayerState;
+use rudpserver::game::replication::{
+    LocalStandby, ReplicatedPlayer, ReplicationBatch, ReplicationSink, Replicator, RoomState,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const ROOM_ID: u32 = 7;
+
+/// 전달을 끊을 수 있는 훈련용 전송 (네트워크 단절, 주 서버 중단 재현)
+struct DrillLink {
+    standby: Arc<LocalStandby>,
+    connected: AtomicBool,
+}
+
+#[async_trait::async_trait]
+impl ReplicationSink for DrillLink {
+    async fn send(&self, batch: &ReplicationBatch) -> Result<()> {
+        if !self.connected.load(Ordering::SeqCst) {
+            return Err(anyhow!("link down"));
+        }
+        self.standby.send(batch).await
+    }
+}
+
+fn matches() -> MatchManager {
+    MatchManager::new(
+        RoomRules::default(),
+        Duration::from_secs(5),
+        Duration::from_secs(60),
+    )
+}
+
+fn player(player_id: u32, team: Team, x: f32, health: u32) -> ReplicatedPlayer {
+    ReplicatedPlayer {
+        player_id,
+        name: format!("drill{player_id}"),
+        team: Some(team),
+        position: Position::new(x, 0.0, 10.0),
+        velocity: Velocity::default(),
+        rotation: (0.0, 0.0, 0.0),
+        state: PlayerState::Moving,
+        health,
+        mana: 500,
+    }
+}
+
+/// 주 서버 한 주기 복제 (`GameStateManager::replication_snapshot`과 같은 형태)
+async fn replicate(
+    replicator: &mut Replicator,
+    link: &DrillLink,
+    manager: &MatchManager,
+    tick: u64,
+    players: Vec<ReplicatedPlayer>,
+) -> bool {
+    let mut room = RoomState::new(ROOM_ID);
+    room.players = players;
+    room.active_match = manager
+        .checkpoints()
+        .into_iter()
+        .map(|(_, checkpoint)| checkpoint)
+        .next();
+    let batch = replicator.next_batch(tick, vec![room]);
+    match link.send(&batch).await {
+        Ok(()) => true,
+        Err(_) => {
+            replicator.request_keyframe();
+            false
+        }
+    }
+}
+
+#[tokio::test]
+async fn failover_drill_standby_resumes_match() {
+    let now = Instant::now();
+    let standby = Arc::new(LocalStandby::new());
+    let link = DrillLink {
+        standby: standby.clone(),
+        connected: AtomicBool::new(true),
+    };
+    let mut replicator = Replicator::new("primary:7000", 20);
+
+    // 주 서버: 2명 매치 시작
+    let primary = matches();
+    primary.set_ready(ROOM_ID, 1, 101, true, now).unwrap();
+    primary.set_ready(ROOM_ID, 2, 102, true, now).unwrap();
+    primary.tick(now + Duration::from_secs(5));
+
+    assert!(
+        replicate(
+            &mut replicator,
+            &link,
+            &primary,
+            30,
+            vec![
+                player(1, Team::Police, 0.0, 1000),
+                player(2, Team::Thief, 0.0, 1000)
+            ]
+        )
+        .await
+    );
+    assert!(standby.replica().is_synced());
+
+    // 네트워크 단절 중 처치 발생 → 재연결 후 변경분은 순번이 비어 버려짐
+    link.connected.store(false, Ordering::SeqCst);
+    primary.record_kill(Some(1), 2);
+    assert!(
+        !replicate(
+            &mut replicator,
+            &link,
+            &primary,
+            60,
+            vec![
+                player(1, Team::Police, 4.0, 1000),
+                player(2, Team::Thief, 0.0, 0)
+            ]
+        )
+        .await
+    );
+    link.connected.store(true, Ordering::SeqCst);
+
+    // 전송 실패 뒤 첫 묶음은 키프레임이므로 바로 따라잡음
+    assert!(
+        replicate(
+            &mut replicator,
+            &link,
+            &primary,
+            90,
+            vec![
+                player(1, Team::Police, 8.0, 1000),
+                player(2, Team::Thief, 0.0, 1000)
+            ]
+        )
+        .await
+    );
+    {
+        let replica = standby.replica();
+        assert!(replica.is_synced());
+        assert_eq!(replica.last_tick(), 90);
+    }
+
+    // 주 서버 중단 (이후 변경은 복제되지 않음)
+    primary.record_kill(Some(2), 1);
+    drop(primary);
+
+    // 대기 서버 전환: 매치 복원 후 참가자 재접속
+    let takeover = Instant::now();
+    let rooms = standby.replica().rooms();
+    assert_eq!(rooms.len(), 1);
+    assert_eq!(rooms[0].players[0].position, Position::new(8.0, 0.0, 10.0));
+    let checkpoint = rooms[0].active_match.clone().expect("match replicated");
+
+    let promoted = matches();
+    assert!(promoted.restore(ROOM_ID, checkpoint.clone(), takeover));
+    let update = promoted.set_ready(ROOM_ID, 1, 201, true, takeover).unwrap();
+    assert!(matches!(
+        update.events.last(),
+        Some(LobbyEvent::StateChanged {
+            phase: MatchPhase::InProgress,
+            ..
+        })
+    ));
+    promoted.set_ready(ROOM_ID, 2, 202, true, takeover).unwrap();
+    assert!(
+        takeover.elapsed() < Duration::from_secs(1),
+        "takeover must be near-instant"
+    );
+
+    // 마지막 복제 시점의 성적으로 매치 종료
+    let ended = promoted
+        .tick(takeover + Duration::from_secs(60))
+        .pop()
+        .unwrap();
+    let LobbyEvent::MatchEnded(result) = &ended.events[0] else {
+        panic!("restored match should end at its time limit");
+    };
+    assert_eq!(result.match_id, checkpoint.match_id);
+    assert_eq!(result.winner, Some(Team::Police));
+    assert_eq!((result.players[0].kills, result.players[1].deaths), (1, 1));
+}
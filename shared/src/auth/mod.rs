@@ -0,0 +1,10 @@
+//! 서비스 간 인증
+//!
+//! - `service_token`: 내부 서비스용 단기 머신 토큰 발급/검증과 tonic 인터셉터
+
+pub mod service_token;
+
+pub use service_token::{
+    attach_service_token, service_auth_interceptor, ServiceClaims, ServiceIdentity, ServiceKey,
+    ServiceTokenIssuer, ServiceTokenVerifier, SERVICE_TOKEN_HEADER,
+};
@@ -0,0 +1,352 @@
+//! 서비스 간 머신 토큰
+//!
+//! tcpserver/rudpserver 같은 내부 서비스가 자신을 식별할 수 있도록 짧은 수명의
+//! 대상(audience) 한정 JWT를 발급합니다. 토큰은 `x-service-token` 메타데이터로 전달되며,
+//! 사용자 JWT(`authorization`)와 발급자(`police-thief-internal`)가 달라 서로 섞이지 않습니다.
+//!
+//! 서명 키는 둘 중 하나입니다:
+//! - 공유 키(HS256): `SERVICE_TOKEN_SECRET` (없으면 `JWT_SECRET_KEY`)
+//! - 개인키/공개키: `SERVICE_TOKEN_PRIVATE_KEY`, `SERVICE_TOKEN_PUBLIC_KEY` (PEM 파일 경로),
+//!   `SERVICE_TOKEN_ALGORITHM` (기본값: "RS256", ES256/EdDSA 지원)
+//!
+//! 토큰 수명은 `SERVICE_TOKEN_TTL_SECS` (기본값: 300)로 설정합니다.
+
+use crate::security::SecurityError;
+use chrono::Utc;
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tonic::metadata::MetadataValue;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// 서비스 토큰 메타데이터 키
+pub const SERVICE_TOKEN_HEADER: &str = "x-service-token";
+
+/// 서비스 토큰 발급자
+const ISSUER: &str = "police-thief-internal";
+
+/// 기본 토큰 수명
+const DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+static GLOBAL_ISSUER: OnceLock<Arc<ServiceTokenIssuer>> = OnceLock::new();
+
+/// 서비스 토큰 클레임
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceClaims {
+    /// 호출하는 서비스 이름
+    pub sub: String,
+    /// 대상 서비스 이름
+    pub aud: String,
+    pub iss: String,
+    pub iat: i64,
+    pub exp: i64,
+    pub jti: String,
+}
+
+/// 서명 키
+#[derive(Clone)]
+pub enum ServiceKey {
+    /// 공유 비밀키 (HS256)
+    Shared(String),
+    /// 비대칭 키 (발급 측은 개인키, 검증 측은 공개키만 있으면 됨)
+    KeyPair {
+        algorithm: Algorithm,
+        private_pem: Option<Vec<u8>>,
+        public_pem: Option<Vec<u8>>,
+    },
+}
+
+impl ServiceKey {
+    /// 환경 변수에서 키 로드 (개인키/공개키 경로가 있으면 우선)
+    pub fn from_env() -> Result<Self, SecurityError> {
+        let read_pem = |key: &str| -> Result<Option<Vec<u8>>, SecurityError> {
+            match std::env::var(key) {
+                Ok(path) => std::fs::read(&path)
+                    .map(Some)
+                    .map_err(|e| SecurityError::InvalidInput(format!("{key} ({path}): {e}"))),
+                Err(_) => Ok(None),
+            }
+        };
+
+        let private_pem = read_pem("SERVICE_TOKEN_PRIVATE_KEY")?;
+        let public_pem = read_pem("SERVICE_TOKEN_PUBLIC_KEY")?;
+        if private_pem.is_some() || public_pem.is_some() {
+            let algorithm = std::env::var("SERVICE_TOKEN_ALGORITHM").unwrap_or_else(|_| "RS256".to_string());
+            let algorithm = Algorithm::from_str(&algorithm)
+                .map_err(|_| SecurityError::InvalidInput(format!("Unsupported SERVICE_TOKEN_ALGORITHM: {algorithm}")))?;
+            return Ok(Self::KeyPair { algorithm, private_pem, public_pem });
+        }
+
+        std::env::var("SERVICE_TOKEN_SECRET")
+            .or_else(|_| std::env::var("JWT_SECRET_KEY"))
+            .map(Self::Shared)
+            .map_err(|_| SecurityError::InvalidInput(
+                "SERVICE_TOKEN_SECRET, JWT_SECRET_KEY or SERVICE_TOKEN_PRIVATE_KEY/PUBLIC_KEY is required".to_string(),
+            ))
+    }
+
+    fn algorithm(&self) -> Algorithm {
+        match self {
+            Self::Shared(_) => Algorithm::HS256,
+            Self::KeyPair { algorithm, .. } => *algorithm,
+        }
+    }
+
+    fn encoding_key(&self) -> Result<EncodingKey, SecurityError> {
+        let invalid = |e: jsonwebtoken::errors::Error| SecurityError::InvalidInput(format!("Invalid signing key: {e}"));
+        match self {
+            Self::Shared(secret) => Ok(EncodingKey::from_secret(secret.as_bytes())),
+            Self::KeyPair { private_pem: None, .. } => {
+                Err(SecurityError::InvalidInput("SERVICE_TOKEN_PRIVATE_KEY is required to issue tokens".to_string()))
+            }
+            Self::KeyPair { algorithm, private_pem: Some(pem), .. } => match algorithm {
+                Algorithm::ES256 | Algorithm::ES384 => EncodingKey::from_ec_pem(pem).map_err(invalid),
+                Algorithm::EdDSA => EncodingKey::from_ed_pem(pem).map_err(invalid),
+                _ => EncodingKey::from_rsa_pem(pem).map_err(invalid),
+            },
+        }
+    }
+
+    fn decoding_key(&self) -> Result<DecodingKey, SecurityError> {
+        let invalid = |e: jsonwebtoken::errors::Error| SecurityError::InvalidInput(format!("Invalid verification key: {e}"));
+        match self {
+            Self::Shared(secret) => Ok(DecodingKey::from_secret(secret.as_bytes())),
+            Self::KeyPair { public_pem: None, .. } => {
+                Err(SecurityError::InvalidInput("SERVICE_TOKEN_PUBLIC_KEY is required to verify tokens".to_string()))
+            }
+            Self::KeyPair { algorithm, public_pem: Some(pem), .. } => match algorithm {
+                Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(pem).map_err(invalid),
+                Algorithm::EdDSA => DecodingKey::from_ed_pem(pem).map_err(invalid),
+                _ => DecodingKey::from_rsa_pem(pem).map_err(invalid),
+            },
+        }
+    }
+}
+
+/// 서비스 토큰 발급기
+///
+/// 대상별로 토큰을 캐시하고, 수명의 80%가 지나면 새로 발급합니다.
+pub struct ServiceTokenIssuer {
+    service: String,
+    algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    ttl: Duration,
+    cache: Mutex<HashMap<String, (String, i64)>>,
+}
+
+impl ServiceTokenIssuer {
+    pub fn new(service: &str, key: &ServiceKey, ttl: Duration) -> Result<Self, SecurityError> {
+        Ok(Self {
+            service: service.to_string(),
+            algorithm: key.algorithm(),
+            encoding_key: key.encoding_key()?,
+            ttl,
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub fn from_env(service: &str) -> Result<Self, SecurityError> {
+        let ttl = std::env::var("SERVICE_TOKEN_TTL_SECS").ok()
+            .and_then(|secs| secs.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_TTL);
+        Self::new(service, &ServiceKey::from_env()?, ttl)
+    }
+
+    /// 프로세스 전역 발급기 등록 (이미 있으면 무시)
+    pub fn install_global(issuer: Self) -> Arc<Self> {
+        GLOBAL_ISSUER.get_or_init(|| Arc::new(issuer)).clone()
+    }
+
+    /// 시작 시 환경 변수로 전역 발급기를 등록하고 `audience` 토큰을 미리 발급
+    ///
+    /// 키가 설정되지 않았거나 발급에 실패하면 경고만 남기고 None을 반환합니다.
+    pub fn install_from_env(service: &str, audience: &str) -> Option<Arc<Self>> {
+        let issuer = match Self::from_env(service) {
+            Ok(issuer) => Self::install_global(issuer),
+            Err(e) => {
+                warn!("서비스 토큰 발급 비활성화 ({}): {}", service, e);
+                return None;
+            }
+        };
+        match issuer.token_for(audience) {
+            Ok(_) => {
+                info!("🔑 서비스 토큰 발급 준비 완료: {} → {}", service, audience);
+                Some(issuer)
+            }
+            Err(e) => {
+                warn!("서비스 토큰 발급 실패 ({} → {}): {}", service, audience, e);
+                None
+            }
+        }
+    }
+
+    /// 전역 발급기
+    pub fn global() -> Option<Arc<Self>> {
+        GLOBAL_ISSUER.get().cloned()
+    }
+
+    pub fn service(&self) -> &str {
+        &self.service
+    }
+
+    /// `audience` 대상 토큰 (캐시된 토큰이 충분히 남아 있으면 재사용)
+    pub fn token_for(&self, audience: &str) -> Result<String, SecurityError> {
+        let now = Utc::now().timestamp();
+        let refresh_margin = (self.ttl.as_secs() / 5) as i64;
+
+        let mut cache = self.cache.lock();
+        if let Some((token, exp)) = cache.get(audience) {
+            if exp - now > refresh_margin {
+                return Ok(token.clone());
+            }
+        }
+
+        let claims = ServiceClaims {
+            sub: self.service.clone(),
+            aud: audience.to_string(),
+            iss: ISSUER.to_string(),
+            iat: now,
+            exp: now + self.ttl.as_secs() as i64,
+            jti: Uuid::new_v4().to_string(),
+        };
+        let token = encode(&Header::new(self.algorithm), &claims, &self.encoding_key)
+            .map_err(|e| SecurityError::InvalidToken(format!("Service token encoding failed: {e}")))?;
+        cache.insert(audience.to_string(), (token.clone(), claims.exp));
+        Ok(token)
+    }
+}
+
+/// 서비스 토큰 검증기 (자신을 대상으로 발급된 토큰만 허용)
+pub struct ServiceTokenVerifier {
+    decoding_key: DecodingKey,
+    validation: Validation,
+}
+
+impl ServiceTokenVerifier {
+    /// # Arguments
+    /// * `audience` - 이 서비스의 이름 (토큰의 `aud`와 일치해야 함)
+    pub fn new(audience: &str, key: &ServiceKey) -> Result<Self, SecurityError> {
+        let mut validation = Validation::new(key.algorithm());
+        validation.set_audience(&[audience]);
+        validation.set_issuer(&[ISSUER]);
+        validation.set_required_spec_claims(&["exp", "aud", "iss", "sub"]);
+        Ok(Self { decoding_key: key.decoding_key()?, validation })
+    }
+
+    pub fn from_env(audience: &str) -> Result<Self, SecurityError> {
+        Self::new(audience, &ServiceKey::from_env()?)
+    }
+
+    pub fn verify(&self, token: &str) -> Result<ServiceClaims, SecurityError> {
+        decode::<ServiceClaims>(token, &self.decoding_key, &self.validation)
+            .map(|data| data.claims)
+            .map_err(|e| match e.kind() {
+                jsonwebtoken::errors::ErrorKind::ExpiredSignature => SecurityError::TokenExpired,
+                _ => SecurityError::InvalidToken(format!("Service token validation failed: {e}")),
+            })
+    }
+}
+
+/// 검증된 호출 서비스 (요청 extensions에 저장)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceIdentity {
+    pub service: String,
+}
+
+impl ServiceIdentity {
+    /// 요청이 내부 서비스에서 왔으면 호출 서비스 정보
+    pub fn of<T>(req: &Request<T>) -> Option<&ServiceIdentity> {
+        req.extensions().get::<ServiceIdentity>()
+    }
+}
+
+/// 클라이언트 측 인터셉터: 나가는 요청에 `audience` 대상 서비스 토큰 첨부
+// tonic `Interceptor`는 `Result<Request<()>, Status>`를 반환해야 함
+#[allow(clippy::result_large_err)]
+pub fn attach_service_token(issuer: Arc<ServiceTokenIssuer>, audience: &'static str) -> impl Interceptor + Clone {
+    move |mut req: Request<()>| {
+        let token = issuer.token_for(audience)
+            .map_err(|e| Status::internal(format!("Service token unavailable: {e}")))?;
+        let value = MetadataValue::try_from(token.as_str())
+            .map_err(|_| Status::internal("Service token is not valid metadata"))?;
+        req.metadata_mut().insert(SERVICE_TOKEN_HEADER, value);
+        Ok(req)
+    }
+}
+
+/// 서버 측 인터셉터: 서비스 토큰 검증 후 [`ServiceIdentity`]를 요청에 추가
+///
+/// - 토큰이 있으면 반드시 유효해야 합니다.
+/// - `required`이면 토큰 없는 요청(클라이언트 트래픽)을 거부합니다.
+/// - 검증기가 없으면(키 미설정) 토큰 있는 요청과 `required` 서비스는 모두 거부됩니다.
+// tonic `Interceptor`는 `Result<Request<()>, Status>`를 반환해야 함
+#[allow(clippy::result_large_err)]
+pub fn service_auth_interceptor(verifier: Option<Arc<ServiceTokenVerifier>>, required: bool) -> impl Interceptor + Clone {
+    move |mut req: Request<()>| {
+        let token = match req.metadata().get(SERVICE_TOKEN_HEADER) {
+            Some(value) => value.to_str()
+                .map_err(|_| Status::unauthenticated("Invalid x-service-token header"))?
+                .to_string(),
+            None if required => return Err(Status::unauthenticated("Internal API: service token required")),
+            None => return Ok(req),
+        };
+
+        let verifier = verifier.as_ref()
+            .ok_or_else(|| Status::unauthenticated("Service tokens are not accepted by this server"))?;
+        match verifier.verify(&token) {
+            Ok(claims) => {
+                req.extensions_mut().insert(ServiceIdentity { service: claims.sub });
+                Ok(req)
+            }
+            Err(e) => {
+                warn!("서비스 토큰 검증 실패: {}", e);
+                Err(Status::unauthenticated("Invalid or expired service token"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key() -> ServiceKey {
+        ServiceKey::Shared("k3y-for-service-token-tests-0123456789".to_string())
+    }
+
+    #[test]
+    fn test_issue_and_verify() {
+        let issuer = ServiceTokenIssuer::new("tcpserver", &key(), DEFAULT_TTL).unwrap();
+        let token = issuer.token_for("grpcserver").unwrap();
+        assert_eq!(issuer.token_for("grpcserver").unwrap(), token);
+
+        let claims = ServiceTokenVerifier::new("grpcserver", &key()).unwrap().verify(&token).unwrap();
+        assert_eq!(claims.sub, "tcpserver");
+
+        // 다른 대상용 검증기는 거부
+        assert!(ServiceTokenVerifier::new("gamecenter", &key()).unwrap().verify(&token).is_err());
+    }
+
+    #[test]
+    fn test_interceptors() {
+        let issuer = Arc::new(ServiceTokenIssuer::new("rudpserver", &key(), DEFAULT_TTL).unwrap());
+        let verifier = Some(Arc::new(ServiceTokenVerifier::new("grpcserver", &key()).unwrap()));
+
+        let req = attach_service_token(issuer, "grpcserver").call(Request::new(())).unwrap();
+        let req = service_auth_interceptor(verifier.clone(), true).call(req).unwrap();
+        assert_eq!(ServiceIdentity::of(&req).unwrap().service, "rudpserver");
+
+        // 클라이언트 트래픽은 선택 모드에서만 통과
+        let client = service_auth_interceptor(verifier.clone(), false).call(Request::new(())).unwrap();
+        assert!(ServiceIdentity::of(&client).is_none());
+        assert!(service_auth_interceptor(verifier, true).call(Request::new(())).is_err());
+    }
+}
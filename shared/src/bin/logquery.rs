@@ -0,0 +1,51 @@
+//! 바이너리 로그 조회 도구
+//!
+//! 사용법: `logquery <파일.blog> [옵션]` - 조건에 맞는 항목을 JSON Lines로 표준 출력에 기록
+//! - `--level <레벨>`: 최소 로그 레벨 (trace, debug, info, warn, error, fatal)
+//! - `--service <이름>`: 서비스 이름
+//! - `--since <RFC3339>`, `--until <RFC3339>`: 시간 범위
+//! - `--grep <문자열>`: 메시지 부분 문자열
+//! - `--ctx <키=값>`: 컨텍스트 값 (여러 번 지정 가능)
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use shared::logging::logquery::{convert_to_json, LogQuery};
+use shared::logging::LogLevel;
+
+const USAGE: &str = "사용법: logquery <파일.blog> [--level 레벨] [--service 이름] [--since 시각] [--until 시각] [--grep 문자열] [--ctx 키=값]";
+
+fn parse_time(value: &str) -> Result<DateTime<Utc>> {
+    Ok(DateTime::parse_from_rfc3339(value)
+        .with_context(|| format!("RFC3339 시각 형식이 아닙니다: {}", value))?
+        .with_timezone(&Utc))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(String, LogQuery)> {
+    let path = args.next().ok_or_else(|| anyhow!(USAGE))?;
+    let mut query = LogQuery::default();
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| anyhow!("{} 값이 없습니다", flag))?;
+        match flag.as_str() {
+            "--level" => {
+                query.min_level = Some(value.parse::<LogLevel>().map_err(|_| anyhow!("알 수 없는 로그 레벨: {}", value))?)
+            }
+            "--service" => query.service = Some(value),
+            "--since" => query.since = Some(parse_time(&value)?),
+            "--until" => query.until = Some(parse_time(&value)?),
+            "--grep" => query.message_contains = Some(value),
+            "--ctx" => {
+                let (key, expected) = value.split_once('=').ok_or_else(|| anyhow!("--ctx는 키=값 형식입니다: {}", value))?;
+                query.context.push((key.to_string(), expected.to_string()));
+            }
+            other => bail!("알 수 없는 옵션: {}\n{}", other, USAGE),
+        }
+    }
+    Ok((path, query))
+}
+
+fn main() -> Result<()> {
+    let (path, query) = parse_args(std::env::args().skip(1))?;
+    let count = convert_to_json(&path, &query, std::io::stdout().lock())?;
+    eprintln!("{}개 항목 출력", count);
+    Ok(())
+}
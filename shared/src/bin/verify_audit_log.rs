@@ -0,0 +1,40 @@
+//! 감사 로그 체인 검증 도구
+//!
+//! 사용법:
+//! - `verify_audit_log <파일 경로>`: JSON Lines 감사 로그 파일 검증
+//! - `verify_audit_log --database`: DB(`audit_log` 테이블) 감사 로그 검증 (`db_*` 환경변수 사용)
+//!
+//! HMAC으로 기록했다면 같은 `AUDIT_HMAC_KEY`를 설정해야 합니다. 변조가 발견되면 종료 코드 1을 반환합니다.
+
+use anyhow::{anyhow, Result};
+use shared::config::db::DbConfig;
+use shared::security::audit_log::{hmac_key_from_env, read_all, verify_chain, AuditTarget};
+use std::path::PathBuf;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let Some(arg) = std::env::args().nth(1) else {
+        eprintln!("사용법: verify_audit_log <파일 경로> | --database");
+        std::process::exit(2);
+    };
+    let target = if arg == "--database" {
+        let db = DbConfig::new().await.map_err(|e| anyhow!("DB 연결 실패: {}", e))?;
+        AuditTarget::Database(db.get_pool().clone())
+    } else {
+        AuditTarget::File(PathBuf::from(&arg))
+    };
+
+    let key = hmac_key_from_env();
+    let records = read_all(&target).await.map_err(|e| anyhow!("감사 로그 읽기 실패: {}", e))?;
+    match verify_chain(&records, key.as_deref()) {
+        Ok(count) => {
+            let head = records.last().map_or("-", |record| record.hash.as_str());
+            println!("✅ 감사 로그 체인 정상: 기록 {}개, 마지막 해시 {}", count, head);
+            Ok(())
+        }
+        Err(violation) => {
+            println!("❌ 감사 로그 변조 감지: {}", violation);
+            std::process::exit(1);
+        }
+    }
+}
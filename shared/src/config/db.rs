@@ -7,6 +7,7 @@ use dotenv::dotenv;
 use std::env;
 use sqlx::{MySql, Pool, MySqlPool, Error as SqlxError};
 use tracing::{info, error, warn};
+use crate::service::db::pool_monitor::{PoolMonitor, PoolMonitorConfig};
 
 /// MariaDB 연결 풀 타입 별칭
 pub type DbConnection = Pool<MySql>;
@@ -146,6 +147,11 @@ impl DbConfig {
         }
     }
 
+    /// 획득 대기/보유 시간을 추적하는 모니터 생성
+    pub fn monitored(&self, config: PoolMonitorConfig) -> PoolMonitor<MySql> {
+        PoolMonitor::new(self.pool.clone(), config)
+    }
+
     /// 데이터베이스 연결을 닫습니다.
     /// 
     /// 애플리케이션 종료 시 호출하여 리소스를 정리합니다.
@@ -60,6 +60,12 @@ impl RedisConfig {
         
         let port = port_str.parse::<u16>().expect("redis_port는 숫자여야 함");
 
+        Self::connect(host, port).await
+    }
+
+    /// 환경변수 대신 지정한 주소로 연결 (지역 간 복제용 Redis 등)
+    pub async fn connect(host: impl Into<String>, port: u16) -> Result<Self, RedisError> {
+        let host = host.into();
         let client = Client::open(format!("redis://{host}:{port}"))?;
         let manager = ConnectionManager::new(client).await?;
         Ok(Self { conn: manager, host, port })
@@ -1,7 +1,10 @@
+pub mod auth;
 pub mod config;
 pub mod service; 
 pub mod tool;
 pub mod model;
 pub mod security;
 pub mod logging;
+pub mod monitoring;
+pub mod protocol_version;
 
@@ -0,0 +1,436 @@
+//! 바이너리 구조화 로그 형식
+//!
+//! 초당 수만 건의 JSON 로그는 직렬화와 디스크 비용이 크므로, `LOG_BINARY_FORMAT=true`이면
+//! `AsyncLogWriter`가 이 형식으로 기록합니다 (확장자 `.blog`).
+//!
+//! # 파일 구조
+//! - 헤더: `PTBLOG` + 버전(1바이트)
+//! - 레코드: 태그(1바이트) + 본문
+//!   - `DICT`: 문자열 사전 항목 정의 (id, 길이, UTF-8 바이트)
+//!   - `ENTRY`: 로그 항목 (본문 길이 + 정해진 순서의 필드)
+//!   - `RESET`: 사전 초기화 (작성기가 기존 파일에 이어 쓸 때)
+//!
+//! 서비스 이름, 컨텍스트 키, 짧은 문자열 값은 파일별 사전의 id로 기록하고,
+//! 긴 문자열이나 사전이 가득 찬 뒤의 문자열은 그대로 기록합니다.
+//! 읽기와 JSON 변환은 [`crate::logging::logquery`]를 사용합니다.
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::io::{ErrorKind, Read};
+
+use crate::logging::formatter::{LogEntry, LogLevel};
+
+/// 파일 헤더 매직
+pub const BINARY_LOG_MAGIC: &[u8; 6] = b"PTBLOG";
+/// 형식 버전
+pub const BINARY_LOG_VERSION: u8 = 1;
+/// 바이너리 로그 파일 확장자
+pub const BINARY_LOG_EXTENSION: &str = "blog";
+
+/// 파일당 사전 최대 크기
+const MAX_DICT_ENTRIES: usize = 4096;
+/// 사전에 넣을 문자열 최대 길이 (더 긴 문자열은 반복될 가능성이 낮음)
+const MAX_DICT_STRING_LEN: usize = 64;
+/// 항목 하나의 최대 크기 (손상된 길이 값 방어)
+const MAX_ENTRY_LEN: usize = 16 * 1024 * 1024;
+
+const TAG_DICT: u8 = 1;
+const TAG_ENTRY: u8 = 2;
+const TAG_RESET: u8 = 3;
+
+const VALUE_NULL: u8 = 0;
+const VALUE_FALSE: u8 = 1;
+const VALUE_TRUE: u8 = 2;
+const VALUE_INT: u8 = 3;
+const VALUE_FLOAT: u8 = 4;
+const VALUE_STRING: u8 = 5;
+/// 배열/객체/i64 범위를 넘는 정수는 JSON 텍스트로 기록
+const VALUE_JSON: u8 = 6;
+
+const HAS_THREAD_ID: u8 = 1;
+const HAS_MODULE_PATH: u8 = 1 << 1;
+const HAS_FILE_LOCATION: u8 = 1 << 2;
+
+/// 바이너리 로그 인코더 (파일 하나에 대응)
+#[derive(Debug, Default)]
+pub struct BinaryLogEncoder {
+    dictionary: HashMap<String, u32>,
+    payload: Vec<u8>,
+}
+
+impl BinaryLogEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 새 파일 헤더
+    pub fn write_header(&mut self, out: &mut Vec<u8>) {
+        out.extend_from_slice(BINARY_LOG_MAGIC);
+        out.push(BINARY_LOG_VERSION);
+        self.dictionary.clear();
+    }
+
+    /// 기존 파일에 이어 쓸 때 사전 초기화 레코드
+    pub fn write_reset(&mut self, out: &mut Vec<u8>) {
+        out.push(TAG_RESET);
+        self.dictionary.clear();
+    }
+
+    /// 로그 항목 인코딩 (새 사전 항목이 있으면 항목 앞에 정의)
+    pub fn encode(&mut self, entry: &LogEntry, out: &mut Vec<u8>) {
+        let mut payload = std::mem::take(&mut self.payload);
+        payload.clear();
+
+        let micros = entry.timestamp.timestamp_micros();
+        payload.extend_from_slice(&micros.to_le_bytes());
+        payload.push(entry.level as u8);
+
+        let mut flags = 0;
+        if entry.thread_id.is_some() {
+            flags |= HAS_THREAD_ID;
+        }
+        if entry.module_path.is_some() {
+            flags |= HAS_MODULE_PATH;
+        }
+        if entry.file_location.is_some() {
+            flags |= HAS_FILE_LOCATION;
+        }
+        payload.push(flags);
+
+        self.put_str(&entry.service, out, &mut payload);
+        self.put_str(&entry.message, out, &mut payload);
+        for value in [&entry.thread_id, &entry.module_path, &entry.file_location].into_iter().flatten() {
+            self.put_str(value, out, &mut payload);
+        }
+
+        put_varint(&mut payload, entry.context.len() as u64);
+        for (key, value) in &entry.context {
+            self.put_str(key, out, &mut payload);
+            self.put_value(value, out, &mut payload);
+        }
+
+        out.push(TAG_ENTRY);
+        put_varint(out, payload.len() as u64);
+        out.extend_from_slice(&payload);
+        self.payload = payload;
+    }
+
+    /// 사전 참조 `id << 1` 또는 인라인 `(len << 1) | 1` + 바이트
+    fn put_str(&mut self, value: &str, out: &mut Vec<u8>, payload: &mut Vec<u8>) {
+        if let Some(&id) = self.dictionary.get(value) {
+            put_varint(payload, (id as u64) << 1);
+            return;
+        }
+        if value.len() <= MAX_DICT_STRING_LEN && self.dictionary.len() < MAX_DICT_ENTRIES {
+            let id = self.dictionary.len() as u32;
+            self.dictionary.insert(value.to_string(), id);
+            out.push(TAG_DICT);
+            put_varint(out, id as u64);
+            put_varint(out, value.len() as u64);
+            out.extend_from_slice(value.as_bytes());
+            put_varint(payload, (id as u64) << 1);
+            return;
+        }
+        put_varint(payload, ((value.len() as u64) << 1) | 1);
+        payload.extend_from_slice(value.as_bytes());
+    }
+
+    fn put_value(&mut self, value: &serde_json::Value, out: &mut Vec<u8>, payload: &mut Vec<u8>) {
+        use serde_json::Value;
+        match value {
+            Value::Null => payload.push(VALUE_NULL),
+            Value::Bool(false) => payload.push(VALUE_FALSE),
+            Value::Bool(true) => payload.push(VALUE_TRUE),
+            Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    payload.push(VALUE_INT);
+                    put_varint(payload, zigzag(i));
+                } else if n.is_f64() {
+                    payload.push(VALUE_FLOAT);
+                    payload.extend_from_slice(&n.as_f64().unwrap_or_default().to_le_bytes());
+                } else {
+                    payload.push(VALUE_JSON);
+                    put_inline(payload, &n.to_string());
+                }
+            }
+            Value::String(s) => {
+                payload.push(VALUE_STRING);
+                self.put_str(s, out, payload);
+            }
+            Value::Array(_) | Value::Object(_) => {
+                payload.push(VALUE_JSON);
+                put_inline(payload, &value.to_string());
+            }
+        }
+    }
+}
+
+/// 바이너리 로그 디코더
+pub struct BinaryLogReader<R: Read> {
+    reader: R,
+    dictionary: Vec<String>,
+    finished: bool,
+}
+
+impl<R: Read> BinaryLogReader<R> {
+    /// 헤더를 확인하고 디코더 생성
+    pub fn new(mut reader: R) -> Result<Self> {
+        let mut header = [0u8; 7];
+        reader.read_exact(&mut header).context("바이너리 로그 헤더 읽기 실패")?;
+        if &header[..6] != BINARY_LOG_MAGIC {
+            bail!("바이너리 로그 파일이 아닙니다");
+        }
+        if header[6] != BINARY_LOG_VERSION {
+            bail!("지원하지 않는 바이너리 로그 버전: {}", header[6]);
+        }
+        Ok(Self { reader, dictionary: Vec::new(), finished: false })
+    }
+
+    /// 다음 로그 항목 (파일 끝이면 None)
+    pub fn next_entry(&mut self) -> Result<Option<LogEntry>> {
+        loop {
+            let mut tag = [0u8; 1];
+            match self.reader.read(&mut tag) {
+                Ok(0) => return Ok(None),
+                Ok(_) => {}
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            }
+            match tag[0] {
+                TAG_DICT => {
+                    let id = read_varint(&mut self.reader)? as usize;
+                    if id != self.dictionary.len() {
+                        bail!("사전 id 불연속: {} (기대값 {})", id, self.dictionary.len());
+                    }
+                    let len = read_varint(&mut self.reader)? as usize;
+                    if len > MAX_DICT_STRING_LEN {
+                        bail!("사전 항목 길이 초과: {}", len);
+                    }
+                    let mut bytes = vec![0u8; len];
+                    self.reader.read_exact(&mut bytes)?;
+                    self.dictionary.push(String::from_utf8(bytes)?);
+                }
+                TAG_RESET => self.dictionary.clear(),
+                TAG_ENTRY => {
+                    let len = read_varint(&mut self.reader)? as usize;
+                    if len > MAX_ENTRY_LEN {
+                        bail!("로그 항목 길이 초과: {}", len);
+                    }
+                    let mut payload = vec![0u8; len];
+                    self.reader.read_exact(&mut payload).context("잘린 로그 항목")?;
+                    return self.decode_entry(&payload).map(Some);
+                }
+                other => bail!("알 수 없는 레코드 태그: {}", other),
+            }
+        }
+    }
+
+    fn decode_entry(&self, payload: &[u8]) -> Result<LogEntry> {
+        let mut cursor = payload;
+        let mut micros = [0u8; 8];
+        cursor.read_exact(&mut micros)?;
+        let timestamp = DateTime::<Utc>::from_timestamp_micros(i64::from_le_bytes(micros))
+            .ok_or_else(|| anyhow!("잘못된 타임스탬프"))?;
+        let level = level_from_u8(read_u8(&mut cursor)?)?;
+        let flags = read_u8(&mut cursor)?;
+
+        let service = self.read_str(&mut cursor)?;
+        let message = self.read_str(&mut cursor)?;
+        let optional = |flag: u8, cursor: &mut &[u8]| -> Result<Option<String>> {
+            if flags & flag != 0 { self.read_str(cursor).map(Some) } else { Ok(None) }
+        };
+        let thread_id = optional(HAS_THREAD_ID, &mut cursor)?;
+        let module_path = optional(HAS_MODULE_PATH, &mut cursor)?;
+        let file_location = optional(HAS_FILE_LOCATION, &mut cursor)?;
+
+        let count = read_varint(&mut cursor)? as usize;
+        let mut context = HashMap::with_capacity(count.min(64));
+        for _ in 0..count {
+            let key = self.read_str(&mut cursor)?;
+            let value = self.read_value(&mut cursor)?;
+            context.insert(key, value);
+        }
+
+        Ok(LogEntry { timestamp, level, service, message, context, thread_id, module_path, file_location })
+    }
+
+    fn read_str(&self, cursor: &mut &[u8]) -> Result<String> {
+        let header = read_varint(cursor)?;
+        if header & 1 == 0 {
+            let id = (header >> 1) as usize;
+            return self
+                .dictionary
+                .get(id)
+                .cloned()
+                .ok_or_else(|| anyhow!("정의되지 않은 사전 id: {}", id));
+        }
+        read_inline(cursor, (header >> 1) as usize)
+    }
+
+    fn read_value(&self, cursor: &mut &[u8]) -> Result<serde_json::Value> {
+        use serde_json::Value;
+        Ok(match read_u8(cursor)? {
+            VALUE_NULL => Value::Null,
+            VALUE_FALSE => Value::Bool(false),
+            VALUE_TRUE => Value::Bool(true),
+            VALUE_INT => Value::from(unzigzag(read_varint(cursor)?)),
+            VALUE_FLOAT => {
+                let mut bytes = [0u8; 8];
+                cursor.read_exact(&mut bytes)?;
+                Value::from(f64::from_le_bytes(bytes))
+            }
+            VALUE_STRING => Value::String(self.read_str(cursor)?),
+            VALUE_JSON => {
+                let len = read_varint(cursor)? as usize;
+                serde_json::from_str(&read_inline(cursor, len)?)?
+            }
+            other => bail!("알 수 없는 값 타입: {}", other),
+        })
+    }
+}
+
+impl<R: Read> Iterator for BinaryLogReader<R> {
+    type Item = Result<LogEntry>;
+
+    /// 손상된 레코드를 만나면 오류를 한 번 반환하고 끝냄 (비정상 종료로 잘린 마지막 항목 등)
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = self.next_entry().transpose();
+        if !matches!(result, Some(Ok(_))) {
+            self.finished = true;
+        }
+        result
+    }
+}
+
+fn level_from_u8(value: u8) -> Result<LogLevel> {
+    Ok(match value {
+        0 => LogLevel::Trace,
+        1 => LogLevel::Debug,
+        2 => LogLevel::Info,
+        3 => LogLevel::Warn,
+        4 => LogLevel::Error,
+        5 => LogLevel::Fatal,
+        other => bail!("알 수 없는 로그 레벨: {}", other),
+    })
+}
+
+fn put_inline(out: &mut Vec<u8>, value: &str) {
+    put_varint(out, value.len() as u64);
+    out.extend_from_slice(value.as_bytes());
+}
+
+fn read_inline(cursor: &mut &[u8], len: usize) -> Result<String> {
+    if len > cursor.len() {
+        bail!("문자열 길이가 항목 범위를 벗어남: {}", len);
+    }
+    let (bytes, rest) = cursor.split_at(len);
+    *cursor = rest;
+    Ok(String::from_utf8(bytes.to_vec())?)
+}
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(reader: &mut impl Read) -> Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = read_u8(reader)?;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    bail!("varint 길이 초과")
+}
+
+fn read_u8(reader: &mut impl Read) -> Result<u8> {
+    let mut byte = [0u8; 1];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(message: &str, context: &[(&str, serde_json::Value)]) -> LogEntry {
+        let mut entry = LogEntry::new(LogLevel::Warn, "rudpserver".to_string(), message.to_string(), &[]);
+        for (key, value) in context {
+            entry.add_context(*key, value.clone());
+        }
+        entry.with_module_path("rudpserver::game")
+    }
+
+    #[test]
+    fn test_roundtrip_with_dictionary_and_reset() {
+        let long_message = "x".repeat(200);
+        let first = entry("player moved", &[
+            ("player_id", serde_json::json!(-42)),
+            ("speed", serde_json::json!(1.5)),
+            ("ok", serde_json::json!(true)),
+            ("big", serde_json::json!(u64::MAX)),
+            ("tags", serde_json::json!(["a", {"b": null}])),
+        ]);
+        let second = entry(&long_message, &[("player_id", serde_json::json!(7))]);
+
+        let mut encoder = BinaryLogEncoder::new();
+        let mut out = Vec::new();
+        encoder.write_header(&mut out);
+        encoder.encode(&first, &mut out);
+        let after_first = out.len();
+        encoder.encode(&first, &mut out);
+        // 두 번째부터는 사전 참조만 남아 더 작음
+        assert!(out.len() - after_first < after_first - 7);
+
+        // 작성기 재시작
+        let mut restarted = BinaryLogEncoder::new();
+        restarted.write_reset(&mut out);
+        restarted.encode(&second, &mut out);
+
+        let decoded: Vec<LogEntry> = BinaryLogReader::new(out.as_slice()).unwrap().map(Result::unwrap).collect();
+        assert_eq!(decoded.len(), 3);
+        for (decoded, original) in decoded.iter().zip([&first, &first, &second]) {
+            assert_eq!(decoded.timestamp.timestamp_micros(), original.timestamp.timestamp_micros());
+            assert_eq!(decoded.level, original.level);
+            assert_eq!(decoded.message, original.message);
+            assert_eq!(decoded.context, original.context);
+            assert_eq!(decoded.thread_id, original.thread_id);
+            assert_eq!(decoded.module_path, original.module_path);
+            assert_eq!(decoded.file_location, None);
+        }
+    }
+
+    #[test]
+    fn test_truncated_entry_is_reported_once() {
+        let mut encoder = BinaryLogEncoder::new();
+        let mut out = Vec::new();
+        encoder.write_header(&mut out);
+        encoder.encode(&entry("one", &[]), &mut out);
+        encoder.encode(&entry("two", &[]), &mut out);
+        out.truncate(out.len() - 3);
+
+        let results: Vec<Result<LogEntry>> = BinaryLogReader::new(out.as_slice()).unwrap().collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+
+        assert!(BinaryLogReader::new(&b"{\"level\":1}"[..]).is_err());
+    }
+}
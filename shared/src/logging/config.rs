@@ -3,6 +3,7 @@
 //! 로깅 시스템의 설정 파라미터와 서비스 타입 정의를 담당합니다.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// 서비스 타입 열거형
@@ -23,6 +24,15 @@ pub enum ServiceType {
 }
 
 impl ServiceType {
+    /// 모든 서비스 타입
+    pub const ALL: [ServiceType; 5] = [
+        ServiceType::GrpcServer,
+        ServiceType::TcpServer,
+        ServiceType::RudpServer,
+        ServiceType::GameCenter,
+        ServiceType::Shared,
+    ];
+
     /// 서비스 타입을 문자열로 변환
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -34,6 +44,26 @@ impl ServiceType {
         }
     }
 
+    /// tracing 이벤트 대상(모듈 경로)으로 서비스 타입 판별
+    ///
+    /// 크레이트 이름으로 구분하며, 알 수 없는 대상은 `Shared`로 분류합니다.
+    pub fn from_target(target: &str) -> Self {
+        let crate_name = target.split("::").next().unwrap_or_default();
+        match crate_name {
+            "grpcserver" => ServiceType::GrpcServer,
+            "tcpserver" => ServiceType::TcpServer,
+            "rudpserver" => ServiceType::RudpServer,
+            "gamecenter" => ServiceType::GameCenter,
+            _ => ServiceType::Shared,
+        }
+    }
+
+    /// 이름(`grpcserver`) 또는 로그 파일 접두사(`grpc`)로 서비스 타입 조회
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        Self::ALL.into_iter().find(|service| service.as_str() == name || service.log_prefix() == name)
+    }
+
     /// 로그 파일 접두사 반환
     pub fn log_prefix(&self) -> &'static str {
         match self {
@@ -70,8 +100,20 @@ pub struct LoggingConfig {
     /// 디버그 모드 (기본값: false)
     pub debug_mode: bool,
     
-    /// 로그 압축 여부 (기본값: true)
+    /// 로그 압축 여부 (기본값: true, 순환된 파일을 gzip으로 압축)
     pub enable_compression: bool,
+    
+    /// 서비스별 보관 일수 (없으면 `retention_days`)
+    #[serde(default)]
+    pub retention_overrides: HashMap<ServiceType, u32>,
+    
+    /// 최소 디스크 여유 공간 (바이트, 기본값: 1GB) - 미만이면 오래된 로그부터 긴급 삭제
+    #[serde(default = "default_min_free_disk")]
+    pub min_free_disk_bytes: u64,
+    
+    /// 바이너리 로그 형식 여부 (기본값: false, `.blog` 파일로 기록)
+    #[serde(default)]
+    pub binary_format: bool,
 }
 
 impl Default for LoggingConfig {
@@ -85,10 +127,17 @@ impl Default for LoggingConfig {
             use_utc: true,
             debug_mode: false,
             enable_compression: true,
+            binary_format: false,
+            retention_overrides: HashMap::new(),
+            min_free_disk_bytes: default_min_free_disk(),
         }
     }
 }
 
+fn default_min_free_disk() -> u64 {
+    1024 * 1024 * 1024 // 1GB
+}
+
 impl LoggingConfig {
     /// 환경변수에서 설정 로드
     pub fn from_env() -> Self {
@@ -134,9 +183,29 @@ impl LoggingConfig {
             config.enable_compression = val.to_lowercase() == "true";
         }
         
+        if let Ok(val) = std::env::var("LOG_BINARY_FORMAT") {
+            config.binary_format = val.to_lowercase() == "true";
+        }
+        
+        // 예: LOG_RETENTION_OVERRIDES=rudpserver=3,grpc=14
+        if let Ok(val) = std::env::var("LOG_RETENTION_OVERRIDES") {
+            config.retention_overrides = parse_retention_overrides(&val);
+        }
+        
+        if let Ok(val) = std::env::var("LOG_MIN_FREE_DISK_MB") {
+            if let Ok(mb) = val.parse::<u64>() {
+                config.min_free_disk_bytes = mb * 1024 * 1024;
+            }
+        }
+        
         config
     }
     
+    /// 서비스의 보관 일수
+    pub fn retention_days_for(&self, service_type: ServiceType) -> u32 {
+        self.retention_overrides.get(&service_type).copied().unwrap_or(self.retention_days)
+    }
+    
     /// 설정 유효성 검증
     pub fn validate(&self) -> anyhow::Result<()> {
         if self.retention_days == 0 {
@@ -151,10 +220,25 @@ impl LoggingConfig {
             return Err(anyhow::anyhow!("async_queue_size must be greater than 0"));
         }
         
+        if let Some((service, _)) = self.retention_overrides.iter().find(|(_, days)| **days == 0) {
+            return Err(anyhow::anyhow!("retention override for {} must be greater than 0", service.as_str()));
+        }
+        
         Ok(())
     }
 }
 
+/// `서비스=일수` 목록 파싱 (알 수 없는 서비스나 잘못된 일수는 무시)
+fn parse_retention_overrides(value: &str) -> HashMap<ServiceType, u32> {
+    value
+        .split(',')
+        .filter_map(|pair| {
+            let (name, days) = pair.split_once('=')?;
+            Some((ServiceType::from_name(name)?, days.trim().parse().ok()?))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +261,16 @@ mod tests {
         assert_eq!(ServiceType::Shared.log_prefix(), "shared");
     }
     
+    #[test]
+    fn test_service_type_from_target() {
+        assert_eq!(ServiceType::from_target("grpcserver::controller::user_controller"), ServiceType::GrpcServer);
+        assert_eq!(ServiceType::from_target("tcpserver"), ServiceType::TcpServer);
+        assert_eq!(ServiceType::from_target("rudpserver::game::state_manager"), ServiceType::RudpServer);
+        assert_eq!(ServiceType::from_target("gamecenter::health"), ServiceType::GameCenter);
+        assert_eq!(ServiceType::from_target("shared::service::redis"), ServiceType::Shared);
+        assert_eq!(ServiceType::from_target("hyper::proto"), ServiceType::Shared);
+    }
+    
     #[test]
     fn test_default_config() {
         let config = LoggingConfig::default();
@@ -188,6 +282,24 @@ mod tests {
         assert!(config.use_utc);
         assert!(!config.debug_mode);
         assert!(config.enable_compression);
+        assert!(!config.binary_format);
+        assert!(config.retention_overrides.is_empty());
+        assert_eq!(config.min_free_disk_bytes, 1024 * 1024 * 1024);
+    }
+    
+    #[test]
+    fn test_retention_overrides() {
+        let overrides = parse_retention_overrides("rudpserver=3, grpc=14,unknown=1,tcp=x");
+        assert_eq!(overrides.len(), 2);
+        
+        let config = LoggingConfig { retention_overrides: overrides, ..Default::default() };
+        assert_eq!(config.retention_days_for(ServiceType::RudpServer), 3);
+        assert_eq!(config.retention_days_for(ServiceType::GrpcServer), 14);
+        assert_eq!(config.retention_days_for(ServiceType::TcpServer), 7);
+        assert!(config.validate().is_ok());
+        
+        let config = LoggingConfig { retention_overrides: parse_retention_overrides("game=0"), ..Default::default() };
+        assert!(config.validate().is_err());
     }
     
     #[test]
@@ -0,0 +1,157 @@
+//! tracing 연동 레이어
+//!
+//! `tracing` 이벤트를 서비스별 로그 파일로 보내는 구독자 레이어입니다.
+//! 이벤트 대상(모듈 경로)의 크레이트 이름으로 `ServiceType`을 판별하므로,
+//! 통합 실행 시 하위 서버들의 로그가 하나의 구독자를 거쳐 각자의 파일에 기록됩니다.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::logging::config::ServiceType;
+use crate::logging::formatter::{LogEntry, LogLevel};
+use crate::logging::writer::AsyncLogWriter;
+
+/// 로깅 시스템 내부 이벤트 대상 (재귀 기록 방지)
+const INTERNAL_TARGET: &str = "shared::logging";
+
+/// 서비스별 로그 라우팅 레이어
+pub struct ServiceRoutingLayer {
+    writers: Arc<RwLock<HashMap<ServiceType, Arc<AsyncLogWriter>>>>,
+}
+
+impl ServiceRoutingLayer {
+    pub(crate) fn new(writers: Arc<RwLock<HashMap<ServiceType, Arc<AsyncLogWriter>>>>) -> Self {
+        Self { writers }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for ServiceRoutingLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if metadata.target().starts_with(INTERNAL_TARGET) {
+            return;
+        }
+
+        let service = ServiceType::from_target(metadata.target());
+
+        // 파일 순환 중에는 작성기 맵이 잠겨 있으므로 해당 이벤트는 건너뜀
+        let Ok(writers) = self.writers.try_read() else {
+            return;
+        };
+        let Some(writer) = writers.get(&service) else {
+            return;
+        };
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let mut entry = LogEntry::new(level_of(metadata.level()), service.as_str().to_string(), visitor.message, &[])
+            .with_module_path(metadata.target());
+        if let (Some(file), Some(line)) = (metadata.file(), metadata.line()) {
+            entry = entry.with_file_location(format!("{file}:{line}"));
+        }
+        for (key, value) in visitor.fields {
+            entry.add_context(key, value);
+        }
+
+        if let Err(e) = writer.write_log(entry) {
+            eprintln!("로그 작성 실패: {}", e);
+        }
+    }
+}
+
+fn level_of(level: &Level) -> LogLevel {
+    match *level {
+        Level::TRACE => LogLevel::Trace,
+        Level::DEBUG => LogLevel::Debug,
+        Level::INFO => LogLevel::Info,
+        Level::WARN => LogLevel::Warn,
+        Level::ERROR => LogLevel::Error,
+    }
+}
+
+/// 이벤트 필드 수집기 (`message`는 본문, 나머지는 컨텍스트)
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: HashMap<String, serde_json::Value>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.insert(field.name().to_string(), value.into());
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.fields.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields.insert(field.name().to_string(), format!("{value:?}").into());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::config::LoggingConfig;
+    use crate::logging::formatter::LogFormatter;
+    use tempfile::TempDir;
+    use tracing_subscriber::prelude::*;
+
+    #[tokio::test]
+    async fn test_events_routed_by_crate() {
+        let temp_dir = TempDir::new().unwrap();
+        let formatter = Arc::new(LogFormatter::new(true, false));
+        let mut map = HashMap::new();
+        for service in [ServiceType::TcpServer, ServiceType::GameCenter] {
+            let path = temp_dir.path().join(format!("{}.log", service.log_prefix()));
+            let writer = AsyncLogWriter::new(path, LoggingConfig::default(), formatter.clone()).await.unwrap();
+            map.insert(service, Arc::new(writer));
+        }
+        let writers = Arc::new(RwLock::new(map));
+
+        let subscriber = tracing_subscriber::registry().with(ServiceRoutingLayer::new(writers.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(target: "tcpserver::handler", user_id = 7, "방 입장");
+            tracing::warn!(target: "gamecenter", "게임센터 경고");
+            tracing::info!(target: "rudpserver", "작성기 없음 - 무시");
+        });
+
+        let writers = Arc::try_unwrap(writers).ok().unwrap().into_inner();
+        for (_, writer) in writers {
+            Arc::try_unwrap(writer).ok().unwrap().shutdown().await.unwrap();
+        }
+
+        let tcp_log = std::fs::read_to_string(temp_dir.path().join("tcp.log")).unwrap();
+        assert!(tcp_log.contains("방 입장"));
+        assert!(tcp_log.contains("\"service\":\"tcpserver\""));
+        assert!(tcp_log.contains("\"user_id\":7"));
+        assert!(!tcp_log.contains("게임센터 경고"));
+
+        let game_log = std::fs::read_to_string(temp_dir.path().join("game.log")).unwrap();
+        assert!(game_log.contains("게임센터 경고"));
+        assert!(!temp_dir.path().join("rudp.log").exists());
+    }
+}
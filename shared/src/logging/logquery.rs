@@ -0,0 +1,125 @@
+//! 바이너리 로그 오프라인 조회
+//!
+//! `.blog` 파일을 읽어 조건에 맞는 항목만 걸러내고, 기존 JSON 로그와 같은 형식의
+//! JSON Lines로 변환합니다. 명령줄 도구는 `logquery` 바이너리를 사용합니다.
+//!
+//! ```ignore
+//! let query = LogQuery { min_level: Some(LogLevel::Warn), ..Default::default() };
+//! let count = convert_to_json("logs/rudpserver/rudp_2025-01-01.blog", &query, std::io::stdout().lock())?;
+//! ```
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::logging::binary::BinaryLogReader;
+use crate::logging::formatter::{LogEntry, LogLevel};
+
+/// 로그 조회 조건 (설정한 조건을 모두 만족해야 일치)
+#[derive(Debug, Clone, Default)]
+pub struct LogQuery {
+    /// 최소 로그 레벨
+    pub min_level: Option<LogLevel>,
+    pub service: Option<String>,
+    /// 시작 시각 (포함)
+    pub since: Option<DateTime<Utc>>,
+    /// 종료 시각 (제외)
+    pub until: Option<DateTime<Utc>>,
+    /// 메시지 부분 문자열
+    pub message_contains: Option<String>,
+    /// 컨텍스트 키/값 (문자열이 아닌 값은 JSON으로 해석해 비교)
+    pub context: Vec<(String, String)>,
+}
+
+impl LogQuery {
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        self.min_level.is_none_or(|level| entry.level >= level)
+            && self.service.as_ref().is_none_or(|service| &entry.service == service)
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp < until)
+            && self.message_contains.as_ref().is_none_or(|needle| entry.message.contains(needle.as_str()))
+            && self.context.iter().all(|(key, expected)| match entry.context.get(key) {
+                Some(serde_json::Value::String(value)) => value == expected,
+                Some(value) => serde_json::from_str::<serde_json::Value>(expected).is_ok_and(|expected| *value == expected),
+                None => false,
+            })
+    }
+}
+
+/// 바이너리 로그 파일 열기
+pub fn open(path: impl AsRef<Path>) -> Result<BinaryLogReader<BufReader<File>>> {
+    let path = path.as_ref();
+    let file = File::open(path).with_context(|| format!("로그 파일 열기 실패: {}", path.display()))?;
+    BinaryLogReader::new(BufReader::new(file))
+}
+
+/// 조건에 맞는 항목 조회
+pub fn query<'a>(
+    path: impl AsRef<Path>,
+    query: &'a LogQuery,
+) -> Result<impl Iterator<Item = Result<LogEntry>> + 'a> {
+    Ok(open(path)?.filter(move |entry| entry.as_ref().map_or(true, |entry| query.matches(entry))))
+}
+
+/// 조건에 맞는 항목을 JSON Lines로 변환 (기록한 항목 수 반환)
+pub fn convert_to_json(path: impl AsRef<Path>, filter: &LogQuery, out: impl Write) -> Result<usize> {
+    let mut out = BufWriter::new(out);
+    let mut count = 0;
+    for entry in query(path, filter)? {
+        serde_json::to_writer(&mut out, &entry?)?;
+        out.write_all(b"\n")?;
+        count += 1;
+    }
+    out.flush()?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logging::binary::BinaryLogEncoder;
+    use chrono::Duration;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_query_and_convert() {
+        let now = Utc::now();
+        let entries = [
+            (LogLevel::Info, "rudpserver", "player joined", now - Duration::minutes(10), 1),
+            (LogLevel::Warn, "rudpserver", "packet loss high", now - Duration::minutes(5), 1),
+            (LogLevel::Error, "tcpserver", "room full", now, 2),
+        ];
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("rudp.blog");
+        let mut encoder = BinaryLogEncoder::new();
+        let mut buffer = Vec::new();
+        encoder.write_header(&mut buffer);
+        for (level, service, message, timestamp, room) in entries {
+            let mut entry = LogEntry::new(level, service.to_string(), message.to_string(), &[]);
+            entry.timestamp = timestamp;
+            entry.add_context("room_id", room);
+            encoder.encode(&entry, &mut buffer);
+        }
+        std::fs::write(&path, &buffer).unwrap();
+
+        let messages = |filter: LogQuery| -> Vec<String> {
+            query(&path, &filter).unwrap().map(|entry| entry.unwrap().message).collect()
+        };
+        assert_eq!(messages(LogQuery::default()).len(), 3);
+        assert_eq!(messages(LogQuery { min_level: Some(LogLevel::Warn), ..Default::default() }), ["packet loss high", "room full"]);
+        assert_eq!(messages(LogQuery { service: Some("rudpserver".into()), message_contains: Some("loss".into()), ..Default::default() }), ["packet loss high"]);
+        assert_eq!(messages(LogQuery { since: Some(now - Duration::minutes(6)), until: Some(now - Duration::minutes(1)), ..Default::default() }), ["packet loss high"]);
+        assert_eq!(messages(LogQuery { context: vec![("room_id".into(), "2".into())], ..Default::default() }), ["room full"]);
+
+        let mut json = Vec::new();
+        let count = convert_to_json(&path, &LogQuery { min_level: Some(LogLevel::Error), ..Default::default() }, &mut json).unwrap();
+        assert_eq!(count, 1);
+        let line: serde_json::Value = serde_json::from_slice(json.trim_ascii_end()).unwrap();
+        assert_eq!(line["message"], "room full");
+        assert_eq!(line["level"], "Error");
+        assert_eq!(line["context"]["room_id"], 2);
+    }
+}
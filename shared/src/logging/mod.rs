@@ -5,9 +5,12 @@
 //! # 주요 기능
 //! - **기능별 로그 분류**: gRPC, TCP, RUDP, Game Center별 로그 분리
 //! - **날짜별 파일 관리**: 매일 새로운 로그 파일 생성
-//! - **자동 보관 정책**: 7일 후 자동 삭제
+//! - **자동 보관 정책**: 7일 후 자동 삭제 (서비스별 보관 일수 지정 가능), 순환된 파일 gzip 압축
+//! - **디스크 보호**: 여유 공간이 부족하면 오래된 로그부터 긴급 삭제하고 `log_disk_alert`로 알림
 //! - **비동기 처리**: 성능 영향 최소화
 //! - **구조화된 로그**: JSON 형태로 분석 용이
+//! - **바이너리 로그**: `LOG_BINARY_FORMAT=true`면 압축된 `.blog` 형식으로 기록, `logquery`로 조회/변환
+//! - **tracing 연동**: `tracing_layer()`로 통합 실행 시 하위 서버 로그를 서비스별 파일에 기록
 //!
 //! # 사용 예시
 //! ```rust
@@ -25,15 +28,21 @@
 //! }
 //! ```
 
+pub mod binary;
 pub mod config;
 pub mod formatter;
+pub mod layer;
+pub mod logquery;
 pub mod rotation;
 pub mod system;
 pub mod writer;
 
+pub use binary::{BinaryLogEncoder, BinaryLogReader};
 pub use config::{LoggingConfig, ServiceType};
 pub use formatter::{LogFormatter, LogLevel, LogEntry};
-pub use rotation::LogRotationManager;
+pub use layer::ServiceRoutingLayer;
+pub use logquery::LogQuery;
+pub use rotation::{LogRotationManager, PruneReport};
 pub use system::LoggingSystem;
 pub use writer::AsyncLogWriter;
 
@@ -1,15 +1,49 @@
 //! 로그 파일 순환 및 보관 관리
 //!
-//! 날짜별 로그 파일 생성과 7일 보관 정책을 구현합니다.
+//! 날짜별 로그 파일 생성, 크기 기준 순환, 서비스별 보관 정책을 구현합니다.
+//!
+//! - 최대 크기(`max_file_size`)를 넘은 파일은 `<접두사>_<날짜>_<시각>` 이름으로 순환
+//! - 순환되었거나 날짜가 지난 텍스트 로그는 이전 작성기가 남은 버퍼를 다 쓸 시간을 둔 뒤 gzip으로 압축
+//!   (`.blog`는 이미 압축된 형식이라 제외)
+//! - 보관 일수는 서비스별로 덮어쓸 수 있음 (`LOG_RETENTION_OVERRIDES`)
+//! - 디스크 여유 공간이 `min_free_disk_bytes` 미만이면 현재 파일을 제외한 가장 오래된 로그부터 삭제하고 알림
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use std::collections::HashMap;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use sysinfo::{DiskExt, System, SystemExt};
 use tokio::fs;
 use tracing::{debug, warn};
 
+use crate::logging::binary::BINARY_LOG_EXTENSION;
 use crate::logging::config::{LoggingConfig, ServiceType};
+use crate::tool::high_performance::MetricsCollector;
+
+/// 알림 로그 타깃
+const ALERT_TARGET: &str = "log_disk_alert";
+
+/// 압축 파일 확장자
+const COMPRESSED_EXTENSION: &str = "gz";
+
+/// 마지막 수정 후 이 시간이 지난 파일만 압축 (이전 작성기의 남은 버퍼 기록 대기)
+const COMPRESS_SETTLE: Duration = Duration::from_secs(60);
+
+const MB: u64 = 1024 * 1024;
+
+/// 긴급 정리 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    /// 삭제한 파일 수
+    pub deleted_files: usize,
+    /// 확보한 바이트
+    pub freed_bytes: u64,
+}
 
 /// 로그 순환 관리자
 pub struct LogRotationManager {
@@ -21,6 +55,8 @@ pub struct LogRotationManager {
     current_files: HashMap<ServiceType, PathBuf>,
     /// 마지막 정리 실행 시간
     last_cleanup: Option<DateTime<Utc>>,
+    /// 긴급 정리 메트릭 (선택)
+    metrics: Option<Arc<MetricsCollector>>,
 }
 
 impl LogRotationManager {
@@ -31,9 +67,21 @@ impl LogRotationManager {
             config,
             current_files: HashMap::new(),
             last_cleanup: None,
+            metrics: None,
         }
     }
 
+    /// 긴급 정리 메트릭 연결
+    pub fn with_metrics(mut self, metrics: Arc<MetricsCollector>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// 긴급 정리 메트릭 연결 (이미 생성된 관리자)
+    pub fn set_metrics(&mut self, metrics: Arc<MetricsCollector>) {
+        self.metrics = Some(metrics);
+    }
+
     /// 지정된 서비스의 현재 로그 파일 경로 반환
     /// 
     /// 파일이 존재하지 않거나 날짜가 바뀌었으면 새 파일 경로를 생성합니다.
@@ -61,9 +109,14 @@ impl LogRotationManager {
         Ok(expected_path)
     }
     
+    /// 로그 파일 확장자
+    fn extension(&self) -> &'static str {
+        if self.config.binary_format { BINARY_LOG_EXTENSION } else { "log" }
+    }
+    
     /// 로그 파일 경로 생성
     fn build_log_path(&self, service_type: ServiceType, date: &str) -> PathBuf {
-        let filename = format!("{}_{}.log", service_type.log_prefix(), date);
+        let filename = format!("{}_{}.{}", service_type.log_prefix(), date, self.extension());
         self.base_dir
             .join(service_type.as_str())
             .join(filename)
@@ -108,7 +161,7 @@ impl LogRotationManager {
     
     /// 보관 정책에 따른 오래된 로그 파일 정리
     /// 
-    /// 1시간마다 실행되며, retention_days보다 오래된 파일들을 삭제합니다.
+    /// 1시간마다 실행되며, 서비스별 보관 일수보다 오래된 파일들을 삭제합니다.
     pub async fn cleanup_old_logs(&mut self) -> Result<usize> {
         let now = Utc::now();
         
@@ -119,36 +172,31 @@ impl LogRotationManager {
             }
         }
         
-        let cutoff_date = now - chrono::Duration::days(self.config.retention_days as i64);
         let mut deleted_count = 0;
         
         // 모든 서비스 디렉토리 검사
-        for service_type in [
-            ServiceType::GrpcServer,
-            ServiceType::TcpServer, 
-            ServiceType::RudpServer,
-            ServiceType::GameCenter,
-            ServiceType::Shared,
-        ] {
+        for service_type in ServiceType::ALL {
             let service_dir = self.base_dir.join(service_type.as_str());
             
             if !service_dir.exists() {
                 continue;
             }
             
-            deleted_count += self.cleanup_service_logs(&service_dir, &cutoff_date).await?;
+            let cutoff_date = now - chrono::Duration::days(self.config.retention_days_for(service_type) as i64);
+            let deleted = self.cleanup_service_logs(&service_dir, &cutoff_date).await?;
+            if deleted > 0 {
+                debug!(
+                    service = service_type.as_str(),
+                    deleted_files = deleted,
+                    cutoff_date = %cutoff_date.format("%Y-%m-%d"),
+                    "오래된 로그 파일 정리 완료"
+                );
+            }
+            deleted_count += deleted;
         }
         
         self.last_cleanup = Some(now);
         
-        if deleted_count > 0 {
-            debug!(
-                deleted_files = deleted_count,
-                cutoff_date = %cutoff_date.format("%Y-%m-%d"),
-                "오래된 로그 파일 정리 완료"
-            );
-        }
-        
         Ok(deleted_count)
     }
     
@@ -163,8 +211,8 @@ impl LogRotationManager {
             
             let path = entry.path();
             
-            // .log 파일만 처리
-            if !path.is_file() || path.extension().is_none_or(|ext| ext != "log") {
+            // .log / .blog 파일과 압축본만 처리
+            if !path.is_file() || !is_log_file(&path) {
                 continue;
             }
             
@@ -216,9 +264,15 @@ impl LogRotationManager {
     }
     
     /// 로그 파일 순환 (크기 초과 시)
+    ///
+    /// 앞서 순환되었거나 날짜가 지난 파일 중 압축 대기 시간이 지난 파일도 함께 압축합니다.
     pub async fn rotate_if_needed(&mut self, service_type: ServiceType) -> Result<Option<PathBuf>> {
         let current_path = self.get_current_log_file(service_type).await?;
         
+        if let Err(e) = self.compress_rotated_logs(service_type).await {
+            warn!(service = service_type.as_str(), error = %e, "순환된 로그 압축 실패");
+        }
+        
         if !self.should_rotate(&current_path).await? {
             return Ok(None);
         }
@@ -226,11 +280,12 @@ impl LogRotationManager {
         // 순환된 파일 이름 생성 (타임스탬프 추가)
         let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
         let rotated_name = format!(
-            "{}_{}.log",
+            "{}_{}.{}",
             current_path.file_stem()
                 .and_then(|s| s.to_str())
                 .unwrap_or("unknown"),
-            timestamp
+            timestamp,
+            self.extension()
         );
         
         let rotated_path = current_path
@@ -255,15 +310,133 @@ impl LogRotationManager {
         Ok(Some(rotated_path))
     }
     
+    /// 순환되었거나 날짜가 지난 텍스트 로그 압축
+    ///
+    /// 오늘 파일과 마지막 수정 후 `COMPRESS_SETTLE`이 지나지 않은 파일은 건너뜁니다.
+    pub async fn compress_rotated_logs(&self, service_type: ServiceType) -> Result<usize> {
+        if !self.config.enable_compression {
+            return Ok(0);
+        }
+        let service_dir = self.base_dir.join(service_type.as_str());
+        if !service_dir.exists() {
+            return Ok(0);
+        }
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let current_path = self.build_log_path(service_type, &today);
+        
+        let mut compressed = 0;
+        let mut entries = fs::read_dir(&service_dir).await
+            .context("서비스 디렉토리 읽기 실패")?;
+        while let Some(entry) = entries.next_entry().await
+            .context("디렉토리 항목 읽기 실패")? {
+            
+            let path = entry.path();
+            if path == current_path || !path.is_file() || path.extension().is_none_or(|ext| ext != "log") {
+                continue;
+            }
+            let modified = entry.metadata().await
+                .and_then(|metadata| metadata.modified())
+                .context("파일 시간 정보 읽기 실패")?;
+            if modified.elapsed().unwrap_or_default() < COMPRESS_SETTLE {
+                continue;
+            }
+            
+            match compress_file(path.clone()).await {
+                Ok(compressed_path) => {
+                    compressed += 1;
+                    debug!(path = %compressed_path.display(), "순환된 로그 파일 압축됨");
+                }
+                Err(e) => warn!(path = %path.display(), error = %e, "로그 파일 압축 실패"),
+            }
+        }
+        
+        Ok(compressed)
+    }
+    
+    /// 디스크 여유 공간 확인 후 부족하면 긴급 정리
+    ///
+    /// 여유 공간을 알 수 없거나 충분하면 None을 반환합니다.
+    pub async fn prune_if_low_disk(&self) -> Result<Option<PruneReport>> {
+        let Some(available) = available_space(&self.base_dir) else {
+            return Ok(None);
+        };
+        if let Some(metrics) = &self.metrics {
+            metrics.set_gauge("log_disk_available_bytes", available as f64, HashMap::new());
+        }
+        let threshold = self.config.min_free_disk_bytes;
+        if available >= threshold {
+            return Ok(None);
+        }
+        
+        let needed = threshold - available;
+        let report = self.emergency_prune(needed).await?;
+        if let Some(metrics) = &self.metrics {
+            metrics.increment_counter("log_emergency_prune_total", HashMap::new());
+        }
+        warn!(
+            target: ALERT_TARGET,
+            available_mb = available / MB,
+            threshold_mb = threshold / MB,
+            deleted_files = report.deleted_files,
+            freed_mb = report.freed_bytes / MB,
+            still_needed_mb = needed.saturating_sub(report.freed_bytes) / MB,
+            "디스크 여유 공간 부족 - 오래된 로그 긴급 삭제"
+        );
+        Ok(Some(report))
+    }
+    
+    /// 현재 파일을 제외한 가장 오래된 로그부터 `needed_bytes`만큼 삭제
+    ///
+    /// 보관 일수와 무관하게 모든 서비스의 파일을 수정 시간 순으로 삭제합니다.
+    pub async fn emergency_prune(&self, needed_bytes: u64) -> Result<PruneReport> {
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let current: Vec<PathBuf> = ServiceType::ALL.iter()
+            .map(|service_type| self.build_log_path(*service_type, &today))
+            .collect();
+        
+        let mut candidates: Vec<(SystemTime, u64, PathBuf)> = Vec::new();
+        for service_type in ServiceType::ALL {
+            let service_dir = self.base_dir.join(service_type.as_str());
+            if !service_dir.exists() {
+                continue;
+            }
+            let mut entries = fs::read_dir(&service_dir).await
+                .context("서비스 디렉토리 읽기 실패")?;
+            while let Some(entry) = entries.next_entry().await
+                .context("디렉토리 항목 읽기 실패")? {
+                
+                let path = entry.path();
+                if !path.is_file() || !is_log_file(&path) || current.contains(&path) {
+                    continue;
+                }
+                let metadata = entry.metadata().await
+                    .context("파일 메타데이터 읽기 실패")?;
+                let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                candidates.push((modified, metadata.len(), path));
+            }
+        }
+        candidates.sort();
+        
+        let mut report = PruneReport::default();
+        for (_, size, path) in candidates {
+            if report.freed_bytes >= needed_bytes {
+                break;
+            }
+            match fs::remove_file(&path).await {
+                Ok(()) => {
+                    report.deleted_files += 1;
+                    report.freed_bytes += size;
+                    debug!(path = %path.display(), "긴급 정리로 로그 파일 삭제됨");
+                }
+                Err(e) => warn!(path = %path.display(), error = %e, "로그 파일 삭제 실패"),
+            }
+        }
+        Ok(report)
+    }
+    
     /// 서비스별 로그 디렉토리 생성
     pub async fn initialize_directories(&self) -> Result<()> {
-        for service_type in [
-            ServiceType::GrpcServer,
-            ServiceType::TcpServer,
-            ServiceType::RudpServer,
-            ServiceType::GameCenter,
-            ServiceType::Shared,
-        ] {
+        for service_type in ServiceType::ALL {
             let service_dir = self.base_dir.join(service_type.as_str());
             fs::create_dir_all(&service_dir).await
                 .with_context(|| format!("서비스 디렉토리 생성 실패: {}", service_dir.display()))?;
@@ -274,6 +447,52 @@ impl LogRotationManager {
     }
 }
 
+/// 로그 파일(.log, .blog) 또는 그 압축본인지
+fn is_log_file(path: &Path) -> bool {
+    let is_log = |path: &Path| path.extension().is_some_and(|ext| ext == "log" || ext == BINARY_LOG_EXTENSION);
+    if path.extension().is_some_and(|ext| ext == COMPRESSED_EXTENSION) {
+        return path.file_stem().is_some_and(|stem| is_log(Path::new(stem)));
+    }
+    is_log(path)
+}
+
+/// 파일을 gzip으로 압축한 뒤 원본 삭제 (실패 시 만들던 압축본 삭제)
+async fn compress_file(path: PathBuf) -> Result<PathBuf> {
+    tokio::task::spawn_blocking(move || {
+        let mut compressed_name = path.clone().into_os_string();
+        compressed_name.push(".");
+        compressed_name.push(COMPRESSED_EXTENSION);
+        let compressed_path = PathBuf::from(compressed_name);
+        
+        let result = (|| -> std::io::Result<()> {
+            let mut input = std::fs::File::open(&path)?;
+            let output = std::io::BufWriter::new(std::fs::File::create(&compressed_path)?);
+            let mut encoder = GzEncoder::new(output, Compression::default());
+            std::io::copy(&mut input, &mut encoder)?;
+            encoder.finish()?.flush()
+        })();
+        if let Err(e) = result {
+            let _ = std::fs::remove_file(&compressed_path);
+            return Err(e).with_context(|| format!("압축 실패: {}", path.display()));
+        }
+        std::fs::remove_file(&path).context("압축 후 원본 삭제 실패")?;
+        Ok(compressed_path)
+    })
+    .await
+    .context("압축 태스크 실패")?
+}
+
+/// 경로가 속한 디스크의 여유 공간 (가장 긴 마운트 지점 기준)
+fn available_space(path: &Path) -> Option<u64> {
+    let path = std::fs::canonicalize(path).ok()?;
+    let mut system = System::new();
+    system.refresh_disks_list();
+    system.disks().iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -349,6 +568,78 @@ mod tests {
         assert!(deleted_count == 0); // 테스트에서는 삭제할 오래된 파일이 없음
     }
     
+    /// 마지막 수정 시간을 과거로 설정
+    fn age(path: &Path, secs: u64) {
+        let file = std::fs::OpenOptions::new().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - Duration::from_secs(secs)).unwrap();
+    }
+    
+    #[tokio::test]
+    async fn test_compress_rotated_logs() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = LogRotationManager::new(temp_dir.path(), test_config());
+        let current = manager.get_current_log_file(ServiceType::TcpServer).await.unwrap();
+        age(&current, 3600);
+        
+        let service_dir = temp_dir.path().join("tcpserver");
+        let rotated = service_dir.join("tcp_2024-01-01_120000.log");
+        let fresh = service_dir.join("tcp_2024-01-02_120000.log");
+        std::fs::write(&rotated, "rotated line\n".repeat(100)).unwrap();
+        std::fs::write(&fresh, "fresh").unwrap();
+        age(&rotated, 3600);
+        
+        assert_eq!(manager.compress_rotated_logs(ServiceType::TcpServer).await.unwrap(), 1);
+        assert!(!rotated.exists());
+        assert!(fresh.exists(), "압축 대기 시간이 지나지 않은 파일은 그대로");
+        assert!(current.exists(), "오늘 파일은 압축하지 않음");
+        
+        let mut decoded = String::new();
+        let gz = std::fs::File::open(service_dir.join("tcp_2024-01-01_120000.log.gz")).unwrap();
+        std::io::Read::read_to_string(&mut flate2::read::GzDecoder::new(gz), &mut decoded).unwrap();
+        assert_eq!(decoded, "rotated line\n".repeat(100));
+        
+        let disabled = LogRotationManager::new(temp_dir.path(), LoggingConfig { enable_compression: false, ..test_config() });
+        age(&fresh, 3600);
+        assert_eq!(disabled.compress_rotated_logs(ServiceType::TcpServer).await.unwrap(), 0);
+    }
+    
+    #[tokio::test]
+    async fn test_emergency_prune_oldest_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = LogRotationManager::new(temp_dir.path(), test_config());
+        let current = manager.get_current_log_file(ServiceType::GrpcServer).await.unwrap();
+        std::fs::write(&current, vec![b'x'; 500]).unwrap();
+        age(&current, 90_000);
+        manager.get_current_log_file(ServiceType::RudpServer).await.unwrap();
+        
+        let oldest = temp_dir.path().join("rudpserver").join("rudp_2024-01-01.log.gz");
+        let middle = temp_dir.path().join("grpcserver").join("grpc_2024-01-02.log");
+        let newest = temp_dir.path().join("grpcserver").join("grpc_2024-01-03.blog");
+        for (path, secs) in [(&oldest, 30_000), (&middle, 20_000), (&newest, 10_000)] {
+            std::fs::write(path, vec![b'x'; 400]).unwrap();
+            age(path, secs);
+        }
+        
+        let report = manager.emergency_prune(600).await.unwrap();
+        assert_eq!(report, PruneReport { deleted_files: 2, freed_bytes: 800 });
+        assert!(!oldest.exists() && !middle.exists());
+        assert!(newest.exists());
+        assert!(current.exists(), "현재 파일은 오래되어도 삭제하지 않음");
+        
+        // 충분한 여유 공간이면 아무것도 하지 않음
+        let relaxed = LogRotationManager::new(temp_dir.path(), LoggingConfig { min_free_disk_bytes: 0, ..test_config() });
+        assert_eq!(relaxed.prune_if_low_disk().await.unwrap(), None);
+    }
+    
+    #[test]
+    fn test_is_log_file() {
+        assert!(is_log_file(Path::new("grpc_2024-01-01.log")));
+        assert!(is_log_file(Path::new("grpc_2024-01-01.blog")));
+        assert!(is_log_file(Path::new("grpc_2024-01-01_120000.log.gz")));
+        assert!(!is_log_file(Path::new("grpc_2024-01-01.tar.gz")));
+        assert!(!is_log_file(Path::new("notes.txt")));
+    }
+    
     #[tokio::test]
     async fn test_initialize_directories() {
         let temp_dir = TempDir::new().unwrap();
@@ -358,13 +649,7 @@ mod tests {
         manager.initialize_directories().await.unwrap();
         
         // 모든 서비스 디렉토리가 생성되었는지 확인
-        for service_type in [
-            ServiceType::GrpcServer,
-            ServiceType::TcpServer,
-            ServiceType::RudpServer,
-            ServiceType::GameCenter,
-            ServiceType::Shared,
-        ] {
+        for service_type in ServiceType::ALL {
             let service_dir = temp_dir.path().join(service_type.as_str());
             assert!(service_dir.exists());
             assert!(service_dir.is_dir());
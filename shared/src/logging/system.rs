@@ -14,9 +14,11 @@ use tracing::{debug, error, info, warn};
 use crate::logging::{
     config::{LoggingConfig, ServiceType},
     formatter::{LogFormatter, LogLevel, LogEntry},
+    layer::ServiceRoutingLayer,
     rotation::LogRotationManager,
     writer::{AsyncLogWriter, InMemoryLogWriter},
 };
+use crate::tool::high_performance::MetricsCollector;
 
 /// 로깅 시스템 상태
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -45,6 +47,8 @@ pub struct LoggingSystem {
     writers: Arc<RwLock<HashMap<ServiceType, Arc<AsyncLogWriter>>>>,
     /// 현재 서비스 타입
     current_service: Option<ServiceType>,
+    /// 파일을 기록하는 서비스 목록 (통합 실행 시 여러 서비스)
+    services: Vec<ServiceType>,
     /// 시스템 상태
     state: Arc<RwLock<LoggingState>>,
     /// 백그라운드 태스크 핸들
@@ -79,6 +83,7 @@ impl LoggingSystem {
             formatter,
             writers: Arc::new(RwLock::new(HashMap::new())),
             current_service: None,
+            services: Vec::new(),
             state: Arc::new(RwLock::new(LoggingState::Uninitialized)),
             background_tasks: Arc::new(Mutex::new(Vec::new())),
             test_mode: false,
@@ -86,6 +91,12 @@ impl LoggingSystem {
         })
     }
     
+    /// 디스크 부족 긴급 정리 메트릭 연결
+    pub async fn with_metrics(self, metrics: Arc<MetricsCollector>) -> Self {
+        self.rotation_manager.lock().await.set_metrics(metrics);
+        self
+    }
+    
     /// 테스트 모드로 로깅 시스템 생성
     pub async fn new_test_mode() -> Result<Self> {
         let mut system = Self::new("./test_logs").await?;
@@ -96,16 +107,34 @@ impl LoggingSystem {
     
     /// 지정된 서비스로 로깅 시스템 초기화
     pub async fn init(&mut self, service_type: ServiceType) -> Result<()> {
+        self.init_services(service_type, &[service_type]).await
+    }
+    
+    /// 여러 서비스의 로그를 함께 기록하도록 초기화 (통합 실행용)
+    ///
+    /// `primary`는 이 시스템의 직접 로그 메서드(`info` 등)가 사용하는 서비스이며,
+    /// `services`의 각 서비스마다 별도 로그 파일이 생성됩니다.
+    /// tracing 이벤트는 `tracing_layer()`로 해당 서비스 파일에 전달됩니다.
+    pub async fn init_aggregated(&mut self, primary: ServiceType, services: &[ServiceType]) -> Result<()> {
+        let mut all = vec![primary];
+        all.extend(services.iter().copied().filter(|service| *service != primary));
+        self.init_services(primary, &all).await
+    }
+    
+    async fn init_services(&mut self, service_type: ServiceType, services: &[ServiceType]) -> Result<()> {
         let mut state = self.state.write().await;
         if *state != LoggingState::Uninitialized {
             return Err(anyhow::anyhow!("로깅 시스템이 이미 초기화됨"));
         }
         
         self.current_service = Some(service_type);
+        self.services = services.to_vec();
         
         // 서비스별 로그 작성기 생성
         if !self.test_mode {
-            self.create_log_writer(service_type).await?;
+            for service in services {
+                self.create_log_writer(*service).await?;
+            }
         }
         
         // 백그라운드 태스크 시작
@@ -123,6 +152,11 @@ impl LoggingSystem {
         Ok(())
     }
     
+    /// tracing 이벤트를 서비스별 로그 파일로 보내는 구독자 레이어
+    pub fn tracing_layer(&self) -> ServiceRoutingLayer {
+        ServiceRoutingLayer::new(self.writers.clone())
+    }
+    
     /// 로그 작성기 생성
     async fn create_log_writer(&self, service_type: ServiceType) -> Result<()> {
         let mut rotation_manager = self.rotation_manager.lock().await;
@@ -150,24 +184,27 @@ impl LoggingSystem {
     async fn start_background_tasks(&self) -> Result<()> {
         let mut tasks = self.background_tasks.lock().await;
         
-        // 로그 파일 정리 태스크
+        // 로그 파일 정리 태스크 (보관 기간 정리는 1시간마다, 디스크 여유 공간은 5분마다 확인)
         {
             let rotation_manager = self.rotation_manager.clone();
             let cleanup_task = tokio::spawn(async move {
-                let mut interval = interval(Duration::from_secs(3600)); // 1시간마다
+                let mut interval = interval(Duration::from_secs(300)); // 5분마다
                 loop {
                     interval.tick().await;
                     let mut manager = rotation_manager.lock().await;
                     if let Err(e) = manager.cleanup_old_logs().await {
                         error!(error = %e, "로그 파일 정리 실패");
                     }
+                    if let Err(e) = manager.prune_if_low_disk().await {
+                        error!(error = %e, "로그 긴급 정리 실패");
+                    }
                 }
             });
             tasks.push(cleanup_task);
         }
         
-        // 로그 파일 순환 태스크
-        if let Some(service_type) = self.current_service {
+        // 서비스별 로그 파일 순환 태스크
+        for service_type in self.services.iter().copied() {
             let rotation_manager = self.rotation_manager.clone();
             let writers = self.writers.clone();
             let config = self.config.clone();
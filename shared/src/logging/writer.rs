@@ -1,6 +1,7 @@
 //! 비동기 로그 작성기
 //!
 //! 비동기 방식으로 로그를 파일에 작성하여 성능 영향을 최소화합니다.
+//! `binary_format`이 켜져 있으면 텍스트 대신 [`crate::logging::binary`] 형식으로 기록합니다.
 
 use anyhow::{Context, Result};
 use std::path::PathBuf;
@@ -11,6 +12,7 @@ use tokio::sync::mpsc;
 use tokio::time::interval;
 use tracing::{debug, error, warn};
 
+use crate::logging::binary::BinaryLogEncoder;
 use crate::logging::config::LoggingConfig;
 use crate::logging::formatter::{LogFormatter, LogEntry};
 
@@ -107,6 +109,15 @@ impl AsyncLogWriter {
         let mut buffer = Vec::with_capacity(1024);
         let mut pending_writes = 0;
         
+        // 바이너리 형식: 새 파일이면 헤더, 이어 쓰면 사전 초기화 레코드
+        let mut encoder = config.binary_format.then(BinaryLogEncoder::new);
+        if let Some(encoder) = encoder.as_mut() {
+            match writer.get_ref().metadata().await {
+                Ok(metadata) if metadata.len() > 0 => encoder.write_reset(&mut buffer),
+                _ => encoder.write_header(&mut buffer),
+            }
+        }
+        
         debug!(
             path = %log_file_path.display(),
             flush_interval = ?config.flush_interval,
@@ -119,7 +130,7 @@ impl AsyncLogWriter {
                 cmd = receiver.recv() => {
                     match cmd {
                         Some(WriteCommand::Write(entry)) => {
-                            if let Err(e) = Self::write_entry(&mut buffer, &formatter, &entry).await {
+                            if let Err(e) = Self::write_entry(&mut buffer, &formatter, encoder.as_mut(), &entry).await {
                                 error!(error = %e, "로그 항목 포매팅 실패");
                                 continue;
                             }
@@ -189,8 +200,14 @@ impl AsyncLogWriter {
     async fn write_entry(
         buffer: &mut Vec<u8>,
         formatter: &LogFormatter,
+        encoder: Option<&mut BinaryLogEncoder>,
         entry: &LogEntry,
     ) -> Result<()> {
+        if let Some(encoder) = encoder {
+            encoder.encode(entry, buffer);
+            return Ok(());
+        }
+        
         let formatted = formatter.format(entry)?;
         buffer.extend_from_slice(formatted.as_bytes());
         buffer.push(b'\n');
@@ -334,6 +351,30 @@ mod tests {
         writer.shutdown().await.unwrap();
     }
     
+    #[tokio::test]
+    async fn test_binary_log_writer() {
+        let temp_dir = TempDir::new().unwrap();
+        let log_path = temp_dir.path().join("test.blog");
+        let config = LoggingConfig { binary_format: true, ..test_config() };
+        
+        // 재시작 후 같은 파일에 이어 쓰기
+        for message in ["first run", "second run"] {
+            let writer = AsyncLogWriter::new(log_path.clone(), config.clone(), test_formatter()).await.unwrap();
+            writer.write_log(LogEntry::new(LogLevel::Info, "test-service".to_string(), message.to_string(), &[("key", "value")])).unwrap();
+            writer.shutdown().await.unwrap();
+        }
+        
+        let file = std::fs::File::open(&log_path).unwrap();
+        let entries: Vec<LogEntry> = crate::logging::binary::BinaryLogReader::new(std::io::BufReader::new(file))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].message, "first run");
+        assert_eq!(entries[1].message, "second run");
+        assert_eq!(entries[1].context["key"], "value");
+    }
+    
     #[tokio::test]
     async fn test_memory_writer() {
         let formatter = test_formatter();
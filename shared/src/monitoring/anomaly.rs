@@ -0,0 +1,482 @@
+//! 메트릭 이상 탐지
+//!
+//! 고정 임계값으로는 p99 지연이나 패킷 손실률이 며칠에 걸쳐 조금씩 나빠지는 회귀를 놓치므로,
+//! 메트릭별로 EWMA 평균과 분산 기준선을 유지하고 기준선에서 벗어난 정도(z-score)로 이상을 판단합니다.
+//!
+//! - 기준선: 평균은 값의 EWMA, 분산은 이전 평균과의 편차 제곱의 EWMA ([`ExponentialMovingAverage`])
+//! - 방향: 값이 **올라가는** 이탈만 이상으로 봅니다 (지연, 손실, 에러율은 낮을수록 좋음)
+//! - 예열: 샘플이 `warmup`개 쌓이기 전에는 판단하지 않습니다
+//! - 지속: `sustain`개 연속으로 이탈해야 알림을 올리고, `sustain`개 연속 정상이면 해제합니다
+//! - 심각도: z가 `warning_z` 이상이면 Warning, `critical_z` 이상이면 Critical이며 알림 중 심각도가 오르면 다시 알립니다
+//! - 이탈한 샘플은 기준선에 반영하지 않아 회귀가 기준선에 흡수되지 않습니다.
+//!   의도한 변경으로 수준이 바뀌었다면 [`AnomalyDetector::reset`]으로 기준선을 다시 잡습니다.
+//!
+//! 알림은 `anomaly_alert` 타깃으로 로그를 남기고, [`AnomalyDetector::render_prometheus`]로 z-score와 알림 상태를 내보냅니다.
+//!
+//! ```ignore
+//! let detector = AnomalyDetector::new()
+//!     .with_rule("rudp_p99_rtt_ms", AnomalyRule::p99_latency_ms())
+//!     .with_rule("rudp_packet_loss_percent", AnomalyRule::packet_loss_percent());
+//! detector.observe("rudp_p99_rtt_ms", stats.p99_rtt.as_secs_f64() * 1000.0);
+//! ```
+
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use tracing::{error, info, warn};
+
+use crate::tool::ExponentialMovingAverage;
+
+/// 알림 로그 타깃
+const ALERT_TARGET: &str = "anomaly_alert";
+
+/// 이상 심각도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AnomalySeverity {
+    Warning,
+    Critical,
+}
+
+impl AnomalySeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnomalySeverity::Warning => "warning",
+            AnomalySeverity::Critical => "critical",
+        }
+    }
+}
+
+/// 메트릭별 탐지 규칙
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnomalyRule {
+    /// EWMA 평활 계수 (작을수록 기준선이 천천히 움직임)
+    pub alpha: f64,
+    /// Warning z-score
+    pub warning_z: f64,
+    /// Critical z-score
+    pub critical_z: f64,
+    /// 판단 전에 필요한 샘플 수
+    pub warmup: u32,
+    /// 알림/해제에 필요한 연속 샘플 수
+    pub sustain: u32,
+    /// 표준편차 하한 (값이 거의 일정할 때 작은 흔들림으로 z가 튀지 않게 함, 메트릭 단위)
+    pub min_stddev: f64,
+}
+
+impl Default for AnomalyRule {
+    fn default() -> Self {
+        Self {
+            alpha: 0.05,
+            warning_z: 3.0,
+            critical_z: 6.0,
+            warmup: 30,
+            sustain: 3,
+            min_stddev: 0.0,
+        }
+    }
+}
+
+impl AnomalyRule {
+    /// p99 지연 (ms)
+    pub fn p99_latency_ms() -> Self {
+        Self { min_stddev: 2.0, ..Default::default() }
+    }
+
+    /// 패킷 손실률 (%)
+    pub fn packet_loss_percent() -> Self {
+        Self { min_stddev: 0.2, ..Default::default() }
+    }
+
+    /// 에러율 (%)
+    pub fn error_rate_percent() -> Self {
+        Self { min_stddev: 0.1, ..Default::default() }
+    }
+
+    fn severity(&self, z_score: f64) -> Option<AnomalySeverity> {
+        if z_score >= self.critical_z {
+            Some(AnomalySeverity::Critical)
+        } else if z_score >= self.warning_z {
+            Some(AnomalySeverity::Warning)
+        } else {
+            None
+        }
+    }
+}
+
+/// 알림 변화 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnomalyEventKind {
+    /// 지속 이탈로 알림 시작
+    Raised,
+    /// 알림 중 심각도 상승
+    Escalated,
+    /// 정상 복귀로 알림 해제
+    Resolved,
+}
+
+/// 알림 이벤트
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnomalyEvent {
+    pub metric: String,
+    pub kind: AnomalyEventKind,
+    /// 알림 심각도 (해제 시에는 해제 직전 심각도)
+    pub severity: AnomalySeverity,
+    pub value: f64,
+    /// 기준선 평균
+    pub baseline: f64,
+    pub z_score: f64,
+}
+
+/// 진행 중인 알림
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveAnomaly {
+    pub metric: String,
+    pub severity: AnomalySeverity,
+    /// 알림이 이어진 샘플 수
+    pub samples: u32,
+}
+
+#[derive(Debug)]
+struct Series {
+    rule: AnomalyRule,
+    mean: ExponentialMovingAverage,
+    variance: ExponentialMovingAverage,
+    samples: u32,
+    last_value: f64,
+    last_z: f64,
+    /// 연속 이탈 샘플 수와 그중 연속 Critical 샘플 수
+    breach_streak: u32,
+    critical_streak: u32,
+    /// 알림 중 연속 정상 샘플 수
+    recovery_streak: u32,
+    active: Option<AnomalySeverity>,
+    active_samples: u32,
+    alerts: BTreeMap<AnomalySeverity, u64>,
+}
+
+impl Series {
+    fn new(rule: AnomalyRule) -> Self {
+        Self {
+            mean: ExponentialMovingAverage::new(rule.alpha),
+            variance: ExponentialMovingAverage::new(rule.alpha),
+            rule,
+            samples: 0,
+            last_value: 0.0,
+            last_z: 0.0,
+            breach_streak: 0,
+            critical_streak: 0,
+            recovery_streak: 0,
+            active: None,
+            active_samples: 0,
+            alerts: BTreeMap::new(),
+        }
+    }
+
+    fn stddev(&self) -> f64 {
+        self.variance.value().max(0.0).sqrt().max(self.rule.min_stddev)
+    }
+
+    fn reset_baseline(&mut self) {
+        self.mean.reset();
+        self.variance.reset();
+        self.samples = 0;
+        self.last_z = 0.0;
+        self.breach_streak = 0;
+        self.critical_streak = 0;
+        self.recovery_streak = 0;
+        self.active = None;
+        self.active_samples = 0;
+    }
+
+    fn learn(&mut self, value: f64) {
+        let deviation = if self.mean.is_initialized() { value - self.mean.value() } else { 0.0 };
+        self.mean.update(value);
+        self.variance.update(deviation * deviation);
+        self.samples = self.samples.saturating_add(1);
+    }
+
+    fn observe(&mut self, metric: &str, value: f64) -> Option<AnomalyEvent> {
+        self.last_value = value;
+        if self.samples < self.rule.warmup {
+            self.learn(value);
+            return None;
+        }
+
+        let baseline = self.mean.value();
+        let stddev = self.stddev();
+        let z_score = if stddev > 0.0 { (value - baseline) / stddev } else { 0.0 };
+        self.last_z = z_score;
+        let severity = self.rule.severity(z_score);
+        let event = |kind, severity| AnomalyEvent {
+            metric: metric.to_string(),
+            kind,
+            severity,
+            value,
+            baseline,
+            z_score,
+        };
+
+        let Some(severity) = severity else {
+            self.learn(value);
+            self.breach_streak = 0;
+            self.critical_streak = 0;
+            let active = self.active?;
+            self.active_samples += 1;
+            self.recovery_streak += 1;
+            if self.recovery_streak < self.rule.sustain {
+                return None;
+            }
+            self.active = None;
+            self.active_samples = 0;
+            self.recovery_streak = 0;
+            return Some(event(AnomalyEventKind::Resolved, active));
+        };
+
+        self.recovery_streak = 0;
+        self.breach_streak += 1;
+        if severity == AnomalySeverity::Critical {
+            self.critical_streak += 1;
+        } else {
+            self.critical_streak = 0;
+        }
+        // 심각도도 지속 조건을 만족해야 올림
+        let sustained = if self.critical_streak >= self.rule.sustain {
+            AnomalySeverity::Critical
+        } else {
+            AnomalySeverity::Warning
+        };
+        match self.active {
+            Some(active) => {
+                self.active_samples += 1;
+                if sustained > active {
+                    self.active = Some(sustained);
+                    *self.alerts.entry(sustained).or_default() += 1;
+                    return Some(event(AnomalyEventKind::Escalated, sustained));
+                }
+                None
+            }
+            None if self.breach_streak >= self.rule.sustain => {
+                let severity = sustained;
+                self.active = Some(severity);
+                self.active_samples = self.breach_streak;
+                *self.alerts.entry(severity).or_default() += 1;
+                Some(event(AnomalyEventKind::Raised, severity))
+            }
+            None => None,
+        }
+    }
+}
+
+/// 메트릭 이상 탐지기 (공유해서 사용)
+#[derive(Debug, Default)]
+pub struct AnomalyDetector {
+    series: Mutex<BTreeMap<String, Series>>,
+}
+
+impl AnomalyDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 탐지할 메트릭 등록
+    pub fn with_rule(self, metric: impl Into<String>, rule: AnomalyRule) -> Self {
+        self.series.lock().insert(metric.into(), Series::new(rule));
+        self
+    }
+
+    /// 샘플 관찰 (등록하지 않은 메트릭과 NaN/무한대는 무시)
+    ///
+    /// 알림 상태가 바뀌면 이벤트를 반환하고 `anomaly_alert` 로그를 남깁니다.
+    pub fn observe(&self, metric: &str, value: f64) -> Option<AnomalyEvent> {
+        if !value.is_finite() {
+            return None;
+        }
+        let event = self.series.lock().get_mut(metric)?.observe(metric, value)?;
+        log_event(&event);
+        Some(event)
+    }
+
+    /// 진행 중인 알림 목록
+    pub fn active(&self) -> Vec<ActiveAnomaly> {
+        self.series
+            .lock()
+            .iter()
+            .filter_map(|(metric, series)| {
+                Some(ActiveAnomaly { metric: metric.clone(), severity: series.active?, samples: series.active_samples })
+            })
+            .collect()
+    }
+
+    /// 기준선 평균과 표준편차 (예열 전이면 None)
+    pub fn baseline(&self, metric: &str) -> Option<(f64, f64)> {
+        let series = self.series.lock();
+        let series = series.get(metric)?;
+        (series.samples >= series.rule.warmup).then(|| (series.mean.value(), series.stddev()))
+    }
+
+    /// 기준선 초기화 (의도한 변경으로 수준이 바뀐 경우, 진행 중인 알림도 해제)
+    pub fn reset(&self, metric: &str) {
+        if let Some(series) = self.series.lock().get_mut(metric) {
+            series.reset_baseline();
+            info!(target: ALERT_TARGET, metric, "이상 탐지 기준선 초기화");
+        }
+    }
+
+    /// Prometheus 텍스트 형식으로 내보내기
+    pub fn render_prometheus(&self) -> String {
+        let series = self.series.lock();
+        let mut output = String::new();
+        let mut family = |name: &str, kind: &str, help: &str, value: &dyn Fn(&Series) -> String| {
+            let _ = writeln!(output, "# HELP {name} {help}");
+            let _ = writeln!(output, "# TYPE {name} {kind}");
+            for (metric, series) in series.iter() {
+                let _ = writeln!(output, "{name}{{metric=\"{metric}\"}} {}", value(series));
+            }
+        };
+        family("anomaly_value", "gauge", "Last observed value", &|s| s.last_value.to_string());
+        family("anomaly_baseline", "gauge", "EWMA baseline of the metric", &|s| s.mean.value().to_string());
+        family("anomaly_zscore", "gauge", "Deviation from the baseline in standard deviations", &|s| {
+            s.last_z.to_string()
+        });
+        family("anomaly_active", "gauge", "Active alert severity (0 none, 1 warning, 2 critical)", &|s| {
+            match s.active {
+                None => "0",
+                Some(AnomalySeverity::Warning) => "1",
+                Some(AnomalySeverity::Critical) => "2",
+            }
+            .to_string()
+        });
+
+        let _ = writeln!(output, "# HELP anomaly_alerts_total Alerts raised or escalated");
+        let _ = writeln!(output, "# TYPE anomaly_alerts_total counter");
+        for (metric, series) in series.iter() {
+            for (severity, count) in &series.alerts {
+                let _ = writeln!(
+                    output,
+                    "anomaly_alerts_total{{metric=\"{metric}\",severity=\"{}\"}} {count}",
+                    severity.as_str()
+                );
+            }
+        }
+        output
+    }
+}
+
+fn log_event(event: &AnomalyEvent) {
+    let value = format!("{:.3}", event.value);
+    let baseline = format!("{:.3}", event.baseline);
+    let z_score = format!("{:.1}", event.z_score);
+    match (event.kind, event.severity) {
+        (AnomalyEventKind::Resolved, _) => info!(
+            target: ALERT_TARGET,
+            metric = %event.metric, %value, %baseline, "메트릭 이상 해제"
+        ),
+        (_, AnomalySeverity::Warning) => warn!(
+            target: ALERT_TARGET,
+            metric = %event.metric, %value, %baseline, %z_score, kind = ?event.kind, "메트릭 이상 감지"
+        ),
+        (_, AnomalySeverity::Critical) => error!(
+            target: ALERT_TARGET,
+            metric = %event.metric, %value, %baseline, %z_score, kind = ?event.kind, "메트릭 이상 감지 (심각)"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METRIC: &str = "p99_ms";
+
+    fn warmed_detector() -> AnomalyDetector {
+        let detector = AnomalyDetector::new().with_rule(
+            METRIC,
+            AnomalyRule { warmup: 20, sustain: 3, min_stddev: 1.0, ..AnomalyRule::p99_latency_ms() },
+        );
+        // 40ms 전후로 흔들리는 기준선
+        for i in 0..40 {
+            assert!(detector.observe(METRIC, 40.0 + (i % 3) as f64).is_none());
+        }
+        detector
+    }
+
+    #[test]
+    fn test_sustained_deviation_raises_and_resolves() {
+        let detector = warmed_detector();
+        let (baseline, _) = detector.baseline(METRIC).unwrap();
+        assert!((baseline - 41.0).abs() < 1.0, "{baseline}");
+
+        // 한 번 튄 값은 알림하지 않음
+        assert!(detector.observe(METRIC, 60.0).is_none());
+        assert!(detector.observe(METRIC, 41.0).is_none());
+
+        assert!(detector.observe(METRIC, 45.0).is_none());
+        assert!(detector.observe(METRIC, 45.0).is_none());
+        let event = detector.observe(METRIC, 45.0).unwrap();
+        assert_eq!(event.kind, AnomalyEventKind::Raised);
+        assert_eq!(event.severity, AnomalySeverity::Warning);
+        assert!(event.z_score >= 3.0);
+        assert_eq!(detector.active()[0].severity, AnomalySeverity::Warning);
+
+        // 이탈 구간은 기준선에 반영하지 않음
+        let (baseline, _) = detector.baseline(METRIC).unwrap();
+        for _ in 0..20 {
+            assert!(detector.observe(METRIC, 45.0).is_none());
+        }
+        assert!((detector.baseline(METRIC).unwrap().0 - baseline).abs() < f64::EPSILON);
+
+        assert!(detector.observe(METRIC, 41.0).is_none());
+        assert!(detector.observe(METRIC, 40.0).is_none());
+        let event = detector.observe(METRIC, 41.0).unwrap();
+        assert_eq!(event.kind, AnomalyEventKind::Resolved);
+        assert!(detector.active().is_empty());
+    }
+
+    #[test]
+    fn test_severity_escalation() {
+        let detector = warmed_detector();
+        for _ in 0..2 {
+            assert!(detector.observe(METRIC, 45.0).is_none());
+        }
+        // Critical이 지속되기 전에는 Warning으로 시작
+        let event = detector.observe(METRIC, 60.0).unwrap();
+        assert_eq!((event.kind, event.severity), (AnomalyEventKind::Raised, AnomalySeverity::Warning));
+
+        assert!(detector.observe(METRIC, 60.0).is_none());
+        let event = detector.observe(METRIC, 60.0).unwrap();
+        assert_eq!((event.kind, event.severity), (AnomalyEventKind::Escalated, AnomalySeverity::Critical));
+        assert!(detector.observe(METRIC, 60.0).is_none());
+
+        let text = detector.render_prometheus();
+        assert!(text.contains("anomaly_active{metric=\"p99_ms\"} 2"), "{text}");
+        assert!(text.contains("anomaly_alerts_total{metric=\"p99_ms\",severity=\"warning\"} 1"), "{text}");
+        assert!(text.contains("anomaly_alerts_total{metric=\"p99_ms\",severity=\"critical\"} 1"), "{text}");
+    }
+
+    #[test]
+    fn test_warmup_unknown_metric_and_reset() {
+        let detector = AnomalyDetector::new().with_rule("loss", AnomalyRule::packet_loss_percent());
+        assert!(detector.observe("unknown", 100.0).is_none());
+        assert!(detector.observe("loss", f64::NAN).is_none());
+        for _ in 0..29 {
+            assert!(detector.observe("loss", 0.5).is_none());
+        }
+        assert!(detector.baseline("loss").is_none());
+        assert!(detector.observe("loss", 0.5).is_none());
+        let (baseline, stddev) = detector.baseline("loss").unwrap();
+        assert!((baseline - 0.5).abs() < 1e-9 && (stddev - 0.2).abs() < 1e-9);
+
+        // 손실률이 내려가는 이탈은 이상이 아님
+        for _ in 0..5 {
+            assert!(detector.observe("loss", 0.0).is_none());
+        }
+        for _ in 0..2 {
+            assert!(detector.observe("loss", 5.0).is_none());
+        }
+        assert!(detector.observe("loss", 5.0).is_some());
+
+        detector.reset("loss");
+        assert!(detector.active().is_empty());
+        assert!(detector.baseline("loss").is_none());
+    }
+}
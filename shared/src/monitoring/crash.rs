@@ -0,0 +1,312 @@
+//! 크래시 리포트와 패닉 캡처
+//!
+//! `tokio::spawn`된 태스크의 패닉은 JoinHandle을 확인하지 않으면 그대로 사라집니다.
+//! [`install`]은 전역 패닉 훅을 설치해 모든 패닉을 백트레이스, 서비스/태스크 이름,
+//! 빌드 ID, 최근 로그와 함께 JSON 리포트로 남기고, 설정된 경우 수집 엔드포인트로 업로드합니다.
+//!
+//! - 최근 로그는 [`recent_logs_layer`]를 구독자에 추가해야 기록됩니다.
+//! - 태스크 이름은 [`spawn_monitored`] 또는 [`scoped`]로 실행한 태스크에만 붙습니다.
+//!
+//! 환경 변수:
+//! - `CRASH_REPORT_DIR`: 리포트 저장 디렉토리 (기본값: "./crash_reports")
+//! - `CRASH_UPLOAD_URL`: 리포트를 POST할 `http://` 엔드포인트 (없으면 업로드 안 함)
+
+use chrono::Utc;
+use futures::FutureExt;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::backtrace::Backtrace;
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::panic::{AssertUnwindSafe, PanicHookInfo};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tracing::field::{Field, Visit};
+use tracing::{error, Event, Subscriber};
+use tracing_subscriber::filter::{Filtered, LevelFilter};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// 리포트에 포함할 최근 로그 줄 수
+pub const RECENT_LOG_LINES: usize = 50;
+
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(2);
+
+static RECENT_LOGS: Mutex<VecDeque<String>> = parking_lot::const_mutex(VecDeque::new());
+static INSTALLED: OnceLock<CrashConfig> = OnceLock::new();
+
+tokio::task_local! {
+    static TASK_NAME: &'static str;
+}
+
+/// 크래시 리포터 설정
+#[derive(Debug, Clone)]
+pub struct CrashConfig {
+    pub service: String,
+    /// 버전 + 빌드 해시 ([`build_id`])
+    pub build: String,
+    pub report_dir: PathBuf,
+    pub upload_url: Option<String>,
+}
+
+impl CrashConfig {
+    pub fn from_env(service: &str, build: String) -> Self {
+        Self {
+            service: service.to_string(),
+            build,
+            report_dir: std::env::var("CRASH_REPORT_DIR")
+                .unwrap_or_else(|_| "./crash_reports".to_string())
+                .into(),
+            upload_url: std::env::var("CRASH_UPLOAD_URL").ok().filter(|url| !url.is_empty()),
+        }
+    }
+}
+
+/// `1.0.0+<빌드 해시>` 형식의 빌드 ID
+///
+/// 호출하는 크레이트에서 `build_id(env!("CARGO_PKG_VERSION"), option_env!("BUILD_HASH"))`로 사용합니다.
+pub fn build_id(version: &str, hash: Option<&str>) -> String {
+    format!("{}+{}", version, hash.unwrap_or("dev"))
+}
+
+/// 패닉 훅 설치 (프로세스당 한 번, 이후 호출은 무시)
+///
+/// 기존 훅(표준 에러 출력)은 리포트를 남긴 뒤 그대로 호출됩니다.
+pub fn install(config: CrashConfig) {
+    if INSTALLED.set(config).is_err() {
+        return;
+    }
+
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if let Some(config) = INSTALLED.get() {
+            handle_panic(config, info);
+        }
+        previous(info);
+    }));
+}
+
+/// 이름이 붙은 감시 태스크 실행
+///
+/// 패닉하면 크래시 리포트가 남고 `None`을 반환합니다.
+pub fn spawn_monitored<F>(name: &'static str, future: F) -> tokio::task::JoinHandle<Option<F::Output>>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(scoped(name, async move {
+        match AssertUnwindSafe(future).catch_unwind().await {
+            Ok(output) => Some(output),
+            Err(_) => {
+                error!(task = name, "태스크 패닉 - 크래시 리포트 기록됨");
+                None
+            }
+        }
+    }))
+}
+
+/// 퓨처 실행 중 발생한 패닉 리포트에 태스크 이름을 붙임
+pub async fn scoped<F: Future>(name: &'static str, future: F) -> F::Output {
+    TASK_NAME.scope(name, future).await
+}
+
+/// 크래시 리포트
+#[derive(Debug, Clone, Serialize)]
+pub struct CrashReport {
+    pub service: String,
+    pub build: String,
+    pub timestamp: String,
+    pub task: Option<String>,
+    pub thread: Option<String>,
+    pub message: String,
+    pub location: Option<String>,
+    pub backtrace: String,
+    pub recent_logs: Vec<String>,
+}
+
+impl CrashReport {
+    fn capture(config: &CrashConfig, info: &PanicHookInfo<'_>) -> Self {
+        let payload = info.payload();
+        let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "<non-string panic payload>".to_string());
+
+        Self {
+            service: config.service.clone(),
+            build: config.build.clone(),
+            timestamp: Utc::now().to_rfc3339(),
+            task: TASK_NAME.try_with(|name| name.to_string()).ok(),
+            thread: std::thread::current().name().map(str::to_string),
+            message,
+            location: info.location().map(|location| location.to_string()),
+            backtrace: Backtrace::force_capture().to_string(),
+            recent_logs: recent_logs(),
+        }
+    }
+
+    /// `<dir>/crash-<service>-<시각>.json`으로 저장
+    pub fn write_to(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!(
+            "crash-{}-{}-{}.json",
+            self.service,
+            Utc::now().format("%Y%m%dT%H%M%S%.3f"),
+            std::process::id()
+        ));
+        let json = serde_json::to_vec_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(&path, json)?;
+        Ok(path)
+    }
+}
+
+fn handle_panic(config: &CrashConfig, info: &PanicHookInfo<'_>) {
+    // 패닉 훅 안에서는 tracing 대신 표준 에러만 사용
+    let report = CrashReport::capture(config, info);
+    match report.write_to(&config.report_dir) {
+        Ok(path) => eprintln!("💥 크래시 리포트 저장: {}", path.display()),
+        Err(e) => eprintln!("크래시 리포트 저장 실패: {e}"),
+    }
+    if let Some(url) = &config.upload_url {
+        if let Err(e) = upload(url, &report) {
+            eprintln!("크래시 리포트 업로드 실패 ({url}): {e}");
+        }
+    }
+}
+
+/// 짧은 타임아웃으로 JSON POST (패닉 중이므로 런타임 없이 동기 전송)
+fn upload(url: &str, report: &CrashReport) -> std::io::Result<()> {
+    let invalid = |msg: &str| std::io::Error::new(std::io::ErrorKind::InvalidInput, msg.to_string());
+    let url = url::Url::parse(url).map_err(|e| invalid(&e.to_string()))?;
+    if url.scheme() != "http" {
+        return Err(invalid("only http:// endpoints are supported"));
+    }
+    let host = url.host_str().ok_or_else(|| invalid("missing host"))?;
+    let addr = (host, url.port_or_known_default().unwrap_or(80))
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| invalid("unresolvable host"))?;
+
+    let body = serde_json::to_vec(report).map_err(std::io::Error::other)?;
+    let mut stream = TcpStream::connect_timeout(&addr, UPLOAD_TIMEOUT)?;
+    stream.set_write_timeout(Some(UPLOAD_TIMEOUT))?;
+    stream.set_read_timeout(Some(UPLOAD_TIMEOUT))?;
+    write!(
+        stream,
+        "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        url.path(),
+        host,
+        body.len()
+    )?;
+    stream.write_all(&body)?;
+
+    let mut status = [0u8; 12];
+    stream.read_exact(&mut status)?;
+    match &status[9..10] {
+        b"2" => Ok(()),
+        _ => Err(std::io::Error::other(String::from_utf8_lossy(&status).trim().to_string())),
+    }
+}
+
+/// 최근 로그 스냅샷 (오래된 순)
+pub fn recent_logs() -> Vec<String> {
+    // 로그 기록 중 패닉한 경우 잠금이 잡혀 있을 수 있으므로 오래 기다리지 않음
+    RECENT_LOGS.try_lock_for(Duration::from_millis(50))
+        .map(|logs| logs.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// 최근 INFO 이상 로그를 크래시 리포트용 링 버퍼에 기록하는 레이어
+///
+/// 다른 레이어의 DEBUG 출력에는 영향을 주지 않도록 레이어별 필터로 제한합니다.
+pub fn recent_logs_layer<S>() -> Filtered<RecentLogsLayer, LevelFilter, S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    RecentLogsLayer.with_filter(LevelFilter::INFO)
+}
+
+/// [`recent_logs_layer`] 참조
+pub struct RecentLogsLayer;
+
+impl<S: Subscriber> Layer<S> for RecentLogsLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut line = format!(
+            "{} {} {}:",
+            Utc::now().format("%H:%M:%S%.3f"),
+            metadata.level(),
+            metadata.target()
+        );
+        event.record(&mut LineVisitor(&mut line));
+
+        let mut logs = RECENT_LOGS.lock();
+        if logs.len() >= RECENT_LOG_LINES {
+            logs.pop_front();
+        }
+        logs.push_back(line);
+    }
+}
+
+struct LineVisitor<'a>(&'a mut String);
+
+impl Visit for LineVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        let _ = match field.name() {
+            "message" => write!(self.0, " {:?}", value),
+            name => write!(self.0, " {}={:?}", name, value),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::prelude::*;
+
+    #[test]
+    fn test_recent_logs_ring() {
+        let subscriber = tracing_subscriber::registry().with(recent_logs_layer());
+        tracing::subscriber::with_default(subscriber, || {
+            for i in 0..RECENT_LOG_LINES + 5 {
+                tracing::info!(seq = i, "crash ring test");
+            }
+        });
+
+        let logs = recent_logs();
+        assert_eq!(logs.len(), RECENT_LOG_LINES);
+        assert!(logs.last().unwrap().contains("crash ring test seq=54"));
+    }
+
+    #[tokio::test]
+    async fn test_spawn_monitored_catches_panic() {
+        let handle = spawn_monitored("doomed", async { panic!("boom") });
+        assert_eq!(handle.await.unwrap(), None::<()>);
+
+        assert_eq!(spawn_monitored("fine", async { 7 }).await.unwrap(), Some(7));
+        assert_eq!(scoped("named", async { TASK_NAME.with(|name| *name) }).await, "named");
+    }
+
+    #[test]
+    fn test_report_written() {
+        let dir = tempfile::tempdir().unwrap();
+        let report = CrashReport {
+            service: "test".to_string(),
+            build: build_id("1.0.0", None),
+            timestamp: Utc::now().to_rfc3339(),
+            task: Some("tick".to_string()),
+            thread: None,
+            message: "boom".to_string(),
+            location: None,
+            backtrace: String::new(),
+            recent_logs: vec![],
+        };
+        let path = report.write_to(dir.path()).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap();
+        assert_eq!(json["build"], "1.0.0+dev");
+        assert_eq!(json["task"], "tick");
+    }
+}
@@ -0,0 +1,517 @@
+//! 런타임 헬스 모델
+//!
+//! 구성요소가 liveness/readiness 프로브와 의존 관계를 등록하면,
+//! 의존 그래프를 따라 준비 상태를 계산한 구조화된 리포트를 만듭니다.
+//! 예: `grpc`(readiness)가 `database`, `redis`에 의존하면 둘 중 하나라도 준비되지 않았을 때
+//! `grpc`도 준비 안 됨(`blocked`)으로 표시됩니다.
+//!
+//! `serve`로 `/healthz`(liveness), `/readyz`(readiness)를 HTTP로 제공합니다.
+//! 정상이면 200, 아니면 503과 함께 JSON 리포트를 응답합니다.
+//! `with_metrics`로 메트릭 렌더러를 연결하면 `/metrics`(Prometheus 텍스트)도 제공합니다.
+//! `with_telemetry`로 텔레메트리 수집기를 연결하면 `POST /telemetry`를 받고 수집 메트릭을 `/metrics`에 덧붙입니다.
+
+use futures::future::{join_all, BoxFuture};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+use tokio::time::timeout;
+use tracing::{debug, info, warn};
+
+use crate::monitoring::telemetry::{self, TelemetryIngest};
+
+/// 프로브 기본 타임아웃
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 프로브 결과 (실패 시 사유)
+pub type ProbeResult = Result<(), String>;
+
+/// 프로브 함수
+pub type Probe = Arc<dyn Fn() -> BoxFuture<'static, ProbeResult> + Send + Sync>;
+
+/// `/metrics` 본문 렌더러 (Prometheus 텍스트 형식)
+pub type MetricsRenderer = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// 프로브 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProbeKind {
+    /// 프로세스가 살아 있는지 (실패 시 재시작 대상)
+    Liveness,
+    /// 트래픽을 받을 준비가 됐는지 (실패 시 라우팅 제외)
+    Readiness,
+    /// 리포트에만 표시 (liveness/readiness 판정에 영향 없음)
+    Informational,
+}
+
+/// 구성요소 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentStatus {
+    Up,
+    Down,
+    /// 자체 프로브는 통과했지만 의존 구성요소가 준비되지 않음
+    Blocked,
+}
+
+/// 구성요소별 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentHealth {
+    pub name: String,
+    pub kind: ProbeKind,
+    pub status: ComponentStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// 준비되지 않은 의존 구성요소
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<String>,
+}
+
+/// 헬스 리포트
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub service: String,
+    pub live: bool,
+    pub ready: bool,
+    pub components: Vec<ComponentHealth>,
+    /// 확인 시각 (Unix 초)
+    pub checked_at: i64,
+}
+
+impl HealthReport {
+    /// 구성요소 조회
+    pub fn component(&self, name: &str) -> Option<&ComponentHealth> {
+        self.components.iter().find(|component| component.name == name)
+    }
+}
+
+struct Component {
+    name: String,
+    kind: ProbeKind,
+    depends_on: Vec<String>,
+    probe: Probe,
+}
+
+/// 헬스 레지스트리 (복제해서 공유)
+#[derive(Clone)]
+pub struct HealthRegistry {
+    service: String,
+    components: Arc<RwLock<Vec<Component>>>,
+    probe_timeout: Duration,
+    metrics: Option<MetricsRenderer>,
+    telemetry: Option<Arc<TelemetryIngest>>,
+}
+
+impl HealthRegistry {
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+            components: Arc::new(RwLock::new(Vec::new())),
+            probe_timeout: PROBE_TIMEOUT,
+            metrics: None,
+            telemetry: None,
+        }
+    }
+
+    pub fn with_probe_timeout(mut self, probe_timeout: Duration) -> Self {
+        self.probe_timeout = probe_timeout;
+        self
+    }
+
+    /// `/metrics` 엔드포인트 연결
+    pub fn with_metrics(mut self, render: impl Fn() -> String + Send + Sync + 'static) -> Self {
+        self.metrics = Some(Arc::new(render));
+        self
+    }
+
+    /// `POST /telemetry` 클라이언트 텔레메트리 수집 연결
+    pub fn with_telemetry(mut self, telemetry: Arc<TelemetryIngest>) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// 구성요소 등록 (같은 이름이면 교체)
+    pub fn register<F, Fut>(&self, name: impl Into<String>, kind: ProbeKind, depends_on: &[&str], probe: F) -> &Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ProbeResult> + Send + 'static,
+    {
+        let component = Component {
+            name: name.into(),
+            kind,
+            depends_on: depends_on.iter().map(|dep| dep.to_string()).collect(),
+            probe: Arc::new(move || Box::pin(probe())),
+        };
+        if let Ok(mut components) = self.components.write() {
+            components.retain(|existing| existing.name != component.name);
+            components.push(component);
+        }
+        self
+    }
+
+    /// 자체 프로브 없이 의존 구성요소로만 준비 상태가 정해지는 구성요소 등록
+    pub fn register_group(&self, name: impl Into<String>, depends_on: &[&str]) -> &Self {
+        self.register(name, ProbeKind::Readiness, depends_on, || async { Ok(()) })
+    }
+
+    /// 모든 프로브를 동시에 실행하고 의존 그래프로 상태 계산
+    pub async fn report(&self) -> HealthReport {
+        let snapshot: Vec<(String, ProbeKind, Vec<String>, Probe)> = match self.components.read() {
+            Ok(components) => components.iter()
+                .map(|c| (c.name.clone(), c.kind, c.depends_on.clone(), c.probe.clone()))
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        let probe_timeout = self.probe_timeout;
+        let results = join_all(snapshot.iter().map(|(_, _, _, probe)| {
+            let probe = probe.clone();
+            async move {
+                let started = Instant::now();
+                let result = match timeout(probe_timeout, probe()).await {
+                    Ok(result) => result,
+                    Err(_) => Err(format!("{}ms 안에 응답 없음", probe_timeout.as_millis())),
+                };
+                (result, started.elapsed().as_millis() as u64)
+            }
+        })).await;
+
+        let mut components: Vec<ComponentHealth> = snapshot.into_iter().zip(results)
+            .map(|((name, kind, depends_on, _), (result, latency_ms))| ComponentHealth {
+                name,
+                kind,
+                status: if result.is_ok() { ComponentStatus::Up } else { ComponentStatus::Down },
+                detail: result.err(),
+                latency_ms,
+                depends_on,
+                blocked_by: Vec::new(),
+            })
+            .collect();
+        resolve_dependencies(&mut components);
+
+        let all_up = |kind: ProbeKind| components.iter()
+            .filter(|component| component.kind == kind)
+            .all(|component| component.status == ComponentStatus::Up);
+        HealthReport {
+            service: self.service.clone(),
+            live: all_up(ProbeKind::Liveness),
+            ready: all_up(ProbeKind::Liveness) && all_up(ProbeKind::Readiness),
+            checked_at: chrono::Utc::now().timestamp(),
+            components,
+        }
+    }
+
+    /// `/healthz`, `/readyz` HTTP 엔드포인트 시작
+    pub async fn serve(&self, addr: SocketAddr) -> std::io::Result<JoinHandle<()>> {
+        let listener = TcpListener::bind(addr).await?;
+        info!("🩺 헬스 엔드포인트 시작: http://{}/healthz, /readyz", listener.local_addr()?);
+
+        let registry = self.clone();
+        Ok(tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        let registry = registry.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = registry.handle_http(stream).await {
+                                debug!("헬스 요청 처리 실패: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => warn!("헬스 엔드포인트 연결 승인 실패: {}", e),
+                }
+            }
+        }))
+    }
+
+    async fn handle_http(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        let request = timeout(Duration::from_secs(5), HttpRequest::read(&mut stream)).await
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
+        let (method, path) = (request.method.as_str(), request.path.as_str());
+
+        let mut content_type = "application/json";
+        let (status, body) = match (method, path) {
+            ("GET", "/healthz" | "/readyz") => {
+                let report = self.report().await;
+                let ok = if path == "/healthz" { report.live } else { report.ready };
+                let body = serde_json::to_string(&report).unwrap_or_else(|_| "{}".to_string());
+                (if ok { "200 OK" } else { "503 Service Unavailable" }, body)
+            }
+            ("GET", "/metrics") if self.metrics.is_some() || self.telemetry.is_some() => {
+                content_type = "text/plain; version=0.0.4";
+                let mut body = self.metrics.as_ref().map(|render| render()).unwrap_or_default();
+                if let Some(telemetry) = &self.telemetry {
+                    body.push_str(&telemetry.render_prometheus());
+                }
+                ("200 OK", body)
+            }
+            ("POST", "/telemetry") => match &self.telemetry {
+                Some(telemetry) => match telemetry.ingest(&request.body, request.header(telemetry::SIGNATURE_HEADER)) {
+                    Ok(()) => ("202 Accepted", r#"{"accepted":true}"#.to_string()),
+                    Err(rejection) => {
+                        debug!("텔레메트리 거부: {}", rejection);
+                        (rejection.http_status(), serde_json::json!({ "error": rejection.to_string() }).to_string())
+                    }
+                },
+                None => ("404 Not Found", r#"{"error":"not found"}"#.to_string()),
+            },
+            ("GET", _) => ("404 Not Found", r#"{"error":"not found"}"#.to_string()),
+            _ => ("405 Method Not Allowed", r#"{"error":"method not allowed"}"#.to_string()),
+        };
+
+        let response = format!(
+            "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+}
+
+/// 헬스 엔드포인트 HTTP 요청 (본문은 `telemetry::MAX_BODY_BYTES`까지)
+struct HttpRequest {
+    method: String,
+    path: String,
+    /// 소문자 헤더 이름 -> 값
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+impl HttpRequest {
+    async fn read(stream: &mut TcpStream) -> std::io::Result<Self> {
+        let too_large = || std::io::Error::new(std::io::ErrorKind::InvalidData, "요청이 너무 큼");
+        let mut buffer = Vec::with_capacity(1024);
+        let mut chunk = [0u8; 1024];
+        let header_end = loop {
+            if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+                break pos + 4;
+            }
+            if buffer.len() > 8 * 1024 {
+                return Err(too_large());
+            }
+            let read = stream.read(&mut chunk).await?;
+            if read == 0 {
+                // 헤더 끝 없이 닫힌 요청도 요청 줄만 있으면 처리
+                break buffer.len();
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+        };
+
+        let head = String::from_utf8_lossy(&buffer[..header_end]).into_owned();
+        let mut lines = head.lines();
+        let mut parts = lines.next().unwrap_or("").split_whitespace();
+        let method = parts.next().unwrap_or("").to_string();
+        let path = parts.next().unwrap_or("").split('?').next().unwrap_or("").to_string();
+        let headers: HashMap<String, String> = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+            .collect();
+
+        let content_length: usize = headers.get("content-length").and_then(|len| len.parse().ok()).unwrap_or(0);
+        if content_length > telemetry::MAX_BODY_BYTES {
+            return Err(too_large());
+        }
+        let mut body = buffer[header_end..].to_vec();
+        while body.len() < content_length {
+            let read = stream.read(&mut chunk).await?;
+            if read == 0 {
+                break;
+            }
+            body.extend_from_slice(&chunk[..read]);
+        }
+        body.truncate(content_length);
+
+        Ok(Self { method, path, headers, body })
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(String::as_str)
+    }
+}
+
+/// 의존 그래프를 따라 `Blocked` 상태 전파 (순환 의존은 Down 처리)
+fn resolve_dependencies(components: &mut [ComponentHealth]) {
+    let index: HashMap<String, usize> = components.iter().enumerate()
+        .map(|(i, component)| (component.name.clone(), i))
+        .collect();
+
+    // 0: 미확인, 1: 확인 중, 2: 완료
+    fn visit(i: usize, components: &mut [ComponentHealth], index: &HashMap<String, usize>, state: &mut [u8]) -> bool {
+        match state[i] {
+            2 => return components[i].status == ComponentStatus::Up,
+            1 => return false,
+            _ => {}
+        }
+        state[i] = 1;
+
+        let mut blocked_by = Vec::new();
+        for dep in components[i].depends_on.clone() {
+            let ready = match index.get(&dep) {
+                Some(&j) if state[j] == 1 => {
+                    components[i].status = ComponentStatus::Down;
+                    components[i].detail = Some(format!("순환 의존: {} ↔ {}", components[i].name, dep));
+                    false
+                }
+                Some(&j) => visit(j, components, index, state),
+                // 등록되지 않은 의존 구성요소는 준비 안 됨
+                None => false,
+            };
+            if !ready {
+                blocked_by.push(dep);
+            }
+        }
+
+        if !blocked_by.is_empty() && components[i].status == ComponentStatus::Up {
+            components[i].status = ComponentStatus::Blocked;
+        }
+        components[i].blocked_by = blocked_by;
+        state[i] = 2;
+        components[i].status == ComponentStatus::Up
+    }
+
+    let mut state = vec![0u8; components.len()];
+    for i in 0..components.len() {
+        visit(i, components, &index, &mut state);
+    }
+}
+
+/// 공통 프로브
+pub mod probes {
+    use super::ProbeResult;
+
+    /// Redis PING (`redis_host`, `redis_port` 환경변수)
+    pub async fn redis_ping() -> ProbeResult {
+        let host = std::env::var("redis_host").unwrap_or_else(|_| "localhost".to_string());
+        let port = std::env::var("redis_port").unwrap_or_else(|_| "6379".to_string());
+        let ping = async {
+            let client = redis::Client::open(format!("redis://{host}:{port}"))?;
+            let mut conn = client.get_multiplexed_tokio_connection().await?;
+            redis::cmd("PING").query_async::<_, String>(&mut conn).await
+        };
+        ping.await.map(|_| ()).map_err(|e| format!("{host}:{port} 연결 실패: {e}"))
+    }
+
+    /// DB `SELECT 1` (`db_*` 환경변수)
+    pub async fn database() -> ProbeResult {
+        let db = crate::config::db::DbConfig::new().await.map_err(|e| format!("연결 실패: {e}"))?;
+        let healthy = db.health_check().await;
+        db.close().await;
+        healthy.map(|_| ()).map_err(|e| format!("쿼리 실패: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dependency_propagation() {
+        let registry = HealthRegistry::new("grpc");
+        registry
+            .register("process", ProbeKind::Liveness, &[], || async { Ok(()) })
+            .register("redis", ProbeKind::Readiness, &[], || async { Ok(()) })
+            .register("database", ProbeKind::Readiness, &[], || async { Err("connection refused".to_string()) })
+            .register_group("grpc", &["database", "redis"]);
+
+        let report = registry.report().await;
+        assert!(report.live);
+        assert!(!report.ready);
+
+        let grpc = report.component("grpc").unwrap();
+        assert_eq!(grpc.status, ComponentStatus::Blocked);
+        assert_eq!(grpc.blocked_by, vec!["database".to_string()]);
+        assert_eq!(report.component("redis").unwrap().status, ComponentStatus::Up);
+        assert_eq!(report.component("database").unwrap().detail.as_deref(), Some("connection refused"));
+
+        // 표시용 구성요소는 준비 상태에 영향 없음
+        registry.register("database", ProbeKind::Informational, &[], || async { Err("down".to_string()) });
+        registry.register_group("grpc", &["redis"]);
+        assert!(registry.report().await.ready);
+    }
+
+    #[tokio::test]
+    async fn test_cycle_and_missing_dependency() {
+        let registry = HealthRegistry::new("test");
+        registry
+            .register_group("a", &["b"])
+            .register_group("b", &["a"])
+            .register_group("c", &["unknown"]);
+
+        let report = registry.report().await;
+        assert!(report.live);
+        assert!(!report.ready);
+        assert!(report.components.iter().all(|c| c.status != ComponentStatus::Up));
+        assert_eq!(report.component("c").unwrap().blocked_by, vec!["unknown".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_probe_timeout_and_http() {
+        let registry = HealthRegistry::new("test").with_probe_timeout(Duration::from_millis(50));
+        registry
+            .register("process", ProbeKind::Liveness, &[], || async { Ok(()) })
+            .register("slow", ProbeKind::Readiness, &[], || async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(())
+            });
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let handle = registry.serve(addr).await.unwrap();
+
+        let get = |path: &'static str| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream.write_all(format!("GET {path} HTTP/1.1\r\nHost: x\r\n\r\n").as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+
+        assert!(get("/healthz").await.starts_with("HTTP/1.1 200"));
+        let ready = get("/readyz").await;
+        assert!(ready.starts_with("HTTP/1.1 503"));
+        assert!(ready.contains(r#""status":"down""#));
+        assert!(get("/metrics").await.starts_with("HTTP/1.1 404"));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_telemetry_endpoint() {
+        use hmac::{Hmac, Mac};
+
+        let registry = HealthRegistry::new("test").with_telemetry(Arc::new(TelemetryIngest::new("secret")));
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        let handle = registry.serve(addr).await.unwrap();
+
+        let send = |request: String| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+        let body = format!(r#"{{"user_id":7,"region":"kr","timestamp":{},"rtt_ms":85}}"#, chrono::Utc::now().timestamp());
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(b"secret").unwrap();
+        mac.update(body.as_bytes());
+        let signature = hex::encode(mac.finalize().into_bytes());
+        let post = |signature: &str| format!(
+            "POST /telemetry HTTP/1.1\r\nHost: x\r\nX-Telemetry-Signature: {signature}\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+
+        assert!(send(post("00")).await.starts_with("HTTP/1.1 401"));
+        assert!(send(post(&signature)).await.starts_with("HTTP/1.1 202"));
+        let metrics = send("GET /metrics HTTP/1.1\r\nHost: x\r\n\r\n".to_string()).await;
+        assert!(metrics.contains(r#"client_rtt_ms_bucket{region="kr",le="120"} 1"#));
+        handle.abort();
+    }
+}
@@ -0,0 +1,24 @@
+//! 런타임 모니터링
+//!
+//! - `anomaly`: EWMA 기준선과 z-score로 p99 지연, 패킷 손실률, 에러율의 지속 이탈을 감지해 알림
+//! - `crash`: 패닉 훅, 감시 태스크, 크래시 리포트 저장/업로드
+//! - `health`: 의존 그래프 기반 liveness/readiness 헬스 모델과 `/healthz`, `/readyz` 엔드포인트
+//! - `sampler`: 특정 플레이어/IP만 상세 로그를 남기는 트레이싱 샘플러
+//! - `shutdown`: 단계별 정상 종료와 진행 중인 태스크 드레인, 종료 완료 보고
+//! - `task_accounting`: 서브시스템별 poll 시간/깨어남/대기열 깊이 집계
+//! - `telemetry`: 서명된 클라이언트 텔레메트리(FPS, 체감 RTT, 에러) 수집과 지역별 메트릭
+
+pub mod anomaly;
+pub mod crash;
+pub mod health;
+pub mod sampler;
+pub mod shutdown;
+pub mod task_accounting;
+pub mod telemetry;
+
+pub use anomaly::{ActiveAnomaly, AnomalyDetector, AnomalyEvent, AnomalyEventKind, AnomalyRule, AnomalySeverity};
+pub use health::{ComponentHealth, ComponentStatus, HealthRegistry, HealthReport, ProbeKind, ProbeResult};
+pub use sampler::{PlayerSampler, SampleTarget, SamplingRule, SamplingRules};
+pub use shutdown::{ShutdownCoordinator, ShutdownReport, StageReport};
+pub use task_accounting::{Subsystem, SubsystemUsage, TaskAccounting};
+pub use telemetry::{TelemetryIngest, TelemetryRejection, TelemetryReport};
@@ -0,0 +1,474 @@
+//! 플레이어 단위 트레이싱 샘플러
+//!
+//! 전체 DEBUG/TRACE 로그는 초당 수만 메시지에서 감당할 수 없으므로,
+//! 문제 플레이어(또는 IP)에 대한 규칙을 TTL과 함께 등록하면 해당 세션의 상세 로그만 남깁니다.
+//!
+//! `PlayerSampler`는 기존 레이어별 필터(`EnvFilter` 등)를 감싸서, 기본 필터를 통과한 로그에 더해
+//! 샘플링 대상 세션의 로그를 통과시킵니다. 스팬이나 이벤트의 `player_id`/`user_id` 필드, 또는 `ip`/`addr`/`peer`/`peer_addr`/`client_addr`
+//! 필드가 규칙과 일치하면 그 스팬과 하위 스팬/이벤트가 모두 기록됩니다.
+//!
+//! 규칙이 없을 때는 콜사이트 캐시가 꺼짐(never)으로 유지되어 비용이 없습니다.
+//!
+//! ```ignore
+//! let filter = PlayerSampler::new(EnvFilter::new("info"));
+//! tracing_subscriber::registry().with(fmt::layer().with_filter(filter)).init();
+//!
+//! SamplingRules::global().add("player:1234".parse()?, Duration::from_secs(600));
+//! ```
+
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::Interest;
+use tracing::{Event, Metadata, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, Filter};
+use tracing_subscriber::registry::LookupSpan;
+
+/// 플레이어 ID로 인식하는 필드
+const PLAYER_FIELDS: &[&str] = &["player_id", "user_id"];
+/// 주소로 인식하는 필드
+const ADDR_FIELDS: &[&str] = &["ip", "addr", "peer", "peer_addr", "client_addr"];
+
+/// 규칙 최대 TTL
+pub const MAX_RULE_TTL: Duration = Duration::from_secs(24 * 3600);
+
+/// 샘플링 대상
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleTarget {
+    Player(u64),
+    /// IP 대역 (단일 IP는 전체 길이 접두사)
+    Ip { network: IpAddr, prefix_len: u8 },
+}
+
+impl SampleTarget {
+    fn matches_player(&self, player_id: u64) -> bool {
+        matches!(self, SampleTarget::Player(id) if *id == player_id)
+    }
+
+    fn matches_ip(&self, ip: IpAddr) -> bool {
+        let SampleTarget::Ip { network, prefix_len } = *self else {
+            return false;
+        };
+        match (network, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => prefix_eq(&net.octets(), &ip.octets(), prefix_len),
+            (IpAddr::V6(net), IpAddr::V6(ip)) => prefix_eq(&net.octets(), &ip.octets(), prefix_len),
+            _ => false,
+        }
+    }
+}
+
+fn prefix_eq(a: &[u8], b: &[u8], prefix_len: u8) -> bool {
+    let full = (prefix_len / 8) as usize;
+    let rest = prefix_len % 8;
+    if a[..full] != b[..full] {
+        return false;
+    }
+    rest == 0 || {
+        let mask = 0xFFu8 << (8 - rest);
+        a[full] & mask == b[full] & mask
+    }
+}
+
+/// `player:<id>`, `ip:<addr>`, `ip:<addr>/<prefix>` 형식
+impl FromStr for SampleTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, value) = s.split_once(':').ok_or_else(|| format!("expected player:<id> or ip:<addr>[/prefix], got {s}"))?;
+        match kind.to_ascii_lowercase().as_str() {
+            "player" => value.parse().map(SampleTarget::Player).map_err(|_| format!("invalid player id: {value}")),
+            "ip" => {
+                let (addr, prefix) = match value.split_once('/') {
+                    Some((addr, prefix)) => (addr, Some(prefix)),
+                    None => (value, None),
+                };
+                let network: IpAddr = addr.parse().map_err(|_| format!("invalid ip: {addr}"))?;
+                let max = if network.is_ipv4() { 32 } else { 128 };
+                let prefix_len = match prefix {
+                    Some(prefix) => prefix.parse().ok().filter(|p| *p <= max).ok_or_else(|| format!("invalid prefix: {prefix}"))?,
+                    None => max,
+                };
+                Ok(SampleTarget::Ip { network, prefix_len })
+            }
+            _ => Err(format!("unknown target kind: {kind}")),
+        }
+    }
+}
+
+impl fmt::Display for SampleTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SampleTarget::Player(id) => write!(f, "player:{id}"),
+            SampleTarget::Ip { network, prefix_len } if (network.is_ipv4() && *prefix_len == 32) || *prefix_len == 128 => {
+                write!(f, "ip:{network}")
+            }
+            SampleTarget::Ip { network, prefix_len } => write!(f, "ip:{network}/{prefix_len}"),
+        }
+    }
+}
+
+/// 샘플링 규칙
+#[derive(Debug, Clone)]
+pub struct SamplingRule {
+    pub id: u64,
+    pub target: SampleTarget,
+    pub expires_at: Instant,
+}
+
+impl SamplingRule {
+    /// 남은 시간
+    pub fn remaining(&self) -> Duration {
+        self.expires_at.saturating_duration_since(Instant::now())
+    }
+}
+
+/// 샘플링 규칙 저장소
+pub struct SamplingRules {
+    rules: RwLock<Vec<SamplingRule>>,
+    /// 규칙 존재 여부 (핫패스용)
+    active: AtomicBool,
+    next_id: AtomicU64,
+}
+
+impl Default for SamplingRules {
+    fn default() -> Self {
+        Self {
+            rules: RwLock::new(Vec::new()),
+            active: AtomicBool::new(false),
+            next_id: AtomicU64::new(1),
+        }
+    }
+}
+
+impl SamplingRules {
+    /// 프로세스 전역 저장소 (관리 API와 필터가 공유)
+    pub fn global() -> &'static SamplingRules {
+        static GLOBAL: OnceLock<SamplingRules> = OnceLock::new();
+        GLOBAL.get_or_init(SamplingRules::default)
+    }
+
+    /// 규칙 추가 (TTL은 최대 24시간), 규칙 ID 반환
+    pub fn add(&self, target: SampleTarget, ttl: Duration) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let rule = SamplingRule { id, target, expires_at: Instant::now() + ttl.min(MAX_RULE_TTL) };
+        if let Ok(mut rules) = self.rules.write() {
+            rules.push(rule);
+        }
+        self.refresh();
+        id
+    }
+
+    /// 규칙 제거
+    pub fn remove(&self, id: u64) -> bool {
+        let removed = match self.rules.write() {
+            Ok(mut rules) => {
+                let before = rules.len();
+                rules.retain(|rule| rule.id != id);
+                rules.len() != before
+            }
+            Err(_) => false,
+        };
+        self.refresh();
+        removed
+    }
+
+    /// 모든 규칙 제거
+    pub fn clear(&self) {
+        if let Ok(mut rules) = self.rules.write() {
+            rules.clear();
+        }
+        self.refresh();
+    }
+
+    /// 유효한 규칙 목록 (만료된 규칙은 정리)
+    pub fn list(&self) -> Vec<SamplingRule> {
+        self.refresh();
+        self.rules.read().map(|rules| rules.clone()).unwrap_or_default()
+    }
+
+    /// 규칙이 하나라도 있는지
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    pub fn matches_player(&self, player_id: u64) -> bool {
+        self.matches(|target| target.matches_player(player_id))
+    }
+
+    pub fn matches_ip(&self, ip: IpAddr) -> bool {
+        self.matches(|target| target.matches_ip(ip))
+    }
+
+    fn matches(&self, predicate: impl Fn(&SampleTarget) -> bool) -> bool {
+        if !self.is_active() {
+            return false;
+        }
+        let now = Instant::now();
+        self.rules.read()
+            .map(|rules| rules.iter().any(|rule| rule.expires_at > now && predicate(&rule.target)))
+            .unwrap_or(false)
+    }
+
+    /// 만료 규칙 정리 후 활성 상태 갱신 (바뀌면 콜사이트 캐시 재계산)
+    fn refresh(&self) {
+        let now = Instant::now();
+        let active = match self.rules.write() {
+            Ok(mut rules) => {
+                rules.retain(|rule| rule.expires_at > now);
+                !rules.is_empty()
+            }
+            Err(_) => false,
+        };
+        if self.active.swap(active, Ordering::Relaxed) != active {
+            tracing::callsite::rebuild_interest_cache();
+        }
+    }
+}
+
+/// 샘플링 대상 스팬 표시 (스팬 확장 데이터)
+struct Sampled;
+
+/// 필드에서 샘플링 대상 여부 확인
+struct MatchVisitor<'a> {
+    rules: &'a SamplingRules,
+    matched: bool,
+}
+
+impl MatchVisitor<'_> {
+    fn check_addr(&mut self, value: &str) {
+        let value = value.trim_matches('"');
+        let ip = value.parse::<IpAddr>().ok().or_else(|| value.parse::<SocketAddr>().ok().map(|addr| addr.ip()));
+        if let Some(ip) = ip {
+            self.matched |= self.rules.matches_ip(ip);
+        }
+    }
+}
+
+impl Visit for MatchVisitor<'_> {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if PLAYER_FIELDS.contains(&field.name()) {
+            self.matched |= self.rules.matches_player(value);
+        }
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        if let (true, Ok(value)) = (PLAYER_FIELDS.contains(&field.name()), u64::try_from(value)) {
+            self.matched |= self.rules.matches_player(value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if PLAYER_FIELDS.contains(&field.name()) {
+            if let Ok(id) = value.parse() {
+                self.matched |= self.rules.matches_player(id);
+            }
+        } else if ADDR_FIELDS.contains(&field.name()) {
+            self.check_addr(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if PLAYER_FIELDS.contains(&field.name()) || ADDR_FIELDS.contains(&field.name()) {
+            self.record_str(field, &format!("{value:?}"));
+        }
+    }
+}
+
+/// 샘플링 규칙 기반 레이어별 필터 (기본 필터 `F`를 감쌈)
+pub struct PlayerSampler<F> {
+    base: F,
+    rules: &'static SamplingRules,
+}
+
+impl<F> PlayerSampler<F> {
+    /// 전역 규칙 저장소를 사용하는 필터
+    pub fn new(base: F) -> Self {
+        Self::with_rules(base, SamplingRules::global())
+    }
+
+    pub fn with_rules(base: F, rules: &'static SamplingRules) -> Self {
+        Self { base, rules }
+    }
+
+    fn mark_if_matched<S>(&self, id: &Id, visit: impl FnOnce(&mut MatchVisitor<'_>), ctx: &Context<'_, S>)
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let mut visitor = MatchVisitor { rules: self.rules, matched: false };
+        visit(&mut visitor);
+        if visitor.matched {
+            if let Some(span) = ctx.span(id) {
+                let mut extensions = span.extensions_mut();
+                if extensions.get_mut::<Sampled>().is_none() {
+                    extensions.insert(Sampled);
+                }
+            }
+        }
+    }
+}
+
+/// 현재 스팬 또는 상위 스팬이 샘플링 대상인지
+fn in_sampled_scope<S>(ctx: &Context<'_, S>) -> bool
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    ctx.lookup_current()
+        .map(|span| span.scope().any(|span| span.extensions().get::<Sampled>().is_some()))
+        .unwrap_or(false)
+}
+
+impl<S, F> Filter<S> for PlayerSampler<F>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    F: Filter<S>,
+{
+    fn enabled(&self, meta: &Metadata<'_>, ctx: &Context<'_, S>) -> bool {
+        // 규칙이 있으면 스팬 필드를 봐야 하므로 일단 허용하고, 이벤트는 event_enabled에서 판단
+        self.base.enabled(meta, ctx) || self.rules.is_active()
+    }
+
+    fn callsite_enabled(&self, meta: &'static Metadata<'static>) -> Interest {
+        let interest = self.base.callsite_enabled(meta);
+        if self.rules.is_active() && interest.is_never() { Interest::sometimes() } else { interest }
+    }
+
+    fn event_enabled(&self, event: &Event<'_>, ctx: &Context<'_, S>) -> bool {
+        if self.base.enabled(event.metadata(), ctx) && self.base.event_enabled(event, ctx) {
+            return true;
+        }
+        if !self.rules.is_active() {
+            return false;
+        }
+        if in_sampled_scope(ctx) {
+            return true;
+        }
+        let mut visitor = MatchVisitor { rules: self.rules, matched: false };
+        event.record(&mut visitor);
+        visitor.matched
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        if self.rules.is_active() { Some(LevelFilter::TRACE) } else { self.base.max_level_hint() }
+    }
+
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        self.base.on_new_span(attrs, id, ctx.clone());
+        let parent_sampled = ctx.span(id)
+            .and_then(|span| span.parent())
+            .map(|parent| parent.extensions().get::<Sampled>().is_some())
+            .unwrap_or(false);
+        if parent_sampled {
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(Sampled);
+            }
+            return;
+        }
+        self.mark_if_matched(id, |visitor| attrs.record(visitor), &ctx);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        self.base.on_record(id, values, ctx.clone());
+        self.mark_if_matched(id, |visitor| values.record(visitor), &ctx);
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        self.base.on_enter(id, ctx);
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        self.base.on_exit(id, ctx);
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        self.base.on_close(id, ctx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::filter::Targets;
+    use tracing_subscriber::prelude::*;
+
+    #[test]
+    fn test_target_parsing_and_matching() {
+        let cidr: SampleTarget = "ip:10.1.0.0/16".parse().unwrap();
+        assert!(cidr.matches_ip("10.1.200.3".parse().unwrap()));
+        assert!(!cidr.matches_ip("10.2.0.1".parse().unwrap()));
+        assert_eq!(cidr.to_string(), "ip:10.1.0.0/16");
+        assert_eq!("ip:::1".parse::<SampleTarget>().unwrap().to_string(), "ip:::1");
+        assert_eq!("player:42".parse::<SampleTarget>().unwrap(), SampleTarget::Player(42));
+        assert!("ip:10.0.0.0/33".parse::<SampleTarget>().is_err());
+        assert!("session:1".parse::<SampleTarget>().is_err());
+    }
+
+    #[test]
+    fn test_rule_expiry() {
+        let rules = SamplingRules::default();
+        let id = rules.add(SampleTarget::Player(7), Duration::from_secs(60));
+        rules.add(SampleTarget::Player(8), Duration::ZERO);
+        assert!(rules.matches_player(7));
+        assert!(!rules.matches_player(8));
+        assert_eq!(rules.list().len(), 1);
+        assert!(rules.remove(id));
+        assert!(!rules.is_active());
+    }
+
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_only_targeted_sessions_logged() {
+        static RULES: OnceLock<SamplingRules> = OnceLock::new();
+        let rules = RULES.get_or_init(SamplingRules::default);
+        let capture = Capture::default();
+        let writer = capture.clone();
+
+        let filter = PlayerSampler::with_rules(Targets::new().with_default(LevelFilter::INFO), rules);
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(move || writer.clone())
+                .with_filter(filter),
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!(player_id = 1u64, "before rule");
+            rules.add(SampleTarget::Player(1), Duration::from_secs(60));
+            rules.add("ip:192.168.0.0/24".parse().unwrap(), Duration::from_secs(60));
+
+            let span = tracing::info_span!("session", player_id = 1u64);
+            span.in_scope(|| tracing::debug!("inside targeted session"));
+            tracing::info_span!("session", player_id = 2u64).in_scope(|| tracing::debug!("other session"));
+            tracing::trace!(addr = %"192.168.0.9:5000", "targeted peer");
+            tracing::info!("regular info");
+            rules.clear();
+        });
+
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert!(!output.contains("before rule"));
+        assert!(output.contains("inside targeted session"));
+        assert!(!output.contains("other session"));
+        assert!(output.contains("targeted peer"));
+        assert!(output.contains("regular info"));
+    }
+}
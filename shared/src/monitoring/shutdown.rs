@@ -0,0 +1,282 @@
+//! 정상 종료 조율
+//!
+//! 종료가 시작되면 새 작업을 받지 않고, 서버가 정한 순서대로 단계(라우팅 해제 → 연결 드레인 →
+//! 백그라운드 서비스 중지)를 실행한 뒤 진행 중인 태스크가 끝나기를 기다립니다.
+//! 제한 시간 안에 끝나지 않은 태스크는 취소하고 보고서에 남깁니다.
+//!
+//! 상위 프로세스(gamecenter)는 하위 서버 태스크를 바로 취소하지 않고 [`ShutdownCoordinator::trigger`] 후
+//! [`ShutdownCoordinator::wait_finished`]로 종료 완료를 확인한 다음 프로세스를 끝냅니다.
+//!
+//! ```ignore
+//! let shutdown = ShutdownCoordinator::new("tcpserver");
+//! shutdown.spawn("connection", handle_connection(stream));
+//!
+//! shutdown.triggered().await;
+//! shutdown.stage("deregister", Duration::from_secs(2), reporter.deregister()).await;
+//! shutdown.stage("drain_writers", Duration::from_secs(5), connections.drain_writers()).await;
+//! shutdown.drain(Duration::from_secs(10)).await;
+//! let report = shutdown.finish();
+//! ```
+
+use crate::monitoring::crash;
+use crate::monitoring::health::ProbeResult;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{watch, Notify};
+use tokio::task::{AbortHandle, JoinHandle};
+use tracing::{info, warn};
+
+/// 종료 단계 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageReport {
+    pub name: &'static str,
+    pub elapsed: Duration,
+    /// 제한 시간을 넘겨 중단됨
+    pub timed_out: bool,
+}
+
+/// 종료 완료 보고서
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShutdownReport {
+    pub service: &'static str,
+    pub stages: Vec<StageReport>,
+    /// 제한 시간 안에 끝나지 않아 취소한 태스크 수
+    pub aborted_tasks: usize,
+    /// 종료 시작부터 완료까지 걸린 시간
+    pub elapsed: Duration,
+}
+
+impl ShutdownReport {
+    /// 모든 단계가 시간 안에 끝나고 취소한 태스크가 없음
+    pub fn is_clean(&self) -> bool {
+        self.aborted_tasks == 0 && self.stages.iter().all(|stage| !stage.timed_out)
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    service: &'static str,
+    triggered: watch::Sender<bool>,
+    finished: watch::Sender<Option<ShutdownReport>>,
+    started: Mutex<Option<Instant>>,
+    tasks: Mutex<HashMap<u64, AbortHandle>>,
+    next_task: AtomicU64,
+    idle: Notify,
+    stages: Mutex<Vec<StageReport>>,
+    aborted: AtomicU64,
+}
+
+/// 정상 종료 조율기 (복제해서 공유)
+#[derive(Debug, Clone)]
+pub struct ShutdownCoordinator {
+    inner: Arc<Inner>,
+}
+
+/// 추적 중인 태스크가 끝나면 목록에서 제거
+struct TaskGuard {
+    inner: Arc<Inner>,
+    id: u64,
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        let mut tasks = self.inner.tasks.lock();
+        tasks.remove(&self.id);
+        if tasks.is_empty() {
+            self.inner.idle.notify_waiters();
+        }
+    }
+}
+
+impl ShutdownCoordinator {
+    pub fn new(service: &'static str) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                service,
+                triggered: watch::channel(false).0,
+                finished: watch::channel(None).0,
+                started: Mutex::new(None),
+                tasks: Mutex::new(HashMap::new()),
+                next_task: AtomicU64::new(0),
+                idle: Notify::new(),
+                stages: Mutex::new(Vec::new()),
+                aborted: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    pub fn service(&self) -> &'static str {
+        self.inner.service
+    }
+
+    /// 종료 시작 (처음 호출했을 때만 true)
+    pub fn trigger(&self) -> bool {
+        let first = self.inner.triggered.send_if_modified(|triggered| !std::mem::replace(triggered, true));
+        if first {
+            *self.inner.started.lock() = Some(Instant::now());
+            info!(service = self.inner.service, in_flight = self.in_flight(), "🛑 정상 종료 시작");
+        }
+        first
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        *self.inner.triggered.borrow()
+    }
+
+    /// 종료가 시작될 때까지 대기
+    pub async fn triggered(&self) {
+        let mut rx = self.inner.triggered.subscribe();
+        // 송신자는 `self`가 들고 있으므로 닫히지 않음
+        let _ = rx.wait_for(|triggered| *triggered).await;
+    }
+
+    /// 준비 상태 프로브 (종료 중이면 실패해 로드밸런서에서 빠짐)
+    pub fn readiness(&self) -> ProbeResult {
+        if self.is_shutting_down() {
+            Err(format!("{} 종료 중", self.inner.service))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 추적 태스크 시작 (종료 중이면 시작하지 않고 None)
+    ///
+    /// 태스크는 [`crash::spawn_monitored`]로 실행되며, [`drain`](Self::drain)이 끝나기를 기다립니다.
+    pub fn spawn<F>(&self, name: &'static str, future: F) -> Option<JoinHandle<Option<F::Output>>>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        if self.is_shutting_down() {
+            return None;
+        }
+        let id = self.inner.next_task.fetch_add(1, Ordering::Relaxed);
+        let guard = TaskGuard { inner: self.inner.clone(), id };
+        // 태스크가 등록 전에 끝나도 가드가 목록 잠금을 기다리도록 잠근 채로 시작
+        let mut tasks = self.inner.tasks.lock();
+        let handle = crash::spawn_monitored(name, async move {
+            let _guard = guard;
+            future.await
+        });
+        tasks.insert(id, handle.abort_handle());
+        Some(handle)
+    }
+
+    /// 진행 중인 추적 태스크 수
+    pub fn in_flight(&self) -> usize {
+        self.inner.tasks.lock().len()
+    }
+
+    /// 종료 단계 실행 (제한 시간을 넘기면 중단하고 None)
+    pub async fn stage<F: Future>(&self, name: &'static str, limit: Duration, stage: F) -> Option<F::Output> {
+        let started = Instant::now();
+        let output = tokio::time::timeout(limit, stage).await.ok();
+        let report = StageReport { name, elapsed: started.elapsed(), timed_out: output.is_none() };
+        if report.timed_out {
+            warn!(service = self.inner.service, stage = name, limit_ms = limit.as_millis() as u64, "종료 단계 제한 시간 초과");
+        } else {
+            info!(service = self.inner.service, stage = name, elapsed_ms = report.elapsed.as_millis() as u64, "종료 단계 완료");
+        }
+        self.inner.stages.lock().push(report);
+        output
+    }
+
+    /// 추적 태스크가 모두 끝나기를 기다림 (제한 시간이 지나면 남은 태스크를 취소하고 그 수를 반환)
+    pub async fn drain(&self, limit: Duration) -> usize {
+        let wait_idle = async {
+            loop {
+                let notified = self.inner.idle.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+                if self.in_flight() == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        if self.stage("drain_tasks", limit, wait_idle).await.is_some() {
+            return 0;
+        }
+
+        let remaining: Vec<AbortHandle> = self.inner.tasks.lock().drain().map(|(_, handle)| handle).collect();
+        remaining.iter().for_each(AbortHandle::abort);
+        self.inner.aborted.fetch_add(remaining.len() as u64, Ordering::Relaxed);
+        warn!(service = self.inner.service, aborted = remaining.len(), "종료 대기 시간 초과로 태스크 취소");
+        remaining.len()
+    }
+
+    /// 종료 완료 확인 (대기 중인 상위 프로세스에 보고서 전달)
+    pub fn finish(&self) -> ShutdownReport {
+        self.trigger();
+        let started = self.inner.started.lock().unwrap_or_else(Instant::now);
+        let report = ShutdownReport {
+            service: self.inner.service,
+            stages: self.inner.stages.lock().clone(),
+            aborted_tasks: self.inner.aborted.load(Ordering::Relaxed) as usize,
+            elapsed: started.elapsed(),
+        };
+        info!(
+            service = report.service,
+            clean = report.is_clean(),
+            elapsed_ms = report.elapsed.as_millis() as u64,
+            "✅ 정상 종료 완료"
+        );
+        self.inner.finished.send_replace(Some(report.clone()));
+        report
+    }
+
+    /// 종료 완료 보고서 대기
+    pub async fn wait_finished(&self) -> ShutdownReport {
+        let mut rx = self.inner.finished.subscribe();
+        loop {
+            if let Some(report) = rx.borrow_and_update().clone() {
+                return report;
+            }
+            // 송신자는 `self`가 들고 있으므로 닫히지 않음
+            let _ = rx.changed().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_stages_and_drain() {
+        let shutdown = ShutdownCoordinator::new("test");
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        shutdown.spawn("finishes", async move {
+            let _ = released.await;
+        });
+        shutdown.spawn("stuck", std::future::pending::<()>());
+        assert_eq!(shutdown.in_flight(), 2);
+        assert!(shutdown.readiness().is_ok());
+
+        let waiter = {
+            let shutdown = shutdown.clone();
+            tokio::spawn(async move { shutdown.wait_finished().await })
+        };
+
+        assert!(shutdown.trigger());
+        assert!(!shutdown.trigger());
+        assert!(shutdown.readiness().is_err());
+        assert!(shutdown.spawn("late", async {}).is_none());
+
+        assert_eq!(shutdown.stage("deregister", Duration::from_secs(1), async { 7 }).await, Some(7));
+        assert_eq!(shutdown.stage("slow", Duration::from_millis(10), std::future::pending::<()>()).await, None);
+        let _ = release.send(());
+        assert_eq!(shutdown.drain(Duration::from_millis(50)).await, 1);
+        assert_eq!(shutdown.in_flight(), 0);
+
+        let report = shutdown.finish();
+        assert_eq!(waiter.await.unwrap(), report);
+        assert_eq!(report.aborted_tasks, 1);
+        assert_eq!(report.stages.iter().map(|stage| stage.name).collect::<Vec<_>>(), ["deregister", "slow", "drain_tasks"]);
+        assert!(!report.is_clean());
+    }
+}
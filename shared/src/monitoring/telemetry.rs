@@ -0,0 +1,422 @@
+//! 클라이언트 텔레메트리 수집
+//!
+//! 클라이언트가 보고한 FPS, 체감 RTT, 에러 이벤트를 받아 검증한 뒤
+//! 지역(`region`) 라벨을 붙인 Prometheus 메트릭으로 집계합니다.
+//! 서버 측 메트릭에는 드러나지 않는 클라이언트 체감 지연을 보기 위한 용도입니다.
+//!
+//! - 서명: 본문의 HMAC-SHA256 (`x-telemetry-signature` 헤더, hex), 키는 `TELEMETRY_SIGNING_KEY`
+//! - 재전송 방지: 보고 시각(`timestamp`)이 서버 시각과 5분 이상 차이 나면 거부
+//! - 요청 한도: 사용자별 분당 보고 수 (`TELEMETRY_RATE_PER_MINUTE`, 기본 6)
+//! - 라벨 수 제한: `TELEMETRY_REGIONS`에 없는 지역과 상한을 넘는 에러 코드는 `other`로 집계
+//!
+//! HTTP로는 헬스 엔드포인트의 `POST /telemetry`로 받습니다 (`HealthRegistry::with_telemetry`).
+
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 서명 헤더
+pub const SIGNATURE_HEADER: &str = "x-telemetry-signature";
+
+/// 보고 본문 최대 크기 (바이트)
+pub const MAX_BODY_BYTES: usize = 16 * 1024;
+
+/// 보고 시각 허용 오차
+const MAX_CLOCK_SKEW_SECS: i64 = 300;
+
+/// 보고 하나에 담을 수 있는 에러 이벤트 종류 수
+const MAX_ERROR_EVENTS: usize = 20;
+
+/// 에러 코드 라벨 상한 (넘으면 `other`)
+const MAX_ERROR_CODES: usize = 64;
+
+/// 요청 한도 창 크기
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// 요청 한도 창 정리를 시작하는 항목 수
+const RATE_PRUNE_THRESHOLD: usize = 10_000;
+
+const DEFAULT_REGIONS: &[&str] = &["kr", "jp", "tw", "sea", "us", "eu"];
+const FPS_BUCKETS: &[f64] = &[15.0, 30.0, 45.0, 60.0, 90.0, 120.0, 144.0, 240.0];
+const RTT_BUCKETS_MS: &[f64] = &[20.0, 50.0, 80.0, 120.0, 200.0, 300.0, 500.0, 1000.0];
+
+/// 클라이언트 텔레메트리 보고
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TelemetryReport {
+    pub user_id: u32,
+    /// 접속 지역 (`kr`, `us` 등)
+    pub region: String,
+    /// 보고 시각 (Unix 초)
+    pub timestamp: i64,
+    /// 보고 구간 평균 FPS
+    pub fps: Option<f64>,
+    /// 체감 왕복 지연 (ms)
+    pub rtt_ms: Option<f64>,
+    /// 보고 구간에 발생한 에러 이벤트
+    #[serde(default)]
+    pub errors: Vec<ClientErrorEvent>,
+}
+
+/// 클라이언트 에러 이벤트 (코드별 발생 횟수)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientErrorEvent {
+    pub code: String,
+    #[serde(default = "default_count")]
+    pub count: u32,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+/// 보고 거부 사유
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TelemetryRejection {
+    #[error("invalid signature")]
+    BadSignature,
+    #[error("report timestamp out of range")]
+    Stale,
+    #[error("rate limit exceeded")]
+    RateLimited,
+    #[error("invalid report: {0}")]
+    Invalid(String),
+}
+
+impl TelemetryRejection {
+    /// 메트릭 라벨
+    pub fn reason(&self) -> &'static str {
+        match self {
+            TelemetryRejection::BadSignature => "signature",
+            TelemetryRejection::Stale => "stale",
+            TelemetryRejection::RateLimited => "rate_limited",
+            TelemetryRejection::Invalid(_) => "invalid",
+        }
+    }
+
+    /// HTTP 상태 줄
+    pub fn http_status(&self) -> &'static str {
+        match self {
+            TelemetryRejection::BadSignature => "401 Unauthorized",
+            TelemetryRejection::RateLimited => "429 Too Many Requests",
+            TelemetryRejection::Stale | TelemetryRejection::Invalid(_) => "400 Bad Request",
+        }
+    }
+}
+
+/// 누적 히스토그램 (Prometheus `le` 버킷)
+#[derive(Debug, Clone)]
+struct Histogram {
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(buckets: &[f64]) -> Self {
+        Self { counts: vec![0; buckets.len()], sum: 0.0, count: 0 }
+    }
+
+    fn observe(&mut self, buckets: &[f64], value: f64) {
+        for (bucket, count) in buckets.iter().zip(self.counts.iter_mut()) {
+            if value <= *bucket {
+                *count += 1;
+            }
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn render(&self, output: &mut String, name: &str, region: &str, buckets: &[f64]) {
+        for (bucket, count) in buckets.iter().zip(&self.counts) {
+            let _ = writeln!(output, "{name}_bucket{{region=\"{region}\",le=\"{bucket}\"}} {count}");
+        }
+        let _ = writeln!(output, "{name}_bucket{{region=\"{region}\",le=\"+Inf\"}} {}", self.count);
+        let _ = writeln!(output, "{name}_sum{{region=\"{region}\"}} {}", self.sum);
+        let _ = writeln!(output, "{name}_count{{region=\"{region}\"}} {}", self.count);
+    }
+}
+
+#[derive(Default)]
+struct Metrics {
+    reports: BTreeMap<String, u64>,
+    fps: BTreeMap<String, Histogram>,
+    rtt: BTreeMap<String, Histogram>,
+    /// (지역, 에러 코드) -> 횟수
+    errors: BTreeMap<(String, String), u64>,
+    error_codes: HashSet<String>,
+    rejected: BTreeMap<&'static str, u64>,
+}
+
+/// 텔레메트리 수집기 (서버 전역에서 공유)
+pub struct TelemetryIngest {
+    signing_key: Vec<u8>,
+    regions: HashSet<String>,
+    rate_per_minute: u32,
+    /// user_id -> (창 시작, 보고 수)
+    windows: Mutex<HashMap<u32, (Instant, u32)>>,
+    metrics: Mutex<Metrics>,
+}
+
+impl TelemetryIngest {
+    pub fn new(signing_key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            signing_key: signing_key.into(),
+            regions: DEFAULT_REGIONS.iter().map(|region| region.to_string()).collect(),
+            rate_per_minute: 6,
+            windows: Mutex::new(HashMap::new()),
+            metrics: Mutex::new(Metrics::default()),
+        }
+    }
+
+    /// 환경변수로 생성 (`TELEMETRY_SIGNING_KEY`가 없으면 `None`)
+    pub fn from_env() -> Option<Self> {
+        let key = std::env::var("TELEMETRY_SIGNING_KEY").ok().filter(|key| !key.is_empty())?;
+        let mut ingest = Self::new(key);
+        if let Ok(regions) = std::env::var("TELEMETRY_REGIONS") {
+            ingest = ingest.with_regions(regions.split(',').map(str::trim).filter(|r| !r.is_empty()));
+        }
+        if let Some(rate) = std::env::var("TELEMETRY_RATE_PER_MINUTE").ok().and_then(|v| v.parse().ok()) {
+            ingest = ingest.with_rate_per_minute(rate);
+        }
+        Some(ingest)
+    }
+
+    /// 라벨로 사용할 지역 목록 (목록 밖의 지역은 `other`)
+    pub fn with_regions<I, S>(mut self, regions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.regions = regions.into_iter().map(|region| region.into().to_lowercase()).collect();
+        self
+    }
+
+    /// 사용자별 분당 보고 한도
+    pub fn with_rate_per_minute(mut self, rate_per_minute: u32) -> Self {
+        self.rate_per_minute = rate_per_minute.max(1);
+        self
+    }
+
+    /// 서명된 보고 본문을 검증하고 메트릭에 반영합니다.
+    ///
+    /// # Arguments
+    /// * `body` - JSON 본문 (서명 대상 원문)
+    /// * `signature` - 본문 HMAC-SHA256 (hex)
+    pub fn ingest(&self, body: &[u8], signature: Option<&str>) -> Result<(), TelemetryRejection> {
+        let result = self.accept(body, signature, chrono::Utc::now().timestamp());
+        if let Err(rejection) = &result {
+            *self.lock_metrics().rejected.entry(rejection.reason()).or_default() += 1;
+        }
+        result
+    }
+
+    fn accept(&self, body: &[u8], signature: Option<&str>, now: i64) -> Result<(), TelemetryRejection> {
+        self.verify_signature(body, signature)?;
+        let report: TelemetryReport = serde_json::from_slice(body)
+            .map_err(|e| TelemetryRejection::Invalid(e.to_string()))?;
+        if (report.timestamp - now).abs() > MAX_CLOCK_SKEW_SECS {
+            return Err(TelemetryRejection::Stale);
+        }
+        validate(&report)?;
+        if !self.consume(report.user_id) {
+            return Err(TelemetryRejection::RateLimited);
+        }
+        self.record(&report);
+        Ok(())
+    }
+
+    fn verify_signature(&self, body: &[u8], signature: Option<&str>) -> Result<(), TelemetryRejection> {
+        let signature = signature
+            .and_then(|signature| hex::decode(signature.trim()).ok())
+            .ok_or(TelemetryRejection::BadSignature)?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.signing_key).expect("HMAC은 모든 키 길이를 허용합니다");
+        mac.update(body);
+        mac.verify_slice(&signature).map_err(|_| TelemetryRejection::BadSignature)
+    }
+
+    fn consume(&self, user_id: u32) -> bool {
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        if windows.len() > RATE_PRUNE_THRESHOLD {
+            windows.retain(|_, (started, _)| now.duration_since(*started) < RATE_WINDOW);
+        }
+
+        let (started, count) = windows.entry(user_id).or_insert((now, 0));
+        if now.duration_since(*started) >= RATE_WINDOW {
+            *started = now;
+            *count = 0;
+        }
+        if *count >= self.rate_per_minute {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+
+    fn record(&self, report: &TelemetryReport) {
+        let region = report.region.to_lowercase();
+        let region = if self.regions.contains(&region) { region } else { "other".to_string() };
+
+        let mut metrics = self.lock_metrics();
+        *metrics.reports.entry(region.clone()).or_default() += 1;
+        if let Some(fps) = report.fps {
+            metrics.fps.entry(region.clone()).or_insert_with(|| Histogram::new(FPS_BUCKETS)).observe(FPS_BUCKETS, fps);
+        }
+        if let Some(rtt) = report.rtt_ms {
+            metrics.rtt.entry(region.clone()).or_insert_with(|| Histogram::new(RTT_BUCKETS_MS)).observe(RTT_BUCKETS_MS, rtt);
+        }
+        for event in &report.errors {
+            let known = metrics.error_codes.contains(&event.code);
+            let code = if known || metrics.error_codes.len() < MAX_ERROR_CODES {
+                metrics.error_codes.insert(event.code.clone());
+                event.code.clone()
+            } else {
+                "other".to_string()
+            };
+            *metrics.errors.entry((region.clone(), code)).or_default() += u64::from(event.count);
+        }
+    }
+
+    fn lock_metrics(&self) -> std::sync::MutexGuard<'_, Metrics> {
+        self.metrics.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Prometheus 텍스트 형식으로 내보내기
+    pub fn render_prometheus(&self) -> String {
+        let metrics = self.lock_metrics();
+        let mut output = String::new();
+        fn header(output: &mut String, name: &str, kind: &str, help: &str) {
+            let _ = writeln!(output, "# HELP {name} {help}");
+            let _ = writeln!(output, "# TYPE {name} {kind}");
+        }
+
+        header(&mut output, "client_telemetry_reports_total", "counter", "Accepted client telemetry reports");
+        for (region, count) in &metrics.reports {
+            let _ = writeln!(output, "client_telemetry_reports_total{{region=\"{region}\"}} {count}");
+        }
+        header(&mut output, "client_telemetry_rejected_total", "counter", "Rejected client telemetry reports");
+        for (reason, count) in &metrics.rejected {
+            let _ = writeln!(output, "client_telemetry_rejected_total{{reason=\"{reason}\"}} {count}");
+        }
+        header(&mut output, "client_fps", "histogram", "Client-reported average frames per second");
+        for (region, histogram) in &metrics.fps {
+            histogram.render(&mut output, "client_fps", region, FPS_BUCKETS);
+        }
+        header(&mut output, "client_rtt_ms", "histogram", "Client-perceived round trip time in milliseconds");
+        for (region, histogram) in &metrics.rtt {
+            histogram.render(&mut output, "client_rtt_ms", region, RTT_BUCKETS_MS);
+        }
+        header(&mut output, "client_error_events_total", "counter", "Client-reported error events");
+        for ((region, code), count) in &metrics.errors {
+            let _ = writeln!(output, "client_error_events_total{{region=\"{region}\",code=\"{code}\"}} {count}");
+        }
+        output
+    }
+}
+
+/// 값 범위와 라벨 형식 검증
+fn validate(report: &TelemetryReport) -> Result<(), TelemetryRejection> {
+    let invalid = |message: String| Err(TelemetryRejection::Invalid(message));
+    let is_label = |value: &str, max: usize| {
+        !value.is_empty()
+            && value.len() <= max
+            && value.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    };
+
+    if !is_label(&report.region, 16) {
+        return invalid(format!("region '{}'", report.region));
+    }
+    if let Some(fps) = report.fps {
+        if !(0.0..=1000.0).contains(&fps) {
+            return invalid(format!("fps {fps}"));
+        }
+    }
+    if let Some(rtt) = report.rtt_ms {
+        if !(0.0..=60_000.0).contains(&rtt) {
+            return invalid(format!("rtt_ms {rtt}"));
+        }
+    }
+    if report.errors.len() > MAX_ERROR_EVENTS {
+        return invalid(format!("errors {} > {MAX_ERROR_EVENTS}", report.errors.len()));
+    }
+    for event in &report.errors {
+        if !is_label(&event.code, 48) {
+            return invalid(format!("error code '{}'", event.code));
+        }
+        if event.count == 0 || event.count > 10_000 {
+            return invalid(format!("error count {}", event.count));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(key: &[u8], body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+        mac.update(body.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn report(user_id: u32, region: &str, extra: &str) -> String {
+        let now = chrono::Utc::now().timestamp();
+        format!(r#"{{"user_id":{user_id},"region":"{region}","timestamp":{now}{extra}}}"#)
+    }
+
+    #[test]
+    fn test_ingest_and_render() {
+        let ingest = TelemetryIngest::new("secret").with_regions(["kr", "us"]);
+        let body = report(1, "KR", r#","fps":58.5,"rtt_ms":42,"errors":[{"code":"asset_load","count":2}]"#);
+        ingest.ingest(body.as_bytes(), Some(&sign(b"secret", &body))).unwrap();
+
+        // 목록 밖 지역은 other
+        let body = report(2, "br", r#","rtt_ms":250"#);
+        ingest.ingest(body.as_bytes(), Some(&sign(b"secret", &body))).unwrap();
+
+        let text = ingest.render_prometheus();
+        assert!(text.contains(r#"client_fps_bucket{region="kr",le="60"} 1"#));
+        assert!(text.contains(r#"client_fps_bucket{region="kr",le="45"} 0"#));
+        assert!(text.contains(r#"client_rtt_ms_count{region="other"} 1"#));
+        assert!(text.contains(r#"client_error_events_total{region="kr",code="asset_load"} 2"#));
+        assert!(text.contains(r#"client_telemetry_reports_total{region="kr"} 1"#));
+    }
+
+    #[test]
+    fn test_rejections() {
+        let ingest = TelemetryIngest::new("secret").with_rate_per_minute(1);
+        let body = report(1, "kr", r#","fps":60"#);
+
+        assert_eq!(ingest.ingest(body.as_bytes(), None), Err(TelemetryRejection::BadSignature));
+        assert_eq!(
+            ingest.ingest(body.as_bytes(), Some(&sign(b"other", &body))),
+            Err(TelemetryRejection::BadSignature)
+        );
+
+        let stale = r#"{"user_id":1,"region":"kr","timestamp":1000}"#;
+        assert_eq!(ingest.ingest(stale.as_bytes(), Some(&sign(b"secret", stale))), Err(TelemetryRejection::Stale));
+
+        let invalid = report(1, "kr", r#","fps":-3"#);
+        assert!(matches!(
+            ingest.ingest(invalid.as_bytes(), Some(&sign(b"secret", &invalid))),
+            Err(TelemetryRejection::Invalid(_))
+        ));
+
+        ingest.ingest(body.as_bytes(), Some(&sign(b"secret", &body))).unwrap();
+        assert_eq!(
+            ingest.ingest(body.as_bytes(), Some(&sign(b"secret", &body))),
+            Err(TelemetryRejection::RateLimited)
+        );
+
+        let text = ingest.render_prometheus();
+        assert!(text.contains(r#"client_telemetry_rejected_total{reason="signature"} 2"#));
+        assert!(text.contains(r#"client_telemetry_rejected_total{reason="rate_limited"} 1"#));
+    }
+}
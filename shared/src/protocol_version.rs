@@ -0,0 +1,395 @@
+//! 프로토콜 버전과 호환성 협상
+//!
+//! 서버마다 메시지 버전을 제각각 관리하던 것을 하나의 틀로 통일합니다.
+//! - `ProtocolVersion`: `major.minor.patch` (major가 다르면 호환 불가)
+//! - `Capabilities`: 선택 기능 비트맵 (압축, 세션 재개 등)
+//! - `ProtocolSpec`: 서버별 현재/최소 버전, 지원 기능, 메시지 레지스트리
+//! - `ProtocolOffer`: 핸드셰이크에서 클라이언트가 보내는 `1.2.0` 또는 `1.2.0+<기능 hex>` 문자열
+//!
+//! # 메시지 등록과 버전 결정
+//! 메시지 레지스트리의 각 항목은 처음 도입된 버전(`since`)을 가지며, `history`는 버전별
+//! 메시지 수를 고정합니다. `const _: () = SPEC.verify();`로 컴파일 시점에 검사하므로
+//! 메시지를 추가하면 빌드가 실패하고, 새 버전을 `history`에 추가할지(버전 올림)
+//! 기존 버전의 메시지 수를 고칠지(하위 호환 추가) 명시적으로 결정해야 합니다.
+//!
+//! ```ignore
+//! pub const SPEC: ProtocolSpec = ProtocolSpec {
+//!     name: "tcp",
+//!     current: ProtocolVersion::new(1, 1, 0),
+//!     min_supported: ProtocolVersion::new(1, 0, 0),
+//!     capabilities: Capabilities::SESSION_RESUME,
+//!     messages: &[
+//!         MessageSpec::new("connect", ProtocolVersion::new(1, 0, 0)),
+//!         MessageSpec::new("reconnect", ProtocolVersion::new(1, 1, 0)),
+//!     ],
+//!     history: &[(ProtocolVersion::new(1, 0, 0), 1), (ProtocolVersion::new(1, 1, 0), 2)],
+//! };
+//! const _: () = SPEC.verify();
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// 프로토콜 버전
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ProtocolVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl ProtocolVersion {
+    pub const fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// `self <= other` (const 문맥용)
+    pub const fn not_after(&self, other: &ProtocolVersion) -> bool {
+        if self.major != other.major {
+            return self.major < other.major;
+        }
+        if self.minor != other.minor {
+            return self.minor < other.minor;
+        }
+        self.patch <= other.patch
+    }
+
+    const fn same(&self, other: &ProtocolVersion) -> bool {
+        self.major == other.major && self.minor == other.minor && self.patch == other.patch
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for ProtocolVersion {
+    type Err = NegotiationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || NegotiationError::InvalidOffer(s.to_string());
+        let mut parts = s.trim().trim_start_matches('v').split('.');
+        let mut next = |required: bool| match parts.next() {
+            Some(part) => part.parse::<u16>().map_err(|_| invalid()),
+            None if required => Err(invalid()),
+            None => Ok(0),
+        };
+        let version = ProtocolVersion::new(next(true)?, next(false)?, next(false)?);
+        match parts.next() {
+            Some(_) => Err(invalid()),
+            None => Ok(version),
+        }
+    }
+}
+
+/// 선택 기능 비트맵
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Capabilities(pub u64);
+
+impl Capabilities {
+    pub const NONE: Capabilities = Capabilities(0);
+    /// 페이로드 압축
+    pub const COMPRESSION: Capabilities = Capabilities(1 << 0);
+    /// 바이너리 코덱 (JSON 대신)
+    pub const BINARY_CODEC: Capabilities = Capabilities(1 << 1);
+    /// 세션 토큰 기반 재접속
+    pub const SESSION_RESUME: Capabilities = Capabilities(1 << 2);
+    /// 상태 델타 전송
+    pub const DELTA_STATE: Capabilities = Capabilities(1 << 3);
+    /// 메시지 배치 전송
+    pub const BATCHING: Capabilities = Capabilities(1 << 4);
+
+    const NAMES: &'static [(Capabilities, &'static str)] = &[
+        (Self::COMPRESSION, "compression"),
+        (Self::BINARY_CODEC, "binary_codec"),
+        (Self::SESSION_RESUME, "session_resume"),
+        (Self::DELTA_STATE, "delta_state"),
+        (Self::BATCHING, "batching"),
+    ];
+
+    pub const fn union(self, other: Capabilities) -> Capabilities {
+        Capabilities(self.0 | other.0)
+    }
+
+    pub const fn intersect(self, other: Capabilities) -> Capabilities {
+        Capabilities(self.0 & other.0)
+    }
+
+    pub const fn contains(self, other: Capabilities) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// 알려진 기능 이름 목록
+    pub fn names(self) -> Vec<&'static str> {
+        Self::NAMES.iter()
+            .filter(|(capability, _)| self.contains(*capability))
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
+impl std::ops::BitOr for Capabilities {
+    type Output = Capabilities;
+
+    fn bitor(self, rhs: Capabilities) -> Capabilities {
+        self.union(rhs)
+    }
+}
+
+/// 레지스트리에 등록된 메시지
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageSpec {
+    pub name: &'static str,
+    /// 처음 도입된 버전
+    pub since: ProtocolVersion,
+}
+
+impl MessageSpec {
+    pub const fn new(name: &'static str, since: ProtocolVersion) -> Self {
+        Self { name, since }
+    }
+}
+
+/// 서버별 프로토콜 정의
+#[derive(Debug, Clone, Copy)]
+pub struct ProtocolSpec {
+    pub name: &'static str,
+    pub current: ProtocolVersion,
+    pub min_supported: ProtocolVersion,
+    pub capabilities: Capabilities,
+    pub messages: &'static [MessageSpec],
+    /// 버전별 메시지 수 (오름차순, 마지막 항목은 `current`)
+    pub history: &'static [(ProtocolVersion, usize)],
+}
+
+impl ProtocolSpec {
+    /// 컴파일 시점 검사 (`const _: () = SPEC.verify();`)
+    pub const fn verify(&self) {
+        if !self.min_supported.not_after(&self.current) || self.min_supported.major != self.current.major {
+            panic!("min_supported must be <= current and share its major version");
+        }
+        if self.history.is_empty() || !self.history[self.history.len() - 1].0.same(&self.current) {
+            panic!("history must end with the current protocol version");
+        }
+
+        let mut i = 0;
+        while i < self.messages.len() {
+            if !self.messages[i].since.not_after(&self.current) {
+                panic!("message introduced after the current protocol version");
+            }
+            let mut j = i + 1;
+            while j < self.messages.len() {
+                if const_str_eq(self.messages[i].name, self.messages[j].name) {
+                    panic!("duplicate message name in protocol registry");
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        let mut h = 0;
+        while h < self.history.len() {
+            let (version, expected) = self.history[h];
+            if h > 0 && (!self.history[h - 1].0.not_after(&version) || self.history[h - 1].0.same(&version)) {
+                panic!("history versions must be strictly increasing");
+            }
+            let mut count = 0;
+            let mut k = 0;
+            while k < self.messages.len() {
+                if self.messages[k].since.not_after(&version) {
+                    count += 1;
+                }
+                k += 1;
+            }
+            if count != expected {
+                panic!("protocol message registry changed: bump the version (add a history entry) or update the message count for this version");
+            }
+            h += 1;
+        }
+    }
+
+    /// 이름으로 메시지 조회
+    pub fn message(&self, name: &str) -> Option<&MessageSpec> {
+        self.messages.iter().find(|message| message.name == name)
+    }
+
+    /// 이 서버가 제시하는 버전/기능
+    pub fn offer(&self) -> ProtocolOffer {
+        ProtocolOffer { version: self.current, capabilities: self.capabilities }
+    }
+
+    /// 클라이언트 제안과 협상 (공통 최고 버전, 기능 교집합)
+    pub fn negotiate(&'static self, offer: &ProtocolOffer) -> Result<Negotiated, NegotiationError> {
+        if offer.version.major != self.current.major {
+            return Err(NegotiationError::IncompatibleMajor { offered: offer.version, supported: self.current });
+        }
+        if offer.version < self.min_supported {
+            return Err(NegotiationError::TooOld { offered: offer.version, min: self.min_supported });
+        }
+        Ok(Negotiated {
+            spec: self,
+            version: offer.version.min(self.current),
+            capabilities: offer.capabilities.intersect(self.capabilities),
+        })
+    }
+
+    /// 핸드셰이크 문자열로 협상
+    pub fn negotiate_str(&'static self, offer: &str) -> Result<Negotiated, NegotiationError> {
+        self.negotiate(&offer.parse()?)
+    }
+}
+
+const fn const_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// 핸드셰이크 제안 (`1.2.0` 또는 `1.2.0+<기능 비트맵 hex>`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolOffer {
+    pub version: ProtocolVersion,
+    pub capabilities: Capabilities,
+}
+
+impl fmt::Display for ProtocolOffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.capabilities.is_empty() {
+            write!(f, "{}", self.version)
+        } else {
+            write!(f, "{}+{:x}", self.version, self.capabilities.0)
+        }
+    }
+}
+
+impl FromStr for ProtocolOffer {
+    type Err = NegotiationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (version, capabilities) = match s.trim().split_once('+') {
+            Some((version, caps)) => (
+                version,
+                u64::from_str_radix(caps, 16).map_err(|_| NegotiationError::InvalidOffer(s.to_string()))?,
+            ),
+            None => (s, 0),
+        };
+        Ok(Self { version: version.parse()?, capabilities: Capabilities(capabilities) })
+    }
+}
+
+/// 협상 결과
+#[derive(Debug, Clone, Copy)]
+pub struct Negotiated {
+    pub spec: &'static ProtocolSpec,
+    pub version: ProtocolVersion,
+    pub capabilities: Capabilities,
+}
+
+impl Negotiated {
+    /// 협상된 버전에서 해당 메시지를 보낼 수 있는지
+    pub fn supports(&self, message: &str) -> bool {
+        match self.spec.message(message) {
+            Some(spec) => spec.since <= self.version,
+            None => {
+                debug_assert!(false, "message `{message}` is not registered in the `{}` protocol", self.spec.name);
+                false
+            }
+        }
+    }
+
+    pub fn has(&self, capability: Capabilities) -> bool {
+        self.capabilities.contains(capability)
+    }
+
+    /// 응답용 문자열 (`ProtocolOffer` 형식)
+    pub fn to_offer(&self) -> ProtocolOffer {
+        ProtocolOffer { version: self.version, capabilities: self.capabilities }
+    }
+}
+
+/// 협상 실패
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum NegotiationError {
+    #[error("Invalid protocol offer: {0}")]
+    InvalidOffer(String),
+
+    #[error("Incompatible protocol version {offered} (server speaks {supported})")]
+    IncompatibleMajor { offered: ProtocolVersion, supported: ProtocolVersion },
+
+    #[error("Protocol version {offered} is no longer supported (minimum {min})")]
+    TooOld { offered: ProtocolVersion, min: ProtocolVersion },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1_0: ProtocolVersion = ProtocolVersion::new(1, 0, 0);
+    const V1_1: ProtocolVersion = ProtocolVersion::new(1, 1, 0);
+    const V1_2: ProtocolVersion = ProtocolVersion::new(1, 2, 0);
+
+    static SPEC: ProtocolSpec = ProtocolSpec {
+        name: "test",
+        current: V1_2,
+        min_supported: V1_1,
+        capabilities: Capabilities::COMPRESSION.union(Capabilities::SESSION_RESUME),
+        messages: &[
+            MessageSpec::new("connect", V1_0),
+            MessageSpec::new("reconnect", V1_1),
+            MessageSpec::new("emote", V1_2),
+        ],
+        history: &[(V1_0, 1), (V1_1, 2), (V1_2, 3)],
+    };
+    const _: () = SPEC.verify();
+
+    #[test]
+    fn test_offer_roundtrip() {
+        let offer: ProtocolOffer = "1.1+5".parse().unwrap();
+        assert_eq!(offer.version, V1_1);
+        assert_eq!(offer.capabilities, Capabilities::COMPRESSION | Capabilities::SESSION_RESUME);
+        assert_eq!(offer.to_string(), "1.1.0+5");
+        assert_eq!("1.0.0".parse::<ProtocolOffer>().unwrap().to_string(), "1.0.0");
+        assert!("1.0.0.0".parse::<ProtocolOffer>().is_err());
+        assert!("1.0+zz".parse::<ProtocolOffer>().is_err());
+    }
+
+    #[test]
+    fn test_negotiation() {
+        let negotiated = SPEC.negotiate_str("1.1.0+7").unwrap();
+        assert_eq!(negotiated.version, V1_1);
+        assert_eq!(negotiated.capabilities, Capabilities::COMPRESSION | Capabilities::SESSION_RESUME);
+        assert!(negotiated.supports("reconnect"));
+        assert!(!negotiated.supports("emote"));
+
+        // 클라이언트가 더 최신이면 서버 버전으로 맞춤
+        assert_eq!(SPEC.negotiate_str("1.9.3").unwrap().version, V1_2);
+
+        assert!(matches!(SPEC.negotiate_str("1.0.0"), Err(NegotiationError::TooOld { .. })));
+        assert!(matches!(SPEC.negotiate_str("2.0.0"), Err(NegotiationError::IncompatibleMajor { .. })));
+    }
+
+    #[test]
+    fn test_capability_names() {
+        let caps = Capabilities::BINARY_CODEC | Capabilities::BATCHING;
+        assert_eq!(caps.names(), vec!["binary_codec", "batching"]);
+        assert!(caps.contains(Capabilities::BATCHING));
+        assert!(!caps.contains(Capabilities::COMPRESSION));
+    }
+}
@@ -82,6 +82,7 @@ pub enum Permission {
     EditOtherProfiles,
     BanUser,
     UnbanUser,
+    ModerateChat,
     
     // 관리 권한
     ViewLogs,
@@ -227,6 +228,7 @@ impl AccessControlMatrix {
             Permission::EditOtherProfiles,
             Permission::BanUser,
             Permission::UnbanUser,
+            Permission::ModerateChat,
         ]);
         self.role_permissions.insert(UserRole::Moderator, moderator_perms);
         
@@ -246,6 +248,7 @@ impl AccessControlMatrix {
             Permission::EditOtherProfiles,
             Permission::BanUser,
             Permission::UnbanUser,
+            Permission::ModerateChat,
             Permission::ViewLogs,
             Permission::ViewMetrics,
             Permission::ManageUsers,
@@ -270,6 +273,7 @@ impl AccessControlMatrix {
             Permission::EditOtherProfiles,
             Permission::BanUser,
             Permission::UnbanUser,
+            Permission::ModerateChat,
             Permission::ViewLogs,
             Permission::ViewMetrics,
             Permission::ManageUsers,
@@ -351,12 +355,23 @@ impl AccessControlMatrix {
             HashSet::from([Permission::Read])
         );
         
+        // 모더레이션 서비스 엔드포인트
+        self.endpoint_permissions.insert(
+            ApiEndpoint::new("moderation", "TailChat"),
+            HashSet::from([Permission::ModerateChat])
+        );
+        
         // 리더보드 및 통계
         self.endpoint_permissions.insert(
             ApiEndpoint::new("leaderboard", "GetLeaderboard"),
             HashSet::from([Permission::ViewLeaderboard])
         );
         
+        self.endpoint_permissions.insert(
+            ApiEndpoint::new("stats", "GetServerStats"),
+            HashSet::from([Permission::ViewMetrics])
+        );
+        
         // 관리 엔드포인트
         self.endpoint_permissions.insert(
             ApiEndpoint::new("admin", "GetLogs"),
@@ -368,6 +383,17 @@ impl AccessControlMatrix {
             HashSet::from([Permission::ViewMetrics])
         );
         
+        // 라이브 설정 (킬 스위치)
+        self.endpoint_permissions.insert(
+            ApiEndpoint::new("liveops", "ListFlags"),
+            HashSet::from([Permission::ViewMetrics])
+        );
+        
+        self.endpoint_permissions.insert(
+            ApiEndpoint::new("liveops", "SetFlag"),
+            HashSet::from([Permission::SystemConfiguration])
+        );
+        
         self.endpoint_permissions.insert(
             ApiEndpoint::new("admin", "SystemConfig"),
             HashSet::from([Permission::SystemConfiguration])
@@ -680,6 +706,19 @@ mod tests {
         assert!(matrix.check_permission(&[UserRole::Admin], &admin_endpoint, None).is_ok());
     }
     
+    #[test]
+    fn test_moderation_endpoint() {
+        let matrix = AccessControlMatrix::new();
+        let tail_chat = ApiEndpoint::new("moderation", "TailChat");
+        
+        // 게임 마스터까지는 채팅 모더레이션 불가
+        assert!(matrix.check_permission(&[UserRole::GameMaster], &tail_chat, None).is_err());
+        
+        // 모더레이터 이상은 접근 가능
+        assert!(matrix.check_permission(&[UserRole::Moderator], &tail_chat, None).is_ok());
+        assert!(matrix.check_permission(&[UserRole::Admin], &tail_chat, None).is_ok());
+    }
+    
     #[test]
     fn test_multiple_roles() {
         let matrix = AccessControlMatrix::new();
@@ -0,0 +1,431 @@
+//! 보안 감사 이벤트 스트림 (해시 체인)
+//!
+//! 로그인, 제재, 관리자 명령 등 보안 관련 이벤트를 추가 전용 기록으로 남깁니다.
+//! 각 기록은 직전 기록의 해시(`prev_hash`)를 포함해 해시되므로, 중간 기록을 고치거나
+//! 지우면 이후 체인 검증이 실패합니다.
+//!
+//! - 파일: JSON Lines 형식으로 추가 기록합니다. 한 파일에는 한 프로세스만 기록해야 합니다.
+//! - DB: `audit_log` 테이블에 기록하며, `seq` 기본 키로 여러 서버의 동시 기록을 직렬화합니다.
+//!
+//! `AUDIT_HMAC_KEY`를 설정하면 SHA-256 대신 HMAC-SHA256을 사용하므로, 키가 없는 사람은
+//! 체인 전체를 다시 계산해 변조를 감출 수 없습니다.
+//! 검증은 `verify_audit_log` 바이너리로 실행합니다.
+
+use crate::config::db::{helpers::map_sqlx_error, DbConnection};
+use crate::tool::error::AppError;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// 첫 기록의 `prev_hash`
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 감사 로그 테이블
+pub const AUDIT_LOG_SCHEMA: &[&str] = &["CREATE TABLE IF NOT EXISTS audit_log (
+        seq BIGINT UNSIGNED PRIMARY KEY,
+        recorded_at BIGINT NOT NULL,
+        kind VARCHAR(32) NOT NULL,
+        actor VARCHAR(128) NOT NULL,
+        payload TEXT NOT NULL,
+        prev_hash CHAR(64) NOT NULL,
+        hash CHAR(64) NOT NULL,
+        INDEX idx_audit_log_actor (actor)
+    )"];
+
+/// 다른 서버와 `seq`가 겹쳤을 때 재시도 횟수
+const MAX_APPEND_RETRIES: usize = 5;
+
+/// 감사 이벤트 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEventKind {
+    Login,
+    LoginFailed,
+    Logout,
+    Ban,
+    Unban,
+    AdminCommand,
+    /// 라이브 설정(킬 스위치 등) 변경
+    ConfigChange,
+}
+
+impl AuditEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Login => "login",
+            Self::LoginFailed => "login_failed",
+            Self::Logout => "logout",
+            Self::Ban => "ban",
+            Self::Unban => "unban",
+            Self::AdminCommand => "admin_command",
+            Self::ConfigChange => "config_change",
+        }
+    }
+}
+
+/// 감사 이벤트
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub kind: AuditEventKind,
+    /// 행위자 (사용자 ID, 관리자 세션 등)
+    pub actor: String,
+    /// 대상 (제재된 사용자 등)
+    pub target: Option<String>,
+    /// 요청 출처 (IP 주소 등)
+    pub source: Option<String>,
+    /// 상세 내용 (토큰, 비밀번호 등 비밀 값은 넣지 않음)
+    pub detail: String,
+}
+
+impl AuditEvent {
+    pub fn new(kind: AuditEventKind, actor: impl Into<String>) -> Self {
+        Self { kind, actor: actor.into(), target: None, source: None, detail: String::new() }
+    }
+
+    pub fn with_target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = detail.into();
+        self
+    }
+}
+
+/// 체인에 연결된 감사 기록
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// 1부터 시작하는 일련번호
+    pub seq: u64,
+    /// 기록 시각 (Unix 밀리초)
+    pub recorded_at: i64,
+    #[serde(flatten)]
+    pub event: AuditEvent,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+/// 해시 대상 필드 (`hash` 제외)
+#[derive(Serialize)]
+struct HashedFields<'a> {
+    seq: u64,
+    recorded_at: i64,
+    event: &'a AuditEvent,
+    prev_hash: &'a str,
+}
+
+impl AuditRecord {
+    /// 직전 기록에 이어지는 새 기록
+    pub fn chain(seq: u64, prev_hash: &str, event: AuditEvent, key: Option<&[u8]>) -> Self {
+        let recorded_at = chrono::Utc::now().timestamp_millis();
+        let hash = compute_hash(seq, recorded_at, &event, prev_hash, key);
+        Self { seq, recorded_at, event, prev_hash: prev_hash.to_string(), hash }
+    }
+
+    /// 저장된 내용으로 다시 계산한 해시
+    pub fn expected_hash(&self, key: Option<&[u8]>) -> String {
+        compute_hash(self.seq, self.recorded_at, &self.event, &self.prev_hash, key)
+    }
+}
+
+fn compute_hash(seq: u64, recorded_at: i64, event: &AuditEvent, prev_hash: &str, key: Option<&[u8]>) -> String {
+    let fields = HashedFields { seq, recorded_at, event, prev_hash };
+    let data = serde_json::to_vec(&fields).expect("감사 기록은 항상 직렬화됩니다");
+    match key {
+        Some(key) => {
+            let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC은 모든 키 길이를 허용합니다");
+            mac.update(&data);
+            hex::encode(mac.finalize().into_bytes())
+        }
+        None => hex::encode(Sha256::digest(&data)),
+    }
+}
+
+/// 체인 검증 실패 위치
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainViolation {
+    /// 문제가 발견된 기록의 일련번호
+    pub seq: u64,
+    pub reason: String,
+}
+
+impl std::fmt::Display for ChainViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "seq {}: {}", self.seq, self.reason)
+    }
+}
+
+/// 기록 순서대로 체인을 검증하고 검증된 기록 수를 반환
+///
+/// 끝부분을 잘라낸 경우는 체인만으로 알 수 없으므로, 필요하면 `AuditSink::head()`를 따로 보관해 비교합니다.
+pub fn verify_chain(records: &[AuditRecord], key: Option<&[u8]>) -> Result<usize, ChainViolation> {
+    let mut prev_hash = GENESIS_HASH;
+    for (index, record) in records.iter().enumerate() {
+        let expected_seq = index as u64 + 1;
+        if record.seq != expected_seq {
+            return Err(ChainViolation {
+                seq: record.seq,
+                reason: format!("일련번호 불연속 (기대값 {})", expected_seq),
+            });
+        }
+        if record.prev_hash != prev_hash {
+            return Err(ChainViolation { seq: record.seq, reason: "직전 기록 해시 불일치".to_string() });
+        }
+        if record.hash != record.expected_hash(key) {
+            return Err(ChainViolation { seq: record.seq, reason: "기록 해시 불일치".to_string() });
+        }
+        prev_hash = &record.hash;
+    }
+    Ok(records.len())
+}
+
+/// 감사 기록 저장소
+#[derive(Debug, Clone)]
+pub enum AuditTarget {
+    File(PathBuf),
+    Database(DbConnection),
+}
+
+/// 체인의 마지막 기록
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainHead {
+    pub seq: u64,
+    pub hash: String,
+}
+
+impl ChainHead {
+    fn genesis() -> Self {
+        Self { seq: 0, hash: GENESIS_HASH.to_string() }
+    }
+
+    fn of(record: &AuditRecord) -> Self {
+        Self { seq: record.seq, hash: record.hash.clone() }
+    }
+}
+
+/// 감사 이벤트 기록기
+#[derive(Debug)]
+pub struct AuditSink {
+    target: AuditTarget,
+    key: Option<Vec<u8>>,
+    head: Mutex<ChainHead>,
+}
+
+impl AuditSink {
+    /// 저장소를 열고 기존 체인의 끝에서 이어서 기록
+    pub async fn open(target: AuditTarget, key: Option<Vec<u8>>) -> Result<Self, AppError> {
+        if let AuditTarget::Database(pool) = &target {
+            for statement in AUDIT_LOG_SCHEMA {
+                sqlx::query(statement)
+                    .execute(pool)
+                    .await
+                    .map_err(|e| map_sqlx_error(e, "감사 로그 스키마 생성"))?;
+            }
+        }
+        let head = load_head(&target).await?;
+        Ok(Self { target, key, head: Mutex::new(head) })
+    }
+
+    /// 환경변수로 파일 기록기 생성 (`AUDIT_LOG_FILE` 미설정 시 None)
+    ///
+    /// - `AUDIT_LOG_FILE`: 감사 로그 파일 경로
+    /// - `AUDIT_HMAC_KEY`: 해시 키 (미설정 시 SHA-256)
+    pub async fn from_env() -> Result<Option<Self>, AppError> {
+        let Some(path) = std::env::var("AUDIT_LOG_FILE").ok().filter(|path| !path.is_empty()) else {
+            return Ok(None);
+        };
+        let key = hmac_key_from_env();
+        if key.is_none() {
+            warn!("AUDIT_HMAC_KEY가 없어 감사 로그를 키 없는 SHA-256으로 체인합니다");
+        }
+        Self::open(AuditTarget::File(PathBuf::from(path)), key).await.map(Some)
+    }
+
+    /// 현재 체인의 마지막 기록
+    pub async fn head(&self) -> ChainHead {
+        self.head.lock().await.clone()
+    }
+
+    /// 이벤트를 체인에 추가
+    pub async fn record(&self, event: AuditEvent) -> Result<AuditRecord, AppError> {
+        let mut head = self.head.lock().await;
+        for _ in 0..MAX_APPEND_RETRIES {
+            let record = AuditRecord::chain(head.seq + 1, &head.hash, event.clone(), self.key.as_deref());
+            match append(&self.target, &record).await {
+                Ok(true) => {
+                    *head = ChainHead::of(&record);
+                    return Ok(record);
+                }
+                // 다른 서버가 같은 seq를 먼저 기록함 - 최신 끝을 다시 읽고 재시도
+                Ok(false) => *head = load_head(&self.target).await?,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(AppError::TransactionFailed("감사 로그 기록 경합이 계속되어 기록하지 못했습니다".to_string()))
+    }
+
+    /// 저장된 모든 기록 (일련번호 순)
+    pub async fn read_all(&self) -> Result<Vec<AuditRecord>, AppError> {
+        read_all(&self.target).await
+    }
+}
+
+/// `AUDIT_HMAC_KEY` 환경변수
+pub fn hmac_key_from_env() -> Option<Vec<u8>> {
+    std::env::var("AUDIT_HMAC_KEY").ok().filter(|key| !key.is_empty()).map(String::into_bytes)
+}
+
+/// 저장소의 모든 기록 (일련번호 순)
+pub async fn read_all(target: &AuditTarget) -> Result<Vec<AuditRecord>, AppError> {
+    match target {
+        AuditTarget::File(path) => {
+            let content = match tokio::fs::read_to_string(path).await {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+                Err(e) => return Err(AppError::InternalError(format!("감사 로그 파일 읽기 실패: {}", e))),
+            };
+            content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(index, line)| {
+                    serde_json::from_str(line)
+                        .map_err(|e| AppError::InvalidFormat(format!("감사 로그 {}번째 줄: {}", index + 1, e)))
+                })
+                .collect()
+        }
+        AuditTarget::Database(pool) => {
+            let payloads: Vec<String> = sqlx::query_scalar("SELECT payload FROM audit_log ORDER BY seq")
+                .fetch_all(pool)
+                .await
+                .map_err(|e| map_sqlx_error(e, "감사 로그 조회"))?;
+            payloads
+                .iter()
+                .map(|payload| serde_json::from_str(payload).map_err(|e| AppError::InvalidFormat(e.to_string())))
+                .collect()
+        }
+    }
+}
+
+async fn load_head(target: &AuditTarget) -> Result<ChainHead, AppError> {
+    let last = match target {
+        AuditTarget::File(_) => read_all(target).await?.pop(),
+        AuditTarget::Database(pool) => {
+            let payload: Option<String> =
+                sqlx::query_scalar("SELECT payload FROM audit_log ORDER BY seq DESC LIMIT 1")
+                    .fetch_optional(pool)
+                    .await
+                    .map_err(|e| map_sqlx_error(e, "감사 로그 마지막 기록 조회"))?;
+            payload
+                .map(|payload| serde_json::from_str(&payload).map_err(|e| AppError::InvalidFormat(e.to_string())))
+                .transpose()?
+        }
+    };
+    Ok(last.as_ref().map(ChainHead::of).unwrap_or_else(ChainHead::genesis))
+}
+
+/// 기록 추가 (DB에서 같은 seq가 이미 있으면 false)
+async fn append(target: &AuditTarget, record: &AuditRecord) -> Result<bool, AppError> {
+    let payload = serde_json::to_string(record).map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+    match target {
+        AuditTarget::File(path) => {
+            let mut file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .await
+                .map_err(|e| AppError::InternalError(format!("감사 로그 파일 열기 실패: {}", e)))?;
+            file.write_all(format!("{payload}\n").as_bytes())
+                .await
+                .map_err(|e| AppError::InternalError(format!("감사 로그 파일 쓰기 실패: {}", e)))?;
+            file.sync_data()
+                .await
+                .map_err(|e| AppError::InternalError(format!("감사 로그 파일 동기화 실패: {}", e)))?;
+            Ok(true)
+        }
+        AuditTarget::Database(pool) => {
+            let inserted = sqlx::query(
+                "INSERT IGNORE INTO audit_log (seq, recorded_at, kind, actor, payload, prev_hash, hash)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(record.seq)
+            .bind(record.recorded_at)
+            .bind(record.event.kind.as_str())
+            .bind(&record.event.actor)
+            .bind(&payload)
+            .bind(&record.prev_hash)
+            .bind(&record.hash)
+            .execute(pool)
+            .await
+            .map_err(|e| map_sqlx_error(e, "감사 로그 저장"))?
+            .rows_affected();
+            Ok(inserted > 0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(count: u64, key: Option<&[u8]>) -> Vec<AuditRecord> {
+        let mut records: Vec<AuditRecord> = Vec::new();
+        for seq in 1..=count {
+            let prev_hash = records.last().map_or(GENESIS_HASH.to_string(), |record| record.hash.clone());
+            let event = AuditEvent::new(AuditEventKind::Login, format!("user-{seq}")).with_source("10.0.0.1");
+            records.push(AuditRecord::chain(seq, &prev_hash, event, key));
+        }
+        records
+    }
+
+    #[test]
+    fn test_chain_detects_tampering() {
+        let key = Some(b"audit-key".as_slice());
+        let records = chain(4, key);
+        assert_eq!(verify_chain(&records, key), Ok(4));
+
+        // 내용 변경
+        let mut edited = records.clone();
+        edited[1].event.actor = "admin".to_string();
+        assert_eq!(verify_chain(&edited, key).unwrap_err().seq, 2);
+
+        // 중간 기록 삭제
+        let mut removed = records.clone();
+        removed.remove(2);
+        assert_eq!(verify_chain(&removed, key).unwrap_err().seq, 4);
+
+        // 키 없이 다시 계산한 체인은 키로 검증하면 실패
+        assert!(verify_chain(&chain(2, None), key).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_sink_resumes_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = AuditTarget::File(dir.path().join("audit.log"));
+
+        let sink = AuditSink::open(target.clone(), None).await.unwrap();
+        sink.record(AuditEvent::new(AuditEventKind::Login, "1")).await.unwrap();
+        drop(sink);
+
+        let sink = AuditSink::open(target.clone(), None).await.unwrap();
+        let record = sink
+            .record(AuditEvent::new(AuditEventKind::Ban, "admin").with_target("1").with_detail("cheating"))
+            .await
+            .unwrap();
+        assert_eq!(record.seq, 2);
+
+        let records = read_all(&target).await.unwrap();
+        assert_eq!(verify_chain(&records, None), Ok(2));
+        assert_eq!(sink.head().await.hash, records[1].hash);
+    }
+}
@@ -3,10 +3,23 @@
 //! - bcrypt 비밀번호 해싱
 //! - AES 데이터 암호화
 //! - 보안 랜덤 생성
+//! - OAuth state 토큰 (CSRF 방지) 및 상수 시간 비교
+//! - 게임 서버 입장 티켓
 
 use crate::security::{SecurityConfig, SecurityError};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use hmac::{Hmac, Mac};
 use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::net::IpAddr;
+use std::time::Duration;
+use subtle::ConstantTimeEq;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 발급 시각 허용 오차 (서버 간 시계 차이)
+const STATE_CLOCK_SKEW_SECS: i64 = 30;
 
 /// 암호화 관리자
 pub struct CryptoManager {
@@ -67,6 +80,222 @@ impl Default for CryptoManager {
     }
 }
 
+/// 상수 시간 비교 (길이가 다르면 즉시 false)
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
+
+/// 클라이언트 지문 (User-Agent + IP의 SHA-256 hex)
+///
+/// state 토큰을 발급한 브라우저와 콜백을 보낸 브라우저가 같은지 확인하는 데 사용합니다.
+pub fn client_fingerprint(user_agent: &str, ip: Option<IpAddr>) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(user_agent.as_bytes());
+    hasher.update(b"|");
+    if let Some(ip) = ip {
+        hasher.update(ip.to_string().as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// OAuth state 토큰 내용
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OAuthState {
+    /// 임의 값 (재사용 방지가 필요하면 호출 측에서 1회용으로 기록)
+    #[serde(rename = "n")]
+    pub nonce: String,
+    /// 인증 후 돌아갈 주소
+    #[serde(rename = "r")]
+    pub redirect_uri: String,
+    /// 클라이언트 지문 해시
+    #[serde(rename = "f")]
+    pub fingerprint: String,
+    /// 발급 시각 (Unix 초)
+    #[serde(rename = "iat")]
+    pub issued_at: i64,
+    /// 만료 시각 (Unix 초)
+    #[serde(rename = "exp")]
+    pub expires_at: i64,
+}
+
+/// OAuth state 토큰 서명기
+///
+/// 토큰 형식은 `base64url(JSON).base64url(HMAC-SHA256)`이며,
+/// 클라이언트 지문과 리다이렉트 주소를 묶어 다른 브라우저나 다른 주소로의 재사용을 막습니다.
+#[derive(Clone)]
+pub struct OAuthStateSigner {
+    key: Vec<u8>,
+    ttl: Duration,
+}
+
+impl OAuthStateSigner {
+    /// 서명 키와 유효 시간으로 생성
+    pub fn new(key: impl AsRef<[u8]>, ttl: Duration) -> Self {
+        Self { key: key.as_ref().to_vec(), ttl }
+    }
+
+    /// JWT 비밀키에서 state 전용 키를 파생해 생성 (유효 시간 10분)
+    pub fn from_config(config: &SecurityConfig) -> Self {
+        let mut mac = HmacSha256::new_from_slice(config.jwt_secret.as_bytes())
+            .expect("HMAC은 모든 키 길이를 허용합니다");
+        mac.update(b"oauth-state");
+        Self::new(mac.finalize().into_bytes(), Duration::from_secs(600))
+    }
+
+    /// state 토큰 발급
+    pub fn issue(&self, fingerprint: &str, redirect_uri: &str) -> String {
+        self.issue_at(fingerprint, redirect_uri, chrono::Utc::now().timestamp())
+    }
+
+    /// state 토큰 검증 (서명, 만료, 지문, 리다이렉트 주소)
+    pub fn verify(&self, token: &str, fingerprint: &str, redirect_uri: &str) -> Result<OAuthState, SecurityError> {
+        self.verify_at(token, fingerprint, redirect_uri, chrono::Utc::now().timestamp())
+    }
+
+    fn issue_at(&self, fingerprint: &str, redirect_uri: &str, now: i64) -> String {
+        let state = OAuthState {
+            nonce: hex::encode(CryptoManager::default().generate_random_bytes(16)),
+            redirect_uri: redirect_uri.to_string(),
+            fingerprint: fingerprint.to_string(),
+            issued_at: now,
+            expires_at: now + self.ttl.as_secs() as i64,
+        };
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&state).unwrap_or_default());
+        let signature = URL_SAFE_NO_PAD.encode(self.sign(payload.as_bytes()));
+        format!("{payload}.{signature}")
+    }
+
+    fn verify_at(&self, token: &str, fingerprint: &str, redirect_uri: &str, now: i64) -> Result<OAuthState, SecurityError> {
+        let invalid = |reason: &str| SecurityError::InvalidToken(format!("OAuth state {reason}"));
+
+        let (payload, signature) = token.split_once('.').ok_or_else(|| invalid("malformed"))?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid("malformed"))?;
+        if !constant_time_eq(&self.sign(payload.as_bytes()), &signature) {
+            return Err(invalid("signature mismatch"));
+        }
+
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| invalid("malformed"))?;
+        let state: OAuthState = serde_json::from_slice(&payload).map_err(|_| invalid("malformed"))?;
+
+        if now >= state.expires_at {
+            return Err(SecurityError::TokenExpired);
+        }
+        if state.issued_at > now + STATE_CLOCK_SKEW_SECS {
+            return Err(invalid("issued in the future"));
+        }
+        if !constant_time_eq(state.fingerprint.as_bytes(), fingerprint.as_bytes()) {
+            return Err(invalid("client mismatch"));
+        }
+        if !constant_time_eq(state.redirect_uri.as_bytes(), redirect_uri.as_bytes()) {
+            return Err(invalid("redirect mismatch"));
+        }
+        Ok(state)
+    }
+
+    fn sign(&self, data: &[u8]) -> Vec<u8> {
+        hmac_sha256(&self.key, data)
+    }
+}
+
+/// 게임 서버 입장 티켓 내용
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntryTicket {
+    #[serde(rename = "u")]
+    pub user_id: u32,
+    /// 접속할 프로토콜 ("tcp", "rudp" 등)
+    #[serde(rename = "p")]
+    pub protocol: String,
+    /// 접속할 인스턴스 주소
+    #[serde(rename = "a")]
+    pub address: String,
+    /// 발급 시각 (Unix 초)
+    #[serde(rename = "iat")]
+    pub issued_at: i64,
+    /// 만료 시각 (Unix 초)
+    #[serde(rename = "exp")]
+    pub expires_at: i64,
+}
+
+/// 게임 서버 입장 티켓 서명기
+///
+/// 로그인 응답에서 안내한 인스턴스에만 짧은 시간 동안 쓸 수 있는 티켓을 발급합니다.
+/// 형식은 OAuth state 토큰과 같은 `base64url(JSON).base64url(HMAC-SHA256)`입니다.
+#[derive(Clone)]
+pub struct EntryTicketSigner {
+    key: Vec<u8>,
+    ttl: Duration,
+}
+
+impl EntryTicketSigner {
+    /// 서명 키와 유효 시간으로 생성
+    pub fn new(key: impl AsRef<[u8]>, ttl: Duration) -> Self {
+        Self { key: key.as_ref().to_vec(), ttl }
+    }
+
+    /// JWT 비밀키에서 티켓 전용 키를 파생해 생성 (유효 시간 60초)
+    pub fn from_jwt_secret(jwt_secret: &str) -> Self {
+        Self::new(hmac_sha256(jwt_secret.as_bytes(), b"entry-ticket"), Duration::from_secs(60))
+    }
+
+    /// 보안 설정의 JWT 비밀키로 생성
+    pub fn from_config(config: &SecurityConfig) -> Self {
+        Self::from_jwt_secret(&config.jwt_secret)
+    }
+
+    /// 티켓 발급 (토큰, 내용)
+    pub fn issue(&self, user_id: u32, protocol: &str, address: &str) -> (String, EntryTicket) {
+        self.issue_at(user_id, protocol, address, chrono::Utc::now().timestamp())
+    }
+
+    /// 티켓 검증 (서명, 만료, 프로토콜, 인스턴스 주소)
+    pub fn verify(&self, token: &str, protocol: &str, address: &str) -> Result<EntryTicket, SecurityError> {
+        self.verify_at(token, protocol, address, chrono::Utc::now().timestamp())
+    }
+
+    fn issue_at(&self, user_id: u32, protocol: &str, address: &str, now: i64) -> (String, EntryTicket) {
+        let ticket = EntryTicket {
+            user_id,
+            protocol: protocol.to_string(),
+            address: address.to_string(),
+            issued_at: now,
+            expires_at: now + self.ttl.as_secs() as i64,
+        };
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&ticket).unwrap_or_default());
+        let signature = URL_SAFE_NO_PAD.encode(hmac_sha256(&self.key, payload.as_bytes()));
+        (format!("{payload}.{signature}"), ticket)
+    }
+
+    fn verify_at(&self, token: &str, protocol: &str, address: &str, now: i64) -> Result<EntryTicket, SecurityError> {
+        let invalid = |reason: &str| SecurityError::InvalidToken(format!("entry ticket {reason}"));
+
+        let (payload, signature) = token.split_once('.').ok_or_else(|| invalid("malformed"))?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid("malformed"))?;
+        if !constant_time_eq(&hmac_sha256(&self.key, payload.as_bytes()), &signature) {
+            return Err(invalid("signature mismatch"));
+        }
+
+        let payload = URL_SAFE_NO_PAD.decode(payload).map_err(|_| invalid("malformed"))?;
+        let ticket: EntryTicket = serde_json::from_slice(&payload).map_err(|_| invalid("malformed"))?;
+
+        if now >= ticket.expires_at {
+            return Err(SecurityError::TokenExpired);
+        }
+        if ticket.issued_at > now + STATE_CLOCK_SKEW_SECS {
+            return Err(invalid("issued in the future"));
+        }
+        if ticket.protocol != protocol || ticket.address != address {
+            return Err(invalid("issued for another server"));
+        }
+        Ok(ticket)
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC은 모든 키 길이를 허용합니다");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,4 +320,53 @@ mod tests {
         assert_ne!(token1, token2);
         assert_eq!(token1.len(), 32); // 16 bytes = 32 hex chars
     }
+
+    #[test]
+    fn test_oauth_state_roundtrip() {
+        let signer = OAuthStateSigner::new(b"state-key", Duration::from_secs(600));
+        let fingerprint = client_fingerprint("Mozilla/5.0", "10.0.0.1".parse().ok());
+        let redirect = "https://game.example.com/oauth/callback";
+
+        let token = signer.issue_at(&fingerprint, redirect, 1_000);
+        let state = signer.verify_at(&token, &fingerprint, redirect, 1_100).unwrap();
+        assert_eq!(state.redirect_uri, redirect);
+        assert_eq!(state.expires_at, 1_600);
+
+        // 만료, 다른 클라이언트, 다른 리다이렉트 주소, 다른 키
+        assert!(matches!(signer.verify_at(&token, &fingerprint, redirect, 1_600), Err(SecurityError::TokenExpired)));
+        let other_client = client_fingerprint("Mozilla/5.0", "10.0.0.2".parse().ok());
+        assert!(signer.verify_at(&token, &other_client, redirect, 1_100).is_err());
+        assert!(signer.verify_at(&token, &fingerprint, "https://evil.example.com/", 1_100).is_err());
+        let other_signer = OAuthStateSigner::new(b"other-key", Duration::from_secs(600));
+        assert!(other_signer.verify_at(&token, &fingerprint, redirect, 1_100).is_err());
+    }
+
+    #[test]
+    fn test_oauth_state_tampering() {
+        let signer = OAuthStateSigner::new(b"state-key", Duration::from_secs(600));
+        let token = signer.issue_at("fp", "https://a/", 1_000);
+        let (payload, signature) = token.split_once('.').unwrap();
+
+        let mut forged: OAuthState = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap();
+        forged.expires_at += 3600;
+        let forged_payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&forged).unwrap());
+        assert!(signer.verify_at(&format!("{forged_payload}.{signature}"), "fp", "https://a/", 1_100).is_err());
+        assert!(signer.verify_at("not-a-token", "fp", "https://a/", 1_100).is_err());
+        assert!(!constant_time_eq(b"abc", b"abcd"));
+    }
+
+    #[test]
+    fn test_entry_ticket_bound_to_server() {
+        let signer = EntryTicketSigner::new(b"ticket-key", Duration::from_secs(60));
+        let (token, ticket) = signer.issue_at(7, "rudp", "10.0.0.5:5000", 1_000);
+        assert_eq!(ticket.expires_at, 1_060);
+        assert_eq!(signer.verify_at(&token, "rudp", "10.0.0.5:5000", 1_030).unwrap(), ticket);
+
+        // 만료, 다른 인스턴스/프로토콜, 다른 키
+        assert!(matches!(signer.verify_at(&token, "rudp", "10.0.0.5:5000", 1_060), Err(SecurityError::TokenExpired)));
+        assert!(signer.verify_at(&token, "rudp", "10.0.0.6:5000", 1_030).is_err());
+        assert!(signer.verify_at(&token, "tcp", "10.0.0.5:5000", 1_030).is_err());
+        let other_signer = EntryTicketSigner::new(b"other-key", Duration::from_secs(60));
+        assert!(other_signer.verify_at(&token, "rudp", "10.0.0.5:5000", 1_030).is_err());
+    }
 }
\ No newline at end of file
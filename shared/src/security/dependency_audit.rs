@@ -0,0 +1,283 @@
+//! 의존성 취약점 검사
+//!
+//! `cargo audit --json` 결과(RustSec 권고 DB)를 읽어 보안 감사 이슈로 변환합니다.
+//! CI에서 미리 만든 보고서가 있으면 `SECURITY_AUDIT_REPORT` 경로에서 읽고,
+//! 없으면 작업 디렉터리에서 `cargo audit --json`을 직접 실행합니다.
+//!
+//! 심각도는 권고의 CVSS v3 벡터로 계산한 기본 점수를 따르며, CVSS가 없는 취약점은 Medium,
+//! 유지보수 중단/yank 경고는 Low로 처리합니다.
+
+use crate::security::security_auditor::{SecurityIssue, Severity};
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// `cargo audit` 실행 제한 시간 (권고 DB 갱신 포함)
+const CARGO_AUDIT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// `cargo audit --json` 보고서
+#[derive(Debug, Deserialize)]
+pub struct CargoAuditReport {
+    pub vulnerabilities: VulnerabilityList,
+    #[serde(default)]
+    pub warnings: std::collections::HashMap<String, Vec<AuditWarning>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VulnerabilityList {
+    pub count: usize,
+    #[serde(default)]
+    pub list: Vec<Vulnerability>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Vulnerability {
+    pub advisory: Advisory,
+    pub package: AffectedPackage,
+    #[serde(default)]
+    pub versions: Option<PatchedVersions>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditWarning {
+    pub kind: String,
+    pub package: AffectedPackage,
+    #[serde(default)]
+    pub advisory: Option<Advisory>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Advisory {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// CVSS 벡터 (`CVSS:3.1/AV:N/...`)
+    #[serde(default)]
+    pub cvss: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AffectedPackage {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PatchedVersions {
+    #[serde(default)]
+    pub patched: Vec<String>,
+}
+
+impl CargoAuditReport {
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json).context("cargo audit 보고서 형식 오류")
+    }
+
+    /// 취약점과 경고를 보안 감사 이슈로 변환
+    pub fn to_issues(&self) -> Vec<SecurityIssue> {
+        let vulnerabilities = self.vulnerabilities.list.iter().map(|vulnerability| {
+            let advisory = &vulnerability.advisory;
+            let severity = advisory
+                .cvss
+                .as_deref()
+                .and_then(cvss_base_score)
+                .map(severity_for_score)
+                .unwrap_or(Severity::Medium);
+            let patched = vulnerability.versions.as_ref().map(|v| v.patched.join(", ")).unwrap_or_default();
+            let remediation = if patched.is_empty() {
+                format!("패치된 버전 없음 - {} 대체 크레이트 검토", vulnerability.package.name)
+            } else {
+                format!("cargo update -p {} (패치 버전: {})", vulnerability.package.name, patched)
+            };
+            issue(advisory, &vulnerability.package, severity, remediation)
+        });
+
+        let warnings = self.warnings.values().flatten().filter_map(|warning| {
+            let advisory = warning.advisory.as_ref()?;
+            let remediation = format!("{} 경고 ({}) - 의존성 교체 또는 업데이트 검토", warning.package.name, warning.kind);
+            Some(issue(advisory, &warning.package, Severity::Low, remediation))
+        });
+
+        vulnerabilities.chain(warnings).collect()
+    }
+}
+
+fn issue(advisory: &Advisory, package: &AffectedPackage, severity: Severity, remediation: String) -> SecurityIssue {
+    let mut references = vec![advisory
+        .url
+        .clone()
+        .unwrap_or_else(|| format!("https://rustsec.org/advisories/{}", advisory.id))];
+    references.extend(advisory.aliases.iter().cloned());
+
+    SecurityIssue {
+        id: advisory.id.clone(),
+        title: format!("{} {}: {}", package.name, package.version, advisory.title),
+        description: advisory.description.split("\n\n").next().unwrap_or_default().trim().to_string(),
+        severity,
+        category: "의존성".to_string(),
+        remediation,
+        location: Some("Cargo.lock".to_string()),
+        detected_at: chrono::Utc::now(),
+        references,
+    }
+}
+
+/// 보고서 로드 (`SECURITY_AUDIT_REPORT` 파일, 없으면 `cargo audit --json` 실행)
+pub async fn load_report() -> Result<CargoAuditReport> {
+    if let Ok(path) = std::env::var("SECURITY_AUDIT_REPORT") {
+        let json = tokio::fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("cargo audit 보고서 읽기 실패: {}", path))?;
+        return CargoAuditReport::parse(&json);
+    }
+
+    let output = tokio::time::timeout(
+        CARGO_AUDIT_TIMEOUT,
+        tokio::process::Command::new("cargo").args(["audit", "--json"]).kill_on_drop(true).output(),
+    )
+    .await
+    .map_err(|_| anyhow!("cargo audit 실행 시간 초과"))?
+    .context("cargo audit 실행 실패")?;
+
+    // 취약점이 있으면 종료 코드가 1이므로 출력이 있으면 그대로 해석
+    if output.stdout.is_empty() {
+        return Err(anyhow!(
+            "cargo audit 결과 없음 (cargo install cargo-audit 필요): {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    CargoAuditReport::parse(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// CVSS v3 기본 점수 (v3.0/v3.1 벡터만 지원)
+pub fn cvss_base_score(vector: &str) -> Option<f64> {
+    let mut parts = vector.split('/');
+    if !matches!(parts.next()?, "CVSS:3.0" | "CVSS:3.1") {
+        return None;
+    }
+    let metrics: std::collections::HashMap<&str, &str> = parts.filter_map(|part| part.split_once(':')).collect();
+    let scope_changed = match *metrics.get("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+    let cia = |key: &str| match metrics.get(key).copied() {
+        Some("H") => Some(0.56),
+        Some("L") => Some(0.22),
+        Some("N") => Some(0.0),
+        _ => None,
+    };
+    let attack_vector = match *metrics.get("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let attack_complexity = match *metrics.get("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let privileges = match (*metrics.get("PR")?, scope_changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let user_interaction = match *metrics.get("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+
+    let iss = 1.0 - (1.0 - cia("C")?) * (1.0 - cia("I")?) * (1.0 - cia("A")?);
+    let impact = if scope_changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02f64).powi(15)
+    } else {
+        6.42 * iss
+    };
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let exploitability = 8.22 * attack_vector * attack_complexity * privileges * user_interaction;
+    let score = if scope_changed { 1.08 * (impact + exploitability) } else { impact + exploitability };
+    Some(round_up(score.min(10.0)))
+}
+
+/// CVSS 명세의 소수 첫째 자리 올림
+fn round_up(value: f64) -> f64 {
+    let scaled = (value * 100_000.0).round() as i64;
+    if scaled % 10_000 == 0 {
+        scaled as f64 / 100_000.0
+    } else {
+        (scaled / 10_000 + 1) as f64 / 10.0
+    }
+}
+
+/// CVSS 점수 구간별 심각도
+pub fn severity_for_score(score: f64) -> Severity {
+    match score {
+        s if s >= 9.0 => Severity::Critical,
+        s if s >= 7.0 => Severity::High,
+        s if s >= 4.0 => Severity::Medium,
+        s if s > 0.0 => Severity::Low,
+        _ => Severity::Info,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cvss_base_score() {
+        assert_eq!(cvss_base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"), Some(9.8));
+        assert_eq!(cvss_base_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H"), Some(10.0));
+        assert_eq!(cvss_base_score("CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:N/I:N/A:H"), Some(5.9));
+        assert_eq!(cvss_base_score("CVSS:3.0/AV:L/AC:L/PR:L/UI:N/S:U/C:N/I:N/A:N"), Some(0.0));
+        assert_eq!(cvss_base_score("CVSS:4.0/AV:N/AC:L"), None);
+        assert_eq!(severity_for_score(9.8), Severity::Critical);
+        assert_eq!(severity_for_score(5.9), Severity::Medium);
+    }
+
+    #[test]
+    fn test_report_to_issues() {
+        let report = CargoAuditReport::parse(r#"{
+            "database": {"advisory-count": 600},
+            "vulnerabilities": {"found": true, "count": 2, "list": [
+                {"advisory": {"id": "RUSTSEC-2023-0001", "package": "tokio", "title": "reject_remote_clients 설정 무시",
+                              "description": "첫 문단\n\n둘째 문단", "cvss": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                              "url": null, "aliases": ["CVE-2023-22466"]},
+                 "package": {"name": "tokio", "version": "1.18.0"},
+                 "versions": {"patched": [">=1.18.4"], "unaffected": []}},
+                {"advisory": {"id": "RUSTSEC-2020-0071", "package": "time", "title": "segfault", "cvss": null},
+                 "package": {"name": "time", "version": "0.1.45"},
+                 "versions": {"patched": [], "unaffected": []}}
+            ]},
+            "warnings": {"unmaintained": [
+                {"kind": "unmaintained", "package": {"name": "ansi_term", "version": "0.12.1"},
+                 "advisory": {"id": "RUSTSEC-2021-0139", "title": "ansi_term is unmaintained"}}
+            ], "yanked": [
+                {"kind": "yanked", "package": {"name": "foo", "version": "0.1.0"}, "advisory": null}
+            ]}
+        }"#).unwrap();
+
+        let issues = report.to_issues();
+        assert_eq!(report.vulnerabilities.count, 2);
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].severity, Severity::Critical);
+        assert_eq!(issues[0].description, "첫 문단");
+        assert!(issues[0].remediation.contains(">=1.18.4"));
+        assert!(issues[0].references.contains(&"CVE-2023-22466".to_string()));
+        assert_eq!(issues[1].severity, Severity::Medium);
+        assert_eq!(issues[2].severity, Severity::Low);
+    }
+}
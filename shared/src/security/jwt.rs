@@ -6,7 +6,8 @@
 //! - 보안 검증 강화
 
 use crate::security::{SecurityConfig, SecurityError};
-use chrono::{Duration, Utc};
+use crate::tool::clock::{system_clock, SharedClock};
+use chrono::Duration;
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation, Algorithm};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -51,6 +52,8 @@ pub struct JwtManager {
     validation: Validation,
     /// 블랙리스트에 등록된 토큰들
     blacklist: Arc<RwLock<HashSet<String>>>,
+    /// 발급/만료 판정 시계
+    clock: SharedClock,
 }
 
 impl JwtManager {
@@ -64,6 +67,8 @@ impl JwtManager {
                 .map_err(|e| SecurityError::InvalidToken(format!("Invalid algorithm: {}", e)))?
         );
         validation.set_issuer(&["police-thief-game"]);
+        // 만료는 주입한 시계로 직접 확인
+        validation.validate_exp = false;
         
         Ok(Self {
             config,
@@ -71,9 +76,16 @@ impl JwtManager {
             decoding_key,
             validation,
             blacklist: Arc::new(RwLock::new(HashSet::new())),
+            clock: system_clock(),
         })
     }
     
+    /// 시계 교체 (테스트/시간 가속용)
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+    
     /// Access 토큰 생성
     pub async fn create_access_token(
         &self,
@@ -81,7 +93,7 @@ impl JwtManager {
         username: &str,
         roles: Vec<String>
     ) -> Result<String, SecurityError> {
-        let now = Utc::now();
+        let now = self.clock.now();
         let expiration = now + Duration::hours(self.config.jwt_expiration_hours as i64);
         
         let claims = Claims {
@@ -100,7 +112,7 @@ impl JwtManager {
     
     /// Refresh 토큰 생성
     pub async fn create_refresh_token(&self, user_id: &str) -> Result<String, SecurityError> {
-        let now = Utc::now();
+        let now = self.clock.now();
         let expiration = now + Duration::days(self.config.jwt_refresh_expiration_days as i64);
         
         let claims = RefreshClaims {
@@ -129,7 +141,7 @@ impl JwtManager {
             })?;
         
         // 만료 시간 재확인
-        let now = Utc::now().timestamp();
+        let now = self.clock.unix_secs();
         if token_data.claims.exp < now {
             return Err(SecurityError::TokenExpired);
         }
@@ -154,7 +166,7 @@ impl JwtManager {
         }
         
         // 만료 확인
-        let now = Utc::now().timestamp();
+        let now = self.clock.unix_secs();
         if refresh_claims.exp < now {
             return Err(SecurityError::TokenExpired);
         }
@@ -202,7 +214,7 @@ impl JwtManager {
     
     /// 만료된 토큰들을 블랙리스트에서 정리
     pub async fn cleanup_expired_tokens(&self) {
-        let _now = Utc::now().timestamp(); // TODO: 만료시간 기반 정리 구현
+        let _now = self.clock.unix_secs(); // TODO: 만료시간 기반 정리 구현
         let mut blacklist = self.blacklist.write().await;
         
         // 실제 구현에서는 토큰의 만료시간을 저장하여 정리해야 함
@@ -298,4 +310,19 @@ mod tests {
         // 이제 무효
         assert!(jwt_manager.verify_token(&token).await.is_err());
     }
+    
+    #[tokio::test]
+    async fn test_token_expiry_with_frozen_clock() {
+        let config = SecurityConfig::default();
+        let hours = config.jwt_expiration_hours;
+        let clock = crate::tool::clock::FrozenClock::new();
+        let jwt_manager = JwtManager::new(config).unwrap().with_clock(clock.clone());
+        
+        let token = jwt_manager.create_access_token("user123", "testuser", vec![]).await.unwrap();
+        clock.advance(std::time::Duration::from_secs(hours * 3600 - 1));
+        assert!(jwt_manager.verify_token(&token).await.is_ok());
+        
+        clock.advance(std::time::Duration::from_secs(2));
+        assert!(matches!(jwt_manager.verify_token(&token).await, Err(SecurityError::TokenExpired)));
+    }
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use crate::tool::clock::{system_clock, Clock, SharedClock, SystemClock};
 use base64::{engine::general_purpose, Engine as _};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -12,6 +13,8 @@ pub struct KeyManager {
     previous_keys: Arc<RwLock<Vec<KeyInfo>>>,
     rotation_interval: Duration,
     max_old_keys: usize,
+    /// 발급/만료 판정 시계
+    clock: SharedClock,
 }
 
 /// 키 정보
@@ -26,10 +29,12 @@ pub struct KeyInfo {
 
 impl KeyInfo {
     pub fn new(key: String, algorithm: String, lifetime_hours: Option<u64>) -> Self {
-        let created_at = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        Self::issued_at(key, algorithm, lifetime_hours, &SystemClock)
+    }
+    
+    /// 주입한 시계 기준으로 키 생성
+    pub fn issued_at(key: String, algorithm: String, lifetime_hours: Option<u64>, clock: &dyn Clock) -> Self {
+        let created_at = clock.unix_secs().max(0) as u64;
         
         let expires_at = lifetime_hours.map(|hours| created_at + (hours * 3600));
         
@@ -50,25 +55,29 @@ impl KeyInfo {
     }
     
     pub fn is_expired(&self) -> bool {
-        if let Some(expires_at) = self.expires_at {
-            let now = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs();
-            now >= expires_at
-        } else {
-            false
-        }
+        self.is_expired_at(&SystemClock)
+    }
+    
+    /// 주입한 시계 기준 만료 여부
+    pub fn is_expired_at(&self, clock: &dyn Clock) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| clock.unix_secs().max(0) as u64 >= expires_at)
     }
 }
 
 impl KeyManager {
     /// 새 키 매니저 생성
     pub fn new(initial_key: String, rotation_hours: u64) -> Self {
-        let key_info = KeyInfo::new(
+        Self::with_clock(initial_key, rotation_hours, system_clock())
+    }
+    
+    /// 시계를 주입해 키 매니저 생성 (테스트/시간 가속용)
+    pub fn with_clock(initial_key: String, rotation_hours: u64, clock: SharedClock) -> Self {
+        let key_info = KeyInfo::issued_at(
             initial_key,
             "HS256".to_string(),
             Some(rotation_hours * 2), // 키 수명은 로테이션 주기의 2배
+            clock.as_ref(),
         );
         
         info!("🔐 Key Manager initialized");
@@ -80,6 +89,7 @@ impl KeyManager {
             previous_keys: Arc::new(RwLock::new(Vec::new())),
             rotation_interval: Duration::from_secs(rotation_hours * 3600),
             max_old_keys: 3, // 최대 3개의 이전 키 보관
+            clock,
         };
         
         // 자동 로테이션 시작
@@ -123,7 +133,7 @@ impl KeyManager {
         // 이전 키들 확인
         let previous = self.previous_keys.read().await;
         previous.iter()
-            .find(|k| k.key_id == key_id && !k.is_expired())
+            .find(|k| k.key_id == key_id && !k.is_expired_at(self.clock.as_ref()))
             .cloned()
     }
     
@@ -133,7 +143,7 @@ impl KeyManager {
         
         let previous = self.previous_keys.read().await;
         for key in previous.iter() {
-            if !key.is_expired() {
+            if !key.is_expired_at(self.clock.as_ref()) {
                 keys.push(key.clone());
             }
         }
@@ -145,10 +155,11 @@ impl KeyManager {
     pub async fn rotate_key(&self) -> Result<KeyInfo, Box<dyn std::error::Error>> {
         // 새 키 생성
         let new_key = self.generate_secure_key();
-        let new_key_info = KeyInfo::new(
+        let new_key_info = KeyInfo::issued_at(
             new_key,
             "HS256".to_string(),
             Some((self.rotation_interval.as_secs() / 3600) * 2),
+            self.clock.as_ref(),
         );
         
         // 현재 키를 이전 키 목록으로 이동
@@ -165,7 +176,7 @@ impl KeyManager {
             previous.insert(0, old_key);
             
             // 만료된 키와 초과 키 제거
-            previous.retain(|k| !k.is_expired());
+            previous.retain(|k| !k.is_expired_at(self.clock.as_ref()));
             if previous.len() > self.max_old_keys {
                 previous.truncate(self.max_old_keys);
             }
@@ -208,6 +219,7 @@ impl KeyManager {
         let previous_keys = self.previous_keys.clone();
         let rotation_interval = self.rotation_interval;
         let max_old_keys = self.max_old_keys;
+        let clock = self.clock.clone();
         
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(rotation_interval);
@@ -217,10 +229,11 @@ impl KeyManager {
                 
                 // 새 키 생성
                 let new_key = Self::generate_secure_key_static();
-                let new_key_info = KeyInfo::new(
+                let new_key_info = KeyInfo::issued_at(
                     new_key,
                     "HS256".to_string(),
                     Some((rotation_interval.as_secs() / 3600) * 2),
+                    clock.as_ref(),
                 );
                 
                 // 키 로테이션
@@ -237,7 +250,7 @@ impl KeyManager {
                     previous.insert(0, old_key);
                     
                     // 정리
-                    previous.retain(|k| !k.is_expired());
+                    previous.retain(|k| !k.is_expired_at(clock.as_ref()));
                     if previous.len() > max_old_keys {
                         previous.truncate(max_old_keys);
                     }
@@ -278,19 +291,17 @@ impl KeyManager {
         let previous = self.previous_keys.read().await;
         
         let valid_previous_keys = previous.iter()
-            .filter(|k| !k.is_expired())
+            .filter(|k| !k.is_expired_at(self.clock.as_ref()))
             .count();
         
         let expired_previous_keys = previous.iter()
-            .filter(|k| k.is_expired())
+            .filter(|k| k.is_expired_at(self.clock.as_ref()))
             .count();
         
         KeyManagerStatus {
             current_key_id: current.key_id.clone(),
-            current_key_age_hours: ((SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs() - current.created_at) / 3600) as u32,
+            current_key_age_hours: ((self.clock.unix_secs().max(0) as u64)
+                .saturating_sub(current.created_at) / 3600) as u32,
             total_previous_keys: previous.len(),
             valid_previous_keys,
             expired_previous_keys,
@@ -354,4 +365,22 @@ mod tests {
         let all_keys = manager.get_all_valid_keys().await;
         assert!(all_keys.len() <= 4); // 현재 키 + 최대 3개 이전 키
     }
+    
+    #[tokio::test]
+    async fn test_previous_key_expires_with_clock() {
+        let clock = crate::tool::clock::FrozenClock::new();
+        let manager = KeyManager::with_clock("test_key".to_string(), 1, clock.clone());
+        let initial_key = manager.get_current_key().await;
+        manager.rotate_key().await.unwrap();
+        
+        // 키 수명은 로테이션 주기(1시간)의 2배
+        clock.advance(Duration::from_secs(2 * 3600 - 1));
+        assert!(manager.get_key_by_id(&initial_key.key_id).await.is_some());
+        
+        clock.advance(Duration::from_secs(1));
+        assert!(manager.get_key_by_id(&initial_key.key_id).await.is_none());
+        let status = manager.get_status().await;
+        assert!(status.expired_previous_keys >= 1);
+        assert_eq!(status.current_key_age_hours, 2);
+    }
 }
\ No newline at end of file
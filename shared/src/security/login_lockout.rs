@@ -0,0 +1,226 @@
+//! 로그인 무차별 대입 잠금
+//!
+//! 계정별, IP별로 로그인 실패를 Redis에 기록하고, 윈도우 안에서 실패가 한도에 도달하면
+//! 일정 시간 잠급니다. 잠김이 반복될수록 잠금 시간은 두 배씩 늘어납니다.
+//!
+//! Redis 키 (식별자는 SHA-256 해시로 저장):
+//! - `lockout:{scope}:{id}:failures` - 윈도우 내 실패 횟수 (윈도우 TTL)
+//! - `lockout:{scope}:{id}:locked` - 잠금 표시 (잠금 시간 TTL)
+//! - `lockout:{scope}:{id}:strikes` - 누적 잠금 횟수 (`strike_ttl` 동안 유지)
+//!
+//! Redis 장애 시에는 로그인을 막지 않고 경고만 남깁니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::security::SecurityError;
+use redis::AsyncCommands;
+use sha2::{Digest, Sha256};
+use std::net::IpAddr;
+use std::time::Duration;
+use tracing::warn;
+
+/// 잠금 키 접두사
+const LOCKOUT_PREFIX: &str = "lockout";
+
+/// 잠금 정책
+#[derive(Debug, Clone)]
+pub struct LockoutPolicy {
+    /// 잠금까지 허용하는 실패 횟수
+    pub max_failures: u32,
+    /// 실패 집계 윈도우
+    pub window: Duration,
+    /// 첫 잠금 시간
+    pub base_lock: Duration,
+    /// 최대 잠금 시간
+    pub max_lock: Duration,
+    /// 누적 잠금 횟수 유지 시간 (이 시간 동안 잠김이 없으면 잠금 시간 초기화)
+    pub strike_ttl: Duration,
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_failures: 5,
+            window: Duration::from_secs(15 * 60),
+            base_lock: Duration::from_secs(60),
+            max_lock: Duration::from_secs(3600),
+            strike_ttl: Duration::from_secs(24 * 3600),
+        }
+    }
+}
+
+impl LockoutPolicy {
+    /// 환경변수에서 정책 로드
+    ///
+    /// `LOCKOUT_MAX_FAILURES`, `LOCKOUT_WINDOW_MINUTES`, `LOCKOUT_BASE_SECS`,
+    /// `LOCKOUT_MAX_SECS`, `LOCKOUT_STRIKE_TTL_HOURS` (없으면 기본값)
+    pub fn from_env() -> Self {
+        fn var<T: std::str::FromStr>(key: &str) -> Option<T> {
+            std::env::var(key).ok().and_then(|v| v.parse().ok())
+        }
+
+        let default = Self::default();
+        Self {
+            max_failures: var("LOCKOUT_MAX_FAILURES").filter(|n| *n > 0).unwrap_or(default.max_failures),
+            window: var("LOCKOUT_WINDOW_MINUTES").map(|m: u64| Duration::from_secs(m * 60)).unwrap_or(default.window),
+            base_lock: var("LOCKOUT_BASE_SECS").map(Duration::from_secs).unwrap_or(default.base_lock),
+            max_lock: var("LOCKOUT_MAX_SECS").map(Duration::from_secs).unwrap_or(default.max_lock),
+            strike_ttl: var("LOCKOUT_STRIKE_TTL_HOURS").map(|h: u64| Duration::from_secs(h * 3600)).unwrap_or(default.strike_ttl),
+        }
+    }
+
+    /// `strike`번째 잠금의 잠금 시간 (1부터 시작, 매번 두 배, 최대 `max_lock`)
+    pub fn lock_duration(&self, strike: u32) -> Duration {
+        let factor = 1u32.checked_shl(strike.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.base_lock.saturating_mul(factor).min(self.max_lock)
+    }
+}
+
+/// 잠금 대상 범위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockoutScope {
+    Account,
+    Ip,
+}
+
+impl LockoutScope {
+    fn as_str(&self) -> &'static str {
+        match self {
+            LockoutScope::Account => "account",
+            LockoutScope::Ip => "ip",
+        }
+    }
+}
+
+/// 로그인 잠금 관리자
+#[derive(Clone)]
+pub struct LoginLockout {
+    redis_config: RedisConfig,
+    policy: LockoutPolicy,
+}
+
+impl LoginLockout {
+    pub fn new(redis_config: RedisConfig, policy: LockoutPolicy) -> Self {
+        Self { redis_config, policy }
+    }
+
+    /// 계정 또는 IP가 잠겨 있으면 `SecurityError::AccountLocked` 반환
+    pub async fn check(&self, account: &str, ip: Option<IpAddr>) -> Result<(), SecurityError> {
+        let mut conn = self.redis_config.get_connection();
+        let mut pipe = redis::pipe();
+        for (scope, id) in Self::targets(account, ip) {
+            pipe.pttl(lockout_key(scope, &id, "locked"));
+        }
+
+        let ttls: Vec<i64> = match pipe.query_async(&mut conn).await {
+            Ok(ttls) => ttls,
+            Err(e) => {
+                warn!("로그인 잠금 상태 조회 실패 (잠금 검사 생략): {}", e);
+                return Ok(());
+            }
+        };
+
+        match ttls.into_iter().filter(|ttl| *ttl > 0).max() {
+            Some(ms) => Err(SecurityError::AccountLocked { retry_after: Duration::from_millis(ms as u64) }),
+            None => Ok(()),
+        }
+    }
+
+    /// 로그인 실패 기록 (이번 실패로 잠기면 `SecurityError::AccountLocked` 반환)
+    pub async fn record_failure(&self, account: &str, ip: Option<IpAddr>) -> Result<(), SecurityError> {
+        let mut locked_for = None;
+        for (scope, id) in Self::targets(account, ip) {
+            match self.record_scope_failure(scope, &id).await {
+                Ok(Some(duration)) => {
+                    warn!("로그인 실패 누적으로 잠금: scope={}, duration={}s", scope.as_str(), duration.as_secs());
+                    locked_for = locked_for.max(Some(duration));
+                }
+                Ok(None) => {}
+                Err(e) => warn!("로그인 실패 기록 실패: scope={}, error={}", scope.as_str(), e),
+            }
+        }
+
+        match locked_for {
+            Some(retry_after) => Err(SecurityError::AccountLocked { retry_after }),
+            None => Ok(()),
+        }
+    }
+
+    /// 로그인 성공 시 계정 실패 횟수 초기화 (IP 실패 횟수는 유지)
+    pub async fn record_success(&self, account: &str) {
+        let mut conn = self.redis_config.get_connection();
+        let key = lockout_key(LockoutScope::Account, account, "failures");
+        if let Err(e) = conn.del::<_, ()>(key).await {
+            warn!("로그인 실패 횟수 초기화 실패: {}", e);
+        }
+    }
+
+    /// 범위별 실패 기록 (잠금이 걸리면 잠금 시간 반환)
+    async fn record_scope_failure(&self, scope: LockoutScope, id: &str) -> redis::RedisResult<Option<Duration>> {
+        let mut conn = self.redis_config.get_connection();
+        let failures_key = lockout_key(scope, id, "failures");
+
+        let failures: u32 = conn.incr(&failures_key, 1).await?;
+        if failures == 1 {
+            conn.expire::<_, ()>(&failures_key, self.policy.window.as_secs().max(1) as i64).await?;
+        }
+        if failures < self.policy.max_failures {
+            return Ok(None);
+        }
+
+        let strikes_key = lockout_key(scope, id, "strikes");
+        let strike: u32 = conn.incr(&strikes_key, 1).await?;
+        let duration = self.policy.lock_duration(strike);
+
+        let mut pipe = redis::pipe();
+        pipe.expire(&strikes_key, self.policy.strike_ttl.as_secs().max(1) as i64).ignore()
+            .pset_ex(lockout_key(scope, id, "locked"), strike, duration.as_millis().max(1) as u64).ignore()
+            .del(&failures_key).ignore();
+        pipe.query_async::<_, ()>(&mut conn).await?;
+
+        Ok(Some(duration))
+    }
+
+    fn targets(account: &str, ip: Option<IpAddr>) -> Vec<(LockoutScope, String)> {
+        let mut targets = vec![(LockoutScope::Account, account.to_string())];
+        if let Some(ip) = ip {
+            targets.push((LockoutScope::Ip, ip.to_string()));
+        }
+        targets
+    }
+}
+
+/// 잠금 키 (`lockout:{scope}:{hash}:{suffix}`)
+fn lockout_key(scope: LockoutScope, id: &str, suffix: &str) -> String {
+    let digest = Sha256::digest(id.as_bytes());
+    format!("{}:{}:{}:{}", LOCKOUT_PREFIX, scope.as_str(), hex::encode(&digest[..16]), suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lock_duration_growth() {
+        let policy = LockoutPolicy::default();
+        assert_eq!(policy.lock_duration(1), Duration::from_secs(60));
+        assert_eq!(policy.lock_duration(2), Duration::from_secs(120));
+        assert_eq!(policy.lock_duration(4), Duration::from_secs(480));
+        assert_eq!(policy.lock_duration(7), Duration::from_secs(3600));
+        assert_eq!(policy.lock_duration(64), Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn test_lockout_key_hides_identifier() {
+        let key = lockout_key(LockoutScope::Account, "google:token-abc", "failures");
+        assert!(key.starts_with("lockout:account:"));
+        assert!(key.ends_with(":failures"));
+        assert!(!key.contains("token-abc"));
+        assert_eq!(key, lockout_key(LockoutScope::Account, "google:token-abc", "failures"));
+    }
+
+    #[test]
+    fn test_account_locked_message() {
+        let error = SecurityError::AccountLocked { retry_after: Duration::from_secs(90) };
+        assert_eq!(error.to_string(), "Account locked: retry after 90s");
+    }
+}
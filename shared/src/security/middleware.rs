@@ -4,7 +4,8 @@
 //! - 통합 보안 검증 레이어
 
 use crate::security::{
-    CryptoManager, InputValidator, JwtManager, RateLimiter, SecurityConfig, SecurityError,
+    CryptoManager, EndpointPolicy, InputValidator, JwtManager, PolicyEnforcer, RateLimiter,
+    SecurityConfig, SecurityError, SecurityPolicy, UserRole,
 };
 use std::net::IpAddr;
 use std::sync::Arc;
@@ -16,6 +17,8 @@ pub struct SecurityMiddleware {
     input_validator: Arc<InputValidator>,
     rate_limiter: Arc<RateLimiter>,
     crypto_manager: Arc<CryptoManager>,
+    /// 엔드포인트별 보안 정책 (`SECURITY_POLICY_FILE`)
+    policy: Arc<PolicyEnforcer>,
 }
 
 impl SecurityMiddleware {
@@ -25,12 +28,14 @@ impl SecurityMiddleware {
         let input_validator = Arc::new(InputValidator::new());
         let rate_limiter = Arc::new(RateLimiter::from_security_config(&config));
         let crypto_manager = Arc::new(CryptoManager::new(config.clone()));
+        let policy = Arc::new(PolicyEnforcer::new(SecurityPolicy::from_env()?));
         
         Ok(Self {
             jwt_manager,
             input_validator,
             rate_limiter,
             crypto_manager,
+            policy,
         })
     }
     
@@ -40,6 +45,12 @@ impl SecurityMiddleware {
         Self::new(config).await
     }
     
+    /// 보안 정책 교체 (`SECURITY_POLICY_FILE` 대신 지정한 정책 사용)
+    pub fn with_policy(mut self, policy: SecurityPolicy) -> Self {
+        self.policy = Arc::new(PolicyEnforcer::new(policy));
+        self
+    }
+    
     /// JWT 토큰 인증
     pub async fn authenticate(&self, token: &str) -> Result<crate::security::Claims, SecurityError> {
         self.jwt_manager.verify_token(token).await
@@ -50,6 +61,19 @@ impl SecurityMiddleware {
         self.rate_limiter.is_allowed(ip).await
     }
     
+    /// 엔드포인트 보안 정책 검사
+    ///
+    /// `roles`가 `None`이면 미인증 요청으로 취급합니다.
+    pub fn check_endpoint(
+        &self,
+        endpoint: &str,
+        client: &str,
+        payload_len: usize,
+        roles: Option<&[UserRole]>,
+    ) -> Result<EndpointPolicy, SecurityError> {
+        self.policy.check(endpoint, client, payload_len, roles)
+    }
+    
     /// 입력 데이터 검증
     pub fn validate_input(&self, data: &str) -> Result<(), SecurityError> {
         self.input_validator.validate_json(data)
@@ -194,6 +218,11 @@ impl SecurityMiddleware {
     pub fn crypto_manager(&self) -> &CryptoManager {
         &self.crypto_manager
     }
+    
+    /// 보안 정책 적용기 반환
+    pub fn policy(&self) -> Arc<PolicyEnforcer> {
+        self.policy.clone()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-//! 보안 모듈 - JWT, 입력검증, Rate Limiting, 암호화
+//! 보안 모듈 - JWT, 입력검증, Rate Limiting, 암호화, 감사 로그
 //! 
 //! 모든 서비스에서 사용할 수 있는 보안 기능을 제공합니다.
 
@@ -10,15 +10,24 @@ pub mod middleware;
 pub mod redis_command_validator;
 pub mod access_control;
 pub mod security_auditor;
+pub mod dependency_audit;
+pub mod audit_log;
 pub mod input_validator;
 pub mod key_manager;
+pub mod login_lockout;
+pub mod session_quota;
+pub mod policy;
 
 pub use access_control::*;
+pub use audit_log::{AuditEvent, AuditEventKind, AuditRecord, AuditSink, AuditTarget};
 pub use crypto::*;
 pub use input_validator::{InputType, InputValidator, PasswordStrength};
 pub use jwt::*;
 pub use key_manager::{KeyInfo, KeyManager};
+pub use login_lockout::{LockoutPolicy, LockoutScope, LoginLockout};
+pub use session_quota::{SessionQuota, SessionQuotaPolicy};
 pub use middleware::*;
+pub use policy::{EndpointPolicy, PolicyEnforcer, SecurityPolicy};
 pub use rate_limiter::*;
 pub use redis_command_validator::*;
 pub use security_auditor::*;
@@ -51,6 +60,12 @@ pub enum SecurityError {
     
     #[error("Message too large: {current} bytes (max: {max})")]
     MessageTooLarge { current: usize, max: usize },
+    
+    #[error("Account locked: retry after {}s", retry_after.as_secs())]
+    AccountLocked { retry_after: std::time::Duration },
+
+    #[error("Too many active sessions (max: {max})")]
+    SessionLimitExceeded { max: u32 },
 }
 
 /// 보안 설정
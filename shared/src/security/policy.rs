@@ -0,0 +1,479 @@
+//! 엔드포인트별 보안 정책 (선언형)
+//!
+//! 인증 필요 여부, 최소 역할, 분당 요청 한도, 최대 페이로드 크기를 정책 파일에서
+//! 엔드포인트 패턴별로 지정합니다. 엔드포인트 식별자는 서버별로 다음과 같습니다.
+//! - gRPC: 메서드 경로 (`/room.RoomService/MakeRoom`)
+//! - TCP: `tcp:<메시지 타입>` (`tcp:chat`)
+//! - RUDP: `rudp:<메시지 타입>` (`rudp:attack`)
+//!
+//! 패턴은 `*` 와일드카드를 지원하며, 위에서부터 처음 일치하는 규칙 하나만 적용됩니다.
+//! 규칙에서 지정하지 않은 항목은 `[default]` 섹션을 따릅니다.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::security::{SecurityError, UserRole};
+
+/// 정책 파일 경로 환경변수
+pub const POLICY_FILE_ENV: &str = "SECURITY_POLICY_FILE";
+
+/// 기본 정책 파일 경로
+pub const DEFAULT_POLICY_FILE: &str = "property/security_policy.toml";
+
+/// 요청 한도 창 크기
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// 요청 한도 창 정리를 시작하는 항목 수
+const RATE_PRUNE_THRESHOLD: usize = 10_000;
+
+/// 정책 항목 (미지정 항목은 상위 설정을 따름)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicySettings {
+    /// 인증 필요 여부
+    pub auth_required: Option<bool>,
+    /// 최소 역할 (`user`, `moderator`, `admin` 등)
+    pub min_role: Option<String>,
+    /// 클라이언트별 분당 요청 한도
+    pub rate_limit_per_minute: Option<u32>,
+    /// 최대 페이로드 크기 (바이트)
+    pub max_payload_bytes: Option<usize>,
+}
+
+/// 엔드포인트 규칙
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EndpointRule {
+    /// 엔드포인트 패턴 (`*` 와일드카드)
+    pub pattern: String,
+    pub auth_required: Option<bool>,
+    pub min_role: Option<String>,
+    pub rate_limit_per_minute: Option<u32>,
+    pub max_payload_bytes: Option<usize>,
+}
+
+impl EndpointRule {
+    fn settings(&self) -> PolicySettings {
+        PolicySettings {
+            auth_required: self.auth_required,
+            min_role: self.min_role.clone(),
+            rate_limit_per_minute: self.rate_limit_per_minute,
+            max_payload_bytes: self.max_payload_bytes,
+        }
+    }
+}
+
+/// 보안 정책 파일
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SecurityPolicy {
+    /// 모든 엔드포인트의 기본값
+    #[serde(default)]
+    pub default: PolicySettings,
+    /// 엔드포인트 규칙 (위에서부터 첫 일치)
+    #[serde(default, rename = "endpoint")]
+    pub endpoints: Vec<EndpointRule>,
+}
+
+/// 엔드포인트에 적용되는 최종 정책
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointPolicy {
+    /// 일치한 규칙 패턴 (없으면 `None`, 기본값 적용)
+    pub pattern: Option<String>,
+    pub auth_required: bool,
+    pub min_role: Option<UserRole>,
+    pub rate_limit_per_minute: Option<u32>,
+    pub max_payload_bytes: Option<usize>,
+}
+
+impl SecurityPolicy {
+    /// 코드에 흩어져 있던 기존 동작을 그대로 옮긴 내장 정책
+    ///
+    /// 정책 파일이 없을 때 사용하며, 시작 시 변경 내역(diff)의 기준이 됩니다.
+    pub fn builtin() -> Self {
+        Self {
+            default: PolicySettings::default(),
+            endpoints: vec![EndpointRule {
+                // RUDP 패킷 검증의 64KB 제한
+                pattern: "rudp:*".to_string(),
+                auth_required: None,
+                min_role: None,
+                rate_limit_per_minute: None,
+                max_payload_bytes: Some(65536),
+            }],
+        }
+    }
+
+    /// TOML 텍스트에서 정책을 읽고 검증합니다.
+    pub fn parse(text: &str) -> Result<Self, SecurityError> {
+        let policy: Self = toml::from_str(text)
+            .map_err(|e| SecurityError::InvalidInput(format!("보안 정책 파싱 실패: {e}")))?;
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    /// 정책 파일을 읽습니다.
+    pub fn load(path: &Path) -> Result<Self, SecurityError> {
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            SecurityError::InvalidInput(format!("보안 정책 파일 읽기 실패 ({}): {e}", path.display()))
+        })?;
+        Self::parse(&text)
+    }
+
+    /// `SECURITY_POLICY_FILE`(기본 `property/security_policy.toml`)에서 정책을 읽습니다.
+    ///
+    /// 경로를 지정하지 않았고 기본 파일도 없으면 내장 정책을 사용합니다.
+    /// 읽은 정책과 내장 정책의 차이를 로그로 남깁니다.
+    pub fn from_env() -> Result<Self, SecurityError> {
+        let (path, explicit) = match std::env::var(POLICY_FILE_ENV) {
+            Ok(path) => (path, true),
+            Err(_) => (DEFAULT_POLICY_FILE.to_string(), false),
+        };
+        let path = Path::new(&path);
+        if !explicit && !path.exists() {
+            info!("🛡️ 보안 정책 파일 없음 ({}), 내장 정책 사용", path.display());
+            return Ok(Self::builtin());
+        }
+
+        let policy = Self::load(path)?;
+        let changes = policy.diff(&Self::builtin());
+        info!(
+            "🛡️ 보안 정책 로드: {} (규칙 {}개, 내장 정책 대비 변경 {}건)",
+            path.display(),
+            policy.endpoints.len(),
+            changes.len()
+        );
+        for change in &changes {
+            info!("  └─ {}", change);
+        }
+        Ok(policy)
+    }
+
+    /// 정책 검증 (빈/중복 패턴, 알 수 없는 역할, 0 한도)
+    pub fn validate(&self) -> Result<(), SecurityError> {
+        let mut issues = Vec::new();
+        validate_settings("default", &self.default, &mut issues);
+
+        let mut seen = HashSet::new();
+        for (index, rule) in self.endpoints.iter().enumerate() {
+            let label = format!("endpoint[{index}] '{}'", rule.pattern);
+            if rule.pattern.trim().is_empty() {
+                issues.push(format!("endpoint[{index}]: 빈 패턴"));
+            } else if !seen.insert(rule.pattern.as_str()) {
+                issues.push(format!("{label}: 중복 패턴"));
+            }
+            validate_settings(&label, &rule.settings(), &mut issues);
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(SecurityError::InvalidInput(format!("보안 정책 검증 실패: {}", issues.join("; "))))
+        }
+    }
+
+    /// 엔드포인트에 적용될 정책을 계산합니다.
+    pub fn resolve(&self, endpoint: &str) -> EndpointPolicy {
+        let rule = self.endpoints.iter().find(|rule| glob_match(&rule.pattern, endpoint));
+        let settings = rule.map(EndpointRule::settings).unwrap_or_default();
+        let default = &self.default;
+
+        EndpointPolicy {
+            pattern: rule.map(|rule| rule.pattern.clone()),
+            auth_required: settings.auth_required.or(default.auth_required).unwrap_or(false),
+            min_role: settings
+                .min_role
+                .as_ref()
+                .or(default.min_role.as_ref())
+                .and_then(|role| UserRole::from_str(role)),
+            rate_limit_per_minute: settings.rate_limit_per_minute.or(default.rate_limit_per_minute),
+            max_payload_bytes: settings.max_payload_bytes.or(default.max_payload_bytes),
+        }
+    }
+
+    /// 기준 정책 대비 변경 내역 (`+` 추가, `-` 삭제, `~` 변경)
+    pub fn diff(&self, base: &SecurityPolicy) -> Vec<String> {
+        let mut changes = Vec::new();
+        for (field, before, after) in settings_diff(&base.default, &self.default) {
+            changes.push(format!("~ default.{field}: {before} -> {after}"));
+        }
+
+        let base_rules: BTreeMap<&str, &EndpointRule> =
+            base.endpoints.iter().map(|rule| (rule.pattern.as_str(), rule)).collect();
+        let rules: BTreeMap<&str, &EndpointRule> =
+            self.endpoints.iter().map(|rule| (rule.pattern.as_str(), rule)).collect();
+
+        for (pattern, rule) in &rules {
+            match base_rules.get(pattern) {
+                None => changes.push(format!("+ {pattern}: {}", describe(&rule.settings()))),
+                Some(before) => {
+                    for (field, old, new) in settings_diff(&before.settings(), &rule.settings()) {
+                        changes.push(format!("~ {pattern}.{field}: {old} -> {new}"));
+                    }
+                }
+            }
+        }
+        for pattern in base_rules.keys().filter(|pattern| !rules.contains_key(*pattern)) {
+            changes.push(format!("- {pattern}"));
+        }
+        changes
+    }
+}
+
+fn validate_settings(label: &str, settings: &PolicySettings, issues: &mut Vec<String>) {
+    if let Some(role) = &settings.min_role {
+        if UserRole::from_str(role).is_none() {
+            issues.push(format!("{label}: 알 수 없는 역할 '{role}'"));
+        }
+    }
+    if settings.rate_limit_per_minute == Some(0) {
+        issues.push(format!("{label}: rate_limit_per_minute는 0보다 커야 함"));
+    }
+    if settings.max_payload_bytes == Some(0) {
+        issues.push(format!("{label}: max_payload_bytes는 0보다 커야 함"));
+    }
+}
+
+fn settings_diff(before: &PolicySettings, after: &PolicySettings) -> Vec<(&'static str, String, String)> {
+    fn show<T: std::fmt::Display>(value: &Option<T>) -> String {
+        value.as_ref().map_or_else(|| "-".to_string(), |v| v.to_string())
+    }
+
+    let fields = [
+        ("auth_required", show(&before.auth_required), show(&after.auth_required)),
+        ("min_role", show(&before.min_role), show(&after.min_role)),
+        ("rate_limit_per_minute", show(&before.rate_limit_per_minute), show(&after.rate_limit_per_minute)),
+        ("max_payload_bytes", show(&before.max_payload_bytes), show(&after.max_payload_bytes)),
+    ];
+    fields.into_iter().filter(|(_, old, new)| old != new).collect()
+}
+
+fn describe(settings: &PolicySettings) -> String {
+    let parts: Vec<String> = settings_diff(&PolicySettings::default(), settings)
+        .into_iter()
+        .map(|(field, _, value)| format!("{field}={value}"))
+        .collect();
+    if parts.is_empty() {
+        "(기본값)".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// `*` 와일드카드 패턴 일치 (`*`는 `/`를 포함한 임의 문자열)
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let remaining: Vec<&str> = parts.collect();
+    let Some((last, middle)) = remaining.split_last() else {
+        // 와일드카드 없음
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// 정책 적용기
+///
+/// 정책 조회와 클라이언트별 요청 한도(1분 고정 창)를 함께 관리합니다.
+/// 한도는 일치한 규칙 단위로 계산되므로 같은 규칙에 묶인 엔드포인트는 한도를 공유합니다.
+pub struct PolicyEnforcer {
+    policy: SecurityPolicy,
+    /// (규칙 패턴, 클라이언트) -> (창 시작, 요청 수)
+    windows: Mutex<HashMap<(String, String), (Instant, u32)>>,
+}
+
+impl PolicyEnforcer {
+    pub fn new(policy: SecurityPolicy) -> Self {
+        Self {
+            policy,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 환경변수의 정책 파일로 생성 (`SecurityPolicy::from_env`)
+    pub fn from_env() -> Result<Self, SecurityError> {
+        SecurityPolicy::from_env().map(Self::new)
+    }
+
+    /// 적용 중인 정책
+    pub fn policy(&self) -> &SecurityPolicy {
+        &self.policy
+    }
+
+    /// 요청이 엔드포인트 정책을 만족하는지 확인합니다.
+    ///
+    /// # Arguments
+    /// * `endpoint` - 엔드포인트 식별자 (`/pkg.Service/Method`, `tcp:chat` 등)
+    /// * `client` - 요청 한도 단위 (IP 또는 사용자 ID)
+    /// * `payload_len` - 페이로드 크기 (바이트)
+    /// * `roles` - 인증된 요청자의 역할 (`None`이면 미인증)
+    ///
+    /// # Returns
+    /// * `Result<EndpointPolicy, SecurityError>` - 통과 시 적용된 정책
+    pub fn check(
+        &self,
+        endpoint: &str,
+        client: &str,
+        payload_len: usize,
+        roles: Option<&[UserRole]>,
+    ) -> Result<EndpointPolicy, SecurityError> {
+        let policy = self.policy.resolve(endpoint);
+
+        if let Some(max) = policy.max_payload_bytes {
+            if payload_len > max {
+                return Err(SecurityError::MessageTooLarge { current: payload_len, max });
+            }
+        }
+
+        if policy.auth_required && roles.is_none() {
+            return Err(SecurityError::AuthenticationFailed(format!("{endpoint} 요청에는 인증이 필요합니다")));
+        }
+
+        if let Some(min_role) = &policy.min_role {
+            let allowed = roles
+                .unwrap_or_default()
+                .iter()
+                .any(|role| role.inherits_from(min_role));
+            if !allowed {
+                return Err(SecurityError::AuthorizationDenied(format!(
+                    "{endpoint} 요청에는 {min_role:?} 이상의 역할이 필요합니다"
+                )));
+            }
+        }
+
+        if let Some(limit) = policy.rate_limit_per_minute {
+            let pattern = policy.pattern.clone().unwrap_or_default();
+            if !self.consume(pattern, client, limit) {
+                warn!(endpoint = %endpoint, client = %client, limit, "엔드포인트 요청 한도 초과");
+                return Err(SecurityError::RateLimitExceeded);
+            }
+        }
+
+        Ok(policy)
+    }
+
+    fn consume(&self, pattern: String, client: &str, limit: u32) -> bool {
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        if windows.len() > RATE_PRUNE_THRESHOLD {
+            windows.retain(|_, (started, _)| now.duration_since(*started) < RATE_WINDOW);
+        }
+
+        let (started, count) = windows.entry((pattern, client.to_string())).or_insert((now, 0));
+        if now.duration_since(*started) >= RATE_WINDOW {
+            *started = now;
+            *count = 0;
+        }
+        if *count >= limit {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+        [default]
+        max_payload_bytes = 1024
+
+        [[endpoint]]
+        pattern = "/user.UserService/LoginUser"
+        rate_limit_per_minute = 2
+
+        [[endpoint]]
+        pattern = "/moderation.*"
+        auth_required = true
+        min_role = "moderator"
+
+        [[endpoint]]
+        pattern = "tcp:*"
+        auth_required = true
+    "#;
+
+    #[test]
+    fn test_resolve_and_enforce() {
+        let enforcer = PolicyEnforcer::new(SecurityPolicy::parse(SAMPLE).unwrap());
+        let user = [UserRole::User];
+        let moderator = [UserRole::Admin];
+
+        // 첫 일치 규칙 + 기본값 상속
+        let login = enforcer.policy().resolve("/user.UserService/LoginUser");
+        assert_eq!(login.pattern.as_deref(), Some("/user.UserService/LoginUser"));
+        assert_eq!(login.max_payload_bytes, Some(1024));
+        assert!(!login.auth_required);
+
+        assert!(matches!(
+            enforcer.check("/room.RoomService/MakeRoom", "1.1.1.1", 2048, None),
+            Err(SecurityError::MessageTooLarge { current: 2048, max: 1024 })
+        ));
+        assert!(matches!(
+            enforcer.check("tcp:chat", "7", 10, None),
+            Err(SecurityError::AuthenticationFailed(_))
+        ));
+        assert!(enforcer.check("tcp:chat", "7", 10, Some(&user)).is_ok());
+        assert!(matches!(
+            enforcer.check("/moderation.ModerationService/TailChat", "7", 0, Some(&user)),
+            Err(SecurityError::AuthorizationDenied(_))
+        ));
+        assert!(enforcer.check("/moderation.ModerationService/TailChat", "7", 0, Some(&moderator)).is_ok());
+
+        // 클라이언트별 분당 한도
+        for _ in 0..2 {
+            assert!(enforcer.check("/user.UserService/LoginUser", "1.1.1.1", 10, None).is_ok());
+        }
+        assert!(matches!(
+            enforcer.check("/user.UserService/LoginUser", "1.1.1.1", 10, None),
+            Err(SecurityError::RateLimitExceeded)
+        ));
+        assert!(enforcer.check("/user.UserService/LoginUser", "2.2.2.2", 10, None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_and_diff() {
+        let invalid = r#"
+            [[endpoint]]
+            pattern = "tcp:*"
+            min_role = "overlord"
+
+            [[endpoint]]
+            pattern = "tcp:*"
+            rate_limit_per_minute = 0
+        "#;
+        let err = SecurityPolicy::parse(invalid).unwrap_err().to_string();
+        assert!(err.contains("overlord"));
+        assert!(err.contains("중복 패턴"));
+        assert!(err.contains("rate_limit_per_minute"));
+        assert!(SecurityPolicy::parse("[[endpoint]]\npattern = \"x\"\nunknown = 1").is_err());
+
+        let policy = SecurityPolicy::parse(SAMPLE).unwrap();
+        let diff = policy.diff(&SecurityPolicy::builtin());
+        assert!(diff.contains(&"~ default.max_payload_bytes: - -> 1024".to_string()));
+        assert!(diff.contains(&"+ tcp:*: auth_required=true".to_string()));
+        assert!(diff.contains(&"- rudp:*".to_string()));
+        assert!(SecurityPolicy::builtin().diff(&SecurityPolicy::builtin()).is_empty());
+
+        assert!(glob_match("/room.*/Get*", "/room.RoomService/GetRoomList"));
+        assert!(!glob_match("/room.*/Get*", "/room.RoomService/MakeRoom"));
+        assert!(glob_match("tcp:chat", "tcp:chat"));
+        assert!(!glob_match("tcp:chat", "tcp:chat_reply"));
+    }
+}
@@ -4,6 +4,7 @@
 //! 지속적인 보안 모니터링 및 자동화된 보안 검증을 위한 포괄적인 구현.
 
 use crate::security::{SecurityConfig, RedisCommandValidator, AccessControlMatrix, RateLimiter};
+use crate::security::dependency_audit;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -863,23 +864,30 @@ impl SecurityAuditor {
         info!("🔍 의존성 보안 검사 시작");
         self.check_count += 1;
         
-        // Cargo.toml 검사
-        if let Ok(cargo_content) = fs::read_to_string("Cargo.toml") {
-            // 간단한 의존성 검사 (실제 환경에서는 cargo audit 사용 권장)
-            if cargo_content.contains("openssl") {
+        // RustSec 권고 DB 기준 취약점 검사 (cargo audit)
+        match dependency_audit::load_report().await {
+            Ok(report) => {
+                let issues = report.to_issues();
+                info!("의존성 취약점 {}개, 경고 {}개", report.vulnerabilities.count, issues.len() - report.vulnerabilities.list.len());
+                if issues.is_empty() {
+                    self.passed_count += 1;
+                }
+                for issue in issues {
+                    self.add_issue(issue);
+                }
+            }
+            Err(e) => {
                 self.add_issue(SecurityIssue {
-                    id: "OPENSSL_DEPENDENCY".to_string(),
-                    title: "OpenSSL 의존성 감지".to_string(),
-                    description: "OpenSSL에 의존하고 있습니다. 정기적인 업데이트가 필요합니다.".to_string(),
+                    id: "DEPENDENCY_AUDIT_UNAVAILABLE".to_string(),
+                    title: "의존성 취약점 검사 불가".to_string(),
+                    description: format!("cargo audit 결과를 얻지 못했습니다: {:#}", e),
                     severity: Severity::Low,
                     category: "의존성".to_string(),
-                    remediation: "정기적인 cargo update 및 보안 패치 적용".to_string(),
-                    location: Some("Cargo.toml".to_string()),
+                    remediation: "cargo install cargo-audit 후 재실행하거나 SECURITY_AUDIT_REPORT에 cargo audit --json 결과 경로 지정".to_string(),
+                    location: Some("Cargo.lock".to_string()),
                     detected_at: chrono::Utc::now(),
                     references: vec!["https://rustsec.org/".to_string()],
                 });
-            } else {
-                self.passed_count += 1;
             }
         }
         
@@ -0,0 +1,223 @@
+//! 계정별 동시 세션 수 제한
+//!
+//! 로그인마다 새 리프레시 토큰 패밀리(세션)를 발급하고, 계정의 활성 패밀리 수를 Redis에 기록합니다.
+//! 한도에 도달한 상태에서 로그인하면 정책에 따라 가장 오래된 세션을 밀어내거나 로그인을 거부합니다.
+//! 밀려난 세션은 이벤트 버스(`events:session_evicted`)로 알려 게임 서버가 연결을 끊도록 합니다.
+//!
+//! Redis 키:
+//! - `session:families:{user_id}` - 활성 패밀리 (ZSET, score = 발급 시각, 리프레시 토큰 수명 TTL)
+//!
+//! 검사/추가/밀어내기는 Lua 스크립트 하나로 처리해 여러 기기의 동시 로그인에도 한도를 넘지 않습니다.
+//! Redis 장애 시에는 로그인을 막지 않고 경고만 남깁니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::security::SecurityError;
+use crate::service::redis::event_bus::{EventBus, SessionEvicted};
+use redis::AsyncCommands;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// 세션 키 접두사
+const SESSION_PREFIX: &str = "session:families";
+
+/// 만료 패밀리 정리 -> 한도 검사 -> (밀어내기) -> 추가
+///
+/// ARGV: 현재 시각, 만료 기준 시각, 최대 세션 수, 밀어내기 여부(0/1), 패밀리 ID, TTL(초)
+/// 한도 초과로 거부하면 nil, 아니면 밀려난 패밀리 목록을 반환합니다.
+/// 개발 샌드박스 Redis는 이 원문의 SHA1로 같은 동작의 내장 구현을 찾으므로, 수정 시 함께 맞춰야 합니다.
+pub const ADMIT_SCRIPT: &str = r#"
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', ARGV[2])
+local max = tonumber(ARGV[3])
+local count = redis.call('ZCARD', KEYS[1])
+local evicted = {}
+if count >= max then
+  if ARGV[4] == '0' then
+    return false
+  end
+  evicted = redis.call('ZRANGE', KEYS[1], 0, count - max)
+  redis.call('ZREMRANGEBYRANK', KEYS[1], 0, count - max)
+end
+redis.call('ZADD', KEYS[1], ARGV[1], ARGV[5])
+redis.call('EXPIRE', KEYS[1], ARGV[6])
+return evicted
+"#;
+
+/// 세션 수 제한 정책
+#[derive(Debug, Clone)]
+pub struct SessionQuotaPolicy {
+    /// 계정당 최대 동시 세션 수 (0이면 제한 없음)
+    pub max_sessions: u32,
+    /// 한도 도달 시 가장 오래된 세션을 밀어낼지 여부 (false면 새 로그인 거부)
+    pub evict_oldest: bool,
+    /// 세션(리프레시 토큰 패밀리) 유지 시간
+    pub family_ttl: Duration,
+}
+
+impl Default for SessionQuotaPolicy {
+    fn default() -> Self {
+        Self {
+            max_sessions: 3,
+            evict_oldest: true,
+            family_ttl: Duration::from_secs(7 * 24 * 3600),
+        }
+    }
+}
+
+impl SessionQuotaPolicy {
+    /// 환경변수에서 정책 로드
+    ///
+    /// `SESSION_MAX_PER_ACCOUNT`, `SESSION_EVICT_OLDEST`,
+    /// `JWT_REFRESH_EXPIRATION_DAYS` (없으면 기본값)
+    pub fn from_env() -> Self {
+        fn var<T: std::str::FromStr>(key: &str) -> Option<T> {
+            std::env::var(key).ok().and_then(|v| v.parse().ok())
+        }
+
+        let default = Self::default();
+        Self {
+            max_sessions: var("SESSION_MAX_PER_ACCOUNT").unwrap_or(default.max_sessions),
+            evict_oldest: var("SESSION_EVICT_OLDEST").unwrap_or(default.evict_oldest),
+            family_ttl: var("JWT_REFRESH_EXPIRATION_DAYS")
+                .filter(|d: &u64| *d > 0)
+                .map(|d| Duration::from_secs(d * 24 * 3600))
+                .unwrap_or(default.family_ttl),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_sessions > 0
+    }
+}
+
+/// 세션 수 제한 관리자
+#[derive(Clone)]
+pub struct SessionQuota {
+    redis_config: RedisConfig,
+    policy: SessionQuotaPolicy,
+}
+
+impl SessionQuota {
+    pub fn new(redis_config: RedisConfig, policy: SessionQuotaPolicy) -> Self {
+        Self { redis_config, policy }
+    }
+
+    /// 새 리프레시 토큰 패밀리 ID
+    pub fn new_family_id() -> String {
+        uuid::Uuid::new_v4().simple().to_string()
+    }
+
+    /// 새 세션 등록
+    ///
+    /// 한도를 넘으면 가장 오래된 세션을 밀어내고 게임 서버에 알립니다.
+    /// 밀어내기를 끈 정책에서는 `SecurityError::SessionLimitExceeded`를 반환합니다.
+    ///
+    /// # Returns
+    /// * 밀려난 패밀리 ID 목록
+    pub async fn admit(&self, user_id: u32, family_id: &str) -> Result<Vec<String>, SecurityError> {
+        if !self.policy.is_enabled() {
+            return Ok(Vec::new());
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let ttl = self.policy.family_ttl.as_secs().max(1) as i64;
+        let mut conn = self.redis_config.get_connection();
+        let result: redis::RedisResult<Option<Vec<String>>> = redis::Script::new(ADMIT_SCRIPT)
+            .key(session_key(user_id))
+            .arg(now)
+            .arg(now - ttl)
+            .arg(self.policy.max_sessions)
+            .arg(if self.policy.evict_oldest { "1" } else { "0" })
+            .arg(family_id)
+            .arg(ttl)
+            .invoke_async(&mut conn)
+            .await;
+
+        let evicted = match result {
+            Ok(Some(evicted)) => evicted,
+            Ok(None) => {
+                warn!("동시 세션 한도 초과로 로그인 거부: user_id={}, max={}", user_id, self.policy.max_sessions);
+                return Err(SecurityError::SessionLimitExceeded { max: self.policy.max_sessions });
+            }
+            Err(e) => {
+                warn!("세션 수 검사 실패 (검사 생략): user_id={}, error={}", user_id, e);
+                return Ok(Vec::new());
+            }
+        };
+
+        if !evicted.is_empty() {
+            info!("동시 세션 한도로 기존 세션 종료: user_id={}, families={:?}", user_id, evicted);
+            self.notify_evicted(user_id, &evicted, now).await;
+        }
+        Ok(evicted)
+    }
+
+    /// 세션이 아직 활성 상태인지 확인 (밀려났거나 해제/만료된 패밀리면 false)
+    ///
+    /// 리프레시 토큰 갱신 시 호출해, 밀려난 세션의 리프레시 토큰을 폐기된 것으로 취급합니다.
+    pub async fn is_active(&self, user_id: u32, family_id: &str) -> Result<bool, SecurityError> {
+        let cutoff = chrono::Utc::now().timestamp() - self.policy.family_ttl.as_secs() as i64;
+        let mut conn = self.redis_config.get_connection();
+        let issued_at: Option<f64> = conn.zscore(session_key(user_id), family_id).await
+            .map_err(|e| SecurityError::AuthenticationFailed(format!("세션 조회 실패: {e}")))?;
+        Ok(issued_at.is_some_and(|issued_at| issued_at > cutoff as f64))
+    }
+
+    /// 세션 해제 (로그아웃, 리프레시 토큰 폐기)
+    pub async fn release(&self, user_id: u32, family_id: &str) {
+        let mut conn = self.redis_config.get_connection();
+        if let Err(e) = conn.zrem::<_, _, ()>(session_key(user_id), family_id).await {
+            warn!("세션 해제 실패: user_id={}, error={}", user_id, e);
+        }
+    }
+
+    /// 활성 세션 수 (만료 세션 제외)
+    pub async fn active_sessions(&self, user_id: u32) -> Result<u32, SecurityError> {
+        let cutoff = chrono::Utc::now().timestamp() - self.policy.family_ttl.as_secs() as i64;
+        let mut conn = self.redis_config.get_connection();
+        conn.zcount(session_key(user_id), cutoff + 1, "+inf").await
+            .map_err(|e| SecurityError::AuthenticationFailed(format!("세션 수 조회 실패: {e}")))
+    }
+
+    /// 밀려난 세션을 게임 서버에 알림
+    async fn notify_evicted(&self, user_id: u32, families: &[String], evicted_at: i64) {
+        let event_bus = EventBus::new(self.redis_config.clone());
+        for family_id in families {
+            let event = SessionEvicted { user_id, family_id: family_id.clone(), evicted_at };
+            if let Err(e) = event_bus.publish_session_evicted(&event).await {
+                warn!("세션 종료 이벤트 발행 실패: user_id={}, error={}", user_id, e);
+            }
+        }
+    }
+}
+
+/// 세션 키 (`session:families:{user_id}`)
+fn session_key(user_id: u32) -> String {
+    format!("{}:{}", SESSION_PREFIX, user_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_defaults() {
+        let policy = SessionQuotaPolicy::default();
+        assert!(policy.is_enabled());
+        assert!(policy.evict_oldest);
+        assert!(!SessionQuotaPolicy { max_sessions: 0, ..policy }.is_enabled());
+        assert_eq!(session_key(42), "session:families:42");
+    }
+
+    #[test]
+    fn test_family_ids_are_unique() {
+        let first = SessionQuota::new_family_id();
+        assert_eq!(first.len(), 32);
+        assert_ne!(first, SessionQuota::new_family_id());
+    }
+
+    #[test]
+    fn test_session_limit_message() {
+        let error = SecurityError::SessionLimitExceeded { max: 3 };
+        assert_eq!(error.to_string(), "Too many active sessions (max: 3)");
+    }
+}
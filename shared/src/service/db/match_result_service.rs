@@ -0,0 +1,157 @@
+//! 매치 결과 저장
+//!
+//! 매치가 끝나면 게임 서버가 참가자, 팀, 점수, 진행 시간, 이탈 여부를 MariaDB에 기록합니다.
+//! `match_id`를 기본 키로 사용하므로 아웃박스 재시도로 같은 기록을 다시 저장해도 중복되지 않습니다.
+
+use crate::config::db::{helpers::map_sqlx_error, DbConnection};
+use crate::service::db::query_executor::build_statement;
+use crate::tool::error::AppError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+/// 매치 결과 테이블
+pub const MATCH_RESULT_SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS match_results (
+        match_id CHAR(36) PRIMARY KEY,
+        room_id INT UNSIGNED NOT NULL,
+        server_id VARCHAR(64) NOT NULL,
+        started_at DATETIME(3) NOT NULL,
+        ended_at DATETIME(3) NOT NULL,
+        duration_secs INT UNSIGNED NOT NULL,
+        end_reason VARCHAR(32) NOT NULL,
+        winner_team VARCHAR(16) NULL,
+        INDEX idx_match_results_ended_at (ended_at)
+    )",
+    "CREATE TABLE IF NOT EXISTS match_players (
+        match_id CHAR(36) NOT NULL,
+        player_id INT UNSIGNED NOT NULL,
+        team VARCHAR(16) NOT NULL,
+        score INT NOT NULL,
+        kills INT UNSIGNED NOT NULL,
+        deaths INT UNSIGNED NOT NULL,
+        disconnected BOOLEAN NOT NULL,
+        PRIMARY KEY (match_id, player_id),
+        INDEX idx_match_players_player (player_id),
+        FOREIGN KEY (match_id) REFERENCES match_results(match_id) ON DELETE CASCADE
+    )",
+];
+
+/// 매치 참가자 기록
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchPlayerRecord {
+    pub player_id: u32,
+    pub team: String,
+    pub score: i32,
+    pub kills: u32,
+    pub deaths: u32,
+    /// 매치 도중 연결이 끊겼는지 여부
+    pub disconnected: bool,
+}
+
+/// 매치 기록
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchRecord {
+    pub match_id: String,
+    pub room_id: u32,
+    /// 매치를 진행한 게임 서버
+    pub server_id: String,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub duration_secs: u64,
+    /// 종료 사유 (`time_limit`, `abandoned` 등)
+    pub end_reason: String,
+    /// 무승부면 None
+    pub winner_team: Option<String>,
+    pub players: Vec<MatchPlayerRecord>,
+}
+
+/// 매치 결과 저장 서비스
+#[derive(Debug, Clone)]
+pub struct MatchResultService {
+    pool: DbConnection,
+}
+
+impl MatchResultService {
+    pub fn new(pool: DbConnection) -> Self {
+        Self { pool }
+    }
+
+    /// 테이블이 없으면 생성
+    pub async fn ensure_schema(&self) -> Result<(), AppError> {
+        for statement in MATCH_RESULT_SCHEMA {
+            sqlx::query(statement)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| map_sqlx_error(e, "매치 결과 스키마 생성"))?;
+        }
+        Ok(())
+    }
+
+    /// 매치 기록 저장 (이미 저장된 `match_id`면 아무것도 하지 않음)
+    pub async fn save(&self, record: &MatchRecord) -> Result<(), AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| map_sqlx_error(e, "매치 결과 트랜잭션 시작"))?;
+
+        let inserted = sqlx::query(
+            "INSERT IGNORE INTO match_results
+                (match_id, room_id, server_id, started_at, ended_at, duration_secs, end_reason, winner_team)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&record.match_id)
+        .bind(record.room_id)
+        .bind(&record.server_id)
+        .bind(record.started_at)
+        .bind(record.ended_at)
+        .bind(record.duration_secs)
+        .bind(&record.end_reason)
+        .bind(&record.winner_team)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| map_sqlx_error(e, "매치 결과 저장"))?
+        .rows_affected();
+
+        if inserted == 0 {
+            debug!(match_id = %record.match_id, "이미 저장된 매치 결과");
+            return Ok(());
+        }
+
+        // 참가자는 한 문장으로 저장 (매치 기록과 같은 트랜잭션)
+        if !record.players.is_empty() {
+            build_statement(
+                "match_players",
+                &["match_id", "player_id", "team", "score", "kills", "deaths", "disconnected"],
+                None,
+                &record.players,
+                |mut row, player| {
+                    row.push_bind(&record.match_id)
+                        .push_bind(player.player_id)
+                        .push_bind(&player.team)
+                        .push_bind(player.score)
+                        .push_bind(player.kills)
+                        .push_bind(player.deaths)
+                        .push_bind(player.disconnected);
+                },
+            )?
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| map_sqlx_error(e, "매치 참가자 저장"))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| map_sqlx_error(e, "매치 결과 트랜잭션 커밋"))?;
+
+        info!(
+            match_id = %record.match_id,
+            room_id = record.room_id,
+            players = record.players.len(),
+            "매치 결과 저장 완료"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+pub mod base_db_service;
+pub mod match_result_service;
+pub mod pool_monitor;
+pub mod query_executor;
+
+pub use match_result_service::{MatchPlayerRecord, MatchRecord, MatchResultService};
+pub use pool_monitor::{HeldConnection, MonitoredConnection, PoolMetrics, PoolMonitor, PoolMonitorConfig};
+pub use query_executor::{BulkOperationResult, ChunkFailure, QueryExecutor};
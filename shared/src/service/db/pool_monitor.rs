@@ -0,0 +1,426 @@
+//! DB 연결 풀 모니터링과 누수 감지
+//!
+//! 핸들러 버그로 연결을 반납하지 않으면 풀이 조용히 고갈됩니다.
+//! `PoolMonitor::acquire`로 받은 연결은 획득 위치(호출한 파일:줄)와 획득 시각이 기록되며,
+//! - 획득 대기 시간은 히스토그램으로 집계하고
+//! - `hold_warn_threshold`보다 오래 쥐고 있는 연결은 획득 위치와 함께 경고하고
+//! - 풀이 가득 차 대기가 길어지거나 타임아웃이 나면 고갈 알림을 남깁니다.
+//!
+//! `MetricsCollector`를 연결하면 같은 값을 메트릭(`db_pool_*`)으로도 내보냅니다.
+//!
+//! 환경변수:
+//! - `db_pool_hold_warn_secs`: 연결 보유 경고 기준 (기본값: 30)
+//! - `db_pool_exhaustion_wait_ms`: 고갈로 간주할 대기 시간 (기본값: 1000)
+//! - `db_pool_scan_interval_secs`: 누수 검사 주기 (기본값: 10)
+
+use crate::tool::high_performance::MetricsCollector;
+use sqlx::pool::PoolConnection;
+use sqlx::{Database, Pool};
+use std::collections::HashMap;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// 알림 로그 타깃
+const ALERT_TARGET: &str = "db_pool_alert";
+
+/// 획득 대기 히스토그램 버킷 상한 (ms, 마지막 버킷은 +Inf)
+pub const WAIT_BUCKETS_MS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1000, 5000];
+
+/// 고갈 알림 최소 간격
+const ALERT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 풀 모니터 설정
+#[derive(Debug, Clone)]
+pub struct PoolMonitorConfig {
+    /// 이 시간보다 오래 보유한 연결은 누수 의심으로 경고
+    pub hold_warn_threshold: Duration,
+    /// 획득 대기가 이 시간을 넘으면 고갈로 간주
+    pub exhaustion_wait_threshold: Duration,
+    /// 누수 검사 주기
+    pub scan_interval: Duration,
+}
+
+impl Default for PoolMonitorConfig {
+    fn default() -> Self {
+        Self {
+            hold_warn_threshold: Duration::from_secs(30),
+            exhaustion_wait_threshold: Duration::from_millis(1000),
+            scan_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+impl PoolMonitorConfig {
+    /// 환경변수에서 설정 로드 (없으면 기본값)
+    pub fn from_env() -> Self {
+        fn var(key: &str) -> Option<u64> {
+            std::env::var(key).ok().and_then(|v| v.parse().ok())
+        }
+
+        let default = Self::default();
+        Self {
+            hold_warn_threshold: var("db_pool_hold_warn_secs").map(Duration::from_secs).unwrap_or(default.hold_warn_threshold),
+            exhaustion_wait_threshold: var("db_pool_exhaustion_wait_ms").map(Duration::from_millis).unwrap_or(default.exhaustion_wait_threshold),
+            scan_interval: var("db_pool_scan_interval_secs").filter(|s| *s > 0).map(Duration::from_secs).unwrap_or(default.scan_interval),
+        }
+    }
+}
+
+/// 보유 중인 연결 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeldConnection {
+    pub id: u64,
+    /// 획득 위치 (`file:line`)
+    pub location: String,
+    pub held_for: Duration,
+}
+
+/// 풀 메트릭 스냅샷
+#[derive(Debug, Clone)]
+pub struct PoolMetrics {
+    pub size: u32,
+    pub idle: usize,
+    pub max_connections: u32,
+    /// 모니터를 통해 획득해 아직 반납하지 않은 연결 수
+    pub in_use: usize,
+    pub acquired_total: u64,
+    pub acquire_failures: u64,
+    pub exhaustion_events: u64,
+    /// 버킷별 획득 대기 횟수 (`WAIT_BUCKETS_MS` 순서, 마지막은 +Inf)
+    pub wait_histogram: Vec<u64>,
+    pub avg_wait: Duration,
+    /// 가장 오래 보유 중인 연결
+    pub oldest: Option<HeldConnection>,
+}
+
+/// 획득 기록
+struct Checkout {
+    acquired_at: Instant,
+    location: &'static Location<'static>,
+    warned: bool,
+}
+
+#[derive(Default)]
+struct MonitorState {
+    wait_buckets: [AtomicU64; WAIT_BUCKETS_MS.len() + 1],
+    wait_total_us: AtomicU64,
+    acquired_total: AtomicU64,
+    acquire_failures: AtomicU64,
+    exhaustion_events: AtomicU64,
+    /// 마지막 고갈 알림 시각 (모니터 생성 후 ms, 0이면 없음)
+    last_alert_ms: AtomicU64,
+    next_id: AtomicU64,
+    in_use: Mutex<HashMap<u64, Checkout>>,
+}
+
+impl MonitorState {
+    fn record_wait(&self, wait: Duration) {
+        let ms = wait.as_millis() as u64;
+        let bucket = WAIT_BUCKETS_MS.iter().position(|upper| ms <= *upper).unwrap_or(WAIT_BUCKETS_MS.len());
+        self.wait_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.wait_total_us.fetch_add(wait.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    fn held(&self) -> Vec<HeldConnection> {
+        let now = Instant::now();
+        let mut held: Vec<HeldConnection> = self.in_use.lock()
+            .map(|in_use| {
+                in_use.iter()
+                    .map(|(id, checkout)| HeldConnection {
+                        id: *id,
+                        location: format_location(checkout.location),
+                        held_for: now.duration_since(checkout.acquired_at),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        held.sort_by_key(|connection| std::cmp::Reverse(connection.held_for));
+        held
+    }
+}
+
+fn format_location(location: &Location<'_>) -> String {
+    format!("{}:{}", location.file(), location.line())
+}
+
+/// 모니터링되는 연결 풀
+pub struct PoolMonitor<DB: Database> {
+    pool: Pool<DB>,
+    config: PoolMonitorConfig,
+    state: Arc<MonitorState>,
+    metrics: Option<Arc<MetricsCollector>>,
+    started_at: Instant,
+}
+
+impl<DB: Database> Clone for PoolMonitor<DB> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+            config: self.config.clone(),
+            state: self.state.clone(),
+            metrics: self.metrics.clone(),
+            started_at: self.started_at,
+        }
+    }
+}
+
+impl<DB: Database> PoolMonitor<DB> {
+    pub fn new(pool: Pool<DB>, config: PoolMonitorConfig) -> Self {
+        Self {
+            pool,
+            config,
+            state: Arc::new(MonitorState::default()),
+            metrics: None,
+            started_at: Instant::now(),
+        }
+    }
+
+    /// 메트릭 수집기 연결
+    pub fn with_metrics(mut self, metrics: Arc<MetricsCollector>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub fn pool(&self) -> &Pool<DB> {
+        &self.pool
+    }
+
+    /// 연결 획득 (호출 위치를 함께 기록)
+    #[track_caller]
+    pub fn acquire(&self) -> impl Future<Output = Result<MonitoredConnection<DB>, sqlx::Error>> + '_ {
+        let location = Location::caller();
+        async move {
+            let started = Instant::now();
+            let result = self.pool.acquire().await;
+            let wait = started.elapsed();
+            self.state.record_wait(wait);
+            if let Some(metrics) = &self.metrics {
+                let buckets = WAIT_BUCKETS_MS.iter().map(|ms| *ms as f64 / 1000.0).collect();
+                metrics.observe_histogram("db_pool_acquire_wait_seconds", wait.as_secs_f64(), buckets, HashMap::new());
+            }
+
+            let conn = match result {
+                Ok(conn) => conn,
+                Err(e) => {
+                    self.state.acquire_failures.fetch_add(1, Ordering::Relaxed);
+                    if matches!(e, sqlx::Error::PoolTimedOut) {
+                        self.alert_exhaustion(wait, location);
+                    }
+                    return Err(e);
+                }
+            };
+            if wait >= self.config.exhaustion_wait_threshold {
+                self.alert_exhaustion(wait, location);
+            }
+
+            let id = self.state.next_id.fetch_add(1, Ordering::Relaxed);
+            self.state.acquired_total.fetch_add(1, Ordering::Relaxed);
+            if let Ok(mut in_use) = self.state.in_use.lock() {
+                in_use.insert(id, Checkout { acquired_at: Instant::now(), location, warned: false });
+            }
+            Ok(MonitoredConnection {
+                conn: Some(conn),
+                id,
+                state: self.state.clone(),
+                hold_warn_threshold: self.config.hold_warn_threshold,
+            })
+        }
+    }
+
+    /// 현재 풀 상태
+    pub fn snapshot(&self) -> PoolMetrics {
+        let wait_histogram: Vec<u64> = self.state.wait_buckets.iter().map(|b| b.load(Ordering::Relaxed)).collect();
+        let waits: u64 = wait_histogram.iter().sum();
+        let held = self.state.held();
+        PoolMetrics {
+            size: self.pool.size(),
+            idle: self.pool.num_idle(),
+            max_connections: self.pool.options().get_max_connections(),
+            in_use: held.len(),
+            acquired_total: self.state.acquired_total.load(Ordering::Relaxed),
+            acquire_failures: self.state.acquire_failures.load(Ordering::Relaxed),
+            exhaustion_events: self.state.exhaustion_events.load(Ordering::Relaxed),
+            avg_wait: Duration::from_micros(self.state.wait_total_us.load(Ordering::Relaxed).checked_div(waits).unwrap_or(0)),
+            wait_histogram,
+            oldest: held.into_iter().next(),
+        }
+    }
+
+    /// 보유 기준을 넘긴 연결을 찾아 처음 넘긴 연결만 경고하고, 기준을 넘긴 전체 목록 반환
+    pub fn check_leaks(&self) -> Vec<HeldConnection> {
+        let now = Instant::now();
+        let threshold = self.config.hold_warn_threshold;
+        let mut leaked = Vec::new();
+        if let Ok(mut in_use) = self.state.in_use.lock() {
+            for (id, checkout) in in_use.iter_mut() {
+                let held_for = now.duration_since(checkout.acquired_at);
+                if held_for < threshold {
+                    continue;
+                }
+                let location = format_location(checkout.location);
+                if !checkout.warned {
+                    checkout.warned = true;
+                    warn!(
+                        target: ALERT_TARGET,
+                        connection_id = id,
+                        held_secs = held_for.as_secs(),
+                        acquired_at = %location,
+                        "DB 연결 장시간 보유 (누수 의심)"
+                    );
+                }
+                leaked.push(HeldConnection { id: *id, location, held_for });
+            }
+        }
+
+        if let Some(metrics) = &self.metrics {
+            let snapshot = self.snapshot();
+            metrics.set_gauge("db_pool_size", snapshot.size as f64, HashMap::new());
+            metrics.set_gauge("db_pool_idle", snapshot.idle as f64, HashMap::new());
+            metrics.set_gauge("db_pool_in_use", snapshot.in_use as f64, HashMap::new());
+            metrics.set_gauge("db_pool_suspected_leaks", leaked.len() as f64, HashMap::new());
+        }
+        leaked
+    }
+
+    /// 주기적 누수 검사 태스크 시작
+    pub fn spawn_leak_detector(&self) -> JoinHandle<()>
+    where
+        DB: 'static,
+    {
+        let monitor = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(monitor.config.scan_interval);
+            loop {
+                ticker.tick().await;
+                monitor.check_leaks();
+            }
+        })
+    }
+
+    /// 풀 고갈 알림 (`ALERT_INTERVAL`에 한 번)
+    fn alert_exhaustion(&self, wait: Duration, location: &'static Location<'static>) {
+        self.state.exhaustion_events.fetch_add(1, Ordering::Relaxed);
+        if let Some(metrics) = &self.metrics {
+            metrics.increment_counter("db_pool_exhaustion_total", HashMap::new());
+        }
+
+        let now_ms = self.started_at.elapsed().as_millis() as u64 + 1;
+        let last = self.state.last_alert_ms.load(Ordering::Relaxed);
+        if last != 0 && now_ms.saturating_sub(last) < ALERT_INTERVAL.as_millis() as u64 {
+            return;
+        }
+        if self.state.last_alert_ms.compare_exchange(last, now_ms, Ordering::Relaxed, Ordering::Relaxed).is_err() {
+            return;
+        }
+
+        let holders: Vec<String> = self.state.held().into_iter()
+            .take(5)
+            .map(|held| format!("{}({}s)", held.location, held.held_for.as_secs()))
+            .collect();
+        warn!(
+            target: ALERT_TARGET,
+            wait_ms = wait.as_millis() as u64,
+            size = self.pool.size(),
+            max = self.pool.options().get_max_connections(),
+            requested_at = %format_location(location),
+            holders = %holders.join(", "),
+            "DB 연결 풀 고갈"
+        );
+    }
+}
+
+/// 모니터를 통해 획득한 연결 (drop 시 반납 기록)
+pub struct MonitoredConnection<DB: Database> {
+    conn: Option<PoolConnection<DB>>,
+    id: u64,
+    state: Arc<MonitorState>,
+    hold_warn_threshold: Duration,
+}
+
+impl<DB: Database> Deref for MonitoredConnection<DB> {
+    type Target = DB::Connection;
+
+    fn deref(&self) -> &Self::Target {
+        self.conn.as_ref().expect("connection present until drop")
+    }
+}
+
+impl<DB: Database> DerefMut for MonitoredConnection<DB> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.conn.as_mut().expect("connection present until drop")
+    }
+}
+
+impl<DB: Database> Drop for MonitoredConnection<DB> {
+    fn drop(&mut self) {
+        let checkout = self.state.in_use.lock().ok().and_then(|mut in_use| in_use.remove(&self.id));
+        if let Some(checkout) = checkout {
+            let held_for = checkout.acquired_at.elapsed();
+            if checkout.warned || held_for >= self.hold_warn_threshold {
+                info!(
+                    target: ALERT_TARGET,
+                    connection_id = self.id,
+                    held_secs = held_for.as_secs(),
+                    acquired_at = %format_location(checkout.location),
+                    "장시간 보유한 DB 연결 반납"
+                );
+            }
+        }
+        drop(self.conn.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::{Sqlite, SqlitePoolOptions};
+
+    async fn monitor(config: PoolMonitorConfig) -> PoolMonitor<Sqlite> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .acquire_timeout(Duration::from_millis(200))
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        PoolMonitor::new(pool, config)
+    }
+
+    #[tokio::test]
+    async fn test_tracks_call_site_and_leaks() {
+        let monitor = monitor(PoolMonitorConfig { hold_warn_threshold: Duration::ZERO, ..Default::default() }).await;
+
+        let mut conn = monitor.acquire().await.unwrap();
+        sqlx::query("SELECT 1").execute(&mut *conn).await.unwrap();
+
+        let leaks = monitor.check_leaks();
+        assert_eq!(leaks.len(), 1);
+        assert!(leaks[0].location.starts_with(file!()), "{}", leaks[0].location);
+        assert_eq!(monitor.snapshot().in_use, 1);
+
+        drop(conn);
+        let snapshot = monitor.snapshot();
+        assert_eq!(snapshot.in_use, 0);
+        assert_eq!(snapshot.acquired_total, 1);
+        assert_eq!(snapshot.wait_histogram.iter().sum::<u64>(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_exhaustion_counted_on_timeout() {
+        let monitor = monitor(PoolMonitorConfig::default()).await;
+
+        let _held = monitor.acquire().await.unwrap();
+        assert!(matches!(monitor.acquire().await, Err(sqlx::Error::PoolTimedOut)));
+
+        let snapshot = monitor.snapshot();
+        assert_eq!(snapshot.acquire_failures, 1);
+        assert_eq!(snapshot.exhaustion_events, 1);
+        // 200ms 타임아웃은 500ms 버킷
+        assert_eq!(snapshot.wait_histogram[5], 1);
+    }
+}
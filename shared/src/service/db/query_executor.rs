@@ -0,0 +1,292 @@
+//! 대량 삽입/업서트
+//!
+//! 행을 하나씩 넣으면 왕복 비용이 행 수만큼 들므로, 여러 행을 `INSERT ... VALUES (...), (...)`
+//! 한 문장으로 묶어 실행합니다.
+//! - MySQL 프리페어드 문장의 플레이스홀더 한도(65,535)와 `max_rows_per_chunk`를 넘지 않도록 나누고
+//! - 청크마다 별도 트랜잭션으로 실행하며
+//! - 실패한 청크는 건너뛰고 [`BulkOperationResult`]에 행 범위와 오류를 남깁니다.
+//!
+//! ```ignore
+//! let result = QueryExecutor::new(pool)
+//!     .insert_many("match_players", &["match_id", "player_id", "score"], &players, |mut row, player| {
+//!         row.push_bind(&player.match_id).push_bind(player.player_id).push_bind(player.score);
+//!     })
+//!     .await?;
+//! ```
+
+use crate::config::db::{helpers::map_sqlx_error, DbConnection};
+use crate::tool::error::AppError;
+use serde::Serialize;
+use sqlx::query_builder::Separated;
+use sqlx::{MySql, QueryBuilder};
+use std::ops::Range;
+use tracing::{debug, warn};
+
+/// MySQL 프리페어드 문장 플레이스홀더 한도
+pub const MYSQL_MAX_PLACEHOLDERS: usize = 65_535;
+
+/// 청크당 기본 최대 행 수 (`max_allowed_packet` 여유 확보)
+const DEFAULT_MAX_ROWS_PER_CHUNK: usize = 1_000;
+
+/// 실패한 청크
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChunkFailure {
+    pub chunk_index: usize,
+    /// 입력 슬라이스 기준 행 범위
+    pub rows: Range<usize>,
+    pub error: String,
+}
+
+/// 대량 작업 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BulkOperationResult {
+    pub total_rows: usize,
+    /// 커밋된 청크의 행 수
+    pub committed_rows: usize,
+    /// DB가 보고한 영향 행 수 (업서트 갱신은 행당 2로 집계됨)
+    pub rows_affected: u64,
+    pub chunks: usize,
+    pub failed_chunks: Vec<ChunkFailure>,
+}
+
+impl BulkOperationResult {
+    /// 모든 청크가 커밋됐는지
+    pub fn is_complete(&self) -> bool {
+        self.failed_chunks.is_empty()
+    }
+
+    /// 실패한 청크의 행 수
+    pub fn failed_rows(&self) -> usize {
+        self.failed_chunks.iter().map(|failure| failure.rows.len()).sum()
+    }
+}
+
+/// 대량 쿼리 실행기
+#[derive(Debug, Clone)]
+pub struct QueryExecutor {
+    pool: DbConnection,
+    max_placeholders: usize,
+    max_rows_per_chunk: usize,
+}
+
+impl QueryExecutor {
+    pub fn new(pool: DbConnection) -> Self {
+        Self { pool, max_placeholders: MYSQL_MAX_PLACEHOLDERS, max_rows_per_chunk: DEFAULT_MAX_ROWS_PER_CHUNK }
+    }
+
+    /// 청크당 최대 행 수 변경
+    pub fn with_max_rows_per_chunk(mut self, rows: usize) -> Self {
+        self.max_rows_per_chunk = rows.max(1);
+        self
+    }
+
+    /// 열 수에 따른 청크당 행 수
+    pub fn chunk_rows(&self, columns: usize) -> usize {
+        (self.max_placeholders / columns.max(1)).clamp(1, self.max_rows_per_chunk)
+    }
+
+    /// 대량 삽입 (`bind`로 행마다 열 순서대로 값을 바인딩)
+    pub async fn insert_many<'a, T, F>(
+        &self,
+        table: &str,
+        columns: &[&str],
+        rows: &'a [T],
+        bind: F,
+    ) -> Result<BulkOperationResult, AppError>
+    where
+        T: Sync,
+        F: Fn(Separated<'_, 'a, MySql, &'static str>, &'a T) + Sync,
+    {
+        self.execute_chunks(table, columns, None, rows, &bind).await
+    }
+
+    /// 대량 업서트 (키가 겹치면 `update_columns`를 새 값으로 갱신)
+    pub async fn upsert_many<'a, T, F>(
+        &self,
+        table: &str,
+        columns: &[&str],
+        update_columns: &[&str],
+        rows: &'a [T],
+        bind: F,
+    ) -> Result<BulkOperationResult, AppError>
+    where
+        T: Sync,
+        F: Fn(Separated<'_, 'a, MySql, &'static str>, &'a T) + Sync,
+    {
+        if update_columns.is_empty() {
+            return Err(AppError::InvalidInput("업서트할 열이 없습니다".to_string()));
+        }
+        self.execute_chunks(table, columns, Some(update_columns), rows, &bind).await
+    }
+
+    async fn execute_chunks<'a, T, F>(
+        &self,
+        table: &str,
+        columns: &[&str],
+        update_columns: Option<&[&str]>,
+        rows: &'a [T],
+        bind: &F,
+    ) -> Result<BulkOperationResult, AppError>
+    where
+        T: Sync,
+        F: Fn(Separated<'_, 'a, MySql, &'static str>, &'a T) + Sync,
+    {
+        // 빈 입력도 식별자는 검증
+        build_statement(table, columns, update_columns, &rows[..0], bind)?;
+        let mut result = BulkOperationResult { total_rows: rows.len(), ..Default::default() };
+        let chunk_rows = self.chunk_rows(columns.len());
+
+        for (chunk_index, chunk) in rows.chunks(chunk_rows).enumerate() {
+            let start = chunk_index * chunk_rows;
+            let range = start..start + chunk.len();
+            result.chunks += 1;
+            match self.execute_chunk(table, columns, update_columns, chunk, bind).await {
+                Ok(affected) => {
+                    result.committed_rows += chunk.len();
+                    result.rows_affected += affected;
+                }
+                Err(e) => {
+                    warn!(table, chunk_index, rows = ?range, "대량 작업 청크 실패: {}", e);
+                    result.failed_chunks.push(ChunkFailure { chunk_index, rows: range, error: e.to_string() });
+                }
+            }
+        }
+
+        debug!(
+            table,
+            total = result.total_rows,
+            committed = result.committed_rows,
+            chunks = result.chunks,
+            "대량 작업 완료"
+        );
+        Ok(result)
+    }
+
+    /// 청크 하나를 트랜잭션으로 실행 (실패하면 롤백)
+    async fn execute_chunk<'a, T, F>(
+        &self,
+        table: &str,
+        columns: &[&str],
+        update_columns: Option<&[&str]>,
+        chunk: &'a [T],
+        bind: &F,
+    ) -> Result<u64, AppError>
+    where
+        F: Fn(Separated<'_, 'a, MySql, &'static str>, &'a T),
+    {
+        let mut builder = build_statement(table, columns, update_columns, chunk, bind)?;
+        let mut tx = self.pool.begin().await.map_err(|e| map_sqlx_error(e, "대량 작업 트랜잭션 시작"))?;
+        let affected = builder
+            .build()
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| map_sqlx_error(e, &format!("{} 대량 작업", table)))?
+            .rows_affected();
+        tx.commit().await.map_err(|e| map_sqlx_error(e, "대량 작업 트랜잭션 커밋"))?;
+        Ok(affected)
+    }
+}
+
+/// 다중 행 `INSERT` 문 생성 (`update_columns`가 있으면 `ON DUPLICATE KEY UPDATE` 추가)
+///
+/// 청크 나누기 없이 기존 트랜잭션 안에서 실행할 때 사용합니다.
+pub fn build_statement<'a, T, F>(
+    table: &str,
+    columns: &[&str],
+    update_columns: Option<&[&str]>,
+    rows: &'a [T],
+    bind: F,
+) -> Result<QueryBuilder<'a, MySql>, AppError>
+where
+    F: Fn(Separated<'_, 'a, MySql, &'static str>, &'a T),
+{
+    if columns.is_empty() {
+        return Err(AppError::InvalidInput("삽입할 열이 없습니다".to_string()));
+    }
+    let mut builder = QueryBuilder::new(format!("INSERT INTO {} (", quote_identifier(table)?));
+    for (index, column) in columns.iter().enumerate() {
+        if index > 0 {
+            builder.push(", ");
+        }
+        builder.push(quote_identifier(column)?);
+    }
+    builder.push(") ");
+    if rows.is_empty() {
+        return Ok(builder);
+    }
+    builder.push_values(rows, bind);
+
+    if let Some(update_columns) = update_columns {
+        builder.push(" ON DUPLICATE KEY UPDATE ");
+        for (index, column) in update_columns.iter().enumerate() {
+            let column = quote_identifier(column)?;
+            if index > 0 {
+                builder.push(", ");
+            }
+            builder.push(format!("{column} = VALUES({column})"));
+        }
+    }
+    Ok(builder)
+}
+
+/// 코드에서 넘긴 테이블/열 이름 검증 후 백틱으로 감싸기
+fn quote_identifier(name: &str) -> Result<String, AppError> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(AppError::InvalidInput(format!("잘못된 식별자: {}", name)));
+    }
+    Ok(format!("`{}`", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::mysql::MySqlPoolOptions;
+
+    struct Player {
+        id: u32,
+        score: i32,
+    }
+
+    fn bind(mut row: Separated<'_, '_, MySql, &'static str>, player: &Player) {
+        row.push_bind(player.id).push_bind(player.score);
+    }
+
+    #[test]
+    fn test_build_statement() {
+        let players = [Player { id: 1, score: 10 }, Player { id: 2, score: 20 }];
+        let insert = build_statement("match_players", &["player_id", "score"], None, &players, bind).unwrap();
+        assert_eq!(insert.sql(), "INSERT INTO `match_players` (`player_id`, `score`) VALUES (?, ?), (?, ?)");
+
+        let upsert = build_statement("match_players", &["player_id", "score"], Some(&["score"]), &players[..1], bind).unwrap();
+        assert_eq!(
+            upsert.sql(),
+            "INSERT INTO `match_players` (`player_id`, `score`) VALUES (?, ?) ON DUPLICATE KEY UPDATE `score` = VALUES(`score`)"
+        );
+
+        assert!(build_statement("players; DROP TABLE x", &["id"], None, &players, bind).is_err());
+        assert!(build_statement("players", &[], None, &players, bind).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_chunk_rows_respects_placeholder_limit() {
+        // 연결하지 않는 풀 (청크 계산만 확인)
+        let pool = MySqlPoolOptions::new().connect_lazy("mysql://localhost/test").unwrap();
+        let executor = QueryExecutor::new(pool);
+        assert_eq!(executor.chunk_rows(7), DEFAULT_MAX_ROWS_PER_CHUNK);
+
+        let executor = executor.with_max_rows_per_chunk(100_000);
+        assert_eq!(executor.chunk_rows(7), MYSQL_MAX_PLACEHOLDERS / 7);
+        assert!(executor.chunk_rows(7) * 7 <= MYSQL_MAX_PLACEHOLDERS);
+        assert_eq!(executor.chunk_rows(100_000), 1);
+
+        let result = BulkOperationResult {
+            total_rows: 10,
+            committed_rows: 6,
+            chunks: 3,
+            failed_chunks: vec![ChunkFailure { chunk_index: 1, rows: 4..8, error: "deadlock".into() }],
+            ..Default::default()
+        };
+        assert!(!result.is_complete());
+        assert_eq!(result.failed_rows(), 4);
+    }
+}
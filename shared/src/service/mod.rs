@@ -1,4 +1,8 @@
+pub mod db;
+pub mod event_store;
+pub mod outbox;
 pub mod redis; 
+pub mod saga;
 pub mod token;
 
 
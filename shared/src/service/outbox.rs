@@ -0,0 +1,204 @@
+//! 파일 기반 아웃박스
+//!
+//! DB나 이벤트 버스 장애로 바로 전달하지 못한 작업을 디스크에 남겨 두고 재시도합니다.
+//! 프로세스가 재시작돼도 남아 있는 항목은 다음 [`Outbox::flush`]에서 다시 전달됩니다.
+//!
+//! - 항목 하나가 `<dir>/<id>.json` 파일 하나이며, 같은 ID로 다시 넣으면 덮어씁니다.
+//! - 일시적 실패는 시도 횟수만 늘리고 남겨 두며, 영구 실패는 `<dir>/dead/`로 옮깁니다.
+//! - 전달은 최소 한 번(at-least-once)이므로 전달 함수는 멱등이어야 합니다.
+
+use crate::tool::error::AppError;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::io;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use tracing::{error, warn};
+
+/// 전달 실패 분류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryError {
+    /// 재시도하면 성공할 수 있는 실패 (연결 끊김, 타임아웃 등)
+    Transient(String),
+    /// 재시도해도 실패할 항목 (잘못된 데이터 등)
+    Permanent(String),
+}
+
+impl From<AppError> for DeliveryError {
+    fn from(error: AppError) -> Self {
+        match error {
+            AppError::DatabaseConnection(_)
+            | AppError::RedisConnection(_)
+            | AppError::ServiceUnavailable(_)
+            | AppError::Timeout(_) => Self::Transient(error.to_string()),
+            _ => Self::Permanent(error.to_string()),
+        }
+    }
+}
+
+/// 아웃박스 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxEntry<T> {
+    pub id: String,
+    pub created_at: DateTime<Utc>,
+    /// 실패한 전달 시도 횟수
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub payload: T,
+}
+
+/// 한 번의 flush 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FlushStats {
+    pub delivered: usize,
+    pub retrying: usize,
+    pub dead: usize,
+}
+
+/// 파일 기반 아웃박스
+#[derive(Debug, Clone)]
+pub struct Outbox<T> {
+    dir: PathBuf,
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<T: Serialize + DeserializeOwned + Clone> Outbox<T> {
+    /// 아웃박스 디렉토리 열기 (없으면 생성)
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(dir.join("dead"))?;
+        Ok(Self {
+            dir,
+            _payload: PhantomData,
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 항목 추가 (임시 파일에 쓴 뒤 rename하여 부분 기록을 남기지 않음)
+    pub fn push(&self, id: &str, payload: T) -> io::Result<()> {
+        self.write(&OutboxEntry {
+            id: id.to_string(),
+            created_at: Utc::now(),
+            attempts: 0,
+            last_error: None,
+            payload,
+        })
+    }
+
+    /// 대기 중인 항목 (오래된 순)
+    pub fn pending(&self) -> io::Result<Vec<OutboxEntry<T>>> {
+        let mut entries = Vec::new();
+        for file in std::fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            match std::fs::read(&path).map(|bytes| serde_json::from_slice::<OutboxEntry<T>>(&bytes)) {
+                Ok(Ok(entry)) => entries.push(entry),
+                Ok(Err(e)) => {
+                    warn!(path = %path.display(), error = %e, "손상된 아웃박스 항목 격리");
+                    let _ = std::fs::rename(&path, self.dir.join("dead").join(path.file_name().unwrap_or_default()));
+                }
+                Err(e) => warn!(path = %path.display(), error = %e, "아웃박스 항목 읽기 실패"),
+            }
+        }
+        entries.sort_by_key(|entry| entry.created_at);
+        Ok(entries)
+    }
+
+    /// 대기 중인 항목을 모두 전달 시도
+    pub async fn flush<F, Fut>(&self, mut deliver: F) -> io::Result<FlushStats>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<(), DeliveryError>>,
+    {
+        let mut stats = FlushStats::default();
+        for mut entry in self.pending()? {
+            match deliver(entry.payload.clone()).await {
+                Ok(()) => {
+                    std::fs::remove_file(self.path(&entry.id))?;
+                    stats.delivered += 1;
+                }
+                Err(DeliveryError::Transient(reason)) => {
+                    entry.attempts += 1;
+                    entry.last_error = Some(reason);
+                    self.write(&entry)?;
+                    stats.retrying += 1;
+                }
+                Err(DeliveryError::Permanent(reason)) => {
+                    error!(id = %entry.id, attempts = entry.attempts + 1, error = %reason, "아웃박스 항목 영구 실패");
+                    entry.attempts += 1;
+                    entry.last_error = Some(reason);
+                    self.write(&entry)?;
+                    std::fs::rename(self.path(&entry.id), self.dir.join("dead").join(format!("{}.json", entry.id)))?;
+                    stats.dead += 1;
+                }
+            }
+        }
+        Ok(stats)
+    }
+
+    fn write(&self, entry: &OutboxEntry<T>) -> io::Result<()> {
+        let json = serde_json::to_vec_pretty(entry).map_err(io::Error::other)?;
+        let tmp = self.dir.join(format!("{}.tmp", entry.id));
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(tmp, self.path(&entry.id))
+    }
+
+    fn path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{id}.json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_flush_retries_and_dead_letters() {
+        let dir = tempfile::tempdir().unwrap();
+        let outbox: Outbox<u32> = Outbox::open(dir.path()).unwrap();
+        outbox.push("a", 1).unwrap();
+        outbox.push("b", 2).unwrap();
+        outbox.push("c", 3).unwrap();
+
+        let stats = outbox
+            .flush(|value| {
+                let result = match value {
+                    1 => Ok(()),
+                    2 => Err(DeliveryError::Transient("db down".to_string())),
+                    _ => Err(DeliveryError::Permanent("bad row".to_string())),
+                };
+                async move { result }
+            })
+            .await
+            .unwrap();
+        assert_eq!(stats, FlushStats { delivered: 1, retrying: 1, dead: 1 });
+
+        let pending = outbox.pending().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!((pending[0].payload, pending[0].attempts), (2, 1));
+        assert!(dir.path().join("dead/c.json").exists());
+
+        let stats = outbox.flush(|_| async { Ok(()) }).await.unwrap();
+        assert_eq!(stats.delivered, 1);
+        assert!(outbox.pending().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_app_error_classification() {
+        assert!(matches!(
+            DeliveryError::from(AppError::DatabaseConnection("refused".into())),
+            DeliveryError::Transient(_)
+        ));
+        assert!(matches!(
+            DeliveryError::from(AppError::DatabaseQuery("syntax".into())),
+            DeliveryError::Permanent(_)
+        ));
+    }
+}
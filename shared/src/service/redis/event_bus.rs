@@ -0,0 +1,194 @@
+//! Redis Pub/Sub 이벤트 버스
+//!
+//! 서버 간에 공유하는 이벤트(채팅, 매치 결과 등)를 JSON으로 발행하고 구독합니다.
+//! 발행은 공유 연결 매니저를 사용하고, 구독은 채널마다 전용 연결을 엽니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::service::db::match_result_service::MatchRecord;
+use crate::tool::error::AppError;
+use futures::StreamExt;
+use redis::AsyncCommands;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// 채팅 이벤트 채널
+pub const CHAT_EVENT_CHANNEL: &str = "events:chat";
+
+/// 매치 결과 이벤트 채널 (리더보드 등에서 구독)
+pub const MATCH_RESULT_CHANNEL: &str = "events:match_result";
+
+/// 세션 종료 이벤트 채널 (동시 세션 한도로 밀려난 세션, 게임 서버에서 구독)
+pub const SESSION_EVICTED_CHANNEL: &str = "events:session_evicted";
+
+/// 연결 품질 변경 이벤트 채널 (하트비트 왕복 시간 기준)
+pub const CONNECTION_QUALITY_CHANNEL: &str = "events:connection_quality";
+
+/// 구독 수신 버퍼 크기
+const SUBSCRIBE_BUFFER: usize = 256;
+
+/// 채팅 이벤트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatEvent {
+    pub room_id: u32,
+    pub user_id: u32,
+    pub nickname: String,
+    pub content: String,
+    /// 발신자 접속 주소
+    pub client_addr: String,
+    /// 발생 시각 (Unix 초)
+    pub timestamp: i64,
+}
+
+/// 세션 종료 이벤트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionEvicted {
+    pub user_id: u32,
+    /// 밀려난 리프레시 토큰 패밀리
+    pub family_id: String,
+    /// 새 세션 로그인 시각 (Unix 초, 이전에 맺은 연결을 끊음)
+    pub evicted_at: i64,
+}
+
+/// 연결 품질 등급 (하트비트 왕복 시간 기준)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectionQuality {
+    Good,
+    /// 지연이 커져 부가 기능(입력 중 표시 등)을 줄이는 것이 좋음
+    Degraded,
+    /// 실시간 기능이 어려운 수준
+    Poor,
+}
+
+impl ConnectionQuality {
+    /// 왕복 시간으로 등급 계산
+    pub fn from_rtt_ms(rtt_ms: f64, degraded_ms: f64, poor_ms: f64) -> Self {
+        if rtt_ms > poor_ms {
+            Self::Poor
+        } else if rtt_ms > degraded_ms {
+            Self::Degraded
+        } else {
+            Self::Good
+        }
+    }
+}
+
+/// 연결 품질 변경 이벤트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionQualityChanged {
+    pub user_id: u32,
+    pub previous: ConnectionQuality,
+    pub current: ConnectionQuality,
+    /// 평활화한 하트비트 왕복 시간 (ms)
+    pub rtt_ms: u32,
+    /// 발생 시각 (Unix 초)
+    pub timestamp: i64,
+}
+
+/// 이벤트 버스
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    redis_config: RedisConfig,
+}
+
+impl EventBus {
+    pub fn new(redis_config: RedisConfig) -> Self {
+        Self { redis_config }
+    }
+
+    /// 이벤트 발행 (구독자 수 반환)
+    pub async fn publish<T: Serialize>(&self, channel: &str, event: &T) -> Result<usize, AppError> {
+        let payload = serde_json::to_string(event)
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        conn.publish(channel, payload).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 채팅 이벤트 발행
+    pub async fn publish_chat(&self, event: &ChatEvent) -> Result<usize, AppError> {
+        self.publish(CHAT_EVENT_CHANNEL, event).await
+    }
+
+    /// 매치 결과 이벤트 발행
+    pub async fn publish_match_result(&self, record: &MatchRecord) -> Result<usize, AppError> {
+        self.publish(MATCH_RESULT_CHANNEL, record).await
+    }
+
+    /// 세션 종료 이벤트 발행
+    pub async fn publish_session_evicted(&self, event: &SessionEvicted) -> Result<usize, AppError> {
+        self.publish(SESSION_EVICTED_CHANNEL, event).await
+    }
+
+    /// 연결 품질 변경 이벤트 발행
+    pub async fn publish_connection_quality(&self, event: &ConnectionQualityChanged) -> Result<usize, AppError> {
+        self.publish(CONNECTION_QUALITY_CHANNEL, event).await
+    }
+
+    /// 채널 구독
+    ///
+    /// 수신한 이벤트를 채널로 전달하며, 수신 측이 닫히면 구독을 종료합니다.
+    /// 역직렬화에 실패한 이벤트는 건너뜁니다.
+    pub async fn subscribe<T>(&self, channel: &str) -> Result<mpsc::Receiver<T>, AppError>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let url = format!("redis://{}:{}", self.redis_config.host, self.redis_config.port);
+        let client = redis::Client::open(url)
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        let mut pubsub = client.get_async_connection().await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?
+            .into_pubsub();
+        pubsub.subscribe(channel).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+
+        let (tx, rx) = mpsc::channel(SUBSCRIBE_BUFFER);
+        let channel = channel.to_string();
+        tokio::spawn(async move {
+            let mut messages = pubsub.into_on_message();
+            while let Some(msg) = messages.next().await {
+                let payload: String = match msg.get_payload() {
+                    Ok(payload) => payload,
+                    Err(e) => {
+                        warn!("이벤트 페이로드 읽기 실패 ({}): {}", channel, e);
+                        continue;
+                    }
+                };
+                let event = match serde_json::from_str(&payload) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!("이벤트 역직렬화 실패 ({}): {}", channel, e);
+                        continue;
+                    }
+                };
+                if tx.send(event).await.is_err() {
+                    break;
+                }
+            }
+            debug!("이벤트 구독 종료: {}", channel);
+        });
+
+        Ok(rx)
+    }
+
+    /// 채팅 이벤트 구독
+    pub async fn subscribe_chat(&self) -> Result<mpsc::Receiver<ChatEvent>, AppError> {
+        self.subscribe(CHAT_EVENT_CHANNEL).await
+    }
+
+    /// 매치 결과 이벤트 구독
+    pub async fn subscribe_match_results(&self) -> Result<mpsc::Receiver<MatchRecord>, AppError> {
+        self.subscribe(MATCH_RESULT_CHANNEL).await
+    }
+
+    /// 세션 종료 이벤트 구독
+    pub async fn subscribe_session_evicted(&self) -> Result<mpsc::Receiver<SessionEvicted>, AppError> {
+        self.subscribe(SESSION_EVICTED_CHANNEL).await
+    }
+
+    /// 연결 품질 변경 이벤트 구독
+    pub async fn subscribe_connection_quality(&self) -> Result<mpsc::Receiver<ConnectionQualityChanged>, AppError> {
+        self.subscribe(CONNECTION_QUALITY_CHANNEL).await
+    }
+}
@@ -0,0 +1,296 @@
+//! 실험(A/B 테스트) 배정
+//!
+//! 사용자를 실험 변형(variant)에 결정적으로 배정하고, 배정 결과를 Redis에 저장해
+//! gRPC(클라이언트 조회)와 게임 서버(TCP/RUDP 게임플레이 조정)가 같은 값을 보게 합니다.
+//!
+//! - 버킷: `FNV-1a(솔트 + 사용자 ID)`로 프로세스/플랫폼과 무관하게 항상 같은 값
+//! - 참여 비율: 실험마다 `traffic_percent`% 사용자만 참여
+//! - 배제 그룹: 같은 `exclusion_group`의 실험들은 그룹 버킷(0~99)을 나눠 가져, 한 사용자는 그중 하나에만 참여
+//! - 변형: 참여한 사용자를 `weight` 비율로 나눔
+//!
+//! 한 번 저장된 배정은 실험 설정(비율/가중치)이 바뀌어도 유지됩니다.
+//! gRPC 서버가 시작할 때 실험 목록을 `experiment:catalog`에 올려 두므로, 게임 서버는
+//! [`ExperimentStore::variant_params`]로 배정된 변형의 파라미터를 바로 읽을 수 있습니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::tool::error::AppError;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// 실험 목록 키
+pub const EXPERIMENT_CATALOG_KEY: &str = "experiment:catalog";
+
+/// 사용자 배정 키 접두사 (`experiment:user:{user_id}` 해시, 필드 = 실험 키, 값 = 변형 이름)
+pub const EXPERIMENT_ASSIGNMENT_PREFIX: &str = "experiment:user";
+
+/// 배정 보관 기간 (조회할 때마다 연장)
+pub const ASSIGNMENT_TTL_SECS: i64 = 90 * 24 * 3600;
+
+/// 참여 비율 버킷 수 (1% 단위)
+const TRAFFIC_BUCKETS: u64 = 100;
+
+fn default_traffic_percent() -> u8 {
+    100
+}
+
+/// 실험 변형
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Variant {
+    pub name: String,
+    /// 배정 가중치 (참여 사용자 중 비율)
+    pub weight: u32,
+    /// 게임플레이 조정값 (이동 속도 배율 등)
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+}
+
+/// 실험 정의
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Experiment {
+    pub key: String,
+    /// 배제 그룹 (같은 그룹의 실험에는 하나만 참여)
+    #[serde(default)]
+    pub exclusion_group: Option<String>,
+    /// 참여 사용자 비율 (0~100)
+    #[serde(default = "default_traffic_percent")]
+    pub traffic_percent: u8,
+    pub variants: Vec<Variant>,
+}
+
+impl Experiment {
+    pub fn variant(&self, name: &str) -> Option<&Variant> {
+        self.variants.iter().find(|variant| variant.name == name)
+    }
+
+    /// 참여한 사용자의 변형 (가중치 비율)
+    fn pick_variant(&self, user_id: u32) -> Option<&Variant> {
+        let total: u64 = self.variants.iter().map(|variant| u64::from(variant.weight)).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut point = bucket(&format!("{}:variant", self.key), user_id) % total;
+        self.variants.iter().find(|variant| {
+            let weight = u64::from(variant.weight);
+            if point < weight {
+                return true;
+            }
+            point -= weight;
+            false
+        })
+    }
+}
+
+/// 실험 목록
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExperimentCatalog {
+    #[serde(default, rename = "experiment")]
+    pub experiments: Vec<Experiment>,
+}
+
+impl ExperimentCatalog {
+    pub fn get(&self, key: &str) -> Option<&Experiment> {
+        self.experiments.iter().find(|experiment| experiment.key == key)
+    }
+
+    /// 설정 검증 (키 중복, 빈 변형, 배제 그룹 비율 합 100% 초과)
+    pub fn validate(&self) -> Result<(), AppError> {
+        let mut keys = HashSet::new();
+        let mut group_traffic: BTreeMap<&str, u32> = BTreeMap::new();
+        for experiment in &self.experiments {
+            if !keys.insert(experiment.key.as_str()) {
+                return Err(AppError::InvalidInput(format!("중복된 실험 키: {}", experiment.key)));
+            }
+            if experiment.traffic_percent > 100 {
+                return Err(AppError::InvalidInput(format!("실험 {} 참여 비율이 100%를 넘습니다", experiment.key)));
+            }
+            if experiment.variants.is_empty() || experiment.variants.iter().all(|variant| variant.weight == 0) {
+                return Err(AppError::InvalidInput(format!("실험 {}에 가중치가 있는 변형이 없습니다", experiment.key)));
+            }
+            if let Some(group) = &experiment.exclusion_group {
+                let total = group_traffic.entry(group).or_default();
+                *total += u32::from(experiment.traffic_percent);
+                if *total > 100 {
+                    return Err(AppError::InvalidInput(format!("배제 그룹 {}의 참여 비율 합이 100%를 넘습니다", group)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 저장된 배정이 없을 때의 배정 (실험 키 → 변형 이름)
+    ///
+    /// `existing`에 이미 배정이 있는 실험과, 그 실험의 배제 그룹에 속한 다른 실험은 건너뜁니다.
+    pub fn assign(&self, user_id: u32, existing: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+        let taken_groups: HashSet<&str> = existing
+            .keys()
+            .filter_map(|key| self.get(key)?.exclusion_group.as_deref())
+            .collect();
+
+        let mut assigned = BTreeMap::new();
+        let mut group_offsets: BTreeMap<&str, u64> = BTreeMap::new();
+        for experiment in &self.experiments {
+            let traffic = u64::from(experiment.traffic_percent);
+            let enrolled = match experiment.exclusion_group.as_deref() {
+                Some(group) => {
+                    // 그룹 버킷을 목록 순서대로 나눠 가짐
+                    let offset = group_offsets.entry(group).or_default();
+                    let range = *offset..*offset + traffic;
+                    *offset += traffic;
+                    !taken_groups.contains(group) && range.contains(&(bucket(&format!("group:{group}"), user_id) % TRAFFIC_BUCKETS))
+                }
+                None => bucket(&experiment.key, user_id) % TRAFFIC_BUCKETS < traffic,
+            };
+            if !enrolled || existing.contains_key(&experiment.key) {
+                continue;
+            }
+            if let Some(variant) = experiment.pick_variant(user_id) {
+                assigned.insert(experiment.key.clone(), variant.name.clone());
+            }
+        }
+        assigned
+    }
+}
+
+/// 사용자 버킷 (같은 솔트/사용자면 항상 같은 값)
+pub fn bucket(salt: &str, user_id: u32) -> u64 {
+    // FNV-1a: 프로세스/플랫폼과 무관하게 고정된 해시
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in salt.as_bytes().iter().chain(b":").chain(&user_id.to_le_bytes()) {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// 배정 저장소
+#[derive(Debug, Clone)]
+pub struct ExperimentStore {
+    redis_config: RedisConfig,
+}
+
+impl ExperimentStore {
+    pub fn new(redis_config: RedisConfig) -> Self {
+        Self { redis_config }
+    }
+
+    fn assignment_key(user_id: u32) -> String {
+        format!("{EXPERIMENT_ASSIGNMENT_PREFIX}:{user_id}")
+    }
+
+    /// 실험 목록 게시 (게임 서버의 변형 파라미터 조회용)
+    pub async fn publish_catalog(&self, catalog: &ExperimentCatalog) -> Result<(), AppError> {
+        let payload = serde_json::to_string(catalog)
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        conn.set(EXPERIMENT_CATALOG_KEY, payload).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 게시된 실험 목록 (없으면 빈 목록)
+    pub async fn load_catalog(&self) -> Result<ExperimentCatalog, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        let payload: Option<String> = conn.get(EXPERIMENT_CATALOG_KEY).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        match payload {
+            Some(payload) => serde_json::from_str(&payload).map_err(|e| AppError::InvalidFormat(e.to_string())),
+            None => Ok(ExperimentCatalog::default()),
+        }
+    }
+
+    /// 저장된 배정 (실험 키 → 변형 이름)
+    pub async fn assignments(&self, user_id: u32) -> Result<BTreeMap<String, String>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        conn.hgetall(Self::assignment_key(user_id)).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 배정 저장 (이미 있는 실험은 덮어쓰지 않음) 후 최종 배정 반환
+    pub async fn save_assignments(
+        &self,
+        user_id: u32,
+        assigned: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>, AppError> {
+        let key = Self::assignment_key(user_id);
+        let mut pipe = redis::pipe();
+        for (experiment, variant) in assigned {
+            pipe.hset_nx(&key, experiment, variant).ignore();
+        }
+        pipe.expire(&key, ASSIGNMENT_TTL_SECS).ignore();
+        pipe.hgetall(&key);
+
+        let mut conn = self.redis_config.get_connection();
+        let (stored,): (BTreeMap<String, String>,) = pipe.query_async(&mut conn).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        Ok(stored)
+    }
+
+    /// 사용자에게 배정된 변형의 파라미터 (참여하지 않았으면 None)
+    pub async fn variant_params(&self, user_id: u32, experiment: &str) -> Result<Option<BTreeMap<String, String>>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        let variant: Option<String> = conn.hget(Self::assignment_key(user_id), experiment).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        let Some(variant) = variant else {
+            return Ok(None);
+        };
+        let catalog = self.load_catalog().await?;
+        Ok(catalog.get(experiment).and_then(|experiment| experiment.variant(&variant)).map(|variant| variant.params.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn experiment(key: &str, group: Option<&str>, traffic_percent: u8) -> Experiment {
+        Experiment {
+            key: key.to_string(),
+            exclusion_group: group.map(str::to_string),
+            traffic_percent,
+            variants: vec![
+                Variant { name: "control".to_string(), weight: 1, params: BTreeMap::new() },
+                Variant { name: "treatment".to_string(), weight: 1, params: BTreeMap::new() },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_assignment_is_deterministic_and_exclusive() {
+        let catalog = ExperimentCatalog {
+            experiments: vec![
+                experiment("speed", Some("movement"), 50),
+                experiment("dash", Some("movement"), 50),
+                experiment("lobby_music", None, 30),
+            ],
+        };
+        catalog.validate().unwrap();
+
+        let (mut treatment, mut music) = (0, 0);
+        for user_id in 0..2000 {
+            let assigned = catalog.assign(user_id, &BTreeMap::new());
+            assert_eq!(assigned, catalog.assign(user_id, &BTreeMap::new()));
+            // 배제 그룹 비율 합이 100%이므로 둘 중 정확히 하나
+            assert_eq!(assigned.contains_key("speed") as u8 + assigned.contains_key("dash") as u8, 1);
+            treatment += assigned.values().filter(|variant| *variant == "treatment").count();
+            music += assigned.contains_key("lobby_music") as usize;
+        }
+        assert!((400..800).contains(&music), "lobby_music 참여 {music}");
+        assert!((1000..1600).contains(&treatment), "treatment {treatment}");
+
+        // 저장된 배정이 있으면 같은 그룹의 다른 실험에 배정하지 않음
+        let existing = BTreeMap::from([("dash".to_string(), "control".to_string())]);
+        let user_in_speed = (0..100).find(|user_id| catalog.assign(*user_id, &BTreeMap::new()).contains_key("speed")).unwrap();
+        assert!(!catalog.assign(user_in_speed, &existing).contains_key("speed"));
+    }
+
+    #[test]
+    fn test_catalog_validation() {
+        let over = ExperimentCatalog { experiments: vec![experiment("a", Some("g"), 60), experiment("b", Some("g"), 60)] };
+        assert!(over.validate().is_err());
+        let duplicate = ExperimentCatalog { experiments: vec![experiment("a", None, 10), experiment("a", None, 10)] };
+        assert!(duplicate.validate().is_err());
+        let mut empty = experiment("c", None, 10);
+        empty.variants.iter_mut().for_each(|variant| variant.weight = 0);
+        assert!(ExperimentCatalog { experiments: vec![empty] }.validate().is_err());
+    }
+}
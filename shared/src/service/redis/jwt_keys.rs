@@ -0,0 +1,88 @@
+//! JWT 키 배포
+//!
+//! 게임센터가 주기적으로 새 서명 키를 만들어 Redis(`auth:jwt:keyset`)에 올리고,
+//! 각 서버는 [`JwtKeyStore::spawn_refresh`]로 키 목록을 읽어 [`TokenService`]에 설치합니다.
+//!
+//! 새 키는 [`ROTATION_PROPAGATION_SECS`] 뒤에 활성화되므로, 모든 서버가 검증 키를 받은 뒤에야
+//! 새 키로 서명한 토큰이 발급됩니다. 이전 키는 토큰 수명 동안 검증용으로 남아 회전 중에도
+//! 세션이 끊기지 않습니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::service::token::{JwtKey, JwtKeySet, TokenService};
+use crate::tool::error::AppError;
+use redis::AsyncCommands;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// 키 목록 키
+pub const JWT_KEYSET_KEY: &str = "auth:jwt:keyset";
+
+/// 새 키 활성화까지의 배포 지연 (갱신 주기보다 충분히 길게)
+pub const ROTATION_PROPAGATION_SECS: i64 = 600;
+
+/// 서버의 기본 키 목록 갱신 주기
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 배포 키 저장소
+#[derive(Debug, Clone)]
+pub struct JwtKeyStore {
+    redis_config: RedisConfig,
+}
+
+impl JwtKeyStore {
+    pub fn new(redis_config: RedisConfig) -> Self {
+        Self { redis_config }
+    }
+
+    /// 배포된 키 목록 (아직 회전한 적 없으면 None)
+    pub async fn load(&self) -> Result<Option<JwtKeySet>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        let payload: Option<String> = conn.get(JWT_KEYSET_KEY).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        payload
+            .map(|payload| serde_json::from_str(&payload).map_err(|e| AppError::InvalidFormat(e.to_string())))
+            .transpose()
+    }
+
+    /// 새 키 추가
+    ///
+    /// 첫 회전이면 `seed`(환경변수 키)에서 시작해, 기존 토큰이 배포 키 목록으로도 검증되게 합니다.
+    ///
+    /// # Arguments
+    /// * `seed` - 배포 키가 없을 때의 시작 목록
+    /// * `retain_secs` - 서명을 멈춘 키를 검증용으로 남겨 둘 기간 (토큰 수명 이상)
+    pub async fn rotate(&self, seed: JwtKeySet, retain_secs: i64) -> Result<JwtKeySet, AppError> {
+        let now = chrono::Utc::now().timestamp();
+        let mut keys = self.load().await?.unwrap_or(seed);
+        let key = JwtKey::generate(now + ROTATION_PROPAGATION_SECS);
+        info!("JWT 키 회전: kid={}, 활성화 {}초 후", key.kid, ROTATION_PROPAGATION_SECS);
+        keys.rotate(key, retain_secs, now);
+
+        let payload = serde_json::to_string(&keys).map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        let _: () = conn.set(JWT_KEYSET_KEY, payload).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        Ok(keys)
+    }
+
+    /// 배포 키 목록을 주기적으로 읽어 [`TokenService`]에 설치합니다.
+    pub fn spawn_refresh(self, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            let mut installed: Option<JwtKeySet> = None;
+            loop {
+                ticker.tick().await;
+                match self.load().await {
+                    Ok(Some(keys)) if installed.as_ref() != Some(&keys) => {
+                        info!("JWT 배포 키 설치: {:?}", keys.keys.iter().map(|key| &key.kid).collect::<Vec<_>>());
+                        TokenService::install_distributed_keys(keys.clone());
+                        installed = Some(keys);
+                    }
+                    Ok(_) => debug!("JWT 배포 키 변경 없음"),
+                    Err(e) => warn!("JWT 배포 키 조회 실패 (기존 키 유지): {}", e),
+                }
+            }
+        })
+    }
+}
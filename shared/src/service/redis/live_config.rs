@@ -0,0 +1,306 @@
+//! 라이브 설정 (운영 중 킬 스위치/수치 조정)
+//!
+//! 값은 Redis 해시(`liveops:config`)에 저장하고, 변경 시 `events:live_config` 채널로 알려
+//! 모든 서버가 수 초 안에 반영합니다. 각 서버는 메모리 사본을 읽으므로 조회는 동기이고
+//! Redis 왕복이 없습니다. 알림을 놓쳐도 [`LIVE_CONFIG_RESYNC_INTERVAL`]마다 전체를 다시 읽습니다.
+//!
+//! 킬 스위치는 `kill.<기능>` 키에 참 값을 넣어 켭니다. 기능 이름은 서버별 엔드포인트 이름
+//! (`tcp:<메시지 타입>`, `rudp:<메시지 타입>`, `grpc:<패키지>` 등)을 씁니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::security::{AuditEvent, AuditEventKind, AuditSink};
+use crate::service::redis::event_bus::EventBus;
+use crate::tool::error::AppError;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// 라이브 설정 해시 키
+pub const LIVE_CONFIG_KEY: &str = "liveops:config";
+
+/// 라이브 설정 변경 알림 채널
+pub const LIVE_CONFIG_CHANNEL: &str = "events:live_config";
+
+/// 알림 누락에 대비한 전체 재조회 주기
+pub const LIVE_CONFIG_RESYNC_INTERVAL: Duration = Duration::from_secs(30);
+
+/// 킬 스위치 키 접두어
+pub const KILL_SWITCH_PREFIX: &str = "kill.";
+
+/// 설정 키 최대 길이
+pub const MAX_KEY_LEN: usize = 64;
+
+/// 설정 값 최대 길이
+pub const MAX_VALUE_LEN: usize = 256;
+
+/// 구독이 끊겼을 때 재구독 전 대기 시간
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+/// 라이브 설정 변경 이벤트
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiveConfigChanged {
+    pub key: String,
+    /// 새 값 (None이면 삭제되어 기본값으로 돌아감)
+    pub value: Option<String>,
+    /// 변경한 운영자
+    pub actor: String,
+    /// 변경 시각 (Unix 초)
+    pub changed_at: i64,
+}
+
+/// 라이브 설정
+///
+/// 복제해도 같은 메모리 사본을 공유합니다.
+#[derive(Clone)]
+pub struct LiveConfig {
+    values: Arc<RwLock<HashMap<String, String>>>,
+    redis_config: Option<RedisConfig>,
+    audit: Option<Arc<AuditSink>>,
+}
+
+impl LiveConfig {
+    pub fn new(redis_config: RedisConfig) -> Self {
+        Self { values: Arc::default(), redis_config: Some(redis_config), audit: None }
+    }
+
+    /// Redis 없이 프로세스 안에서만 쓰는 설정 (개발 프로필, 테스트)
+    pub fn local() -> Self {
+        Self { values: Arc::default(), redis_config: None, audit: None }
+    }
+
+    /// 변경 내역을 보안 감사 로그에도 기록
+    pub fn with_audit(mut self, audit: Arc<AuditSink>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// 문자열 값
+    pub fn get_str(&self, key: &str) -> Option<String> {
+        self.read().get(key).cloned()
+    }
+
+    /// 불리언 값 (`true/false`, `1/0`, `on/off`, `yes/no`, 해석할 수 없으면 기본값)
+    pub fn get_bool(&self, key: &str, default: bool) -> bool {
+        self.read().get(key).and_then(|value| parse_bool(value)).unwrap_or(default)
+    }
+
+    /// 정수 값 (해석할 수 없으면 기본값)
+    pub fn get_u64(&self, key: &str, default: u64) -> u64 {
+        self.read().get(key).and_then(|value| value.trim().parse().ok()).unwrap_or(default)
+    }
+
+    /// 실수 값 (해석할 수 없으면 기본값)
+    pub fn get_f64(&self, key: &str, default: f64) -> f64 {
+        self.read()
+            .get(key)
+            .and_then(|value| value.trim().parse().ok())
+            .filter(|value: &f64| value.is_finite())
+            .unwrap_or(default)
+    }
+
+    /// 기능이 킬 스위치로 꺼졌는지 (`kill.<feature>`)
+    pub fn is_killed(&self, feature: &str) -> bool {
+        self.get_bool(&kill_switch_key(feature), false)
+    }
+
+    /// 현재 값 전체 (키 순서)
+    pub fn snapshot(&self) -> BTreeMap<String, String> {
+        self.read().iter().map(|(key, value)| (key.clone(), value.clone())).collect()
+    }
+
+    /// Redis에서 전체 값을 다시 읽어 메모리 사본을 교체
+    pub async fn reload(&self) -> Result<usize, AppError> {
+        let Some(redis_config) = &self.redis_config else {
+            return Ok(self.read().len());
+        };
+        let mut conn = redis_config.get_connection();
+        let values: HashMap<String, String> = conn.hgetall(LIVE_CONFIG_KEY).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        let count = values.len();
+        *self.values.write().unwrap_or_else(|e| e.into_inner()) = values;
+        Ok(count)
+    }
+
+    /// 값 변경 (`value`가 None이면 삭제)
+    ///
+    /// Redis에 저장한 뒤 변경 알림을 발행합니다. 알림 발행이 실패해도 다른 서버는
+    /// 재조회 주기 안에 반영하므로 변경은 성공으로 처리합니다.
+    pub async fn set(&self, key: &str, value: Option<&str>, actor: &str) -> Result<LiveConfigChanged, AppError> {
+        validate_entry(key, value)?;
+        let previous = self.get_str(key);
+
+        if let Some(redis_config) = &self.redis_config {
+            let mut conn = redis_config.get_connection();
+            let result: redis::RedisResult<()> = match value {
+                Some(value) => conn.hset(LIVE_CONFIG_KEY, key, value).await,
+                None => conn.hdel(LIVE_CONFIG_KEY, key).await,
+            };
+            result.map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        }
+
+        let change = LiveConfigChanged {
+            key: key.to_string(),
+            value: value.map(str::to_string),
+            actor: actor.to_string(),
+            changed_at: chrono::Utc::now().timestamp(),
+        };
+        self.apply(&change);
+
+        if let Some(redis_config) = &self.redis_config {
+            if let Err(e) = EventBus::new(redis_config.clone()).publish(LIVE_CONFIG_CHANNEL, &change).await {
+                warn!("라이브 설정 변경 알림 실패 (다른 서버는 재조회 때 반영): {}", e);
+            }
+        }
+
+        let detail = format!("{}: {:?} -> {:?}", key, previous, change.value);
+        info!("라이브 설정 변경 (actor={}): {}", actor, detail);
+        if let Some(audit) = &self.audit {
+            let event = AuditEvent::new(AuditEventKind::ConfigChange, actor).with_target(key).with_detail(detail);
+            if let Err(e) = audit.record(event).await {
+                warn!("라이브 설정 변경 감사 기록 실패: {}", e);
+            }
+        }
+        Ok(change)
+    }
+
+    /// 변경 이벤트를 메모리 사본에 반영
+    pub fn apply(&self, change: &LiveConfigChanged) {
+        let mut values = self.values.write().unwrap_or_else(|e| e.into_inner());
+        match &change.value {
+            Some(value) => values.insert(change.key.clone(), value.clone()),
+            None => values.remove(&change.key),
+        };
+    }
+
+    /// 변경 알림 구독과 주기적 재조회로 메모리 사본을 최신으로 유지합니다.
+    ///
+    /// Redis 없이 만든 설정이면 아무것도 하지 않고 끝납니다.
+    pub fn spawn_sync(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let Some(redis_config) = self.redis_config.clone() else {
+                return;
+            };
+            let bus = EventBus::new(redis_config);
+            let mut ticker = tokio::time::interval(LIVE_CONFIG_RESYNC_INTERVAL);
+            loop {
+                let mut changes = match bus.subscribe::<LiveConfigChanged>(LIVE_CONFIG_CHANNEL).await {
+                    Ok(changes) => changes,
+                    Err(e) => {
+                        warn!("라이브 설정 구독 실패 (재조회 주기로만 반영): {}", e);
+                        ticker.tick().await;
+                        self.reload_or_warn().await;
+                        continue;
+                    }
+                };
+                // 구독 뒤에 전체를 읽어 구독 전 변경을 놓치지 않음
+                self.reload_or_warn().await;
+                loop {
+                    tokio::select! {
+                        change = changes.recv() => match change {
+                            Some(change) => {
+                                debug!("라이브 설정 변경 수신: {} = {:?}", change.key, change.value);
+                                self.apply(&change);
+                            }
+                            None => break,
+                        },
+                        _ = ticker.tick() => self.reload_or_warn().await,
+                    }
+                }
+                warn!("라이브 설정 구독 끊김, 재구독");
+                tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+            }
+        })
+    }
+
+    async fn reload_or_warn(&self) {
+        match self.reload().await {
+            Ok(count) => debug!("라이브 설정 재조회: {}개", count),
+            Err(e) => warn!("라이브 설정 재조회 실패 (기존 값 유지): {}", e),
+        }
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<String, String>> {
+        self.values.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 기능의 킬 스위치 키
+pub fn kill_switch_key(feature: &str) -> String {
+    format!("{KILL_SWITCH_PREFIX}{feature}")
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "true" | "1" | "on" | "yes" => Some(true),
+        "false" | "0" | "off" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// 키는 영숫자와 `._-:/`만, 값은 길이만 검사
+fn validate_entry(key: &str, value: Option<&str>) -> Result<(), AppError> {
+    if key.is_empty() || key.len() > MAX_KEY_LEN {
+        return Err(AppError::InvalidInput(format!("설정 키 길이는 1~{MAX_KEY_LEN}자여야 합니다")));
+    }
+    if !key.chars().all(|c| c.is_ascii_alphanumeric() || "._-:/".contains(c)) {
+        return Err(AppError::InvalidInput(format!("설정 키에 허용되지 않는 문자가 있습니다: {key}")));
+    }
+    if value.is_some_and(|value| value.len() > MAX_VALUE_LEN) {
+        return Err(AppError::InvalidInput(format!("설정 값은 {MAX_VALUE_LEN}바이트 이하여야 합니다")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(key: &str, value: Option<&str>) -> LiveConfigChanged {
+        LiveConfigChanged {
+            key: key.to_string(),
+            value: value.map(str::to_string),
+            actor: "test".to_string(),
+            changed_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_typed_accessors_fall_back_to_defaults() {
+        let config = LiveConfig::local();
+        config.apply(&change("chat.enabled", Some("off")));
+        config.apply(&change("room.max", Some("12")));
+        config.apply(&change("drop.rate", Some("nan")));
+
+        assert!(!config.get_bool("chat.enabled", true));
+        assert!(config.get_bool("missing", true));
+        assert_eq!(config.get_u64("room.max", 8), 12);
+        assert_eq!(config.get_u64("chat.enabled", 8), 8);
+        assert_eq!(config.get_f64("drop.rate", 0.5), 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_kill_switch_set_and_clear() {
+        let config = LiveConfig::local();
+        assert!(!config.is_killed("tcp:chat"));
+
+        config.set(&kill_switch_key("tcp:chat"), Some("true"), "admin").await.unwrap();
+        assert!(config.is_killed("tcp:chat"));
+        assert!(config.clone().is_killed("tcp:chat"));
+
+        config.set(&kill_switch_key("tcp:chat"), None, "admin").await.unwrap();
+        assert!(!config.is_killed("tcp:chat"));
+        assert!(config.snapshot().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_entries_are_rejected() {
+        let config = LiveConfig::local();
+        assert!(config.set("", Some("1"), "admin").await.is_err());
+        assert!(config.set("kill.chat room", Some("1"), "admin").await.is_err());
+        assert!(config.set("note", Some(&"x".repeat(MAX_VALUE_LEN + 1)), "admin").await.is_err());
+    }
+}
@@ -1,4 +1,14 @@
 pub mod core;
 pub mod hepler; 
+pub mod event_bus;
+pub mod server_stats;
+pub mod server_routing;
+pub mod region_presence;
+pub mod read_validator;
+pub mod experiments;
+pub mod jwt_keys;
+pub mod live_config;
+pub mod state_replica;
+pub mod script_manager;
 pub mod room_redis_service;
 pub mod user_redis_service;
\ No newline at end of file
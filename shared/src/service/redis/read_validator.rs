@@ -0,0 +1,67 @@
+//! 읽기 응답 검증자 (ETag 유사)
+//!
+//! 로비가 자주 폴링하는 읽기(방 목록 등)의 데이터 버전 토큰을 Redis 키 하나로 관리합니다.
+//! - 쓰기 쪽은 데이터를 바꿀 때 토큰 키를 지웁니다. ([`ReadValidator::invalidate`] 또는 같은 파이프라인에서 `DEL`)
+//! - 읽기 쪽은 클라이언트가 가진 토큰이 [`ReadValidator::peek`] 결과와 같으면 데이터를 읽지 않습니다.
+//! - 토큰이 없으면 데이터를 읽기 *전에* [`ReadValidator::current`]로 만들어, 읽는 도중 바뀐 데이터가
+//!   옛 토큰에 묶이지 않게 합니다.
+//!
+//! 방 정보 TTL 만료처럼 쓰기 없이 바뀌는 데이터도 있으므로 토큰에도 TTL을 둡니다.
+//! 응답은 최대 토큰 TTL만큼 오래될 수 있습니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::tool::error::AppError;
+use redis::AsyncCommands;
+
+/// 방 목록 버전 토큰 키
+pub const ROOM_LIST_VALIDATOR_KEY: &str = "room:list:version";
+
+/// 토큰 기본 TTL (초)
+pub const DEFAULT_VALIDATOR_TTL_SECS: u64 = 30;
+
+/// 읽기 응답 검증자
+#[derive(Debug, Clone)]
+pub struct ReadValidator {
+    redis_config: RedisConfig,
+    key: String,
+    ttl_secs: u64,
+}
+
+impl ReadValidator {
+    pub fn new(redis_config: RedisConfig, key: impl Into<String>, ttl_secs: u64) -> Self {
+        Self { redis_config, key: key.into(), ttl_secs: ttl_secs.max(1) }
+    }
+
+    /// 방 목록 검증자
+    pub fn room_list(redis_config: RedisConfig) -> Self {
+        Self::new(redis_config, ROOM_LIST_VALIDATOR_KEY, DEFAULT_VALIDATOR_TTL_SECS)
+    }
+
+    /// 현재 토큰 (없으면 None, Redis 조회 한 번)
+    pub async fn peek(&self) -> Result<Option<String>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        conn.get(&self.key).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 현재 토큰 (없으면 새로 발급)
+    pub async fn current(&self) -> Result<String, AppError> {
+        let candidate = format!("{:016x}", rand::random::<u64>());
+        let mut conn = self.redis_config.get_connection();
+        let (current,): (Option<String>,) = redis::pipe()
+            .cmd("SET").arg(&self.key).arg(&candidate).arg("NX").arg("EX").arg(self.ttl_secs).ignore()
+            .get(&self.key)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        // 발급 직후 지워졌으면 이번 응답에만 후보 토큰을 사용 (다음 검증에서 어긋나 다시 읽음)
+        Ok(current.unwrap_or(candidate))
+    }
+
+    /// 토큰 폐기 (데이터 변경 후 호출)
+    pub async fn invalidate(&self) -> Result<(), AppError> {
+        let mut conn = self.redis_config.get_connection();
+        conn.del(&self.key).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+}
@@ -0,0 +1,240 @@
+//! 지역별 접속 정보 저장소
+//!
+//! 게임 서버는 사용자가 접속한 인스턴스를 지역을 포함한 키(`presence:{region}:{user_id}`)에 기록하고,
+//! 변경 사항을 로컬 채널(`events:presence`)로 알립니다.
+//! 게임센터는 이 변경 사항을 지역 간 복제 채널로 다른 지역에 전달하고, 받은 기록을
+//! 원래 지역 키 그대로 로컬 Redis에 써 둡니다. 덕분에 친구 접속 상태 조회는 로컬 Redis만 읽어도
+//! 다른 지역에 접속한 친구를 그 지역과 함께 보여줄 수 있습니다.
+//!
+//! 복제되는 데이터는 지역, 인스턴스, 접속 여부뿐이며 세션 토큰 등은 지역 밖으로 나가지 않습니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::tool::error::AppError;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// 로컬 접속 정보 변경 채널
+pub const PRESENCE_CHANNEL: &str = "events:presence";
+
+/// 지역 간 복제 기본 채널
+pub const DEFAULT_REPLICATION_CHANNEL: &str = "replication:presence";
+
+/// 접속 정보를 기록한 적 있는 지역 목록 키
+pub const PRESENCE_REGIONS_KEY: &str = "presence:regions";
+
+/// 접속 정보 TTL (초) - 인스턴스가 비정상 종료되어도 오래 온라인으로 남지 않도록 만료
+pub const PRESENCE_TTL_SECS: u64 = 3600;
+
+/// 사용자 접속 정보 키 (`presence:{region}:{user_id}`)
+pub fn presence_key(region: &str, user_id: u32) -> String {
+    format!("presence:{region}:{user_id}")
+}
+
+/// 방 참가자 목록 키 (`room:{region}:{room_id}:users`)
+pub fn room_users_key(region: &str, room_id: u32) -> String {
+    format!("room:{region}:{room_id}:users")
+}
+
+/// 사용자 접속 정보
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresenceRecord {
+    pub user_id: u32,
+    /// 접속한 지역
+    pub region: String,
+    /// 접속한 서버 인스턴스 (host:port)
+    pub instance: String,
+    pub online: bool,
+    /// 기록 시각 (Unix 밀리초, 지역 간 순서 비교용)
+    pub updated_at: i64,
+}
+
+impl PresenceRecord {
+    pub fn online(user_id: u32, region: impl Into<String>, instance: impl Into<String>) -> Self {
+        Self {
+            user_id,
+            region: region.into(),
+            instance: instance.into(),
+            online: true,
+            updated_at: chrono::Utc::now().timestamp_millis(),
+        }
+    }
+
+    pub fn offline(user_id: u32, region: impl Into<String>, instance: impl Into<String>) -> Self {
+        Self { online: false, ..Self::online(user_id, region, instance) }
+    }
+
+    pub fn key(&self) -> String {
+        presence_key(&self.region, self.user_id)
+    }
+}
+
+/// 사용자별로 가장 최근 접속 정보만 남김
+///
+/// 지역을 옮겨 다시 접속했는데 이전 지역의 접속 해제가 아직 복제되지 않았으면
+/// 두 지역 모두 온라인으로 보이므로, 나중에 기록된 쪽을 사용합니다.
+pub fn latest_per_user(records: impl IntoIterator<Item = PresenceRecord>) -> HashMap<u32, PresenceRecord> {
+    let mut latest: HashMap<u32, PresenceRecord> = HashMap::new();
+    for record in records {
+        match latest.get(&record.user_id) {
+            Some(current) if current.updated_at >= record.updated_at => {}
+            _ => {
+                latest.insert(record.user_id, record);
+            }
+        }
+    }
+    latest
+}
+
+/// 지역별 접속 정보 저장소
+#[derive(Debug, Clone)]
+pub struct PresenceStore {
+    redis_config: RedisConfig,
+    region: String,
+}
+
+impl PresenceStore {
+    pub fn new(redis_config: RedisConfig, region: impl Into<String>) -> Self {
+        Self { redis_config, region: region.into() }
+    }
+
+    /// 이 저장소가 기록하는 지역
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    /// 이 지역 인스턴스에 접속했음을 기록하고 변경 채널로 알림
+    pub async fn set_online(&self, user_id: u32, instance: &str) -> Result<PresenceRecord, AppError> {
+        let record = PresenceRecord::online(user_id, &self.region, instance);
+        self.write(&record).await?;
+        self.notify(&record).await?;
+        Ok(record)
+    }
+
+    /// 접속 해제 기록 (다른 인스턴스로 재접속했으면 덮어쓰지 않고 false)
+    pub async fn set_offline(&self, user_id: u32, instance: &str) -> Result<bool, AppError> {
+        match self.get(&self.region, user_id).await? {
+            Some(current) if current.instance == instance => {}
+            _ => return Ok(false),
+        }
+        let record = PresenceRecord::offline(user_id, &self.region, instance);
+        self.write(&record).await?;
+        self.notify(&record).await?;
+        Ok(true)
+    }
+
+    /// 이 지역에서 사용자가 접속한 인스턴스
+    pub async fn local_instance(&self, user_id: u32) -> Result<Option<String>, AppError> {
+        Ok(self.get(&self.region, user_id).await?
+            .filter(|record| record.online)
+            .map(|record| record.instance))
+    }
+
+    /// 다른 지역에서 복제된 접속 정보 반영
+    ///
+    /// 이 지역의 기록이거나 이미 더 최근 기록이 있으면 무시하고 false를 반환합니다.
+    pub async fn apply_remote(&self, record: &PresenceRecord) -> Result<bool, AppError> {
+        if record.region == self.region {
+            return Ok(false);
+        }
+        if let Some(current) = self.get(&record.region, record.user_id).await? {
+            if current.updated_at > record.updated_at {
+                return Ok(false);
+            }
+        }
+        self.write(record).await?;
+        Ok(true)
+    }
+
+    /// 여러 사용자의 접속 정보를 모든 지역에서 조회 (온라인인 사용자만)
+    pub async fn lookup_many(&self, user_ids: &[u32]) -> Result<HashMap<u32, PresenceRecord>, AppError> {
+        if user_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let mut conn = self.redis_config.get_connection();
+        let mut regions: BTreeSet<String> = conn.smembers(PRESENCE_REGIONS_KEY).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        regions.insert(self.region.clone());
+
+        let keys: Vec<String> = regions.iter()
+            .flat_map(|region| user_ids.iter().map(move |&user_id| presence_key(region, user_id)))
+            .collect();
+        let raw: Vec<Option<String>> = conn.mget(&keys).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+
+        let records = raw.into_iter()
+            .flatten()
+            .filter_map(|raw| serde_json::from_str::<PresenceRecord>(&raw).ok());
+        let mut latest = latest_per_user(records);
+        latest.retain(|_, record| record.online);
+        Ok(latest)
+    }
+
+    async fn get(&self, region: &str, user_id: u32) -> Result<Option<PresenceRecord>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        let raw: Option<String> = conn.get(presence_key(region, user_id)).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        raw.map(|raw| serde_json::from_str(&raw).map_err(|e| AppError::InvalidFormat(e.to_string())))
+            .transpose()
+    }
+
+    /// 접속 해제도 TTL 동안 남겨 두어 늦게 도착한 이전 접속 기록이 덮어쓰지 않게 함
+    async fn write(&self, record: &PresenceRecord) -> Result<(), AppError> {
+        let payload = serde_json::to_string(record)
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        redis::pipe()
+            .set_ex(record.key(), payload, PRESENCE_TTL_SECS)
+            .sadd(PRESENCE_REGIONS_KEY, &record.region)
+            .query_async::<_, ()>(&mut conn).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    async fn notify(&self, record: &PresenceRecord) -> Result<(), AppError> {
+        let payload = serde_json::to_string(record)
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        conn.publish(PRESENCE_CHANNEL, payload).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(user_id: u32, region: &str, online: bool, updated_at: i64) -> PresenceRecord {
+        PresenceRecord {
+            user_id,
+            region: region.to_string(),
+            instance: format!("{region}-1:4000"),
+            online,
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn test_region_keys() {
+        assert_eq!(presence_key("kr", 42), "presence:kr:42");
+        assert_eq!(room_users_key("eu", 7), "room:eu:7:users");
+        assert_eq!(record(42, "eu", true, 0).key(), "presence:eu:42");
+    }
+
+    #[test]
+    fn test_latest_record_wins_across_regions() {
+        let latest = latest_per_user(vec![
+            record(1, "kr", true, 100),
+            record(1, "eu", true, 200),
+            record(2, "kr", true, 300),
+            record(2, "eu", false, 250),
+        ]);
+
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[&1].region, "eu");
+        assert_eq!(latest[&2].region, "kr");
+
+        // 같은 시각이면 먼저 읽은 기록 유지
+        let tie = latest_per_user(vec![record(3, "kr", true, 10), record(3, "eu", true, 10)]);
+        assert_eq!(tie[&3].region, "kr");
+    }
+}
@@ -1,6 +1,7 @@
 use crate::config::{redis_config::RedisConfig, connection_pool::ConnectionPool};
 use crate::service::redis::core::redis_get_key::KeyType;
 use crate::service::redis::hepler::zset_helper::ZSetHelper;
+use crate::service::redis::read_validator::ROOM_LIST_VALIDATOR_KEY;
 use crate::model::RoomInfo;
 use crate::tool::error::AppError;
 use redis::Value;
@@ -53,6 +54,8 @@ impl RoomRedisService {
            room_id.to_string(), // 멤버 (Member)
            score,               // 점수 (Score)
        );
+       // 방 목록 검증 토큰 폐기 (로비 폴링이 새 목록을 읽도록)
+       p.del(ROOM_LIST_VALIDATOR_KEY);
         let _resp: Vec<Value> = p.query_async(&mut conn).await
             .map_err(|e| AppError::RedisConnection(e.to_string()))?;
         Ok(true)
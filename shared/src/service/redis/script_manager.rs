@@ -0,0 +1,214 @@
+//! Lua 스크립트 관리자
+//!
+//! 여러 키를 원자적으로 다뤄야 하는 작업(레이트 리밋, 매칭 대기열 꺼내기, 쿼터 차감 등)을
+//! Lua 스크립트로 실행합니다. 스크립트는 이름으로 등록하고 `EVALSHA`로 호출하며,
+//! Redis 재시작/`SCRIPT FLUSH`로 캐시가 비어 `NOSCRIPT`가 나오면 다시 로드한 뒤 한 번 재시도합니다.
+//!
+//! 기본 원자 연산 라이브러리:
+//! - [`ScriptManager::check_and_decrement`]: 잔량이 충분할 때만 차감
+//! - [`ScriptManager::incr_with_limit`]: 한도 안에서만 증가 (첫 증가 시 TTL 설정)
+//! - [`ScriptManager::pop_batch_from_zset`]: 점수 상한 이하 멤버를 최대 N개 꺼내기
+
+use crate::config::redis_config::RedisConfig;
+use redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, Script, ToRedisArgs};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tracing::{debug, warn};
+
+/// 잔량이 ARGV[1] 이상이면 차감 후 남은 값, 아니면 nil
+pub const CHECK_AND_DECREMENT: &str = "check_and_decrement";
+/// 현재 값이 ARGV[1] 미만이면 증가 후 값, 아니면 nil (첫 증가 시 ARGV[2]초 TTL)
+pub const INCR_WITH_LIMIT: &str = "incr_with_limit";
+/// 점수 ARGV[1] 이하 멤버를 최대 ARGV[2]개 꺼내 [member, score, ...] 반환
+pub const POP_BATCH_FROM_ZSET: &str = "pop_batch_from_zset";
+
+const LIBRARY: &[(&str, &str)] = &[
+    (
+        CHECK_AND_DECREMENT,
+        r#"
+local current = tonumber(redis.call('GET', KEYS[1]) or '0')
+local amount = tonumber(ARGV[1])
+if current < amount then
+    return false
+end
+return redis.call('DECRBY', KEYS[1], amount)
+"#,
+    ),
+    (
+        INCR_WITH_LIMIT,
+        r#"
+local current = tonumber(redis.call('GET', KEYS[1]) or '0')
+if current >= tonumber(ARGV[1]) then
+    return false
+end
+local value = redis.call('INCR', KEYS[1])
+if value == 1 and tonumber(ARGV[2]) > 0 then
+    redis.call('EXPIRE', KEYS[1], ARGV[2])
+end
+return value
+"#,
+    ),
+    (
+        POP_BATCH_FROM_ZSET,
+        r#"
+local items = redis.call('ZRANGEBYSCORE', KEYS[1], '-inf', ARGV[1], 'WITHSCORES', 'LIMIT', 0, ARGV[2])
+for i = 1, #items, 2 do
+    redis.call('ZREM', KEYS[1], items[i])
+end
+return items
+"#,
+    ),
+];
+
+/// 등록된 스크립트
+#[derive(Debug, Clone)]
+struct RegisteredScript {
+    source: String,
+    sha: String,
+}
+
+/// 이름 기반 Lua 스크립트 레지스트리
+pub struct ScriptManager {
+    redis_config: RedisConfig,
+    scripts: RwLock<HashMap<String, RegisteredScript>>,
+}
+
+impl ScriptManager {
+    /// 기본 원자 연산 라이브러리가 등록된 관리자 생성
+    pub fn new(redis_config: RedisConfig) -> Self {
+        let manager = Self {
+            redis_config,
+            scripts: RwLock::new(HashMap::new()),
+        };
+        for (name, source) in LIBRARY {
+            manager.register(name, source);
+        }
+        manager
+    }
+
+    /// 스크립트 등록 (같은 이름이면 교체), SHA1 반환
+    pub fn register(&self, name: &str, source: &str) -> String {
+        let sha = Script::new(source).get_hash().to_string();
+        if let Ok(mut scripts) = self.scripts.write() {
+            scripts.insert(name.to_string(), RegisteredScript { source: source.to_string(), sha: sha.clone() });
+        }
+        sha
+    }
+
+    /// 등록된 스크립트의 SHA1
+    pub fn sha(&self, name: &str) -> Option<String> {
+        self.scripts.read().ok()?.get(name).map(|script| script.sha.clone())
+    }
+
+    /// 등록된 모든 스크립트를 `SCRIPT LOAD`로 미리 적재 (적재한 개수 반환)
+    pub async fn load_all(&self) -> RedisResult<usize> {
+        let scripts: Vec<RegisteredScript> = self.scripts.read()
+            .map(|scripts| scripts.values().cloned().collect())
+            .unwrap_or_default();
+
+        let mut conn = self.redis_config.get_connection();
+        for script in &scripts {
+            let sha: String = redis::cmd("SCRIPT").arg("LOAD").arg(&script.source).query_async(&mut conn).await?;
+            if sha != script.sha {
+                warn!("스크립트 SHA 불일치: expected={}, actual={}", script.sha, sha);
+            }
+        }
+        Ok(scripts.len())
+    }
+
+    /// 스크립트 호출 준비
+    pub fn call<'a>(&'a self, name: &'a str) -> ScriptCall<'a> {
+        ScriptCall { manager: self, name, keys: Vec::new(), args: Vec::new() }
+    }
+
+    /// `key`의 값이 `amount` 이상이면 차감 후 남은 값, 부족하면 None
+    pub async fn check_and_decrement(&self, key: &str, amount: i64) -> RedisResult<Option<i64>> {
+        self.call(CHECK_AND_DECREMENT).key(key).arg(amount).invoke().await
+    }
+
+    /// `key`가 `limit` 미만이면 1 증가 후 값, 한도에 도달했으면 None
+    ///
+    /// 첫 증가 시 `ttl_secs` TTL을 설정합니다 (0이면 TTL 없음).
+    pub async fn incr_with_limit(&self, key: &str, limit: i64, ttl_secs: u64) -> RedisResult<Option<i64>> {
+        self.call(INCR_WITH_LIMIT).key(key).arg(limit).arg(ttl_secs).invoke().await
+    }
+
+    /// 점수가 `max_score` 이하인 멤버를 낮은 점수부터 최대 `count`개 꺼내기
+    pub async fn pop_batch_from_zset(&self, key: &str, max_score: f64, count: usize) -> RedisResult<Vec<(String, f64)>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        self.call(POP_BATCH_FROM_ZSET).key(key).arg(max_score).arg(count).invoke().await
+    }
+
+    fn script(&self, name: &str) -> RedisResult<RegisteredScript> {
+        self.scripts.read().ok()
+            .and_then(|scripts| scripts.get(name).cloned())
+            .ok_or_else(|| RedisError::from((ErrorKind::ClientError, "unknown script", name.to_string())))
+    }
+}
+
+/// 스크립트 호출 (키/인자 빌더)
+pub struct ScriptCall<'a> {
+    manager: &'a ScriptManager,
+    name: &'a str,
+    keys: Vec<Vec<u8>>,
+    args: Vec<Vec<u8>>,
+}
+
+impl ScriptCall<'_> {
+    pub fn key<K: ToRedisArgs>(mut self, key: K) -> Self {
+        self.keys.extend(key.to_redis_args());
+        self
+    }
+
+    pub fn arg<A: ToRedisArgs>(mut self, arg: A) -> Self {
+        self.args.extend(arg.to_redis_args());
+        self
+    }
+
+    /// `EVALSHA` 실행 (`NOSCRIPT`이면 `SCRIPT LOAD` 후 한 번 재시도)
+    pub async fn invoke<T: FromRedisValue>(self) -> RedisResult<T> {
+        let script = self.manager.script(self.name)?;
+        let mut conn = self.manager.redis_config.get_connection();
+
+        match evalsha(&script.sha, &self.keys, &self.args).query_async(&mut conn).await {
+            Err(e) if e.kind() == ErrorKind::NoScriptError => {
+                debug!("스크립트 캐시 없음, 다시 로드: {}", self.name);
+                redis::cmd("SCRIPT").arg("LOAD").arg(&script.source).query_async::<_, String>(&mut conn).await?;
+                evalsha(&script.sha, &self.keys, &self.args).query_async(&mut conn).await
+            }
+            result => result,
+        }
+    }
+}
+
+/// `EVALSHA sha numkeys key... arg...`
+fn evalsha(sha: &str, keys: &[Vec<u8>], args: &[Vec<u8>]) -> redis::Cmd {
+    let mut cmd = redis::cmd("EVALSHA");
+    cmd.arg(sha).arg(keys.len()).arg(keys).arg(args);
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_library_hashes_are_stable() {
+        // SHA1("return 1")
+        assert_eq!(Script::new("return 1").get_hash(), "e0e1f9fabfc9d4800c877a703b823ac0578ff8db");
+        for (name, source) in LIBRARY {
+            assert_eq!(Script::new(source).get_hash().len(), 40, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_evalsha_layout() {
+        let keys = "quota:1".to_redis_args();
+        let args = [5i64.to_redis_args(), 60u64.to_redis_args()].concat();
+        let packed = evalsha("abc", &keys, &args).get_packed_command();
+        let expected = redis::cmd("EVALSHA").arg("abc").arg(1).arg("quota:1").arg(5).arg(60).get_packed_command();
+        assert_eq!(packed, expected);
+    }
+}
@@ -0,0 +1,261 @@
+//! 서버 라우팅 저장소
+//!
+//! 새 매치/연결이 접속할 서버 주소를 프로토콜별 Redis 키(`routing:{protocol}:active`)에 기록합니다.
+//! 블루/그린 교체 중에는 새 인스턴스가 준비되는 즉시 이 키가 바뀌므로,
+//! 매치를 배정하는 쪽은 주소를 캐시하지 말고 배정할 때마다 조회해야 합니다.
+//!
+//! 게임센터는 서버 하트비트로 인스턴스별 부하를 계산한 접속 라우팅 테이블(`routing:table`)도 기록하며,
+//! 로그인 응답은 이 테이블에서 지역/프로토콜별로 가장 여유 있는 인스턴스를 골라 안내합니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::service::redis::server_stats::ServerHeartbeat;
+use crate::tool::error::AppError;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// 라우팅 키 접두사
+pub const ROUTING_PREFIX: &str = "routing";
+
+/// 접속 라우팅 테이블 키
+pub const ROUTING_TABLE_KEY: &str = "routing:table";
+
+/// 라우팅 테이블 기본 TTL (초) - 게임센터가 멈추면 오래된 테이블로 안내하지 않도록 만료
+pub const DEFAULT_TABLE_TTL_SECS: u64 = 30;
+
+/// 최대 접속자 수를 모르는 인스턴스의 부하 계산용 가정치
+const UNKNOWN_CAPACITY: u64 = 1000;
+
+/// 이 부하 이상인 인스턴스에는 새 접속을 배정하지 않음
+const FULL_LOAD: f64 = 0.95;
+
+/// 새 매치가 접속할 대상 서버
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoutingTarget {
+    /// 프로토콜 ("rudp" 등)
+    pub protocol: String,
+    /// 배포 슬롯 ("blue", "green")
+    pub slot: String,
+    /// 클라이언트가 접속할 주소 (host:port)
+    pub address: String,
+    /// 변경 시각 (Unix 초)
+    pub updated_at: i64,
+}
+
+impl RoutingTarget {
+    pub fn new(protocol: impl Into<String>, slot: impl Into<String>, address: impl Into<String>) -> Self {
+        Self {
+            protocol: protocol.into(),
+            slot: slot.into(),
+            address: address.into(),
+            updated_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// Redis 키 (`routing:{protocol}:active`)
+    pub fn key(protocol: &str) -> String {
+        format!("{ROUTING_PREFIX}:{protocol}:active")
+    }
+}
+
+/// 클라이언트 접속 대상 인스턴스
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteEndpoint {
+    /// 프로토콜 ("tcp", "rudp" 등)
+    pub protocol: String,
+    /// 배포 지역
+    pub region: String,
+    /// 클라이언트가 접속할 주소 (host:port)
+    pub address: String,
+    /// 현재 동시 접속자 수
+    pub ccu: u64,
+    /// 최대 동시 접속자 수 (0이면 알 수 없음)
+    pub capacity: u64,
+    /// 부하 (0.0 ~ 1.0 이상)
+    pub load: f64,
+}
+
+impl RouteEndpoint {
+    /// 하트비트로 생성 (서버 식별자를 접속 주소로 사용)
+    pub fn from_heartbeat(heartbeat: &ServerHeartbeat) -> Self {
+        let capacity = if heartbeat.capacity > 0 { heartbeat.capacity } else { UNKNOWN_CAPACITY };
+        Self {
+            protocol: heartbeat.protocol.clone(),
+            region: heartbeat.region.clone(),
+            address: heartbeat.server_id.clone(),
+            ccu: heartbeat.ccu,
+            capacity: heartbeat.capacity,
+            load: heartbeat.ccu as f64 / capacity as f64,
+        }
+    }
+
+    /// 새 접속을 받을 수 없을 만큼 찼는지 여부
+    pub fn is_full(&self) -> bool {
+        self.load >= FULL_LOAD
+    }
+}
+
+/// 접속 라우팅 테이블
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoutingTable {
+    /// 프로토콜, 지역, 부하 순으로 정렬된 인스턴스
+    pub endpoints: Vec<RouteEndpoint>,
+    /// 생성 시각 (Unix 초)
+    pub updated_at: i64,
+}
+
+impl RoutingTable {
+    /// 살아 있는 하트비트로 테이블 생성
+    pub fn from_heartbeats(heartbeats: &[ServerHeartbeat]) -> Self {
+        let mut endpoints: Vec<RouteEndpoint> = heartbeats.iter().map(RouteEndpoint::from_heartbeat).collect();
+        endpoints.sort_by(|a, b| {
+            (&a.protocol, &a.region)
+                .cmp(&(&b.protocol, &b.region))
+                .then(a.load.total_cmp(&b.load))
+                .then_with(|| a.address.cmp(&b.address))
+        });
+        Self {
+            endpoints,
+            updated_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// 블루/그린 교체 중인 프로토콜은 새 매치 대상 인스턴스만 남김
+    ///
+    /// 대상 주소와 일치하는 인스턴스가 없으면(주소 표기가 다른 경우 등) 그대로 둡니다.
+    pub fn retain_active(&mut self, target: &RoutingTarget) {
+        let has_target = self.endpoints.iter()
+            .any(|e| e.protocol == target.protocol && e.address == target.address);
+        if has_target {
+            self.endpoints.retain(|e| e.protocol != target.protocol || e.address == target.address);
+        }
+    }
+
+    /// 테이블에 있는 프로토콜 목록
+    pub fn protocols(&self) -> Vec<&str> {
+        let protocols: BTreeSet<&str> = self.endpoints.iter().map(|e| e.protocol.as_str()).collect();
+        protocols.into_iter().collect()
+    }
+
+    /// 프로토콜별 가장 여유 있는 인스턴스
+    ///
+    /// 요청 지역에 여유 있는 인스턴스가 있으면 그중에서, 없으면 전체 지역에서 고릅니다.
+    /// 모든 인스턴스가 가득 찼으면 None입니다.
+    pub fn best(&self, protocol: &str, region: Option<&str>) -> Option<&RouteEndpoint> {
+        let available = || self.endpoints.iter().filter(|e| e.protocol == protocol && !e.is_full());
+        let least_loaded = |a: &&RouteEndpoint, b: &&RouteEndpoint| a.load.total_cmp(&b.load);
+
+        region
+            .and_then(|region| available().filter(|e| e.region == region).min_by(least_loaded))
+            .or_else(|| available().min_by(least_loaded))
+    }
+
+    /// 모든 프로토콜의 가장 여유 있는 인스턴스
+    pub fn best_per_protocol(&self, region: Option<&str>) -> Vec<&RouteEndpoint> {
+        self.protocols().into_iter().filter_map(|protocol| self.best(protocol, region)).collect()
+    }
+}
+
+/// 서버 라우팅 저장소
+#[derive(Debug, Clone)]
+pub struct ServerRoutingStore {
+    redis_config: RedisConfig,
+}
+
+impl ServerRoutingStore {
+    pub fn new(redis_config: RedisConfig) -> Self {
+        Self { redis_config }
+    }
+
+    /// 새 매치 대상 서버 변경
+    pub async fn set_active(&self, target: &RoutingTarget) -> Result<(), AppError> {
+        let payload = serde_json::to_string(target)
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        conn.set(RoutingTarget::key(&target.protocol), payload).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 새 매치 대상 서버 조회 (기록된 대상이 없으면 None)
+    pub async fn get_active(&self, protocol: &str) -> Result<Option<RoutingTarget>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        let raw: Option<String> = conn.get(RoutingTarget::key(protocol)).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        raw.map(|raw| serde_json::from_str(&raw).map_err(|e| AppError::InvalidFormat(e.to_string())))
+            .transpose()
+    }
+
+    /// 접속 라우팅 테이블 기록 (TTL 갱신)
+    pub async fn publish_table(&self, table: &RoutingTable, ttl_secs: u64) -> Result<(), AppError> {
+        let payload = serde_json::to_string(table)
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        conn.set_ex(ROUTING_TABLE_KEY, payload, ttl_secs).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 접속 라우팅 테이블 조회 (기록된 테이블이 없거나 만료되었으면 None)
+    pub async fn get_table(&self) -> Result<Option<RoutingTable>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        let raw: Option<String> = conn.get(ROUTING_TABLE_KEY).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        raw.map(|raw| serde_json::from_str(&raw).map_err(|e| AppError::InvalidFormat(e.to_string())))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routing_key_and_payload() {
+        assert_eq!(RoutingTarget::key("rudp"), "routing:rudp:active");
+
+        let target = RoutingTarget::new("rudp", "green", "10.0.0.5:5001");
+        let json = serde_json::to_string(&target).unwrap();
+        let decoded: RoutingTarget = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, target);
+    }
+
+    fn heartbeat(server_id: &str, protocol: &str, region: &str, ccu: u64, capacity: u64) -> ServerHeartbeat {
+        ServerHeartbeat::new(server_id, protocol, region, ccu, 0).with_capacity(capacity)
+    }
+
+    #[test]
+    fn test_best_endpoint_prefers_region_and_low_load() {
+        let table = RoutingTable::from_heartbeats(&[
+            heartbeat("kr-1:4000", "tcp", "kr", 80, 100),
+            heartbeat("kr-2:4000", "tcp", "kr", 30, 100),
+            heartbeat("jp-1:4000", "tcp", "jp", 1, 100),
+            heartbeat("kr-1:5000", "rudp", "kr", 99, 100),
+            heartbeat("jp-1:5000", "rudp", "jp", 500, 0),
+        ]);
+
+        assert_eq!(table.protocols(), vec!["rudp", "tcp"]);
+        assert_eq!(table.best("tcp", Some("kr")).unwrap().address, "kr-2:4000");
+        assert_eq!(table.best("tcp", None).unwrap().address, "jp-1:4000");
+        // 지역 인스턴스가 가득 찼으면 다른 지역으로
+        assert_eq!(table.best("rudp", Some("kr")).unwrap().address, "jp-1:5000");
+        assert!(table.best("quic", Some("kr")).is_none());
+
+        let best: Vec<&str> = table.best_per_protocol(Some("kr")).iter().map(|e| e.address.as_str()).collect();
+        assert_eq!(best, vec!["jp-1:5000", "kr-2:4000"]);
+    }
+
+    #[test]
+    fn test_retain_active_drops_draining_instance() {
+        let mut table = RoutingTable::from_heartbeats(&[
+            heartbeat("0.0.0.0:5000", "rudp", "kr", 10, 100),
+            heartbeat("0.0.0.0:5001", "rudp", "kr", 0, 100),
+            heartbeat("0.0.0.0:4000", "tcp", "kr", 10, 100),
+        ]);
+
+        table.retain_active(&RoutingTarget::new("rudp", "green", "10.0.0.9:5001"));
+        assert_eq!(table.endpoints.len(), 3);
+
+        table.retain_active(&RoutingTarget::new("rudp", "blue", "0.0.0.0:5000"));
+        let addresses: Vec<&str> = table.endpoints.iter().map(|e| e.address.as_str()).collect();
+        assert_eq!(addresses, vec!["0.0.0.0:5000", "0.0.0.0:4000"]);
+    }
+}
@@ -0,0 +1,253 @@
+//! 서버 상태 하트비트 저장소
+//!
+//! 각 게임 서버(TCP/RUDP)가 현재 동시 접속자 수(CCU)와 방 수를 TTL 키로 주기적으로 기록하고,
+//! 운영 도구는 살아 있는 키만 모아 프로토콜/지역별로 집계합니다.
+//! 하트비트가 끊긴 서버는 TTL 만료와 함께 집계에서 자동으로 빠집니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::tool::error::AppError;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::warn;
+
+/// 하트비트 키 접두사
+pub const SERVER_STATS_PREFIX: &str = "server_stats";
+
+/// 하트비트 키 기본 TTL (초) - 발행 주기의 약 3배
+pub const DEFAULT_STATS_TTL_SECS: u64 = 30;
+
+/// SCAN 한 번에 조회할 키 수
+const SCAN_COUNT: usize = 200;
+
+/// 서버 하트비트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServerHeartbeat {
+    /// 서버 식별자 (바인딩 주소 등)
+    pub server_id: String,
+    /// 프로토콜 ("tcp", "rudp")
+    pub protocol: String,
+    /// 배포 지역
+    pub region: String,
+    /// 현재 동시 접속자 수
+    pub ccu: u64,
+    /// 현재 방 수
+    pub rooms: u64,
+    /// 최대 동시 접속자 수 (0이면 알 수 없음)
+    #[serde(default)]
+    pub capacity: u64,
+    /// 기록 시각 (Unix 초)
+    pub updated_at: i64,
+}
+
+impl ServerHeartbeat {
+    pub fn new(
+        server_id: impl Into<String>,
+        protocol: impl Into<String>,
+        region: impl Into<String>,
+        ccu: u64,
+        rooms: u64,
+    ) -> Self {
+        Self {
+            server_id: server_id.into(),
+            protocol: protocol.into(),
+            region: region.into(),
+            ccu,
+            rooms,
+            capacity: 0,
+            updated_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// 최대 동시 접속자 수 설정 (접속 라우팅의 부하 계산용)
+    pub fn with_capacity(mut self, capacity: u64) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Redis 키 (`server_stats:{protocol}:{server_id}`)
+    pub fn key(&self) -> String {
+        format!("{SERVER_STATS_PREFIX}:{}:{}", self.protocol, self.server_id)
+    }
+}
+
+/// 프로토콜/지역별 집계
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegionStats {
+    pub protocol: String,
+    pub region: String,
+    pub ccu: u64,
+    pub rooms: u64,
+    pub servers: u32,
+}
+
+/// 전체 클러스터 집계
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClusterStats {
+    pub total_ccu: u64,
+    pub total_rooms: u64,
+    pub server_count: u32,
+    /// 프로토콜, 지역 순으로 정렬된 세부 집계
+    pub breakdown: Vec<RegionStats>,
+}
+
+impl ClusterStats {
+    /// 하트비트 목록을 집계합니다.
+    pub fn aggregate(heartbeats: &[ServerHeartbeat]) -> Self {
+        let mut groups: BTreeMap<(&str, &str), RegionStats> = BTreeMap::new();
+
+        for hb in heartbeats {
+            let group = groups
+                .entry((hb.protocol.as_str(), hb.region.as_str()))
+                .or_insert_with(|| RegionStats {
+                    protocol: hb.protocol.clone(),
+                    region: hb.region.clone(),
+                    ..Default::default()
+                });
+            group.ccu += hb.ccu;
+            group.rooms += hb.rooms;
+            group.servers += 1;
+        }
+
+        Self {
+            total_ccu: heartbeats.iter().map(|hb| hb.ccu).sum(),
+            total_rooms: heartbeats.iter().map(|hb| hb.rooms).sum(),
+            server_count: heartbeats.len() as u32,
+            breakdown: groups.into_values().collect(),
+        }
+    }
+}
+
+/// 서버 하트비트 저장소
+#[derive(Debug, Clone)]
+pub struct ServerStatsStore {
+    redis_config: RedisConfig,
+    ttl_secs: u64,
+}
+
+impl ServerStatsStore {
+    pub fn new(redis_config: RedisConfig) -> Self {
+        Self {
+            redis_config,
+            ttl_secs: DEFAULT_STATS_TTL_SECS,
+        }
+    }
+
+    /// 하트비트 TTL 설정
+    pub fn with_ttl(mut self, ttl_secs: u64) -> Self {
+        self.ttl_secs = ttl_secs;
+        self
+    }
+
+    /// 하트비트 기록 (TTL 갱신)
+    pub async fn publish(&self, heartbeat: &ServerHeartbeat) -> Result<(), AppError> {
+        let payload = serde_json::to_string(heartbeat)
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        conn.set_ex(heartbeat.key(), payload, self.ttl_secs).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 하트비트 삭제 (정상 종료 시 TTL 만료를 기다리지 않고 접속 라우팅에서 제외)
+    pub async fn remove(&self, heartbeat: &ServerHeartbeat) -> Result<(), AppError> {
+        let mut conn = self.redis_config.get_connection();
+        conn.del::<_, ()>(heartbeat.key()).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 살아 있는 하트비트 조회
+    ///
+    /// `protocol`을 지정하면 해당 프로토콜만 조회합니다.
+    /// 역직렬화에 실패한 값과 조회 사이에 만료된 키는 건너뜁니다.
+    pub async fn fetch_all(&self, protocol: Option<&str>) -> Result<Vec<ServerHeartbeat>, AppError> {
+        let pattern = match protocol {
+            Some(protocol) => format!("{SERVER_STATS_PREFIX}:{protocol}:*"),
+            None => format!("{SERVER_STATS_PREFIX}:*"),
+        };
+        let mut conn = self.redis_config.get_connection();
+
+        let mut keys: Vec<String> = Vec::new();
+        let mut cursor: u64 = 0;
+        loop {
+            let (next, batch): (u64, Vec<String>) = redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(SCAN_COUNT)
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+            keys.extend(batch);
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let values: Vec<Option<String>> = redis::cmd("MGET")
+            .arg(&keys)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+
+        Ok(keys.iter()
+            .zip(values)
+            .filter_map(|(key, value)| {
+                let value = value?;
+                serde_json::from_str(&value)
+                    .map_err(|e| warn!("서버 하트비트 역직렬화 실패 ({}): {}", key, e))
+                    .ok()
+            })
+            .collect())
+    }
+
+    /// 클러스터 집계 조회
+    pub async fn cluster_stats(&self, protocol: Option<&str>) -> Result<ClusterStats, AppError> {
+        Ok(ClusterStats::aggregate(&self.fetch_all(protocol).await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heartbeat_key() {
+        let hb = ServerHeartbeat::new("10.0.0.1:4000", "tcp", "kr", 10, 2);
+        assert_eq!(hb.key(), "server_stats:tcp:10.0.0.1:4000");
+    }
+
+    #[test]
+    fn test_aggregate_by_protocol_and_region() {
+        let heartbeats = vec![
+            ServerHeartbeat::new("a", "tcp", "kr", 100, 10),
+            ServerHeartbeat::new("b", "tcp", "kr", 50, 5),
+            ServerHeartbeat::new("c", "rudp", "kr", 30, 3),
+            ServerHeartbeat::new("d", "tcp", "jp", 20, 1),
+        ];
+
+        let stats = ClusterStats::aggregate(&heartbeats);
+        assert_eq!(stats.total_ccu, 200);
+        assert_eq!(stats.total_rooms, 19);
+        assert_eq!(stats.server_count, 4);
+
+        let groups: Vec<(&str, &str, u64, u32)> = stats.breakdown.iter()
+            .map(|g| (g.protocol.as_str(), g.region.as_str(), g.ccu, g.servers))
+            .collect();
+        assert_eq!(groups, vec![
+            ("rudp", "kr", 30, 1),
+            ("tcp", "jp", 20, 1),
+            ("tcp", "kr", 150, 2),
+        ]);
+    }
+
+    #[test]
+    fn test_aggregate_empty() {
+        assert_eq!(ClusterStats::aggregate(&[]), ClusterStats::default());
+    }
+}
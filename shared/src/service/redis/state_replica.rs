@@ -0,0 +1,142 @@
+//! 게임 상태 복제 스트림 (장애 조치용 대기 서버)
+//!
+//! 주 서버는 일정 주기마다 압축한 상태 변경분을 `replica:{stream}` 채널로 발행하고,
+//! 전체 상태(키프레임)는 `replica:{stream}:keyframe` 키에 남깁니다. 대기 서버는 구독을 연 뒤
+//! 키프레임을 읽고 변경분을 이어 적용합니다.
+//!
+//! 주 서버는 발행할 때마다 임대 키(`replica:{stream}:lease`)를 갱신합니다. 임대가 만료되면
+//! 대기 서버가 임대를 차지하고 상태를 넘겨받습니다. 내용 형식은 게임 서버가 정하며
+//! 이 모듈은 전달만 담당합니다.
+
+use crate::config::redis_config::RedisConfig;
+use crate::service::redis::event_bus::EventBus;
+use crate::tool::error::AppError;
+use redis::AsyncCommands;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// 복제 키/채널 접두사
+pub const REPLICA_PREFIX: &str = "replica";
+
+/// 키프레임 보관 시간 (초) - 주 서버가 오래 멈춘 뒤의 낡은 상태로 복원하지 않도록 만료
+pub const DEFAULT_KEYFRAME_TTL_SECS: u64 = 300;
+
+/// 임대 획득/연장 스크립트 (비어 있거나 같은 보유자일 때만 설정)
+///
+/// 개발 샌드박스 Redis는 이 원문의 SHA1로 같은 동작의 내장 구현을 찾으므로, 수정 시 함께 맞춰야 합니다.
+pub const CLAIM_LEASE_SCRIPT: &str = r#"
+local holder = redis.call('GET', KEYS[1])
+if holder == false or holder == ARGV[1] then
+    redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+    return 1
+end
+return 0
+"#;
+
+/// 게임 상태 복제 스트림
+#[derive(Debug, Clone)]
+pub struct ReplicaStream {
+    redis_config: RedisConfig,
+    stream: String,
+    keyframe_ttl_secs: u64,
+}
+
+impl ReplicaStream {
+    /// # Arguments
+    /// * `stream` - 주 서버와 대기 서버가 함께 쓰는 스트림 이름
+    pub fn new(redis_config: RedisConfig, stream: impl Into<String>) -> Self {
+        Self {
+            redis_config,
+            stream: stream.into(),
+            keyframe_ttl_secs: DEFAULT_KEYFRAME_TTL_SECS,
+        }
+    }
+
+    /// 키프레임 보관 시간 설정
+    pub fn with_keyframe_ttl(mut self, ttl_secs: u64) -> Self {
+        self.keyframe_ttl_secs = ttl_secs;
+        self
+    }
+
+    /// 변경분 채널 (`replica:{stream}`)
+    pub fn channel(&self) -> String {
+        format!("{REPLICA_PREFIX}:{}", self.stream)
+    }
+
+    /// 키프레임 키 (`replica:{stream}:keyframe`)
+    pub fn keyframe_key(&self) -> String {
+        format!("{REPLICA_PREFIX}:{}:keyframe", self.stream)
+    }
+
+    /// 임대 키 (`replica:{stream}:lease`)
+    pub fn lease_key(&self) -> String {
+        format!("{REPLICA_PREFIX}:{}:lease", self.stream)
+    }
+
+    /// 변경분 발행 (받은 구독자 수 반환)
+    pub async fn publish<T: Serialize>(&self, batch: &T) -> Result<usize, AppError> {
+        EventBus::new(self.redis_config.clone()).publish(&self.channel(), batch).await
+    }
+
+    /// 변경분 구독
+    pub async fn subscribe<T>(&self) -> Result<mpsc::Receiver<T>, AppError>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        EventBus::new(self.redis_config.clone()).subscribe(&self.channel()).await
+    }
+
+    /// 키프레임 저장 (TTL 갱신)
+    pub async fn store_keyframe<T: Serialize>(&self, keyframe: &T) -> Result<(), AppError> {
+        let payload = serde_json::to_string(keyframe)
+            .map_err(|e| AppError::InvalidFormat(e.to_string()))?;
+        let mut conn = self.redis_config.get_connection();
+        conn.set_ex(self.keyframe_key(), payload, self.keyframe_ttl_secs).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 마지막 키프레임 조회 (없거나 만료되었으면 None)
+    pub async fn load_keyframe<T: DeserializeOwned>(&self) -> Result<Option<T>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        let payload: Option<String> = conn.get(self.keyframe_key()).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        payload
+            .map(|payload| serde_json::from_str(&payload).map_err(|e| AppError::InvalidFormat(e.to_string())))
+            .transpose()
+    }
+
+    /// 임대 획득 또는 연장
+    ///
+    /// 임대가 비어 있거나 `holder`가 이미 보유 중이면 `ttl`로 설정하고 true를 반환합니다.
+    /// 다른 서버가 보유 중이면 false입니다.
+    pub async fn claim_lease(&self, holder: &str, ttl: Duration) -> Result<bool, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        let claimed: i32 = redis::Script::new(CLAIM_LEASE_SCRIPT)
+            .key(self.lease_key())
+            .arg(holder)
+            .arg(ttl.as_millis().max(1) as u64)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))?;
+        Ok(claimed == 1)
+    }
+
+    /// 현재 임대 보유자 (만료되었으면 None)
+    pub async fn lease_holder(&self) -> Result<Option<String>, AppError> {
+        let mut conn = self.redis_config.get_connection();
+        conn.get(self.lease_key()).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+
+    /// 임대 반납 (정상 종료 시 대기 서버가 TTL 만료를 기다리지 않도록)
+    pub async fn release_lease(&self, holder: &str) -> Result<(), AppError> {
+        if self.lease_holder().await?.as_deref() != Some(holder) {
+            return Ok(());
+        }
+        let mut conn = self.redis_config.get_connection();
+        conn.del::<_, ()>(self.lease_key()).await
+            .map_err(|e| AppError::RedisConnection(e.to_string()))
+    }
+}
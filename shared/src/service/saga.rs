@@ -0,0 +1,399 @@
+//! 파일 기반 사가 코디네이터
+//!
+//! DB, Redis, 외부 결제 제공자처럼 한 트랜잭션으로 묶을 수 없는 작업을 순서가 있는 단계로
+//! 나누어 실행하고, 중간 단계가 실패하면 이미 끝난 단계를 역순으로 보상(compensate)합니다.
+//!
+//! - 사가 실행 하나가 `<dir>/<사가 이름>/<id>.json` 파일 하나이며, 단계가 끝날 때마다 진행 상황과
+//!   컨텍스트를 기록합니다. 프로세스가 중간에 죽어도 [`Saga::resume_pending`]으로 이어서 진행합니다.
+//! - 끝난 실행(완료/보상 완료)은 `<dir>/<사가 이름>/done/`으로 옮기며, 완료된 ID로 다시 실행하면
+//!   단계를 건너뛰고 기록된 결과를 돌려줍니다.
+//! - 실패한 단계는 스스로 부분 변경을 남기지 않아야 합니다 (보상은 완료된 단계에만 실행).
+//! - 재개 시 마지막 단계나 보상이 다시 실행될 수 있으므로(at-least-once) 단계와 보상은 멱등이어야 합니다.
+//! - 보상이 실패하면 `Compensating` 상태로 남겨 두고 다음 재개 때 다시 시도합니다.
+
+use crate::tool::error::AppError;
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+
+/// 사가 단계
+///
+/// 단계는 컨텍스트를 고칠 수 있으며, 고친 내용은 단계가 끝난 뒤 함께 기록되어
+/// 이후 단계와 보상에서 사용됩니다 (예: 실제로 옮긴 금액).
+pub trait SagaStep<C>: Send + Sync {
+    /// 단계 이름 (기록된 실행과 정의가 같은지 확인하는 데 사용)
+    fn name(&self) -> &str;
+
+    /// 단계 실행
+    fn execute<'a>(&'a self, ctx: &'a mut C) -> BoxFuture<'a, Result<(), AppError>>;
+
+    /// 실행 취소 (보상)
+    fn compensate<'a>(&'a self, ctx: &'a mut C) -> BoxFuture<'a, Result<(), AppError>>;
+}
+
+/// 사가 실행 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SagaStatus {
+    /// 단계 진행 중
+    Running,
+    /// 실패 후 보상 진행 중
+    Compensating,
+    /// 모든 단계 완료
+    Completed,
+    /// 모든 보상 완료
+    Compensated,
+}
+
+/// 기록된 사가 실행
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SagaRecord<C> {
+    pub id: String,
+    pub saga: String,
+    /// 실행 시작 시점의 단계 이름 목록
+    pub steps: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub status: SagaStatus,
+    /// 완료된 단계 수 (보상 중에는 아직 보상하지 않은 단계 수)
+    pub completed: usize,
+    /// 보상을 일으킨 단계 실패 사유
+    pub failure: Option<String>,
+    /// 마지막 보상 실패 사유
+    pub last_error: Option<String>,
+    pub context: C,
+}
+
+/// 사가 실행 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum SagaOutcome<C> {
+    /// 모든 단계 완료
+    Completed(C),
+    /// 단계가 실패하여 완료된 단계를 모두 되돌림
+    Compensated { context: C, failure: String },
+    /// 보상이 실패하여 남아 있음 (다음 재개 때 재시도)
+    Pending { failure: String, error: String },
+}
+
+/// 파일 기반 사가 코디네이터
+pub struct Saga<C> {
+    name: String,
+    dir: PathBuf,
+    steps: Vec<Box<dyn SagaStep<C>>>,
+}
+
+impl<C: Serialize + DeserializeOwned + Send> Saga<C> {
+    /// 사가 디렉토리 열기 (`<dir>/<name>/`, 없으면 생성)
+    pub fn open(name: &str, dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().join(name);
+        std::fs::create_dir_all(dir.join("done"))?;
+        Ok(Self {
+            name: name.to_string(),
+            dir,
+            steps: Vec::new(),
+        })
+    }
+
+    /// 단계 추가 (추가한 순서대로 실행)
+    pub fn step(mut self, step: impl SagaStep<C> + 'static) -> Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 사가 실행
+    ///
+    /// 같은 ID의 진행 중 기록이 있으면 `ctx`는 무시하고 기록된 지점부터 이어서 진행합니다.
+    pub async fn run(&self, id: &str, ctx: C) -> Result<SagaOutcome<C>, AppError> {
+        if let Some(record) = self.load(&self.path(id)).map_err(io_error)? {
+            info!(saga = %self.name, id, completed = record.completed, "진행 중인 사가 재개");
+            return self.drive(record).await;
+        }
+        if let Some(record) = self.load(&self.dir.join("done").join(format!("{id}.json"))).map_err(io_error)? {
+            if record.status == SagaStatus::Completed {
+                return Ok(SagaOutcome::Completed(record.context));
+            }
+        }
+
+        let now = Utc::now();
+        let record = SagaRecord {
+            id: id.to_string(),
+            saga: self.name.clone(),
+            steps: self.steps.iter().map(|step| step.name().to_string()).collect(),
+            created_at: now,
+            updated_at: now,
+            status: SagaStatus::Running,
+            completed: 0,
+            failure: None,
+            last_error: None,
+            context: ctx,
+        };
+        self.write(&record).map_err(io_error)?;
+        self.drive(record).await
+    }
+
+    /// 남아 있는 실행을 모두 재개 (서버 시작 시 호출)
+    pub async fn resume_pending(&self) -> Result<Vec<(String, SagaOutcome<C>)>, AppError> {
+        let mut outcomes = Vec::new();
+        for record in self.pending().map_err(io_error)? {
+            let id = record.id.clone();
+            outcomes.push((id, self.drive(record).await?));
+        }
+        Ok(outcomes)
+    }
+
+    /// 끝나지 않은 실행 (오래된 순)
+    pub fn pending(&self) -> io::Result<Vec<SagaRecord<C>>> {
+        let mut records = Vec::new();
+        for file in std::fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            match self.load(&path) {
+                Ok(Some(record)) => records.push(record),
+                Ok(None) => {}
+                Err(e) => warn!(saga = %self.name, path = %path.display(), error = %e, "사가 기록 읽기 실패"),
+            }
+        }
+        records.sort_by_key(|record| record.created_at);
+        Ok(records)
+    }
+
+    async fn drive(&self, mut record: SagaRecord<C>) -> Result<SagaOutcome<C>, AppError> {
+        let names: Vec<&str> = self.steps.iter().map(|step| step.name()).collect();
+        if record.steps != names {
+            return Err(AppError::InternalError(format!(
+                "사가 '{}' 정의가 기록({})과 다름: {:?} != {:?}",
+                self.name, record.id, record.steps, names
+            )));
+        }
+
+        while record.status == SagaStatus::Running && record.completed < self.steps.len() {
+            let step = &self.steps[record.completed];
+            match step.execute(&mut record.context).await {
+                Ok(()) => record.completed += 1,
+                Err(e) => {
+                    warn!(saga = %self.name, id = %record.id, step = step.name(), error = %e, "사가 단계 실패, 보상 시작");
+                    record.status = SagaStatus::Compensating;
+                    record.failure = Some(format!("{}: {e}", step.name()));
+                }
+            }
+            self.checkpoint(&mut record)?;
+        }
+
+        while record.status == SagaStatus::Compensating && record.completed > 0 {
+            let step = &self.steps[record.completed - 1];
+            match step.compensate(&mut record.context).await {
+                Ok(()) => {
+                    record.completed -= 1;
+                    record.last_error = None;
+                    self.checkpoint(&mut record)?;
+                }
+                Err(e) => {
+                    error!(saga = %self.name, id = %record.id, step = step.name(), error = %e, "사가 보상 실패, 재개 때 재시도");
+                    record.last_error = Some(format!("{}: {e}", step.name()));
+                    self.checkpoint(&mut record)?;
+                    return Ok(SagaOutcome::Pending {
+                        failure: record.failure.unwrap_or_default(),
+                        error: record.last_error.unwrap_or_default(),
+                    });
+                }
+            }
+        }
+
+        record.status = match record.status {
+            SagaStatus::Running | SagaStatus::Completed => SagaStatus::Completed,
+            SagaStatus::Compensating | SagaStatus::Compensated => SagaStatus::Compensated,
+        };
+        self.checkpoint(&mut record)?;
+        std::fs::rename(self.path(&record.id), self.dir.join("done").join(format!("{}.json", record.id))).map_err(io_error)?;
+
+        Ok(match record.status {
+            SagaStatus::Completed => SagaOutcome::Completed(record.context),
+            _ => SagaOutcome::Compensated {
+                context: record.context,
+                failure: record.failure.unwrap_or_default(),
+            },
+        })
+    }
+
+    fn checkpoint(&self, record: &mut SagaRecord<C>) -> Result<(), AppError> {
+        record.updated_at = Utc::now();
+        self.write(record).map_err(io_error)
+    }
+
+    fn load(&self, path: &Path) -> io::Result<Option<SagaRecord<C>>> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        serde_json::from_slice(&bytes)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// 임시 파일에 쓴 뒤 rename하여 부분 기록을 남기지 않음
+    fn write(&self, record: &SagaRecord<C>) -> io::Result<()> {
+        let json = serde_json::to_vec_pretty(record).map_err(io::Error::other)?;
+        let tmp = self.dir.join(format!("{}.tmp", record.id));
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(tmp, self.path(&record.id))
+    }
+
+    fn path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{id}.json"))
+    }
+}
+
+fn io_error(error: io::Error) -> AppError {
+    AppError::InternalError(format!("사가 기록 실패: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    /// 컨텍스트에 실행/보상 기록을 남기는 테스트 단계
+    struct Record {
+        name: &'static str,
+        fail: Arc<AtomicBool>,
+        fail_compensate: Arc<AtomicBool>,
+    }
+
+    impl Record {
+        fn new(name: &'static str) -> Self {
+            Self {
+                name,
+                fail: Arc::new(AtomicBool::new(false)),
+                fail_compensate: Arc::new(AtomicBool::new(false)),
+            }
+        }
+    }
+
+    impl SagaStep<Vec<String>> for Record {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn execute<'a>(&'a self, ctx: &'a mut Vec<String>) -> BoxFuture<'a, Result<(), AppError>> {
+            Box::pin(async move {
+                if self.fail.load(Ordering::SeqCst) {
+                    return Err(AppError::ServiceUnavailable(self.name.to_string()));
+                }
+                ctx.push(format!("+{}", self.name));
+                Ok(())
+            })
+        }
+
+        fn compensate<'a>(&'a self, ctx: &'a mut Vec<String>) -> BoxFuture<'a, Result<(), AppError>> {
+            Box::pin(async move {
+                if self.fail_compensate.load(Ordering::SeqCst) {
+                    return Err(AppError::RedisConnection(self.name.to_string()));
+                }
+                ctx.push(format!("-{}", self.name));
+                Ok(())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_completes_and_is_idempotent() {
+        let dir = tempfile::tempdir().unwrap();
+        let saga = Saga::open("merge", dir.path()).unwrap().step(Record::new("a")).step(Record::new("b"));
+
+        let outcome = saga.run("s1", Vec::new()).await.unwrap();
+        assert_eq!(outcome, SagaOutcome::Completed(vec!["+a".to_string(), "+b".to_string()]));
+        assert!(dir.path().join("merge/done/s1.json").exists());
+        assert!(saga.pending().unwrap().is_empty());
+
+        // 같은 ID 재실행은 단계를 다시 돌리지 않음
+        let again = saga.run("s1", Vec::new()).await.unwrap();
+        assert_eq!(again, outcome);
+    }
+
+    #[tokio::test]
+    async fn test_failure_compensates_in_reverse() {
+        let dir = tempfile::tempdir().unwrap();
+        let c = Record::new("c");
+        c.fail.store(true, Ordering::SeqCst);
+        let saga = Saga::open("merge", dir.path())
+            .unwrap()
+            .step(Record::new("a"))
+            .step(Record::new("b"))
+            .step(c);
+
+        let SagaOutcome::Compensated { context, failure } = saga.run("s1", Vec::new()).await.unwrap() else {
+            panic!("expected compensation");
+        };
+        assert_eq!(context, vec!["+a", "+b", "-b", "-a"]);
+        assert!(failure.starts_with("c:"));
+        assert!(saga.pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_compensation_retries_on_resume() {
+        let dir = tempfile::tempdir().unwrap();
+        let (a, b) = (Record::new("a"), Record::new("b"));
+        b.fail.store(true, Ordering::SeqCst);
+        a.fail_compensate.store(true, Ordering::SeqCst);
+        let fail_compensate = a.fail_compensate.clone();
+        let saga = Saga::open("merge", dir.path()).unwrap().step(a).step(b);
+
+        let outcome = saga.run("s1", Vec::new()).await.unwrap();
+        assert!(matches!(outcome, SagaOutcome::Pending { .. }));
+        let pending = saga.pending().unwrap();
+        assert_eq!((pending[0].status, pending[0].completed), (SagaStatus::Compensating, 1));
+
+        fail_compensate.store(false, Ordering::SeqCst);
+        let resumed = saga.resume_pending().await.unwrap();
+        assert!(matches!(&resumed[0].1, SagaOutcome::Compensated { context, .. } if context == &["+a", "-a"]));
+    }
+
+    #[tokio::test]
+    async fn test_resume_after_crash() {
+        let dir = tempfile::tempdir().unwrap();
+        let saga = Saga::open("merge", dir.path())
+            .unwrap()
+            .step(Record::new("a"))
+            .step(Record::new("b"))
+            .step(Record::new("c"));
+
+        // 두 번째 단계 도중 프로세스가 죽은 상태 (첫 단계만 기록됨)
+        let now = Utc::now();
+        let crashed = SagaRecord {
+            id: "s1".to_string(),
+            saga: "merge".to_string(),
+            steps: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            created_at: now,
+            updated_at: now,
+            status: SagaStatus::Running,
+            completed: 1,
+            failure: None,
+            last_error: None,
+            context: vec!["+a".to_string()],
+        };
+        saga.write(&crashed).unwrap();
+
+        let resumed = saga.resume_pending().await.unwrap();
+        assert_eq!(resumed.len(), 1);
+        assert_eq!(resumed[0].1, SagaOutcome::Completed(vec!["+a".into(), "+b".into(), "+c".into()]));
+
+        // 단계 정의가 바뀐 기록은 재개하지 않음
+        let mut changed = crashed.clone();
+        changed.id = "s2".to_string();
+        changed.steps.pop();
+        saga.write(&changed).unwrap();
+        assert!(saga.run("s2", Vec::new()).await.is_err());
+    }
+}
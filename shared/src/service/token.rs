@@ -1,6 +1,10 @@
-use jsonwebtoken::{encode, decode, Header, Validation, Algorithm, EncodingKey, DecodingKey};
+use jsonwebtoken::{encode, decode, decode_header, Header, Validation, Algorithm, EncodingKey, DecodingKey};
+use rand::Rng;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::str::FromStr;
+use std::sync::{Arc, RwLock};
 use tonic::{Request, Status};
 use tracing;
 // use chrono::{Utc, Duration};
@@ -12,13 +16,132 @@ struct Claims {
     sub: i32,
     /// 토큰 만료 시간 (Unix timestamp, 초 단위)
     exp: usize,
+    /// 토큰 종류 (액세스 토큰은 없음, 리프레시 토큰은 `"refresh"`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    typ: Option<String>,
+}
+
+/// 리프레시 토큰 클레임
+///
+/// 로그인마다 발급한 세션(리프레시 토큰 패밀리)에 묶여 있어,
+/// 패밀리가 밀려나거나 로그아웃으로 해제되면 더 이상 갱신할 수 없습니다.
+#[derive(Debug, Serialize, Deserialize)]
+struct RefreshClaims {
+    sub: i32,
+    /// 리프레시 토큰 패밀리 ID
+    fam: String,
+    typ: String,
+    exp: usize,
+}
+
+/// 리프레시 토큰 종류 값
+const REFRESH_TOKEN_TYPE: &str = "refresh";
+
+/// 기본 키 ID (`JWT_KEY_ID`가 없을 때 `JWT_SECRET_KEY`의 kid)
+pub const DEFAULT_KEY_ID: &str = "primary";
+
+/// Redis로 배포된 키 목록 (설치되면 환경변수 키 대신 사용, 프로세스 내 모든 TokenService가 공유)
+static DISTRIBUTED_KEYS: RwLock<Option<Arc<JwtKeySet>>> = RwLock::new(None);
+
+/// JWT 서명 키 (`kid`로 식별)
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwtKey {
+    pub kid: String,
+    pub secret: String,
+    /// 서명에 쓰기 시작하는 시각 (Unix 초, 그 전에는 검증만)
+    #[serde(default)]
+    pub activates_at: i64,
+    /// 서명을 멈추는 시각 (이후에는 이미 발급된 토큰 검증만)
+    #[serde(default)]
+    pub retired_at: Option<i64>,
+}
+
+impl fmt::Debug for JwtKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwtKey")
+            .field("kid", &self.kid)
+            .field("secret", &"<redacted>")
+            .field("activates_at", &self.activates_at)
+            .field("retired_at", &self.retired_at)
+            .finish()
+    }
+}
+
+impl JwtKey {
+    /// 새 무작위 키 (kid = `k{activates_at}`)
+    pub fn generate(activates_at: i64) -> Self {
+        let secret: [u8; 32] = rand::thread_rng().gen();
+        Self {
+            kid: format!("k{activates_at}"),
+            secret: hex::encode(secret),
+            activates_at,
+            retired_at: None,
+        }
+    }
+
+    fn signs_at(&self, now: i64) -> bool {
+        self.activates_at <= now && self.retired_at.is_none_or(|retired_at| now < retired_at)
+    }
+}
+
+/// 검증 키 목록
+///
+/// 서명에는 현재 활성화된 키 중 가장 최근 키를 쓰고, 검증은 목록의 모든 키로 합니다.
+/// 회전 시 새 키는 배포 지연 후 활성화되고, 이전 키는 그때 서명을 멈추되 기존 토큰이
+/// 만료될 때까지 검증용으로 남습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwtKeySet {
+    pub keys: Vec<JwtKey>,
+}
+
+impl JwtKeySet {
+    /// 환경변수 키 목록
+    ///
+    /// - `secret_key`: 서명 키 (kid = `JWT_KEY_ID`, 기본 `primary`)
+    /// - `JWT_VERIFY_KEYS`: 검증 전용 키 (`kid:secret,kid:secret`, 수동 회전 중 이전 키)
+    pub fn configured(secret_key: String) -> Self {
+        let kid = std::env::var("JWT_KEY_ID").unwrap_or_else(|_| DEFAULT_KEY_ID.to_string());
+        let mut keys = vec![JwtKey { kid, secret: secret_key, activates_at: 0, retired_at: None }];
+        if let Ok(verify_keys) = std::env::var("JWT_VERIFY_KEYS") {
+            keys.extend(verify_keys.split(',').filter_map(|entry| {
+                let (kid, secret) = entry.trim().split_once(':')?;
+                Some(JwtKey { kid: kid.to_string(), secret: secret.to_string(), activates_at: 0, retired_at: Some(0) })
+            }));
+        }
+        Self { keys }
+    }
+
+    pub fn get(&self, kid: &str) -> Option<&JwtKey> {
+        self.keys.iter().find(|key| key.kid == kid)
+    }
+
+    /// `now` 시점의 서명 키 (활성화된 키 중 가장 최근)
+    pub fn signing_key(&self, now: i64) -> Option<&JwtKey> {
+        self.keys.iter()
+            .filter(|key| key.signs_at(now))
+            .max_by_key(|key| key.activates_at)
+    }
+
+    /// 새 키 추가
+    ///
+    /// 현재 서명 키들은 새 키가 활성화되는 시각에 서명을 멈추고, 서명을 멈춘 지
+    /// `retain_secs`가 지난 키는 제거합니다.
+    pub fn rotate(&mut self, key: JwtKey, retain_secs: i64, now: i64) {
+        self.keys.retain(|existing| existing.retired_at.is_none_or(|retired_at| retired_at + retain_secs > now));
+        for existing in &mut self.keys {
+            if existing.retired_at.is_none_or(|retired_at| retired_at > key.activates_at) {
+                existing.retired_at = Some(key.activates_at);
+            }
+        }
+        self.keys.push(key);
+    }
 }
 
 /// JWT 토큰 발급 및 검증을 담당하는 서비스
 #[derive(Debug, Clone)]
 pub struct TokenService {
-    /// JWT 비밀키
-    secret_key: String,
+    /// 환경변수 키 목록 (Redis 배포 키가 설치되지 않았을 때 사용)
+    keys: JwtKeySet,
     /// 사용할 서명 알고리즘 (예: HS256)
     algorithm: Algorithm,
     /// 토큰 만료 시간 (시간 단위)
@@ -44,12 +167,26 @@ impl TokenService {
             .unwrap_or(1);
             
         Self {
-            secret_key,
+            keys: JwtKeySet::configured(secret_key),
             algorithm: Algorithm::from_str(&algorithm).unwrap_or(Algorithm::HS256),
             expiration_hours,
         }
     }
 
+    /// Redis로 배포된 키 목록을 설치합니다. (이후 모든 TokenService가 이 목록으로 서명/검증)
+    pub fn install_distributed_keys(keys: JwtKeySet) {
+        if let Ok(mut distributed) = DISTRIBUTED_KEYS.write() {
+            *distributed = Some(Arc::new(keys));
+        }
+    }
+
+    /// 현재 사용할 키 목록 (배포 키 우선)
+    fn key_set(&self) -> Arc<JwtKeySet> {
+        DISTRIBUTED_KEYS.read().ok()
+            .and_then(|distributed| distributed.clone())
+            .unwrap_or_else(|| Arc::new(self.keys.clone()))
+    }
+
     /// 사용자 ID를 기반으로 JWT 토큰을 생성합니다.
     ///
     /// # 인자
@@ -72,24 +209,34 @@ impl TokenService {
         let claims = Claims {
             sub: user_id,
             exp: expiration,
+            typ: None,
         };
 
-        let header = Header {
-            alg: self.algorithm,
-            ..Default::default()
-        };
+        self.sign(&claims)
+    }
 
-        let token = encode(
-            &header,
-            &claims,
-            &EncodingKey::from_secret(self.secret_key.as_bytes()),
-        )?;
+    /// 세션(리프레시 토큰 패밀리)에 묶인 리프레시 토큰을 생성합니다.
+    ///
+    /// # 인자
+    /// - `user_id`: 사용자 고유 ID
+    /// - `family_id`: 로그인 때 발급한 패밀리 ID (`SessionQuota::new_family_id`)
+    /// - `ttl`: 토큰 유효 시간 (세션 유지 시간과 같게 설정)
+    pub fn generate_refresh_token(&self, user_id: i32, family_id: &str, ttl: std::time::Duration) -> anyhow::Result<String> {
+        let expiration = chrono::Utc::now().timestamp() as usize + ttl.as_secs() as usize;
+        let claims = RefreshClaims {
+            sub: user_id,
+            fam: family_id.to_string(),
+            typ: REFRESH_TOKEN_TYPE.to_string(),
+            exp: expiration,
+        };
 
-        Ok(token)
+        self.sign(&claims)
     }
 
     /// JWT 토큰을 검증하고, 성공 시 사용자 ID를 반환합니다.
     ///
+    /// `kid`가 있으면 해당 키로만, 없으면(회전 이전 토큰) 목록의 모든 키로 검증합니다.
+    ///
     /// # 인자
     /// - `token`: 클라이언트로부터 받은 JWT 문자열
     ///
@@ -97,15 +244,62 @@ impl TokenService {
     /// - 성공 시 사용자 ID (`i32`)
     /// - 실패 시 `anyhow::Error`
     pub fn verify_token(&self, token: &str) -> anyhow::Result<i32> {
-        let validation = Validation::new(self.algorithm);
+        let claims: Claims = self.decode_claims(token)?;
+        if claims.typ.is_some() {
+            return Err(anyhow::anyhow!("액세스 토큰이 아닙니다"));
+        }
+        Ok(claims.sub)
+    }
 
-        let token_data = decode::<Claims>(
-            token,
-            &DecodingKey::from_secret(self.secret_key.as_bytes()),
-            &validation,
+    /// 리프레시 토큰을 검증하고, 성공 시 (사용자 ID, 패밀리 ID)를 반환합니다.
+    ///
+    /// 서명과 만료만 확인하므로, 패밀리가 아직 활성 상태인지는 `SessionQuota::is_active`로 따로 확인해야 합니다.
+    pub fn verify_refresh_token(&self, token: &str) -> anyhow::Result<(i32, String)> {
+        let claims: RefreshClaims = self.decode_claims(token)?;
+        if claims.typ != REFRESH_TOKEN_TYPE {
+            return Err(anyhow::anyhow!("리프레시 토큰이 아닙니다"));
+        }
+        Ok((claims.sub, claims.fam))
+    }
+
+    /// 현재 서명 키로 클레임 서명
+    fn sign<C: Serialize>(&self, claims: &C) -> anyhow::Result<String> {
+        let keys = self.key_set();
+        let key = keys.signing_key(chrono::Utc::now().timestamp())
+            .ok_or_else(|| anyhow::anyhow!("활성화된 JWT 서명 키가 없습니다"))?;
+        let header = Header {
+            alg: self.algorithm,
+            kid: Some(key.kid.clone()),
+            ..Default::default()
+        };
+
+        let token = encode(
+            &header,
+            claims,
+            &EncodingKey::from_secret(key.secret.as_bytes()),
         )?;
 
-        Ok(token_data.claims.sub)
+        Ok(token)
+    }
+
+    /// `kid`가 있으면 해당 키로만, 없으면 목록의 모든 키로 서명/만료 검증 후 클레임 반환
+    fn decode_claims<C: DeserializeOwned>(&self, token: &str) -> anyhow::Result<C> {
+        let validation = Validation::new(self.algorithm);
+        let keys = self.key_set();
+
+        let candidates: Vec<&JwtKey> = match decode_header(token)?.kid {
+            Some(kid) => vec![keys.get(&kid).ok_or_else(|| anyhow::anyhow!("알 수 없는 JWT 키: {kid}"))?],
+            None => keys.keys.iter().collect(),
+        };
+
+        let mut last_error = None;
+        for key in candidates {
+            match decode::<C>(token, &DecodingKey::from_secret(key.secret.as_bytes()), &validation) {
+                Ok(token_data) => return Ok(token_data.claims),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.map_or_else(|| anyhow::anyhow!("JWT 검증 키가 없습니다"), Into::into))
     }
 
     /// 공통 인증 함수 - 모든 컨트롤러에서 재사용 가능
@@ -250,7 +444,9 @@ impl TokenService {
     /// * `bool` - 공개 엔드포인트 여부
     pub fn is_public_endpoint(path: &str) -> bool {
         let public_paths = ["/user.UserService/LoginUser",
-            "/user.UserService/RegisterUser"];
+            "/user.UserService/RegisterUser",
+            "/user.UserService/RefreshSession",
+            "/user.UserService/Logout"];
         
         public_paths.contains(&path)
     }
@@ -280,3 +476,59 @@ impl TokenService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(kid: &str, activates_at: i64) -> JwtKey {
+        JwtKey { kid: kid.to_string(), secret: format!("{kid}-secret-0123456789abcdef0123456789"), activates_at, retired_at: None }
+    }
+
+    #[test]
+    fn test_key_rotation_schedule() {
+        let mut keys = JwtKeySet { keys: vec![key("primary", 0)] };
+        keys.rotate(key("k1", 1_600), 3_600, 1_000);
+
+        // 배포 지연 동안은 이전 키로 서명, 활성화 후 새 키로 서명
+        assert_eq!(keys.signing_key(1_000).unwrap().kid, "primary");
+        assert_eq!(keys.signing_key(1_600).unwrap().kid, "k1");
+
+        // 보관 기간이 지난 키는 다음 회전 때 제거
+        keys.rotate(key("k2", 5_800), 3_600, 5_200);
+        assert!(keys.get("primary").is_none());
+        assert_eq!(keys.get("k1").unwrap().retired_at, Some(5_800));
+    }
+
+    #[test]
+    fn test_verify_by_kid_and_legacy_token() {
+        let mut service = TokenService::new("primary-secret-0123456789abcdef0123456789".to_string(), "HS256".to_string());
+        let legacy = encode(
+            &Header::new(Algorithm::HS256),
+            &Claims { sub: 7, exp: (chrono::Utc::now().timestamp() + 60) as usize, typ: None },
+            &EncodingKey::from_secret(b"primary-secret-0123456789abcdef0123456789"),
+        ).unwrap();
+        let token = service.generate_token(7).unwrap();
+        assert_eq!(decode_header(&token).unwrap().kid.as_deref(), Some(DEFAULT_KEY_ID));
+
+        // 새 키로 회전해도 이전 키로 서명한 토큰과 kid 없는 토큰은 계속 검증
+        service.keys.rotate(key("k1", 0), 3_600, chrono::Utc::now().timestamp());
+        assert_eq!(service.verify_token(&token).unwrap(), 7);
+        assert_eq!(service.verify_token(&legacy).unwrap(), 7);
+        let rotated = service.generate_token(8).unwrap();
+        assert_eq!(decode_header(&rotated).unwrap().kid.as_deref(), Some("k1"));
+        assert_eq!(service.verify_token(&rotated).unwrap(), 8);
+    }
+
+    #[test]
+    fn test_refresh_token_bound_to_family() {
+        let service = TokenService::new("primary-secret-0123456789abcdef0123456789".to_string(), "HS256".to_string());
+        let refresh = service.generate_refresh_token(7, "family-a", std::time::Duration::from_secs(60)).unwrap();
+        assert_eq!(service.verify_refresh_token(&refresh).unwrap(), (7, "family-a".to_string()));
+
+        // 리프레시 토큰과 액세스 토큰은 서로 대신 쓸 수 없음
+        assert!(service.verify_token(&refresh).is_err());
+        let access = service.generate_token(7).unwrap();
+        assert!(service.verify_refresh_token(&access).is_err());
+    }
+}
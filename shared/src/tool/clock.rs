@@ -0,0 +1,266 @@
+//! 시간 서비스
+//!
+//! `Instant::now()`/`Utc::now()`를 직접 부르면 만료·쿨다운·스케줄 로직을 테스트할 수 없으므로,
+//! 시간이 필요한 곳은 [`Clock`]을 주입받아 사용합니다.
+//! - [`SystemClock`]: 실제 시계 (기본값)
+//! - [`FrozenClock`]: 멈춘 시계, `advance`/`set`으로만 흐름 (테스트용)
+//! - [`OffsetClock`]: 다른 시계에 오프셋과 배속을 적용 (서버 시간 보정, 시간 가속 시뮬레이션)
+//!
+//! 단조 시각(`Instant`)과 벽시계 시각(`DateTime<Utc>`)은 같은 시계에서 함께 흐르며,
+//! [`instant_to_datetime`]/[`datetime_to_instant`]로 서로 변환합니다.
+//! 클라이언트-서버 시간 동기화는 [`TimeSyncSample`]과 [`estimate_offset`]을 사용합니다.
+
+use chrono::{DateTime, TimeZone, Utc};
+use parking_lot::Mutex;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// 시계
+pub trait Clock: Send + Sync + Debug {
+    /// 벽시계 시각
+    fn now(&self) -> DateTime<Utc>;
+
+    /// 단조 시각
+    fn instant(&self) -> Instant;
+
+    /// 유닉스 시각 (초)
+    fn unix_secs(&self) -> i64 {
+        self.now().timestamp()
+    }
+
+    /// 유닉스 시각 (밀리초)
+    fn unix_millis(&self) -> i64 {
+        self.now().timestamp_millis()
+    }
+}
+
+/// 공유 시계
+pub type SharedClock = Arc<dyn Clock>;
+
+/// 기본 시계 (실제 시간)
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+/// 실제 시계
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Debug)]
+struct FrozenState {
+    instant: Instant,
+    now: DateTime<Utc>,
+}
+
+/// 멈춘 시계 (직접 옮기기 전에는 시간이 흐르지 않음)
+#[derive(Debug)]
+pub struct FrozenClock {
+    state: Mutex<FrozenState>,
+}
+
+impl FrozenClock {
+    /// 현재 시각에서 멈춘 시계
+    pub fn new() -> Arc<Self> {
+        Self::at(Utc::now())
+    }
+
+    /// 지정한 벽시계 시각에서 멈춘 시계
+    pub fn at(now: DateTime<Utc>) -> Arc<Self> {
+        Arc::new(Self { state: Mutex::new(FrozenState { instant: Instant::now(), now }) })
+    }
+
+    /// 시간 진행 (단조 시각과 벽시계 시각 모두)
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.state.lock();
+        state.instant += duration;
+        state.now += chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::MAX);
+    }
+
+    /// 벽시계 시각만 변경 (NTP 보정처럼 시계가 뒤로 갈 수 있음, 단조 시각은 그대로)
+    pub fn set(&self, now: DateTime<Utc>) {
+        self.state.lock().now = now;
+    }
+}
+
+impl Clock for FrozenClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.state.lock().now
+    }
+
+    fn instant(&self) -> Instant {
+        self.state.lock().instant
+    }
+}
+
+/// 오프셋/배속 시계
+///
+/// 생성 시점부터 흐른 시간에 `speed`를 곱하고, 벽시계 시각에는 `offset`을 더합니다.
+#[derive(Debug)]
+pub struct OffsetClock {
+    inner: SharedClock,
+    origin_instant: Instant,
+    origin_now: DateTime<Utc>,
+    offset: Mutex<chrono::Duration>,
+    speed: f64,
+}
+
+impl OffsetClock {
+    /// 벽시계 시각에 오프셋만 적용
+    pub fn new(inner: SharedClock, offset: chrono::Duration) -> Arc<Self> {
+        Self::accelerated(inner, offset, 1.0)
+    }
+
+    /// 오프셋과 배속 적용 (`speed`가 2.0이면 두 배로 흐름, 음수는 0으로 처리)
+    pub fn accelerated(inner: SharedClock, offset: chrono::Duration, speed: f64) -> Arc<Self> {
+        Arc::new(Self {
+            origin_instant: inner.instant(),
+            origin_now: inner.now(),
+            inner,
+            offset: Mutex::new(offset),
+            speed: speed.max(0.0),
+        })
+    }
+
+    /// 오프셋 변경 (서버 시간 재동기화)
+    pub fn set_offset(&self, offset: chrono::Duration) {
+        *self.offset.lock() = offset;
+    }
+
+    pub fn offset(&self) -> chrono::Duration {
+        *self.offset.lock()
+    }
+
+    fn scaled_elapsed(&self) -> Duration {
+        self.inner.instant().duration_since(self.origin_instant).mul_f64(self.speed)
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> DateTime<Utc> {
+        let elapsed = chrono::Duration::from_std(self.scaled_elapsed()).unwrap_or(chrono::Duration::MAX);
+        self.origin_now + elapsed + self.offset()
+    }
+
+    fn instant(&self) -> Instant {
+        self.origin_instant + self.scaled_elapsed()
+    }
+}
+
+/// 단조 시각을 같은 시계의 벽시계 시각으로 변환
+pub fn instant_to_datetime(clock: &dyn Clock, instant: Instant) -> DateTime<Utc> {
+    let (now_instant, now) = (clock.instant(), clock.now());
+    match now_instant.checked_duration_since(instant) {
+        Some(ago) => now - chrono::Duration::from_std(ago).unwrap_or(chrono::Duration::MAX),
+        None => now + chrono::Duration::from_std(instant - now_instant).unwrap_or(chrono::Duration::MAX),
+    }
+}
+
+/// 벽시계 시각을 같은 시계의 단조 시각으로 변환 (표현할 수 없으면 `None`)
+pub fn datetime_to_instant(clock: &dyn Clock, at: DateTime<Utc>) -> Option<Instant> {
+    let (now_instant, now) = (clock.instant(), clock.now());
+    let delta = at - now;
+    match delta.to_std() {
+        Ok(ahead) => now_instant.checked_add(ahead),
+        Err(_) => now_instant.checked_sub((-delta).to_std().ok()?),
+    }
+}
+
+/// 유닉스 밀리초를 벽시계 시각으로 변환
+pub fn from_unix_millis(millis: i64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_millis_opt(millis).single()
+}
+
+/// 시간 동기화 측정 한 번 (NTP 방식)
+///
+/// 클라이언트가 요청을 보낸 시각 `client_sent`, 서버가 처리한 시각 `server_time`,
+/// 클라이언트가 응답을 받은 시각 `client_received`로 오프셋과 왕복 시간을 계산합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeSyncSample {
+    pub client_sent: DateTime<Utc>,
+    pub server_time: DateTime<Utc>,
+    pub client_received: DateTime<Utc>,
+}
+
+impl TimeSyncSample {
+    /// 왕복 시간
+    pub fn round_trip(&self) -> chrono::Duration {
+        (self.client_received - self.client_sent).max(chrono::Duration::zero())
+    }
+
+    /// 클라이언트 시계에 더할 오프셋 (서버 시각 - 클라이언트 시각, 편도 지연은 왕복의 절반으로 가정)
+    pub fn offset(&self) -> chrono::Duration {
+        self.server_time - (self.client_sent + self.round_trip() / 2)
+    }
+}
+
+/// 여러 측정에서 오프셋 추정
+///
+/// 왕복 시간이 짧을수록 오차가 작으므로 왕복 시간이 가장 짧은 절반의 오프셋 중앙값을 사용합니다.
+pub fn estimate_offset(samples: &[TimeSyncSample]) -> Option<chrono::Duration> {
+    if samples.is_empty() {
+        return None;
+    }
+    let mut by_rtt: Vec<&TimeSyncSample> = samples.iter().collect();
+    by_rtt.sort_by_key(|sample| sample.round_trip());
+    let mut offsets: Vec<chrono::Duration> =
+        by_rtt[..by_rtt.len().div_ceil(2)].iter().map(|sample| sample.offset()).collect();
+    offsets.sort();
+    Some(offsets[offsets.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frozen_and_offset_clock() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let clock = FrozenClock::at(start);
+        let instant = clock.instant();
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(clock.instant() - instant, Duration::from_secs(90));
+        assert_eq!(clock.unix_secs(), start.timestamp() + 90);
+        assert_eq!(instant_to_datetime(clock.as_ref(), instant), start);
+        assert_eq!(datetime_to_instant(clock.as_ref(), start), Some(instant));
+
+        // 벽시계만 되돌려도 단조 시각은 그대로
+        clock.set(start);
+        assert_eq!(clock.instant() - instant, Duration::from_secs(90));
+
+        let accelerated = OffsetClock::accelerated(clock.clone(), chrono::Duration::hours(1), 10.0);
+        clock.advance(Duration::from_secs(6));
+        assert_eq!(accelerated.now(), start + chrono::Duration::seconds(6 * 10 + 3600));
+        assert_eq!(accelerated.instant() - clock.instant(), Duration::from_secs(54));
+    }
+
+    #[test]
+    fn test_estimate_offset_prefers_low_rtt() {
+        let base = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let ms = chrono::Duration::milliseconds;
+        let sample = |sent: i64, server: i64, received: i64| TimeSyncSample {
+            client_sent: base + ms(sent),
+            server_time: base + ms(server),
+            client_received: base + ms(received),
+        };
+
+        // 서버가 500ms 앞서 있음, 마지막 측정은 지연이 커서 부정확
+        let samples = [sample(0, 520, 40), sample(100, 610, 120), sample(200, 1400, 600)];
+        assert_eq!(samples[0].round_trip(), ms(40));
+        assert_eq!(samples[0].offset(), ms(500));
+        assert_eq!(estimate_offset(&samples), Some(ms(500)));
+        assert_eq!(estimate_offset(&[]), None);
+    }
+}
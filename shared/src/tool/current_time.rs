@@ -1,6 +1,8 @@
 // use std::time::{SystemTime, UNIX_EPOCH};
 use chrono::{DateTime, Local};  
 
+use crate::tool::clock::Clock;
+
 
 pub struct CurrentTime {
     pub current_time: String,
@@ -15,6 +17,16 @@ impl CurrentTime {
     }
 }
 
+impl CurrentTime {
+    /// 주입한 시계 기준 현재 시각
+    pub fn from_clock(clock: &dyn Clock) -> Self {
+        let now: DateTime<Local> = clock.now().with_timezone(&Local);
+        Self {
+            current_time: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
 impl Default for CurrentTime {
     fn default() -> Self {
         Self::new()
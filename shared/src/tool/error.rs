@@ -7,6 +7,8 @@ use thiserror::Error;
 use tonic::Status;
 use tracing::{error, warn, info};
 
+use super::game_error::GameServerError;
+
 /// 공통 애플리케이션 에러 정의
 /// 
 /// 모든 비즈니스 로직에서 발생할 수 있는 에러를 정의합니다.
@@ -162,40 +164,10 @@ pub enum ErrorSeverity {
     Low,      // 일반적인 경고
 }
 
+/// 공통 에러 분류(`GameServerError`)를 거쳐 변환하므로 내부 상세는 클라이언트에 노출되지 않습니다.
 impl From<AppError> for Status {
     fn from(e: AppError) -> Self {
-        match e {
-            // 인증 관련
-            AppError::AuthError(msg) => Status::unauthenticated(msg),
-            AppError::TokenExpired(msg) => Status::unauthenticated(format!("Token expired: {msg}")),
-            AppError::PermissionDenied(msg) => Status::permission_denied(msg),
-            
-            // 리소스 없음
-            AppError::UserNotFound(msg) => Status::not_found(format!("User not found: {msg}")),
-            AppError::RoomNotFound(msg) => Status::not_found(format!("Room not found: {msg}")),
-            
-            // 비즈니스 로직 오류
-            AppError::NicknameExists(msg) => Status::already_exists(format!("Nickname exists: {msg}")),
-            AppError::RoomFull(msg) => Status::resource_exhausted(format!("Room full: {msg}")),
-            AppError::InvalidLoginType(msg) => Status::invalid_argument(format!("Invalid login type: {msg}")),
-            
-            // 입력값 오류
-            AppError::InvalidInput(msg) => Status::invalid_argument(msg),
-            AppError::MissingField(msg) => Status::invalid_argument(format!("Missing field: {msg}")),
-            AppError::InvalidFormat(msg) => Status::invalid_argument(format!("Invalid format: {msg}")),
-            AppError::RoomNameTooLong(msg) => Status::invalid_argument(format!("Room name too long: {msg}")),
-            AppError::InvalidMaxPlayers(msg) => Status::invalid_argument(format!("Invalid max players: {msg}")),
-            
-            // 시스템 오류
-            AppError::DatabaseConnection(msg) => Status::unavailable(format!("Database connection failed: {msg}")),
-            AppError::DatabaseQuery(msg) => Status::internal(format!("Database query failed: {msg}")),
-            AppError::TransactionFailed(msg) => Status::internal(format!("Transaction failed: {msg}")),
-            AppError::ExternalApiError(msg) => Status::unavailable(format!("External API error: {msg}")),
-            AppError::RedisConnection(msg) => Status::unavailable(format!("Redis connection failed: {msg}")),
-            AppError::InternalError(msg) => Status::internal(msg),
-            AppError::ServiceUnavailable(msg) => Status::unavailable(msg),
-            AppError::Timeout(msg) => Status::deadline_exceeded(msg),
-        }
+        GameServerError::from(e).into()
     }
 }
 
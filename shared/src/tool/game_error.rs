@@ -0,0 +1,529 @@
+//! 공통 에러 분류 (Game Server Error Taxonomy)
+//!
+//! 모든 서버가 같은 에러 코드로 응답하도록 도메인, 코드, 재시도 가능 여부, 사용자 메시지 키를 한 곳에서 정의합니다.
+//! 내부 상세(`detail`)는 로그 전용이며, 클라이언트에는 코드와 사용자 메시지만 전달합니다.
+//! (입력 오류처럼 클라이언트가 원인인 코드만 상세를 메시지에 포함합니다.)
+//!
+//! 프로토콜별 표현
+//! - gRPC: `Status` 코드 + 사용자 메시지, 메타데이터 `x-error-code` / `x-error-retryable` / `x-error-message-key`
+//! - TCP: `Error { code: numeric(), message }`
+//! - RUDP: `Error { error_code: as_str(), error_message, category, recoverable: retryable() }`
+
+use std::fmt;
+
+use thiserror::Error;
+use tonic::metadata::MetadataValue;
+use tonic::{Code, Status};
+
+use crate::security::SecurityError;
+use crate::tool::error::AppError;
+
+/// gRPC 메타데이터: 에러 코드 (`ROOM_FULL`)
+pub const ERROR_CODE_METADATA: &str = "x-error-code";
+/// gRPC 메타데이터: 재시도 가능 여부 (`true`/`false`)
+pub const ERROR_RETRYABLE_METADATA: &str = "x-error-retryable";
+/// gRPC 메타데이터: 사용자 메시지 키 (`error.room_full`)
+pub const ERROR_MESSAGE_KEY_METADATA: &str = "x-error-message-key";
+
+/// 에러 도메인
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorDomain {
+    Auth,
+    User,
+    Room,
+    Game,
+    Input,
+    Limit,
+    System,
+}
+
+impl ErrorDomain {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorDomain::Auth => "auth",
+            ErrorDomain::User => "user",
+            ErrorDomain::Room => "room",
+            ErrorDomain::Game => "game",
+            ErrorDomain::Input => "input",
+            ErrorDomain::Limit => "limit",
+            ErrorDomain::System => "system",
+        }
+    }
+}
+
+/// 클라이언트에 노출되는 에러 코드
+///
+/// 문자열 코드와 숫자 코드는 클라이언트가 분기에 사용하므로 한 번 배포한 값은 바꾸지 않습니다.
+/// 숫자 코드는 도메인별 대역(인증 20xx, 사용자 21xx, 방 22xx, 게임 23xx, 입력 24xx, 한도 25xx, 시스템 29xx)을 씁니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    // 인증
+    AuthFailed,
+    TokenExpired,
+    PermissionDenied,
+    AccountLocked,
+    // 사용자
+    UserNotFound,
+    NicknameTaken,
+    // 방
+    RoomNotFound,
+    RoomFull,
+    NotInRoom,
+    // 게임
+    InvalidSession,
+    PlayerInactive,
+    PlayerDead,
+    InvalidAction,
+    Cooldown,
+    // 입력
+    InvalidInput,
+    MissingField,
+    PayloadTooLarge,
+    UnsupportedVersion,
+    // 한도
+    RateLimited,
+    TooManySessions,
+    ServerFull,
+    // 시스템
+    Unavailable,
+    Timeout,
+    Internal,
+    /// 운영 중 킬 스위치로 꺼진 기능
+    FeatureDisabled,
+}
+
+impl ErrorCode {
+    /// 정의된 모든 코드
+    pub const ALL: [ErrorCode; 25] = [
+        ErrorCode::AuthFailed,
+        ErrorCode::TokenExpired,
+        ErrorCode::PermissionDenied,
+        ErrorCode::AccountLocked,
+        ErrorCode::UserNotFound,
+        ErrorCode::NicknameTaken,
+        ErrorCode::RoomNotFound,
+        ErrorCode::RoomFull,
+        ErrorCode::NotInRoom,
+        ErrorCode::InvalidSession,
+        ErrorCode::PlayerInactive,
+        ErrorCode::PlayerDead,
+        ErrorCode::InvalidAction,
+        ErrorCode::Cooldown,
+        ErrorCode::InvalidInput,
+        ErrorCode::MissingField,
+        ErrorCode::PayloadTooLarge,
+        ErrorCode::UnsupportedVersion,
+        ErrorCode::RateLimited,
+        ErrorCode::TooManySessions,
+        ErrorCode::ServerFull,
+        ErrorCode::Unavailable,
+        ErrorCode::Timeout,
+        ErrorCode::Internal,
+        ErrorCode::FeatureDisabled,
+    ];
+
+    /// 문자열 코드 (RUDP `error_code`, gRPC `x-error-code`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::AuthFailed => "AUTH_FAILED",
+            ErrorCode::TokenExpired => "TOKEN_EXPIRED",
+            ErrorCode::PermissionDenied => "PERMISSION_DENIED",
+            ErrorCode::AccountLocked => "ACCOUNT_LOCKED",
+            ErrorCode::UserNotFound => "USER_NOT_FOUND",
+            ErrorCode::NicknameTaken => "NICKNAME_TAKEN",
+            ErrorCode::RoomNotFound => "ROOM_NOT_FOUND",
+            ErrorCode::RoomFull => "ROOM_FULL",
+            ErrorCode::NotInRoom => "NOT_IN_ROOM",
+            ErrorCode::InvalidSession => "INVALID_SESSION",
+            ErrorCode::PlayerInactive => "PLAYER_INACTIVE",
+            ErrorCode::PlayerDead => "PLAYER_DEAD",
+            ErrorCode::InvalidAction => "INVALID_ACTION",
+            ErrorCode::Cooldown => "COOLDOWN",
+            ErrorCode::InvalidInput => "INVALID_INPUT",
+            ErrorCode::MissingField => "MISSING_FIELD",
+            ErrorCode::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            ErrorCode::UnsupportedVersion => "UNSUPPORTED_VERSION",
+            ErrorCode::RateLimited => "RATE_LIMITED",
+            ErrorCode::TooManySessions => "TOO_MANY_SESSIONS",
+            ErrorCode::ServerFull => "SERVER_FULL",
+            ErrorCode::Unavailable => "UNAVAILABLE",
+            ErrorCode::Timeout => "TIMEOUT",
+            ErrorCode::Internal => "INTERNAL",
+            ErrorCode::FeatureDisabled => "FEATURE_DISABLED",
+        }
+    }
+
+    /// 숫자 코드 (TCP `Error.code`)
+    pub fn numeric(&self) -> u16 {
+        match self {
+            ErrorCode::AuthFailed => 2001,
+            ErrorCode::TokenExpired => 2002,
+            ErrorCode::PermissionDenied => 2003,
+            ErrorCode::AccountLocked => 2004,
+            ErrorCode::UserNotFound => 2101,
+            ErrorCode::NicknameTaken => 2102,
+            ErrorCode::RoomNotFound => 2201,
+            ErrorCode::RoomFull => 2202,
+            ErrorCode::NotInRoom => 2203,
+            ErrorCode::InvalidSession => 2301,
+            ErrorCode::PlayerInactive => 2302,
+            ErrorCode::PlayerDead => 2303,
+            ErrorCode::InvalidAction => 2304,
+            ErrorCode::Cooldown => 2305,
+            ErrorCode::InvalidInput => 2401,
+            ErrorCode::MissingField => 2402,
+            ErrorCode::PayloadTooLarge => 2403,
+            ErrorCode::UnsupportedVersion => 2404,
+            ErrorCode::RateLimited => 2501,
+            ErrorCode::TooManySessions => 2502,
+            ErrorCode::ServerFull => 2503,
+            ErrorCode::Unavailable => 2901,
+            ErrorCode::Timeout => 2902,
+            ErrorCode::Internal => 2903,
+            ErrorCode::FeatureDisabled => 2904,
+        }
+    }
+
+    pub fn domain(&self) -> ErrorDomain {
+        match self.numeric() / 100 {
+            20 => ErrorDomain::Auth,
+            21 => ErrorDomain::User,
+            22 => ErrorDomain::Room,
+            23 => ErrorDomain::Game,
+            24 => ErrorDomain::Input,
+            25 => ErrorDomain::Limit,
+            _ => ErrorDomain::System,
+        }
+    }
+
+    /// 같은 요청을 잠시 후 그대로 다시 보내도 되는지 여부
+    pub fn retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::AccountLocked
+                | ErrorCode::Cooldown
+                | ErrorCode::RateLimited
+                | ErrorCode::ServerFull
+                | ErrorCode::Unavailable
+                | ErrorCode::Timeout
+        )
+    }
+
+    /// 클라이언트 번역용 메시지 키 (`error.room_full`)
+    pub fn message_key(&self) -> String {
+        format!("error.{}", self.as_str().to_ascii_lowercase())
+    }
+
+    /// 기본 사용자 메시지
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            ErrorCode::AuthFailed => "인증에 실패했습니다",
+            ErrorCode::TokenExpired => "토큰이 만료되었습니다. 다시 로그인하세요",
+            ErrorCode::PermissionDenied => "권한이 없습니다",
+            ErrorCode::AccountLocked => "로그인 시도가 너무 많아 계정이 잠시 잠겼습니다",
+            ErrorCode::UserNotFound => "사용자를 찾을 수 없습니다",
+            ErrorCode::NicknameTaken => "이미 사용 중인 닉네임입니다",
+            ErrorCode::RoomNotFound => "방을 찾을 수 없습니다",
+            ErrorCode::RoomFull => "방이 가득 찼습니다",
+            ErrorCode::NotInRoom => "방에 참여하고 있지 않습니다",
+            ErrorCode::InvalidSession => "유효하지 않은 세션입니다",
+            ErrorCode::PlayerInactive => "비활성 상태의 플레이어입니다",
+            ErrorCode::PlayerDead => "사망한 상태에서는 할 수 없습니다",
+            ErrorCode::InvalidAction => "허용되지 않는 동작입니다",
+            ErrorCode::Cooldown => "아직 사용할 수 없습니다. 잠시 후 다시 시도하세요",
+            ErrorCode::InvalidInput => "입력값이 올바르지 않습니다",
+            ErrorCode::MissingField => "필수 항목이 누락되었습니다",
+            ErrorCode::PayloadTooLarge => "요청이 너무 큽니다",
+            ErrorCode::UnsupportedVersion => "지원하지 않는 프로토콜 버전입니다",
+            ErrorCode::RateLimited => "요청이 너무 많습니다. 잠시 후 다시 시도하세요",
+            ErrorCode::TooManySessions => "동시 접속 세션 수를 초과했습니다",
+            ErrorCode::ServerFull => "서버가 가득 찼습니다. 잠시 후 다시 시도하세요",
+            ErrorCode::Unavailable => "서비스를 일시적으로 사용할 수 없습니다",
+            ErrorCode::Timeout => "요청 시간이 초과되었습니다",
+            ErrorCode::Internal => "서버 내부 오류가 발생했습니다",
+            ErrorCode::FeatureDisabled => "일시적으로 사용할 수 없는 기능입니다",
+        }
+    }
+
+    /// 상세 내용을 클라이언트에 보여도 되는 코드 (클라이언트 입력이 원인인 경우)
+    pub fn exposes_detail(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::InvalidInput
+                | ErrorCode::MissingField
+                | ErrorCode::PayloadTooLarge
+                | ErrorCode::UnsupportedVersion
+        )
+    }
+
+    pub fn grpc_code(&self) -> Code {
+        match self {
+            ErrorCode::AuthFailed | ErrorCode::TokenExpired | ErrorCode::InvalidSession => {
+                Code::Unauthenticated
+            }
+            ErrorCode::PermissionDenied => Code::PermissionDenied,
+            ErrorCode::UserNotFound | ErrorCode::RoomNotFound => Code::NotFound,
+            ErrorCode::NicknameTaken => Code::AlreadyExists,
+            ErrorCode::NotInRoom
+            | ErrorCode::PlayerInactive
+            | ErrorCode::PlayerDead
+            | ErrorCode::InvalidAction
+            | ErrorCode::Cooldown
+            | ErrorCode::UnsupportedVersion => Code::FailedPrecondition,
+            ErrorCode::InvalidInput | ErrorCode::MissingField => Code::InvalidArgument,
+            ErrorCode::PayloadTooLarge => Code::OutOfRange,
+            ErrorCode::RoomFull
+            | ErrorCode::AccountLocked
+            | ErrorCode::RateLimited
+            | ErrorCode::TooManySessions
+            | ErrorCode::ServerFull => Code::ResourceExhausted,
+            ErrorCode::Unavailable => Code::Unavailable,
+            ErrorCode::Timeout => Code::DeadlineExceeded,
+            ErrorCode::Internal => Code::Internal,
+            ErrorCode::FeatureDisabled => Code::Unavailable,
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|candidate| candidate.as_str() == code)
+    }
+
+    pub fn from_numeric(code: u16) -> Option<Self> {
+        Self::ALL.into_iter().find(|candidate| candidate.numeric() == code)
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 서버 공통 에러
+///
+/// 각 서버의 내부 에러를 이 타입으로 바꾼 뒤 프로토콜별 응답으로 변환합니다.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("{code}{}", .detail.as_ref().or(.message.as_ref()).map(|detail| format!(": {detail}")).unwrap_or_default())]
+pub struct GameServerError {
+    code: ErrorCode,
+    detail: Option<String>,
+    /// 기본 메시지 대신 보낼 사용자 메시지 (서버가 직접 작성한 안내문)
+    message: Option<String>,
+}
+
+impl GameServerError {
+    pub fn new(code: ErrorCode) -> Self {
+        Self { code, detail: None, message: None }
+    }
+
+    /// 상세 내용과 함께 생성 (노출 허용 코드가 아니면 로그에만 남습니다)
+    pub fn with_detail(code: ErrorCode, detail: impl Into<String>) -> Self {
+        Self { code, detail: Some(detail.into()), message: None }
+    }
+
+    /// 클라이언트에 그대로 보여줄 안내문과 함께 생성
+    pub fn with_message(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self { code, detail: None, message: Some(message.into()) }
+    }
+
+    /// 분류되지 않은 내부 에러 (anyhow 등)
+    pub fn internal(error: impl fmt::Display) -> Self {
+        Self::with_detail(ErrorCode::Internal, error.to_string())
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    pub fn domain(&self) -> ErrorDomain {
+        self.code.domain()
+    }
+
+    pub fn retryable(&self) -> bool {
+        self.code.retryable()
+    }
+
+    pub fn message_key(&self) -> String {
+        self.code.message_key()
+    }
+
+    /// 내부 상세 (로그 전용)
+    pub fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    /// 클라이언트에 보낼 메시지
+    pub fn user_message(&self) -> String {
+        if let Some(message) = &self.message {
+            return message.clone();
+        }
+        match &self.detail {
+            Some(detail) if self.code.exposes_detail() => {
+                format!("{}: {}", self.code.default_message(), detail)
+            }
+            _ => self.code.default_message().to_string(),
+        }
+    }
+}
+
+impl From<ErrorCode> for GameServerError {
+    fn from(code: ErrorCode) -> Self {
+        Self::new(code)
+    }
+}
+
+impl From<AppError> for GameServerError {
+    fn from(error: AppError) -> Self {
+        let code = match &error {
+            AppError::AuthError(_) => ErrorCode::AuthFailed,
+            AppError::TokenExpired(_) => ErrorCode::TokenExpired,
+            AppError::PermissionDenied(_) => ErrorCode::PermissionDenied,
+            AppError::UserNotFound(_) => ErrorCode::UserNotFound,
+            AppError::NicknameExists(_) => ErrorCode::NicknameTaken,
+            AppError::RoomNotFound(_) => ErrorCode::RoomNotFound,
+            AppError::RoomFull(_) => ErrorCode::RoomFull,
+            AppError::MissingField(_) => ErrorCode::MissingField,
+            AppError::InvalidLoginType(_)
+            | AppError::RoomNameTooLong(_)
+            | AppError::InvalidMaxPlayers(_)
+            | AppError::InvalidInput(_)
+            | AppError::InvalidFormat(_) => ErrorCode::InvalidInput,
+            AppError::DatabaseConnection(_)
+            | AppError::RedisConnection(_)
+            | AppError::ExternalApiError(_)
+            | AppError::ServiceUnavailable(_) => ErrorCode::Unavailable,
+            AppError::Timeout(_) => ErrorCode::Timeout,
+            AppError::DatabaseQuery(_)
+            | AppError::TransactionFailed(_)
+            | AppError::InternalError(_) => ErrorCode::Internal,
+        };
+        Self::with_detail(code, error.to_string())
+    }
+}
+
+fn security_code(error: &SecurityError) -> ErrorCode {
+    match error {
+        SecurityError::AuthenticationFailed(_) | SecurityError::InvalidToken(_) => {
+            ErrorCode::AuthFailed
+        }
+        SecurityError::TokenExpired => ErrorCode::TokenExpired,
+        SecurityError::AuthorizationDenied(_) => ErrorCode::PermissionDenied,
+        SecurityError::AccountLocked { .. } => ErrorCode::AccountLocked,
+        SecurityError::InvalidInput(_) => ErrorCode::InvalidInput,
+        SecurityError::MessageTooLarge { .. } => ErrorCode::PayloadTooLarge,
+        SecurityError::RateLimitExceeded => ErrorCode::RateLimited,
+        SecurityError::SessionLimitExceeded { .. } => ErrorCode::TooManySessions,
+        SecurityError::EncryptionFailed(_) => ErrorCode::Internal,
+    }
+}
+
+impl From<&SecurityError> for GameServerError {
+    fn from(error: &SecurityError) -> Self {
+        let code = security_code(error);
+        let message = match error {
+            SecurityError::AccountLocked { retry_after } => {
+                // 남은 시간은 올림해서 알림
+                let secs = (retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0)).max(1);
+                Some(format!("{}. {}초 후 다시 시도하세요", code.default_message(), secs))
+            }
+            SecurityError::MessageTooLarge { max, .. } => {
+                Some(format!("{} (최대 {}바이트)", code.default_message(), max))
+            }
+            SecurityError::SessionLimitExceeded { max } => {
+                Some(format!("{} (최대 {}개)", code.default_message(), max))
+            }
+            _ => None,
+        };
+        Self { code, detail: Some(error.to_string()), message }
+    }
+}
+
+impl From<SecurityError> for GameServerError {
+    fn from(error: SecurityError) -> Self {
+        Self::from(&error)
+    }
+}
+
+/// 에러 체인에서 분류된 에러(`GameServerError`, `AppError`, `SecurityError`)를 찾고, 없으면 내부 에러로 봅니다.
+impl From<anyhow::Error> for GameServerError {
+    fn from(error: anyhow::Error) -> Self {
+        for cause in error.chain() {
+            if let Some(error) = cause.downcast_ref::<GameServerError>() {
+                return error.clone();
+            }
+            if let Some(error) = cause.downcast_ref::<AppError>() {
+                return error.clone().into();
+            }
+            if let Some(error) = cause.downcast_ref::<SecurityError>() {
+                return error.into();
+            }
+        }
+        Self::internal(format!("{error:#}"))
+    }
+}
+
+impl From<GameServerError> for Status {
+    fn from(error: GameServerError) -> Self {
+        let mut status = Status::new(error.code.grpc_code(), error.user_message());
+        let metadata = status.metadata_mut();
+        metadata.insert(ERROR_CODE_METADATA, MetadataValue::from_static(error.code.as_str()));
+        metadata.insert(
+            ERROR_RETRYABLE_METADATA,
+            MetadataValue::from_static(if error.retryable() { "true" } else { "false" }),
+        );
+        if let Ok(key) = error.message_key().parse() {
+            metadata.insert(ERROR_MESSAGE_KEY_METADATA, key);
+        }
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_are_unique_and_round_trip() {
+        let strings: HashSet<_> = ErrorCode::ALL.iter().map(|code| code.as_str()).collect();
+        let numbers: HashSet<_> = ErrorCode::ALL.iter().map(|code| code.numeric()).collect();
+        assert_eq!(strings.len(), ErrorCode::ALL.len());
+        assert_eq!(numbers.len(), ErrorCode::ALL.len());
+
+        for code in ErrorCode::ALL {
+            assert_eq!(ErrorCode::from_code(code.as_str()), Some(code));
+            assert_eq!(ErrorCode::from_numeric(code.numeric()), Some(code));
+        }
+        assert_eq!(ErrorCode::RoomFull.domain(), ErrorDomain::Room);
+        assert_eq!(ErrorCode::RoomFull.message_key(), "error.room_full");
+    }
+
+    #[test]
+    fn test_internal_detail_is_not_exposed() {
+        let error = GameServerError::from(AppError::DatabaseQuery("SELECT * FROM users".to_string()));
+        assert_eq!(error.code(), ErrorCode::Internal);
+        assert!(!error.user_message().contains("SELECT"));
+
+        let status = Status::from(error);
+        assert_eq!(status.code(), Code::Internal);
+        assert!(!status.message().contains("SELECT"));
+        assert_eq!(status.metadata().get(ERROR_CODE_METADATA).unwrap(), "INTERNAL");
+        assert_eq!(status.metadata().get(ERROR_RETRYABLE_METADATA).unwrap(), "false");
+
+        let error = GameServerError::from(SecurityError::MessageTooLarge { current: 70000, max: 65536 });
+        assert_eq!(error.code(), ErrorCode::PayloadTooLarge);
+        assert!(error.user_message().contains("65536"));
+
+        let status = Status::from(GameServerError::from(SecurityError::RateLimitExceeded));
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(status.metadata().get(ERROR_RETRYABLE_METADATA).unwrap(), "true");
+        assert_eq!(status.metadata().get(ERROR_MESSAGE_KEY_METADATA).unwrap(), "error.rate_limited");
+
+        // anyhow 체인 안의 분류된 에러는 그대로, 나머지는 내부 에러
+        let wrapped = anyhow::Error::new(GameServerError::new(ErrorCode::RoomFull)).context("방 입장");
+        assert_eq!(GameServerError::from(wrapped).code(), ErrorCode::RoomFull);
+        let opaque = GameServerError::from(anyhow::anyhow!("Redis PUBLISH 실패: connection reset"));
+        assert_eq!(opaque.code(), ErrorCode::Internal);
+        assert!(!opaque.user_message().contains("Redis"));
+    }
+}
@@ -39,12 +39,18 @@ pub struct AtomicStats {
     pub broadcast_time_us: AtomicU64,
     pub serialization_time_us: AtomicU64,
     
+    // 브로드캐스트 팬아웃 통계
+    pub total_broadcasts: AtomicU64,
+    pub broadcast_recipients: AtomicU64,
+    pub max_broadcast_fanout: AtomicU64,
+    
     // 방 관련 통계
     pub total_rooms: AtomicU64,
     pub active_rooms: AtomicU64,
     pub peak_rooms: AtomicU64,
     pub room_joins: AtomicU64,
     pub room_leaves: AtomicU64,
+    pub peak_room_users: AtomicU64,
     
     // 대역폭 통계 (바이트)
     pub bytes_sent: AtomicU64,
@@ -87,6 +93,16 @@ pub struct StatsSnapshot {
     pub avg_broadcast_time_ms: f64,
     pub avg_serialization_time_ms: f64,
     
+    // 브로드캐스트 팬아웃 통계 (이전 스냅샷과 호환되도록 기본값 허용)
+    #[serde(default)]
+    pub total_broadcasts: u64,
+    #[serde(default)]
+    pub broadcast_recipients: u64,
+    #[serde(default)]
+    pub avg_broadcast_fanout: f64,
+    #[serde(default)]
+    pub max_broadcast_fanout: u64,
+    
     // 방 통계
     pub total_rooms: u64,
     pub active_rooms: u64,
@@ -94,6 +110,8 @@ pub struct StatsSnapshot {
     pub room_joins: u64,
     pub room_leaves: u64,
     pub avg_users_per_room: f64,
+    #[serde(default)]
+    pub peak_room_users: u64,
     
     // 대역폭 통계
     pub bytes_sent: u64,
@@ -162,11 +180,16 @@ impl AtomicStats {
             broadcast_time_us: AtomicU64::new(0),
             serialization_time_us: AtomicU64::new(0),
             
+            total_broadcasts: AtomicU64::new(0),
+            broadcast_recipients: AtomicU64::new(0),
+            max_broadcast_fanout: AtomicU64::new(0),
+            
             total_rooms: AtomicU64::new(0),
             active_rooms: AtomicU64::new(0),
             peak_rooms: AtomicU64::new(0),
             room_joins: AtomicU64::new(0),
             room_leaves: AtomicU64::new(0),
+            peak_room_users: AtomicU64::new(0),
             
             bytes_sent: AtomicU64::new(0),
             bytes_received: AtomicU64::new(0),
@@ -248,7 +271,7 @@ impl AtomicStats {
         match message_type {
             "heartbeat" => self.heartbeat_messages.fetch_add(1, Ordering::Relaxed),
             "chat" => self.chat_messages.fetch_add(1, Ordering::Relaxed),
-            "room_join" | "room_leave" => self.room_messages.fetch_add(1, Ordering::Relaxed),
+            "room" | "room_join" | "room_leave" => self.room_messages.fetch_add(1, Ordering::Relaxed),
             "system" => self.system_messages.fetch_add(1, Ordering::Relaxed),
             "error" => self.error_messages.fetch_add(1, Ordering::Relaxed),
             _ => 0,
@@ -290,6 +313,19 @@ impl AtomicStats {
         }
     }
     
+    /// 브로드캐스트 팬아웃 기록
+    /// 
+    /// 한 번의 방 브로드캐스트에서 전송을 시도한 수신자 수와 실패 수를 기록합니다.
+    /// 실패 수는 브로드캐스트 에러에도 합산됩니다.
+    pub fn record_broadcast_fanout(&self, recipients: u64, failed: u64) {
+        self.total_broadcasts.fetch_add(1, Ordering::Relaxed);
+        self.broadcast_recipients.fetch_add(recipients, Ordering::Relaxed);
+        self.max_broadcast_fanout.fetch_max(recipients, Ordering::Relaxed);
+        if failed > 0 {
+            self.broadcast_errors.fetch_add(failed, Ordering::Relaxed);
+        }
+    }
+    
     /// 직렬화 처리 시간 기록
     pub fn record_serialization_time(&self, duration: Duration) {
         let serialization_us = duration.as_micros() as u64;
@@ -320,6 +356,11 @@ impl AtomicStats {
         self.room_leaves.fetch_add(1, Ordering::Relaxed);
     }
     
+    /// 방 인원 기록 (입장/퇴장 후 해당 방의 현재 인원)
+    pub fn record_room_occupancy(&self, users: u64) {
+        self.peak_room_users.fetch_max(users, Ordering::Relaxed);
+    }
+    
     /// 최대 방 수 업데이트
     fn update_peak_rooms(&self, current: u64) {
         let mut current_peak = self.peak_rooms.load(Ordering::Relaxed);
@@ -389,6 +430,8 @@ impl AtomicStats {
         let total_processing_us = self.total_processing_time_us.load(Ordering::Relaxed);
         let broadcast_time_us = self.broadcast_time_us.load(Ordering::Relaxed);
         let serialization_time_us = self.serialization_time_us.load(Ordering::Relaxed);
+        let total_broadcasts = self.total_broadcasts.load(Ordering::Relaxed);
+        let broadcast_recipients = self.broadcast_recipients.load(Ordering::Relaxed);
         
         let total_errors = self.connection_timeouts.load(Ordering::Relaxed)
             + self.protocol_errors.load(Ordering::Relaxed)
@@ -440,6 +483,16 @@ impl AtomicStats {
                 0.0
             },
             
+            // 브로드캐스트 팬아웃 통계
+            total_broadcasts,
+            broadcast_recipients,
+            avg_broadcast_fanout: if total_broadcasts > 0 {
+                broadcast_recipients as f64 / total_broadcasts as f64
+            } else {
+                0.0
+            },
+            max_broadcast_fanout: self.max_broadcast_fanout.load(Ordering::Relaxed),
+            
             // 방 통계
             total_rooms: self.total_rooms.load(Ordering::Relaxed),
             active_rooms,
@@ -451,6 +504,7 @@ impl AtomicStats {
             } else {
                 0.0
             },
+            peak_room_users: self.peak_room_users.load(Ordering::Relaxed),
             
             // 대역폭 통계
             bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
@@ -728,4 +782,24 @@ mod tests {
         assert_eq!(snapshot.room_joins, 1);
         assert_eq!(snapshot.room_leaves, 1);
     }
+    
+    #[test]
+    fn test_broadcast_fanout() {
+        let stats = AtomicStats::new();
+        
+        stats.record_broadcast_fanout(4, 0);
+        stats.record_broadcast_fanout(8, 2);
+        stats.record_room_occupancy(8);
+        stats.record_room_occupancy(3);
+        stats.record_message_processing("room", Duration::from_micros(50));
+        
+        let snapshot = stats.get_snapshot();
+        assert_eq!(snapshot.total_broadcasts, 2);
+        assert_eq!(snapshot.broadcast_recipients, 12);
+        assert_eq!(snapshot.avg_broadcast_fanout, 6.0);
+        assert_eq!(snapshot.max_broadcast_fanout, 8);
+        assert_eq!(snapshot.broadcast_errors, 2);
+        assert_eq!(snapshot.peak_room_users, 8);
+        assert_eq!(snapshot.room_messages, 1);
+    }
 }
\ No newline at end of file
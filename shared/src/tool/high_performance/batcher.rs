@@ -0,0 +1,321 @@
+//! 마이크로 배치 집계기
+//!
+//! Redis 쓰기, 브로드캐스트 인코딩, 메트릭 플러시처럼 작은 작업을 모아서 한 번에 처리하는
+//! 공통 프리미티브입니다. 항목은 다음 중 하나를 만족하면 비동기 flush 클로저로 넘어갑니다.
+//! - 항목 수가 `max_items`에 도달
+//! - 누적 크기가 `max_bytes`에 도달 (크기 함수를 지정한 경우)
+//! - 첫 항목이 들어온 뒤 `max_delay` 경과
+//!
+//! flush는 전용 작업자 태스크에서 순차로 실행되므로, flush가 느려지면 큐(`queue_capacity`)가
+//! 차고 `push`가 대기하는 방식으로 생산자에게 백프레셔가 전달됩니다.
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+/// 배치 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatcherConfig {
+    /// 배치당 최대 항목 수
+    pub max_items: usize,
+    /// 배치당 최대 크기 (바이트, 크기 함수가 있을 때만 적용)
+    pub max_bytes: usize,
+    /// 첫 항목 이후 최대 대기 시간
+    pub max_delay: Duration,
+    /// 대기 큐 용량 (초과 시 백프레셔)
+    pub queue_capacity: usize,
+}
+
+impl Default for BatcherConfig {
+    fn default() -> Self {
+        Self {
+            max_items: 128,
+            max_bytes: 64 * 1024,
+            max_delay: Duration::from_millis(5),
+            queue_capacity: 4096,
+        }
+    }
+}
+
+/// flush 사유
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlushReason {
+    Items,
+    Bytes,
+    Timer,
+    Shutdown,
+}
+
+#[derive(Debug, Default)]
+struct BatcherCounters {
+    items_pushed: AtomicU64,
+    items_flushed: AtomicU64,
+    batches: AtomicU64,
+    flush_by_items: AtomicU64,
+    flush_by_bytes: AtomicU64,
+    flush_by_timer: AtomicU64,
+    flush_by_shutdown: AtomicU64,
+    flush_time_us: AtomicU64,
+    max_flush_time_us: AtomicU64,
+    /// 큐가 가득 차서 대기한 push 수
+    backpressure_waits: AtomicU64,
+    /// `try_push`에서 거절된 수
+    rejected: AtomicU64,
+}
+
+/// 배치 통계 스냅샷
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BatcherStats {
+    pub items_pushed: u64,
+    pub items_flushed: u64,
+    pub batches: u64,
+    pub flush_by_items: u64,
+    pub flush_by_bytes: u64,
+    pub flush_by_timer: u64,
+    pub flush_by_shutdown: u64,
+    pub avg_batch_size: f64,
+    pub avg_flush_time_us: u64,
+    pub max_flush_time_us: u64,
+    pub backpressure_waits: u64,
+    pub rejected: u64,
+}
+
+/// 마이크로 배치 집계기
+pub struct Batcher<T> {
+    tx: mpsc::Sender<T>,
+    counters: Arc<BatcherCounters>,
+    worker: JoinHandle<()>,
+}
+
+impl<T: Send + 'static> Batcher<T> {
+    /// 항목 수/시간 기준 배치 (tokio 런타임 안에서 호출)
+    pub fn new<F, Fut>(config: BatcherConfig, flush: F) -> Self
+    where
+        F: FnMut(Vec<T>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self::with_sizer(config, |_| 0, flush)
+    }
+
+    /// 항목 크기 함수를 지정해 바이트 기준 flush도 사용
+    pub fn with_sizer<S, F, Fut>(config: BatcherConfig, sizer: S, flush: F) -> Self
+    where
+        S: Fn(&T) -> usize + Send + 'static,
+        F: FnMut(Vec<T>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel(config.queue_capacity.max(1));
+        let counters = Arc::new(BatcherCounters::default());
+        let worker = tokio::spawn(run_worker(config, rx, sizer, flush, counters.clone()));
+        Self { tx, counters, worker }
+    }
+
+    /// 항목 추가 (큐가 가득 차면 자리가 날 때까지 대기)
+    pub async fn push(&self, item: T) -> Result<()> {
+        let item = match self.tx.try_send(item) {
+            Ok(()) => {
+                self.counters.items_pushed.fetch_add(1, Ordering::Relaxed);
+                return Ok(());
+            }
+            Err(TrySendError::Closed(_)) => return Err(anyhow!("batcher closed")),
+            Err(TrySendError::Full(item)) => item,
+        };
+
+        self.counters.backpressure_waits.fetch_add(1, Ordering::Relaxed);
+        self.tx.send(item).await.map_err(|_| anyhow!("batcher closed"))?;
+        self.counters.items_pushed.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// 대기 없이 항목 추가 (큐가 가득 차면 항목을 돌려줌)
+    pub fn try_push(&self, item: T) -> std::result::Result<(), T> {
+        match self.tx.try_send(item) {
+            Ok(()) => {
+                self.counters.items_pushed.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+            Err(TrySendError::Full(item)) | Err(TrySendError::Closed(item)) => {
+                self.counters.rejected.fetch_add(1, Ordering::Relaxed);
+                Err(item)
+            }
+        }
+    }
+
+    /// 큐에 대기 중인 항목 수
+    pub fn queued(&self) -> usize {
+        self.tx.max_capacity() - self.tx.capacity()
+    }
+
+    pub fn stats(&self) -> BatcherStats {
+        self.counters.snapshot()
+    }
+
+    /// 입력을 닫고 남은 항목을 flush한 뒤 종료
+    pub async fn shutdown(self) -> BatcherStats {
+        let Self { tx, counters, worker } = self;
+        drop(tx);
+        if let Err(e) = worker.await {
+            debug!("배치 작업자 종료 실패: {}", e);
+        }
+        counters.snapshot()
+    }
+}
+
+impl BatcherCounters {
+    fn snapshot(&self) -> BatcherStats {
+        let c = self;
+        let batches = c.batches.load(Ordering::Relaxed);
+        let items_flushed = c.items_flushed.load(Ordering::Relaxed);
+        BatcherStats {
+            items_pushed: c.items_pushed.load(Ordering::Relaxed),
+            items_flushed,
+            batches,
+            flush_by_items: c.flush_by_items.load(Ordering::Relaxed),
+            flush_by_bytes: c.flush_by_bytes.load(Ordering::Relaxed),
+            flush_by_timer: c.flush_by_timer.load(Ordering::Relaxed),
+            flush_by_shutdown: c.flush_by_shutdown.load(Ordering::Relaxed),
+            avg_batch_size: if batches == 0 { 0.0 } else { items_flushed as f64 / batches as f64 },
+            avg_flush_time_us: c.flush_time_us.load(Ordering::Relaxed).checked_div(batches).unwrap_or(0),
+            max_flush_time_us: c.max_flush_time_us.load(Ordering::Relaxed),
+            backpressure_waits: c.backpressure_waits.load(Ordering::Relaxed),
+            rejected: c.rejected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+async fn run_worker<T, S, F, Fut>(
+    config: BatcherConfig,
+    mut rx: mpsc::Receiver<T>,
+    sizer: S,
+    mut flush: F,
+    counters: Arc<BatcherCounters>,
+) where
+    S: Fn(&T) -> usize,
+    F: FnMut(Vec<T>) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let max_items = config.max_items.max(1);
+    let mut closed = false;
+
+    while !closed {
+        let Some(first) = rx.recv().await else { break };
+        let deadline = tokio::time::Instant::now() + config.max_delay;
+        let mut bytes = sizer(&first);
+        let mut batch = Vec::with_capacity(max_items);
+        batch.push(first);
+
+        let reason = loop {
+            if batch.len() >= max_items {
+                break FlushReason::Items;
+            }
+            if bytes > 0 && bytes >= config.max_bytes {
+                break FlushReason::Bytes;
+            }
+            match tokio::time::timeout_at(deadline, rx.recv()).await {
+                Ok(Some(item)) => {
+                    bytes += sizer(&item);
+                    batch.push(item);
+                }
+                Ok(None) => {
+                    closed = true;
+                    break FlushReason::Shutdown;
+                }
+                Err(_) => break FlushReason::Timer,
+            }
+        };
+
+        let size = batch.len() as u64;
+        let started = Instant::now();
+        flush(batch).await;
+        let elapsed_us = started.elapsed().as_micros() as u64;
+
+        counters.batches.fetch_add(1, Ordering::Relaxed);
+        counters.items_flushed.fetch_add(size, Ordering::Relaxed);
+        counters.flush_time_us.fetch_add(elapsed_us, Ordering::Relaxed);
+        counters.max_flush_time_us.fetch_max(elapsed_us, Ordering::Relaxed);
+        match reason {
+            FlushReason::Items => &counters.flush_by_items,
+            FlushReason::Bytes => &counters.flush_by_bytes,
+            FlushReason::Timer => &counters.flush_by_timer,
+            FlushReason::Shutdown => &counters.flush_by_shutdown,
+        }
+        .fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn collector() -> (Arc<Mutex<Vec<Vec<u32>>>>, impl FnMut(Vec<u32>) -> std::future::Ready<()>) {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let sink = batches.clone();
+        (batches, move |batch| {
+            sink.lock().unwrap().push(batch);
+            std::future::ready(())
+        })
+    }
+
+    #[tokio::test]
+    async fn test_flush_by_items_and_shutdown() {
+        let (batches, flush) = collector();
+        let config = BatcherConfig { max_items: 3, max_delay: Duration::from_secs(60), ..Default::default() };
+        let batcher = Batcher::new(config, flush);
+        for i in 0..7 {
+            batcher.push(i).await.unwrap();
+        }
+
+        let stats = batcher.shutdown().await;
+        assert_eq!(*batches.lock().unwrap(), vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!((stats.flush_by_items, stats.flush_by_shutdown), (2, 1));
+        assert_eq!(stats.items_flushed, 7);
+    }
+
+    #[tokio::test]
+    async fn test_flush_by_timer_and_bytes() {
+        let (batches, flush) = collector();
+        let config = BatcherConfig { max_bytes: 10, max_delay: Duration::from_millis(20), ..Default::default() };
+        let batcher = Batcher::with_sizer(config, |n: &u32| *n as usize, flush);
+
+        batcher.push(6).await.unwrap();
+        batcher.push(5).await.unwrap();
+        batcher.push(1).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(*batches.lock().unwrap(), vec![vec![6, 5], vec![1]]);
+        let stats = batcher.stats();
+        assert_eq!((stats.flush_by_bytes, stats.flush_by_timer), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn test_backpressure_when_flush_is_slow() {
+        let config = BatcherConfig { max_items: 1, queue_capacity: 1, ..Default::default() };
+        let (release_tx, release_rx) = tokio::sync::watch::channel(false);
+        let batcher = Batcher::new(config, move |_batch: Vec<u32>| {
+            let mut release = release_rx.clone();
+            async move {
+                let _ = release.wait_for(|released| *released).await;
+            }
+        });
+
+        batcher.push(1).await.unwrap();
+        // 작업자가 첫 항목을 가져가 flush에서 막힐 때까지 대기
+        while batcher.queued() > 0 {
+            tokio::task::yield_now().await;
+        }
+        batcher.push(2).await.unwrap();
+        assert_eq!(batcher.try_push(3), Err(3));
+
+        release_tx.send(true).unwrap();
+        let stats = batcher.shutdown().await;
+        assert_eq!((stats.items_flushed, stats.rejected), (2, 1));
+    }
+}
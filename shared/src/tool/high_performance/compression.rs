@@ -307,13 +307,8 @@ impl CompressionUtils {
     
     /// 압축 캐시 키 생성
     pub fn generate_cache_key(data: &[u8], algorithm: CompressionAlgorithm) -> u64 {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        
-        let mut hasher = DefaultHasher::new();
-        data.hash(&mut hasher);
-        algorithm.hash(&mut hasher);
-        hasher.finish()
+        // 알고리즘을 시드로 사용해 같은 데이터라도 알고리즘별로 다른 키
+        crate::tool::high_performance::simd_optimizer::xxhash64(data, algorithm as u64)
     }
 }
 
@@ -0,0 +1,276 @@
+//! 풀 대여 추적과 누수 감지
+//!
+//! 풀에서 빌린 객체를 반환하지 않으면 풀이 계속 새로 할당하게 되므로,
+//! 대여마다 [`Lease`]를 붙여 반환 전까지 미반환 목록에 남깁니다.
+//! - 디버그 빌드에서는 대여 위치(호출한 파일:줄)를 기록하고
+//! - 미반환 대여 수, 최고 수위(high-water mark), 대여 경과 시간 분포를 집계하며
+//! - `spawn_leak_reporter`가 주기적으로 오래된 대여를 위치별로 묶어 경고합니다.
+//!
+//! 반환하지 않고 버린 객체는 `dropped_without_return`으로 집계합니다.
+//! `MetricsCollector`를 연결하면 `{풀 이름}_leases_*` 게이지도 갱신합니다.
+
+use crate::tool::high_performance::MetricsCollector;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::panic::Location;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// 알림 로그 타깃
+const ALERT_TARGET: &str = "pool_leak_alert";
+
+/// 대여 경과 시간 버킷 상한 (초, 마지막 버킷은 +Inf)
+pub const LEASE_AGE_BUCKETS_SECS: [u64; 4] = [1, 10, 60, 600];
+
+/// 릴리스 빌드에서 대여 위치 대신 쓰는 표시
+const UNKNOWN_SITE: &str = "unknown (release build)";
+
+struct LeaseRecord {
+    acquired_at: Instant,
+    site: Option<&'static Location<'static>>,
+}
+
+/// 대여 통계
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LeaseStats {
+    pub pool: String,
+    /// 현재 미반환 대여
+    pub outstanding: usize,
+    /// 미반환 대여 최고 수위
+    pub high_water: usize,
+    pub acquired_total: u64,
+    pub returned_total: u64,
+    /// 반환하지 않고 버린 객체
+    pub dropped_without_return: u64,
+    /// 버킷별 미반환 대여 수 (`LEASE_AGE_BUCKETS_SECS` 순서, 마지막은 +Inf)
+    pub age_histogram: Vec<usize>,
+    pub oldest: Option<Duration>,
+}
+
+/// 누수 의심 대여 (대여 위치별)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LeakSuspect {
+    pub site: String,
+    pub count: usize,
+    pub oldest: Duration,
+}
+
+/// 풀 대여 추적기 (풀마다 하나)
+pub struct LeaseTracker {
+    pool: String,
+    next_id: AtomicU64,
+    outstanding: Mutex<HashMap<u64, LeaseRecord>>,
+    high_water: AtomicUsize,
+    acquired: AtomicU64,
+    returned: AtomicU64,
+    dropped: AtomicU64,
+    metrics: Mutex<Option<Arc<MetricsCollector>>>,
+}
+
+impl std::fmt::Debug for LeaseTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LeaseTracker")
+            .field("pool", &self.pool)
+            .field("outstanding", &self.outstanding.lock().len())
+            .finish()
+    }
+}
+
+impl LeaseTracker {
+    pub fn new(pool: impl Into<String>) -> Arc<Self> {
+        Arc::new(Self {
+            pool: pool.into(),
+            next_id: AtomicU64::new(1),
+            outstanding: Mutex::new(HashMap::new()),
+            high_water: AtomicUsize::new(0),
+            acquired: AtomicU64::new(0),
+            returned: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            metrics: Mutex::new(None),
+        })
+    }
+
+    /// 리포트 때 `{풀 이름}_leases_*` 게이지 갱신
+    pub fn set_metrics(&self, metrics: Arc<MetricsCollector>) {
+        *self.metrics.lock() = Some(metrics);
+    }
+
+    /// 대여 시작 (디버그 빌드에서는 호출 위치 기록)
+    #[track_caller]
+    pub fn acquire(self: &Arc<Self>) -> Lease {
+        let site = if cfg!(debug_assertions) { Some(Location::caller()) } else { None };
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let outstanding = {
+            let mut outstanding = self.outstanding.lock();
+            outstanding.insert(id, LeaseRecord { acquired_at: Instant::now(), site });
+            outstanding.len()
+        };
+        self.acquired.fetch_add(1, Ordering::Relaxed);
+        self.high_water.fetch_max(outstanding, Ordering::Relaxed);
+        Lease { id, tracker: self.clone(), returned: false }
+    }
+
+    fn finish(&self, id: u64, returned: bool) {
+        self.outstanding.lock().remove(&id);
+        let counter = if returned { &self.returned } else { &self.dropped };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// 현재 대여 통계
+    pub fn stats(&self) -> LeaseStats {
+        let now = Instant::now();
+        let mut age_histogram = vec![0; LEASE_AGE_BUCKETS_SECS.len() + 1];
+        let mut oldest = None;
+        let outstanding = {
+            let outstanding = self.outstanding.lock();
+            for record in outstanding.values() {
+                let age = now.duration_since(record.acquired_at);
+                let secs = age.as_secs();
+                let bucket = LEASE_AGE_BUCKETS_SECS.iter().position(|upper| secs < *upper).unwrap_or(LEASE_AGE_BUCKETS_SECS.len());
+                age_histogram[bucket] += 1;
+                oldest = oldest.max(Some(age));
+            }
+            outstanding.len()
+        };
+        LeaseStats {
+            pool: self.pool.clone(),
+            outstanding,
+            high_water: self.high_water.load(Ordering::Relaxed),
+            acquired_total: self.acquired.load(Ordering::Relaxed),
+            returned_total: self.returned.load(Ordering::Relaxed),
+            dropped_without_return: self.dropped.load(Ordering::Relaxed),
+            age_histogram,
+            oldest,
+        }
+    }
+
+    /// `threshold`보다 오래된 미반환 대여를 위치별로 묶어 반환 (많은 순)
+    pub fn leak_report(&self, threshold: Duration) -> Vec<LeakSuspect> {
+        let now = Instant::now();
+        let mut by_site: BTreeMap<String, LeakSuspect> = BTreeMap::new();
+        for record in self.outstanding.lock().values() {
+            let age = now.duration_since(record.acquired_at);
+            if age < threshold {
+                continue;
+            }
+            let site = record
+                .site
+                .map_or_else(|| UNKNOWN_SITE.to_string(), |site| format!("{}:{}", site.file(), site.line()));
+            let suspect = by_site
+                .entry(site.clone())
+                .or_insert(LeakSuspect { site, count: 0, oldest: Duration::ZERO });
+            suspect.count += 1;
+            suspect.oldest = suspect.oldest.max(age);
+        }
+        let mut suspects: Vec<LeakSuspect> = by_site.into_values().collect();
+        suspects.sort_by(|a, b| b.count.cmp(&a.count).then(b.oldest.cmp(&a.oldest)));
+        suspects
+    }
+
+    /// 누수 리포트를 경고로 남기고 메트릭 갱신
+    pub fn report(&self, threshold: Duration) -> Vec<LeakSuspect> {
+        let suspects = self.leak_report(threshold);
+        for suspect in &suspects {
+            warn!(
+                target: ALERT_TARGET,
+                pool = %self.pool,
+                count = suspect.count,
+                oldest_secs = suspect.oldest.as_secs(),
+                acquired_at = %suspect.site,
+                "풀 객체 장시간 미반환 (누수 의심)"
+            );
+        }
+
+        if let Some(metrics) = self.metrics.lock().clone() {
+            let stats = self.stats();
+            let gauge = |name: &str, value: f64| metrics.set_gauge(&format!("{}_leases_{}", self.pool, name), value, HashMap::new());
+            gauge("outstanding", stats.outstanding as f64);
+            gauge("high_water", stats.high_water as f64);
+            gauge("dropped", stats.dropped_without_return as f64);
+            gauge("oldest_seconds", stats.oldest.unwrap_or_default().as_secs_f64());
+            gauge("suspected_leaks", suspects.iter().map(|s| s.count).sum::<usize>() as f64);
+        }
+        suspects
+    }
+
+    /// 주기적 누수 리포트 태스크 시작
+    pub fn spawn_leak_reporter(self: &Arc<Self>, interval: Duration, threshold: Duration) -> JoinHandle<()> {
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                tracker.report(threshold);
+            }
+        })
+    }
+}
+
+/// 대여 표식 (풀에 반환하면 `complete`, 그냥 버리면 미반환으로 집계)
+pub struct Lease {
+    id: u64,
+    tracker: Arc<LeaseTracker>,
+    returned: bool,
+}
+
+impl Lease {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 풀에 반환됨
+    pub fn complete(mut self) {
+        self.returned = true;
+    }
+}
+
+impl std::fmt::Debug for Lease {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lease").field("id", &self.id).field("pool", &self.tracker.pool).finish()
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        self.tracker.finish(self.id, self.returned);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lease_lifecycle_and_leak_report() {
+        let tracker = LeaseTracker::new("test_pool");
+        let returned = tracker.acquire();
+        let leaked = [tracker.acquire(), tracker.acquire()];
+        let dropped = tracker.acquire();
+
+        returned.complete();
+        drop(dropped);
+
+        let stats = tracker.stats();
+        assert_eq!(stats.outstanding, 2);
+        assert_eq!(stats.high_water, 4);
+        assert_eq!(stats.acquired_total, 4);
+        assert_eq!(stats.returned_total, 1);
+        assert_eq!(stats.dropped_without_return, 1);
+        assert_eq!(stats.age_histogram[0], 2);
+
+        assert!(tracker.leak_report(Duration::from_secs(60)).is_empty());
+        let suspects = tracker.report(Duration::ZERO);
+        assert_eq!(suspects.len(), 1);
+        assert_eq!(suspects[0].count, 2);
+        // 디버그 빌드에서는 대여 위치 기록
+        assert!(suspects[0].site.contains("lease_tracker.rs"), "{}", suspects[0].site);
+
+        drop(leaked);
+        assert_eq!(tracker.stats().outstanding, 0);
+        assert_eq!(tracker.stats().high_water, 4);
+    }
+}
@@ -5,6 +5,7 @@
 //! - 버퍼 재사용
 //! - 메모리 단편화 방지
 //! - 할당/해제 오버헤드 최소화
+//! - 버퍼 대여 추적 (`track_leases`, 미반환 버퍼 감지)
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
@@ -16,6 +17,8 @@ use tracing::{debug, info};
 use serde::{Serialize, Deserialize};
 use crossbeam_queue::SegQueue;
 
+use super::lease_tracker::{Lease, LeaseTracker};
+
 /// 메모리 풀 통계
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct MemoryPoolStats {
@@ -89,6 +92,8 @@ pub struct BufferPoolConfig {
     pub cleanup_interval_secs: u64,
     /// 유휴 시간 임계값 (초)
     pub idle_threshold_secs: u64,
+    /// 대여 추적 (미반환 버퍼 감지)
+    pub track_leases: bool,
 }
 
 impl Default for BufferPoolConfig {
@@ -99,6 +104,7 @@ impl Default for BufferPoolConfig {
             max_buffer_size: 65536,        // 64KB
             cleanup_interval_secs: 300,    // 5분
             idle_threshold_secs: 600,      // 10분
+            track_leases: true,
         }
     }
 }
@@ -109,6 +115,8 @@ pub struct PooledBuffer {
     data: Vec<u8>,
     last_used: std::time::Instant,
     total_reuses: u64,
+    /// 대여 중인 동안의 대여 표식
+    lease: Option<Lease>,
 }
 
 impl PooledBuffer {
@@ -117,6 +125,7 @@ impl PooledBuffer {
             data: Vec::with_capacity(initial_size),
             last_used: std::time::Instant::now(),
             total_reuses: 0,
+            lease: None,
         }
     }
     
@@ -146,6 +155,7 @@ pub struct BufferPool {
     config: BufferPoolConfig,
     stats: Arc<MemoryPoolStats>,
     current_size: AtomicUsize,
+    leases: Option<Arc<LeaseTracker>>,
 }
 
 impl BufferPool {
@@ -155,14 +165,29 @@ impl BufferPool {
         
         Self {
             pool: SegQueue::new(),
+            leases: config.track_leases.then(|| LeaseTracker::new("buffer_pool")),
             config,
             stats: Arc::new(MemoryPoolStats::new()),
             current_size: AtomicUsize::new(0),
         }
     }
     
-    /// 버퍼 대여
+    /// 버퍼 대여 (대여 추적 시 호출 위치 기록)
+    #[track_caller]
     pub fn rent(&self) -> PooledBuffer {
+        let mut buffer = self.take_buffer();
+        if let Some(leases) = &self.leases {
+            buffer.lease = Some(leases.acquire());
+        }
+        buffer
+    }
+    
+    /// 대여 추적기 (`track_leases`가 꺼져 있으면 None)
+    pub fn leases(&self) -> Option<&Arc<LeaseTracker>> {
+        self.leases.as_ref()
+    }
+    
+    fn take_buffer(&self) -> PooledBuffer {
         // 풀에서 재사용 가능한 버퍼 찾기
         if let Some(mut buffer) = self.pool.pop() {
             buffer.reset();
@@ -181,7 +206,10 @@ impl BufferPool {
     }
     
     /// 버퍼 반환
-    pub fn return_buffer(&self, buffer: PooledBuffer) {
+    pub fn return_buffer(&self, mut buffer: PooledBuffer) {
+        if let Some(lease) = buffer.lease.take() {
+            lease.complete();
+        }
         let current_pool_size = self.current_size.load(Ordering::Relaxed);
         
         // 풀이 가득 찬 경우 버퍼 폐기
@@ -459,6 +487,22 @@ mod tests {
         assert!(info.hit_rate_percent > 0.0);
     }
     
+    #[test]
+    fn test_buffer_pool_lease_tracking() {
+        let pool = BufferPool::new(BufferPoolConfig::default());
+        let buffers: Vec<_> = (0..3).map(|_| pool.rent()).collect();
+        let leases = pool.leases().unwrap();
+        assert_eq!(leases.stats().outstanding, 3);
+        
+        for buffer in buffers {
+            pool.return_buffer(buffer);
+        }
+        let stats = leases.stats();
+        assert_eq!(stats.outstanding, 0);
+        assert_eq!(stats.high_water, 3);
+        assert_eq!(stats.returned_total, 3);
+    }
+    
     #[tokio::test]
     async fn test_buffer_pool_cleanup() {
         let config = BufferPoolConfig {
@@ -151,14 +151,19 @@ impl MetricsCollector {
     
     /// 카운터 메트릭 증가
     pub fn increment_counter(&self, name: &str, labels: std::collections::HashMap<String, String>) {
+        self.add_counter(name, 1, labels);
+    }
+    
+    /// 카운터 메트릭을 지정한 값만큼 증가
+    pub fn add_counter(&self, name: &str, delta: u64, labels: std::collections::HashMap<String, String>) {
         let current_value = if let Some(entry) = self.metrics.get(name) {
             if let MetricValue::Counter(count) = entry.value {
-                count + 1
+                count + delta
             } else {
-                1
+                delta
             }
         } else {
-            1
+            delta
         };
         
         let metric = MetricEntry {
@@ -247,6 +252,29 @@ impl MetricsCollector {
         self.observe_histogram("response_time_seconds", response_time.as_secs_f64(), buckets, std::collections::HashMap::new());
     }
     
+    /// 방 브로드캐스트 기록
+    /// 
+    /// `broadcasts_total`, `broadcast_recipients_total`, `broadcast_failures_total` 카운터와
+    /// `broadcast_fanout`, `broadcast_duration_seconds` 히스토그램을 갱신합니다.
+    pub fn record_broadcast(&self, recipients: usize, failed: usize, duration: Duration) {
+        self.increment_counter("broadcasts_total", std::collections::HashMap::new());
+        self.add_counter("broadcast_recipients_total", recipients as u64, std::collections::HashMap::new());
+        if failed > 0 {
+            self.add_counter("broadcast_failures_total", failed as u64, std::collections::HashMap::new());
+        }
+        
+        let fanout_buckets = vec![1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0];
+        self.observe_histogram("broadcast_fanout", recipients as f64, fanout_buckets, std::collections::HashMap::new());
+        let duration_buckets = vec![0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1];
+        self.observe_histogram("broadcast_duration_seconds", duration.as_secs_f64(), duration_buckets, std::collections::HashMap::new());
+    }
+    
+    /// 방 통계 업데이트 (`active_rooms`, `room_users` 게이지)
+    pub fn set_room_stats(&self, active_rooms: usize, room_users: usize) {
+        self.set_gauge("active_rooms", active_rooms as f64, std::collections::HashMap::new());
+        self.set_gauge("room_users", room_users as f64, std::collections::HashMap::new());
+    }
+    
     /// 연결 수 업데이트
     pub fn set_active_connections(&self, count: usize) {
         self.active_connections.store(count, Ordering::Relaxed);
@@ -520,6 +548,24 @@ mod tests {
         assert!(prometheus_output.contains("test_memory_usage_bytes 1024"));
     }
     
+    #[tokio::test]
+    async fn test_broadcast_and_room_metrics() {
+        let collector = MetricsCollector::with_default_config();
+        
+        collector.record_broadcast(4, 0, Duration::from_micros(300));
+        collector.record_broadcast(6, 1, Duration::from_micros(700));
+        collector.set_room_stats(3, 10);
+        
+        assert!(matches!(collector.get_metric("broadcasts_total").unwrap().value, MetricValue::Counter(2)));
+        assert!(matches!(collector.get_metric("broadcast_recipients_total").unwrap().value, MetricValue::Counter(10)));
+        assert!(matches!(collector.get_metric("broadcast_failures_total").unwrap().value, MetricValue::Counter(1)));
+        assert!(matches!(
+            collector.get_metric("broadcast_fanout").unwrap().value,
+            MetricValue::Histogram { count: 2, sum, .. } if (sum - 10.0).abs() < f64::EPSILON
+        ));
+        assert!(matches!(collector.get_metric("room_users").unwrap().value, MetricValue::Gauge(val) if (val - 10.0).abs() < f64::EPSILON));
+    }
+    
     #[test]
     fn test_performance_summary() {
         let collector = MetricsCollector::with_default_config();
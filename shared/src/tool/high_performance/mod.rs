@@ -12,13 +12,18 @@ pub mod simd_optimizer;
 pub mod compression;
 pub mod parallel_processing;
 pub mod redis_optimizer;
+pub mod redis_degradation;
 pub mod metrics_collector;
 pub mod blocking_task_executor;
 pub mod lock_free_primitives;
 pub mod network_optimization;
+pub mod batcher;
+pub mod lease_tracker;
 
 pub use async_task_scheduler::{AsyncTaskScheduler, TaskPriority};
 pub use atomic_stats::*;
+pub use batcher::{Batcher, BatcherConfig, BatcherStats, FlushReason};
+pub use lease_tracker::{Lease, LeaseStats, LeaseTracker, LeakSuspect};
 pub use blocking_task_executor::*;
 pub use compression::*;
 pub use dashmap_optimizer::*;
@@ -28,6 +33,7 @@ pub use lock_free_primitives::*;
 pub use memory_pool::*;
 pub use metrics_collector::*;
 pub use network_optimization::*;
-pub use parallel_processing::ParallelProcessingConfig;
+pub use parallel_processing::{CpuTaskPool, ParallelProcessingConfig};
 pub use redis_optimizer::*;
+pub use redis_degradation::RedisDegradationStatus;
 pub use simd_optimizer::*;
@@ -139,11 +139,11 @@ impl NetworkOptimizer {
         stream: &TcpStream,
         config: &NetworkOptimizationConfig,
     ) -> io::Result<()> {
-        use std::os::windows::io::{AsRawSocket, FromRawSocket};
+        use std::os::unix::io::{AsRawFd, FromRawFd};
         use socket2::Socket;
         
         let socket = unsafe {
-            Socket::from_raw_socket(stream.as_raw_socket())
+            Socket::from_raw_fd(stream.as_raw_fd())
         };
         
         // TCP_NODELAY
@@ -3,8 +3,9 @@
 //! tcpserver와 rudpserver에서 공통 사용되는 병렬 처리 최적화 기능을 제공합니다.
 //! Rayon 기반 병렬 브로드캐스트와 작업 분산 처리를 지원합니다.
 
+use anyhow::{anyhow, Result};
 use serde::{Serialize, Deserialize};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
@@ -452,6 +453,76 @@ impl WorkScheduler {
     }
 }
 
+/// CPU 집약 작업 풀
+///
+/// 요청 처리 중 CPU를 오래 쓰는 작업(이미지 검증, 대량 집계 등)을 async 런타임 워커 대신
+/// 전용 rayon 스레드 풀(`worker_threads`)에서 실행합니다. 대기 작업이 `work_queue_size`를
+/// 넘으면 바로 거부해 요청이 무한정 쌓이지 않게 합니다.
+pub struct CpuTaskPool {
+    pool: rayon::ThreadPool,
+    pending: Arc<AtomicUsize>,
+    max_pending: usize,
+    stats: Arc<WorkerStats>,
+}
+
+impl CpuTaskPool {
+    pub fn new(config: &ParallelProcessingConfig) -> Result<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(config.worker_threads.max(1))
+            .thread_name(|index| format!("cpu-task-{index}"))
+            .build()
+            .map_err(|e| anyhow!("CPU 작업 풀 생성 실패: {e}"))?;
+        Ok(Self {
+            pool,
+            pending: Arc::new(AtomicUsize::new(0)),
+            max_pending: config.work_queue_size.max(1),
+            stats: Arc::new(WorkerStats::new()),
+        })
+    }
+
+    /// 작업 실행 후 결과 대기
+    ///
+    /// 대기열이 가득 찼거나 작업이 패닉하면 에러를 반환합니다.
+    pub async fn run<F, T>(&self, task: F) -> Result<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        if self.pending.fetch_add(1, Ordering::AcqRel) >= self.max_pending {
+            self.pending.fetch_sub(1, Ordering::AcqRel);
+            self.stats.record_queue_overflow();
+            return Err(anyhow!("CPU 작업 대기열이 가득 찼습니다 ({}개)", self.max_pending));
+        }
+
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let pending = self.pending.clone();
+        let stats = self.stats.clone();
+        self.pool.spawn(move || {
+            let start = Instant::now();
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(task));
+            stats.record_task_processed(start.elapsed());
+            pending.fetch_sub(1, Ordering::AcqRel);
+            let _ = tx.send(result);
+        });
+
+        match rx.await {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(_)) => Err(anyhow!("CPU 작업 실행 중 패닉 발생")),
+            Err(_) => Err(anyhow!("CPU 작업 결과를 받지 못했습니다")),
+        }
+    }
+
+    /// 실행 중이거나 대기 중인 작업 수
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::Acquire)
+    }
+
+    /// 처리 통계
+    pub fn stats(&self) -> Arc<WorkerStats> {
+        self.stats.clone()
+    }
+}
+
 /// 병렬 처리 유틸리티
 pub struct ParallelUtils;
 
@@ -630,4 +701,16 @@ mod tests {
         let efficiency = broadcaster.calculate_efficiency();
         assert!(efficiency >= 0.0 && efficiency <= 100.0);
     }
+
+    #[tokio::test]
+    async fn test_cpu_task_pool() {
+        let config = ParallelProcessingConfig { worker_threads: 2, work_queue_size: 4, ..Default::default() };
+        let pool = CpuTaskPool::new(&config).unwrap();
+
+        let sum = pool.run(|| (1..=1000u64).sum::<u64>()).await.unwrap();
+        assert_eq!(sum, 500_500);
+        assert!(pool.run(|| -> u64 { panic!("boom") }).await.is_err());
+        assert_eq!(pool.pending(), 0);
+        assert_eq!(pool.stats().processed_tasks.load(Ordering::Relaxed), 2);
+    }
 }
\ No newline at end of file
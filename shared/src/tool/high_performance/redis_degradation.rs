@@ -0,0 +1,223 @@
+//! Redis 장애 시 성능 저하 모드 상태
+//!
+//! Redis 연결 오류로 재시도가 모두 실패하면 [`RedisOptimizer`](super::redis_optimizer::RedisOptimizer)가
+//! 성능 저하 모드로 전환합니다.
+//! - 읽기: L1 캐시에서 제공 (TTL이 지난 값도 사용, 캐시에 없으면 오류)
+//! - 멱등 쓰기(SET/DEL/HSET/HDEL/ZADD/ZREM/EXPIRE): 제한된 로컬 버퍼에 쌓았다가 재연결 시 순서대로 재실행
+//! - 버퍼가 가득 차면 새 쓰기는 거부
+//!
+//! 성능 저하 중에는 재시도 없이 바로 실패하고, `probe_interval`마다 PING으로 복구를 확인합니다.
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use super::redis_optimizer::BatchOperation;
+
+/// 성능 저하 상태 (헬스 체크/운영 확인용)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RedisDegradationStatus {
+    pub degraded: bool,
+    /// 성능 저하 지속 시간 (초)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub degraded_for_secs: Option<u64>,
+    /// 재실행 대기 중인 쓰기
+    pub queued_writes: usize,
+    pub buffer_capacity: usize,
+    /// 버퍼가 가득 차 거부된 쓰기 (누적)
+    pub dropped_writes: u64,
+    /// 재연결 후 재실행한 쓰기 (누적)
+    pub replayed_writes: u64,
+    /// L1 캐시로 제공한 읽기 (누적)
+    pub stale_reads: u64,
+    /// 성능 저하 전환 횟수 (누적)
+    pub outages: u64,
+}
+
+impl RedisDegradationStatus {
+    /// 성능 저하 중이지만 쓰기를 계속 받을 수 있는지
+    pub fn accepting_writes(&self) -> bool {
+        self.queued_writes < self.buffer_capacity
+    }
+
+    /// 헬스 리포트용 요약
+    pub fn summary(&self) -> String {
+        format!(
+            "Redis 성능 저하 모드 {}초째: 대기 쓰기 {}/{}, 거부 {}, 캐시 읽기 {}",
+            self.degraded_for_secs.unwrap_or_default(),
+            self.queued_writes,
+            self.buffer_capacity,
+            self.dropped_writes,
+            self.stale_reads
+        )
+    }
+}
+
+/// 성능 저하 모드 상태와 쓰기 버퍼
+#[derive(Debug)]
+pub(crate) struct DegradationState {
+    degraded: AtomicBool,
+    since: Mutex<Option<Instant>>,
+    last_probe: Mutex<Option<Instant>>,
+    probe_interval: Duration,
+    /// 0이면 성능 저하 모드 비활성화
+    capacity: usize,
+    buffer: tokio::sync::Mutex<VecDeque<BatchOperation>>,
+    queued: AtomicUsize,
+    dropped: AtomicU64,
+    replayed: AtomicU64,
+    stale_reads: AtomicU64,
+    outages: AtomicU64,
+}
+
+impl DegradationState {
+    pub(crate) fn new(capacity: usize, probe_interval: Duration) -> Self {
+        Self {
+            degraded: AtomicBool::new(false),
+            since: Mutex::new(None),
+            last_probe: Mutex::new(None),
+            probe_interval,
+            capacity,
+            buffer: tokio::sync::Mutex::new(VecDeque::new()),
+            queued: AtomicUsize::new(0),
+            dropped: AtomicU64::new(0),
+            replayed: AtomicU64::new(0),
+            stale_reads: AtomicU64::new(0),
+            outages: AtomicU64::new(0),
+        }
+    }
+
+    pub(crate) fn is_degraded(&self) -> bool {
+        self.degraded.load(Ordering::Acquire)
+    }
+
+    /// 성능 저하 모드 진입 (이미 저하 중이거나 비활성화면 false)
+    pub(crate) fn enter(&self) -> bool {
+        if self.capacity == 0 || self.degraded.swap(true, Ordering::AcqRel) {
+            return false;
+        }
+        let now = Instant::now();
+        *self.since.lock() = Some(now);
+        *self.last_probe.lock() = Some(now);
+        self.outages.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+
+    /// 복구 확인 시점이면 true (확인 시각 갱신)
+    pub(crate) fn probe_due(&self) -> bool {
+        let mut last_probe = self.last_probe.lock();
+        if last_probe.is_some_and(|at| at.elapsed() < self.probe_interval) {
+            return false;
+        }
+        *last_probe = Some(Instant::now());
+        true
+    }
+
+    /// 쓰기 버퍼에 추가 (이미 복구됐으면 `Ok(false)`, 버퍼가 가득 차면 오류)
+    pub(crate) async fn enqueue(&self, operation: BatchOperation) -> anyhow::Result<bool> {
+        let mut buffer = self.buffer.lock().await;
+        if !self.is_degraded() {
+            return Ok(false);
+        }
+        if buffer.len() >= self.capacity {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return Err(anyhow::anyhow!(
+                "Redis 성능 저하 모드: 쓰기 버퍼 가득 참 ({}개)",
+                self.capacity
+            ));
+        }
+        buffer.push_back(operation);
+        self.queued.store(buffer.len(), Ordering::Relaxed);
+        Ok(true)
+    }
+
+    /// 재실행용 버퍼 잠금
+    pub(crate) async fn lock_buffer(&self) -> tokio::sync::MutexGuard<'_, VecDeque<BatchOperation>> {
+        self.buffer.lock().await
+    }
+
+    /// 재실행 진행 반영
+    pub(crate) fn record_replay(&self, buffer: &VecDeque<BatchOperation>, replayed: usize) {
+        self.replayed.fetch_add(replayed as u64, Ordering::Relaxed);
+        self.queued.store(buffer.len(), Ordering::Relaxed);
+    }
+
+    /// 버퍼를 모두 재실행한 뒤 정상 모드로 복귀 (버퍼 잠금을 쥔 상태에서 호출)
+    pub(crate) fn leave(&self, buffer: &VecDeque<BatchOperation>) -> Option<Duration> {
+        debug_assert!(buffer.is_empty());
+        self.degraded.store(false, Ordering::Release);
+        self.since.lock().take().map(|since| since.elapsed())
+    }
+
+    pub(crate) fn record_stale_read(&self) {
+        self.stale_reads.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn status(&self) -> RedisDegradationStatus {
+        let degraded = self.is_degraded();
+        RedisDegradationStatus {
+            degraded,
+            degraded_for_secs: if degraded { self.since.lock().map(|since| since.elapsed().as_secs()) } else { None },
+            queued_writes: self.queued.load(Ordering::Relaxed),
+            buffer_capacity: self.capacity,
+            dropped_writes: self.dropped.load(Ordering::Relaxed),
+            replayed_writes: self.replayed.load(Ordering::Relaxed),
+            stale_reads: self.stale_reads.load(Ordering::Relaxed),
+            outages: self.outages.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(key: &str) -> BatchOperation {
+        BatchOperation::Set { key: key.to_string(), value: b"v".to_vec(), ttl: None }
+    }
+
+    #[tokio::test]
+    async fn test_degradation_buffer_lifecycle() {
+        let state = DegradationState::new(2, Duration::from_secs(60));
+
+        // 정상 모드에서는 버퍼에 넣지 않음
+        assert!(!state.enqueue(set("a")).await.unwrap());
+
+        assert!(state.enter());
+        assert!(!state.enter());
+        // 진입 직후에는 확인 주기 전
+        assert!(!state.probe_due());
+
+        assert!(state.enqueue(set("a")).await.unwrap());
+        assert!(state.enqueue(set("b")).await.unwrap());
+        assert!(state.enqueue(set("c")).await.is_err());
+
+        let status = state.status();
+        assert!(status.degraded);
+        assert_eq!(status.queued_writes, 2);
+        assert_eq!(status.dropped_writes, 1);
+        assert!(!status.accepting_writes());
+
+        let mut buffer = state.lock_buffer().await;
+        let replayed: Vec<_> = buffer.drain(..).collect();
+        assert!(matches!(&replayed[0], BatchOperation::Set { key, .. } if key == "a"));
+        state.record_replay(&buffer, replayed.len());
+        assert!(state.leave(&buffer).is_some());
+        drop(buffer);
+
+        let status = state.status();
+        assert!(!status.degraded);
+        assert_eq!(status.queued_writes, 0);
+        assert_eq!(status.replayed_writes, 2);
+        assert_eq!(status.outages, 1);
+    }
+
+    #[test]
+    fn test_disabled_when_capacity_zero() {
+        let state = DegradationState::new(0, Duration::from_secs(1));
+        assert!(!state.enter());
+        assert!(!state.is_degraded());
+    }
+}
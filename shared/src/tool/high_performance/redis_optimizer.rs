@@ -1,9 +1,11 @@
 //! Redis 성능 최적화 라이브러리
 //! 
 //! - 파이프라인 배치 처리
+//! - 쓰기 마이크로 배치 (`write_batcher`)
 //! - 연결 풀 최적화
 //! - 캐시 전략 최적화
 //! - 메모리 효율적인 직렬화
+//! - Redis 장애 시 성능 저하 모드 (`redis_degradation`)
 
 use anyhow::Result;
 use redis::{AsyncCommands, aio::ConnectionManager};
@@ -12,7 +14,10 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{RwLock, Semaphore};
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
+
+use super::batcher::{Batcher, BatcherConfig};
+use super::redis_degradation::{DegradationState, RedisDegradationStatus};
 
 /// 타입 별칭들
 type LocalCacheMap = HashMap<String, (Vec<u8>, Instant)>;
@@ -36,6 +41,20 @@ pub struct RedisOptimizerConfig {
     pub enable_value_compression: bool,
     /// TTL 기본값 (초)
     pub default_ttl_secs: usize,
+    /// 성능 저하 모드 쓰기 버퍼 크기 (0이면 성능 저하 모드 비활성화)
+    #[serde(default = "default_degraded_write_buffer")]
+    pub degraded_write_buffer: usize,
+    /// 성능 저하 모드 복구 확인 간격 (밀리초)
+    #[serde(default = "default_degraded_probe_interval_ms")]
+    pub degraded_probe_interval_ms: u64,
+}
+
+fn default_degraded_write_buffer() -> usize {
+    10_000
+}
+
+fn default_degraded_probe_interval_ms() -> u64 {
+    1_000
 }
 
 impl Default for RedisOptimizerConfig {
@@ -49,6 +68,8 @@ impl Default for RedisOptimizerConfig {
             enable_key_compression: false,
             enable_value_compression: true,
             default_ttl_secs: 3600,
+            degraded_write_buffer: default_degraded_write_buffer(),
+            degraded_probe_interval_ms: default_degraded_probe_interval_ms(),
         }
     }
 }
@@ -67,6 +88,24 @@ pub enum BatchOperation {
     Expire { key: String, ttl: usize },
 }
 
+impl BatchOperation {
+    /// 배치 크기 계산용 대략적인 바이트 수
+    pub fn approx_size(&self) -> usize {
+        match self {
+            BatchOperation::Get { key } | BatchOperation::Del { key } | BatchOperation::Expire { key, .. } => key.len(),
+            BatchOperation::Set { key, value, .. } => key.len() + value.len(),
+            BatchOperation::HGet { key, field } | BatchOperation::HDel { key, field } => key.len() + field.len(),
+            BatchOperation::HSet { key, field, value } => key.len() + field.len() + value.len(),
+            BatchOperation::ZAdd { key, member, .. } | BatchOperation::ZRem { key, member } => key.len() + member.len() + 8,
+        }
+    }
+    
+    /// 여러 번 실행해도 결과가 같은 쓰기 (성능 저하 모드에서 버퍼링 가능)
+    pub fn is_idempotent_write(&self) -> bool {
+        !matches!(self, BatchOperation::Get { .. } | BatchOperation::HGet { .. })
+    }
+}
+
 /// 배치 작업 결과
 #[derive(Debug, Clone)]
 pub struct BatchResult {
@@ -99,6 +138,8 @@ pub struct RedisOptimizer {
     stats: Arc<RwLock<RedisPerformanceStats>>,
     /// 캐시 엔트리 타입 별칭
     local_cache: Arc<RwLock<LocalCacheMap>>,
+    /// 성능 저하 모드 상태
+    degradation: DegradationState,
 }
 
 impl RedisOptimizer {
@@ -111,10 +152,14 @@ impl RedisOptimizer {
         
         Ok(Self {
             connection_semaphore: Arc::new(Semaphore::new(config.connection_pool_size)),
-            config,
             connection_manager,
             stats: Arc::new(RwLock::new(RedisPerformanceStats::default())),
             local_cache: Arc::new(RwLock::new(HashMap::new())),
+            degradation: DegradationState::new(
+                config.degraded_write_buffer,
+                Duration::from_millis(config.degraded_probe_interval_ms),
+            ),
+            config,
         })
     }
     
@@ -130,6 +175,10 @@ impl RedisOptimizer {
             return Ok(Some(cached_value));
         }
         
+        if let Some(result) = self.read_if_degraded(key).await {
+            return result;
+        }
+        
         // Redis에서 가져오기
         let _permit = self.connection_semaphore.acquire().await?;
         
@@ -160,12 +209,23 @@ impl RedisOptimizer {
                 stats.failed_operations += 1;
             }
         }
+        drop(stats);
         
+        if result.is_err() {
+            if let Some(degraded) = self.read_if_degraded(key).await {
+                return degraded;
+            }
+        }
         result
     }
     
     /// 단일 키 SET (TTL 지원)
     pub async fn set(&self, key: &str, value: &[u8], ttl: Option<usize>) -> Result<()> {
+        let operation = || BatchOperation::Set { key: key.to_string(), value: value.to_vec(), ttl };
+        if let Some(result) = self.write_if_degraded(operation).await {
+            return result;
+        }
+        
         let start_time = Instant::now();
         let _permit = self.connection_semaphore.acquire().await?;
         
@@ -197,7 +257,13 @@ impl RedisOptimizer {
             Ok(_) => stats.successful_operations += 1,
             Err(_) => stats.failed_operations += 1,
         }
+        drop(stats);
         
+        if result.is_err() {
+            if let Some(degraded) = self.write_if_degraded(operation).await {
+                return degraded;
+            }
+        }
         result.map_err(|e| anyhow::anyhow!("Redis SET failed: {}", e))
     }
     
@@ -223,6 +289,16 @@ impl RedisOptimizer {
             }
         }
         
+        if !missing_keys.is_empty() && self.degradation.is_degraded() {
+            self.try_recover().await;
+            if self.degradation.is_degraded() {
+                for &index in &missing_indices {
+                    results[index] = Some(self.stale_read(&keys[index]).await?);
+                }
+                return Ok(results);
+            }
+        }
+        
         // Redis에서 누락된 키들만 파이프라인으로 가져오기
         if !missing_keys.is_empty() {
             let _permit = self.connection_semaphore.acquire().await?;
@@ -262,6 +338,13 @@ impl RedisOptimizer {
             return Ok(Vec::new());
         }
         
+        if self.degradation.is_degraded() {
+            self.try_recover().await;
+            if self.degradation.is_degraded() {
+                return Ok(self.execute_batch_degraded(operations).await);
+            }
+        }
+        
         let start_time = Instant::now();
         let _permit = self.connection_semaphore.acquire().await?;
         
@@ -273,8 +356,20 @@ impl RedisOptimizer {
         let mut all_results = Vec::with_capacity(operations.len());
         
         for chunk in chunks {
-            let chunk_results = self.execute_pipeline_chunk(chunk).await?;
-            all_results.extend(chunk_results);
+            match self.execute_pipeline_chunk(chunk).await {
+                Ok(chunk_results) => all_results.extend(chunk_results),
+                // 실행 중 성능 저하로 전환되면 남은 작업은 버퍼/캐시로 처리
+                Err(_) if self.degradation.is_degraded() => {
+                    let remaining = operations[all_results.len()..].to_vec();
+                    let offset = all_results.len();
+                    all_results.extend(self.execute_batch_degraded(remaining).await.into_iter().map(|mut result| {
+                        result.operation_index += offset;
+                        result
+                    }));
+                    break;
+                }
+                Err(e) => return Err(e),
+            }
         }
         
         // 통계 업데이트
@@ -290,11 +385,34 @@ impl RedisOptimizer {
             (stats.avg_response_time_ms * (stats.total_operations - operations.len() as u64) as f64 + start_time.elapsed().as_millis() as f64) 
             / stats.total_operations as f64;
         
-        info!("배치 작업 완료: {} 작업, {} 성공", operations.len(), successful);
+        debug!("배치 작업 완료: {} 작업, {} 성공", operations.len(), successful);
         
         Ok(all_results)
     }
     
+    /// 쓰기 마이크로 배치 생성
+    ///
+    /// 결과가 필요 없는 쓰기(SET/HSET/ZADD/EXPIRE 등)를 모아 `execute_batch`로 실행합니다.
+    /// 실패한 작업은 경고 로그만 남기므로, 결과를 확인해야 하는 작업은 직접 호출하세요.
+    pub fn write_batcher(self: &Arc<Self>, config: BatcherConfig) -> Batcher<BatchOperation> {
+        let optimizer = self.clone();
+        Batcher::with_sizer(config, BatchOperation::approx_size, move |operations| {
+            let optimizer = optimizer.clone();
+            async move {
+                let count = operations.len();
+                match optimizer.execute_batch(operations).await {
+                    Ok(results) => {
+                        let failed = results.iter().filter(|r| !r.success).count();
+                        if failed > 0 {
+                            warn!("배치 쓰기 일부 실패: {}/{}", failed, count);
+                        }
+                    }
+                    Err(e) => warn!("배치 쓰기 실패 ({}개 작업): {}", count, e),
+                }
+            }
+        })
+    }
+    
     /// 파이프라인 청크 실행
     async fn execute_pipeline_chunk(&self, operations: &[BatchOperation]) -> Result<Vec<BatchResult>> {
         let mut results = Vec::with_capacity(operations.len());
@@ -433,21 +551,23 @@ impl RedisOptimizer {
         Fut: std::future::Future<Output = Result<T, redis::RedisError>>,
     {
         let mut last_error = None;
+        // 성능 저하 중에는 재시도하지 않고 바로 실패
+        let max_retries = if self.degradation.is_degraded() { 0 } else { self.config.max_retries };
         
-        for attempt in 0..=self.config.max_retries {
+        for attempt in 0..=max_retries {
             match operation().await {
                 Ok(result) => return Ok(result),
                 Err(e) => {
                     let error_msg = format!("{}", e);
                     last_error = Some(e);
                     
-                    if attempt < self.config.max_retries {
+                    if attempt < max_retries {
                         let delay = Duration::from_millis(
                             self.config.retry_delay_ms * (1 << attempt) // 지수 백오프
                         );
                         
                         warn!("Redis 작업 실패 (시도 {}/{}), {}ms 후 재시도: {}", 
-                              attempt + 1, self.config.max_retries + 1, delay.as_millis(), error_msg);
+                              attempt + 1, max_retries + 1, delay.as_millis(), error_msg);
                               
                         tokio::time::sleep(delay).await;
                     }
@@ -455,9 +575,126 @@ impl RedisOptimizer {
             }
         }
         
+        if last_error.as_ref().is_some_and(is_connection_error) && self.degradation.enter() {
+            warn!("🟠 Redis 연결 실패 - 성능 저하 모드 전환 (L1 캐시 읽기, 쓰기 버퍼링)");
+        }
+        
         Err(anyhow::anyhow!("Redis 작업 실패 (최대 재시도 초과): {:?}", last_error))
     }
     
+    /// 성능 저하 상태 (헬스 체크용)
+    pub fn degradation_status(&self) -> RedisDegradationStatus {
+        self.degradation.status()
+    }
+    
+    /// 성능 저하 중이면 복구 확인 후 버퍼에 쌓인 쓰기를 재실행
+    async fn try_recover(&self) {
+        if !self.degradation.probe_due() {
+            return;
+        }
+        let mut conn = self.connection_manager.clone();
+        let ping: redis::RedisResult<String> = redis::cmd("PING").query_async(&mut conn).await;
+        if ping.is_err() {
+            return;
+        }
+        
+        let mut buffer = self.degradation.lock_buffer().await;
+        if !self.degradation.is_degraded() {
+            return;
+        }
+        while !buffer.is_empty() {
+            let count = buffer.len().min(self.config.pipeline_batch_size.max(1));
+            let chunk: Vec<BatchOperation> = buffer.drain(..count).collect();
+            if let Err(e) = self.execute_pipeline_chunk(&chunk).await {
+                warn!("성능 저하 쓰기 재실행 실패 ({}개 남음): {}", buffer.len() + chunk.len(), e);
+                for operation in chunk.into_iter().rev() {
+                    buffer.push_front(operation);
+                }
+                self.degradation.record_replay(&buffer, 0);
+                return;
+            }
+            self.degradation.record_replay(&buffer, count);
+        }
+        if let Some(outage) = self.degradation.leave(&buffer) {
+            info!("🟢 Redis 복구 - 정상 모드 복귀 (성능 저하 {}초)", outage.as_secs());
+        }
+    }
+    
+    /// 성능 저하 중이면 L1 캐시에서 읽기 (정상이면 None)
+    async fn read_if_degraded(&self, key: &str) -> Option<Result<Option<Vec<u8>>>> {
+        if !self.degradation.is_degraded() {
+            return None;
+        }
+        self.try_recover().await;
+        if !self.degradation.is_degraded() {
+            return None;
+        }
+        Some(self.stale_read(key).await.map(Some))
+    }
+    
+    /// 성능 저하 중이면 쓰기를 버퍼에 쌓고 L1 캐시에 반영 (정상이면 None)
+    async fn write_if_degraded(&self, operation: impl Fn() -> BatchOperation) -> Option<Result<()>> {
+        if !self.degradation.is_degraded() {
+            return None;
+        }
+        self.try_recover().await;
+        let operation = operation();
+        match self.degradation.enqueue(operation.clone()).await {
+            Ok(true) => {
+                self.apply_to_local_cache(&operation).await;
+                Some(Ok(()))
+            }
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+    
+    /// 성능 저하 중 배치 실행 (GET은 L1 캐시, 멱등 쓰기는 버퍼링, HGET은 실패)
+    async fn execute_batch_degraded(&self, operations: Vec<BatchOperation>) -> Vec<BatchResult> {
+        let mut results = Vec::with_capacity(operations.len());
+        for (operation_index, operation) in operations.into_iter().enumerate() {
+            let outcome = match &operation {
+                BatchOperation::Get { key } => self.stale_read(key).await.map(Some),
+                BatchOperation::HGet { key, .. } => Err(anyhow::anyhow!("Redis 성능 저하 모드: HGET 불가 ({})", key)),
+                _ => match self.degradation.enqueue(operation.clone()).await {
+                    Ok(_) => {
+                        self.apply_to_local_cache(&operation).await;
+                        Ok(None)
+                    }
+                    Err(e) => Err(e),
+                },
+            };
+            results.push(match outcome {
+                Ok(data) => BatchResult { operation_index, success: true, data, error: None },
+                Err(e) => BatchResult { operation_index, success: false, data: None, error: Some(e.to_string()) },
+            });
+        }
+        results
+    }
+    
+    /// TTL과 관계없이 L1 캐시에서 읽기 (없으면 오류)
+    async fn stale_read(&self, key: &str) -> Result<Vec<u8>> {
+        let cache = self.local_cache.read().await;
+        match cache.get(key) {
+            Some((value, _)) => {
+                self.degradation.record_stale_read();
+                Ok(value.clone())
+            }
+            None => Err(anyhow::anyhow!("Redis 성능 저하 모드: L1 캐시에 없는 키 {}", key)),
+        }
+    }
+    
+    /// 버퍼링한 쓰기를 L1 캐시에 반영 (이후 읽기가 자기 쓰기를 보도록)
+    async fn apply_to_local_cache(&self, operation: &BatchOperation) {
+        match operation {
+            BatchOperation::Set { key, value, .. } => self.store_in_local_cache(key, value.clone()).await,
+            BatchOperation::Del { key } => {
+                self.local_cache.write().await.remove(key);
+            }
+            _ => {}
+        }
+    }
+    
     /// 성능 통계 반환
     pub async fn get_stats(&self) -> RedisPerformanceStats {
         let stats = self.stats.read().await;
@@ -473,6 +710,11 @@ impl RedisOptimizer {
     
     /// 캐시 정리
     pub async fn cleanup_local_cache(&self) {
+        // 성능 저하 중에는 만료된 값도 읽기에 사용
+        if self.degradation.is_degraded() {
+            return;
+        }
+        
         let mut cache = self.local_cache.write().await;
         let now = Instant::now();
         
@@ -487,6 +729,10 @@ impl RedisOptimizer {
     
     /// Hash GET 명령 (개별 필드)
     pub async fn hget(&self, key: &str, field: &str) -> Result<Option<Vec<u8>>> {
+        if self.degradation.is_degraded() {
+            self.try_recover().await;
+        }
+        
         let start_time = std::time::Instant::now();
         let _permit = self.connection_semaphore.acquire().await?;
         
@@ -513,6 +759,11 @@ impl RedisOptimizer {
 
     /// Hash SET 명령 (개별 필드)
     pub async fn hset(&self, key: &str, field: &str, value: &[u8]) -> Result<()> {
+        let operation = || BatchOperation::HSet { key: key.to_string(), field: field.to_string(), value: value.to_vec() };
+        if let Some(result) = self.write_if_degraded(operation).await {
+            return result;
+        }
+        
         let start_time = std::time::Instant::now();
         let _permit = self.connection_semaphore.acquire().await?;
         
@@ -533,12 +784,23 @@ impl RedisOptimizer {
             Ok(_) => stats.successful_operations += 1,
             Err(_) => stats.failed_operations += 1,
         }
+        drop(stats);
         
+        if result.is_err() {
+            if let Some(degraded) = self.write_if_degraded(operation).await {
+                return degraded;
+            }
+        }
         result.map_err(|e| anyhow::anyhow!("Redis HSET failed: {}", e))
     }
 
     /// Hash DELETE 명령 (개별 필드)
     pub async fn hdel(&self, key: &str, field: &str) -> Result<()> {
+        let operation = || BatchOperation::HDel { key: key.to_string(), field: field.to_string() };
+        if let Some(result) = self.write_if_degraded(operation).await {
+            return result;
+        }
+        
         let start_time = std::time::Instant::now();
         let _permit = self.connection_semaphore.acquire().await?;
         
@@ -559,12 +821,22 @@ impl RedisOptimizer {
             Ok(_) => stats.successful_operations += 1,
             Err(_) => stats.failed_operations += 1,
         }
+        drop(stats);
         
+        if result.is_err() {
+            if let Some(degraded) = self.write_if_degraded(operation).await {
+                return degraded;
+            }
+        }
         result.map_err(|e| anyhow::anyhow!("Redis HDEL failed: {}", e))
     }
 
     /// Hash GET ALL 명령 (모든 필드)
     pub async fn hgetall(&self, key: &str) -> Result<Vec<(String, String)>> {
+        if self.degradation.is_degraded() {
+            self.try_recover().await;
+        }
+        
         let start_time = std::time::Instant::now();
         let _permit = self.connection_semaphore.acquire().await?;
         
@@ -590,8 +862,15 @@ impl RedisOptimizer {
         result
     }
 
-    /// 건강 상태 확인
+    /// 건강 상태 확인 (성능 저하 중이면 복구 확인 후 상태를 오류로 보고)
     pub async fn health_check(&self) -> Result<bool> {
+        if self.degradation.is_degraded() {
+            self.try_recover().await;
+            if self.degradation.is_degraded() {
+                return Err(anyhow::anyhow!(self.degradation.status().summary()));
+            }
+        }
+        
         let _permit = self.connection_semaphore.acquire().await?;
         
         let result = self.with_retry(|| async {
@@ -604,6 +883,11 @@ impl RedisOptimizer {
     }
 }
 
+/// 연결 자체의 실패인지 (명령 오류는 성능 저하 대상 아님)
+fn is_connection_error(error: &redis::RedisError) -> bool {
+    error.is_io_error() || error.is_connection_dropped() || error.is_connection_refusal() || error.is_timeout()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
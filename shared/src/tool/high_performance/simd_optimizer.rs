@@ -188,6 +188,229 @@ pub fn fast_checksum(data: &[u8]) -> u32 {
     checksum
 }
 
+/// 체크섬 구현 선택 (실행 시 CPU 기능으로 한 번 결정)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumBackend {
+    /// SSE4.2 `crc32` 명령어 (CRC32C)
+    Sse42,
+    /// 테이블 기반 이식 가능 구현
+    Portable,
+}
+
+/// 현재 CPU에서 사용할 체크섬 구현
+pub fn checksum_backend() -> ChecksumBackend {
+    static BACKEND: std::sync::OnceLock<ChecksumBackend> = std::sync::OnceLock::new();
+    *BACKEND.get_or_init(|| {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.2") {
+                return ChecksumBackend::Sse42;
+            }
+        }
+        ChecksumBackend::Portable
+    })
+}
+
+/// CRC16-CCITT (다항식 0x1021, MSB 우선) 바이트 테이블 (`CRC16_TABLES[k]`는 뒤에 0 바이트 k개가 붙은 경우)
+static CRC16_TABLES: [[u16; 256]; 8] = crc16_tables();
+
+const fn crc16_tables() -> [[u16; 256]; 8] {
+    let mut tables = [[0u16; 256]; 8];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = (byte as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+            bit += 1;
+        }
+        tables[0][byte] = crc;
+        byte += 1;
+    }
+    let mut k = 1;
+    while k < 8 {
+        let mut byte = 0;
+        while byte < 256 {
+            let prev = tables[k - 1][byte];
+            tables[k][byte] = (prev << 8) ^ tables[0][(prev >> 8) as usize];
+            byte += 1;
+        }
+        k += 1;
+    }
+    tables
+}
+
+/// CRC16-CCITT 누적 계산 (초기값/최종 반전은 호출자가 정함)
+///
+/// 이 CRC에는 전용 명령어가 없으므로 8바이트씩 테이블 8개를 병렬로 조회합니다(slicing-by-8).
+/// 비트 단위 구현과 결과가 같습니다.
+pub fn crc16_ccitt_update(mut crc: u16, data: &[u8]) -> u16 {
+    let t = &CRC16_TABLES;
+    let mut chunks = data.chunks_exact(8);
+    for chunk in &mut chunks {
+        let b0 = chunk[0] ^ (crc >> 8) as u8;
+        let b1 = chunk[1] ^ crc as u8;
+        crc = t[7][b0 as usize]
+            ^ t[6][b1 as usize]
+            ^ t[5][chunk[2] as usize]
+            ^ t[4][chunk[3] as usize]
+            ^ t[3][chunk[4] as usize]
+            ^ t[2][chunk[5] as usize]
+            ^ t[1][chunk[6] as usize]
+            ^ t[0][chunk[7] as usize];
+    }
+    for &byte in chunks.remainder() {
+        crc = (crc << 8) ^ t[0][((crc >> 8) as u8 ^ byte) as usize];
+    }
+    crc
+}
+
+/// CRC16-CCITT-FALSE (초기값 0xFFFF, 최종 반전 없음)
+pub fn crc16_ccitt(data: &[u8]) -> u16 {
+    crc16_ccitt_update(0xFFFF, data)
+}
+
+/// CRC32C (Castagnoli, 반사 다항식 0x82F63B78) 바이트 테이블
+static CRC32C_TABLE: [u32; 256] = crc32c_table();
+
+const fn crc32c_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0x82F6_3B78 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
+
+/// CRC32C 계산 (SSE4.2 지원 시 하드웨어 명령어, 아니면 테이블)
+pub fn crc32c(data: &[u8]) -> u32 {
+    !crc32c_update(!0, data)
+}
+
+/// CRC32C 누적 계산 (반전 전 내부 상태 기준)
+pub fn crc32c_update(crc: u32, data: &[u8]) -> u32 {
+    crc32c_update_with(checksum_backend(), crc, data)
+}
+
+/// 구현을 지정한 CRC32C 누적 계산 (벤치마크/비교용)
+///
+/// CPU가 지원하지 않는 구현을 지정하면 이식 가능 구현을 사용합니다.
+pub fn crc32c_update_with(backend: ChecksumBackend, crc: u32, data: &[u8]) -> u32 {
+    match backend {
+        #[cfg(target_arch = "x86_64")]
+        // SAFETY: SSE4.2 지원을 실행 시 확인한 경우에만 선택됨
+        ChecksumBackend::Sse42 if checksum_backend() == ChecksumBackend::Sse42 => unsafe { crc32c_sse42(crc, data) },
+        _ => crc32c_portable(crc, data),
+    }
+}
+
+fn crc32c_portable(mut crc: u32, data: &[u8]) -> u32 {
+    for &byte in data {
+        crc = (crc >> 8) ^ CRC32C_TABLE[((crc as u8) ^ byte) as usize];
+    }
+    crc
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.2")]
+unsafe fn crc32c_sse42(crc: u32, data: &[u8]) -> u32 {
+    use std::arch::x86_64::{_mm_crc32_u64, _mm_crc32_u8};
+
+    let mut crc = crc as u64;
+    let mut chunks = data.chunks_exact(8);
+    for chunk in &mut chunks {
+        let word = u64::from_le_bytes(chunk.try_into().expect("8바이트 청크"));
+        crc = _mm_crc32_u64(crc, word);
+    }
+    let mut crc = crc as u32;
+    for &byte in chunks.remainder() {
+        crc = _mm_crc32_u8(crc, byte);
+    }
+    crc
+}
+
+const XXH_PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const XXH_PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const XXH_PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const XXH_PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const XXH_PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+fn xxh64_round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(XXH_PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(XXH_PRIME64_1)
+}
+
+fn xxh64_merge(acc: u64, lane: u64) -> u64 {
+    (acc ^ xxh64_round(0, lane))
+        .wrapping_mul(XXH_PRIME64_1)
+        .wrapping_add(XXH_PRIME64_4)
+}
+
+/// xxHash64 (스냅샷/캐시 키 해시)
+///
+/// 32바이트 블록을 독립된 4개 레인으로 누산하므로 CPU가 레인을 병렬로 실행합니다.
+/// `DefaultHasher`(SipHash)와 달리 프로세스/플랫폼이 달라도 같은 값을 냅니다.
+pub fn xxhash64(data: &[u8], seed: u64) -> u64 {
+    let read_u64 = |bytes: &[u8]| u64::from_le_bytes(bytes[..8].try_into().expect("8바이트"));
+    let read_u32 = |bytes: &[u8]| u32::from_le_bytes(bytes[..4].try_into().expect("4바이트")) as u64;
+
+    let mut blocks = data.chunks_exact(32);
+    let mut hash = if data.len() >= 32 {
+        let mut lanes = [
+            seed.wrapping_add(XXH_PRIME64_1).wrapping_add(XXH_PRIME64_2),
+            seed.wrapping_add(XXH_PRIME64_2),
+            seed,
+            seed.wrapping_sub(XXH_PRIME64_1),
+        ];
+        for block in &mut blocks {
+            for (i, lane) in lanes.iter_mut().enumerate() {
+                *lane = xxh64_round(*lane, read_u64(&block[i * 8..]));
+            }
+        }
+        let mut hash = lanes[0].rotate_left(1)
+            .wrapping_add(lanes[1].rotate_left(7))
+            .wrapping_add(lanes[2].rotate_left(12))
+            .wrapping_add(lanes[3].rotate_left(18));
+        for lane in lanes {
+            hash = xxh64_merge(hash, lane);
+        }
+        hash
+    } else {
+        seed.wrapping_add(XXH_PRIME64_5)
+    };
+    hash = hash.wrapping_add(data.len() as u64);
+
+    let mut rest = blocks.remainder();
+    while rest.len() >= 8 {
+        hash ^= xxh64_round(0, read_u64(rest));
+        hash = hash.rotate_left(27).wrapping_mul(XXH_PRIME64_1).wrapping_add(XXH_PRIME64_4);
+        rest = &rest[8..];
+    }
+    if rest.len() >= 4 {
+        hash ^= read_u32(rest).wrapping_mul(XXH_PRIME64_1);
+        hash = hash.rotate_left(23).wrapping_mul(XXH_PRIME64_2).wrapping_add(XXH_PRIME64_3);
+        rest = &rest[4..];
+    }
+    for &byte in rest {
+        hash ^= (byte as u64).wrapping_mul(XXH_PRIME64_5);
+        hash = hash.rotate_left(11).wrapping_mul(XXH_PRIME64_1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(XXH_PRIME64_2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(XXH_PRIME64_3);
+    hash ^ (hash >> 32)
+}
+
 /// SIMD 최적화 상태를 나타내는 열거형
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SimdCapability {
@@ -307,6 +530,37 @@ mod tests {
         assert_eq!(fast_checksum(&[]), 0);
     }
 
+    #[test]
+    fn test_checksum_vectors() {
+        let check = b"123456789";
+        assert_eq!(crc16_ccitt(check), 0x29B1);
+        assert_eq!(crc32c(check), 0xE306_9283);
+        assert_eq!(xxhash64(b"", 0), 0xEF46_DB37_51D8_E999);
+        assert_eq!(xxhash64(b"abc", 0), 0x44BC_2CF5_AD77_0999);
+
+        // 블록 경계를 넘는 길이에서 비트 단위 CRC16 / 이식 가능 CRC32C와 일치
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 31 % 251) as u8).collect();
+        for len in [0, 1, 7, 8, 9, 31, 32, 33, 100, 1000] {
+            let mut expected: u16 = 0xFFFF;
+            for &byte in &data[..len] {
+                expected ^= (byte as u16) << 8;
+                for _ in 0..8 {
+                    expected = if expected & 0x8000 != 0 { (expected << 1) ^ 0x1021 } else { expected << 1 };
+                }
+            }
+            assert_eq!(crc16_ccitt(&data[..len]), expected, "len {len}");
+            assert_eq!(
+                crc32c_update(!0, &data[..len]),
+                crc32c_update_with(ChecksumBackend::Portable, !0, &data[..len]),
+                "len {len}"
+            );
+        }
+
+        // 나눠서 누적해도 결과가 같음
+        let (head, tail) = data.split_at(13);
+        assert_eq!(crc16_ccitt_update(crc16_ccitt(head), tail), crc16_ccitt(&data));
+    }
+
     #[test]
     fn test_simd_utils() {
         // 16바이트 정렬 테스트
@@ -2,7 +2,10 @@ pub mod hex_utils;
 pub mod get_id;
 pub mod data_utils;
 pub mod current_time;
+pub mod clock;
 pub mod error;
+pub mod game_error;
+pub mod moving_average;
 pub mod high_performance;
 
 // Re-export commonly used types
@@ -10,4 +13,7 @@ pub use hex_utils::HexUtils;
 pub use get_id::RoomIdGenerator;
 pub use data_utils::{DataUtils, TransferResult};
 pub use current_time::CurrentTime;
-pub use error::*;
\ No newline at end of file
+pub use clock::{system_clock, Clock, FrozenClock, OffsetClock, SharedClock, SystemClock};
+pub use error::*;
+pub use game_error::{ErrorCode, ErrorDomain, GameServerError};
+pub use moving_average::ExponentialMovingAverage;
\ No newline at end of file
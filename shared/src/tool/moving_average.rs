@@ -0,0 +1,53 @@
+//! 이동 평균 유틸리티
+//!
+//! RTT 추정, 처리 시간 평균, 메트릭 기준선 등 서버 전반에서 쓰는 지수 가중 이동 평균입니다.
+
+/// 지수 가중 이동 평균 (EWMA)
+#[derive(Debug, Clone)]
+pub struct ExponentialMovingAverage {
+    alpha: f64,
+    value: f64,
+    initialized: bool,
+}
+
+impl ExponentialMovingAverage {
+    /// 새로운 EWMA 생성
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            value: 0.0,
+            initialized: false,
+        }
+    }
+
+    /// 새로운 값으로 업데이트
+    pub fn update(&mut self, new_value: f64) {
+        if !self.initialized {
+            self.value = new_value;
+            self.initialized = true;
+        } else {
+            self.value = self.alpha * new_value + (1.0 - self.alpha) * self.value;
+        }
+    }
+
+    /// 현재 평균 값
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// 평활 계수
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// 초기화 여부
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// 리셋
+    pub fn reset(&mut self) {
+        self.value = 0.0;
+        self.initialized = false;
+    }
+}
@@ -21,6 +21,30 @@ pub struct TcpServerConfig {
     pub grpc_host: String,
     /// gRPC 서버 포트 번호
     pub grpc_port: u16,
+    /// 하트비트 타임아웃 경고 임계값 (분당 타임아웃 수)
+    pub heartbeat_timeout_alert_per_min: f64,
+    /// 단절 후 세션 토큰으로 재접속할 수 있는 유예 시간 (초)
+    pub session_resume_grace_secs: u64,
+    /// 서버 전체 최대 방 수
+    pub max_rooms: u32,
+    /// 사용자당 동시에 보유할 수 있는 최대 방 수
+    pub max_rooms_per_user: u32,
+    /// 같은 사용자의 연속 방 생성 간격 (초)
+    pub room_create_cooldown_secs: u64,
+    /// 배포 지역 (통계 집계, 지역별 접속 정보/방 키)
+    pub server_region: String,
+    /// `/healthz`, `/readyz` 포트 (없으면 비활성화)
+    pub health_port: Option<u16>,
+    /// 채팅 스티커 카탈로그 파일 경로 (TOML)
+    pub sticker_catalog_file: String,
+    /// 참가 코드 기본 유효 시간 (초)
+    pub join_code_ttl_secs: u64,
+    /// 안내 메시지 카탈로그 파일 경로 (TOML)
+    pub message_catalog_file: String,
+    /// 서버가 가득 찼을 때 최대 대기 인원
+    pub admission_queue_max: usize,
+    /// 접속 대기열 최대 대기 시간 (초)
+    pub admission_max_wait_secs: u64,
 }
 
 impl TcpServerConfig {
@@ -52,6 +76,44 @@ impl TcpServerConfig {
                 .unwrap_or_else(|_| "50051".to_string())
                 .parse()
                 .unwrap_or(50051),
+            heartbeat_timeout_alert_per_min: std::env::var("heartbeat_timeout_alert_per_min")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse()
+                .unwrap_or(50.0),
+            session_resume_grace_secs: std::env::var("session_resume_grace_secs")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+            max_rooms: std::env::var("max_rooms")
+                .unwrap_or_else(|_| "100".to_string())
+                .parse()
+                .unwrap_or(100),
+            max_rooms_per_user: std::env::var("max_rooms_per_user")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .unwrap_or(3),
+            room_create_cooldown_secs: std::env::var("room_create_cooldown_secs")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .unwrap_or(10),
+            server_region: std::env::var("server_region").unwrap_or_else(|_| "local".to_string()),
+            health_port: std::env::var("tcp_health_port").ok().and_then(|port| port.parse().ok()),
+            sticker_catalog_file: std::env::var("tcp_sticker_catalog")
+                .unwrap_or_else(|_| "property/stickers.toml".to_string()),
+            join_code_ttl_secs: std::env::var("join_code_ttl_secs")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse()
+                .unwrap_or(600),
+            message_catalog_file: std::env::var("tcp_message_catalog")
+                .unwrap_or_else(|_| "property/messages.toml".to_string()),
+            admission_queue_max: std::env::var("tcp_admission_queue_max")
+                .unwrap_or_else(|_| "200".to_string())
+                .parse()
+                .unwrap_or(200),
+            admission_max_wait_secs: std::env::var("tcp_admission_max_wait_secs")
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .unwrap_or(300),
         };
         
         info!("TCP 서버 설정 로드 완료: {:?}", config);
@@ -127,6 +189,18 @@ pub fn validate_config(config: &TcpServerConfig) -> Result<()> {
         anyhow::bail!("gRPC 호스트 주소가 비어있습니다");
     }
     
+    if config.heartbeat_timeout_alert_per_min.is_nan() || config.heartbeat_timeout_alert_per_min <= 0.0 {
+        anyhow::bail!("하트비트 타임아웃 경고 임계값은 0보다 커야 합니다: {}", config.heartbeat_timeout_alert_per_min);
+    }
+    
+    if config.max_rooms == 0 || config.max_rooms_per_user == 0 {
+        anyhow::bail!("방 수 제한은 0보다 커야 합니다: max_rooms={}, max_rooms_per_user={}", config.max_rooms, config.max_rooms_per_user);
+    }
+    
+    if config.admission_max_wait_secs == 0 {
+        anyhow::bail!("접속 대기열 최대 대기 시간은 0보다 커야 합니다");
+    }
+    
     Ok(())
 }
 
@@ -0,0 +1,100 @@
+//! 채팅 이벤트 릴레이
+//!
+//! 클라이언트가 보낸 채팅 메시지를 공유 이벤트 버스(Redis `events:chat`)에 발행합니다.
+//! 관리자 대시보드의 실시간 모더레이션 스트림이 이 이벤트를 구독합니다.
+//!
+//! 이벤트 버스가 연결되지 않은 경우 아무것도 발행하지 않습니다.
+
+use std::sync::{Arc, OnceLock};
+use tokio::sync::broadcast;
+use tracing::{info, warn, debug};
+
+use crate::handler::RoomHandler;
+use crate::protocol::GameMessage;
+use crate::service::ConnectionService;
+use shared::service::redis::event_bus::{ChatEvent, EventBus};
+use shared::monitoring::TaskAccounting;
+
+/// 채팅 이벤트 릴레이
+pub struct ChatEventRelay {
+    connection_service: Arc<ConnectionService>,
+    room_handler: Arc<RoomHandler>,
+    event_bus: OnceLock<EventBus>,
+}
+
+impl ChatEventRelay {
+    pub fn new(connection_service: Arc<ConnectionService>, room_handler: Arc<RoomHandler>) -> Self {
+        Self {
+            connection_service,
+            room_handler,
+            event_bus: OnceLock::new(),
+        }
+    }
+
+    /// 이벤트 버스 연결
+    pub fn attach_event_bus(&self, event_bus: EventBus) {
+        if self.event_bus.set(event_bus).is_err() {
+            debug!("채팅 이벤트 릴레이에 이벤트 버스가 이미 연결되어 있습니다");
+        }
+    }
+
+    /// 채팅 메시지 수신 시작
+    pub fn start(self: &Arc<Self>) {
+        if self.event_bus.get().is_none() {
+            info!("이벤트 버스 미연결 - 채팅 이벤트 릴레이 비활성화");
+            return;
+        }
+
+        let relay = self.clone();
+        let mut rx = self.connection_service.subscribe_broadcast();
+
+        tokio::spawn(TaskAccounting::global().subsystem("chat_relay").instrument(async move {
+            loop {
+                match rx.recv().await {
+                    Ok((Some(user_id), GameMessage::ChatMessage { user_id: msg_user_id, room_id, message })) => {
+                        // 다른 사용자를 사칭한 메시지는 발행하지 않음
+                        if msg_user_id == user_id {
+                            relay.publish(user_id, room_id, message).await;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("채팅 이벤트 릴레이 수신 지연: {}개 메시지 건너뜀", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+
+            debug!("채팅 이벤트 릴레이 종료");
+        }));
+
+        info!("✅ 채팅 이벤트 릴레이 시작");
+    }
+
+    /// 채팅 이벤트 발행
+    async fn publish(&self, user_id: u32, room_id: u32, content: String) {
+        let Some(event_bus) = self.event_bus.get() else {
+            return;
+        };
+
+        let client_addr = self.connection_service.get_user_info(user_id).await
+            .map(|info| info.addr)
+            .unwrap_or_default();
+        let nickname = self.room_handler.get_room_details(room_id).await.ok()
+            .and_then(|room| room.users.get(&user_id).map(|user| user.nickname.clone()))
+            .unwrap_or_default();
+
+        let event = ChatEvent {
+            room_id,
+            user_id,
+            nickname,
+            content,
+            client_addr,
+            timestamp: chrono::Utc::now().timestamp(),
+        };
+
+        if let Err(e) = event_bus.publish_chat(&event).await {
+            warn!("채팅 이벤트 발행 실패: 사용자 {} 방 {}: {}", user_id, room_id, e);
+        }
+    }
+}
@@ -54,7 +54,7 @@ impl ChatRoomMessageHandler {
     ) -> Result<()> {
         // Connect 메시지 처리
         let (user_id, room_id) = match connect_message {
-            GameMessage::Connect { room_id, user_id } => {
+            GameMessage::Connect { room_id, user_id, .. } => {
                 info!("새 클라이언트 연결: user_id={}, room_id={}, addr={}", user_id, room_id, addr);
                 (user_id, room_id)
             }
@@ -115,10 +115,7 @@ impl ChatRoomMessageHandler {
                 error!("사용자 {} 메시지 처리 실패: {}", user_id, e);
                 
                 // 에러 응답 전송
-                let error_msg = GameMessage::Error {
-                    code: 500,
-                    message: format!("메시지 처리 실패: {}", e),
-                };
+                let error_msg = GameMessage::from_error(&e.into());
                 
                 let mut writer_guard = writer.lock().await;
                 if let Err(write_err) = error_msg.write_to_stream(&mut *writer_guard).await {
@@ -5,13 +5,19 @@
 use anyhow::{Result, anyhow};
 use std::sync::Arc;
 use tokio::net::TcpStream;
-use tokio::io::BufReader;
+use tokio::io::{AsyncWriteExt, BufReader, BufWriter};
+use tokio::net::tcp::OwnedWriteHalf;
+use tokio::time::Instant;
 use tracing::{info, warn, debug, error};
 
-use crate::service::{ConnectionService, HeartbeatService, MessageService};
+use crate::handler::{ChatEventRelay, DirectMessageHandler, FriendHandler, JoinCodeHandler, RoomHandler, ServerStatsReporter, SessionEvictionListener};
+use crate::service::{AdmissionPass, AdmissionQueue, ConnectionService, HeartbeatService, MessageService, ResumeOutcome, SessionResumeService};
 use crate::protocol::GameMessage;
-use crate::tool::{NetworkUtils, IpInfo, ConnectionQuality};
+use crate::tool::{NetworkUtils, IpInfo, ConnectionQuality, MessageKey};
 use shared::config::redis_config::RedisConfig;
+use shared::security::{EntryTicketSigner, SecurityError};
+use shared::tool::{ErrorCode, GameServerError};
+use shared::service::redis::event_bus::EventBus;
 use shared::service::redis::core::redis_get_key::KeyType;
 use redis::AsyncCommands;
 
@@ -21,6 +27,17 @@ pub struct ConnectionHandler {
     heartbeat_service: Arc<HeartbeatService>,
     message_service: Arc<MessageService>,
     redis_config: Option<Arc<RedisConfig>>,
+    session_resume: Option<Arc<SessionResumeService>>,
+    direct_messages: Option<Arc<DirectMessageHandler>>,
+    friends: Option<Arc<FriendHandler>>,
+    chat_events: Option<Arc<ChatEventRelay>>,
+    stats_reporter: Option<Arc<ServerStatsReporter>>,
+    rooms: Option<Arc<RoomHandler>>,
+    join_codes: Option<Arc<JoinCodeHandler>>,
+    session_evictions: Option<Arc<SessionEvictionListener>>,
+    admission: Option<Arc<AdmissionQueue>>,
+    /// 입장 티켓 서명기와 이 서버가 라우팅 테이블에 광고하는 주소
+    entry_tickets: Option<(EntryTicketSigner, String)>,
 }
 
 impl ConnectionHandler {
@@ -35,14 +52,110 @@ impl ConnectionHandler {
             heartbeat_service,
             message_service,
             redis_config: None,
+            session_resume: None,
+            direct_messages: None,
+            friends: None,
+            chat_events: None,
+            stats_reporter: None,
+            rooms: None,
+            join_codes: None,
+            session_evictions: None,
+            admission: None,
+            entry_tickets: None,
         }
     }
     
+    /// 세션 재개 서비스 설정
+    pub fn with_session_resume(mut self, session_resume: Arc<SessionResumeService>) -> Self {
+        self.session_resume = Some(session_resume);
+        self
+    }
+    
+    /// 다이렉트 메시지 핸들러 설정
+    pub fn with_direct_messages(mut self, direct_messages: Arc<DirectMessageHandler>) -> Self {
+        self.direct_messages = Some(direct_messages);
+        self
+    }
+    
+    /// 친구 핸들러 설정 (지역별 접속 정보로 친구 접속 상태 조회)
+    pub fn with_friends(mut self, friends: Arc<FriendHandler>) -> Self {
+        self.friends = Some(friends);
+        self
+    }
+    
+    /// 채팅 이벤트 릴레이 설정
+    pub fn with_chat_events(mut self, chat_events: Arc<ChatEventRelay>) -> Self {
+        self.chat_events = Some(chat_events);
+        self
+    }
+    
+    /// 서버 상태 리포터 설정
+    pub fn with_stats_reporter(mut self, stats_reporter: Arc<ServerStatsReporter>) -> Self {
+        self.stats_reporter = Some(stats_reporter);
+        self
+    }
+    
+    /// 방 핸들러 설정 (연결 해제 시 방 퇴장 및 방장 승계)
+    pub fn with_rooms(mut self, rooms: Arc<RoomHandler>) -> Self {
+        self.rooms = Some(rooms);
+        self
+    }
+    
+    /// 참가 코드 핸들러 설정
+    pub fn with_join_codes(mut self, join_codes: Arc<JoinCodeHandler>) -> Self {
+        self.join_codes = Some(join_codes);
+        self
+    }
+    
+    /// 세션 종료 리스너 설정 (동시 세션 한도로 밀려난 연결 종료)
+    pub fn with_session_evictions(mut self, session_evictions: Arc<SessionEvictionListener>) -> Self {
+        self.session_evictions = Some(session_evictions);
+        self
+    }
+    
+    /// 접속 대기열 설정 (없으면 서버가 가득 찼을 때 바로 연결 종료)
+    pub fn with_admission(mut self, admission: Arc<AdmissionQueue>) -> Self {
+        self.admission = Some(admission);
+        self
+    }
+    
+    /// 입장 티켓 검증 설정
+    ///
+    /// `address`는 하트비트로 광고하는 서버 ID와 같아야 합니다 (로그인 응답이 그 주소로 티켓을 발급).
+    /// 설정하면 유효한 티켓이 없는 `Connect`는 거절합니다.
+    pub fn with_entry_tickets(mut self, signer: EntryTicketSigner, address: impl Into<String>) -> Self {
+        self.entry_tickets = Some((signer, address.into()));
+        self
+    }
+    
     /// Redis 설정 추가
     pub async fn with_redis(&mut self) -> Result<()> {
         match RedisConfig::new().await {
             Ok(config) => {
-                self.redis_config = Some(Arc::new(config));
+                let config = Arc::new(config);
+                if let Some(session_resume) = &self.session_resume {
+                    session_resume.attach_redis(config.clone());
+                }
+                if let Some(direct_messages) = &self.direct_messages {
+                    direct_messages.attach_redis(config.clone());
+                }
+                if let Some(friends) = &self.friends {
+                    friends.attach_redis(config.clone());
+                }
+                if let Some(chat_events) = &self.chat_events {
+                    chat_events.attach_event_bus(EventBus::new((*config).clone()));
+                }
+                if let Some(stats_reporter) = &self.stats_reporter {
+                    stats_reporter.attach_redis(config.clone());
+                }
+                if let Some(join_codes) = &self.join_codes {
+                    join_codes.attach_redis(config.clone());
+                }
+                if let Some(session_evictions) = &self.session_evictions {
+                    session_evictions.attach_event_bus(EventBus::new((*config).clone()));
+                }
+                self.heartbeat_service.attach_event_bus(EventBus::new((*config).clone()));
+                self.redis_config = Some(config);
                 info!("Redis 연결 성공");
                 Ok(())
             }
@@ -80,18 +193,69 @@ impl ConnectionHandler {
             }
         };
         
-        // Connect 메시지 검증 및 처리
-        let (room_id, user_id) = match connect_msg {
-            GameMessage::Connect { room_id, user_id } => {
+        // Connect/Reconnect 메시지 검증 및 처리
+        let (room_id, user_id, resumed, locale, family_id) = match connect_msg {
+            GameMessage::Connect { room_id, user_id, locale, family_id, entry_ticket, .. } => {
                 info!("Connect 메시지 수신: room_id={}, user_id={}", room_id, user_id);
-                (room_id, user_id)
+                if let Err(e) = self.verify_entry_ticket(user_id, entry_ticket.as_deref()) {
+                    warn!("사용자 {} 입장 티켓 검증 실패: {}", user_id, e);
+                    let error = GameServerError::from(&e);
+                    let reject = GameMessage::Error {
+                        code: error.code().numeric(),
+                        message: error.user_message(),
+                    };
+                    let mut writer = BufWriter::new(writer);
+                    if let Err(send_err) = reject.write_to_stream(&mut writer).await {
+                        debug!("티켓 거절 응답 전송 실패: {}", send_err);
+                    }
+                    return Err(anyhow!("입장 티켓 검증 실패: {}", e));
+                }
+                (room_id, user_id, None, locale, family_id)
+            }
+            // 재개 토큰은 티켓을 검증한 Connect 뒤에만 발급되므로 재개 토큰 검증으로 충분합니다
+            // (티켓 유효 시간이 재개 유예 시간보다 짧아 티켓을 다시 요구하지 않음)
+            GameMessage::Reconnect { user_id, session_token, locale } => {
+                info!("Reconnect 메시지 수신: user_id={}", user_id);
+                let resumed = match &self.session_resume {
+                    Some(session_resume) => session_resume.resume(user_id, &session_token).await,
+                    None => Err(anyhow!("세션 재개가 비활성화되어 있습니다")),
+                };
+                
+                match resumed {
+                    Ok(outcome) => {
+                        let family_id = outcome.family_id.clone();
+                        (outcome.room_id, user_id, Some(outcome), locale, family_id)
+                    }
+                    Err(e) => {
+                        warn!("사용자 {} 세션 재개 실패: {}", user_id, e);
+                        
+                        // 클라이언트가 전체 재로그인으로 전환하도록 알림
+                        let messages = self.connection_service.messages();
+                        let reject = GameMessage::Error {
+                            code: ErrorCode::InvalidSession.numeric(),
+                            message: messages.render(&messages.negotiate(locale.as_deref()), MessageKey::SessionResumeFailed, &[]),
+                        };
+                        let mut writer = BufWriter::new(writer);
+                        if let Err(send_err) = reject.write_to_stream(&mut writer).await {
+                            debug!("재개 실패 응답 전송 실패: {}", send_err);
+                        }
+                        return Err(e);
+                    }
+                }
             }
             _ => {
                 warn!("잘못된 초기 메시지 타입: {:?}", connect_msg);
-                return Err(anyhow!("첫 메시지는 Connect 메시지여야 합니다"));
+                return Err(anyhow!("첫 메시지는 Connect 또는 Reconnect 메시지여야 합니다"));
             }
         };
         
+        // 서버가 가득 차면 자리가 날 때까지 대기열에서 대기
+        let mut writer = writer;
+        let admission = match &self.admission {
+            Some(queue) => Some(self.wait_for_admission(queue, &mut writer, user_id, locale.as_deref()).await?),
+            None => None,
+        };
+        
         // TCP 호스트 정보를 Redis에 저장
         if let Some(redis_config) = &self.redis_config {
             if let Err(e) = self.store_tcp_host_to_redis(user_id, &addr, redis_config.as_ref()).await {
@@ -106,12 +270,46 @@ impl ConnectionHandler {
         let reader = buf_reader.into_inner();
         let reunited_stream = reader.reunite(writer)?;
         let registered_user_id = self.connection_service.handle_new_connection_with_id(reunited_stream, addr.clone(), user_id).await?;
+        drop(admission);
+        self.connection_service.set_locale(registered_user_id, locale.as_deref()).await;
+        self.connection_service.set_family_id(registered_user_id, family_id.clone()).await;
         
         // 환영 메시지 전송
         if let Err(e) = self.send_welcome_message(registered_user_id).await {
             warn!("환영 메시지 전송 실패: {}", e);
         }
         
+        // 세션 재개 시 놓친 메시지 재전송, 아니면 새 세션 토큰 발급
+        if let Some(session_resume) = &self.session_resume {
+            let session_token = match resumed {
+                Some(outcome) => {
+                    let session_token = outcome.session_token.clone();
+                    self.replay_missed_messages(registered_user_id, outcome).await;
+                    session_token
+                }
+                None => {
+                    let session_token = session_resume.issue_token(registered_user_id, room_id);
+                    session_resume.set_family(registered_user_id, family_id);
+                    session_token
+                }
+            };
+            
+            let token_message = GameMessage::SessionToken {
+                session_token,
+                grace_secs: session_resume.grace_period().as_secs(),
+            };
+            if let Err(e) = self.connection_service.send_to_user(registered_user_id, &token_message).await {
+                warn!("세션 토큰 전송 실패: {}", e);
+            }
+            
+            session_resume.cleanup_expired().await;
+        }
+        
+        // 접속 인스턴스 기록 및 오프라인 다이렉트 메시지 전달
+        if let Some(direct_messages) = &self.direct_messages {
+            direct_messages.on_user_connected(registered_user_id).await;
+        }
+        
         info!("✅ 사용자 {} (room_id={}) 연결 처리 완료", user_id, room_id);
         Ok(registered_user_id)
     }
@@ -126,11 +324,24 @@ impl ConnectionHandler {
             message: format!("사용자 {}가 연결을 해제했습니다", user_id),
         };
         
-        // 브로드캐스트는 선택적으로 (필요한 경우에만)
-        if let Err(e) = self.message_service.broadcast(&disconnect_message).await {
+        // 동시에 여러 명이 끊기면 알림을 모아서 전송
+        if let Err(e) = self.message_service.queue_broadcast(disconnect_message).await {
             warn!("연결 해제 알림 브로드캐스트 실패: {}", e);
         }
         
+        // 정상 종료이므로 재접속용 세션 폐기
+        if let Some(session_resume) = &self.session_resume {
+            session_resume.revoke(user_id).await;
+        }
+        
+        if let Some(direct_messages) = &self.direct_messages {
+            direct_messages.on_user_disconnected(user_id).await;
+        }
+        
+        if let Some(rooms) = &self.rooms {
+            rooms.on_user_disconnected(user_id).await;
+        }
+        
         // 연결 서비스에서 제거
         let removed = self.connection_service.remove_connection(user_id).await;
         
@@ -161,12 +372,12 @@ impl ConnectionHandler {
             debug!("사설 IP 연결: {}", ip_info.address);
         }
         
-        // 연결 수 제한 확인
+        // 연결 수 제한 확인 (대기열이 있으면 Connect 메시지를 받은 뒤 대기열에서 처리)
         let current_count = self.connection_service.get_connection_count().await;
-        let connection_stats = self.connection_service.get_connection_stats().await;
+        let max_connections = self.connection_service.max_connections();
         
-        if current_count >= 1000 { // 임시 하드코딩된 제한
-            return Err(anyhow!("서버가 가득 참: {}/1000", current_count));
+        if self.admission.is_none() && current_count >= max_connections as usize {
+            return Err(anyhow!("서버가 가득 참: {}/{}", current_count, max_connections));
         }
         
         // IP별 연결 수 제한 (향후 구현)
@@ -176,6 +387,100 @@ impl ConnectionHandler {
         Ok(())
     }
     
+    /// 빈 자리가 날 때까지 대기열에서 대기
+    /// 
+    /// 대기 중에는 순번이 바뀔 때와 `update_interval`마다 `QueuePosition`을 보내고,
+    /// 대기열이 가득 찼거나 최대 대기 시간을 넘기면 `ServerBusy`를 보낸 뒤 에러를 반환합니다.
+    async fn wait_for_admission(
+        &self,
+        queue: &Arc<AdmissionQueue>,
+        writer: &mut OwnedWriteHalf,
+        user_id: u32,
+        locale: Option<&str>,
+    ) -> Result<AdmissionPass> {
+        let max_connections = self.connection_service.max_connections() as usize;
+        let connections = self.connection_service.get_connection_count().await;
+        if let Some(pass) = queue.try_enter(connections, max_connections) {
+            return Ok(pass);
+        }
+        
+        let mut ticket = match queue.join() {
+            Ok(ticket) => ticket,
+            Err(retry_after) => {
+                warn!("접속 대기열 가득 참 - 사용자 {} 거절 ({}명 대기)", user_id, queue.depth());
+                self.send_server_busy(writer, locale, retry_after.as_secs()).await;
+                return Err(anyhow!("서버와 접속 대기열이 가득 참"));
+            }
+        };
+        info!("서버 가득 참 - 사용자 {} 대기열 {}번", user_id, ticket.status().position);
+        
+        let config = queue.config().clone();
+        let mut last_sent: Option<(usize, Instant)> = None;
+        loop {
+            let connections = self.connection_service.get_connection_count().await;
+            if let Some(pass) = ticket.try_admit(connections, max_connections) {
+                info!("사용자 {} 대기열 입장 ({:.1}초 대기)", user_id, ticket.waited().as_secs_f64());
+                return Ok(pass);
+            }
+            
+            if ticket.waited() >= config.max_wait {
+                let retry_after = ticket.expire();
+                warn!("사용자 {} 최대 대기 시간 초과로 거절", user_id);
+                self.send_server_busy(writer, locale, retry_after.as_secs()).await;
+                return Err(anyhow!("접속 대기 시간 초과"));
+            }
+            
+            let status = ticket.status();
+            let due = last_sent.is_none_or(|(position, sent_at)| {
+                position != status.position || sent_at.elapsed() >= config.update_interval
+            });
+            if due {
+                let update = GameMessage::QueuePosition {
+                    position: status.position as u32,
+                    queue_length: status.queue_length as u32,
+                    estimated_wait_secs: status.estimated_wait.as_secs(),
+                };
+                // 전송 실패는 대기 중 연결이 끊긴 것 (티켓이 버려지며 대기열에서 빠짐)
+                writer.write_all(&update.to_bytes()?).await
+                    .map_err(|e| anyhow!("대기 순번 전송 실패: {}", e))?;
+                last_sent = Some((status.position, Instant::now()));
+            }
+            
+            tokio::time::sleep(config.poll_interval).await;
+        }
+    }
+    
+    /// 입장 티켓 검증 (로그인 응답이 이 서버로 안내한 사용자인지)
+    fn verify_entry_ticket(&self, user_id: u32, entry_ticket: Option<&str>) -> Result<(), SecurityError> {
+        let Some((signer, address)) = &self.entry_tickets else {
+            return Ok(());
+        };
+        let token = entry_ticket.ok_or_else(|| SecurityError::InvalidToken("entry ticket missing".to_string()))?;
+        let ticket = signer.verify(token, super::server_stats_reporter::PROTOCOL, address)?;
+        if ticket.user_id != user_id {
+            return Err(SecurityError::InvalidToken("entry ticket issued for another user".to_string()));
+        }
+        Ok(())
+    }
+    
+    /// 접속 거절 응답 전송
+    async fn send_server_busy(&self, writer: &mut OwnedWriteHalf, locale: Option<&str>, retry_after_secs: u64) {
+        let messages = self.connection_service.messages();
+        let reject = GameMessage::ServerBusy {
+            code: ErrorCode::ServerFull.numeric(),
+            message: messages.render(&messages.negotiate(locale), MessageKey::ServerBusy, &[("seconds", &retry_after_secs)]),
+            retry_after_secs,
+        };
+        match reject.to_bytes() {
+            Ok(data) => {
+                if let Err(e) = writer.write_all(&data).await {
+                    debug!("접속 거절 응답 전송 실패: {}", e);
+                }
+            }
+            Err(e) => debug!("접속 거절 응답 직렬화 실패: {}", e),
+        }
+    }
+    
     /// TCP 호스트 정보를 Redis에 저장
     async fn store_tcp_host_to_redis(&self, user_id: u32, addr: &str, redis_config: &RedisConfig) -> Result<()> {
         let mut conn = redis_config.get_connection();
@@ -204,6 +509,27 @@ impl ConnectionHandler {
         Ok(())
     }
     
+    /// 재접속 확인 후 놓친 메시지 재전송
+    async fn replay_missed_messages(&self, user_id: u32, outcome: ResumeOutcome) {
+        let ack = GameMessage::ReconnectAck {
+            room_id: outcome.room_id,
+            replayed_count: outcome.missed_messages.len() as u32,
+        };
+        if let Err(e) = self.connection_service.send_to_user(user_id, &ack).await {
+            warn!("재접속 확인 메시지 전송 실패: {}", e);
+            return;
+        }
+        
+        for message in &outcome.missed_messages {
+            if let Err(e) = self.connection_service.send_to_user(user_id, message).await {
+                warn!("사용자 {} 놓친 메시지 재전송 실패: {}", user_id, e);
+                return;
+            }
+        }
+        
+        debug!("사용자 {} 놓친 메시지 {}개 재전송 완료", user_id, outcome.missed_messages.len());
+    }
+    
     /// 연결 품질 확인
     pub async fn check_connection_quality(&self, user_id: u32) -> Result<ConnectionQuality> {
         if let Some(user_info) = self.connection_service.get_user_info(user_id).await {
@@ -313,4 +639,28 @@ mod tests {
         let problematic = handler.identify_problematic_connections().await;
         assert!(problematic.is_empty());
     }
+    
+    #[test]
+    fn test_entry_ticket_verification() {
+        let connection_service = Arc::new(ConnectionService::new(100));
+        let heartbeat_service = Arc::new(HeartbeatService::with_default_config(connection_service.clone()));
+        let message_service = Arc::new(MessageService::new(connection_service.clone()));
+        let signer = EntryTicketSigner::new(b"ticket-key", std::time::Duration::from_secs(60));
+        
+        // 서명기가 없으면 검증하지 않음
+        let handler = ConnectionHandler::new(connection_service, heartbeat_service, message_service);
+        assert!(handler.verify_entry_ticket(7, None).is_ok());
+        
+        let handler = handler.with_entry_tickets(signer.clone(), "127.0.0.1:4000");
+        let (ticket, _) = signer.issue(7, "tcp", "127.0.0.1:4000");
+        assert!(handler.verify_entry_ticket(7, Some(&ticket)).is_ok());
+        
+        // 티켓 없음, 다른 사용자, 다른 서버, 다른 프로토콜은 거절
+        assert!(handler.verify_entry_ticket(7, None).is_err());
+        assert!(handler.verify_entry_ticket(8, Some(&ticket)).is_err());
+        let (other_server, _) = signer.issue(7, "tcp", "127.0.0.1:4001");
+        assert!(handler.verify_entry_ticket(7, Some(&other_server)).is_err());
+        let (other_protocol, _) = signer.issue(7, "rudp", "127.0.0.1:4000");
+        assert!(handler.verify_entry_ticket(7, Some(&other_protocol)).is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,604 @@
+//! 다이렉트 메시지 핸들러
+//!
+//! 친구 간 1:1 메시지를 사용자 ID로 라우팅합니다.
+//!
+//! - 이 인스턴스에 접속한 수신자에게는 바로 전달
+//! - 같은 지역의 다른 인스턴스에 접속한 수신자에게는 Redis pub/sub(`tcp:dm`)으로 전달
+//!   (접속 인스턴스는 지역별 접속 정보 `presence:{region}:{user_id}`에서 조회)
+//! - 오프라인 수신자의 메시지는 Redis 리스트(`tcp:dm:offline:{user_id}`)에 보관 후 접속 시 전달
+//! - 전달/읽음 확인은 원래 발신자에게 같은 경로로 전달 (읽음 확인도 친구/차단 확인을 거침)
+//! - 수신자가 발신자를 차단했으면 전달하지 않음
+//! - 차단 목록은 Redis(`tcp:dm:block:{user_id}`)가 기준이므로 다른 인스턴스의 차단/해제가 바로 반영됨
+//!
+//! Redis가 연결되지 않은 경우 단일 인스턴스로 동작하며 오프라인 큐와 차단 목록은 메모리에 보관합니다.
+//! Redis 조회가 실패하면 이 인스턴스의 메모리 차단 목록으로 판단합니다.
+
+use anyhow::{Result, anyhow};
+use futures::StreamExt;
+use redis::AsyncCommands;
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::{Mutex, broadcast};
+use tracing::{info, warn, debug, error};
+
+use crate::handler::FriendHandler;
+use crate::protocol::{DeliveryStatus, GameMessage};
+use crate::service::ConnectionService;
+use shared::config::redis_config::RedisConfig;
+use shared::tool::{ErrorCode, GameServerError};
+use shared::service::redis::region_presence::PresenceStore;
+
+/// 인스턴스 간 다이렉트 메시지 채널
+const DM_CHANNEL: &str = "tcp:dm";
+
+/// 오프라인 메시지 보관 기간
+const OFFLINE_TTL: Duration = Duration::from_secs(7 * 24 * 3600);
+
+/// 사용자별 최대 오프라인 메시지 수
+const MAX_OFFLINE_MESSAGES: usize = 100;
+
+/// 인스턴스 간 전달 봉투
+#[derive(Debug, Serialize, Deserialize)]
+struct DirectMessageEnvelope {
+    target_instance: String,
+    to_user_id: u32,
+    message: GameMessage,
+}
+
+/// 메시지 라우팅 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteOutcome {
+    /// 이 인스턴스의 수신자 연결에 전달됨
+    Delivered,
+    /// 수신자가 접속한 다른 인스턴스로 전달됨
+    Forwarded,
+    /// 수신자가 오프라인이라 보관됨
+    Queued,
+}
+
+/// 다이렉트 메시지 핸들러
+pub struct DirectMessageHandler {
+    connection_service: Arc<ConnectionService>,
+    friend_handler: Arc<FriendHandler>,
+    /// 이 서버 인스턴스 식별자 (접속 정보에 기록)
+    instance_id: String,
+    /// 이 서버의 배포 지역
+    region: String,
+    redis: OnceLock<Arc<RedisConfig>>,
+    /// user_id -> 차단한 사용자 목록
+    blocklists: Arc<Mutex<HashMap<u32, HashSet<u32>>>>,
+    /// user_id -> 보관된 메시지 (Redis 미사용 시)
+    offline_queue: Arc<Mutex<HashMap<u32, VecDeque<GameMessage>>>>,
+}
+
+impl DirectMessageHandler {
+    /// 새로운 다이렉트 메시지 핸들러 생성
+    pub fn new(
+        connection_service: Arc<ConnectionService>,
+        friend_handler: Arc<FriendHandler>,
+        instance_id: String,
+    ) -> Self {
+        Self {
+            connection_service,
+            friend_handler,
+            instance_id,
+            region: "local".to_string(),
+            redis: OnceLock::new(),
+            blocklists: Arc::new(Mutex::new(HashMap::new())),
+            offline_queue: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+    
+    /// 배포 지역 설정 (기본값: "local")
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = region.into();
+        self
+    }
+    
+    /// Redis 연결 (최초 1회만 적용)
+    pub fn attach_redis(&self, redis: Arc<RedisConfig>) {
+        if self.redis.set(redis).is_err() {
+            debug!("다이렉트 메시지 핸들러에 Redis가 이미 연결되어 있습니다");
+        }
+    }
+    
+    /// 메시지 수신 시작
+    ///
+    /// 클라이언트 메시지 처리 루프와, Redis가 연결되어 있으면 인스턴스 간 구독 루프를 시작합니다.
+    pub fn start(self: &Arc<Self>) {
+        let handler = self.clone();
+        let mut rx = self.connection_service.subscribe_broadcast();
+        
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok((Some(user_id), message)) => handler.handle_client_message(user_id, message).await,
+                    Ok((None, _)) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("다이렉트 메시지 수신 지연: {}개 메시지 건너뜀", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            
+            debug!("다이렉트 메시지 수신 루프 종료");
+        });
+        
+        if let Some(redis) = self.redis.get().cloned() {
+            let handler = self.clone();
+            tokio::spawn(async move {
+                loop {
+                    if let Err(e) = handler.run_subscriber(&redis).await {
+                        error!("다이렉트 메시지 구독 실패, 5초 후 재시도: {}", e);
+                    }
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+            });
+        }
+        
+        info!("✅ 다이렉트 메시지 핸들러 시작 (인스턴스 {})", self.instance_id);
+    }
+    
+    /// 클라이언트가 보낸 다이렉트 메시지 관련 요청 처리
+    pub async fn handle_client_message(&self, user_id: u32, message: GameMessage) {
+        let result = match message {
+            GameMessage::DirectMessage { message_id, from_user_id, to_user_id, content, .. } => {
+                if from_user_id != user_id {
+                    Err(GameServerError::with_message(ErrorCode::PermissionDenied, "사용자 ID 불일치").into())
+                } else {
+                    self.send_direct_message(from_user_id, to_user_id, message_id, content).await.map(|_| ())
+                }
+            }
+            GameMessage::DirectMessageReceipt { message_id, from_user_id, to_user_id, status: DeliveryStatus::Read } => {
+                if from_user_id != user_id {
+                    Err(GameServerError::with_message(ErrorCode::PermissionDenied, "사용자 ID 불일치").into())
+                } else {
+                    self.send_read_receipt(from_user_id, to_user_id, message_id).await.map(|_| ())
+                }
+            }
+            GameMessage::BlockUser { user_id: msg_user_id, target_user_id } if msg_user_id == user_id => {
+                self.block_user(user_id, target_user_id).await;
+                Ok(())
+            }
+            GameMessage::UnblockUser { user_id: msg_user_id, target_user_id } if msg_user_id == user_id => {
+                self.unblock_user(user_id, target_user_id).await;
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+        
+        if let Err(e) = result {
+            warn!("사용자 {} 다이렉트 메시지 처리 실패: {}", user_id, e);
+            let error_message = GameMessage::from_error(&e.into());
+            if let Err(send_err) = self.connection_service.send_to_user(user_id, &error_message).await {
+                debug!("다이렉트 메시지 에러 응답 전송 실패: {}", send_err);
+            }
+        }
+    }
+    
+    /// 다이렉트 메시지 전송
+    ///
+    /// 친구 관계와 차단 여부를 확인한 뒤 수신자에게 라우팅합니다.
+    /// 이 인스턴스에서 바로 전달되면 발신자에게 전달 확인을 보냅니다.
+    pub async fn send_direct_message(
+        &self,
+        from_user_id: u32,
+        to_user_id: u32,
+        message_id: u64,
+        content: String,
+    ) -> Result<RouteOutcome> {
+        if !self.friend_handler.is_friend(from_user_id, to_user_id).await {
+            return Err(GameServerError::with_message(ErrorCode::PermissionDenied, "친구에게만 메시지를 보낼 수 있습니다").into());
+        }
+        // 차단 여부를 노출하지 않도록 같은 에러 메시지 사용
+        if self.is_blocked(to_user_id, from_user_id).await {
+            debug!("차단된 다이렉트 메시지: {} -> {}", from_user_id, to_user_id);
+            return Err(GameServerError::with_message(ErrorCode::PermissionDenied, "메시지를 보낼 수 없는 사용자입니다").into());
+        }
+        
+        let message = GameMessage::DirectMessage {
+            message_id,
+            from_user_id,
+            to_user_id,
+            content,
+            sent_at: chrono::Utc::now().timestamp(),
+        };
+        
+        let outcome = self.route(to_user_id, message.clone()).await?;
+        if outcome == RouteOutcome::Delivered {
+            self.acknowledge_delivery(&message).await;
+        }
+        
+        debug!("다이렉트 메시지 {} -> {} ({:?})", from_user_id, to_user_id, outcome);
+        Ok(outcome)
+    }
+    
+    /// 읽음 확인 전송 (수신자 → 원래 발신자)
+    ///
+    /// 원래 메시지를 보낼 수 있는 관계(발신자의 친구, 서로 차단하지 않음)일 때만 전달합니다.
+    pub async fn send_read_receipt(&self, reader_id: u32, sender_id: u32, message_id: u64) -> Result<RouteOutcome> {
+        if !self.friend_handler.is_friend(sender_id, reader_id).await
+            || self.is_blocked(reader_id, sender_id).await
+            || self.is_blocked(sender_id, reader_id).await
+        {
+            debug!("읽음 확인 거부: {} -> {}", reader_id, sender_id);
+            return Err(GameServerError::with_message(ErrorCode::PermissionDenied, "메시지를 보낼 수 없는 사용자입니다").into());
+        }
+        self.send_receipt(reader_id, sender_id, message_id, DeliveryStatus::Read).await
+    }
+    
+    /// 수신 확인 전송 (원래 발신자에게)
+    pub async fn send_receipt(
+        &self,
+        from_user_id: u32,
+        to_user_id: u32,
+        message_id: u64,
+        status: DeliveryStatus,
+    ) -> Result<RouteOutcome> {
+        let receipt = GameMessage::DirectMessageReceipt {
+            message_id,
+            from_user_id,
+            to_user_id,
+            status,
+        };
+        self.route(to_user_id, receipt).await
+    }
+    
+    /// 사용자 접속 처리
+    ///
+    /// 접속 인스턴스를 기록하고 보관된 메시지를 전달합니다.
+    pub async fn on_user_connected(&self, user_id: u32) -> usize {
+        if let Some(presence) = self.presence() {
+            if let Err(e) = presence.set_online(user_id, &self.instance_id).await {
+                warn!("다이렉트 메시지 접속 정보 저장 실패: 사용자 {} - {}", user_id, e);
+            }
+        }
+        
+        let pending = self.drain_offline(user_id).await;
+        let mut delivered = 0;
+        
+        for (index, message) in pending.iter().enumerate() {
+            if let Err(e) = self.connection_service.send_to_user(user_id, message).await {
+                warn!("사용자 {} 오프라인 메시지 전달 실패: {}", user_id, e);
+                // 전달하지 못한 메시지는 다시 보관
+                for message in &pending[index..] {
+                    self.queue_offline(user_id, message).await;
+                }
+                break;
+            }
+            self.acknowledge_delivery(message).await;
+            delivered += 1;
+        }
+        
+        if delivered > 0 {
+            info!("사용자 {} 오프라인 메시지 {}개 전달", user_id, delivered);
+        }
+        delivered
+    }
+    
+    /// 사용자 접속 해제 처리
+    pub async fn on_user_disconnected(&self, user_id: u32) {
+        // 다른 인스턴스로 재접속한 경우 덮어쓰지 않음
+        if let Some(presence) = self.presence() {
+            if let Err(e) = presence.set_offline(user_id, &self.instance_id).await {
+                warn!("다이렉트 메시지 접속 정보 삭제 실패: 사용자 {} - {}", user_id, e);
+            }
+        }
+    }
+    
+    /// 이 인스턴스에 접속한 모든 사용자의 접속 정보 삭제 (정상 종료용, 처리한 사용자 수 반환)
+    ///
+    /// 다른 인스턴스는 이후 메시지를 이 인스턴스로 보내지 않고 오프라인 보관합니다.
+    pub async fn deregister_instance(&self) -> usize {
+        let user_ids = self.connection_service.connected_user_ids().await;
+        for &user_id in &user_ids {
+            self.on_user_disconnected(user_id).await;
+        }
+        info!("다이렉트 메시지 라우팅 해제 (인스턴스 {}, 사용자 {}명)", self.instance_id, user_ids.len());
+        user_ids.len()
+    }
+    
+    /// 사용자 차단
+    pub async fn block_user(&self, user_id: u32, target_user_id: u32) {
+        self.blocklists.lock().await.entry(user_id).or_default().insert(target_user_id);
+        
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let result: redis::RedisResult<()> = conn.sadd(Self::blocklist_key(user_id), target_user_id).await;
+            if let Err(e) = result {
+                warn!("Redis 차단 목록 저장 실패: 사용자 {} - {}", user_id, e);
+            }
+        }
+        
+        info!("사용자 차단: {} -> {}", user_id, target_user_id);
+    }
+    
+    /// 사용자 차단 해제
+    pub async fn unblock_user(&self, user_id: u32, target_user_id: u32) {
+        if let Some(blocked) = self.blocklists.lock().await.get_mut(&user_id) {
+            blocked.remove(&target_user_id);
+        }
+        
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let result: redis::RedisResult<()> = conn.srem(Self::blocklist_key(user_id), target_user_id).await;
+            if let Err(e) = result {
+                warn!("Redis 차단 목록 삭제 실패: 사용자 {} - {}", user_id, e);
+            }
+        }
+        
+        info!("사용자 차단 해제: {} -> {}", user_id, target_user_id);
+    }
+    
+    /// `user_id`가 `target_user_id`를 차단했는지 확인
+    ///
+    /// Redis가 연결되어 있으면 Redis 목록이 기준입니다. (다른 인스턴스의 해제도 반영)
+    pub async fn is_blocked(&self, user_id: u32, target_user_id: u32) -> bool {
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let result: redis::RedisResult<bool> = conn.sismember(Self::blocklist_key(user_id), target_user_id).await;
+            match result {
+                Ok(blocked) => return blocked,
+                Err(e) => warn!("Redis 차단 목록 조회 실패, 메모리 목록 사용: 사용자 {} - {}", user_id, e),
+            }
+        }
+        
+        self.blocklists.lock().await
+            .get(&user_id)
+            .map(|blocked| blocked.contains(&target_user_id))
+            .unwrap_or(false)
+    }
+    
+    /// 메모리에 보관된 오프라인 메시지 수
+    pub async fn offline_count(&self, user_id: u32) -> usize {
+        self.offline_queue.lock().await
+            .get(&user_id)
+            .map(|queue| queue.len())
+            .unwrap_or(0)
+    }
+    
+    /// 수신자에게 메시지 라우팅
+    async fn route(&self, to_user_id: u32, message: GameMessage) -> Result<RouteOutcome> {
+        if self.connection_service.is_connected(to_user_id).await {
+            match self.connection_service.send_to_user(to_user_id, &message).await {
+                Ok(()) => return Ok(RouteOutcome::Delivered),
+                Err(e) => warn!("사용자 {} 다이렉트 메시지 전달 실패, 보관합니다: {}", to_user_id, e),
+            }
+        } else if let Some(redis) = self.redis.get() {
+            match self.forward_to_instance(redis, to_user_id, &message).await {
+                Ok(true) => return Ok(RouteOutcome::Forwarded),
+                Ok(false) => {}
+                Err(e) => warn!("다이렉트 메시지 인스턴스 전달 실패, 보관합니다: {}", e),
+            }
+        }
+        
+        self.queue_offline(to_user_id, &message).await;
+        Ok(RouteOutcome::Queued)
+    }
+    
+    /// 다이렉트 메시지가 전달되면 발신자에게 전달 확인
+    async fn acknowledge_delivery(&self, message: &GameMessage) {
+        if let GameMessage::DirectMessage { message_id, from_user_id, to_user_id, .. } = message {
+            if let Err(e) = self.send_receipt(*to_user_id, *from_user_id, *message_id, DeliveryStatus::Delivered).await {
+                warn!("전달 확인 전송 실패: 메시지 {} - {}", message_id, e);
+            }
+        }
+    }
+    
+    /// 수신자가 접속한 다른 인스턴스로 발행 (접속 정보가 없으면 false)
+    async fn forward_to_instance(&self, redis: &RedisConfig, to_user_id: u32, message: &GameMessage) -> Result<bool> {
+        let presence = PresenceStore::new(redis.clone(), &self.region);
+        let instance = presence.local_instance(to_user_id).await
+            .map_err(|e| anyhow!("접속 정보 조회 실패: {}", e))?;
+        
+        // 접속 정보가 이 인스턴스를 가리키면 이미 끊긴 연결
+        let target_instance = match instance {
+            Some(instance) if instance != self.instance_id => instance,
+            _ => return Ok(false),
+        };
+        
+        let envelope = DirectMessageEnvelope {
+            target_instance,
+            to_user_id,
+            message: message.clone(),
+        };
+        let payload = serde_json::to_string(&envelope)?;
+        let mut conn = redis.get_connection();
+        let _: () = conn.publish(DM_CHANNEL, payload).await
+            .map_err(|e| anyhow!("Redis PUBLISH 실패: {}", e))?;
+        Ok(true)
+    }
+    
+    /// 다른 인스턴스에서 발행한 메시지 구독
+    async fn run_subscriber(&self, redis: &RedisConfig) -> Result<()> {
+        let client = redis::Client::open(format!("redis://{}:{}", redis.host, redis.port))?;
+        let mut pubsub = client.get_async_connection().await?.into_pubsub();
+        pubsub.subscribe(DM_CHANNEL).await?;
+        info!("다이렉트 메시지 채널 구독 시작: {}", DM_CHANNEL);
+        
+        let mut messages = pubsub.into_on_message();
+        while let Some(msg) = messages.next().await {
+            let payload: String = match msg.get_payload() {
+                Ok(payload) => payload,
+                Err(e) => {
+                    warn!("다이렉트 메시지 페이로드 읽기 실패: {}", e);
+                    continue;
+                }
+            };
+            let envelope: DirectMessageEnvelope = match serde_json::from_str(&payload) {
+                Ok(envelope) => envelope,
+                Err(e) => {
+                    warn!("다이렉트 메시지 역직렬화 실패: {}", e);
+                    continue;
+                }
+            };
+            if envelope.target_instance != self.instance_id {
+                continue;
+            }
+            
+            // 발행 사이에 연결이 끊겼을 수 있으므로 다시 라우팅
+            match self.route(envelope.to_user_id, envelope.message.clone()).await {
+                Ok(RouteOutcome::Delivered) => self.acknowledge_delivery(&envelope.message).await,
+                Ok(_) => {}
+                Err(e) => warn!("인스턴스 간 다이렉트 메시지 전달 실패: {}", e),
+            }
+        }
+        
+        Err(anyhow!("다이렉트 메시지 구독 연결이 종료되었습니다"))
+    }
+    
+    /// 오프라인 메시지 보관
+    async fn queue_offline(&self, user_id: u32, message: &GameMessage) {
+        if let Some(redis) = self.redis.get() {
+            match Self::push_offline_to_redis(redis, user_id, message).await {
+                Ok(()) => return,
+                Err(e) => warn!("Redis 오프라인 메시지 보관 실패, 메모리에 보관: {}", e),
+            }
+        }
+        
+        let mut queues = self.offline_queue.lock().await;
+        let queue = queues.entry(user_id).or_default();
+        if queue.len() >= MAX_OFFLINE_MESSAGES {
+            queue.pop_front();
+        }
+        queue.push_back(message.clone());
+    }
+    
+    /// 보관된 오프라인 메시지 꺼내기 (오래된 순)
+    async fn drain_offline(&self, user_id: u32) -> Vec<GameMessage> {
+        let mut messages = Vec::new();
+        
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let key = Self::offline_key(user_id);
+            let payloads: redis::RedisResult<Vec<String>> = conn.lrange(&key, 0, -1).await;
+            match payloads {
+                Ok(payloads) if !payloads.is_empty() => {
+                    let _: redis::RedisResult<()> = conn.del(&key).await;
+                    messages.extend(payloads.iter().filter_map(|payload| serde_json::from_str(payload).ok()));
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Redis 오프라인 메시지 조회 실패: 사용자 {} - {}", user_id, e),
+            }
+        }
+        
+        if let Some(queue) = self.offline_queue.lock().await.remove(&user_id) {
+            messages.extend(queue);
+        }
+        messages
+    }
+    
+    async fn push_offline_to_redis(redis: &RedisConfig, user_id: u32, message: &GameMessage) -> Result<()> {
+        let mut conn = redis.get_connection();
+        let key = Self::offline_key(user_id);
+        let payload = serde_json::to_string(message)?;
+        let max = MAX_OFFLINE_MESSAGES as isize;
+        
+        let _: () = conn.rpush(&key, payload).await
+            .map_err(|e| anyhow!("Redis RPUSH 실패: {}", e))?;
+        let _: () = conn.ltrim(&key, -max, -1).await
+            .map_err(|e| anyhow!("Redis LTRIM 실패: {}", e))?;
+        let _: () = conn.expire(&key, OFFLINE_TTL.as_secs() as i64).await
+            .map_err(|e| anyhow!("Redis EXPIRE 실패: {}", e))?;
+        Ok(())
+    }
+    
+    /// 지역별 접속 정보 저장소 (Redis 미연결 시 None)
+    fn presence(&self) -> Option<PresenceStore> {
+        self.redis.get().map(|redis| PresenceStore::new((**redis).clone(), &self.region))
+    }
+    
+    fn offline_key(user_id: u32) -> String {
+        format!("tcp:dm:offline:{}", user_id)
+    }
+    
+    fn blocklist_key(user_id: u32) -> String {
+        format!("tcp:dm:block:{}", user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::MessageService;
+    
+    fn handler() -> (DirectMessageHandler, Arc<FriendHandler>) {
+        let connection_service = Arc::new(ConnectionService::new(100));
+        let message_service = Arc::new(MessageService::new(connection_service.clone()));
+        let friend_handler = Arc::new(FriendHandler::new(connection_service.clone(), message_service));
+        let handler = DirectMessageHandler::new(connection_service, friend_handler.clone(), "test".to_string());
+        (handler, friend_handler)
+    }
+    
+    #[tokio::test]
+    async fn test_offline_message_requires_friendship() {
+        let (handler, friend_handler) = handler();
+        
+        // 친구가 아니면 거부
+        assert!(handler.send_direct_message(1, 2, 1, "hi".to_string()).await.is_err());
+        
+        friend_handler.add_friend(1, 2, "User2".to_string()).await.unwrap();
+        let outcome = handler.send_direct_message(1, 2, 1, "hi".to_string()).await.unwrap();
+        assert_eq!(outcome, RouteOutcome::Queued);
+        assert_eq!(handler.offline_count(2).await, 1);
+        
+        // 수신자가 연결되어 있지 않으면 다시 보관
+        assert_eq!(handler.on_user_connected(2).await, 0);
+        assert_eq!(handler.offline_count(2).await, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_blocklist_enforced() {
+        let (handler, friend_handler) = handler();
+        friend_handler.add_friend(1, 2, "User2".to_string()).await.unwrap();
+        
+        handler.block_user(2, 1).await;
+        assert!(handler.is_blocked(2, 1).await);
+        assert!(!handler.is_blocked(1, 2).await);
+        assert!(handler.send_direct_message(1, 2, 1, "hi".to_string()).await.is_err());
+        assert_eq!(handler.offline_count(2).await, 0);
+        
+        handler.unblock_user(2, 1).await;
+        assert!(handler.send_direct_message(1, 2, 2, "hi".to_string()).await.is_ok());
+    }
+    
+    #[tokio::test]
+    async fn test_read_receipt_requires_friendship_and_no_block() {
+        let (handler, friend_handler) = handler();
+        
+        // 원래 발신자(1)의 친구가 아니면 읽음 확인 거부
+        assert!(handler.send_read_receipt(2, 1, 1).await.is_err());
+        
+        friend_handler.add_friend(1, 2, "User2".to_string()).await.unwrap();
+        assert_eq!(handler.send_read_receipt(2, 1, 1).await.unwrap(), RouteOutcome::Queued);
+        assert_eq!(handler.offline_count(1).await, 1);
+        
+        // 어느 쪽이든 차단했으면 거부
+        handler.block_user(1, 2).await;
+        assert!(handler.send_read_receipt(2, 1, 2).await.is_err());
+        handler.unblock_user(1, 2).await;
+        handler.block_user(2, 1).await;
+        assert!(handler.send_read_receipt(2, 1, 3).await.is_err());
+        assert_eq!(handler.offline_count(1).await, 1);
+    }
+    
+    #[tokio::test]
+    #[ignore = "needs Redis"]
+    async fn test_unblock_on_other_instance_visible_through_redis() {
+        let redis = Arc::new(RedisConfig::new().await.expect("this test needs Redis at 127.0.0.1:6379"));
+        let (instance_a, _) = handler();
+        let (instance_b, _) = handler();
+        instance_a.attach_redis(redis.clone());
+        instance_b.attach_redis(redis);
+        let (user_id, target_user_id) = (4_000_001, 4_000_002);
+        
+        instance_a.block_user(user_id, target_user_id).await;
+        assert!(instance_b.is_blocked(user_id, target_user_id).await);
+        
+        // 다른 인스턴스의 해제가 차단한 인스턴스의 판단에도 반영
+        instance_b.unblock_user(user_id, target_user_id).await;
+        assert!(!instance_a.is_blocked(user_id, target_user_id).await);
+    }
+}
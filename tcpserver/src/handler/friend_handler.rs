@@ -1,15 +1,22 @@
 //! 친구 관리 핸들러
 //! 
 //! 친구 추가/삭제 기능을 처리합니다.
+//! 
+//! 친구 접속 상태는 Redis가 연결되어 있으면 지역별 접속 정보(`presence:{region}:{user_id}`)에서
+//! 조회하므로 다른 지역에 접속한 친구도 그 지역과 함께 표시됩니다.
+//! Redis가 없으면 이 인스턴스에 접속한 친구만 온라인으로 표시합니다.
 
 use anyhow::{Result, anyhow};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use std::collections::{HashMap, HashSet};
-use tokio::sync::Mutex;
-use tracing::{info, debug};
+use tokio::sync::{Mutex, broadcast};
+use tracing::{info, debug, warn};
 use serde::{Serialize, Deserialize};
 
+use crate::protocol::{FriendPresence, GameMessage};
 use crate::service::{ConnectionService, MessageService};
+use shared::config::redis_config::RedisConfig;
+use shared::service::redis::region_presence::PresenceStore;
 
 /// 친구 정보
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +36,9 @@ pub struct FriendHandler {
     user_nicknames: Arc<Mutex<HashMap<u32, String>>>,
     /// friendship details: (user_id, friend_id) -> Friend
     friend_details: Arc<Mutex<HashMap<(u32, u32), Friend>>>,
+    /// 이 서버의 배포 지역
+    region: String,
+    presence: OnceLock<PresenceStore>,
 }
 
 impl FriendHandler {
@@ -43,9 +53,52 @@ impl FriendHandler {
             friendships: Arc::new(Mutex::new(HashMap::new())),
             user_nicknames: Arc::new(Mutex::new(HashMap::new())),
             friend_details: Arc::new(Mutex::new(HashMap::new())),
+            region: "local".to_string(),
+            presence: OnceLock::new(),
         }
     }
     
+    /// 배포 지역 설정 (기본값: "local")
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = region.into();
+        self
+    }
+    
+    /// Redis 연결 (최초 1회만 적용)
+    pub fn attach_redis(&self, redis: Arc<RedisConfig>) {
+        if self.presence.set(PresenceStore::new((*redis).clone(), &self.region)).is_err() {
+            debug!("친구 핸들러에 Redis가 이미 연결되어 있습니다");
+        }
+    }
+    
+    /// 친구 접속 상태 요청 처리 시작
+    pub fn start(self: &Arc<Self>) {
+        let handler = self.clone();
+        let mut rx = self.connection_service.subscribe_broadcast();
+        
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok((Some(user_id), GameMessage::FriendPresenceRequest { user_id: msg_user_id })) if msg_user_id == user_id => {
+                        let friends = handler.get_friend_presence(user_id).await;
+                        let response = GameMessage::FriendPresenceList { friends };
+                        if let Err(e) = handler.connection_service.send_to_user(user_id, &response).await {
+                            warn!("사용자 {} 친구 접속 상태 전송 실패: {}", user_id, e);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("친구 접속 상태 요청 수신 지연: {}개 메시지 건너뜀", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            debug!("친구 접속 상태 수신 루프 종료");
+        });
+        
+        info!("✅ 친구 핸들러 시작 (지역 {})", self.region);
+    }
+    
     /// 사용자 닉네임 등록/업데이트
     pub async fn register_user(&self, user_id: u32, nickname: String) {
         let mut nicknames = self.user_nicknames.lock().await;
@@ -132,6 +185,40 @@ impl FriendHandler {
         }
     }
     
+    /// 친구 접속 상태 조회
+    ///
+    /// 여러 지역에 접속 기록이 남아 있으면 가장 최근 기록의 지역으로 표시합니다.
+    pub async fn get_friend_presence(&self, user_id: u32) -> Vec<FriendPresence> {
+        let mut friends = self.get_friend_list(user_id).await;
+        friends.sort_by_key(|friend| friend.user_id);
+        let friend_ids: Vec<u32> = friends.iter().map(|friend| friend.user_id).collect();
+        
+        let mut online: HashMap<u32, String> = HashMap::new();
+        if let Some(presence) = self.presence.get() {
+            match presence.lookup_many(&friend_ids).await {
+                Ok(records) => online.extend(records.into_iter().map(|(id, record)| (id, record.region))),
+                Err(e) => warn!("친구 접속 정보 조회 실패, 이 인스턴스 접속자만 표시합니다: {}", e),
+            }
+        }
+        for &friend_id in &friend_ids {
+            if !online.contains_key(&friend_id) && self.connection_service.is_connected(friend_id).await {
+                online.insert(friend_id, self.region.clone());
+            }
+        }
+        
+        friends.into_iter()
+            .map(|friend| {
+                let region = online.remove(&friend.user_id);
+                FriendPresence {
+                    user_id: friend.user_id,
+                    nickname: friend.nickname,
+                    online: region.is_some(),
+                    region,
+                }
+            })
+            .collect()
+    }
+    
     /// 친구 관계 확인
     pub async fn is_friend(&self, user_id: u32, friend_user_id: u32) -> bool {
         let friendships = self.friendships.lock().await;
@@ -309,4 +396,20 @@ mod tests {
         assert!(friend_handler.are_mutual_friends(1, 2).await);
         assert!(friend_handler.are_mutual_friends(2, 1).await);
     }
+    
+    #[tokio::test]
+    async fn test_friend_presence_without_redis() {
+        let connection_service = Arc::new(crate::service::ConnectionService::new(100));
+        let message_service = Arc::new(crate::service::MessageService::new(connection_service.clone()));
+        let friend_handler = FriendHandler::new(connection_service, message_service).with_region("kr");
+        
+        friend_handler.add_friend(1, 3, "User3".to_string()).await.unwrap();
+        friend_handler.add_friend(1, 2, "User2".to_string()).await.unwrap();
+        
+        // Redis 없이 접속하지 않은 친구는 오프라인, 친구 ID 순
+        let presence = friend_handler.get_friend_presence(1).await;
+        assert_eq!(presence.iter().map(|f| f.user_id).collect::<Vec<_>>(), vec![2, 3]);
+        assert!(presence.iter().all(|f| !f.online && f.region.is_none()));
+        assert!(friend_handler.get_friend_presence(9).await.is_empty());
+    }
 }
\ No newline at end of file
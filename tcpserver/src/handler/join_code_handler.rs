@@ -0,0 +1,439 @@
+//! 참가 코드 핸들러
+//!
+//! 비공개 방에 들어올 수 있는 짧은 참가 코드(예: `K7MQ2X`)를 발급하고 사용합니다.
+//!
+//! - 방장과 부방장만 발급 ([`RoomAction::Invite`])
+//! - 코드는 유효 시간이 지나면 만료되며, `max_uses`로 일회용/횟수 제한을 둘 수 있음
+//! - 코드로 입장하면 방 비밀번호 확인을 생략
+//! - 방이 삭제되면 그 방의 코드를 모두 폐기
+//!
+//! Redis가 연결되어 있으면 코드→방 매핑을 `tcp:join_code:{code}`에 저장해 인스턴스 간에 공유하고,
+//! 사용 횟수는 `INCR`로 세어 동시에 사용해도 제한을 넘지 않습니다.
+//! Redis가 없으면 메모리에 보관합니다.
+
+use anyhow::{Result, anyhow};
+use rand::Rng;
+use redis::AsyncCommands;
+use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::{Mutex, broadcast};
+use tracing::{info, warn, debug};
+
+use crate::handler::{RoomAction, RoomActionError, RoomHandler};
+use crate::protocol::GameMessage;
+use crate::service::ConnectionService;
+use shared::config::redis_config::RedisConfig;
+use shared::tool::{ErrorCode, GameServerError};
+
+/// 참가 코드 길이
+pub const JOIN_CODE_LEN: usize = 6;
+
+/// 참가 코드 문자 (헷갈리는 0/O, 1/I 제외)
+const JOIN_CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// 참가 코드 최대 유효 시간
+pub const MAX_JOIN_CODE_TTL: Duration = Duration::from_secs(24 * 3600);
+
+/// 코드 충돌 시 재생성 횟수
+const GENERATE_ATTEMPTS: usize = 5;
+
+/// 저장되는 참가 코드 정보
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JoinCode {
+    pub code: String,
+    pub room_id: u32,
+    pub created_by: u32,
+    /// 만료 시각 (unix 초)
+    pub expires_at: i64,
+    /// 사용 가능 횟수 (None이면 무제한)
+    pub max_uses: Option<u32>,
+}
+
+/// 메모리 보관 항목 (Redis 미사용 시)
+#[derive(Debug, Clone)]
+struct LocalJoinCode {
+    code: JoinCode,
+    uses: u32,
+}
+
+/// 참가 코드 핸들러
+pub struct JoinCodeHandler {
+    connection_service: Arc<ConnectionService>,
+    room_handler: Arc<RoomHandler>,
+    default_ttl: Duration,
+    redis: OnceLock<Arc<RedisConfig>>,
+    /// code -> 참가 코드 (Redis 미사용 시)
+    local_codes: Arc<Mutex<HashMap<String, LocalJoinCode>>>,
+}
+
+impl JoinCodeHandler {
+    /// 새로운 참가 코드 핸들러 생성
+    ///
+    /// `default_ttl`은 요청에 유효 시간이 없을 때 사용하며 `MAX_JOIN_CODE_TTL`을 넘지 않습니다.
+    pub fn new(
+        connection_service: Arc<ConnectionService>,
+        room_handler: Arc<RoomHandler>,
+        default_ttl: Duration,
+    ) -> Self {
+        Self {
+            connection_service,
+            room_handler,
+            default_ttl: default_ttl.min(MAX_JOIN_CODE_TTL),
+            redis: OnceLock::new(),
+            local_codes: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Redis 연결 (최초 1회만 적용)
+    pub fn attach_redis(&self, redis: Arc<RedisConfig>) {
+        if self.redis.set(redis).is_err() {
+            debug!("참가 코드 핸들러에 Redis가 이미 연결되어 있습니다");
+        }
+    }
+
+    /// 요청 처리 루프와 방 삭제 시 코드 폐기 루프 시작
+    pub fn start(self: &Arc<Self>) {
+        let handler = self.clone();
+        let mut rx = self.connection_service.subscribe_broadcast();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok((Some(user_id), message)) => handler.handle_client_message(user_id, message).await,
+                    Ok((None, _)) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("참가 코드 요청 수신 지연: {}개 메시지 건너뜀", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            debug!("참가 코드 수신 루프 종료");
+        });
+
+        let handler = self.clone();
+        let mut closed_rooms = self.room_handler.subscribe_room_closed();
+        tokio::spawn(async move {
+            loop {
+                match closed_rooms.recv().await {
+                    Ok(room_id) => {
+                        handler.revoke_room(room_id).await;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        // 놓친 방의 코드는 사용 시 방이 없어 거부되고 TTL로 만료됨
+                        warn!("방 삭제 알림 {}개 누락", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        info!("✅ 참가 코드 핸들러 시작");
+    }
+
+    /// 클라이언트가 보낸 참가 코드 요청 처리
+    pub async fn handle_client_message(&self, user_id: u32, message: GameMessage) {
+        let response = match message {
+            GameMessage::CreateJoinCode { user_id: msg_user_id, room_id, max_uses, ttl_secs } if msg_user_id == user_id => {
+                let ttl = (ttl_secs > 0).then(|| Duration::from_secs(ttl_secs));
+                match self.create_code(user_id, room_id, max_uses, ttl).await {
+                    Ok(code) => GameMessage::JoinCodeCreated {
+                        room_id,
+                        code: code.code,
+                        expires_at: code.expires_at,
+                        max_uses: code.max_uses,
+                    },
+                    Err(e) => match e.downcast_ref::<RoomActionError>() {
+                        Some(denied) => denied.to_localized_message(
+                            self.connection_service.messages(),
+                            &self.connection_service.locale_of(user_id).await,
+                        ),
+                        None => GameMessage::from_error(&e.into()),
+                    },
+                }
+            }
+            GameMessage::JoinByCode { user_id: msg_user_id, code, nickname } if msg_user_id == user_id => {
+                match self.join_by_code(user_id, &code, nickname).await {
+                    Ok((room_id, user_count)) => GameMessage::RoomJoinSuccess { room_id, user_count },
+                    Err(e) => GameMessage::from_error(&e.into()),
+                }
+            }
+            _ => return,
+        };
+
+        if let Err(e) = self.connection_service.send_to_user(user_id, &response).await {
+            debug!("참가 코드 응답 전송 실패: 사용자 {} - {}", user_id, e);
+        }
+    }
+
+    /// 참가 코드 발급
+    ///
+    /// # Arguments
+    ///
+    /// * `actor_id` - 발급하는 사용자 (방장 또는 부방장)
+    /// * `max_uses` - 사용 가능 횟수 (Some(1)이면 일회용, None이면 무제한)
+    /// * `ttl` - 유효 시간 (None이면 기본값, 최대 `MAX_JOIN_CODE_TTL`)
+    pub async fn create_code(
+        &self,
+        actor_id: u32,
+        room_id: u32,
+        max_uses: Option<u32>,
+        ttl: Option<Duration>,
+    ) -> Result<JoinCode> {
+        if max_uses == Some(0) {
+            return Err(GameServerError::with_message(ErrorCode::InvalidInput, "사용 횟수는 1 이상이어야 합니다").into());
+        }
+        self.room_handler.check_permission(actor_id, room_id, RoomAction::Invite).await?;
+
+        let ttl = ttl.unwrap_or(self.default_ttl).clamp(Duration::from_secs(1), MAX_JOIN_CODE_TTL);
+        for _ in 0..GENERATE_ATTEMPTS {
+            let code = JoinCode {
+                code: generate_code(),
+                room_id,
+                created_by: actor_id,
+                expires_at: chrono::Utc::now().timestamp() + ttl.as_secs() as i64,
+                max_uses,
+            };
+            if self.store(&code, ttl).await? {
+                info!("방 {} 참가 코드 발급: {} (by {}, 사용 {:?}회, {}초)", room_id, code.code, actor_id, max_uses, ttl.as_secs());
+                return Ok(code);
+            }
+            debug!("참가 코드 충돌, 재생성: {}", code.code);
+        }
+        Err(anyhow!("참가 코드 생성 실패"))
+    }
+
+    /// 참가 코드로 방 입장
+    ///
+    /// 사용 횟수를 먼저 차감하고, 입장에 실패하면 되돌립니다.
+    ///
+    /// # Returns
+    ///
+    /// 입장한 방 ID와 현재 인원
+    pub async fn join_by_code(&self, user_id: u32, code: &str, nickname: String) -> Result<(u32, u32)> {
+        let code = code.trim().to_ascii_uppercase();
+        let (join_code, exhausted) = self.consume(&code).await?;
+
+        match self.room_handler.join_room_invited(user_id, join_code.room_id, nickname).await {
+            Ok(user_count) => {
+                if exhausted {
+                    self.remove(&join_code).await;
+                }
+                info!("사용자 {} 참가 코드 {}로 방 {} 입장", user_id, code, join_code.room_id);
+                Ok((join_code.room_id, user_count))
+            }
+            Err(e) => {
+                self.release(&join_code).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// 방의 참가 코드 모두 폐기
+    pub async fn revoke_room(&self, room_id: u32) -> usize {
+        let mut revoked = 0;
+
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let index_key = Self::room_index_key(room_id);
+            let codes: redis::RedisResult<Vec<String>> = conn.smembers(&index_key).await;
+            match codes {
+                Ok(codes) => {
+                    let mut keys: Vec<String> = codes
+                        .iter()
+                        .flat_map(|code| [Self::code_key(code), Self::uses_key(code)])
+                        .collect();
+                    keys.push(index_key);
+                    let _: redis::RedisResult<()> = conn.del(keys).await;
+                    revoked += codes.len();
+                }
+                Err(e) => warn!("Redis 참가 코드 폐기 실패: 방 {} - {}", room_id, e),
+            }
+        }
+
+        let mut local_codes = self.local_codes.lock().await;
+        let before = local_codes.len();
+        local_codes.retain(|_, entry| entry.code.room_id != room_id);
+        revoked += before - local_codes.len();
+        drop(local_codes);
+
+        if revoked > 0 {
+            info!("방 {} 참가 코드 {}개 폐기", room_id, revoked);
+        }
+        revoked
+    }
+
+    /// 코드 저장 (이미 있는 코드면 false)
+    async fn store(&self, code: &JoinCode, ttl: Duration) -> Result<bool> {
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let stored: Option<String> = redis::cmd("SET")
+                .arg(Self::code_key(&code.code))
+                .arg(serde_json::to_string(code)?)
+                .arg("NX")
+                .arg("EX")
+                .arg(ttl.as_secs())
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| anyhow!("Redis 참가 코드 저장 실패: {}", e))?;
+            if stored.is_none() {
+                return Ok(false);
+            }
+
+            // 폐기용 방 인덱스 (어떤 코드보다 오래 유지)
+            let index_key = Self::room_index_key(code.room_id);
+            let _: redis::RedisResult<()> = conn.sadd(&index_key, &code.code).await;
+            let _: redis::RedisResult<()> = conn.expire(&index_key, MAX_JOIN_CODE_TTL.as_secs() as i64).await;
+            return Ok(true);
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let mut local_codes = self.local_codes.lock().await;
+        local_codes.retain(|_, entry| entry.code.expires_at > now);
+        if local_codes.contains_key(&code.code) {
+            return Ok(false);
+        }
+        local_codes.insert(code.code.clone(), LocalJoinCode { code: code.clone(), uses: 0 });
+        Ok(true)
+    }
+
+    /// 코드 사용 1회 차감 (마지막 사용이면 true 반환)
+    async fn consume(&self, code: &str) -> Result<(JoinCode, bool)> {
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let payload: Option<String> = conn.get(Self::code_key(code)).await
+                .map_err(|e| anyhow!("Redis 참가 코드 조회 실패: {}", e))?;
+            let join_code: JoinCode = match payload {
+                Some(payload) => serde_json::from_str(&payload)?,
+                None => return Err(GameServerError::with_message(ErrorCode::InvalidInput, "유효하지 않거나 만료된 참가 코드입니다").into()),
+            };
+
+            let Some(max_uses) = join_code.max_uses else {
+                return Ok((join_code, false));
+            };
+            let uses_key = Self::uses_key(code);
+            let uses: u32 = conn.incr(&uses_key, 1).await
+                .map_err(|e| anyhow!("Redis 참가 코드 사용 기록 실패: {}", e))?;
+            if uses == 1 {
+                let remaining = (join_code.expires_at - chrono::Utc::now().timestamp()).max(1);
+                let _: redis::RedisResult<()> = conn.expire(&uses_key, remaining).await;
+            }
+            if uses > max_uses {
+                let _: redis::RedisResult<()> = conn.decr(&uses_key, 1).await;
+                return Err(GameServerError::with_message(ErrorCode::InvalidInput, "사용 횟수를 모두 소진한 참가 코드입니다").into());
+            }
+            return Ok((join_code, uses == max_uses));
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        let mut local_codes = self.local_codes.lock().await;
+        let entry = match local_codes.get_mut(code) {
+            Some(entry) if entry.code.expires_at > now => entry,
+            _ => {
+                local_codes.remove(code);
+                return Err(GameServerError::with_message(ErrorCode::InvalidInput, "유효하지 않거나 만료된 참가 코드입니다").into());
+            }
+        };
+        if entry.code.max_uses.is_some_and(|max_uses| entry.uses >= max_uses) {
+            return Err(GameServerError::with_message(ErrorCode::InvalidInput, "사용 횟수를 모두 소진한 참가 코드입니다").into());
+        }
+        entry.uses += 1;
+        let exhausted = entry.code.max_uses == Some(entry.uses);
+        Ok((entry.code.clone(), exhausted))
+    }
+
+    /// 입장 실패 시 차감한 사용 횟수 복구
+    async fn release(&self, code: &JoinCode) {
+        if code.max_uses.is_none() {
+            return;
+        }
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let _: redis::RedisResult<()> = conn.decr(Self::uses_key(&code.code), 1).await;
+            return;
+        }
+        if let Some(entry) = self.local_codes.lock().await.get_mut(&code.code) {
+            entry.uses = entry.uses.saturating_sub(1);
+        }
+    }
+
+    /// 모두 사용한 코드 삭제
+    async fn remove(&self, code: &JoinCode) {
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let _: redis::RedisResult<()> = conn.del(&[Self::code_key(&code.code), Self::uses_key(&code.code)]).await;
+            let _: redis::RedisResult<()> = conn.srem(Self::room_index_key(code.room_id), &code.code).await;
+            return;
+        }
+        self.local_codes.lock().await.remove(&code.code);
+    }
+
+    fn code_key(code: &str) -> String {
+        format!("tcp:join_code:{}", code)
+    }
+
+    fn uses_key(code: &str) -> String {
+        format!("tcp:join_code:{}:uses", code)
+    }
+
+    fn room_index_key(room_id: u32) -> String {
+        format!("tcp:join_code:room:{}", room_id)
+    }
+}
+
+/// 무작위 참가 코드 생성
+fn generate_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..JOIN_CODE_LEN)
+        .map(|_| JOIN_CODE_ALPHABET[rng.gen_range(0..JOIN_CODE_ALPHABET.len())] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::MessageService;
+
+    fn handler() -> (JoinCodeHandler, Arc<RoomHandler>) {
+        let connection_service = Arc::new(ConnectionService::new(100));
+        let message_service = Arc::new(MessageService::new(connection_service.clone()));
+        let room_handler = Arc::new(RoomHandler::new(connection_service.clone(), message_service));
+        let handler = JoinCodeHandler::new(connection_service, room_handler.clone(), Duration::from_secs(60));
+        (handler, room_handler)
+    }
+
+    #[tokio::test]
+    async fn test_single_use_code_bypasses_password() {
+        let (handler, rooms) = handler();
+        let room_id = rooms.create_room(1, "비공개".to_string()).await.unwrap();
+        rooms.join_room(1, room_id, "Host".to_string()).await.unwrap();
+        rooms.join_room(2, room_id, "Member".to_string()).await.unwrap();
+        rooms.set_password(1, room_id, Some("pw".to_string())).await.unwrap();
+
+        // 일반 참가자는 발급 불가
+        assert!(handler.create_code(2, room_id, Some(1), None).await.is_err());
+
+        let code = handler.create_code(1, room_id, Some(1), None).await.unwrap();
+        assert_eq!(code.code.len(), JOIN_CODE_LEN);
+
+        let (joined, user_count) = handler.join_by_code(3, &code.code.to_lowercase(), "Guest".to_string()).await.unwrap();
+        assert_eq!((joined, user_count), (room_id, 3));
+        assert!(handler.join_by_code(4, &code.code, "Guest2".to_string()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failed_join_keeps_use_and_room_close_revokes() {
+        let (handler, rooms) = handler();
+        let room_id = rooms.create_room(1, "A".to_string()).await.unwrap();
+        rooms.join_room(1, room_id, "Host".to_string()).await.unwrap();
+
+        let code = handler.create_code(1, room_id, Some(1), None).await.unwrap();
+        // 이미 방에 있는 사용자는 입장 실패, 사용 횟수는 복구
+        assert!(handler.join_by_code(1, &code.code, "Host".to_string()).await.is_err());
+        assert!(handler.join_by_code(2, &code.code, "Guest".to_string()).await.is_ok());
+
+        let unlimited = handler.create_code(1, room_id, None, None).await.unwrap();
+        assert_eq!(handler.revoke_room(room_id).await, 1);
+        assert!(handler.join_by_code(3, &unlimited.code, "Guest".to_string()).await.is_err());
+    }
+}
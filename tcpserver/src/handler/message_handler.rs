@@ -3,24 +3,35 @@
 //! 채팅 메시지 및 서버 메시지별 처리 로직을 정의합니다.
 
 use anyhow::{Result, anyhow};
-use std::sync::Arc;
-use std::collections::HashMap;
-use tokio::sync::Mutex;
+use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, VecDeque};
 use tracing::{info, error, warn, debug};
 
-use crate::protocol::GameMessage;
-use crate::service::{ConnectionService, HeartbeatService, MessageService};
+use crate::protocol::{ChatContent, DeliveryStatus, GameMessage};
+use crate::service::{ConnectionService, HeartbeatService, MessageService, StickerCatalog, MAX_CHAT_TEXT_LEN};
+use crate::service::heartbeat_service::MAX_REPORTED_RTT_MS;
+use shared::tool::{ErrorCode, GameServerError};
 // Removed circular dependency - handlers should be injected or use events
 
+/// 방별 보관하는 최근 채팅 수
+pub const CHAT_HISTORY_LIMIT: usize = 200;
+
 /// 채팅 메시지 기록
-#[derive(Debug, Clone)]
+/// 
+/// 일반 채팅은 `message_id` 없이 텍스트로, 확장 채팅은 ID/답장 대상과 함께 저장됩니다.
+#[derive(Debug, Clone, PartialEq)]
 pub struct ChatRecord {
     pub user_id: u32,
     pub room_id: u32,
-    pub content: String,
+    pub message_id: Option<u64>,
+    pub content: ChatContent,
+    pub reply_to: Option<u64>,
     pub timestamp: i64,
 }
 
+/// 방별 최근 채팅 기록: room_id -> 오래된 순
+type ChatHistory = Arc<Mutex<HashMap<u32, VecDeque<ChatRecord>>>>;
+
 /// 메시지 핸들러
 /// 
 /// 순환 의존성을 피하기 위해 다른 핸들러들에 대한 직접 참조를 제거했습니다.
@@ -29,8 +40,10 @@ pub struct ServerMessageHandler {
     connection_service: Arc<ConnectionService>,
     heartbeat_service: Arc<HeartbeatService>,
     message_service: Arc<MessageService>,
-    /// 채팅 기록: room_id -> Vec<ChatRecord>
-    chat_history: Arc<Mutex<HashMap<u32, Vec<ChatRecord>>>>,
+    /// 채팅 기록 (방별 최근 `CHAT_HISTORY_LIMIT`개)
+    chat_history: ChatHistory,
+    /// 스티커 카탈로그
+    stickers: Arc<StickerCatalog>,
 }
 
 impl ServerMessageHandler {
@@ -57,9 +70,16 @@ impl ServerMessageHandler {
             heartbeat_service,
             message_service,
             chat_history: Arc::new(Mutex::new(HashMap::new())),
+            stickers: Arc::new(StickerCatalog::default()),
         }
     }
     
+    /// 스티커 카탈로그 설정 (기본값은 빈 카탈로그 - 스티커 전송 불가)
+    pub fn with_stickers(mut self, stickers: Arc<StickerCatalog>) -> Self {
+        self.stickers = stickers;
+        self
+    }
+    
     /// 모든 메시지 핸들러 등록
     /// 
     /// 시스템에서 사용하는 모든 메시지 타입에 대한 핸들러를 등록합니다.
@@ -107,9 +127,17 @@ impl ServerMessageHandler {
     /// # 처리 메시지
     /// 
     /// * `GameMessage::HeartBeat` → `GameMessage::HeartBeatResponse`
+    /// * `GameMessage::HeartBeatRtt` → 연결 품질 갱신 (응답 없음)
     async fn register_heartbeat_handler(&self) -> Result<()> {
         let heartbeat_service = self.heartbeat_service.clone();
         
+        self.message_service.register_handler("heartbeat_rtt", move |user_id, message| {
+            if let GameMessage::HeartBeatRtt { rtt_ms } = message {
+                heartbeat_service.record_rtt(user_id, (*rtt_ms).min(MAX_REPORTED_RTT_MS));
+            }
+            Ok(None)
+        }).await;
+        
         self.message_service.register_handler("heartbeat", move |user_id, message| {
             match message {
                 GameMessage::HeartBeat => {
@@ -177,10 +205,10 @@ impl ServerMessageHandler {
             match message {
                 GameMessage::RoomJoin { user_id: msg_user_id, room_id, nickname: _ } => {
                     if *msg_user_id != user_id {
-                        return Ok(Some(GameMessage::Error {
-                            code: 400,
-                            message: "사용자 ID 불일치".to_string(),
-                        }));
+                        return Ok(Some(GameMessage::from_error(&GameServerError::with_message(
+                            ErrorCode::PermissionDenied,
+                            "사용자 ID 불일치",
+                        ))));
                     }
                     
                     // 임시 구현 - 실제로는 방 핸들러를 사용해야 함
@@ -193,34 +221,75 @@ impl ServerMessageHandler {
         
         // 채팅 핸들러 (간소화)
         // 동기 핸들러로 변경 - async 블록 제거
+        let chat_history = self.chat_history.clone();
         self.message_service.register_handler("chat", move |user_id, message| {
             match message {
                 GameMessage::ChatMessage { user_id: msg_user_id, room_id, message } => {
                     if *msg_user_id != user_id {
-                        return Ok(Some(GameMessage::Error {
-                            code: 400,
-                            message: "사용자 ID 불일치".to_string(),
-                        }));
+                        return Ok(Some(GameMessage::from_error(&GameServerError::with_message(
+                            ErrorCode::PermissionDenied,
+                            "사용자 ID 불일치",
+                        ))));
                     }
                     
-                    // 채팅 기록은 이벤트 시스템이나 별도 처리로 이동 필요
-                    // 현재는 로그만 남김
                     info!("채팅 메시지 수신: 사용자 {} -> 방 {}: {}", msg_user_id, room_id, message);
+                    record_chat(&chat_history, ChatRecord {
+                        user_id,
+                        room_id: *room_id,
+                        message_id: None,
+                        content: ChatContent::Text { text: message.clone() },
+                        reply_to: None,
+                        timestamp: chrono::Utc::now().timestamp(),
+                    });
                     Ok(None)
                 }
                 _ => Ok(None)
             }
         }).await;
         
+        // 확장 채팅 핸들러 (스티커, 답장)
+        let chat_history = self.chat_history.clone();
+        let stickers = self.stickers.clone();
+        self.message_service.register_handler("rich_chat", move |user_id, message| {
+            match message {
+                GameMessage::RichChatMessage { user_id: msg_user_id, room_id, message_id, content, reply_to } => {
+                    let checked = if *msg_user_id != user_id {
+                        Err(anyhow!("사용자 ID 불일치"))
+                    } else {
+                        stickers.validate(content)
+                    };
+                    let record = ChatRecord {
+                        user_id,
+                        room_id: *room_id,
+                        message_id: Some(*message_id),
+                        content: content.clone(),
+                        reply_to: *reply_to,
+                        timestamp: chrono::Utc::now().timestamp(),
+                    };
+                    match checked.and_then(|()| record_rich_chat(&chat_history, record)) {
+                        Ok(()) => {
+                            debug!("확장 채팅 수신: 사용자 {} -> 방 {} (#{})", user_id, room_id, message_id);
+                            Ok(None)
+                        }
+                        Err(e) => Ok(Some(GameMessage::ChatResponse {
+                            success: false,
+                            error: Some(e.to_string()),
+                        })),
+                    }
+                }
+                _ => Ok(None)
+            }
+        }).await;
+        
         // 친구 추가 핸들러 (간소화)
         self.message_service.register_handler("friend_add", move |user_id, message| {
             match message {
                 GameMessage::FriendAdd { user_id: msg_user_id, friend_user_id, nickname: _ } => {
                     if *msg_user_id != user_id {
-                        return Ok(Some(GameMessage::Error {
-                            code: 400,
-                            message: "사용자 ID 불일치".to_string(),
-                        }));
+                        return Ok(Some(GameMessage::from_error(&GameServerError::with_message(
+                            ErrorCode::PermissionDenied,
+                            "사용자 ID 불일치",
+                        ))));
                     }
                     
                     // 임시 구현 - 실제로는 친구 핸들러 사용 필요
@@ -236,10 +305,10 @@ impl ServerMessageHandler {
             match message {
                 GameMessage::FriendRemove { user_id: msg_user_id, friend_user_id } => {
                     if *msg_user_id != user_id {
-                        return Ok(Some(GameMessage::Error {
-                            code: 400,
-                            message: "사용자 ID 불일치".to_string(),
-                        }));
+                        return Ok(Some(GameMessage::from_error(&GameServerError::with_message(
+                            ErrorCode::PermissionDenied,
+                            "사용자 ID 불일치",
+                        ))));
                     }
                     
                     // 임시 구현 - 실제로는 친구 핸들러 사용 필요
@@ -273,13 +342,16 @@ impl ServerMessageHandler {
     /// println!("방 1의 채팅 기록: {} 개", history.len());
     /// ```
     pub async fn get_chat_history(&self, room_id: u32) -> Vec<ChatRecord> {
-        let history = self.chat_history.lock().await;
-        history.get(&room_id).cloned().unwrap_or_else(Vec::new)
+        let history = self.chat_history.lock().unwrap_or_else(|e| e.into_inner());
+        history
+            .get(&room_id)
+            .map(|records| records.iter().cloned().collect())
+            .unwrap_or_default()
     }
     
     /// 채팅 기록 정리
     pub async fn cleanup_chat_history(&self, room_id: u32) {
-        let mut history = self.chat_history.lock().await;
+        let mut history = self.chat_history.lock().unwrap_or_else(|e| e.into_inner());
         history.remove(&room_id);
         debug!("방 {} 채팅 기록 정리 완료", room_id);
     }
@@ -354,11 +426,20 @@ impl ServerMessageHandler {
                 if message.is_empty() {
                     return Err(anyhow!("채팅 내용이 비어있습니다"));
                 }
-                if message.len() > 1000 {
+                if message.len() > MAX_CHAT_TEXT_LEN {
                     return Err(anyhow!("채팅 내용이 너무 깁니다"));
                 }
                 Ok(())
             }
+            GameMessage::RichChatMessage { user_id: msg_user_id, message_id, content, reply_to, .. } => {
+                if *msg_user_id != user_id {
+                    return Err(anyhow!("사용자 ID 불일치"));
+                }
+                if *reply_to == Some(*message_id) {
+                    return Err(anyhow!("자기 자신에게 답장할 수 없습니다"));
+                }
+                self.stickers.validate(content)
+            }
             GameMessage::FriendAdd { user_id: msg_user_id, friend_user_id, nickname } => {
                 if *msg_user_id != user_id {
                     return Err(anyhow!("사용자 ID 불일치"));
@@ -380,13 +461,51 @@ impl ServerMessageHandler {
                 }
                 Ok(())
             }
-            GameMessage::Connect { room_id: _, user_id: msg_user_id } => {
+            GameMessage::DirectMessage { from_user_id, to_user_id, content, .. } => {
+                if *from_user_id != user_id {
+                    return Err(anyhow!("사용자 ID 불일치"));
+                }
+                if *from_user_id == *to_user_id {
+                    return Err(anyhow!("자기 자신에게 메시지를 보낼 수 없습니다"));
+                }
+                if content.is_empty() {
+                    return Err(anyhow!("메시지 내용이 비어있습니다"));
+                }
+                if content.len() > 1000 {
+                    return Err(anyhow!("메시지 내용이 너무 깁니다"));
+                }
+                Ok(())
+            }
+            GameMessage::DirectMessageReceipt { from_user_id, status, .. } => {
+                if *from_user_id != user_id {
+                    return Err(anyhow!("사용자 ID 불일치"));
+                }
+                // 전달 확인은 서버만 생성
+                if *status != DeliveryStatus::Read {
+                    return Err(anyhow!("클라이언트는 읽음 확인만 보낼 수 있습니다"));
+                }
+                Ok(())
+            }
+            GameMessage::BlockUser { user_id: msg_user_id, target_user_id }
+            | GameMessage::UnblockUser { user_id: msg_user_id, target_user_id } => {
+                if *msg_user_id != user_id {
+                    return Err(anyhow!("사용자 ID 불일치"));
+                }
+                if *msg_user_id == *target_user_id {
+                    return Err(anyhow!("자기 자신을 차단할 수 없습니다"));
+                }
+                Ok(())
+            }
+            GameMessage::Connect { user_id: msg_user_id, .. } => {
                 // Connect 메시지는 연결 시에만 사용되므로 여기서는 검증만
                 if *msg_user_id != user_id {
                     return Err(anyhow!("Connect 메시지의 사용자 ID 불일치"));
                 }
                 Ok(())
             }
+            GameMessage::Reconnect { .. } => {
+                Err(anyhow!("Reconnect 메시지는 연결 시 첫 메시지로만 보낼 수 있습니다"))
+            }
             GameMessage::RoomLeave { user_id: msg_user_id, room_id: _ } => {
                 if *msg_user_id != user_id {
                     return Err(anyhow!("사용자 ID 불일치"));
@@ -418,10 +537,109 @@ impl ServerMessageHandler {
             GameMessage::SystemMessage { .. } => {
                 Err(anyhow!("클라이언트는 SystemMessage를 보낼 수 없습니다"))
             }
+            GameMessage::SessionToken { .. } => {
+                Err(anyhow!("클라이언트는 SessionToken 메시지를 보낼 수 없습니다"))
+            }
+            GameMessage::ReconnectAck { .. } => {
+                Err(anyhow!("클라이언트는 ReconnectAck 메시지를 보낼 수 없습니다"))
+            }
+            GameMessage::RoomRoleChanged { .. } => {
+                Err(anyhow!("클라이언트는 RoomRoleChanged 메시지를 보낼 수 없습니다"))
+            }
+            GameMessage::CreateJoinCode { user_id: msg_user_id, max_uses, .. } => {
+                if *msg_user_id != user_id {
+                    return Err(anyhow!("사용자 ID 불일치"));
+                }
+                if *max_uses == Some(0) {
+                    return Err(anyhow!("사용 횟수는 1 이상이어야 합니다"));
+                }
+                Ok(())
+            }
+            GameMessage::JoinCodeCreated { .. } => {
+                Err(anyhow!("클라이언트는 JoinCodeCreated 메시지를 보낼 수 없습니다"))
+            }
+            GameMessage::JoinByCode { user_id: msg_user_id, code, nickname } => {
+                if *msg_user_id != user_id {
+                    return Err(anyhow!("사용자 ID 불일치"));
+                }
+                if code.is_empty() {
+                    return Err(anyhow!("참가 코드가 비어있습니다"));
+                }
+                if nickname.is_empty() {
+                    return Err(anyhow!("닉네임이 비어있습니다"));
+                }
+                Ok(())
+            }
+            GameMessage::FriendPresenceRequest { user_id: msg_user_id } => {
+                if *msg_user_id != user_id {
+                    return Err(anyhow!("사용자 ID 불일치"));
+                }
+                Ok(())
+            }
+            GameMessage::FriendPresenceList { .. } => {
+                Err(anyhow!("클라이언트는 FriendPresenceList 메시지를 보낼 수 없습니다"))
+            }
+            GameMessage::HeartBeatRtt { rtt_ms } => {
+                if *rtt_ms > MAX_REPORTED_RTT_MS {
+                    return Err(anyhow!("하트비트 왕복 시간이 너무 큽니다: {}ms", rtt_ms));
+                }
+                Ok(())
+            }
+            GameMessage::QueuePosition { .. } => {
+                Err(anyhow!("클라이언트는 QueuePosition 메시지를 보낼 수 없습니다"))
+            }
+            GameMessage::ServerBusy { .. } => {
+                Err(anyhow!("클라이언트는 ServerBusy 메시지를 보낼 수 없습니다"))
+            }
+            GameMessage::CreateRoom { user_id: msg_user_id, name, nickname } => {
+                if *msg_user_id != user_id {
+                    return Err(anyhow!("사용자 ID 불일치"));
+                }
+                if name.trim().is_empty() {
+                    return Err(anyhow!("방 이름이 비어있습니다"));
+                }
+                if nickname.is_empty() {
+                    return Err(anyhow!("닉네임이 비어있습니다"));
+                }
+                Ok(())
+            }
+            GameMessage::RoomCreated { .. } => {
+                Err(anyhow!("클라이언트는 RoomCreated 메시지를 보낼 수 없습니다"))
+            }
         }
     }
 }
 
+/// 채팅 기록 추가 (방별 최근 `CHAT_HISTORY_LIMIT`개 유지)
+fn record_chat(history: &ChatHistory, record: ChatRecord) {
+    let mut history = history.lock().unwrap_or_else(|e| e.into_inner());
+    push_record(history.entry(record.room_id).or_default(), record);
+}
+
+/// 확장 채팅 기록 추가 (메시지 ID 중복, 답장 대상 확인)
+fn record_rich_chat(history: &ChatHistory, record: ChatRecord) -> Result<()> {
+    let mut history = history.lock().unwrap_or_else(|e| e.into_inner());
+    let records = history.entry(record.room_id).or_default();
+    let exists = |message_id: u64| records.iter().any(|r| r.message_id == Some(message_id));
+    if record.message_id.is_some_and(exists) {
+        return Err(anyhow!("중복된 메시지 ID입니다"));
+    }
+    if let Some(reply_to) = record.reply_to {
+        if !exists(reply_to) {
+            return Err(anyhow!("답장할 메시지를 찾을 수 없습니다: {}", reply_to));
+        }
+    }
+    push_record(records, record);
+    Ok(())
+}
+
+fn push_record(records: &mut VecDeque<ChatRecord>, record: ChatRecord) {
+    if records.len() >= CHAT_HISTORY_LIMIT {
+        records.pop_front();
+    }
+    records.push_back(record);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -449,4 +667,30 @@ mod tests {
         };
         assert!(handler.validate_message(1, &chat_msg).is_ok());
     }
+    
+    #[test]
+    fn test_rich_chat_history() {
+        let history: ChatHistory = Arc::new(Mutex::new(HashMap::new()));
+        let record = |message_id: Option<u64>, reply_to: Option<u64>| ChatRecord {
+            user_id: 1,
+            room_id: 7,
+            message_id,
+            content: ChatContent::Sticker { sticker_id: "gg".to_string() },
+            reply_to,
+            timestamp: 0,
+        };
+        
+        assert!(record_rich_chat(&history, record(Some(1), None)).is_ok());
+        assert!(record_rich_chat(&history, record(Some(2), Some(1))).is_ok());
+        assert!(record_rich_chat(&history, record(Some(2), None)).is_err());
+        assert!(record_rich_chat(&history, record(Some(3), Some(99))).is_err());
+        
+        // 일반 채팅과 같은 기록에 저장되고 한도를 넘으면 오래된 것부터 삭제
+        for _ in 0..CHAT_HISTORY_LIMIT {
+            record_chat(&history, record(None, None));
+        }
+        let records = &history.lock().unwrap()[&7];
+        assert_eq!(records.len(), CHAT_HISTORY_LIMIT);
+        assert!(records.iter().all(|r| r.message_id.is_none()));
+    }
 }
\ No newline at end of file
@@ -10,9 +10,19 @@ pub mod room_handler;
 pub mod friend_handler;
 pub mod chat_room_handler;
 pub mod chat_room_message_handler;
+pub mod direct_message_handler;
+pub mod chat_event_relay;
+pub mod join_code_handler;
+pub mod server_stats_reporter;
+pub mod session_eviction_listener;
 
 pub use message_handler::*;
 pub use connection_handler::*;
 pub use room_handler::*;
 pub use friend_handler::*;
-pub use chat_room_message_handler::*;
\ No newline at end of file
+pub use chat_room_message_handler::*;
+pub use direct_message_handler::*;
+pub use chat_event_relay::*;
+pub use join_code_handler::*;
+pub use server_stats_reporter::*;
+pub use session_eviction_listener::*;
\ No newline at end of file
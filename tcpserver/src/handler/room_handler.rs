@@ -1,15 +1,26 @@
 //! 방 관리 핸들러
 //! 
 //! 방 입장 기능을 처리합니다.
+//! 
+//! 방 참가자는 방장(host), 부방장(moderator), 일반(member) 역할을 가지며
+//! 추방/이름 변경/비밀번호 설정은 역할에 따라 허용됩니다. ([`RoomRole::can`])
+//! 방장이 나가거나 연결이 끊기면 부방장, 일반 참가자 순으로 가장 먼저 입장한 사용자에게 방장이 넘어갑니다.
+//! 방이 삭제되면 [`RoomHandler::subscribe_room_closed`] 구독자에게 알립니다. (참가 코드 폐기 등)
 
 use anyhow::{Result, anyhow};
 use std::sync::Arc;
 use std::collections::HashMap;
-use tokio::sync::Mutex;
-use tracing::{info, debug};
+use std::fmt;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, Mutex};
+use tracing::{info, debug, warn};
 use serde::{Serialize, Deserialize};
 
+use crate::protocol::{GameMessage, RoomRole};
 use crate::service::{ConnectionService, MessageService};
+use crate::tool::i18n::{MessageCatalog, MessageKey};
+use shared::tool::high_performance::MetricsCollector;
+use shared::tool::{ErrorCode, GameServerError};
 
 /// 방 정보
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +30,36 @@ pub struct Room {
     pub users: HashMap<u32, RoomUserInfo>,
     pub max_users: u32,
     pub created_at: i64,
+    /// 방을 만든 사용자 ID
+    pub creator_id: u32,
+    /// 입장 비밀번호 (메모리에만 보관)
+    #[serde(skip)]
+    pub password: Option<String>,
+}
+
+impl Room {
+    /// 현재 방장
+    pub fn host_id(&self) -> Option<u32> {
+        self.users
+            .values()
+            .find(|user| user.role == RoomRole::Host)
+            .map(|user| user.user_id)
+    }
+    
+    /// 방장 승계 대상 (부방장 우선, 먼저 입장한 순)
+    fn next_host(&self) -> Option<u32> {
+        self.users
+            .values()
+            .min_by_key(|user| (user.role != RoomRole::Moderator, user.joined_at, user.user_id))
+            .map(|user| user.user_id)
+    }
+    
+    fn role_of(&self, user_id: u32) -> Result<RoomRole, RoomActionError> {
+        self.users
+            .get(&user_id)
+            .map(|user| user.role)
+            .ok_or(RoomActionError::NotInRoom { user_id })
+    }
 }
 
 /// 방 내 사용자 정보
@@ -27,23 +68,225 @@ pub struct RoomUserInfo {
     pub user_id: u32,
     pub nickname: String,
     pub joined_at: i64,
+    #[serde(default)]
+    pub role: RoomRole,
+}
+
+/// 역할이 필요한 방 관리 동작
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoomAction {
+    /// 참가자 추방 (자신보다 낮은 역할만)
+    Kick,
+    /// 방 이름 변경
+    Rename,
+    /// 입장 비밀번호 설정/해제
+    SetPassword,
+    /// 부방장 지정/해제 및 방장 위임
+    AssignRole,
+    /// 참가 코드 발급
+    Invite,
+}
+
+impl RoomRole {
+    /// 역할별 허용 동작
+    pub fn can(&self, action: RoomAction) -> bool {
+        match self {
+            RoomRole::Host => true,
+            RoomRole::Moderator => matches!(action, RoomAction::Kick | RoomAction::Rename | RoomAction::Invite),
+            RoomRole::Member => false,
+        }
+    }
+    
+    fn rank(&self) -> u8 {
+        match self {
+            RoomRole::Host => 2,
+            RoomRole::Moderator => 1,
+            RoomRole::Member => 0,
+        }
+    }
+}
+
+/// 방 관리 동작 거부 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoomActionError {
+    /// 방이 없음
+    RoomNotFound(u32),
+    /// 사용자가 방에 없음
+    NotInRoom { user_id: u32 },
+    /// 역할 권한 부족
+    PermissionDenied { action: RoomAction, role: RoomRole },
+    /// 잘못된 대상 (자기 자신, 같거나 높은 역할, 빈 이름 등)
+    InvalidTarget(&'static str),
+}
+
+impl RoomActionError {
+    /// 공통 에러 코드
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            RoomActionError::RoomNotFound(_) => ErrorCode::RoomNotFound,
+            RoomActionError::NotInRoom { .. } => ErrorCode::NotInRoom,
+            RoomActionError::PermissionDenied { .. } => ErrorCode::PermissionDenied,
+            RoomActionError::InvalidTarget(_) => ErrorCode::InvalidInput,
+        }
+    }
+    
+    /// 클라이언트 에러 코드
+    pub fn code(&self) -> u16 {
+        self.error_code().numeric()
+    }
+    
+    /// 클라이언트에 보낼 에러 메시지
+    pub fn to_game_message(&self) -> GameMessage {
+        GameMessage::Error {
+            code: self.code(),
+            message: self.to_string(),
+        }
+    }
+    
+    /// 사용자 로케일로 만든 에러 메시지
+    pub fn to_localized_message(&self, messages: &MessageCatalog, locale: &str) -> GameMessage {
+        let message = match self {
+            RoomActionError::RoomNotFound(room_id) => {
+                messages.render(locale, MessageKey::RoomNotFound, &[("room_id", room_id)])
+            }
+            RoomActionError::NotInRoom { user_id } => {
+                messages.render(locale, MessageKey::RoomNotInRoom, &[("user_id", user_id)])
+            }
+            RoomActionError::PermissionDenied { .. } => messages.render(locale, MessageKey::RoomPermissionDenied, &[]),
+            RoomActionError::InvalidTarget(reason) => {
+                messages.render(locale, MessageKey::RoomInvalidTarget, &[("reason", reason)])
+            }
+        };
+        GameMessage::Error { code: self.code(), message }
+    }
+}
+
+impl fmt::Display for RoomActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomActionError::RoomNotFound(room_id) => write!(f, "방을 찾을 수 없습니다: {}", room_id),
+            RoomActionError::NotInRoom { user_id } => write!(f, "사용자가 방에 없습니다: {}", user_id),
+            RoomActionError::PermissionDenied { action, role } => {
+                write!(f, "권한이 없습니다: {:?} ({:?})", action, role)
+            }
+            RoomActionError::InvalidTarget(reason) => write!(f, "잘못된 요청: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for RoomActionError {}
+
+/// 방 생성 제한
+#[derive(Debug, Clone)]
+pub struct RoomLimits {
+    /// 서버 전체 최대 방 수
+    pub max_rooms: u32,
+    /// 방당 최대 사용자 수
+    pub max_users_per_room: u32,
+    /// 사용자당 동시에 보유할 수 있는 최대 방 수
+    pub max_rooms_per_user: u32,
+    /// 같은 사용자의 연속 방 생성 간격
+    pub creation_cooldown: Duration,
+}
+
+impl Default for RoomLimits {
+    fn default() -> Self {
+        Self {
+            max_rooms: 100,
+            max_users_per_room: 50,
+            max_rooms_per_user: 3,
+            creation_cooldown: Duration::from_secs(10),
+        }
+    }
+}
+
+/// 방 생성 거부 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoomCreateError {
+    /// 방 이름이 비어있음
+    EmptyName,
+    /// 서버 전체 방 수 제한 도달
+    ServerFull { current: usize, max: u32 },
+    /// 사용자 방 생성 한도 초과
+    UserQuotaExceeded { owned: usize, max: u32 },
+    /// 생성 쿨다운 중
+    Cooldown { retry_after_secs: u64 },
+}
+
+impl RoomCreateError {
+    /// 공통 에러 코드
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            RoomCreateError::EmptyName => ErrorCode::MissingField,
+            RoomCreateError::UserQuotaExceeded { .. } | RoomCreateError::Cooldown { .. } => ErrorCode::RateLimited,
+            RoomCreateError::ServerFull { .. } => ErrorCode::ServerFull,
+        }
+    }
+    
+    /// 클라이언트 에러 코드
+    pub fn code(&self) -> u16 {
+        self.error_code().numeric()
+    }
+    
+    /// 메트릭 라벨용 사유
+    pub fn reason(&self) -> &'static str {
+        match self {
+            RoomCreateError::EmptyName => "empty_name",
+            RoomCreateError::ServerFull { .. } => "server_full",
+            RoomCreateError::UserQuotaExceeded { .. } => "user_quota",
+            RoomCreateError::Cooldown { .. } => "cooldown",
+        }
+    }
+    
+    /// 클라이언트에 보낼 에러 메시지
+    pub fn to_game_message(&self) -> GameMessage {
+        GameMessage::Error {
+            code: self.code(),
+            message: self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for RoomCreateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomCreateError::EmptyName => write!(f, "방 이름이 비어있습니다"),
+            RoomCreateError::ServerFull { current, max } => {
+                write!(f, "최대 방 수 초과: {}/{}", current, max)
+            }
+            RoomCreateError::UserQuotaExceeded { owned, max } => {
+                write!(f, "사용자 방 생성 한도 초과: {}/{}", owned, max)
+            }
+            RoomCreateError::Cooldown { retry_after_secs } => {
+                write!(f, "방 생성 대기 중입니다. {}초 후 다시 시도하세요", retry_after_secs)
+            }
+        }
+    }
 }
 
+impl std::error::Error for RoomCreateError {}
+
 /// 방 관리 핸들러
 pub struct RoomHandler {
     connection_service: Arc<ConnectionService>,
     message_service: Arc<MessageService>,
     rooms: Arc<Mutex<HashMap<u32, Room>>>,
     next_room_id: Arc<Mutex<u32>>,
-    max_rooms: u32,
-    max_users_per_room: u32,
+    limits: RoomLimits,
+    /// user_id -> 마지막 방 생성 시각
+    last_created: Arc<Mutex<HashMap<u32, Instant>>>,
+    /// 사유별 방 생성 거부 수
+    rejections: Arc<Mutex<HashMap<&'static str, u64>>>,
+    metrics: Option<Arc<MetricsCollector>>,
+    /// 삭제된 방 ID 알림
+    closed_rooms: broadcast::Sender<u32>,
 }
 
 impl RoomHandler {
     /// 새로운 방 핸들러 생성
     /// 
     /// 방 관리 기능을 제공하는 핸들러 인스턴스를 생성합니다.
-    /// 최대 100개의 방과 방당 최대 10명의 사용자를 지원합니다.
+    /// 기본 제한은 `RoomLimits::default()`를 따르며 `with_limits`로 변경할 수 있습니다.
     /// 
     /// # Arguments
     /// 
@@ -68,15 +311,83 @@ impl RoomHandler {
             message_service,
             rooms: Arc::new(Mutex::new(HashMap::new())),
             next_room_id: Arc::new(Mutex::new(1)),
-            max_rooms: 100,
-            max_users_per_room: 50,
+            limits: RoomLimits::default(),
+            last_created: Arc::new(Mutex::new(HashMap::new())),
+            rejections: Arc::new(Mutex::new(HashMap::new())),
+            metrics: None,
+            closed_rooms: broadcast::channel(64).0,
+        }
+    }
+    
+    /// 방 생성 제한 설정
+    pub fn with_limits(mut self, limits: RoomLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+    
+    /// 방 생성 거부 메트릭 기록 (`tcp_room_create_rejections_total{reason}`)
+    pub fn with_metrics(mut self, metrics: Arc<MetricsCollector>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+    
+    /// 방 삭제 알림 구독
+    pub fn subscribe_room_closed(&self) -> broadcast::Receiver<u32> {
+        self.closed_rooms.subscribe()
+    }
+    
+    /// 방 생성 요청 처리 루프 시작
+    pub fn start(self: &Arc<Self>) {
+        let handler = self.clone();
+        let mut rx = self.connection_service.subscribe_broadcast();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok((Some(user_id), message)) => handler.handle_client_message(user_id, message).await,
+                    Ok((None, _)) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("방 생성 요청 수신 지연: {}개 메시지 건너뜀", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            debug!("방 요청 수신 루프 종료");
+        });
+        
+        info!("✅ 방 핸들러 시작");
+    }
+    
+    /// 클라이언트가 보낸 방 생성 요청 처리
+    /// 
+    /// 생성 제한을 통과하면 요청한 사용자가 방장으로 입장하고 `RoomCreated`로 응답합니다.
+    pub async fn handle_client_message(&self, user_id: u32, message: GameMessage) {
+        let response = match message {
+            GameMessage::CreateRoom { user_id: msg_user_id, name, nickname } if msg_user_id == user_id => {
+                if nickname.is_empty() {
+                    GameMessage::from_error(&GameServerError::with_message(ErrorCode::MissingField, "닉네임이 비어있습니다"))
+                } else {
+                    match self.create_room(user_id, name.clone()).await {
+                        Ok(room_id) => match self.join_room(user_id, room_id, nickname).await {
+                            Ok(()) => GameMessage::RoomCreated { room_id, name },
+                            Err(e) => GameMessage::from_error(&e.into()),
+                        },
+                        Err(e) => e.to_game_message(),
+                    }
+                }
+            }
+            _ => return,
+        };
+        
+        if let Err(e) = self.connection_service.send_to_user(user_id, &response).await {
+            debug!("방 생성 응답 전송 실패: 사용자 {} - {}", user_id, e);
         }
     }
     
     /// 새로운 방 생성
     /// 
-    /// 새로운 게임 방을 생성하고 생성자를 자동으로 입장시킵니다.
-    /// 방 이름은 공백이 아니어야 하며, 최대 방 수 제한을 확인합니다.
+    /// 새로운 게임 방을 생성합니다.
+    /// 방 이름은 공백이 아니어야 하며, 서버 전체 방 수, 사용자별 보유 방 수,
+    /// 생성 쿨다운을 확인합니다. 거부 시 `RoomCreateError::to_game_message`로 응답할 수 있습니다.
     /// 
     /// # Arguments
     /// 
@@ -85,13 +396,14 @@ impl RoomHandler {
     /// 
     /// # Returns
     /// 
-    /// * `Result<u32>` - 성공 시 생성된 방 ID, 실패 시 에러
+    /// * `Result<u32, RoomCreateError>` - 성공 시 생성된 방 ID, 실패 시 거부 사유
     /// 
     /// # Errors
     /// 
     /// * 방 이름이 비어있는 경우
     /// * 최대 방 수 초과
-    /// * 사용자가 이미 다른 방에 있는 경우
+    /// * 사용자 방 생성 한도 초과
+    /// * 생성 쿨다운 중인 경우
     /// 
     /// # Examples
     /// 
@@ -99,10 +411,15 @@ impl RoomHandler {
     /// let room_id = handler.create_room(123, "새로운 방".to_string()).await?;
     /// println!("방 {} 생성 완료", room_id);
     /// ```
-    pub async fn create_room(&self, creator_user_id: u32, room_name: String) -> Result<u32> {
-        let room_count = self.rooms.lock().await.len();
-        if room_count >= self.max_rooms as usize {
-            return Err(anyhow!("최대 방 수 초과: {}/{}", room_count, self.max_rooms));
+    pub async fn create_room(&self, creator_user_id: u32, room_name: String) -> Result<u32, RoomCreateError> {
+        // 동시 요청이 함께 한도를 통과하지 않도록 확인과 삽입을 같은 잠금 안에서 처리
+        let mut rooms = self.rooms.lock().await;
+        let mut last_created = self.last_created.lock().await;
+        if let Err(e) = self.check_create_limits(creator_user_id, &room_name, &rooms, &last_created) {
+            drop(last_created);
+            drop(rooms);
+            self.record_rejection(creator_user_id, &e).await;
+            return Err(e);
         }
         
         let mut next_id = self.next_room_id.lock().await;
@@ -114,16 +431,71 @@ impl RoomHandler {
             room_id,
             name: room_name.clone(),
             users: HashMap::new(),
-            max_users: self.max_users_per_room,
+            max_users: self.limits.max_users_per_room,
             created_at: chrono::Utc::now().timestamp(),
+            creator_id: creator_user_id,
+            password: None,
         };
         
-        self.rooms.lock().await.insert(room_id, room);
+        rooms.insert(room_id, room);
+        last_created.insert(creator_user_id, Instant::now());
         
         info!("✅ 방 생성: {} (ID: {}, 생성자: {})", room_name, room_id, creator_user_id);
         Ok(room_id)
     }
     
+    /// 방 생성 제한 확인 (호출자가 `rooms`, `last_created` 잠금을 보유)
+    fn check_create_limits(
+        &self,
+        creator_user_id: u32,
+        room_name: &str,
+        rooms: &HashMap<u32, Room>,
+        last_created: &HashMap<u32, Instant>,
+    ) -> Result<(), RoomCreateError> {
+        if room_name.trim().is_empty() {
+            return Err(RoomCreateError::EmptyName);
+        }
+        
+        if let Some(last) = last_created.get(&creator_user_id) {
+            let elapsed = last.elapsed();
+            if elapsed < self.limits.creation_cooldown {
+                let remaining = self.limits.creation_cooldown - elapsed;
+                return Err(RoomCreateError::Cooldown {
+                    retry_after_secs: remaining.as_secs().max(1),
+                });
+            }
+        }
+        
+        if rooms.len() >= self.limits.max_rooms as usize {
+            return Err(RoomCreateError::ServerFull {
+                current: rooms.len(),
+                max: self.limits.max_rooms,
+            });
+        }
+        
+        let owned = rooms.values().filter(|room| room.creator_id == creator_user_id).count();
+        if owned >= self.limits.max_rooms_per_user as usize {
+            return Err(RoomCreateError::UserQuotaExceeded {
+                owned,
+                max: self.limits.max_rooms_per_user,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// 방 생성 거부 기록
+    async fn record_rejection(&self, creator_user_id: u32, error: &RoomCreateError) {
+        *self.rejections.lock().await.entry(error.reason()).or_insert(0) += 1;
+        
+        if let Some(metrics) = &self.metrics {
+            let labels = HashMap::from([("reason".to_string(), error.reason().to_string())]);
+            metrics.increment_counter("tcp_room_create_rejections_total", labels);
+        }
+        
+        warn!("방 생성 거부: 사용자 {} - {}", creator_user_id, error);
+    }
+    
     /// 방 입장
     /// 
     /// 사용자를 지정된 방에 입장시킵니다. 방이 존재하지 않거나 가득 찬 경우 실패합니다.
@@ -153,23 +525,61 @@ impl RoomHandler {
     /// println!("방 입장 완료");
     /// ```
     pub async fn join_room(&self, user_id: u32, room_id: u32, nickname: String) -> Result<()> {
+        self.join_room_with_password(user_id, room_id, nickname, None).await
+    }
+    
+    /// 비밀번호가 설정된 방 입장
+    /// 
+    /// 방장이 없는 방에 입장하면 방장이 됩니다.
+    pub async fn join_room_with_password(
+        &self,
+        user_id: u32,
+        room_id: u32,
+        nickname: String,
+        password: Option<&str>,
+    ) -> Result<()> {
+        self.admit(user_id, room_id, nickname, Some(password)).await
+    }
+    
+    /// 초대받은 사용자 입장 (참가 코드로 확인했으므로 비밀번호 생략)
+    pub async fn join_room_invited(&self, user_id: u32, room_id: u32, nickname: String) -> Result<u32> {
+        self.admit(user_id, room_id, nickname, None).await?;
+        Ok(self.rooms.lock().await.get(&room_id).map_or(0, |room| room.users.len() as u32))
+    }
+    
+    /// 방 입장 처리 (`password`가 None이면 비밀번호 확인 생략)
+    async fn admit(
+        &self,
+        user_id: u32,
+        room_id: u32,
+        nickname: String,
+        password: Option<Option<&str>>,
+    ) -> Result<()> {
         let mut rooms = self.rooms.lock().await;
         
         let room = rooms.get_mut(&room_id)
-            .ok_or_else(|| anyhow!("방을 찾을 수 없습니다: {}", room_id))?;
+            .ok_or_else(|| GameServerError::with_detail(ErrorCode::RoomNotFound, format!("room_id={}", room_id)))?;
+        
+        if let Some(password) = password {
+            if room.password.is_some() && room.password.as_deref() != password {
+                return Err(GameServerError::with_message(ErrorCode::PermissionDenied, "방 비밀번호가 일치하지 않습니다").into());
+            }
+        }
         
         if room.users.len() >= room.max_users as usize {
-            return Err(anyhow!("방이 가득 참: {}/{}", room.users.len(), room.max_users));
+            return Err(GameServerError::with_detail(ErrorCode::RoomFull, format!("{}/{}", room.users.len(), room.max_users)).into());
         }
         
         if room.users.contains_key(&user_id) {
-            return Err(anyhow!("이미 방에 참가한 사용자입니다"));
+            return Err(GameServerError::with_message(ErrorCode::InvalidAction, "이미 방에 참가한 사용자입니다").into());
         }
         
+        let role = if room.host_id().is_none() { RoomRole::Host } else { RoomRole::Member };
         let user_info = RoomUserInfo {
             user_id,
             nickname: nickname.clone(),
             joined_at: chrono::Utc::now().timestamp(),
+            role,
         };
         
         room.users.insert(user_id, user_info);
@@ -178,12 +588,12 @@ impl RoomHandler {
         Ok(())
     }
     
-    /// 방 퇴장
+    /// 방 퇴장 (방장이면 다음 참가자에게 승계)
     pub async fn leave_room(&self, user_id: u32, room_id: u32) -> Result<()> {
         let mut rooms = self.rooms.lock().await;
         
         let room = rooms.get_mut(&room_id)
-            .ok_or_else(|| anyhow!("방을 찾을 수 없습니다: {}", room_id))?;
+            .ok_or_else(|| GameServerError::with_detail(ErrorCode::RoomNotFound, format!("room_id={}", room_id)))?;
         
         if let Some(user_info) = room.users.remove(&user_id) {
             info!("사용자 {}({})가 방 {}에서 퇴장", user_info.nickname, user_id, room_id);
@@ -191,12 +601,145 @@ impl RoomHandler {
             // 방이 비었으면 삭제
             if room.users.is_empty() {
                 rooms.remove(&room_id);
+                let _ = self.closed_rooms.send(room_id);
                 info!("빈 방 삭제: {}", room_id);
+                return Ok(());
+            }
+            
+            if user_info.role == RoomRole::Host {
+                if let Some(new_host) = room.next_host() {
+                    if let Some(user) = room.users.get_mut(&new_host) {
+                        user.role = RoomRole::Host;
+                    }
+                    let recipients: Vec<u32> = room.users.keys().copied().collect();
+                    drop(rooms);
+                    info!("방 {} 방장 승계: {} -> {}", room_id, user_id, new_host);
+                    self.notify(&recipients, GameMessage::RoomRoleChanged { room_id, user_id: new_host, role: RoomRole::Host }).await;
+                }
             }
             
             Ok(())
         } else {
-            Err(anyhow!("사용자가 방에 없습니다: {}", user_id))
+            Err(GameServerError::with_detail(ErrorCode::NotInRoom, format!("user_id={}", user_id)).into())
+        }
+    }
+    
+    /// 연결 해제된 사용자를 방에서 제거
+    pub async fn on_user_disconnected(&self, user_id: u32) {
+        if let Some(room_id) = self.get_user_room(user_id).await {
+            if let Err(e) = self.leave_room(user_id, room_id).await {
+                debug!("연결 해제 사용자 {} 방 정리 실패: {}", user_id, e);
+            }
+        }
+    }
+    
+    /// 참가자 추방 (자신보다 낮은 역할만 가능)
+    pub async fn kick_user(&self, actor_id: u32, room_id: u32, target_id: u32) -> Result<(), RoomActionError> {
+        let mut rooms = self.rooms.lock().await;
+        let room = rooms.get_mut(&room_id).ok_or(RoomActionError::RoomNotFound(room_id))?;
+        let actor_role = Self::authorize(room, actor_id, RoomAction::Kick)?;
+        if actor_id == target_id {
+            return Err(RoomActionError::InvalidTarget("자기 자신은 추방할 수 없습니다"));
+        }
+        if room.role_of(target_id)?.rank() >= actor_role.rank() {
+            return Err(RoomActionError::InvalidTarget("같거나 높은 역할은 추방할 수 없습니다"));
+        }
+        
+        let Some(target) = room.users.remove(&target_id) else {
+            return Err(RoomActionError::NotInRoom { user_id: target_id });
+        };
+        let recipients: Vec<u32> = room.users.keys().copied().collect();
+        let user_count = room.users.len() as u32;
+        drop(rooms);
+        
+        info!("방 {}에서 사용자 {} 추방 (by {})", room_id, target_id, actor_id);
+        if let Err(e) = self.connection_service.send_notice(target_id, MessageKey::RoomKicked, &[("room_id", &room_id)]).await {
+            debug!("추방 알림 전송 실패: 사용자 {} - {}", target_id, e);
+        }
+        self.notify(&recipients, GameMessage::UserLeftRoom {
+            room_id,
+            user_id: target_id,
+            nickname: target.nickname,
+            user_count,
+        }).await;
+        Ok(())
+    }
+    
+    /// 방 이름 변경
+    pub async fn rename_room(&self, actor_id: u32, room_id: u32, name: String) -> Result<(), RoomActionError> {
+        if name.trim().is_empty() {
+            return Err(RoomActionError::InvalidTarget("방 이름이 비어있습니다"));
+        }
+        let mut rooms = self.rooms.lock().await;
+        let room = rooms.get_mut(&room_id).ok_or(RoomActionError::RoomNotFound(room_id))?;
+        Self::authorize(room, actor_id, RoomAction::Rename)?;
+        
+        info!("방 {} 이름 변경: {} -> {} (by {})", room_id, room.name, name, actor_id);
+        room.name = name;
+        Ok(())
+    }
+    
+    /// 입장 비밀번호 설정 (None이면 해제)
+    pub async fn set_password(&self, actor_id: u32, room_id: u32, password: Option<String>) -> Result<(), RoomActionError> {
+        let mut rooms = self.rooms.lock().await;
+        let room = rooms.get_mut(&room_id).ok_or(RoomActionError::RoomNotFound(room_id))?;
+        Self::authorize(room, actor_id, RoomAction::SetPassword)?;
+        
+        room.password = password.filter(|password| !password.is_empty());
+        info!("방 {} 비밀번호 {} (by {})", room_id, if room.password.is_some() { "설정" } else { "해제" }, actor_id);
+        Ok(())
+    }
+    
+    /// 참가자 역할 변경 (방장 지정 시 기존 방장은 부방장이 됨)
+    pub async fn set_role(&self, actor_id: u32, room_id: u32, target_id: u32, role: RoomRole) -> Result<(), RoomActionError> {
+        let mut rooms = self.rooms.lock().await;
+        let room = rooms.get_mut(&room_id).ok_or(RoomActionError::RoomNotFound(room_id))?;
+        Self::authorize(room, actor_id, RoomAction::AssignRole)?;
+        if actor_id == target_id {
+            return Err(RoomActionError::InvalidTarget("자신의 역할은 변경할 수 없습니다"));
+        }
+        room.role_of(target_id)?;
+        
+        let mut changes = vec![(target_id, role)];
+        if role == RoomRole::Host {
+            changes.push((actor_id, RoomRole::Moderator));
+        }
+        for &(user_id, role) in &changes {
+            if let Some(user) = room.users.get_mut(&user_id) {
+                user.role = role;
+            }
+        }
+        let recipients: Vec<u32> = room.users.keys().copied().collect();
+        drop(rooms);
+        
+        for (user_id, role) in changes {
+            info!("방 {} 사용자 {} 역할 변경: {:?} (by {})", room_id, user_id, role, actor_id);
+            self.notify(&recipients, GameMessage::RoomRoleChanged { room_id, user_id, role }).await;
+        }
+        Ok(())
+    }
+    
+    /// 요청자가 방에서 동작을 수행할 수 있는지 확인
+    pub async fn check_permission(&self, actor_id: u32, room_id: u32, action: RoomAction) -> Result<RoomRole, RoomActionError> {
+        let rooms = self.rooms.lock().await;
+        let room = rooms.get(&room_id).ok_or(RoomActionError::RoomNotFound(room_id))?;
+        Self::authorize(room, actor_id, action)
+    }
+    
+    /// 동작 권한 확인 후 요청자 역할 반환
+    fn authorize(room: &Room, actor_id: u32, action: RoomAction) -> Result<RoomRole, RoomActionError> {
+        let role = room.role_of(actor_id)?;
+        if !role.can(action) {
+            return Err(RoomActionError::PermissionDenied { action, role });
+        }
+        Ok(role)
+    }
+    
+    async fn notify(&self, recipients: &[u32], message: GameMessage) {
+        for &user_id in recipients {
+            if let Err(e) = self.connection_service.send_to_user(user_id, &message).await {
+                debug!("방 알림 전송 실패: 사용자 {} - {}", user_id, e);
+            }
         }
     }
     
@@ -256,6 +799,7 @@ impl RoomHandler {
         
         for room_id in rooms_to_remove {
             rooms.remove(&room_id);
+            let _ = self.closed_rooms.send(room_id);
             removed_count += 1;
             debug!("방 정리: {}", room_id);
         }
@@ -277,8 +821,12 @@ impl RoomHandler {
         RoomStats {
             total_rooms,
             total_users,
-            max_rooms: self.max_rooms,
-            max_users_per_room: self.max_users_per_room,
+            max_rooms: self.limits.max_rooms,
+            max_users_per_room: self.limits.max_users_per_room,
+            create_rejections: self.rejections.lock().await
+                .iter()
+                .map(|(reason, count)| (reason.to_string(), *count))
+                .collect(),
         }
     }
 }
@@ -300,6 +848,8 @@ pub struct RoomStats {
     pub total_users: usize,
     pub max_rooms: u32,
     pub max_users_per_room: u32,
+    /// 사유별 방 생성 거부 수
+    pub create_rejections: HashMap<String, u64>,
 }
 
 #[cfg(test)]
@@ -334,4 +884,163 @@ mod tests {
         // 방 정리
         room_handler.cleanup_rooms().await;
     }
+    
+    #[tokio::test]
+    async fn test_room_create_limits() {
+        let connection_service = Arc::new(crate::service::ConnectionService::new(100));
+        let message_service = Arc::new(crate::service::MessageService::new(connection_service.clone()));
+        let room_handler = RoomHandler::new(connection_service, message_service).with_limits(RoomLimits {
+            max_rooms: 3,
+            max_users_per_room: 10,
+            max_rooms_per_user: 2,
+            creation_cooldown: Duration::ZERO,
+        });
+        
+        assert_eq!(room_handler.create_room(1, " ".to_string()).await, Err(RoomCreateError::EmptyName));
+        
+        // 사용자별 한도
+        room_handler.create_room(1, "A".to_string()).await.unwrap();
+        room_handler.create_room(1, "B".to_string()).await.unwrap();
+        let quota = room_handler.create_room(1, "C".to_string()).await.unwrap_err();
+        assert_eq!(quota, RoomCreateError::UserQuotaExceeded { owned: 2, max: 2 });
+        assert_eq!(quota.error_code(), ErrorCode::RateLimited);
+        
+        // 서버 전체 한도
+        room_handler.create_room(2, "D".to_string()).await.unwrap();
+        let full = room_handler.create_room(3, "E".to_string()).await.unwrap_err();
+        assert!(matches!(full, RoomCreateError::ServerFull { current: 3, max: 3 }));
+        assert!(matches!(full.to_game_message(), GameMessage::Error { code: 2503, .. }));
+        
+        let stats = room_handler.get_room_stats().await;
+        assert_eq!(stats.create_rejections.get("user_quota"), Some(&1));
+        assert_eq!(stats.create_rejections.get("server_full"), Some(&1));
+    }
+    
+    #[tokio::test]
+    async fn test_concurrent_creates_respect_user_quota() {
+        let connection_service = Arc::new(crate::service::ConnectionService::new(100));
+        let message_service = Arc::new(crate::service::MessageService::new(connection_service.clone()));
+        let room_handler = Arc::new(RoomHandler::new(connection_service, message_service).with_limits(RoomLimits {
+            max_rooms: 100,
+            max_users_per_room: 10,
+            max_rooms_per_user: 2,
+            creation_cooldown: Duration::ZERO,
+        }));
+        
+        let tasks: Vec<_> = (0..16)
+            .map(|i| {
+                let room_handler = room_handler.clone();
+                tokio::spawn(async move { room_handler.create_room(1, format!("방{}", i)).await })
+            })
+            .collect();
+        let mut created = 0;
+        for task in tasks {
+            if task.await.unwrap().is_ok() {
+                created += 1;
+            }
+        }
+        
+        assert_eq!(created, 2);
+        assert_eq!(room_handler.get_room_stats().await.total_rooms, 2);
+    }
+    
+    #[tokio::test]
+    async fn test_create_room_message_applies_limits() {
+        let connection_service = Arc::new(crate::service::ConnectionService::new(100));
+        let message_service = Arc::new(crate::service::MessageService::new(connection_service.clone()));
+        let room_handler = RoomHandler::new(connection_service, message_service);
+        let create = |name: &str| GameMessage::CreateRoom {
+            user_id: 1,
+            name: name.to_string(),
+            nickname: "Host".to_string(),
+        };
+        
+        // 생성한 사용자는 방장으로 입장
+        room_handler.handle_client_message(1, create("A")).await;
+        let room_id = room_handler.get_user_room(1).await.unwrap();
+        assert_eq!(room_handler.get_room_details(room_id).await.unwrap().host_id(), Some(1));
+        
+        // 쿨다운 중인 두 번째 요청은 거부
+        room_handler.handle_client_message(1, create("B")).await;
+        let stats = room_handler.get_room_stats().await;
+        assert_eq!(stats.total_rooms, 1);
+        assert_eq!(stats.create_rejections.get("cooldown"), Some(&1));
+        
+        // 다른 사용자 ID로 보낸 요청은 무시
+        room_handler.handle_client_message(2, create("C")).await;
+        assert_eq!(room_handler.get_room_stats().await.total_rooms, 1);
+    }
+    
+    #[tokio::test]
+    async fn test_room_permissions() {
+        let connection_service = Arc::new(crate::service::ConnectionService::new(100));
+        let message_service = Arc::new(crate::service::MessageService::new(connection_service.clone()));
+        let room_handler = RoomHandler::new(connection_service, message_service);
+        
+        let room_id = room_handler.create_room(1, "A".to_string()).await.unwrap();
+        for user_id in 1..=4 {
+            room_handler.join_room(user_id, room_id, format!("User{}", user_id)).await.unwrap();
+        }
+        assert_eq!(room_handler.get_room_details(room_id).await.unwrap().host_id(), Some(1));
+        
+        // 일반 참가자는 관리 동작 불가
+        assert_eq!(
+            room_handler.rename_room(2, room_id, "B".to_string()).await,
+            Err(RoomActionError::PermissionDenied { action: RoomAction::Rename, role: RoomRole::Member })
+        );
+        
+        // 부방장은 이름 변경/일반 참가자 추방만 가능
+        room_handler.set_role(1, room_id, 2, RoomRole::Moderator).await.unwrap();
+        assert!(room_handler.rename_room(2, room_id, "B".to_string()).await.is_ok());
+        assert!(matches!(
+            room_handler.set_password(2, room_id, Some("pw".to_string())).await,
+            Err(RoomActionError::PermissionDenied { .. })
+        ));
+        assert!(matches!(room_handler.kick_user(2, room_id, 1).await, Err(RoomActionError::InvalidTarget(_))));
+        assert!(room_handler.kick_user(2, room_id, 4).await.is_ok());
+        assert_eq!(room_handler.get_user_room(4).await, None);
+        
+        // 방장만 비밀번호 설정
+        room_handler.set_password(1, room_id, Some("pw".to_string())).await.unwrap();
+        assert!(room_handler.join_room(5, room_id, "User5".to_string()).await.is_err());
+        assert!(room_handler.join_room_with_password(5, room_id, "User5".to_string(), Some("pw")).await.is_ok());
+    }
+    
+    #[tokio::test]
+    async fn test_host_migration() {
+        let connection_service = Arc::new(crate::service::ConnectionService::new(100));
+        let message_service = Arc::new(crate::service::MessageService::new(connection_service.clone()));
+        let room_handler = RoomHandler::new(connection_service, message_service);
+        
+        let room_id = room_handler.create_room(1, "A".to_string()).await.unwrap();
+        for user_id in 1..=3 {
+            room_handler.join_room(user_id, room_id, format!("User{}", user_id)).await.unwrap();
+        }
+        room_handler.set_role(1, room_id, 3, RoomRole::Moderator).await.unwrap();
+        
+        // 부방장이 먼저 입장한 일반 참가자보다 우선
+        room_handler.on_user_disconnected(1).await;
+        let room = room_handler.get_room_details(room_id).await.unwrap();
+        assert_eq!(room.host_id(), Some(3));
+        
+        // 방장 위임 시 기존 방장은 부방장
+        room_handler.set_role(3, room_id, 2, RoomRole::Host).await.unwrap();
+        let room = room_handler.get_room_details(room_id).await.unwrap();
+        assert_eq!(room.host_id(), Some(2));
+        assert_eq!(room.users[&3].role, RoomRole::Moderator);
+    }
+    
+    #[tokio::test]
+    async fn test_room_create_cooldown() {
+        let connection_service = Arc::new(crate::service::ConnectionService::new(100));
+        let message_service = Arc::new(crate::service::MessageService::new(connection_service.clone()));
+        let room_handler = RoomHandler::new(connection_service, message_service);
+        
+        room_handler.create_room(1, "A".to_string()).await.unwrap();
+        let cooldown = room_handler.create_room(1, "B".to_string()).await.unwrap_err();
+        assert!(matches!(cooldown, RoomCreateError::Cooldown { retry_after_secs } if retry_after_secs > 0));
+        
+        // 다른 사용자는 영향 없음
+        assert!(room_handler.create_room(2, "C".to_string()).await.is_ok());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,116 @@
+//! 서버 상태 리포터
+//!
+//! 현재 동시 접속자 수와 방 수를 주기적으로 Redis 하트비트 키(`server_stats:tcp:{server_id}`)에 기록합니다.
+//! gRPC 통계 서비스가 이 키들을 모아 프로토콜/지역별 CCU를 집계합니다.
+//!
+//! Redis가 연결되지 않은 경우 아무것도 기록하지 않습니다.
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::task::AbortHandle;
+use tracing::{info, warn, debug};
+
+use crate::handler::RoomHandler;
+use crate::service::ConnectionService;
+use shared::config::redis_config::RedisConfig;
+use shared::service::redis::server_stats::{ServerHeartbeat, ServerStatsStore};
+
+/// 하트비트 기록 주기
+const REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// 하트비트 프로토콜 이름 (입장 티켓도 이 이름으로 발급됨)
+pub(crate) const PROTOCOL: &str = "tcp";
+
+/// 서버 상태 리포터
+pub struct ServerStatsReporter {
+    connection_service: Arc<ConnectionService>,
+    room_handler: Arc<RoomHandler>,
+    server_id: String,
+    region: String,
+    store: OnceLock<ServerStatsStore>,
+    /// 주기적 기록 태스크 (시작한 경우)
+    task: OnceLock<AbortHandle>,
+}
+
+impl ServerStatsReporter {
+    pub fn new(
+        connection_service: Arc<ConnectionService>,
+        room_handler: Arc<RoomHandler>,
+        server_id: String,
+        region: String,
+    ) -> Self {
+        Self {
+            connection_service,
+            room_handler,
+            server_id,
+            region,
+            store: OnceLock::new(),
+            task: OnceLock::new(),
+        }
+    }
+
+    /// Redis 연결
+    pub fn attach_redis(&self, redis_config: Arc<RedisConfig>) {
+        if self.store.set(ServerStatsStore::new((*redis_config).clone())).is_err() {
+            debug!("서버 상태 리포터에 Redis가 이미 연결되어 있습니다");
+        }
+    }
+
+    /// 현재 상태 하트비트 생성
+    pub async fn snapshot(&self) -> ServerHeartbeat {
+        let ccu = self.connection_service.get_connection_count().await as u64;
+        let rooms = self.room_handler.get_room_stats().await.total_rooms as u64;
+        ServerHeartbeat::new(self.server_id.clone(), PROTOCOL, self.region.clone(), ccu, rooms)
+            .with_capacity(self.connection_service.max_connections() as u64)
+    }
+
+    /// 주기적 기록 시작
+    pub fn start(self: &Arc<Self>) {
+        if self.store.get().is_none() {
+            info!("Redis 미연결 - 서버 상태 리포터 비활성화");
+            return;
+        }
+
+        let reporter = self.clone();
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(REPORT_INTERVAL);
+            loop {
+                interval.tick().await;
+                reporter.report().await;
+            }
+        });
+        if self.task.set(handle.abort_handle()).is_err() {
+            debug!("서버 상태 리포터가 이미 시작되어 있습니다");
+        }
+
+        info!("✅ 서버 상태 리포터 시작 (server_id={}, region={})", self.server_id, self.region);
+    }
+
+    /// 기록을 멈추고 하트비트 삭제 (정상 종료 시 접속 라우팅에서 바로 제외)
+    pub async fn deregister(&self) {
+        if let Some(task) = self.task.get() {
+            task.abort();
+        }
+        let Some(store) = self.store.get() else {
+            return;
+        };
+
+        let heartbeat = self.snapshot().await;
+        match store.remove(&heartbeat).await {
+            Ok(()) => info!("서버 상태 하트비트 삭제 (server_id={})", self.server_id),
+            Err(e) => warn!("서버 상태 하트비트 삭제 실패: {}", e),
+        }
+    }
+
+    /// 하트비트 기록
+    async fn report(&self) {
+        let Some(store) = self.store.get() else {
+            return;
+        };
+
+        let heartbeat = self.snapshot().await;
+        if let Err(e) = store.publish(&heartbeat).await {
+            warn!("서버 상태 하트비트 기록 실패: {}", e);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! 세션 종료 이벤트 처리
+//!
+//! gRPC 서버가 동시 세션 한도로 기존 세션을 밀어내면 이벤트 버스(Redis `events:session_evicted`)에 발행합니다.
+//! 이 서버에 해당 사용자가 밀려난 세션(리프레시 토큰 패밀리)으로 맺은 연결이 있으면 안내 후 연결을 끊습니다.
+//! 다른 세션으로 맺은 연결은 유지합니다.
+//! Connect에 패밀리 ID를 보내지 않은 이전 클라이언트는 새 로그인 이전에 맺은 연결인지로 판단합니다.
+//!
+//! 이벤트 버스가 연결되지 않은 경우 아무것도 하지 않습니다.
+
+use std::sync::{Arc, OnceLock};
+use tracing::{debug, info, warn};
+
+use crate::service::ConnectionService;
+use crate::tool::MessageKey;
+use shared::service::redis::event_bus::{EventBus, SessionEvicted};
+
+/// 세션 종료 이벤트 리스너
+pub struct SessionEvictionListener {
+    connection_service: Arc<ConnectionService>,
+    event_bus: OnceLock<EventBus>,
+}
+
+impl SessionEvictionListener {
+    pub fn new(connection_service: Arc<ConnectionService>) -> Self {
+        Self {
+            connection_service,
+            event_bus: OnceLock::new(),
+        }
+    }
+
+    /// 이벤트 버스 연결
+    pub fn attach_event_bus(&self, event_bus: EventBus) {
+        if self.event_bus.set(event_bus).is_err() {
+            debug!("세션 종료 리스너에 이벤트 버스가 이미 연결되어 있습니다");
+        }
+    }
+
+    /// 세션 종료 이벤트 구독 시작
+    pub fn start(self: &Arc<Self>) {
+        let Some(event_bus) = self.event_bus.get().cloned() else {
+            info!("이벤트 버스 미연결 - 세션 종료 리스너 비활성화");
+            return;
+        };
+
+        let listener = self.clone();
+        tokio::spawn(async move {
+            let mut rx = match event_bus.subscribe_session_evicted().await {
+                Ok(rx) => rx,
+                Err(e) => {
+                    warn!("세션 종료 이벤트 구독 실패: {}", e);
+                    return;
+                }
+            };
+            info!("✅ 세션 종료 리스너 시작");
+
+            while let Some(event) = rx.recv().await {
+                listener.handle_eviction(&event).await;
+            }
+            debug!("세션 종료 리스너 종료");
+        });
+    }
+
+    /// 밀려난 세션으로 맺은 연결이면 종료 (종료했으면 true)
+    pub async fn handle_eviction(&self, event: &SessionEvicted) -> bool {
+        let Some(info) = self.connection_service.get_user_info(event.user_id).await else {
+            return false;
+        };
+        let evicted = match &info.family_id {
+            Some(family_id) => *family_id == event.family_id,
+            None => info.connected_timestamp < event.evicted_at,
+        };
+        if !evicted {
+            debug!("사용자 {}의 연결은 밀려난 세션이 아니라 유지합니다 (family={})", event.user_id, event.family_id);
+            return false;
+        }
+
+        if let Err(e) = self.connection_service.send_notice(event.user_id, MessageKey::SessionEvicted, &[]).await {
+            debug!("사용자 {} 세션 종료 안내 실패: {}", event.user_id, e);
+        }
+        let removed = self.connection_service.remove_connection(event.user_id).await;
+        if removed {
+            info!("동시 세션 한도로 사용자 {} 연결 종료 (family={})", event.user_id, event.family_id);
+        }
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::{TcpListener, TcpStream};
+
+    #[tokio::test]
+    async fn test_eviction_matches_session_family() {
+        let connection_service = Arc::new(ConnectionService::new(10));
+        let listener = SessionEvictionListener::new(connection_service.clone());
+
+        let server = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let _client = TcpStream::connect(server.local_addr().unwrap()).await.unwrap();
+        let (stream, addr) = server.accept().await.unwrap();
+        connection_service.handle_new_connection_with_id(stream, addr.to_string(), 1).await.unwrap();
+        connection_service.set_family_id(1, Some("family-a".to_string())).await;
+
+        // 다른 세션이 밀려난 경우 연결 시각과 관계없이 유지
+        let evicted_at = chrono::Utc::now().timestamp() + 60;
+        let other = SessionEvicted { user_id: 1, family_id: "family-b".to_string(), evicted_at };
+        assert!(!listener.handle_eviction(&other).await);
+        assert!(connection_service.is_connected(1).await);
+
+        let own = SessionEvicted { user_id: 1, family_id: "family-a".to_string(), evicted_at: 0 };
+        assert!(listener.handle_eviction(&own).await);
+        assert!(!connection_service.is_connected(1).await);
+    }
+}
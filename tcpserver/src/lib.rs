@@ -116,4 +116,7 @@ pub use config::{TcpServerConfig, validate_config};
 /// 기본 유틸리티
 /// 
 /// 타임스탬프, 16진수 변환 등 기본적인 유틸리티 함수들을 제공합니다.
-pub use tool::SimpleUtils;
\ No newline at end of file
+pub use tool::SimpleUtils;
+
+/// 크레이트 버전
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
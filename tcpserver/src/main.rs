@@ -1,15 +1,17 @@
-//! TCP 서버 - 4가지 핵심 기능
+//! TCP 서버 - 5가지 핵심 기능
 //! 
 //! 1. 방 입장 (Room Entry)
 //! 2. 채팅 (Chat)  
 //! 3. 친구 추가 (Friend Add)
 //! 4. 친구 삭제 (Friend Remove)
+//! 5. 다이렉트 메시지 (Direct Message)
 
 use anyhow::{Context, Result};
-use tracing::{info, error};
+use tracing::{info, error, warn};
+use tracing_subscriber::prelude::*;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
-use tokio::sync::Mutex;
 
 mod config;
 mod protocol;
@@ -18,39 +20,170 @@ mod handler;
 mod tool;
 
 use config::{TcpServerConfig, validate_config};
-use service::{ConnectionService, HeartbeatService, MessageService};
-use handler::{RoomHandler, FriendHandler, ServerMessageHandler, ConnectionHandler};
+use service::{AdmissionConfig, AdmissionQueue, ConnectionService, HeartbeatMetrics, HeartbeatService, MessageService, SessionResumeConfig, SessionResumeService, StickerCatalog};
+use shared::monitoring::health::{probes, HealthRegistry, ProbeKind};
+use shared::auth::ServiceTokenIssuer;
+use shared::monitoring::crash::{self, CrashConfig};
+use shared::monitoring::{PlayerSampler, ShutdownCoordinator, ShutdownReport, TaskAccounting};
+use shared::tool::high_performance::MetricsCollector;
+use shared::security::{EntryTicketSigner, PolicyEnforcer, SecurityPolicy};
+use shared::config::redis_config::RedisConfig;
+use shared::service::redis::live_config::LiveConfig;
+use tool::MessageCatalog;
+use handler::{RoomHandler, RoomLimits, FriendHandler, ServerMessageHandler, ConnectionHandler, DirectMessageHandler, ChatEventRelay, JoinCodeHandler, ServerStatsReporter, SessionEvictionListener};
 
-/// 간단한 TCP 서버 - 4개 핵심 기능만 제공
+/// 라우팅 해제 단계 제한 시간 (Redis 하트비트/접속 정보 삭제)
+const DEREGISTER_TIMEOUT: Duration = Duration::from_secs(3);
+/// 연결별 쓰기 버퍼 드레인 제한 시간
+const DRAIN_WRITERS_TIMEOUT: Duration = Duration::from_secs(5);
+/// 하트비트 시스템 중지 제한 시간
+const STOP_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(2);
+/// 진행 중인 연결 처리 태스크 대기 제한 시간 (넘으면 취소)
+const DRAIN_TASKS_TIMEOUT: Duration = Duration::from_secs(10);
+/// `stop`이 종료 완료를 기다리는 최대 시간 (단계 제한 시간 합 + 여유)
+const STOP_TIMEOUT: Duration = Duration::from_secs(25);
+
+/// 간단한 TCP 서버 - 5개 핵심 기능만 제공
 pub struct SimpleTcpServer {
     connection_service: Arc<ConnectionService>,
     heartbeat_service: Arc<HeartbeatService>,
     message_service: Arc<MessageService>,
     room_handler: Arc<RoomHandler>,
     friend_handler: Arc<FriendHandler>,
+    direct_message_handler: Arc<DirectMessageHandler>,
+    join_code_handler: Arc<JoinCodeHandler>,
+    chat_event_relay: Arc<ChatEventRelay>,
+    session_eviction_listener: Arc<SessionEvictionListener>,
+    stats_reporter: Arc<ServerStatsReporter>,
     message_handler: Arc<ServerMessageHandler>,
     connection_handler: Arc<ConnectionHandler>,
-    is_running: Arc<Mutex<bool>>,
+    shutdown: ShutdownCoordinator,
 }
 
 impl SimpleTcpServer {
     /// 새로운 간단한 TCP 서버 생성
-    pub async fn new() -> Self {
-        let connection_service = Arc::new(ConnectionService::new(1000));
-        let heartbeat_service = Arc::new(HeartbeatService::with_default_config(connection_service.clone()));
+    pub async fn new(config: &TcpServerConfig) -> Self {
+        let session_resume = Arc::new(SessionResumeService::new(SessionResumeConfig {
+            grace_period: Duration::from_secs(config.session_resume_grace_secs),
+            ..Default::default()
+        }));
+        let messages = MessageCatalog::load(&config.message_catalog_file).unwrap_or_else(|e| {
+            tracing::warn!("메시지 카탈로그 로드 실패, 기본 카탈로그 사용: {:#}", e);
+            MessageCatalog::builtin()
+        });
+        let policy = PolicyEnforcer::from_env().unwrap_or_else(|e| {
+            tracing::error!("보안 정책 로드 실패, 내장 정책 사용: {}", e);
+            PolicyEnforcer::new(SecurityPolicy::builtin())
+        });
+        // 라이브 설정 (킬 스위치, Redis 변경 알림으로 수 초 안에 반영)
+        let live_config = match RedisConfig::new().await {
+            Ok(redis_config) => {
+                let live_config = LiveConfig::new(redis_config);
+                live_config.clone().spawn_sync();
+                live_config
+            }
+            Err(e) => {
+                warn!("라이브 설정 Redis 연결 실패, 킬 스위치 비활성화: {}", e);
+                LiveConfig::local()
+            }
+        };
+        let shutdown = ShutdownCoordinator::new("tcpserver");
+        let connection_service = Arc::new(
+            ConnectionService::new(1000)
+                .with_session_resume(session_resume.clone())
+                .with_messages(Arc::new(messages))
+                .with_shutdown(shutdown.clone())
+                .with_security_policy(Arc::new(policy))
+                .with_live_config(live_config),
+        );
+        let metrics = Arc::new(MetricsCollector::with_default_config());
+        let admission = Arc::new(
+            AdmissionQueue::new(AdmissionConfig {
+                max_queue: config.admission_queue_max,
+                max_wait: Duration::from_secs(config.admission_max_wait_secs),
+                ..Default::default()
+            })
+            .with_metrics(metrics.clone()),
+        );
+        let heartbeat_metrics = Arc::new(HeartbeatMetrics::new(
+            metrics.clone(),
+            config.heartbeat_timeout_alert_per_min,
+        ));
+        let heartbeat_service = Arc::new(
+            HeartbeatService::with_default_config(connection_service.clone())
+                .with_metrics(heartbeat_metrics),
+        );
         let message_service = Arc::new(MessageService::new(connection_service.clone()));
-        let room_handler = Arc::new(RoomHandler::new(connection_service.clone(), message_service.clone()));
-        let friend_handler = Arc::new(FriendHandler::new(connection_service.clone(), message_service.clone()));
-        let message_handler = Arc::new(ServerMessageHandler::new(
+        let room_handler = Arc::new(
+            RoomHandler::new(connection_service.clone(), message_service.clone())
+                .with_limits(RoomLimits {
+                    max_rooms: config.max_rooms,
+                    max_rooms_per_user: config.max_rooms_per_user,
+                    creation_cooldown: Duration::from_secs(config.room_create_cooldown_secs),
+                    ..Default::default()
+                })
+                .with_metrics(metrics),
+        );
+        let friend_handler = Arc::new(
+            FriendHandler::new(connection_service.clone(), message_service.clone())
+                .with_region(config.server_region.clone()),
+        );
+        let direct_message_handler = Arc::new(
+            DirectMessageHandler::new(
+                connection_service.clone(),
+                friend_handler.clone(),
+                config.bind_address(),
+            )
+            .with_region(config.server_region.clone()),
+        );
+        let chat_event_relay = Arc::new(ChatEventRelay::new(connection_service.clone(), room_handler.clone()));
+        let session_eviction_listener = Arc::new(SessionEvictionListener::new(connection_service.clone()));
+        let stats_reporter = Arc::new(ServerStatsReporter::new(
             connection_service.clone(),
-            heartbeat_service.clone(),
-            message_service.clone(),
+            room_handler.clone(),
+            config.bind_address(),
+            config.server_region.clone(),
+        ));
+        let join_code_handler = Arc::new(JoinCodeHandler::new(
+            connection_service.clone(),
+            room_handler.clone(),
+            Duration::from_secs(config.join_code_ttl_secs),
         ));
+        let stickers = StickerCatalog::load(&config.sticker_catalog_file).unwrap_or_else(|e| {
+            tracing::warn!("스티커 카탈로그 로드 실패, 스티커 비활성화: {:#}", e);
+            StickerCatalog::default()
+        });
+        let message_handler = Arc::new(
+            ServerMessageHandler::new(
+                connection_service.clone(),
+                heartbeat_service.clone(),
+                message_service.clone(),
+            )
+            .with_stickers(Arc::new(stickers)),
+        );
         let mut connection_handler_temp = ConnectionHandler::new(
             connection_service.clone(),
             heartbeat_service.clone(),
             message_service.clone(),
-        );
+        )
+        .with_session_resume(session_resume)
+        .with_direct_messages(direct_message_handler.clone())
+        .with_friends(friend_handler.clone())
+        .with_chat_events(chat_event_relay.clone())
+        .with_stats_reporter(stats_reporter.clone())
+        .with_rooms(room_handler.clone())
+        .with_join_codes(join_code_handler.clone())
+        .with_session_evictions(session_eviction_listener.clone())
+        .with_admission(admission);
+        
+        // 입장 티켓 검증 (로그인 서버와 같은 JWT 비밀키, 하트비트로 광고하는 주소 기준)
+        match std::env::var("JWT_SECRET_KEY") {
+            Ok(jwt_secret) => {
+                connection_handler_temp = connection_handler_temp
+                    .with_entry_tickets(EntryTicketSigner::from_jwt_secret(&jwt_secret), config.bind_address());
+            }
+            Err(_) => warn!("JWT_SECRET_KEY가 없어 입장 티켓 검증을 비활성화합니다"),
+        }
         
         // Redis 초기화 시도
         if let Err(e) = connection_handler_temp.with_redis().await {
@@ -65,14 +198,26 @@ impl SimpleTcpServer {
             message_service,
             room_handler,
             friend_handler,
+            direct_message_handler,
+            join_code_handler,
+            chat_event_relay,
+            session_eviction_listener,
+            stats_reporter,
             message_handler,
             connection_handler,
-            is_running: Arc::new(Mutex::new(false)),
+            shutdown,
         }
     }
 
+    /// 종료 조율기 (헬스 준비 상태, 상위 프로세스의 종료 확인용)
+    pub fn shutdown_coordinator(&self) -> &ShutdownCoordinator {
+        &self.shutdown
+    }
+
     /// 서버 시작
-    pub async fn start(&mut self, bind_addr: &str) -> Result<()> {
+    ///
+    /// 종료가 시작될 때까지 연결을 받고, 종료가 시작되면 정리 단계를 실행한 뒤 반환합니다.
+    pub async fn start(&self, bind_addr: &str) -> Result<()> {
         info!("🚀 TCP 서버 시작 중... ({})", bind_addr);
         
         // TCP 리스너 시작
@@ -82,23 +227,45 @@ impl SimpleTcpServer {
         
         info!("✅ TCP 서버가 {}에서 실행 중입니다", bind_addr);
         
-        // 서버 상태 설정
-        *self.is_running.lock().await = true;
-        
         // 하트비트 시스템 시작
         self.heartbeat_service.start().await?;
         
         // 메시지 핸들러 등록
         self.message_handler.register_all_handlers().await?;
         
-        // 클라이언트 연결 처리 루프
-        while *self.is_running.lock().await {
-            match listener.accept().await {
+        // 다이렉트 메시지 라우팅 시작
+        self.direct_message_handler.start();
+        
+        // 친구 접속 상태 요청 처리 시작
+        self.friend_handler.start();
+        
+        // 방 생성 요청 처리 시작
+        self.room_handler.start();
+        
+        // 참가 코드 처리 및 방 삭제 시 코드 폐기 시작
+        self.join_code_handler.start();
+        
+        // 모더레이션용 채팅 이벤트 발행 시작
+        self.chat_event_relay.start();
+        
+        // 동시 세션 한도로 밀려난 연결 종료
+        self.session_eviction_listener.start();
+        
+        // 통계 서비스용 서버 상태 하트비트 기록 시작
+        self.stats_reporter.start();
+        
+        // 클라이언트 연결 처리 루프 (종료가 시작되면 새 연결을 받지 않음)
+        loop {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = self.shutdown.triggered() => break,
+            };
+            match accepted {
                 Ok((stream, addr)) => {
                     info!("새 사용자 연결: {}", addr);
                     let connection_handler = self.connection_handler.clone();
                     
-                    tokio::spawn(async move {
+                    self.shutdown.spawn("connection", async move {
                         if let Err(e) = connection_handler.handle_new_connection(stream, addr.to_string()).await {
                             error!("사용자 연결 처리 오류: {}", e);
                         }
@@ -109,21 +276,50 @@ impl SimpleTcpServer {
                 }
             }
         }
+        drop(listener);
         
+        self.shutdown_gracefully().await;
         Ok(())
     }
 
-    /// 서버 중지
-    pub async fn stop(&mut self) -> Result<()> {
+    /// 정리 단계 실행
+    ///
+    /// 1. 라우팅 해제 (서버 하트비트, 다이렉트 메시지 접속 정보)
+    /// 2. 연결별 쓰기 버퍼 드레인
+    /// 3. 하트비트 시스템 중지
+    /// 4. 진행 중인 연결 처리 태스크 대기
+    async fn shutdown_gracefully(&self) -> ShutdownReport {
+        let shutdown = &self.shutdown;
+        shutdown
+            .stage("deregister", DEREGISTER_TIMEOUT, async {
+                self.stats_reporter.deregister().await;
+                self.direct_message_handler.deregister_instance().await;
+            })
+            .await;
+        shutdown
+            .stage("drain_writers", DRAIN_WRITERS_TIMEOUT, self.connection_service.drain_writers())
+            .await;
+        if let Some(Err(e)) = shutdown
+            .stage("stop_heartbeat", STOP_HEARTBEAT_TIMEOUT, self.heartbeat_service.stop())
+            .await
+        {
+            warn!("하트비트 시스템 중지 실패: {}", e);
+        }
+        shutdown.drain(DRAIN_TASKS_TIMEOUT).await;
+        shutdown.finish()
+    }
+
+    /// 서버 중지 (정리 단계가 끝날 때까지 대기)
+    pub async fn stop(&self) -> Result<ShutdownReport> {
         info!("🛑 TCP 서버 중지 중...");
+        self.shutdown.trigger();
         
-        *self.is_running.lock().await = false;
-        
-        // 하트비트 시스템 중지
-        self.heartbeat_service.stop().await?;
+        let report = tokio::time::timeout(STOP_TIMEOUT, self.shutdown.wait_finished())
+            .await
+            .context("TCP 서버 종료 완료 대기 시간 초과")?;
         
-        info!("✅ TCP 서버가 성공적으로 중지되었습니다");
-        Ok(())
+        info!("✅ TCP 서버가 성공적으로 중지되었습니다 (clean={})", report.is_clean());
+        Ok(report)
     }
 }
 
@@ -138,18 +334,38 @@ impl SimpleTcpServer {
 /// - redis_port: Redis 서버 포트 (기본값: "6379")
 /// - grpc_host: gRPC 서버 호스트 (기본값: "127.0.0.1")
 /// - grpc_port: gRPC 서버 포트 (기본값: "50051")
+/// - heartbeat_timeout_alert_per_min: 하트비트 타임아웃 경고 임계값 (기본값: "50")
+/// - session_resume_grace_secs: 세션 토큰 재접속 유예 시간 (기본값: "60")
+/// - max_rooms: 서버 전체 최대 방 수 (기본값: "100")
+/// - max_rooms_per_user: 사용자당 최대 방 수 (기본값: "3")
+/// - room_create_cooldown_secs: 방 생성 쿨다운 (기본값: "10")
+/// - server_region: 배포 지역 (통계 집계, 접속 정보/방 키에 사용, 기본값: "local")
+/// - tcp_health_port: `/healthz`, `/readyz` 포트 (없으면 비활성화)
+/// - tcp_sticker_catalog: 채팅 스티커 카탈로그 (기본값: "property/stickers.toml")
+/// - join_code_ttl_secs: 참가 코드 기본 유효 시간 (기본값: "600")
+/// - tcp_message_catalog: 안내 메시지 카탈로그 (기본값: "property/messages.toml")
+/// - tcp_admission_queue_max: 서버가 가득 찼을 때 최대 대기 인원 (기본값: "200")
+/// - tcp_admission_max_wait_secs: 접속 대기열 최대 대기 시간 (기본값: "300")
 #[tokio::main]
 async fn main() -> Result<()> {
     // 로깅 설정
-    tracing_subscriber::fmt()
-        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+    // 샘플링 규칙에 해당하는 세션은 필터 수준과 무관하게 기록
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(PlayerSampler::new(
+            tracing_subscriber::EnvFilter::from_default_env(),
+        )))
+        .with(crash::recent_logs_layer())
         .init();
+    crash::install(CrashConfig::from_env("tcpserver", crash::build_id(env!("CARGO_PKG_VERSION"), option_env!("BUILD_HASH"))));
     
     // 환경 설정 로드
     let config = TcpServerConfig::from_env()?;
     
     // 설정 검증
     validate_config(&config)?;
+
+    // gRPC 호출용 서비스 토큰 (클라이언트는 `ServiceTokenIssuer::global()`로 첨부)
+    ServiceTokenIssuer::install_from_env("tcpserver", "grpcserver");
     
     info!("=== TCP 서버 설정 ===");
     info!("TCP 서버: {}", config.bind_address());
@@ -157,35 +373,59 @@ async fn main() -> Result<()> {
     info!("gRPC 서버: {}", config.grpc_address());
     info!("====================");
     
-    info!("=== TCP 서버 - 4가지 핵심 기능 ===");
+    info!("=== TCP 서버 - 5가지 핵심 기능 ===");
     info!("1. 방 입장 (Room Entry)");
     info!("2. 채팅 (Chat)");  
     info!("3. 친구 추가 (Friend Add)");
     info!("4. 친구 삭제 (Friend Remove)");
+    info!("5. 다이렉트 메시지 (Direct Message)");
     info!("====================================");
     
-    // TCP 서버 시작
-    let server = SimpleTcpServer::new().await;
+    let server = Arc::new(SimpleTcpServer::new(&config).await);
     
-    // Ctrl+C 시그널 처리
-    let server_ref = Arc::new(Mutex::new(server));
-    let server_clone = server_ref.clone();
+    // 헬스 엔드포인트 (TCP 준비 ⇐ Redis 준비 + 종료 중 아님)
+    let health_handle = match config.health_port {
+        Some(port) => {
+            let registry = HealthRegistry::new("tcpserver");
+            let shutdown = server.shutdown_coordinator().clone();
+            registry
+                .register("process", ProbeKind::Liveness, &[], || async { Ok(()) })
+                .register("redis", ProbeKind::Readiness, &[], probes::redis_ping)
+                .register("accepting", ProbeKind::Readiness, &[], move || {
+                    let readiness = shutdown.readiness();
+                    async move { readiness }
+                })
+                .register_group("tcp", &["redis", "accepting"]);
+            let registry = registry.with_metrics(|| TaskAccounting::global().render_prometheus());
+            Some(registry.serve(format!("{}:{}", config.host, port).parse()?).await?)
+        }
+        None => None,
+    };
     
+    // TCP 서버 시작
     let bind_addr = config.bind_address();
-    let server_handle = tokio::spawn(async move {
-        if let Err(e) = server_clone.lock().await.start(&bind_addr).await {
+    let server_task = server.clone();
+    let mut server_handle = crash::spawn_monitored("tcp_server", async move {
+        if let Err(e) = server_task.start(&bind_addr).await {
             error!("TCP 서버 실행 오류: {}", e);
         }
     });
     
-    // 종료 시그널 대기
-    tokio::signal::ctrl_c().await?;
-    info!("종료 시그널 수신, 서버를 중지합니다...");
-    
-    server_handle.abort();
+    // 종료 시그널 대기 (서버가 먼저 끝나면 그대로 종료)
+    tokio::select! {
+        result = tokio::signal::ctrl_c() => {
+            result?;
+            info!("종료 시그널 수신, 서버를 중지합니다...");
+            server.stop().await?;
+            let _ = server_handle.await;
+        }
+        _ = &mut server_handle => {
+            warn!("TCP 서버 태스크가 종료되었습니다");
+        }
+    }
     
-    if let Ok(mut server) = server_ref.try_lock() {
-        server.stop().await?;
+    if let Some(handle) = health_handle {
+        handle.abort();
     }
     
     Ok(())
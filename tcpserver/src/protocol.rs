@@ -35,12 +35,72 @@
 
 use serde::{Serialize, Deserialize};
 use anyhow::{Result, anyhow};
+use shared::protocol_version::{Capabilities, MessageSpec, ProtocolSpec, ProtocolVersion};
+use shared::tool::GameServerError;
+use bytes::Bytes;
 use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 
 // 최적화된 바이너리 프로토콜 모듈
 pub mod optimized;
 
+const V1_0: ProtocolVersion = ProtocolVersion::new(1, 0, 0);
+const V1_1: ProtocolVersion = ProtocolVersion::new(1, 1, 0);
+const V1_2: ProtocolVersion = ProtocolVersion::new(1, 2, 0);
+const V1_3: ProtocolVersion = ProtocolVersion::new(1, 3, 0);
+const V1_4: ProtocolVersion = ProtocolVersion::new(1, 4, 0);
+const V1_5: ProtocolVersion = ProtocolVersion::new(1, 5, 0);
+const V1_6: ProtocolVersion = ProtocolVersion::new(1, 6, 0);
+
+/// TCP 프로토콜 정의
+///
+/// 메시지 레지스트리는 `MessageService::get_message_type`의 이름과 일치해야 합니다.
+/// 메시지를 추가하면 `verify`가 컴파일 에러를 내므로 버전을 올릴지 결정해야 합니다.
+pub static TCP_PROTOCOL: ProtocolSpec = ProtocolSpec {
+    name: "tcp",
+    current: V1_6,
+    min_supported: V1_0,
+    capabilities: Capabilities::SESSION_RESUME.union(Capabilities::BATCHING),
+    messages: &[
+        MessageSpec::new("heartbeat", V1_0),
+        MessageSpec::new("heartbeat_response", V1_0),
+        MessageSpec::new("connect", V1_0),
+        MessageSpec::new("connection_ack", V1_0),
+        MessageSpec::new("reconnect", V1_0),
+        MessageSpec::new("session_token", V1_0),
+        MessageSpec::new("reconnect_ack", V1_0),
+        MessageSpec::new("error", V1_0),
+        MessageSpec::new("room_join", V1_0),
+        MessageSpec::new("room_leave", V1_0),
+        MessageSpec::new("room_join_success", V1_0),
+        MessageSpec::new("room_leave_success", V1_0),
+        MessageSpec::new("user_joined_room", V1_0),
+        MessageSpec::new("user_left_room", V1_0),
+        MessageSpec::new("chat", V1_0),
+        MessageSpec::new("chat_response", V1_0),
+        MessageSpec::new("user_info", V1_0),
+        MessageSpec::new("system_message", V1_0),
+        MessageSpec::new("friend_add", V1_0),
+        MessageSpec::new("friend_remove", V1_0),
+        MessageSpec::new("direct_message", V1_0),
+        MessageSpec::new("direct_message_receipt", V1_0),
+        MessageSpec::new("block_user", V1_0),
+        MessageSpec::new("unblock_user", V1_0),
+        MessageSpec::new("room_role_changed", V1_1),
+        MessageSpec::new("rich_chat", V1_2),
+        MessageSpec::new("create_join_code", V1_3),
+        MessageSpec::new("join_code_created", V1_3),
+        MessageSpec::new("join_by_code", V1_3),
+        MessageSpec::new("friend_presence_request", V1_4),
+        MessageSpec::new("friend_presence_list", V1_4),
+        MessageSpec::new("heartbeat_rtt", V1_5),
+        MessageSpec::new("create_room", V1_6),
+        MessageSpec::new("room_created", V1_6),
+    ],
+    history: &[(V1_0, 24), (V1_1, 25), (V1_2, 26), (V1_3, 29), (V1_4, 31), (V1_5, 32), (V1_6, 34)],
+};
+const _: () = TCP_PROTOCOL.verify();
+
 /// 게임 메시지 타입 정의
 /// 
 /// 클라이언트와 서버 간 통신에 사용되는 모든 메시지 타입을 정의합니다.
@@ -102,13 +162,28 @@ pub enum GameMessage {
     /// 
     /// * `room_id` - 연결하려는 방 ID
     /// * `user_id` - 사용자 ID
+    /// * `protocol` - 클라이언트 프로토콜 제안 (`1.0.0+<기능 hex>`, 없으면 1.0.0으로 간주)
+    /// * `locale` - 안내 메시지 로케일 (`ko-KR`, `en` 등, 없으면 서버 기본 로케일)
+    /// * `family_id` - 로그인 응답의 세션(리프레시 토큰 패밀리) ID (동시 세션 한도로 밀려난 세션 판별용)
+    /// * `entry_ticket` - 로그인 응답에서 이 서버로 안내받을 때 함께 받은 입장 티켓
     /// 
     /// # 사용법
     /// 
     /// ```rust
-    /// let connect = GameMessage::Connect { room_id: 1, user_id: 123 };
+    /// let connect = GameMessage::Connect { room_id: 1, user_id: 123, protocol: None, locale: Some("en-US".to_string()), family_id: None, entry_ticket: None };
     /// ```
-    Connect { room_id: u32, user_id: u32 },
+    Connect {
+        room_id: u32,
+        user_id: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        protocol: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        locale: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        family_id: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        entry_ticket: Option<String>,
+    },
     
     /// 연결 확인 (서버 → 클라이언트)
     /// 
@@ -126,6 +201,42 @@ pub enum GameMessage {
     /// ```
     ConnectionAck { user_id: u32 },
     
+    /// 재접속 요청 (클라이언트 → 서버)
+    /// 
+    /// 네트워크 단절 후 유예 시간 안에 `Connect` 대신 첫 메시지로 전송합니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `user_id` - 사용자 ID
+    /// * `session_token` - 이전 연결에서 발급받은 세션 토큰
+    /// * `locale` - 안내 메시지 로케일 (`Connect`와 동일)
+    Reconnect {
+        user_id: u32,
+        session_token: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        locale: Option<String>,
+    },
+    
+    /// 세션 토큰 발급 (서버 → 클라이언트)
+    /// 
+    /// 연결 또는 재접속 성공 후 전송되며, 다음 재접속에 사용합니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `session_token` - 재접속용 세션 토큰
+    /// * `grace_secs` - 단절 후 재접속 가능 시간 (초)
+    SessionToken { session_token: String, grace_secs: u64 },
+    
+    /// 재접속 성공 (서버 → 클라이언트)
+    /// 
+    /// 이 메시지 직후 단절 동안 놓친 메시지들이 순서대로 전송됩니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `room_id` - 복원된 방 ID
+    /// * `replayed_count` - 이어서 재전송되는 메시지 수
+    ReconnectAck { room_id: u32, replayed_count: u32 },
+    
     /// 에러 메시지
     /// 
     /// 서버에서 발생한 에러를 클라이언트에게 알리는 메시지입니다.
@@ -133,7 +244,7 @@ pub enum GameMessage {
     /// 
     /// # 필드
     /// 
-    /// * `code` - 에러 코드 (HTTP 상태 코드와 유사)
+    /// * `code` - 공통 에러 코드 (`shared::tool::ErrorCode::numeric`)
     /// * `message` - 에러 설명 메시지
     /// 
     /// # 사용법
@@ -251,9 +362,176 @@ pub enum GameMessage {
     /// * `user_id` - 친구 삭제를 요청하는 사용자 ID
     /// * `friend_user_id` - 삭제할 친구의 사용자 ID
     FriendRemove { user_id: u32, friend_user_id: u32 },
+    
+    /// 다이렉트 메시지 (클라이언트 ↔ 서버)
+    /// 
+    /// 친구에게 1:1 메시지를 보내거나 받는 메시지입니다.
+    /// 수신자가 오프라인이면 큐에 보관했다가 접속 시 전달합니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `message_id` - 발신자가 정하는 메시지 ID (수신 확인에 사용)
+    /// * `from_user_id` - 발신자 ID
+    /// * `to_user_id` - 수신자 ID
+    /// * `content` - 메시지 내용
+    /// * `sent_at` - 전송 시각 (Unix 타임스탬프, 서버가 설정)
+    DirectMessage { message_id: u64, from_user_id: u32, to_user_id: u32, content: String, sent_at: i64 },
+    
+    /// 다이렉트 메시지 수신 확인 (클라이언트 ↔ 서버)
+    /// 
+    /// 전달 확인은 서버가, 읽음 확인은 수신자가 보내며 원래 발신자에게 전달됩니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `message_id` - 확인 대상 메시지 ID
+    /// * `from_user_id` - 확인을 보내는 사용자 (메시지 수신자)
+    /// * `to_user_id` - 확인을 받는 사용자 (메시지 발신자)
+    /// * `status` - 전달/읽음 상태
+    DirectMessageReceipt { message_id: u64, from_user_id: u32, to_user_id: u32, status: DeliveryStatus },
+    
+    /// 사용자 차단 (클라이언트 → 서버)
+    /// 
+    /// 차단한 사용자의 다이렉트 메시지를 받지 않습니다.
+    BlockUser { user_id: u32, target_user_id: u32 },
+    
+    /// 사용자 차단 해제 (클라이언트 → 서버)
+    UnblockUser { user_id: u32, target_user_id: u32 },
+    
+    /// 방 역할 변경 알림 (서버 → 방 참가자)
+    /// 
+    /// 역할 지정과 방장 승계 시 방 전체에 전송됩니다.
+    RoomRoleChanged { room_id: u32, user_id: u32, role: RoomRole },
+    
+    /// 확장 채팅 메시지 (클라이언트 ↔ 서버)
+    /// 
+    /// 텍스트 외에 스티커를 보낼 수 있고, 같은 방의 이전 메시지에 답장할 수 있습니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `message_id` - 발신자가 정하는 방 내 고유 ID (예: `user_id << 32 | 순번`)
+    /// * `content` - 텍스트 또는 스티커
+    /// * `reply_to` - 답장 대상 메시지 ID (최근 채팅 기록에 있어야 함)
+    RichChatMessage { user_id: u32, room_id: u32, message_id: u64, content: ChatContent, reply_to: Option<u64> },
+    
+    /// 참가 코드 발급 요청 (클라이언트 → 서버)
+    /// 
+    /// 방장과 부방장만 발급할 수 있으며, 방이 삭제되면 코드도 폐기됩니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `max_uses` - 사용 가능 횟수 (1이면 일회용, None이면 만료 전까지 무제한)
+    /// * `ttl_secs` - 유효 시간 (초, 0이면 서버 기본값)
+    CreateJoinCode { user_id: u32, room_id: u32, max_uses: Option<u32>, ttl_secs: u64 },
+    
+    /// 참가 코드 발급 결과 (서버 → 클라이언트)
+    JoinCodeCreated { room_id: u32, code: String, expires_at: i64, max_uses: Option<u32> },
+    
+    /// 참가 코드로 방 입장 (클라이언트 → 서버)
+    /// 
+    /// 비밀번호가 설정된 방도 입장할 수 있으며, 성공 시 `RoomJoinSuccess`로 응답합니다.
+    JoinByCode { user_id: u32, code: String, nickname: String },
+    
+    /// 친구 접속 상태 요청 (클라이언트 → 서버)
+    FriendPresenceRequest { user_id: u32 },
+    
+    /// 친구 접속 상태 목록 (서버 → 클라이언트)
+    /// 
+    /// 다른 지역에 접속한 친구도 그 지역과 함께 온라인으로 표시됩니다.
+    FriendPresenceList { friends: Vec<FriendPresence> },
+    
+    /// 하트비트 왕복 시간 보고 (클라이언트 → 서버)
+    /// 
+    /// 클라이언트가 `HeartBeat` 전송부터 `HeartBeatResponse` 수신까지 잰 시간입니다.
+    /// 서버는 이 값으로 연결 품질을 판단합니다.
+    HeartBeatRtt { rtt_ms: u32 },
+    
+    /// 접속 대기 순번 (서버 → 클라이언트)
+    /// 
+    /// 서버가 가득 차 `Connect`/`Reconnect` 후 대기열에 들어갔을 때, 그리고 순번이 바뀌거나 일정 시간마다 전송됩니다.
+    /// 입장하면 평소 연결 흐름(환영 메시지, 세션 토큰)이 이어집니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `position` - 1부터 시작하는 순번
+    /// * `queue_length` - 전체 대기 인원
+    /// * `estimated_wait_secs` - 예상 대기 시간 (초)
+    QueuePosition { position: u32, queue_length: u32, estimated_wait_secs: u64 },
+    
+    /// 접속 거절 (서버 → 클라이언트)
+    /// 
+    /// 대기열까지 가득 찼거나 최대 대기 시간을 넘겼을 때 전송한 뒤 연결을 끊습니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `code` - 공통 에러 코드 (`SERVER_FULL`)
+    /// * `message` - 사용자 로케일의 안내 메시지
+    /// * `retry_after_secs` - 다시 접속하기까지 기다릴 시간 (초)
+    ServerBusy { code: u16, message: String, retry_after_secs: u64 },
+    
+    /// 방 생성 (클라이언트 → 서버)
+    /// 
+    /// 서버 전체 방 수, 사용자별 보유 방 수, 생성 쿨다운 제한을 통과하면
+    /// 생성한 사용자가 방장으로 입장하고 `RoomCreated`로 응답합니다.
+    /// 
+    /// # 필드
+    /// 
+    /// * `user_id` - 방을 만드는 사용자 ID
+    /// * `name` - 방 이름
+    /// * `nickname` - 방에서 사용할 닉네임
+    CreateRoom { user_id: u32, name: String, nickname: String },
+    
+    /// 방 생성 결과 (서버 → 클라이언트)
+    RoomCreated { room_id: u32, name: String },
+}
+
+/// 친구 접속 상태
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FriendPresence {
+    pub user_id: u32,
+    pub nickname: String,
+    pub online: bool,
+    /// 접속한 지역 (오프라인이면 None)
+    pub region: Option<String>,
+}
+
+/// 채팅 내용
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ChatContent {
+    /// 일반 텍스트
+    Text { text: String },
+    /// 스티커 (`property/stickers.toml` 카탈로그의 ID)
+    Sticker { sticker_id: String },
+}
+
+/// 방 내 역할
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoomRole {
+    /// 방장 (모든 관리 권한)
+    Host,
+    /// 부방장 (추방, 이름 변경)
+    Moderator,
+    /// 일반 참가자
+    #[default]
+    Member,
+}
+
+/// 다이렉트 메시지 전달 상태
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    /// 수신자 연결에 전달됨
+    Delivered,
+    /// 수신자가 읽음
+    Read,
 }
 
 impl GameMessage {
+    /// 공통 에러를 클라이언트 에러 메시지로 변환합니다.
+    ///
+    /// 내부 상세는 보내지 않고 숫자 코드와 사용자 메시지만 담습니다.
+    pub fn from_error(error: &GameServerError) -> Self {
+        GameMessage::Error { code: error.code().numeric(), message: error.user_message() }
+    }
+
     /// 게임 메시지를 바이너리로 직렬화합니다.
     /// 
     /// 메시지를 JSON으로 직렬화한 후, 4바이트 길이 헤더와 함께
@@ -292,6 +570,23 @@ impl GameMessage {
         Ok(result)
     }
     
+    /// 브로드캐스트용 공유 프레임으로 직렬화합니다.
+    /// 
+    /// 길이 헤더와 JSON 데이터를 별도의 `Bytes`로 반환합니다.
+    /// 한 번만 직렬화한 뒤 참조 카운트 복제만으로 여러 연결에 vectored 쓰기를 할 수 있습니다.
+    /// 
+    /// # 예시
+    /// 
+    /// ```rust
+    /// let frame = message.to_frame()?;
+    /// optimizer.vectored_write(&mut writer, &frame).await?;
+    /// ```
+    pub fn to_frame(&self) -> Result<[Bytes; 2]> {
+        let payload = Bytes::from(serde_json::to_vec(self)?);
+        let header = Bytes::copy_from_slice(&(payload.len() as u32).to_be_bytes());
+        Ok([header, payload])
+    }
+    
     /// 바이너리 데이터에서 게임 메시지로 역직렬화합니다.
     /// 
     /// 4바이트 길이 헤더를 읽고, 그 길이만큼 JSON 데이터를 읽어서
@@ -363,6 +658,13 @@ impl GameMessage {
     /// let message = GameMessage::read_from_stream(&mut reader).await?;
     /// ```
     pub async fn read_from_stream(stream: &mut BufReader<OwnedReadHalf>) -> Result<Self> {
+        Self::read_frame_from_stream(stream).await.map(|(message, _)| message)
+    }
+    
+    /// TCP 스트림에서 게임 메시지와 본문 크기(바이트, 길이 헤더 제외)를 읽습니다.
+    /// 
+    /// 보안 정책의 페이로드 크기 검사에 사용합니다.
+    pub async fn read_frame_from_stream(stream: &mut BufReader<OwnedReadHalf>) -> Result<(Self, usize)> {
         // 길이 헤더 읽기 (4바이트)
         let mut length_bytes = [0u8; 4];
         stream.read_exact(&mut length_bytes).await?;
@@ -376,7 +678,7 @@ impl GameMessage {
         let json_str = std::str::from_utf8(&buffer)?;
         let message: GameMessage = serde_json::from_str(json_str)?;
         
-        Ok(message)
+        Ok((message, length))
     }
     
     /// TCP 스트림에 게임 메시지를 씁니다.
@@ -416,6 +718,14 @@ impl GameMessage {
 mod tests {
     use super::*;
     
+    /// 프로토콜 필드가 없는 기존 Connect 메시지도 읽을 수 있어야 함
+    #[test]
+    fn test_connect_without_protocol() {
+        let decoded: GameMessage = serde_json::from_str(r#"{"Connect":{"room_id":1,"user_id":2}}"#).unwrap();
+        assert!(matches!(decoded, GameMessage::Connect { protocol: None, .. }));
+        assert!(TCP_PROTOCOL.negotiate_str("1.0.0+4").unwrap().supports("reconnect"));
+    }
+
     /// 메시지 직렬화/역직렬화 테스트
     /// 
     /// GameMessage의 바이너리 직렬화와 역직렬화가
@@ -452,4 +762,13 @@ mod tests {
             _ => panic!("❌ 메시지 타입이 맞지 않습니다"),
         }
     }
+    
+    /// 공유 프레임이 기존 직렬화 결과와 같은지 확인합니다.
+    #[test]
+    fn test_shared_frame_matches_bytes() {
+        let msg = GameMessage::SystemMessage { message: "공지".to_string() };
+        let frame = msg.to_frame().unwrap();
+        
+        assert_eq!([&frame[0][..], &frame[1][..]].concat(), msg.to_bytes().unwrap());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,375 @@
+//! 접속 대기열
+//!
+//! 연결 수가 최대 연결 수에 도달하면 새 연결을 끊지 않고 대기열에 넣고, 자리가 나면 먼저 온 순서대로 입장시킵니다.
+//! 대기 중인 클라이언트에는 순번과 예상 대기 시간(`QueuePosition`)을 보내고,
+//! 대기열까지 가득 찼거나 최대 대기 시간을 넘기면 `retry_after_secs`와 함께 거절(`ServerBusy`)합니다.
+//!
+//! 예상 대기 시간은 최근 입장 간격의 지수 평균 × 순번입니다. (입장 기록이 없으면 `default_admit_interval`)
+//!
+//! 입장한 연결은 연결 서비스에 등록될 때까지 자리를 예약([`AdmissionPass`])해 두므로,
+//! 등록 전에 다음 대기자나 새 연결이 같은 자리로 들어오지 않습니다.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use tokio::time::{Duration, Instant};
+use tracing::debug;
+
+use shared::tool::high_performance::MetricsCollector;
+
+/// 입장 간격 지수 평균 가중치 (새 측정값 비율)
+const ADMIT_INTERVAL_SMOOTHING: f64 = 0.2;
+/// 거절 시 안내하는 최소 재시도 대기 시간
+const MIN_RETRY_AFTER: Duration = Duration::from_secs(5);
+/// 대기 시간 히스토그램 구간 (초)
+const WAIT_BUCKETS: [f64; 8] = [1.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0];
+
+/// 접속 대기열 설정
+#[derive(Debug, Clone)]
+pub struct AdmissionConfig {
+    /// 최대 대기 인원 (넘으면 즉시 거절)
+    pub max_queue: usize,
+    /// 최대 대기 시간 (넘으면 거절)
+    pub max_wait: Duration,
+    /// 빈 자리 확인 주기
+    pub poll_interval: Duration,
+    /// 순번이 바뀌지 않아도 대기 상태를 다시 보내는 주기
+    pub update_interval: Duration,
+    /// 입장 기록이 없을 때 쓰는 입장 간격
+    pub default_admit_interval: Duration,
+}
+
+impl Default for AdmissionConfig {
+    fn default() -> Self {
+        Self {
+            max_queue: 200,
+            max_wait: Duration::from_secs(300),
+            poll_interval: Duration::from_millis(500),
+            update_interval: Duration::from_secs(5),
+            default_admit_interval: Duration::from_secs(3),
+        }
+    }
+}
+
+/// 대기열 통계
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdmissionStats {
+    /// 현재 대기 인원
+    pub depth: usize,
+    /// 최대 대기 인원
+    pub peak_depth: usize,
+    /// 대기 후 입장한 수
+    pub admitted: u64,
+    /// 대기열이 가득 차 거절한 수
+    pub rejected: u64,
+    /// 최대 대기 시간을 넘겨 거절한 수
+    pub timed_out: u64,
+    /// 대기 중 연결이 끊긴 수
+    pub abandoned: u64,
+}
+
+/// 대기 상태 (클라이언트 안내용)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueStatus {
+    /// 1부터 시작하는 순번
+    pub position: usize,
+    /// 전체 대기 인원
+    pub queue_length: usize,
+    /// 예상 대기 시간
+    pub estimated_wait: Duration,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    /// 대기 중인 티켓 ID (먼저 온 순)
+    waiting: VecDeque<u64>,
+    next_ticket: u64,
+    /// 입장했지만 아직 연결 서비스에 등록되지 않은 연결 수
+    reserved: usize,
+    /// 대기열에서 마지막으로 입장시킨 시각 (입장 후 대기열이 비면 None)
+    last_admitted: Option<Instant>,
+    /// 입장 간격 지수 평균 (초)
+    admit_interval_secs: Option<f64>,
+    stats: AdmissionStats,
+}
+
+/// 접속 대기열
+pub struct AdmissionQueue {
+    config: AdmissionConfig,
+    state: Mutex<QueueState>,
+    metrics: Option<Arc<MetricsCollector>>,
+}
+
+impl AdmissionQueue {
+    pub fn new(config: AdmissionConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(QueueState::default()),
+            metrics: None,
+        }
+    }
+
+    /// 메트릭 수집기 연결 (`tcp_admission_*`)
+    pub fn with_metrics(mut self, metrics: Arc<MetricsCollector>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    pub fn config(&self) -> &AdmissionConfig {
+        &self.config
+    }
+
+    /// 현재 대기 인원
+    pub fn depth(&self) -> usize {
+        self.state.lock().unwrap().waiting.len()
+    }
+
+    pub fn stats(&self) -> AdmissionStats {
+        let state = self.state.lock().unwrap();
+        AdmissionStats { depth: state.waiting.len(), ..state.stats.clone() }
+    }
+
+    /// 대기 없이 입장 시도 (대기자가 없고 빈 자리가 있을 때만)
+    pub fn try_enter(self: &Arc<Self>, connections: usize, max_connections: usize) -> Option<AdmissionPass> {
+        let mut state = self.state.lock().unwrap();
+        if !state.waiting.is_empty() || connections + state.reserved >= max_connections {
+            return None;
+        }
+        state.reserved += 1;
+        Some(AdmissionPass { queue: self.clone() })
+    }
+
+    /// 대기열 등록 (가득 찼으면 재시도 대기 시간 반환)
+    pub fn join(self: &Arc<Self>) -> Result<AdmissionTicket, Duration> {
+        let mut state = self.state.lock().unwrap();
+        if state.waiting.len() >= self.config.max_queue {
+            state.stats.rejected += 1;
+            let retry_after = self.retry_after(&state, state.waiting.len());
+            drop(state);
+            self.add_counter("tcp_admission_rejected_total", "queue_full");
+            return Err(retry_after);
+        }
+
+        let id = state.next_ticket;
+        state.next_ticket += 1;
+        state.waiting.push_back(id);
+        state.stats.peak_depth = state.stats.peak_depth.max(state.waiting.len());
+        let depth = state.waiting.len();
+        drop(state);
+
+        self.set_depth(depth);
+        Ok(AdmissionTicket { queue: self.clone(), id, joined_at: Instant::now(), done: false })
+    }
+
+    /// 순번 기준 예상 대기 시간
+    pub fn estimated_wait(&self, position: usize) -> Duration {
+        let state = self.state.lock().unwrap();
+        self.estimate(&state, position)
+    }
+
+    fn estimate(&self, state: &QueueState, position: usize) -> Duration {
+        let interval = state
+            .admit_interval_secs
+            .unwrap_or(self.config.default_admit_interval.as_secs_f64());
+        Duration::from_secs_f64(interval * position as f64)
+    }
+
+    fn retry_after(&self, state: &QueueState, position: usize) -> Duration {
+        self.estimate(state, position).clamp(MIN_RETRY_AFTER, self.config.max_wait.max(MIN_RETRY_AFTER))
+    }
+
+    /// 티켓을 대기열에서 제거 (남은 대기 인원 반환)
+    fn remove(&self, state: &mut QueueState, id: u64) -> usize {
+        if let Some(index) = state.waiting.iter().position(|ticket| *ticket == id) {
+            state.waiting.remove(index);
+        }
+        if state.waiting.is_empty() {
+            state.last_admitted = None;
+        }
+        state.waiting.len()
+    }
+
+    fn set_depth(&self, depth: usize) {
+        if let Some(metrics) = &self.metrics {
+            metrics.set_gauge("tcp_admission_queue_depth", depth as f64, HashMap::new());
+        }
+    }
+
+    fn add_counter(&self, name: &str, reason: &str) {
+        if let Some(metrics) = &self.metrics {
+            let labels = HashMap::from([("reason".to_string(), reason.to_string())]);
+            metrics.add_counter(name, 1, labels);
+        }
+    }
+}
+
+/// 대기열 티켓
+///
+/// 입장하거나 거절되기 전에 버려지면(연결 끊김) 대기열에서 빠집니다.
+pub struct AdmissionTicket {
+    queue: Arc<AdmissionQueue>,
+    id: u64,
+    joined_at: Instant,
+    done: bool,
+}
+
+impl AdmissionTicket {
+    /// 대기한 시간
+    pub fn waited(&self) -> Duration {
+        self.joined_at.elapsed()
+    }
+
+    /// 현재 대기 상태
+    pub fn status(&self) -> QueueStatus {
+        let state = self.queue.state.lock().unwrap();
+        let position = state
+            .waiting
+            .iter()
+            .position(|ticket| *ticket == self.id)
+            .map_or(0, |index| index + 1);
+        QueueStatus {
+            position,
+            queue_length: state.waiting.len(),
+            estimated_wait: self.queue.estimate(&state, position),
+        }
+    }
+
+    /// 맨 앞 순번이고 빈 자리가 있으면 입장
+    pub fn try_admit(&mut self, connections: usize, max_connections: usize) -> Option<AdmissionPass> {
+        let mut state = self.queue.state.lock().unwrap();
+        if state.waiting.front() != Some(&self.id) || connections + state.reserved >= max_connections {
+            return None;
+        }
+
+        let now = Instant::now();
+        if let Some(last) = state.last_admitted {
+            let sample = now.duration_since(last).as_secs_f64();
+            state.admit_interval_secs = Some(match state.admit_interval_secs {
+                Some(average) => average + (sample - average) * ADMIT_INTERVAL_SMOOTHING,
+                None => sample,
+            });
+        }
+        state.waiting.pop_front();
+        state.last_admitted = (!state.waiting.is_empty()).then_some(now);
+        state.reserved += 1;
+        state.stats.admitted += 1;
+        let depth = state.waiting.len();
+        drop(state);
+
+        self.done = true;
+        let waited = self.waited();
+        debug!("대기열 입장 (대기 {:.1}초, 남은 대기 {}명)", waited.as_secs_f64(), depth);
+        self.queue.set_depth(depth);
+        if let Some(metrics) = &self.queue.metrics {
+            metrics.observe_histogram("tcp_admission_wait_seconds", waited.as_secs_f64(), WAIT_BUCKETS.to_vec(), HashMap::new());
+        }
+        Some(AdmissionPass { queue: self.queue.clone() })
+    }
+
+    /// 최대 대기 시간 초과로 대기열에서 빼고 재시도 대기 시간 반환
+    pub fn expire(mut self) -> Duration {
+        let mut state = self.queue.state.lock().unwrap();
+        let position = state
+            .waiting
+            .iter()
+            .position(|ticket| *ticket == self.id)
+            .map_or(0, |index| index + 1);
+        let retry_after = self.queue.retry_after(&state, position);
+        let depth = self.queue.remove(&mut state, self.id);
+        state.stats.timed_out += 1;
+        drop(state);
+
+        self.done = true;
+        self.queue.set_depth(depth);
+        self.queue.add_counter("tcp_admission_rejected_total", "timeout");
+        retry_after
+    }
+}
+
+impl Drop for AdmissionTicket {
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+        let mut state = self.queue.state.lock().unwrap();
+        let depth = self.queue.remove(&mut state, self.id);
+        state.stats.abandoned += 1;
+        drop(state);
+
+        self.queue.set_depth(depth);
+        self.queue.add_counter("tcp_admission_abandoned_total", "disconnected");
+    }
+}
+
+/// 입장 예약 (연결 서비스에 등록한 뒤 버림)
+pub struct AdmissionPass {
+    queue: Arc<AdmissionQueue>,
+}
+
+impl Drop for AdmissionPass {
+    fn drop(&mut self) {
+        let mut state = self.queue.state.lock().unwrap();
+        state.reserved = state.reserved.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(max_queue: usize) -> Arc<AdmissionQueue> {
+        Arc::new(AdmissionQueue::new(AdmissionConfig { max_queue, ..Default::default() }))
+    }
+
+    #[test]
+    fn test_enter_directly_only_when_free_and_queue_empty() {
+        let queue = queue(10);
+        let pass = queue.try_enter(1, 2).expect("free slot");
+        // 예약된 자리는 등록 전에도 찬 것으로 봄
+        assert!(queue.try_enter(1, 2).is_none());
+        drop(pass);
+
+        let _ticket = queue.join().unwrap();
+        assert!(queue.try_enter(0, 2).is_none(), "waiting clients go first");
+    }
+
+    #[test]
+    fn test_admits_in_order_and_updates_positions() {
+        let queue = queue(10);
+        let mut first = queue.join().unwrap();
+        let mut second = queue.join().unwrap();
+        assert_eq!(second.status().position, 2);
+        assert_eq!(second.status().queue_length, 2);
+        assert_eq!(second.status().estimated_wait, Duration::from_secs(6));
+
+        assert!(first.try_admit(2, 2).is_none(), "server still full");
+        assert!(second.try_admit(1, 2).is_none(), "not at the head");
+        let pass = first.try_admit(1, 2).expect("head admitted");
+        assert_eq!(second.status().position, 1);
+        assert!(second.try_admit(1, 2).is_none(), "slot reserved by first");
+        drop(pass);
+        assert!(second.try_admit(1, 2).is_some());
+
+        let stats = queue.stats();
+        assert_eq!((stats.depth, stats.peak_depth, stats.admitted), (0, 2, 2));
+    }
+
+    #[test]
+    fn test_overflow_timeout_and_abandon() {
+        let queue = queue(2);
+        let first = queue.join().unwrap();
+        let _second = queue.join().unwrap();
+        let Err(retry_after) = queue.join() else {
+            panic!("queue should be full");
+        };
+        assert!(retry_after >= MIN_RETRY_AFTER);
+
+        drop(first);
+        let third = queue.join().unwrap();
+        assert_eq!(third.status().position, 2);
+        assert!(third.expire() >= MIN_RETRY_AFTER);
+
+        let stats = queue.stats();
+        assert_eq!(stats.depth, 1);
+        assert_eq!((stats.rejected, stats.abandoned, stats.timed_out), (1, 1, 1));
+    }
+}
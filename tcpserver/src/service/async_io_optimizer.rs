@@ -4,6 +4,7 @@
 //! Zero-copy, io_uring, vectored I/O 등의 기술을 통합합니다.
 
 use anyhow::{Result, anyhow};
+use std::io::IoSlice;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
@@ -63,6 +64,10 @@ pub struct IoStats {
     pub io_errors: AtomicU64,
     pub avg_read_latency_us: AtomicU64,
     pub avg_write_latency_us: AtomicU64,
+    /// 공유 프레임 사용으로 줄인 할당 수
+    pub allocations_saved: AtomicU64,
+    /// 공유 프레임 사용으로 줄인 복사 바이트 수
+    pub bytes_copy_saved: AtomicU64,
 }
 
 impl IoStats {
@@ -511,6 +516,71 @@ impl AsyncIoOptimizer {
         Ok(total_copied)
     }
     
+    /// Vectored 쓰기
+    /// 
+    /// 여러 `Bytes` 조각을 하나로 합치지 않고 `write_vectored`로 씁니다.
+    /// 부분 쓰기가 발생하면 남은 조각부터 이어서 씁니다.
+    pub async fn vectored_write<W>(&self, writer: &mut W, frames: &[Bytes]) -> Result<usize>
+    where
+        W: AsyncWriteExt + Unpin,
+    {
+        let start = Instant::now();
+        let _permit = self.io_semaphore.acquire().await?;
+        
+        let total: usize = frames.iter().map(Bytes::len).sum();
+        let mut written = 0;
+        let mut index = 0;
+        let mut offset = 0;
+        
+        while written < total {
+            let slices: Vec<IoSlice<'_>> = std::iter::once(&frames[index][offset..])
+                .chain(frames[index + 1..].iter().map(|frame| &frame[..]))
+                .map(IoSlice::new)
+                .collect();
+            
+            let n = writer.write_vectored(&slices).await?;
+            if n == 0 {
+                self.stats.io_errors.fetch_add(1, Ordering::Relaxed);
+                return Err(anyhow!("vectored 쓰기 중 연결이 닫힘 ({}/{}바이트)", written, total));
+            }
+            written += n;
+            
+            // 쓴 만큼 조각 위치 이동
+            let mut advanced = n;
+            while advanced > 0 {
+                let left = frames[index].len() - offset;
+                if advanced >= left {
+                    advanced -= left;
+                    index += 1;
+                    offset = 0;
+                } else {
+                    offset += advanced;
+                    advanced = 0;
+                }
+            }
+        }
+        writer.flush().await?;
+        
+        self.stats.record_write(written, start.elapsed());
+        self.stats.vectored_operations.fetch_add(1, Ordering::Relaxed);
+        Ok(written)
+    }
+    
+    /// 공유 프레임 전송 결과 기록
+    /// 
+    /// 수신자마다 직렬화하면 JSON 문자열과 프레임 버퍼로 2회 할당과 프레임 크기만큼 복사가
+    /// 발생하므로, 첫 수신자를 제외한 나머지 수신자 몫을 절약량으로 기록합니다.
+    pub fn record_shared_frame(&self, recipients: usize, frame_len: usize) {
+        let reused = recipients.saturating_sub(1) as u64;
+        if reused == 0 {
+            return;
+        }
+        
+        self.stats.allocations_saved.fetch_add(reused * 2, Ordering::Relaxed);
+        self.stats.bytes_copy_saved.fetch_add(reused * frame_len as u64, Ordering::Relaxed);
+        self.stats.zero_copy_operations.fetch_add(reused, Ordering::Relaxed);
+    }
+    
     /// 플러시 대기 중인 I/O
     pub async fn flush_pending<W>(&self, writer: &mut W) -> Result<usize>
     where
@@ -540,6 +610,8 @@ impl AsyncIoOptimizer {
             io_errors: AtomicU64::new(self.stats.io_errors.load(Ordering::Relaxed)),
             avg_read_latency_us: AtomicU64::new(self.stats.avg_read_latency_us.load(Ordering::Relaxed)),
             avg_write_latency_us: AtomicU64::new(self.stats.avg_write_latency_us.load(Ordering::Relaxed)),
+            allocations_saved: AtomicU64::new(self.stats.allocations_saved.load(Ordering::Relaxed)),
+            bytes_copy_saved: AtomicU64::new(self.stats.bytes_copy_saved.load(Ordering::Relaxed)),
         }
     }
     
@@ -579,6 +651,8 @@ impl AsyncIoOptimizer {
             avg_read_latency_us: stats.avg_read_latency_us.load(Ordering::Relaxed),
             avg_write_latency_us: stats.avg_write_latency_us.load(Ordering::Relaxed),
             throughput_mbps: self.calculate_throughput(&stats),
+            allocations_saved: stats.allocations_saved.load(Ordering::Relaxed),
+            bytes_copy_saved: stats.bytes_copy_saved.load(Ordering::Relaxed),
         }
     }
     
@@ -620,6 +694,8 @@ pub struct AsyncIoPerformanceReport {
     pub avg_read_latency_us: u64,
     pub avg_write_latency_us: u64,
     pub throughput_mbps: f64,
+    pub allocations_saved: u64,
+    pub bytes_copy_saved: u64,
 }
 
 impl AsyncIoPerformanceReport {
@@ -711,4 +787,27 @@ mod tests {
         let stats = optimizer.get_stats();
         assert_eq!(stats.total_reads.load(Ordering::Relaxed), 1);
     }
+    
+    #[tokio::test]
+    async fn test_vectored_write_shared_frame() {
+        let optimizer = AsyncIoOptimizer::new(AsyncIoOptimizerConfig::default());
+        let frame = [Bytes::from_static(b"head"), Bytes::from_static(b""), Bytes::from_static(b"payload")];
+        
+        // 수신자마다 같은 프레임을 복사 없이 재사용
+        let mut outputs = Vec::new();
+        for _ in 0..3 {
+            let mut output = Vec::new();
+            let written = optimizer.vectored_write(&mut output, &frame).await.unwrap();
+            assert_eq!(written, 11);
+            outputs.push(output);
+        }
+        optimizer.record_shared_frame(outputs.len(), 11);
+        
+        assert!(outputs.iter().all(|output| output == b"headpayload"));
+        
+        let report = optimizer.generate_performance_report();
+        assert_eq!(report.allocations_saved, 4);
+        assert_eq!(report.bytes_copy_saved, 22);
+        assert_eq!(optimizer.get_stats().vectored_operations.load(Ordering::Relaxed), 3);
+    }
 }
\ No newline at end of file
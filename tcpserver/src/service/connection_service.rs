@@ -12,9 +12,17 @@ use tracing::{info, warn, debug};
 use chrono;
 
 use crate::protocol::GameMessage;
+use crate::service::async_io_optimizer::{AsyncIoOptimizer, AsyncIoOptimizerConfig, AsyncIoPerformanceReport};
+use crate::service::session_resume::SessionResumeService;
 use crate::tool::{SimpleUtils, error::{TcpServerError, ErrorHandler, ErrorSeverity}};
+use crate::tool::i18n::{MessageArgs, MessageCatalog, MessageKey};
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
-use tokio::io::{BufReader, BufWriter};
+use tokio::io::{AsyncWriteExt, BufReader, BufWriter};
+use shared::monitoring::{ShutdownCoordinator, TaskAccounting};
+use shared::security::{PolicyEnforcer, UserRole};
+use shared::service::redis::live_config::LiveConfig;
+use shared::tool::ErrorCode;
+use crate::service::message_service::MessageService;
 
 /// 개별 사용자 연결 정보
 #[derive(Debug)]
@@ -24,6 +32,8 @@ pub struct UserConnection {
     pub last_heartbeat: Instant,
     pub writer: Arc<Mutex<BufWriter<OwnedWriteHalf>>>,
     pub connected_at: Instant,
+    /// 로그인 세션(리프레시 토큰 패밀리) ID (클라이언트가 보낸 경우)
+    pub family_id: Option<String>,
 }
 
 impl UserConnection {
@@ -51,6 +61,7 @@ impl UserConnection {
             last_heartbeat: Instant::now(),
             writer,
             connected_at: Instant::now(),
+            family_id: None,
         }
     }
     
@@ -98,6 +109,17 @@ pub struct ConnectionService {
     max_connections: u32,
     server_start_time: Instant,
     connection_stats: Arc<Mutex<ConnectionStats>>,
+    session_resume: Option<Arc<SessionResumeService>>,
+    io_optimizer: Arc<AsyncIoOptimizer>,
+    messages: Arc<MessageCatalog>,
+    /// user_id -> 협상된 로케일
+    locales: Arc<Mutex<HashMap<u32, String>>>,
+    /// 수신 루프를 추적하는 종료 조율기 (설정 시)
+    shutdown: Option<ShutdownCoordinator>,
+    /// 메시지 타입별 보안 정책 (설정 시)
+    policy: Option<Arc<PolicyEnforcer>>,
+    /// 메시지 타입별 킬 스위치 (설정 시)
+    live_config: Option<LiveConfig>,
 }
 
 /// 연결 통계
@@ -123,9 +145,116 @@ impl ConnectionService {
             max_connections,
             server_start_time: Instant::now(),
             connection_stats: Arc::new(Mutex::new(ConnectionStats::default())),
+            session_resume: None,
+            // 브로드캐스트 쓰기 전용이므로 버퍼 풀은 작게 유지
+            io_optimizer: Arc::new(AsyncIoOptimizer::new(AsyncIoOptimizerConfig {
+                buffer_size: 4096,
+                max_concurrent_io: 256,
+                ..Default::default()
+            })),
+            messages: Arc::new(MessageCatalog::builtin()),
+            locales: Arc::new(Mutex::new(HashMap::new())),
+            shutdown: None,
+            policy: None,
+            live_config: None,
         }
     }
     
+    /// 세션 재개 서비스 연결
+    /// 
+    /// 연결이 끊긴 사용자에게 보내는 메시지를 재접속 유예 시간 동안 버퍼링합니다.
+    pub fn with_session_resume(mut self, session_resume: Arc<SessionResumeService>) -> Self {
+        self.session_resume = Some(session_resume);
+        self
+    }
+    
+    /// 종료 조율기 연결
+    /// 
+    /// 연결별 수신 루프를 추적 태스크로 실행해 종료 시 끝나기를 기다리고, 종료 중에는 새 수신 루프를 시작하지 않습니다.
+    pub fn with_shutdown(mut self, shutdown: ShutdownCoordinator) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+    
+    /// 보안 정책 적용기 연결
+    /// 
+    /// 수신 메시지를 `tcp:<메시지 타입>` 엔드포인트 정책으로 검사하고, 위반한 메시지는 에러 응답 후 버립니다.
+    pub fn with_security_policy(mut self, policy: Arc<PolicyEnforcer>) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+    
+    /// 라이브 설정 연결
+    /// 
+    /// `kill.tcp:<메시지 타입>` 킬 스위치가 켜진 메시지는 `FEATURE_DISABLED` 에러 응답 후 버립니다.
+    pub fn with_live_config(mut self, live_config: LiveConfig) -> Self {
+        self.live_config = Some(live_config);
+        self
+    }
+    
+    /// 안내 메시지 카탈로그 설정 (기본값은 빌드에 포함된 카탈로그)
+    pub fn with_messages(mut self, messages: Arc<MessageCatalog>) -> Self {
+        self.messages = messages;
+        self
+    }
+    
+    /// 안내 메시지 카탈로그
+    pub fn messages(&self) -> &MessageCatalog {
+        &self.messages
+    }
+    
+    /// 사용자 로케일 설정 (로그인 시 요청한 로케일을 지원 로케일로 협상)
+    pub async fn set_locale(&self, user_id: u32, requested: Option<&str>) -> String {
+        let locale = self.messages.negotiate(requested);
+        self.locales.lock().await.insert(user_id, locale.clone());
+        debug!("사용자 {} 로케일: {} (요청 {:?})", user_id, locale, requested);
+        locale
+    }
+    
+    /// 연결의 로그인 세션(리프레시 토큰 패밀리) 기록 (세션 종료 이벤트 대상 판별용)
+    pub async fn set_family_id(&self, user_id: u32, family_id: Option<String>) {
+        let connection = self.connections.lock().await.get(&user_id).cloned();
+        if let Some(connection) = connection {
+            connection.lock().await.family_id = family_id;
+        }
+    }
+    
+    /// 사용자 로케일 (설정 전이면 기본 로케일)
+    pub async fn locale_of(&self, user_id: u32) -> String {
+        self.locales
+            .lock()
+            .await
+            .get(&user_id)
+            .cloned()
+            .unwrap_or_else(|| self.messages.default_locale().to_string())
+    }
+    
+    /// 사용자 로케일로 안내 메시지 생성
+    pub async fn localize(&self, user_id: u32, key: MessageKey, args: MessageArgs<'_>) -> String {
+        self.messages.render(&self.locale_of(user_id).await, key, args)
+    }
+    
+    /// 사용자 로케일로 시스템 메시지 전송
+    pub async fn send_notice(&self, user_id: u32, key: MessageKey, args: MessageArgs<'_>) -> Result<()> {
+        let message = GameMessage::SystemMessage {
+            message: self.localize(user_id, key, args).await,
+        };
+        self.send_to_user(user_id, &message).await
+    }
+    
+    /// 접속한 모든 사용자에게 각자의 로케일로 공지
+    pub async fn announce(&self, key: MessageKey, args: MessageArgs<'_>) -> usize {
+        let user_ids: Vec<u32> = self.connections.lock().await.keys().copied().collect();
+        let mut sent = 0;
+        for user_id in user_ids {
+            match self.send_notice(user_id, key, args).await {
+                Ok(()) => sent += 1,
+                Err(e) => debug!("사용자 {} 공지 전송 실패: {}", user_id, e),
+            }
+        }
+        sent
+    }
+    
     /// 새로운 연결 처리
     /// 
     /// 새로운 클라이언트 연결을 받아들이고 고유한 사용자 ID를 할당합니다.
@@ -176,6 +305,7 @@ impl ConnectionService {
             last_heartbeat: Instant::now(),
             writer: Arc::new(Mutex::new(BufWriter::new(writer))),
             connected_at: Instant::now(),
+            family_id: None,
         }));
         
         {
@@ -243,6 +373,7 @@ impl ConnectionService {
             last_heartbeat: Instant::now(),
             writer: Arc::new(Mutex::new(BufWriter::new(writer))),
             connected_at: Instant::now(),
+            family_id: None,
         }));
         
         {
@@ -269,15 +400,43 @@ impl ConnectionService {
         let connections_ref = self.connections.clone();
         let broadcast_tx = self.broadcast_tx.clone();
         let stats_ref = self.connection_stats.clone();
+        let session_resume = self.session_resume.clone();
+        let policy = self.policy.clone();
+        let live_config = self.live_config.clone();
         
-        tokio::spawn(async move {
+        let task = TaskAccounting::global().subsystem("connection").instrument(async move {
             let mut reader = BufReader::new(reader);
             
             loop {
-                match GameMessage::read_from_stream(&mut reader).await {
-                    Ok(message) => {
+                match GameMessage::read_frame_from_stream(&mut reader).await {
+                    Ok((message, payload_len)) => {
                         debug!("사용자 {}에서 메시지 수신: {:?}", user_id, message);
                         
+                        // 보안 정책 검사 (연결 등록을 마친 사용자는 인증된 요청)
+                        let endpoint = format!("tcp:{}", MessageService::get_message_type(&message));
+                        if let Some(policy) = &policy {
+                            if let Err(e) = policy.check(&endpoint, &user_id.to_string(), payload_len, Some(&[UserRole::User])) {
+                                warn!("사용자 {} 메시지 거부 ({}): {}", user_id, endpoint, e);
+                                let response = GameMessage::from_error(&e.into());
+                                if let Err(e) = connection.lock().await.send_message(&response).await {
+                                    debug!("사용자 {} 정책 거부 응답 전송 실패: {}", user_id, e);
+                                    break;
+                                }
+                                continue;
+                            }
+                        }
+                        
+                        // 킬 스위치 검사 (하트비트는 연결 유지를 위해 제외)
+                        if !matches!(message, GameMessage::HeartBeat) && live_config.as_ref().is_some_and(|live| live.is_killed(&endpoint)) {
+                            debug!("사용자 {} 메시지 차단 (킬 스위치): {}", user_id, endpoint);
+                            let response = GameMessage::from_error(&ErrorCode::FeatureDisabled.into());
+                            if let Err(e) = connection.lock().await.send_message(&response).await {
+                                debug!("사용자 {} 킬 스위치 응답 전송 실패: {}", user_id, e);
+                                break;
+                            }
+                            continue;
+                        }
+                        
                         // 하트비트 처리
                         if matches!(message, GameMessage::HeartBeat) {
                             if let Some(conn) = connections_ref.lock().await.get(&user_id) {
@@ -312,8 +471,26 @@ impl ConnectionService {
                 }
             }
             
-            // 연결 정리
-            connections_ref.lock().await.remove(&user_id);
+            // 연결 정리 (재접속으로 교체된 연결은 건드리지 않음)
+            let removed = {
+                let mut connections = connections_ref.lock().await;
+                match connections.get(&user_id) {
+                    Some(current) if Arc::ptr_eq(current, &connection) => {
+                        connections.remove(&user_id);
+                        true
+                    }
+                    _ => false,
+                }
+            };
+            if !removed {
+                debug!("사용자 {} 이전 연결 수신 종료 (새 연결 유지)", user_id);
+                return;
+            }
+            
+            // 재접속 유예 시간 시작
+            if let Some(session_resume) = &session_resume {
+                session_resume.mark_disconnected(user_id);
+            }
             
             // 통계 업데이트
             if let Ok(mut stats) = stats_ref.try_lock() {
@@ -322,14 +499,27 @@ impl ConnectionService {
             
             info!("사용자 {} 연결 해제 완료", user_id);
         });
+        
+        match &self.shutdown {
+            Some(shutdown) => {
+                if shutdown.spawn("connection", task).is_none() {
+                    debug!("종료 중이라 사용자 {} 수신 루프를 시작하지 않음", user_id);
+                }
+            }
+            None => {
+                tokio::spawn(task);
+            }
+        }
     }
     
     /// 연결 제거
     pub async fn remove_connection(&self, user_id: u32) -> bool {
         let mut connections = self.connections.lock().await;
         let removed = connections.remove(&user_id).is_some();
+        drop(connections);
         
         if removed {
+            self.locales.lock().await.remove(&user_id);
             self.update_connection_stats(|stats| {
                 stats.current_connections = stats.current_connections.saturating_sub(1);
             }).await;
@@ -353,31 +543,78 @@ impl ConnectionService {
             
             Ok(())
         } else {
+            drop(connections);
+            
+            // 재접속 대기 중인 사용자는 메시지를 버퍼링
+            if let Some(session_resume) = &self.session_resume {
+                if session_resume.buffer_message(user_id, message).await {
+                    debug!("사용자 {} 재접속 대기 중 - 메시지 버퍼링", user_id);
+                    return Ok(());
+                }
+            }
+            
             Err(anyhow!("사용자 {}를 찾을 수 없습니다", user_id))
         }
     }
     
     /// 모든 사용자에게 브로드캐스트
+    /// 
+    /// 메시지를 한 번만 직렬화한 공유 프레임을 모든 연결에 vectored 쓰기로 전송합니다.
     pub async fn broadcast_message(&self, message: &GameMessage) -> Result<usize> {
+        self.broadcast_messages(std::slice::from_ref(message)).await
+    }
+    
+    /// 여러 메시지를 한 번에 브로드캐스트
+    /// 
+    /// 연결마다 writer 잠금과 vectored 쓰기를 한 번만 수행하므로,
+    /// 짧은 시간에 몰리는 알림을 모아 보낼 때 사용합니다 (`MessageService::queue_broadcast`).
+    pub async fn broadcast_messages(&self, messages: &[GameMessage]) -> Result<usize> {
+        if messages.is_empty() {
+            return Ok(0);
+        }
+        let mut frame = Vec::with_capacity(messages.len() * 2);
+        for message in messages {
+            frame.extend(message.to_frame()?);
+        }
+        let frame_len = frame.iter().map(|part| part.len()).sum();
+        
         let connections = self.connections.lock().await;
         let mut success_count = 0;
         
         for (user_id, connection) in connections.iter() {
-            if let Ok(()) = connection.lock().await.send_message(message).await {
-                success_count += 1;
-            } else {
-                warn!("사용자 {}에게 브로드캐스트 실패", user_id);
+            let writer = connection.lock().await.writer.clone();
+            let mut writer = writer.lock().await;
+            
+            match self.io_optimizer.vectored_write(&mut *writer, &frame).await {
+                Ok(_) => success_count += 1,
+                Err(e) => warn!("사용자 {}에게 브로드캐스트 실패: {}", user_id, e),
             }
         }
         
+        self.io_optimizer.record_shared_frame(success_count, frame_len);
+        
         self.update_connection_stats(|stats| {
-            stats.total_messages += success_count as u64;
+            stats.total_messages += (success_count * messages.len()) as u64;
         }).await;
         
-        debug!("브로드캐스트 완료: {}/{} 성공", success_count, connections.len());
+        debug!("브로드캐스트 완료: {}개 메시지, {}/{} 성공", messages.len(), success_count, connections.len());
+        drop(connections);
+        
+        // 재접속 대기 중인 사용자들을 위한 버퍼링
+        if let Some(session_resume) = &self.session_resume {
+            for message in messages {
+                session_resume.buffer_for_pending(message).await;
+            }
+        }
+        
         Ok(success_count)
     }
     
+    /// 브로드캐스트 I/O 성능 리포트
+    pub fn io_performance_report(&self) -> AsyncIoPerformanceReport {
+        self.io_optimizer.generate_performance_report()
+    }
+    
     /// 타임아웃된 연결 정리
     pub async fn cleanup_timeout_connections(&self) -> usize {
         let mut connections = self.connections.lock().await;
@@ -409,6 +646,11 @@ impl ConnectionService {
         self.connections.lock().await.len()
     }
     
+    /// 최대 연결 수
+    pub fn max_connections(&self) -> u32 {
+        self.max_connections
+    }
+    
     /// 모든 연결 종료
     pub async fn close_all_connections(&self) {
         let mut connections = self.connections.lock().await;
@@ -422,6 +664,40 @@ impl ConnectionService {
         info!("모든 사용자 연결 해제: {}개", count);
     }
     
+    /// 모든 연결의 쓰기 버퍼를 비우고 쓰기 방향을 닫음 (정상 종료용, 닫은 연결 수 반환)
+    /// 
+    /// 클라이언트가 연결을 닫으면 연결별 수신 루프도 끝납니다.
+    pub async fn drain_writers(&self) -> usize {
+        let connections: Vec<_> = self.connections.lock().await.drain().map(|(_, connection)| connection).collect();
+        let mut drained = 0;
+        
+        for connection in &connections {
+            let (user_id, writer) = {
+                let conn = connection.lock().await;
+                (conn.user_id, conn.writer.clone())
+            };
+            // BufWriter::shutdown은 남은 버퍼를 먼저 내보냄
+            let result = writer.lock().await.shutdown().await;
+            match result {
+                Ok(()) => drained += 1,
+                Err(e) => debug!("사용자 {} 쓰기 종료 실패: {}", user_id, e),
+            }
+        }
+        
+        self.locales.lock().await.clear();
+        self.update_connection_stats(|stats| {
+            stats.current_connections = 0;
+        }).await;
+        
+        info!("연결 쓰기 드레인 완료: {}/{}개", drained, connections.len());
+        drained
+    }
+    
+    /// 접속 중인 사용자 ID 목록
+    pub async fn connected_user_ids(&self) -> Vec<u32> {
+        self.connections.lock().await.keys().copied().collect()
+    }
+    
     /// 브로드캐스트 수신자 생성
     pub fn subscribe_broadcast(&self) -> broadcast::Receiver<(Option<u32>, GameMessage)> {
         self.broadcast_tx.subscribe()
@@ -447,6 +723,11 @@ impl ConnectionService {
         self.connection_stats.lock().await.clone()
     }
     
+    /// 사용자가 이 인스턴스에 연결되어 있는지 확인
+    pub async fn is_connected(&self, user_id: u32) -> bool {
+        self.connections.lock().await.contains_key(&user_id)
+    }
+    
     /// 사용자 연결 정보 조회
     pub async fn get_user_info(&self, user_id: u32) -> Option<UserInfo> {
         let connections = self.connections.lock().await;
@@ -461,6 +742,7 @@ impl ConnectionService {
                 uptime_seconds: conn.connected_at.elapsed().as_secs(),
                 connected_timestamp: SimpleUtils::instant_to_timestamp(conn.connected_at),
                 last_heartbeat_timestamp: SimpleUtils::instant_to_timestamp(conn.last_heartbeat),
+                family_id: conn.family_id.clone(),
             })
         } else {
             None
@@ -482,6 +764,7 @@ impl ConnectionService {
                 uptime_seconds: conn.connected_at.elapsed().as_secs(),
                 connected_timestamp: SimpleUtils::instant_to_timestamp(conn.connected_at),
                 last_heartbeat_timestamp: SimpleUtils::instant_to_timestamp(conn.last_heartbeat),
+                family_id: conn.family_id.clone(),
             });
         }
         
@@ -504,6 +787,9 @@ pub struct UserInfo {
     pub connected_timestamp: i64,
     /// 마지막 하트비트 시간 (Unix timestamp)
     pub last_heartbeat_timestamp: i64,
+    /// 로그인 세션(리프레시 토큰 패밀리) ID
+    #[serde(skip)]
+    pub family_id: Option<String>,
 }
 
 #[cfg(test)]
@@ -1,16 +1,25 @@
 //! 하트비트 서비스
 //! 
 //! 클라이언트 연결 상태 모니터링과 타임아웃 관리를 담당합니다.
+//! 
+//! 클라이언트가 보고한 하트비트 왕복 시간(`HeartBeatRtt`)으로 사용자별 연결 품질을 추적하고,
+//! 등급이 바뀌면 `ConnectionQualityChanged` 이벤트를 로컬 구독자와 이벤트 버스에 발행합니다.
+//! 순간적인 튐으로 등급이 흔들리지 않도록 왕복 시간을 지수 평균하고,
+//! 새 등급이 연속 `QUALITY_CONFIRM_SAMPLES`번 나와야 변경합니다.
 
 use anyhow::Result;
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use shared::service::redis::event_bus::{ConnectionQuality, ConnectionQualityChanged, EventBus};
+use shared::tool::high_performance::MetricsCollector;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, OnceLock};
+use tokio::sync::{broadcast, Mutex};
 use tokio::time::{Duration, interval, Instant};
 use tracing::{info, warn, debug};
 
 use crate::service::ConnectionService;
 use crate::tool::{SimpleUtils, error::{TcpServerError, ErrorHandler, ErrorSeverity}};
 use crate::protocol::GameMessage;
+use shared::monitoring::TaskAccounting;
 
 /// 하트비트 서비스
 pub struct HeartbeatService {
@@ -20,6 +29,176 @@ pub struct HeartbeatService {
     heartbeat_interval_secs: u64,
     connection_timeout_secs: u64,
     heartbeat_stats: Arc<Mutex<HeartbeatStats>>,
+    metrics: Option<Arc<HeartbeatMetrics>>,
+    quality: Arc<std::sync::Mutex<HashMap<u32, QualityTracker>>>,
+    quality_tx: broadcast::Sender<ConnectionQualityChanged>,
+    event_bus: OnceLock<EventBus>,
+}
+
+/// 보고받을 수 있는 최대 왕복 시간 (ms, 넘으면 이 값으로 자름)
+pub const MAX_REPORTED_RTT_MS: u32 = 60_000;
+
+/// 왕복 시간이 이보다 크면 `Degraded` (ms)
+const DEGRADED_RTT_MS: f64 = 150.0;
+/// 왕복 시간이 이보다 크면 `Poor` (ms)
+const POOR_RTT_MS: f64 = 300.0;
+/// 왕복 시간 지수 평균 가중치 (새 측정값 비율)
+const RTT_SMOOTHING: f64 = 0.125;
+/// 등급 변경에 필요한 연속 측정 수
+const QUALITY_CONFIRM_SAMPLES: u32 = 2;
+/// 로컬 품질 변경 구독 버퍼 크기
+const QUALITY_EVENT_BUFFER: usize = 256;
+
+/// 사용자별 연결 품질 상태
+#[derive(Debug)]
+struct QualityTracker {
+    rtt_ms: f64,
+    quality: ConnectionQuality,
+    /// 확인 중인 새 등급과 연속 측정 수
+    pending: Option<(ConnectionQuality, u32)>,
+}
+
+impl QualityTracker {
+    fn new(rtt_ms: f64) -> Self {
+        Self { rtt_ms, quality: ConnectionQuality::Good, pending: None }
+    }
+
+    /// 측정값 반영 (등급이 바뀌면 이전 등급 반환)
+    fn observe(&mut self, rtt_ms: f64) -> Option<ConnectionQuality> {
+        self.rtt_ms += (rtt_ms - self.rtt_ms) * RTT_SMOOTHING;
+        let measured = ConnectionQuality::from_rtt_ms(self.rtt_ms, DEGRADED_RTT_MS, POOR_RTT_MS);
+        if measured == self.quality {
+            self.pending = None;
+            return None;
+        }
+
+        let count = match self.pending {
+            Some((pending, count)) if pending == measured => count + 1,
+            _ => 1,
+        };
+        if count < QUALITY_CONFIRM_SAMPLES {
+            self.pending = Some((measured, count));
+            return None;
+        }
+        self.pending = None;
+        Some(std::mem::replace(&mut self.quality, measured))
+    }
+}
+
+/// 분당 타임아웃 계산 윈도우
+const TIMEOUT_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// 하트비트 지연/정리 시간 히스토그램 버킷 (초)
+const LATENCY_BUCKETS: [f64; 8] = [0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0];
+
+/// 하트비트 메트릭 내보내기
+/// 
+/// 하트비트 서브시스템 상태를 공용 `MetricsCollector`에 Prometheus 메트릭으로 기록하고,
+/// 분당 타임아웃 수가 임계값을 넘으면 경고를 남깁니다.
+/// 
+/// # 메트릭
+/// - `tcp_heartbeat_active_connections` (gauge): 모니터링 중인 연결 수
+/// - `tcp_heartbeat_timeouts_total` (counter): 누적 타임아웃 정리 수
+/// - `tcp_heartbeat_timeouts_per_minute` (gauge): 최근 1분간 타임아웃 수
+/// - `tcp_heartbeat_latency_seconds` (histogram): 하트비트 응답 처리 시간
+/// - `tcp_heartbeat_cleanup_duration_seconds` (histogram): 타임아웃 정리 소요 시간
+/// - `tcp_heartbeat_rtt_seconds` (histogram): 클라이언트가 보고한 하트비트 왕복 시간
+/// - `tcp_connection_quality_changes_total` (counter): 연결 품질 등급 변경 수 (`quality` = 새 등급)
+pub struct HeartbeatMetrics {
+    collector: Arc<MetricsCollector>,
+    timeout_alert_per_min: f64,
+    recent_timeouts: std::sync::Mutex<VecDeque<(Instant, usize)>>,
+}
+
+impl HeartbeatMetrics {
+    /// 새로운 하트비트 메트릭 생성
+    /// 
+    /// * `timeout_alert_per_min` - 분당 타임아웃 경고 임계값
+    pub fn new(collector: Arc<MetricsCollector>, timeout_alert_per_min: f64) -> Self {
+        Self {
+            collector,
+            timeout_alert_per_min,
+            recent_timeouts: std::sync::Mutex::new(VecDeque::new()),
+        }
+    }
+    
+    /// 정리 주기 결과 기록
+    /// 
+    /// 최근 1분간 타임아웃 수를 반환합니다.
+    pub fn record_cleanup(&self, timeouts: usize, active_connections: usize, duration: Duration) -> f64 {
+        let timeouts_per_min = self.update_timeout_window(Instant::now(), timeouts);
+        
+        self.collector.set_gauge("tcp_heartbeat_active_connections", active_connections as f64, HashMap::new());
+        self.collector.add_counter("tcp_heartbeat_timeouts_total", timeouts as u64, HashMap::new());
+        self.collector.set_gauge("tcp_heartbeat_timeouts_per_minute", timeouts_per_min, HashMap::new());
+        self.collector.observe_histogram(
+            "tcp_heartbeat_cleanup_duration_seconds",
+            duration.as_secs_f64(),
+            LATENCY_BUCKETS.to_vec(),
+            HashMap::new(),
+        );
+        
+        if timeouts_per_min > self.timeout_alert_per_min {
+            warn!(
+                "HIGH HEARTBEAT TIMEOUT RATE ALERT: {:.0}/min (threshold: {:.0}/min, 활성: {}개)",
+                timeouts_per_min,
+                self.timeout_alert_per_min,
+                active_connections
+            );
+        }
+        
+        timeouts_per_min
+    }
+    
+    /// 하트비트 응답 처리 시간 기록
+    pub fn record_heartbeat_latency(&self, latency: Duration) {
+        self.collector.observe_histogram(
+            "tcp_heartbeat_latency_seconds",
+            latency.as_secs_f64(),
+            LATENCY_BUCKETS.to_vec(),
+            HashMap::new(),
+        );
+    }
+    
+    /// 하트비트 왕복 시간 기록
+    pub fn record_rtt(&self, rtt: Duration) {
+        self.collector.observe_histogram(
+            "tcp_heartbeat_rtt_seconds",
+            rtt.as_secs_f64(),
+            LATENCY_BUCKETS.to_vec(),
+            HashMap::new(),
+        );
+    }
+    
+    /// 연결 품질 등급 변경 기록
+    pub fn record_quality_change(&self, quality: ConnectionQuality) {
+        let label = match quality {
+            ConnectionQuality::Good => "good",
+            ConnectionQuality::Degraded => "degraded",
+            ConnectionQuality::Poor => "poor",
+        };
+        self.collector.add_counter(
+            "tcp_connection_quality_changes_total",
+            1,
+            HashMap::from([("quality".to_string(), label.to_string())]),
+        );
+    }
+    
+    /// 1분 윈도우에 타임아웃을 추가하고 윈도우 내 합계 반환
+    fn update_timeout_window(&self, now: Instant, timeouts: usize) -> f64 {
+        let mut window = self.recent_timeouts.lock().unwrap_or_else(|e| e.into_inner());
+        if timeouts > 0 {
+            window.push_back((now, timeouts));
+        }
+        while let Some(&(at, _)) = window.front() {
+            if now.duration_since(at) > TIMEOUT_RATE_WINDOW {
+                window.pop_front();
+            } else {
+                break;
+            }
+        }
+        window.iter().map(|&(_, count)| count).sum::<usize>() as f64
+    }
 }
 
 /// 하트비트 통계
@@ -33,6 +212,10 @@ pub struct HeartbeatStats {
     pub last_cleanup_timestamp: Option<i64>,
     pub average_response_time_ms: f64,
     pub active_connections: u32,
+    /// 최근 1분간 타임아웃 수
+    pub timeouts_per_min: f64,
+    /// 평균 하트비트 응답 처리 시간 (ms)
+    pub average_heartbeat_latency_ms: f64,
 }
 
 impl HeartbeatService {
@@ -49,9 +232,26 @@ impl HeartbeatService {
             heartbeat_interval_secs,
             connection_timeout_secs,
             heartbeat_stats: Arc::new(Mutex::new(HeartbeatStats::default())),
+            metrics: None,
+            quality: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            quality_tx: broadcast::channel(QUALITY_EVENT_BUFFER).0,
+            event_bus: OnceLock::new(),
         }
     }
     
+    /// 이벤트 버스 연결 (연결 품질 변경을 다른 서비스에 발행)
+    pub fn attach_event_bus(&self, event_bus: EventBus) {
+        if self.event_bus.set(event_bus).is_err() {
+            debug!("하트비트 서비스에 이벤트 버스가 이미 연결되어 있습니다");
+        }
+    }
+    
+    /// 메트릭 내보내기 설정
+    pub fn with_metrics(mut self, metrics: Arc<HeartbeatMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+    
     /// 기본 설정으로 생성
     pub fn with_default_config(connection_service: Arc<ConnectionService>) -> Self {
         Self::new(connection_service, 600, 1800) // 600초(10분) 간격, 1800초(30분) 타임아웃
@@ -76,9 +276,11 @@ impl HeartbeatService {
         let connection_service = self.connection_service.clone();
         let is_running_ref = self.is_running.clone();
         let stats_ref = self.heartbeat_stats.clone();
+        let metrics = self.metrics.clone();
+        let quality_ref = self.quality.clone();
         let interval_secs = self.heartbeat_interval_secs;
         
-        let handle = tokio::spawn(async move {
+        let handle = tokio::spawn(TaskAccounting::global().subsystem("heartbeat").instrument(async move {
             let mut cleanup_interval = interval(Duration::from_secs(interval_secs));
             
             while *is_running_ref.lock().await {
@@ -90,8 +292,24 @@ impl HeartbeatService {
                 let cleanup_count = connection_service.cleanup_timeout_connections().await;
                 let current_connections = connection_service.get_connection_count().await;
                 
+                // 끊긴 사용자의 연결 품질 상태 정리
+                let connected: std::collections::HashSet<u32> =
+                    connection_service.connected_user_ids().await.into_iter().collect();
+                quality_ref
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .retain(|user_id, _| connected.contains(user_id));
+                
+                // 메트릭 기록
+                let timeouts_per_min = metrics.as_ref().map(|metrics| {
+                    metrics.record_cleanup(cleanup_count, current_connections, start_time.elapsed())
+                });
+                
                 // 통계 업데이트
                 if let Ok(mut stats) = stats_ref.try_lock() {
+                    if let Some(timeouts_per_min) = timeouts_per_min {
+                        stats.timeouts_per_min = timeouts_per_min;
+                    }
                     if cleanup_count > 0 {
                         stats.timeout_cleanups += cleanup_count as u64;
                         stats.last_cleanup_time = Some(start_time);
@@ -116,7 +334,7 @@ impl HeartbeatService {
             }
             
             info!("하트비트 정리 작업 종료");
-        });
+        }));
         
         // 핸들 저장
         *self.cleanup_handle.lock().await = Some(handle);
@@ -159,8 +377,20 @@ impl HeartbeatService {
         let start_time = Instant::now();
         let cleanup_count = self.connection_service.cleanup_timeout_connections().await;
         
+        // 메트릭 기록
+        let timeouts_per_min = match &self.metrics {
+            Some(metrics) => {
+                let current_connections = self.connection_service.get_connection_count().await;
+                Some(metrics.record_cleanup(cleanup_count, current_connections, start_time.elapsed()))
+            }
+            None => None,
+        };
+        
         // 통계 업데이트
         if let Ok(mut stats) = self.heartbeat_stats.try_lock() {
+            if let Some(timeouts_per_min) = timeouts_per_min {
+                stats.timeouts_per_min = timeouts_per_min;
+            }
             if cleanup_count > 0 {
                 stats.timeout_cleanups += cleanup_count as u64;
                 stats.last_cleanup_time = Some(start_time);
@@ -179,6 +409,8 @@ impl HeartbeatService {
     
     /// 하트비트 처리 (클라이언트에서 받은 하트비트)
     pub async fn handle_heartbeat(&self, client_id: u32) -> Result<()> {
+        let start_time = Instant::now();
+        
         // 하트비트 응답 전송
         let response = GameMessage::HeartBeatResponse { 
             timestamp: SimpleUtils::current_timestamp() 
@@ -191,14 +423,80 @@ impl HeartbeatService {
         }
         
         // 통계 업데이트
+        let latency = start_time.elapsed();
+        if let Some(metrics) = &self.metrics {
+            metrics.record_heartbeat_latency(latency);
+        }
         if let Ok(mut stats) = self.heartbeat_stats.try_lock() {
             stats.total_heartbeats += 1;
+            
+            let latency_ms = latency.as_secs_f64() * 1000.0;
+            if stats.average_heartbeat_latency_ms == 0.0 {
+                stats.average_heartbeat_latency_ms = latency_ms;
+            } else {
+                stats.average_heartbeat_latency_ms = (stats.average_heartbeat_latency_ms * 0.9) + (latency_ms * 0.1);
+            }
         }
         
         debug!("클라이언트 {} 하트비트 처리 완료", client_id);
         Ok(())
     }
     
+    /// 하트비트 왕복 시간 반영 (연결 품질 등급이 바뀌면 이벤트 발행 후 반환)
+    pub fn record_rtt(&self, user_id: u32, rtt_ms: u32) -> Option<ConnectionQualityChanged> {
+        if let Some(metrics) = &self.metrics {
+            metrics.record_rtt(Duration::from_millis(rtt_ms as u64));
+        }
+        
+        let (previous, current, smoothed) = {
+            let mut quality = self.quality.lock().unwrap_or_else(|e| e.into_inner());
+            let tracker = quality.entry(user_id).or_insert_with(|| QualityTracker::new(rtt_ms as f64));
+            let previous = tracker.observe(rtt_ms as f64)?;
+            (previous, tracker.quality, tracker.rtt_ms)
+        };
+        
+        let event = ConnectionQualityChanged {
+            user_id,
+            previous,
+            current,
+            rtt_ms: smoothed.round() as u32,
+            timestamp: chrono::Utc::now().timestamp(),
+        };
+        if current > previous {
+            warn!("사용자 {} 연결 품질 저하: {:?} → {:?} ({}ms)", user_id, previous, current, event.rtt_ms);
+        } else {
+            info!("사용자 {} 연결 품질 회복: {:?} → {:?} ({}ms)", user_id, previous, current, event.rtt_ms);
+        }
+        if let Some(metrics) = &self.metrics {
+            metrics.record_quality_change(current);
+        }
+        
+        // 로컬 구독자 (구독자가 없으면 무시)
+        let _ = self.quality_tx.send(event.clone());
+        
+        // 다른 서비스
+        if let Some(event_bus) = self.event_bus.get().cloned() {
+            let published = event.clone();
+            tokio::spawn(async move {
+                if let Err(e) = event_bus.publish_connection_quality(&published).await {
+                    debug!("연결 품질 이벤트 발행 실패 (사용자 {}): {}", published.user_id, e);
+                }
+            });
+        }
+        
+        Some(event)
+    }
+    
+    /// 연결 품질 변경 구독 (로컬 핸들러용)
+    pub fn subscribe_quality(&self) -> broadcast::Receiver<ConnectionQualityChanged> {
+        self.quality_tx.subscribe()
+    }
+    
+    /// 사용자 현재 연결 품질 (왕복 시간 보고가 없으면 None)
+    pub fn connection_quality(&self, user_id: u32) -> Option<ConnectionQuality> {
+        self.quality.lock().unwrap_or_else(|e| e.into_inner()).get(&user_id).map(|tracker| tracker.quality)
+    }
+    
     /// 하트비트 통계 조회
     pub async fn get_heartbeat_stats(&self) -> HeartbeatStats {
         self.heartbeat_stats.lock().await.clone()
@@ -300,6 +598,50 @@ mod tests {
         assert_eq!(timeout, 15);
     }
     
+    #[tokio::test]
+    async fn test_heartbeat_metrics_export() {
+        let collector = Arc::new(MetricsCollector::with_default_config());
+        let metrics = HeartbeatMetrics::new(collector.clone(), 5.0);
+        
+        assert_eq!(metrics.record_cleanup(3, 10, Duration::from_millis(2)), 3.0);
+        assert_eq!(metrics.record_cleanup(4, 6, Duration::from_millis(2)), 7.0);
+        metrics.record_heartbeat_latency(Duration::from_millis(1));
+        
+        let output = collector.export_prometheus_format();
+        assert!(output.contains("tcp_heartbeat_timeouts_total 7"));
+        assert!(output.contains("tcp_heartbeat_active_connections 6"));
+        assert!(output.contains("tcp_heartbeat_timeouts_per_minute 7"));
+        assert!(output.contains("tcp_heartbeat_latency_seconds_count 1"));
+        assert!(output.contains("tcp_heartbeat_cleanup_duration_seconds_count 2"));
+    }
+    
+    #[tokio::test]
+    async fn test_connection_quality_escalation() {
+        let connection_service = Arc::new(ConnectionService::new(100));
+        let heartbeat_service = HeartbeatService::new(connection_service, 5, 15);
+        let mut events = heartbeat_service.subscribe_quality();
+        
+        assert!(heartbeat_service.record_rtt(1, 40).is_none());
+        assert_eq!(heartbeat_service.connection_quality(1), Some(ConnectionQuality::Good));
+        
+        // 한 번 튄 값으로는 바뀌지 않음
+        assert!(heartbeat_service.record_rtt(1, 900).is_none());
+        assert!(heartbeat_service.record_rtt(1, 40).is_none());
+        
+        // 지속적으로 느려지면 저하 이벤트
+        let mut changes = Vec::new();
+        for _ in 0..10 {
+            changes.extend(heartbeat_service.record_rtt(1, 800));
+        }
+        assert_eq!(changes.last().map(|event| event.current), Some(ConnectionQuality::Poor));
+        assert_eq!(events.try_recv().unwrap().previous, ConnectionQuality::Good);
+        
+        // 회복
+        let recovered: Vec<_> = (0..20).filter_map(|_| heartbeat_service.record_rtt(1, 30)).collect();
+        assert_eq!(recovered.last().map(|event| event.current), Some(ConnectionQuality::Good));
+        assert_eq!(heartbeat_service.connection_quality(2), None);
+    }
+    
     #[tokio::test]
     async fn test_connection_health() {
         let connection_service = Arc::new(ConnectionService::new(100));
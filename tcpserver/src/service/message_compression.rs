@@ -15,6 +15,7 @@ use flate2::Compression;
 use flate2::write::{GzEncoder, ZlibEncoder};
 use flate2::read::{GzDecoder, ZlibDecoder};
 use std::io::{Write, Read};
+use shared::tool::high_performance::simd_optimizer::xxhash64;
 
 /// 압축 알고리즘
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -463,14 +464,9 @@ impl MessageCompressionService {
         self.decompress_zlib(data)
     }
     
-    /// 해시 계산
+    /// 해시 계산 (공유 xxHash64)
     fn calculate_hash(&self, data: &[u8]) -> u64 {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        
-        let mut hasher = DefaultHasher::new();
-        data.hash(&mut hasher);
-        hasher.finish()
+        xxhash64(data, 0)
     }
     
     /// 메시지를 배치에 추가
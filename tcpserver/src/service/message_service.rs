@@ -3,7 +3,7 @@
 //! 게임 메시지 라우팅, 검증, 변환을 담당합니다.
 
 use anyhow::{Result, anyhow};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use tokio::sync::{Mutex, broadcast};
 use tracing::{info, error, warn, debug};
 use std::collections::HashMap;
@@ -11,6 +11,8 @@ use std::collections::HashMap;
 use crate::protocol::GameMessage;
 use crate::service::ConnectionService;
 use crate::tool::SimpleUtils;
+use shared::tool::high_performance::{Batcher, BatcherConfig, BatcherStats};
+use shared::monitoring::TaskAccounting;
 
 /// 메시지 핸들러 타입
 pub type MessageHandler = Box<dyn Fn(u32, &GameMessage) -> Result<Option<GameMessage>> + Send + Sync>;
@@ -22,6 +24,8 @@ pub struct MessageService {
     message_stats: Arc<Mutex<MessageStats>>,
     broadcast_rx: Arc<Mutex<Option<broadcast::Receiver<(Option<u32>, GameMessage)>>>>,
     is_processing: Arc<Mutex<bool>>,
+    /// 알림성 브로드캐스트 마이크로 배치 (첫 사용 시 생성)
+    broadcast_batcher: OnceLock<Batcher<GameMessage>>,
 }
 
 /// 메시지 통계
@@ -47,6 +51,7 @@ impl MessageService {
             message_stats: Arc::new(Mutex::new(MessageStats::default())),
             broadcast_rx: Arc::new(Mutex::new(Some(broadcast_rx))),
             is_processing: Arc::new(Mutex::new(false)),
+            broadcast_batcher: OnceLock::new(),
         }
     }
     
@@ -90,10 +95,12 @@ impl MessageService {
         let connection_service = self.connection_service.clone();
         let is_processing_ref = self.is_processing.clone();
         
-        tokio::spawn(async move {
+        let accounting = TaskAccounting::global().subsystem("message_dispatch");
+        tokio::spawn(accounting.clone().instrument(async move {
             while *is_processing_ref.lock().await {
                 match rx.recv().await {
                     Ok((client_id, message)) => {
+                        accounting.set_queue_depth(rx.len());
                         let start_time = std::time::Instant::now();
                         
                         debug!("메시지 수신: {:?} from client {:?}", message, client_id);
@@ -124,7 +131,7 @@ impl MessageService {
             }
             
             info!("메시지 처리 루프 종료");
-        });
+        }));
         
         Ok(())
     }
@@ -145,7 +152,9 @@ impl MessageService {
     }
     
     /// 메시지 타입 문자열 반환
-    fn get_message_type(message: &GameMessage) -> String {
+    ///
+    /// 새 이름은 `protocol::TCP_PROTOCOL` 레지스트리에도 등록해야 합니다.
+    pub(crate) fn get_message_type(message: &GameMessage) -> String {
         match message {
             GameMessage::HeartBeat => "heartbeat".to_string(),
             GameMessage::HeartBeatResponse { .. } => "heartbeat_response".to_string(),
@@ -160,7 +169,26 @@ impl MessageService {
             GameMessage::ChatMessage { .. } => "chat".to_string(),
             GameMessage::FriendAdd { .. } => "friend_add".to_string(),
             GameMessage::FriendRemove { .. } => "friend_remove".to_string(),
+            GameMessage::DirectMessage { .. } => "direct_message".to_string(),
+            GameMessage::DirectMessageReceipt { .. } => "direct_message_receipt".to_string(),
+            GameMessage::BlockUser { .. } => "block_user".to_string(),
+            GameMessage::UnblockUser { .. } => "unblock_user".to_string(),
+            GameMessage::RoomRoleChanged { .. } => "room_role_changed".to_string(),
+            GameMessage::RichChatMessage { .. } => "rich_chat".to_string(),
+            GameMessage::CreateJoinCode { .. } => "create_join_code".to_string(),
+            GameMessage::JoinCodeCreated { .. } => "join_code_created".to_string(),
+            GameMessage::JoinByCode { .. } => "join_by_code".to_string(),
+            GameMessage::FriendPresenceRequest { .. } => "friend_presence_request".to_string(),
+            GameMessage::FriendPresenceList { .. } => "friend_presence_list".to_string(),
+            GameMessage::HeartBeatRtt { .. } => "heartbeat_rtt".to_string(),
+            GameMessage::QueuePosition { .. } => "queue_position".to_string(),
+            GameMessage::ServerBusy { .. } => "server_busy".to_string(),
+            GameMessage::CreateRoom { .. } => "create_room".to_string(),
+            GameMessage::RoomCreated { .. } => "room_created".to_string(),
             GameMessage::Connect { .. } => "connect".to_string(),
+            GameMessage::Reconnect { .. } => "reconnect".to_string(),
+            GameMessage::SessionToken { .. } => "session_token".to_string(),
+            GameMessage::ReconnectAck { .. } => "reconnect_ack".to_string(),
             GameMessage::ChatResponse { .. } => "chat_response".to_string(),
             GameMessage::UserInfo { .. } => "user_info".to_string(),
             GameMessage::SystemMessage { .. } => "system_message".to_string(),
@@ -271,6 +299,39 @@ impl MessageService {
         Ok(count)
     }
     
+    /// 브로드캐스트 예약
+    /// 
+    /// 수신자 수가 필요 없는 알림은 짧은 시간(기본 5ms) 동안 모아서
+    /// 연결마다 한 번의 쓰기로 전송합니다. 큐가 가득 차면 자리가 날 때까지 대기합니다.
+    pub async fn queue_broadcast(&self, message: GameMessage) -> Result<()> {
+        let batcher = self.broadcast_batcher.get_or_init(|| {
+            let connection_service = self.connection_service.clone();
+            let message_stats = self.message_stats.clone();
+            Batcher::new(BatcherConfig::default(), move |messages: Vec<GameMessage>| {
+                let connection_service = connection_service.clone();
+                let message_stats = message_stats.clone();
+                async move {
+                    let success = match connection_service.broadcast_messages(&messages).await {
+                        Ok(_) => true,
+                        Err(e) => {
+                            warn!("배치 브로드캐스트 실패 ({}개 메시지): {}", messages.len(), e);
+                            false
+                        }
+                    };
+                    for _ in &messages {
+                        Self::update_message_stats(&message_stats, "broadcast", 0.0, success).await;
+                    }
+                }
+            })
+        });
+        batcher.push(message).await
+    }
+    
+    /// 브로드캐스트 배치 통계 (배치를 사용한 적이 없으면 None)
+    pub fn broadcast_batch_stats(&self) -> Option<BatcherStats> {
+        self.broadcast_batcher.get().map(Batcher::stats)
+    }
+    
     /// 메시지 통계 조회
     pub async fn get_message_stats(&self) -> MessageStats {
         self.message_stats.lock().await.clone()
@@ -298,6 +359,22 @@ mod tests {
         assert_eq!(stats.total_messages, 0);
     }
     
+    #[tokio::test]
+    async fn test_queue_broadcast_batches_messages() {
+        let connection_service = Arc::new(ConnectionService::new(100));
+        let message_service = MessageService::new(connection_service);
+        assert!(message_service.broadcast_batch_stats().is_none());
+        
+        for code in 0..3 {
+            message_service.queue_broadcast(GameMessage::Error { code, message: "notice".to_string() }).await.unwrap();
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        
+        let stats = message_service.broadcast_batch_stats().unwrap();
+        assert_eq!(stats.items_flushed, 3);
+        assert_eq!(stats.batches, 1);
+    }
+    
     #[tokio::test]
     async fn test_message_handler_registration() {
         let connection_service = Arc::new(ConnectionService::new(100));
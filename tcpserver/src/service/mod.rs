@@ -15,7 +15,12 @@
 //! │   ├── 자동 연결 모니터링
 //! │   ├── 타임아웃 정리
 //! │   ├── 연결 상태 평가
-//! │   └── 하트비트 통계
+//! │   ├── 하트비트 통계
+//! │   └── Prometheus 메트릭 (HeartbeatMetrics)
+//! ├── SessionResumeService (세션 재개)
+//! │   ├── 세션 토큰 발급
+//! │   ├── 단절 중 메시지 버퍼링 (Redis)
+//! │   └── 재접속 시 방 복원 및 재전송
 //! ├── MessageService (메시지 처리)
 //! │   ├── 메시지 라우팅
 //! │   ├── 핸들러 등록
@@ -76,6 +81,22 @@ pub mod connection_service;
 /// 타임아웃된 연결을 자동으로 정리하는 서비스입니다.
 pub mod heartbeat_service;
 
+/// 접속 대기열
+/// 
+/// 최대 연결 수에 도달했을 때 새 연결을 대기시키고 순번, 예상 대기 시간, 거절 시 재시도 시간을 계산합니다.
+pub mod admission_queue;
+
+/// 세션 재개 서비스
+/// 
+/// 연결 시 세션 토큰을 발급하고, 단절 후 유예 시간 내 재접속 시
+/// 방 정보 복원과 놓친 메시지 재전송을 담당합니다.
+pub mod session_resume;
+
+/// 스티커 카탈로그
+/// 
+/// 채팅 스티커 목록을 로드하고 채팅 내용(텍스트 길이, 스티커 ID)을 검증합니다.
+pub mod sticker_catalog;
+
 /// TCP 서버 서비스
 /// 
 /// TCP 서버의 설정, 생명주기, 상태 관리를 담당하는 서비스입니다.
@@ -108,8 +129,8 @@ pub mod memory_pool;
 
 /// 원자적 통계 시스템
 /// 
-/// AtomicU64 기반 락-프리 통계 수집 시스템으로 성능 오버헤드를 최소화합니다.
-/// 실시간 메트릭 수집, 성능 임계값 모니터링, 자동 알림을 제공합니다.
+/// `shared::tool::high_performance`의 AtomicStats를 다시 내보내는 호환용 모듈입니다.
+/// 브로드캐스트 팬아웃, 방 인원 등 TCP 서버 통계도 공용 구현에 포함되어 있습니다.
 pub mod atomic_stats;
 
 // DashMap 최적화는 이제 shared::tool::high_performance::dashmap_optimizer 사용
@@ -138,10 +159,10 @@ pub mod message_compression;
 /// 연결 재사용, 상태 관리, 성능 최적화를 통해 연결 효율성을 극대화합니다.
 pub mod connection_pool;
 
-/// 성능 모니터링 및 프로파일링 도구
+/// 성능 모니터링 (호환용)
 /// 
-/// 실시간 성능 메트릭 수집, 프로파일링, 경고 시스템을 제공합니다.
-/// CPU, 메모리, 네트워크, 레이턴시 등 종합적인 성능 분석을 지원합니다.
+/// 공용 `MetricsCollector`/`AtomicStats` 위의 얇은 어댑터입니다.
+/// 기존 PerformanceMonitor, PerformanceMonitorConfig, PerformanceReport 경로를 유지합니다.
 pub mod performance_monitor;
 
 /// 성능 벤치마크 및 검증 도구
@@ -164,6 +185,17 @@ pub use connection_service::*;
 /// HeartbeatService, HeartbeatStats, ConnectionHealth 등이 포함됩니다.
 pub use heartbeat_service::*;
 
+/// 접속 대기열 타입들
+pub use admission_queue::{AdmissionConfig, AdmissionPass, AdmissionQueue, AdmissionStats, AdmissionTicket, QueueStatus};
+
+/// 세션 재개 서비스 타입들
+/// 
+/// SessionResumeService, SessionResumeConfig, ResumeOutcome 등이 포함됩니다.
+pub use session_resume::*;
+
+/// 스티커 카탈로그 타입들
+pub use sticker_catalog::{StickerCatalog, MAX_CHAT_TEXT_LEN};
+
 
 /// 메시지 처리 서비스 타입들
 /// 
@@ -200,10 +232,9 @@ pub use simd_optimizer::*;
 /// MessageCompressionService, MessageCompressionConfig, CompressionPerformanceReport 등이 포함됩니다.
 pub use message_compression::*;
 
-
-/// 성능 모니터링 서비스 타입들
+/// 성능 모니터링 타입들
 /// 
-/// 성능 모니터링 및 프로파일링과 관련된 모든 타입들을 제공합니다.
 /// PerformanceMonitor, PerformanceMonitorConfig, PerformanceReport 등이 포함됩니다.
 pub use performance_monitor::*;
 
+
@@ -7,17 +7,19 @@ use anyhow::Result;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use std::collections::HashMap;
+use tokio::io::AsyncWriteExt;
 use tokio::sync::Mutex;
 use tracing::{info, warn};
 use serde::{Serialize, Deserialize};
 use rayon::prelude::*;
 
+use crate::protocol::GameMessage;
 use crate::service::{
     AsyncIoOptimizer, AsyncIoOptimizerConfig,
     SimdOptimizer, SimdOptimizerConfig,
     MessageCompressionService, MessageCompressionConfig,
-    PerformanceMonitor, PerformanceMonitorConfig,
 };
+use shared::tool::high_performance::{AtomicStats, MetricsCollector};
 use shared::tool::high_performance::dashmap_optimizer::{
     DashMapOptimizer, DashMapOptimizerConfig,
 };
@@ -202,6 +204,82 @@ impl PerformanceBenchmark {
         Ok(result)
     }
     
+    /// 공유 프레임 브로드캐스트 벤치마크
+    /// 
+    /// 수신자마다 직렬화해서 쓰는 기존 방식과, 한 번 직렬화한 프레임을
+    /// vectored 쓰기로 공유하는 방식을 비교합니다.
+    pub async fn benchmark_shared_frame_broadcast(&self) -> Result<BenchmarkResult> {
+        info!("🚀 공유 프레임 브로드캐스트 벤치마크 시작");
+        
+        let optimizer = AsyncIoOptimizer::new(AsyncIoOptimizerConfig::default());
+        let recipients = self.config.concurrent_users.max(1);
+        let message = GameMessage::SystemMessage {
+            message: "x".repeat(self.config.message_size),
+        };
+        
+        // 기존 방식: 수신자마다 직렬화 후 쓰기
+        let baseline_start = Instant::now();
+        for _ in 0..self.config.iterations {
+            for _ in 0..recipients {
+                let mut sink = tokio::io::sink();
+                sink.write_all(&message.to_bytes()?).await?;
+            }
+        }
+        let baseline_duration = baseline_start.elapsed();
+        
+        let start = Instant::now();
+        let mut success_count = 0;
+        let mut latencies = Vec::new();
+        
+        // 공유 프레임 방식: 한 번 직렬화 후 수신자마다 vectored 쓰기
+        for _ in 0..self.config.iterations {
+            let op_start = Instant::now();
+            
+            let frame = message.to_frame()?;
+            let frame_len = frame.iter().map(|part| part.len()).sum();
+            let mut delivered = 0;
+            for _ in 0..recipients {
+                let mut sink = tokio::io::sink();
+                if optimizer.vectored_write(&mut sink, &frame).await.is_ok() {
+                    delivered += 1;
+                }
+            }
+            optimizer.record_shared_frame(delivered, frame_len);
+            
+            latencies.push(op_start.elapsed());
+            if delivered == recipients {
+                success_count += 1;
+            }
+        }
+        
+        let total_duration = start.elapsed();
+        let avg_latency = latencies.iter().sum::<Duration>() / latencies.len() as u32;
+        let min_latency = *latencies.iter().min().unwrap();
+        let max_latency = *latencies.iter().max().unwrap();
+        
+        let report = optimizer.generate_performance_report();
+        let speedup = baseline_duration.as_secs_f64() / total_duration.as_secs_f64().max(f64::EPSILON);
+        
+        let result = BenchmarkResult {
+            test_name: "공유 프레임 브로드캐스트".to_string(),
+            iterations: self.config.iterations,
+            total_duration,
+            avg_latency,
+            min_latency,
+            max_latency,
+            throughput_ops_per_sec: self.config.iterations as f64 / total_duration.as_secs_f64(),
+            success_rate: (success_count as f64 / self.config.iterations as f64) * 100.0,
+            memory_usage_mb: 2.0, // 추정값
+            cpu_usage_percent: 5.0, // 추정값
+        };
+        
+        self.results.lock().await.insert("shared_frame_broadcast".to_string(), result.clone());
+        
+        info!("✅ 공유 프레임 브로드캐스트 벤치마크 완료: {:.2}배 빠름, 할당 {}회 / 복사 {}바이트 절약",
+              speedup, report.allocations_saved, report.bytes_copy_saved);
+        Ok(result)
+    }
+    
     /// SIMD 최적화 벤치마크
     pub async fn benchmark_simd_optimizer(&self) -> Result<BenchmarkResult> {
         info!("🚀 SIMD 최적화 벤치마크 시작");
@@ -309,11 +387,13 @@ impl PerformanceBenchmark {
     }
     
     /// 성능 모니터링 벤치마크
+    ///
+    /// 서버가 실제로 사용하는 공유 `AtomicStats`/`MetricsCollector` 기록 비용을 측정합니다.
     pub async fn benchmark_performance_monitor(&self) -> Result<BenchmarkResult> {
         info!("🚀 성능 모니터링 벤치마크 시작");
         
-        let config = PerformanceMonitorConfig::default();
-        let monitor = PerformanceMonitor::new(config).await;
+        let stats = AtomicStats::new();
+        let metrics = MetricsCollector::with_default_config();
         
         let start = Instant::now();
         let mut success_count = 0;
@@ -324,9 +404,9 @@ impl PerformanceBenchmark {
             let op_start = Instant::now();
             
             // 메트릭 기록
-            monitor.record_latency("test_latency", Duration::from_millis(10)).await;
-            monitor.increment_request_count().await;
-            monitor.set_connection_count(i % 100).await;
+            stats.record_message_processing("chat", Duration::from_millis(10));
+            metrics.record_request(Duration::from_millis(10), false);
+            metrics.set_active_connections(i % 100);
             
             success_count += 1;
             
@@ -335,7 +415,8 @@ impl PerformanceBenchmark {
         }
         
         // 성능 보고서 생성
-        let _report = monitor.generate_report().await;
+        let _snapshot = stats.get_snapshot();
+        let _summary = metrics.generate_performance_summary();
         
         let total_duration = start.elapsed();
         let avg_latency = latencies.iter().sum::<Duration>() / latencies.len() as u32;
@@ -380,6 +461,11 @@ impl PerformanceBenchmark {
         let async_io_result = self.benchmark_async_io_optimizer().await;
         results.push(("async_io".to_string(), async_io_result, start.elapsed()));
         
+        // 공유 프레임 브로드캐스트 벤치마크
+        let start = Instant::now();
+        let shared_frame_result = self.benchmark_shared_frame_broadcast().await;
+        results.push(("shared_frame_broadcast".to_string(), shared_frame_result, start.elapsed()));
+        
         // SIMD 벤치마크
         let start = Instant::now();
         let simd_result = self.benchmark_simd_optimizer().await;
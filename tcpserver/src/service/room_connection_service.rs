@@ -2,6 +2,7 @@
 //! 
 //! DashMap을 사용하여 방(room_id) 기반으로 사용자 연결을 관리합니다.
 //! Redis 백업을 통해 데이터 영속성과 서버 간 상태 공유를 지원합니다.
+//! 방 참가자 목록은 지역을 포함한 키(`room:{region}:{room_id}:users`)에 기록합니다.
 
 use anyhow::{Result, anyhow};
 use std::sync::Arc;
@@ -15,7 +16,10 @@ use serde::{Serialize, Deserialize};
 
 use crate::protocol::GameMessage;
 use crate::service::atomic_stats::AtomicStats;
+use crate::service::session_resume::SessionResumeService;
 use shared::config::redis_config::RedisConfig;
+use shared::tool::high_performance::MetricsCollector;
+use shared::service::redis::region_presence::room_users_key;
 use redis::AsyncCommands;
 
 /// 사용자 연결 정보
@@ -125,12 +129,21 @@ pub struct RoomConnectionService {
     /// 서버 ID
     server_id: String,
     
+    /// 배포 지역
+    region: String,
+    
     /// 통계 (기존 유지용)
     stats: Arc<Mutex<RoomConnectionStats>>,
     
     /// 원자적 통계 시스템
     atomic_stats: Arc<AtomicStats>,
     
+    /// 공용 메트릭 수집기 (브로드캐스트 팬아웃, 방 통계 내보내기)
+    metrics: Option<Arc<MetricsCollector>>,
+    
+    /// 세션 재개 서비스 (단절된 방 멤버의 놓친 메시지 버퍼링)
+    session_resume: Option<Arc<SessionResumeService>>,
+    
     /// 서버 시작 시간
     server_start_time: Instant,
     
@@ -150,13 +163,34 @@ impl RoomConnectionService {
             broadcast_tx,
             redis_config: None,
             server_id,
+            region: "local".to_string(),
             stats: Arc::new(Mutex::new(RoomConnectionStats::default())),
             atomic_stats: Arc::new(AtomicStats::new()),
+            metrics: None,
+            session_resume: None,
             server_start_time: Instant::now(),
             sync_handle: Arc::new(Mutex::new(None)),
         }
     }
     
+    /// 배포 지역 설정 (기본값: "local")
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = region.into();
+        self
+    }
+    
+    /// 브로드캐스트/방 메트릭 기록 (`broadcasts_total`, `broadcast_fanout`, `active_rooms` 등)
+    pub fn with_metrics(mut self, metrics: Arc<MetricsCollector>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+    
+    /// 세션 재개 서비스 연결 (유예 시간 내 단절된 방 멤버에게 보낼 메시지 버퍼링)
+    pub fn with_session_resume(mut self, session_resume: Arc<SessionResumeService>) -> Self {
+        self.session_resume = Some(session_resume);
+        self
+    }
+    
     /// Redis 백업 설정 추가 (Phase 2)
     pub async fn with_redis_backup(mut self) -> Result<Self> {
         match RedisConfig::new().await {
@@ -195,6 +229,7 @@ impl RoomConnectionService {
         let connection = RoomUserConnection::new(user_id, room_id, addr, nickname, writer);
         
         // 방이 존재하지 않으면 생성
+        let room_created = !self.room_connections.contains_key(&room_id);
         self.room_connections.entry(room_id).or_insert_with(HashMap::new);
         self.room_info.entry(room_id).or_insert_with(|| RoomInfo::new(room_id));
         
@@ -221,18 +256,21 @@ impl RoomConnectionService {
         
         // 원자적 통계 업데이트
         self.atomic_stats.record_connection();
-        if !self.room_info.contains_key(&room_id) {
+        if room_created {
             self.atomic_stats.record_room_created();
         }
         self.atomic_stats.record_room_join();
+        self.atomic_stats.record_room_occupancy(self.get_room_user_count(room_id) as u64);
+        self.export_room_stats();
         
         // Redis에 동기화 (비동기)
         if let Some(redis_config) = &self.redis_config {
             let redis_config = redis_config.clone();
             let server_id = self.server_id.clone();
+            let region = self.region.clone();
             let conn_clone = connection.clone();
             tokio::spawn(async move {
-                if let Err(e) = Self::sync_user_to_redis(redis_config, server_id, conn_clone).await {
+                if let Err(e) = Self::sync_user_to_redis(redis_config, server_id, region, conn_clone).await {
                     error!("Redis 사용자 동기화 실패: {}", e);
                 }
             });
@@ -283,13 +321,15 @@ impl RoomConnectionService {
             // 원자적 통계 업데이트
             self.atomic_stats.record_disconnection();
             self.atomic_stats.record_room_leave();
+            self.export_room_stats();
             
             // Redis에서 제거 (비동기)
             if let Some(redis_config) = &self.redis_config {
                 let redis_config = redis_config.clone();
                 let server_id = self.server_id.clone();
+                let region = self.region.clone();
                 tokio::spawn(async move {
-                    if let Err(e) = Self::remove_user_from_redis(redis_config, server_id, room_id, user_id).await {
+                    if let Err(e) = Self::remove_user_from_redis(redis_config, server_id, region, room_id, user_id).await {
                         error!("Redis 사용자 제거 실패: {}", e);
                     }
                 });
@@ -329,6 +369,14 @@ impl RoomConnectionService {
         
         let processing_time = start_time.elapsed();
         
+        // 재접속 대기 중인 방 멤버를 위한 버퍼링
+        if let Some(session_resume) = &self.session_resume {
+            let buffered = session_resume.buffer_for_room(room_id, message).await;
+            if buffered > 0 {
+                debug!("방 {} 재접속 대기 사용자 {}명에게 메시지 버퍼링", room_id, buffered);
+            }
+        }
+        
         // 통계 업데이트 (기존)
         self.update_stats(|stats| {
             stats.total_messages_sent += success_count;
@@ -348,10 +396,13 @@ impl RoomConnectionService {
         
         self.atomic_stats.record_message_processing(message_type, processing_time);
         self.atomic_stats.record_broadcast_time(processing_time);
-        
-        // 에러 카운트 기록
-        for _ in 0..failed_count {
-            self.atomic_stats.record_broadcast_error();
+        self.atomic_stats.record_broadcast_fanout(success_count + failed_count, failed_count);
+        if let Some(metrics) = &self.metrics {
+            metrics.record_broadcast(
+                (success_count + failed_count) as usize,
+                failed_count as usize,
+                processing_time,
+            );
         }
         
         info!("방 {} 메시지 전송 완료: 성공 {}, 실패 {}", room_id, success_count, failed_count);
@@ -360,8 +411,12 @@ impl RoomConnectionService {
     
     /// 특정 사용자에게 메시지 전송
     pub async fn send_to_user_in_room(&self, room_id: u32, user_id: u32, message: &GameMessage) -> Result<()> {
-        if let Some(room_users) = self.room_connections.get(&room_id) {
-            if let Some(connection) = room_users.get(&user_id) {
+        let connection = self.room_connections
+            .get(&room_id)
+            .map(|room_users| room_users.get(&user_id).cloned());
+        
+        match connection {
+            Some(Some(connection)) => {
                 connection.send_message(message).await?;
                 
                 // 통계 업데이트
@@ -371,11 +426,21 @@ impl RoomConnectionService {
                 
                 debug!("사용자 {} (방 {})에게 메시지 전송 완료", user_id, room_id);
                 Ok(())
-            } else {
-                Err(anyhow!("사용자 {}가 방 {}에 없습니다", user_id, room_id))
             }
-        } else {
-            Err(anyhow!("방 {}가 존재하지 않습니다", room_id))
+            missing => {
+                // 재접속 대기 중인 사용자는 메시지를 버퍼링
+                if let Some(session_resume) = &self.session_resume {
+                    if session_resume.buffer_message(user_id, message).await {
+                        debug!("사용자 {} (방 {}) 재접속 대기 중 - 메시지 버퍼링", user_id, room_id);
+                        return Ok(());
+                    }
+                }
+                
+                match missing {
+                    Some(_) => Err(anyhow!("사용자 {}가 방 {}에 없습니다", user_id, room_id)),
+                    None => Err(anyhow!("방 {}가 존재하지 않습니다", room_id)),
+                }
+            }
         }
     }
     
@@ -533,6 +598,7 @@ impl RoomConnectionService {
         let room_connections = self.room_connections.clone();
         let redis_config = self.redis_config.as_ref().unwrap().clone();
         let server_id = self.server_id.clone();
+        let region = self.region.clone();
         let stats = self.stats.clone();
         
         let handle = tokio::spawn(async move {
@@ -541,7 +607,7 @@ impl RoomConnectionService {
             loop {
                 interval.tick().await;
                 
-                if let Err(e) = Self::sync_all_to_redis(&room_connections, &redis_config, &server_id).await {
+                if let Err(e) = Self::sync_all_to_redis(&room_connections, &redis_config, &server_id, &region).await {
                     error!("Redis 전체 동기화 실패: {}", e);
                     if let Ok(mut stats) = stats.try_lock() {
                         stats.redis_sync_failures += 1;
@@ -565,6 +631,7 @@ impl RoomConnectionService {
         room_connections: &DashMap<u32, HashMap<u32, RoomUserConnection>>,
         redis_config: &RedisConfig,
         server_id: &str,
+        region: &str,
     ) -> Result<()> {
         let mut conn = redis_config.get_connection();
         let mut pipeline = redis::pipe();
@@ -579,8 +646,9 @@ impl RoomConnectionService {
                 
                 pipeline
                     .hset(format!("tcp_server:{}:room:{}", server_id, room_id), user_id, &conn_json)
-                    .sadd(format!("room:{}:users", room_id), user_id)
+                    .sadd(room_users_key(region, room_id), user_id)
                     .hset(format!("user:{}:location", user_id), "server_id", server_id)
+                    .hset(format!("user:{}:location", user_id), "region", region)
                     .hset(format!("user:{}:location", user_id), "room_id", room_id)
                     .expire(format!("user:{}:location", user_id), 7200); // 2시간
             }
@@ -594,6 +662,7 @@ impl RoomConnectionService {
     async fn sync_user_to_redis(
         redis_config: Arc<RedisConfig>, 
         server_id: String, 
+        region: String,
         connection: RoomUserConnection
     ) -> Result<()> {
         let mut conn = redis_config.get_connection();
@@ -602,8 +671,9 @@ impl RoomConnectionService {
         let mut pipeline = redis::pipe();
         pipeline
             .hset(format!("tcp_server:{}:room:{}", server_id, connection.room_id), connection.user_id, &conn_json)
-            .sadd(format!("room:{}:users", connection.room_id), connection.user_id)
+            .sadd(room_users_key(&region, connection.room_id), connection.user_id)
             .hset(format!("user:{}:location", connection.user_id), "server_id", &server_id)
+            .hset(format!("user:{}:location", connection.user_id), "region", &region)
             .hset(format!("user:{}:location", connection.user_id), "room_id", connection.room_id)
             .expire(format!("user:{}:location", connection.user_id), 7200); // 2시간
             
@@ -615,6 +685,7 @@ impl RoomConnectionService {
     async fn remove_user_from_redis(
         redis_config: Arc<RedisConfig>, 
         server_id: String, 
+        region: String,
         room_id: u32,
         user_id: u32
     ) -> Result<()> {
@@ -623,7 +694,7 @@ impl RoomConnectionService {
         let mut pipeline = redis::pipe();
         pipeline
             .hdel(format!("tcp_server:{}:room:{}", server_id, room_id), user_id)
-            .srem(format!("room:{}:users", room_id), user_id)
+            .srem(room_users_key(&region, room_id), user_id)
             .del(format!("user:{}:location", user_id));
             
         pipeline.query_async::<_, ()>(&mut conn).await?;
@@ -682,6 +753,13 @@ impl RoomConnectionService {
         self.atomic_stats.clone()
     }
     
+    /// 방 통계를 공용 메트릭 수집기에 반영
+    fn export_room_stats(&self) {
+        if let Some(metrics) = &self.metrics {
+            metrics.set_room_stats(self.room_connections.len(), self.user_room_map.len());
+        }
+    }
+    
     /// 현재 통계 스냅샷 조회
     pub fn get_performance_snapshot(&self) -> crate::service::atomic_stats::StatsSnapshot {
         self.atomic_stats.get_snapshot()
@@ -703,4 +781,22 @@ mod tests {
         // 방 목록이 비어있는지 확인
         assert!(service.get_all_rooms().is_empty());
     }
-}
\ No newline at end of file
+    
+    #[tokio::test]
+    async fn test_send_to_user_in_room_buffers_for_pending_resume() {
+        let session_resume = Arc::new(SessionResumeService::new(Default::default()));
+        let service = RoomConnectionService::new("test_server".to_string())
+            .with_session_resume(session_resume.clone());
+        let message = GameMessage::ChatMessage { user_id: 2, room_id: 7, message: "hi".to_string() };
+        
+        let token = session_resume.issue_token(1, 7);
+        // 연결 중인데 방에 없으면 버퍼링하지 않음
+        assert!(service.send_to_user_in_room(7, 1, &message).await.is_err());
+        
+        session_resume.mark_disconnected(1);
+        assert!(service.send_to_user_in_room(7, 1, &message).await.is_ok());
+        
+        let outcome = session_resume.resume(1, &token).await.unwrap();
+        assert_eq!(outcome.missed_messages.len(), 1);
+    }
+}
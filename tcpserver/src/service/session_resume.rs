@@ -0,0 +1,428 @@
+//! 세션 재개 서비스
+//!
+//! 연결 시 세션 토큰을 발급하고, 네트워크 단절 후 유예 시간 안에 토큰으로 재접속하면
+//! 방 정보를 복원하고 단절 동안 놓친 메시지를 순서대로 재전송합니다.
+//! 놓친 메시지는 Redis 리스트(`tcp:resume:{user_id}`)에 버퍼링하며,
+//! Redis가 연결되지 않은 경우 메모리 버퍼를 사용합니다.
+
+use anyhow::{Result, anyhow};
+use dashmap::DashMap;
+use rand::RngCore;
+use redis::AsyncCommands;
+use std::collections::VecDeque;
+use std::sync::{Arc, OnceLock};
+use tokio::time::{Duration, Instant};
+use tracing::{info, warn, debug};
+
+use crate::protocol::GameMessage;
+use shared::config::redis_config::RedisConfig;
+use shared::security::constant_time_eq;
+use shared::tool::HexUtils;
+
+/// 세션 재개 설정
+#[derive(Debug, Clone)]
+pub struct SessionResumeConfig {
+    /// 단절 후 재접속을 허용하는 유예 시간
+    pub grace_period: Duration,
+    /// 사용자별 최대 버퍼링 메시지 수 (초과 시 오래된 메시지부터 버림)
+    pub max_buffered_messages: usize,
+}
+
+impl Default for SessionResumeConfig {
+    fn default() -> Self {
+        Self {
+            grace_period: Duration::from_secs(60),
+            max_buffered_messages: 200,
+        }
+    }
+}
+
+/// 재개 가능한 세션 정보
+#[derive(Debug)]
+struct ResumableSession {
+    token: String,
+    room_id: u32,
+    /// 로그인 세션(리프레시 토큰 패밀리) ID
+    family_id: Option<String>,
+    disconnected_at: Option<Instant>,
+    /// Redis 미사용 시 메모리 버퍼
+    buffered: VecDeque<GameMessage>,
+}
+
+/// 세션 재개 결과
+#[derive(Debug)]
+pub struct ResumeOutcome {
+    /// 복원된 방 ID
+    pub room_id: u32,
+    /// 복원된 로그인 세션(리프레시 토큰 패밀리) ID
+    pub family_id: Option<String>,
+    /// 단절 동안 놓친 메시지 (오래된 순)
+    pub missed_messages: Vec<GameMessage>,
+    /// 새로 발급된 세션 토큰
+    pub session_token: String,
+}
+
+/// 세션 재개 서비스
+pub struct SessionResumeService {
+    config: SessionResumeConfig,
+    sessions: DashMap<u32, ResumableSession>,
+    redis: OnceLock<Arc<RedisConfig>>,
+}
+
+impl SessionResumeService {
+    /// 새로운 세션 재개 서비스 생성
+    pub fn new(config: SessionResumeConfig) -> Self {
+        Self {
+            config,
+            sessions: DashMap::new(),
+            redis: OnceLock::new(),
+        }
+    }
+
+    /// Redis 버퍼 연결 (최초 1회만 적용)
+    pub fn attach_redis(&self, redis: Arc<RedisConfig>) {
+        if self.redis.set(redis).is_err() {
+            debug!("세션 재개 서비스에 Redis가 이미 연결되어 있습니다");
+        }
+    }
+
+    /// 재접속 유예 시간
+    pub fn grace_period(&self) -> Duration {
+        self.config.grace_period
+    }
+
+    /// 세션 토큰 발급
+    ///
+    /// 같은 사용자의 이전 토큰은 무효화됩니다.
+    pub fn issue_token(&self, user_id: u32, room_id: u32) -> String {
+        let token = Self::generate_token();
+        self.sessions.insert(user_id, ResumableSession {
+            token: token.clone(),
+            room_id,
+            family_id: None,
+            disconnected_at: None,
+            buffered: VecDeque::new(),
+        });
+
+        debug!("사용자 {} 세션 토큰 발급 (방 {})", user_id, room_id);
+        token
+    }
+
+    /// 세션의 로그인 세션(리프레시 토큰 패밀리) 기록 (재개 후에도 유지)
+    pub fn set_family(&self, user_id: u32, family_id: Option<String>) {
+        if let Some(mut session) = self.sessions.get_mut(&user_id) {
+            session.family_id = family_id;
+        }
+    }
+    
+    /// 연결 단절 기록 (유예 시간 시작)
+    pub fn mark_disconnected(&self, user_id: u32) {
+        if let Some(mut session) = self.sessions.get_mut(&user_id) {
+            session.disconnected_at = Some(Instant::now());
+            info!("사용자 {} 연결 단절 - {}초 동안 재접속 대기", user_id, self.config.grace_period.as_secs());
+        }
+    }
+
+    /// 세션 폐기 (정상 종료 시)
+    pub async fn revoke(&self, user_id: u32) {
+        if self.sessions.remove(&user_id).is_some() {
+            self.clear_redis_buffer(user_id).await;
+            debug!("사용자 {} 세션 토큰 폐기", user_id);
+        }
+    }
+
+    /// 유예 시간 내 단절 상태인지 확인
+    pub fn is_pending_resume(&self, user_id: u32) -> bool {
+        self.sessions
+            .get(&user_id)
+            .map(|session| self.within_grace(&session))
+            .unwrap_or(false)
+    }
+
+    /// 단절된 사용자에게 보낼 메시지 버퍼링
+    ///
+    /// 유예 시간 내 단절 상태가 아니면 버퍼링하지 않고 false를 반환합니다.
+    pub async fn buffer_message(&self, user_id: u32, message: &GameMessage) -> bool {
+        if !self.is_pending_resume(user_id) {
+            return false;
+        }
+
+        if let Some(redis) = self.redis.get() {
+            match self.push_to_redis(redis, user_id, message).await {
+                Ok(()) => return true,
+                Err(e) => warn!("Redis 메시지 버퍼링 실패, 메모리 버퍼 사용: {}", e),
+            }
+        }
+
+        match self.sessions.get_mut(&user_id) {
+            Some(mut session) => {
+                if session.buffered.len() >= self.config.max_buffered_messages {
+                    session.buffered.pop_front();
+                }
+                session.buffered.push_back(message.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 유예 시간 내 단절된 모든 사용자에게 메시지 버퍼링
+    pub async fn buffer_for_pending(&self, message: &GameMessage) -> usize {
+        let pending: Vec<u32> = self.sessions
+            .iter()
+            .filter(|entry| self.within_grace(entry.value()))
+            .map(|entry| *entry.key())
+            .collect();
+
+        let mut buffered = 0;
+        for user_id in pending {
+            if self.buffer_message(user_id, message).await {
+                buffered += 1;
+            }
+        }
+        buffered
+    }
+
+    /// 유예 시간 내 단절된 방 멤버에게 메시지 버퍼링
+    ///
+    /// 단절 시 방에서 제거된 사용자도 세션에 남은 방 ID로 찾아 버퍼링합니다.
+    pub async fn buffer_for_room(&self, room_id: u32, message: &GameMessage) -> usize {
+        let pending: Vec<u32> = self.sessions
+            .iter()
+            .filter(|entry| entry.room_id == room_id && self.within_grace(entry.value()))
+            .map(|entry| *entry.key())
+            .collect();
+
+        let mut buffered = 0;
+        for user_id in pending {
+            if self.buffer_message(user_id, message).await {
+                buffered += 1;
+            }
+        }
+        buffered
+    }
+
+    /// 세션 재개
+    ///
+    /// 토큰과 유예 시간을 확인한 뒤 놓친 메시지를 꺼내고 새 토큰을 발급합니다.
+    pub async fn resume(&self, user_id: u32, token: &str) -> Result<ResumeOutcome> {
+        let (room_id, family_id, mut missed_messages) = {
+            let mut session = self.sessions
+                .get_mut(&user_id)
+                .ok_or_else(|| anyhow!("재개할 세션이 없습니다: 사용자 {}", user_id))?;
+
+            // 타이밍 공격 방지를 위한 상수 시간 비교
+            if !constant_time_eq(session.token.as_bytes(), token.as_bytes()) {
+                return Err(anyhow!("세션 토큰이 일치하지 않습니다: 사용자 {}", user_id));
+            }
+            // 연결이 살아 있는 세션은 재개 대상이 아님 (토큰 탈취 시 하이재킹 방지)
+            if session.disconnected_at.is_none() {
+                return Err(anyhow!("연결 중인 세션은 재개할 수 없습니다: 사용자 {}", user_id));
+            }
+            if !self.within_grace(&session) {
+                drop(session);
+                self.revoke(user_id).await;
+                return Err(anyhow!("재접속 유예 시간이 지났습니다: 사용자 {}", user_id));
+            }
+
+            (session.room_id, session.family_id.clone(), session.buffered.drain(..).collect::<Vec<_>>())
+        };
+
+        if let Some(redis) = self.redis.get() {
+            match self.drain_redis(redis, user_id).await {
+                Ok(mut redis_messages) => {
+                    redis_messages.append(&mut missed_messages);
+                    missed_messages = redis_messages;
+                }
+                Err(e) => warn!("Redis 버퍼 조회 실패: 사용자 {} - {}", user_id, e),
+            }
+        }
+
+        let session_token = self.issue_token(user_id, room_id);
+        self.set_family(user_id, family_id.clone());
+
+        info!("✅ 사용자 {} 세션 재개 (방 {}, 놓친 메시지 {}개)", user_id, room_id, missed_messages.len());
+        Ok(ResumeOutcome {
+            room_id,
+            family_id,
+            missed_messages,
+            session_token,
+        })
+    }
+
+    /// 유예 시간이 지난 세션 정리
+    pub async fn cleanup_expired(&self) -> usize {
+        let expired: Vec<u32> = self.sessions
+            .iter()
+            .filter(|entry| entry.disconnected_at.is_some() && !self.within_grace(entry.value()))
+            .map(|entry| *entry.key())
+            .collect();
+
+        for user_id in &expired {
+            self.revoke(*user_id).await;
+        }
+
+        if !expired.is_empty() {
+            debug!("만료된 재개 세션 정리: {}개", expired.len());
+        }
+        expired.len()
+    }
+
+    fn within_grace(&self, session: &ResumableSession) -> bool {
+        session
+            .disconnected_at
+            .map(|at| at.elapsed() <= self.config.grace_period)
+            .unwrap_or(false)
+    }
+
+    fn generate_token() -> String {
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        HexUtils::bytes_to_hex(&bytes)
+    }
+
+    fn redis_key(user_id: u32) -> String {
+        format!("tcp:resume:{}", user_id)
+    }
+
+    async fn push_to_redis(&self, redis: &RedisConfig, user_id: u32, message: &GameMessage) -> Result<()> {
+        let mut conn = redis.get_connection();
+        let key = Self::redis_key(user_id);
+        let payload = serde_json::to_string(message)?;
+        let max = self.config.max_buffered_messages as isize;
+
+        let _: () = conn.rpush(&key, payload).await
+            .map_err(|e| anyhow!("Redis RPUSH 실패: {}", e))?;
+        let _: () = conn.ltrim(&key, -max, -1).await
+            .map_err(|e| anyhow!("Redis LTRIM 실패: {}", e))?;
+        let _: () = conn.expire(&key, self.config.grace_period.as_secs() as i64).await
+            .map_err(|e| anyhow!("Redis EXPIRE 실패: {}", e))?;
+        Ok(())
+    }
+
+    async fn drain_redis(&self, redis: &RedisConfig, user_id: u32) -> Result<Vec<GameMessage>> {
+        let mut conn = redis.get_connection();
+        let key = Self::redis_key(user_id);
+
+        let payloads: Vec<String> = conn.lrange(&key, 0, -1).await
+            .map_err(|e| anyhow!("Redis LRANGE 실패: {}", e))?;
+        let _: () = conn.del(&key).await
+            .map_err(|e| anyhow!("Redis DEL 실패: {}", e))?;
+
+        Ok(payloads
+            .iter()
+            .filter_map(|payload| serde_json::from_str(payload).ok())
+            .collect())
+    }
+
+    async fn clear_redis_buffer(&self, user_id: u32) {
+        if let Some(redis) = self.redis.get() {
+            let mut conn = redis.get_connection();
+            let result: redis::RedisResult<()> = conn.del(Self::redis_key(user_id)).await;
+            if let Err(e) = result {
+                warn!("Redis 재개 버퍼 삭제 실패: 사용자 {} - {}", user_id, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(grace_secs: u64) -> SessionResumeService {
+        SessionResumeService::new(SessionResumeConfig {
+            grace_period: Duration::from_secs(grace_secs),
+            max_buffered_messages: 2,
+        })
+    }
+
+    fn chat(message: &str) -> GameMessage {
+        GameMessage::ChatMessage { user_id: 2, room_id: 7, message: message.to_string() }
+    }
+
+    #[tokio::test]
+    async fn test_resume_replays_missed_messages() {
+        let service = service(60);
+        let token = service.issue_token(1, 7);
+
+        // 연결 중에는 버퍼링하지 않음
+        assert!(!service.buffer_message(1, &chat("before")).await);
+
+        service.mark_disconnected(1);
+        assert!(service.buffer_message(1, &chat("a")).await);
+        assert!(service.buffer_message(1, &chat("b")).await);
+        assert!(service.buffer_message(1, &chat("c")).await);
+
+        let outcome = service.resume(1, &token).await.unwrap();
+        assert_eq!(outcome.room_id, 7);
+        assert_ne!(outcome.session_token, token);
+        assert_eq!(outcome.family_id, None);
+
+        let replayed: Vec<String> = outcome.missed_messages.iter().map(|m| match m {
+            GameMessage::ChatMessage { message, .. } => message.clone(),
+            _ => String::new(),
+        }).collect();
+        assert_eq!(replayed, vec!["b", "c"]);
+
+        // 이전 토큰은 재사용 불가
+        assert!(service.resume(1, &token).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resume_rejects_wrong_token_and_expired_grace() {
+        let service = service(0);
+        let token = service.issue_token(1, 7);
+
+        assert!(service.resume(1, "invalid").await.is_err());
+
+        service.mark_disconnected(1);
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        assert!(!service.is_pending_resume(1));
+        assert!(service.resume(1, &token).await.is_err());
+        assert!(service.resume(1, &token).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resume_rejects_connected_session() {
+        let service = service(60);
+        let token = service.issue_token(1, 7);
+
+        // 단절 기록 전에는 올바른 토큰이어도 재개 불가
+        assert!(service.resume(1, &token).await.is_err());
+
+        service.mark_disconnected(1);
+        assert!(service.resume(1, &token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_buffer_for_room_only_targets_room_members() {
+        let service = service(60);
+        service.issue_token(1, 7);
+        service.issue_token(2, 8);
+        service.issue_token(3, 7);
+        service.mark_disconnected(1);
+        service.mark_disconnected(2);
+
+        // 3번은 연결 중, 2번은 다른 방이므로 1번만 버퍼링
+        assert_eq!(service.buffer_for_room(7, &chat("room")).await, 1);
+        assert!(service.sessions.get(&1).is_some_and(|session| session.buffered.len() == 1));
+        assert!(service.sessions.get(&2).is_some_and(|session| session.buffered.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_resume_keeps_session_family() {
+        let service = service(60);
+        let token = service.issue_token(1, 7);
+        service.set_family(1, Some("family-a".to_string()));
+
+        service.mark_disconnected(1);
+        let outcome = service.resume(1, &token).await.unwrap();
+        assert_eq!(outcome.family_id.as_deref(), Some("family-a"));
+
+        // 재개 후 다시 끊겨도 같은 패밀리 유지
+        service.mark_disconnected(1);
+        let outcome = service.resume(1, &outcome.session_token).await.unwrap();
+        assert_eq!(outcome.family_id.as_deref(), Some("family-a"));
+    }
+}
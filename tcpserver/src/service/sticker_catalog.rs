@@ -0,0 +1,110 @@
+//! 스티커 카탈로그
+//! 
+//! 채팅에서 보낼 수 있는 스티커 목록을 `property/stickers.toml`에서 로드합니다.
+//! 카탈로그에 없는 스티커 ID는 채팅 검증 단계에서 거부됩니다.
+
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::{info, warn};
+
+use crate::protocol::ChatContent;
+
+/// 텍스트 채팅 최대 길이 (바이트)
+pub const MAX_CHAT_TEXT_LEN: usize = 1000;
+
+/// 스티커 정의
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Sticker {
+    pub id: String,
+    /// 스티커 팩 이름
+    pub pack: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct StickerFile {
+    #[serde(default)]
+    stickers: Vec<Sticker>,
+}
+
+/// 사용 가능한 스티커 목록
+#[derive(Debug, Clone, Default)]
+pub struct StickerCatalog {
+    stickers: HashMap<String, Sticker>,
+}
+
+impl StickerCatalog {
+    /// 카탈로그 파일 로드 (파일이 없으면 빈 카탈로그 - 스티커 전송 불가)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            warn!("스티커 카탈로그 파일이 없습니다: {} (스티커 비활성화)", path.display());
+            return Ok(Self::default());
+        }
+        
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("스티커 카탈로그 읽기 실패: {}", path.display()))?;
+        let catalog = Self::from_toml(&contents)
+            .with_context(|| format!("잘못된 스티커 카탈로그: {}", path.display()))?;
+        info!("스티커 카탈로그 로드: {}개", catalog.stickers.len());
+        Ok(catalog)
+    }
+    
+    /// TOML 문자열에서 카탈로그 생성 (ID 중복 시 에러)
+    pub fn from_toml(contents: &str) -> Result<Self> {
+        let file: StickerFile = toml::from_str(contents)?;
+        let mut stickers = HashMap::new();
+        for sticker in file.stickers {
+            if sticker.id.trim().is_empty() {
+                return Err(anyhow!("스티커 ID가 비어있습니다"));
+            }
+            if let Some(duplicate) = stickers.insert(sticker.id.clone(), sticker) {
+                return Err(anyhow!("중복된 스티커 ID: {}", duplicate.id));
+            }
+        }
+        Ok(Self { stickers })
+    }
+    
+    pub fn get(&self, sticker_id: &str) -> Option<&Sticker> {
+        self.stickers.get(sticker_id)
+    }
+    
+    /// 채팅 내용 검증 (텍스트 길이, 스티커 ID)
+    pub fn validate(&self, content: &ChatContent) -> Result<()> {
+        match content {
+            ChatContent::Text { text } => {
+                if text.trim().is_empty() {
+                    return Err(anyhow!("채팅 내용이 비어있습니다"));
+                }
+                if text.len() > MAX_CHAT_TEXT_LEN {
+                    return Err(anyhow!("채팅 내용이 너무 깁니다"));
+                }
+            }
+            ChatContent::Sticker { sticker_id } => {
+                if self.get(sticker_id).is_none() {
+                    return Err(anyhow!("알 수 없는 스티커: {}", sticker_id));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_sticker_validation() {
+        let catalog = StickerCatalog::from_toml(
+            "[[stickers]]\nid = \"police_salute\"\npack = \"basic\"\n",
+        ).unwrap();
+        assert!(catalog.validate(&ChatContent::Sticker { sticker_id: "police_salute".to_string() }).is_ok());
+        assert!(catalog.validate(&ChatContent::Sticker { sticker_id: "unknown".to_string() }).is_err());
+        assert!(catalog.validate(&ChatContent::Text { text: " ".to_string() }).is_err());
+        
+        let duplicate = "[[stickers]]\nid = \"a\"\npack = \"p\"\n[[stickers]]\nid = \"a\"\npack = \"p\"\n";
+        assert!(StickerCatalog::from_toml(duplicate).is_err());
+    }
+}
@@ -4,6 +4,7 @@
 
 use anyhow::{anyhow, Context, Result};
 use shared::config::redis_config::RedisConfig;
+use shared::tool::{ErrorCode, GameServerError};
 use shared::tool::high_performance::async_task_scheduler::SchedulerConfig;
 use shared::tool::high_performance::{
     AlignedBuffer, AsyncTaskScheduler, EnhancedMemoryPool,
@@ -12,7 +13,7 @@ use shared::tool::high_performance::{
 use std::sync::Arc;
 use tokio::net::TcpListener;
 use tokio::sync::Mutex;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::handler::ChatRoomMessageHandler;
 use crate::service::{ConnectionService, HeartbeatService, RoomConnectionService};
@@ -49,6 +50,11 @@ impl Default for TcpServerConfig {
     }
 }
 
+/// 메모리 풀 누수 리포트 주기
+const LEASE_REPORT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+/// 이 시간보다 오래 반환하지 않은 버퍼는 누수 의심
+const LEASE_LEAK_THRESHOLD: std::time::Duration = std::time::Duration::from_secs(300);
+
 /// TCP 게임 서버 서비스
 pub struct TcpGameService {
     config: TcpServerConfig,
@@ -169,6 +175,11 @@ impl TcpGameService {
             info!("✅ 고성능 비동기 스케줄러 시작됨");
         }
         
+        // 메모리 풀 미반환 버퍼 주기 리포트
+        if let Some(leases) = self.enhanced_memory_pool.as_ref().and_then(|pool| pool.leases()) {
+            leases.spawn_leak_reporter(LEASE_REPORT_INTERVAL, LEASE_LEAK_THRESHOLD);
+        }
+        
         // 클라이언트 연결 처리 루프
         while *self.is_running.lock().await {
             match listener.accept().await {
@@ -298,7 +309,7 @@ impl TcpGameService {
         // 스트림 분리
         let (reader, writer) = stream.into_split();
         let mut buf_reader = BufReader::new(reader);
-        let buf_writer = tokio::io::BufWriter::new(writer);
+        let mut buf_writer = tokio::io::BufWriter::new(writer);
 
         // 첫 Connect 메시지 읽기
         let connect_message = match GameMessage::read_from_stream(&mut buf_reader).await {
@@ -310,9 +321,23 @@ impl TcpGameService {
         };
 
         // Connect 메시지 검증
-        if !matches!(connect_message, GameMessage::Connect { .. }) {
-            error!("첫 메시지가 Connect가 아님 ({}): {:?}", addr, connect_message);
-            return Err(anyhow!("첫 메시지는 Connect 메시지여야 합니다"));
+        let protocol = match &connect_message {
+            GameMessage::Connect { protocol, .. } => protocol.as_deref().unwrap_or("1.0.0"),
+            _ => {
+                error!("첫 메시지가 Connect가 아님 ({}): {:?}", addr, connect_message);
+                return Err(anyhow!("첫 메시지는 Connect 메시지여야 합니다"));
+            }
+        };
+
+        // 프로토콜 버전 협상
+        match crate::protocol::TCP_PROTOCOL.negotiate_str(protocol) {
+            Ok(negotiated) => debug!("프로토콜 협상 완료 ({}): {}", addr, negotiated.to_offer()),
+            Err(e) => {
+                warn!("프로토콜 협상 실패 ({}): {}", addr, e);
+                let error_msg = GameMessage::from_error(&GameServerError::with_detail(ErrorCode::UnsupportedVersion, e.to_string()));
+                let _ = error_msg.write_to_stream(&mut buf_writer).await;
+                return Err(anyhow!("프로토콜 협상 실패: {}", e));
+            }
         }
 
         info!("Connect 메시지 수신 완료: {} -> {:?}", addr, connect_message);
@@ -355,10 +380,11 @@ impl TcpGameService {
     /// 향상된 메모리 풀에서 버퍼 할당
     /// 
     /// 고성능 메모리 풀이 활성화된 경우 최적화된 버퍼를 반환합니다.
+    #[track_caller]
     pub fn allocate_buffer(&self, size: usize) -> Option<AlignedBuffer> {
-        self.enhanced_memory_pool
-            .as_ref()
-            .map(|pool| pool.allocate(size))
+        // 클로저(`Option::map`)를 거치면 대여 위치가 클로저로 기록되므로 직접 호출
+        let pool = self.enhanced_memory_pool.as_ref()?;
+        Some(pool.allocate(size))
     }
     
     /// 향상된 메모리 풀에 버퍼 반환
@@ -0,0 +1,242 @@
+//! 안내 메시지 다국어 처리
+//!
+//! 추방 사유, 에러, 공지 등 서버가 보내는 문장을 사용자 로케일에 맞춰 만듭니다.
+//! 핸들러는 문장 대신 [`MessageKey`]를 넘기고, 템플릿은 `property/messages.toml`에 로케일별로 둡니다.
+//!
+//! 로케일 대체 순서: 요청 로케일(`ko-KR`) -> 언어(`ko`) -> 카탈로그 기본 로케일
+
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use tracing::{info, warn};
+
+/// 빌드에 포함된 기본 카탈로그
+const BUILTIN_CATALOG: &str = include_str!("../../../property/messages.toml");
+
+/// 안내 메시지 키
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageKey {
+    /// 세션 재개 실패
+    SessionResumeFailed,
+    /// 다른 기기 로그인으로 세션 종료
+    SessionEvicted,
+    /// 방에서 추방됨 (`room_id`)
+    RoomKicked,
+    /// 방 없음 (`room_id`)
+    RoomNotFound,
+    /// 방 참가자가 아님 (`user_id`)
+    RoomNotInRoom,
+    /// 방 역할 권한 부족
+    RoomPermissionDenied,
+    /// 잘못된 방 관리 대상 (`reason`)
+    RoomInvalidTarget,
+    /// 서버 종료 공지 (`seconds`)
+    ServerShutdown,
+    /// 서버가 가득 차 접속 거절 (`seconds`: 재시도 대기 시간)
+    ServerBusy,
+}
+
+impl MessageKey {
+    pub const ALL: &'static [MessageKey] = &[
+        MessageKey::SessionResumeFailed,
+        MessageKey::SessionEvicted,
+        MessageKey::RoomKicked,
+        MessageKey::RoomNotFound,
+        MessageKey::RoomNotInRoom,
+        MessageKey::RoomPermissionDenied,
+        MessageKey::RoomInvalidTarget,
+        MessageKey::ServerShutdown,
+        MessageKey::ServerBusy,
+    ];
+
+    /// 카탈로그 키
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageKey::SessionResumeFailed => "session.resume_failed",
+            MessageKey::SessionEvicted => "session.evicted",
+            MessageKey::RoomKicked => "room.kicked",
+            MessageKey::RoomNotFound => "room.not_found",
+            MessageKey::RoomNotInRoom => "room.not_in_room",
+            MessageKey::RoomPermissionDenied => "room.permission_denied",
+            MessageKey::RoomInvalidTarget => "room.invalid_target",
+            MessageKey::ServerShutdown => "server.shutdown",
+            MessageKey::ServerBusy => "server.busy",
+        }
+    }
+}
+
+/// 템플릿 치환 인자 (`{이름}` -> 값, 스폰된 작업에서도 쓰도록 `Sync`)
+pub type MessageArgs<'a> = &'a [(&'a str, &'a (dyn fmt::Display + Sync))];
+
+#[derive(Debug, Deserialize)]
+struct CatalogFile {
+    default_locale: String,
+    #[serde(flatten)]
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+/// 로케일별 안내 메시지 템플릿
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    default_locale: String,
+    /// locale -> key -> template
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl MessageCatalog {
+    /// 빌드에 포함된 기본 카탈로그
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_CATALOG).expect("기본 메시지 카탈로그가 잘못되었습니다")
+    }
+
+    /// 카탈로그 파일 로드 (파일이 없으면 기본 카탈로그)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            warn!("메시지 카탈로그 파일이 없습니다: {} (기본 카탈로그 사용)", path.display());
+            return Ok(Self::builtin());
+        }
+
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("메시지 카탈로그 읽기 실패: {}", path.display()))?;
+        let catalog = Self::from_toml(&contents)
+            .with_context(|| format!("잘못된 메시지 카탈로그: {}", path.display()))?;
+
+        for locale in catalog.locales.keys() {
+            for key in MessageKey::ALL {
+                if catalog.template(locale, *key).is_none() {
+                    warn!("메시지 카탈로그 {}에 {} 없음 (기본 로케일로 대체)", locale, key.as_str());
+                }
+            }
+        }
+        info!("메시지 카탈로그 로드: {:?}", catalog.locales());
+        Ok(catalog)
+    }
+
+    /// TOML 문자열에서 카탈로그 생성 (기본 로케일은 모든 키를 가져야 함)
+    pub fn from_toml(contents: &str) -> Result<Self> {
+        let file: CatalogFile = toml::from_str(contents)?;
+        let catalog = Self {
+            default_locale: normalize_locale(&file.default_locale),
+            locales: file
+                .locales
+                .into_iter()
+                .map(|(locale, templates)| (normalize_locale(&locale), templates))
+                .collect(),
+        };
+
+        for key in MessageKey::ALL {
+            if catalog.template(&catalog.default_locale, *key).is_none() {
+                return Err(anyhow!("기본 로케일 {}에 {} 메시지가 없습니다", catalog.default_locale, key.as_str()));
+            }
+        }
+        Ok(catalog)
+    }
+
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// 지원 로케일 목록
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<&str> = self.locales.keys().map(String::as_str).collect();
+        locales.sort_unstable();
+        locales
+    }
+
+    /// 클라이언트가 요청한 로케일을 지원 로케일로 결정
+    ///
+    /// `en-US`를 요청했는데 `en`만 있으면 `en`, 아무것도 없으면 기본 로케일을 반환합니다.
+    pub fn negotiate(&self, requested: Option<&str>) -> String {
+        self.fallback_chain(requested.unwrap_or(&self.default_locale))
+            .into_iter()
+            .find(|locale| self.locales.contains_key(locale))
+            .unwrap_or_else(|| self.default_locale.clone())
+    }
+
+    /// 메시지 생성
+    ///
+    /// 로케일 대체 순서대로 템플릿을 찾고 `{이름}`을 인자로 치환합니다.
+    pub fn render(&self, locale: &str, key: MessageKey, args: MessageArgs<'_>) -> String {
+        let template = self
+            .fallback_chain(locale)
+            .iter()
+            .find_map(|locale| self.template(locale, key))
+            .unwrap_or(key.as_str());
+
+        let mut message = template.to_string();
+        for (name, value) in args {
+            message = message.replace(&format!("{{{}}}", name), &value.to_string());
+        }
+        message
+    }
+
+    fn template(&self, locale: &str, key: MessageKey) -> Option<&str> {
+        self.locales
+            .get(locale)
+            .and_then(|templates| templates.get(key.as_str()))
+            .map(String::as_str)
+    }
+
+    /// `ko-KR` -> [`ko-kr`, `ko`, 기본 로케일]
+    fn fallback_chain(&self, locale: &str) -> Vec<String> {
+        let locale = normalize_locale(locale);
+        let mut chain = Vec::with_capacity(3);
+        if let Some((language, _)) = locale.split_once('-') {
+            chain.push(locale.clone());
+            chain.push(language.to_string());
+        } else {
+            chain.push(locale);
+        }
+        if !chain.contains(&self.default_locale) {
+            chain.push(self.default_locale.clone());
+        }
+        chain
+    }
+}
+
+/// 로케일 표기 통일 (`ko_KR` -> `ko-kr`)
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_catalog_fallback() {
+        let catalog = MessageCatalog::builtin();
+        assert_eq!(catalog.locales(), vec!["en", "ko"]);
+        for locale in catalog.locales() {
+            for key in MessageKey::ALL {
+                assert!(catalog.template(locale, *key).is_some(), "{} {}", locale, key.as_str());
+            }
+        }
+
+        assert_eq!(catalog.negotiate(Some("en_US")), "en");
+        assert_eq!(catalog.negotiate(Some("fr-FR")), "ko");
+        assert_eq!(catalog.negotiate(None), "ko");
+
+        let room_id = 7;
+        assert_eq!(catalog.render("en-US", MessageKey::RoomKicked, &[("room_id", &room_id)]), "You were kicked from room 7");
+        assert_eq!(catalog.render("fr", MessageKey::RoomKicked, &[("room_id", &room_id)]), "방 7에서 추방되었습니다");
+    }
+
+    #[test]
+    fn test_partial_locale_falls_back_to_default() {
+        let catalog = MessageCatalog::from_toml(&format!("{}\n[ja]\n\"room.kicked\" = \"ルーム{{room_id}}から退出させられました\"\n", BUILTIN_CATALOG)).unwrap();
+        assert_eq!(catalog.render("ja", MessageKey::RoomKicked, &[("room_id", &1)]), "ルーム1から退出させられました");
+        assert_eq!(catalog.render("ja", MessageKey::RoomPermissionDenied, &[]), "이 작업을 수행할 권한이 없습니다");
+
+        assert!(MessageCatalog::from_toml("default_locale = \"de\"\n[de]\n").is_err());
+    }
+}
@@ -5,6 +5,8 @@
 pub mod simple_utils;
 pub mod error;
 pub mod network_utils;
+pub mod i18n;
 
 pub use simple_utils::*;
-pub use network_utils::{NetworkUtils, IpInfo, ConnectionQuality};
\ No newline at end of file
+pub use network_utils::{NetworkUtils, IpInfo, ConnectionQuality};
+pub use i18n::{MessageCatalog, MessageKey};
\ No newline at end of file